@@ -1,7 +1,10 @@
+use edda_core::policy::DomainOwner;
+use edda_core::types::{Attachment, DecisionScope};
 use edda_core::Event;
 use edda_ledger::DecisionView;
 use edda_ledger::Ledger;
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 pub mod staleness;
 
@@ -52,6 +55,14 @@ pub fn detect_input_type(query: &str, known_domains: &[String]) -> InputType {
 pub struct AskResult {
     pub query: String,
     pub input_type: String,
+    /// The moment the answer describes, when asked with
+    /// [`AskOptions::as_of`] (normalized to UTC).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<String>,
+    /// The best hits across decisions, commits, notes and conversations,
+    /// highest score first; see [`rank_hits`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub top: Vec<RankedHit>,
     pub decisions: Vec<DecisionHit>,
     pub timeline: Vec<DecisionHit>,
     pub related_commits: Vec<CommitHit>,
@@ -65,6 +76,73 @@ pub struct AskResult {
     pub dependents: Vec<DependentHit>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub override_risk: Option<OverrideRisk>,
+    /// Chronological rationale chain, populated only in [`AskMode::Why`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rationale: Vec<RationaleStep>,
+    /// Every value each matching key has held, populated only in
+    /// [`AskMode::History`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub history: Vec<KeyHistory>,
+    /// True when hits were dropped to fit `AskOptions::max_response_chars`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+}
+
+/// One step of a "why" rationale chain. Steps are ordered by timestamp.
+#[derive(Debug, Clone, Serialize)]
+pub struct RationaleStep {
+    /// "decision" | "commit" | "note" | "conversation"
+    pub kind: String,
+    /// Event id, or the transcript doc id for conversations.
+    pub id: String,
+    pub ts: String,
+    /// How the step joins the chain: "origin", "supersedes", "evidence",
+    /// "discussed", or the provenance rel that linked it.
+    pub link: String,
+    pub summary: String,
+}
+
+/// One hit in [`AskResult::top`], scored against the whole answer.
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedHit {
+    /// "decision" | "commit" | "note" | "conversation"
+    pub kind: String,
+    /// Event id, or the transcript doc id for conversations.
+    pub id: String,
+    pub ts: String,
+    pub summary: String,
+    pub score: f64,
+}
+
+/// The values one decision key has held over time ("history" mode).
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyHistory {
+    pub key: String,
+    pub domain: String,
+    /// True when a value that matched the query was held and later dropped,
+    /// and no matching value holds the key now: "tried it, moved on".
+    pub abandoned: bool,
+    /// Ordered by `from`.
+    pub values: Vec<ValueSpan>,
+}
+
+/// A stretch of time during which one value held a key on a branch.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueSpan {
+    pub value: String,
+    pub reason: String,
+    pub branch: String,
+    /// Decision that set the value.
+    pub event_id: String,
+    pub from: String,
+    /// When the next decision on the key replaced it; `None` while it still
+    /// holds or when it was walked back without a replacement.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// "active" | "superseded" | "revoked"
+    pub status: String,
+    /// Whether this value (or its key or reason) matched the query.
+    pub matched: bool,
 }
 
 /// A task matched by `ask`. The receipt is the point — it is where a finished
@@ -90,17 +168,63 @@ pub struct DecisionHit {
     pub branch: String,
     pub ts: String,
     pub is_active: bool,
+    /// True when the decision was walked back with `edda revoke`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub revoked: bool,
     /// Tags parsed from JSON array in the decision row.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
     /// Village scope identifier.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub village_id: Option<String>,
+    /// How far the decision reaches; omitted for the project default.
+    #[serde(skip_serializing_if = "is_project_scope")]
+    pub scope: DecisionScope,
     /// Decision-code freshness (q334 EDDA-STALENESS1). None ⇒ not checked
     /// (feature off, or repo_root missing at query time). Serialized only when
     /// present so existing JSON consumers stay unaffected.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub staleness: Option<crate::staleness::DecisionStaleness>,
+    /// Files attached with `edda decide --attach`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    /// Owner of the decision's domain from `policy.yaml` `domains`, with
+    /// `owners` resolved to the actors asked to approve.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<DomainOwner>,
+    /// Whether the decision was reviewed through a draft before it landed.
+    pub governance: Governance,
+}
+
+/// How a decision was governed: recorded directly, or cited as evidence by
+/// a draft that was approved and applied.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(tag = "via", rename_all = "snake_case")]
+pub enum Governance {
+    /// Recorded with `edda decide` and never reviewed.
+    #[default]
+    Direct,
+    Draft(DraftGovernance),
+}
+
+/// The applied draft a decision went through.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DraftGovernance {
+    pub draft_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_commit_id: Option<String>,
+    /// Everyone who approved the draft, in approval order.
+    pub approvers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<GovernanceStage>,
+}
+
+/// One approval stage of a governing draft.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GovernanceStage {
+    pub stage_id: String,
+    pub role: String,
+    pub approved_by: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -119,6 +243,12 @@ pub struct NoteHit {
     pub text: String,
     pub ts: String,
     pub branch: String,
+    /// True when the note was walked back with `edda revoke`.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub revoked: bool,
+    /// Files attached with `edda note --attach`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -150,6 +280,22 @@ pub struct OverrideRisk {
 
 // ── Options ──────────────────────────────────────────────────────────
 
+/// What `ask` should reconstruct.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AskMode {
+    /// Decisions, timeline, and related events for the query.
+    #[default]
+    Standard,
+    /// Rationale chain for an exact key: the original decision, the decisions
+    /// that superseded it, the commits that implemented them, and the notes
+    /// and conversations in between.
+    Why,
+    /// Every value every key has ever held, for keys where the query appears
+    /// in a key, value, or reason — "have we ever tried X?".
+    History,
+}
+
+#[derive(Clone)]
 pub struct AskOptions {
     pub limit: usize,
     pub include_superseded: bool,
@@ -159,10 +305,31 @@ pub struct AskOptions {
     pub after: Option<String>,
     /// ISO 8601 upper bound (inclusive) for temporal filtering.
     pub before: Option<String>,
+    /// Answer as of this moment (ISO 8601 timestamp or `YYYY-MM-DD`, which
+    /// means the end of that day): decisions are the ones active then, and
+    /// timelines stop there. See [`Ledger::decisions_as_of`].
+    pub as_of: Option<String>,
     /// Filter decisions whose tags contain any of these values (OR semantics).
     pub tags: Vec<String>,
     /// Filter decisions belonging to a specific village.
     pub village_id: Option<String>,
+    /// Keep only decisions made at this scope.
+    pub scope: Option<DecisionScope>,
+    pub mode: AskMode,
+    /// Cut note text, commit purposes, and conversation snippets to at most
+    /// this many characters. `None` keeps them whole.
+    pub snippet_chars: Option<usize>,
+    /// When cutting a snippet, end at the last sentence boundary inside the
+    /// limit instead of mid-word.
+    pub sentence_boundary: bool,
+    /// Upper bound on the serialized JSON size of the whole result. Lowest
+    /// priority hits are dropped until it fits; see [`apply_response_budget`].
+    pub max_response_chars: Option<usize>,
+    /// Rank keyword queries by similarity before the lexical `LIKE` match.
+    /// `false` keeps only exact substring hits.
+    pub semantic: bool,
+    /// Size of the cross-section [`AskResult::top`] ranking; 0 leaves it out.
+    pub top: usize,
 }
 
 impl Default for AskOptions {
@@ -174,8 +341,16 @@ impl Default for AskOptions {
             impact: false,
             after: None,
             before: None,
+            as_of: None,
             tags: vec![],
             village_id: None,
+            scope: None,
+            mode: AskMode::Standard,
+            snippet_chars: None,
+            sentence_boundary: false,
+            max_response_chars: None,
+            semantic: true,
+            top: DEFAULT_TOP,
         }
     }
 }
@@ -193,6 +368,15 @@ pub fn ask(
 ) -> anyhow::Result<AskResult> {
     let domains = ledger.list_domains()?;
     let input_type = detect_input_type(query, &domains);
+    if opts.mode == AskMode::Why && !matches!(input_type, InputType::ExactKey(_)) {
+        anyhow::bail!(
+            "why mode needs an exact decision key (e.g. db.engine), got {:?}",
+            query.trim()
+        );
+    }
+    if opts.mode == AskMode::History {
+        return ask_history(ledger, query, opts);
+    }
 
     // Branch filter helper: keep only decisions matching the requested branch
     let branch_filter = |hits: Vec<DecisionHit>| -> Vec<DecisionHit> {
@@ -202,13 +386,25 @@ pub fn ask(
         }
     };
 
-    // Tags filter helper: keep only decisions that have at least one matching tag (OR semantics)
+    // Tags filter helper: keep only decisions that have at least one matching tag (OR semantics).
+    // Both sides go through `edda tag rename` aliases so old names keep matching.
+    let aliases = if opts.tags.is_empty() {
+        edda_ledger::TagAliases::default()
+    } else {
+        ledger.tag_aliases()?
+    };
     let tags_filter = |hits: Vec<DecisionHit>| -> Vec<DecisionHit> {
         if opts.tags.is_empty() {
             return hits;
         }
         hits.into_iter()
-            .filter(|d| d.tags.iter().any(|t| opts.tags.contains(t)))
+            .filter(|d| {
+                d.tags.iter().any(|t| {
+                    opts.tags
+                        .iter()
+                        .any(|q| aliases.resolve(q) == aliases.resolve(t))
+                })
+            })
             .collect()
     };
 
@@ -223,8 +419,22 @@ pub fn ask(
         }
     };
 
+    // Scope filter helper: keep only decisions made at the requested scope
+    let scope_filter = |hits: Vec<DecisionHit>| -> Vec<DecisionHit> {
+        match opts.scope {
+            Some(s) => hits.into_iter().filter(|d| d.scope == s).collect(),
+            None => hits,
+        }
+    };
+
+    let revoked = ledger.revoked_events()?;
+    let as_of = opts.as_of.as_deref().map(normalize_as_of).transpose()?;
     let after_ref = opts.after.as_deref();
-    let before_ref = opts.before.as_deref();
+    // Timelines stop at `as_of` when it is earlier than `before`.
+    let before_ref = match (opts.before.as_deref(), as_of.as_deref()) {
+        (Some(b), Some(a)) => Some(b.min(a)),
+        (b, a) => b.or(a),
+    };
 
     let (decisions, timeline) = match &input_type {
         InputType::ExactKey(key) => {
@@ -265,14 +475,18 @@ pub fn ask(
             (active, tl)
         }
         InputType::Keyword(kw) => {
-            let mut semantic_hits = semantic_decision_search(
-                ledger,
-                kw,
-                opts.branch.as_deref(),
-                after_ref,
-                before_ref,
-                opts.limit,
-            )?;
+            let mut semantic_hits = if opts.semantic {
+                semantic_decision_search(
+                    ledger,
+                    kw,
+                    opts.branch.as_deref(),
+                    after_ref,
+                    before_ref,
+                    opts.limit,
+                )?
+            } else {
+                Vec::new()
+            };
 
             let lexical_fallback = ledger
                 .active_decisions_limited(None, Some(kw), after_ref, before_ref, opts.limit)?
@@ -320,9 +534,14 @@ pub fn ask(
                                     branch: event.branch.clone(),
                                     ts: event.ts.clone(),
                                     is_active: false,
+                                    revoked: revoked.contains_key(&event.event_id),
                                     tags: dp.tags.unwrap_or_default(),
                                     village_id: dp.village_id,
+                                    scope: dp.scope.unwrap_or_default(),
                                     staleness: None,
+                                    attachments: Attachment::from_payload(&event.payload),
+                                    owner: None,
+                                    governance: Governance::Direct,
                                 });
                             }
                         }
@@ -343,6 +562,34 @@ pub fn ask(
         }
     };
 
+    // Time travel: the decisions are the ones active at `as_of`, whatever
+    // happened to them since.
+    let decisions = match as_of.as_deref() {
+        Some(at) => {
+            let mut hits: Vec<DecisionHit> = ledger
+                .decisions_as_of(at)?
+                .iter()
+                .filter(|d| match &input_type {
+                    InputType::ExactKey(key) => d.key == *key,
+                    InputType::Domain(domain) => d.domain.eq_ignore_ascii_case(domain),
+                    InputType::Keyword(kw) => {
+                        let kw = kw.to_lowercase();
+                        d.key.to_lowercase().contains(&kw)
+                            || d.value.to_lowercase().contains(&kw)
+                            || d.reason.to_lowercase().contains(&kw)
+                    }
+                    InputType::Overview => true,
+                })
+                .filter(|d| after_ref.is_none_or(|a| d.ts.as_deref().unwrap_or("") >= a))
+                .map(to_decision_hit)
+                .collect();
+            hits = branch_filter(hits);
+            hits.truncate(opts.limit);
+            hits
+        }
+        None => decisions,
+    };
+
     // Apply tags filter (OR semantics) across all code paths
     let decisions = tags_filter(decisions);
     let timeline = tags_filter(timeline);
@@ -351,6 +598,10 @@ pub fn ask(
     let decisions = village_filter(decisions);
     let timeline = village_filter(timeline);
 
+    // Apply scope filter across all code paths
+    let decisions = scope_filter(decisions);
+    let timeline = scope_filter(timeline);
+
     // Collect decision event_ids for evidence chain matching
     let decision_event_ids: Vec<&str> = decisions
         .iter()
@@ -364,7 +615,7 @@ pub fn ask(
         ledger.find_related_commits(opts.branch.as_deref(), q, &decision_event_ids, opts.limit)?;
     let related_commits = to_commit_hits(&commit_events, &decision_event_ids, q, opts.limit);
     let note_events = ledger.find_related_notes(opts.branch.as_deref(), q, opts.limit)?;
-    let related_notes = to_note_hits(&note_events, opts.limit);
+    let related_notes = to_note_hits(&note_events, &revoked, opts.limit);
 
     let conversations = match transcript_search {
         Some(search_fn) if !q.is_empty() => search_fn(q, opts.limit),
@@ -426,9 +677,30 @@ pub fn ask(
         (vec![], None)
     };
 
-    Ok(AskResult {
+    let rationale = match (&input_type, opts.mode) {
+        (InputType::ExactKey(_), AskMode::Why) => {
+            build_rationale_chain(ledger, &timeline, &conversations, opts)?
+        }
+        _ => vec![],
+    };
+
+    let mut decisions = decisions;
+    load_attachments(ledger, &mut decisions);
+    load_owners(ledger, &mut decisions);
+    let mut timeline = timeline;
+    load_governance(ledger, decisions.iter_mut().chain(timeline.iter_mut()));
+
+    let now = match as_of.as_deref() {
+        Some(at) => {
+            time::OffsetDateTime::parse(at, &time::format_description::well_known::Rfc3339)?
+        }
+        None => time::OffsetDateTime::now_utc(),
+    };
+    let mut result = AskResult {
         query: q.to_string(),
         input_type: input_type_str.to_string(),
+        as_of,
+        top: vec![],
         decisions,
         timeline,
         related_commits,
@@ -437,6 +709,703 @@ pub fn ask(
         tasks,
         dependents,
         override_risk,
+        rationale,
+        history: vec![],
+        truncated: false,
+    };
+    if let Some(max) = opts.snippet_chars {
+        truncate_snippets(&mut result, max, opts.sentence_boundary);
+    }
+    result.top = rank_hits(&result, now, opts.top);
+    if let Some(max) = opts.max_response_chars {
+        apply_response_budget(&mut result, max);
+    }
+    Ok(result)
+}
+
+/// Decisions kept per key in a batch answer's timeline.
+pub const BATCH_TIMELINE_LEN: usize = 5;
+
+/// One key's answer in a batch query; see [`ask_keys`].
+#[derive(Debug, Serialize)]
+pub struct KeyAnswer {
+    /// The decision holding the key (at `as_of`, when set); `None` when the
+    /// key was never decided or no decision on it is active.
+    pub active: Option<DecisionHit>,
+    /// The latest decisions on the key, oldest first, at most
+    /// [`BATCH_TIMELINE_LEN`].
+    pub timeline: Vec<DecisionHit>,
+}
+
+/// Answer several exact keys at once: key → active decision plus a short
+/// timeline. For agents checking a handful of constraints before acting.
+///
+/// Honors the branch, time, tag, village and scope filters of `opts`; the
+/// mode, impact and snippet options do not apply to a batch.
+pub fn ask_keys(
+    ledger: &Ledger,
+    keys: &[String],
+    opts: &AskOptions,
+) -> anyhow::Result<BTreeMap<String, KeyAnswer>> {
+    let mut answers = BTreeMap::new();
+    for key in keys {
+        let key = key.trim();
+        if !matches!(detect_input_type(key, &[]), InputType::ExactKey(_)) {
+            anyhow::bail!("batch queries take exact decision keys (e.g. db.engine), got {key:?}");
+        }
+        let key_opts = AskOptions {
+            limit: opts.limit,
+            branch: opts.branch.clone(),
+            after: opts.after.clone(),
+            before: opts.before.clone(),
+            as_of: opts.as_of.clone(),
+            tags: opts.tags.clone(),
+            village_id: opts.village_id.clone(),
+            scope: opts.scope,
+            ..Default::default()
+        };
+        let result = ask(ledger, key, &key_opts, None)?;
+        let mut timeline = result.timeline;
+        timeline.sort_by(|a, b| a.ts.cmp(&b.ts));
+        let skip = timeline.len().saturating_sub(BATCH_TIMELINE_LEN);
+        answers.insert(
+            key.to_string(),
+            KeyAnswer {
+                active: result.decisions.into_iter().next(),
+                timeline: timeline.into_iter().skip(skip).collect(),
+            },
+        );
+    }
+    Ok(answers)
+}
+
+/// Turn an `as_of` argument into a UTC RFC 3339 bound comparable with event
+/// timestamps. A bare date means the end of that day.
+pub fn normalize_as_of(as_of: &str) -> anyhow::Result<String> {
+    use time::format_description::well_known::Rfc3339;
+    let s = as_of.trim();
+    if s.len() == 10 && time::OffsetDateTime::parse(&format!("{s}T00:00:00Z"), &Rfc3339).is_ok() {
+        return Ok(format!("{s}T23:59:59Z"));
+    }
+    let ts = time::OffsetDateTime::parse(s, &Rfc3339).map_err(|_| {
+        anyhow::anyhow!("as-of must be a date (2026-01-15) or an RFC 3339 timestamp, got {s:?}")
+    })?;
+    Ok(ts.to_offset(time::UtcOffset::UTC).format(&Rfc3339)?)
+}
+
+// ── Truncation controls ──────────────────────────────────────────────
+
+/// Cut `text` to at most `max_chars` characters, appending "..." when cut.
+///
+/// With `sentence_boundary`, the cut lands after the last `.`, `!`, `?`, `。`
+/// or newline inside the limit, provided that keeps at least half of it.
+pub fn truncate_snippet(text: &str, max_chars: usize, sentence_boundary: bool) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let head: String = text.chars().take(max_chars).collect();
+    if sentence_boundary {
+        let cut = head
+            .char_indices()
+            .filter(|(_, c)| matches!(c, '.' | '!' | '?' | '。' | '！' | '？' | '\n'))
+            .map(|(i, c)| i + c.len_utf8())
+            .next_back();
+        if let Some(end) = cut {
+            if head[..end].chars().count() * 2 >= max_chars {
+                return head[..end].trim_end().to_string();
+            }
+        }
+    }
+    format!("{}...", head.trim_end())
+}
+
+fn truncate_snippets(result: &mut AskResult, max_chars: usize, sentence_boundary: bool) {
+    let cut = |s: &mut String| *s = truncate_snippet(s, max_chars, sentence_boundary);
+    result
+        .related_notes
+        .iter_mut()
+        .for_each(|n| cut(&mut n.text));
+    result
+        .related_commits
+        .iter_mut()
+        .for_each(|c| cut(&mut c.purpose));
+    result
+        .conversations
+        .iter_mut()
+        .for_each(|c| cut(&mut c.snippet));
+    result
+        .rationale
+        .iter_mut()
+        .for_each(|r| cut(&mut r.summary));
+    for t in &mut result.tasks {
+        if let Some(r) = t.receipt.as_mut() {
+            cut(r);
+        }
+    }
+}
+
+/// Drop hits until the serialized result fits in `max_chars`.
+///
+/// Sections are emptied from the least to the most authoritative:
+/// conversations, notes, commits, tasks, dependents, timeline, rationale,
+/// history, and decisions last. Within a section the tail goes first, since every section
+/// is already ordered by relevance or recency. Sets `truncated` when anything
+/// was dropped.
+pub fn apply_response_budget(result: &mut AskResult, max_chars: usize) {
+    let size = |r: &AskResult| serde_json::to_string(r).map(|s| s.len()).unwrap_or(0);
+    while size(result) > max_chars {
+        let dropped = result.conversations.pop().is_some()
+            || result.related_notes.pop().is_some()
+            || result.related_commits.pop().is_some()
+            || result.tasks.pop().is_some()
+            || result.dependents.pop().is_some()
+            || result.timeline.pop().is_some()
+            || result.rationale.pop().is_some()
+            || result.history.pop().is_some()
+            || result.top.pop().is_some()
+            || result.decisions.pop().is_some();
+        if !dropped {
+            break;
+        }
+        result.truncated = true;
+    }
+}
+
+// ── Ranking ──────────────────────────────────────────────────────────
+
+/// Hits kept in [`AskResult::top`] unless [`AskOptions::top`] says otherwise.
+pub const DEFAULT_TOP: usize = 10;
+
+/// Age in days at which a hit's recency weight halves.
+const RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+/// Weight of the share of query terms a hit contains.
+const KEYWORD_WEIGHT: f64 = 1.0;
+/// Weight of a hit made at `now`; it decays with age.
+const RECENCY_WEIGHT: f64 = 0.5;
+/// Added for a commit that implements a matched decision, and for a
+/// decision or note with attached evidence.
+const EVIDENCE_BOOST: f64 = 0.3;
+
+/// Rank the decisions, commits, notes and conversations of `result`
+/// together and keep the best `n`.
+///
+/// A hit scores [`KEYWORD_WEIGHT`] times the share of query terms it
+/// contains, plus [`RECENCY_WEIGHT`] halved every
+/// [`RECENCY_HALF_LIFE_DAYS`] of age at `now`, plus [`EVIDENCE_BOOST`] when
+/// it is linked to evidence. Revoked hits are left out. Ties go to the
+/// newer hit.
+pub fn rank_hits(result: &AskResult, now: time::OffsetDateTime, n: usize) -> Vec<RankedHit> {
+    if n == 0 {
+        return vec![];
+    }
+    let terms: Vec<String> = result
+        .query
+        .split_whitespace()
+        .map(str::to_lowercase)
+        .collect();
+    let hit =
+        |kind: &str, id: &str, ts: &str, summary: String, text: &str, evidence: bool| RankedHit {
+            kind: kind.to_string(),
+            id: id.to_string(),
+            ts: ts.to_string(),
+            summary,
+            score: relevance_score(&terms, text, ts, now, evidence),
+        };
+
+    let decisions = result.decisions.iter().filter(|d| !d.revoked).map(|d| {
+        let text = format!("{} {} {} {}", d.key, d.value, d.reason, d.tags.join(" "));
+        let summary = format!("{} = {}", d.key, d.value);
+        hit(
+            "decision",
+            &d.event_id,
+            &d.ts,
+            summary,
+            &text,
+            !d.attachments.is_empty(),
+        )
+    });
+    let commits = result.related_commits.iter().map(|c| {
+        let text = format!("{} {}", c.title, c.purpose);
+        hit(
+            "commit",
+            &c.event_id,
+            &c.ts,
+            c.title.clone(),
+            &text,
+            c.match_type == "evidence",
+        )
+    });
+    let notes = result.related_notes.iter().filter(|n| !n.revoked).map(|n| {
+        let summary = truncate_snippet(&n.text, 120, false);
+        hit(
+            "note",
+            &n.event_id,
+            &n.ts,
+            summary,
+            &n.text,
+            !n.attachments.is_empty(),
+        )
+    });
+    let conversations = result.conversations.iter().map(|c| {
+        let summary = truncate_snippet(&c.snippet, 120, false);
+        hit("conversation", &c.doc_id, &c.ts, summary, &c.snippet, false)
+    });
+
+    let mut ranked: Vec<RankedHit> = decisions
+        .chain(commits)
+        .chain(notes)
+        .chain(conversations)
+        .collect();
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| b.ts.cmp(&a.ts)));
+    ranked.truncate(n);
+    ranked
+}
+
+fn relevance_score(
+    terms: &[String],
+    text: &str,
+    ts: &str,
+    now: time::OffsetDateTime,
+    evidence: bool,
+) -> f64 {
+    let keyword = if terms.is_empty() {
+        0.0
+    } else {
+        let text = text.to_lowercase();
+        let matched = terms.iter().filter(|t| text.contains(t.as_str())).count();
+        matched as f64 / terms.len() as f64
+    };
+    // An unreadable timestamp gets no recency; one after `now` gets all of it.
+    let recency = parse_hit_ts(ts).map_or(0.0, |at| {
+        let age_days = ((now - at).as_seconds_f64() / 86_400.0).max(0.0);
+        0.5_f64.powf(age_days / RECENCY_HALF_LIFE_DAYS)
+    });
+    let boost = if evidence { EVIDENCE_BOOST } else { 0.0 };
+    let score = KEYWORD_WEIGHT * keyword + RECENCY_WEIGHT * recency + boost;
+    (score * 1000.0).round() / 1000.0
+}
+
+/// An RFC 3339 timestamp, or a bare date read as its midnight UTC.
+fn parse_hit_ts(ts: &str) -> Option<time::OffsetDateTime> {
+    use time::format_description::well_known::Rfc3339;
+    time::OffsetDateTime::parse(ts, &Rfc3339)
+        .or_else(|_| time::OffsetDateTime::parse(&format!("{ts}T00:00:00Z"), &Rfc3339))
+        .ok()
+}
+
+// ── Citations ────────────────────────────────────────────────────────
+
+/// Ledger events cited by `result`, as `(event_id, label)` pairs in section
+/// order without duplicates.
+///
+/// Covers decisions, timeline, commits, notes, rationale steps and history
+/// spans; tasks, dependents and conversations are not ledger events.
+pub fn cited_events(result: &AskResult) -> Vec<(String, String)> {
+    let decisions = result
+        .decisions
+        .iter()
+        .chain(&result.timeline)
+        .map(|d| (&d.event_id, format!("{}={}", d.key, d.value)));
+    let commits = result
+        .related_commits
+        .iter()
+        .map(|c| (&c.event_id, c.title.clone()));
+    let notes = result
+        .related_notes
+        .iter()
+        .map(|n| (&n.event_id, truncate_snippet(&n.text, 80, false)));
+    let steps = result
+        .rationale
+        .iter()
+        .filter(|r| r.kind != "conversation")
+        .map(|r| (&r.id, truncate_snippet(&r.summary, 80, false)));
+    let spans = result.history.iter().flat_map(|h| {
+        h.values
+            .iter()
+            .map(|v| (&v.event_id, format!("{}={}", h.key, v.value)))
+    });
+
+    let mut out: Vec<(String, String)> = Vec::new();
+    for (id, label) in decisions
+        .chain(commits)
+        .chain(notes)
+        .chain(steps)
+        .chain(spans)
+    {
+        if !out.iter().any(|(seen, _)| seen == id) {
+            out.push((id.clone(), label));
+        }
+    }
+    out
+}
+
+// ── Natural-language questions ───────────────────────────────────────
+
+/// Most search terms taken from one question.
+const MAX_QUESTION_TERMS: usize = 6;
+
+/// Words that carry no topic in a question ("why did we stop using redis").
+const QUESTION_STOPWORDS: &[&str] = &[
+    "about", "after", "and", "are", "because", "before", "but", "can", "could", "did", "does",
+    "for", "from", "had", "has", "have", "how", "into", "its", "our", "should", "stop", "stopped",
+    "that", "the", "their", "them", "then", "there", "they", "this", "use", "used", "using", "was",
+    "were", "what", "when", "where", "which", "who", "why", "will", "with", "would", "you",
+];
+
+/// The topic words of a natural-language question, in order: lowercased,
+/// without stopwords and words under three characters. Dotted keys such as
+/// `db.engine` are kept whole.
+pub fn question_terms(question: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in question.split(|c: char| !(c.is_alphanumeric() || "._-".contains(c))) {
+        let word = word
+            .trim_matches(|c: char| ".-_".contains(c))
+            .to_lowercase();
+        if word.chars().count() < 3
+            || QUESTION_STOPWORDS.contains(&word.as_str())
+            || terms.contains(&word)
+        {
+            continue;
+        }
+        terms.push(word);
+        if terms.len() == MAX_QUESTION_TERMS {
+            break;
+        }
+    }
+    terms
+}
+
+/// Answer a natural-language question with the structured pipeline: [`ask`]
+/// once per [`question_terms`] term, hits merged without duplicates and
+/// [`AskResult::top`] re-ranked against all terms.
+pub fn ask_question(
+    ledger: &Ledger,
+    question: &str,
+    opts: &AskOptions,
+    transcript_search: Option<&TranscriptSearchFn>,
+) -> anyhow::Result<AskResult> {
+    let terms = question_terms(question);
+    if terms.is_empty() {
+        anyhow::bail!("no searchable terms in question {:?}", question.trim());
+    }
+    let per_term = AskOptions {
+        top: 0,
+        max_response_chars: None,
+        ..opts.clone()
+    };
+    let mut merged: Option<AskResult> = None;
+    for term in &terms {
+        let result = ask(ledger, term, &per_term, transcript_search)?;
+        match merged.as_mut() {
+            None => merged = Some(result),
+            Some(into) => merge_results(into, result),
+        }
+    }
+    let mut result = merged.expect("at least one term");
+    result.query = terms.join(" ");
+    result.input_type = "question".to_string();
+    let now = match result.as_of.as_deref() {
+        Some(at) => {
+            time::OffsetDateTime::parse(at, &time::format_description::well_known::Rfc3339)?
+        }
+        None => time::OffsetDateTime::now_utc(),
+    };
+    result.top = rank_hits(&result, now, opts.top);
+    if let Some(max) = opts.max_response_chars {
+        apply_response_budget(&mut result, max);
+    }
+    Ok(result)
+}
+
+/// Append the hits of `other` that `into` does not have yet.
+fn merge_results(into: &mut AskResult, other: AskResult) {
+    fn extend<T, K: PartialEq>(into: &mut Vec<T>, other: Vec<T>, id: impl Fn(&T) -> K) {
+        for hit in other {
+            if !into.iter().any(|h| id(h) == id(&hit)) {
+                into.push(hit);
+            }
+        }
+    }
+    extend(&mut into.decisions, other.decisions, |d| d.event_id.clone());
+    extend(&mut into.timeline, other.timeline, |d| d.event_id.clone());
+    extend(&mut into.related_commits, other.related_commits, |c| {
+        c.event_id.clone()
+    });
+    extend(&mut into.related_notes, other.related_notes, |n| {
+        n.event_id.clone()
+    });
+    extend(&mut into.conversations, other.conversations, |c| {
+        c.doc_id.clone()
+    });
+    extend(&mut into.tasks, other.tasks, |t| t.task_id);
+    extend(&mut into.dependents, other.dependents, |d| d.key.clone());
+    into.override_risk = into.override_risk.take().or(other.override_risk);
+}
+
+// ── Value history ("history" mode) ───────────────────────────────────
+
+fn ask_history(ledger: &Ledger, query: &str, opts: &AskOptions) -> anyhow::Result<AskResult> {
+    let q = query.trim();
+    if q.is_empty() {
+        anyhow::bail!("history mode needs a keyword (e.g. redis)");
+    }
+    let rows: Vec<DecisionView> = ledger
+        .decision_history(q, opts.after.as_deref(), opts.before.as_deref())?
+        .into_iter()
+        .filter(|r| opts.branch.as_ref().is_none_or(|b| r.branch == *b))
+        .filter(|r| {
+            opts.scope
+                .is_none_or(|s| r.propagation.parse::<DecisionScope>().ok() == Some(s))
+        })
+        .filter(|r| {
+            opts.village_id
+                .as_ref()
+                .is_none_or(|v| r.village_id.as_deref() == Some(v.as_str()))
+        })
+        .collect();
+
+    let mut by_key: BTreeMap<String, Vec<DecisionView>> = BTreeMap::new();
+    for row in rows {
+        by_key.entry(row.key.clone()).or_default().push(row);
+    }
+    let history = by_key
+        .into_iter()
+        .filter_map(|(key, rows)| key_history(&key, &rows, q))
+        .take(opts.limit)
+        .collect();
+
+    let mut result = AskResult {
+        query: q.to_string(),
+        input_type: "history".to_string(),
+        top: vec![],
+        as_of: None,
+        decisions: vec![],
+        timeline: vec![],
+        related_commits: vec![],
+        related_notes: vec![],
+        conversations: vec![],
+        tasks: vec![],
+        dependents: vec![],
+        override_risk: None,
+        rationale: vec![],
+        history,
+        truncated: false,
+    };
+    if let Some(max) = opts.max_response_chars {
+        apply_response_budget(&mut result, max);
+    }
+    Ok(result)
+}
+
+/// Fold one key's decisions (time-ordered) into value spans. A span ends
+/// where the next decision on the same branch begins; repeated decisions of
+/// the same value extend the span. `None` when nothing on the key matched
+/// `query` (the key was only returned through a filter-dropped row).
+fn key_history(key: &str, rows: &[DecisionView], query: &str) -> Option<KeyHistory> {
+    let needle = query.to_lowercase();
+    let key_matches = key.to_lowercase().contains(&needle);
+    let mut values: Vec<ValueSpan> = Vec::new();
+    // Index of the open span per branch.
+    let mut open: BTreeMap<&str, usize> = BTreeMap::new();
+
+    for row in rows {
+        let ts = row.ts.clone().unwrap_or_default();
+        let status = match row.status.as_str() {
+            "active" | "experimental" => "active",
+            "revoked" => "revoked",
+            _ => "superseded",
+        };
+        if let Some(&i) = open.get(row.branch.as_str()) {
+            if values[i].value == row.value {
+                values[i].status = status.to_string();
+                continue;
+            }
+            values[i].until = Some(ts.clone());
+        }
+        let matched = key_matches
+            || row.value.to_lowercase().contains(&needle)
+            || row.reason.to_lowercase().contains(&needle);
+        open.insert(row.branch.as_str(), values.len());
+        values.push(ValueSpan {
+            value: row.value.clone(),
+            reason: row.reason.clone(),
+            branch: row.branch.clone(),
+            event_id: row.event_id.clone(),
+            from: ts,
+            until: None,
+            status: status.to_string(),
+            matched,
+        });
+    }
+
+    if !values.iter().any(|v| v.matched) {
+        return None;
+    }
+    values.sort_by(|a, b| a.from.cmp(&b.from));
+    let abandoned = values.iter().any(|v| v.matched && v.status != "active")
+        && !values.iter().any(|v| v.matched && v.status == "active");
+    Some(KeyHistory {
+        key: key.to_string(),
+        domain: rows.first().map(|r| r.domain.clone()).unwrap_or_default(),
+        abandoned,
+        values,
+    })
+}
+
+// ── Rationale chain ("why" mode) ─────────────────────────────────────
+
+/// Build the rationale chain for a key from its decision timeline.
+///
+/// Decisions are walked back along `supersedes` edges so an origin recorded
+/// under another key still heads the chain. Commits and notes join when their
+/// refs or provenance point at a chain decision, or when a chain decision
+/// cites them. Conversations join when they fall inside the chain's time span.
+fn build_rationale_chain(
+    ledger: &Ledger,
+    timeline: &[DecisionHit],
+    conversations: &[ConversationHit],
+    opts: &AskOptions,
+) -> anyhow::Result<Vec<RationaleStep>> {
+    let mut decisions: Vec<DecisionView> = Vec::new();
+    for hit in timeline {
+        if let Some(row) = ledger.get_decision_by_event_id(&hit.event_id)? {
+            decisions.push(row);
+        }
+    }
+
+    // Follow supersedes edges past the start of the key's own timeline.
+    let mut cursor = decisions.first().and_then(|d| d.supersedes_id.clone());
+    while let Some(id) = cursor {
+        if decisions.iter().any(|d| d.event_id == id) {
+            break;
+        }
+        match ledger.get_decision_by_event_id(&id)? {
+            Some(row) => {
+                cursor = row.supersedes_id.clone();
+                decisions.insert(0, row);
+            }
+            None => break,
+        }
+    }
+
+    let mut steps: Vec<RationaleStep> = Vec::new();
+    let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let ids: Vec<&str> = decisions.iter().map(|d| d.event_id.as_str()).collect();
+
+    for d in &decisions {
+        let link = match &d.supersedes_id {
+            Some(_) => "supersedes",
+            None => "origin",
+        };
+        let summary = if d.reason.is_empty() {
+            format!("{} = {}", d.key, d.value)
+        } else {
+            format!("{} = {} — {}", d.key, d.value, d.reason)
+        };
+        seen.insert(d.event_id.clone());
+        steps.push(RationaleStep {
+            kind: "decision".to_string(),
+            id: d.event_id.clone(),
+            ts: d.ts.clone().unwrap_or_default(),
+            link: link.to_string(),
+            summary,
+        });
+
+        // Events the decision itself cites (evidence refs, based_on, ...).
+        let Some(event) = ledger.get_event(&d.event_id)? else {
+            continue;
+        };
+        let cited = event
+            .refs
+            .events
+            .iter()
+            .map(|t| (t.as_str(), "evidence"))
+            .chain(
+                event
+                    .refs
+                    .provenance
+                    .iter()
+                    .filter(|p| p.rel != edda_core::types::rel::SUPERSEDES)
+                    .map(|p| (p.target.as_str(), p.rel.as_str())),
+            );
+        for (target, link) in cited {
+            if ids.contains(&target) || seen.contains(target) {
+                continue;
+            }
+            if let Some(cited_event) = ledger.get_event(target)? {
+                if let Some(step) = event_step(&cited_event, link) {
+                    seen.insert(step.id.clone());
+                    steps.push(step);
+                }
+            }
+        }
+    }
+
+    // Commits and notes that point back at a chain decision.
+    let commits = ledger.find_related_commits(opts.branch.as_deref(), "", &ids, opts.limit)?;
+    let notes = ledger.iter_events_by_type("note")?;
+    let citing = commits.iter().chain(notes.iter().filter(|e| {
+        !edda_core::decision::is_decision(&e.payload)
+            && opts.branch.as_ref().is_none_or(|b| e.branch == *b)
+    }));
+    for event in citing {
+        if seen.contains(&event.event_id) {
+            continue;
+        }
+        let link = if event.refs.events.iter().any(|t| ids.contains(&t.as_str())) {
+            Some("evidence")
+        } else {
+            event
+                .refs
+                .provenance
+                .iter()
+                .find(|p| ids.contains(&p.target.as_str()))
+                .map(|p| p.rel.as_str())
+        };
+        if let Some(step) = link.and_then(|l| event_step(event, l)) {
+            seen.insert(step.id.clone());
+            steps.push(step);
+        }
+    }
+
+    // Conversations that happened while the chain was being written.
+    let start = steps.iter().map(|s| s.ts.clone()).min().unwrap_or_default();
+    let end = steps.iter().map(|s| s.ts.clone()).max().unwrap_or_default();
+    for c in conversations {
+        if !start.is_empty() && c.ts >= start && c.ts <= end {
+            steps.push(RationaleStep {
+                kind: "conversation".to_string(),
+                id: c.doc_id.clone(),
+                ts: c.ts.clone(),
+                link: "discussed".to_string(),
+                summary: format!("[{}] {}", c.session_id, c.snippet),
+            });
+        }
+    }
+
+    steps.sort_by(|a, b| a.ts.cmp(&b.ts));
+    Ok(steps)
+}
+
+/// Turn a cited commit or plain note into a rationale step.
+fn event_step(event: &Event, link: &str) -> Option<RationaleStep> {
+    let (kind, summary) = match event.event_type.as_str() {
+        "commit" => (
+            "commit",
+            event.payload["title"].as_str().unwrap_or("").to_string(),
+        ),
+        "note" if !edda_core::decision::is_decision(&event.payload) => (
+            "note",
+            event.payload["text"].as_str().unwrap_or("").to_string(),
+        ),
+        _ => return None,
+    };
+    Some(RationaleStep {
+        kind: kind.to_string(),
+        id: event.event_id.clone(),
+        ts: event.ts.clone(),
+        link: link.to_string(),
+        summary,
     })
 }
 
@@ -621,7 +1590,11 @@ fn to_commit_hits(
 
 /// Convert SQL-prefiltered note events into `NoteHit`.
 /// Events are already filtered by SQL (branch, keyword, excluding decisions/digests).
-fn to_note_hits(events: &[Event], limit: usize) -> Vec<NoteHit> {
+fn to_note_hits(
+    events: &[Event],
+    revoked: &BTreeMap<String, String>,
+    limit: usize,
+) -> Vec<NoteHit> {
     events
         .iter()
         .take(limit)
@@ -636,6 +1609,8 @@ fn to_note_hits(events: &[Event], limit: usize) -> Vec<NoteHit> {
                 text: text.to_string(),
                 ts: event.ts.clone(),
                 branch: event.branch.clone(),
+                revoked: revoked.contains_key(&event.event_id),
+                attachments: Attachment::from_payload(&event.payload),
             }
         })
         .collect()
@@ -773,15 +1748,51 @@ fn vector_norm(
 
 // ── Human-readable formatting ────────────────────────────────────────
 
+/// Render a batch answer ([`ask_keys`]): one block per key.
+pub fn format_keys_human(answers: &BTreeMap<String, KeyAnswer>) -> String {
+    let mut out = String::new();
+    for (key, answer) in answers {
+        out.push_str(&format!("── {key} "));
+        out.push_str(&"─".repeat(36usize.saturating_sub(key.chars().count() + 4)));
+        out.push('\n');
+        match &answer.active {
+            Some(d) => out.push_str(&format!(
+                "  {} = {} — {}\n  branch: {} | {}\n",
+                d.key, d.value, d.reason, d.branch, d.ts
+            )),
+            None => out.push_str("  (no active decision)\n"),
+        }
+        for d in &answer.timeline {
+            out.push_str(&format!(
+                "    {}  {}  ({})\n",
+                d.ts,
+                d.value,
+                decision_status(d)
+            ));
+        }
+        out.push('\n');
+    }
+    out
+}
+
 pub fn format_human(result: &AskResult) -> String {
     let mut out = String::new();
+    if let Some(at) = &result.as_of {
+        out.push_str(&format!("As of {at}\n\n"));
+    }
+    let header_len = out.len();
 
     if !result.decisions.is_empty() {
         out.push_str("── Decisions ──────────────────────────\n");
         for d in &result.decisions {
-            let status = if d.is_active { "active" } else { "superseded" };
+            let status = decision_status(d);
+            let scope = if is_project_scope(&d.scope) {
+                String::new()
+            } else {
+                format!(" | scope: {}", d.scope)
+            };
             out.push_str(&format!(
-                "  {} = {} — {}\n  branch: {} | {} | {}\n",
+                "  {} = {} — {}\n  branch: {} | {} | {}{scope}\n",
                 d.key, d.value, d.reason, d.branch, d.ts, status
             ));
             if let Some(st) = &d.staleness {
@@ -800,6 +1811,11 @@ pub fn format_human(result: &AskResult) -> String {
                     }
                 }
             }
+            if let Some(owner) = &d.owner {
+                push_owner(&mut out, owner);
+            }
+            push_governance(&mut out, &d.governance);
+            push_attachments(&mut out, &d.attachments);
             out.push('\n');
         }
     }
@@ -807,7 +1823,7 @@ pub fn format_human(result: &AskResult) -> String {
     if !result.timeline.is_empty() {
         out.push_str("── Timeline ───────────────────────────\n");
         for d in &result.timeline {
-            let status = if d.is_active { "active" } else { "superseded" };
+            let status = decision_status(d);
             out.push_str(&format!(
                 "  {}  {} = {}  ({})\n",
                 d.ts, d.key, d.value, status
@@ -816,9 +1832,59 @@ pub fn format_human(result: &AskResult) -> String {
         out.push('\n');
     }
 
-    if !result.related_commits.is_empty() {
-        out.push_str("── Related Commits ────────────────────\n");
-        for c in &result.related_commits {
+    if !result.rationale.is_empty() {
+        out.push_str("── Rationale ──────────────────────────\n");
+        for step in &result.rationale {
+            let summary = if step.summary.len() > 120 {
+                format!(
+                    "{}...",
+                    &step.summary[..step.summary.floor_char_boundary(117)]
+                )
+            } else {
+                step.summary.clone()
+            };
+            out.push_str(&format!(
+                "  {}  [{}] {}  ({})\n",
+                step.ts, step.kind, summary, step.link
+            ));
+        }
+        out.push('\n');
+    }
+
+    if !result.history.is_empty() {
+        out.push_str("── History ────────────────────────────\n");
+        for h in &result.history {
+            let mark = if h.abandoned {
+                "  (tried, abandoned)"
+            } else {
+                ""
+            };
+            out.push_str(&format!("  {}{mark}\n", h.key));
+            for v in &h.values {
+                let from = v.from.get(..10).unwrap_or(&v.from);
+                let until = match (&v.until, v.status.as_str()) {
+                    (Some(u), _) => u.get(..10).unwrap_or(u).to_string(),
+                    (None, "active") => "now".to_string(),
+                    (None, _) => "?".to_string(),
+                };
+                let hit = if v.matched { "*" } else { " " };
+                let reason = if v.reason.is_empty() {
+                    String::new()
+                } else {
+                    format!(" — {}", v.reason)
+                };
+                out.push_str(&format!(
+                    "  {hit} {from} → {until}  {}{reason}  ({}, {})\n",
+                    v.value, v.status, v.branch
+                ));
+            }
+            out.push('\n');
+        }
+    }
+
+    if !result.related_commits.is_empty() {
+        out.push_str("── Related Commits ────────────────────\n");
+        for c in &result.related_commits {
             out.push_str(&format!(
                 "  {} ({}, {})\n  match: {}\n\n",
                 c.title, c.ts, c.branch, c.match_type
@@ -829,17 +1895,23 @@ pub fn format_human(result: &AskResult) -> String {
     if !result.related_notes.is_empty() {
         out.push_str("── Related Notes ──────────────────────\n");
         for n in &result.related_notes {
+            let mark = if n.revoked { "[revoked] " } else { "" };
             if n.text.len() > 120 {
                 let end = n.text.floor_char_boundary(117);
                 out.push_str(&format!(
-                    "  \"{}...\" ({}, {})\n\n",
+                    "  {mark}\"{}...\" ({}, {})\n",
                     &n.text[..end],
                     n.ts,
                     n.branch
                 ));
             } else {
-                out.push_str(&format!("  \"{}\" ({}, {})\n\n", n.text, n.ts, n.branch));
+                out.push_str(&format!(
+                    "  {mark}\"{}\" ({}, {})\n",
+                    n.text, n.ts, n.branch
+                ));
             }
+            push_attachments(&mut out, &n.attachments);
+            out.push('\n');
         }
     }
 
@@ -900,15 +1972,63 @@ pub fn format_human(result: &AskResult) -> String {
         }
     }
 
-    if out.is_empty() {
+    if out.len() == header_len {
         out.push_str("No results found.\n");
     }
 
+    if result.truncated {
+        out.push_str("(some results omitted to fit the response size limit)\n");
+    }
+
     out
 }
 
 // ── Internal helpers ─────────────────────────────────────────────────
 
+fn decision_status(d: &DecisionHit) -> &'static str {
+    if d.revoked {
+        "revoked"
+    } else if d.is_active {
+        "active"
+    } else {
+        "superseded"
+    }
+}
+
+/// One `attached:` line per file, as a Markdown link to its blob.
+fn push_governance(out: &mut String, governance: &Governance) {
+    let Governance::Draft(g) = governance else {
+        return;
+    };
+    if g.approvers.is_empty() {
+        out.push_str(&format!("  via draft {}\n", g.draft_id));
+    } else {
+        out.push_str(&format!(
+            "  via draft {}, approved by {}\n",
+            g.draft_id,
+            g.approvers.join(", ")
+        ));
+    }
+}
+
+fn push_owner(out: &mut String, owner: &DomainOwner) {
+    if owner.owners.is_empty() {
+        out.push_str(&format!("  owner: {}\n", owner.role));
+    } else {
+        out.push_str(&format!(
+            "  owner: {} ({})\n",
+            owner.role,
+            owner.owners.join(", ")
+        ));
+    }
+}
+
+fn push_attachments(out: &mut String, attachments: &[Attachment]) {
+    for a in attachments {
+        out.push_str(&format!("  attached: {}\n", a.markdown_link()));
+    }
+}
+
 fn to_decision_hit(row: &DecisionView) -> DecisionHit {
     DecisionHit {
         event_id: row.event_id.clone(),
@@ -919,12 +2039,106 @@ fn to_decision_hit(row: &DecisionView) -> DecisionHit {
         branch: row.branch.clone(),
         ts: row.ts.clone().unwrap_or_default(),
         is_active: matches!(row.status.as_str(), "active" | "experimental"),
+        revoked: row.status == "revoked",
         tags: row.tags.clone(),
         village_id: row.village_id.clone(),
+        scope: row.propagation.parse().unwrap_or_default(),
         staleness: None,
+        attachments: Vec::new(),
+        owner: None,
+        governance: Governance::Direct,
     }
 }
 
+/// Fill in the files attached to each decision, read from its event.
+/// Decision rows do not carry them; an unreadable event leaves none.
+fn load_attachments(ledger: &Ledger, hits: &mut [DecisionHit]) {
+    for hit in hits.iter_mut().filter(|h| h.attachments.is_empty()) {
+        if let Ok(Some(event)) = ledger.get_event(&hit.event_id) {
+            hit.attachments = Attachment::from_payload(&event.payload);
+        }
+    }
+}
+
+/// Fill in each decision's domain owner from `policy.yaml`. Best-effort: an
+/// unreadable policy leaves every owner unset.
+fn load_owners(ledger: &Ledger, hits: &mut [DecisionHit]) {
+    let edda_dir = &ledger.paths.edda_dir;
+    let owners = match edda_core::policy::load_domain_owners_from_dir(edda_dir) {
+        Ok(owners) if !owners.is_empty() => owners,
+        _ => return,
+    };
+    let actors = edda_core::policy::load_actors_from_dir(edda_dir).unwrap_or_default();
+    for hit in hits.iter_mut() {
+        hit.owner = owners.get(&hit.domain).map(|o| o.resolved(&actors));
+    }
+}
+
+/// Mark decisions cited as evidence by an applied draft, with the draft's
+/// approvers and stages. Drafts deleted after applying still count.
+/// Best-effort: unreadable draft events leave every decision `Direct`.
+fn load_governance<'a>(ledger: &Ledger, hits: impl Iterator<Item = &'a mut DecisionHit>) {
+    let Ok(events) = ledger.draft_events() else {
+        return;
+    };
+    let events: Vec<Event> = events
+        .into_iter()
+        .filter(|e| e.event_type != "draft.deleted")
+        .collect();
+    let mut by_decision: BTreeMap<String, DraftGovernance> = BTreeMap::new();
+    for draft in edda_ledger::drafts::project_drafts(&events)
+        .into_iter()
+        .filter(|d| d.status == edda_ledger::DraftStatus::Applied)
+    {
+        let governance = draft_governance(&draft);
+        for id in draft
+            .evidence
+            .iter()
+            .filter_map(|e| e.get("event_id").and_then(|v| v.as_str()))
+        {
+            // The first applied draft is the one that reviewed it.
+            by_decision
+                .entry(id.to_string())
+                .or_insert_with(|| governance.clone());
+        }
+    }
+    if by_decision.is_empty() {
+        return;
+    }
+    for hit in hits {
+        if let Some(g) = by_decision.get(&hit.event_id) {
+            hit.governance = Governance::Draft(g.clone());
+        }
+    }
+}
+
+fn draft_governance(draft: &edda_ledger::DraftView) -> DraftGovernance {
+    let mut approvers: Vec<String> = Vec::new();
+    for a in draft.approvals.iter().filter(|a| a.decision == "approve") {
+        if !approvers.contains(&a.actor) {
+            approvers.push(a.actor.clone());
+        }
+    }
+    DraftGovernance {
+        draft_id: draft.draft_id.clone(),
+        applied_commit_id: draft.applied_commit_id.clone(),
+        approvers,
+        stages: draft
+            .stages
+            .iter()
+            .map(|s| GovernanceStage {
+                stage_id: s.stage_id.clone(),
+                role: s.role.clone(),
+                approved_by: s.approved_by.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn is_project_scope(scope: &DecisionScope) -> bool {
+    *scope == DecisionScope::Local
+}
+
 /// Return the affected_paths carried by each decision in `result.decisions`,
 /// looked up from the ledger by event_id. Position-aligned with the input;
 /// missing/unreadable rows return empty Vec (best-effort).
@@ -1066,6 +2280,102 @@ mod tests {
         assert_eq!(hit.evidence_paths, vec!["dist/drill.json".to_string()]);
     }
 
+    #[test]
+    fn decisions_show_the_applied_draft_that_reviewed_them() {
+        use edda_core::event::{
+            new_approval_event, new_draft_applied_event, new_draft_deleted_event,
+            new_draft_proposed_event, ApprovalEventParams, DraftProposedParams, DraftStageSpec,
+        };
+        let (_tmp, ledger) = setup();
+        let reviewed = make_decision("main", "db.engine", "postgres", None, None);
+        ledger.append_event(&reviewed).unwrap();
+        ledger
+            .append_event(&make_decision("main", "db.pool", "16", None, None))
+            .unwrap();
+
+        let evidence = vec![serde_json::json!({ "event_id": reviewed.event_id })];
+        let stages = vec![DraftStageSpec {
+            stage_id: "dba".into(),
+            role: "dba".into(),
+            min_approvals: 1,
+            assignees: vec!["alice".into()],
+        }];
+        ledger
+            .append_event(
+                &new_draft_proposed_event(&DraftProposedParams {
+                    branch: "main",
+                    parent_hash: None,
+                    draft_id: "drf_db",
+                    created_at: None,
+                    base_parent_hash: "",
+                    title: "Switch to postgres",
+                    purpose: "",
+                    contribution: "",
+                    labels: &[],
+                    evidence: &evidence,
+                    auto_preview_lines: &[],
+                    route_rule_id: "",
+                    stages: &stages,
+                    policy_require_approval: true,
+                    policy_min_approvals: 1,
+                    origin: None,
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        ledger
+            .append_event(
+                &new_approval_event(&ApprovalEventParams {
+                    branch: "main",
+                    parent_hash: None,
+                    draft_id: "drf_db",
+                    draft_sha256: "",
+                    decision: "approve",
+                    actor: "alice",
+                    note: "",
+                    stage_id: "dba",
+                    role: "dba",
+                    device_id: None,
+                })
+                .unwrap(),
+            )
+            .unwrap();
+        ledger
+            .append_event(&new_draft_applied_event("main", None, "drf_db", "evt_commit").unwrap())
+            .unwrap();
+        // Deleting the applied draft does not erase that it was reviewed.
+        ledger
+            .append_event(&new_draft_deleted_event("main", None, "drf_db").unwrap())
+            .unwrap();
+
+        let result = ask(&ledger, "db", &AskOptions::default(), None).unwrap();
+        let governance = |key: &str| {
+            result
+                .decisions
+                .iter()
+                .find(|d| d.key == key)
+                .map(|d| d.governance.clone())
+                .unwrap()
+        };
+        assert_eq!(governance("db.pool"), Governance::Direct);
+        let Governance::Draft(g) = governance("db.engine") else {
+            panic!("db.engine went through drf_db");
+        };
+        assert_eq!(g.draft_id, "drf_db");
+        assert_eq!(g.applied_commit_id.as_deref(), Some("evt_commit"));
+        assert_eq!(g.approvers, ["alice"]);
+        assert_eq!(g.stages[0].approved_by, ["alice"]);
+
+        let json = serde_json::to_value(&result.decisions).unwrap();
+        let vias: Vec<_> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["governance"]["via"].as_str().unwrap())
+            .collect();
+        assert!(vias.contains(&"direct") && vias.contains(&"draft"));
+    }
+
     fn make_decision(
         branch: &str,
         key: &str,
@@ -1200,6 +2510,397 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn ask_keys_answers_each_key() {
+        let (tmp, ledger) = setup();
+        let mut prev: Option<String> = None;
+        for v in ["a", "b", "c", "d", "e", "f", "g"] {
+            let d = make_decision("main", "db.engine", v, None, prev.as_deref());
+            prev = Some(d.event_id.clone());
+            ledger.append_event(&d).unwrap();
+        }
+        ledger
+            .append_event(&make_decision("main", "auth.method", "jwt", None, None))
+            .unwrap();
+
+        let keys = ["db.engine", "auth.method", "cache.backend"].map(String::from);
+        let answers = ask_keys(&ledger, &keys, &AskOptions::default()).unwrap();
+        assert_eq!(answers.len(), 3);
+        let db = &answers["db.engine"];
+        assert_eq!(db.active.as_ref().unwrap().value, "g");
+        let values: Vec<_> = db.timeline.iter().map(|d| d.value.as_str()).collect();
+        assert_eq!(values, ["c", "d", "e", "f", "g"]);
+        assert_eq!(answers["auth.method"].active.as_ref().unwrap().value, "jwt");
+        assert!(answers["cache.backend"].active.is_none());
+        assert!(answers["cache.backend"].timeline.is_empty());
+
+        let err = ask_keys(&ledger, &["postgres".to_string()], &AskOptions::default());
+        assert!(err.unwrap_err().to_string().contains("exact decision keys"));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_why_builds_rationale_chain() {
+        let (tmp, ledger) = setup();
+        let d1 = make_decision("main", "db.engine", "sqlite", Some("zero ops"), None);
+        ledger.append_event(&d1).unwrap();
+        let mut why_note = make_note("main", "sqlite locks under concurrent writers");
+        why_note.refs.events.push(d1.event_id.clone());
+        finalize_event(&mut why_note).unwrap();
+        ledger.append_event(&why_note).unwrap();
+        let d2 = make_decision(
+            "main",
+            "db.engine",
+            "postgres",
+            Some("need concurrent writers"),
+            Some(&d1.event_id),
+        );
+        ledger.append_event(&d2).unwrap();
+        let commit = make_commit("main", "switch to postgres", "migrate", &[&d2.event_id]);
+        ledger.append_event(&commit).unwrap();
+        ledger
+            .append_event(&make_note("main", "unrelated postgres musing"))
+            .unwrap();
+
+        let opts = AskOptions {
+            mode: AskMode::Why,
+            ..Default::default()
+        };
+        let result = ask(&ledger, "db.engine", &opts, None).unwrap();
+        let chain: Vec<(&str, &str)> = result
+            .rationale
+            .iter()
+            .map(|s| (s.kind.as_str(), s.link.as_str()))
+            .collect();
+        assert_eq!(
+            chain,
+            vec![
+                ("decision", "origin"),
+                ("note", "evidence"),
+                ("decision", "supersedes"),
+                ("commit", "evidence"),
+            ]
+        );
+        assert!(result.rationale[0].summary.contains("zero ops"));
+        assert!(format_human(&result).contains("Rationale"));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_why_requires_exact_key() {
+        let (tmp, ledger) = setup();
+        let opts = AskOptions {
+            mode: AskMode::Why,
+            ..Default::default()
+        };
+        let err = ask(&ledger, "postgres", &opts, None).unwrap_err();
+        assert!(err.to_string().contains("exact decision key"));
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_history_dates_every_value_of_matching_keys() {
+        let (tmp, ledger) = setup();
+        let d1 = make_decision("main", "cache.backend", "redis", Some("fast"), None);
+        ledger.append_event(&d1).unwrap();
+        let d2 = make_decision(
+            "main",
+            "cache.backend",
+            "memcached",
+            Some("redis needed a sidecar"),
+            Some(&d1.event_id),
+        );
+        ledger.append_event(&d2).unwrap();
+        let d3 = make_decision(
+            "main",
+            "cache.backend",
+            "in-process",
+            None,
+            Some(&d2.event_id),
+        );
+        ledger.append_event(&d3).unwrap();
+        let other = make_decision("main", "queue.broker", "redis", Some("streams"), None);
+        ledger.append_event(&other).unwrap();
+        ledger
+            .append_event(&make_decision("main", "db.engine", "postgres", None, None))
+            .unwrap();
+
+        let opts = AskOptions {
+            mode: AskMode::History,
+            ..Default::default()
+        };
+        let result = ask(&ledger, "redis", &opts, None).unwrap();
+        assert_eq!(result.input_type, "history");
+        let keys: Vec<&str> = result.history.iter().map(|h| h.key.as_str()).collect();
+        assert_eq!(keys, ["cache.backend", "queue.broker"]);
+
+        let cache = &result.history[0];
+        assert!(
+            cache.abandoned,
+            "redis was replaced and nothing matching holds now"
+        );
+        let spans: Vec<(&str, &str, bool)> = cache
+            .values
+            .iter()
+            .map(|v| (v.value.as_str(), v.status.as_str(), v.matched))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("redis", "superseded", true),
+                ("memcached", "superseded", true),
+                ("in-process", "active", false),
+            ]
+        );
+        assert_eq!(cache.values[0].until.as_deref(), Some(d2.ts.as_str()));
+        assert_eq!(cache.values[2].until, None);
+        assert!(
+            !result.history[1].abandoned,
+            "queue.broker still uses redis"
+        );
+
+        let human = format_human(&result);
+        assert!(
+            human.contains("cache.backend  (tried, abandoned)"),
+            "{human}"
+        );
+        assert!(human.contains("→ now  in-process"), "{human}");
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_history_requires_a_keyword() {
+        let (tmp, ledger) = setup();
+        let opts = AskOptions {
+            mode: AskMode::History,
+            ..Default::default()
+        };
+        let err = ask(&ledger, "  ", &opts, None).unwrap_err();
+        assert!(err.to_string().contains("history mode needs a keyword"));
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_as_of_replays_the_decisions_active_then() {
+        let (tmp, ledger) = setup();
+        let at = |mut e: Event, ts: &str| {
+            e.ts = ts.to_string();
+            e
+        };
+        let auth = make_decision("main", "auth.method", "jwt", None, None);
+        ledger
+            .append_event(&at(auth, "2026-01-05T00:00:00Z"))
+            .unwrap();
+        let d1 = at(
+            make_decision("main", "db.engine", "sqlite", Some("MVP"), None),
+            "2026-01-10T00:00:00Z",
+        );
+        ledger.append_event(&d1).unwrap();
+        let cache = at(
+            make_decision("main", "cache.backend", "redis", None, None),
+            "2026-01-12T00:00:00Z",
+        );
+        ledger.append_event(&cache).unwrap();
+        let revoke = edda_core::event::new_revoke_event("main", None, &cache, "wrong").unwrap();
+        ledger
+            .append_event(&at(revoke, "2026-01-20T00:00:00Z"))
+            .unwrap();
+        let d2 = make_decision(
+            "main",
+            "db.engine",
+            "postgres",
+            Some("JSONB"),
+            Some(&d1.event_id),
+        );
+        ledger
+            .append_event(&at(d2, "2026-02-01T00:00:00Z"))
+            .unwrap();
+
+        let opts = |as_of: &str| AskOptions {
+            as_of: Some(as_of.to_string()),
+            ..Default::default()
+        };
+        let values = |r: &AskResult| -> Vec<String> {
+            r.decisions
+                .iter()
+                .map(|d| format!("{}={}", d.key, d.value))
+                .collect()
+        };
+
+        let mid_jan = ask(&ledger, "", &opts("2026-01-15"), None).unwrap();
+        assert_eq!(mid_jan.as_of.as_deref(), Some("2026-01-15T23:59:59Z"));
+        assert_eq!(
+            values(&mid_jan),
+            ["auth.method=jwt", "cache.backend=redis", "db.engine=sqlite"]
+        );
+        assert!(mid_jan.decisions.iter().all(|d| d.is_active));
+
+        let late_jan = ask(&ledger, "", &opts("2026-01-25"), None).unwrap();
+        assert_eq!(values(&late_jan), ["auth.method=jwt", "db.engine=sqlite"]);
+
+        let key = ask(&ledger, "db.engine", &opts("2026-01-15"), None).unwrap();
+        assert_eq!(values(&key), ["db.engine=sqlite"]);
+        assert_eq!(key.timeline.len(), 1, "the timeline stops at as_of");
+
+        let now = ask(&ledger, "db.engine", &AskOptions::default(), None).unwrap();
+        assert_eq!(values(&now), ["db.engine=postgres"]);
+        assert!(format_human(&mid_jan).starts_with("As of 2026-01-15T23:59:59Z"));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn normalize_as_of_accepts_dates_and_timestamps() {
+        assert_eq!(
+            normalize_as_of("2026-01-15").unwrap(),
+            "2026-01-15T23:59:59Z"
+        );
+        assert_eq!(
+            normalize_as_of("2026-01-15T09:00:00+09:00").unwrap(),
+            "2026-01-15T00:00:00Z"
+        );
+        assert!(normalize_as_of("last tuesday").is_err());
+    }
+
+    #[test]
+    fn ask_marks_revoked_decisions_and_notes() {
+        let (tmp, ledger) = setup();
+        let d1 = make_decision("main", "cache.backend", "redis", Some("fast"), None);
+        ledger.append_event(&d1).unwrap();
+        let note = make_note("main", "cache.backend redis needs a sidecar");
+        ledger.append_event(&note).unwrap();
+        for target in [&d1, &note] {
+            let rv = edda_core::event::new_revoke_event("main", None, target, "wrong").unwrap();
+            ledger.append_event(&rv).unwrap();
+        }
+
+        let result = ask(&ledger, "cache.backend", &AskOptions::default(), None).unwrap();
+        assert!(result.decisions.is_empty(), "revoked key is inactive");
+        assert!(result.timeline[0].revoked);
+        assert!(result.related_notes.iter().any(|n| n.revoked));
+        let human = format_human(&result);
+        assert!(human.contains("(revoked)"), "{human}");
+        assert!(human.contains("[revoked] \"cache.backend redis"), "{human}");
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_tag_filter_follows_tag_renames() {
+        let (tmp, ledger) = setup();
+        let dp = edda_core::types::DecisionPayload {
+            key: "cache.ttl".to_string(),
+            value: "60s".to_string(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: Some(vec!["perf".to_string()]),
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let decision = edda_core::event::new_decision_event("main", None, "system", &dp).unwrap();
+        ledger.append_event(&decision).unwrap();
+        let rename =
+            edda_core::event::new_tag_rename_event("main", None, "perf", "performance").unwrap();
+        ledger.append_event(&rename).unwrap();
+
+        for tag in ["performance", "perf"] {
+            let opts = AskOptions {
+                tags: vec![tag.to_string()],
+                ..Default::default()
+            };
+            let result = ask(&ledger, "", &opts, None).unwrap();
+            assert_eq!(result.decisions.len(), 1, "tag {tag}");
+        }
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn truncate_snippet_respects_sentence_boundary() {
+        let text = "Postgres handles concurrent writes. SQLite does not. More detail follows here.";
+        assert_eq!(truncate_snippet(text, 200, true), text);
+        assert_eq!(
+            truncate_snippet(text, 60, true),
+            "Postgres handles concurrent writes. SQLite does not."
+        );
+        assert_eq!(truncate_snippet(text, 20, false), "Postgres handles con...");
+        // Boundary too early in the window: fall back to a hard cut.
+        assert_eq!(
+            truncate_snippet("Ok. then a long tail", 15, true),
+            "Ok. then a long..."
+        );
+        // Multi-byte text is cut on characters, not bytes.
+        assert_eq!(truncate_snippet("決策一。決策二。", 5, true), "決策一。");
+    }
+
+    #[test]
+    fn ask_applies_snippet_and_response_budget() {
+        let (tmp, ledger) = setup();
+        ledger
+            .append_event(&make_decision("main", "db.engine", "postgres", None, None))
+            .unwrap();
+        for i in 0..5 {
+            let text = format!("postgres note {i}: {}", "x".repeat(300));
+            ledger.append_event(&make_note("main", &text)).unwrap();
+        }
+
+        let opts = AskOptions {
+            snippet_chars: Some(40),
+            ..Default::default()
+        };
+        let result = ask(&ledger, "postgres", &opts, None).unwrap();
+        assert_eq!(result.related_notes.len(), 5);
+        assert!(result
+            .related_notes
+            .iter()
+            .all(|n| n.text.chars().count() <= 43));
+        assert!(!result.truncated);
+
+        let opts = AskOptions {
+            max_response_chars: Some(900),
+            ..Default::default()
+        };
+        let result = ask(&ledger, "postgres", &opts, None).unwrap();
+        assert!(result.truncated);
+        assert!(serde_json::to_string(&result).unwrap().len() <= 900);
+        assert_eq!(result.decisions.len(), 1, "decisions are dropped last");
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn cited_events_dedupes_across_sections() {
+        let (tmp, ledger) = setup();
+        ledger
+            .append_event(&make_decision("main", "db.engine", "postgres", None, None))
+            .unwrap();
+        ledger
+            .append_event(&make_note("main", "postgres needs a pooler"))
+            .unwrap();
+
+        let result = ask(&ledger, "postgres", &AskOptions::default(), None).unwrap();
+        let cited = cited_events(&result);
+        let decision_id = &result.decisions[0].event_id;
+        assert_eq!(
+            cited.iter().filter(|(id, _)| id == decision_id).count(),
+            1,
+            "a decision in both decisions and timeline is cited once"
+        );
+        assert_eq!(cited[0].1, "db.engine=postgres");
+        assert!(cited
+            .iter()
+            .any(|(id, label)| *id == result.related_notes[0].event_id
+                && label == "postgres needs a pooler"));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn ask_domain() {
         let (tmp, ledger) = setup();
@@ -1323,6 +3024,159 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn question_terms_drop_stopwords_and_keep_keys() {
+        assert_eq!(
+            question_terms("Why did we stop using Redis for the cache?"),
+            ["redis", "cache"]
+        );
+        assert_eq!(
+            question_terms("what is db.engine now?"),
+            ["db.engine", "now"]
+        );
+        assert!(question_terms("why did we?").is_empty());
+    }
+
+    #[test]
+    fn ask_question_merges_hits_for_each_term() {
+        let (tmp, ledger) = setup();
+        let d1 = make_decision(
+            "main",
+            "cache.backend",
+            "memcached",
+            Some("redis kept running out of memory"),
+            None,
+        );
+        ledger.append_event(&d1).unwrap();
+        let n1 = make_note("main", "Dropping redis after the outage");
+        ledger.append_event(&n1).unwrap();
+
+        let result = ask_question(
+            &ledger,
+            "Why did we stop using Redis for the cache?",
+            &AskOptions::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result.input_type, "question");
+        assert_eq!(result.query, "redis cache");
+        assert_eq!(result.decisions.len(), 1, "merged without duplicates");
+        assert_eq!(result.decisions[0].event_id, d1.event_id);
+        assert!(result
+            .related_notes
+            .iter()
+            .any(|n| n.event_id == n1.event_id));
+        assert_eq!(result.top[0].id, d1.event_id);
+
+        assert!(ask_question(&ledger, "why?", &AskOptions::default(), None).is_err());
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn ask_ranks_top_hits_across_sections() {
+        let (tmp, ledger) = setup();
+        let d1 = make_decision("main", "db.engine", "postgres", Some("JSONB"), None);
+        let d1_id = d1.event_id.clone();
+        ledger.append_event(&d1).unwrap();
+        let c1 = make_commit("main", "feat: migrate", "schema", &[&d1_id]);
+        ledger.append_event(&c1).unwrap();
+
+        let result = ask(&ledger, "db.engine", &AskOptions::default(), None).unwrap();
+        let kinds: Vec<&str> = result.top.iter().map(|h| h.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["decision", "commit"]);
+        assert_eq!(result.top[0].id, d1_id);
+        assert_eq!(result.top[0].summary, "db.engine = postgres");
+        assert!(result.top[0].score > result.top[1].score);
+
+        let opts = AskOptions {
+            top: 0,
+            ..Default::default()
+        };
+        let result = ask(&ledger, "db.engine", &opts, None).unwrap();
+        assert!(result.top.is_empty());
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("top").is_none());
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rank_hits_weighs_keywords_recency_and_evidence() {
+        let note = |id: &str, text: &str, ts: &str, revoked: bool| NoteHit {
+            event_id: id.into(),
+            text: text.into(),
+            ts: ts.into(),
+            branch: "main".into(),
+            revoked,
+            attachments: vec![],
+        };
+        let commit = |id: &str, title: &str, ts: &str, match_type: &str| CommitHit {
+            event_id: id.into(),
+            title: title.into(),
+            purpose: String::new(),
+            ts: ts.into(),
+            branch: "main".into(),
+            match_type: match_type.into(),
+        };
+        let result = AskResult {
+            query: "postgres migration".into(),
+            input_type: "keyword".into(),
+            as_of: None,
+            top: vec![],
+            decisions: vec![],
+            timeline: vec![],
+            related_commits: vec![
+                commit(
+                    "c-old",
+                    "postgres migration",
+                    "2026-01-01T00:00:00Z",
+                    "title",
+                ),
+                commit("c-evidence", "postgres", "2025-01-01T00:00:00Z", "evidence"),
+            ],
+            related_notes: vec![
+                note(
+                    "n-both",
+                    "Postgres migration plan",
+                    "2026-03-01T00:00:00Z",
+                    false,
+                ),
+                note("n-one", "postgres is up", "2026-03-01T00:00:00Z", false),
+                note(
+                    "n-revoked",
+                    "postgres migration",
+                    "2026-03-01T00:00:00Z",
+                    true,
+                ),
+                note("n-none", "unrelated", "2026-03-01T00:00:00Z", false),
+            ],
+            conversations: vec![],
+            tasks: vec![],
+            dependents: vec![],
+            override_risk: None,
+            rationale: vec![],
+            history: vec![],
+            truncated: false,
+        };
+        let now = parse_hit_ts("2026-03-01").unwrap();
+
+        let ids = |n| -> Vec<String> {
+            rank_hits(&result, now, n)
+                .into_iter()
+                .map(|h| h.id)
+                .collect()
+        };
+        // Both terms and recent, then both terms but old, then one term
+        // recent, then one old term with an evidence link, then no terms.
+        assert_eq!(
+            ids(10),
+            vec!["n-both", "c-old", "n-one", "c-evidence", "n-none"]
+        );
+        assert_eq!(ids(2), vec!["n-both", "c-old"]);
+        assert!(ids(0).is_empty());
+        assert_eq!(rank_hits(&result, now, 1)[0].score, 1.5);
+    }
+
     #[test]
     fn find_related_commits_title_match() {
         let (tmp, ledger) = setup();
@@ -1428,6 +3282,7 @@ mod tests {
         let result = AskResult {
             query: "postgres".into(),
             input_type: "keyword".into(),
+            top: vec![],
             decisions: vec![DecisionHit {
                 event_id: "e1".into(),
                 key: "db.engine".into(),
@@ -1437,9 +3292,27 @@ mod tests {
                 branch: "main".into(),
                 ts: "2026-02-15".into(),
                 is_active: true,
+                revoked: false,
                 tags: vec![],
                 village_id: None,
+                scope: DecisionScope::Local,
                 staleness: None,
+                attachments: vec![Attachment {
+                    name: "benchmark.csv".into(),
+                    blob: "blob:sha256:beef".into(),
+                    size: 12,
+                }],
+                owner: Some(DomainOwner {
+                    role: "dba".into(),
+                    owners: vec!["alice".into()],
+                    min_approvals: 1,
+                }),
+                governance: Governance::Draft(DraftGovernance {
+                    draft_id: "drf_1".into(),
+                    applied_commit_id: Some("evt_c".into()),
+                    approvers: vec!["alice".into(), "bob".into()],
+                    stages: vec![],
+                }),
             }],
             timeline: vec![],
             related_commits: vec![CommitHit {
@@ -1455,6 +3328,10 @@ mod tests {
             tasks: vec![],
             dependents: vec![],
             override_risk: None,
+            rationale: vec![],
+            history: vec![],
+            as_of: None,
+            truncated: false,
         };
 
         let output = format_human(&result);
@@ -1462,6 +3339,31 @@ mod tests {
         assert!(output.contains("postgres"));
         assert!(output.contains("Related Commits"));
         assert!(output.contains("feat: migrate"));
+        assert!(output.contains("attached: [benchmark.csv](.edda/ledger/blobs/beef)"));
+        assert!(output.contains("owner: dba (alice)"));
+        assert!(output.contains("via draft drf_1, approved by alice, bob"));
+    }
+
+    #[test]
+    fn decision_and_note_attachments_come_from_their_events() {
+        let (tmp, ledger) = setup();
+        let sheet = Attachment {
+            name: "load-test.csv".into(),
+            blob: "blob:sha256:cafe".into(),
+            size: 3,
+        };
+        let mut d = make_decision("main", "cache.engine", "redis", None, None);
+        edda_core::event::attach_files(&mut d, std::slice::from_ref(&sheet)).unwrap();
+        ledger.append_event(&d).unwrap();
+        let mut n = new_note_event("main", None, "user", "redis load numbers", &[]).unwrap();
+        edda_core::event::attach_files(&mut n, std::slice::from_ref(&sheet)).unwrap();
+        ledger.append_event(&n).unwrap();
+
+        let result = ask(&ledger, "redis", &AskOptions::default(), None).unwrap();
+        assert_eq!(result.decisions[0].attachments, vec![sheet.clone()]);
+        assert_eq!(result.related_notes[0].attachments, vec![sheet]);
+
+        let _ = std::fs::remove_dir_all(&tmp);
     }
 
     #[test]
@@ -1501,6 +3403,7 @@ mod tests {
         let result = AskResult {
             query: "test".into(),
             input_type: "keyword".into(),
+            top: vec![],
             decisions: vec![],
             timeline: vec![],
             related_commits: vec![],
@@ -1509,11 +3412,17 @@ mod tests {
                 text: long_text,
                 ts: "2026-02-26".into(),
                 branch: "main".into(),
+                revoked: false,
+                attachments: vec![],
             }],
             conversations: vec![],
             tasks: vec![],
             dependents: vec![],
             override_risk: None,
+            rationale: vec![],
+            history: vec![],
+            as_of: None,
+            truncated: false,
         };
 
         let output = format_human(&result);
@@ -1530,6 +3439,7 @@ mod tests {
         let result = AskResult {
             query: "nonexistent".into(),
             input_type: "keyword".into(),
+            top: vec![],
             decisions: vec![],
             timeline: vec![],
             related_commits: vec![],
@@ -1538,6 +3448,10 @@ mod tests {
             tasks: vec![],
             dependents: vec![],
             override_risk: None,
+            rationale: vec![],
+            history: vec![],
+            as_of: None,
+            truncated: false,
         };
 
         let output = format_human(&result);
@@ -1819,6 +3733,16 @@ mod tests {
             "semantic search should retrieve discount precedent"
         );
 
+        let lexical = AskOptions {
+            semantic: false,
+            ..Default::default()
+        };
+        let result = ask(&ledger, query, &lexical, None).unwrap();
+        assert!(
+            result.decisions.is_empty(),
+            "no decision contains the query as a substring"
+        );
+
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
@@ -1827,6 +3751,7 @@ mod tests {
         let result = AskResult {
             query: "db.engine".into(),
             input_type: "exact_key".into(),
+            top: vec![],
             decisions: vec![DecisionHit {
                 event_id: "e1".into(),
                 key: "db.engine".into(),
@@ -1836,9 +3761,14 @@ mod tests {
                 branch: "main".into(),
                 ts: "2026-02-15".into(),
                 is_active: true,
+                revoked: false,
                 tags: vec![],
                 village_id: None,
+                scope: DecisionScope::Local,
                 staleness: None,
+                attachments: vec![],
+                owner: None,
+                governance: Governance::Direct,
             }],
             timeline: vec![],
             related_commits: vec![],
@@ -1864,6 +3794,10 @@ mod tests {
                 dependent_count: 2,
                 suggestion: Some("建議覆蓋順序: api.format → db.schema".into()),
             }),
+            rationale: vec![],
+            history: vec![],
+            as_of: None,
+            truncated: false,
         };
 
         let output = format_human(&result);
@@ -1890,6 +3824,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ask_filters_by_scope() {
+        let (_tmp, ledger) = setup();
+        let ev1 = make_decision("main", "db.engine", "postgres", Some("jsonb"), None);
+        ledger.append_event(&ev1).unwrap();
+        let mut ev2 = make_decision("main", "db.engine", "duckdb", Some("spike"), None);
+        ev2.payload["decision"]["scope"] = serde_json::json!("session");
+        finalize_event(&mut ev2).unwrap();
+        ledger.append_event(&ev2).unwrap();
+
+        let result = ask(&ledger, "db.engine", &AskOptions::default(), None).unwrap();
+        assert_eq!(result.decisions.len(), 2, "both layers are active");
+
+        let opts = AskOptions {
+            scope: Some(DecisionScope::Session),
+            ..Default::default()
+        };
+        let result = ask(&ledger, "db.engine", &opts, None).unwrap();
+        assert_eq!(result.decisions.len(), 1);
+        assert_eq!(result.decisions[0].value, "duckdb");
+        let json = serde_json::to_value(&result.decisions[0]).unwrap();
+        assert_eq!(json["scope"], "session");
+        assert!(format_human(&result).contains("| scope: session"));
+
+        let opts = AskOptions {
+            scope: Some(DecisionScope::Local),
+            ..Default::default()
+        };
+        let result = ask(&ledger, "db.engine", &opts, None).unwrap();
+        assert_eq!(result.decisions.len(), 1);
+        assert_eq!(result.decisions[0].value, "postgres");
+        let json = serde_json::to_value(&result.decisions[0]).unwrap();
+        assert!(json.get("scope").is_none());
+    }
+
     #[test]
     fn ask_filters_by_village_id() {
         let (_tmp, ledger) = setup();
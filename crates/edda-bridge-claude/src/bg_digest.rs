@@ -146,9 +146,7 @@ pub fn run_digest(project_id: &str, session_id: &str, cwd: &str) -> Result<()> {
 // ── Internal Helpers ──
 
 fn transcript_path(project_id: &str, session_id: &str) -> PathBuf {
-    edda_store::project_dir(project_id)
-        .join("transcripts")
-        .join(format!("{session_id}.jsonl"))
+    edda_store::project_subdir(project_id, "transcripts").join(format!("{session_id}.jsonl"))
 }
 
 fn state_dir(project_id: &str) -> PathBuf {
@@ -356,7 +354,7 @@ mod tests {
         let _ = edda_store::ensure_dirs(pid);
 
         // Create a transcript so that check passes
-        let transcript_dir = edda_store::project_dir(pid).join("transcripts");
+        let transcript_dir = edda_store::project_subdir(pid, "transcripts");
         let _ = fs::create_dir_all(&transcript_dir);
         let _ = fs::write(transcript_dir.join(format!("{sid}.jsonl")), "{}");
 
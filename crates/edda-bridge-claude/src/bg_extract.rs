@@ -227,9 +227,8 @@ pub fn extract_decisions(
     session_id: &str,
     api_key: &str,
 ) -> Result<ExtractionResult> {
-    let transcript_path = edda_store::project_dir(project_id)
-        .join("transcripts")
-        .join(format!("{session_id}.jsonl"));
+    let transcript_path =
+        edda_store::project_subdir(project_id, "transcripts").join(format!("{session_id}.jsonl"));
 
     if !transcript_path.exists() {
         anyhow::bail!("Transcript not found: {}", transcript_path.display());
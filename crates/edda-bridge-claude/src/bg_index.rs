@@ -30,7 +30,9 @@ pub fn should_run(project_id: &str) -> bool {
 /// a project dir means reading the process-wide store root, and a test that
 /// redirected it would corrupt every other test sharing this process.
 fn index_exists(proj_dir: &Path) -> bool {
-    proj_dir.join("search").join("tantivy").exists()
+    edda_store::store_subdir(proj_dir, "search")
+        .join("tantivy")
+        .exists()
 }
 
 /// Bring the index up to date with events written during this session.
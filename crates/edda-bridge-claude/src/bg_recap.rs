@@ -0,0 +1,53 @@
+//! Session recap at SessionEnd — appends a `recap` event to the workspace
+//! ledger via the `edda-chronicle` recap pipeline.
+//!
+//! Unlike `bg_digest`, this runs without `EDDA_LLM_API_KEY`: the pipeline
+//! falls back to a template recap, so every indexed session gets one.
+//! Idempotency lives in the pipeline (one recap per session id).
+
+use anyhow::Result;
+use edda_store::project_dir;
+use std::path::Path;
+
+/// Run when background tasks are enabled and the session has been indexed.
+pub fn should_run(project_id: &str, session_id: &str) -> bool {
+    if std::env::var("EDDA_BG_ENABLED").unwrap_or_else(|_| "1".into()) == "0" {
+        return false;
+    }
+    session_indexed(&project_dir(project_id), session_id)
+}
+
+/// Split out from `should_run` for the same reason as
+/// `bg_index::index_exists`: tests must not redirect the store root.
+fn session_indexed(proj_dir: &Path, session_id: &str) -> bool {
+    !session_id.is_empty()
+        && proj_dir
+            .join("index")
+            .join(format!("{session_id}.jsonl"))
+            .exists()
+}
+
+/// Write the recap for this session, if the cwd is inside a workspace.
+pub fn run_recap(project_id: &str, session_id: &str, cwd: &str) -> Result<()> {
+    let Some(root) = edda_ledger::EddaPaths::find_root(Path::new(cwd)) else {
+        return Ok(());
+    };
+    let written = edda_chronicle::recap_session(&root, &project_dir(project_id), session_id)?;
+    tracing::debug!(event_id = ?written, "session recap");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn session_indexed_requires_an_index_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(!session_indexed(tmp.path(), "s1"));
+        std::fs::create_dir_all(tmp.path().join("index")).unwrap();
+        std::fs::write(tmp.path().join("index").join("s1.jsonl"), "").unwrap();
+        assert!(session_indexed(tmp.path(), "s1"));
+        assert!(!session_indexed(tmp.path(), ""));
+    }
+}
@@ -0,0 +1,233 @@
+//! PreToolUse binding guardrail.
+//!
+//! Only operator-ratified decisions are binding (GH-401). When an Edit/Write
+//! introduces a value that a binding decision superseded — `sqlite` while
+//! `db.engine=postgres` is ratified — the hook reports the contradiction so
+//! it can be warned about or soft-blocked before the write lands.
+//!
+//! Matching is lexical: a superseded value counts as "introduced" when it
+//! appears as a whole word in the new text but not in the text it replaces.
+
+use std::path::Path;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+// ── Session-scoped cache ────────────────────────────────────────────
+
+/// A ratified decision and the values it replaced on the same branch.
+#[derive(Debug, Clone)]
+struct BindingRule {
+    key: String,
+    value: String,
+    superseded: Vec<String>,
+}
+
+struct BindingCache {
+    key: Option<(String, String)>,
+    rules: Vec<BindingRule>,
+    loaded_at: std::time::Instant,
+}
+
+static BINDING_CACHE: LazyLock<Mutex<BindingCache>> = LazyLock::new(|| {
+    Mutex::new(BindingCache {
+        key: None,
+        rules: Vec::new(),
+        loaded_at: std::time::Instant::now(),
+    })
+});
+
+const CACHE_TTL_SECS: u64 = 120;
+
+/// Values too generic to flag on a lexical match.
+const GENERIC_VALUES: &[&str] = &[
+    "true", "false", "yes", "no", "none", "null", "on", "off", "default",
+];
+
+// ── Public API ──────────────────────────────────────────────────────
+
+/// List the binding decisions that `new_text` contradicts.
+///
+/// Each entry reads like ``db.engine=postgres` (superseded: sqlite)`.
+/// `old_text` is the text being replaced (Edit `old_string`), so values
+/// already present before the edit are not reported again.
+pub(crate) fn binding_contradictions(
+    repo_root: &Path,
+    branch: &str,
+    old_text: &str,
+    new_text: &str,
+) -> Vec<String> {
+    let rules = load_rules_cached(repo_root, branch);
+    let old_lower = old_text.to_lowercase();
+    let new_lower = new_text.to_lowercase();
+
+    rules
+        .iter()
+        .filter_map(|rule| {
+            let hits: Vec<&str> = rule
+                .superseded
+                .iter()
+                .filter(|v| {
+                    let v = v.to_lowercase();
+                    mentions(&new_lower, &v) && !mentions(&old_lower, &v)
+                })
+                .map(|v| v.as_str())
+                .collect();
+            if hits.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "`{}={}` (superseded: {})",
+                    rule.key,
+                    rule.value,
+                    hits.join(", ")
+                ))
+            }
+        })
+        .collect()
+}
+
+// ── Internal helpers ────────────────────────────────────────────────
+
+fn load_rules_cached(repo_root: &Path, branch: &str) -> Vec<BindingRule> {
+    let mut cache = BINDING_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    let key = (repo_root.display().to_string(), branch.to_string());
+
+    if let Some(ref cached_key) = cache.key {
+        if *cached_key == key && cache.loaded_at.elapsed().as_secs() < CACHE_TTL_SECS {
+            return cache.rules.clone();
+        }
+    }
+
+    let rules = edda_ledger::Ledger::open(repo_root)
+        .and_then(|ledger| load_rules(&ledger, branch))
+        .unwrap_or_default();
+
+    cache.key = Some(key);
+    cache.rules = rules.clone();
+    cache.loaded_at = std::time::Instant::now();
+    rules
+}
+
+fn load_rules(ledger: &edda_ledger::Ledger, branch: &str) -> anyhow::Result<Vec<BindingRule>> {
+    let ratified = ledger.ratified_decision_events()?;
+    if ratified.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut rules = Vec::new();
+    for d in ledger.active_decisions(None, None, None, None)? {
+        if d.branch != branch || !ratified.contains(&d.event_id) {
+            continue;
+        }
+        let current = d.value.to_lowercase();
+        let mut superseded: Vec<String> = Vec::new();
+        for past in ledger.decision_timeline(&d.key, None, None)? {
+            let lower = past.value.to_lowercase();
+            if past.branch != branch
+                || lower == current
+                || current.contains(&lower)
+                || !is_distinctive(&lower)
+                || superseded.iter().any(|s| s.to_lowercase() == lower)
+            {
+                continue;
+            }
+            superseded.push(past.value);
+        }
+        if !superseded.is_empty() {
+            rules.push(BindingRule {
+                key: d.key,
+                value: d.value,
+                superseded,
+            });
+        }
+    }
+    Ok(rules)
+}
+
+fn is_distinctive(value: &str) -> bool {
+    value.chars().count() >= 3
+        && !value.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && !GENERIC_VALUES.contains(&value)
+}
+
+/// Whole-word, already-lowercased containment check.
+fn mentions(haystack: &str, needle: &str) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    haystack.match_indices(needle).any(|(i, m)| {
+        let before = haystack[..i].chars().next_back();
+        let after = haystack[i + m.len()..].chars().next();
+        !before.is_some_and(is_word) && !after.is_some_and(is_word)
+    })
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn append(ledger: &edda_ledger::Ledger, event: &mut edda_core::Event) {
+        event.parent_hash = ledger.last_event_hash().unwrap();
+        edda_core::event::finalize_event(event).unwrap();
+        ledger.append_event(event).unwrap();
+    }
+
+    fn decide(ledger: &edda_ledger::Ledger, key: &str, value: &str) {
+        let dp = edda_core::types::DecisionPayload {
+            key: key.to_string(),
+            value: value.to_string(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let mut event = edda_core::event::new_decision_event("main", None, "system", &dp).unwrap();
+        append(ledger, &mut event);
+    }
+
+    fn invalidate_cache() {
+        let mut cache = BINDING_CACHE.lock().unwrap();
+        cache.key = None;
+        cache.rules.clear();
+    }
+
+    #[test]
+    fn mentions_requires_word_boundaries() {
+        assert!(mentions("use sqlite here", "sqlite"));
+        assert!(mentions("sqlite::open()", "sqlite"));
+        assert!(!mentions("rusqlite::open()", "sqlite"));
+        assert!(!mentions("sqlite3_open", "sqlite"));
+    }
+
+    #[test]
+    fn flags_superseded_value_only_once_ratified() {
+        invalidate_cache();
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let ledger = edda_ledger::Ledger::open_or_init(root).unwrap();
+        ledger.set_head_branch("main").unwrap();
+        decide(&ledger, "db.engine", "sqlite");
+        decide(&ledger, "db.engine", "postgres");
+
+        // Unratified: guidance only, nothing binding to contradict.
+        let hits = binding_contradictions(root, "main", "", "let db = sqlite::open()");
+        assert!(hits.is_empty());
+
+        let mut ratify =
+            edda_core::event::new_decision_ratify_event("main", None, "db.engine", "op", None)
+                .unwrap();
+        append(&ledger, &mut ratify);
+        invalidate_cache();
+
+        let hits = binding_contradictions(root, "main", "", "let db = sqlite::open()");
+        assert_eq!(hits, vec!["`db.engine=postgres` (superseded: sqlite)"]);
+
+        // Already present in the replaced text: not introduced by this edit.
+        let hits = binding_contradictions(root, "main", "sqlite::open()", "sqlite::open(p)");
+        assert!(hits.is_empty());
+    }
+}
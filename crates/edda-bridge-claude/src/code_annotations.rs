@@ -0,0 +1,374 @@
+//! Code annotations — links `edda:decision <key>` comments to ledger decisions.
+//!
+//! A comment such as `// edda:decision db.engine` (any comment syntax works,
+//! the marker is matched anywhere on the line) ties a code location to a
+//! decision key. `edda annotate-code` scans the repo, resolves each key against
+//! the active decisions on the head branch, and writes the result to
+//! `.edda/code_annotations.json` so editor extensions can show the decision
+//! and its reason on hover without opening the ledger themselves.
+
+use anyhow::{Context, Result};
+use edda_ledger::Ledger;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use crate::bg_extract::now_rfc3339;
+
+/// File name of the hover export inside `.edda/`.
+pub const EXPORT_FILE: &str = "code_annotations.json";
+
+/// Files larger than this are not scanned (generated or vendored blobs).
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Directories skipped when the repo is not a git checkout.
+const SKIP_DIRS: &[&str] = &[".git", ".edda", "target", "node_modules"];
+
+static RE_ANNOTATION: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"edda:decision\s+([A-Za-z0-9_-]+(?:\.[A-Za-z0-9_-]+)+)")
+        .expect("static regex")
+});
+
+// ── Data Structures ──
+
+/// One `edda:decision` marker found in the source tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeAnnotation {
+    /// Repo-relative path with `/` separators.
+    pub path: String,
+    /// 1-based line number of the marker.
+    pub line: usize,
+    pub key: String,
+}
+
+/// Hover data for one annotation, joined with the ledger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HoverEntry {
+    pub path: String,
+    pub line: usize,
+    pub key: String,
+    /// False when no active decision exists for `key` on the branch.
+    pub resolved: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ts: Option<String>,
+}
+
+/// The on-disk export consumed by editor extensions.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AnnotationExport {
+    pub generated_at: String,
+    pub branch: String,
+    pub entries: Vec<HoverEntry>,
+}
+
+impl HoverEntry {
+    /// The bare annotation, for re-resolving against a newer ledger state.
+    pub fn annotation(&self) -> CodeAnnotation {
+        CodeAnnotation {
+            path: self.path.clone(),
+            line: self.line,
+            key: self.key.clone(),
+        }
+    }
+}
+
+impl AnnotationExport {
+    pub fn unresolved(&self) -> impl Iterator<Item = &HoverEntry> {
+        self.entries.iter().filter(|e| !e.resolved)
+    }
+}
+
+// ── Scanning ──
+
+/// Find every `edda:decision` marker in `content`.
+pub fn scan_text(path: &str, content: &str) -> Vec<CodeAnnotation> {
+    let mut found = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        for cap in RE_ANNOTATION.captures_iter(line) {
+            found.push(CodeAnnotation {
+                path: path.to_string(),
+                line: idx + 1,
+                key: cap[1].to_string(),
+            });
+        }
+    }
+    found
+}
+
+/// Scan the repo for annotations, sorted by path and line.
+///
+/// Uses `git ls-files` so ignored files are skipped; falls back to a
+/// directory walk outside a git checkout. Binary and oversized files are
+/// ignored.
+pub fn scan_repo(repo_root: &Path) -> Result<Vec<CodeAnnotation>> {
+    let files = match git_files(repo_root) {
+        Some(files) => files,
+        None => {
+            let mut files = Vec::new();
+            walk_files(repo_root, repo_root, &mut files)?;
+            files
+        }
+    };
+
+    let mut found = Vec::new();
+    for rel in files {
+        let abs = repo_root.join(&rel);
+        match std::fs::metadata(&abs) {
+            Ok(meta) if meta.is_file() && meta.len() <= MAX_FILE_BYTES => {}
+            _ => continue,
+        }
+        let Ok(content) = std::fs::read_to_string(&abs) else {
+            continue;
+        };
+        found.extend(scan_text(&normalize_path(&rel), &content));
+    }
+    found.sort_by(|a, b| a.path.cmp(&b.path).then(a.line.cmp(&b.line)));
+    Ok(found)
+}
+
+/// Tracked and untracked-but-not-ignored files, or `None` outside git.
+fn git_files(repo_root: &Path) -> Option<Vec<String>> {
+    let output = std::process::Command::new("git")
+        .args([
+            "ls-files",
+            "-z",
+            "--cached",
+            "--others",
+            "--exclude-standard",
+        ])
+        .current_dir(repo_root)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let mut files: Vec<String> = output
+        .stdout
+        .split(|b| *b == 0)
+        .filter(|s| !s.is_empty())
+        .map(|s| String::from_utf8_lossy(s).into_owned())
+        .collect();
+    files.dedup();
+    Some(files)
+}
+
+fn walk_files(root: &Path, dir: &Path, out: &mut Vec<String>) -> Result<()> {
+    let entries =
+        std::fs::read_dir(dir).with_context(|| format!("cannot read {}", dir.display()))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let name = entry.file_name();
+            if SKIP_DIRS.iter().any(|d| name == *d) {
+                continue;
+            }
+            walk_files(root, &path, out)?;
+        } else if file_type.is_file() {
+            if let Ok(rel) = path.strip_prefix(root) {
+                out.push(rel.to_string_lossy().into_owned());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Repo-relative path with `/` separators and no leading `./`.
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./").unwrap_or(&path).to_string()
+}
+
+// ── Ledger join ──
+
+/// Resolve annotations against the active decisions on `branch`.
+pub fn resolve(
+    ledger: &Ledger,
+    branch: &str,
+    annotations: &[CodeAnnotation],
+) -> Result<Vec<HoverEntry>> {
+    let mut cache = HashMap::new();
+    let mut entries = Vec::with_capacity(annotations.len());
+    for ann in annotations {
+        if !cache.contains_key(&ann.key) {
+            let decision = ledger.find_active_decision(branch, &ann.key)?;
+            cache.insert(ann.key.clone(), decision);
+        }
+        let decision = cache.get(&ann.key).and_then(|d| d.as_ref());
+        entries.push(HoverEntry {
+            path: ann.path.clone(),
+            line: ann.line,
+            key: ann.key.clone(),
+            resolved: decision.is_some(),
+            value: decision.map(|d| d.value.clone()),
+            reason: decision.map(|d| d.reason.clone()),
+            status: decision.map(|d| d.status.clone()),
+            event_id: decision.map(|d| d.event_id.clone()),
+            ts: decision.and_then(|d| d.ts.clone()),
+        });
+    }
+    Ok(entries)
+}
+
+/// Scan `repo_root` and join the result with the head branch.
+pub fn build_export(ledger: &Ledger, repo_root: &Path) -> Result<AnnotationExport> {
+    let branch = ledger.head_branch()?;
+    let annotations = scan_repo(repo_root)?;
+    let entries = resolve(ledger, &branch, &annotations)?;
+    Ok(AnnotationExport {
+        generated_at: now_rfc3339(),
+        branch,
+        entries,
+    })
+}
+
+/// Location of the export for a workspace.
+pub fn export_path(edda_dir: &Path) -> PathBuf {
+    edda_dir.join(EXPORT_FILE)
+}
+
+pub fn write_export(edda_dir: &Path, export: &AnnotationExport) -> Result<PathBuf> {
+    let path = export_path(edda_dir);
+    let data = serde_json::to_vec_pretty(export)?;
+    edda_store::write_atomic(&path, &data)?;
+    Ok(path)
+}
+
+/// Read a previous export; `None` when `edda annotate-code` has not run yet.
+pub fn read_export(edda_dir: &Path) -> Result<Option<AnnotationExport>> {
+    let path = export_path(edda_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("cannot read {}", path.display()))?;
+    let export = serde_json::from_str(&content)
+        .with_context(|| format!("invalid annotation export {}", path.display()))?;
+    Ok(Some(export))
+}
+
+// ── Lookup ──
+
+/// Hover entries for `path:line`.
+///
+/// Matches markers on the line itself and on the line directly above, so
+/// hovering the code under an annotation comment also shows the decision.
+pub fn lookup<'a>(entries: &'a [HoverEntry], path: &str, line: usize) -> Vec<&'a HoverEntry> {
+    let path = normalize_path(path);
+    entries
+        .iter()
+        .filter(|e| e.path == path && (e.line == line || e.line + 1 == line))
+        .collect()
+}
+
+/// Parse a `FILE:LINE` lookup target.
+pub fn parse_location(target: &str) -> Result<(String, usize)> {
+    let (path, line) = target
+        .rsplit_once(':')
+        .with_context(|| format!("expected FILE:LINE, got '{target}'"))?;
+    let line: usize = line
+        .parse()
+        .with_context(|| format!("invalid line number in '{target}'"))?;
+    if path.is_empty() || line == 0 {
+        anyhow::bail!("expected FILE:LINE with a 1-based line, got '{target}'");
+    }
+    Ok((normalize_path(path), line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::new_decision_event;
+    use edda_core::types::DecisionPayload;
+
+    #[test]
+    fn scan_text_finds_markers_in_any_comment_style() {
+        let src = "fn main() {}\n// edda:decision db.engine\n# edda:decision auth.method, see docs\n/* edda:decision nokey */\n";
+        let found = scan_text("src/main.rs", src);
+        assert_eq!(
+            found,
+            vec![
+                CodeAnnotation {
+                    path: "src/main.rs".into(),
+                    line: 2,
+                    key: "db.engine".into(),
+                },
+                CodeAnnotation {
+                    path: "src/main.rs".into(),
+                    line: 3,
+                    key: "auth.method".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn export_resolves_active_decisions_and_lookup_matches_next_line() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let paths = edda_ledger::EddaPaths::discover(root);
+        paths.ensure_layout().unwrap();
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(root).unwrap();
+        let event = new_decision_event(
+            "main",
+            None,
+            "system",
+            &DecisionPayload {
+                key: "db.engine".into(),
+                value: "sqlite".into(),
+                reason: Some("embedded, zero-ops".into()),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            },
+        )
+        .unwrap();
+        ledger.append_event(&event).unwrap();
+
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(
+            root.join("src/db.rs"),
+            "// edda:decision db.engine\nfn open() {}\n// edda:decision cache.ttl\n",
+        )
+        .unwrap();
+
+        let export = build_export(&ledger, root).unwrap();
+        assert_eq!(export.branch, "main");
+        assert_eq!(export.entries.len(), 2);
+        assert_eq!(export.unresolved().count(), 1);
+
+        write_export(&paths.edda_dir, &export).unwrap();
+        let back = read_export(&paths.edda_dir).unwrap().unwrap();
+        assert_eq!(back, export);
+
+        let hits = lookup(&back.entries, "./src/db.rs", 2);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].value.as_deref(), Some("sqlite"));
+        assert_eq!(hits[0].reason.as_deref(), Some("embedded, zero-ops"));
+        assert!(lookup(&back.entries, "src/db.rs", 5).is_empty());
+
+        assert_eq!(
+            parse_location("src/db.rs:2").unwrap(),
+            ("src/db.rs".to_string(), 2)
+        );
+        assert!(parse_location("src/db.rs").is_err());
+    }
+}
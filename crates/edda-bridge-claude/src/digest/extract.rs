@@ -6,7 +6,9 @@ use super::helpers::{
     compute_duration_minutes, extract_bash_command, extract_envelope_cwd, extract_exit_code,
     extract_file_path, extract_git_commit_msg,
 };
-use super::{ActivityType, DigestTaskSnapshot, FailedCommand, SessionOutcome, SessionStats};
+use super::{
+    ActivityType, DigestTaskSnapshot, FailedCommand, SessionOutcome, SessionStats, SubagentStats,
+};
 
 pub fn extract_stats(session_ledger_path: &Path) -> anyhow::Result<SessionStats> {
     let mut stats = SessionStats::default();
@@ -17,6 +19,9 @@ pub fn extract_stats(session_ledger_path: &Path) -> anyhow::Result<SessionStats>
     let mut last_event_name = String::new();
     let mut trailing_failures: u32 = 0;
 
+    // Sub-agents between their SubagentStart and SubagentStop, latest last.
+    let mut running_subagents: Vec<String> = Vec::new();
+
     if !session_ledger_path.exists() {
         return Ok(stats);
     }
@@ -58,7 +63,48 @@ pub fn extract_stats(session_ledger_path: &Path) -> anyhow::Result<SessionStats>
             last_event_name = event_name.to_string();
         }
 
+        let raw = envelope.get("raw").unwrap_or(&serde_json::Value::Null);
         match event_name {
+            "SubagentStart" => {
+                let agent_id = crate::parse::get_str(raw, "agent_id");
+                if !agent_id.is_empty() {
+                    subagent_entry(&mut stats.subagents, &agent_id).agent_type =
+                        crate::parse::get_str(raw, "agent_type");
+                    running_subagents.push(agent_id);
+                }
+            }
+            "SubagentStop" => {
+                let agent_id = crate::parse::get_str(raw, "agent_id");
+                running_subagents.retain(|id| *id != agent_id);
+            }
+            "PostToolUse" if subagent_of(raw, &running_subagents).is_some() => {
+                let agent_id = subagent_of(raw, &running_subagents).unwrap_or_default();
+                let sub = subagent_entry(&mut stats.subagents, &agent_id);
+                sub.tool_calls += 1;
+                let tool_name = envelope
+                    .get("tool_name")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                    .unwrap_or_else(|| crate::parse::get_str(raw, "tool_name"));
+                if tool_name == "Edit" || tool_name == "Write" {
+                    if let Some(fp) = extract_file_path(&envelope) {
+                        if !crate::signals::is_noise_file(&fp) && !sub.files_modified.contains(&fp)
+                        {
+                            sub.files_modified.push(fp);
+                        }
+                    }
+                }
+                if tool_name == "Bash" {
+                    if let Some(cmd) = extract_bash_command(&envelope) {
+                        if cmd.contains("git commit") {
+                            let msg = extract_git_commit_msg(&cmd);
+                            if !msg.is_empty() {
+                                sub.commits_made.push(msg);
+                            }
+                        }
+                    }
+                }
+            }
             "PostToolUse" => {
                 stats.tool_calls += 1;
                 // Extract tool_name and accumulate per-tool breakdown
@@ -128,12 +174,16 @@ pub fn extract_stats(session_ledger_path: &Path) -> anyhow::Result<SessionStats>
             }
             "UserPromptSubmit" => {
                 stats.user_prompts += 1;
+                // The parent is taking prompts again, so any sub-agent whose
+                // SubagentStop never arrived is no longer running.
+                running_subagents.clear();
             }
             _ => {}
         }
     }
 
     stats.files_modified = files_set.into_iter().collect();
+    stats.subagents.retain(|s| s.tool_calls > 0);
     stats.file_edit_counts = file_edit_map.into_iter().collect();
     stats.duration_minutes = compute_duration_minutes(&stats.first_ts, &stats.last_ts);
 
@@ -152,6 +202,34 @@ pub fn extract_stats(session_ledger_path: &Path) -> anyhow::Result<SessionStats>
     Ok(stats)
 }
 
+/// The sub-agent a tool call belongs to: the hook's own `agent_id` when the
+/// payload carries one, else the most recently started sub-agent still running
+/// (the parent waits on its Task call meanwhile).
+fn subagent_of(raw: &serde_json::Value, running: &[String]) -> Option<String> {
+    let agent_id = crate::parse::get_str(raw, "agent_id");
+    if !agent_id.is_empty() {
+        return Some(agent_id);
+    }
+    running.last().cloned()
+}
+
+fn subagent_entry<'a>(
+    subagents: &'a mut Vec<SubagentStats>,
+    agent_id: &str,
+) -> &'a mut SubagentStats {
+    let idx = match subagents.iter().position(|s| s.agent_id == agent_id) {
+        Some(idx) => idx,
+        None => {
+            subagents.push(SubagentStats {
+                agent_id: agent_id.to_string(),
+                ..Default::default()
+            });
+            subagents.len() - 1
+        }
+    };
+    &mut subagents[idx]
+}
+
 /// Load tasks snapshot from state/active_tasks.json for a project.
 /// Returns empty vec if file doesn't exist or can't be parsed.
 pub fn load_tasks_for_digest(project_id: &str) -> Vec<DigestTaskSnapshot> {
@@ -249,6 +327,25 @@ pub fn render_digest_text(session_id: &str, stats: &SessionStats) -> String {
         }
     }
 
+    if !stats.subagents.is_empty() {
+        lines.push("Sub-agents:".to_string());
+        for sub in &stats.subagents {
+            let name = if sub.agent_type.is_empty() {
+                sub.agent_id.clone()
+            } else {
+                format!("{} ({})", sub.agent_id, sub.agent_type)
+            };
+            let mut line = format!("  - {name}: {} tool calls", sub.tool_calls);
+            if !sub.files_modified.is_empty() {
+                line.push_str(&format!(", files: {}", sub.files_modified.join(", ")));
+            }
+            if !sub.commits_made.is_empty() {
+                line.push_str(&format!(", {} commits", sub.commits_made.len()));
+            }
+            lines.push(line);
+        }
+    }
+
     // Tool breakdown
     if !stats.tool_call_breakdown.is_empty() {
         let breakdown: Vec<String> = stats
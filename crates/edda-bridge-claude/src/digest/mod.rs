@@ -121,6 +121,18 @@ pub struct SessionStats {
     pub estimated_cost_usd: f64,
     /// Activity classification for this session.
     pub activity: ActivityType,
+    /// Tool calls made by Task-tool sub-agents, which are not counted above.
+    pub subagents: Vec<SubagentStats>,
+}
+
+/// Tool activity of one sub-agent within a session.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SubagentStats {
+    pub agent_id: String,
+    pub agent_type: String,
+    pub tool_calls: u64,
+    pub files_modified: Vec<String>,
+    pub commits_made: Vec<String>,
 }
 
 /// Extract statistics from a session ledger file.
@@ -129,6 +141,7 @@ mod helpers;
 mod orchestrate;
 mod prev;
 mod render;
+mod schedule;
 
 // Re-export all public items to preserve API
 pub use extract::{extract_stats, load_tasks_for_digest, render_digest_text};
@@ -142,6 +155,10 @@ pub use prev::{
     write_prev_digest_from_store, PrevDigest,
 };
 pub use render::{build_cmd_milestone_event, build_digest_event, extract_session_digest};
+pub use schedule::{
+    digest_idle_sessions, idle_threshold_secs, is_session_idle, sweep_idle_sessions,
+    DEFAULT_IDLE_MINUTES,
+};
 
 #[cfg(test)]
 #[path = "tests.rs"]
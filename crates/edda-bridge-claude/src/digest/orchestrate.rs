@@ -57,11 +57,13 @@ fn digest_state_path(project_id: &str) -> std::path::PathBuf {
         .join("last_digested_session.json")
 }
 
-/// Find session ledger files in the store, excluding the current session.
+/// Find session ledger files in the store, excluding the current session
+/// and sessions still active within the idle window (see [`super::schedule`]).
 fn find_pending_sessions(
     project_id: &str,
     current_session_id: &str,
     state: &DigestState,
+    idle_secs: u64,
 ) -> Vec<String> {
     let ledger_dir = edda_store::project_dir(project_id).join("ledger");
     let entries = match std::fs::read_dir(&ledger_dir) {
@@ -84,6 +86,10 @@ fn find_pending_sessions(
         if session_id == state.session_id {
             continue;
         }
+        // Skip peers still working; they are digested once they go quiet
+        if !super::schedule::is_session_idle(project_id, &session_id, idle_secs) {
+            continue;
+        }
         sessions.push(session_id);
     }
     // Sort for deterministic order (oldest first by ULID/name)
@@ -171,7 +177,8 @@ pub fn digest_previous_sessions_with_opts(
     }
 
     // Find sessions to digest
-    let pending = find_pending_sessions(project_id, current_session_id, &state);
+    let idle_secs = super::schedule::idle_threshold_secs(cwd);
+    let pending = find_pending_sessions(project_id, current_session_id, &state, idle_secs);
     if pending.is_empty() {
         // Check if there's a retry pending
         if !state.pending_session_id.is_empty() && state.retry_count > 0 {
@@ -236,7 +236,10 @@ pub fn collect_session_ledger_extras(
         } else if tags.contains(&"session") {
             // Session note written by agent via `edda note --tag session`
             if let Some(text) = event.payload.get("text").and_then(|v| v.as_str()) {
-                notes.push(text.to_string());
+                match edda_core::NoteBody::from_payload(&event.payload) {
+                    Some(body) => notes.push(format!("{text} {}", body.summary())),
+                    None => notes.push(text.to_string()),
+                }
             }
         }
     }
@@ -50,6 +50,7 @@ pub fn build_digest_event(
             "estimated_cost_usd": stats.estimated_cost_usd,
             "activity": stats.activity.to_string(),
             "file_edit_counts": stats.file_edit_counts,
+            "subagents": stats.subagents,
             "notes": notes,
         }
     });
@@ -0,0 +1,95 @@
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use super::orchestrate::{digest_previous_sessions_with_opts, DigestResult};
+
+// ── Smart Digest Scheduling ──
+
+/// Minutes a session's heartbeat must be quiet before it is digested.
+pub const DEFAULT_IDLE_MINUTES: u64 = 30;
+
+/// Upper bound on sessions digested by one [`digest_idle_sessions`] sweep,
+/// so a backlog drains over a few sweeps instead of holding the workspace
+/// lock for minutes.
+const MAX_PER_SWEEP: usize = 10;
+
+/// Idle window in seconds: `EDDA_BRIDGE_DIGEST_IDLE_MINUTES`, else
+/// `bridge.digest_idle_minutes` in `.edda/config.json`, else 30 minutes.
+/// `0` digests other sessions as soon as any hook runs.
+pub fn idle_threshold_secs(cwd: &str) -> u64 {
+    let minutes = std::env::var("EDDA_BRIDGE_DIGEST_IDLE_MINUTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .or_else(|| {
+            crate::render::config_usize(cwd, "bridge.digest_idle_minutes").map(|v| v as u64)
+        })
+        .unwrap_or(DEFAULT_IDLE_MINUTES);
+    minutes * 60
+}
+
+/// Whether a session has gone quiet long enough to digest.
+///
+/// A session without a heartbeat has ended (SessionEnd removes it) or never
+/// registered one, so it is idle. Otherwise the heartbeat, touched by every
+/// hook, must be at least `idle_secs` old.
+pub fn is_session_idle(project_id: &str, session_id: &str, idle_secs: u64) -> bool {
+    let Some(hb) = crate::peers::read_heartbeat(project_id, session_id) else {
+        return true;
+    };
+    let last = OffsetDateTime::parse(&hb.last_heartbeat, &Rfc3339)
+        .map(|t| t.unix_timestamp())
+        .unwrap_or(0);
+    let now = OffsetDateTime::now_utc().unix_timestamp();
+    now.saturating_sub(last) >= idle_secs as i64
+}
+
+/// Digest every session that has gone idle, oldest first, without waiting
+/// for a new session to start. Stops at the first session that is not
+/// written (lock timeout, failure, nothing left). Returns the event IDs
+/// written.
+///
+/// Meant for periodic callers such as `edda serve`; hooks use
+/// [`digest_previous_sessions_with_opts`], which digests one per call.
+pub fn digest_idle_sessions(
+    project_id: &str,
+    cwd: &str,
+    lock_timeout_ms: u64,
+    digest_failed_cmds: bool,
+) -> Vec<String> {
+    let mut written = Vec::new();
+    for _ in 0..MAX_PER_SWEEP {
+        match digest_previous_sessions_with_opts(
+            project_id,
+            "",
+            cwd,
+            lock_timeout_ms,
+            digest_failed_cmds,
+        ) {
+            DigestResult::Written { event_id } => written.push(event_id),
+            _ => break,
+        }
+    }
+    written
+}
+
+/// [`digest_idle_sessions`] with the settings hooks use:
+/// `bridge.auto_digest` (or `EDDA_BRIDGE_AUTO_DIGEST`) must not be off,
+/// and `EDDA_BRIDGE_LOCK_TIMEOUT_MS` and `bridge.digest_failed_cmds` apply.
+pub fn sweep_idle_sessions(project_id: &str, cwd: &str) -> Vec<String> {
+    let enabled = match std::env::var("EDDA_BRIDGE_AUTO_DIGEST") {
+        Ok(val) => val != "0",
+        Err(_) => crate::render::config_bool(cwd, "bridge.auto_digest").unwrap_or(true),
+    };
+    if !enabled {
+        return Vec::new();
+    }
+    let lock_timeout_ms = std::env::var("EDDA_BRIDGE_LOCK_TIMEOUT_MS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(2000);
+    let digest_failed_cmds = match std::env::var("EDDA_BRIDGE_DIGEST_FAILED_CMDS") {
+        Ok(val) => val != "0",
+        Err(_) => crate::render::config_bool(cwd, "bridge.digest_failed_cmds").unwrap_or(true),
+    };
+    digest_idle_sessions(project_id, cwd, lock_timeout_ms, digest_failed_cmds)
+}
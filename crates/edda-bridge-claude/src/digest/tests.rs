@@ -136,6 +136,66 @@ fn digest_extracts_files() {
     assert!(stats.files_modified.contains(&"/src/main.rs".to_string()));
 }
 
+#[test]
+fn digest_attributes_subagent_tool_calls_to_the_subagent() {
+    let tmp = tempfile::tempdir().unwrap();
+    let edit = |path: &str, extra: serde_json::Value| {
+        let mut raw = serde_json::json!({ "tool_input": { "file_path": path } });
+        if let Some(obj) = extra.as_object() {
+            for (k, v) in obj {
+                raw[k.clone()] = v.clone();
+            }
+        }
+        make_envelope("PostToolUse", "Edit", raw)
+    };
+    let lines = vec![
+        edit("/src/lib.rs", serde_json::json!({})),
+        make_envelope(
+            "SubagentStart",
+            "",
+            serde_json::json!({ "agent_id": "agent-1", "agent_type": "Explore" }),
+        ),
+        edit("/src/db.rs", serde_json::json!({})),
+        make_envelope(
+            "PostToolUse",
+            "Bash",
+            serde_json::json!({ "tool_input": { "command": "git commit -m \"feat: db\"" } }),
+        ),
+        make_envelope(
+            "SubagentStop",
+            "",
+            serde_json::json!({ "agent_id": "agent-1" }),
+        ),
+        // A payload naming its agent is attributed even outside a window.
+        edit(
+            "/src/cache.rs",
+            serde_json::json!({ "agent_id": "agent-2" }),
+        ),
+        edit("/src/main.rs", serde_json::json!({})),
+    ];
+    let path = write_session_ledger(tmp.path(), &lines);
+    let stats = extract_stats(&path).unwrap();
+
+    assert_eq!(stats.tool_calls, 2);
+    assert_eq!(
+        stats.files_modified,
+        vec!["/src/lib.rs".to_string(), "/src/main.rs".to_string()]
+    );
+    assert!(stats.commits_made.is_empty());
+
+    assert_eq!(stats.subagents.len(), 2);
+    assert_eq!(stats.subagents[0].agent_id, "agent-1");
+    assert_eq!(stats.subagents[0].agent_type, "Explore");
+    assert_eq!(stats.subagents[0].tool_calls, 2);
+    assert_eq!(stats.subagents[0].files_modified, vec!["/src/db.rs"]);
+    assert_eq!(stats.subagents[0].commits_made, vec!["feat: db"]);
+    assert_eq!(stats.subagents[1].files_modified, vec!["/src/cache.rs"]);
+
+    let text = render_digest_text("test_session", &stats);
+    assert!(text.contains("Sub-agents:"));
+    assert!(text.contains("  - agent-1 (Explore): 2 tool calls, files: /src/db.rs, 1 commits"));
+}
+
 #[test]
 fn digest_extracts_failed_cmds() {
     let tmp = tempfile::tempdir().unwrap();
@@ -542,6 +602,55 @@ fn digest_skips_already_digested() {
     assert_eq!(ledger.iter_events().unwrap().len(), 1);
 }
 
+#[test]
+fn digest_waits_for_peer_sessions_to_go_idle() {
+    let tmp = tempfile::tempdir().unwrap();
+    let (workspace, project_id) = setup_digest_workspace(tmp.path());
+    let ws = workspace.to_str().unwrap();
+    for sid in ["sess-live", "sess-quiet"] {
+        write_store_session_ledger(
+            &project_id,
+            sid,
+            &[make_envelope("PostToolUse", "Bash", serde_json::json!({}))],
+        );
+    }
+    // A peer still working, and one whose heartbeat went stale hours ago.
+    crate::peers::write_heartbeat_minimal(&project_id, "sess-live", "live", ws);
+    let state_dir = edda_store::project_dir(&project_id).join("state");
+    std::fs::write(
+        state_dir.join("session.sess-quiet.json"),
+        serde_json::json!({
+            "session_id": "sess-quiet",
+            "started_at": "2020-01-01T00:00:00Z",
+            "last_heartbeat": "2020-01-01T00:00:00Z",
+            "label": "quiet",
+            "focus_files": [],
+            "active_tasks": [],
+            "files_modified_count": 0,
+            "total_edits": 0,
+            "recent_commits": []
+        })
+        .to_string(),
+    )
+    .unwrap();
+    assert!(!is_session_idle(&project_id, "sess-live", 30 * 60));
+    assert!(is_session_idle(&project_id, "sess-quiet", 30 * 60));
+    assert!(is_session_idle(&project_id, "sess-gone", 30 * 60));
+
+    // A sweep digests only the idle session.
+    let written = digest_idle_sessions(&project_id, ws, 2000, false);
+    assert_eq!(written.len(), 1);
+    let ledger_dir = edda_store::project_dir(&project_id).join("ledger");
+    assert!(!ledger_dir.join("sess-quiet.jsonl").exists());
+    assert!(ledger_dir.join("sess-live.jsonl").exists());
+
+    // Once the live peer ends (SessionEnd removes its heartbeat), it is next.
+    crate::peers::remove_heartbeat(&project_id, "sess-live");
+    let r = digest_previous_sessions(&project_id, "sess-new", ws, 2000);
+    assert!(matches!(r, DigestResult::Written { .. }));
+    assert!(!ledger_dir.join("sess-live.jsonl").exists());
+}
+
 #[test]
 fn digest_no_reduplicate_across_sessions() {
     let tmp = tempfile::tempdir().unwrap();
@@ -3,6 +3,19 @@ use std::path::{Path, PathBuf};
 
 use super::read_workspace_config_bool;
 
+// ── Warm Pack ──
+
+/// Rebuild `packs/warm.md` from the workspace ledger's session digests.
+/// Returns the rendered pack, or `None` outside a workspace or before the
+/// first digest.
+pub(crate) fn refresh_warm_pack(project_id: &str, cwd: &str) -> Option<String> {
+    if cwd.is_empty() {
+        return None;
+    }
+    let root = edda_ledger::EddaPaths::find_root(Path::new(cwd))?;
+    edda_pack::refresh_warm_pack(&edda_store::project_dir(project_id), &root)
+}
+
 // ── Active Plan ──
 
 /// Default maximum chars for the plan excerpt.
@@ -164,6 +177,7 @@ pub(super) fn run_auto_digest(
     ) {
         crate::digest::DigestResult::Written { event_id } => {
             tracing::info!(%event_id, "digested previous session");
+            refresh_warm_pack(project_id, cwd);
             None
         }
         crate::digest::DigestResult::PermanentFailure(warning) => Some(warning),
@@ -184,7 +198,7 @@ pub(super) fn extract_prior_session_last_message(
     project_id: &str,
     current_session_id: &str,
 ) -> Option<String> {
-    let transcripts_dir = edda_store::project_dir(project_id).join("transcripts");
+    let transcripts_dir = edda_store::project_subdir(project_id, "transcripts");
     if !transcripts_dir.is_dir() {
         return None;
     }
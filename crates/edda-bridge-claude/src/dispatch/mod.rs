@@ -16,9 +16,10 @@ pub(crate) use helpers::render_active_plan;
 // Sub-module function imports used in hook_entrypoint_from_stdin
 use events::{try_write_subagent_completed_note_event, try_write_task_completed_note_event};
 use helpers::run_auto_digest;
+pub use session::ingest_and_build_pack;
 use session::{
     dispatch_session_end, dispatch_session_start, dispatch_subagent_context,
-    dispatch_user_prompt_submit, ingest_and_build_pack,
+    dispatch_user_prompt_submit,
 };
 use tools::{dispatch_post_tool_use, dispatch_pre_tool_use};
 
@@ -379,6 +380,10 @@ fn read_workspace_config_bool(cwd: &str, key: &str) -> Option<bool> {
     render::config_bool(cwd, key)
 }
 
+fn read_workspace_config_str(cwd: &str, key: &str) -> Option<String> {
+    render::config_value(cwd, key)?.as_str().map(str::to_string)
+}
+
 pub(crate) fn read_hot_pack(project_id: &str) -> Option<String> {
     let pack_path = edda_store::project_dir(project_id)
         .join("packs")
@@ -386,6 +391,15 @@ pub(crate) fn read_hot_pack(project_id: &str) -> Option<String> {
     fs::read_to_string(&pack_path).ok()
 }
 
+/// A hot pack is empty when it is missing or rendered zero turns — e.g. a
+/// brand-new project store, or a session whose transcript did not exist yet.
+pub(crate) fn hot_pack_is_empty(pack: Option<&str>) -> bool {
+    match pack {
+        None => true,
+        Some(p) => p.contains("\n- turns: 0\n"),
+    }
+}
+
 // ── Workspace Context (delegate to render module) ──
 
 pub(crate) fn render_workspace_section(cwd: &str, workspace_budget: usize) -> Option<String> {
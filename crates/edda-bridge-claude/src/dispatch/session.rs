@@ -4,22 +4,20 @@ use std::path::Path;
 use crate::signals::{extract_session_signals, save_session_signals, TaskSnapshot};
 
 use super::helpers::{
-    extract_prior_session_last_message, inject_karvi_brief, read_project_state, render_active_plan,
-    render_skill_guide_directive, run_auto_digest,
+    extract_prior_session_last_message, inject_karvi_brief, read_project_state, refresh_warm_pack,
+    render_active_plan, render_skill_guide_directive, run_auto_digest,
 };
 use super::{
-    apply_context_budget, context_budget, is_same_as_last_inject, read_counter, read_hot_pack,
-    read_peer_count, read_workspace_config_bool, render_workspace_section,
+    apply_context_budget, context_budget, hot_pack_is_empty, is_same_as_last_inject, read_counter,
+    read_hot_pack, read_peer_count, read_workspace_config_bool, render_workspace_section,
     render_write_back_protocol, take_compact_pending, wrap_context_boundary, write_inject_hash,
     write_peer_count, HookResult,
 };
 
-pub(super) fn ingest_and_build_pack(
-    project_id: &str,
-    session_id: &str,
-    transcript_path: &str,
-    cwd: &str,
-) {
+/// Ingest the transcript delta (with index records), then rebuild the hot
+/// pack, session signals and heartbeat. Shared with the Codex bridge, whose
+/// rollout files are normalized during ingest.
+pub fn ingest_and_build_pack(project_id: &str, session_id: &str, transcript_path: &str, cwd: &str) {
     if transcript_path.is_empty() || session_id.is_empty() {
         return;
     }
@@ -52,23 +50,32 @@ pub(super) fn ingest_and_build_pack(
         transcript,
         Some(&index_writer),
     );
+    let _ = edda_store::health::refresh_if_stale(&project_dir, project_id, 60);
+
+    // Re-size the pack if the session switched models since the last hook.
+    crate::model_switch::observe(project_id, session_id, transcript_path, cwd);
+    let profile = crate::model_switch::session_profile(project_id, session_id);
 
     // Build turns and render pack
     let max_turns: usize = std::env::var("EDDA_PACK_TURNS")
         .ok()
         .and_then(|v| v.parse().ok())
         .unwrap_or(12);
-    let budget: usize = std::env::var("EDDA_PACK_BUDGET_CHARS")
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(6000);
+    let budget: usize = profile.budget(
+        std::env::var("EDDA_PACK_BUDGET_CHARS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(6000),
+    );
 
     if let Ok(turns) = edda_pack::build_turns(&project_dir, session_id, max_turns) {
         // Compute workspace section from .edda/ ledger
-        let workspace_budget: usize = std::env::var("EDDA_WORKSPACE_BUDGET_CHARS")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(2500);
+        let workspace_budget: usize = profile.budget(
+            std::env::var("EDDA_WORKSPACE_BUDGET_CHARS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(2500),
+        );
         let workspace_section = render_workspace_section(cwd, workspace_budget);
         let ws_len = workspace_section.as_ref().map(|s| s.len()).unwrap_or(0);
         let turns_budget = budget.saturating_sub(ws_len);
@@ -101,9 +108,8 @@ pub(super) fn ingest_and_build_pack(
     }
 
     // Extract session signals (tasks, files, commits) from stored transcript
-    let store_path = project_dir
-        .join("transcripts")
-        .join(format!("{session_id}.jsonl"));
+    let store_path =
+        edda_store::store_subdir(&project_dir, "transcripts").join(format!("{session_id}.jsonl"));
     let signals = extract_session_signals(&store_path);
     save_session_signals(project_id, session_id, &signals);
 
@@ -122,10 +128,13 @@ pub(super) fn dispatch_with_workspace_only(
     cwd: &str,
     event_name: &str,
 ) -> anyhow::Result<HookResult> {
-    let workspace_budget: usize = std::env::var("EDDA_WORKSPACE_BUDGET_CHARS")
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(2500);
+    let profile = crate::model_switch::session_profile(project_id, session_id);
+    let workspace_budget: usize = profile.budget(
+        std::env::var("EDDA_WORKSPACE_BUDGET_CHARS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(2500),
+    );
     let mut ws = render_workspace_section(cwd, workspace_budget);
 
     // Inject project-level state (karvi board summary, etc.)
@@ -179,10 +188,24 @@ pub(super) fn dispatch_with_workspace_only(
         });
     }
 
+    // A model switch re-sizes this injection; say so once, and never dedup it
+    // away — the content may be unchanged while the budget is not.
+    let model_change = crate::model_switch::take_pending_change(project_id, session_id);
+    if let Some(change) = &model_change {
+        let notice = change.render(workspace_budget);
+        ws = Some(match ws {
+            Some(w) => format!("{notice}\n\n{w}"),
+            None => notice,
+        });
+    }
+
     if let Some(ws) = ws {
         let wrapped = wrap_context_boundary(&ws);
         // Dedup: skip if identical to last injection
-        if !session_id.is_empty() && is_same_as_last_inject(project_id, session_id, &wrapped) {
+        if model_change.is_none()
+            && !session_id.is_empty()
+            && is_same_as_last_inject(project_id, session_id, &wrapped)
+        {
             return Ok(HookResult::empty());
         }
         if !session_id.is_empty() {
@@ -211,6 +234,9 @@ pub(super) fn dispatch_user_prompt_submit(
     cwd: &str,
 ) -> anyhow::Result<HookResult> {
     let post_compact = take_compact_pending(project_id);
+    // The last turn's model is only visible once it has answered, so this
+    // is the first hook that can see a `/model` switch.
+    crate::model_switch::observe(project_id, session_id, transcript_path, cwd);
 
     if post_compact {
         // Re-ingest so state files are fresh for future hooks.
@@ -288,7 +314,7 @@ pub(super) fn dispatch_session_end(
     // 2e. L3 post-mortem analysis (best-effort, fire-and-forget)
     run_postmortem(project_id, session_id, cwd);
 
-    // 2f–2i. Background tasks with channel-based completion tracking.
+    // 2f–2k. Background tasks with channel-based completion tracking.
     // Previously these were fire-and-forget spawns whose JoinHandles were
     // dropped.  Because SessionEnd is the last hook event the process could
     // exit before the threads finished, truncating LLM API calls and state
@@ -372,6 +398,22 @@ pub(super) fn dispatch_session_end(
         bg_count += 1;
     }
 
+    // 2k. Background session recap (chronicle pipeline; template fallback
+    // without an API key, so not gated on EDDA_LLM_API_KEY).
+    if crate::bg_recap::should_run(project_id, session_id) {
+        let tx = bg_tx.clone();
+        let pid = project_id.to_string();
+        let sid = session_id.to_string();
+        let cwd_owned = cwd.to_string();
+        std::thread::spawn(move || {
+            if let Err(e) = crate::bg_recap::run_recap(&pid, &sid, &cwd_owned) {
+                tracing::warn!(error = %e, "session recap failed");
+            }
+            let _ = tx.send("bg_recap");
+        });
+        bg_count += 1;
+    }
+
     // Drop the original sender so the channel closes when all threads finish.
     drop(bg_tx);
 
@@ -644,8 +686,12 @@ pub(super) fn cleanup_session_state(project_id: &str, session_id: &str, peers_ac
     let _ = fs::remove_file(state_dir.join(format!("coord_offset.{session_id}")));
     // Auto-claim state file (#24)
     crate::peers::remove_autoclaim_state(project_id, session_id);
+    // High-risk draft proposals
+    crate::high_risk::remove_state(project_id, session_id);
     // Agent phase state file (#55)
     let _ = fs::remove_file(state_dir.join(format!("phase.{session_id}.json")));
+    // Last-seen model for switch detection
+    let _ = fs::remove_file(state_dir.join(format!("model.{session_id}.json")));
     // Clean up any orphaned sub-agent heartbeats belonging to this session
     crate::peers::cleanup_subagent_heartbeats(project_id, session_id);
     // Peer heartbeat + unclaim (L2 — keep remove_heartbeat unconditional as idempotent cleanup)
@@ -655,6 +701,7 @@ pub(super) fn cleanup_session_state(project_id: &str, session_id: &str, peers_ac
     }
     // NOTE: The following state files are intentionally NOT deleted here — they
     // persist across sessions to provide continuity context at the next SessionStart:
+    //   - scratch.{sid}.json → the session's scratchpad, restored if it is resumed
     //   - active_tasks.json  → L1 narrative shows previous session's final task state
     //   - files_modified.json → activity summary for carry-over context
     //   - recent_commits.json → commit history carry-over
@@ -700,7 +747,17 @@ pub(super) fn dispatch_session_start(
     // See CONDUCTOR-SPEC.md §10.2.
     let conductor_mode = std::env::var("EDDA_CONDUCTOR_MODE").is_ok();
 
-    let pack = read_hot_pack(project_id);
+    let mut pack = read_hot_pack(project_id);
+    // No recent turns: start from the warm pack (last sessions' narrative)
+    // so a brand-new session still knows what happened yesterday.
+    if hot_pack_is_empty(pack.as_deref()) {
+        if let Some(warm) = refresh_warm_pack(project_id, cwd) {
+            pack = Some(match pack {
+                Some(p) => format!("{p}\n{warm}"),
+                None => warm,
+            });
+        }
+    }
     let guide_mode = match std::env::var("EDDA_SKILL_GUIDE") {
         Ok(val) => val == "1",
         Err(_) => read_workspace_config_bool(cwd, "skill_guide").unwrap_or(false),
@@ -828,6 +885,13 @@ pub(super) fn dispatch_session_start(
         tail.push_str(&format!("\n\n{nudge}"));
     }
 
+    // The session's own scratchpad, restored after a resume or compaction.
+    // Size-capped at write time, so it rides in the tail rather than competing
+    // with the body for budget.
+    if let Some(scratch) = edda_store::scratch::read(project_id, session_id).render() {
+        tail.push_str(&format!("\n\n{scratch}"));
+    }
+
     // Coordination protocol for multi-session awareness.
     if let Some(coord) = crate::peers::render_coordination_protocol(project_id, session_id, cwd) {
         tail.push_str(&format!("\n\n{coord}"));
@@ -883,7 +947,12 @@ pub(super) fn dispatch_session_start(
     }
 
     // Apply budget: body gets (total - tail.len()), tail appended unconditionally.
-    let total_budget = context_budget(cwd);
+    // The session's model picks the profile the budget is scaled to.
+    let total_budget =
+        crate::model_switch::session_profile(project_id, session_id).budget(context_budget(cwd));
+    if let Some(change) = crate::model_switch::take_pending_change(project_id, session_id) {
+        tail.push_str(&format!("\n\n{}", change.render(total_budget)));
+    }
     let body_budget = total_budget.saturating_sub(tail.len());
 
     if let Some(ctx) = content {
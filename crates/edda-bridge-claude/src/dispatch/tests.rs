@@ -3,7 +3,7 @@ use std::fs;
 // Imports from dispatch/mod.rs
 use super::{
     apply_context_budget, context_budget, has_active_peers, hook_entrypoint_from_stdin,
-    increment_counter, is_same_as_last_inject, mark_nudge_sent, read_counter,
+    hot_pack_is_empty, increment_counter, is_same_as_last_inject, mark_nudge_sent, read_counter,
     render_workspace_section, render_write_back_protocol, set_compact_pending,
     take_compact_pending, wrap_context_boundary, write_inject_hash, write_peer_count, HookResult,
     EDDA_BOUNDARY_END, EDDA_BOUNDARY_START,
@@ -18,7 +18,8 @@ use super::session::{
     dispatch_session_start, dispatch_user_prompt_submit, dispatch_with_workspace_only,
 };
 use super::tools::{
-    check_offlimits, check_pending_requests, dispatch_post_tool_use, dispatch_pre_tool_use,
+    check_guardrails, check_offlimits, check_pending_requests, dispatch_post_tool_use,
+    dispatch_pre_tool_use,
 };
 // Imports from crate
 use crate::parse::resolve_project_id;
@@ -532,6 +533,69 @@ fn session_start_no_signals_no_extra_sections() {
     let _ = fs::remove_dir_all(edda_store::project_dir(pid));
 }
 
+#[test]
+fn hot_pack_empty_when_missing_or_no_turns() {
+    assert!(hot_pack_is_empty(None));
+    assert!(hot_pack_is_empty(Some(
+        "# edda memory pack (hot)\n\n- session_id: s1\n- turns: 0\n\n"
+    )));
+    assert!(!hot_pack_is_empty(Some(
+        "# edda memory pack (hot)\n\n- session_id: s1\n- turns: 3\n\n"
+    )));
+}
+
+#[test]
+fn session_start_injects_warm_pack_when_hot_pack_empty() {
+    let pid = "test_session_start_warm_pack";
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+    let _ = edda_store::ensure_dirs(pid);
+
+    let tmp = tempfile::tempdir().unwrap();
+    let ledger = edda_ledger::Ledger::open_or_init(tmp.path()).unwrap();
+    let parent = ledger.last_event_hash().unwrap();
+    let mut digest = edda_core::event::new_note_event(
+        "main",
+        parent.as_deref(),
+        "system",
+        "digest",
+        &["session_digest".to_string()],
+    )
+    .unwrap();
+    digest.payload["session_id"] = serde_json::json!("yesterday-session");
+    digest.payload["session_stats"] = serde_json::json!({
+        "outcome": "completed",
+        "duration_minutes": 30,
+        "files_modified": ["src/warm.rs"],
+        "commits_made": ["feat: warm things up"],
+    });
+    edda_core::event::finalize_event(&mut digest).unwrap();
+    ledger.append_event(&digest).unwrap();
+    drop(ledger);
+    let cwd = tmp.path().to_string_lossy().to_string();
+
+    crate::with_env_guard(
+        &[("EDDA_PLANS_DIR", Some("/nonexistent/plans/dir"))],
+        || {
+            let result = dispatch_session_start(pid, "new-session", &cwd, None).unwrap();
+            let output: serde_json::Value =
+                serde_json::from_str(result.stdout.as_ref().unwrap()).unwrap();
+            let ctx = output["hookSpecificOutput"]["additionalContext"]
+                .as_str()
+                .unwrap();
+            assert!(
+                ctx.contains("# edda memory pack (warm)"),
+                "missing warm pack:\n{ctx}"
+            );
+            assert!(ctx.contains("feat: warm things up"), "{ctx}");
+        },
+    );
+
+    let warm = edda_store::project_dir(pid).join("packs").join("warm.md");
+    assert!(fs::read_to_string(warm).unwrap().contains("### yesterda"));
+
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+}
+
 #[test]
 fn active_plan_renders_from_custom_dir() {
     // Test render_active_plan_from_dir directly (avoids env var race in parallel tests)
@@ -1283,6 +1347,47 @@ fn user_prompt_submit_dedup_skips_identical_state() {
     let _ = fs::remove_dir_all(&cwd);
 }
 
+#[test]
+fn user_prompt_submit_reinjects_after_model_switch() {
+    let pid = "test_ups_model_switch";
+    let sid = "switch-sess";
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+    let _ = edda_store::ensure_dirs(pid);
+
+    let cwd = std::env::temp_dir().join("edda_model_switch_cwd");
+    let _ = fs::create_dir_all(&cwd);
+    let transcript = cwd.join("transcript.jsonl");
+    let assistant = |model: &str| {
+        serde_json::json!({"type": "assistant", "message": {"model": model, "content": []}})
+            .to_string()
+    };
+    fs::write(&transcript, assistant("claude-sonnet-4-20250514")).unwrap();
+    let tp = transcript.to_str().unwrap();
+
+    crate::peers::write_binding(pid, "s1", "auth", "cache.backend", "redis");
+
+    let _eg = crate::ENV_LOCK.lock().unwrap();
+    std::env::set_var("EDDA_BRIDGE_AUTO_DIGEST", "0");
+    std::env::set_var("EDDA_PLANS_DIR", "/nonexistent");
+    let r1 = dispatch_user_prompt_submit(pid, sid, tp, cwd.to_str().unwrap()).unwrap();
+    assert!(!r1.stdout.unwrap().contains("Model Changed"));
+    let r2 = dispatch_user_prompt_submit(pid, sid, tp, cwd.to_str().unwrap()).unwrap();
+    assert!(r2.stdout.is_none(), "unchanged state is dedup-skipped");
+
+    // Same workspace state, new model: injected again, with the notice.
+    fs::write(&transcript, assistant("claude-haiku-4-5")).unwrap();
+    let r3 = dispatch_user_prompt_submit(pid, sid, tp, cwd.to_str().unwrap()).unwrap();
+    let out = r3.stdout.expect("a model switch bypasses dedup");
+    assert!(out.contains("claude-sonnet-4-20250514 → claude-haiku-4-5"));
+    assert!(out.contains("compact profile (1250 chars)"));
+
+    std::env::remove_var("EDDA_BRIDGE_AUTO_DIGEST");
+    std::env::remove_var("EDDA_PLANS_DIR");
+    drop(_eg);
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+    let _ = fs::remove_dir_all(&cwd);
+}
+
 // ── Issue #148 Gap 5: Solo session binding visibility ──
 
 #[test]
@@ -1860,6 +1965,7 @@ fn write_test_heartbeat(pid: &str, sid: &str, branch: Option<&str>) {
         branch: branch.map(|s| s.to_string()),
         current_phase: None,
         parent_session_id: None,
+        subagents: Vec::new(),
     };
     let path = edda_store::project_dir(pid)
         .join("state")
@@ -2808,3 +2914,83 @@ fn session_end_bg_threads_joined_zero_threads() {
     std::env::remove_var("EDDA_BG_JOIN_TIMEOUT_SECS");
     let _ = fs::remove_dir_all(edda_store::project_dir(pid));
 }
+
+// ── Guardrail tests ──
+
+#[test]
+fn guardrail_warns_on_peer_claimed_path_without_enforcement() {
+    let pid = "test-guardrail-claim";
+    let sid = "s-self-guard";
+    let peer_sid = "s-peer-guard";
+    let _ = edda_store::ensure_dirs(pid);
+
+    crate::peers::write_heartbeat_minimal(pid, peer_sid, "api-agent", ".");
+    crate::peers::write_claim(pid, peer_sid, "api-agent", &["src/api/*".into()]);
+    write_peer_count(pid, sid, 1);
+
+    let raw = serde_json::json!({
+        "tool_name": "Write",
+        "tool_input": { "file_path": "src/api/routes.rs", "content": "fn routes() {}" }
+    });
+    let warning = check_guardrails(&raw, "", pid, sid).expect("claimed path should warn");
+    assert!(warning.contains("Guardrail"));
+    assert!(warning.contains("api-agent"));
+
+    // Soft-block mode turns the same finding into a permission prompt.
+    crate::with_env_guard(
+        &[
+            ("EDDA_GUARDRAILS", Some("block")),
+            ("EDDA_ENFORCE_OFFLIMITS", Some("0")),
+        ],
+        || {
+            let mut raw = raw.clone();
+            raw["session_id"] = serde_json::json!(sid);
+            raw["hook_event_name"] = serde_json::json!("PreToolUse");
+            let result = dispatch_pre_tool_use(&raw, "", pid, sid).unwrap();
+            let output: serde_json::Value =
+                serde_json::from_str(&result.stdout.expect("output")).unwrap();
+            assert_eq!(output["hookSpecificOutput"]["permissionDecision"], "ask");
+        },
+    );
+
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+}
+
+#[test]
+fn guardrail_off_skips_checks() {
+    let pid = "test-guardrail-off";
+    let sid = "s-self-off";
+    let peer_sid = "s-peer-off";
+    let _ = edda_store::ensure_dirs(pid);
+
+    crate::peers::write_heartbeat_minimal(pid, peer_sid, "api-agent", ".");
+    crate::peers::write_claim(pid, peer_sid, "api-agent", &["src/api/*".into()]);
+    write_peer_count(pid, sid, 1);
+
+    crate::with_env_guard(
+        &[
+            ("EDDA_GUARDRAILS", Some("off")),
+            ("EDDA_ENFORCE_OFFLIMITS", Some("0")),
+            ("EDDA_CLAUDE_AUTO_APPROVE", Some("1")),
+        ],
+        || {
+            let raw = serde_json::json!({
+                "session_id": sid,
+                "hook_event_name": "PreToolUse",
+                "tool_name": "Edit",
+                "tool_input": {
+                    "file_path": "src/api/routes.rs",
+                    "old_string": "a",
+                    "new_string": "b"
+                }
+            });
+            let result = dispatch_pre_tool_use(&raw, "", pid, sid).unwrap();
+            let output: serde_json::Value =
+                serde_json::from_str(&result.stdout.expect("output")).unwrap();
+            assert_eq!(output["hookSpecificOutput"]["permissionDecision"], "allow");
+            assert!(output["hookSpecificOutput"]["additionalContext"].is_null());
+        },
+    );
+
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+}
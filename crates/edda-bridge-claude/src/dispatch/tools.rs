@@ -3,6 +3,7 @@ use std::path::Path;
 
 use globset::Glob;
 
+use crate::binding_guard::binding_contradictions;
 use crate::decision_warning::decision_file_warning;
 use crate::parse::*;
 
@@ -11,7 +12,7 @@ use super::events::{
 };
 use super::{
     increment_counter, mark_nudge_sent, read_counter, read_peer_count, read_workspace_config_bool,
-    should_nudge, wrap_context_boundary, HookResult,
+    read_workspace_config_str, should_nudge, wrap_context_boundary, HookResult,
 };
 
 pub(super) fn dispatch_pre_tool_use(
@@ -92,6 +93,38 @@ pub(super) fn dispatch_pre_tool_use(
         }
     }
 
+    // ── High-risk actions: propose a draft and pause until it is approved ──
+    if let Some(reason) = crate::high_risk::gate(raw, cwd, project_id, session_id) {
+        let output = serde_json::json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PreToolUse",
+                "permissionDecision": "block",
+                "permissionDecisionReason": reason
+            }
+        });
+        return Ok(HookResult::output(serde_json::to_string(&output)?));
+    }
+
+    // ── Guardrails: peer-claimed paths and binding contradictions ──
+    let guardrail_mode = guardrail_mode(cwd);
+    let guardrail_warning = if guardrail_mode == GuardrailMode::Off {
+        None
+    } else {
+        check_guardrails(raw, cwd, project_id, session_id)
+    };
+    if guardrail_mode == GuardrailMode::Block {
+        if let Some(reason) = guardrail_warning {
+            let output = serde_json::json!({
+                "hookSpecificOutput": {
+                    "hookEventName": "PreToolUse",
+                    "permissionDecision": "ask",
+                    "permissionDecisionReason": reason
+                }
+            });
+            return Ok(HookResult::output(serde_json::to_string(&output)?));
+        }
+    }
+
     let auto_approve = std::env::var("EDDA_CLAUDE_AUTO_APPROVE").unwrap_or_else(|_| "1".into());
 
     // Pattern matching (only for Edit/Write)
@@ -134,11 +167,17 @@ pub(super) fn dispatch_pre_tool_use(
         result
     };
 
-    // Combine pattern context, request nudge, rules warning, and decision warning
-    let combined_ctx = [pattern_ctx, request_nudge, rules_warning, decision_warning]
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>();
+    // Combine pattern context, request nudge, rules warning, decision and guardrail warnings
+    let combined_ctx = [
+        pattern_ctx,
+        request_nudge,
+        rules_warning,
+        decision_warning,
+        guardrail_warning,
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
     let combined_ctx = if combined_ctx.is_empty() {
         None
     } else {
@@ -170,6 +209,96 @@ pub(super) fn dispatch_pre_tool_use(
     }
 }
 
+/// How tool-call guardrails react to a peer-claimed path or a binding
+/// contradiction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GuardrailMode {
+    Off,
+    /// Inject the finding into `additionalContext` (default).
+    Warn,
+    /// Soft-block: `permissionDecision: "ask"` so the user confirms.
+    Block,
+}
+
+/// `EDDA_GUARDRAILS` env, else `bridge.guardrails` in workspace config.
+pub(super) fn guardrail_mode(cwd: &str) -> GuardrailMode {
+    let raw = std::env::var("EDDA_GUARDRAILS")
+        .ok()
+        .or_else(|| read_workspace_config_str(cwd, "bridge.guardrails"));
+    match raw.as_deref() {
+        Some("off") | Some("0") => GuardrailMode::Off,
+        Some("block") => GuardrailMode::Block,
+        _ => GuardrailMode::Warn,
+    }
+}
+
+/// Check an Edit/Write/MultiEdit against peer claims and binding decisions.
+///
+/// Returns a markdown finding, or `None` when the call is clean. Hard
+/// off-limits enforcement (`bridge.enforce_offlimits`) runs earlier and
+/// blocks outright; this is the advisory layer on top of it.
+pub(super) fn check_guardrails(
+    raw: &serde_json::Value,
+    cwd: &str,
+    project_id: &str,
+    session_id: &str,
+) -> Option<String> {
+    let tool_name = get_str(raw, "tool_name");
+    if !matches!(tool_name.as_str(), "Edit" | "Write" | "MultiEdit") {
+        return None;
+    }
+    let input = raw.get("tool_input").or_else(|| raw.get("input"))?;
+    let file_path = input
+        .get("file_path")
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+
+    let mut findings = Vec::new();
+    if !file_path.is_empty() {
+        if let Some((peer_label, matched_glob)) = check_offlimits(project_id, session_id, file_path)
+        {
+            findings.push(format!(
+                "- `{file_path}` is claimed by **{peer_label}** ({matched_glob}). \
+                 Coordinate with `edda request \"{peer_label}\" \"need to edit {file_path}\"`."
+            ));
+        }
+    }
+
+    let (old_text, new_text) = edit_texts(input);
+    if !new_text.is_empty() {
+        if let Some(root) = edda_ledger::EddaPaths::find_root(Path::new(cwd)) {
+            let branch = edda_ledger::Ledger::open(&root)
+                .and_then(|l| l.head_branch())
+                .unwrap_or_default();
+            for hit in binding_contradictions(&root, &branch, &old_text, &new_text) {
+                findings.push(format!("- Contradicts binding decision {hit}"));
+            }
+        }
+    }
+
+    if findings.is_empty() {
+        return None;
+    }
+    findings.insert(0, "**[edda] Guardrail**:".to_string());
+    Some(findings.join("\n"))
+}
+
+/// The (replaced, new) text of an Edit, Write, or MultiEdit tool input.
+fn edit_texts(input: &serde_json::Value) -> (String, String) {
+    let field = |v: &serde_json::Value, k: &str| {
+        v.get(k).and_then(|s| s.as_str()).unwrap_or("").to_string()
+    };
+    if let Some(content) = input.get("content").and_then(|v| v.as_str()) {
+        return (String::new(), content.to_string());
+    }
+    if let Some(edits) = input.get("edits").and_then(|v| v.as_array()) {
+        let old: Vec<String> = edits.iter().map(|e| field(e, "old_string")).collect();
+        let new: Vec<String> = edits.iter().map(|e| field(e, "new_string")).collect();
+        return (old.join("\n"), new.join("\n"));
+    }
+    (field(input, "old_string"), field(input, "new_string"))
+}
+
 /// Check for pending coordination requests addressed to this session.
 /// Uses a cooldown counter: only returns a nudge every 3rd PreToolUse call.
 /// Skips all I/O for solo sessions (no peers).
@@ -0,0 +1,261 @@
+//! Handoff briefs — the in-flight state of a project, gathered into one
+//! Markdown note so the next session (or a human) picks up where the last
+//! one stopped.
+//!
+//! Written by `edda handoff` as a NOTE tagged `handoff`; [`latest`] reads
+//! the most recent one back.
+
+use edda_core::Event;
+use edda_ledger::Ledger;
+use serde::Serialize;
+
+use crate::digest::{load_tasks_for_digest, DigestTaskSnapshot};
+use crate::peers::{compute_board_state, BoardState, ClaimEntry, RequestEntry};
+use crate::signals::{load_state_vec, FailedBashCmd};
+
+/// Tag carried by handoff notes.
+pub const HANDOFF_TAG: &str = "handoff";
+
+/// Recent decisions listed in a brief.
+const MAX_DECISIONS: usize = 5;
+
+/// A command that kept failing in the session being handed off.
+#[derive(Debug, Clone, Serialize)]
+pub struct FailingCommand {
+    pub command: String,
+    pub stderr: String,
+    pub count: usize,
+}
+
+/// A decision recorded recently, in brief form.
+#[derive(Debug, Clone, Serialize)]
+pub struct HandoffDecision {
+    pub event_id: String,
+    pub key: String,
+    pub value: String,
+    pub reason: String,
+}
+
+/// Everything a handoff brief reports.
+#[derive(Debug, Clone, Serialize)]
+pub struct Handoff {
+    /// Label of the session handing off.
+    pub from: String,
+    /// Who picks the work up; `None` for whoever starts next.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    pub claims: Vec<ClaimEntry>,
+    /// Tasks not yet completed.
+    pub tasks: Vec<DigestTaskSnapshot>,
+    pub failing_commands: Vec<FailingCommand>,
+    /// Most recent active decisions, newest first.
+    pub decisions: Vec<HandoffDecision>,
+    /// Peer requests nobody has acknowledged yet.
+    pub open_requests: Vec<RequestEntry>,
+}
+
+impl Handoff {
+    /// Nothing in flight: no claims, tasks, failures or open requests.
+    pub fn is_idle(&self) -> bool {
+        self.claims.is_empty()
+            && self.tasks.is_empty()
+            && self.failing_commands.is_empty()
+            && self.open_requests.is_empty()
+    }
+}
+
+/// Gather the in-flight state of `project_id` from the project store and the
+/// ledger.
+pub fn collect(
+    project_id: &str,
+    ledger: &Ledger,
+    from: &str,
+    to: Option<&str>,
+) -> anyhow::Result<Handoff> {
+    let board = compute_board_state(project_id);
+    let tasks = load_tasks_for_digest(project_id)
+        .into_iter()
+        .filter(|t| t.status != "completed")
+        .collect();
+    let failing_commands =
+        load_state_vec::<FailedBashCmd>(project_id, "failed_commands.json", "failed_commands")
+            .into_iter()
+            .map(|f| FailingCommand {
+                command: f.command_base,
+                stderr: f.stderr_snippet,
+                count: f.count,
+            })
+            .collect();
+    let decisions = ledger
+        .active_decisions_limited(None, None, None, None, MAX_DECISIONS)?
+        .into_iter()
+        .map(|d| HandoffDecision {
+            event_id: d.event_id,
+            key: d.key,
+            value: d.value,
+            reason: d.reason,
+        })
+        .collect();
+    Ok(Handoff {
+        from: from.to_string(),
+        to: to.map(str::to_string),
+        open_requests: open_requests(&board),
+        claims: board.claims,
+        tasks,
+        failing_commands,
+        decisions,
+    })
+}
+
+/// Requests on the board with no acknowledgement from their addressee since
+/// they were made.
+pub fn open_requests(board: &BoardState) -> Vec<RequestEntry> {
+    board
+        .requests
+        .iter()
+        .filter(|r| {
+            !board
+                .request_acks
+                .iter()
+                .any(|a| a.from_label == r.from_label && a.ts >= r.ts)
+        })
+        .cloned()
+        .collect()
+}
+
+/// Render the brief as Markdown. Empty sections are left out.
+pub fn render(h: &Handoff) -> String {
+    let to = h.to.as_deref().unwrap_or("the next session");
+    let mut out = format!("# Handoff from {} to {to}\n", h.from);
+    if h.is_idle() {
+        out.push_str("\nNothing in flight.\n");
+    }
+
+    let mut section = |title: &str, lines: Vec<String>| {
+        if !lines.is_empty() {
+            out.push_str(&format!("\n## {title}\n\n{}\n", lines.join("\n")));
+        }
+    };
+    section(
+        "Active claims",
+        h.claims
+            .iter()
+            .map(|c| {
+                if c.paths.is_empty() {
+                    format!("- [{}]", c.label)
+                } else {
+                    format!("- [{}] {}", c.label, c.paths.join(", "))
+                }
+            })
+            .collect(),
+    );
+    section(
+        "Incomplete tasks",
+        h.tasks
+            .iter()
+            .map(|t| format!("- {} ({})", t.subject, t.status))
+            .collect(),
+    );
+    section(
+        "Failing commands",
+        h.failing_commands
+            .iter()
+            .map(|f| {
+                let times = if f.count > 1 {
+                    format!(" ×{}", f.count)
+                } else {
+                    String::new()
+                };
+                let stderr = f.stderr.lines().next().unwrap_or("").trim();
+                if stderr.is_empty() {
+                    format!("- `{}`{times}", f.command)
+                } else {
+                    format!("- `{}`{times}: {stderr}", f.command)
+                }
+            })
+            .collect(),
+    );
+    section(
+        "Open requests",
+        h.open_requests
+            .iter()
+            .map(|r| format!("- [{}] → [{}]: {}", r.from_label, r.to_label, r.message))
+            .collect(),
+    );
+    section(
+        "Recent decisions",
+        h.decisions
+            .iter()
+            .map(|d| {
+                if d.reason.is_empty() {
+                    format!("- {} = {} ({})", d.key, d.value, d.event_id)
+                } else {
+                    format!("- {} = {} — {} ({})", d.key, d.value, d.reason, d.event_id)
+                }
+            })
+            .collect(),
+    );
+    out
+}
+
+/// The most recent handoff note, optionally only those addressed to `to`.
+pub fn latest(ledger: &Ledger, to: Option<&str>) -> anyhow::Result<Option<Event>> {
+    Ok(ledger
+        .iter_events_by_type("note")?
+        .into_iter()
+        .rev()
+        .filter(|e| {
+            e.payload["tags"]
+                .as_array()
+                .is_some_and(|tags| tags.iter().any(|t| t == HANDOFF_TAG))
+        })
+        .find(|e| to.is_none_or(|to| e.payload["handoff"]["to"].as_str() == Some(to))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::peers::{write_claim, write_request, write_request_ack};
+    use std::fs;
+
+    #[test]
+    fn brief_lists_in_flight_work() {
+        let pid = "test_handoff_brief";
+        let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+        let _ = edda_store::ensure_dirs(pid);
+        let state = edda_store::project_dir(pid).join("state");
+        fs::write(
+            state.join("active_tasks.json"),
+            r#"{"tasks":[{"subject":"Wire login","status":"in_progress"},{"subject":"Scaffold","status":"completed"}]}"#,
+        )
+        .unwrap();
+        fs::write(
+            state.join("failed_commands.json"),
+            r#"{"failed_commands":[{"command_base":"cargo test","stderr_snippet":"error[E0425]: x\nmore","count":3}]}"#,
+        )
+        .unwrap();
+        write_claim(pid, "s1", "auth", &["src/auth/*".into()]);
+        write_request(pid, "s2", "billing", "auth", "Export AuthToken type");
+        write_request(pid, "s3", "docs", "auth", "Document login");
+        write_request_ack(pid, "s1", "docs");
+
+        let tmp = tempfile::tempdir().unwrap();
+        let ledger = Ledger::open_or_init(tmp.path()).unwrap();
+        let h = collect(pid, &ledger, "auth", Some("billing")).unwrap();
+
+        assert_eq!(h.tasks.len(), 1);
+        assert_eq!(h.open_requests.len(), 1);
+        assert!(!h.is_idle());
+        let md = render(&h);
+        assert!(md.starts_with("# Handoff from auth to billing\n"));
+        assert!(md.contains("## Active claims\n\n- [auth] src/auth/*\n"));
+        assert!(md.contains("- Wire login (in_progress)"));
+        assert!(!md.contains("Scaffold"));
+        assert!(md.contains("- `cargo test` ×3: error[E0425]: x\n"));
+        assert!(md.contains("- [billing] → [auth]: Export AuthToken type"));
+        assert!(!md.contains("Document login"));
+        assert!(!md.contains("## Recent decisions"));
+
+        let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+    }
+}
@@ -0,0 +1,569 @@
+//! PreToolUse high-risk action gate.
+//!
+//! When `policy.yaml` lists `high_risk.actions`, the bridge stops the agent
+//! before a schema migration, a dependency major-version bump, or a delete
+//! under `high_risk.protected_paths`. Instead of running the tool it proposes
+//! a draft (routed through the `high_risk.stages` approvals) and blocks the
+//! call with a pause instruction naming the draft. Once a human approves the
+//! draft, the same call goes through on retry; a rejected draft keeps it
+//! blocked.
+//!
+//! Detection is lexical and deliberately narrow: known migration runners and
+//! migration directories, version strings in `Cargo.toml`/`package.json`
+//! edits, and `rm`/`git rm` arguments matched against the protected globs.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+use edda_core::event::{
+    new_approval_request_event, new_draft_id, new_draft_proposed_event, ApprovalRequestParams,
+    DraftProposedParams,
+};
+use edda_core::policy::{
+    assign_stages, load_actors_from_dir, load_high_risk_from_dir, HighRiskPolicy, RISK_MAJOR_BUMP,
+    RISK_PROTECTED_DELETE, RISK_SCHEMA_MIGRATION,
+};
+use edda_ledger::{import_legacy_drafts, EddaPaths, Ledger, WorkspaceLock};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+use crate::parse::get_str;
+
+/// A tool call the policy wants a human to approve first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RiskyAction {
+    pub kind: &'static str,
+    /// What the action touches (command, file, or dependency); part of the
+    /// fingerprint that lets an approved retry through.
+    pub target: String,
+    pub summary: String,
+}
+
+/// Check a PreToolUse payload against the workspace's `high_risk` policy.
+///
+/// Returns the block reason (a pause instruction) or `None` when the call may
+/// proceed: no policy, nothing risky, or its draft has been approved.
+pub(crate) fn gate(
+    raw: &serde_json::Value,
+    cwd: &str,
+    project_id: &str,
+    session_id: &str,
+) -> Option<String> {
+    let root = EddaPaths::find_root(Path::new(cwd))?;
+    let paths = EddaPaths::discover(&root);
+    let policy = load_high_risk_from_dir(&paths.edda_dir).ok()??;
+    let action = detect(raw, &root, &policy)?;
+
+    let fingerprint = fingerprint(&action);
+    let mut proposed = load_proposed(project_id, session_id);
+    if let Some(draft_id) = proposed.get(&fingerprint) {
+        match draft_status(&root, draft_id).as_deref() {
+            Some("approved") | Some("applied") => return None,
+            Some("rejected") => {
+                return Some(format!(
+                    "[edda] High-risk action rejected: {} (draft {draft_id} was rejected). \
+                     Do not retry or work around it; ask the user how to proceed.",
+                    action.summary
+                ));
+            }
+            Some(_) => return Some(pause_message(&action, draft_id)),
+            None => {} // draft deleted: propose again
+        }
+    }
+
+    match propose_draft(&root, &policy, &action, session_id) {
+        Ok(draft_id) => {
+            proposed.insert(fingerprint, draft_id.clone());
+            save_proposed(project_id, session_id, &proposed);
+            Some(pause_message(&action, &draft_id))
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, kind = action.kind, "high-risk draft proposal failed");
+            Some(format!(
+                "[edda] High-risk action: {}. A draft could not be created ({e}); \
+                 stop and ask the user to approve this step before continuing.",
+                action.summary
+            ))
+        }
+    }
+}
+
+fn pause_message(action: &RiskyAction, draft_id: &str) -> String {
+    format!(
+        "[edda] High-risk action paused: {}. Draft {draft_id} needs approval \
+         (`edda draft approve {draft_id}`). Stop here: do not retry, \
+         split, or work around this call. Tell the user what you intended and wait \
+         for them to approve; then retry the same call.",
+        action.summary
+    )
+}
+
+// ── Detection ──
+
+static RE_MIGRATION_CMD: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r"\b(diesel\s+migration\s+(run|redo|revert)|sqlx\s+migrate\s+(run|revert)|alembic\s+(upgrade|downgrade)|prisma\s+(migrate\s+(deploy|dev|reset)|db\s+push)|(rails|rake)\s+db:(migrate|rollback|schema:load)|manage\.py\s+migrate|knex\s+migrate:(latest|up|down|rollback)|sequelize(-cli)?\s+db:migrate|flyway\s+migrate|sea-orm-cli\s+migrate)\b",
+    )
+    .expect("static regex")
+});
+
+static RE_CARGO_DEP: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(
+        r#"(?m)^\s*([A-Za-z0-9_-]+)\s*=\s*(?:\{[^}\n]*?\bversion\s*=\s*)?"[\^~=<>\s]*(\d+)(?:\.(\d+))?"#,
+    )
+    .expect("static regex")
+});
+
+static RE_NPM_DEP: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r#""(@?[A-Za-z0-9_./-]+)"\s*:\s*"[\^~=<>\s]*v?(\d+)(?:\.(\d+))?"#)
+        .expect("static regex")
+});
+
+/// Classify a tool call; `None` when it is not one of the watched actions.
+pub(crate) fn detect(
+    raw: &serde_json::Value,
+    root: &Path,
+    policy: &HighRiskPolicy,
+) -> Option<RiskyAction> {
+    let tool_name = get_str(raw, "tool_name");
+    let input = raw.get("tool_input").or_else(|| raw.get("input"))?;
+    let field = |k: &str| input.get(k).and_then(|v| v.as_str()).unwrap_or("");
+
+    match tool_name.as_str() {
+        "Bash" => {
+            let command = field("command");
+            if policy.watches(RISK_SCHEMA_MIGRATION) {
+                if let Some(m) = RE_MIGRATION_CMD.find(command) {
+                    return Some(RiskyAction {
+                        kind: RISK_SCHEMA_MIGRATION,
+                        target: m.as_str().to_string(),
+                        summary: format!("schema migration `{}`", m.as_str()),
+                    });
+                }
+            }
+            if policy.watches(RISK_PROTECTED_DELETE) {
+                let protected = build_globset(&policy.protected_paths)?;
+                let hits: Vec<String> = deleted_paths(command)
+                    .into_iter()
+                    .map(|p| relative_to(root, &p))
+                    .filter(|p| {
+                        // Deleting a directory deletes whatever is protected inside it.
+                        protected.is_match(p) || protected.is_match(format!("{p}/_"))
+                    })
+                    .collect();
+                if !hits.is_empty() {
+                    return Some(RiskyAction {
+                        kind: RISK_PROTECTED_DELETE,
+                        target: hits.join(" "),
+                        summary: format!("delete of protected path(s) {}", hits.join(", ")),
+                    });
+                }
+            }
+            None
+        }
+        "Edit" | "Write" | "MultiEdit" => {
+            let file_path = field("file_path");
+            if file_path.is_empty() {
+                return None;
+            }
+            let rel = relative_to(root, file_path);
+            if policy.watches(RISK_SCHEMA_MIGRATION) && is_migration_file(&rel) {
+                return Some(RiskyAction {
+                    kind: RISK_SCHEMA_MIGRATION,
+                    target: rel.clone(),
+                    summary: format!("schema migration change in `{rel}`"),
+                });
+            }
+            if policy.watches(RISK_MAJOR_BUMP) {
+                let (old, new) = manifest_texts(input, file_path);
+                let bumps = major_bumps(&rel, &old, &new);
+                if !bumps.is_empty() {
+                    return Some(RiskyAction {
+                        kind: RISK_MAJOR_BUMP,
+                        target: format!("{rel}:{}", bumps.join(",")),
+                        summary: format!("major version bump in `{rel}`: {}", bumps.join(", ")),
+                    });
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn build_globset(patterns: &[String]) -> Option<GlobSet> {
+    if patterns.is_empty() {
+        return None;
+    }
+    let mut builder = GlobSetBuilder::new();
+    for p in patterns {
+        if let Ok(glob) = Glob::new(p.trim_start_matches("./")) {
+            builder.add(glob);
+        }
+    }
+    builder.build().ok()
+}
+
+/// Path arguments of every `rm` / `git rm` in a (possibly chained) command.
+fn deleted_paths(command: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    for segment in command.split(['&', ';', '|', '\n']) {
+        let mut words = segment.split_whitespace().skip_while(|w| *w == "sudo");
+        let args: Vec<&str> = match words.next() {
+            Some("rm") => words.collect(),
+            Some("git") if segment.split_whitespace().nth(1) == Some("rm") => {
+                words.skip(1).collect()
+            }
+            _ => continue,
+        };
+        paths.extend(
+            args.into_iter()
+                .filter(|a| !a.starts_with('-'))
+                .map(|a| a.trim_matches(['"', '\'']).to_string()),
+        );
+    }
+    paths
+}
+
+/// `path` relative to the repo root, `/`-separated, without a leading `./`.
+fn relative_to(root: &Path, path: &str) -> String {
+    let p = PathBuf::from(path);
+    let rel = p.strip_prefix(root).unwrap_or(&p);
+    let s = rel.to_string_lossy().replace('\\', "/");
+    s.trim_start_matches("./").trim_end_matches('/').to_string()
+}
+
+fn is_migration_file(rel: &str) -> bool {
+    let mut parts = rel.split('/');
+    let file = parts.next_back().unwrap_or("");
+    parts.any(|dir| dir == "migrations" || dir == "migrate")
+        || matches!(file, "schema.prisma" | "schema.rb")
+}
+
+/// (before, after) manifest text for an edit. A `Write` replaces the whole
+/// file, so "before" is what is on disk now.
+fn manifest_texts(input: &serde_json::Value, file_path: &str) -> (String, String) {
+    let field = |v: &serde_json::Value, k: &str| {
+        v.get(k).and_then(|s| s.as_str()).unwrap_or("").to_string()
+    };
+    if let Some(content) = input.get("content").and_then(|v| v.as_str()) {
+        let old = fs::read_to_string(file_path).unwrap_or_default();
+        return (old, content.to_string());
+    }
+    if let Some(edits) = input.get("edits").and_then(|v| v.as_array()) {
+        let old: Vec<String> = edits.iter().map(|e| field(e, "old_string")).collect();
+        let new: Vec<String> = edits.iter().map(|e| field(e, "new_string")).collect();
+        return (old.join("\n"), new.join("\n"));
+    }
+    (field(input, "old_string"), field(input, "new_string"))
+}
+
+/// `name old→new` for every dependency whose major version rises. For `0.x`
+/// versions the minor counts as major, as Cargo and npm both treat it.
+fn major_bumps(rel: &str, old: &str, new: &str) -> Vec<String> {
+    let re: &regex::Regex = match rel.rsplit('/').next() {
+        Some("Cargo.toml") => &RE_CARGO_DEP,
+        Some("package.json") => &RE_NPM_DEP,
+        _ => return Vec::new(),
+    };
+    let majors = |text: &str| -> BTreeMap<String, (u64, u64)> {
+        re.captures_iter(text)
+            .filter_map(|c| {
+                let name = c.get(1)?.as_str();
+                if matches!(name, "version" | "rust-version" | "edition") {
+                    return None;
+                }
+                let major: u64 = c.get(2)?.as_str().parse().ok()?;
+                let minor: u64 = c.get(3).and_then(|m| m.as_str().parse().ok()).unwrap_or(0);
+                Some((
+                    name.to_string(),
+                    (major, if major == 0 { minor } else { 0 }),
+                ))
+            })
+            .collect()
+    };
+    let before = majors(old);
+    majors(new)
+        .into_iter()
+        .filter_map(|(name, after)| {
+            let was = before.get(&name)?;
+            (after > *was).then(|| {
+                let fmt = |(major, minor): (u64, u64)| {
+                    if major == 0 {
+                        format!("0.{minor}")
+                    } else {
+                        major.to_string()
+                    }
+                };
+                format!("{name} {}→{}", fmt(*was), fmt(after))
+            })
+        })
+        .collect()
+}
+
+fn fingerprint(action: &RiskyAction) -> String {
+    let hash = blake3::hash(format!("{}\n{}", action.kind, action.target).as_bytes());
+    hash.to_hex()[..16].to_string()
+}
+
+// ── Session state: which drafts this session already proposed ──
+
+fn proposed_path(project_id: &str, session_id: &str) -> PathBuf {
+    edda_store::project_dir(project_id)
+        .join("state")
+        .join(format!("high_risk.{session_id}.json"))
+}
+
+fn load_proposed(project_id: &str, session_id: &str) -> BTreeMap<String, String> {
+    fs::read_to_string(proposed_path(project_id, session_id))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_proposed(project_id: &str, session_id: &str, proposed: &BTreeMap<String, String>) {
+    let path = proposed_path(project_id, session_id);
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(proposed) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Remove this session's proposal map (SessionEnd cleanup).
+pub(crate) fn remove_state(project_id: &str, session_id: &str) {
+    let _ = fs::remove_file(proposed_path(project_id, session_id));
+}
+
+fn draft_status(root: &Path, draft_id: &str) -> Option<String> {
+    let ledger = Ledger::open(root).ok()?;
+    let draft = ledger.draft_view(draft_id).ok()??;
+    Some(draft.status.to_string())
+}
+
+// ── Draft proposal ──
+
+/// Propose a `draft.proposed` event in the shape `edda draft` reads and emit
+/// one `approval_request` per `high_risk.stages` entry.
+fn propose_draft(
+    root: &Path,
+    policy: &HighRiskPolicy,
+    action: &RiskyAction,
+    session_id: &str,
+) -> anyhow::Result<String> {
+    let ledger = Ledger::open(root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+    import_legacy_drafts(&ledger)?;
+    let branch = ledger.head_branch()?;
+    let base_parent_hash = ledger.last_event_hash()?.unwrap_or_default();
+    let actors = load_actors_from_dir(&ledger.paths.edda_dir)?;
+
+    let title = format!("High-risk: {}", action.summary);
+    let purpose = format!(
+        "Agent session {} attempted a {} action; approve to let it proceed.",
+        &session_id[..session_id.len().min(8)],
+        action.kind
+    );
+    let labels = vec!["high_risk".to_string(), action.kind.to_string()];
+    let stages = assign_stages(&policy.stages, &actors);
+    let origin = serde_json::json!({
+        "kind": action.kind,
+        "target": action.target,
+        "session_id": session_id,
+    });
+
+    let draft_id = new_draft_id();
+    let proposal = new_draft_proposed_event(&DraftProposedParams {
+        branch: &branch,
+        parent_hash: (!base_parent_hash.is_empty()).then_some(base_parent_hash.as_str()),
+        draft_id: &draft_id,
+        created_at: None,
+        base_parent_hash: &base_parent_hash,
+        title: &title,
+        purpose: &purpose,
+        contribution: &action.summary,
+        labels: &labels,
+        evidence: &[],
+        auto_preview_lines: &[],
+        route_rule_id: "high_risk",
+        stages: &stages,
+        policy_require_approval: true,
+        policy_min_approvals: 1,
+        origin: Some(&origin),
+    })?;
+    ledger.append_event(&proposal)?;
+
+    for stage in &stages {
+        let parent_hash = ledger.last_event_hash()?;
+        let req = new_approval_request_event(&ApprovalRequestParams {
+            branch: &branch,
+            parent_hash: parent_hash.as_deref(),
+            draft_id: &draft_id,
+            draft_sha256: &proposal.hash,
+            route_rule_id: "high_risk",
+            stage_id: &stage.stage_id,
+            role: &stage.role,
+            assignees: &stage.assignees,
+            reason: &format!("high-risk {}", action.kind),
+        })?;
+        ledger.append_event(&req)?;
+    }
+    edda_derive::rebuild_all(&ledger)?;
+    Ok(draft_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(actions: &[&str], protected: &[&str]) -> HighRiskPolicy {
+        HighRiskPolicy {
+            actions: actions.iter().map(|s| s.to_string()).collect(),
+            protected_paths: protected.iter().map(|s| s.to_string()).collect(),
+            stages: vec![],
+        }
+    }
+
+    fn bash(command: &str) -> serde_json::Value {
+        serde_json::json!({"tool_name": "Bash", "tool_input": {"command": command}})
+    }
+
+    fn edit(path: &str, old: &str, new: &str) -> serde_json::Value {
+        serde_json::json!({"tool_name": "Edit", "tool_input": {
+            "file_path": path, "old_string": old, "new_string": new}})
+    }
+
+    #[test]
+    fn detects_migration_commands_and_files() {
+        let root = Path::new("/repo");
+        let p = policy(&[RISK_SCHEMA_MIGRATION], &[]);
+        let hit = detect(&bash("cd api && sqlx migrate run"), root, &p).unwrap();
+        assert_eq!(hit.kind, RISK_SCHEMA_MIGRATION);
+        assert_eq!(hit.target, "sqlx migrate run");
+        assert!(detect(&bash("cargo test migrate"), root, &p).is_none());
+
+        let write = serde_json::json!({"tool_name": "Write", "tool_input": {
+            "file_path": "/repo/db/migrations/0003_users.sql", "content": "ALTER TABLE"}});
+        assert_eq!(
+            detect(&write, root, &p).unwrap().target,
+            "db/migrations/0003_users.sql"
+        );
+        assert!(
+            detect(
+                &bash("sqlx migrate run"),
+                root,
+                &policy(&[RISK_MAJOR_BUMP], &[])
+            )
+            .is_none(),
+            "unwatched kinds pass"
+        );
+    }
+
+    #[test]
+    fn detects_major_bumps_but_not_minor() {
+        let root = Path::new("/repo");
+        let p = policy(&[RISK_MAJOR_BUMP], &[]);
+        let hit = detect(
+            &edit(
+                "/repo/Cargo.toml",
+                "axum = \"0.7\"\ntokio = { version = \"1.40\", features = [\"full\"] }",
+                "axum = \"0.8\"\ntokio = { version = \"1.41\", features = [\"full\"] }",
+            ),
+            root,
+            &p,
+        )
+        .unwrap();
+        assert_eq!(
+            hit.summary,
+            "major version bump in `Cargo.toml`: axum 0.7→0.8"
+        );
+
+        let npm = edit(
+            "/repo/web/package.json",
+            "\"react\": \"^18.2.0\",",
+            "\"react\": \"^19.0.0\",",
+        );
+        assert!(detect(&npm, root, &p)
+            .unwrap()
+            .target
+            .contains("react 18→19"));
+        assert!(detect(
+            &edit(
+                "/repo/Cargo.toml",
+                "version = \"0.2.1\"",
+                "version = \"1.0.0\""
+            ),
+            root,
+            &p
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn detects_deletes_under_protected_globs() {
+        let root = Path::new("/repo");
+        let p = policy(&[RISK_PROTECTED_DELETE], &["migrations/**", ".github/**"]);
+        let hit = detect(&bash("git rm -r .github/workflows/ci.yml"), root, &p).unwrap();
+        assert_eq!(hit.target, ".github/workflows/ci.yml");
+        let dir = detect(&bash("ls && rm -rf ./migrations"), root, &p).unwrap();
+        assert_eq!(dir.target, "migrations");
+        assert!(detect(&bash("rm -rf target"), root, &p).is_none());
+    }
+
+    #[test]
+    fn proposes_draft_and_lets_approved_retry_through() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap();
+        Ledger::open_or_init(&root).unwrap();
+        fs::write(
+            root.join(".edda/policy.yaml"),
+            "version: 2\nhigh_risk:\n  actions: [schema_migration]\n",
+        )
+        .unwrap();
+        let pid = format!("test_high_risk_{}", std::process::id());
+        let sid = "sess-high-risk";
+        let cwd = root.to_string_lossy().to_string();
+        let call = bash("diesel migration run");
+
+        let reason = gate(&call, &cwd, &pid, sid).unwrap();
+        assert!(reason.contains("High-risk action paused"), "{reason}");
+        let proposed = load_proposed(&pid, sid);
+        let draft_id = proposed.values().next().unwrap().clone();
+        assert_eq!(draft_status(&root, &draft_id).as_deref(), Some("proposed"));
+        let ledger = Ledger::open(&root).unwrap();
+        assert_eq!(
+            ledger
+                .iter_events_by_type("approval_request")
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // Still pending: blocked again, no second draft.
+        assert!(gate(&call, &cwd, &pid, sid).unwrap().contains(&draft_id));
+        assert_eq!(load_proposed(&pid, sid).len(), 1);
+
+        let draft = ledger.draft_view(&draft_id).unwrap().unwrap();
+        let approval =
+            edda_core::event::new_approval_event(&edda_core::event::ApprovalEventParams {
+                branch: "main",
+                parent_hash: ledger.last_event_hash().unwrap().as_deref(),
+                draft_id: &draft_id,
+                draft_sha256: &draft.proposal_hash,
+                decision: "approve",
+                actor: "alice",
+                note: "",
+                stage_id: "lead",
+                role: "lead",
+                device_id: None,
+            })
+            .unwrap();
+        ledger.append_event(&approval).unwrap();
+        assert_eq!(gate(&call, &cwd, &pid, sid), None);
+
+        remove_state(&pid, sid);
+        let _ = fs::remove_dir_all(edda_store::project_dir(&pid));
+    }
+}
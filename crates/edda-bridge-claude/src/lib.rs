@@ -3,21 +3,28 @@ pub mod bg_detect;
 pub mod bg_digest;
 pub mod bg_extract;
 pub mod bg_index;
+pub mod bg_recap;
 pub mod bg_scan;
+pub mod code_annotations;
 pub mod controls_suggest;
 pub mod digest;
+pub mod handoff;
 pub mod issue_proposal;
+pub mod model_switch;
 pub mod pattern;
 pub mod peers;
 pub mod redact;
 pub mod render;
+pub mod sessions;
 pub mod state;
 pub mod task_nudge;
 pub mod watch;
 
 mod admin;
+pub(crate) mod binding_guard;
 pub(crate) mod decision_warning;
 mod dispatch;
+pub(crate) mod high_risk;
 mod narrative;
 pub mod nudge;
 mod parse;
@@ -26,7 +33,7 @@ mod signals;
 
 // Re-export public API (CLI consumers unchanged)
 pub use admin::{doctor, install, uninstall};
-pub use dispatch::{hook_entrypoint_from_stdin, HookResult};
+pub use dispatch::{hook_entrypoint_from_stdin, ingest_and_build_pack, HookResult};
 
 /// Serialize tests that mutate env vars to avoid races.
 /// Same pattern as edda-store's `ENV_STORE_LOCK`.
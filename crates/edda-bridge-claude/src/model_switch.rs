@@ -0,0 +1,312 @@
+//! Model-switch detection and per-model context profiles.
+//!
+//! A session can change models mid-run (`/model`), and every assistant record
+//! in the transcript names the model that produced it. The bridge compares the
+//! latest one with the last model it saw for the session, records a
+//! `model_change` event when they differ, and sizes every later injection by
+//! the new model's [`ContextProfile`]. The next injection also carries a short
+//! notice, so the agent knows why its context shrank or grew.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// How much of the end of a transcript is read to find the latest model.
+const TAIL_BYTES: u64 = 256 * 1024;
+
+/// Model-name substrings that get the compact profile when
+/// `EDDA_COMPACT_MODELS` is unset.
+const DEFAULT_COMPACT_MODELS: &str = "haiku";
+
+/// How much context the bridge injects for a model.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContextProfile {
+    /// The configured budgets as they are.
+    #[default]
+    Full,
+    /// Half of every budget, for small-context or cost-sensitive models.
+    Compact,
+}
+
+impl ContextProfile {
+    /// `Compact` when the model name contains any of the comma-separated
+    /// substrings in `EDDA_COMPACT_MODELS` (default `haiku`).
+    pub fn for_model(model: &str) -> Self {
+        let patterns = std::env::var("EDDA_COMPACT_MODELS")
+            .unwrap_or_else(|_| DEFAULT_COMPACT_MODELS.to_string());
+        let model = model.to_ascii_lowercase();
+        let compact = patterns
+            .split(',')
+            .map(|p| p.trim().to_ascii_lowercase())
+            .any(|p| !p.is_empty() && model.contains(&p));
+        if compact {
+            Self::Compact
+        } else {
+            Self::Full
+        }
+    }
+
+    /// Scale a configured char budget to this profile.
+    pub fn budget(self, full: usize) -> usize {
+        match self {
+            Self::Full => full,
+            Self::Compact => full / 2,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Compact => "compact",
+        }
+    }
+}
+
+/// The last model seen for a session, persisted in `state/model.{sid}.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelState {
+    pub model: String,
+    pub profile: ContextProfile,
+    pub since: String,
+    /// The previous model, while the switch has not yet been announced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_from: Option<String>,
+}
+
+/// A switch waiting to be announced at the next injection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelChange {
+    pub from: String,
+    pub to: String,
+    pub profile: ContextProfile,
+}
+
+impl ModelChange {
+    /// The notice injected with the first context after the switch.
+    pub fn render(&self, budget: usize) -> String {
+        format!(
+            "## Model Changed\n{} → {}: context re-sized to the {} profile ({budget} chars).",
+            self.from,
+            self.to,
+            self.profile.as_str()
+        )
+    }
+}
+
+fn state_path(project_id: &str, session_id: &str) -> PathBuf {
+    edda_store::project_dir(project_id)
+        .join("state")
+        .join(format!("model.{session_id}.json"))
+}
+
+/// Read the last model recorded for a session.
+pub fn read_model_state(project_id: &str, session_id: &str) -> Option<ModelState> {
+    let content = fs::read_to_string(state_path(project_id, session_id)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_model_state(project_id: &str, session_id: &str, state: &ModelState) {
+    let path = state_path(project_id, session_id);
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(state) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// The profile injections for this session should use; `Full` until a model
+/// has been seen.
+pub fn session_profile(project_id: &str, session_id: &str) -> ContextProfile {
+    read_model_state(project_id, session_id)
+        .map(|s| s.profile)
+        .unwrap_or_default()
+}
+
+/// The model of the most recent assistant record in a transcript.
+///
+/// Only the tail is read: hooks run on every prompt and transcripts grow into
+/// the megabytes. Synthetic records (`<synthetic>`) are skipped.
+pub fn latest_model(transcript: &Path) -> Option<String> {
+    let mut file = fs::File::open(transcript).ok()?;
+    let len = file.metadata().ok()?.len();
+    let start = len.saturating_sub(TAIL_BYTES);
+    file.seek(SeekFrom::Start(start)).ok()?;
+    let mut reader = std::io::BufReader::new(file);
+    if start > 0 {
+        // Drop the partial first line.
+        let mut skipped = Vec::new();
+        reader.read_until(b'\n', &mut skipped).ok()?;
+    }
+    let mut tail = String::new();
+    reader.read_to_string(&mut tail).ok()?;
+
+    tail.lines().rev().find_map(|line| {
+        let record: serde_json::Value = serde_json::from_str(line).ok()?;
+        if record.get("type").and_then(|t| t.as_str()) != Some("assistant") {
+            return None;
+        }
+        let model = record.get("message")?.get("model")?.as_str()?;
+        (!model.is_empty() && !model.starts_with('<')).then(|| model.to_string())
+    })
+}
+
+/// Compare the transcript's latest model with the one last seen for this
+/// session. On a switch, persist the new model as pending and record a
+/// `model_change` event in the workspace ledger (best-effort).
+///
+/// The first model seen is recorded without an event: there is nothing it
+/// changed from.
+pub fn observe(
+    project_id: &str,
+    session_id: &str,
+    transcript_path: &str,
+    cwd: &str,
+) -> Option<ModelChange> {
+    if session_id.is_empty() || transcript_path.is_empty() {
+        return None;
+    }
+    let model = latest_model(Path::new(transcript_path))?;
+    let previous = read_model_state(project_id, session_id);
+    if previous.as_ref().is_some_and(|p| p.model == model) {
+        return None;
+    }
+
+    let profile = ContextProfile::for_model(&model);
+    let from = previous.map(|p| p.model);
+    write_model_state(
+        project_id,
+        session_id,
+        &ModelState {
+            model: model.clone(),
+            profile,
+            since: crate::parse::now_rfc3339(),
+            pending_from: from.clone(),
+        },
+    );
+
+    let change = ModelChange {
+        from: from?,
+        to: model,
+        profile,
+    };
+    try_write_model_change_event(cwd, session_id, &change);
+    Some(change)
+}
+
+/// Take the switch not yet announced to the agent, if any.
+pub fn take_pending_change(project_id: &str, session_id: &str) -> Option<ModelChange> {
+    let mut state = read_model_state(project_id, session_id)?;
+    let from = state.pending_from.take()?;
+    write_model_state(project_id, session_id, &state);
+    Some(ModelChange {
+        from,
+        to: state.model,
+        profile: state.profile,
+    })
+}
+
+/// Write a `model_change` event to the workspace ledger (best-effort,
+/// skipped when the workspace is locked).
+fn try_write_model_change_event(cwd: &str, session_id: &str, change: &ModelChange) {
+    if cwd.is_empty() {
+        return;
+    }
+    let Some(root) = edda_ledger::EddaPaths::find_root(Path::new(cwd)) else {
+        return;
+    };
+    let Ok(ledger) = edda_ledger::Ledger::open(&root) else {
+        return;
+    };
+    let Ok(_lock) = edda_ledger::WorkspaceLock::acquire(&ledger.paths) else {
+        return;
+    };
+    let Ok(branch) = ledger.head_branch() else {
+        return;
+    };
+    let Ok(parent_hash) = ledger.last_event_hash() else {
+        return;
+    };
+    let params = edda_core::event::ModelChangeParams {
+        branch: &branch,
+        parent_hash: parent_hash.as_deref(),
+        session_id,
+        from: &change.from,
+        to: &change.to,
+        profile: change.profile.as_str(),
+    };
+    if let Ok(event) = edda_core::event::new_model_change_event(&params) {
+        let _ = ledger.append_event(&event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assistant(model: &str) -> String {
+        serde_json::json!({
+            "type": "assistant",
+            "message": { "model": model, "content": [] }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn latest_model_is_the_last_real_assistant_record() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("t.jsonl");
+        let lines = [
+            assistant("claude-sonnet-4-20250514"),
+            r#"{"type":"user","message":{"content":"hi"}}"#.to_string(),
+            assistant("claude-haiku-4-5"),
+            assistant("<synthetic>"),
+        ];
+        fs::write(&path, lines.join("\n")).unwrap();
+        assert_eq!(latest_model(&path).as_deref(), Some("claude-haiku-4-5"));
+        assert_eq!(latest_model(&tmp.path().join("missing.jsonl")), None);
+    }
+
+    #[test]
+    fn profile_follows_the_model_family() {
+        assert_eq!(
+            ContextProfile::for_model("claude-haiku-4-5"),
+            ContextProfile::Compact
+        );
+        assert_eq!(
+            ContextProfile::for_model("claude-sonnet-4-20250514"),
+            ContextProfile::Full
+        );
+        assert_eq!(ContextProfile::Compact.budget(8000), 4000);
+        assert_eq!(ContextProfile::Full.budget(8000), 8000);
+    }
+
+    #[test]
+    fn switch_is_detected_once_and_announced_once() {
+        let pid = "test_model_switch_observe";
+        let sid = "sess-model";
+        let _ = edda_store::ensure_dirs(pid);
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("t.jsonl");
+        let transcript = path.to_str().unwrap();
+
+        fs::write(&path, assistant("claude-sonnet-4-20250514")).unwrap();
+        assert_eq!(observe(pid, sid, transcript, ""), None);
+        assert_eq!(session_profile(pid, sid), ContextProfile::Full);
+        assert_eq!(take_pending_change(pid, sid), None);
+
+        fs::write(&path, assistant("claude-haiku-4-5")).unwrap();
+        let change = observe(pid, sid, transcript, "").unwrap();
+        assert_eq!(change.from, "claude-sonnet-4-20250514");
+        assert_eq!(change.profile, ContextProfile::Compact);
+        assert_eq!(observe(pid, sid, transcript, ""), None);
+        assert_eq!(session_profile(pid, sid), ContextProfile::Compact);
+
+        assert_eq!(take_pending_change(pid, sid), Some(change));
+        assert_eq!(take_pending_change(pid, sid), None);
+
+        let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+    }
+}
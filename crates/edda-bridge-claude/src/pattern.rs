@@ -1,3 +1,4 @@
+use edda_core::text::display_width;
 use globset::Glob;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -134,7 +135,7 @@ pub fn render_pattern_context(
         }
         entry.push('\n');
 
-        if output.len() + entry.len() > budget_chars {
+        if display_width(&output) + display_width(&entry) > budget_chars {
             break;
         }
         output.push_str(&entry);
@@ -85,6 +85,7 @@ pub fn discover_active_peers(project_id: &str, current_session_id: &str) -> Vec<
             claimed_paths,
             branch: hb.branch,
             current_phase: hb.current_phase,
+            subagents: hb.subagents,
         });
     }
 
@@ -150,6 +151,7 @@ pub fn discover_all_sessions(project_id: &str) -> Vec<PeerSummary> {
             claimed_paths,
             branch: hb.branch,
             current_phase: hb.current_phase,
+            subagents: hb.subagents,
         });
     }
 
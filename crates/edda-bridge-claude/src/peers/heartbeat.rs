@@ -8,7 +8,7 @@ use super::board::compute_board_state;
 use super::helpers::auto_label;
 use super::{
     coordination_path, detect_git_branch_in, env_label, heartbeat_path, BindingConflict,
-    CoordEvent, CoordEventType, SessionHeartbeat,
+    CoordEvent, CoordEventType, SessionHeartbeat, SubagentActivity,
 };
 
 // ── Heartbeat Write/Read ──
@@ -59,6 +59,28 @@ pub(crate) fn write_heartbeat(
         current_phase: crate::agent_phase::read_phase_state(project_id, session_id)
             .map(|ps| ps.phase.to_string()),
         parent_session_id: None,
+        subagents: signals
+            .subagents
+            .iter()
+            .map(|sub| SubagentActivity {
+                agent_id: sub.agent_id.clone(),
+                focus_files: sub
+                    .files_modified
+                    .iter()
+                    .take(5)
+                    .map(|f| f.path.clone())
+                    .collect(),
+                files_modified_count: sub.files_modified.len(),
+                total_edits: sub.files_modified.iter().map(|f| f.count).sum(),
+                recent_commits: sub
+                    .commits
+                    .iter()
+                    .rev()
+                    .take(3)
+                    .map(|c| format!("{} {}", &c.hash[..7.min(c.hash.len())], c.message))
+                    .collect(),
+            })
+            .collect(),
     };
 
     let data = match serde_json::to_string_pretty(&heartbeat) {
@@ -143,6 +165,7 @@ pub fn write_heartbeat_minimal(project_id: &str, session_id: &str, label: &str,
         branch: detect_git_branch_in(cwd),
         current_phase: None,
         parent_session_id: None,
+        subagents: Vec::new(),
     };
 
     let data = match serde_json::to_string_pretty(&heartbeat) {
@@ -176,6 +199,7 @@ pub(crate) fn write_subagent_heartbeat(
         branch: detect_git_branch_in(cwd),
         current_phase: None,
         parent_session_id: Some(parent_session_id.to_string()),
+        subagents: Vec::new(),
     };
     let data = match serde_json::to_string_pretty(&heartbeat) {
         Ok(d) => d,
@@ -2,6 +2,7 @@ use super::board::compute_board_state;
 use super::heartbeat::read_heartbeat;
 use super::RequestEntry;
 use crate::signals::SessionSignals;
+use edda_core::text::{display_width, width_boundary};
 
 pub(crate) fn pending_requests_for_session(
     project_id: &str,
@@ -143,12 +144,13 @@ pub fn format_age(secs: u64) -> String {
     }
 }
 
-/// Truncate content to budget, cutting at last newline before budget.
+/// Truncate content to `budget` display columns, cutting at the last newline
+/// before the budget.
 pub(super) fn truncate_to_budget(content: &str, budget: usize) -> String {
-    if content.len() <= budget {
+    if display_width(content) <= budget {
         return content.to_string();
     }
-    let truncated = &content[..budget.min(content.len())];
+    let truncated = &content[..width_boundary(content, budget)];
     // Cut at last newline for clean truncation
     if let Some(pos) = truncated.rfind('\n') {
         truncated[..pos].to_string()
@@ -68,6 +68,20 @@ pub struct SessionHeartbeat {
     /// Used for orphan cleanup and extended stale threshold.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_session_id: Option<String>,
+    /// Edits made by this session's Task-tool sub-agents, kept out of
+    /// `focus_files` and the edit counts above.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subagents: Vec<SubagentActivity>,
+}
+
+/// A sub-agent's edits, rolled up on its parent's heartbeat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubagentActivity {
+    pub agent_id: String,
+    pub focus_files: Vec<String>,
+    pub files_modified_count: usize,
+    pub total_edits: usize,
+    pub recent_commits: Vec<String>,
 }
 
 /// Append-only coordination event.
@@ -145,7 +159,7 @@ pub struct SubagentCompletedEntry {
 }
 
 /// Computed board state from coordination.jsonl.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize)]
 pub struct BoardState {
     pub claims: Vec<ClaimEntry>,
     pub bindings: Vec<BindingEntry>,
@@ -155,7 +169,7 @@ pub struct BoardState {
 }
 
 /// Summary of a peer session for rendering.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PeerSummary {
     pub session_id: String,
     pub label: String,
@@ -167,6 +181,7 @@ pub struct PeerSummary {
     pub claimed_paths: Vec<String>,
     pub branch: Option<String>,
     pub current_phase: Option<String>,
+    pub subagents: Vec<SubagentActivity>,
 }
 
 /// Conflict info when a binding with the same key but different value exists.
@@ -1,4 +1,5 @@
 use crate::signals::FileEditCount;
+use edda_core::text::display_width;
 
 use super::autoclaim::derive_scope_from_files;
 use super::board::compute_board_state;
@@ -143,7 +144,7 @@ pub fn render_coordination_protocol_with(
             lines.push(format!("- {}: {} ({})", d.key, d.value, d.by_label));
         }
         let result = lines.join("\n");
-        return Some(if result.len() > budget {
+        return Some(if display_width(&result) > budget {
             truncate_to_budget(&result, budget)
         } else {
             result
@@ -188,7 +189,9 @@ pub fn render_coordination_protocol_with(
     // Peer activity (tasks + focus files)
     let active_peers: Vec<&PeerSummary> = peers
         .iter()
-        .filter(|p| !p.task_subjects.is_empty() || !p.focus_files.is_empty())
+        .filter(|p| {
+            !p.task_subjects.is_empty() || !p.focus_files.is_empty() || !p.subagents.is_empty()
+        })
         .collect();
     if !active_peers.is_empty() {
         lines.push("### Peers Working On".to_string());
@@ -211,6 +214,26 @@ pub fn render_coordination_protocol_with(
                     p.label,
                     files.join(", ")
                 ));
+            } else {
+                lines.push(format!(
+                    "- {} ({age}){branch_suffix}: delegating to sub-agents",
+                    p.label
+                ));
+            }
+            // Sub-agent edits are listed under their parent, not mixed into it.
+            for sub in p.subagents.iter().take(2) {
+                let files: Vec<&str> = sub
+                    .focus_files
+                    .iter()
+                    .take(2)
+                    .map(|f| f.rsplit(['/', '\\']).next().unwrap_or(f.as_str()))
+                    .collect();
+                lines.push(format!(
+                    "  - sub-agent {}: editing {} ({} files)",
+                    sub.agent_id.chars().take(8).collect::<String>(),
+                    files.join(", "),
+                    sub.files_modified_count
+                ));
             }
         }
     }
@@ -281,7 +304,7 @@ pub fn render_coordination_protocol_with(
     let result = lines.join("\n");
 
     // Apply budget
-    if result.len() > budget {
+    if display_width(&result) > budget {
         Some(truncate_to_budget(&result, budget))
     } else {
         Some(result)
@@ -322,7 +345,7 @@ pub(crate) fn render_peer_updates_with(
             lines.push(format!("- {}: {} ({})", d.key, d.value, d.by_label));
         }
         let result = lines.join("\n");
-        return Some(if result.len() > PEER_UPDATES_BUDGET {
+        return Some(if display_width(&result) > PEER_UPDATES_BUDGET {
             truncate_to_budget(&result, PEER_UPDATES_BUDGET)
         } else {
             result
@@ -400,7 +423,7 @@ pub(crate) fn render_peer_updates_with(
     }
 
     let result = lines.join("\n");
-    if result.len() > PEER_UPDATES_BUDGET {
+    if display_width(&result) > PEER_UPDATES_BUDGET {
         Some(truncate_to_budget(&result, PEER_UPDATES_BUDGET))
     } else {
         Some(result)
@@ -534,7 +557,7 @@ pub(crate) fn render_coord_diff(project_id: &str, session_id: &str) -> Option<St
     }
 
     let mut result = format!("[coordination update]\n{}", diff_lines.join("\n"));
-    if result.len() > COORD_DIFF_BUDGET {
+    if display_width(&result) > COORD_DIFF_BUDGET {
         result = helpers::truncate_to_budget(&result, COORD_DIFF_BUDGET);
     }
     Some(result)
@@ -10,6 +10,7 @@
 //! *waiting* there, and nothing else: the pack's job is to make an agent
 //! suspect there is something to look up, not to be the lookup.
 
+use edda_core::text::{display_width, width_boundary};
 use edda_ledger::tasks::TaskStatus;
 use edda_ledger::Ledger;
 use edda_store::fleet::{fan_out, FleetMiss};
@@ -193,15 +194,12 @@ pub fn render_fleet_section(
 /// it is allowed to exist: it is the least important thing in the pack and must
 /// be the first to give way.
 fn truncate_on_line(s: &str, budget: usize) -> String {
-    if s.len() <= budget {
+    if display_width(s) <= budget {
         return s.to_string();
     }
     let marker = "- (fleet truncated by budget)\n";
-    let room = budget.saturating_sub(marker.len());
-    let mut end = 0;
-    for (i, _) in s.char_indices().filter(|(i, c)| *c == '\n' && *i < room) {
-        end = i + 1;
-    }
+    let room = width_boundary(s, budget.saturating_sub(display_width(marker)));
+    let end = s[..room].rfind('\n').map_or(0, |i| i + 1);
     format!("{}{marker}", &s[..end])
 }
 
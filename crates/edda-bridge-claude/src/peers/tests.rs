@@ -8,7 +8,7 @@ use super::helpers::*;
 use super::render_coord::*;
 use super::*;
 use crate::parse::now_rfc3339;
-use crate::signals::{CommitInfo, FileEditCount, SessionSignals, TaskSnapshot};
+use crate::signals::{CommitInfo, FileEditCount, SessionSignals, SubagentSignals, TaskSnapshot};
 
 #[test]
 fn heartbeat_write_read_roundtrip() {
@@ -118,6 +118,54 @@ fn render_protocol_solo_no_bindings_returns_none() {
     let _ = fs::remove_dir_all(edda_store::project_dir(pid));
 }
 
+#[test]
+fn subagent_edits_roll_up_under_the_parent_peer() {
+    let pid = "test_peers_subagent_rollup";
+    let _ = edda_store::ensure_dirs(pid);
+    let _ = fs::remove_file(coordination_path(pid));
+
+    let signals = SessionSignals {
+        files_modified: vec![FileEditCount {
+            path: "src/auth/mod.rs".into(),
+            count: 1,
+        }],
+        subagents: vec![SubagentSignals {
+            agent_id: "agent-7f3a9c21".into(),
+            parent_session_id: "s1".into(),
+            files_modified: vec![
+                FileEditCount {
+                    path: "src/db/pool.rs".into(),
+                    count: 3,
+                },
+                FileEditCount {
+                    path: "src/db/mod.rs".into(),
+                    count: 1,
+                },
+            ],
+            commits: vec![],
+        }],
+        ..Default::default()
+    };
+    write_heartbeat(pid, "s1", &signals, Some("auth"), ".");
+    write_heartbeat(pid, "s2", &SessionSignals::default(), Some("billing"), ".");
+
+    let hb = read_heartbeat(pid, "s1").unwrap();
+    assert_eq!(hb.focus_files, vec!["src/auth/mod.rs".to_string()]);
+    assert_eq!(hb.total_edits, 1);
+    assert_eq!(hb.subagents.len(), 1);
+    assert_eq!(hb.subagents[0].files_modified_count, 2);
+    assert_eq!(hb.subagents[0].total_edits, 4);
+
+    let result = render_coordination_protocol(pid, "s2", ".").unwrap();
+    assert!(result.contains("- auth"));
+    assert!(result.contains("editing mod.rs"));
+    assert!(result.contains("  - sub-agent agent-7f: editing pool.rs, mod.rs (2 files)"));
+
+    remove_heartbeat(pid, "s1");
+    remove_heartbeat(pid, "s2");
+    let _ = fs::remove_dir_all(edda_store::project_dir(pid));
+}
+
 #[test]
 fn render_protocol_multi_session() {
     let pid = "test_peers_multi";
@@ -199,6 +247,15 @@ fn format_age_display() {
     assert_eq!(format_age(3700), "1h ago");
 }
 
+#[test]
+fn truncate_to_budget_cuts_cjk_by_width_on_a_line() {
+    let content = "- 資料庫: PostgreSQL\n- 認證: JWT 與刷新權杖\n";
+    assert_eq!(truncate_to_budget(content, 80), content);
+    // The second line ends past 30 columns, so only the first survives,
+    // and a cut inside a three-byte character no longer panics.
+    assert_eq!(truncate_to_budget(content, 30), "- 資料庫: PostgreSQL");
+}
+
 #[test]
 fn parse_rfc3339_basic() {
     let epoch = parse_rfc3339_to_epoch("2026-02-16T10:05:23Z").unwrap();
@@ -1530,6 +1587,7 @@ fn suggest_claim_command_from_focus_files() {
         branch: Some("feat/issue-131".into()),
         current_phase: None,
         parent_session_id: None,
+        subagents: Vec::new(),
     };
     let result = suggest_claim_command("worker", &Some(hb));
     assert!(result.contains("edda claim"), "should contain edda claim");
@@ -1554,6 +1612,7 @@ fn suggest_claim_command_from_branch() {
         branch: Some("feat/auth-refactor".into()),
         current_phase: None,
         parent_session_id: None,
+        subagents: Vec::new(),
     };
     let result = suggest_claim_command("", &Some(hb));
     assert!(
@@ -1673,6 +1732,7 @@ fn protocol_nudge_uses_branch_context() {
         branch: Some("feat/billing-v2".into()),
         current_phase: None,
         parent_session_id: None,
+        subagents: Vec::new(),
     };
     let hb_path = heartbeat_path(pid, "s2");
     let _ = fs::create_dir_all(hb_path.parent().unwrap());
@@ -2164,6 +2224,7 @@ fn subagent_stale_threshold_extended() {
         branch: None,
         current_phase: None,
         parent_session_id: Some("parent-session".to_string()),
+        subagents: Vec::new(),
     };
     let path = heartbeat_path(pid, "sub-stale");
     let _ = fs::create_dir_all(path.parent().unwrap());
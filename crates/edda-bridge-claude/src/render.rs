@@ -3,6 +3,7 @@
 //! Contains shared context rendering utilities used by both Claude and OpenClaw
 //! bridges, plus thin wrappers for CLI commands.
 
+use edda_core::text::{display_width, width_boundary};
 use std::fs;
 use std::path::Path;
 
@@ -32,13 +33,13 @@ pub fn context_budget(cwd: &str) -> usize {
         .unwrap_or(DEFAULT_MAX_CONTEXT_CHARS)
 }
 
-/// Truncate content to fit within the char budget, preserving UTF-8 boundaries.
+/// Truncate content to fit within the budget, measured in display columns so
+/// CJK text is not cut to a third, and cut between grapheme clusters.
 pub fn apply_budget(content: &str, budget: usize) -> String {
-    if content.len() <= budget {
+    if display_width(content) <= budget {
         return content.to_string();
     }
-    let cut = content.len().min(budget.saturating_sub(50));
-    let safe = content.floor_char_boundary(cut);
+    let safe = width_boundary(content, budget.saturating_sub(50));
     format!(
         "{}\n\n... (truncated to {} char budget)",
         &content[..safe],
@@ -118,7 +119,7 @@ pub fn workspace(cwd: &str, budget: usize) -> Option<String> {
             let mut section = transform_context_to_section(&raw);
             // If edda ledger has no commit events, fall back to `git log`
             supplement_git_commits(&mut section, cwd_path, d);
-            if section.len() <= budget {
+            if display_width(&section) <= budget {
                 // Hint for LLM agents to discover the ask tool
                 section.push_str(
                     "\n> Use edda_ask MCP tool or `edda ask <keyword>` for detailed decision history\n",
@@ -127,7 +128,13 @@ pub fn workspace(cwd: &str, budget: usize) -> Option<String> {
             }
         }
     }
-    None
+    // Nothing fits; pinned items still go in on their own.
+    let pinned = edda_derive::render_pinned(&ledger, budget.min(edda_derive::pinned_budget()))
+        .unwrap_or_default();
+    if pinned.is_empty() {
+        return None;
+    }
+    Some(format!("## Workspace Context\n\n{pinned}"))
 }
 
 /// Transform `render_context` output into a pack-embeddable section.
@@ -196,7 +203,8 @@ pub fn config_usize(cwd: &str, key: &str) -> Option<usize> {
     config_value(cwd, key)?.as_u64().map(|v| v as usize)
 }
 
-/// Read a raw JSON value from `.edda/config.json` using dot-notation keys.
+/// Read a raw JSON value from `.edda/config.json` using dot-notation keys:
+/// a flat `"a.b"` key as `edda config set` writes it, or a nested path.
 pub fn config_value(cwd: &str, key: &str) -> Option<serde_json::Value> {
     if cwd.is_empty() {
         return None;
@@ -205,6 +213,9 @@ pub fn config_value(cwd: &str, key: &str) -> Option<serde_json::Value> {
     let config_path = root.join(".edda").join("config.json");
     let content = fs::read_to_string(&config_path).ok()?;
     let val: serde_json::Value = serde_json::from_str(&content).ok()?;
+    if let Some(flat) = val.get(key) {
+        return Some(flat.clone());
+    }
     let mut current = val;
     for part in key.split('.') {
         current = current.get(part)?.clone();
@@ -264,6 +275,17 @@ mod tests {
         assert!(result.contains("truncated"));
     }
 
+    #[test]
+    fn apply_budget_measures_cjk_by_width() {
+        // 300 CJK characters: 900 bytes but 600 columns.
+        let content = "決".repeat(300);
+        assert_eq!(apply_budget(&content, 600), content);
+
+        let result = apply_budget(&content, 250);
+        assert!(result.starts_with(&"決".repeat(100)));
+        assert!(!result.starts_with(&"決".repeat(101)));
+    }
+
     #[test]
     fn context_budget_uses_env_var() {
         crate::with_env_guard(&[("EDDA_MAX_CONTEXT_CHARS", Some("1234"))], || {
@@ -280,6 +302,21 @@ mod tests {
         });
     }
 
+    #[test]
+    fn config_value_reads_flat_and_nested_keys() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(tmp.path().join(".edda")).unwrap();
+        fs::write(
+            tmp.path().join(".edda").join("config.json"),
+            r#"{"bridge.digest_idle_minutes": 45, "bridge": {"auto_digest": false}}"#,
+        )
+        .unwrap();
+        let cwd = tmp.path().to_str().unwrap();
+        assert_eq!(config_usize(cwd, "bridge.digest_idle_minutes"), Some(45));
+        assert_eq!(config_bool(cwd, "bridge.auto_digest"), Some(false));
+        assert_eq!(config_bool(cwd, "bridge.missing"), None);
+    }
+
     #[test]
     fn writeback_contains_decide_command() {
         let text = writeback();
@@ -297,4 +334,35 @@ mod tests {
         assert!(!section.contains("How to cite evidence"));
         assert!(!section.contains("Use event_id"));
     }
+
+    #[test]
+    fn workspace_keeps_pins_when_full_context_is_over_budget() {
+        let tmp = tempfile::tempdir().unwrap();
+        edda_ledger::Ledger::ensure_initialized(tmp.path()).unwrap();
+        let ledger = edda_ledger::Ledger::open(tmp.path()).unwrap();
+        let note = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "no billing schema changes without a migration plan",
+            &[],
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+        let pin = edda_core::event::new_pin_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            &note.event_id,
+            true,
+            None,
+        )
+        .unwrap();
+        ledger.append_event(&pin).unwrap();
+
+        let cwd = tmp.path().to_str().unwrap();
+        let section = workspace(cwd, 150).unwrap();
+        assert!(section.starts_with("## Workspace Context\n"));
+        assert!(section.contains("billing schema"));
+        assert!(!section.contains("## Branch"));
+    }
 }
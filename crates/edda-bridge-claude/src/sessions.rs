@@ -0,0 +1,259 @@
+//! Per-session drill-down for dashboards — heartbeat, latest hot pack,
+//! transcript signals, usage totals and recap for one agent session.
+//!
+//! Served by `edda serve` as `/api/sessions` and `/api/sessions/{id}`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use edda_ledger::Ledger;
+use serde::Serialize;
+
+use crate::peers::{self, SessionHeartbeat};
+use crate::signals::{self, TaskSnapshot, UsageSnapshot};
+
+/// One row of the session list.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionListItem {
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Heartbeat is fresher than the peer stale threshold.
+    pub live: bool,
+    /// Seconds since the last heartbeat; `None` once the heartbeat is gone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_phase: Option<String>,
+    /// The session has a transcript index in the project store.
+    pub indexed: bool,
+    pub has_recap: bool,
+}
+
+/// The latest hot pack, when it was built for this session.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionPack {
+    pub git_branch: String,
+    pub turn_count: usize,
+    pub markdown: String,
+}
+
+/// What the session's transcript shows it did.
+#[derive(Debug, Clone, Serialize)]
+pub struct SignalSummary {
+    pub tasks: Vec<TaskSnapshot>,
+    pub files_modified: usize,
+    /// Most-edited files, most edits first (at most 10).
+    pub top_files: Vec<FileEdits>,
+    pub commits: Vec<String>,
+    /// Distinct failing commands (`command_base`).
+    pub failed_commands: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileEdits {
+    pub path: String,
+    pub edits: usize,
+}
+
+/// Token usage summed over the session's assistant messages.
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageTotals {
+    #[serde(flatten)]
+    pub tokens: UsageSnapshot,
+    /// Approximate USD cost; 0 for models without known pricing.
+    pub estimated_cost_usd: f64,
+}
+
+/// Everything known about one session.
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionDetail {
+    #[serde(flatten)]
+    pub summary: SessionListItem,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heartbeat: Option<SessionHeartbeat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pack: Option<SessionPack>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signals: Option<SignalSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<UsageTotals>,
+    /// Payload of the session's `recap` event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recap: Option<serde_json::Value>,
+}
+
+/// Every session with a heartbeat or a transcript index: live sessions
+/// first (freshest heartbeat first), then finished ones by id.
+pub fn list_sessions(project_id: &str, ledger: &Ledger) -> anyhow::Result<Vec<SessionListItem>> {
+    let proj_dir = edda_store::project_dir(project_id);
+    let recaps = recaps_by_session(ledger)?;
+    let indexed = edda_index::indexed_sessions(&proj_dir.join("index"))?;
+    let stale = peers::stale_secs();
+
+    let mut items: BTreeMap<String, SessionListItem> = BTreeMap::new();
+    for peer in peers::discover_all_sessions(project_id) {
+        items.insert(
+            peer.session_id.clone(),
+            SessionListItem {
+                has_recap: recaps.contains_key(&peer.session_id),
+                indexed: indexed.contains(&peer.session_id),
+                live: peer.age_secs <= stale,
+                age_secs: Some(peer.age_secs),
+                label: Some(peer.label).filter(|l| !l.is_empty()),
+                branch: peer.branch,
+                current_phase: peer.current_phase,
+                session_id: peer.session_id,
+            },
+        );
+    }
+    for sid in indexed {
+        items.entry(sid.clone()).or_insert_with(|| SessionListItem {
+            has_recap: recaps.contains_key(&sid),
+            indexed: true,
+            live: false,
+            age_secs: None,
+            label: None,
+            branch: None,
+            current_phase: None,
+            session_id: sid,
+        });
+    }
+
+    let mut items: Vec<SessionListItem> = items.into_values().collect();
+    items.sort_by_key(|i| (!i.live, i.age_secs.unwrap_or(u64::MAX)));
+    Ok(items)
+}
+
+/// The drill-down for one session, or `None` when nothing is known about it.
+pub fn session_detail(
+    project_id: &str,
+    ledger: &Ledger,
+    session_id: &str,
+) -> anyhow::Result<Option<SessionDetail>> {
+    let proj_dir = edda_store::project_dir(project_id);
+    let Some(summary) = list_sessions(project_id, ledger)?
+        .into_iter()
+        .find(|s| s.session_id == session_id)
+    else {
+        return Ok(None);
+    };
+
+    let transcript =
+        edda_store::store_subdir(&proj_dir, "transcripts").join(format!("{session_id}.jsonl"));
+    let (signals, usage) = if transcript.exists() {
+        let (signals, usage) = signal_summary(&transcript);
+        (Some(signals), Some(usage))
+    } else {
+        (None, None)
+    };
+
+    Ok(Some(SessionDetail {
+        heartbeat: peers::read_heartbeat(project_id, session_id),
+        pack: read_pack(&proj_dir, session_id),
+        signals,
+        usage,
+        recap: recaps_by_session(ledger)?.remove(session_id),
+        summary,
+    }))
+}
+
+/// The hot pack, if its metadata says it was built for `session_id`.
+fn read_pack(proj_dir: &Path, session_id: &str) -> Option<SessionPack> {
+    let packs = proj_dir.join("packs");
+    let meta: edda_pack::PackMetadata =
+        serde_json::from_str(&std::fs::read_to_string(packs.join("hot.meta.json")).ok()?).ok()?;
+    if meta.session_id != session_id {
+        return None;
+    }
+    Some(SessionPack {
+        git_branch: meta.git_branch,
+        turn_count: meta.turn_count,
+        markdown: std::fs::read_to_string(packs.join("hot.md")).ok()?,
+    })
+}
+
+fn signal_summary(transcript: &Path) -> (SignalSummary, UsageTotals) {
+    let s = signals::extract_session_signals(transcript);
+    let mut files = s.files_modified;
+    files.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.path.cmp(&b.path)));
+    let summary = SignalSummary {
+        tasks: s.tasks,
+        files_modified: files.len(),
+        top_files: files
+            .into_iter()
+            .take(10)
+            .map(|f| FileEdits {
+                path: f.path,
+                edits: f.count,
+            })
+            .collect(),
+        commits: s
+            .commits
+            .into_iter()
+            .map(|c| format!("{} {}", c.hash, c.message))
+            .collect(),
+        failed_commands: s
+            .failed_commands
+            .into_iter()
+            .map(|c| c.command_base)
+            .collect(),
+    };
+    let usage = UsageTotals {
+        estimated_cost_usd: signals::estimate_cost(&s.usage),
+        tokens: s.usage,
+    };
+    (summary, usage)
+}
+
+/// Recap payloads keyed by session id; the latest recap wins.
+fn recaps_by_session(ledger: &Ledger) -> anyhow::Result<BTreeMap<String, serde_json::Value>> {
+    Ok(ledger
+        .iter_events_by_type("recap")?
+        .into_iter()
+        .filter_map(|e| {
+            let sid = e.payload.get("session_id")?.as_str()?.to_string();
+            Some((sid, e.payload))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signal_summary_ranks_files_and_sums_usage() {
+        let tmp = tempfile::tempdir().unwrap();
+        let transcript = tmp.path().join("s1.jsonl");
+        let edit = |id: &str, path: &str| {
+            serde_json::json!({
+                "type": "assistant",
+                "message": {
+                    "model": "claude-sonnet-4-20250514",
+                    "usage": { "input_tokens": 1000, "output_tokens": 100 },
+                    "content": [{
+                        "type": "tool_use", "id": id, "name": "Edit",
+                        "input": { "file_path": path, "old_string": "a", "new_string": "b" }
+                    }]
+                }
+            })
+            .to_string()
+        };
+        let lines = [
+            edit("t1", "/repo/src/a.rs"),
+            edit("t2", "/repo/src/b.rs"),
+            edit("t3", "/repo/src/b.rs"),
+        ];
+        std::fs::write(&transcript, lines.join("\n") + "\n").unwrap();
+
+        let (summary, usage) = signal_summary(&transcript);
+        assert_eq!(summary.files_modified, 2);
+        assert_eq!(summary.top_files[0].path, "/repo/src/b.rs");
+        assert_eq!(summary.top_files[0].edits, 2);
+        assert_eq!(usage.tokens.input_tokens, 3000);
+        assert_eq!(usage.tokens.output_tokens, 300);
+    }
+}
@@ -64,6 +64,19 @@ pub(crate) struct SessionSignals {
     pub failed_commands: Vec<FailedBashCmd>,
     #[serde(default)]
     pub usage: UsageSnapshot,
+    /// Edits and commits of Task-tool sub-agents, kept apart from the
+    /// session's own.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subagents: Vec<SubagentSignals>,
+}
+
+/// What one sub-agent changed, as seen in its parent's transcript.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct SubagentSignals {
+    pub agent_id: String,
+    pub parent_session_id: String,
+    pub files_modified: Vec<FileEditCount>,
+    pub commits: Vec<CommitInfo>,
 }
 
 /// Lightweight summary extracted when a sub-agent completes.
@@ -76,6 +89,11 @@ pub(crate) struct SubagentSummary {
 }
 
 /// One-pass transcript scan: extract tasks, files modified, and commits.
+///
+/// Records a sub-agent wrote (`isSidechain: true`) are kept out of the
+/// session's own signals and rolled up per agent in `subagents`, so a
+/// Task-tool child's edits do not show up as the parent's heartbeat or
+/// claims. Token usage still counts toward the session: it pays for them.
 pub(crate) fn extract_session_signals(transcript_store_path: &Path) -> SessionSignals {
     use std::io::BufRead;
 
@@ -85,22 +103,9 @@ pub(crate) fn extract_session_signals(transcript_store_path: &Path) -> SessionSi
     };
 
     let mut usage = UsageSnapshot::default();
-
-    let mut tasks: std::collections::HashMap<String, TaskSnapshot> =
-        std::collections::HashMap::new();
-    let mut next_task_id: usize = 1;
-
-    let mut file_counts: std::collections::HashMap<String, usize> =
-        std::collections::HashMap::new();
-
-    let mut pending_commits: std::collections::HashMap<String, String> =
-        std::collections::HashMap::new(); // tool_use_id -> commit_msg_from_cmd
-    let mut commits: Vec<CommitInfo> = Vec::new();
-
-    let mut pending_bash: std::collections::HashMap<String, String> =
-        std::collections::HashMap::new(); // tool_use_id -> command
-    let mut failed_cmd_map: std::collections::HashMap<String, (String, usize)> =
-        std::collections::HashMap::new(); // command_base -> (stderr_snippet, count)
+    let mut own = ToolSignals::default();
+    // agent_id -> (parent session id, signals), in first-seen order
+    let mut children: Vec<(String, String, ToolSignals)> = Vec::new();
 
     for line in std::io::BufReader::new(file).lines() {
         let line = match line {
@@ -122,6 +127,7 @@ pub(crate) fn extract_session_signals(transcript_store_path: &Path) -> SessionSi
                         usage.model = model.to_string();
                     }
                 }
+                continue;
             }
             "assistant" => {
                 // Accumulate usage from assistant messages
@@ -149,182 +155,261 @@ pub(crate) fn extract_session_signals(transcript_store_path: &Path) -> SessionSi
                         usage.model = model.to_string();
                     }
                 }
-                let content = match record
-                    .get("message")
-                    .and_then(|m| m.get("content"))
-                    .and_then(|c| c.as_array())
-                {
-                    Some(c) => c,
-                    None => continue,
-                };
+            }
+            "user" => {}
+            _ => continue,
+        }
 
-                for item in content {
-                    if item.get("type").and_then(|t| t.as_str()) != Some("tool_use") {
-                        continue;
-                    }
-                    let name = item.get("name").and_then(|n| n.as_str()).unwrap_or("");
-                    let input = match item.get("input") {
-                        Some(i) => i,
-                        None => continue,
-                    };
-                    let tool_use_id = item.get("id").and_then(|s| s.as_str()).unwrap_or("");
-
-                    match name {
-                        "TaskCreate" => {
-                            let id = next_task_id.to_string();
-                            next_task_id += 1;
-                            let subject = input
-                                .get("subject")
-                                .and_then(|s| s.as_str())
-                                .unwrap_or("")
-                                .to_string();
-                            tasks.insert(
-                                id.clone(),
-                                TaskSnapshot {
-                                    id,
-                                    subject,
-                                    status: "pending".to_string(),
-                                },
-                            );
+        let is_sidechain = record
+            .get("isSidechain")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let target = if is_sidechain {
+            let agent_id = record
+                .get("agentId")
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .unwrap_or("sidechain");
+            let idx = match children.iter().position(|(id, _, _)| id == agent_id) {
+                Some(idx) => idx,
+                None => {
+                    let parent = record
+                        .get("sessionId")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    children.push((agent_id.to_string(), parent, ToolSignals::default()));
+                    children.len() - 1
+                }
+            };
+            &mut children[idx].2
+        } else {
+            &mut own
+        };
+
+        if record_type == "assistant" {
+            target.scan_tool_uses(&record);
+        } else {
+            target.scan_tool_results(&record);
+        }
+    }
+
+    let mut signals = own.finish();
+    signals.usage = usage;
+    signals.subagents = children
+        .into_iter()
+        .map(|(agent_id, parent_session_id, tools)| {
+            let child = tools.finish();
+            SubagentSignals {
+                agent_id,
+                parent_session_id,
+                files_modified: child.files_modified,
+                commits: child.commits,
+            }
+        })
+        .filter(|s| !s.files_modified.is_empty() || !s.commits.is_empty())
+        .collect();
+    signals
+}
+
+/// Tool-call state accumulated for one actor (the session or a sub-agent).
+#[derive(Default)]
+struct ToolSignals {
+    tasks: std::collections::HashMap<String, TaskSnapshot>,
+    next_task_id: usize,
+    file_counts: std::collections::HashMap<String, usize>,
+    /// tool_use_id -> commit_msg_from_cmd
+    pending_commits: std::collections::HashMap<String, String>,
+    commits: Vec<CommitInfo>,
+    /// tool_use_id -> command
+    pending_bash: std::collections::HashMap<String, String>,
+    /// command_base -> (stderr_snippet, count)
+    failed_cmd_map: std::collections::HashMap<String, (String, usize)>,
+}
+
+impl ToolSignals {
+    /// Track the tool calls of an assistant record.
+    fn scan_tool_uses(&mut self, record: &serde_json::Value) {
+        let content = match record
+            .get("message")
+            .and_then(|m| m.get("content"))
+            .and_then(|c| c.as_array())
+        {
+            Some(c) => c,
+            None => return,
+        };
+
+        for item in content {
+            if item.get("type").and_then(|t| t.as_str()) != Some("tool_use") {
+                continue;
+            }
+            let name = item.get("name").and_then(|n| n.as_str()).unwrap_or("");
+            let input = match item.get("input") {
+                Some(i) => i,
+                None => continue,
+            };
+            let tool_use_id = item.get("id").and_then(|s| s.as_str()).unwrap_or("");
+
+            match name {
+                "TaskCreate" => {
+                    self.next_task_id += 1;
+                    let id = self.next_task_id.to_string();
+                    let subject = input
+                        .get("subject")
+                        .and_then(|s| s.as_str())
+                        .unwrap_or("")
+                        .to_string();
+                    self.tasks.insert(
+                        id.clone(),
+                        TaskSnapshot {
+                            id,
+                            subject,
+                            status: "pending".to_string(),
+                        },
+                    );
+                }
+                "TaskUpdate" => {
+                    let task_id = input.get("taskId").and_then(|s| s.as_str()).unwrap_or("");
+                    if let Some(task) = self.tasks.get_mut(task_id) {
+                        if let Some(s) = input.get("status").and_then(|s| s.as_str()) {
+                            task.status = s.to_string();
                         }
-                        "TaskUpdate" => {
-                            let task_id =
-                                input.get("taskId").and_then(|s| s.as_str()).unwrap_or("");
-                            if let Some(task) = tasks.get_mut(task_id) {
-                                if let Some(s) = input.get("status").and_then(|s| s.as_str()) {
-                                    task.status = s.to_string();
-                                }
-                                if let Some(s) = input.get("subject").and_then(|s| s.as_str()) {
-                                    task.subject = s.to_string();
-                                }
-                            }
+                        if let Some(s) = input.get("subject").and_then(|s| s.as_str()) {
+                            task.subject = s.to_string();
                         }
-                        "Edit" | "Write" => {
-                            if let Some(fp) = input.get("file_path").and_then(|s| s.as_str()) {
-                                if !is_noise_file(fp) {
-                                    *file_counts.entry(fp.to_string()).or_insert(0) += 1;
-                                }
-                            }
+                    }
+                }
+                "Edit" | "Write" => {
+                    if let Some(fp) = input.get("file_path").and_then(|s| s.as_str()) {
+                        if !is_noise_file(fp) {
+                            *self.file_counts.entry(fp.to_string()).or_insert(0) += 1;
                         }
-                        "Bash" => {
-                            if let Some(cmd) = input.get("command").and_then(|s| s.as_str()) {
-                                pending_bash.insert(tool_use_id.to_string(), cmd.to_string());
-                                if cmd.contains("git commit") {
-                                    // Extract message from -m flag if present
-                                    let msg = extract_commit_msg_from_cmd(cmd);
-                                    pending_commits.insert(tool_use_id.to_string(), msg);
-                                }
-                            }
+                    }
+                }
+                "Bash" => {
+                    if let Some(cmd) = input.get("command").and_then(|s| s.as_str()) {
+                        self.pending_bash
+                            .insert(tool_use_id.to_string(), cmd.to_string());
+                        if cmd.contains("git commit") {
+                            // Extract message from -m flag if present
+                            let msg = extract_commit_msg_from_cmd(cmd);
+                            self.pending_commits.insert(tool_use_id.to_string(), msg);
                         }
-                        _ => {}
                     }
                 }
+                _ => {}
             }
-            "user" => {
-                // Look for tool_results that match pending git commit calls
-                let content = match record
-                    .get("message")
-                    .and_then(|m| m.get("content"))
-                    .and_then(|c| c.as_array())
-                {
-                    Some(c) => c,
-                    None => continue,
-                };
+        }
+    }
 
-                for item in content {
-                    if item.get("type").and_then(|t| t.as_str()) != Some("tool_result") {
-                        continue;
-                    }
-                    let tool_use_id = item
-                        .get("tool_use_id")
-                        .and_then(|s| s.as_str())
-                        .unwrap_or("");
-                    // Extract result text (shared between commit and error processing)
-                    let result_text = item
-                        .get("content")
-                        .and_then(|c| {
-                            if let Some(s) = c.as_str() {
-                                Some(s.to_string())
-                            } else if let Some(arr) = c.as_array() {
-                                arr.iter()
-                                    .find_map(|x| x.get("text").and_then(|t| t.as_str()))
-                                    .map(|s| s.to_string())
-                            } else {
-                                None
-                            }
-                        })
-                        .unwrap_or_default();
+    /// Match a user record's tool_results against pending commits and Bash calls.
+    fn scan_tool_results(&mut self, record: &serde_json::Value) {
+        let content = match record
+            .get("message")
+            .and_then(|m| m.get("content"))
+            .and_then(|c| c.as_array())
+        {
+            Some(c) => c,
+            None => return,
+        };
 
-                    // Check for git commit results
-                    if let Some(cmd_msg) = pending_commits.remove(tool_use_id) {
-                        if let Some(ci) = parse_commit_result(&result_text, &cmd_msg) {
-                            commits.push(ci);
-                        }
+        for item in content {
+            if item.get("type").and_then(|t| t.as_str()) != Some("tool_result") {
+                continue;
+            }
+            let tool_use_id = item
+                .get("tool_use_id")
+                .and_then(|s| s.as_str())
+                .unwrap_or("");
+            // Extract result text (shared between commit and error processing)
+            let result_text = item
+                .get("content")
+                .and_then(|c| {
+                    if let Some(s) = c.as_str() {
+                        Some(s.to_string())
+                    } else if let Some(arr) = c.as_array() {
+                        arr.iter()
+                            .find_map(|x| x.get("text").and_then(|t| t.as_str()))
+                            .map(|s| s.to_string())
+                    } else {
+                        None
                     }
+                })
+                .unwrap_or_default();
 
-                    // Check for failed Bash commands
-                    let is_error = item
-                        .get("is_error")
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(false);
-                    if is_error {
-                        if let Some(cmd) = pending_bash.remove(tool_use_id) {
-                            let base = command_base(&cmd);
-                            let snippet = truncate_stderr(&result_text, 200);
-                            let entry = failed_cmd_map
-                                .entry(base)
-                                .or_insert_with(|| (snippet.clone(), 0));
-                            entry.1 += 1;
-                            // Keep the most recent stderr snippet
-                            if !snippet.is_empty() {
-                                entry.0 = snippet;
-                            }
-                        }
-                    } else {
-                        // Successful result — healing: clear stale failures for this command
-                        if let Some(cmd) = pending_bash.remove(tool_use_id) {
-                            let base = command_base(&cmd);
-                            failed_cmd_map.remove(&base);
-                        }
+            // Check for git commit results
+            if let Some(cmd_msg) = self.pending_commits.remove(tool_use_id) {
+                if let Some(ci) = parse_commit_result(&result_text, &cmd_msg) {
+                    self.commits.push(ci);
+                }
+            }
+
+            // Check for failed Bash commands
+            let is_error = item
+                .get("is_error")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            if is_error {
+                if let Some(cmd) = self.pending_bash.remove(tool_use_id) {
+                    let base = command_base(&cmd);
+                    let snippet = truncate_stderr(&result_text, 200);
+                    let entry = self
+                        .failed_cmd_map
+                        .entry(base)
+                        .or_insert_with(|| (snippet.clone(), 0));
+                    entry.1 += 1;
+                    // Keep the most recent stderr snippet
+                    if !snippet.is_empty() {
+                        entry.0 = snippet;
                     }
                 }
+            } else {
+                // Successful result — healing: clear stale failures for this command
+                if let Some(cmd) = self.pending_bash.remove(tool_use_id) {
+                    let base = command_base(&cmd);
+                    self.failed_cmd_map.remove(&base);
+                }
             }
-            _ => {}
         }
     }
 
-    // Sort tasks by ID
-    let mut sorted_tasks: Vec<TaskSnapshot> = tasks.into_values().collect();
-    sorted_tasks.sort_by(|a, b| {
-        a.id.parse::<usize>()
-            .unwrap_or(0)
-            .cmp(&b.id.parse::<usize>().unwrap_or(0))
-    });
-
-    // Sort files by count descending
-    let mut sorted_files: Vec<FileEditCount> = file_counts
-        .into_iter()
-        .map(|(path, count)| FileEditCount { path, count })
-        .collect();
-    sorted_files.sort_by_key(|file| std::cmp::Reverse(file.count));
+    /// Sorted signals; `usage` and `subagents` are left for the caller.
+    fn finish(self) -> SessionSignals {
+        // Sort tasks by ID
+        let mut sorted_tasks: Vec<TaskSnapshot> = self.tasks.into_values().collect();
+        sorted_tasks.sort_by(|a, b| {
+            a.id.parse::<usize>()
+                .unwrap_or(0)
+                .cmp(&b.id.parse::<usize>().unwrap_or(0))
+        });
 
-    // Build failed commands list, sorted by count descending
-    let mut failed_commands: Vec<FailedBashCmd> = failed_cmd_map
-        .into_iter()
-        .map(|(command_base, (stderr_snippet, count))| FailedBashCmd {
-            command_base,
-            stderr_snippet,
-            count,
-        })
-        .collect();
-    failed_commands.sort_by_key(|command| std::cmp::Reverse(command.count));
+        // Sort files by count descending
+        let mut sorted_files: Vec<FileEditCount> = self
+            .file_counts
+            .into_iter()
+            .map(|(path, count)| FileEditCount { path, count })
+            .collect();
+        sorted_files.sort_by_key(|file| std::cmp::Reverse(file.count));
+
+        // Build failed commands list, sorted by count descending
+        let mut failed_commands: Vec<FailedBashCmd> = self
+            .failed_cmd_map
+            .into_iter()
+            .map(|(command_base, (stderr_snippet, count))| FailedBashCmd {
+                command_base,
+                stderr_snippet,
+                count,
+            })
+            .collect();
+        failed_commands.sort_by_key(|command| std::cmp::Reverse(command.count));
 
-    SessionSignals {
-        tasks: sorted_tasks,
-        files_modified: sorted_files,
-        commits,
-        failed_commands,
-        usage,
+        SessionSignals {
+            tasks: sorted_tasks,
+            files_modified: sorted_files,
+            commits: self.commits,
+            failed_commands,
+            ..Default::default()
+        }
     }
 }
 
@@ -1239,6 +1324,55 @@ mod tests {
         let _ = fs::remove_file(&path);
     }
 
+    #[test]
+    fn signals_keep_sidechain_edits_apart_from_the_session() {
+        let edit = |id: &str, path: &str| {
+            serde_json::json!({ "type": "tool_use", "id": id, "name": "Edit",
+                "input": { "file_path": path, "old_string": "a", "new_string": "b" } })
+        };
+        let records = vec![
+            serde_json::json!({
+                "type": "assistant", "sessionId": "parent-1",
+                "message": { "role": "assistant", "content": [edit("e1", "/repo/src/lib.rs")],
+                    "usage": { "input_tokens": 10, "output_tokens": 5 } }
+            }),
+            serde_json::json!({
+                "type": "assistant", "sessionId": "parent-1",
+                "isSidechain": true, "agentId": "agent-a1",
+                "message": { "role": "assistant", "content": [
+                    edit("e2", "/repo/src/db.rs"),
+                    edit("e3", "/repo/src/db.rs"),
+                    { "type": "tool_use", "id": "b1", "name": "Bash",
+                      "input": { "command": "git commit -m \"feat: db\"" } }
+                ], "usage": { "input_tokens": 20, "output_tokens": 7 } }
+            }),
+            serde_json::json!({
+                "type": "user", "isSidechain": true, "agentId": "agent-a1",
+                "message": { "role": "user", "content": [{
+                    "type": "tool_result", "tool_use_id": "b1",
+                    "content": "[main def5678] feat: db\n 1 file changed"
+                }]}
+            }),
+        ];
+        let path = make_transcript(&records);
+        let signals = extract_session_signals(&path);
+
+        assert_eq!(signals.files_modified.len(), 1);
+        assert_eq!(signals.files_modified[0].path, "/repo/src/lib.rs");
+        assert!(signals.commits.is_empty());
+        // The session pays for its sub-agents' tokens.
+        assert_eq!(signals.usage.input_tokens, 30);
+
+        assert_eq!(signals.subagents.len(), 1);
+        let sub = &signals.subagents[0];
+        assert_eq!(sub.agent_id, "agent-a1");
+        assert_eq!(sub.parent_session_id, "parent-1");
+        assert_eq!(sub.files_modified[0].path, "/repo/src/db.rs");
+        assert_eq!(sub.files_modified[0].count, 2);
+        assert_eq!(sub.commits[0].hash, "def5678");
+        let _ = fs::remove_file(&path);
+    }
+
     #[test]
     fn signals_extract_commits() {
         let records = vec![
@@ -1,17 +1,25 @@
 //! Public API for edda watch TUI — snapshot of peers, board state, and events.
+//!
+//! The same snapshot is pushed to `/ws` clients by `edda serve`.
 
 use std::path::Path;
 
+use edda_core::agent_phase::AgentPhaseMap;
+use serde::Serialize;
+
 use crate::peers::{self, BoardState, PeerSummary};
 
 /// A point-in-time snapshot of workspace state for the TUI.
+#[derive(Serialize)]
 pub struct WatchData {
     pub peers: Vec<PeerSummary>,
     pub board: BoardState,
     pub events: Vec<edda_core::types::Event>,
+    pub phases: AgentPhaseMap,
 }
 
-/// Collect a snapshot of peers, coordination board, and recent ledger events.
+/// Collect a snapshot of peers, coordination board, agent phases, and
+/// recent ledger events (newest first).
 pub fn snapshot(
     project_id: &str,
     repo_root: &Path,
@@ -19,6 +27,7 @@ pub fn snapshot(
 ) -> anyhow::Result<WatchData> {
     let peers = peers::discover_all_sessions(project_id);
     let board = peers::compute_board_state(project_id);
+    let phases = crate::agent_phase::build_phase_map(project_id, "");
 
     let events = match edda_ledger::Ledger::open_or_init(repo_root) {
         Ok(ledger) => ledger
@@ -35,5 +44,6 @@ pub fn snapshot(
         peers,
         board,
         events,
+        phases,
     })
 }
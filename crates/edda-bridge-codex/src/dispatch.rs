@@ -76,7 +76,7 @@ pub fn hook_entrypoint_from_stdin(stdin: &str) -> anyhow::Result<HookResult> {
         "UserPromptSubmit" => dispatch_user_prompt_submit(&project_id, &envelope),
         "PreToolUse" => dispatch_pre_tool_use(&project_id, &envelope),
         "PostToolUse" => dispatch_post_tool_use(&project_id, &envelope),
-        "PreCompact" => dispatch_pre_compact(&project_id),
+        "PreCompact" => dispatch_pre_compact(&project_id, &envelope),
         "SessionEnd" | "Stop" => dispatch_session_end(&project_id, &envelope),
         // Codex-only events — forward-compatible stubs
         "SubagentStart" | "SubagentStop" | "PostCompact" | "PermissionRequest"
@@ -99,6 +99,14 @@ fn dispatch_session_start(
         edda_bridge_claude::peers::write_heartbeat_minimal(project_id, session_id, &label, cwd);
     }
 
+    // Ingest the rollout so the hot pack reflects this session's history.
+    edda_bridge_claude::ingest_and_build_pack(
+        project_id,
+        session_id,
+        &envelope.transcript_path,
+        cwd,
+    );
+
     let mut body_parts: Vec<String> = Vec::new();
 
     // 1. Doctrine pack (havamal contract) — placed first, budget-capped.
@@ -257,7 +265,13 @@ fn dispatch_post_tool_use(
 
 // ── PreCompact / SessionEnd ──
 
-fn dispatch_pre_compact(project_id: &str) -> anyhow::Result<HookResult> {
+fn dispatch_pre_compact(project_id: &str, envelope: &CodexEnvelope) -> anyhow::Result<HookResult> {
+    edda_bridge_claude::ingest_and_build_pack(
+        project_id,
+        &envelope.session_id,
+        &envelope.transcript_path,
+        &envelope.cwd,
+    );
     state::set_compact_pending(project_id);
     Ok(ok())
 }
@@ -265,6 +279,12 @@ fn dispatch_pre_compact(project_id: &str) -> anyhow::Result<HookResult> {
 fn dispatch_session_end(project_id: &str, envelope: &CodexEnvelope) -> anyhow::Result<HookResult> {
     let cwd = &envelope.cwd;
     let session_id = &envelope.session_id;
+    edda_bridge_claude::ingest_and_build_pack(
+        project_id,
+        session_id,
+        &envelope.transcript_path,
+        cwd,
+    );
     if !session_id.is_empty() {
         let _ =
             edda_bridge_claude::digest::digest_session_manual(project_id, session_id, cwd, true);
@@ -359,6 +379,38 @@ mod tests {
         std::env::remove_var("EDDA_BRIDGE_AUTO_DIGEST");
     }
 
+    #[test]
+    fn session_start_ingests_codex_rollout_into_hot_pack() {
+        std::env::set_var("EDDA_BRIDGE_AUTO_DIGEST", "0");
+        let tmp = tempfile::tempdir().unwrap();
+        let rollout = tmp.path().join("rollout.jsonl");
+        std::fs::write(
+            &rollout,
+            [
+                r#"{"timestamp":"2025-09-01T10:00:00Z","type":"session_meta","payload":{"id":"codex-ss-3"}}"#,
+                r#"{"timestamp":"2025-09-01T10:00:01Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"migrate the billing parser"}]}}"#,
+                r#"{"timestamp":"2025-09-01T10:00:05Z","type":"response_item","payload":{"type":"message","role":"assistant","content":[{"type":"output_text","text":"Parser migrated."}]}}"#,
+            ]
+            .join("\n")
+                + "\n",
+        )
+        .unwrap();
+        let stdin = serde_json::json!({
+            "hook_event_name": "SessionStart",
+            "session_id": "codex-ss-3",
+            "cwd": tmp.path().to_str().unwrap(),
+            "transcript_path": rollout.to_str().unwrap(),
+        })
+        .to_string();
+        let r = hook_entrypoint_from_stdin(&stdin).unwrap();
+        let v: serde_json::Value = serde_json::from_str(r.stdout.as_ref().unwrap()).unwrap();
+        let ctx = v["hookSpecificOutput"]["additionalContext"]
+            .as_str()
+            .unwrap();
+        assert!(ctx.contains("migrate the billing parser"));
+        std::env::remove_var("EDDA_BRIDGE_AUTO_DIGEST");
+    }
+
     #[test]
     fn pre_tool_use_no_rules_returns_ok() {
         std::env::set_var("EDDA_POSTMORTEM", "1");
@@ -5,11 +5,12 @@
 //!
 //! | Codex event         | edda action                                             |
 //! |---------------------|---------------------------------------------------------|
-//! | SessionStart        | inject doctrine + hot pack + workspace context          |
+//! | SessionStart        | ingest rollout, inject doctrine + hot pack + workspace  |
 //! | UserPromptSubmit    | inject lightweight workspace context (peer diff)        |
 //! | PreToolUse          | evaluate L3 rules against the pending command           |
 //! | PostToolUse         | detect decision signals, emit nudges                    |
-//! | SessionEnd / Stop   | auto-digest, cleanup                                    |
+//! | PreCompact          | ingest rollout + rebuild hot pack                       |
+//! | SessionEnd / Stop   | ingest rollout, auto-digest, cleanup                    |
 //! | SubagentStart/Stop  | write / clear sub-agent heartbeat                       |
 //! | PermissionRequest   | Codex-only: L3 can decline permission at the gate       |
 //!
@@ -3,14 +3,22 @@ use std::path::{Path, PathBuf};
 
 // ── Plugin Content ──
 
-const PLUGIN_PACKAGE_JSON: &str = r#"{
+/// Plugin version written at install time; tracks the edda binary so the
+/// doctor can tell a stale plugin from a current one.
+pub const PLUGIN_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const PLUGIN_PACKAGE_JSON: &str = concat!(
+    r#"{
   "name": "@edda/openclaw-bridge",
-  "version": "0.2.0",
+  "version": ""#,
+    env!("CARGO_PKG_VERSION"),
+    r#"",
   "openclaw": {
     "extensions": [{ "entry": "index.js" }]
   }
 }
-"#;
+"#
+);
 
 const PLUGIN_INDEX_JS: &str = r#"const { execSync } = require("child_process");
 
@@ -172,8 +180,98 @@ pub fn uninstall(target_dir: Option<&Path>) -> anyhow::Result<()> {
 
 // ── Doctor ──
 
+/// What an installed plugin looks like next to the one this binary ships.
+#[derive(Debug, Default)]
+pub struct PluginProbe {
+    /// `version` from the installed `package.json`.
+    pub installed_version: Option<String>,
+    /// The `openclaw.extensions` entry file, if declared and present.
+    pub entry_ok: bool,
+    /// Hooks the installed `index.js` registers via `api.on(...)`.
+    pub registered_hooks: Vec<String>,
+    /// Hooks this binary's plugin registers that the installed one lacks.
+    pub missing_hooks: Vec<String>,
+    /// Registered hooks that `edda hook openclaw` does not dispatch.
+    pub unsupported_hooks: Vec<String>,
+    /// Installed `index.js` is byte-identical to the shipped one.
+    pub content_matches: bool,
+}
+
+impl PluginProbe {
+    /// Whether the installed plugin differs from what `install` would write.
+    pub fn drift(&self) -> bool {
+        self.installed_version.as_deref() != Some(PLUGIN_VERSION)
+            || !self.entry_ok
+            || !self.missing_hooks.is_empty()
+            || !self.unsupported_hooks.is_empty()
+            || !self.content_matches
+    }
+}
+
+/// Inspect an installed plugin directory. Returns `None` if nothing is
+/// installed there.
+pub fn probe_plugin(dir: &Path) -> Option<PluginProbe> {
+    let index_js = fs::read_to_string(dir.join("index.js")).ok();
+    let package: Option<serde_json::Value> = fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok());
+    if index_js.is_none() && package.is_none() {
+        return None;
+    }
+
+    let installed_version = package
+        .as_ref()
+        .and_then(|p| p.get("version"))
+        .and_then(|v| v.as_str())
+        .map(String::from);
+    let entry_ok = package
+        .as_ref()
+        .and_then(|p| p.pointer("/openclaw/extensions/0/entry"))
+        .and_then(|v| v.as_str())
+        .is_some_and(|entry| dir.join(entry).is_file());
+
+    let index_js = index_js.unwrap_or_default();
+    let registered_hooks = hooks_in(&index_js);
+    let missing_hooks = hooks_in(PLUGIN_INDEX_JS)
+        .into_iter()
+        .filter(|h| !registered_hooks.contains(h))
+        .collect();
+    let unsupported_hooks = registered_hooks
+        .iter()
+        .filter(|h| !crate::dispatch::DISPATCHED_HOOKS.contains(&h.as_str()))
+        .cloned()
+        .collect();
+
+    Some(PluginProbe {
+        installed_version,
+        entry_ok,
+        registered_hooks,
+        missing_hooks,
+        unsupported_hooks,
+        content_matches: index_js == PLUGIN_INDEX_JS,
+    })
+}
+
+/// Hook names passed to `api.on("...")`, in registration order.
+fn hooks_in(index_js: &str) -> Vec<String> {
+    index_js
+        .split("api.on(")
+        .skip(1)
+        .filter_map(|rest| {
+            let rest = rest.trim_start();
+            let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+            let name = &rest[1..];
+            name.find(quote).map(|end| name[..end].to_string())
+        })
+        .collect()
+}
+
 /// Check OpenClaw bridge health.
-pub fn doctor() -> anyhow::Result<()> {
+///
+/// Returns `true` when the installed plugin has drifted from the one this
+/// binary ships (version, entry point, or hook surface), so the caller can
+/// offer a reinstall.
+pub fn doctor() -> anyhow::Result<bool> {
     // 1. Check edda in PATH
     let edda_in_path = which_edda();
     println!(
@@ -184,13 +282,10 @@ pub fn doctor() -> anyhow::Result<()> {
 
     // 2. Check plugin files exist
     let plugin_dir = default_plugin_dir();
-    let has_plugin = plugin_dir
-        .as_ref()
-        .map(|d| d.join("index.js").exists())
-        .unwrap_or(false);
+    let probe = plugin_dir.as_deref().and_then(probe_plugin);
     println!(
         "[{}] plugin installed: {}",
-        if has_plugin { "OK" } else { "WARN" },
+        if probe.is_some() { "OK" } else { "WARN" },
         plugin_dir
             .as_ref()
             .map(|d| d.display().to_string())
@@ -205,7 +300,47 @@ pub fn doctor() -> anyhow::Result<()> {
         root.display()
     );
 
-    Ok(())
+    let Some(probe) = probe else {
+        return Ok(false);
+    };
+    report_probe(&probe);
+    Ok(probe.drift())
+}
+
+fn report_probe(probe: &PluginProbe) {
+    let version_ok = probe.installed_version.as_deref() == Some(PLUGIN_VERSION);
+    println!(
+        "[{}] plugin version: {} (expected {PLUGIN_VERSION})",
+        if version_ok { "OK" } else { "WARN" },
+        probe.installed_version.as_deref().unwrap_or("unknown")
+    );
+    println!(
+        "[{}] extension entry: {}",
+        if probe.entry_ok { "OK" } else { "FAIL" },
+        if probe.entry_ok {
+            "declared and present"
+        } else {
+            "missing from package.json or on disk"
+        }
+    );
+    let surface_ok = probe.missing_hooks.is_empty() && probe.unsupported_hooks.is_empty();
+    let mut detail = format!("{} hooks registered", probe.registered_hooks.len());
+    if !probe.missing_hooks.is_empty() {
+        detail.push_str(&format!("; missing: {}", probe.missing_hooks.join(", ")));
+    }
+    if !probe.unsupported_hooks.is_empty() {
+        detail.push_str(&format!(
+            "; not handled by this edda: {}",
+            probe.unsupported_hooks.join(", ")
+        ));
+    }
+    println!(
+        "[{}] hook API surface: {detail}",
+        if surface_ok { "OK" } else { "WARN" }
+    );
+    if surface_ok && version_ok && !probe.content_matches {
+        println!("[WARN] plugin index.js: modified since install");
+    }
 }
 
 fn which_edda() -> Option<String> {
@@ -263,6 +398,46 @@ mod tests {
         assert!(!dir.exists());
     }
 
+    #[test]
+    fn fresh_install_probes_clean() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("edda-bridge");
+        assert!(probe_plugin(&dir).is_none());
+        install(Some(&dir)).unwrap();
+
+        let probe = probe_plugin(&dir).unwrap();
+        assert_eq!(probe.installed_version.as_deref(), Some(PLUGIN_VERSION));
+        assert!(probe.entry_ok);
+        assert!(probe
+            .registered_hooks
+            .contains(&"before_agent_start".to_string()));
+        assert!(!probe.drift(), "{probe:?}");
+    }
+
+    #[test]
+    fn probe_detects_version_and_hook_drift() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("edda-bridge");
+        install(Some(&dir)).unwrap();
+
+        let old_pkg = PLUGIN_PACKAGE_JSON.replace(PLUGIN_VERSION, "0.1.0");
+        fs::write(dir.join("package.json"), old_pkg).unwrap();
+        let old_js = PLUGIN_INDEX_JS
+            .replace(r#"api.on("message_sent""#, r#"api.on("message_received""#)
+            .replace(r#"api.on("session_end""#, r#"api.on('session_finish'"#);
+        fs::write(dir.join("index.js"), old_js).unwrap();
+
+        let probe = probe_plugin(&dir).unwrap();
+        assert_eq!(probe.installed_version.as_deref(), Some("0.1.0"));
+        assert_eq!(probe.missing_hooks, vec!["message_sent", "session_end"]);
+        assert_eq!(probe.unsupported_hooks, vec!["session_finish"]);
+        assert!(probe.drift());
+
+        // Reinstalling resolves the drift.
+        install(Some(&dir)).unwrap();
+        assert!(!probe_plugin(&dir).unwrap().drift());
+    }
+
     #[test]
     fn uninstall_nonexistent_is_ok() {
         let tmp = tempfile::tempdir().unwrap();
@@ -31,6 +31,21 @@ impl HookResult {
 
 // ── Hook dispatch ──
 
+/// Hook names `hook_entrypoint_from_stdin` knows, including the P2 stubs.
+/// The doctor checks an installed plugin's `api.on(...)` calls against it.
+pub(crate) const DISPATCHED_HOOKS: &[&str] = &[
+    "session_start",
+    "before_agent_start",
+    "after_tool_call",
+    "before_compaction",
+    "message_sent",
+    "agent_end",
+    "session_end",
+    "before_tool_call",
+    "after_compaction",
+    "message_received",
+];
+
 /// Main hook entrypoint: parse stdin JSON, dispatch by hook_event_name.
 ///
 /// Returns `HookResult` with optional stdout JSON.
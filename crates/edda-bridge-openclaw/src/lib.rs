@@ -2,5 +2,5 @@ mod admin;
 mod dispatch;
 mod parse;
 
-pub use admin::{doctor, install, uninstall};
+pub use admin::{doctor, install, probe_plugin, uninstall, PluginProbe, PLUGIN_VERSION};
 pub use dispatch::{hook_entrypoint_from_stdin, HookResult};
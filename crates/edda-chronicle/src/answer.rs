@@ -0,0 +1,134 @@
+//! Natural-language answers for `edda ask --answer`: the LLM answers a
+//! question from the sources the structured ask found, citing them by id.
+
+use crate::synthesize::call_llm;
+use serde::Serialize;
+
+/// Most sources put in one prompt.
+const MAX_SOURCES: usize = 40;
+
+/// Characters of each source's text put in the prompt.
+const SOURCE_CHARS: usize = 600;
+
+/// One ledger event (or conversation) the answer may cite.
+#[derive(Debug, Clone)]
+pub struct AnswerSource {
+    /// Event id, or the transcript doc id for conversations.
+    pub id: String,
+    /// "decision" | "commit" | "note" | "conversation"
+    pub kind: String,
+    pub ts: String,
+    pub text: String,
+}
+
+/// An LLM answer and the source ids it cites, in order of first citation.
+#[derive(Debug, Clone, Serialize)]
+pub struct SourcedAnswer {
+    pub answer: String,
+    pub citations: Vec<String>,
+}
+
+/// Ask the LLM to answer `question` from `sources` when `EDDA_LLM_API_KEY`
+/// is set. Returns `None` without a key, without sources, or when the call
+/// fails, so callers can show the structured result instead.
+///
+/// Citations are the `[id]` references in the answer that name a source;
+/// ids the model made up are not reported.
+pub async fn answer_question(question: &str, sources: &[AnswerSource]) -> Option<SourcedAnswer> {
+    let api_key = std::env::var("EDDA_LLM_API_KEY").unwrap_or_default();
+    if api_key.is_empty() || sources.is_empty() {
+        return None;
+    }
+    match call_llm(&api_key, build_answer_prompt(question, sources)).await {
+        Ok(Some(text)) if !text.trim().is_empty() => Some(SourcedAnswer {
+            citations: extract_citations(&text, sources),
+            answer: text.trim().to_string(),
+        }),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::warn!(error = %e, "ask answer: LLM call failed");
+            None
+        }
+    }
+}
+
+fn build_answer_prompt(question: &str, sources: &[AnswerSource]) -> String {
+    let mut prompt = String::from(
+        "You answer questions about a software project from its decision log.\n\
+         Use only the sources below. Cite every claim with the source id in \
+         square brackets, e.g. [evt_123]. If the sources do not answer the \
+         question, say so plainly. Answer in a few sentences.\n\n## Sources\n",
+    );
+    for source in sources.iter().take(MAX_SOURCES) {
+        let text: String = source.text.chars().take(SOURCE_CHARS).collect();
+        prompt.push_str(&format!(
+            "[{}] {} ({}): {}\n",
+            source.id,
+            source.kind,
+            source.ts,
+            text.replace('\n', " ")
+        ));
+    }
+    prompt.push_str(&format!("\n## Question\n{}\n", question.trim()));
+    prompt
+}
+
+/// Source ids cited as `[id]` in `text`, first citation first.
+pub fn extract_citations(text: &str, sources: &[AnswerSource]) -> Vec<String> {
+    let mut cited: Vec<String> = Vec::new();
+    for chunk in text.split('[').skip(1) {
+        let Some((inner, _)) = chunk.split_once(']') else {
+            continue;
+        };
+        for id in inner.split(',').map(str::trim) {
+            if sources.iter().any(|s| s.id == id) && !cited.iter().any(|c| c == id) {
+                cited.push(id.to_string());
+            }
+        }
+    }
+    cited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(id: &str, text: &str) -> AnswerSource {
+        AnswerSource {
+            id: id.into(),
+            kind: "decision".into(),
+            ts: "2026-03-01T00:00:00Z".into(),
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn citations_are_known_ids_in_order() {
+        let sources = [
+            source("evt_a", "cache.backend=memcached"),
+            source("evt_b", "outage"),
+        ];
+        let text = "Redis ran out of memory [evt_b], so the cache moved to memcached \
+                    [evt_a, evt_b]. See also [evt_made_up].";
+        assert_eq!(extract_citations(text, &sources), ["evt_b", "evt_a"]);
+    }
+
+    #[test]
+    fn prompt_lists_sources_and_question() {
+        let prompt = build_answer_prompt(
+            "why did we stop using redis?",
+            &[source("evt_a", "cache.backend=memcached\nredis OOM")],
+        );
+        assert!(prompt.contains(
+            "[evt_a] decision (2026-03-01T00:00:00Z): cache.backend=memcached redis OOM"
+        ));
+        assert!(prompt.ends_with("why did we stop using redis?\n"));
+    }
+
+    #[tokio::test]
+    async fn no_api_key_means_no_answer() {
+        std::env::remove_var("EDDA_LLM_API_KEY");
+        let answer = answer_question("why?", &[source("evt_a", "x")]).await;
+        assert!(answer.is_none());
+    }
+}
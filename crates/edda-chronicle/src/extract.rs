@@ -48,14 +48,21 @@ pub fn extract_key_turns(
     Ok(turns)
 }
 
+/// `IndexRecordV1` serializes its kind as `type`; older fixtures used
+/// `record_type`.
+fn record_type(record: &serde_json::Value) -> &str {
+    record
+        .get("type")
+        .or_else(|| record.get("record_type"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+}
+
 fn extract_coding_turns(records: &[serde_json::Value], max_turns: usize) -> Vec<KeyTurn> {
     let mut turns = Vec::new();
 
     for (idx, record) in records.iter().enumerate() {
-        let record_type = record
-            .get("record_type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let record_type = record_type(record);
         let assistant = record.get("assistant");
 
         if record_type == "assistant" {
@@ -107,10 +114,7 @@ fn extract_research_turns(records: &[serde_json::Value], max_turns: usize) -> Ve
     let mut turns = Vec::new();
 
     for (idx, record) in records.iter().enumerate() {
-        let record_type = record
-            .get("record_type")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let record_type = record_type(record);
         let assistant = record.get("assistant");
 
         if record_type == "assistant" {
@@ -1,8 +1,10 @@
 pub mod anchor;
+pub mod answer;
 pub mod attention;
 pub mod classify;
 pub mod extract;
 pub mod relate;
+pub mod session;
 pub mod state;
 pub mod synthesize;
 
@@ -27,9 +29,13 @@ pub struct RecapOptions {
 }
 
 pub use anchor::{resolve_anchor, Anchor, ResolvedAnchor};
+pub use answer::{answer_question, AnswerSource, SourcedAnswer};
 pub use attention::{get_attention_items, AttentionItem};
 pub use classify::{classify_session, SessionType};
 pub use extract::{extract_key_turns, KeyTurn};
 pub use relate::{find_related_content, RelatedContent};
+pub use session::{
+    gather_session, recap_session, synthesize_session_recap, SessionActivity, SessionRecap,
+};
 pub use state::{load_state, save_state, LastRecap, RecapState};
 pub use synthesize::{synthesize_recap, SynthesisInput, TurnContent};
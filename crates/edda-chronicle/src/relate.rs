@@ -15,7 +15,7 @@ pub fn find_related_content(
     project_root: &std::path::Path,
     _max_results: usize,
 ) -> Result<Vec<RelatedContent>> {
-    let search_dir = project_root.join("search").join("tantivy");
+    let search_dir = edda_store::store_subdir(project_root, "search").join("tantivy");
 
     if !search_dir.exists() {
         return Ok(vec![]);
@@ -29,7 +29,7 @@ pub fn find_related_content(
 }
 
 pub fn build_search_index_if_needed(project_root: &std::path::Path) -> Result<()> {
-    let search_dir = project_root.join("search").join("tantivy");
+    let search_dir = edda_store::store_subdir(project_root, "search").join("tantivy");
 
     if !search_dir.exists() {
         // Index doesn't exist - would need to trigger index build
@@ -0,0 +1,512 @@
+//! Session recap pipeline — run at SessionEnd.
+//!
+//! Gathers one session's turns (from the store index and transcript), the
+//! decisions and commits written to the ledger while it ran, asks the LLM
+//! for a short narrative, and appends it as a `recap` event. Without
+//! `EDDA_LLM_API_KEY`, or when the API call fails, a template recap built
+//! from the same material is written instead.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use edda_core::decision::extract_decision;
+use edda_index::IndexRecordV1;
+use edda_ledger::Ledger;
+use serde::{Deserialize, Serialize};
+
+use crate::classify::{classify_session, SessionType};
+use crate::extract::extract_key_turns;
+use crate::synthesize::{call_llm, TurnContent};
+
+/// Key turns quoted to the LLM.
+const MAX_KEY_TURNS: usize = 8;
+/// Characters kept from each quoted turn.
+const MAX_TURN_CHARS: usize = 400;
+/// Ledger events scanned for the session window.
+const MAX_WINDOW_EVENTS: usize = 500;
+
+/// Everything the recap is written from.
+#[derive(Debug, Clone)]
+pub struct SessionActivity {
+    pub session_id: String,
+    pub session_type: SessionType,
+    pub started_at: Option<String>,
+    pub ended_at: Option<String>,
+    pub turn_count: usize,
+    pub key_turns: Vec<TurnContent>,
+    pub decisions: Vec<String>,
+    pub commits: Vec<String>,
+}
+
+/// Structured summary stored in the `recap` event payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecap {
+    pub what_happened: String,
+    pub decisions: Vec<String>,
+    pub open_threads: Vec<String>,
+    /// `"llm"` or `"template"`.
+    pub source: String,
+}
+
+/// Collect the session's turns from `project_dir` and the decisions and
+/// commits written to the HEAD branch since its first turn.
+pub fn gather_session(
+    ledger: &Ledger,
+    project_dir: &Path,
+    session_id: &str,
+) -> Result<SessionActivity> {
+    let index_path = project_dir
+        .join("index")
+        .join(format!("{session_id}.jsonl"));
+    let records = edda_index::read_index_tail(&index_path, usize::MAX, u64::MAX)?;
+
+    let mut tool_names = Vec::new();
+    let mut bash_commands = Vec::new();
+    for meta in records.iter().filter_map(|r| r.assistant.as_ref()) {
+        tool_names.extend(meta.tool_use_names.iter().cloned());
+        bash_commands.extend(meta.bash_commands.iter().cloned());
+    }
+    let edit_count = tool_names
+        .iter()
+        .filter(|t| matches!(t.as_str(), "Edit" | "Write" | "MultiEdit"))
+        .count();
+    let read_count = tool_names.iter().filter(|t| *t == "Read").count();
+    let turn_count = records.iter().filter(|r| r.record_type == "user").count();
+
+    let started = records.iter().find_map(record_time);
+    let ended = records.iter().rev().find_map(record_time);
+    let duration_secs = match (started, ended) {
+        (Some(s), Some(e)) => (e - s).num_seconds().max(0) as u64,
+        _ => 0,
+    };
+
+    let session_type = classify_session(
+        &tool_names,
+        &bash_commands,
+        edit_count,
+        read_count,
+        turn_count,
+        duration_secs,
+    );
+
+    let store_path =
+        edda_store::store_subdir(project_dir, "transcripts").join(format!("{session_id}.jsonl"));
+    let key_turns = extract_key_turns(session_id, &session_type, project_dir, MAX_KEY_TURNS)?
+        .into_iter()
+        .filter_map(|turn| {
+            let raw = edda_index::fetch_store_line(&store_path, turn.offset, turn.length).ok()?;
+            let content = turn_text(&serde_json::from_slice(&raw).ok()?)?;
+            Some(TurnContent {
+                turn_index: turn.turn_index,
+                content,
+            })
+        })
+        .collect();
+
+    let mut decisions = Vec::new();
+    let mut commits = Vec::new();
+    if let Some(start) = started {
+        // Millisecond precision keeps `ts >= start` correct against ledger
+        // timestamps with or without fractional seconds.
+        let after = start.to_rfc3339_opts(SecondsFormat::Millis, true);
+        let branch = ledger.head_branch()?;
+        let mut events = ledger.iter_events_filtered(
+            &branch,
+            None,
+            None,
+            Some(&after),
+            None,
+            MAX_WINDOW_EVENTS,
+        )?;
+        events.reverse();
+        // No upper bound: ledger writes from the final turn can land after
+        // its transcript record.
+        for event in &events {
+            match event.event_type.as_str() {
+                "commit" => {
+                    if let Some(title) = event.payload.get("title").and_then(|v| v.as_str()) {
+                        commits.push(title.to_string());
+                    }
+                }
+                "note" => {
+                    if let Some(d) = extract_decision(&event.payload) {
+                        decisions.push(format!("{} = {}", d.key, d.value));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(SessionActivity {
+        session_id: session_id.to_string(),
+        session_type,
+        started_at: started.map(|t| t.to_rfc3339()),
+        ended_at: ended.map(|t| t.to_rfc3339()),
+        turn_count,
+        key_turns,
+        decisions,
+        commits,
+    })
+}
+
+/// Ask the LLM for a recap when `EDDA_LLM_API_KEY` is set, falling back to
+/// the template when it is not, the call fails, or the answer is unusable.
+pub async fn synthesize_session_recap(activity: &SessionActivity) -> SessionRecap {
+    let api_key = std::env::var("EDDA_LLM_API_KEY").unwrap_or_default();
+    if !api_key.is_empty() {
+        match call_llm(&api_key, build_session_prompt(activity)).await {
+            Ok(Some(text)) => {
+                if let Some(recap) = parse_session_recap(&text) {
+                    return recap;
+                }
+                tracing::warn!("session recap: unparseable LLM output, using template");
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!(error = %e, "session recap: LLM call failed"),
+        }
+    }
+    template_recap(activity)
+}
+
+/// Gather, synthesize, and append a `recap` event for one session.
+///
+/// Returns the new event id, or `None` when the session has no indexed
+/// turns or already has a recap.
+pub fn recap_session(
+    repo_root: &Path,
+    project_dir: &Path,
+    session_id: &str,
+) -> Result<Option<String>> {
+    let ledger = Ledger::open(repo_root)?;
+    if has_recap(&ledger, session_id)? {
+        return Ok(None);
+    }
+
+    let activity = gather_session(&ledger, project_dir, session_id)?;
+    if activity.turn_count == 0 {
+        return Ok(None);
+    }
+
+    let recap = tokio::runtime::Runtime::new()
+        .context("failed to start runtime for session recap")?
+        .block_on(synthesize_session_recap(&activity));
+
+    let _lock = edda_ledger::lock::WorkspaceLock::acquire(&ledger.paths)?;
+    // Re-check under the lock: a concurrent SessionEnd may have won.
+    if has_recap(&ledger, session_id)? {
+        return Ok(None);
+    }
+    let branch = ledger.head_branch()?;
+    let parent_hash = ledger.last_event_hash()?;
+    let payload = serde_json::json!({
+        "session_id": activity.session_id,
+        "session_type": format!("{:?}", activity.session_type),
+        "started_at": activity.started_at,
+        "ended_at": activity.ended_at,
+        "turns": activity.turn_count,
+        "commits": activity.commits.len(),
+        "what_happened": recap.what_happened,
+        "decisions": recap.decisions,
+        "open_threads": recap.open_threads,
+        "source": recap.source,
+    });
+    let event = edda_core::event::new_recap_event(&branch, parent_hash.as_deref(), payload)?;
+    ledger.append_event(&event)?;
+    tracing::info!(event_id = %event.event_id, session_id, "session recap written");
+    Ok(Some(event.event_id))
+}
+
+fn has_recap(ledger: &Ledger, session_id: &str) -> Result<bool> {
+    Ok(ledger
+        .iter_events_by_type("recap")?
+        .iter()
+        .any(|e| e.payload.get("session_id").and_then(|v| v.as_str()) == Some(session_id)))
+}
+
+fn record_time(record: &IndexRecordV1) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(&record.ts)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Text of a stored transcript line: `message.content` as a string, or its
+/// text blocks, with tool calls reduced to their names.
+fn turn_text(record: &serde_json::Value) -> Option<String> {
+    let content = record.get("message")?.get("content")?;
+    let text = match content {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(blocks) => blocks
+            .iter()
+            .filter_map(|b| match b.get("type").and_then(|t| t.as_str()) {
+                Some("text") => b.get("text").and_then(|t| t.as_str()).map(str::to_string),
+                Some("tool_use") => b
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .map(|n| format!("[{n}]")),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => return None,
+    };
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(if text.len() > MAX_TURN_CHARS {
+        format!("{}...", &text[..text.floor_char_boundary(MAX_TURN_CHARS)])
+    } else {
+        text.to_string()
+    })
+}
+
+fn template_recap(activity: &SessionActivity) -> SessionRecap {
+    let mut what_happened = format!(
+        "{:?} session, {} turn(s)",
+        activity.session_type, activity.turn_count
+    );
+    if activity.commits.is_empty() {
+        what_happened.push_str(", no commits.");
+    } else {
+        what_happened.push_str(&format!(
+            ", {} commit(s): {}.",
+            activity.commits.len(),
+            activity.commits.join("; ")
+        ));
+    }
+
+    let mut open_threads = Vec::new();
+    if activity.commits.is_empty()
+        && matches!(
+            activity.session_type,
+            SessionType::Coding | SessionType::Debugging
+        )
+    {
+        open_threads.push("Code was changed but nothing was committed".to_string());
+    }
+
+    SessionRecap {
+        what_happened,
+        decisions: activity.decisions.clone(),
+        open_threads,
+        source: "template".to_string(),
+    }
+}
+
+fn build_session_prompt(activity: &SessionActivity) -> String {
+    let list = |items: &[String]| {
+        if items.is_empty() {
+            "(none)".to_string()
+        } else {
+            items
+                .iter()
+                .map(|i| format!("- {i}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    };
+    format!(
+        r#"你是開發 session 的記錄員。根據以下資料寫一份 session recap。
+
+## Session
+類型: {:?}，{} 輪對話
+
+## 關鍵段落
+{}
+
+## Commits
+{}
+
+## Decisions
+{}
+
+請輸出（條列，每條一句，簡潔）：
+
+## 發生了什麼
+[1-3 句：最終留下了什麼]
+
+## 決策
+[做了哪些決定、為什麼；沒有就寫「無」]
+
+## 未完成
+[留給下一個 session 的事；沒有就寫「無」]"#,
+        activity.session_type,
+        activity.turn_count,
+        activity
+            .key_turns
+            .iter()
+            .map(|t| format!("Turn {}: {}", t.turn_index, t.content))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        list(&activity.commits),
+        list(&activity.decisions),
+    )
+}
+
+/// Parse the three sections of the recap prompt. `None` when the summary
+/// section is missing, so the caller falls back to the template.
+fn parse_session_recap(text: &str) -> Option<SessionRecap> {
+    let mut what_happened = Vec::new();
+    let mut decisions = Vec::new();
+    let mut open_threads = Vec::new();
+    let mut section = None;
+
+    for line in text.lines().map(str::trim) {
+        if line.starts_with("## 發生了什麼") || line.starts_with("## 发生了什么") {
+            section = Some(0);
+        } else if line.starts_with("## 決策") || line.starts_with("## 决策") {
+            section = Some(1);
+        } else if line.starts_with("## 未完成") {
+            section = Some(2);
+        } else {
+            let item = line.trim_start_matches(['-', '•', '*']).trim();
+            if item.is_empty() || item == "無" || item == "无" {
+                continue;
+            }
+            match section {
+                Some(0) => what_happened.push(item.to_string()),
+                Some(1) => decisions.push(item.to_string()),
+                Some(2) => open_threads.push(item.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    if what_happened.is_empty() {
+        return None;
+    }
+    Some(SessionRecap {
+        what_happened: what_happened.join(" "),
+        decisions,
+        open_threads,
+        source: "llm".to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_commit_event, new_decision_event, CommitEventParams};
+    use edda_core::types::DecisionPayload;
+
+    fn write_session(project_dir: &Path, session_id: &str) {
+        let store = [
+            serde_json::json!({"type": "user", "timestamp": "2026-03-01T10:00:00.000Z",
+                "message": {"content": "switch the cache to redis"}}),
+            serde_json::json!({"type": "assistant", "timestamp": "2026-03-01T10:05:00.000Z",
+            "message": {"content": [
+                {"type": "text", "text": "Replacing the in-memory cache."},
+                {"type": "tool_use", "name": "Edit", "id": "t1", "input": {}},
+                    {"type": "tool_use", "name": "Bash", "id": "t2", "input": {"command": "cargo test"}}
+            ]}}),
+        ];
+        let mut store_text = String::new();
+        let mut index_text = String::new();
+        for record in &store {
+            let line = record.to_string();
+            let rec = edda_index::build_index_record(
+                session_id,
+                store_text.len() as u64,
+                line.len() as u64 + 1,
+                record,
+            );
+            store_text.push_str(&line);
+            store_text.push('\n');
+            index_text.push_str(&serde_json::to_string(&rec).unwrap());
+            index_text.push('\n');
+        }
+        for (dir, text) in [("transcripts", store_text), ("index", index_text)] {
+            std::fs::create_dir_all(project_dir.join(dir)).unwrap();
+            std::fs::write(
+                project_dir.join(dir).join(format!("{session_id}.jsonl")),
+                text,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn gathers_turns_and_ledger_window_then_writes_one_recap() {
+        std::env::remove_var("EDDA_LLM_API_KEY");
+        let repo = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let ledger = Ledger::open_or_init(repo.path()).unwrap();
+        write_session(project.path(), "s1");
+
+        let decision: DecisionPayload =
+            serde_json::from_value(serde_json::json!({"key": "cache.backend", "value": "redis"}))
+                .unwrap();
+        let mut d = new_decision_event("main", None, "agent", &decision).unwrap();
+        d.ts = "2026-03-01T10:03:00Z".into();
+        edda_core::event::finalize_event(&mut d).unwrap();
+        ledger.append_event(&d).unwrap();
+        let mut c = new_commit_event(&mut CommitEventParams {
+            branch: "main",
+            parent_hash: Some(&d.hash),
+            title: "use redis cache",
+            purpose: None,
+            prev_summary: "",
+            contribution: "",
+            evidence: vec![],
+            labels: vec![],
+        })
+        .unwrap();
+        c.ts = "2026-03-01T10:04:00Z".into();
+        edda_core::event::finalize_event(&mut c).unwrap();
+        ledger.append_event(&c).unwrap();
+
+        let activity = gather_session(&ledger, project.path(), "s1").unwrap();
+        assert_eq!(activity.turn_count, 1);
+        assert_eq!(activity.decisions, ["cache.backend = redis"]);
+        assert_eq!(activity.commits, ["use redis cache"]);
+        assert!(activity
+            .key_turns
+            .iter()
+            .any(|t| t.content.contains("Replacing the in-memory cache.\n[Edit]")));
+        drop(ledger);
+
+        let id = recap_session(repo.path(), project.path(), "s1")
+            .unwrap()
+            .expect("recap written");
+        assert_eq!(
+            recap_session(repo.path(), project.path(), "s1").unwrap(),
+            None
+        );
+
+        let ledger = Ledger::open(repo.path()).unwrap();
+        let event = ledger.get_event(&id).unwrap().unwrap();
+        assert_eq!(event.event_type, "recap");
+        assert_eq!(event.payload["session_id"], "s1");
+        assert_eq!(event.payload["source"], "template");
+        assert_eq!(event.payload["decisions"][0], "cache.backend = redis");
+        assert!(event.payload["what_happened"]
+            .as_str()
+            .unwrap()
+            .contains("use redis cache"));
+    }
+
+    #[test]
+    fn session_without_turns_is_skipped() {
+        let repo = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        Ledger::open_or_init(repo.path()).unwrap();
+        assert_eq!(
+            recap_session(repo.path(), project.path(), "missing").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn parses_llm_sections_and_rejects_missing_summary() {
+        let text = "## 發生了什麼\n- 把快取換成 redis。\n\n## 決策\n- cache.backend=redis，因為要跨程序共享\n\n## 未完成\n無";
+        let recap = parse_session_recap(text).unwrap();
+        assert_eq!(recap.what_happened, "把快取換成 redis。");
+        assert_eq!(recap.decisions.len(), 1);
+        assert!(recap.open_threads.is_empty());
+        assert_eq!(recap.source, "llm");
+
+        assert_eq!(parse_session_recap("## 決策\n- x"), None);
+    }
+}
@@ -4,6 +4,8 @@ use anyhow::{Context, Result};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
+const LLM_MODEL: &str = "claude-3-5-haiku-20241022";
+
 #[derive(Debug, Clone)]
 pub struct SynthesisInput {
     pub anchor_description: String,
@@ -54,12 +56,20 @@ pub async fn synthesize_recap(input: SynthesisInput) -> Result<crate::RecapOutpu
 }
 
 async fn synthesize_with_llm(api_key: &str, input: SynthesisInput) -> Result<crate::RecapOutput> {
-    let client = Client::new();
-
     let prompt = build_prompt(&input);
+    match call_llm(api_key, prompt).await? {
+        Some(text) => parse_llm_output(&text),
+        None => synthesize_with_template(input),
+    }
+}
+
+/// Send one prompt to the configured model. Returns `None` when the API
+/// answers with an error status, so callers can fall back to a template.
+pub(crate) async fn call_llm(api_key: &str, prompt: String) -> Result<Option<String>> {
+    let client = Client::new();
 
     let request = AnthropicRequest {
-        model: "claude-3-5-haiku-20241022".to_string(),
+        model: LLM_MODEL.to_string(),
         max_tokens: 1024,
         messages: vec![Message {
             role: "user".to_string(),
@@ -81,7 +91,7 @@ async fn synthesize_with_llm(api_key: &str, input: SynthesisInput) -> Result<cra
         let status = response.status();
         let body = response.text().await.unwrap_or_default();
         tracing::warn!(%status, %body, "LLM API failed");
-        return synthesize_with_template(input);
+        return Ok(None);
     }
 
     let api_response: AnthropicResponse = response
@@ -89,13 +99,13 @@ async fn synthesize_with_llm(api_key: &str, input: SynthesisInput) -> Result<cra
         .await
         .with_context(|| "Failed to parse Anthropic response")?;
 
-    let text = api_response
-        .content
-        .first()
-        .map(|block| block.text.as_str())
-        .unwrap_or("");
-
-    parse_llm_output(text)
+    Ok(Some(
+        api_response
+            .content
+            .first()
+            .map(|block| block.text.clone())
+            .unwrap_or_default(),
+    ))
 }
 
 fn synthesize_with_template(input: SynthesisInput) -> Result<crate::RecapOutput> {
@@ -0,0 +1,494 @@
+//! `edda adr export|sync --dir <dir>` — Architecture Decision Records
+//! generated from the ledger.
+//!
+//! One numbered Markdown file per decision key on the HEAD branch,
+//! `NNNN-<key-slug>.md`, in the Nygard layout: status, the decision, context
+//! from its reason and the notes about it, consequences from the commits
+//! linked to it, and the key's full history. The ledger stays authoritative;
+//! each file starts with a marker naming its key, which is how `sync` finds
+//! the file again, keeps its number, and rewrites it when the key is
+//! re-decided or revoked. Numbers continue after the highest ADR already in
+//! the directory, so hand-written ADRs can live alongside.
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use edda_core::secret_guard::redact;
+use edda_core::Event;
+use edda_ledger::{DecisionView, Ledger};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// ── CLI Schema ──
+
+#[derive(Subcommand)]
+pub enum AdrCmd {
+    /// Write one ADR per decision key into a directory with no edda ADRs yet
+    Export {
+        /// ADR directory
+        #[arg(long, default_value = "docs/adr")]
+        dir: PathBuf,
+    },
+    /// Update exported ADRs from the ledger and add ADRs for new keys
+    Sync {
+        /// ADR directory
+        #[arg(long, default_value = "docs/adr")]
+        dir: PathBuf,
+    },
+}
+
+// ── Dispatch ──
+
+pub fn run(cmd: AdrCmd, repo_root: &Path) -> Result<()> {
+    let (dir, sync) = match cmd {
+        AdrCmd::Export { dir } => (dir, false),
+        AdrCmd::Sync { dir } => (dir, true),
+    };
+    let dir = if dir.is_absolute() {
+        dir
+    } else {
+        repo_root.join(dir)
+    };
+    let report = write_adrs(repo_root, &dir, sync)?;
+    for file in &report.added {
+        println!("added    {file}");
+    }
+    for file in &report.updated {
+        println!("updated  {file}");
+    }
+    println!(
+        "{} ADR(s) in {}: {} added, {} updated, {} unchanged",
+        report.added.len() + report.updated.len() + report.unchanged,
+        dir.display(),
+        report.added.len(),
+        report.updated.len(),
+        report.unchanged
+    );
+    Ok(())
+}
+
+/// First line of every generated ADR, followed by the key.
+const MARKER: &str = "<!-- edda-adr key=";
+const MARKER_END: &str = " — generated by edda from the ledger; record a new decision and run `edda adr sync` instead of editing -->";
+
+/// Generated ADRs by key, as `(number, file name)`.
+type AdrFiles = BTreeMap<String, (u32, String)>;
+
+#[derive(Debug, Default)]
+struct Report {
+    added: Vec<String>,
+    updated: Vec<String>,
+    unchanged: usize,
+}
+
+/// Everything the ledger says about one key.
+struct KeyRecord {
+    key: String,
+    /// Oldest first.
+    timeline: Vec<DecisionView>,
+    /// `(ts, event_id, text)` of notes about the key.
+    notes: Vec<(String, String, String)>,
+    /// `(ts, event_id, title)` of commits linked to the key's decisions.
+    commits: Vec<(String, String, String)>,
+    /// The key whose decision superseded this key's latest one.
+    superseded_by: Option<String>,
+}
+
+impl KeyRecord {
+    fn latest(&self) -> &DecisionView {
+        self.timeline
+            .last()
+            .expect("a key has at least one decision")
+    }
+
+    fn first_ts(&self) -> &str {
+        self.timeline[0].ts.as_deref().unwrap_or("")
+    }
+}
+
+fn write_adrs(repo_root: &Path, dir: &Path, sync: bool) -> Result<Report> {
+    let ledger = Ledger::open(repo_root)?;
+    let records = collect(&ledger)?;
+
+    fs::create_dir_all(dir).with_context(|| format!("create {dir:?}"))?;
+    let (mut files, mut last_number) = scan_dir(dir)?;
+    if !sync && !files.is_empty() {
+        anyhow::bail!(
+            "{} already holds {} edda ADR(s); run `edda adr sync --dir {}` to update them",
+            dir.display(),
+            files.len(),
+            dir.display()
+        );
+    }
+
+    let mut report = Report::default();
+    let mut new_keys = BTreeSet::new();
+    for record in &records {
+        if !files.contains_key(&record.key) {
+            last_number += 1;
+            let file = format!("{last_number:04}-{}.md", slug(&record.key));
+            files.insert(record.key.clone(), (last_number, file));
+            new_keys.insert(record.key.as_str());
+        }
+    }
+
+    for record in &records {
+        let (number, file) = &files[&record.key];
+        let body = render_adr(record, *number, &files);
+        let path = dir.join(file);
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing == body => {
+                report.unchanged += 1;
+                continue;
+            }
+            _ => {}
+        }
+        fs::write(&path, &body).with_context(|| format!("write {path:?}"))?;
+        if new_keys.contains(record.key.as_str()) {
+            report.added.push(file.clone());
+        } else {
+            report.updated.push(file.clone());
+        }
+    }
+    Ok(report)
+}
+
+/// Gather the HEAD branch's decisions by key, with their notes and commits,
+/// ordered by when each key was first decided.
+fn collect(ledger: &Ledger) -> Result<Vec<KeyRecord>> {
+    let branch = ledger.head_branch()?;
+    let mut by_key: BTreeMap<String, Vec<DecisionView>> = BTreeMap::new();
+    for d in ledger.decision_history("", None, None)? {
+        if d.branch == branch {
+            by_key.entry(d.key.clone()).or_default().push(d);
+        }
+    }
+
+    // Decision event id -> key, for linking notes, commits and supersessions.
+    let key_of: BTreeMap<&str, &str> = by_key
+        .iter()
+        .flat_map(|(key, ds)| ds.iter().map(move |d| (d.event_id.as_str(), key.as_str())))
+        .collect();
+    let linked_keys = |event: &Event| -> BTreeSet<String> {
+        event
+            .refs
+            .events
+            .iter()
+            .chain(event.refs.provenance.iter().map(|p| &p.target))
+            .filter_map(|id| key_of.get(id.as_str()).map(|k| k.to_string()))
+            .collect()
+    };
+
+    let mut notes: BTreeMap<String, Vec<(String, String, String)>> = BTreeMap::new();
+    for event in ledger.iter_events_by_type("note")? {
+        if event.branch != branch || edda_core::decision::is_decision(&event.payload) {
+            continue;
+        }
+        let text = event.payload["text"].as_str().unwrap_or("").trim();
+        if text.is_empty() {
+            continue;
+        }
+        let mut keys = linked_keys(&event);
+        let lower = text.to_lowercase();
+        keys.extend(
+            by_key
+                .keys()
+                .filter(|k| lower.contains(&k.to_lowercase()))
+                .cloned(),
+        );
+        for key in keys {
+            notes.entry(key).or_default().push((
+                event.ts.clone(),
+                event.event_id.clone(),
+                text.to_string(),
+            ));
+        }
+    }
+
+    let mut commits: BTreeMap<String, Vec<(String, String, String)>> = BTreeMap::new();
+    for event in ledger.iter_events_by_type("commit")? {
+        let title = event.payload["title"].as_str().unwrap_or("").to_string();
+        for key in linked_keys(&event) {
+            commits.entry(key).or_default().push((
+                event.ts.clone(),
+                event.event_id.clone(),
+                title.clone(),
+            ));
+        }
+    }
+
+    let mut superseded_by: BTreeMap<String, String> = BTreeMap::new();
+    for (key, ds) in &by_key {
+        for d in ds {
+            if let Some(target) = d.supersedes_id.as_deref() {
+                match key_of.get(target) {
+                    Some(other) if *other != key => {
+                        superseded_by.insert(other.to_string(), key.clone());
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let mut records: Vec<KeyRecord> = by_key
+        .into_iter()
+        .map(|(key, timeline)| KeyRecord {
+            notes: notes.remove(&key).unwrap_or_default(),
+            commits: commits.remove(&key).unwrap_or_default(),
+            superseded_by: superseded_by.remove(&key),
+            key,
+            timeline,
+        })
+        .collect();
+    records.sort_by(|a, b| (a.first_ts(), &a.key).cmp(&(b.first_ts(), &b.key)));
+    Ok(records)
+}
+
+/// Generated ADRs in `dir`, and the highest ADR number of any file there,
+/// generated or not.
+fn scan_dir(dir: &Path) -> Result<(AdrFiles, u32)> {
+    let mut files = BTreeMap::new();
+    let mut last = 0;
+    for entry in fs::read_dir(dir).with_context(|| format!("read {dir:?}"))? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !name.ends_with(".md") {
+            continue;
+        }
+        let digits: String = name.chars().take_while(char::is_ascii_digit).collect();
+        let Ok(number) = digits.parse::<u32>() else {
+            continue;
+        };
+        last = last.max(number);
+        let content = fs::read_to_string(entry.path()).unwrap_or_default();
+        let key = content
+            .lines()
+            .next()
+            .and_then(|line| line.strip_prefix(MARKER))
+            .and_then(|rest| rest.split(' ').next());
+        if let Some(key) = key {
+            files.insert(key.to_string(), (number, name));
+        }
+    }
+    Ok((files, last))
+}
+
+/// File name part for a key: `db.engine` -> `db-engine`.
+fn slug(key: &str) -> String {
+    let mut out = String::new();
+    for c in key.to_lowercase().chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else if !out.ends_with('-') {
+            out.push('-');
+        }
+    }
+    out.trim_matches('-').to_string()
+}
+
+fn date(ts: Option<&str>) -> &str {
+    ts.and_then(|t| t.get(..10)).unwrap_or("?")
+}
+
+/// ADR status for a decision's ledger status.
+fn adr_status(status: &str) -> &'static str {
+    match status {
+        "active" => "Accepted",
+        "experimental" => "Accepted (experimental)",
+        "proposed" => "Proposed",
+        "superseded" => "Superseded",
+        "revoked" => "Deprecated (revoked)",
+        _ => "Deprecated",
+    }
+}
+
+fn render_adr(record: &KeyRecord, number: u32, files: &AdrFiles) -> String {
+    let latest = record.latest();
+    let (value, _) = redact(&latest.value);
+    let mut out = String::with_capacity(1024);
+    out.push_str(&format!("{MARKER}{}{MARKER_END}\n", record.key));
+    out.push_str(&format!("# {number}. {}: {value}\n\n", record.key));
+    out.push_str(&format!("Date: {}\n\n", date(latest.ts.as_deref())));
+
+    out.push_str("## Status\n\n");
+    match record
+        .superseded_by
+        .as_ref()
+        .and_then(|k| files.get(k).map(|f| (k, f)))
+    {
+        Some((key, (n, file))) => out.push_str(&format!("Superseded by [{n}. {key}]({file})\n\n")),
+        None => {
+            out.push_str(adr_status(&latest.status));
+            out.push_str("\n\n");
+        }
+    }
+
+    out.push_str("## Context\n\n");
+    let (reason, _) = redact(&latest.reason);
+    if reason.trim().is_empty() {
+        out.push_str("_No reason was recorded._\n\n");
+    } else {
+        out.push_str(reason.trim());
+        out.push_str("\n\n");
+    }
+    for (ts, id, text) in &record.notes {
+        let (text, _) = redact(text);
+        out.push_str(&format!(
+            "- {} — {} (`{id}`)\n",
+            date(Some(ts)),
+            text.replace('\n', " ")
+        ));
+    }
+    if !record.notes.is_empty() {
+        out.push('\n');
+    }
+
+    out.push_str("## Decision\n\n");
+    out.push_str(&format!("`{}` = `{value}`", record.key));
+    if latest.domain.is_empty() {
+        out.push_str("\n\n");
+    } else {
+        out.push_str(&format!(" (domain `{}`)\n\n", latest.domain));
+    }
+    if !latest.affected_paths.is_empty() {
+        let paths: Vec<String> = latest
+            .affected_paths
+            .iter()
+            .map(|p| format!("`{p}`"))
+            .collect();
+        out.push_str(&format!("Affects {}.\n\n", paths.join(", ")));
+    }
+
+    out.push_str("## Consequences\n\n");
+    if record.commits.is_empty() {
+        out.push_str("_No commits linked yet._\n\n");
+    } else {
+        for (ts, id, title) in &record.commits {
+            out.push_str(&format!("- {} — {title} (`{id}`)\n", date(Some(ts))));
+        }
+        out.push('\n');
+    }
+
+    out.push_str("## History\n\n");
+    out.push_str("| Date | Value | Status | Reason | Event |\n");
+    out.push_str("|------|-------|--------|--------|-------|\n");
+    for d in &record.timeline {
+        let (value, _) = redact(&d.value);
+        let (reason, _) = redact(&d.reason);
+        out.push_str(&format!(
+            "| {} | `{}` | {} | {} | `{}` |\n",
+            date(d.ts.as_deref()),
+            value,
+            d.status,
+            reason.replace('|', "\\|").replace('\n', " "),
+            d.event_id
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{
+        new_commit_event, new_decision_event, new_note_event, CommitEventParams,
+    };
+    use edda_core::types::DecisionPayload;
+
+    fn decide(ledger: &Ledger, key: &str, value: &str, reason: &str) -> Event {
+        let dp = DecisionPayload {
+            key: key.into(),
+            value: value.into(),
+            reason: Some(reason.into()),
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let parent = ledger.last_event_hash().unwrap();
+        let event = new_decision_event("main", parent.as_deref(), "system", &dp).unwrap();
+        ledger.append_event(&event).unwrap();
+        event
+    }
+
+    #[test]
+    fn export_then_sync_keeps_numbers_and_follows_the_ledger() {
+        let dir = std::env::temp_dir().join(format!("edda_cmdadr_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Ledger::ensure_initialized(&dir).unwrap();
+        let adr_dir = dir.join("docs/adr");
+        std::fs::create_dir_all(&adr_dir).unwrap();
+        std::fs::write(
+            adr_dir.join("0001-record-architecture.md"),
+            "# 1. Hand-written\n",
+        )
+        .unwrap();
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let d1 = decide(&ledger, "db.engine", "mysql", "familiar");
+        let parent = ledger.last_event_hash().unwrap();
+        let mut commit = new_commit_event(&mut CommitEventParams {
+            branch: "main",
+            parent_hash: parent.as_deref(),
+            title: "add mysql pool",
+            purpose: None,
+            prev_summary: "",
+            contribution: "",
+            evidence: vec![],
+            labels: vec![],
+        })
+        .unwrap();
+        commit.refs.events.push(d1.event_id.clone());
+        edda_core::event::finalize_event(&mut commit).unwrap();
+        ledger.append_event(&commit).unwrap();
+        let parent = ledger.last_event_hash().unwrap();
+        let note = new_note_event(
+            "main",
+            parent.as_deref(),
+            "user",
+            "benchmarked db.engine options",
+            &[],
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+
+        let first = write_adrs(&dir, &adr_dir, false).unwrap();
+        assert_eq!(first.added, vec!["0002-db-engine.md"]);
+        let adr = std::fs::read_to_string(adr_dir.join("0002-db-engine.md")).unwrap();
+        assert!(adr.starts_with("<!-- edda-adr key=db.engine "), "{adr}");
+        assert!(adr.contains("# 2. db.engine: mysql"), "{adr}");
+        assert!(adr.contains("## Status\n\nAccepted"), "{adr}");
+        assert!(adr.contains("add mysql pool"), "{adr}");
+        assert!(adr.contains("benchmarked db.engine options"), "{adr}");
+        assert!(
+            write_adrs(&dir, &adr_dir, false).is_err(),
+            "export refuses to overwrite"
+        );
+
+        decide(&ledger, "db.engine", "postgres", "JSONB");
+        decide(&ledger, "auth.method", "jwt", "stateless");
+        let synced = write_adrs(&dir, &adr_dir, true).unwrap();
+        assert_eq!(synced.updated, vec!["0002-db-engine.md"]);
+        assert_eq!(synced.added, vec!["0003-auth-method.md"]);
+        let adr = std::fs::read_to_string(adr_dir.join("0002-db-engine.md")).unwrap();
+        assert!(adr.contains("# 2. db.engine: postgres"), "{adr}");
+        assert!(adr.contains("| `mysql` | superseded | familiar |"), "{adr}");
+
+        let again = write_adrs(&dir, &adr_dir, true).unwrap();
+        assert_eq!(again.unchanged, 2);
+        assert!(again.added.is_empty() && again.updated.is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn slugs_are_file_name_safe() {
+        assert_eq!(slug("db.engine"), "db-engine");
+        assert_eq!(slug("API/Auth Method"), "api-auth-method");
+    }
+}
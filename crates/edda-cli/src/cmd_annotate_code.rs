@@ -0,0 +1,110 @@
+use edda_bridge_claude::code_annotations::{self, AnnotationExport, HoverEntry};
+use edda_ledger::Ledger;
+use std::path::Path;
+
+/// `edda annotate-code [--json] [--lookup FILE:LINE]`
+///
+/// Without `--lookup`, scans the repo for `edda:decision <key>` comments,
+/// joins them with the active decisions on HEAD and writes the hover export
+/// to `.edda/code_annotations.json`. With `--lookup`, answers from the
+/// existing export (scanning on the fly if there is none) without writing.
+pub fn execute(repo_root: &Path, json: bool, lookup: Option<&str>) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+
+    if let Some(target) = lookup {
+        let (path, line) = code_annotations::parse_location(target)?;
+        let export = match code_annotations::read_export(&ledger.paths.edda_dir)? {
+            Some(export) => export,
+            None => code_annotations::build_export(&ledger, repo_root)?,
+        };
+        let hits = code_annotations::lookup(&export.entries, &path, line);
+        if json {
+            println!("{}", serde_json::to_string_pretty(&hits)?);
+        } else if hits.is_empty() {
+            println!("No decision annotations at {path}:{line}.");
+        } else {
+            for entry in hits {
+                print!("{}", render_hover(entry));
+            }
+        }
+        return Ok(());
+    }
+
+    let export = code_annotations::build_export(&ledger, repo_root)?;
+    let out = code_annotations::write_export(&ledger.paths.edda_dir, &export)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&export)?);
+    } else {
+        print!("{}", render_summary(&export, &out));
+    }
+    Ok(())
+}
+
+fn render_hover(entry: &HoverEntry) -> String {
+    let mut out = format!("{}:{}  {}", entry.path, entry.line, entry.key);
+    match &entry.value {
+        Some(value) => {
+            out.push_str(&format!(" = {value}"));
+            if let Some(status) = &entry.status {
+                out.push_str(&format!(" [{status}]"));
+            }
+            out.push('\n');
+            if let Some(reason) = entry.reason.as_deref().filter(|r| !r.is_empty()) {
+                out.push_str(&format!("  reason: {reason}\n"));
+            }
+            if let Some(event_id) = &entry.event_id {
+                out.push_str(&format!("  event:  {event_id}\n"));
+            }
+        }
+        None => out.push_str("  (no active decision)\n"),
+    }
+    out
+}
+
+fn render_summary(export: &AnnotationExport, out: &Path) -> String {
+    let unresolved: Vec<&HoverEntry> = export.unresolved().collect();
+    let mut s = format!(
+        "{} annotation(s) on {}, {} unresolved\nWrote {}\n",
+        export.entries.len(),
+        export.branch,
+        unresolved.len(),
+        out.display()
+    );
+    if !unresolved.is_empty() {
+        s.push_str("\nNo active decision for:\n");
+        for entry in unresolved {
+            s.push_str(&format!("  {}:{}  {}\n", entry.path, entry.line, entry.key));
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hover_rendering_shows_reason_or_missing_decision() {
+        let mut entry = HoverEntry {
+            path: "src/db.rs".into(),
+            line: 3,
+            key: "db.engine".into(),
+            resolved: true,
+            value: Some("sqlite".into()),
+            reason: Some("embedded".into()),
+            status: Some("active".into()),
+            event_id: Some("evt_1".into()),
+            ts: None,
+        };
+        let text = render_hover(&entry);
+        assert!(text.starts_with("src/db.rs:3  db.engine = sqlite [active]\n"));
+        assert!(text.contains("reason: embedded"));
+
+        entry.resolved = false;
+        entry.value = None;
+        assert_eq!(
+            render_hover(&entry),
+            "src/db.rs:3  db.engine  (no active decision)\n"
+        );
+    }
+}
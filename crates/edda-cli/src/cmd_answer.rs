@@ -0,0 +1,111 @@
+use edda_core::event::new_answer_event;
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::Ledger;
+use std::path::Path;
+
+/// `edda answer <event_id> [text] [--by <event_id>]`
+///
+/// Appends a note tagged `answer` linked to a question note with an
+/// `answers` provenance entry, which drops the question from the open
+/// questions in context and status. `--by` links an existing note or
+/// decision as the answer.
+pub fn execute(
+    repo_root: &Path,
+    event_id: &str,
+    text: Option<&str>,
+    by: Option<&str>,
+) -> anyhow::Result<()> {
+    let text = text.map(str::trim).filter(|t| !t.is_empty());
+    if text.is_none() && by.is_none() {
+        anyhow::bail!("give the answer text or --by <event_id>");
+    }
+
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let Some(question) = ledger.get_event(event_id)? else {
+        anyhow::bail!("no event with id {event_id}");
+    };
+    let is_question = question.event_type == "note"
+        && question
+            .payload
+            .get("tags")
+            .and_then(|t| t.as_array())
+            .is_some_and(|tags| tags.iter().any(|t| t.as_str() == Some("question")));
+    if !is_question {
+        anyhow::bail!("{event_id} is not a note tagged `question`");
+    }
+
+    let by = match by {
+        Some(id) => match ledger.get_event(id)? {
+            Some(ev) if ev.event_type == "note" => Some(ev),
+            Some(ev) => anyhow::bail!(
+                "{id} is a {} event; only notes and decisions can answer",
+                ev.event_type
+            ),
+            None => anyhow::bail!("no event with id {id}"),
+        },
+        None => None,
+    };
+
+    // Answer on the question's branch so its open-questions list sees it.
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_answer_event(
+        &question.branch,
+        parent_hash.as_deref(),
+        &question,
+        text,
+        by.as_ref(),
+    )?;
+    ledger.append_event(&event)?;
+    let _ = edda_derive::rebuild_branch(&ledger, &question.branch);
+
+    println!("Answered {event_id} ({})", event.event_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_closes_question_and_rejects_other_events() {
+        let dir = std::env::temp_dir().join(format!("edda_cmdanswer_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Ledger::ensure_initialized(&dir).unwrap();
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let question = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "which queue?",
+            &["question".to_string()],
+        )
+        .unwrap();
+        ledger.append_event(&question).unwrap();
+        let plain = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "queue: sqs",
+            &[],
+        )
+        .unwrap();
+        ledger.append_event(&plain).unwrap();
+        drop(ledger);
+
+        assert!(execute(&dir, &question.event_id, None, None).is_err());
+        let err = execute(&dir, &plain.event_id, Some("x"), None).unwrap_err();
+        assert!(err.to_string().contains("not a note tagged"));
+        execute(&dir, &question.event_id, None, Some(&plain.event_id)).unwrap();
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let snap = edda_derive::rebuild_branch(&ledger, "main").unwrap();
+        assert_eq!(snap.open_questions().count(), 0);
+        assert_eq!(snap.questions[0].answers.len(), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
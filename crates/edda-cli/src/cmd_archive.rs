@@ -0,0 +1,198 @@
+use crate::cmd_gc::format_size;
+use clap::Subcommand;
+use edda_ledger::cold::{self, ColdLocation};
+use edda_ledger::{Ledger, WorkspaceLock};
+use std::path::Path;
+
+// ── CLI Schema ──
+
+#[derive(Subcommand)]
+pub enum ArchiveCmd {
+    /// Show archive files and whether they are local, cold, or both
+    Status {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Move archived event segments and blobs to cold storage
+    Push {
+        /// Cold location (directory, file:// or s3:// URL); defaults to
+        /// `archive.cold` in .edda/config.json
+        #[arg(long)]
+        to: Option<String>,
+        /// Upload but keep the local copies
+        #[arg(long)]
+        keep_local: bool,
+        /// Show what would move without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Fetch cold files back into the local archive
+    Fetch {
+        /// Archive file names (e.g. events-2025-01.jsonl.zst); all when omitted
+        names: Vec<String>,
+    },
+    /// Remove local copies of files that are safely in cold storage
+    Evict {
+        /// Show what would be removed without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+// ── Dispatch ──
+
+pub fn run(cmd: ArchiveCmd, repo_root: &Path) -> anyhow::Result<()> {
+    match cmd {
+        ArchiveCmd::Status { json } => status(repo_root, json),
+        ArchiveCmd::Push {
+            to,
+            keep_local,
+            dry_run,
+            json,
+        } => push(repo_root, to.as_deref(), keep_local, dry_run, json),
+        ArchiveCmd::Fetch { names } => fetch(repo_root, &names),
+        ArchiveCmd::Evict { dry_run } => evict(repo_root, dry_run),
+    }
+}
+
+// ── Command Implementations ──
+
+/// `edda archive status [--json]`
+fn status(repo_root: &Path, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let files = cold::status(&ledger.paths)?;
+    let location = ColdLocation::from_config(&ledger.paths)?;
+    if json {
+        let out = serde_json::json!({
+            "location": location.map(|l| l.to_string()),
+            "files": files,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    match &location {
+        Some(l) => println!("Cold location: {l}"),
+        None => println!("Cold location: (not set, see `edda config set archive.cold`)"),
+    }
+    if files.is_empty() {
+        println!("Archive is empty.");
+        return Ok(());
+    }
+    let local: u64 = files.iter().filter(|f| f.local).map(|f| f.size).sum();
+    let cold_only: u64 = files
+        .iter()
+        .filter(|f| f.cold && !f.local)
+        .map(|f| f.size)
+        .sum();
+    println!(
+        "{} file(s): {} local, {} cold only",
+        files.len(),
+        format_size(local),
+        format_size(cold_only)
+    );
+    println!();
+    for f in &files {
+        let state = match (f.local, f.cold, f.changed) {
+            (true, true, false) => "local+cold",
+            (true, true, true) => "changed",
+            (true, false, _) => "local",
+            (false, _, _) => "cold",
+        };
+        println!("  {state:<10}  {:>10}  {}", format_size(f.size), f.name);
+    }
+    Ok(())
+}
+
+/// `edda archive push [--to <location>] [--keep-local] [--dry-run] [--json]`
+fn push(
+    repo_root: &Path,
+    to: Option<&str>,
+    keep_local: bool,
+    dry_run: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let location = match to {
+        Some(spec) => ColdLocation::parse(spec)?,
+        None => ColdLocation::from_config(&ledger.paths)?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "no cold location: pass --to or run `edda config set {} <dir|s3://bucket/prefix>`",
+                cold::COLD_LOCATION_KEY
+            )
+        })?,
+    };
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+    let report = cold::push(&ledger.paths, &location, keep_local, dry_run)?;
+
+    if json {
+        let out = serde_json::json!({
+            "location": location.to_string(),
+            "dry_run": dry_run,
+            "report": report,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+    let (push_verb, remove_verb) = if dry_run {
+        ("Would push", "would remove")
+    } else {
+        ("Pushed", "removed")
+    };
+    println!(
+        "{push_verb} {} file(s) ({}) to {location}",
+        report.pushed.len(),
+        format_size(report.pushed_bytes)
+    );
+    for name in &report.pushed {
+        println!("  {name}");
+    }
+    if report.removed_local > 0 {
+        println!(
+            "{remove_verb} {} local file(s), freeing {}",
+            report.removed_local,
+            format_size(report.freed_bytes)
+        );
+    }
+    Ok(())
+}
+
+/// `edda archive fetch [names...]`
+fn fetch(repo_root: &Path, names: &[String]) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let fetched = if names.is_empty() {
+        cold::fetch_all(&ledger.paths)?
+    } else {
+        let mut fetched = Vec::new();
+        for name in names {
+            match cold::fetch(&ledger.paths, name)? {
+                Some(_) => fetched.push(name.clone()),
+                None => anyhow::bail!("{name} is neither in the local archive nor in cold storage"),
+            }
+        }
+        fetched
+    };
+    println!("Fetched {} file(s)", fetched.len());
+    for name in &fetched {
+        println!("  {name}");
+    }
+    Ok(())
+}
+
+/// `edda archive evict [--dry-run]`
+fn evict(repo_root: &Path, dry_run: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+    let report = cold::evict(&ledger.paths, dry_run)?;
+    let verb = if dry_run { "Would remove" } else { "Removed" };
+    println!(
+        "{verb} {} cached file(s), {}",
+        report.files,
+        format_size(report.bytes)
+    );
+    Ok(())
+}
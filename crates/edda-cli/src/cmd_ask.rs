@@ -1,7 +1,8 @@
 use edda_ask::{
-    affected_paths_for_hits, ask, format_human, staleness::annotate_hits, AskOptions,
-    ConversationHit, TranscriptSearchFn,
+    affected_paths_for_hits, ask, ask_keys, ask_question, format_human, format_keys_human,
+    staleness::annotate_hits, AskMode, AskOptions, AskResult, ConversationHit, TranscriptSearchFn,
 };
+use edda_chronicle::{answer_question, AnswerSource, SourcedAnswer};
 use edda_ledger::Ledger;
 use std::path::Path;
 
@@ -15,38 +16,84 @@ pub fn execute(
     all: bool,
     branch: Option<&str>,
     impact: bool,
+    why: bool,
+    history: bool,
+    as_of: Option<&str>,
+    snippet_chars: Option<usize>,
+    sentence_cut: bool,
+    max_chars: Option<usize>,
     fleet: bool,
+    project: Option<&str>,
+    scope: Option<edda_core::types::DecisionScope>,
+    keys: &[String],
+    answer: Option<&str>,
 ) -> anyhow::Result<()> {
     let q = query.unwrap_or("");
 
+    // `--project`: answer from another registered workspace, as if run there.
+    let project_root;
+    let repo_root = match project {
+        Some(p) => {
+            let entry = edda_store::registry::find_project(p)?;
+            project_root = std::path::PathBuf::from(&entry.path);
+            if !project_root.join(".edda").is_dir() {
+                anyhow::bail!(
+                    "project '{}' has no workspace at {} (run `edda projects list --prune`)",
+                    entry.name,
+                    entry.path
+                );
+            }
+            project_root.as_path()
+        }
+        None => repo_root,
+    };
+
     let opts = AskOptions {
         limit,
         include_superseded: all,
         branch: branch.map(|s| s.to_string()),
         impact,
+        as_of: as_of.map(|s| s.to_string()),
+        mode: if why {
+            AskMode::Why
+        } else if history {
+            AskMode::History
+        } else {
+            AskMode::Standard
+        },
+        snippet_chars,
+        sentence_boundary: sentence_cut,
+        max_response_chars: max_chars,
+        scope,
         ..Default::default()
     };
 
     if fleet {
         return execute_fleet(repo_root, q, &opts, json);
     }
+    if let Some(question) = answer {
+        return execute_answer(repo_root, question, &opts, json);
+    }
 
     let ledger = Ledger::open(repo_root)?;
 
+    if !keys.is_empty() {
+        let answers = ask_keys(&ledger, keys, &opts)?;
+        if json {
+            println!("{}", serde_json::to_string_pretty(&answers)?);
+        } else {
+            print!("{}", format_keys_human(&answers));
+        }
+        return Ok(());
+    }
+
     // Build transcript search callback
     let transcript_cb = build_transcript_callback(repo_root, None);
     let transcript_ref: Option<&edda_ask::TranscriptSearchFn> =
         transcript_cb.as_ref().map(|f| f.as_ref());
 
     let mut result = ask(&ledger, q, &opts, transcript_ref)?;
-
-    // EDDA-STALENESS1 q334: annotate decisions whose affected_paths have
-    // shifted since the decision was recorded. Query-time derivation; ledger
-    // untouched. Best-effort: file-system errors leave staleness=None.
-    let decisions_paths = affected_paths_for_hits(&ledger, &result.decisions);
-    annotate_hits(&mut result.decisions, &decisions_paths, Some(repo_root));
-    let timeline_paths = affected_paths_for_hits(&ledger, &result.timeline);
-    annotate_hits(&mut result.timeline, &timeline_paths, Some(repo_root));
+    annotate_staleness(&ledger, &mut result, repo_root);
 
     if json {
         println!("{}", serde_json::to_string_pretty(&result)?);
@@ -66,6 +113,115 @@ pub fn execute(
     Ok(())
 }
 
+/// EDDA-STALENESS1 q334: annotate decisions whose affected_paths have
+/// shifted since the decision was recorded. Query-time derivation; ledger
+/// untouched. Best-effort: file-system errors leave staleness=None.
+fn annotate_staleness(ledger: &Ledger, result: &mut AskResult, repo_root: &Path) {
+    let decisions_paths = affected_paths_for_hits(ledger, &result.decisions);
+    annotate_hits(&mut result.decisions, &decisions_paths, Some(repo_root));
+    let timeline_paths = affected_paths_for_hits(ledger, &result.timeline);
+    annotate_hits(&mut result.timeline, &timeline_paths, Some(repo_root));
+}
+
+/// `edda ask --answer "<question>"` — the structured answer, read back as
+/// prose with event-id citations by the chronicle LLM.
+///
+/// Without `EDDA_LLM_API_KEY`, without hits, or when the LLM call fails, the
+/// structured result is printed instead, so the command always answers.
+fn execute_answer(
+    repo_root: &Path,
+    question: &str,
+    opts: &AskOptions,
+    json: bool,
+) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let transcript_cb = build_transcript_callback(repo_root, None);
+    let transcript_ref: Option<&TranscriptSearchFn> = transcript_cb.as_ref().map(|f| f.as_ref());
+    let mut result = ask_question(&ledger, question, opts, transcript_ref)?;
+    annotate_staleness(&ledger, &mut result, repo_root);
+
+    let sources = answer_sources(&result);
+    let answer = tokio::runtime::Runtime::new()?.block_on(answer_question(question, &sources));
+
+    if json {
+        let out = serde_json::json!({
+            "question": question,
+            "answer": answer,
+            "result": result,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    match answer {
+        Some(answer) => print!("{}", format_answer(&answer, &sources)),
+        None => {
+            if std::env::var("EDDA_LLM_API_KEY").is_ok_and(|k| !k.is_empty()) {
+                eprintln!("No prose answer; showing what ask found.");
+            } else {
+                eprintln!("No LLM configured (set EDDA_LLM_API_KEY); showing what ask found.");
+            }
+            print!("{}", format_human(&result));
+        }
+    }
+    Ok(())
+}
+
+/// The hits of `result` an answer may cite, without revoked ones.
+fn answer_sources(result: &AskResult) -> Vec<AnswerSource> {
+    let mut sources: Vec<AnswerSource> = Vec::new();
+    let mut push = |id: &str, kind: &str, ts: &str, text: String| {
+        if !sources.iter().any(|s| s.id == id) {
+            sources.push(AnswerSource {
+                id: id.to_string(),
+                kind: kind.to_string(),
+                ts: ts.to_string(),
+                text,
+            });
+        }
+    };
+    for d in result.decisions.iter().chain(&result.timeline) {
+        if d.revoked {
+            continue;
+        }
+        let status = if d.is_active { "" } else { " (superseded)" };
+        let text = format!("{} = {}{status}. {}", d.key, d.value, d.reason);
+        push(&d.event_id, "decision", &d.ts, text);
+    }
+    for c in &result.related_commits {
+        push(
+            &c.event_id,
+            "commit",
+            &c.ts,
+            format!("{} {}", c.title, c.purpose),
+        );
+    }
+    for n in result.related_notes.iter().filter(|n| !n.revoked) {
+        push(&n.event_id, "note", &n.ts, n.text.clone());
+    }
+    for c in &result.conversations {
+        push(&c.doc_id, "conversation", &c.ts, c.snippet.clone());
+    }
+    sources
+}
+
+fn format_answer(answer: &SourcedAnswer, sources: &[AnswerSource]) -> String {
+    let mut out = format!("{}\n", answer.answer);
+    if answer.citations.is_empty() {
+        out.push_str("\nSources: none cited\n");
+        return out;
+    }
+    out.push_str("\nSources:\n");
+    for id in &answer.citations {
+        if let Some(s) = sources.iter().find(|s| &s.id == id) {
+            let date = s.ts.get(..10).unwrap_or(&s.ts);
+            let summary = edda_ask::truncate_snippet(&s.text, 80, false);
+            out.push_str(&format!("  [{id}] {} {date}  {summary}\n", s.kind));
+        }
+    }
+    out
+}
+
 /// Everything `format_human` would render as a hit.
 ///
 /// The count has to span every collection, not the obvious two: a project whose
@@ -80,6 +236,8 @@ fn hit_count(r: &edda_ask::AskResult) -> usize {
         + r.conversations.len()
         + r.tasks.len()
         + r.dependents.len()
+        + r.rationale.len()
+        + r.history.len()
 }
 
 /// Ask the rest of the fleet whether a local miss is really absence (GH-407,
@@ -179,9 +337,7 @@ fn build_transcript_callback(
     label: Option<&str>,
 ) -> Option<Box<TranscriptSearchFn>> {
     let project_id = edda_store::project_id(repo_root);
-    let index_dir = edda_store::project_dir(&project_id)
-        .join("search")
-        .join("tantivy");
+    let index_dir = edda_store::project_subdir(&project_id, "search").join("tantivy");
 
     if !index_dir.exists() {
         return None;
@@ -239,6 +395,7 @@ mod tests {
         let mut r = edda_ask::AskResult {
             query: "q".to_string(),
             input_type: "keyword".to_string(),
+            top: vec![],
             decisions: Vec::new(),
             timeline: Vec::new(),
             related_commits: Vec::new(),
@@ -247,6 +404,10 @@ mod tests {
             tasks: Vec::new(),
             dependents: Vec::new(),
             override_risk: None,
+            rationale: Vec::new(),
+            history: Vec::new(),
+            as_of: None,
+            truncated: false,
         };
         assert_eq!(hit_count(&r), 0, "an empty result is empty");
 
@@ -255,6 +416,8 @@ mod tests {
             ts: "2026-07-16T00:00:00Z".to_string(),
             text: "the answer is here".to_string(),
             branch: "main".to_string(),
+            revoked: false,
+            attachments: Vec::new(),
         });
         assert_eq!(
             hit_count(&r),
@@ -276,6 +439,7 @@ mod tests {
         let empty = edda_ask::AskResult {
             query: "q".to_string(),
             input_type: "keyword".to_string(),
+            top: vec![],
             decisions: Vec::new(),
             timeline: Vec::new(),
             related_commits: Vec::new(),
@@ -284,6 +448,10 @@ mod tests {
             tasks: Vec::new(),
             dependents: Vec::new(),
             override_risk: None,
+            rationale: Vec::new(),
+            history: Vec::new(),
+            as_of: None,
+            truncated: false,
         };
 
         assert_eq!(hit_count(&empty), 0, "nothing was found");
@@ -293,4 +461,96 @@ mod tests {
              branch gated on a blank body is dead code"
         );
     }
+
+    #[test]
+    fn ask_project_resolves_through_the_registry() {
+        let _store = crate::test_support::isolated_store();
+        let here = tempfile::tempdir().unwrap();
+        let other = tempfile::tempdir().unwrap();
+        let infra = other.path().join("infra");
+        std::fs::create_dir_all(&infra).unwrap();
+        edda_store::registry::register_project(&infra).unwrap();
+
+        let run = |project: &str| {
+            execute(
+                here.path(),
+                Some("db"),
+                5,
+                true,
+                false,
+                None,
+                false,
+                false,
+                false,
+                None,
+                None,
+                false,
+                None,
+                false,
+                Some(project),
+                None,
+                &[],
+                None,
+            )
+        };
+
+        assert!(run("nope")
+            .unwrap_err()
+            .to_string()
+            .contains("unknown project"));
+        // Registered, but the workspace is not initialized (or was deleted).
+        assert!(run("infra")
+            .unwrap_err()
+            .to_string()
+            .contains("no workspace"));
+
+        Ledger::ensure_initialized(&infra).unwrap();
+        run("infra").unwrap();
+    }
+
+    #[test]
+    fn answer_cites_only_live_sources_with_labels() {
+        let note = |id: &str, text: &str, revoked: bool| edda_ask::NoteHit {
+            event_id: id.to_string(),
+            ts: "2026-07-16T00:00:00Z".to_string(),
+            text: text.to_string(),
+            branch: "main".to_string(),
+            revoked,
+            attachments: Vec::new(),
+        };
+        let r = edda_ask::AskResult {
+            query: "redis".to_string(),
+            input_type: "question".to_string(),
+            top: vec![],
+            decisions: Vec::new(),
+            timeline: Vec::new(),
+            related_commits: Vec::new(),
+            related_notes: vec![
+                note("evt_1", "dropping redis after the outage", false),
+                note("evt_2", "keep redis", true),
+            ],
+            conversations: Vec::new(),
+            tasks: Vec::new(),
+            dependents: Vec::new(),
+            override_risk: None,
+            rationale: Vec::new(),
+            history: Vec::new(),
+            as_of: None,
+            truncated: false,
+        };
+
+        let sources = answer_sources(&r);
+        let ids: Vec<&str> = sources.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["evt_1"], "revoked notes are not sources");
+
+        let answer = SourcedAnswer {
+            answer: "Redis was dropped after an outage [evt_1].".to_string(),
+            citations: vec!["evt_1".to_string()],
+        };
+        assert_eq!(
+            format_answer(&answer, &sources),
+            "Redis was dropped after an outage [evt_1].\n\nSources:\n  \
+             [evt_1] note 2026-07-16  dropping redis after the outage\n"
+        );
+    }
 }
@@ -3,6 +3,8 @@ use clap::Subcommand;
 use edda_ledger::blob_meta::{self, BlobClass};
 use edda_ledger::blob_store::{blob_list, blob_list_archived};
 use edda_ledger::{EddaPaths, Ledger};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 // ── CLI Schema ──
@@ -32,10 +34,27 @@ pub enum BlobCmd {
         /// Blob hash or prefix
         hash: String,
     },
+    /// Write a blob's content to stdout (reassembling chunked blobs)
+    Cat {
+        /// Blob hash or prefix
+        hash: String,
+    },
     /// Show blob store statistics
     Stats,
     /// List tombstones (deleted blob records)
     Tombstones,
+    /// Attribute blob usage and GC-eligible bytes to sessions or labels
+    GcPreview {
+        /// Group by the session whose event first referenced each blob
+        #[arg(long, conflicts_with = "by_label")]
+        by_session: bool,
+        /// Group by session label (agent role)
+        #[arg(long)]
+        by_label: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 // ── Dispatch ──
@@ -46,8 +65,23 @@ pub fn run(cmd: BlobCmd, repo_root: &Path) -> anyhow::Result<()> {
         BlobCmd::Pin { hash } => pin(repo_root, &hash),
         BlobCmd::Unpin { hash } => unpin(repo_root, &hash),
         BlobCmd::Info { hash } => info(repo_root, &hash),
+        BlobCmd::Cat { hash } => cat(repo_root, &hash),
         BlobCmd::Stats => stats(repo_root),
         BlobCmd::Tombstones => tombstones(repo_root),
+        BlobCmd::GcPreview {
+            by_session,
+            by_label,
+            json,
+        } => {
+            let group = if by_label {
+                Grouping::Label
+            } else if by_session {
+                Grouping::Session
+            } else {
+                Grouping::Class
+            };
+            gc_preview(repo_root, group, json)
+        }
     }
 }
 
@@ -129,7 +163,15 @@ pub fn info(repo_root: &Path, hash: &str) -> anyhow::Result<()> {
     };
 
     println!("Hash:     {resolved}");
-    println!("Size:     {} ({} bytes)", format_size(size), size);
+    match edda_ledger::blob_manifest(&paths, &resolved) {
+        Some(m) => println!(
+            "Size:     {} ({} bytes), stored in {} chunk(s)",
+            format_size(m.size),
+            m.size,
+            m.chunks.len()
+        ),
+        None => println!("Size:     {} ({} bytes)", format_size(size), size),
+    }
     println!("Class:    {}", entry.class);
     println!("Pinned:   {}", entry.pinned);
     println!("Location: {location}");
@@ -161,6 +203,19 @@ pub fn info(repo_root: &Path, hash: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `edda blob cat <hash>`
+pub fn cat(repo_root: &Path, hash: &str) -> anyhow::Result<()> {
+    use std::io::Write;
+    let paths = EddaPaths::discover(repo_root);
+    if !paths.is_initialized() {
+        anyhow::bail!("No .edda/ workspace found. Run `edda init` first.");
+    }
+    let resolved = resolve_hash(&paths, hash)?;
+    let bytes = edda_ledger::blob_read(&paths, &format!("blob:sha256:{resolved}"))?;
+    std::io::stdout().write_all(&bytes)?;
+    Ok(())
+}
+
 /// Explain why a blob is retained or eligible for GC.
 fn explain_retention(
     paths: &EddaPaths,
@@ -294,6 +349,23 @@ pub fn stats(repo_root: &Path) -> anyhow::Result<()> {
     );
     println!("  pinned:             {pinned_count:>4}");
 
+    let dedup = edda_ledger::blob_dedup_stats(&paths, &meta_map)?;
+    println!();
+    println!(
+        "Deduplication (active only): {} logical, {} stored, ratio {:.2}x",
+        format_size(dedup.logical_bytes),
+        format_size(dedup.stored_bytes),
+        dedup.ratio()
+    );
+    println!(
+        "  {} chunked blob(s) sharing {} chunk(s)",
+        dedup.chunked_blobs, dedup.chunks
+    );
+    for row in &dedup.by_class {
+        let label = format!("{}:", row.class);
+        println!("  {label:<20}saved {:>8}", format_size(row.saved_bytes()));
+    }
+
     // Show quota usage if configured
     let config_path = &paths.config_json;
     if let Some(quota_mb) = read_config_u32(config_path, "gc.blob_quota_mb") {
@@ -344,10 +416,194 @@ pub fn tombstones(repo_root: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Bucket for blobs no event references, so no session can be credited.
+const UNREFERENCED: &str = "(unreferenced)";
+/// Bucket for blobs referenced only by events that carry no session.
+const UNATTRIBUTED: &str = "(unattributed)";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grouping {
+    Class,
+    Session,
+    Label,
+}
+
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
+struct ClassBytes {
+    artifact: u64,
+    decision_evidence: u64,
+    trace_noise: u64,
+}
+
+impl ClassBytes {
+    fn add(&mut self, class: BlobClass, size: u64) {
+        match class {
+            BlobClass::Artifact => self.artifact += size,
+            BlobClass::DecisionEvidence => self.decision_evidence += size,
+            BlobClass::TraceNoise => self.trace_noise += size,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct UsageRow {
+    key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    blobs: usize,
+    bytes: u64,
+    by_class: ClassBytes,
+    gc_eligible_blobs: usize,
+    gc_eligible_bytes: u64,
+}
+
+/// Who a blob belongs to: the session and label of the earliest event that
+/// references it.
+#[derive(Debug, Clone)]
+struct Owner {
+    session: Option<String>,
+    label: Option<String>,
+}
+
+/// `edda blob gc-preview [--by-session | --by-label] [--json]`
+///
+/// Read-only: shows where active blob bytes come from and how much of each
+/// group `edda gc` would remove at the configured `gc.blob_keep_days`.
+fn gc_preview(repo_root: &Path, group: Grouping, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let project_id = edda_store::project_id(repo_root);
+    let labels: HashMap<String, String> =
+        edda_bridge_claude::peers::discover_all_sessions(&project_id)
+            .into_iter()
+            .filter(|p| !p.label.is_empty())
+            .map(|p| (p.session_id, p.label))
+            .collect();
+    let keep_days = read_config_u32(&ledger.paths.config_json, "gc.blob_keep_days").unwrap_or(90);
+    let rows = blob_usage(&ledger, group, &labels, keep_days)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+    if rows.is_empty() {
+        println!("No active blobs.");
+        return Ok(());
+    }
+    let heading = match group {
+        Grouping::Class => "class",
+        Grouping::Session => "session",
+        Grouping::Label => "label",
+    };
+    println!(
+        "{heading:<24} {:>6} {:>10} {:>10} {:>10} {:>10} {:>10}",
+        "blobs", "total", "artifact", "evidence", "noise", "gc"
+    );
+    for row in &rows {
+        let key = match &row.label {
+            Some(label) => format!("{} ({label})", row.key),
+            None => row.key.clone(),
+        };
+        println!(
+            "{key:<24} {:>6} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            row.blobs,
+            format_size(row.bytes),
+            format_size(row.by_class.artifact),
+            format_size(row.by_class.decision_evidence),
+            format_size(row.by_class.trace_noise),
+            format_size(row.gc_eligible_bytes),
+        );
+    }
+    let eligible: u64 = rows.iter().map(|r| r.gc_eligible_bytes).sum();
+    println!();
+    println!(
+        "GC would remove {} at keep_days={keep_days} (before quota).",
+        format_size(eligible)
+    );
+    Ok(())
+}
+
+fn blob_usage(
+    ledger: &Ledger,
+    group: Grouping,
+    labels: &HashMap<String, String>,
+    keep_days: u32,
+) -> anyhow::Result<Vec<UsageRow>> {
+    let mut owners: HashMap<String, Owner> = HashMap::new();
+    for event in ledger.iter_events()? {
+        let payload_str = |k: &str| {
+            event
+                .payload
+                .get(k)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+        };
+        for blob_ref in &event.refs.blobs {
+            if let Some(hex) = blob_ref.strip_prefix("blob:sha256:") {
+                owners.entry(hex.to_string()).or_insert_with(|| {
+                    let session = payload_str("session_id");
+                    let label = payload_str("label")
+                        .or_else(|| session.as_ref().and_then(|sid| labels.get(sid).cloned()));
+                    Owner { session, label }
+                });
+            }
+        }
+    }
+
+    let meta = blob_meta::load_blob_meta(&ledger.paths.blob_meta_json)?;
+    let cutoff = time::OffsetDateTime::now_utc() - time::Duration::days(i64::from(keep_days));
+    let mut rows: BTreeMap<String, UsageRow> = BTreeMap::new();
+    for blob in blob_list(&ledger.paths)? {
+        let entry = blob_meta::get_meta(&meta, &blob.hash);
+        let owner = owners.get(&blob.hash);
+        let (key, label) = match (group, owner) {
+            (Grouping::Class, _) => (entry.class.to_string(), None),
+            (_, None) => (UNREFERENCED.to_string(), None),
+            (Grouping::Session, Some(o)) => (
+                o.session
+                    .clone()
+                    .unwrap_or_else(|| UNATTRIBUTED.to_string()),
+                o.label.clone(),
+            ),
+            (Grouping::Label, Some(o)) => (
+                o.label.clone().unwrap_or_else(|| UNATTRIBUTED.to_string()),
+                None,
+            ),
+        };
+        // Same rule as `edda gc`: unpinned, not an artifact, unreferenced
+        // and older than the retention window.
+        let expired = ledger
+            .paths
+            .blobs_dir
+            .join(&blob.hash)
+            .metadata()
+            .and_then(|m| m.modified())
+            .is_ok_and(|t| time::OffsetDateTime::from(t) < cutoff);
+        let eligible =
+            !entry.pinned && entry.class != BlobClass::Artifact && owner.is_none() && expired;
+
+        let row = rows.entry(key.clone()).or_insert_with(|| UsageRow {
+            key,
+            label,
+            ..Default::default()
+        });
+        row.blobs += 1;
+        row.bytes += blob.size;
+        row.by_class.add(entry.class, blob.size);
+        if eligible {
+            row.gc_eligible_blobs += 1;
+            row.gc_eligible_bytes += blob.size;
+        }
+    }
+
+    let mut rows: Vec<UsageRow> = rows.into_values().collect();
+    rows.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.key.cmp(&b.key)));
+    Ok(rows)
+}
+
 /// Resolve a hash prefix to a full hash. Errors if ambiguous or not found.
 fn resolve_hash(paths: &EddaPaths, prefix: &str) -> anyhow::Result<String> {
     // Try exact match first
-    if paths.blobs_dir.join(prefix).exists() || paths.archive_blobs_dir.join(prefix).exists() {
+    if paths.blobs_dir.join(prefix).is_file() || paths.archive_blobs_dir.join(prefix).is_file() {
         return Ok(prefix.to_string());
     }
 
@@ -362,6 +618,7 @@ fn resolve_hash(paths: &EddaPaths, prefix: &str) -> anyhow::Result<String> {
                 let name = entry.file_name().to_string_lossy().to_string();
                 if name.starts_with(prefix)
                     && !name.starts_with(".tmp_")
+                    && entry.file_type().is_ok_and(|t| t.is_file())
                     && !matches.contains(&name)
                 {
                     matches.push(name);
@@ -624,6 +881,65 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn gc_preview_attributes_blobs_to_sessions_and_labels() {
+        let (tmp, paths) = setup_workspace();
+        let ledger = Ledger::open(&tmp).unwrap();
+        let noisy = blob_put(&paths, b"a very long tool trace").unwrap();
+        let kept = blob_put(&paths, b"evidence").unwrap();
+        blob_put(&paths, b"orphan").unwrap();
+        classify(
+            &tmp,
+            kept.strip_prefix("blob:sha256:").unwrap(),
+            "decision_evidence",
+        )
+        .unwrap();
+
+        let refs = |blob: &str, payload: serde_json::Value| {
+            let parent = ledger.last_event_hash().unwrap();
+            let mut event =
+                edda_core::event::new_note_event("main", parent.as_deref(), "system", "x", &[])
+                    .unwrap();
+            event.payload = payload;
+            event.refs.blobs.push(blob.to_string());
+            edda_core::event::finalize_event(&mut event).unwrap();
+            ledger.append_event(&event).unwrap();
+        };
+        refs(
+            &noisy,
+            serde_json::json!({"session_id": "s1", "label": "tester"}),
+        );
+        refs(&kept, serde_json::json!({"session_id": "s2"}));
+        // A later reference from another session does not move ownership.
+        refs(&noisy, serde_json::json!({"session_id": "s2"}));
+
+        let labels = HashMap::from([("s2".to_string(), "builder".to_string())]);
+        let rows = blob_usage(&ledger, Grouping::Session, &labels, 0).unwrap();
+        let keys: Vec<_> = rows
+            .iter()
+            .map(|r| (r.key.as_str(), r.label.as_deref()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("s1", Some("tester")),
+                ("s2", Some("builder")),
+                (UNREFERENCED, None)
+            ]
+        );
+        assert_eq!(rows[0].by_class.trace_noise, 22);
+        assert_eq!(rows[1].by_class.decision_evidence, 8);
+        assert_eq!(rows[0].gc_eligible_bytes, 0);
+        // Only the unreferenced blob is eligible at keep_days=0.
+        assert_eq!(rows[2].gc_eligible_blobs, 1);
+
+        let rows = blob_usage(&ledger, Grouping::Label, &labels, 90).unwrap();
+        assert_eq!(rows[0].key, "tester");
+        assert_eq!(rows[2].gc_eligible_bytes, 0);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn info_on_archived_blob() {
         let (tmp, paths) = setup_workspace();
@@ -1,7 +1,7 @@
 use anyhow::Context;
 use clap::Subcommand;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 // ── CLI Schema ──
 
@@ -256,10 +256,29 @@ pub enum DoctorCmd {
     Codex,
     /// Check Hermes bridge health
     Hermes,
-    /// Check OpenClaw bridge health
-    Openclaw,
+    /// Check OpenClaw bridge health (plugin version and hook API drift)
+    Openclaw {
+        /// Reinstall the plugin without asking when drift is detected
+        #[arg(long)]
+        fix: bool,
+    },
     /// Check Cursor bridge health
     Cursor,
+    /// Check ledger.db indexes and query plans for the hot read paths
+    Ledger {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check the whole workspace: hash chain, schema, indexes, blobs, heartbeats
+    Workspace {
+        /// Repair or re-derive what can be fixed
+        #[arg(long)]
+        fix: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -279,6 +298,33 @@ pub enum IndexCmd {
         #[arg(long)]
         all: bool,
     },
+    /// Check every index record against its store file, quarantining bad ones
+    Sweep {
+        /// Project ID
+        #[arg(long)]
+        project: String,
+        /// Only this session (default: every indexed session)
+        #[arg(long)]
+        session: Option<String>,
+        /// Re-index store records no valid index record points at
+        #[arg(long)]
+        rebuild: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Drop index records whose transcript bytes were GC'd or truncated
+    Compact {
+        /// Project ID
+        #[arg(long)]
+        project: String,
+        /// Only this session (default: every indexed session)
+        #[arg(long)]
+        session: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 // ── Dispatch ──
@@ -311,6 +357,8 @@ pub fn run_bridge(cmd: BridgeCmd, repo_root: &Path) -> anyhow::Result<()> {
                 None,
                 &paths,
                 &tags,
+                None,
+                &[],
             ),
             BridgeClaudeCmd::Request {
                 to,
@@ -395,8 +443,10 @@ pub fn run_doctor(cmd: DoctorCmd, repo_root: &Path) -> anyhow::Result<()> {
         DoctorCmd::Claude => doctor(repo_root),
         DoctorCmd::Codex => doctor_codex(),
         DoctorCmd::Hermes => doctor_hermes(),
-        DoctorCmd::Openclaw => doctor_openclaw(),
+        DoctorCmd::Openclaw { fix } => doctor_openclaw(fix),
         DoctorCmd::Cursor => doctor_cursor(),
+        DoctorCmd::Ledger { json } => doctor_ledger(repo_root, json),
+        DoctorCmd::Workspace { fix, json } => crate::cmd_doctor::workspace(repo_root, fix, json),
     }
 }
 
@@ -408,6 +458,17 @@ pub fn run_index(cmd: IndexCmd) -> anyhow::Result<()> {
             sample,
             all,
         } => index_verify(&project, &session, sample, all),
+        IndexCmd::Sweep {
+            project,
+            session,
+            rebuild,
+            json,
+        } => index_sweep(&project, session.as_deref(), rebuild, json),
+        IndexCmd::Compact {
+            project,
+            session,
+            json,
+        } => index_compact(&project, session.as_deref(), json),
     }
 }
 
@@ -649,6 +710,8 @@ pub fn decide(
     scope_str: Option<&str>,
     paths: &[String],
     tags: &[String],
+    actor_name: Option<&str>,
+    attach: &[PathBuf],
 ) -> anyhow::Result<()> {
     let (key, value) = decision.split_once('=').ok_or_else(|| {
         anyhow::anyhow!("decision must be in key=value format (e.g. \"auth.method=JWT RS256\")")
@@ -682,6 +745,45 @@ pub fn decide(
         );
     }
 
+    // Branch protection and append rules: reject before anything is
+    // broadcast or written.
+    let mut ledger = edda_ledger::Ledger::open(repo_root).context("cmd_bridge: opening ledger")?;
+    if actor_name.is_some() {
+        ledger.set_actor(actor_name);
+    }
+    let branch = ledger.head_branch()?;
+    if let Some(denial) = edda_core::policy::check_branch_protection(
+        &ledger.paths.edda_dir,
+        &branch,
+        edda_core::policy::ACTION_DECIDE,
+        actor_name,
+    )? {
+        anyhow::bail!(denial);
+    }
+    let probe = edda_core::types::DecisionPayload {
+        key: key.to_string(),
+        value: value.to_string(),
+        reason: None,
+        scope: None,
+        authority: None,
+        affected_paths: None,
+        tags: None,
+        review_after: None,
+        reversibility: None,
+        village_id: None,
+    };
+    ledger.authorize_append(&edda_core::event::new_decision_event(
+        &branch, None, "system", &probe,
+    )?)?;
+
+    // The project default is stored as no scope at all.
+    let scope = scope_str
+        .map(|s| s.parse::<edda_core::types::DecisionScope>())
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("{e}"))?
+        .filter(|s| *s != edda_core::types::DecisionScope::Local);
+    let narrow = scope.is_some_and(|s| s.is_narrow());
+
     let project_id = edda_store::project_id(repo_root);
     let (session_id, label) = resolve_session_id(cli_session, &project_id, "cli");
 
@@ -704,13 +806,13 @@ pub fn decide(
         );
     }
 
-    // 1. Broadcast to peers (real-time)
-    edda_bridge_claude::peers::write_binding(&project_id, &session_id, &label, key, value);
+    // 1. Broadcast to peers (real-time). A session experiment stays local.
+    if scope != Some(edda_core::types::DecisionScope::Session) {
+        edda_bridge_claude::peers::write_binding(&project_id, &session_id, &label, key, value);
+    }
 
     // 2. Write to workspace ledger (permanent)
-    let ledger = edda_ledger::Ledger::open(repo_root).context("cmd_bridge: opening ledger")?;
     let _lock = edda_ledger::lock::WorkspaceLock::acquire(&ledger.paths)?;
-    let branch = ledger.head_branch()?;
     let parent_hash = ledger.last_event_hash()?;
 
     // Use resolved label as actor (not hardcoded "system")
@@ -727,11 +829,6 @@ pub fn decide(
     } else {
         edda_core::types::authority::AGENT
     };
-    let scope = scope_str
-        .filter(|s| *s != "local")
-        .map(|s| s.parse::<edda_core::types::DecisionScope>())
-        .transpose()
-        .map_err(|e| anyhow::anyhow!("{e}"))?;
     let dp = edda_core::types::DecisionPayload {
         key: key.to_string(),
         value: value.to_string(),
@@ -754,11 +851,25 @@ pub fn decide(
     };
     let mut event =
         edda_core::event::new_decision_event(&branch, parent_hash.as_deref(), actor, &dp)?;
+    let attachments = attach
+        .iter()
+        .map(|f| edda_ledger::blob_put_attachment(&ledger.paths, f))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    edda_core::event::attach_files(&mut event, &attachments)?;
 
     // Check for prior decision with same key → supersede via provenance (only if value differs)
     let prior = ledger.find_active_decision(&branch, key)?;
     if let Some(prior_row) = &prior {
-        if prior_row.value != value {
+        let prior_scope: edda_core::types::DecisionScope =
+            prior_row.propagation.parse().unwrap_or_default();
+        if narrow && prior_scope > scope.unwrap_or_default() {
+            // Layered on top; the wider decision stays active.
+            eprintln!(
+                "  Overrides {prior_scope} decision \"{key}={}\" for this {} only",
+                prior_row.value,
+                scope.unwrap_or_default()
+            );
+        } else if prior_row.value != value {
             eprintln!(
                 "\u{26a0} Conflict: key \"{key}\" previously decided as \"{}\" in this workspace",
                 prior_row.value
@@ -793,7 +904,7 @@ pub fn decide(
 
     // Re-finalize after payload/refs mutation
     edda_core::event::finalize_event(&mut event)?;
-    ledger.append_event(&event)?;
+    ledger.append_event_rebasing(&event)?;
 
     // Insert dependency edges
     let domain = edda_core::decision::extract_domain(key);
@@ -827,6 +938,9 @@ pub fn decide(
     if !tags.is_empty() {
         println!("  tags: {}", tags.join(", "));
     }
+    for a in &attachments {
+        println!("  attached: {} ({})", a.name, a.blob);
+    }
 
     // Refresh derived markdown views (log.md / main.md / commit.md) so operators
     // reading the ledger by eye see the decision immediately, not only after the
@@ -923,7 +1037,7 @@ pub fn request_ack(
 /// 2. `EDDA_SESSION_ID` env var (conductor path, user override)
 /// 3. Heartbeat inference (auto-detect sole active session)
 /// 4. `"cli-{fallback_label}"` (genuine CLI usage)
-fn resolve_session_id(
+pub(crate) fn resolve_session_id(
     cli_session: Option<&str>,
     project_id: &str,
     fallback_label: &str,
@@ -1005,9 +1119,8 @@ pub fn index_verify(
     let index_path = project_dir
         .join("index")
         .join(format!("{session_id}.jsonl"));
-    let store_path = project_dir
-        .join("transcripts")
-        .join(format!("{session_id}.jsonl"));
+    let store_path =
+        edda_store::store_subdir(&project_dir, "transcripts").join(format!("{session_id}.jsonl"));
 
     if !index_path.exists() {
         anyhow::bail!("index file not found: {}", index_path.display());
@@ -1065,6 +1178,150 @@ pub fn index_verify(
     Ok(())
 }
 
+/// `edda index sweep` — verify every index record resolves to its store
+/// record; bad records go to `quarantine/index/<session>.jsonl`.
+pub fn index_sweep(
+    project_id: &str,
+    session_id: Option<&str>,
+    rebuild: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let project_dir = edda_store::project_dir(project_id);
+    let index_dir = project_dir.join("index");
+    if !index_dir.is_dir() {
+        anyhow::bail!("index directory not found: {}", index_dir.display());
+    }
+
+    let sessions: Vec<String> = match session_id {
+        Some(sid) => vec![sid.to_string()],
+        None => {
+            let mut ids: Vec<String> = std::fs::read_dir(&index_dir)?
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("jsonl"))
+                .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(String::from))
+                .collect();
+            ids.sort();
+            ids
+        }
+    };
+
+    let mut results = Vec::new();
+    for sid in &sessions {
+        let index_path = index_dir.join(format!("{sid}.jsonl"));
+        if !index_path.exists() {
+            anyhow::bail!("index file not found: {}", index_path.display());
+        }
+        let store_path = project_dir.join("transcripts").join(format!("{sid}.jsonl"));
+        let quarantine_path = project_dir
+            .join("quarantine")
+            .join("index")
+            .join(format!("{sid}.jsonl"));
+        let report =
+            edda_index::sweep_index(&index_path, &store_path, &quarantine_path, sid, rebuild)?;
+        results.push((sid.clone(), report));
+    }
+
+    if json {
+        let out: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(sid, r)| {
+                serde_json::json!({
+                    "session_id": sid,
+                    "checked": r.checked,
+                    "quarantined": r.quarantined,
+                    "rebuilt": r.rebuilt,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    let mut bad = 0;
+    for (sid, r) in &results {
+        println!(
+            "{sid}: {} checked, {} quarantined, {} rebuilt",
+            r.checked,
+            r.quarantined.len(),
+            r.rebuilt
+        );
+        for q in &r.quarantined {
+            println!("  line {}: {}", q.line, q.problem);
+        }
+        bad += r.quarantined.len();
+    }
+    if bad > 0 {
+        println!(
+            "Quarantined records are in {}",
+            project_dir.join("quarantine").join("index").display()
+        );
+        if !rebuild {
+            println!("Run again with --rebuild to re-index them from the store.");
+        }
+    }
+    Ok(())
+}
+
+/// `edda index compact` — drop index records (and rotated segments) whose
+/// transcript bytes no longer exist.
+pub fn index_compact(project_id: &str, session_id: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let project_dir = edda_store::project_dir(project_id);
+    let index_dir = project_dir.join("index");
+    if !index_dir.is_dir() {
+        anyhow::bail!("index directory not found: {}", index_dir.display());
+    }
+    let sessions = match session_id {
+        Some(sid) => vec![sid.to_string()],
+        None => edda_index::indexed_sessions(&index_dir)?,
+    };
+    let results = compact_sessions(&project_dir, &sessions)?;
+
+    if json {
+        let out: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(sid, r)| {
+                let mut v = serde_json::to_value(r).unwrap_or_default();
+                v["session_id"] = serde_json::json!(sid);
+                v
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    for (sid, r) in &results {
+        if r.removed_index {
+            println!(
+                "{sid}: transcript gone, removed index ({} records)",
+                r.records_dropped
+            );
+        } else {
+            println!(
+                "{sid}: {} records dropped, {} segment(s) removed, {} checked, {} skipped",
+                r.records_dropped, r.segments_removed, r.segments_checked, r.segments_skipped
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Compact the index of each session against its transcript store.
+pub(crate) fn compact_sessions(
+    project_dir: &Path,
+    sessions: &[String],
+) -> anyhow::Result<Vec<(String, edda_index::CompactReport)>> {
+    let index_dir = project_dir.join("index");
+    let mut results = Vec::new();
+    for sid in sessions {
+        let index_path = index_dir.join(format!("{sid}.jsonl"));
+        let store_path = project_dir.join("transcripts").join(format!("{sid}.jsonl"));
+        let report = edda_index::compact_index(&index_path, &store_path)?;
+        results.push((sid.clone(), report));
+    }
+    Ok(results)
+}
+
 // ── Render Commands ──
 
 /// `edda bridge claude render-writeback`
@@ -1355,9 +1612,33 @@ pub fn hook_openclaw() -> anyhow::Result<()> {
     })
 }
 
-/// `edda doctor openclaw`
-pub fn doctor_openclaw() -> anyhow::Result<()> {
-    edda_bridge_openclaw::doctor()
+/// `edda doctor openclaw [--fix]`
+///
+/// On plugin drift, reinstalls with `--fix`, or after confirmation when
+/// attached to a terminal.
+pub fn doctor_openclaw(fix: bool) -> anyhow::Result<()> {
+    if !edda_bridge_openclaw::doctor()? {
+        return Ok(());
+    }
+    let reinstall = fix || {
+        use std::io::{BufRead, IsTerminal, Write};
+        if std::io::stdin().is_terminal() {
+            print!("\nInstalled plugin differs from this edda. Reinstall now? [y/N] ");
+            let _ = std::io::stdout().flush();
+            let mut answer = String::new();
+            let _ = std::io::stdin().lock().read_line(&mut answer);
+            matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+        } else {
+            false
+        }
+    };
+    if reinstall {
+        println!();
+        edda_bridge_openclaw::install(None)
+    } else {
+        println!("\nRun `edda doctor openclaw --fix` to reinstall the plugin.");
+        Ok(())
+    }
 }
 
 /// `edda bridge codex install`
@@ -1441,6 +1722,61 @@ pub fn doctor_cursor() -> anyhow::Result<()> {
     edda_bridge_cursor::doctor()
 }
 
+/// `edda doctor ledger [--json]`
+///
+/// Opening the ledger runs any pending migrations, so the report reflects the
+/// schema as migrated.
+pub fn doctor_ledger(repo_root: &Path, json: bool) -> anyhow::Result<()> {
+    let ledger = edda_ledger::Ledger::open(repo_root)?;
+    let report = ledger.diagnose_queries()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", format_ledger_report(&report));
+    }
+    Ok(())
+}
+
+fn format_ledger_report(report: &edda_ledger::LedgerDiagnostics) -> String {
+    let mut out = format!(
+        "Ledger: schema v{}, {} decision rows\n",
+        report.schema_version, report.decisions
+    );
+    for index in &report.missing_indexes {
+        out.push_str(&format!(
+            "[ERROR] missing-index: {index} (re-open with this edda version to re-run migrations)\n"
+        ));
+    }
+    for q in &report.queries {
+        let tag = if q.full_scan || q.slow { "WARN" } else { "OK" };
+        let mut notes = Vec::new();
+        if q.full_scan {
+            notes.push("full table scan");
+        }
+        if q.slow {
+            notes.push("slow");
+        }
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" — {}", notes.join(", "))
+        };
+        out.push_str(&format!(
+            "[{tag}] {}: {:.1} ms, {} rows{notes}\n",
+            q.name, q.elapsed_ms, q.rows
+        ));
+        if tag != "OK" {
+            for step in &q.plan {
+                out.push_str(&format!("    {step}\n"));
+            }
+        }
+    }
+    if report.is_healthy() {
+        out.push_str("[OK] all indexes present, no full scans\n");
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1471,6 +1807,38 @@ mod tests {
         (tmp, ledger)
     }
 
+    #[test]
+    fn ledger_report_flags_missing_indexes_and_scans() {
+        let report = edda_ledger::LedgerDiagnostics {
+            schema_version: 14,
+            decisions: 3,
+            missing_indexes: vec!["idx_decisions_key".into()],
+            queries: vec![edda_ledger::QueryCheck {
+                name: "decision timeline for key".into(),
+                plan: vec!["SCAN d".into()],
+                full_scan: true,
+                elapsed_ms: 0.4,
+                rows: 2,
+                slow: false,
+            }],
+        };
+        let text = format_ledger_report(&report);
+        assert!(
+            text.starts_with("Ledger: schema v14, 3 decision rows"),
+            "{text}"
+        );
+        assert!(
+            text.contains("[ERROR] missing-index: idx_decisions_key"),
+            "{text}"
+        );
+        assert!(
+            text.contains("[WARN] decision timeline for key: 0.4 ms, 2 rows — full table scan"),
+            "{text}"
+        );
+        assert!(text.contains("    SCAN d"), "{text}");
+        assert!(!text.contains("no full scans"), "{text}");
+    }
+
     #[test]
     fn find_active_decision_returns_value() {
         let (tmp, ledger) = setup_workspace();
@@ -1538,6 +1906,8 @@ mod tests {
             None,
             &[],
             &[],
+            None,
+            &[],
         )
         .unwrap();
 
@@ -1580,6 +1950,8 @@ mod tests {
             None,
             &[],
             &[],
+            None,
+            &[],
         )
         .unwrap();
 
@@ -1608,6 +1980,104 @@ mod tests {
         let _ = std::fs::remove_dir_all(edda_store::project_dir(&pid));
     }
 
+    #[test]
+    fn decide_attaches_files_as_decision_evidence() {
+        let _store = crate::test_support::isolated_store();
+        let _env = env_guard();
+        let (tmp, ledger) = setup_workspace();
+        let pid = edda_store::project_id(&tmp);
+        let _ = edda_store::ensure_dirs(&pid);
+        let diagram = tmp.join("diagram.png");
+        std::fs::write(&diagram, b"png bytes").unwrap();
+
+        decide(
+            &tmp,
+            "ui.layout=two-pane",
+            None,
+            &[],
+            None,
+            None,
+            &[],
+            &[],
+            None,
+            &[diagram],
+        )
+        .unwrap();
+
+        let e = &ledger.iter_events().unwrap()[0];
+        let attachments = edda_core::Attachment::from_payload(&e.payload);
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].name, "diagram.png");
+        assert_eq!(attachments[0].size, 9);
+        assert_eq!(e.refs.blobs, vec![attachments[0].blob.clone()]);
+
+        let hex = attachments[0].blob.strip_prefix("blob:sha256:").unwrap();
+        let meta = edda_ledger::blob_meta::load_blob_meta(&ledger.paths.blob_meta_json).unwrap();
+        assert_eq!(meta[hex].class, edda_ledger::BlobClass::DecisionEvidence);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+        let _ = std::fs::remove_dir_all(edda_store::project_dir(&pid));
+    }
+
+    #[test]
+    fn decide_on_protected_branch_requires_draft_or_override_role() {
+        let _store = crate::test_support::isolated_store();
+        let _env = env_guard();
+        let (tmp, ledger) = setup_workspace();
+        let pid = edda_store::project_id(&tmp);
+        let _ = edda_store::ensure_dirs(&pid);
+        std::fs::write(
+            ledger.paths.edda_dir.join("policy.yaml"),
+            "version: 2\nprotected_branches:\n  - branch: main\n    override_roles: [lead]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            ledger.paths.edda_dir.join("actors.yaml"),
+            "version: 1\nactors:\n  alice:\n    roles: [lead]\n",
+        )
+        .unwrap();
+
+        let err = decide(
+            &tmp,
+            "db.engine=postgres",
+            None,
+            &[],
+            None,
+            None,
+            &[],
+            &[],
+            None,
+            &[],
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("protected"), "{err}");
+        assert!(err.contains("edda draft propose"), "{err}");
+        assert!(ledger.iter_events().unwrap().is_empty());
+        let coord = edda_store::project_dir(&pid)
+            .join("state")
+            .join("coordination.jsonl");
+        assert!(!coord.exists(), "rejected decide must not broadcast");
+
+        decide(
+            &tmp,
+            "db.engine=postgres",
+            None,
+            &[],
+            None,
+            None,
+            &[],
+            &[],
+            Some("alice"),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(ledger.iter_events().unwrap().len(), 1);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+        let _ = std::fs::remove_dir_all(edda_store::project_dir(&pid));
+    }
+
     #[test]
     fn ratify_records_separate_event_and_makes_decision_binding() {
         let _store = crate::test_support::isolated_store();
@@ -1627,6 +2097,8 @@ mod tests {
             None,
             &[],
             &[],
+            None,
+            &[],
         )
         .unwrap();
 
@@ -1689,7 +2161,19 @@ mod tests {
         std::env::set_var("EDDA_SESSION_ID", "test-decide-super-s3");
         std::env::set_var("EDDA_SESSION_LABEL", "infra");
 
-        decide(&tmp, "db.engine=SQLite", None, &[], None, None, &[], &[]).unwrap();
+        decide(
+            &tmp,
+            "db.engine=SQLite",
+            None,
+            &[],
+            None,
+            None,
+            &[],
+            &[],
+            None,
+            &[],
+        )
+        .unwrap();
         decide(
             &tmp,
             "db.engine=PostgreSQL",
@@ -1699,6 +2183,8 @@ mod tests {
             None,
             &[],
             &[],
+            None,
+            &[],
         )
         .unwrap();
 
@@ -1723,6 +2209,59 @@ mod tests {
         let _ = std::fs::remove_dir_all(edda_store::project_dir(&pid));
     }
 
+    #[test]
+    fn decide_session_scope_layers_over_project_decision() {
+        let _store = crate::test_support::isolated_store();
+        let _env = env_guard();
+        let (tmp, ledger) = setup_workspace();
+        let pid = edda_store::project_id(&tmp);
+        let _ = edda_store::ensure_dirs(&pid);
+
+        std::env::set_var("EDDA_SESSION_ID", "test-decide-scope-s1");
+        std::env::set_var("EDDA_SESSION_LABEL", "infra");
+
+        let decide_at = |decision: &str, scope: &str| {
+            decide(
+                &tmp,
+                decision,
+                None,
+                &[],
+                None,
+                Some(scope),
+                &[],
+                &[],
+                None,
+                &[],
+            )
+        };
+        decide_at("db.engine=PostgreSQL", "project").unwrap();
+        decide_at("db.engine=DuckDB", "session").unwrap();
+        assert!(decide_at("db.engine=x", "galaxy").is_err());
+
+        let events = ledger.iter_events().unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(
+            events[1].refs.provenance.is_empty(),
+            "a session decision must not supersede the project one"
+        );
+        assert_eq!(events[1].payload["decision"]["scope"], "session");
+        assert!(events[0].payload["decision"].get("scope").is_none());
+
+        let mut active: Vec<String> = ledger
+            .active_decisions(None, None, None, None)
+            .unwrap()
+            .into_iter()
+            .map(|d| format!("{}@{}", d.value, d.propagation))
+            .collect();
+        active.sort();
+        assert_eq!(active, ["DuckDB@session", "PostgreSQL@local"]);
+
+        std::env::remove_var("EDDA_SESSION_ID");
+        std::env::remove_var("EDDA_SESSION_LABEL");
+        let _ = std::fs::remove_dir_all(&tmp);
+        let _ = std::fs::remove_dir_all(edda_store::project_dir(&pid));
+    }
+
     // ── Integration: resolve_session_id 4-tier fallback (Issue #148 Gap 4) ──
 
     #[test]
@@ -31,13 +31,27 @@ pub struct CommitCliParams<'a> {
     pub auto: bool,
     pub dry_run: bool,
     pub max_evidence: usize,
+    pub actor: Option<&'a str>,
 }
 
 pub fn execute(p: CommitCliParams<'_>) -> anyhow::Result<()> {
-    let ledger = Ledger::open(p.repo_root)?;
+    let mut ledger = Ledger::open(p.repo_root)?;
+    if p.actor.is_some() {
+        ledger.set_actor(p.actor);
+    }
     let _lock = WorkspaceLock::acquire(&ledger.paths)?;
 
     let branch = ledger.head_branch()?;
+    if !p.dry_run {
+        if let Some(denial) = edda_core::policy::check_branch_protection(
+            &ledger.paths.edda_dir,
+            &branch,
+            edda_core::policy::ACTION_COMMIT,
+            p.actor,
+        )? {
+            anyhow::bail!(denial);
+        }
+    }
 
     // Parse manual evidence
     let manual_evidence: Vec<serde_json::Value> = p
@@ -0,0 +1,267 @@
+//! `edda completion-context` — one compact JSON object for other tools'
+//! prompt builders.
+//!
+//! Scripts and agents edda has no bridge for can embed this instead of
+//! scraping `edda context`. The shape is a stable contract, tagged with
+//! [`SCHEMA`]: fields are only ever added, and a breaking change gets a new
+//! schema tag. Output never exceeds `--max-bytes`; the oldest items are
+//! dropped first and counted in `omitted`.
+
+use edda_ledger::tasks::TaskStatus;
+use edda_ledger::Ledger;
+use serde::Serialize;
+use std::path::Path;
+
+/// Schema tag of the output object.
+pub const SCHEMA: &str = "edda.completion_context.v1";
+
+/// Longest `reason` or title kept, in characters.
+const TEXT_CHARS: usize = 160;
+
+#[derive(Debug, Serialize)]
+pub struct CompletionContext {
+    pub schema: &'static str,
+    pub branch: String,
+    /// Active decisions on the branch, newest first.
+    pub decisions: Vec<ContextDecision>,
+    /// Tasks that are blocked, ready or running, by id.
+    pub tasks: Vec<ContextTask>,
+    /// Scope claims on the coordination board.
+    pub claims: Vec<ContextClaim>,
+    pub last_commit: Option<ContextCommit>,
+    /// Items dropped to fit the size cap.
+    pub omitted: Omitted,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContextDecision {
+    pub key: String,
+    pub value: String,
+    pub reason: String,
+    pub ts: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContextTask {
+    pub id: u64,
+    pub title: String,
+    pub status: TaskStatus,
+    pub assignee: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContextClaim {
+    pub label: String,
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContextCommit {
+    pub event_id: String,
+    pub title: String,
+    pub ts: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Omitted {
+    pub decisions: usize,
+    pub tasks: usize,
+    pub claims: usize,
+}
+
+pub fn execute(repo_root: &Path, branch: Option<&str>, max_bytes: usize) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let branch = match branch {
+        Some(b) => b.to_string(),
+        None => ledger.head_branch()?,
+    };
+    let project_id = edda_store::project_id(repo_root);
+    let mut ctx = collect(&ledger, &branch, &project_id)?;
+    let json = fit(&mut ctx, max_bytes)?;
+    println!("{json}");
+    Ok(())
+}
+
+fn clip(s: &str) -> String {
+    match s.char_indices().nth(TEXT_CHARS) {
+        Some((i, _)) => format!("{}…", &s[..i]),
+        None => s.to_string(),
+    }
+}
+
+fn collect(ledger: &Ledger, branch: &str, project_id: &str) -> anyhow::Result<CompletionContext> {
+    let mut decisions: Vec<ContextDecision> = ledger
+        .active_decisions(None, None, None, None)?
+        .into_iter()
+        .filter(|d| d.branch == branch)
+        .map(|d| ContextDecision {
+            key: d.key,
+            value: clip(&d.value),
+            reason: clip(&d.reason),
+            ts: d.ts.unwrap_or_default(),
+        })
+        .collect();
+    decisions.sort_by(|a, b| b.ts.cmp(&a.ts).then_with(|| a.key.cmp(&b.key)));
+
+    let tasks = ledger
+        .task_views()?
+        .into_iter()
+        .filter(|t| {
+            matches!(
+                t.status,
+                TaskStatus::Blocked | TaskStatus::Ready | TaskStatus::Running
+            )
+        })
+        .map(|t| ContextTask {
+            id: t.task_id,
+            title: clip(&t.title),
+            status: t.status,
+            assignee: t.assignee,
+        })
+        .collect();
+
+    let claims = edda_bridge_claude::peers::compute_board_state(project_id)
+        .claims
+        .into_iter()
+        .map(|c| ContextClaim {
+            label: c.label,
+            paths: c.paths,
+        })
+        .collect();
+
+    let last_commit = ledger
+        .iter_events_by_type("commit")?
+        .into_iter()
+        .rev()
+        .find(|e| e.branch == branch)
+        .map(|e| ContextCommit {
+            title: clip(e.payload["title"].as_str().unwrap_or("")),
+            event_id: e.event_id,
+            ts: e.ts,
+        });
+
+    Ok(CompletionContext {
+        schema: SCHEMA,
+        branch: branch.to_string(),
+        decisions,
+        tasks,
+        claims,
+        last_commit,
+        omitted: Omitted::default(),
+        truncated: false,
+    })
+}
+
+/// Serialize `ctx` in at most `max_bytes`, dropping the last item of the
+/// longest list until it fits (the oldest decision, the highest task id, the
+/// latest claim). When the lists are empty and it still does not fit, the
+/// last commit goes too; the bare object is printed regardless.
+fn fit(ctx: &mut CompletionContext, max_bytes: usize) -> anyhow::Result<String> {
+    loop {
+        let json = serde_json::to_string(ctx)?;
+        if json.len() <= max_bytes {
+            return Ok(json);
+        }
+        let longest = [ctx.decisions.len(), ctx.tasks.len(), ctx.claims.len()]
+            .into_iter()
+            .max()
+            .unwrap_or(0);
+        if longest == 0 {
+            if ctx.last_commit.take().is_none() {
+                return Ok(json);
+            }
+        } else if ctx.decisions.len() == longest {
+            ctx.decisions.pop();
+            ctx.omitted.decisions += 1;
+        } else if ctx.tasks.len() == longest {
+            ctx.tasks.pop();
+            ctx.omitted.tasks += 1;
+        } else {
+            ctx.claims.pop();
+            ctx.omitted.claims += 1;
+        }
+        ctx.truncated = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_decision_event, new_task_created_event, TaskCreatedParams};
+    use edda_core::types::DecisionPayload;
+
+    fn decide(ledger: &Ledger, key: &str, value: &str) {
+        let dp = DecisionPayload {
+            key: key.into(),
+            value: value.into(),
+            reason: Some("because".into()),
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let parent = ledger.last_event_hash().unwrap();
+        let event = new_decision_event("main", parent.as_deref(), "system", &dp).unwrap();
+        ledger.append_event(&event).unwrap();
+    }
+
+    #[test]
+    fn collects_open_work_and_fits_the_cap() {
+        let _store = crate::test_support::isolated_store();
+        let tmp = tempfile::tempdir().unwrap();
+        Ledger::ensure_initialized(tmp.path()).unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        for i in 0..10 {
+            decide(&ledger, &format!("svc.key{i}"), "on");
+        }
+        let parent = ledger.last_event_hash().unwrap();
+        let task = new_task_created_event(&TaskCreatedParams {
+            branch: "main",
+            parent_hash: parent.as_deref(),
+            task_id: 1,
+            title: "wire the cache",
+            assignee: Some("tester"),
+            agent_kind: None,
+            after: &[],
+            plan_id: None,
+            work_unit_ref: None,
+            brief_ref: None,
+            idempotency_key: None,
+        })
+        .unwrap();
+        ledger.append_event(&task).unwrap();
+        let project_id = edda_store::project_id(tmp.path());
+        edda_bridge_claude::peers::write_claim(&project_id, "s1", "auth", &["src/auth/**".into()]);
+
+        let mut ctx = collect(&ledger, "main", &project_id).unwrap();
+        assert_eq!(ctx.decisions.len(), 10);
+        assert_eq!(ctx.tasks.len(), 1);
+        assert_eq!(ctx.tasks[0].status, TaskStatus::Ready);
+        assert_eq!(ctx.claims[0].label, "auth");
+        assert!(ctx.last_commit.is_none());
+        let full = fit(&mut ctx, usize::MAX).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&full).unwrap();
+        assert_eq!(value["schema"], SCHEMA);
+        assert_eq!(value["truncated"], false);
+
+        let oldest = ctx.decisions.last().unwrap().key.clone();
+        let capped = fit(&mut ctx, 600).unwrap();
+        assert!(capped.len() <= 600, "{}", capped.len());
+        let value: serde_json::Value = serde_json::from_str(&capped).unwrap();
+        assert_eq!(value["truncated"], true);
+        assert!(value["omitted"]["decisions"].as_u64().unwrap() > 0);
+        assert!(!capped.contains(&format!("\"{oldest}\"")));
+        assert_eq!(value["tasks"][0]["id"], 1, "short lists outlast long ones");
+    }
+
+    #[test]
+    fn clip_cuts_on_char_boundaries() {
+        assert_eq!(clip("short"), "short");
+        let long = "é".repeat(TEXT_CHARS + 5);
+        assert_eq!(clip(&long).chars().count(), TEXT_CHARS + 1);
+    }
+}
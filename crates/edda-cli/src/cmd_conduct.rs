@@ -1,10 +1,14 @@
 use anyhow::{bail, Context, Result};
 use clap::Subcommand;
+use edda_conductor::agent::adapter::AgentRouter;
 use edda_conductor::agent::budget::BudgetTracker;
 use edda_conductor::agent::launcher::{phase_session_id, ClaudeCodeLauncher};
 use edda_conductor::check::engine::CheckEngine;
+use edda_conductor::plan::lint::{lint_plan, Severity};
 use edda_conductor::plan::parser::load_plan;
-use edda_conductor::runner::notify::StdoutNotifier;
+use edda_conductor::plan::schema::AgentKind;
+use edda_conductor::runner::notify::Notifier;
+use edda_conductor::runner::resume::prepare_resume;
 use edda_conductor::runner::sequential::{run_plan, RunContext};
 use edda_conductor::state::machine::{PhaseStatus, PlanState, PlanStatus};
 use edda_conductor::state::persist::{load_state, save_state};
@@ -36,6 +40,31 @@ pub enum ConductCmd {
         #[arg(long)]
         tmux: bool,
     },
+    /// Resume an interrupted plan, re-validating phases that already passed
+    Resume {
+        /// Plan name
+        plan_name: String,
+        /// Path to plan.yaml (defaults to the file the plan was started from)
+        #[arg(long)]
+        file: Option<String>,
+        /// Working directory the plan ran in (defaults to the repo root)
+        #[arg(long)]
+        cwd: Option<String>,
+        /// Suppress live agent activity output
+        #[arg(short, long)]
+        quiet: bool,
+        /// Output events as JSONL to stdout (for machine consumption)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Check a plan for mistakes without running it
+    Lint {
+        /// Path to plan.yaml
+        plan_file: String,
+        /// Output diagnostics as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Show status of running/completed plans
     Status {
         /// Plan name (auto-detects if only one)
@@ -89,6 +118,21 @@ pub fn run_cmd(cmd: ConductCmd, repo_root: &Path) -> Result<()> {
             json,
             tmux,
         ),
+        ConductCmd::Resume {
+            plan_name,
+            file,
+            cwd,
+            quiet,
+            json,
+        } => resume(
+            repo_root,
+            &plan_name,
+            file.as_deref().map(Path::new),
+            cwd.as_deref().map(Path::new),
+            !quiet,
+            json,
+        ),
+        ConductCmd::Lint { plan_file, json } => lint(Path::new(&plan_file), json),
         ConductCmd::Status { plan_name, json } => status(repo_root, plan_name.as_deref(), json),
         ConductCmd::Retry { phase_id, plan } => retry(repo_root, &phase_id, plan.as_deref()),
         ConductCmd::Skip {
@@ -206,11 +250,17 @@ pub fn run(
         .join(&plan.name)
         .join("transcripts");
 
-    let mut launcher = ClaudeCodeLauncher::new().with_verbose(verbose);
-    launcher.transcript_dir = Some(transcript_dir.clone());
-    launcher.verify_available()?;
+    let mut claude = ClaudeCodeLauncher::new().with_verbose(verbose);
+    claude.transcript_dir = Some(transcript_dir.clone());
+    if plan.phases.iter().any(|p| p.agent == AgentKind::Claude) {
+        claude.verify_available()?;
+    }
+    let launcher = AgentRouter::new(claude);
     let engine = CheckEngine::new(cwd.clone());
-    let notifier = StdoutNotifier;
+    let notifier = EddaNotifier {
+        plan: plan.name.clone(),
+        config: edda_notify::NotifyConfig::load(&edda_ledger::EddaPaths::discover(&cwd)),
+    };
     let mut budget = BudgetTracker::new(plan.budget_usd);
     let cancel = CancellationToken::new();
 
@@ -269,6 +319,104 @@ pub fn run(
     result
 }
 
+/// Execute `edda conduct resume <plan-name>`: settle interrupted phases,
+/// re-run the checks of passed ones, then continue the run.
+pub fn resume(
+    repo_root: &Path,
+    plan_name: &str,
+    plan_file: Option<&Path>,
+    cwd_override: Option<&Path>,
+    verbose: bool,
+    json_events: bool,
+) -> Result<()> {
+    let cwd = cwd_override.unwrap_or(repo_root);
+    let cwd = if cwd.is_relative() {
+        std::env::current_dir()?.join(cwd)
+    } else {
+        cwd.to_path_buf()
+    };
+    let mut state = load_state(&cwd, plan_name)?.ok_or_else(|| {
+        anyhow::anyhow!("no state for plan \"{plan_name}\"; start it with `edda conduct run`")
+    })?;
+
+    let plan_file = match plan_file {
+        Some(p) => p.to_path_buf(),
+        None => resolve_plan_file(&cwd, &state.plan_file)?,
+    };
+    let plan = load_plan(&plan_file)?;
+    if plan.name != plan_name {
+        bail!(
+            "{} defines plan \"{}\", not \"{plan_name}\"",
+            plan_file.display(),
+            plan.name
+        );
+    }
+
+    if !json_events {
+        println!("Re-validating plan \"{plan_name}\"");
+    }
+    let engine = CheckEngine::new(cwd.clone());
+    let rt = tokio::runtime::Runtime::new()?;
+    let report = rt.block_on(prepare_resume(&plan, &mut state, &engine, &cwd))?;
+    drop(rt);
+
+    if !json_events {
+        for id in &report.interrupted {
+            println!("  \u{21BB} {id}: interrupted, will re-run");
+        }
+        for id in &report.kept {
+            println!("  \u{2713} {id}: checks still pass, skipping");
+        }
+        for id in &report.invalidated {
+            println!("  \u{2717} {id}: checks no longer pass, will re-run");
+        }
+    }
+
+    run(&plan_file, Some(&cwd), false, verbose, json_events, false)
+}
+
+/// Execute `edda conduct lint <plan.yaml>`. Fails when the plan has errors;
+/// warnings alone pass.
+pub fn lint(plan_file: &Path, json: bool) -> Result<()> {
+    let yaml = std::fs::read_to_string(plan_file)
+        .with_context(|| format!("reading {}", plan_file.display()))?;
+    let diagnostics = lint_plan(&yaml);
+    let errors = diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "file": plan_file.display().to_string(),
+                "errors": errors,
+                "warnings": warnings,
+                "diagnostics": diagnostics,
+            }))?
+        );
+    } else {
+        for d in &diagnostics {
+            match d.line {
+                Some(line) => println!("{}:{line}: {d}", plan_file.display()),
+                None => println!("{}: {d}", plan_file.display()),
+            }
+        }
+        if diagnostics.is_empty() {
+            println!("{}: ok", plan_file.display());
+        } else {
+            println!("{errors} error(s), {warnings} warning(s)");
+        }
+    }
+
+    if errors > 0 {
+        bail!("plan {} has {errors} error(s)", plan_file.display());
+    }
+    Ok(())
+}
+
 /// Execute `edda conduct status [plan-name]`
 pub fn status(repo_root: &Path, plan_name: Option<&str>, json: bool) -> Result<()> {
     let conductor_dir = repo_root.join(".edda").join("conductor");
@@ -341,33 +489,10 @@ pub fn retry(repo_root: &Path, phase_id: &str, plan_name: Option<&str>) -> Resul
     let mut state = load_state(repo_root, &name)?
         .ok_or_else(|| anyhow::anyhow!("no state for plan \"{name}\""))?;
 
-    let current_status = {
-        let ps = state.get_phase_mut(phase_id)?;
-        if ps.status != PhaseStatus::Failed && ps.status != PhaseStatus::Stale {
-            bail!(
-                "Phase \"{}\" is {:?}, not Failed or Stale. Cannot retry.",
-                phase_id,
-                ps.status
-            );
-        }
-        ps.status
-    };
-
-    edda_conductor::state::machine::transition(
-        &mut state,
-        phase_id,
-        current_status,
-        PhaseStatus::Pending,
-        None,
-    )?;
-
-    // Reset plan status so runner picks up
-    if state.plan_status == PlanStatus::Blocked {
-        state.plan_status = PlanStatus::Running;
-    }
+    state.retry_phase(phase_id)?;
 
     save_state(repo_root, &state)?;
-    println!("Phase \"{phase_id}\" reset to Pending. Run `edda conduct run` to resume.");
+    println!("Phase \"{phase_id}\" reset to Pending. Run `edda conduct resume {name}` to resume.");
     Ok(())
 }
 
@@ -382,25 +507,7 @@ pub fn skip(
     let mut state = load_state(repo_root, &name)?
         .ok_or_else(|| anyhow::anyhow!("no state for plan \"{name}\""))?;
 
-    let ps = state.get_phase_mut(phase_id)?;
-    if ps.status != PhaseStatus::Failed
-        && ps.status != PhaseStatus::Stale
-        && ps.status != PhaseStatus::Pending
-    {
-        bail!(
-            "Phase \"{}\" is {:?}. Can only skip Failed, Stale, or Pending phases.",
-            phase_id,
-            ps.status
-        );
-    }
-
-    ps.status = PhaseStatus::Skipped;
-    ps.skip_reason = Some(reason.unwrap_or("manually skipped").to_string());
-
-    // Unblock plan
-    if state.plan_status == PlanStatus::Blocked {
-        state.plan_status = PlanStatus::Running;
-    }
+    state.skip_phase(phase_id, reason)?;
 
     save_state(repo_root, &state)?;
     println!("Phase \"{phase_id}\" skipped.");
@@ -459,6 +566,27 @@ fn resolve_plan_name(repo_root: &Path, explicit: Option<&str>) -> Result<String>
     }
 }
 
+/// Locate the plan file a run was started from. `state.plan_file` is stored
+/// as given on the command line, so a relative path is tried against the
+/// current directory first, then the plan's working directory.
+fn resolve_plan_file(cwd: &Path, recorded: &str) -> Result<std::path::PathBuf> {
+    if recorded.is_empty() {
+        bail!("plan state does not record its plan file; pass --file <plan.yaml>");
+    }
+    let recorded = Path::new(recorded);
+    let candidates = if recorded.is_absolute() {
+        vec![recorded.to_path_buf()]
+    } else {
+        vec![std::env::current_dir()?.join(recorded), cwd.join(recorded)]
+    };
+    candidates.into_iter().find(|p| p.is_file()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "plan file {} not found; pass --file <plan.yaml>",
+            recorded.display()
+        )
+    })
+}
+
 fn print_status(state: &PlanState) {
     println!("\nPlan: {} ({:?})", state.plan_name, state.plan_status);
     if !state.plan_file.is_empty() {
@@ -497,6 +625,35 @@ fn print_status(state: &PlanState) {
     println!();
 }
 
+/// Prints progress like `StdoutNotifier` and pushes human gates to the
+/// workspace's notify channels, which queue them during quiet hours.
+struct EddaNotifier {
+    plan: String,
+    config: edda_notify::NotifyConfig,
+}
+
+#[async_trait::async_trait]
+impl Notifier for EddaNotifier {
+    async fn notify(&self, message: &str) {
+        println!("[conductor] {message}");
+    }
+
+    async fn notify_gate(&self, message: &str) {
+        self.notify(message).await;
+        edda_notify::dispatch(
+            &self.config,
+            &edda_notify::NotifyEvent::PlanGate {
+                plan: self.plan.clone(),
+                message: message.to_string(),
+            },
+        );
+    }
+
+    fn quiet_remaining(&self) -> Option<std::time::Duration> {
+        self.config.quiet_remaining()
+    }
+}
+
 fn now_rfc3339() -> String {
     time::OffsetDateTime::now_utc()
         .format(&time::format_description::well_known::Rfc3339)
@@ -1,4 +1,6 @@
 use clap::Subcommand;
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::{settings, Ledger};
 use std::path::Path;
 
 // ── CLI Schema ──
@@ -33,28 +35,6 @@ pub fn run(cmd: ConfigCmd, repo_root: &Path) -> anyhow::Result<()> {
 
 // ── Command Implementations ──
 
-/// Read config from `.edda/config.json`. Returns empty map if file doesn't exist.
-fn read_config(path: &Path) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
-    if !path.exists() {
-        return Ok(serde_json::Map::new());
-    }
-    let content = std::fs::read_to_string(path)?;
-    let val: serde_json::Value = serde_json::from_str(&content)?;
-    match val {
-        serde_json::Value::Object(map) => Ok(map),
-        _ => Ok(serde_json::Map::new()),
-    }
-}
-
-/// Write config to `.edda/config.json`.
-fn write_config(
-    path: &Path,
-    config: &serde_json::Map<String, serde_json::Value>,
-) -> anyhow::Result<()> {
-    let json = serde_json::to_string_pretty(&config)?;
-    edda_store::write_atomic(path, json.as_bytes())
-}
-
 /// Parse a string value into an appropriate JSON value (bool/number/string).
 fn parse_value(s: &str) -> serde_json::Value {
     match s {
@@ -73,14 +53,16 @@ fn parse_value(s: &str) -> serde_json::Value {
 }
 
 /// `edda config set <key> <value>`
+///
+/// Records a `config_change` event when the value changes.
 pub fn set(repo_root: &Path, key: &str, value: &str) -> anyhow::Result<()> {
     let paths = edda_ledger::EddaPaths::discover(repo_root);
     if !paths.is_initialized() {
         anyhow::bail!("No .edda/ workspace found. Run `edda init` first.");
     }
-    let mut config = read_config(&paths.config_json)?;
-    config.insert(key.to_string(), parse_value(value));
-    write_config(&paths.config_json, &config)?;
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+    settings::set_config(&ledger, key, Some(parse_value(value)))?;
     println!("{key} = {value}");
     Ok(())
 }
@@ -91,7 +73,7 @@ pub fn get(repo_root: &Path, key: &str) -> anyhow::Result<()> {
     if !paths.is_initialized() {
         anyhow::bail!("No .edda/ workspace found. Run `edda init` first.");
     }
-    let config = read_config(&paths.config_json)?;
+    let config = settings::read_config(&paths.config_json)?;
     match config.get(key) {
         Some(val) => println!("{val}"),
         None => println!("(not set)"),
@@ -105,7 +87,7 @@ pub fn list(repo_root: &Path) -> anyhow::Result<()> {
     if !paths.is_initialized() {
         anyhow::bail!("No .edda/ workspace found. Run `edda init` first.");
     }
-    let config = read_config(&paths.config_json)?;
+    let config = settings::read_config(&paths.config_json)?;
     if config.is_empty() {
         println!("(no config set)");
     } else {
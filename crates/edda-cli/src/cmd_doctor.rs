@@ -0,0 +1,423 @@
+use edda_ledger::blob_meta::{self, BlobClass};
+use edda_ledger::{settings, Ledger};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Heartbeats untouched for this long belong to sessions that ended without
+/// a SessionEnd hook.
+const STALE_HEARTBEAT_SECS: u64 = 24 * 60 * 60;
+
+/// Config changes listed by the `config` check.
+const RECENT_CONFIG_CHANGES: usize = 5;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Ok,
+    Warn,
+    Error,
+    Fixed,
+}
+
+#[derive(Debug, Serialize)]
+struct Check {
+    name: &'static str,
+    status: Status,
+    message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    details: Vec<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            message: message.into(),
+            details: Vec::new(),
+        }
+    }
+}
+
+/// `edda doctor workspace [--fix] [--json]`
+///
+/// Validates the hash chain, the schema against the columns and indexes its
+/// version promises, index offsets against the transcript store, blob
+/// references, and session heartbeats, and lists recent config changes.
+/// With `--fix`, repairs what can be repaired or re-derived. Fails when a
+/// problem remains.
+pub fn workspace(repo_root: &Path, fix: bool, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = if fix {
+        Some(edda_ledger::lock::WorkspaceLock::acquire(&ledger.paths)?)
+    } else {
+        None
+    };
+    let project_id = edda_store::project_id(repo_root);
+    let project_dir = edda_store::project_dir(&project_id);
+
+    let checks = vec![
+        check_chain(&ledger)?,
+        check_schema(&ledger, fix)?,
+        check_index(&project_dir, fix)?,
+        check_blobs(&ledger, fix)?,
+        check_heartbeats(&project_id, fix),
+        check_config(&ledger)?,
+    ];
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&checks)?);
+    } else {
+        print!("{}", render(&checks, fix));
+    }
+
+    let remaining = checks
+        .iter()
+        .filter(|c| matches!(c.status, Status::Warn | Status::Error))
+        .count();
+    if remaining > 0 {
+        anyhow::bail!("{remaining} check(s) need attention");
+    }
+    Ok(())
+}
+
+fn check_chain(ledger: &Ledger) -> anyhow::Result<Check> {
+    let events = ledger.iter_events()?.len();
+    Ok(match ledger.verify_chain() {
+        Ok(()) => Check::new("chain", Status::Ok, format!("{events} event(s) verified")),
+        Err(e) => {
+            let mut check = Check::new(
+                "chain",
+                Status::Error,
+                "hash chain is broken (cannot be repaired automatically)",
+            );
+            check.details.push(format!("{e:#}"));
+            check
+        }
+    })
+}
+
+fn check_schema(ledger: &Ledger, fix: bool) -> anyhow::Result<Check> {
+    let version = ledger.schema_version()?;
+    let drift = ledger.schema_drift()?;
+    if drift.is_empty() {
+        return Ok(Check::new(
+            "schema",
+            Status::Ok,
+            format!("v{version}, every column and index present"),
+        ));
+    }
+    let details = drift
+        .iter()
+        .map(|d| {
+            format!(
+                "v{} ({}): missing {}",
+                d.version,
+                d.migration,
+                d.missing.join(", ")
+            )
+        })
+        .collect();
+    let mut check = if fix {
+        let repaired = ledger.repair_schema()?;
+        Check::new(
+            "schema",
+            Status::Fixed,
+            format!(
+                "v{version}: re-applied {} migration step(s)",
+                repaired.len()
+            ),
+        )
+    } else {
+        Check::new(
+            "schema",
+            Status::Error,
+            format!("v{version} but {} step(s) are missing pieces", drift.len()),
+        )
+    };
+    check.details = details;
+    Ok(check)
+}
+
+fn check_index(project_dir: &Path, fix: bool) -> anyhow::Result<Check> {
+    let index_dir = project_dir.join("index");
+    let sessions = edda_index::indexed_sessions(&index_dir)?;
+
+    let mut checked = 0;
+    let mut details = Vec::new();
+    let mut bad_sessions = Vec::new();
+    let mut orphaned = Vec::new();
+    for sid in &sessions {
+        let index_path = index_dir.join(format!("{sid}.jsonl"));
+        let store_path = project_dir.join("transcripts").join(format!("{sid}.jsonl"));
+        if !store_path.exists() {
+            details.push(format!("{sid}: transcript is gone"));
+            orphaned.push(sid.clone());
+            continue;
+        }
+        if !index_path.exists() {
+            continue;
+        }
+        let report = edda_index::verify_index(&index_path, &store_path)?;
+        checked += report.checked;
+        if !report.quarantined.is_empty() {
+            details.push(format!(
+                "{sid}: {} of {} record(s) do not resolve",
+                report.quarantined.len(),
+                report.checked
+            ));
+            bad_sessions.push(sid.clone());
+        }
+    }
+
+    if bad_sessions.is_empty() && orphaned.is_empty() {
+        return Ok(Check::new(
+            "index",
+            Status::Ok,
+            format!(
+                "{checked} record(s) in {} session(s) resolve",
+                sessions.len()
+            ),
+        ));
+    }
+    let mut check = if fix {
+        crate::cmd_bridge::compact_sessions(project_dir, &orphaned)?;
+        for sid in &bad_sessions {
+            edda_index::sweep_index(
+                &index_dir.join(format!("{sid}.jsonl")),
+                &project_dir.join("transcripts").join(format!("{sid}.jsonl")),
+                &project_dir
+                    .join("quarantine")
+                    .join("index")
+                    .join(format!("{sid}.jsonl")),
+                sid,
+                true,
+            )?;
+        }
+        Check::new(
+            "index",
+            Status::Fixed,
+            format!(
+                "swept and rebuilt {} session(s), removed {} orphaned index(es)",
+                bad_sessions.len(),
+                orphaned.len()
+            ),
+        )
+    } else {
+        Check::new(
+            "index",
+            Status::Error,
+            format!(
+                "{} session(s) with bad records, {} orphaned index(es)",
+                bad_sessions.len(),
+                orphaned.len()
+            ),
+        )
+    };
+    check.details = details;
+    Ok(check)
+}
+
+fn check_blobs(ledger: &Ledger, fix: bool) -> anyhow::Result<Check> {
+    let referenced: HashSet<String> = ledger
+        .iter_events()?
+        .iter()
+        .flat_map(|e| e.refs.blobs.iter())
+        .filter_map(|r| r.strip_prefix("blob:sha256:").map(str::to_string))
+        .collect();
+    let blobs = edda_ledger::blob_list(&ledger.paths)?;
+    let stored: HashSet<&str> = blobs.iter().map(|b| b.hash.as_str()).collect();
+    let meta = blob_meta::load_blob_meta(&ledger.paths.blob_meta_json)?;
+
+    let mut missing: Vec<&String> = referenced
+        .iter()
+        .filter(|h| {
+            !stored.contains(h.as_str()) && !edda_ledger::blob_is_archived(&ledger.paths, h)
+        })
+        .collect();
+    missing.sort();
+    let orphaned: Vec<&str> = blobs
+        .iter()
+        .filter(|b| !referenced.contains(&b.hash))
+        .filter(|b| {
+            let entry = blob_meta::get_meta(&meta, &b.hash);
+            !entry.pinned && entry.class != BlobClass::Artifact
+        })
+        .map(|b| b.hash.as_str())
+        .collect();
+
+    let mut details: Vec<String> = missing
+        .iter()
+        .map(|h| format!("missing: {h} (referenced, not in store or archive)"))
+        .collect();
+    if missing.is_empty() && orphaned.is_empty() {
+        return Ok(Check::new(
+            "blobs",
+            Status::Ok,
+            format!("{} blob(s), all referenced blobs present", blobs.len()),
+        ));
+    }
+
+    let mut archived = 0;
+    if fix {
+        for hash in &orphaned {
+            edda_ledger::blob_archive(&ledger.paths, hash)?;
+            archived += 1;
+        }
+    } else {
+        details.extend(orphaned.iter().map(|h| format!("orphaned: {h}")));
+    }
+    let status = if !missing.is_empty() {
+        Status::Error
+    } else if fix {
+        Status::Fixed
+    } else {
+        Status::Warn
+    };
+    let message = if fix {
+        format!(
+            "{} missing (cannot be restored), archived {archived} orphaned blob(s)",
+            missing.len()
+        )
+    } else {
+        format!(
+            "{} missing, {} orphaned (unreferenced, not pinned)",
+            missing.len(),
+            orphaned.len()
+        )
+    };
+    let mut check = Check::new("blobs", status, message);
+    check.details = details;
+    Ok(check)
+}
+
+fn check_heartbeats(project_id: &str, fix: bool) -> Check {
+    let sessions = edda_bridge_claude::peers::discover_all_sessions(project_id);
+    let stale: Vec<_> = sessions
+        .iter()
+        .filter(|p| p.age_secs > STALE_HEARTBEAT_SECS)
+        .collect();
+    if stale.is_empty() {
+        return Check::new(
+            "heartbeats",
+            Status::Ok,
+            format!("{} heartbeat(s), none stale", sessions.len()),
+        );
+    }
+    let details = stale
+        .iter()
+        .map(|p| {
+            format!(
+                "{} ({}): last beat {}h ago",
+                p.session_id,
+                p.label,
+                p.age_secs / 3600
+            )
+        })
+        .collect();
+    let mut check = if fix {
+        for p in &stale {
+            edda_bridge_claude::peers::remove_heartbeat(project_id, &p.session_id);
+        }
+        Check::new(
+            "heartbeats",
+            Status::Fixed,
+            format!("removed {} stale heartbeat(s)", stale.len()),
+        )
+    } else {
+        Check::new(
+            "heartbeats",
+            Status::Warn,
+            format!("{} heartbeat(s) older than 24h", stale.len()),
+        )
+    };
+    check.details = details;
+    check
+}
+
+/// Informational: the latest recorded `config.json` changes, so a change
+/// in behavior can be matched to the setting behind it.
+fn check_config(ledger: &Ledger) -> anyhow::Result<Check> {
+    let changes = settings::recent_config_changes(ledger, RECENT_CONFIG_CHANGES)?;
+    let message = match changes.first() {
+        None => "no recorded config changes".to_string(),
+        Some(latest) => format!("last change {} ({})", latest.ts, latest.key),
+    };
+    let mut check = Check::new("config", Status::Ok, message);
+    check.details = changes.iter().map(|c| c.summary()).collect();
+    Ok(check)
+}
+
+fn render(checks: &[Check], fix: bool) -> String {
+    let mut out = String::new();
+    for c in checks {
+        let tag = match c.status {
+            Status::Ok => "OK",
+            Status::Warn => "WARN",
+            Status::Error => "ERROR",
+            Status::Fixed => "FIXED",
+        };
+        out.push_str(&format!("[{tag}] {}: {}\n", c.name, c.message));
+        for d in &c.details {
+            out.push_str(&format!("    {d}\n"));
+        }
+    }
+    let fixable = checks
+        .iter()
+        .any(|c| matches!(c.status, Status::Warn | Status::Error) && c.name != "chain");
+    if !fix && fixable {
+        out.push_str("Run `edda doctor workspace --fix` to repair what can be repaired.\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orphaned_blobs_are_archived_by_fix() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = edda_ledger::EddaPaths::discover(tmp.path());
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        let blob_ref = edda_ledger::blob_store::blob_put(&paths, b"nobody points here").unwrap();
+        let hash = blob_ref.strip_prefix("blob:sha256:").unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+
+        let check = check_blobs(&ledger, false).unwrap();
+        assert_eq!(check.status, Status::Warn);
+        assert_eq!(check.details, vec![format!("orphaned: {hash}")]);
+
+        assert_eq!(check_blobs(&ledger, true).unwrap().status, Status::Fixed);
+        assert!(edda_ledger::blob_is_archived(&paths, hash));
+        assert_eq!(check_blobs(&ledger, false).unwrap().status, Status::Ok);
+    }
+
+    #[test]
+    fn config_check_lists_recorded_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = edda_ledger::EddaPaths::discover(tmp.path());
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        assert_eq!(
+            check_config(&ledger).unwrap().message,
+            "no recorded config changes"
+        );
+
+        settings::set_config(&ledger, "skill_guide", Some(serde_json::json!(true))).unwrap();
+        let check = check_config(&ledger).unwrap();
+        assert_eq!(check.status, Status::Ok);
+        assert!(
+            check.message.ends_with("(skill_guide)"),
+            "{}",
+            check.message
+        );
+        assert_eq!(check.details.len(), 1);
+        assert!(check.details[0].ends_with("skill_guide (unset) -> true"));
+    }
+}
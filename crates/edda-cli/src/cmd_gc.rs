@@ -1,7 +1,9 @@
 use edda_ledger::blob_meta::{self, BlobClass};
 use edda_ledger::blob_store::{blob_list, blob_list_archived};
 use edda_ledger::tombstone::{self, DeleteReason};
-use edda_ledger::{blob_archive, blob_remove, Ledger};
+use edda_ledger::{
+    blob_archive, blob_remove, ArchiveReport, Ledger, RetentionPolicy, WorkspaceLock,
+};
 use std::collections::HashSet;
 use std::path::Path;
 
@@ -22,6 +24,8 @@ pub struct GcParams<'a> {
 }
 
 const DEFAULT_STATE_KEEP_DAYS: u32 = 7;
+/// Unused chunks younger than this may belong to a blob still being written.
+const CHUNK_GRACE: std::time::Duration = std::time::Duration::from_secs(600);
 
 /// Candidate blob for removal/archival.
 struct GcCandidate {
@@ -49,8 +53,9 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
     });
     let quota_mb = read_config_u32(&ledger.paths.config_json, "gc.blob_quota_mb");
 
-    // Phase 1: Scan events to collect active blob refs
-    let events = ledger.iter_events()?;
+    // Phase 1: Scan events to collect active blob refs. Archived events
+    // still own their blobs.
+    let events = ledger.iter_events_with_archive()?;
     let mut active_refs: HashSet<String> = HashSet::new();
     for event in &events {
         for blob_ref in &event.refs.blobs {
@@ -201,7 +206,7 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
     let mut transcript_candidates: Vec<(std::path::PathBuf, u64)> = Vec::new();
     if params.global {
         let pid = edda_store::project_id(params.repo_root);
-        let transcripts_dir = edda_store::project_dir(&pid).join("transcripts");
+        let transcripts_dir = edda_store::project_subdir(&pid, "transcripts");
         if transcripts_dir.exists() {
             let transcript_cutoff = time::OffsetDateTime::now_utc()
                 - time::Duration::days(i64::from(transcript_keep_days));
@@ -259,8 +264,22 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
         // Index files: {project_dir}/index/{session_id}.jsonl
         let index_dir = edda_store::project_dir(&pid).join("index");
         scan_expired_files(&index_dir, "jsonl", session_cutoff, &mut session_candidates);
+        // Rotated segments and their manifests: {index_dir}/segments/
+        let segments_dir = index_dir.join(edda_index::SEGMENTS_DIR);
+        scan_expired_files(
+            &segments_dir,
+            "jsonl",
+            session_cutoff,
+            &mut session_candidates,
+        );
+        scan_expired_files(
+            &segments_dir,
+            "json",
+            session_cutoff,
+            &mut session_candidates,
+        );
 
-        // Stale state files: inject_hash.*, transcript_cursor.*, progress_last.*
+        // Stale state files: inject_hash.*, transcript_cursor.*, progress_last.*, scratch.*
         let state_dir = edda_store::project_dir(&pid).join("state");
         let state_cutoff = time::OffsetDateTime::now_utc()
             - time::Duration::days(i64::from(DEFAULT_STATE_KEEP_DAYS));
@@ -305,8 +324,63 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
         }
     }
 
+    // Phase 4e: Scheduled maintenance (gc.maintenance_days)
+    if !params.dry_run {
+        if let Some(days) = read_config_u32(&ledger.paths.config_json, "gc.maintenance_days") {
+            if crate::cmd_maintenance::is_due(&ledger.paths.edda_dir, days) {
+                println!("Running scheduled maintenance (every {days} day(s)):");
+                match crate::cmd_maintenance::run(params.repo_root, false) {
+                    Ok(report) => {
+                        for line in crate::cmd_maintenance::render(&report).lines() {
+                            println!("  {line}");
+                        }
+                    }
+                    Err(e) => eprintln!("  warning: maintenance failed: {e}"),
+                }
+            }
+        }
+    }
+
+    // Phase 4f: Event retention (gc.retention.*)
+    let retention = RetentionPolicy::from_config(&ledger.paths.config_json)?;
+    let now = time::OffsetDateTime::now_utc();
+    let event_plan = ledger.archive_expired_events(&retention, now, true)?;
+    if event_plan.archived > 0 {
+        let by_type: Vec<String> = event_plan
+            .by_type
+            .iter()
+            .map(|(t, n)| format!("{t}: {n}"))
+            .collect();
+        println!(
+            "  {} event(s) past retention ({}) → {}",
+            event_plan.archived,
+            by_type.join(", "),
+            event_plan.files.join(", ")
+        );
+    }
+
+    // Phase 4g: Ledger rotation (gc.ledger_max_mb)
+    let ledger_max_bytes = read_config_u32(&ledger.paths.config_json, "gc.ledger_max_mb")
+        .map(|mb| u64::from(mb) * 1024 * 1024);
+    let rotate_plan = match ledger_max_bytes {
+        Some(max_bytes) => ledger.rotate_events(max_bytes, true)?,
+        None => ArchiveReport::default(),
+    };
+    if rotate_plan.archived > 0 {
+        println!(
+            "  {} oldest event(s) over the {} ledger limit → {}",
+            rotate_plan.archived,
+            format_size(ledger_max_bytes.unwrap_or(0)),
+            rotate_plan.files.join(", ")
+        );
+    }
+
     // Phase 5: Execute or dry-run
-    let total_items = candidates.len() + transcript_candidates.len() + session_candidates.len();
+    let total_items = candidates.len()
+        + transcript_candidates.len()
+        + session_candidates.len()
+        + event_plan.archived
+        + rotate_plan.archived;
     if total_items == 0 {
         println!("\nNothing to clean up.");
         return Ok(());
@@ -372,6 +446,7 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
             Err(e) => eprintln!("  warning: failed to process blob {}: {e}", candidate.hash),
         }
     }
+    freed += remove_unused_chunks(&ledger.paths);
 
     // Delete transcripts
     for (path, size) in &transcript_candidates {
@@ -384,6 +459,24 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
         }
     }
 
+    // Drop index records that pointed into the deleted transcripts
+    if !transcript_candidates.is_empty() {
+        let pid = edda_store::project_id(params.repo_root);
+        let sessions: Vec<String> = transcript_candidates
+            .iter()
+            .filter_map(|(path, _)| path.file_stem()?.to_str().map(String::from))
+            .collect();
+        match crate::cmd_bridge::compact_sessions(&edda_store::project_dir(&pid), &sessions) {
+            Ok(results) => {
+                let removed = results.iter().filter(|(_, r)| r.removed_index).count();
+                if removed > 0 {
+                    println!("  removed {removed} index(es) of deleted transcripts");
+                }
+            }
+            Err(e) => eprintln!("  warning: index compaction failed: {e}"),
+        }
+    }
+
     // Delete session files (ledger, index, state)
     for (path, size) in &session_candidates {
         match std::fs::remove_file(path) {
@@ -391,10 +484,30 @@ pub fn execute(params: &GcParams) -> anyhow::Result<()> {
                 freed += size;
                 processed_count += 1;
             }
+            // Already removed with its transcript above.
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
             Err(e) => eprintln!("  warning: failed to remove {}: {e}", path.display()),
         }
     }
 
+    // Move expired events, then the oldest events over the size limit, to
+    // the cold archive
+    if event_plan.archived > 0 || rotate_plan.archived > 0 {
+        let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+        let mut archived = ledger
+            .archive_expired_events(&retention, now, false)?
+            .archived;
+        if let Some(max_bytes) = ledger_max_bytes {
+            archived += ledger.rotate_events(max_bytes, false)?.archived;
+        }
+        processed_count += archived;
+        println!(
+            "  archived {} event(s) to {}",
+            archived,
+            ledger.paths.archive_dir.display()
+        );
+    }
+
     let action = if params.archive { "Archived" } else { "Freed" };
     println!(
         "\n{} {} ({} item(s) processed)",
@@ -504,6 +617,7 @@ fn purge_archive(params: &GcParams) -> anyhow::Result<()> {
             Err(e) => eprintln!("  warning: failed to delete archived blob {hash}: {e}"),
         }
     }
+    freed += remove_unused_chunks(&ledger.paths);
 
     println!(
         "\nPurged {} ({} blob(s) deleted)",
@@ -551,7 +665,8 @@ fn scan_expired_files(
 }
 
 /// Scan state directory for stale per-session files.
-/// Matches: inject_hash.*, transcript_cursor.*, progress_last.*, ingest.*.lock
+/// Matches: inject_hash.*, transcript_cursor.*, progress_last.*, ingest.*.lock,
+/// scratch.* (scratchpads of sessions nobody resumed)
 /// Preserves: active_tasks.json, files_modified.json, recent_commits.json
 fn scan_stale_state_files(
     state_dir: &Path,
@@ -571,6 +686,7 @@ fn scan_stale_state_files(
         "progress_last.",
         "ingest.",
         "session.",
+        "scratch.",
     ];
     for entry in entries.flatten() {
         let name = entry.file_name().to_string_lossy().to_string();
@@ -592,6 +708,23 @@ fn scan_stale_state_files(
 
 /// Compact coordination.jsonl if it exceeds the line threshold.
 /// Returns the number of original lines (0 if no compaction needed).
+/// Drop chunks of chunked blobs that no remaining blob uses. Returns bytes
+/// freed.
+fn remove_unused_chunks(paths: &edda_ledger::EddaPaths) -> u64 {
+    match edda_ledger::blob_gc_chunks(paths, CHUNK_GRACE) {
+        Ok((removed, bytes)) => {
+            if removed > 0 {
+                println!("  removed {removed} unused blob chunk(s)");
+            }
+            bytes
+        }
+        Err(e) => {
+            eprintln!("  warning: blob chunk cleanup failed: {e}");
+            0
+        }
+    }
+}
+
 fn compact_coordination_log(project_id: &str, max_lines: usize, dry_run: bool) -> usize {
     let path = edda_store::project_dir(project_id)
         .join("state")
@@ -626,7 +759,7 @@ fn compact_coordination_log(project_id: &str, max_lines: usize, dry_run: bool) -
     lines.len()
 }
 
-fn format_size(bytes: u64) -> String {
+pub(crate) fn format_size(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
     const GB: u64 = MB * 1024;
@@ -887,6 +1020,53 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn gc_archives_events_past_retention_and_keeps_their_blobs() {
+        let (tmp, paths) = setup_workspace();
+        let ledger = Ledger::open(&tmp).unwrap();
+        std::fs::write(&paths.config_json, r#"{"gc.retention.signal": 30}"#).unwrap();
+
+        let blob = blob_put(&paths, b"old output").unwrap();
+        let hex = blob.strip_prefix("blob:sha256:").unwrap();
+        set_file_time_old(&paths.blobs_dir.join(hex));
+
+        let mut old = new_note_event("main", None, "system", "old", &[]).unwrap();
+        old.ts = "2020-01-02T00:00:00Z".into();
+        old.refs.blobs.push(blob.clone());
+        edda_core::event::finalize_event(&mut old).unwrap();
+        ledger.append_event(&old).unwrap();
+        let mut tip = new_note_event("main", Some(&old.hash), "system", "tip", &[]).unwrap();
+        edda_core::event::finalize_event(&mut tip).unwrap();
+        ledger.append_event(&tip).unwrap();
+
+        let params = GcParams {
+            repo_root: &tmp,
+            dry_run: false,
+            keep_days: Some(0),
+            force: true,
+            global: false,
+            archive: false,
+            purge_archive: false,
+            archive_keep_days: None,
+            include_sessions: false,
+        };
+        execute(&params).unwrap();
+
+        let live: Vec<String> = ledger
+            .iter_events()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_id)
+            .collect();
+        assert_eq!(live, vec![tip.event_id.clone()]);
+        assert!(paths.archive_dir.join("events-2020-01.jsonl.zst").exists());
+        assert_eq!(ledger.iter_events_with_archive().unwrap().len(), 2);
+        ledger.verify_chain().unwrap();
+        assert!(edda_ledger::blob_store::blob_get_path(&paths, &blob).is_ok());
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn gc_quota_enforcement() {
         let (tmp, paths) = setup_workspace();
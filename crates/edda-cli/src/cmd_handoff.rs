@@ -0,0 +1,130 @@
+use edda_bridge_claude::handoff::{self, HANDOFF_TAG};
+use edda_core::event::{finalize_event, new_note_event};
+use edda_core::secret_guard::redact;
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::Ledger;
+use std::path::Path;
+
+/// `edda handoff [--to label]` — write a brief of in-flight work and store
+/// it as a NOTE tagged `handoff`.
+pub fn execute(
+    repo_root: &Path,
+    to: Option<&str>,
+    cli_session: Option<&str>,
+    dry_run: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let project_id = edda_store::project_id(repo_root);
+    let (session_id, from) = crate::cmd_bridge::resolve_session_id(cli_session, &project_id, "cli");
+
+    let brief = handoff::collect(&project_id, &ledger, &from, to)?;
+    // Failing-command stderr and request text come straight from the
+    // session; scrub them like any other note.
+    let (text, hits) = redact(&handoff::render(&brief));
+    if !hits.is_empty() {
+        eprintln!(
+            "⚠ secret-guard: redacted {n} secret pattern(s) before writing handoff ({kinds})",
+            n = hits.len(),
+            kinds = hits.iter().map(|h| h.kind).collect::<Vec<_>>().join(", ")
+        );
+    }
+
+    let event_id = if dry_run {
+        None
+    } else {
+        let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+        let branch = ledger.head_branch()?;
+        let parent_hash = ledger.last_event_hash()?;
+        let mut event = new_note_event(
+            &branch,
+            parent_hash.as_deref(),
+            "system",
+            &text,
+            &[HANDOFF_TAG.to_string()],
+        )?;
+        event.payload["source"] = serde_json::json!("cli:handoff");
+        event.payload["handoff"] = serde_json::json!({
+            "from": from,
+            "to": to,
+            "session_id": session_id,
+        });
+        finalize_event(&mut event)?;
+        ledger.append_event(&event)?;
+        let _ = edda_derive::rebuild_branch(&ledger, &branch);
+        Some(event.event_id)
+    };
+
+    if json {
+        let out = serde_json::json!({
+            "event_id": event_id,
+            "session_id": session_id,
+            "handoff": brief,
+            "text": text,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+    print!("{text}");
+    match event_id {
+        Some(id) => println!("\nWrote NOTE {id}"),
+        None => println!("\n(dry run, nothing written)"),
+    }
+    Ok(())
+}
+
+/// `edda handoff --show [--to label]` — print the latest handoff.
+pub fn show(repo_root: &Path, to: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let Some(event) = handoff::latest(&ledger, to)? else {
+        if json {
+            println!("null");
+        } else {
+            println!("No handoff recorded.");
+        }
+        return Ok(());
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&event)?);
+        return Ok(());
+    }
+    println!("{} · {}", event.event_id, event.ts);
+    println!();
+    print!("{}", event.payload["text"].as_str().unwrap_or(""));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handoff_is_stored_and_shown_per_recipient() {
+        let _store = crate::test_support::isolated_store();
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = edda_ledger::EddaPaths::discover(tmp.path());
+        paths.ensure_layout().unwrap();
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+
+        execute(tmp.path(), Some("billing"), Some("s-auth"), false, false).unwrap();
+        execute(tmp.path(), None, Some("s-auth"), false, false).unwrap();
+        execute(tmp.path(), Some("docs"), Some("s-auth"), true, false).unwrap();
+
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let notes = ledger.iter_events_by_type("note").unwrap();
+        assert_eq!(notes.len(), 2);
+
+        let billing = handoff::latest(&ledger, Some("billing")).unwrap().unwrap();
+        assert_eq!(billing.event_id, notes[0].event_id);
+        assert_eq!(billing.payload["handoff"]["session_id"], "s-auth");
+        assert!(billing.payload["text"]
+            .as_str()
+            .unwrap()
+            .contains(" to billing\n"));
+        let any = handoff::latest(&ledger, None).unwrap().unwrap();
+        assert_eq!(any.event_id, notes[1].event_id);
+        assert!(handoff::latest(&ledger, Some("docs")).unwrap().is_none());
+    }
+}
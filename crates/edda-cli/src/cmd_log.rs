@@ -17,6 +17,8 @@ pub struct LogParams<'a> {
     pub json: bool,
     /// Read every project in scope instead of just this workspace (GH-407).
     pub fleet: bool,
+    /// Also read events `edda gc` moved to `.edda/archive/`.
+    pub include_archive: bool,
 }
 
 /// One workspace's answer: the events matching `params`, newest first, capped.
@@ -25,8 +27,12 @@ pub struct LogParams<'a> {
 /// different repo without also inheriting the printing.
 fn collect_matching(params: &LogParams<'_>) -> anyhow::Result<Vec<Event>> {
     let ledger = Ledger::open(params.repo_root)?;
-    let mut matched: Vec<Event> = ledger
-        .iter_events()?
+    let events = if params.include_archive {
+        ledger.iter_events_with_archive()?
+    } else {
+        ledger.iter_events()?
+    };
+    let mut matched: Vec<Event> = events
         .into_iter()
         .rev() // newest first
         .filter(|e| matches_filter(e, params))
@@ -259,7 +265,7 @@ fn print_event_line(event: &Event) {
     );
 }
 
-fn is_session_digest(event: &Event) -> bool {
+pub(crate) fn is_session_digest(event: &Event) -> bool {
     event
         .payload
         .get("tags")
@@ -378,7 +384,7 @@ fn format_event_detail(event: &Event) -> String {
     }
 }
 
-fn format_session_digest_detail(event: &Event) -> String {
+pub(crate) fn format_session_digest_detail(event: &Event) -> String {
     let ss = event.payload.get("session_stats");
     let tool_calls = ss
         .and_then(|s| s.get("tool_calls"))
@@ -573,6 +579,7 @@ mod tests {
             limit: 50,
             json: false,
             fleet: false,
+            include_archive: false,
         }
     }
 
@@ -582,8 +589,8 @@ mod tests {
     fn fleet_log_tags_each_event_with_the_project_it_came_from() {
         let a = temp_ws("fleet_a");
         let b = temp_ws("fleet_b");
-        crate::cmd_note::execute(&a, "note from edda", "agent", &[]).unwrap();
-        crate::cmd_note::execute(&b, "note from dazun", "agent", &[]).unwrap();
+        crate::cmd_note::execute(&a, "note from edda", "agent", &[], &[]).unwrap();
+        crate::cmd_note::execute(&b, "note from dazun", "agent", &[], &[]).unwrap();
 
         let scope = vec![fleet_entry("edda", &a), fleet_entry("dazun", &b)];
         let (hits, misses) = collect_fleet(&scope, &params_for(&a));
@@ -616,8 +623,8 @@ mod tests {
         let a = temp_ws("fleet_lim_a");
         let b = temp_ws("fleet_lim_b");
         for i in 0..3 {
-            crate::cmd_note::execute(&a, &format!("a{i}"), "agent", &[]).unwrap();
-            crate::cmd_note::execute(&b, &format!("b{i}"), "agent", &[]).unwrap();
+            crate::cmd_note::execute(&a, &format!("a{i}"), "agent", &[], &[]).unwrap();
+            crate::cmd_note::execute(&b, &format!("b{i}"), "agent", &[], &[]).unwrap();
         }
 
         let scope = vec![fleet_entry("edda", &a), fleet_entry("dazun", &b)];
@@ -635,7 +642,7 @@ mod tests {
     #[test]
     fn fleet_log_reports_an_unreadable_project_instead_of_dropping_it() {
         let a = temp_ws("fleet_live");
-        crate::cmd_note::execute(&a, "still here", "agent", &[]).unwrap();
+        crate::cmd_note::execute(&a, "still here", "agent", &[], &[]).unwrap();
         let gone = std::env::temp_dir().join(format!("edda_log_never_{}", std::process::id()));
         let _ = std::fs::remove_dir_all(&gone);
 
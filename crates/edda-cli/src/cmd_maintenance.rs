@@ -0,0 +1,204 @@
+use crate::cmd_gc::format_size;
+use edda_ledger::{Ledger, LedgerMaintenance};
+use edda_store::OrphanTempFile;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Temp files younger than this may belong to a write still in progress.
+const TEMP_MIN_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Last completed run, read by `edda gc` to decide whether one is due.
+const STATE_FILE: &str = "maintenance.json";
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct IndexSummary {
+    pub sessions: usize,
+    pub records_dropped: usize,
+    pub segments_removed: usize,
+    pub indexes_removed: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct MaintenanceReport {
+    pub dry_run: bool,
+    pub ledger: LedgerMaintenance,
+    pub index: IndexSummary,
+    pub temp_files: Vec<OrphanTempFile>,
+    /// Legacy draft files imported into the ledger (or waiting, in a dry run).
+    pub drafts_imported: usize,
+}
+
+/// `edda maintenance [--dry-run] [--json]`
+///
+/// Checkpoints the WAL, VACUUMs and ANALYZEs `ledger.db`, compacts the
+/// project's transcript indexes, removes temp files left by interrupted
+/// atomic writes, and imports draft files from before ledger-backed drafts.
+pub fn execute(repo_root: &Path, dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let report = run(repo_root, dry_run)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{}", render(&report));
+    }
+    Ok(())
+}
+
+pub(crate) fn run(repo_root: &Path, dry_run: bool) -> anyhow::Result<MaintenanceReport> {
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = if dry_run {
+        None
+    } else {
+        Some(edda_ledger::lock::WorkspaceLock::acquire(&ledger.paths)?)
+    };
+
+    let ledger_report = ledger.maintain(dry_run)?;
+    let drafts_imported = if dry_run {
+        edda_ledger::legacy_draft_count(&ledger.paths)
+    } else {
+        edda_ledger::import_legacy_drafts(&ledger)?
+    };
+
+    let project_dir = edda_store::project_dir(&edda_store::project_id(repo_root));
+    let mut index = IndexSummary::default();
+    let sessions = edda_index::indexed_sessions(&project_dir.join("index"))?;
+    index.sessions = sessions.len();
+    if !dry_run {
+        for (_, r) in crate::cmd_bridge::compact_sessions(&project_dir, &sessions)? {
+            index.records_dropped += r.records_dropped;
+            index.segments_removed += r.segments_removed;
+            index.indexes_removed += usize::from(r.removed_index);
+        }
+    }
+
+    let mut temp_files =
+        edda_store::sweep_temp_files(&ledger.paths.edda_dir, TEMP_MIN_AGE, dry_run)?;
+    temp_files.extend(edda_store::sweep_temp_files(
+        &project_dir,
+        TEMP_MIN_AGE,
+        dry_run,
+    )?);
+
+    if !dry_run {
+        record_run(&ledger.paths.edda_dir)?;
+    }
+    Ok(MaintenanceReport {
+        dry_run,
+        ledger: ledger_report,
+        index,
+        temp_files,
+        drafts_imported,
+    })
+}
+
+fn state_path(edda_dir: &Path) -> PathBuf {
+    edda_dir.join(STATE_FILE)
+}
+
+fn record_run(edda_dir: &Path) -> anyhow::Result<()> {
+    let now = time::OffsetDateTime::now_utc()
+        .format(&time::format_description::well_known::Rfc3339)
+        .unwrap_or_default();
+    let state = serde_json::json!({ "last_run": now });
+    edda_store::write_atomic(
+        &state_path(edda_dir),
+        serde_json::to_string_pretty(&state)?.as_bytes(),
+    )
+}
+
+/// Whether more than `interval_days` have passed since the last run (or
+/// there never was one).
+pub(crate) fn is_due(edda_dir: &Path, interval_days: u32) -> bool {
+    let last = std::fs::read_to_string(state_path(edda_dir))
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .and_then(|v| {
+            let ts = v.get("last_run")?.as_str()?.to_string();
+            time::OffsetDateTime::parse(&ts, &time::format_description::well_known::Rfc3339).ok()
+        });
+    match last {
+        Some(last) => {
+            time::OffsetDateTime::now_utc() - last >= time::Duration::days(i64::from(interval_days))
+        }
+        None => true,
+    }
+}
+
+pub(crate) fn render(report: &MaintenanceReport) -> String {
+    let l = &report.ledger;
+    let reclaimable = format_size(l.free_pages * l.page_size);
+    let mut out = if report.dry_run {
+        format!(
+            "ledger.db: {} (WAL {}), {} free page(s) ({reclaimable}) reclaimable\n",
+            format_size(l.db_bytes_before),
+            format_size(l.wal_bytes_before),
+            l.free_pages
+        )
+    } else {
+        let mut s = format!(
+            "ledger.db: {} → {} (WAL {} → {}), {} free page(s) ({reclaimable}) reclaimed, statistics refreshed\n",
+            format_size(l.db_bytes_before),
+            format_size(l.db_bytes_after),
+            format_size(l.wal_bytes_before),
+            format_size(l.wal_bytes_after),
+            l.free_pages
+        );
+        if l.checkpoint_busy {
+            s.push_str("  warning: another process held the WAL; it was not fully checkpointed\n");
+        }
+        s
+    };
+
+    let i = &report.index;
+    if report.dry_run {
+        out.push_str(&format!("index: {} session(s) to compact\n", i.sessions));
+    } else {
+        out.push_str(&format!(
+            "index: {} session(s) compacted, {} record(s) dropped, {} segment(s) removed, {} index(es) of deleted transcripts removed\n",
+            i.sessions, i.records_dropped, i.segments_removed, i.indexes_removed
+        ));
+    }
+
+    let temp_bytes: u64 = report.temp_files.iter().map(|f| f.bytes).sum();
+    out.push_str(&format!(
+        "temp files: {} {} ({})\n",
+        report.temp_files.len(),
+        if report.dry_run {
+            "orphaned"
+        } else {
+            "removed"
+        },
+        format_size(temp_bytes)
+    ));
+    if report.dry_run {
+        for f in &report.temp_files {
+            out.push_str(&format!("  {}\n", f.path.display()));
+        }
+    }
+    if report.drafts_imported > 0 {
+        out.push_str(&format!(
+            "drafts: {} legacy draft file(s) {}\n",
+            report.drafts_imported,
+            if report.dry_run {
+                "to import"
+            } else {
+                "imported"
+            }
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn due_without_a_recorded_run_and_not_right_after_one() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(is_due(tmp.path(), 7));
+        record_run(tmp.path()).unwrap();
+        assert!(!is_due(tmp.path(), 7));
+        assert!(is_due(tmp.path(), 0));
+    }
+}
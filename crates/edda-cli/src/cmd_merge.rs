@@ -1,8 +1,13 @@
-use edda_core::event::new_merge_event;
+use edda_core::event::{finalize_event, new_decision_event, new_merge_event};
+use edda_core::types::{authority, rel, DecisionPayload, Provenance};
 use edda_derive::rebuild_all;
 use edda_ledger::lock::WorkspaceLock;
-use edda_ledger::{validate_branch_name, Ledger};
+use edda_ledger::{
+    decision_conflicts, decision_diff, validate_branch_name, BranchDecisionDiff, DecisionConflict,
+    DecisionResolution, Ledger, MergeStrategy,
+};
 use std::collections::HashSet;
+use std::io::{BufRead, IsTerminal, Write};
 use std::path::Path;
 
 fn collect_commit_ids(ledger: &Ledger, branch: &str) -> anyhow::Result<Vec<String>> {
@@ -15,16 +20,18 @@ fn collect_commit_ids(ledger: &Ledger, branch: &str) -> anyhow::Result<Vec<Strin
     Ok(ids)
 }
 
-pub fn execute(repo_root: &Path, src: &str, dst: &str, reason: &str) -> anyhow::Result<()> {
+pub fn execute(
+    repo_root: &Path,
+    src: &str,
+    dst: &str,
+    reason: &str,
+    strategy: Option<MergeStrategy>,
+    actor: Option<&str>,
+    dry_run: bool,
+) -> anyhow::Result<()> {
     validate_branch_name(src)?;
     validate_branch_name(dst)?;
     let ledger = Ledger::open(repo_root)?;
-    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
-
-    let head = ledger.head_branch()?;
-    if head != dst {
-        anyhow::bail!("merge dst must equal HEAD (HEAD={head}, dst={dst})");
-    }
 
     // Check both branches exist
     if !ledger.paths.branch_dir(src)?.exists() {
@@ -34,6 +41,19 @@ pub fn execute(repo_root: &Path, src: &str, dst: &str, reason: &str) -> anyhow::
         anyhow::bail!("branch does not exist: {dst}");
     }
 
+    if dry_run {
+        let diff = decision_diff(&ledger, src, dst)?;
+        print!("{}", render_diff(src, dst, &diff));
+        return Ok(());
+    }
+
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let head = ledger.head_branch()?;
+    if head != dst {
+        anyhow::bail!("merge dst must equal HEAD (HEAD={head}, dst={dst})");
+    }
+
     let src_commits = collect_commit_ids(&ledger, src)?;
     let dst_commits: HashSet<String> = collect_commit_ids(&ledger, dst)?.into_iter().collect();
 
@@ -43,14 +63,389 @@ pub fn execute(repo_root: &Path, src: &str, dst: &str, reason: &str) -> anyhow::
         .filter(|id| !dst_commits.contains(id))
         .collect();
 
-    let parent_hash = ledger.last_event_hash()?;
+    // Conflicting decisions must be settled explicitly; merging silently
+    // would leave DST contradicting what SRC's work was built on.
+    let conflicts = decision_conflicts(&ledger, src, dst)?;
+    let resolutions = match strategy {
+        Some(_) if conflicts.is_empty() => Vec::new(),
+        Some(strategy) => {
+            if strategy == MergeStrategy::Interactive && !std::io::stdin().is_terminal() {
+                anyhow::bail!(
+                    "--strategy interactive needs a terminal; use ours, theirs or union instead"
+                );
+            }
+            // Every strategy but `ours` can write decisions on DST, which a
+            // protected branch only takes through a draft.
+            if strategy != MergeStrategy::Ours {
+                if let Some(denial) = edda_core::policy::check_branch_protection(
+                    &ledger.paths.edda_dir,
+                    dst,
+                    edda_core::policy::ACTION_DECIDE,
+                    actor,
+                )? {
+                    anyhow::bail!(denial);
+                }
+            }
+            let mut stdin = std::io::stdin().lock();
+            resolve_conflicts(&ledger, src, dst, strategy, &conflicts, |c| match strategy
+                .resolve(&c.ours.value, &c.theirs.value)
+            {
+                Some(value) => Ok(value),
+                None => prompt_resolution(&mut stdin, src, dst, c),
+            })?
+        }
+        None if conflicts.is_empty() => Vec::new(),
+        None => {
+            let mut msg = format!(
+                "{} decision key(s) differ between {src} and {dst}:\n",
+                conflicts.len()
+            );
+            for c in &conflicts {
+                msg.push_str(&format!(
+                    "  {}: {} ({dst}) vs {} ({src})\n",
+                    c.key, c.ours.value, c.theirs.value
+                ));
+            }
+            msg.push_str(
+                "re-run with --strategy ours|theirs|union|interactive to resolve them \
+                 (--dry-run shows the full decision diff)",
+            );
+            anyhow::bail!(msg);
+        }
+    };
 
-    let event = new_merge_event(dst, parent_hash.as_deref(), src, dst, reason, &adopted)?;
+    let parent_hash = ledger.last_event_hash()?;
+    let mut event = new_merge_event(dst, parent_hash.as_deref(), src, dst, reason, &adopted)?;
+    if let Some(strategy) = strategy.filter(|_| !conflicts.is_empty()) {
+        event.payload["decision_strategy"] = serde_json::json!(strategy.to_string());
+        event.payload["decision_resolutions"] = serde_json::to_value(&resolutions)?;
+        finalize_event(&mut event)?;
+    }
     ledger.append_event(&event)?;
 
     rebuild_all(&ledger)?;
 
     println!("Merged {src} -> {dst} (adopted {} commits)", adopted.len());
     println!("  {}", event.event_id);
+    for r in &resolutions {
+        let applied = if r.decision_event.is_some() {
+            ""
+        } else {
+            " (unchanged)"
+        };
+        println!(
+            "  {}: {} vs {} -> {}{applied}",
+            r.key, r.ours, r.theirs, r.resolved
+        );
+    }
     Ok(())
 }
+
+/// Settle each conflicting key with the value `choose` picks, writing a
+/// decision on `dst` wherever it differs from what `dst` already holds.
+///
+/// The written decision supersedes `dst`'s prior one and is `based_on` the
+/// source branch's, so `edda ask` shows where the merged value came from.
+fn resolve_conflicts(
+    ledger: &Ledger,
+    src: &str,
+    dst: &str,
+    strategy: MergeStrategy,
+    conflicts: &[DecisionConflict],
+    mut choose: impl FnMut(&DecisionConflict) -> anyhow::Result<String>,
+) -> anyhow::Result<Vec<DecisionResolution>> {
+    let mut out = Vec::with_capacity(conflicts.len());
+    for c in conflicts {
+        let resolved = choose(c)?;
+        let decision_event = if resolved == c.ours.value {
+            None
+        } else {
+            let dp = DecisionPayload {
+                key: c.key.clone(),
+                value: resolved.clone(),
+                reason: Some(format!("merge {src} -> {dst} (strategy: {strategy})")),
+                scope: None,
+                authority: Some(authority::SYSTEM.to_string()),
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            let parent_hash = ledger.last_event_hash()?;
+            let mut event = new_decision_event(dst, parent_hash.as_deref(), "system", &dp)?;
+            event.refs.provenance.push(Provenance {
+                target: c.ours.event_id.clone(),
+                rel: rel::SUPERSEDES.to_string(),
+                note: Some(format!("key '{}' reconciled at merge", c.key)),
+            });
+            event.refs.provenance.push(Provenance {
+                target: c.theirs.event_id.clone(),
+                rel: rel::BASED_ON.to_string(),
+                note: Some(format!("value from {src}")),
+            });
+            finalize_event(&mut event)?;
+            ledger.append_event(&event)?;
+            Some(event.event_id)
+        };
+        out.push(DecisionResolution {
+            key: c.key.clone(),
+            ours: c.ours.value.clone(),
+            theirs: c.theirs.value.clone(),
+            resolved,
+            ours_event: c.ours.event_id.clone(),
+            theirs_event: c.theirs.event_id.clone(),
+            decision_event,
+        });
+    }
+    Ok(out)
+}
+
+/// Ask which value `dst` should keep for one conflicting key.
+fn prompt_resolution(
+    input: &mut impl BufRead,
+    src: &str,
+    dst: &str,
+    c: &DecisionConflict,
+) -> anyhow::Result<String> {
+    eprintln!("\n{}", c.key);
+    eprintln!("  ours   ({dst}): {}", c.ours.value);
+    eprintln!("  theirs ({src}): {}", c.theirs.value);
+    loop {
+        eprint!("  keep [o]urs, [t]heirs, [u]nion, or type a value: ");
+        std::io::stderr().flush()?;
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            anyhow::bail!("merge aborted: no answer for '{}'", c.key);
+        }
+        if let Some(value) = parse_choice(&answer, c) {
+            return Ok(value);
+        }
+    }
+}
+
+/// Map an interactive answer to a value; `None` for a blank answer.
+fn parse_choice(answer: &str, c: &DecisionConflict) -> Option<String> {
+    let strategy = match answer.trim() {
+        "" => return None,
+        "o" | "ours" => MergeStrategy::Ours,
+        "t" | "theirs" => MergeStrategy::Theirs,
+        "u" | "union" => MergeStrategy::Union,
+        value => return Some(value.to_string()),
+    };
+    strategy.resolve(&c.ours.value, &c.theirs.value)
+}
+
+/// Pre-merge report of how the two branches' decisions differ.
+fn render_diff(src: &str, dst: &str, diff: &BranchDecisionDiff) -> String {
+    let mut out = format!("Decision diff {src} -> {dst}\n");
+    if diff.conflicts.is_empty() && diff.only_src.is_empty() && diff.only_dst.is_empty() {
+        out.push_str(&format!("  no differences ({} shared key(s))\n", diff.same));
+        return out;
+    }
+    if !diff.conflicts.is_empty() {
+        out.push_str(&format!(
+            "\nConflicting ({}): needs --strategy\n",
+            diff.conflicts.len()
+        ));
+        for c in &diff.conflicts {
+            out.push_str(&format!(
+                "  {}: {} ({dst}) vs {} ({src})\n",
+                c.key, c.ours.value, c.theirs.value
+            ));
+        }
+    }
+    if !diff.only_src.is_empty() {
+        out.push_str(&format!(
+            "\nOnly on {src} ({}): not carried over\n",
+            diff.only_src.len()
+        ));
+        for d in &diff.only_src {
+            out.push_str(&format!("  {} = {}\n", d.key, d.value));
+        }
+    }
+    if !diff.only_dst.is_empty() {
+        out.push_str(&format!("\nOnly on {dst} ({})\n", diff.only_dst.len()));
+        for d in &diff.only_dst {
+            out.push_str(&format!("  {} = {}\n", d.key, d.value));
+        }
+    }
+    out.push_str(&format!("\nSame on both: {}\n", diff.same));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decide(ledger: &Ledger, branch: &str, key: &str, value: &str) {
+        let dp = DecisionPayload {
+            key: key.into(),
+            value: value.into(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let parent = ledger.last_event_hash().unwrap();
+        let e = new_decision_event(branch, parent.as_deref(), "user", &dp).unwrap();
+        ledger.append_event(&e).unwrap();
+    }
+
+    #[test]
+    fn theirs_strategy_applies_source_value_and_records_resolution() {
+        let tmp = tempfile::tempdir().unwrap();
+        Ledger::ensure_initialized(tmp.path()).unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        std::fs::create_dir_all(ledger.paths.branch_dir("feat").unwrap()).unwrap();
+        decide(&ledger, "main", "db.engine", "postgres");
+        decide(&ledger, "main", "lint.tools", "clippy");
+        decide(&ledger, "feat", "db.engine", "sqlite");
+        decide(&ledger, "feat", "lint.tools", "clippy");
+        drop(ledger);
+
+        execute(
+            tmp.path(),
+            "feat",
+            "main",
+            "land feat",
+            Some(MergeStrategy::Theirs),
+            None,
+            false,
+        )
+        .unwrap();
+
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let active = ledger.find_active_decision("main", "db.engine").unwrap();
+        assert_eq!(active.unwrap().value, "sqlite");
+
+        let merge = ledger
+            .iter_events()
+            .unwrap()
+            .into_iter()
+            .rfind(|e| e.event_type == "merge")
+            .unwrap();
+        assert_eq!(merge.payload["decision_strategy"], "theirs");
+        let res: Vec<DecisionResolution> =
+            serde_json::from_value(merge.payload["decision_resolutions"].clone()).unwrap();
+        assert_eq!(res.len(), 1, "identical values are not conflicts");
+        assert_eq!(res[0].key, "db.engine");
+        assert_eq!(res[0].ours, "postgres");
+        assert_eq!(res[0].resolved, "sqlite");
+        assert!(res[0].decision_event.is_some());
+    }
+
+    #[test]
+    fn conflicts_without_strategy_refuse_to_merge() {
+        let tmp = tempfile::tempdir().unwrap();
+        Ledger::ensure_initialized(tmp.path()).unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        std::fs::create_dir_all(ledger.paths.branch_dir("feat").unwrap()).unwrap();
+        decide(&ledger, "main", "db.engine", "postgres");
+        decide(&ledger, "feat", "db.engine", "sqlite");
+        decide(&ledger, "feat", "cache.ttl", "60");
+        drop(ledger);
+
+        let err = execute(tmp.path(), "feat", "main", "land feat", None, None, false).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("db.engine: postgres (main) vs sqlite (feat)"));
+        assert!(msg.contains("--strategy"));
+
+        // Dry run only reports.
+        execute(tmp.path(), "feat", "main", "land feat", None, None, true).unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        assert!(ledger
+            .iter_events()
+            .unwrap()
+            .iter()
+            .all(|e| e.event_type != "merge"));
+
+        let diff = decision_diff(&ledger, "feat", "main").unwrap();
+        let report = render_diff("feat", "main", &diff);
+        assert!(report.contains("Conflicting (1): needs --strategy"));
+        assert!(report.contains("Only on feat (1): not carried over\n  cache.ttl = 60"));
+    }
+
+    #[test]
+    fn strategy_writes_to_protected_destination_need_an_override_role() {
+        let tmp = tempfile::tempdir().unwrap();
+        Ledger::ensure_initialized(tmp.path()).unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        std::fs::create_dir_all(ledger.paths.branch_dir("feat").unwrap()).unwrap();
+        std::fs::write(
+            ledger.paths.edda_dir.join("policy.yaml"),
+            "version: 2\nprotected_branches:\n  - branch: main\n    override_roles: [lead]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            ledger.paths.edda_dir.join("actors.yaml"),
+            "version: 1\nactors:\n  alice:\n    roles: [lead]\n",
+        )
+        .unwrap();
+        decide(&ledger, "main", "db.engine", "postgres");
+        decide(&ledger, "feat", "db.engine", "sqlite");
+        drop(ledger);
+
+        let theirs = Some(MergeStrategy::Theirs);
+        let err = execute(tmp.path(), "feat", "main", "land", theirs, None, false).unwrap_err();
+        assert!(err.to_string().contains("branch 'main' is protected"));
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        assert_eq!(
+            ledger
+                .find_active_decision("main", "db.engine")
+                .unwrap()
+                .unwrap()
+                .value,
+            "postgres"
+        );
+        drop(ledger);
+
+        // `ours` keeps DST's decisions, so nothing needs a draft.
+        let ours = Some(MergeStrategy::Ours);
+        execute(tmp.path(), "feat", "main", "land", ours, None, false).unwrap();
+        execute(
+            tmp.path(),
+            "feat",
+            "main",
+            "land",
+            theirs,
+            Some("alice"),
+            false,
+        )
+        .unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let active = ledger.find_active_decision("main", "db.engine").unwrap();
+        assert_eq!(active.unwrap().value, "sqlite");
+    }
+
+    #[test]
+    fn interactive_answers_pick_a_side_or_a_custom_value() {
+        let tmp = tempfile::tempdir().unwrap();
+        Ledger::ensure_initialized(tmp.path()).unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        decide(&ledger, "main", "lint.tools", "clippy");
+        decide(&ledger, "feat", "lint.tools", "deny");
+        let conflict = decision_conflicts(&ledger, "feat", "main")
+            .unwrap()
+            .remove(0);
+
+        assert_eq!(parse_choice("\n", &conflict), None);
+        assert_eq!(parse_choice("o\n", &conflict).as_deref(), Some("clippy"));
+        assert_eq!(parse_choice("theirs", &conflict).as_deref(), Some("deny"));
+        assert_eq!(parse_choice("u", &conflict).as_deref(), Some("clippy,deny"));
+        assert_eq!(
+            parse_choice(" clippy,rustfmt \n", &conflict).as_deref(),
+            Some("clippy,rustfmt")
+        );
+
+        let mut input = std::io::Cursor::new("\nt\n");
+        let value = prompt_resolution(&mut input, "feat", "main", &conflict).unwrap();
+        assert_eq!(value, "deny");
+        let mut empty = std::io::Cursor::new("");
+        assert!(prompt_resolution(&mut empty, "feat", "main", &conflict).is_err());
+    }
+}
@@ -1,10 +1,16 @@
-use edda_core::event::new_note_event;
+use edda_core::event::{attach_files, new_note_event};
 use edda_core::secret_guard::redact;
 use edda_ledger::lock::WorkspaceLock;
-use edda_ledger::Ledger;
-use std::path::Path;
+use edda_ledger::{blob_put_attachment, Ledger};
+use std::path::{Path, PathBuf};
 
-pub fn execute(repo_root: &Path, text: &str, role: &str, tags: &[String]) -> anyhow::Result<()> {
+pub fn execute(
+    repo_root: &Path,
+    text: &str,
+    role: &str,
+    tags: &[String],
+    attach: &[PathBuf],
+) -> anyhow::Result<()> {
     let ledger = Ledger::open(repo_root)?;
     let _lock = WorkspaceLock::acquire(&ledger.paths)?;
 
@@ -22,10 +28,18 @@ pub fn execute(repo_root: &Path, text: &str, role: &str, tags: &[String]) -> any
         );
     }
 
-    let event = new_note_event(&branch, parent_hash.as_deref(), role, &safe_text, tags)?;
-    ledger.append_event(&event)?;
+    let mut event = new_note_event(&branch, parent_hash.as_deref(), role, &safe_text, tags)?;
+    let attachments = attach
+        .iter()
+        .map(|f| blob_put_attachment(&ledger.paths, f))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    attach_files(&mut event, &attachments)?;
+    ledger.append_event_rebasing(&event)?;
 
     println!("Wrote NOTE {}", event.event_id);
+    for a in &attachments {
+        println!("  attached: {} ({})", a.name, a.blob);
+    }
 
     // Refresh derived markdown views (log.md / main.md / commit.md) so operators
     // reading the ledger by eye see the note immediately, not only after the
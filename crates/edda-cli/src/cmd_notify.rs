@@ -8,6 +8,20 @@ pub enum NotifyCmd {
     Test,
     /// Show configured notification channels
     Status,
+    /// Send queued notifications now (quiet hours, rate limit, pending retries)
+    Flush,
+    /// Send the summary of every digest channel whose schedule has come due
+    Digest {
+        /// Send every digest channel's summary now, due or not
+        #[arg(long)]
+        now: bool,
+    },
+    /// Render a sample notification per channel type with the configured templates
+    Preview {
+        /// Event type: approval_pending, phase_change, session_end, anomaly, plan_gate, digest or rule_match
+        #[arg(default_value = "approval_pending")]
+        event: String,
+    },
 }
 
 pub fn run(cmd: NotifyCmd, repo_root: &Path) -> anyhow::Result<()> {
@@ -17,6 +31,9 @@ pub fn run(cmd: NotifyCmd, repo_root: &Path) -> anyhow::Result<()> {
     match cmd {
         NotifyCmd::Test => run_test(&config),
         NotifyCmd::Status => run_status(&config),
+        NotifyCmd::Flush => run_flush(&config),
+        NotifyCmd::Digest { now } => run_digest(&config, now),
+        NotifyCmd::Preview { event } => run_preview(&config, &event),
     }
 }
 
@@ -52,8 +69,108 @@ fn run_status(config: &edda_notify::NotifyConfig) -> anyhow::Result<()> {
     }
 
     println!("{} channel(s) configured:", config.channels.len());
+    let next: std::collections::HashMap<_, _> = edda_notify::next_digests(config)
+        .into_iter()
+        .map(|(name, schedule, at)| (name, (schedule, at)))
+        .collect();
     for ch in &config.channels {
-        println!("  - {}", ch.display_name());
+        let name = ch.display_name();
+        match next.get(&name) {
+            Some((schedule, at)) => println!(
+                "  - {name} — digest {schedule}, next at {}",
+                at.format(&time::format_description::well_known::Rfc3339)
+                    .unwrap_or_default()
+            ),
+            None => println!("  - {name}"),
+        }
+    }
+    if let Some(q) = &config.quiet_hours {
+        let offset = q.utc_offset.as_deref().unwrap_or("UTC");
+        let state = if config.quiet_remaining().is_some() {
+            "active now"
+        } else {
+            "inactive"
+        };
+        println!("Quiet hours: {}-{} ({offset}), {state}", q.start, q.end);
+    }
+    let d = &config.delivery;
+    println!(
+        "Delivery: up to {} attempt(s), backoff from {}s, dedup {}s, {}/min",
+        d.max_attempts, d.backoff_secs, d.dedup_window_secs, d.rate_limit_per_minute
+    );
+    if !config.templates.is_empty() {
+        println!(
+            "Templates: {} event(s) — `edda notify preview <event>` renders them",
+            config.templates.0.len()
+        );
+    }
+    if !config.rules.is_empty() {
+        println!(
+            "{} rule(s) evaluated on ledger appends:",
+            config.rules.len()
+        );
+        for rule in &config.rules {
+            println!("  - {}", rule.name);
+        }
+    }
+    let queued = edda_notify::queued_count(config);
+    if queued > 0 {
+        println!("{queued} notification(s) queued — `edda notify flush` sends them now");
+    }
+    Ok(())
+}
+
+fn run_flush(config: &edda_notify::NotifyConfig) -> anyhow::Result<()> {
+    let report = edda_notify::flush_queue(config);
+    println!("Flushed {} queued notification(s).", report.sent);
+    if report.pending > 0 {
+        println!(
+            "  {} still failing — kept in the queue for another retry",
+            report.pending
+        );
+    }
+    if report.dropped > 0 {
+        println!(
+            "  {} dropped after {} failed attempts",
+            report.dropped, config.delivery.max_attempts
+        );
+    }
+    Ok(())
+}
+
+fn run_digest(config: &edda_notify::NotifyConfig, now: bool) -> anyhow::Result<()> {
+    if !config
+        .channels
+        .iter()
+        .any(|c| c.mode() == edda_notify::ChannelMode::Digest)
+    {
+        println!("No digest channels configured.");
+        return Ok(());
+    }
+    let report = edda_notify::send_due_digests(config, now);
+    println!("Sent {} digest(s).", report.sent);
+    if report.empty > 0 {
+        println!("  {} had nothing to report", report.empty);
+    }
+    if report.failed > 0 {
+        println!(
+            "  {} failed — retried on the next notification or `edda notify digest`",
+            report.failed
+        );
+    }
+    Ok(())
+}
+
+fn run_preview(config: &edda_notify::NotifyConfig, event: &str) -> anyhow::Result<()> {
+    let Some(sample) = edda_notify::sample_event(event) else {
+        anyhow::bail!(
+            "unknown event {event:?}: expected approval_pending, phase_change, session_end, anomaly, plan_gate, digest or rule_match"
+        );
+    };
+    for (kind, text) in edda_notify::preview(&config.templates, &sample) {
+        println!("── {kind} ──");
+        println!("{text}");
+        println!();
     }
     Ok(())
 }
@@ -0,0 +1,155 @@
+use edda_core::event::new_pin_event;
+use edda_core::types::Event;
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::Ledger;
+use std::path::Path;
+
+/// `edda pin <event_id> [--reason <text>]`
+///
+/// Appends a `pin` event for a decision or note. Pinned items are rendered
+/// in every context snapshot and hot pack, inside their own budget slice.
+pub fn pin(repo_root: &Path, event_id: &str, reason: Option<&str>) -> anyhow::Result<()> {
+    let reason = reason.map(str::trim).filter(|r| !r.is_empty());
+
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let Some(target) = ledger.get_event(event_id)? else {
+        anyhow::bail!("no event with id {event_id}");
+    };
+    if target.event_type != "note" {
+        anyhow::bail!(
+            "{event_id} is a {} event; only decisions and notes can be pinned",
+            target.event_type
+        );
+    }
+    if ledger.revoked_events()?.contains_key(event_id) {
+        anyhow::bail!("{event_id} is revoked; revoked items cannot be pinned");
+    }
+    if is_pinned(&ledger, event_id)? {
+        anyhow::bail!("{event_id} is already pinned");
+    }
+
+    let branch = ledger.head_branch()?;
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_pin_event(&branch, parent_hash.as_deref(), event_id, true, reason)?;
+    ledger.append_event(&event)?;
+
+    println!("Pinned {event_id}: {}", summary(&target));
+    Ok(())
+}
+
+/// `edda unpin <event_id>`
+pub fn unpin(repo_root: &Path, event_id: &str) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    if !is_pinned(&ledger, event_id)? {
+        anyhow::bail!("{event_id} is not pinned (see `edda pin`)");
+    }
+
+    let branch = ledger.head_branch()?;
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_pin_event(&branch, parent_hash.as_deref(), event_id, false, None)?;
+    ledger.append_event(&event)?;
+
+    println!("Unpinned {event_id}.");
+    Ok(())
+}
+
+/// `edda pin` with no event ID: list what is pinned, oldest first.
+pub fn list(repo_root: &Path, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let pinned = ledger.pinned_events()?;
+
+    if json {
+        let items: Vec<serde_json::Value> = pinned
+            .iter()
+            .map(|e| {
+                serde_json::json!({
+                    "event_id": e.event_id,
+                    "ts": e.ts,
+                    "branch": e.branch,
+                    "text": summary(e),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+
+    if pinned.is_empty() {
+        println!("Nothing pinned. Use `edda pin <event_id>` to pin a decision or note.");
+        return Ok(());
+    }
+    for e in &pinned {
+        println!("  {}  {}", e.event_id, summary(e));
+    }
+    Ok(())
+}
+
+fn is_pinned(ledger: &Ledger, event_id: &str) -> anyhow::Result<bool> {
+    Ok(ledger
+        .pinned_events()?
+        .iter()
+        .any(|e| e.event_id == event_id))
+}
+
+/// One-line summary: `key=value` for decisions, the note text otherwise.
+fn summary(event: &Event) -> String {
+    if let Some(dp) = edda_core::decision::extract_decision(&event.payload) {
+        return format!("{}={}", dp.key, dp.value);
+    }
+    event
+        .payload
+        .get("text")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pin_validates_targets_and_unpin_requires_a_pin() {
+        let dir = std::env::temp_dir().join(format!("edda_cmdpin_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Ledger::ensure_initialized(&dir).unwrap();
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let note = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "never touch the billing schema without a migration plan",
+            &[],
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+        drop(ledger);
+
+        assert!(pin(&dir, "evt_missing", None).is_err());
+        assert!(unpin(&dir, &note.event_id).is_err());
+        pin(&dir, &note.event_id, Some("invariant")).unwrap();
+        let err = pin(&dir, &note.event_id, None).unwrap_err();
+        assert!(err.to_string().contains("already pinned"));
+
+        let ledger = Ledger::open(&dir).unwrap();
+        assert_eq!(ledger.pinned_events().unwrap().len(), 1);
+        let pin_event = ledger.iter_events_by_type("pin").unwrap().remove(0);
+        assert_eq!(pin_event.payload["reason"], "invariant");
+        let err = pin(&dir, &pin_event.event_id, None).unwrap_err();
+        assert!(err.to_string().contains("only decisions and notes"));
+
+        unpin(&dir, &note.event_id).unwrap();
+        assert!(ledger.pinned_events().unwrap().is_empty());
+        // Unpinned items can be pinned again.
+        pin(&dir, &note.event_id, None).unwrap();
+        assert_eq!(ledger.pinned_events().unwrap().len(), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
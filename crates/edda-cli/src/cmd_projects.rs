@@ -0,0 +1,147 @@
+//! `edda projects` — the user-level registry of initialized workspaces.
+
+use clap::Subcommand;
+use edda_store::registry::{self, ProjectEntry};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Subcommand)]
+pub enum ProjectsCmd {
+    /// List registered workspaces with their last ledger activity
+    List {
+        /// Remove stale entries (projects whose .edda/ no longer exists)
+        #[arg(long)]
+        prune: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub fn execute(cmd: ProjectsCmd) -> anyhow::Result<()> {
+    match cmd {
+        ProjectsCmd::List { prune, json } => list(prune, json),
+    }
+}
+
+/// A registry entry plus what its workspace looks like right now.
+#[derive(Serialize)]
+struct ProjectRow {
+    #[serde(flatten)]
+    entry: ProjectEntry,
+    /// `ok`, or `stale` when the workspace's `.edda/` is gone.
+    status: &'static str,
+    /// Timestamp of the newest ledger event; `None` for stale or empty ledgers.
+    last_activity: Option<String>,
+}
+
+fn project_row(entry: ProjectEntry) -> ProjectRow {
+    let root = Path::new(&entry.path);
+    if !root.join(".edda").is_dir() {
+        return ProjectRow {
+            entry,
+            status: "stale",
+            last_activity: None,
+        };
+    }
+    let last_activity = edda_ledger::Ledger::open(root)
+        .and_then(|l| l.last_event_ts())
+        .ok()
+        .flatten();
+    ProjectRow {
+        entry,
+        status: "ok",
+        last_activity,
+    }
+}
+
+/// Shared with `edda user projects`.
+pub(crate) fn list(prune: bool, json: bool) -> anyhow::Result<()> {
+    if prune {
+        let (_valid, stale) = registry::validate_projects();
+        if stale.is_empty() {
+            println!("No stale projects found.");
+        } else {
+            for entry in &stale {
+                registry::unregister_project(&entry.project_id)?;
+                println!("  Removed: {} ({})", entry.name, entry.path);
+            }
+            println!("{} stale project(s) removed.", stale.len());
+        }
+        return Ok(());
+    }
+
+    let mut rows: Vec<ProjectRow> = registry::list_projects()
+        .into_iter()
+        .map(project_row)
+        .collect();
+    // Most recently active first; never-active and stale projects last.
+    rows.sort_by(|a, b| {
+        b.last_activity
+            .cmp(&a.last_activity)
+            .then_with(|| a.entry.name.cmp(&b.entry.name))
+    });
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    if rows.is_empty() {
+        println!("No registered projects. Run `edda init` in a repository to register it.");
+        return Ok(());
+    }
+
+    println!("Registered projects ({}):\n", rows.len());
+    for r in &rows {
+        let group = r
+            .entry
+            .group
+            .as_deref()
+            .map(|g| format!(" group={g}"))
+            .unwrap_or_default();
+        println!(
+            "  {} [{}] {}{group}\n    path: {}\n    last activity: {}\n",
+            r.entry.name,
+            r.status,
+            r.entry.project_id,
+            r.entry.path,
+            r.last_activity.as_deref().unwrap_or("(none)")
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_row_reads_last_activity_from_ledger() {
+        let _store = crate::test_support::isolated_store();
+        let tmp = tempfile::tempdir().unwrap();
+        edda_ledger::Ledger::ensure_initialized(tmp.path()).unwrap();
+        registry::register_project(tmp.path()).unwrap();
+
+        let ledger = edda_ledger::Ledger::open(tmp.path()).unwrap();
+        let note = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "hello",
+            &[],
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+
+        let entry = registry::list_projects().pop().unwrap();
+        let row = project_row(entry.clone());
+        assert_eq!(row.status, "ok");
+        assert_eq!(row.last_activity.as_deref(), Some(note.ts.as_str()));
+
+        drop(tmp);
+        let row = project_row(entry);
+        assert_eq!(row.status, "stale");
+        assert!(row.last_activity.is_none());
+    }
+}
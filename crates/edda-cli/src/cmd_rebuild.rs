@@ -1,5 +1,5 @@
 use edda_core::event::new_rebuild_event;
-use edda_derive::{rebuild_all, rebuild_branch};
+use edda_derive::{rebuild_all_full, rebuild_branch_full};
 use edda_ledger::lock::WorkspaceLock;
 use edda_ledger::Ledger;
 use std::path::Path;
@@ -20,7 +20,7 @@ pub fn execute(
         let event = new_rebuild_event(&head, parent_hash.as_deref(), "all", None, reason)?;
         ledger.append_event(&event)?;
 
-        let snaps = rebuild_all(&ledger)?;
+        let snaps = rebuild_all_full(&ledger)?;
         println!("Rebuilt views for all branches ({} branches).", snaps.len());
     } else {
         let target = branch.unwrap_or(&head);
@@ -33,7 +33,7 @@ pub fn execute(
         )?;
         ledger.append_event(&event)?;
 
-        rebuild_branch(&ledger, target)?;
+        rebuild_branch_full(&ledger, target)?;
         println!("Rebuilt views for {target}.");
     }
 
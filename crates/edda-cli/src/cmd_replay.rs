@@ -0,0 +1,405 @@
+//! `edda replay <session>` — a past session as one interleaved narrative.
+//!
+//! Merges the session's transcript (user prompts, assistant replies, tool
+//! calls, read through the store index) with the ledger events written while
+//! it ran (decisions, commits, merges) and its recap, ordered by timestamp.
+//! Meant for reviewing what an agent actually did, step by step, after the
+//! fact.
+
+use edda_core::decision::extract_decision;
+use edda_core::Event;
+use edda_index::IndexRecordV1;
+use edda_ledger::Ledger;
+use serde::Serialize;
+use std::path::Path;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+/// Characters shown per entry unless `--full`.
+const PREVIEW_CHARS: usize = 160;
+/// Ledger writes from the session's last turn can land after its final
+/// transcript record.
+const TRAILING_GRACE_SECS: i64 = 120;
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Entry {
+    pub(crate) ts: String,
+    pub(crate) kind: &'static str,
+    pub(crate) text: String,
+    /// A tool call's arguments, one `key: value` per line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) detail: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Replay {
+    pub(crate) session_id: String,
+    pub(crate) started_at: String,
+    pub(crate) ended_at: String,
+    pub(crate) prompts: usize,
+    pub(crate) entries: Vec<Entry>,
+}
+
+pub fn execute(repo_root: &Path, session: &str, full: bool, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let project_dir = edda_store::project_dir(&edda_store::project_id(repo_root));
+    let session_id = resolve_session(&project_dir, session)?;
+    let replay = build(&ledger, &project_dir, &session_id)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&replay)?);
+    } else {
+        print!("{}", render(&replay, full));
+    }
+    Ok(())
+}
+
+/// Indexed sessions of a project with when each was last written, newest
+/// first.
+pub(crate) fn indexed_sessions(project_dir: &Path) -> Vec<(String, OffsetDateTime)> {
+    let mut sessions: Vec<(String, OffsetDateTime)> = std::fs::read_dir(project_dir.join("index"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let name = e.file_name().into_string().ok()?;
+            let id = name.strip_suffix(".jsonl")?.to_string();
+            let modified = e.metadata().ok()?.modified().ok()?;
+            Some((id, OffsetDateTime::from(modified)))
+        })
+        .collect();
+    sessions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    sessions
+}
+
+/// Accept a full session id or an unambiguous prefix of one.
+fn resolve_session(project_dir: &Path, session: &str) -> anyhow::Result<String> {
+    let index_dir = project_dir.join("index");
+    if index_dir.join(format!("{session}.jsonl")).exists() {
+        return Ok(session.to_string());
+    }
+    let mut matches: Vec<String> = std::fs::read_dir(&index_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|e| {
+            let name = e.file_name().into_string().ok()?;
+            let id = name.strip_suffix(".jsonl")?;
+            id.starts_with(session).then(|| id.to_string())
+        })
+        .collect();
+    matches.sort();
+    match matches.len() {
+        0 => anyhow::bail!("no indexed session matches {session:?}"),
+        1 => Ok(matches.remove(0)),
+        n => anyhow::bail!("{n} sessions match {session:?}: {}", matches.join(", ")),
+    }
+}
+
+pub(crate) fn build(
+    ledger: &Ledger,
+    project_dir: &Path,
+    session_id: &str,
+) -> anyhow::Result<Replay> {
+    let index_path = project_dir
+        .join("index")
+        .join(format!("{session_id}.jsonl"));
+    let store_path =
+        edda_store::store_subdir(project_dir, "transcripts").join(format!("{session_id}.jsonl"));
+    let records = edda_index::read_index_tail(&index_path, usize::MAX, u64::MAX)?;
+
+    let mut entries = Vec::new();
+    let mut prompts = 0;
+    for record in &records {
+        let raw = edda_index::fetch_store_line(&store_path, record.store_offset, record.store_len)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<serde_json::Value>(&bytes).ok());
+        let before = entries.len();
+        transcript_entries(record, raw.as_ref(), &mut entries);
+        if record.record_type == "user" && entries.len() > before {
+            prompts += 1;
+        }
+    }
+
+    let started = records.iter().find_map(|r| parse_ts(&r.ts));
+    let ended = records.iter().rev().find_map(|r| parse_ts(&r.ts));
+    if let (Some(start), Some(end)) = (started, ended) {
+        let window_end = end + time::Duration::seconds(TRAILING_GRACE_SECS);
+        for event in ledger.iter_events()? {
+            let in_window = parse_ts(&event.ts).is_some_and(|t| t >= start && t <= window_end);
+            let tagged =
+                event.payload.get("session_id").and_then(|v| v.as_str()) == Some(session_id);
+            if in_window || tagged {
+                if let Some(entry) = ledger_entry(&event) {
+                    entries.push(entry);
+                }
+            }
+        }
+    }
+    // Stable: a tool call keeps its place after the reply that made it.
+    entries.sort_by_key(|e| parse_ts(&e.ts));
+
+    let fmt =
+        |t: Option<OffsetDateTime>| t.and_then(|t| t.format(&Rfc3339).ok()).unwrap_or_default();
+    Ok(Replay {
+        session_id: session_id.to_string(),
+        started_at: fmt(started),
+        ended_at: fmt(ended),
+        prompts,
+        entries,
+    })
+}
+
+/// Prompts, replies and tool calls from one transcript record. Falls back
+/// to the index's tool names when the stored line is unavailable.
+fn transcript_entries(
+    record: &IndexRecordV1,
+    raw: Option<&serde_json::Value>,
+    out: &mut Vec<Entry>,
+) {
+    let entry = |kind, text: String| Entry {
+        ts: record.ts.clone(),
+        kind,
+        text,
+        detail: None,
+    };
+    let content = raw.and_then(|r| r.get("message")?.get("content"));
+    match record.record_type.as_str() {
+        "user" => {
+            // Tool results come back as user records with no text blocks.
+            let text = match content {
+                Some(serde_json::Value::String(s)) => s.clone(),
+                Some(serde_json::Value::Array(blocks)) => block_texts(blocks),
+                _ => String::new(),
+            };
+            if !text.trim().is_empty() {
+                out.push(entry("user", text));
+            }
+        }
+        "assistant" => {
+            let Some(serde_json::Value::Array(blocks)) = content else {
+                let names = record
+                    .assistant
+                    .as_ref()
+                    .map(|a| a.tool_use_names.clone())
+                    .unwrap_or_default();
+                out.extend(names.into_iter().map(|n| entry("tool", n)));
+                return;
+            };
+            let text = block_texts(blocks);
+            if !text.trim().is_empty() {
+                out.push(entry("assistant", text));
+            }
+            for block in blocks {
+                if block.get("type").and_then(|t| t.as_str()) == Some("tool_use") {
+                    out.push(Entry {
+                        detail: tool_detail(block),
+                        ..entry("tool", tool_call(block))
+                    });
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn block_texts(blocks: &[serde_json::Value]) -> String {
+    blocks
+        .iter()
+        .filter(|b| b.get("type").and_then(|t| t.as_str()) == Some("text"))
+        .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `Bash cargo test`, `Edit src/lib.rs`, `Grep TODO` — the tool name plus
+/// its most telling argument.
+fn tool_call(block: &serde_json::Value) -> String {
+    let name = block.get("name").and_then(|n| n.as_str()).unwrap_or("?");
+    let input = block.get("input");
+    let arg = ["command", "file_path", "path", "pattern", "url", "query"]
+        .iter()
+        .find_map(|k| input?.get(*k)?.as_str());
+    match arg {
+        Some(arg) => format!("{name} {arg}"),
+        None => name.to_string(),
+    }
+}
+
+/// A tool call's arguments, one `key: value` per line, each value
+/// previewed.
+fn tool_detail(block: &serde_json::Value) -> Option<String> {
+    let input = block.get("input")?.as_object()?;
+    let lines: Vec<String> = input
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => preview(s),
+                other => preview(&other.to_string()),
+            };
+            format!("{key}: {value}")
+        })
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+fn ledger_entry(event: &Event) -> Option<Entry> {
+    let str_field = |k: &str| event.payload.get(k).and_then(|v| v.as_str());
+    let (kind, text) = match event.event_type.as_str() {
+        "commit" => ("commit", str_field("title")?.to_string()),
+        "merge" => (
+            "merge",
+            format!("{} → {}", str_field("src")?, str_field("dst")?),
+        ),
+        "recap" => ("recap", str_field("what_happened")?.to_string()),
+        "note" => {
+            let d = extract_decision(&event.payload)?;
+            ("decision", format!("{} = {}", d.key, d.value))
+        }
+        _ => return None,
+    };
+    Some(Entry {
+        ts: event.ts.clone(),
+        kind,
+        text,
+        detail: None,
+    })
+}
+
+fn parse_ts(ts: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts, &Rfc3339).ok()
+}
+
+fn render(replay: &Replay, full: bool) -> String {
+    let clock = |ts: &str| {
+        parse_ts(ts)
+            .map(|t| format!("{:02}:{:02}:{:02}", t.hour(), t.minute(), t.second()))
+            .unwrap_or_else(|| "--:--:--".to_string())
+    };
+    let mut out = format!(
+        "Session {} — {} → {} ({} prompt(s))\n\n",
+        replay.session_id, replay.started_at, replay.ended_at, replay.prompts
+    );
+    for e in &replay.entries {
+        let text = if full {
+            e.text.trim().replace('\n', "\n                     ")
+        } else {
+            preview(&e.text)
+        };
+        out.push_str(&format!("{}  {:<9}  {}\n", clock(&e.ts), e.kind, text));
+    }
+    out
+}
+
+/// First non-empty line, cut at [`PREVIEW_CHARS`].
+fn preview(text: &str) -> String {
+    let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty());
+    let first = lines.next().unwrap_or("");
+    let more = lines.next().is_some();
+    match first.char_indices().nth(PREVIEW_CHARS) {
+        Some((i, _)) => format!("{}...", &first[..i]),
+        None if more => format!("{first} ..."),
+        None => first.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{finalize_event, new_commit_event, CommitEventParams};
+
+    fn write_session(project_dir: &Path, session_id: &str, records: &[serde_json::Value]) {
+        let mut store = String::new();
+        let mut index = String::new();
+        for record in records {
+            let line = record.to_string();
+            let rec = edda_index::build_index_record(
+                session_id,
+                store.len() as u64,
+                line.len() as u64 + 1,
+                record,
+            );
+            store.push_str(&line);
+            store.push('\n');
+            index.push_str(&serde_json::to_string(&rec).unwrap());
+            index.push('\n');
+        }
+        for (dir, text) in [("transcripts", store), ("index", index)] {
+            std::fs::create_dir_all(project_dir.join(dir)).unwrap();
+            std::fs::write(
+                project_dir.join(dir).join(format!("{session_id}.jsonl")),
+                text,
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn interleaves_transcript_and_ledger_by_time() {
+        let repo = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let ledger = Ledger::open_or_init(repo.path()).unwrap();
+        write_session(
+            project.path(),
+            "sess-abc",
+            &[
+                serde_json::json!({"type": "user", "timestamp": "2026-03-01T10:00:00Z",
+                    "message": {"content": "add a health check"}}),
+                serde_json::json!({"type": "assistant", "timestamp": "2026-03-01T10:01:00Z",
+                "message": {"content": [
+                    {"type": "text", "text": "Adding /health."},
+                    {"type": "tool_use", "name": "Bash", "id": "t1",
+                        "input": {"command": "cargo test"}}
+                ]}}),
+                serde_json::json!({"type": "user", "timestamp": "2026-03-01T10:01:05Z",
+                    "message": {"content": [{"type": "tool_result", "tool_use_id": "t1"}]}}),
+                serde_json::json!({"type": "assistant", "timestamp": "2026-03-01T10:05:00Z",
+                    "message": {"content": [{"type": "text", "text": "Done."}]}}),
+            ],
+        );
+        let mut commit = new_commit_event(&mut CommitEventParams {
+            branch: "main",
+            parent_hash: None,
+            title: "add health check",
+            purpose: None,
+            prev_summary: "",
+            contribution: "",
+            evidence: vec![],
+            labels: vec![],
+        })
+        .unwrap();
+        commit.ts = "2026-03-01T10:03:00Z".into();
+        finalize_event(&mut commit).unwrap();
+        ledger.append_event(&commit).unwrap();
+
+        assert_eq!(
+            resolve_session(project.path(), "sess-a").unwrap(),
+            "sess-abc"
+        );
+        let replay = build(&ledger, project.path(), "sess-abc").unwrap();
+        assert_eq!(replay.prompts, 1, "tool results are not prompts");
+        let kinds: Vec<_> = replay.entries.iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, ["user", "assistant", "tool", "commit", "assistant"]);
+        assert_eq!(
+            replay.entries[2].detail.as_deref(),
+            Some("command: cargo test")
+        );
+
+        let out = render(&replay, false);
+        assert!(
+            out.contains("10:01:00  tool       Bash cargo test"),
+            "{out}"
+        );
+        assert!(
+            out.contains("10:03:00  commit     add health check"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn unknown_session_is_an_error() {
+        let project = tempfile::tempdir().unwrap();
+        let err = resolve_session(project.path(), "nope").unwrap_err();
+        assert!(err.to_string().contains("no indexed session"), "{err}");
+    }
+}
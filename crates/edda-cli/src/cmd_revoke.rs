@@ -0,0 +1,84 @@
+use edda_core::event::new_revoke_event;
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::Ledger;
+use std::path::Path;
+
+/// `edda revoke <event_id> --reason <text>`
+///
+/// Appends a `revoke` event pointing at a decision or note. The target stays
+/// in the ledger; a revoked decision's key becomes inactive until decided
+/// again, and `ask`/context mark the target as revoked.
+pub fn execute(repo_root: &Path, event_id: &str, reason: &str) -> anyhow::Result<()> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+        anyhow::bail!("--reason must not be empty");
+    }
+
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let Some(target) = ledger.get_event(event_id)? else {
+        anyhow::bail!("no event with id {event_id}");
+    };
+    if target.event_type != "note" {
+        anyhow::bail!(
+            "{event_id} is a {} event; only decisions and notes can be revoked",
+            target.event_type
+        );
+    }
+    if ledger.revoked_events()?.contains_key(event_id) {
+        anyhow::bail!("{event_id} is already revoked");
+    }
+
+    // Revoke on the target's branch so its branch view and decisions row
+    // pick the revocation up, whatever HEAD currently is.
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_revoke_event(&target.branch, parent_hash.as_deref(), &target, reason)?;
+    ledger.append_event(&event)?;
+    let _ = edda_derive::rebuild_branch(&ledger, &target.branch);
+
+    match event.payload.get("key").and_then(|k| k.as_str()) {
+        Some(key) => println!("Revoked decision '{key}' ({event_id}) — key is now inactive."),
+        None => println!("Revoked note {event_id}."),
+    }
+    println!("  reason: {reason}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revoke_rejects_non_notes_and_double_revocation() {
+        let dir = std::env::temp_dir().join(format!("edda_cmdrevoke_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Ledger::ensure_initialized(&dir).unwrap();
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let note = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "try redis",
+            &[],
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+        drop(ledger);
+
+        assert!(execute(&dir, "evt_missing", "x").is_err());
+        assert!(execute(&dir, &note.event_id, "  ").is_err());
+        execute(&dir, &note.event_id, "never mind").unwrap();
+        let err = execute(&dir, &note.event_id, "again").unwrap_err();
+        assert!(err.to_string().contains("already revoked"));
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let revoke = ledger.iter_events_by_type("revoke").unwrap().remove(0);
+        let err = execute(&dir, &revoke.event_id, "x").unwrap_err();
+        assert!(err.to_string().contains("only decisions and notes"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,180 @@
+//! `edda scratch` — the current session's scratchpad.
+//!
+//! Working state that does not merit a ledger event but should survive a
+//! resume or compaction. Stored per session under the project's `state/`
+//! directory (see `edda_store::scratch`) and injected at SessionStart.
+
+use clap::Subcommand;
+use edda_store::scratch;
+use std::path::Path;
+
+// ── CLI Schema ──
+
+#[derive(Subcommand)]
+pub enum ScratchCmd {
+    /// Set a key, or replace the freeform text with --text
+    Set {
+        /// Key to set
+        #[arg(required_unless_present = "text", requires = "value")]
+        key: Option<String>,
+        /// Value for the key
+        value: Option<String>,
+        /// Replace the freeform text block instead of setting a key
+        #[arg(long, conflicts_with = "key")]
+        text: Option<String>,
+    },
+    /// Print one key, or the whole pad when no key is given
+    Get {
+        /// Key to read
+        key: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Remove a key
+    Unset {
+        /// Key to remove
+        key: String,
+    },
+    /// Empty the pad
+    Clear,
+}
+
+// ── Dispatch ──
+
+pub fn run(cmd: ScratchCmd, repo_root: &Path, session: Option<&str>) -> anyhow::Result<()> {
+    let project_id = edda_store::project_id(repo_root);
+    let session_id = resolve_session(&project_id, session)?;
+    match cmd {
+        ScratchCmd::Set { key, value, text } => {
+            scratch::update(&project_id, &session_id, |pad| match (key, value, text) {
+                (Some(k), Some(v), _) => {
+                    pad.entries.insert(k, v);
+                }
+                (_, _, Some(t)) => pad.text = t,
+                _ => {}
+            })?;
+            println!("Scratchpad updated for session {session_id}");
+            Ok(())
+        }
+        ScratchCmd::Get { key, json } => get(&project_id, &session_id, key.as_deref(), json),
+        ScratchCmd::Unset { key } => {
+            let mut removed = false;
+            scratch::update(&project_id, &session_id, |pad| {
+                removed = pad.entries.remove(&key).is_some();
+            })?;
+            if removed {
+                println!("Removed {key}");
+            } else {
+                println!("No key {key} in the scratchpad");
+            }
+            Ok(())
+        }
+        ScratchCmd::Clear => {
+            scratch::update(&project_id, &session_id, |pad| {
+                *pad = scratch::Scratchpad::default()
+            })?;
+            println!("Scratchpad cleared for session {session_id}");
+            Ok(())
+        }
+    }
+}
+
+// ── Command Implementations ──
+
+fn get(project_id: &str, session_id: &str, key: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let pad = scratch::read(project_id, session_id);
+    if let Some(key) = key {
+        let Some(value) = pad.entries.get(key) else {
+            anyhow::bail!("No key {key} in the scratchpad for session {session_id}");
+        };
+        if json {
+            println!("{}", serde_json::json!({ "key": key, "value": value }));
+        } else {
+            println!("{value}");
+        }
+        return Ok(());
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&pad)?);
+        return Ok(());
+    }
+    match pad.render() {
+        Some(section) => print!("{section}"),
+        None => println!("Scratchpad is empty for session {session_id}"),
+    }
+    Ok(())
+}
+
+/// The session whose pad to use: `--session`, then `EDDA_SESSION_ID`, then
+/// the sole active session.
+///
+/// Unlike the coordination commands there is no `cli-*` fallback: a pad
+/// written under an invented session id would never be injected anywhere, so
+/// the write would look successful and be lost.
+fn resolve_session(project_id: &str, cli_session: Option<&str>) -> anyhow::Result<String> {
+    if let Some(sid) = cli_session.filter(|s| !s.is_empty()) {
+        return Ok(sid.to_string());
+    }
+    if let Ok(sid) = std::env::var("EDDA_SESSION_ID") {
+        if !sid.is_empty() {
+            return Ok(sid);
+        }
+    }
+    if let Some((sid, _label)) = edda_bridge_claude::peers::infer_session_id(project_id) {
+        return Ok(sid);
+    }
+    anyhow::bail!(
+        "No session to attach the scratchpad to: pass --session <id> or set EDDA_SESSION_ID"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_unset_and_clear_round_trip() {
+        let _store = crate::test_support::isolated_store();
+        let tmp = tempfile::tempdir().unwrap();
+        let pid = edda_store::project_id(tmp.path());
+
+        run(
+            ScratchCmd::Set {
+                key: Some("focus".into()),
+                value: Some("tokenizer".into()),
+                text: None,
+            },
+            tmp.path(),
+            Some("s1"),
+        )
+        .unwrap();
+        run(
+            ScratchCmd::Set {
+                key: None,
+                value: None,
+                text: Some("halfway through bigram tests".into()),
+            },
+            tmp.path(),
+            Some("s1"),
+        )
+        .unwrap();
+        let pad = scratch::read(&pid, "s1");
+        assert_eq!(pad.entries["focus"], "tokenizer");
+        assert_eq!(pad.text, "halfway through bigram tests");
+
+        run(
+            ScratchCmd::Unset {
+                key: "focus".into(),
+            },
+            tmp.path(),
+            Some("s1"),
+        )
+        .unwrap();
+        assert!(scratch::read(&pid, "s1").entries.is_empty());
+
+        run(ScratchCmd::Clear, tmp.path(), Some("s1")).unwrap();
+        assert!(scratch::read(&pid, "s1").is_empty());
+    }
+}
@@ -1,7 +1,8 @@
 use clap::Subcommand;
 use edda_index::fetch_store_line;
 use edda_ledger::Ledger;
-use edda_search_fts::{schema, search, sync};
+use edda_search_fts::analyzer::AnalyzerConfig;
+use edda_search_fts::{schema, search, stats, sync};
 use edda_store::project_dir;
 use std::path::{Path, PathBuf};
 
@@ -38,7 +39,8 @@ pub enum SearchCmd {
         /// Exact match (disable fuzzy)
         #[arg(long)]
         exact: bool,
-        /// Maximum results, per project when --fleet (default: 20)
+        /// Maximum results, per project when --fleet; in total when
+        /// --all-projects (default: 20)
         #[arg(long, default_value_t = 20)]
         limit: usize,
         /// Search every project in the fleet, not just this workspace
@@ -50,6 +52,22 @@ pub enum SearchCmd {
         /// remove (GH-407).
         #[arg(long, conflicts_with = "project")]
         fleet: bool,
+        /// Search every project index in the local store, merged by score
+        ///
+        /// Wider than `--fleet`: it reads each index under
+        /// `~/.edda/projects` whether or not the project is registered or its
+        /// repo is checked out here, and ranks all hits as one list.
+        #[arg(long, conflicts_with_all = ["project", "fleet"])]
+        all_projects: bool,
+    },
+    /// Report index size, document counts, query latency and staleness
+    Stats {
+        /// Project ID (defaults to current repo)
+        #[arg(long)]
+        project: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Show full content of a specific turn
     Show {
@@ -80,7 +98,18 @@ pub fn run_cmd(cmd: SearchCmd, repo_root: &Path) -> anyhow::Result<()> {
             exact,
             limit,
             fleet,
+            all_projects,
         } => {
+            if all_projects {
+                let opts = search::SearchOptions {
+                    project_id: None,
+                    session_id: session.as_deref(),
+                    doc_type: doc_type.as_deref(),
+                    event_type: event_type.as_deref(),
+                    exact,
+                };
+                return query_all_projects(&q, &opts, limit);
+            }
             let pid = project.as_deref().unwrap_or(&default_pid);
             query(
                 repo_root,
@@ -94,6 +123,10 @@ pub fn run_cmd(cmd: SearchCmd, repo_root: &Path) -> anyhow::Result<()> {
                 fleet,
             )
         }
+        SearchCmd::Stats { project, json } => {
+            let pid = project.as_deref().unwrap_or(&default_pid);
+            show_stats(repo_root, pid, json)
+        }
         SearchCmd::Show { turn, project } => {
             let pid = project.as_deref().unwrap_or(&default_pid);
             show(pid, &turn)
@@ -124,9 +157,7 @@ fn query_fleet(
     let scope = edda_store::registry::fleet_scope(repo_root);
 
     let (hits, misses) = crate::fleet::fan_out(&scope, |entry| {
-        let index_dir = project_dir(&entry.project_id)
-            .join("search")
-            .join("tantivy");
+        let index_dir = edda_store::project_subdir(&entry.project_id, "search").join("tantivy");
         if !index_dir.exists() {
             anyhow::bail!(
                 "index not built — run `edda search index --project {}`",
@@ -195,6 +226,158 @@ fn query_fleet(
     Ok(())
 }
 
+/// One `--all-projects` hit, tagged with the project whose index it came from.
+struct ProjectHit {
+    project: String,
+    result: search::SearchResult,
+}
+
+/// `edda search query --all-projects` — one query over every project index in
+/// the store, merged into a single ranked list.
+///
+/// Like `--fleet` it never builds: a project whose index is outdated or will not
+/// open is reported as a miss. A project with no index at all is only counted,
+/// since most stores hold projects that were never searched.
+fn query_all_projects(
+    query_str: &str,
+    opts: &search::SearchOptions<'_>,
+    limit: usize,
+) -> anyhow::Result<()> {
+    let projects_root = edda_store::store_root().join("projects");
+    let (hits, misses, searched, unindexed) =
+        search_all_projects(&projects_root, query_str, opts, limit, |pid| {
+            edda_store::registry::get_project(pid).map(|e| e.name)
+        })?;
+
+    if hits.is_empty() {
+        println!(
+            "{}",
+            crate::fleet::empty_summary(
+                "results",
+                &format!(" for: {query_str}"),
+                searched,
+                &misses
+            )
+        );
+    } else {
+        println!(
+            "Found {} result(s) across {searched} project(s) for: {query_str}\n",
+            hits.len()
+        );
+        for (i, h) in hits.iter().enumerate() {
+            let r = &h.result;
+            let type_label = if r.doc_type == "event" {
+                format!("[{}]", r.event_type)
+            } else {
+                "[turn]".to_string()
+            };
+            let sid_display = if r.session_id.is_empty() {
+                String::new()
+            } else {
+                format!(" session={}", &r.session_id[..r.session_id.len().min(8)])
+            };
+            println!(
+                "  {}. [{}] {} {}{} ts={} score={:.2}",
+                i + 1,
+                h.project,
+                type_label,
+                r.doc_id,
+                sid_display,
+                r.ts,
+                r.rank,
+            );
+            if !r.snippet.is_empty() {
+                println!("     {}\n", r.snippet.replace('\n', " "));
+            } else {
+                println!();
+            }
+        }
+    }
+    crate::fleet::print_misses(&misses);
+    if unindexed > 0 {
+        println!("  ({unindexed} project(s) have no search index and were skipped)");
+    }
+    Ok(())
+}
+
+/// Run the query against each project index under `projects_root` and keep the
+/// best `limit` hits overall, highest score first.
+///
+/// Scores come from each project's own index, so the merge ranks by relevance
+/// only approximately; ties keep project order. Returns the hits, the projects
+/// that could not be read, how many were searched, and how many had no index.
+/// `name_of` is injected (production reads the registry) so a test need not
+/// touch the process-wide store root.
+fn search_all_projects(
+    projects_root: &Path,
+    query_str: &str,
+    opts: &search::SearchOptions<'_>,
+    limit: usize,
+    name_of: impl Fn(&str) -> Option<String>,
+) -> anyhow::Result<(Vec<ProjectHit>, Vec<crate::fleet::FleetMiss>, usize, usize)> {
+    let mut pids: Vec<String> = match std::fs::read_dir(projects_root) {
+        Ok(entries) => entries
+            .filter_map(Result::ok)
+            .filter(|e| e.path().is_dir())
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+    pids.sort();
+
+    let mut hits = Vec::new();
+    let mut misses = Vec::new();
+    let mut searched = 0;
+    let mut unindexed = 0;
+    for pid in &pids {
+        let index_dir =
+            edda_store::store_subdir(&projects_root.join(pid), "search").join("tantivy");
+        if !index_dir.exists() {
+            unindexed += 1;
+            continue;
+        }
+        searched += 1;
+        let project = name_of(pid).unwrap_or_else(|| pid.clone());
+        if schema::index_is_outdated(&index_dir) {
+            misses.push(crate::fleet::FleetMiss {
+                project,
+                reason: format!(
+                    "index schema is outdated — run `edda search index --project {pid}`"
+                ),
+            });
+            continue;
+        }
+        let Some(index) = schema::open_index(&index_dir) else {
+            misses.push(crate::fleet::FleetMiss {
+                project,
+                reason: format!(
+                    "index could not be opened — run `edda search index --project {pid}` to rebuild"
+                ),
+            });
+            continue;
+        };
+        let per_project = search::SearchOptions {
+            project_id: Some(pid),
+            ..*opts
+        };
+        match search::search(&index, query_str, &per_project, limit) {
+            Ok(results) => hits.extend(results.into_iter().map(|result| ProjectHit {
+                project: project.clone(),
+                result,
+            })),
+            Err(e) => misses.push(crate::fleet::FleetMiss {
+                project,
+                reason: format!("{e}"),
+            }),
+        }
+    }
+
+    hits.sort_by(|a, b| b.result.rank.total_cmp(&a.result.rank));
+    hits.truncate(limit);
+    Ok((hits, misses, searched, unindexed))
+}
+
 /// Ask the rest of the fleet whether a local miss is really absence (GH-407,
 /// acceptance 4).
 ///
@@ -210,9 +393,7 @@ fn fleet_hint_for_query(
 ) -> Option<String> {
     let scope = edda_store::registry::fleet_scope(repo_root);
     crate::fleet::elsewhere_hint(&scope, project_id, "result", |entry| {
-        let index_dir = project_dir(&entry.project_id)
-            .join("search")
-            .join("tantivy");
+        let index_dir = edda_store::project_subdir(&entry.project_id, "search").join("tantivy");
         if !index_dir.exists() || schema::index_is_outdated(&index_dir) {
             anyhow::bail!("no usable index");
         }
@@ -246,7 +427,7 @@ pub fn query(
         );
     }
     let proj_dir = project_dir(project_id);
-    let index_dir = proj_dir.join("search").join("tantivy");
+    let index_dir = edda_store::store_subdir(&proj_dir, "search").join("tantivy");
 
     // GH-403: an unusable index is not a dead end. Announce, then fix it — a
     // silent 25s stall reads as a hang, and telling the user to go run another
@@ -294,7 +475,9 @@ pub fn query(
         event_type,
         exact,
     };
+    let started = std::time::Instant::now();
     let results = search::search(&index, query_str, &opts, limit)?;
+    let _ = stats::record_query(&proj_dir, started.elapsed());
 
     if results.is_empty() {
         println!("No results found for: {query_str}");
@@ -348,7 +531,7 @@ pub fn query(
 /// of this line is honesty about staleness, a confidently wrong count is worse
 /// than none, so it is omitted rather than guessed.
 fn print_watermark(repo_root: &Path, proj_dir: &Path, project_id: &str) {
-    let meta_path = proj_dir.join("search").join("meta.sqlite");
+    let meta_path = edda_store::store_subdir(proj_dir, "search").join("meta.sqlite");
     let Ok(conn) = schema::ensure_meta_db(&meta_path) else {
         return;
     };
@@ -453,6 +636,14 @@ pub fn index(repo_root: &Path, project_id: &str, session_id: Option<&str>) -> an
         edda_store::registry::get_project(pid).map(|e| e.path)
     })?;
     let ledger = Ledger::open(&ledger_root)?;
+    // The workspace's `search.analyzer.*` settings; `sync` rebuilds the index
+    // when they differ from the ones it was built with.
+    let analyzers = AnalyzerConfig::from_config(&ledger.paths.config_json)?;
+    let search_dir = edda_store::store_subdir(&proj_dir, "search");
+    analyzers.save(&search_dir)?;
+    if schema::analyzers_changed(&search_dir.join("tantivy"), &analyzers) {
+        println!("Search analyzers changed; rebuilding the index.");
+    }
     let stats = sync::sync(&proj_dir, project_id, session_id, |after| {
         ledger.events_after_rowid(after)
     })?;
@@ -467,10 +658,103 @@ pub fn index(repo_root: &Path, project_id: &str, session_id: Option<&str>) -> an
     Ok(())
 }
 
+/// `edda search stats` output: the index's own telemetry plus how far it
+/// trails the ledger.
+#[derive(serde::Serialize)]
+struct StatsReport {
+    project_id: String,
+    #[serde(flatten)]
+    index: stats::IndexStats,
+    /// Ledger events not yet indexed; `None` when no ledger could be resolved
+    /// for the project, which is reported rather than guessed (GH-414).
+    events_behind: Option<usize>,
+}
+
+/// Execute `edda search stats` — report whether a reindex is warranted.
+pub fn show_stats(repo_root: &Path, project_id: &str, json: bool) -> anyhow::Result<()> {
+    let proj_dir = project_dir(project_id);
+    let index = stats::index_stats(&proj_dir, project_id)?;
+
+    let events_behind = ledger_root_for(repo_root, project_id, |pid| {
+        edda_store::registry::get_project(pid).map(|e| e.path)
+    })
+    .ok()
+    .and_then(|root| Ledger::open(&root).ok())
+    .and_then(|l| l.events_after_rowid(index.indexed_rowid).ok())
+    .map(|v| v.len());
+
+    let report = StatsReport {
+        project_id: project_id.to_string(),
+        index,
+        events_behind,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    print!("{}", format_stats(&report));
+    Ok(())
+}
+
+fn format_stats(r: &StatsReport) -> String {
+    let s = &r.index;
+    let mut out = format!("Search index for project {}\n", r.project_id);
+    if !s.present {
+        out.push_str("  No search index — run `edda search index` to build one.\n");
+        return out;
+    }
+
+    let counts = |m: &std::collections::BTreeMap<String, u64>| {
+        if m.is_empty() {
+            "(none)".to_string()
+        } else {
+            m.iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+    };
+    out.push_str(&format!("  documents:    {}\n", counts(&s.docs_by_type)));
+    out.push_str(&format!("  event types:  {}\n", counts(&s.events_by_type)));
+    out.push_str(&format!(
+        "  size on disk: {:.1} MiB\n",
+        s.size_bytes as f64 / (1024.0 * 1024.0)
+    ));
+    out.push_str(&format!(
+        "  last commit:  {}\n",
+        s.last_commit.as_deref().unwrap_or("(unknown)")
+    ));
+    match s.avg_query_ms {
+        Some(ms) => out.push_str(&format!(
+            "  avg query:    {ms:.1} ms over {} recent quer{}\n",
+            s.query_samples,
+            if s.query_samples == 1 { "y" } else { "ies" }
+        )),
+        None => out.push_str("  avg query:    (no queries recorded)\n"),
+    }
+    out.push_str(&format!(
+        "  indexed through: {}\n",
+        s.indexed_through.as_deref().unwrap_or("(nothing)")
+    ));
+
+    let staleness = if s.outdated {
+        "outdated schema — run `edda search index` to rebuild".to_string()
+    } else {
+        match r.events_behind {
+            Some(0) => "up to date with the ledger".to_string(),
+            Some(n) => format!("{n} ledger event(s) not yet indexed — run `edda search index`"),
+            None => "unknown (no ledger found for this project)".to_string(),
+        }
+    };
+    out.push_str(&format!("  staleness:    {staleness}\n"));
+    out
+}
+
 /// Execute `edda search show` — retrieve full turn content by turn_id.
 pub fn show(project_id: &str, turn_id: &str) -> anyhow::Result<()> {
     let proj_dir = project_dir(project_id);
-    let meta_db_path = proj_dir.join("search").join("meta.sqlite");
+    let meta_db_path = edda_store::store_subdir(&proj_dir, "search").join("meta.sqlite");
     if !meta_db_path.exists() {
         anyhow::bail!("No search metadata found. Run `edda search index` first.");
     }
@@ -484,8 +768,7 @@ pub fn show(project_id: &str, turn_id: &str) -> anyhow::Result<()> {
         }
     };
 
-    let store_path = proj_dir
-        .join("transcripts")
+    let store_path = edda_store::store_subdir(&proj_dir, "transcripts")
         .join(format!("{}.jsonl", meta.session_id));
 
     println!("Turn: {}", meta.turn_id);
@@ -578,6 +861,32 @@ fn extract_message_text(json: &serde_json::Value) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn format_stats_flags_events_behind_the_ledger() {
+        let mut index = stats::IndexStats {
+            present: true,
+            avg_query_ms: Some(3.25),
+            query_samples: 4,
+            ..Default::default()
+        };
+        index.docs_by_type.insert("event".into(), 12);
+        let report = StatsReport {
+            project_id: "p1".into(),
+            index,
+            events_behind: Some(3),
+        };
+        let text = format_stats(&report);
+        assert!(text.contains("documents:    event=12"), "{text}");
+        assert!(text.contains("3.2 ms over 4 recent queries"), "{text}");
+        assert!(text.contains("3 ledger event(s) not yet indexed"), "{text}");
+
+        let report = StatsReport {
+            events_behind: None,
+            ..report
+        };
+        assert!(format_stats(&report).contains("staleness:    unknown"));
+    }
+
     /// `--project` and `--fleet` are contradictory, so one has to lose. Losing
     /// silently is the trap: the reader asked about one project, got sixteen,
     /// and was told nothing — a read verb answering a question nobody asked,
@@ -606,6 +915,69 @@ mod tests {
         assert!(W::try_parse_from(["edda", "query", "x", "--project", "abc"]).is_ok());
     }
 
+    #[test]
+    fn all_projects_merges_hits_by_score_and_tags_each_project() {
+        let tmp = tempfile::tempdir().unwrap();
+        let projects = tmp.path().join("projects");
+        let build = |pid: &str, notes: &[&str]| {
+            let mut events = Vec::new();
+            for (i, text) in notes.iter().enumerate() {
+                let e = edda_core::event::new_note_event("main", None, "user", text, &[]).unwrap();
+                events.push((i as i64 + 1, e));
+            }
+            sync::sync(&projects.join(pid), pid, None, |after| {
+                Ok(events.iter().filter(|(r, _)| *r > after).cloned().collect())
+            })
+            .unwrap();
+        };
+        build(
+            "aaa",
+            &["retry semantics: exponential backoff for webhooks"],
+        );
+        build(
+            "bbb",
+            &[
+                "retry semantics retry semantics: at most three retry attempts",
+                "unrelated note about logging",
+            ],
+        );
+        std::fs::create_dir_all(projects.join("ccc")).unwrap();
+
+        let opts = search::SearchOptions::default();
+        let name_of = |pid: &str| (pid == "bbb").then(|| "billing".to_string());
+        let (hits, misses, searched, unindexed) =
+            search_all_projects(&projects, "retry semantics", &opts, 10, name_of).unwrap();
+
+        assert!(misses.is_empty());
+        assert_eq!((searched, unindexed), (2, 1));
+        let tagged: Vec<&str> = hits.iter().map(|h| h.project.as_str()).collect();
+        assert_eq!(tagged.len(), 2);
+        assert!(tagged.contains(&"aaa") && tagged.contains(&"billing"));
+        assert!(hits[0].result.rank >= hits[1].result.rank);
+
+        let (top, ..) =
+            search_all_projects(&projects, "retry semantics", &opts, 1, name_of).unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].result.doc_id, hits[0].result.doc_id);
+    }
+
+    #[test]
+    fn all_projects_conflicts_with_project_and_fleet() {
+        use clap::Parser;
+        #[derive(Parser)]
+        struct W {
+            #[command(subcommand)]
+            cmd: SearchCmd,
+        }
+
+        for other in [&["--project", "abc"][..], &["--fleet"][..]] {
+            let mut args = vec!["edda", "query", "x", "--all-projects"];
+            args.extend_from_slice(other);
+            let err = W::try_parse_from(args).err().expect("conflicting scopes");
+            assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+        }
+    }
+
     /// The registry must not even be consulted for the project we are standing
     /// in — that is the overwhelmingly common case and it must not depend on
     /// being registered.
@@ -0,0 +1,482 @@
+//! `edda stats` — how the workspace's memory is actually being used.
+//!
+//! The workspace half answers a reviewer's questions: which domains hold the
+//! decisions, how busy the ledger has been day to day, how often work gets
+//! committed, which files the agents keep editing (from the session digests'
+//! edit signals), and what the blob store and packs weigh.
+//!
+//! The append half reads the metrics the ledger keeps in `.edda/stats.json`:
+//! event counts and payload bytes per type, append latency, and the payload
+//! fields carrying the most bytes. The usual question is "why is ledger.db so
+//! big?", and the field table answers it.
+
+use edda_core::Event;
+use edda_ledger::blob_meta::{get_meta, load_blob_meta};
+use edda_ledger::{blob_list, blob_list_archived, AppendStats, EddaPaths, Ledger};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::cmd_gc::format_size;
+
+/// Everything `edda stats --json` prints.
+#[derive(Debug, Serialize)]
+struct Report {
+    workspace: WorkspaceStats,
+    appends: AppendStats,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct WorkspaceStats {
+    /// Active decisions per domain.
+    decisions_by_domain: BTreeMap<String, usize>,
+    /// Ledger events per UTC day, within the `--days` window.
+    events_per_day: BTreeMap<String, usize>,
+    commits: CommitCadence,
+    /// Most-edited files across session digests.
+    top_files: Vec<FileEdits>,
+    /// Blob count and bytes per class; archived blobs under `"archived"`.
+    blobs: BTreeMap<String, Footprint>,
+    /// Size of each file in the project's packs directory.
+    packs: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct CommitCadence {
+    total: usize,
+    in_window: usize,
+    per_week: f64,
+    /// Median time between consecutive commits, all time.
+    median_gap_hours: Option<f64>,
+    last_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct FileEdits {
+    path: String,
+    edits: u64,
+    sessions: usize,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Footprint {
+    count: usize,
+    bytes: u64,
+}
+
+pub fn execute(
+    repo_root: &Path,
+    top: usize,
+    days: u32,
+    json: bool,
+    reset: bool,
+) -> anyhow::Result<()> {
+    let paths = EddaPaths::discover(repo_root);
+    if !paths.is_initialized() {
+        anyhow::bail!("No .edda/ workspace found. Run `edda init` first.");
+    }
+
+    if reset {
+        AppendStats::reset(&paths)?;
+        println!("Append stats reset.");
+        return Ok(());
+    }
+
+    let ledger = Ledger::open(repo_root)?;
+    let project_dir = edda_store::project_dir(&edda_store::project_id(repo_root));
+    let report = Report {
+        workspace: workspace_stats(&ledger, &project_dir, OffsetDateTime::now_utc(), days, top)?,
+        appends: AppendStats::load(&paths)?,
+    };
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+    print!("{}", render_workspace(&report.workspace, days));
+    if report.appends.by_type.is_empty() {
+        println!("\nNo appends recorded yet.");
+    } else {
+        print!("\n{}", render(&report.appends, top));
+    }
+    Ok(())
+}
+
+fn workspace_stats(
+    ledger: &Ledger,
+    project_dir: &Path,
+    now: OffsetDateTime,
+    days: u32,
+    top: usize,
+) -> anyhow::Result<WorkspaceStats> {
+    let since = (now - time::Duration::days(i64::from(days))).format(&Rfc3339)?;
+    let events = ledger.iter_events()?;
+
+    let mut stats = WorkspaceStats::default();
+    for d in ledger.active_decisions(None, None, None, None)? {
+        let domain = if d.domain.is_empty() {
+            "(none)".to_string()
+        } else {
+            d.domain
+        };
+        *stats.decisions_by_domain.entry(domain).or_default() += 1;
+    }
+    for e in events.iter().filter(|e| e.ts >= since) {
+        let day = e.ts.get(..10).unwrap_or(&e.ts).to_string();
+        *stats.events_per_day.entry(day).or_default() += 1;
+    }
+    stats.commits = commit_cadence(&events, &since, days);
+    stats.top_files = top_files(&events, top);
+
+    let meta = load_blob_meta(&ledger.paths.blob_meta_json)?;
+    for blob in blob_list(&ledger.paths)? {
+        let class = get_meta(&meta, &blob.hash).class.to_string();
+        let entry = stats.blobs.entry(class).or_default();
+        entry.count += 1;
+        entry.bytes += blob.size;
+    }
+    let archived = blob_list_archived(&ledger.paths)?;
+    if !archived.is_empty() {
+        stats.blobs.insert(
+            "archived".to_string(),
+            Footprint {
+                count: archived.len(),
+                bytes: archived.iter().map(|b| b.size).sum(),
+            },
+        );
+    }
+
+    for entry in std::fs::read_dir(project_dir.join("packs"))
+        .into_iter()
+        .flatten()
+        .flatten()
+    {
+        let Ok(meta) = entry.metadata() else { continue };
+        if meta.is_file() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            stats.packs.insert(name, meta.len());
+        }
+    }
+    Ok(stats)
+}
+
+fn commit_cadence(events: &[Event], since: &str, days: u32) -> CommitCadence {
+    let times: Vec<(&str, OffsetDateTime)> = events
+        .iter()
+        .filter(|e| e.event_type == "commit")
+        .filter_map(|e| Some((e.ts.as_str(), OffsetDateTime::parse(&e.ts, &Rfc3339).ok()?)))
+        .collect();
+    let in_window = times.iter().filter(|(ts, _)| *ts >= since).count();
+
+    let mut gaps: Vec<f64> = times
+        .windows(2)
+        .map(|w| (w[1].1 - w[0].1).as_seconds_f64() / 3600.0)
+        .collect();
+    gaps.sort_by(f64::total_cmp);
+    let median_gap_hours = match gaps.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(gaps[n / 2]),
+        n => Some((gaps[n / 2 - 1] + gaps[n / 2]) / 2.0),
+    };
+
+    CommitCadence {
+        total: times.len(),
+        in_window,
+        per_week: if days == 0 {
+            0.0
+        } else {
+            in_window as f64 * 7.0 / f64::from(days)
+        },
+        median_gap_hours,
+        last_at: times.last().map(|(ts, _)| ts.to_string()),
+    }
+}
+
+/// Sum the `session_stats.file_edit_counts` signals of every session digest.
+fn top_files(events: &[Event], top: usize) -> Vec<FileEdits> {
+    let mut by_path: BTreeMap<String, (u64, usize)> = BTreeMap::new();
+    for e in events {
+        let counts = e
+            .payload
+            .get("session_stats")
+            .and_then(|s| s.get("file_edit_counts"))
+            .and_then(|c| c.as_array());
+        for pair in counts.into_iter().flatten() {
+            let (Some(path), Some(n)) = (
+                pair.get(0).and_then(|p| p.as_str()),
+                pair.get(1).and_then(|n| n.as_u64()),
+            ) else {
+                continue;
+            };
+            let entry = by_path.entry(path.to_string()).or_default();
+            entry.0 += n;
+            entry.1 += 1;
+        }
+    }
+    let mut files: Vec<FileEdits> = by_path
+        .into_iter()
+        .map(|(path, (edits, sessions))| FileEdits {
+            path,
+            edits,
+            sessions,
+        })
+        .collect();
+    files.sort_by(|a, b| b.edits.cmp(&a.edits).then(a.path.cmp(&b.path)));
+    files.truncate(top);
+    files
+}
+
+fn render_workspace(stats: &WorkspaceStats, days: u32) -> String {
+    let mut out = String::from("Workspace stats\n");
+
+    let total: usize = stats.decisions_by_domain.values().sum();
+    out.push_str(&format!("\nActive decisions: {total}\n"));
+    let mut domains: Vec<_> = stats.decisions_by_domain.iter().collect();
+    domains.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    for (domain, n) in domains {
+        out.push_str(&format!("  {domain:<22} {n:>5}\n"));
+    }
+
+    let events: usize = stats.events_per_day.values().sum();
+    out.push_str(&format!("\nEvents, last {days} day(s): {events}\n"));
+    let peak = stats.events_per_day.values().copied().max().unwrap_or(0);
+    for (day, n) in &stats.events_per_day {
+        let bar = "#".repeat((n * 40).div_ceil(peak.max(1)));
+        out.push_str(&format!("  {day}  {n:>5}  {bar}\n"));
+    }
+
+    let c = &stats.commits;
+    out.push_str(&format!(
+        "\nCommits: {} total, {} in window ({:.1}/week)",
+        c.total, c.in_window, c.per_week
+    ));
+    if let Some(gap) = c.median_gap_hours {
+        out.push_str(&format!(", median gap {gap:.1}h"));
+    }
+    if let Some(last) = &c.last_at {
+        out.push_str(&format!(", last {last}"));
+    }
+    out.push('\n');
+
+    if !stats.top_files.is_empty() {
+        out.push_str(&format!(
+            "\nMost-edited files (top {}):\n",
+            stats.top_files.len()
+        ));
+        for f in &stats.top_files {
+            out.push_str(&format!(
+                "  {:<48} {:>5} edit(s) in {} session(s)\n",
+                f.path, f.edits, f.sessions
+            ));
+        }
+    }
+
+    if !stats.blobs.is_empty() {
+        out.push_str("\nBlobs:\n");
+        for (class, f) in &stats.blobs {
+            out.push_str(&format!(
+                "  {:<22} {:>5} {:>10}\n",
+                class,
+                f.count,
+                format_size(f.bytes)
+            ));
+        }
+    }
+
+    if !stats.packs.is_empty() {
+        out.push_str("\nPacks:\n");
+        for (name, bytes) in &stats.packs {
+            out.push_str(&format!("  {:<22} {:>10}\n", name, format_size(*bytes)));
+        }
+    }
+    out
+}
+
+fn render(stats: &AppendStats, top: usize) -> String {
+    let mut out = String::new();
+    let total = stats.totals();
+    out.push_str(&format!(
+        "Ledger append stats (since {})\n\n",
+        stats.since.as_deref().unwrap_or("?")
+    ));
+    out.push_str(&format!(
+        "Total: {} event(s), {} payload\n\n",
+        total.count,
+        format_size(total.bytes)
+    ));
+
+    let mut types: Vec<_> = stats.by_type.iter().collect();
+    types.sort_by(|a, b| b.1.size.bytes.cmp(&a.1.size.bytes).then(a.0.cmp(b.0)));
+    out.push_str(&format!(
+        "  {:<22} {:>7} {:>10} {:>10} {:>10} {:>9} {:>9}\n",
+        "type", "count", "bytes", "avg", "max", "avg ms", "max ms"
+    ));
+    for (name, t) in types {
+        out.push_str(&format!(
+            "  {:<22} {:>7} {:>10} {:>10} {:>10} {:>9.2} {:>9.2}\n",
+            name,
+            t.size.count,
+            format_size(t.size.bytes),
+            format_size(t.size.avg_bytes()),
+            format_size(t.size.max_bytes),
+            t.latency_ms_avg(),
+            t.latency_ms_max,
+        ));
+    }
+
+    let fields = stats.top_fields(top);
+    if !fields.is_empty() {
+        out.push_str(&format!(
+            "\nLargest payload fields (top {}):\n",
+            fields.len()
+        ));
+        for (name, f) in fields {
+            let share = if total.bytes == 0 {
+                0.0
+            } else {
+                f.bytes as f64 * 100.0 / total.bytes as f64
+            };
+            out.push_str(&format!(
+                "  {:<32} {:>10} {:>5.1}%  max {}\n",
+                name,
+                format_size(f.bytes),
+                share,
+                format_size(f.max_bytes),
+            ));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{
+        finalize_event, new_commit_event, new_decision_event, new_note_event, CommitEventParams,
+    };
+    use edda_core::types::DecisionPayload;
+
+    fn decision(key: &str) -> DecisionPayload {
+        DecisionPayload {
+            key: key.into(),
+            value: "x".into(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        }
+    }
+
+    /// Append `events` as one chain, stamping each with its timestamp.
+    fn append_chain(ledger: &Ledger, events: Vec<(Event, &str)>) {
+        let mut prev: Option<String> = ledger.last_event_hash().unwrap();
+        for (mut e, ts) in events {
+            e.ts = ts.to_string();
+            e.parent_hash = prev.clone();
+            finalize_event(&mut e).unwrap();
+            ledger.append_event(&e).unwrap();
+            prev = Some(e.hash.clone());
+        }
+    }
+
+    fn commit(title: &str) -> Event {
+        new_commit_event(&mut CommitEventParams {
+            branch: "main",
+            parent_hash: None,
+            title,
+            purpose: None,
+            prev_summary: "",
+            contribution: "",
+            evidence: vec![],
+            labels: vec![],
+        })
+        .unwrap()
+    }
+
+    fn digest(edits: serde_json::Value) -> Event {
+        let mut e = new_note_event("main", None, "system", "digest", &[]).unwrap();
+        e.payload["session_stats"] = serde_json::json!({ "file_edit_counts": edits });
+        e
+    }
+
+    #[test]
+    fn workspace_stats_cover_decisions_activity_files_blobs_and_packs() {
+        let repo = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let ledger = Ledger::open_or_init(repo.path()).unwrap();
+        append_chain(
+            &ledger,
+            vec![
+                (
+                    new_decision_event("main", None, "user", &decision("db.engine")).unwrap(),
+                    "2026-03-01T09:00:00Z",
+                ),
+                (
+                    new_decision_event("main", None, "user", &decision("db.pool")).unwrap(),
+                    "2026-03-01T09:30:00Z",
+                ),
+                (commit("first"), "2026-03-01T10:00:00Z"),
+                (commit("second"), "2026-03-01T12:00:00Z"),
+                (commit("third"), "2026-03-02T04:00:00Z"),
+                (
+                    digest(serde_json::json!([["src/a.rs", 3], ["src/b.rs", 1]])),
+                    "2026-03-02T05:00:00Z",
+                ),
+                (
+                    digest(serde_json::json!([["src/a.rs", 2]])),
+                    "2026-03-02T06:00:00Z",
+                ),
+            ],
+        );
+        edda_ledger::blob_store::blob_put(&ledger.paths, b"some artifact").unwrap();
+        std::fs::create_dir_all(project.path().join("packs")).unwrap();
+        std::fs::write(project.path().join("packs/hot.md"), "x".repeat(300)).unwrap();
+
+        let now = OffsetDateTime::parse("2026-03-03T00:00:00Z", &Rfc3339).unwrap();
+        let stats = workspace_stats(&ledger, project.path(), now, 7, 5).unwrap();
+
+        assert_eq!(stats.decisions_by_domain["db"], 2);
+        assert_eq!(stats.events_per_day["2026-03-01"], 4);
+        assert_eq!(stats.events_per_day["2026-03-02"], 3);
+        assert_eq!(stats.commits.total, 3);
+        assert_eq!(stats.commits.in_window, 3);
+        assert_eq!(stats.commits.median_gap_hours, Some(9.0), "gaps 2h and 16h");
+        assert_eq!(stats.top_files[0].path, "src/a.rs");
+        assert_eq!(stats.top_files[0].edits, 5);
+        assert_eq!(stats.top_files[0].sessions, 2);
+        assert_eq!(stats.blobs["trace_noise"].count, 1);
+        assert_eq!(stats.packs["hot.md"], 300);
+
+        let out = render_workspace(&stats, 7);
+        assert!(out.contains("Active decisions: 2"), "{out}");
+        assert!(
+            out.contains("Commits: 3 total, 3 in window (3.0/week)"),
+            "{out}"
+        );
+        assert!(out.contains("src/a.rs"), "{out}");
+    }
+
+    #[test]
+    fn render_ranks_types_and_fields_by_bytes() {
+        let mut stats = AppendStats::default();
+        let small = new_note_event("main", None, "user", "hi", &[]).unwrap();
+        let big = new_note_event("main", None, "user", &"x".repeat(4000), &[]).unwrap();
+        stats.record(&small, 1.0, "2026-03-01T00:00:00Z");
+        stats.record(&big, 3.0, "2026-03-01T00:01:00Z");
+
+        let out = render(&stats, 2);
+        assert!(out.contains("since 2026-03-01T00:00:00Z"), "{out}");
+        assert!(out.contains("Total: 2 event(s)"), "{out}");
+        assert!(out.contains("2.00      3.00"), "avg and max latency: {out}");
+        let fields = out
+            .split("Largest payload fields (top 2):\n")
+            .nth(1)
+            .unwrap();
+        assert!(fields.trim_start().starts_with("note.text"), "{out}");
+    }
+}
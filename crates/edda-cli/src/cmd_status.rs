@@ -1,7 +1,12 @@
 use edda_derive::rebuild_branch;
-use edda_ledger::Ledger;
+use edda_ledger::{settings, Ledger};
 use std::path::Path;
 
+/// Config changes listed under the branch summary.
+const RECENT_CONFIG_CHANGES: usize = 3;
+/// Open questions listed under the branch summary; the rest are counted.
+const OPEN_QUESTIONS_SHOWN: usize = 5;
+
 pub fn execute(repo_root: &Path) -> anyhow::Result<()> {
     let ledger = Ledger::open(repo_root)?;
     let head = ledger.head_branch()?;
@@ -16,5 +21,21 @@ pub fn execute(repo_root: &Path) -> anyhow::Result<()> {
     }
 
     println!("Uncommitted events: {}", snap.uncommitted_events);
+
+    let questions: Vec<_> = snap.open_questions().collect();
+    if !questions.is_empty() {
+        println!("Open questions: {}", questions.len());
+        for q in questions.iter().rev().take(OPEN_QUESTIONS_SHOWN) {
+            println!("  {} {}", q.event_id, q.text);
+        }
+    }
+
+    let changes = settings::recent_config_changes(&ledger, RECENT_CONFIG_CHANGES)?;
+    if !changes.is_empty() {
+        println!("Recent config changes:");
+        for c in &changes {
+            println!("  {}", c.summary());
+        }
+    }
     Ok(())
 }
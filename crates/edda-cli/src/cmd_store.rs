@@ -0,0 +1,212 @@
+use crate::cmd_gc::format_size;
+use clap::Subcommand;
+use edda_store::health::{self, Severity, StoreHealth};
+use std::path::Path;
+
+// ── CLI Schema ──
+
+#[derive(Subcommand)]
+pub enum StoreCmd {
+    /// Check per-project store health (sizes, ingest freshness, index lag)
+    Doctor {
+        /// Project ID (defaults to the current repo)
+        #[arg(long)]
+        project: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show where the store and a project's data live, after overrides
+    Paths {
+        /// Project ID (defaults to the current repo)
+        #[arg(long)]
+        project: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Fold stores split across project IDs by path spelling (symlinks,
+    /// drive-letter case, UNC forms) into the canonical one
+    MergeDuplicates {
+        /// Show what would move without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+// ── Dispatch ──
+
+pub fn run(cmd: StoreCmd, repo_root: &Path) -> anyhow::Result<()> {
+    match cmd {
+        StoreCmd::Doctor { project, json } => doctor(repo_root, project.as_deref(), json),
+        StoreCmd::Paths { project, json } => paths(repo_root, project.as_deref(), json),
+        StoreCmd::MergeDuplicates { dry_run, json } => merge_duplicates(dry_run, json),
+    }
+}
+
+// ── Command Implementations ──
+
+/// `edda store doctor [--project <id>] [--json]`
+///
+/// Recomputes `state/health.json` and prints the anomalies found in it.
+pub fn doctor(repo_root: &Path, project: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let project_id = project
+        .map(str::to_string)
+        .unwrap_or_else(|| edda_store::project_id(repo_root));
+    let project_dir = edda_store::project_dir(&project_id);
+    if !project_dir.is_dir() {
+        anyhow::bail!(
+            "No store for project {project_id} at {}. Start a bridged session first.",
+            project_dir.display()
+        );
+    }
+
+    let report = health::write_health(&project_dir, &project_id)?;
+    let findings = health::diagnose(&report);
+
+    if json {
+        let out = serde_json::json!({ "health": report, "findings": findings });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    print_summary(&report);
+    if findings.is_empty() {
+        println!("[OK] no anomalies");
+    }
+    for f in &findings {
+        let tag = match f.severity {
+            Severity::Error => "ERROR",
+            Severity::Warn => "WARN",
+        };
+        match &f.session_id {
+            Some(sid) => println!("[{tag}] {} ({sid}): {}", f.code, f.message),
+            None => println!("[{tag}] {}: {}", f.code, f.message),
+        }
+    }
+    Ok(())
+}
+
+/// `edda store paths [--project <id>] [--json]`
+///
+/// Prints the resolved store root and every project subdirectory, so a
+/// `store_root` or `project_storage` override can be checked before data moves.
+pub fn paths(repo_root: &Path, project: Option<&str>, json: bool) -> anyhow::Result<()> {
+    let project_id = project
+        .map(str::to_string)
+        .unwrap_or_else(|| edda_store::project_id(repo_root));
+    let project_dir = edda_store::project_dir(&project_id);
+    let subdirs: Vec<(&str, std::path::PathBuf)> =
+        ["ledger", "transcripts", "index", "packs", "state", "search"]
+            .into_iter()
+            .map(|sub| (sub, edda_store::project_subdir(&project_id, sub)))
+            .collect();
+
+    if json {
+        let dirs: serde_json::Map<String, serde_json::Value> = subdirs
+            .iter()
+            .map(|(sub, path)| (sub.to_string(), path.display().to_string().into()))
+            .collect();
+        let out = serde_json::json!({
+            "config": edda_store::user_config::user_config_path(),
+            "store_root": edda_store::store_root(),
+            "project_id": project_id,
+            "project_dir": project_dir,
+            "dirs": dirs,
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    println!(
+        "Config:     {}",
+        edda_store::user_config::user_config_path().display()
+    );
+    println!("Store root: {}", edda_store::store_root().display());
+    println!("Project:    {project_id} ({})", project_dir.display());
+    for (sub, path) in &subdirs {
+        let marker = if path.starts_with(&project_dir) {
+            ""
+        } else {
+            "  (override)"
+        };
+        println!("  {sub:<12} {}{marker}", path.display());
+    }
+    Ok(())
+}
+
+/// `edda store merge-duplicates [--dry-run] [--json]`
+///
+/// Finds registered projects whose path now hashes to another project ID and
+/// moves their store data into that ID's store.
+pub fn merge_duplicates(dry_run: bool, json: bool) -> anyhow::Result<()> {
+    let groups = edda_store::duplicates::find_duplicates();
+    let reports = groups
+        .iter()
+        .map(|g| edda_store::duplicates::merge_duplicates(g, dry_run))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if json {
+        let out = serde_json::json!({ "dry_run": dry_run, "groups": groups, "reports": reports });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    if reports.is_empty() {
+        println!("[OK] no duplicate project stores");
+        return Ok(());
+    }
+    let verb = if dry_run { "would move" } else { "moved" };
+    for (group, report) in groups.iter().zip(&reports) {
+        println!("{} → {}", report.path, report.project_id);
+        println!(
+            "  from {}: {verb} {} files, {} identical",
+            group.duplicates.join(", "),
+            report.moved_files,
+            report.identical_files
+        );
+        for conflict in &report.conflicts {
+            println!("  [CONFLICT] {} differs; left in place", conflict.display());
+        }
+    }
+    if dry_run {
+        println!("Dry run: nothing changed. Close agent sessions, then rerun without --dry-run.");
+    } else if reports.iter().any(|r| !r.conflicts.is_empty()) {
+        println!("Resolve the conflicting files by hand, then rerun to finish.");
+    }
+    Ok(())
+}
+
+fn print_summary(report: &StoreHealth) {
+    println!("Store: {}", report.project_id);
+    let dirs: Vec<String> = report
+        .dir_bytes
+        .iter()
+        .map(|(dir, bytes)| format!("{dir} {}", format_size(*bytes)))
+        .collect();
+    println!(
+        "  size: {} ({})",
+        format_size(report.total_bytes),
+        dirs.join(", ")
+    );
+    let last_ingest = report
+        .last_ingest_unix
+        .map(|t| format!("{}s ago", report.generated_at_unix - t))
+        .unwrap_or_else(|| "never".to_string());
+    println!(
+        "  sessions: {}, last ingest: {last_ingest}, index lag: {}",
+        report.sessions.len(),
+        format_size(report.index_lag_bytes)
+    );
+    if !report.lock_contention.is_empty() {
+        let locks: Vec<String> = report
+            .lock_contention
+            .iter()
+            .map(|(kind, n)| format!("{kind} {n}"))
+            .collect();
+        println!("  lock contention: {}", locks.join(", "));
+    }
+}
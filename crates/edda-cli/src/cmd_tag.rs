@@ -0,0 +1,245 @@
+use clap::Subcommand;
+use edda_core::event::new_tag_rename_event;
+use edda_core::types::Event;
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::tags::{count_tags, resolved_tags};
+use edda_ledger::{Ledger, TagQuery};
+use std::path::Path;
+
+// ── CLI Schema ──
+
+#[derive(Subcommand)]
+pub enum TagCmd {
+    /// List tags on notes and decisions with usage counts
+    List {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Rename a tag across history (appends a `tag_rename` event; nothing is rewritten)
+    Rename {
+        /// Current tag name
+        from: String,
+        /// New tag name (may be an existing tag, which merges the two)
+        to: String,
+    },
+    /// Find notes and decisions by tag combination
+    Query {
+        /// Require every one of these tags (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        all: Vec<String>,
+        /// Require at least one of these tags (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        any: Vec<String>,
+        /// Exclude events carrying any of these tags (comma-separated)
+        #[arg(long = "not", value_delimiter = ',')]
+        none: Vec<String>,
+        /// Maximum number of results, newest first
+        #[arg(long, default_value = "20")]
+        limit: usize,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+// ── Dispatch ──
+
+pub fn run(cmd: TagCmd, repo_root: &Path) -> anyhow::Result<()> {
+    match cmd {
+        TagCmd::List { json } => list(repo_root, json),
+        TagCmd::Rename { from, to } => rename(repo_root, &from, &to),
+        TagCmd::Query {
+            all,
+            any,
+            none,
+            limit,
+            json,
+        } => {
+            let query = TagQuery { all, any, none };
+            query_tags(repo_root, &query, limit, json)
+        }
+    }
+}
+
+// ── Command Implementations ──
+
+fn list(repo_root: &Path, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let aliases = ledger.tag_aliases()?;
+    let notes = ledger.iter_events_by_type("note")?;
+
+    let mut counts: Vec<(String, usize)> = count_tags(&notes, &aliases).into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    if json {
+        let tags: Vec<serde_json::Value> = counts
+            .iter()
+            .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+            .collect();
+        let renames: serde_json::Map<String, serde_json::Value> = aliases
+            .iter()
+            .map(|(from, to)| (from.to_string(), serde_json::json!(to)))
+            .collect();
+        let out = serde_json::json!({ "tags": tags, "renames": renames });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+        return Ok(());
+    }
+
+    if counts.is_empty() {
+        println!("(no tags)");
+        return Ok(());
+    }
+    let width = counts.iter().map(|(t, _)| t.len()).max().unwrap_or(0);
+    for (tag, count) in &counts {
+        println!("  {tag:<width$}  {count}");
+    }
+    let renames: Vec<_> = aliases.iter().collect();
+    if !renames.is_empty() {
+        println!();
+        println!("Renamed:");
+        for (from, to) in renames {
+            println!("  {from} → {to}");
+        }
+    }
+    Ok(())
+}
+
+fn rename(repo_root: &Path, from: &str, to: &str) -> anyhow::Result<()> {
+    let (from, to) = (from.trim(), to.trim());
+    for tag in [from, to] {
+        if tag.is_empty() || tag.contains(|c: char| c.is_whitespace() || c == ',') {
+            anyhow::bail!("invalid tag '{tag}': tags must be non-empty without spaces or commas");
+        }
+    }
+    if from == to {
+        anyhow::bail!("'{from}' and '{to}' are the same tag");
+    }
+
+    let ledger = Ledger::open(repo_root)?;
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let aliases = ledger.tag_aliases()?;
+    if aliases.is_renamed(from) {
+        anyhow::bail!(
+            "'{from}' was already renamed to '{}'; rename that tag instead",
+            aliases.resolve(from)
+        );
+    }
+    let notes = ledger.iter_events_by_type("note")?;
+    let affected = count_tags(&notes, &aliases).get(from).copied().unwrap_or(0);
+    if affected == 0 {
+        anyhow::bail!("unknown tag '{from}' (see `edda tag list`)");
+    }
+
+    let branch = ledger.head_branch()?;
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_tag_rename_event(&branch, parent_hash.as_deref(), from, to)?;
+    ledger.append_event(&event)?;
+
+    println!("Renamed tag '{from}' → '{to}' ({affected} event(s)).");
+    Ok(())
+}
+
+fn query_tags(repo_root: &Path, query: &TagQuery, limit: usize, json: bool) -> anyhow::Result<()> {
+    if query.is_empty() {
+        anyhow::bail!("give at least one of --all, --any or --not");
+    }
+
+    let ledger = Ledger::open(repo_root)?;
+    let aliases = ledger.tag_aliases()?;
+    let notes = ledger.iter_events_by_type("note")?;
+
+    let hits: Vec<(&Event, Vec<String>)> = notes
+        .iter()
+        .rev()
+        .map(|e| (e, resolved_tags(e, &aliases)))
+        .filter(|(_, tags)| query.matches(tags, &aliases))
+        .take(limit)
+        .collect();
+
+    if json {
+        let items: Vec<serde_json::Value> = hits
+            .iter()
+            .map(|(e, tags)| {
+                serde_json::json!({
+                    "event_id": e.event_id,
+                    "ts": e.ts,
+                    "branch": e.branch,
+                    "text": summary(e),
+                    "tags": tags,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+
+    if hits.is_empty() {
+        println!("No notes or decisions match.");
+        return Ok(());
+    }
+    for (e, tags) in &hits {
+        let ts = e.ts.get(..16).unwrap_or(&e.ts);
+        println!(
+            "{ts}  {}  [{}]  {}",
+            e.event_id,
+            tags.join(", "),
+            summary(e)
+        );
+    }
+    Ok(())
+}
+
+/// One-line summary: `key=value` for decisions, the note text otherwise.
+fn summary(event: &Event) -> String {
+    if let Some(dp) = edda_core::decision::extract_decision(&event.payload) {
+        return format!("{}={}", dp.key, dp.value);
+    }
+    event
+        .payload
+        .get("text")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rename_validates_and_appends_annotation() {
+        let dir = std::env::temp_dir().join(format!("edda_cmdtag_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        Ledger::ensure_initialized(&dir).unwrap();
+
+        let ledger = Ledger::open(&dir).unwrap();
+        let note = edda_core::event::new_note_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            "index the cache",
+            &["perf".to_string()],
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+        drop(ledger);
+
+        assert!(rename(&dir, "missing", "x").is_err());
+        assert!(rename(&dir, "perf", "perf").is_err());
+        assert!(rename(&dir, "perf", "has space").is_err());
+        rename(&dir, "perf", "performance").unwrap();
+        let err = rename(&dir, "perf", "speed").unwrap_err();
+        assert!(err.to_string().contains("already renamed"));
+
+        let ledger = Ledger::open(&dir).unwrap();
+        assert_eq!(ledger.tag_aliases().unwrap().resolve("perf"), "performance");
+        // The original note is untouched.
+        let stored = ledger.get_event(&note.event_id).unwrap().unwrap();
+        assert_eq!(stored.payload["tags"], serde_json::json!(["perf"]));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
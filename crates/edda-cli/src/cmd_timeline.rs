@@ -0,0 +1,224 @@
+//! `edda timeline` — a day-grouped narrative of recent work.
+//!
+//! `edda log` prints every event as a flat line. The timeline keeps only the
+//! milestones a person would mention at standup — commits, decisions, merges
+//! and session ends — and groups them by day with a one-line tally, so a week
+//! reads as a story rather than a dump.
+
+use edda_core::decision::extract_decision;
+use edda_core::Event;
+use edda_ledger::Ledger;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use time::format_description::well_known::Rfc3339;
+
+/// One milestone on the timeline.
+#[derive(Debug, Serialize)]
+struct Entry {
+    ts: String,
+    kind: &'static str,
+    branch: String,
+    event_id: String,
+    summary: String,
+}
+
+/// A day's milestones, oldest first.
+#[derive(Debug, Serialize)]
+struct Day {
+    date: String,
+    entries: Vec<Entry>,
+}
+
+pub fn execute(
+    repo_root: &Path,
+    days: u32,
+    branch: Option<&str>,
+    json: bool,
+) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let since = (time::OffsetDateTime::now_utc() - time::Duration::days(i64::from(days)))
+        .format(&Rfc3339)?;
+    let timeline = build(&ledger.iter_events()?, &since, branch);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&timeline)?);
+        return Ok(());
+    }
+    if timeline.is_empty() {
+        println!("No commits, decisions, merges or sessions in the last {days} day(s).");
+        return Ok(());
+    }
+    print!("{}", render(&timeline));
+    Ok(())
+}
+
+/// Milestones at or after `since` (an RFC 3339 timestamp), grouped by day,
+/// newest day first.
+fn build(events: &[Event], since: &str, branch: Option<&str>) -> Vec<Day> {
+    let mut by_day: BTreeMap<String, Vec<Entry>> = BTreeMap::new();
+    for e in events {
+        if e.ts.as_str() < since || branch.is_some_and(|b| e.branch != b) {
+            continue;
+        }
+        let Some((kind, summary)) = classify(e) else {
+            continue;
+        };
+        let date = e.ts.get(..10).unwrap_or(&e.ts).to_string();
+        by_day.entry(date).or_default().push(Entry {
+            ts: e.ts.clone(),
+            kind,
+            branch: e.branch.clone(),
+            event_id: e.event_id.clone(),
+            summary,
+        });
+    }
+    by_day
+        .into_iter()
+        .rev()
+        .map(|(date, entries)| Day { date, entries })
+        .collect()
+}
+
+/// The timeline kind and one-line summary of an event, or `None` for events
+/// that are not milestones.
+fn classify(e: &Event) -> Option<(&'static str, String)> {
+    if crate::cmd_log::is_session_digest(e) {
+        return Some(("session", crate::cmd_log::format_session_digest_detail(e)));
+    }
+    match e.event_type.as_str() {
+        "commit" => {
+            let title = e.payload.get("title").and_then(|v| v.as_str())?;
+            Some(("commit", title.to_string()))
+        }
+        "merge" => {
+            let src = e.payload.get("src").and_then(|v| v.as_str())?;
+            let dst = e.payload.get("dst").and_then(|v| v.as_str())?;
+            Some(("merge", format!("{src} → {dst}")))
+        }
+        "note" => {
+            let d = extract_decision(&e.payload)?;
+            let summary = match d.reason {
+                Some(r) => format!("{} = {} — {r}", d.key, d.value),
+                None => format!("{} = {}", d.key, d.value),
+            };
+            Some(("decision", summary))
+        }
+        _ => None,
+    }
+}
+
+fn render(days: &[Day]) -> String {
+    let mut out = String::new();
+    for day in days {
+        out.push_str(&format!("## {}{}\n", day_label(&day.date), tally(day)));
+        for e in &day.entries {
+            let hhmm = e.ts.get(11..16).unwrap_or("");
+            out.push_str(&format!("  {hhmm}  {:<8}  {}\n", e.kind, e.summary));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// `2026-10-16` → `Fri 2026-10-16`; the date alone if it does not parse.
+fn day_label(date: &str) -> String {
+    match chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(d) => format!("{} {date}", d.format("%a")),
+        Err(_) => date.to_string(),
+    }
+}
+
+/// ` — 3 commits, 1 decision` for a day header.
+fn tally(day: &Day) -> String {
+    let mut counts: Vec<(&str, usize)> = Vec::new();
+    for kind in ["commit", "decision", "merge", "session"] {
+        let n = day.entries.iter().filter(|e| e.kind == kind).count();
+        if n > 0 {
+            counts.push((kind, n));
+        }
+    }
+    let parts: Vec<String> = counts
+        .into_iter()
+        .map(|(kind, n)| {
+            if n == 1 {
+                format!("1 {kind}")
+            } else {
+                format!("{n} {kind}s")
+            }
+        })
+        .collect();
+    format!(" — {}", parts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_decision_event, new_merge_event, new_note_event};
+    use edda_core::types::DecisionPayload;
+
+    fn at(mut e: Event, ts: &str) -> Event {
+        e.ts = ts.to_string();
+        e
+    }
+
+    fn decision(key: &str, value: &str, reason: Option<&str>) -> Event {
+        let dp = DecisionPayload {
+            key: key.into(),
+            value: value.into(),
+            reason: reason.map(String::from),
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        new_decision_event("main", None, "user", &dp).unwrap()
+    }
+
+    #[test]
+    fn groups_milestones_by_day_newest_first() {
+        let events = vec![
+            at(
+                decision("db.engine", "sqlite", None),
+                "2026-10-10T09:00:00Z",
+            ),
+            at(
+                decision("db.engine", "postgres", Some("need JSONB")),
+                "2026-10-14T09:30:00Z",
+            ),
+            at(
+                new_note_event("main", None, "user", "plain note", &[]).unwrap(),
+                "2026-10-14T10:00:00Z",
+            ),
+            at(
+                new_merge_event("main", None, "feat/auth", "main", "done", &[]).unwrap(),
+                "2026-10-15T16:45:00Z",
+            ),
+            at(
+                new_merge_event("feat/x", None, "feat/x", "feat/y", "", &[]).unwrap(),
+                "2026-10-15T17:00:00Z",
+            ),
+        ];
+
+        let days = build(&events, "2026-10-12T00:00:00Z", Some("main"));
+        let dates: Vec<&str> = days.iter().map(|d| d.date.as_str()).collect();
+        assert_eq!(dates, ["2026-10-15", "2026-10-14"]);
+        assert_eq!(days[1].entries.len(), 1, "plain notes are not milestones");
+        assert_eq!(
+            days[1].entries[0].summary,
+            "db.engine = postgres — need JSONB"
+        );
+
+        let text = render(&days);
+        assert!(text.contains("## Thu 2026-10-15 — 1 merge"), "{text}");
+        assert!(
+            text.contains("  16:45  merge     feat/auth → main"),
+            "{text}"
+        );
+        assert!(text.contains("## Wed 2026-10-14 — 1 decision"), "{text}");
+        assert!(text.find("2026-10-15") < text.find("2026-10-14"));
+    }
+}
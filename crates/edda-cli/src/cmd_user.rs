@@ -7,7 +7,7 @@ use edda_store::registry;
 
 #[derive(Subcommand)]
 pub enum UserCmd {
-    /// List all registered projects
+    /// List all registered projects (same as `edda projects list`)
     Projects {
         /// Remove stale entries (projects whose .edda/ no longer exists)
         #[arg(long)]
@@ -88,7 +88,7 @@ pub enum UserConfigCmd {
 
 pub fn execute(cmd: UserCmd) -> anyhow::Result<()> {
     match cmd {
-        UserCmd::Projects { prune, json } => execute_projects(prune, json),
+        UserCmd::Projects { prune, json } => crate::cmd_projects::list(prune, json),
         UserCmd::Overview {
             after,
             before,
@@ -110,49 +110,6 @@ pub fn execute(cmd: UserCmd) -> anyhow::Result<()> {
     }
 }
 
-fn execute_projects(prune: bool, json: bool) -> anyhow::Result<()> {
-    if prune {
-        let (_valid, stale) = registry::validate_projects();
-        if stale.is_empty() {
-            println!("No stale projects found.");
-        } else {
-            for entry in &stale {
-                registry::unregister_project(&entry.project_id)?;
-                println!("  Removed: {} ({})", entry.name, entry.path);
-            }
-            println!("{} stale project(s) removed.", stale.len());
-        }
-        return Ok(());
-    }
-
-    let projects = registry::list_projects();
-    if json {
-        println!("{}", serde_json::to_string_pretty(&projects)?);
-        return Ok(());
-    }
-
-    if projects.is_empty() {
-        println!("No registered projects. Run `edda init` in a repository to register it.");
-        return Ok(());
-    }
-
-    println!("Registered projects ({}):\n", projects.len());
-    let (valid, _) = registry::validate_projects();
-    for p in &projects {
-        let status = if valid.iter().any(|v| v.project_id == p.project_id) {
-            "ok"
-        } else {
-            "stale"
-        };
-        println!(
-            "  {} [{}] {}\n    path: {}\n    registered: {}\n    last_seen: {}\n",
-            p.name, status, p.project_id, p.path, p.registered_at, p.last_seen
-        );
-    }
-
-    Ok(())
-}
-
 fn execute_overview(
     after: Option<String>,
     before: Option<String>,
@@ -0,0 +1,77 @@
+use edda_ledger::Ledger;
+use std::path::Path;
+
+/// `edda verify [--signatures] [--json]`
+///
+/// Checks the hash chain and, with `--signatures`, every stored event
+/// signature. Exits non-zero when either check fails.
+pub fn execute(repo_root: &Path, signatures: bool, json: bool) -> anyhow::Result<()> {
+    let ledger = Ledger::open(repo_root)?;
+    let chain = ledger.verify_chain();
+    let report = if signatures {
+        Some(ledger.verify_signatures()?)
+    } else {
+        None
+    };
+    let local_key = if signatures {
+        edda_ledger::signing::local_signer()
+    } else {
+        None
+    };
+    let local_key_id = local_key.as_ref().map(|s| s.key_id().to_string());
+
+    if json {
+        let out = serde_json::json!({
+            "chain": {
+                "ok": chain.is_ok(),
+                "error": chain.as_ref().err().map(|e| format!("{e:#}")),
+            },
+            "signatures": report,
+            "local_key_id": local_key_id,
+            "local_public_key": local_key.as_ref().map(|s| s.public_key_hex()),
+        });
+        println!("{}", serde_json::to_string_pretty(&out)?);
+    } else {
+        match &chain {
+            Ok(()) => println!("[OK] hash chain"),
+            Err(e) => println!("[FAIL] hash chain: {e:#}"),
+        }
+        if let Some(r) = &report {
+            println!(
+                "[{}] signatures: {} signed, {} unsigned of {} events",
+                if r.is_ok() { "OK" } else { "FAIL" },
+                r.signed,
+                r.unsigned,
+                r.total_events
+            );
+            for (key_id, count) in &r.keys {
+                let mine = if local_key_id.as_deref() == Some(key_id.as_str()) {
+                    " (this user)"
+                } else if r.untrusted_keys.contains(key_id) {
+                    " (untrusted)"
+                } else {
+                    ""
+                };
+                println!("  key {key_id}{mine}: {count} events");
+            }
+            for (event_id, reason) in &r.invalid {
+                println!("  invalid {event_id}: {reason}");
+            }
+            if let Some(key) = &local_key {
+                println!("  this user's public key: {}", key.public_key_hex());
+            }
+            if !r.gaps.is_empty() {
+                println!(
+                    "[WARN] {} unsigned events after signing began (first: {})",
+                    r.gaps.len(),
+                    r.gaps[0]
+                );
+            }
+        }
+    }
+
+    if chain.is_err() || report.as_ref().is_some_and(|r| !r.is_ok()) {
+        anyhow::bail!("ledger verification failed");
+    }
+    Ok(())
+}
@@ -2,20 +2,23 @@ use std::path::Path;
 
 /// Launch the real-time watch view.
 ///
-/// With the `tui` feature (default): opens the interactive ratatui TUI.
-/// Without: prints a plain-text event stream to stdout.
-pub fn execute(repo_root: &Path) -> anyhow::Result<()> {
+/// With the `tui` feature (default): opens the interactive ratatui TUI in
+/// the given theme. Without: prints a plain-text event stream to stdout and
+/// `theme` is ignored.
+pub fn execute(repo_root: &Path, theme: Option<&str>) -> anyhow::Result<()> {
     let project_id = edda_store::project_id(repo_root);
 
     #[cfg(feature = "tui")]
     {
-        crate::tui::run(project_id, repo_root.to_path_buf())
+        crate::tui::run(project_id, repo_root.to_path_buf(), theme)
     }
 
     #[cfg(not(feature = "tui"))]
     {
         use edda_bridge_claude::watch;
 
+        let _ = theme;
+
         // Auto-init
         if let Err(e) = edda_store::ensure_dirs(&project_id) {
             eprintln!("Warning: failed to ensure store dirs: {e}");
@@ -1,4 +1,8 @@
 mod cmd_actor;
+mod cmd_adr;
+mod cmd_annotate_code;
+mod cmd_answer;
+mod cmd_archive;
 mod cmd_ask;
 mod cmd_blob;
 mod cmd_branch;
@@ -6,42 +10,56 @@ mod cmd_bridge;
 mod cmd_brief;
 mod cmd_bundle;
 mod cmd_commit;
+mod cmd_completion_context;
 mod cmd_conduct;
 mod cmd_config;
 mod cmd_context;
 mod cmd_controls;
+mod cmd_doctor;
 mod cmd_draft;
 mod cmd_export;
 mod cmd_gc;
 mod cmd_group;
+mod cmd_handoff;
 mod cmd_init;
 mod cmd_intake;
 mod cmd_log;
+mod cmd_maintenance;
 mod cmd_merge;
 mod cmd_note;
 mod cmd_notify;
 mod cmd_pair;
 mod cmd_pattern;
 mod cmd_phase;
+mod cmd_pin;
 mod cmd_pipeline;
 mod cmd_plan;
 mod cmd_policy;
+mod cmd_projects;
 mod cmd_propose;
 mod cmd_prs;
 mod cmd_rebuild;
 mod cmd_recap;
+mod cmd_replay;
+mod cmd_revoke;
 mod cmd_rules;
 mod cmd_run;
 mod cmd_scan;
+mod cmd_scratch;
 mod cmd_search;
 mod cmd_serve;
 mod cmd_skill;
+mod cmd_stats;
 mod cmd_status;
+mod cmd_store;
 mod cmd_switch;
 mod cmd_sync;
+mod cmd_tag;
 mod cmd_task;
+mod cmd_timeline;
 mod cmd_tool_tier;
 mod cmd_user;
+mod cmd_verify;
 mod cmd_watch;
 mod fleet;
 mod pipeline_templates;
@@ -86,6 +104,9 @@ enum Command {
         /// Tags for the note (repeatable)
         #[arg(long = "tag")]
         tags: Vec<String>,
+        /// File to attach as evidence, stored in the blob store (repeatable)
+        #[arg(long = "attach")]
+        attach: Vec<std::path::PathBuf>,
     },
     /// Record a decision — agent-authored, unratified until `edda ratify` (shortcut for `bridge claude decide`)
     Decide {
@@ -100,8 +121,10 @@ enum Command {
         /// Session ID (auto-inferred from active heartbeats if omitted)
         #[arg(long)]
         session: Option<String>,
-        /// Decision scope: local (default), shared, or global
-        #[arg(long, default_value = "local")]
+        /// Decision scope: session, branch, project (default), shared, or org.
+        /// Session and branch decisions layer over the project decision
+        /// instead of superseding it
+        #[arg(long, default_value = "project")]
         scope: String,
         /// File glob patterns this decision governs (repeatable)
         #[arg(long = "paths")]
@@ -109,6 +132,13 @@ enum Command {
         /// Comma-separated tags for this decision
         #[arg(long, value_delimiter = ',')]
         tags: Vec<String>,
+        /// Actor name from actors.yaml; an override role lets it write to a
+        /// protected branch (self-asserted, not verified)
+        #[arg(long)]
+        actor: Option<String>,
+        /// File to attach as evidence, stored in the blob store (repeatable)
+        #[arg(long = "attach")]
+        attach: Vec<std::path::PathBuf>,
     },
     /// Ratify an active decision — confer operator authority (GH-401)
     Ratify {
@@ -126,6 +156,51 @@ enum Command {
         #[arg(long)]
         session: Option<String>,
     },
+    /// Answer a question note — appends an answer linked to it, closing the question
+    Answer {
+        /// Event ID of the note tagged `question`
+        event_id: String,
+        /// Answer text (defaults to the text of the --by event)
+        text: Option<String>,
+        /// Event ID of an existing note or decision that answers it
+        #[arg(long)]
+        by: Option<String>,
+    },
+    /// Walk back a decision or note — appends a `revokes` event; the target stays in the ledger
+    #[command(alias = "undo")]
+    Revoke {
+        /// Event ID of the decision or note to revoke
+        event_id: String,
+        /// Why it is being revoked
+        #[arg(long)]
+        reason: String,
+    },
+    /// Pin a decision or note so it appears in every pack and context (no ID: list pins)
+    Pin {
+        /// Event ID of the decision or note to pin
+        event_id: Option<String>,
+        /// Why it is pinned
+        #[arg(long, requires = "event_id")]
+        reason: Option<String>,
+        /// Output the pin list as JSON
+        #[arg(long, conflicts_with = "event_id")]
+        json: bool,
+    },
+    /// Remove a pin added with `edda pin`
+    Unpin {
+        /// Event ID of the pinned decision or note
+        event_id: String,
+    },
+    /// List, rename and query tags on notes and decisions
+    Tag {
+        #[command(subcommand)]
+        cmd: cmd_tag::TagCmd,
+    },
+    /// List workspaces in the user-level project registry
+    Projects {
+        #[command(subcommand)]
+        cmd: cmd_projects::ProjectsCmd,
+    },
     /// Manage project groups for cross-project sync
     Group {
         #[command(subcommand)]
@@ -183,6 +258,14 @@ enum Command {
         #[arg(long)]
         session: Option<String>,
     },
+    /// Per-session scratchpad, restored at the session's next SessionStart
+    Scratch {
+        /// Session ID (auto-inferred from active heartbeats if omitted)
+        #[arg(long, global = true)]
+        session: Option<String>,
+        #[command(subcommand)]
+        cmd: cmd_scratch::ScratchCmd,
+    },
     /// Setup a bridge integration (shortcut for `bridge <platform> install`)
     Setup {
         #[command(subcommand)]
@@ -207,9 +290,69 @@ enum Command {
         /// Show impact analysis for override safety
         #[arg(long)]
         impact: bool,
+        /// Reconstruct the rationale chain for an exact key (e.g. `--why db.engine`)
+        #[arg(long)]
+        why: bool,
+        /// List every value each matching key has ever held, with the dates it
+        /// was active ("have we ever tried X?")
+        #[arg(long, conflicts_with = "why")]
+        history: bool,
+        /// Answer as of a date (2026-01-15) or RFC 3339 timestamp: the
+        /// decisions that were active then
+        #[arg(long, value_name = "WHEN", conflicts_with = "history")]
+        as_of: Option<String>,
+        /// Cut notes, commit purposes, and transcript snippets to N characters
+        #[arg(long, value_name = "N")]
+        snippet_chars: Option<usize>,
+        /// With --snippet-chars, cut at the last sentence boundary
+        #[arg(long, requires = "snippet_chars")]
+        sentence_cut: bool,
+        /// Drop lowest-priority hits until the JSON result fits in N characters
+        #[arg(long, value_name = "N")]
+        max_chars: Option<usize>,
         /// Ask every project in the fleet, not just this workspace
         #[arg(long)]
         fleet: bool,
+        /// Ask another registered project (id, id prefix, or name) instead of this workspace
+        #[arg(long, conflicts_with = "fleet")]
+        project: Option<String>,
+        /// Only decisions made at this scope (session, branch, project, shared, org)
+        #[arg(long)]
+        scope: Option<edda_core::types::DecisionScope>,
+        /// Answer several exact keys at once (comma-separated): each key's
+        /// active decision and recent timeline
+        #[arg(
+            long,
+            value_delimiter = ',',
+            conflicts_with_all = ["query", "why", "history", "impact", "fleet"]
+        )]
+        keys: Vec<String>,
+        /// Answer a question in prose, citing event ids, from what ask finds
+        /// (needs EDDA_LLM_API_KEY; otherwise prints the structured result)
+        #[arg(
+            long,
+            value_name = "QUESTION",
+            conflicts_with_all = ["query", "why", "history", "fleet", "keys"]
+        )]
+        answer: Option<String>,
+    },
+    /// Brief the next session: claims, open tasks, failing commands, requests and recent decisions
+    Handoff {
+        /// Who picks up the work (a peer label or person)
+        #[arg(long)]
+        to: Option<String>,
+        /// Session ID (auto-inferred from active heartbeats if omitted)
+        #[arg(long)]
+        session: Option<String>,
+        /// Print the latest handoff (addressed to --to, if given) instead of writing one
+        #[arg(long)]
+        show: bool,
+        /// Print the brief without storing it
+        #[arg(long, conflicts_with = "show")]
+        dry_run: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Chronicle synthesis - cognitive zoom across sessions
     Recap {
@@ -231,6 +374,17 @@ enum Command {
         #[arg(long)]
         json: bool,
     },
+    /// Replay a past session: prompts, replies, tool calls, decisions and commits in order
+    Replay {
+        /// Session id (or an unambiguous prefix)
+        session: String,
+        /// Show full message text instead of one-line previews
+        #[arg(long)]
+        full: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Run a command and record its output
     Run {
         /// Command and arguments (after --)
@@ -239,6 +393,15 @@ enum Command {
     },
     /// Show workspace status
     Status,
+    /// Verify the ledger hash chain (and event signatures with --signatures)
+    Verify {
+        /// Also verify ed25519 event signatures
+        #[arg(long)]
+        signatures: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Create a commit event
     Commit {
         /// Commit title
@@ -265,6 +428,10 @@ enum Command {
         /// Maximum number of auto-evidence items
         #[arg(long, default_value_t = 20)]
         max_evidence: usize,
+        /// Actor name from actors.yaml; an override role lets it write to a
+        /// protected branch (self-asserted, not verified)
+        #[arg(long)]
+        actor: Option<String>,
     },
     /// Query events from the ledger with filters
     Log {
@@ -301,6 +468,45 @@ enum Command {
         /// Read every project in the fleet, not just this workspace
         #[arg(long)]
         fleet: bool,
+        /// Also read events `edda gc` moved to the cold archive
+        #[arg(long)]
+        include_archive: bool,
+    },
+    /// Day-grouped history of commits, decisions, merges and sessions
+    Timeline {
+        /// How many days back to cover
+        #[arg(long, default_value_t = 7)]
+        days: u32,
+        /// Only events on this branch
+        #[arg(long)]
+        branch: Option<String>,
+        /// Output as JSON (days newest first)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Workspace analytics (decisions, activity, commits, files, blobs, packs) and ledger append metrics
+    Stats {
+        /// How many files and payload fields to list
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Window for events per day and commit cadence
+        #[arg(long, default_value_t = 14)]
+        days: u32,
+        /// Output the raw stats as JSON
+        #[arg(long)]
+        json: bool,
+        /// Clear the recorded stats and start over
+        #[arg(long, conflicts_with = "json")]
+        reset: bool,
+    },
+    /// Link `edda:decision <key>` code comments to ledger decisions (editor hover export)
+    AnnotateCode {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+        /// Show the decisions annotated at FILE:LINE instead of rescanning
+        #[arg(long, value_name = "FILE:LINE")]
+        lookup: Option<String>,
     },
     /// Output context snapshot as Markdown
     Context {
@@ -311,6 +517,15 @@ enum Command {
         #[arg(long, default_value = "5")]
         depth: usize,
     },
+    /// Print a compact JSON context (decisions, open tasks, claims, last commit) for other tools
+    CompletionContext {
+        /// Branch name (defaults to HEAD)
+        #[arg(long)]
+        branch: Option<String>,
+        /// Upper bound on the output size in bytes
+        #[arg(long, default_value = "4000")]
+        max_bytes: usize,
+    },
     /// Rebuild derived views
     Rebuild {
         /// Rebuild a specific branch (defaults to HEAD)
@@ -340,14 +555,29 @@ enum Command {
         /// Destination branch (must be HEAD)
         dst: String,
         /// Reason for merge
-        #[arg(short = 'm', long = "reason")]
-        reason: String,
+        #[arg(short = 'm', long = "reason", required_unless_present = "dry_run")]
+        reason: Option<String>,
+        /// Reconcile decision keys that differ between the branches: ours, theirs, union or interactive
+        #[arg(long)]
+        strategy: Option<edda_ledger::MergeStrategy>,
+        /// Actor name from actors.yaml; an override role lets a strategy write
+        /// resolved decisions to a protected destination (self-asserted, not verified)
+        #[arg(long)]
+        actor: Option<String>,
+        /// Only report how the branches' decisions differ; write nothing
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Draft commit operations (propose, show, list, apply, delete)
     Draft {
         #[command(subcommand)]
         cmd: cmd_draft::DraftCmd,
     },
+    /// Render each decision key's history as a numbered ADR (export, sync)
+    Adr {
+        #[command(subcommand)]
+        cmd: cmd_adr::AdrCmd,
+    },
     /// Export the ledger as human-readable Markdown (read-only projection; SQLite stays authoritative)
     Export {
         /// Output format (currently only "md" is supported)
@@ -374,6 +604,11 @@ enum Command {
         #[command(subcommand)]
         cmd: cmd_bridge::DoctorCmd,
     },
+    /// Per-project store maintenance (health, diagnostics)
+    Store {
+        #[command(subcommand)]
+        cmd: cmd_store::StoreCmd,
+    },
     /// Index operations
     Index {
         #[command(subcommand)]
@@ -399,6 +634,11 @@ enum Command {
         #[command(subcommand)]
         cmd: cmd_search::SearchCmd,
     },
+    /// Cold storage for the archive: push old event segments and blobs off-disk, fetch them back
+    Archive {
+        #[command(subcommand)]
+        cmd: cmd_archive::ArchiveCmd,
+    },
     /// Manage blob metadata (classify, pin, unpin, info, stats)
     Blob {
         #[command(subcommand)]
@@ -463,7 +703,11 @@ enum Command {
         cmd: cmd_policy::PolicyCmd,
     },
     /// Launch the real-time peer status and event TUI
-    Watch,
+    Watch {
+        /// Color theme: dark, light, mono or ascii (overrides `tui.theme`)
+        #[arg(long)]
+        theme: Option<String>,
+    },
     /// Push notification management
     Notify {
         #[command(subcommand)]
@@ -510,6 +754,15 @@ enum Command {
         #[arg(long)]
         include_sessions: bool,
     },
+    /// Vacuum ledger.db, compact transcript indexes, remove orphaned temp files
+    Maintenance {
+        /// Report what would be done without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// User-level aggregation (cross-repo queries, rollup, config)
     User {
         #[command(subcommand)]
@@ -982,16 +1235,27 @@ fn main() -> anyhow::Result<()> {
         .init();
 
     let cli = parse_cli();
+    // Every command that appends (hooks, serve, mcp, decide, …) runs here,
+    // so `notify_rules` see all of them. Matches are only queued during the
+    // append: servers deliver them from a task that stops with the server,
+    // every other command (hooks too) once done, locks released.
+    edda_notify::rules::install();
     let cwd = std::env::current_dir()?;
     let repo_root = edda_ledger::EddaPaths::find_root(&cwd).unwrap_or(cwd);
+    let deliver_on_exit = !matches!(&cli.cmd, Command::Serve { .. } | Command::Mcp { .. });
 
-    match cli.cmd {
+    let result = match cli.cmd {
         Command::Init {
             no_hooks,
             force_skills,
         } => cmd_init::execute(&repo_root, no_hooks, force_skills),
         Command::Actor { cmd } => cmd_actor::run(cmd, &repo_root),
-        Command::Note { text, role, tags } => cmd_note::execute(&repo_root, &text, &role, &tags),
+        Command::Note {
+            text,
+            role,
+            tags,
+            attach,
+        } => cmd_note::execute(&repo_root, &text, &role, &tags, &attach),
         Command::Decide {
             decision,
             reason,
@@ -1000,6 +1264,8 @@ fn main() -> anyhow::Result<()> {
             scope,
             paths,
             tags,
+            actor,
+            attach,
         } => cmd_bridge::decide(
             &repo_root,
             &decision,
@@ -1009,6 +1275,8 @@ fn main() -> anyhow::Result<()> {
             Some(&scope),
             &paths,
             &tags,
+            actor.as_deref(),
+            &attach,
         ),
         Command::Ratify {
             key,
@@ -1022,6 +1290,21 @@ fn main() -> anyhow::Result<()> {
             by.as_deref(),
             session.as_deref(),
         ),
+        Command::Answer { event_id, text, by } => {
+            cmd_answer::execute(&repo_root, &event_id, text.as_deref(), by.as_deref())
+        }
+        Command::Revoke { event_id, reason } => cmd_revoke::execute(&repo_root, &event_id, &reason),
+        Command::Pin {
+            event_id,
+            reason,
+            json,
+        } => match event_id {
+            Some(id) => cmd_pin::pin(&repo_root, &id, reason.as_deref()),
+            None => cmd_pin::list(&repo_root, json),
+        },
+        Command::Unpin { event_id } => cmd_pin::unpin(&repo_root, &event_id),
+        Command::Tag { cmd } => cmd_tag::run(cmd, &repo_root),
+        Command::Projects { cmd } => cmd_projects::execute(cmd),
         Command::Group { cmd } => cmd_group::execute(cmd, &repo_root),
         Command::Sync { from, dry_run } => cmd_sync::execute(&repo_root, from.as_deref(), dry_run),
         Command::Task { cmd } => cmd_task::execute(cmd, &repo_root),
@@ -1042,6 +1325,7 @@ fn main() -> anyhow::Result<()> {
         Command::Coord { session } => {
             cmd_bridge::render_coordination(&repo_root, session.as_deref())
         }
+        Command::Scratch { session, cmd } => cmd_scratch::run(cmd, &repo_root, session.as_deref()),
         Command::Setup { cmd } => match cmd {
             SetupCmd::Openclaw { target, uninstall } => {
                 let path = target.as_deref().map(std::path::Path::new);
@@ -1059,7 +1343,17 @@ fn main() -> anyhow::Result<()> {
             all,
             branch,
             impact,
+            why,
+            history,
+            as_of,
+            snippet_chars,
+            sentence_cut,
+            max_chars,
             fleet,
+            project,
+            scope,
+            keys,
+            answer,
         } => cmd_ask::execute(
             &repo_root,
             query.as_deref(),
@@ -1068,8 +1362,36 @@ fn main() -> anyhow::Result<()> {
             all,
             branch.as_deref(),
             impact,
+            why,
+            history,
+            as_of.as_deref(),
+            snippet_chars,
+            sentence_cut,
+            max_chars,
             fleet,
+            project.as_deref(),
+            scope,
+            &keys,
+            answer.as_deref(),
         ),
+        Command::Replay {
+            session,
+            full,
+            json,
+        } => cmd_replay::execute(&repo_root, &session, full, json),
+        Command::Handoff {
+            to,
+            session,
+            show,
+            dry_run,
+            json,
+        } => {
+            if show {
+                cmd_handoff::show(&repo_root, to.as_deref(), json)
+            } else {
+                cmd_handoff::execute(&repo_root, to.as_deref(), session.as_deref(), dry_run, json)
+            }
+        }
         Command::Recap {
             query,
             project,
@@ -1088,6 +1410,7 @@ fn main() -> anyhow::Result<()> {
         ),
         Command::Run { argv } => cmd_run::execute(&repo_root, &argv),
         Command::Status => cmd_status::execute(&repo_root),
+        Command::Verify { signatures, json } => cmd_verify::execute(&repo_root, signatures, json),
         Command::Commit {
             title,
             purpose,
@@ -1097,6 +1420,7 @@ fn main() -> anyhow::Result<()> {
             auto,
             dry_run,
             max_evidence,
+            actor,
         } => cmd_commit::execute(cmd_commit::CommitCliParams {
             repo_root: &repo_root,
             title: &title,
@@ -1107,6 +1431,7 @@ fn main() -> anyhow::Result<()> {
             auto,
             dry_run,
             max_evidence,
+            actor: actor.as_deref(),
         }),
         Command::Log {
             event_type,
@@ -1120,6 +1445,7 @@ fn main() -> anyhow::Result<()> {
             limit,
             json,
             fleet,
+            include_archive,
         } => cmd_log::execute(&cmd_log::LogParams {
             repo_root: &repo_root,
             event_type: event_type.as_deref(),
@@ -1133,10 +1459,26 @@ fn main() -> anyhow::Result<()> {
             limit,
             json,
             fleet,
+            include_archive,
         }),
+        Command::Timeline { days, branch, json } => {
+            cmd_timeline::execute(&repo_root, days, branch.as_deref(), json)
+        }
+        Command::Stats {
+            top,
+            days,
+            json,
+            reset,
+        } => cmd_stats::execute(&repo_root, top, days, json, reset),
+        Command::AnnotateCode { json, lookup } => {
+            cmd_annotate_code::execute(&repo_root, json, lookup.as_deref())
+        }
         Command::Context { branch, depth } => {
             cmd_context::execute(&repo_root, branch.as_deref(), depth)
         }
+        Command::CompletionContext { branch, max_bytes } => {
+            cmd_completion_context::execute(&repo_root, branch.as_deref(), max_bytes)
+        }
         Command::Rebuild {
             branch,
             all,
@@ -1144,8 +1486,24 @@ fn main() -> anyhow::Result<()> {
         } => cmd_rebuild::execute(&repo_root, branch.as_deref(), all, &reason),
         Command::Branch { cmd } => cmd_branch::run(cmd, &repo_root),
         Command::Switch { name } => cmd_switch::execute(&repo_root, &name),
-        Command::Merge { src, dst, reason } => cmd_merge::execute(&repo_root, &src, &dst, &reason),
+        Command::Merge {
+            src,
+            dst,
+            reason,
+            strategy,
+            actor,
+            dry_run,
+        } => cmd_merge::execute(
+            &repo_root,
+            &src,
+            &dst,
+            reason.as_deref().unwrap_or_default(),
+            strategy,
+            actor.as_deref(),
+            dry_run,
+        ),
         Command::Draft { cmd } => cmd_draft::run(cmd, &repo_root),
+        Command::Adr { cmd } => cmd_adr::run(cmd, &repo_root),
         Command::Export {
             format,
             out,
@@ -1159,6 +1517,7 @@ fn main() -> anyhow::Result<()> {
         Command::Bridge { cmd } => cmd_bridge::run_bridge(cmd, &repo_root),
         Command::Hook { cmd } => cmd_bridge::run_hook(cmd),
         Command::Doctor { cmd } => cmd_bridge::run_doctor(cmd, &repo_root),
+        Command::Store { cmd } => cmd_store::run(cmd, &repo_root),
         Command::Index { cmd } => cmd_bridge::run_index(cmd),
         Command::Config { cmd } => cmd_config::run(cmd, &repo_root),
         Command::Pattern { cmd } => cmd_pattern::run(cmd, &repo_root),
@@ -1169,6 +1528,7 @@ fn main() -> anyhow::Result<()> {
             }
         },
         Command::Search { cmd } => cmd_search::run_cmd(cmd, &repo_root),
+        Command::Archive { cmd } => cmd_archive::run(cmd, &repo_root),
         Command::Blob { cmd } => cmd_blob::run(cmd, &repo_root),
         Command::Plan { cmd } => cmd_plan::run(cmd, &repo_root),
         Command::Conduct { cmd } => cmd_conduct::run_cmd(cmd, &repo_root),
@@ -1214,7 +1574,7 @@ fn main() -> anyhow::Result<()> {
             }
         }
         Command::Policy { cmd } => cmd_policy::run(cmd, &repo_root),
-        Command::Watch => cmd_watch::execute(&repo_root),
+        Command::Watch { theme } => cmd_watch::execute(&repo_root, theme.as_deref()),
         Command::Notify { cmd } => cmd_notify::run(cmd, &repo_root),
         Command::Pair { cmd } => cmd_pair::execute(cmd, &repo_root),
         Command::Serve { bind, port } => cmd_serve::execute(&repo_root, &bind, port),
@@ -1238,6 +1598,9 @@ fn main() -> anyhow::Result<()> {
             archive_keep_days,
             include_sessions,
         }),
+        Command::Maintenance { dry_run, json } => {
+            cmd_maintenance::execute(&repo_root, dry_run, json)
+        }
         Command::User { cmd } => cmd_user::execute(cmd),
         Command::Rules { cmd } => cmd_rules::execute(cmd, &repo_root),
         Command::Scan { cmd } => cmd_scan::execute(cmd, &repo_root),
@@ -1245,7 +1608,11 @@ fn main() -> anyhow::Result<()> {
         Command::ProposePatch { cmd } => cmd_controls::execute(cmd, &repo_root),
         Command::Skill { cmd } => cmd_skill::execute(cmd, &repo_root),
         Command::ToolTier { cmd } => cmd_tool_tier::run(cmd, &repo_root),
+    };
+    if deliver_on_exit {
+        edda_notify::rules::deliver_queued(&repo_root);
     }
+    result
 }
 
 fn parse_cli() -> Cli {
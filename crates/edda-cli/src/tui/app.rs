@@ -1,8 +1,15 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::path::PathBuf;
 
 use edda_bridge_claude::peers::{BoardState, PeerSummary};
 use edda_bridge_claude::watch;
+use edda_conductor::state::machine::{PhaseState, PlanState};
+use edda_conductor::state::persist;
+use edda_core::types::Event;
+use edda_ledger::{DecisionView, Ledger};
+
+use super::replay::ReplayView;
+use super::theme::Theme;
 
 /// Domains considered internal (shown collapsed by default).
 /// All other domains are expanded by default.
@@ -13,12 +20,62 @@ pub fn is_internal_domain(domain: &str) -> bool {
     INTERNAL_DOMAINS.contains(&domain)
 }
 
+/// Linked commits shown under an expanded decision.
+const DETAIL_COMMIT_LIMIT: usize = 10;
+
+/// Which top-level view is shown; `d` and `v` switch between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+    /// Peers, events, bindings and plans.
+    Live,
+    /// Active ledger decisions with their supersede history.
+    Decisions,
+    /// Past sessions, replayed turn by turn.
+    Replay,
+}
+
+/// The supersede timeline and linked commits of the expanded decision.
+pub struct DecisionDetail {
+    pub event_id: String,
+    /// Every decision on the key, oldest first, the active one included.
+    pub timeline: Vec<DecisionView>,
+    pub commits: Vec<Event>,
+}
+
+/// One row of the decisions tab. Detail rows carry the decision they
+/// belong to, so `r` works from anywhere under it.
+#[derive(Debug, Clone, Copy)]
+pub enum DecisionRow<'a> {
+    Domain(&'a str, usize),
+    Decision(&'a DecisionView),
+    History(&'a DecisionView, &'a DecisionView),
+    Commit(&'a DecisionView, &'a Event),
+}
+
+impl<'a> DecisionRow<'a> {
+    /// The active decision this row is, or belongs to.
+    pub fn decision(self) -> Option<&'a DecisionView> {
+        match self {
+            Self::Domain(..) => None,
+            Self::Decision(d) | Self::History(d, _) | Self::Commit(d, _) => Some(d),
+        }
+    }
+}
+
 /// Which panel is currently focused.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Panel {
     Peers,
     Events,
     Decisions,
+    Plans,
+}
+
+/// One row of the plans pane: a plan header or one of its phases.
+#[derive(Debug, Clone, Copy)]
+pub enum PlanRow<'a> {
+    Plan(&'a PlanState),
+    Phase(&'a PlanState, &'a PhaseState),
 }
 
 impl Panel {
@@ -26,15 +83,17 @@ impl Panel {
         match self {
             Panel::Peers => Panel::Events,
             Panel::Events => Panel::Decisions,
-            Panel::Decisions => Panel::Peers,
+            Panel::Decisions => Panel::Plans,
+            Panel::Plans => Panel::Peers,
         }
     }
 
     pub fn prev(self) -> Self {
         match self {
-            Panel::Peers => Panel::Decisions,
+            Panel::Peers => Panel::Plans,
             Panel::Events => Panel::Peers,
             Panel::Decisions => Panel::Events,
+            Panel::Plans => Panel::Decisions,
         }
     }
 }
@@ -44,6 +103,7 @@ pub struct App {
     pub project_id: String,
     pub repo_root: PathBuf,
     pub should_quit: bool,
+    pub tab: Tab,
     pub active_panel: Panel,
     pub paused: bool,
 
@@ -51,17 +111,34 @@ pub struct App {
     pub peers: Vec<PeerSummary>,
     pub board: BoardState,
     pub events: Vec<edda_core::types::Event>,
+    pub plans: Vec<PlanState>,
+    /// Active ledger decisions, for the decisions tab.
+    pub decisions: Vec<DecisionView>,
+    pub decision_detail: Option<DecisionDetail>,
+    /// Indexed sessions for the replay tab, newest first.
+    pub replay_sessions: Vec<(String, time::OffsetDateTime)>,
+    /// The session being replayed; `None` shows the session list.
+    pub replay: Option<ReplayView>,
+    /// The search being typed in the replay tab, after `/`.
+    pub replay_input: Option<String>,
     pub error: Option<String>,
+    /// Result of the last retry/skip action, shown in the status bar.
+    pub notice: Option<String>,
 
     // Scroll positions (per panel)
     pub peer_scroll: usize,
     pub event_scroll: usize,
     pub decision_scroll: usize,
+    pub plan_scroll: usize,
+    pub browser_scroll: usize,
+    pub session_scroll: usize,
 
     // Filters
     pub show_cmd_events: bool,
     pub show_stale_peers: bool,
     pub expanded_domains: HashSet<String>,
+
+    pub theme: Theme,
 }
 
 impl App {
@@ -70,18 +147,30 @@ impl App {
             project_id,
             repo_root,
             should_quit: false,
+            tab: Tab::Live,
             active_panel: Panel::Peers,
             paused: false,
             peers: Vec::new(),
             board: BoardState::default(),
             events: Vec::new(),
+            plans: Vec::new(),
+            decisions: Vec::new(),
+            decision_detail: None,
+            replay_sessions: Vec::new(),
+            replay: None,
+            replay_input: None,
             error: None,
+            notice: None,
             peer_scroll: 0,
             event_scroll: 0,
             decision_scroll: 0,
+            plan_scroll: 0,
+            browser_scroll: 0,
+            session_scroll: 0,
             show_cmd_events: false,
             show_stale_peers: false,
             expanded_domains: HashSet::new(),
+            theme: Theme::default(),
         }
     }
 
@@ -112,6 +201,52 @@ impl App {
             .collect()
     }
 
+    /// Flatten conductor plans into rows: each plan header followed by its phases.
+    pub fn plan_rows(&self) -> Vec<PlanRow<'_>> {
+        let mut rows = Vec::new();
+        for plan in &self.plans {
+            rows.push(PlanRow::Plan(plan));
+            for phase in &plan.phases {
+                rows.push(PlanRow::Phase(plan, phase));
+            }
+        }
+        rows
+    }
+
+    /// The (plan name, phase id) under the cursor in the plans pane, if any.
+    pub fn selected_phase(&self) -> Option<(String, String)> {
+        match self.plan_rows().get(self.plan_scroll)? {
+            PlanRow::Phase(plan, phase) => Some((plan.plan_name.clone(), phase.id.clone())),
+            PlanRow::Plan(_) => None,
+        }
+    }
+
+    /// Rows of the decisions tab: each domain header followed by its active
+    /// decisions, with the expanded one's timeline and commits beneath it.
+    pub fn decision_rows(&self) -> Vec<DecisionRow<'_>> {
+        let mut groups: BTreeMap<&str, Vec<&DecisionView>> = BTreeMap::new();
+        for d in &self.decisions {
+            groups.entry(d.domain.as_str()).or_default().push(d);
+        }
+        let mut rows = Vec::new();
+        for (domain, decisions) in groups {
+            rows.push(DecisionRow::Domain(domain, decisions.len()));
+            for d in decisions {
+                rows.push(DecisionRow::Decision(d));
+                let Some(detail) = self
+                    .decision_detail
+                    .as_ref()
+                    .filter(|x| x.event_id == d.event_id)
+                else {
+                    continue;
+                };
+                rows.extend(detail.timeline.iter().map(|h| DecisionRow::History(d, h)));
+                rows.extend(detail.commits.iter().map(|c| DecisionRow::Commit(d, c)));
+            }
+        }
+        rows
+    }
+
     /// Refresh data from disk (unless paused).
     /// Errors are stored in `self.error` instead of propagating.
     pub fn refresh_data(&mut self) {
@@ -129,22 +264,253 @@ impl App {
                 self.error = Some(e.to_string());
             }
         }
+        self.reload_plans();
+        self.reload_decisions();
+    }
+
+    /// Reload active decisions. A decision that stopped being active takes
+    /// its expanded detail with it.
+    fn reload_decisions(&mut self) {
+        let Ok(decisions) =
+            Ledger::open(&self.repo_root).and_then(|l| l.active_decisions(None, None, None, None))
+        else {
+            return;
+        };
+        self.decisions = decisions;
+        if let Some(detail) = &self.decision_detail {
+            if !self.decisions.iter().any(|d| d.event_id == detail.event_id) {
+                self.decision_detail = None;
+            }
+        }
+        let rows = self.decision_rows().len();
+        self.browser_scroll = self.browser_scroll.min(rows.saturating_sub(1));
+    }
+
+    /// Expand the decision under the cursor into its supersede timeline and
+    /// the commits citing any decision on it, or collapse it again.
+    fn toggle_decision_detail(&mut self) {
+        let Some(d) = self
+            .decision_rows()
+            .get(self.browser_scroll)
+            .and_then(|r| r.decision())
+            .cloned()
+        else {
+            return;
+        };
+        if self
+            .decision_detail
+            .as_ref()
+            .is_some_and(|x| x.event_id == d.event_id)
+        {
+            self.decision_detail = None;
+            return;
+        }
+        let detail = Ledger::open(&self.repo_root).and_then(|ledger| {
+            let timeline = ledger.decision_timeline(&d.key, None, None)?;
+            let ids: Vec<&str> = timeline.iter().map(|t| t.event_id.as_str()).collect();
+            let commits = ledger.find_related_commits(None, "", &ids, DETAIL_COMMIT_LIMIT)?;
+            Ok(DecisionDetail {
+                event_id: d.event_id.clone(),
+                timeline,
+                commits,
+            })
+        });
+        match detail {
+            Ok(detail) => self.decision_detail = Some(detail),
+            Err(e) => self.notice = Some(e.to_string()),
+        }
+    }
+
+    /// Flag the decision under the cursor for review: a `review` note that
+    /// cites it, so it shows up in `edda ask --why` for the key.
+    fn mark_for_review(&mut self) {
+        let Some(d) = self
+            .decision_rows()
+            .get(self.browser_scroll)
+            .and_then(|r| r.decision())
+            .cloned()
+        else {
+            return;
+        };
+        let result = Ledger::open(&self.repo_root).and_then(|ledger| {
+            let _lock = edda_ledger::lock::WorkspaceLock::acquire(&ledger.paths)?;
+            let branch = ledger.head_branch()?;
+            let parent_hash = ledger.last_event_hash()?;
+            let mut event = edda_core::event::new_note_event(
+                &branch,
+                parent_hash.as_deref(),
+                "user",
+                &format!("Review requested: {}={}", d.key, d.value),
+                &["review".to_string()],
+            )?;
+            event.refs.events.push(d.event_id.clone());
+            edda_core::event::finalize_event(&mut event)?;
+            ledger.append_event(&event)?;
+            let _ = edda_derive::rebuild_branch(&ledger, &branch);
+            Ok(())
+        });
+        self.notice = Some(match result {
+            Ok(()) => format!("{}: marked for review", d.key),
+            Err(e) => e.to_string(),
+        });
+    }
+
+    /// Reload conductor plan states. Unreadable state files are skipped,
+    /// matching `edda conduct status --json`.
+    fn reload_plans(&mut self) {
+        let names = persist::list_plans(&self.repo_root).unwrap_or_default();
+        self.plans = names
+            .iter()
+            .filter_map(|name| persist::load_state(&self.repo_root, name).ok().flatten())
+            .collect();
+        let rows = self.plan_rows().len();
+        self.plan_scroll = self.plan_scroll.min(rows.saturating_sub(1));
+    }
+
+    /// Retry or skip the selected phase, persisting straight to the plan's
+    /// state file so a running `edda conduct run` picks it up.
+    fn act_on_selected_phase(&mut self, retry: bool) {
+        if self.active_panel != Panel::Plans {
+            return;
+        }
+        let Some((plan_name, phase_id)) = self.selected_phase() else {
+            return;
+        };
+        let result = persist::load_state(&self.repo_root, &plan_name).and_then(|state| {
+            let mut state =
+                state.ok_or_else(|| anyhow::anyhow!("no state for plan \"{plan_name}\""))?;
+            if retry {
+                state.retry_phase(&phase_id)?;
+            } else {
+                state.skip_phase(&phase_id, Some("skipped from edda watch"))?;
+            }
+            persist::save_state(&self.repo_root, &state)
+        });
+        self.notice = Some(match result {
+            Ok(()) if retry => format!("{phase_id}: reset to pending"),
+            Ok(()) => format!("{phase_id}: skipped"),
+            Err(e) => e.to_string(),
+        });
+        self.reload_plans();
+    }
+
+    /// Reload the replay tab's session list.
+    fn reload_replay_sessions(&mut self) {
+        let project_dir = edda_store::project_dir(&self.project_id);
+        self.replay_sessions = crate::cmd_replay::indexed_sessions(&project_dir);
+        self.session_scroll = self
+            .session_scroll
+            .min(self.replay_sessions.len().saturating_sub(1));
+    }
+
+    /// Load the session under the cursor into the replay viewer.
+    fn open_replay(&mut self) {
+        let Some((session_id, _)) = self.replay_sessions.get(self.session_scroll) else {
+            return;
+        };
+        let project_dir = edda_store::project_dir(&self.project_id);
+        let replay = Ledger::open(&self.repo_root)
+            .and_then(|ledger| crate::cmd_replay::build(&ledger, &project_dir, session_id));
+        match replay {
+            Ok(replay) => self.replay = Some(ReplayView::new(replay)),
+            Err(e) => self.notice = Some(e.to_string()),
+        }
+    }
+
+    /// Keys of the replay tab. Returns `false` for keys it leaves to the
+    /// global bindings.
+    fn handle_replay_key(&mut self, key: crossterm::event::KeyEvent) -> bool {
+        use crossterm::event::KeyCode;
+
+        if let Some(input) = &mut self.replay_input {
+            match key.code {
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    let query = self.replay_input.take().unwrap_or_default();
+                    if let Some(view) = &mut self.replay {
+                        if !view.search(&query) && !query.trim().is_empty() {
+                            self.notice = Some(format!("no match for \"{}\"", query.trim()));
+                        }
+                    }
+                }
+                KeyCode::Esc => self.replay_input = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        let Some(view) = &mut self.replay else {
+            match key.code {
+                KeyCode::Char('j') | KeyCode::Down => {
+                    if self.session_scroll + 1 < self.replay_sessions.len() {
+                        self.session_scroll += 1;
+                    }
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.session_scroll = self.session_scroll.saturating_sub(1);
+                }
+                KeyCode::Enter => self.open_replay(),
+                _ => return false,
+            }
+            return true;
+        };
+        match key.code {
+            KeyCode::Char('l') | KeyCode::Right => view.next_turn(),
+            KeyCode::Char('h') | KeyCode::Left => view.prev_turn(),
+            KeyCode::Char('j') | KeyCode::Down => view.scroll += 1,
+            KeyCode::Char('k') | KeyCode::Up => view.scroll = view.scroll.saturating_sub(1),
+            KeyCode::Char('/') => self.replay_input = Some(String::new()),
+            KeyCode::Char('n') => {
+                view.next_match(true);
+            }
+            KeyCode::Char('N') => {
+                view.next_match(false);
+            }
+            KeyCode::Backspace => self.replay = None,
+            _ => return false,
+        }
+        true
     }
 
     /// Handle a key press.
     pub fn handle_key(&mut self, key: crossterm::event::KeyEvent) {
         use crossterm::event::KeyCode;
 
+        if self.tab == Tab::Replay && self.handle_replay_key(key) {
+            return;
+        }
+
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
             KeyCode::Tab => self.active_panel = self.active_panel.next(),
             KeyCode::BackTab => self.active_panel = self.active_panel.prev(),
+            KeyCode::Char('d') => {
+                self.tab = match self.tab {
+                    Tab::Decisions => Tab::Live,
+                    Tab::Live | Tab::Replay => Tab::Decisions,
+                }
+            }
+            KeyCode::Char('v') => {
+                if self.tab == Tab::Replay {
+                    self.tab = Tab::Live;
+                } else {
+                    self.tab = Tab::Replay;
+                    self.reload_replay_sessions();
+                }
+            }
             KeyCode::Char(' ') => self.paused = !self.paused,
             KeyCode::Char('c') => self.show_cmd_events = !self.show_cmd_events,
             KeyCode::Char('p') => self.show_stale_peers = !self.show_stale_peers,
             KeyCode::Char('j') | KeyCode::Down => self.scroll_down(),
             KeyCode::Char('k') | KeyCode::Up => self.scroll_up(),
+            KeyCode::Enter if self.tab == Tab::Decisions => self.toggle_decision_detail(),
             KeyCode::Enter => self.toggle_domain_expand(),
+            KeyCode::Char('r') if self.tab == Tab::Decisions => self.mark_for_review(),
+            KeyCode::Char('r') if self.tab == Tab::Live => self.act_on_selected_phase(true),
+            KeyCode::Char('s') if self.tab == Tab::Live => self.act_on_selected_phase(false),
             _ => {}
         }
     }
@@ -203,18 +569,26 @@ impl App {
     }
 
     fn active_scroll_and_max(&self) -> (usize, usize) {
+        if self.tab == Tab::Decisions {
+            return (self.browser_scroll, self.decision_rows().len());
+        }
         match self.active_panel {
             Panel::Peers => (self.peer_scroll, self.active_peers().len()),
             Panel::Events => (self.event_scroll, self.visible_events().len()),
             Panel::Decisions => (self.decision_scroll, self.decisions_row_count()),
+            Panel::Plans => (self.plan_scroll, self.plan_rows().len()),
         }
     }
 
     fn active_scroll_mut(&mut self) -> &mut usize {
+        if self.tab == Tab::Decisions {
+            return &mut self.browser_scroll;
+        }
         match self.active_panel {
             Panel::Peers => &mut self.peer_scroll,
             Panel::Events => &mut self.event_scroll,
             Panel::Decisions => &mut self.decision_scroll,
+            Panel::Plans => &mut self.plan_scroll,
         }
     }
 }
@@ -252,6 +626,7 @@ mod tests {
             claimed_paths: vec![],
             branch: None,
             current_phase: None,
+            subagents: vec![],
         }
     }
 
@@ -335,8 +710,9 @@ mod tests {
     fn panel_cycling() {
         assert_eq!(Panel::Peers.next(), Panel::Events);
         assert_eq!(Panel::Events.next(), Panel::Decisions);
-        assert_eq!(Panel::Decisions.next(), Panel::Peers);
-        assert_eq!(Panel::Peers.prev(), Panel::Decisions);
+        assert_eq!(Panel::Decisions.next(), Panel::Plans);
+        assert_eq!(Panel::Plans.next(), Panel::Peers);
+        assert_eq!(Panel::Peers.prev(), Panel::Plans);
     }
 
     #[test]
@@ -376,4 +752,215 @@ mod tests {
         app.handle_key(tab);
         assert_eq!(app.active_panel, Panel::Decisions);
     }
+
+    fn key(c: char) -> crossterm::event::KeyEvent {
+        crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Char(c),
+            crossterm::event::KeyModifiers::empty(),
+        )
+    }
+
+    fn write_plan(dir: &std::path::Path) {
+        let plan = edda_conductor::plan::parser::parse_plan(
+            "name: demo\nphases:\n  - id: build\n    prompt: x\n  - id: test\n    prompt: x\n",
+        )
+        .unwrap();
+        let mut state = PlanState::from_plan(&plan, "plan.yaml");
+        state.phases[0].status = edda_conductor::state::machine::PhaseStatus::Failed;
+        state.plan_status = edda_conductor::state::machine::PlanStatus::Blocked;
+        persist::save_state(dir, &state).unwrap();
+    }
+
+    #[test]
+    fn plan_rows_interleave_headers_and_phases() {
+        let dir = tempfile::tempdir().unwrap();
+        write_plan(dir.path());
+        let mut app = App::new("test".into(), dir.path().to_path_buf());
+        app.reload_plans();
+        assert_eq!(app.plan_rows().len(), 3);
+        assert!(app.selected_phase().is_none()); // header row
+        app.plan_scroll = 1;
+        assert_eq!(
+            app.selected_phase(),
+            Some(("demo".to_string(), "build".to_string()))
+        );
+    }
+
+    #[test]
+    fn retry_key_resets_failed_phase_on_disk() {
+        use edda_conductor::state::machine::{PhaseStatus, PlanStatus};
+
+        let dir = tempfile::tempdir().unwrap();
+        write_plan(dir.path());
+        let mut app = App::new("test".into(), dir.path().to_path_buf());
+        app.reload_plans();
+        app.active_panel = Panel::Plans;
+        app.plan_scroll = 1;
+
+        app.handle_key(key('r'));
+        let state = persist::load_state(dir.path(), "demo").unwrap().unwrap();
+        assert_eq!(state.phases[0].status, PhaseStatus::Pending);
+        assert_eq!(state.plan_status, PlanStatus::Running);
+        assert_eq!(app.notice.as_deref(), Some("build: reset to pending"));
+
+        // Retrying a pending phase surfaces the error instead of writing.
+        app.handle_key(key('r'));
+        assert!(app.notice.as_deref().unwrap().contains("Cannot retry"));
+    }
+
+    fn decide(ledger: &Ledger, key: &str, value: &str, ts: &str) -> String {
+        let mut e = edda_core::event::new_decision_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            "user",
+            &edda_core::types::DecisionPayload {
+                key: key.into(),
+                value: value.into(),
+                reason: Some(format!("why {value}")),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            },
+        )
+        .unwrap();
+        e.ts = ts.into();
+        edda_core::event::finalize_event(&mut e).unwrap();
+        ledger.append_event(&e).unwrap();
+        e.event_id
+    }
+
+    #[test]
+    fn decisions_tab_expands_history_and_marks_for_review() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = edda_ledger::EddaPaths::discover(dir.path());
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(dir.path()).unwrap();
+        let first = decide(&ledger, "db.engine", "sqlite", "2026-01-01T00:00:00Z");
+        decide(&ledger, "db.engine", "postgres", "2026-02-01T00:00:00Z");
+        decide(&ledger, "auth.method", "jwt", "2026-02-02T00:00:00Z");
+        let mut commit =
+            edda_core::event::new_commit_event(&mut edda_core::event::CommitEventParams {
+                branch: "main",
+                parent_hash: ledger.last_event_hash().unwrap().as_deref(),
+                title: "switch to sqlite",
+                purpose: None,
+                prev_summary: "",
+                contribution: "",
+                evidence: vec![],
+                labels: vec![],
+            })
+            .unwrap();
+        commit.refs.events.push(first);
+        edda_core::event::finalize_event(&mut commit).unwrap();
+        ledger.append_event(&commit).unwrap();
+
+        let mut app = App::new("test".into(), dir.path().to_path_buf());
+        app.reload_decisions();
+        app.handle_key(key('d'));
+        assert_eq!(app.tab, Tab::Decisions);
+        // auth header, auth.method, db header, db.engine
+        assert_eq!(app.decision_rows().len(), 4);
+
+        app.handle_key(key('j'));
+        app.handle_key(key('j'));
+        app.handle_key(key('j'));
+        app.handle_key(crossterm::event::KeyEvent::new(
+            crossterm::event::KeyCode::Enter,
+            crossterm::event::KeyModifiers::empty(),
+        ));
+        let rows = app.decision_rows();
+        let history: Vec<&str> = rows
+            .iter()
+            .filter_map(|r| match r {
+                DecisionRow::History(_, h) => Some(h.value.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(history, vec!["sqlite", "postgres"]);
+        assert!(rows
+            .iter()
+            .any(|r| matches!(r, DecisionRow::Commit(d, _) if d.key == "db.engine")));
+
+        // `r` on a detail row reviews the decision it belongs to.
+        app.handle_key(key('j'));
+        app.handle_key(key('r'));
+        assert_eq!(app.notice.as_deref(), Some("db.engine: marked for review"));
+        let ledger = Ledger::open(dir.path()).unwrap();
+        let note = ledger.iter_events().unwrap().pop().unwrap();
+        assert_eq!(note.payload["text"], "Review requested: db.engine=postgres");
+        let active = &app.decisions.iter().find(|d| d.key == "db.engine").unwrap();
+        assert_eq!(note.refs.events, vec![active.event_id.clone()]);
+    }
+
+    #[test]
+    fn replay_tab_searches_and_keeps_esc_for_the_search_box() {
+        use crate::cmd_replay::{Entry, Replay};
+        use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+        let entry = |kind, text: &str| Entry {
+            ts: "2026-03-01T10:00:00Z".into(),
+            kind,
+            text: text.into(),
+            detail: None,
+        };
+        let mut app = App::new("test".into(), PathBuf::from("/tmp"));
+        app.handle_key(key('v'));
+        assert_eq!(app.tab, Tab::Replay);
+        app.replay = Some(ReplayView::new(Replay {
+            session_id: "sess".into(),
+            started_at: String::new(),
+            ended_at: String::new(),
+            prompts: 2,
+            entries: vec![
+                entry("user", "add a health check"),
+                entry("commit", "add health check"),
+                entry("user", "now the docs"),
+            ],
+        }));
+
+        app.handle_key(key('/'));
+        for c in "DOCS".chars() {
+            app.handle_key(key(c));
+        }
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+        assert!(app.replay_input.is_none());
+        assert_eq!(app.replay.as_ref().unwrap().turn, 1);
+
+        app.handle_key(key('/'));
+        app.handle_key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        assert!(!app.should_quit, "Esc only closes the search box");
+        assert!(app.replay_input.is_none());
+
+        app.handle_key(key('h'));
+        assert_eq!(app.replay.as_ref().unwrap().turn, 0);
+        app.handle_key(KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty()));
+        assert!(app.replay.is_none());
+        app.handle_key(key('v'));
+        assert_eq!(app.tab, Tab::Live);
+    }
+
+    #[test]
+    fn skip_key_ignored_outside_plans_panel() {
+        use edda_conductor::state::machine::PhaseStatus;
+
+        let dir = tempfile::tempdir().unwrap();
+        write_plan(dir.path());
+        let mut app = App::new("test".into(), dir.path().to_path_buf());
+        app.reload_plans();
+        app.plan_scroll = 2;
+
+        app.handle_key(key('s'));
+        assert!(app.notice.is_none());
+
+        app.active_panel = Panel::Plans;
+        app.handle_key(key('s'));
+        let state = persist::load_state(dir.path(), "demo").unwrap().unwrap();
+        assert_eq!(state.phases[1].status, PhaseStatus::Skipped);
+    }
 }
@@ -1,4 +1,6 @@
 pub mod app;
+pub mod replay;
+pub mod theme;
 pub mod ui;
 
 use std::path::PathBuf;
@@ -7,9 +9,16 @@ use std::time::{Duration, Instant};
 use crossterm::event::{self, Event, KeyEventKind};
 
 use app::App;
+use theme::Theme;
 
 /// Run the interactive TUI (called by `edda watch` when the `tui` feature is enabled).
-pub fn run(project_id: String, repo_root: PathBuf) -> anyhow::Result<()> {
+pub fn run(project_id: String, repo_root: PathBuf, theme: Option<&str>) -> anyhow::Result<()> {
+    // Resolve before taking over the terminal so a bad name prints normally.
+    let theme = Theme::resolve(
+        theme,
+        &edda_ledger::EddaPaths::discover(&repo_root).config_json,
+    )?;
+
     // Auto-init: ensure .edda/ and store dirs exist
     if let Err(e) = edda_store::ensure_dirs(&project_id) {
         eprintln!("Warning: failed to ensure store dirs: {e}");
@@ -19,7 +28,7 @@ pub fn run(project_id: String, repo_root: PathBuf) -> anyhow::Result<()> {
     }
 
     let mut terminal = ratatui::init();
-    let result = run_loop(&mut terminal, project_id, repo_root);
+    let result = run_loop(&mut terminal, project_id, repo_root, theme);
     ratatui::restore();
 
     result
@@ -29,8 +38,10 @@ fn run_loop(
     terminal: &mut ratatui::DefaultTerminal,
     project_id: String,
     repo_root: PathBuf,
+    theme: Theme,
 ) -> anyhow::Result<()> {
     let mut app = App::new(project_id, repo_root);
+    app.theme = theme;
     let interval = Duration::from_secs(1);
     let mut last_refresh = Instant::now();
 
@@ -0,0 +1,201 @@
+//! The replay tab: step through a past session turn by turn.
+//!
+//! Built on `edda replay`'s merged narrative. A turn is a user prompt and
+//! everything up to the next one — replies, tool calls, and the decisions
+//! and commits the ledger recorded meanwhile. Entries before the first
+//! prompt form a turn of their own.
+
+use std::ops::Range;
+
+use crate::cmd_replay::{Entry, Replay};
+
+/// A loaded session and where the viewer is in it.
+pub struct ReplayView {
+    pub replay: Replay,
+    /// Entry ranges, one per turn.
+    pub turns: Vec<Range<usize>>,
+    pub turn: usize,
+    /// Line offset within the current turn.
+    pub scroll: usize,
+    /// The last confirmed search, lowercased.
+    pub query: Option<String>,
+}
+
+impl ReplayView {
+    pub fn new(replay: Replay) -> Self {
+        let mut turns = Vec::new();
+        let mut start = 0;
+        for (i, e) in replay.entries.iter().enumerate() {
+            if e.kind == "user" && i > start {
+                turns.push(start..i);
+                start = i;
+            }
+        }
+        if start < replay.entries.len() {
+            turns.push(start..replay.entries.len());
+        }
+        Self {
+            replay,
+            turns,
+            turn: 0,
+            scroll: 0,
+            query: None,
+        }
+    }
+
+    /// Entries of the current turn.
+    pub fn entries(&self) -> &[Entry] {
+        self.turns
+            .get(self.turn)
+            .map(|r| &self.replay.entries[r.clone()])
+            .unwrap_or(&[])
+    }
+
+    pub fn next_turn(&mut self) {
+        if self.turn + 1 < self.turns.len() {
+            self.turn += 1;
+            self.scroll = 0;
+        }
+    }
+
+    pub fn prev_turn(&mut self) {
+        if self.turn > 0 {
+            self.turn -= 1;
+            self.scroll = 0;
+        }
+    }
+
+    /// Whether an entry matches the current query.
+    pub fn is_match(&self, entry: &Entry) -> bool {
+        self.query
+            .as_deref()
+            .is_some_and(|q| entry_matches(entry, q))
+    }
+
+    /// Search for `query` (case-insensitive) starting at the current turn,
+    /// then wrapping. Returns whether any turn matched.
+    pub fn search(&mut self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            self.query = None;
+            return false;
+        }
+        self.query = Some(query);
+        self.seek(0, true)
+    }
+
+    /// Move to the next (or previous) turn matching the current query,
+    /// wrapping around.
+    pub fn next_match(&mut self, forward: bool) -> bool {
+        self.seek(1, forward)
+    }
+
+    /// Look `from`.. turns away from the current one in the given direction.
+    fn seek(&mut self, from: usize, forward: bool) -> bool {
+        let Some(query) = self.query.as_deref() else {
+            return false;
+        };
+        let n = self.turns.len();
+        let found = (from..from + n)
+            .map(|step| {
+                if forward {
+                    (self.turn + step) % n
+                } else {
+                    (self.turn + n * 2 - step) % n
+                }
+            })
+            .find(|&t| {
+                self.replay.entries[self.turns[t].clone()]
+                    .iter()
+                    .any(|e| entry_matches(e, query))
+            });
+        match found {
+            Some(t) => {
+                if t != self.turn {
+                    self.turn = t;
+                    self.scroll = 0;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn entry_matches(entry: &Entry, query: &str) -> bool {
+    entry.text.to_lowercase().contains(query)
+        || entry
+            .detail
+            .as_deref()
+            .is_some_and(|d| d.to_lowercase().contains(query))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: &'static str, text: &str) -> Entry {
+        Entry {
+            ts: "2026-03-01T10:00:00Z".into(),
+            kind,
+            text: text.into(),
+            detail: None,
+        }
+    }
+
+    fn view() -> ReplayView {
+        ReplayView::new(Replay {
+            session_id: "sess".into(),
+            started_at: String::new(),
+            ended_at: String::new(),
+            prompts: 3,
+            entries: vec![
+                entry("recap", "earlier"),
+                entry("user", "add a health check"),
+                entry("tool", "Bash cargo test"),
+                entry("commit", "add health check"),
+                entry("user", "now the docs"),
+                entry("assistant", "Updated README."),
+                entry("user", "run the tests again"),
+                Entry {
+                    detail: Some("command: cargo test --workspace".into()),
+                    ..entry("tool", "Bash")
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn turns_split_at_each_prompt() {
+        let v = view();
+        assert_eq!(v.turns, vec![0..1, 1..4, 4..6, 6..8]);
+        assert_eq!(v.entries()[0].text, "earlier");
+    }
+
+    #[test]
+    fn stepping_stops_at_both_ends() {
+        let mut v = view();
+        v.prev_turn();
+        assert_eq!(v.turn, 0);
+        for _ in 0..10 {
+            v.next_turn();
+        }
+        assert_eq!(v.turn, 3);
+    }
+
+    #[test]
+    fn search_finds_turns_and_wraps() {
+        let mut v = view();
+        assert!(v.search("CARGO TEST"));
+        assert_eq!(v.turn, 1);
+        assert!(v.next_match(true));
+        assert_eq!(v.turn, 3, "matches tool details too");
+        assert!(v.next_match(true));
+        assert_eq!(v.turn, 1, "wraps to the first match");
+        assert!(v.next_match(false));
+        assert_eq!(v.turn, 3);
+
+        assert!(!v.search("nowhere"));
+        assert_eq!(v.turn, 3);
+    }
+}
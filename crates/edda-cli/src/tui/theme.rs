@@ -0,0 +1,246 @@
+//! Color themes and glyph sets for `edda watch`.
+//!
+//! Every style and symbol the TUI draws comes from a [`Theme`], so terminals
+//! without 256 colors, light backgrounds, and screen readers that stumble over
+//! box-drawing characters all get a usable view. Selected with
+//! `edda watch --theme`, the `tui.theme` config key, or `NO_COLOR`.
+
+use std::path::Path;
+
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::border;
+
+/// Names accepted by `--theme` and `tui.theme`.
+pub const THEME_NAMES: &[&str] = &["dark", "light", "mono", "ascii"];
+
+/// Symbols used for status markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub peer_active: &'static str,
+    pub peer_idle: &'static str,
+    pub expanded: &'static str,
+    pub collapsed: &'static str,
+    pub arrow: &'static str,
+    pub pass: &'static str,
+    pub fail: &'static str,
+    pub running: &'static str,
+    pub skipped: &'static str,
+    pub stale: &'static str,
+    pub pending: &'static str,
+    pub commit: &'static str,
+    pub merge: &'static str,
+}
+
+const UNICODE_GLYPHS: Glyphs = Glyphs {
+    peer_active: "●",
+    peer_idle: "○",
+    expanded: "▾",
+    collapsed: "▸",
+    arrow: "→",
+    pass: "✓",
+    fail: "✗",
+    running: "▶",
+    skipped: "⊘",
+    stale: "⏰",
+    pending: "○",
+    commit: "●",
+    merge: "◆",
+};
+
+const ASCII_GLYPHS: Glyphs = Glyphs {
+    peer_active: "*",
+    peer_idle: "o",
+    expanded: "v",
+    collapsed: ">",
+    arrow: "->",
+    pass: "+",
+    fail: "x",
+    running: ">",
+    skipped: "-",
+    stale: "!",
+    pending: "o",
+    commit: "*",
+    merge: "=",
+};
+
+const ASCII_BORDER: border::Set = border::Set {
+    top_left: "+",
+    top_right: "+",
+    bottom_left: "+",
+    bottom_right: "+",
+    vertical_left: "|",
+    vertical_right: "|",
+    horizontal_top: "-",
+    horizontal_bottom: "-",
+};
+
+/// Styles and symbols for one rendering of the TUI.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub name: &'static str,
+    /// Focused panel border.
+    pub accent: Style,
+    /// Unfocused borders, secondary detail, internal domains.
+    pub muted: Style,
+    pub ok: Style,
+    pub warn: Style,
+    pub error: Style,
+    /// Merges and running phases.
+    pub info: Style,
+    pub status_bar: Style,
+    pub status_error: Style,
+    pub glyphs: Glyphs,
+    pub border: border::Set,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The original palette, for dark terminal backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            name: "dark",
+            accent: Style::default().fg(Color::Cyan),
+            muted: Style::default().fg(Color::DarkGray),
+            ok: Style::default().fg(Color::Green),
+            warn: Style::default().fg(Color::Yellow),
+            error: Style::default().fg(Color::Red),
+            info: Style::default().fg(Color::Cyan),
+            status_bar: Style::default().fg(Color::White).bg(Color::DarkGray),
+            status_error: Style::default().fg(Color::White).bg(Color::Red),
+            glyphs: UNICODE_GLYPHS,
+            border: border::PLAIN,
+        }
+    }
+
+    /// Colors that stay readable on a light background (no yellow or cyan).
+    pub fn light() -> Self {
+        Self {
+            name: "light",
+            accent: Style::default().fg(Color::Blue),
+            muted: Style::default().fg(Color::DarkGray),
+            ok: Style::default().fg(Color::Green),
+            warn: Style::default().fg(Color::Magenta),
+            error: Style::default().fg(Color::Red),
+            info: Style::default().fg(Color::Blue),
+            status_bar: Style::default().fg(Color::Black).bg(Color::Gray),
+            status_error: Style::default().fg(Color::White).bg(Color::Red),
+            ..Self::dark()
+        }
+    }
+
+    /// No colors at all; emphasis comes from bold, dim and reverse video.
+    pub fn mono() -> Self {
+        let plain = Style::default();
+        Self {
+            name: "mono",
+            accent: plain.add_modifier(Modifier::BOLD),
+            muted: plain.add_modifier(Modifier::DIM),
+            ok: plain,
+            warn: plain.add_modifier(Modifier::BOLD),
+            error: plain.add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            info: plain,
+            status_bar: plain.add_modifier(Modifier::REVERSED),
+            status_error: plain.add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            ..Self::dark()
+        }
+    }
+
+    /// [`Theme::mono`] with ASCII-only glyphs and borders, for screen readers
+    /// and terminals without Unicode fonts.
+    pub fn ascii() -> Self {
+        Self {
+            name: "ascii",
+            glyphs: ASCII_GLYPHS,
+            border: ASCII_BORDER,
+            ..Self::mono()
+        }
+    }
+
+    pub fn by_name(name: &str) -> anyhow::Result<Self> {
+        match name {
+            "dark" => Ok(Self::dark()),
+            "light" => Ok(Self::light()),
+            "mono" => Ok(Self::mono()),
+            "ascii" => Ok(Self::ascii()),
+            other => anyhow::bail!(
+                "unknown theme {other:?}; expected one of: {}",
+                THEME_NAMES.join(", ")
+            ),
+        }
+    }
+
+    /// Pick the theme: `--theme`, then `tui.theme` in `.edda/config.json`,
+    /// then `mono` when `NO_COLOR` is set, then `dark`.
+    pub fn resolve(cli: Option<&str>, config_json: &Path) -> anyhow::Result<Self> {
+        if let Some(name) = cli {
+            return Self::by_name(name);
+        }
+        let configured = std::fs::read_to_string(config_json)
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+            .and_then(|v| v.get("tui.theme")?.as_str().map(str::to_string));
+        if let Some(name) = configured {
+            return Self::by_name(&name);
+        }
+        if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return Ok(Self::mono());
+        }
+        Ok(Self::dark())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cli_overrides_config_and_unknown_names_fail() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = tmp.path().join("config.json");
+        std::fs::write(&config, r#"{"tui.theme": "light"}"#).unwrap();
+
+        assert_eq!(Theme::resolve(None, &config).unwrap().name, "light");
+        assert_eq!(
+            Theme::resolve(Some("ascii"), &config).unwrap().name,
+            "ascii"
+        );
+        let err = Theme::resolve(Some("neon"), &config).unwrap_err();
+        assert!(
+            err.to_string().contains("dark, light, mono, ascii"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn ascii_theme_has_no_colors_or_non_ascii_symbols() {
+        let t = Theme::ascii();
+        for style in [t.accent, t.muted, t.ok, t.warn, t.error, t.info] {
+            assert_eq!(style.fg, None);
+        }
+        let g = t.glyphs;
+        let symbols = [
+            g.peer_active,
+            g.peer_idle,
+            g.expanded,
+            g.collapsed,
+            g.arrow,
+            g.pass,
+            g.fail,
+            g.running,
+            g.skipped,
+            g.stale,
+            g.pending,
+            g.commit,
+            g.merge,
+            t.border.top_left,
+            t.border.horizontal_top,
+            t.border.vertical_left,
+        ];
+        assert!(symbols.iter().all(|s| s.is_ascii()), "{symbols:?}");
+    }
+}
@@ -1,13 +1,16 @@
 use std::collections::BTreeMap;
 
 use edda_bridge_claude::peers::BindingEntry;
+use edda_conductor::state::machine::{CheckStatus, PhaseState, PhaseStatus, PlanState};
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 use ratatui::Frame;
 
-use super::app::{is_internal_domain, App, Panel};
+use super::app::{is_internal_domain, App, DecisionRow, Panel, PlanRow, Tab};
+use super::replay::ReplayView;
+use super::theme::Theme;
 
 /// Render the full TUI frame.
 pub fn render(f: &mut Frame, app: &App) {
@@ -19,6 +22,31 @@ pub fn render(f: &mut Frame, app: &App) {
         ])
         .split(f.area());
 
+    if app.tab == Tab::Decisions {
+        render_decision_browser(f, app, chunks[0]);
+        render_status_bar(f, app, chunks[1]);
+        return;
+    }
+    if app.tab == Tab::Replay {
+        match &app.replay {
+            Some(view) => render_replay(f, app, view, chunks[0]),
+            None => render_session_list(f, app, chunks[0]),
+        }
+        render_status_bar(f, app, chunks[1]);
+        return;
+    }
+
+    // Conductor plans get a full-width pane under the main columns.
+    let (main_area, plans_area) = if app.plans.is_empty() {
+        (chunks[0], None)
+    } else {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(chunks[0]);
+        (split[0], Some(split[1]))
+    };
+
     let active_peers = app.active_peers();
     let has_peers = !active_peers.is_empty();
     let has_claims_or_requests = !app.board.claims.is_empty() || !app.board.requests.is_empty();
@@ -32,7 +60,7 @@ pub fn render(f: &mut Frame, app: &App) {
                 Constraint::Percentage(50), // events
                 Constraint::Percentage(25), // decisions
             ])
-            .split(chunks[0]);
+            .split(main_area);
 
         render_peers(f, app, main_chunks[0]);
         render_events(f, app, main_chunks[1]);
@@ -45,20 +73,24 @@ pub fn render(f: &mut Frame, app: &App) {
                 Constraint::Percentage(60), // events
                 Constraint::Percentage(40), // decisions
             ])
-            .split(chunks[0]);
+            .split(main_area);
 
         render_events(f, app, main_chunks[0]);
         render_decisions(f, app, main_chunks[1]);
     }
 
+    if let Some(area) = plans_area {
+        render_plans(f, app, area);
+    }
+
     render_status_bar(f, app, chunks[1]);
 }
 
 fn panel_style(app: &App, panel: Panel) -> Style {
     if app.active_panel == panel {
-        Style::default().fg(Color::Cyan)
+        app.theme.accent
     } else {
-        Style::default().fg(Color::DarkGray)
+        app.theme.muted
     }
 }
 
@@ -73,12 +105,13 @@ fn render_peers(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(app.theme.border)
         .border_style(panel_style(app, Panel::Peers));
 
     if active.is_empty() {
         let msg = Paragraph::new("No active peers")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::DarkGray))
+            .style(app.theme.muted)
             .block(block);
         f.render_widget(msg, area);
         return;
@@ -89,7 +122,11 @@ fn render_peers(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
         .enumerate()
         .skip(app.peer_scroll)
         .flat_map(|(i, peer)| {
-            let indicator = if peer.age_secs < 120 { "●" } else { "○" };
+            let indicator = if peer.age_secs < 120 {
+                app.theme.glyphs.peer_active
+            } else {
+                app.theme.glyphs.peer_idle
+            };
             let label = if peer.label.is_empty() {
                 "unknown"
             } else {
@@ -117,7 +154,7 @@ fn render_peers(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 let detail = format!("   {}{branch_str}", files.join(", "));
                 lines.push(ListItem::new(Line::from(Span::styled(
                     detail,
-                    Style::default().fg(Color::DarkGray),
+                    app.theme.muted,
                 ))));
             }
             if !peer.task_subjects.is_empty() {
@@ -125,7 +162,7 @@ fn render_peers(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 let detail = format!("   >> {task}");
                 lines.push(ListItem::new(Line::from(Span::styled(
                     detail,
-                    Style::default().fg(Color::Yellow),
+                    app.theme.warn,
                 ))));
             }
             lines
@@ -147,6 +184,7 @@ fn render_events(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(app.theme.border)
         .border_style(panel_style(app, Panel::Events));
 
     let max_preview = area.width.saturating_sub(22) as usize; // borders + time(5) + type(10) + spaces
@@ -159,7 +197,7 @@ fn render_events(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             } else {
                 &evt.ts
             };
-            let (dtype, preview, style) = event_display(&evt.payload, &evt.event_type);
+            let (dtype, preview, style) = event_display(&evt.payload, &evt.event_type, &app.theme);
             let preview = truncate_str(&preview, max_preview);
             let line = format!(" {ts}  {dtype:<10} {preview}");
             ListItem::new(Line::from(Span::styled(line, style)))
@@ -177,6 +215,7 @@ fn render_decisions(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(app.theme.border)
         .border_style(panel_style(app, Panel::Decisions));
 
     if has_claims_or_requests {
@@ -212,10 +251,14 @@ fn render_bindings_grouped(f: &mut Frame, app: &App, area: ratatui::layout::Rect
         let expanded = app.expanded_domains.contains(*domain) || !is_internal;
 
         // Domain header
-        let arrow = if expanded { "▾" } else { "▸" };
+        let arrow = if expanded {
+            app.theme.glyphs.expanded
+        } else {
+            app.theme.glyphs.collapsed
+        };
         let header = format!(" {arrow} {domain} ({})", bindings.len());
         let header_style = if is_internal {
-            Style::default().fg(Color::DarkGray)
+            app.theme.muted
         } else {
             Style::default().add_modifier(Modifier::BOLD)
         };
@@ -239,7 +282,10 @@ fn render_bindings_grouped(f: &mut Frame, app: &App, area: ratatui::layout::Rect
     // Apply scroll offset
     let items: Vec<ListItem> = items.into_iter().skip(app.decision_scroll).collect();
 
-    let binding_block = Block::default().title(" Bindings ").borders(Borders::TOP);
+    let binding_block = Block::default()
+        .title(" Bindings ")
+        .borders(Borders::TOP)
+        .border_set(app.theme.border);
     let list = List::new(items).block(binding_block);
     f.render_widget(list, area);
 }
@@ -259,7 +305,10 @@ fn render_claims(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
             ListItem::new(Line::from(line))
         })
         .collect();
-    let block = Block::default().title(" Claims ").borders(Borders::TOP);
+    let block = Block::default()
+        .title(" Claims ")
+        .borders(Borders::TOP)
+        .border_set(app.theme.border);
     let list = List::new(items).block(block);
     f.render_widget(list, area);
 }
@@ -276,24 +325,373 @@ fn render_requests(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
                 .iter()
                 .any(|a| a.from_label == r.from_label);
             let msg = truncate_str(&r.message, 40);
+            let arrow = app.theme.glyphs.arrow;
             let line = if is_acked {
-                format!(" [ack] {} → {}: {msg}", r.from_label, r.to_label)
+                format!(" [ack] {} {arrow} {}: {msg}", r.from_label, r.to_label)
             } else {
-                format!(" {} → {}: {msg}", r.from_label, r.to_label)
+                format!(" {} {arrow} {}: {msg}", r.from_label, r.to_label)
             };
             let style = if is_acked {
-                Style::default().fg(Color::DarkGray)
+                app.theme.muted
             } else {
                 Style::default()
             };
             ListItem::new(Line::from(line)).style(style)
         })
         .collect();
-    let block = Block::default().title(" Requests ").borders(Borders::TOP);
+    let block = Block::default()
+        .title(" Requests ")
+        .borders(Borders::TOP)
+        .border_set(app.theme.border);
     let list = List::new(items).block(block);
     f.render_widget(list, area);
 }
 
+fn render_plans(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let block = Block::default()
+        .title(format!(" Plans ({}) ", app.plans.len()))
+        .borders(Borders::ALL)
+        .border_set(app.theme.border)
+        .border_style(panel_style(app, Panel::Plans));
+
+    let now = chrono::Utc::now();
+    let max_detail = area.width.saturating_sub(10) as usize;
+    let items: Vec<ListItem> = app
+        .plan_rows()
+        .into_iter()
+        .enumerate()
+        .skip(app.plan_scroll)
+        .flat_map(|(i, row)| {
+            let selected = app.active_panel == Panel::Plans && i == app.plan_scroll;
+            let mut lines = match row {
+                PlanRow::Plan(plan) => vec![plan_header_item(plan, now)],
+                PlanRow::Phase(_, phase) => phase_items(phase, now, max_detail, &app.theme),
+            };
+            if selected {
+                lines[0] = lines[0]
+                    .clone()
+                    .style(Style::default().add_modifier(Modifier::REVERSED));
+            }
+            lines
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    f.render_widget(list, area);
+}
+
+fn plan_header_item(plan: &PlanState, now: chrono::DateTime<chrono::Utc>) -> ListItem<'static> {
+    let elapsed = elapsed_secs(
+        plan.started_at.as_deref(),
+        plan.completed_at.as_deref(),
+        now,
+    )
+    .map(|s| format!("  {}", format_elapsed(s)))
+    .unwrap_or_default();
+    let done = plan
+        .phases
+        .iter()
+        .filter(|p| matches!(p.status, PhaseStatus::Passed | PhaseStatus::Skipped))
+        .count();
+    let line = format!(
+        " {} [{:?}] {done}/{} phases  ${:.2}{elapsed}",
+        plan.plan_name,
+        plan.plan_status,
+        plan.phases.len(),
+        plan.total_cost_usd
+    );
+    ListItem::new(Line::from(Span::styled(
+        line,
+        Style::default().add_modifier(Modifier::BOLD),
+    )))
+}
+
+/// Phase line plus, when there is one, the last check result or error.
+fn phase_items(
+    phase: &PhaseState,
+    now: chrono::DateTime<chrono::Utc>,
+    max_detail: usize,
+    theme: &Theme,
+) -> Vec<ListItem<'static>> {
+    let (icon, style) = phase_icon(phase.status, theme);
+    let elapsed = elapsed_secs(
+        phase.started_at.as_deref(),
+        phase.completed_at.as_deref(),
+        now,
+    )
+    .map(format_elapsed)
+    .unwrap_or_default();
+    let attempts = if phase.attempts > 1 {
+        format!("  attempt {}", phase.attempts)
+    } else {
+        String::new()
+    };
+    let line = format!(
+        "   {icon} {:<20} {:<8} {elapsed:>6}{attempts}",
+        phase.id,
+        format!("{:?}", phase.status).to_lowercase()
+    );
+    let mut items = vec![ListItem::new(Line::from(Span::styled(line, style)))];
+
+    if let Some(detail) = phase_detail(phase) {
+        items.push(ListItem::new(Line::from(Span::styled(
+            format!("       {}", truncate_str(&detail, max_detail)),
+            theme.muted,
+        ))));
+    }
+    items
+}
+
+fn phase_icon(status: PhaseStatus, theme: &Theme) -> (&'static str, Style) {
+    let g = &theme.glyphs;
+    match status {
+        PhaseStatus::Passed => (g.pass, theme.ok),
+        PhaseStatus::Failed => (g.fail, theme.error),
+        PhaseStatus::Running | PhaseStatus::Checking => (g.running, theme.info),
+        PhaseStatus::Skipped => (g.skipped, theme.muted),
+        PhaseStatus::Stale => (g.stale, theme.warn),
+        PhaseStatus::Pending => (g.pending, Style::default()),
+    }
+}
+
+/// Describe the latest check outcome (or failure/skip reason) for a phase.
+fn phase_detail(phase: &PhaseState) -> Option<String> {
+    if phase.status == PhaseStatus::Skipped {
+        return phase.skip_reason.clone();
+    }
+    if let Some(check) = phase.checks.last() {
+        let status = match check.status {
+            CheckStatus::Waiting => "waiting",
+            CheckStatus::Running => "running",
+            CheckStatus::Passed => "passed",
+            CheckStatus::Failed => "failed",
+        };
+        let detail = check
+            .detail
+            .as_deref()
+            .map(|d| format!(": {}", first_line(d)))
+            .unwrap_or_default();
+        return Some(format!("{} {status}{detail}", check.check_type));
+    }
+    phase
+        .error
+        .as_ref()
+        .map(|e| first_line(&e.message).to_string())
+}
+
+fn render_decision_browser(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let block = Block::default()
+        .title(format!(" Decisions ({} active) ", app.decisions.len()))
+        .borders(Borders::ALL)
+        .border_set(app.theme.border)
+        .border_style(app.theme.accent);
+
+    let max_detail = area.width.saturating_sub(12) as usize;
+    let items: Vec<ListItem> = app
+        .decision_rows()
+        .into_iter()
+        .enumerate()
+        .skip(app.browser_scroll)
+        .map(|(i, row)| {
+            let expanded = app.decision_detail.as_ref().map(|x| x.event_id.as_str());
+            let item = decision_row_item(row, expanded, max_detail, &app.theme);
+            if i == app.browser_scroll {
+                item.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                item
+            }
+        })
+        .collect();
+
+    let list = List::new(items).block(block);
+    f.render_widget(list, area);
+}
+
+/// One line of the decisions tab.
+fn decision_row_item(
+    row: DecisionRow<'_>,
+    expanded: Option<&str>,
+    max_detail: usize,
+    theme: &Theme,
+) -> ListItem<'static> {
+    let line = match row {
+        DecisionRow::Domain(domain, count) => Line::from(Span::styled(
+            format!(" {domain} ({count})"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        DecisionRow::Decision(d) => {
+            let short_key = d
+                .key
+                .strip_prefix(&format!("{}.", d.domain))
+                .unwrap_or(&d.key);
+            let marker = if expanded == Some(d.event_id.as_str()) {
+                theme.glyphs.expanded
+            } else {
+                theme.glyphs.collapsed
+            };
+            Line::from(vec![
+                Span::raw(format!("  {marker} {short_key} = {}", d.value)),
+                Span::styled(
+                    format!("  {} {}", short_date(d.ts.as_deref()), d.authority),
+                    theme.muted,
+                ),
+            ])
+        }
+        DecisionRow::History(_, h) => {
+            let style = if h.status == "active" {
+                theme.ok
+            } else {
+                theme.muted
+            };
+            let reason = if h.reason.is_empty() {
+                String::new()
+            } else {
+                format!("  {}", truncate_str(first_line(&h.reason), max_detail / 2))
+            };
+            Line::from(Span::styled(
+                format!(
+                    "      {} {} = {} [{}]{reason}",
+                    short_date(h.ts.as_deref()),
+                    h.key,
+                    h.value,
+                    h.status
+                ),
+                style,
+            ))
+        }
+        DecisionRow::Commit(_, c) => {
+            let title = c
+                .payload
+                .get("title")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            Line::from(Span::styled(
+                format!(
+                    "      {} {} {}",
+                    theme.glyphs.commit,
+                    short_date(Some(&c.ts)),
+                    truncate_str(first_line(title), max_detail)
+                ),
+                theme.ok,
+            ))
+        }
+    };
+    ListItem::new(line)
+}
+
+fn render_session_list(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
+    let block = Block::default()
+        .title(format!(" Sessions ({}) ", app.replay_sessions.len()))
+        .borders(Borders::ALL)
+        .border_set(app.theme.border)
+        .border_style(app.theme.accent);
+
+    if app.replay_sessions.is_empty() {
+        let msg = Paragraph::new("No indexed sessions")
+            .style(app.theme.muted)
+            .alignment(Alignment::Center)
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .replay_sessions
+        .iter()
+        .enumerate()
+        .skip(app.session_scroll)
+        .map(|(i, (id, modified))| {
+            let item = ListItem::new(Line::from(vec![
+                Span::raw(format!(" {id}")),
+                Span::styled(
+                    format!(
+                        "  {}-{:02}-{:02} {:02}:{:02}",
+                        modified.year(),
+                        u8::from(modified.month()),
+                        modified.day(),
+                        modified.hour(),
+                        modified.minute()
+                    ),
+                    app.theme.muted,
+                ),
+            ]));
+            if i == app.session_scroll {
+                item.style(Style::default().add_modifier(Modifier::REVERSED))
+            } else {
+                item
+            }
+        })
+        .collect();
+    f.render_widget(List::new(items).block(block), area);
+}
+
+/// One turn of the replayed session: each entry on its own line, with
+/// continuation lines and tool arguments indented beneath it.
+fn render_replay(f: &mut Frame, app: &App, view: &ReplayView, area: ratatui::layout::Rect) {
+    let title = if view.turns.is_empty() {
+        format!(" Replay {}: no transcript entries ", view.replay.session_id)
+    } else {
+        format!(
+            " Replay {}: turn {}/{} ",
+            view.replay.session_id,
+            view.turn + 1,
+            view.turns.len()
+        )
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_set(app.theme.border)
+        .border_style(app.theme.accent);
+
+    const INDENT: &str = "                    ";
+    let mut lines = Vec::new();
+    for e in view.entries() {
+        let mut style = match e.kind {
+            "user" => app.theme.accent.add_modifier(Modifier::BOLD),
+            "tool" => app.theme.info,
+            "decision" => app.theme.warn,
+            "commit" | "merge" => app.theme.ok,
+            "recap" => app.theme.muted,
+            _ => Style::default(),
+        };
+        if view.is_match(e) {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+        let mut text = e.text.trim().lines();
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}  ", clock(&e.ts)), app.theme.muted),
+            Span::styled(format!("{:<9} ", e.kind), style),
+            Span::raw(text.next().unwrap_or("").to_string()),
+        ]));
+        lines.extend(text.map(|l| Line::from(format!("{INDENT}{l}"))));
+        if let Some(detail) = &e.detail {
+            lines.extend(
+                detail
+                    .lines()
+                    .map(|l| Line::from(Span::styled(format!("{INDENT}{l}"), app.theme.muted))),
+            );
+        }
+    }
+
+    let scroll = u16::try_from(view.scroll).unwrap_or(u16::MAX);
+    let body = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0))
+        .block(block);
+    f.render_widget(body, area);
+}
+
+/// `HH:MM:SS` from an RFC 3339 timestamp.
+fn clock(ts: &str) -> &str {
+    ts.get(11..19).unwrap_or("--:--:--")
+}
+
+/// `YYYY-MM-DD` from an RFC 3339 timestamp.
+fn short_date(ts: Option<&str>) -> &str {
+    ts.map(|t| t.get(..10).unwrap_or(t)).unwrap_or("")
+}
+
 fn render_status_bar(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     let pause_indicator = if app.paused { " [PAUSED]" } else { "" };
     let cmd_indicator = if app.show_cmd_events {
@@ -301,22 +699,40 @@ fn render_status_bar(f: &mut Frame, app: &App, area: ratatui::layout::Rect) {
     } else {
         " [cmd:hidden]"
     };
-    let panel_name = match app.active_panel {
-        Panel::Peers => "Peers",
-        Panel::Events => "Events",
-        Panel::Decisions => "Decisions",
+    let panel_name = match (app.tab, app.active_panel) {
+        (Tab::Decisions, _) => "Decision browser",
+        (Tab::Replay, _) => "Replay",
+        (Tab::Live, Panel::Peers) => "Peers",
+        (Tab::Live, Panel::Events) => "Events",
+        (Tab::Live, Panel::Decisions) => "Decisions",
+        (Tab::Live, Panel::Plans) => "Plans",
+    };
+    let search;
+    let keys = if let Some(input) = &app.replay_input {
+        search = format!("/{input}_  Enter:search  Esc:cancel");
+        search.as_str()
+    } else if app.tab == Tab::Replay && app.replay.is_some() {
+        "h/l:turn  j/k:scroll  /:search  n/N:match  Backspace:sessions  v:live  q:quit"
+    } else if app.tab == Tab::Replay {
+        "v:live  Enter:replay  j/k:select  q:quit"
+    } else if app.tab == Tab::Decisions {
+        "d:live  Enter:history  r:review  j/k:scroll  Space:pause  q:quit"
+    } else if app.active_panel == Panel::Plans {
+        "Tab:switch  d:decisions  v:replay  r:retry  s:skip  j/k:scroll  Space:pause  q:quit"
+    } else {
+        "Tab:switch  d:decisions  v:replay  c:cmd  j/k:scroll  Space:pause  q:quit"
     };
+    let notice = app
+        .notice
+        .as_deref()
+        .map(|n| format!(" | {n}"))
+        .unwrap_or_default();
     let (text, style) = if let Some(err) = &app.error {
-        (
-            format!(" ERROR: {err}"),
-            Style::default().fg(Color::White).bg(Color::Red),
-        )
+        (format!(" ERROR: {err}"), app.theme.status_error)
     } else {
         (
-            format!(
-                " edda watch | {panel_name}{pause_indicator}{cmd_indicator} | Tab:switch  c:cmd  j/k:scroll  Space:pause  q:quit"
-            ),
-            Style::default().fg(Color::White).bg(Color::DarkGray),
+            format!(" edda watch | {panel_name}{pause_indicator}{cmd_indicator} | {keys}{notice}"),
+            app.theme.status_bar,
         )
     };
     let bar = Paragraph::new(Line::from(Span::styled(text, style)));
@@ -353,7 +769,11 @@ pub fn group_bindings(bindings: &[BindingEntry]) -> Vec<(&str, Vec<&BindingEntry
 // ── Event formatting ──
 
 /// Extract display type, preview text, and style from an event.
-fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, String, Style) {
+fn event_display(
+    payload: &serde_json::Value,
+    event_type: &str,
+    theme: &Theme,
+) -> (String, String, Style) {
     let default = Style::default();
     match event_type {
         "note" => {
@@ -364,7 +784,11 @@ fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, Stri
                 let stats = &payload["session_stats"];
                 let dur = stats["duration_minutes"].as_u64().unwrap_or(0);
                 let outcome = stats["outcome"].as_str().unwrap_or("?");
-                let icon = if outcome == "completed" { "✓" } else { "✗" };
+                let icon = if outcome == "completed" {
+                    theme.glyphs.pass
+                } else {
+                    theme.glyphs.fail
+                };
                 let dur_str = format_duration(dur);
                 let decides = stats["decide_count"].as_u64().unwrap_or(0);
                 let files_mod = stats["files_modified"]
@@ -423,20 +847,16 @@ fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, Stri
                 };
 
                 let style = if outcome == "completed" {
-                    Style::default().fg(Color::DarkGray)
+                    theme.muted
                 } else {
-                    Style::default().fg(Color::Red)
+                    theme.error
                 };
                 ("digest".into(), preview, style)
             } else if has_tag("decision") {
                 let d = &payload["decision"];
                 let key = d["key"].as_str().unwrap_or("?");
                 let val = d["value"].as_str().unwrap_or("?");
-                (
-                    "decide".into(),
-                    format!("{key} = {val}"),
-                    Style::default().fg(Color::Yellow),
-                )
+                ("decide".into(), format!("{key} = {val}"), theme.warn)
             } else {
                 let text = first_line(payload["text"].as_str().unwrap_or(""));
                 ("note".into(), text.to_string(), default)
@@ -445,11 +865,7 @@ fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, Stri
         "decision" => {
             let key = payload["key"].as_str().unwrap_or("?");
             let val = payload["value"].as_str().unwrap_or("?");
-            (
-                "decision".into(),
-                format!("{key} = {val}"),
-                Style::default().fg(Color::Yellow),
-            )
+            ("decision".into(), format!("{key} = {val}"), theme.warn)
         }
         "cmd" => {
             let argv = &payload["argv"];
@@ -466,7 +882,7 @@ fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, Stri
                 (
                     "cmd:fail".into(),
                     format!("$ {short} [exit:{exit}]"),
-                    Style::default().fg(Color::Red),
+                    theme.error,
                 )
             }
         }
@@ -474,8 +890,8 @@ fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, Stri
             let title = first_line(payload["title"].as_str().unwrap_or(""));
             (
                 "commit".into(),
-                format!("● {title}"),
-                Style::default().fg(Color::Green),
+                format!("{} {title}", theme.glyphs.commit),
+                theme.ok,
             )
         }
         "merge" => {
@@ -483,8 +899,8 @@ fn event_display(payload: &serde_json::Value, event_type: &str) -> (String, Stri
             let dst = payload["dst"].as_str().unwrap_or("?");
             (
                 "merge".into(),
-                format!("◆ {src} → {dst}"),
-                Style::default().fg(Color::Cyan),
+                format!("{} {src} {} {dst}", theme.glyphs.merge, theme.glyphs.arrow),
+                theme.info,
             )
         }
         other => {
@@ -518,6 +934,31 @@ fn format_duration(minutes: u64) -> String {
     }
 }
 
+/// Seconds between `start` and `end` (or `now` while still running).
+fn elapsed_secs(
+    start: Option<&str>,
+    end: Option<&str>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Option<u64> {
+    let start = chrono::DateTime::parse_from_rfc3339(start?).ok()?;
+    let end = match end {
+        Some(e) => chrono::DateTime::parse_from_rfc3339(e).ok()?.to_utc(),
+        None => now,
+    };
+    u64::try_from((end - start.to_utc()).num_seconds()).ok()
+}
+
+/// Format elapsed seconds compactly: `42s`, `3m12s`, `1h05m`.
+fn format_elapsed(secs: u64) -> String {
+    if secs >= 3600 {
+        format!("{}h{:02}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
 /// Extract the meaningful command from a shell invocation.
 fn shorten_cmd(cmd: &str) -> String {
     let cmd = cmd.trim();
@@ -550,6 +991,7 @@ fn truncate_str(s: &str, max_chars: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ratatui::style::Color;
     use serde_json::json;
 
     #[test]
@@ -559,7 +1001,7 @@ mod tests {
             "exit_code": 0,
             "duration_ms": 1200
         });
-        let (dtype, preview, _) = event_display(&payload, "cmd");
+        let (dtype, preview, _) = event_display(&payload, "cmd", &Theme::default());
         assert_eq!(dtype, "cmd");
         assert_eq!(preview, "$ cargo test --workspace");
     }
@@ -570,7 +1012,7 @@ mod tests {
             "argv": ["cd /tmp && python3 script.py 2>&1"],
             "exit_code": 1
         });
-        let (dtype, preview, style) = event_display(&payload, "cmd");
+        let (dtype, preview, style) = event_display(&payload, "cmd", &Theme::default());
         assert_eq!(dtype, "cmd:fail");
         assert!(preview.contains("[exit:1]"));
         assert_eq!(style.fg, Some(Color::Red));
@@ -592,7 +1034,7 @@ mod tests {
                 "tasks_snapshot": [{"subject": "Add user authentication", "status": "completed"}]
             }
         });
-        let (dtype, preview, _) = event_display(&payload, "note");
+        let (dtype, preview, _) = event_display(&payload, "note", &Theme::default());
         assert_eq!(dtype, "digest");
         assert!(preview.starts_with("✓"), "got: {preview}");
         assert!(
@@ -618,7 +1060,7 @@ mod tests {
                 "files_modified": ["src/login.rs"]
             }
         });
-        let (_, preview, _) = event_display(&payload, "note");
+        let (_, preview, _) = event_display(&payload, "note", &Theme::default());
         assert!(preview.starts_with("✓"), "got: {preview}");
         assert!(preview.contains("fix: resolve login bug"), "got: {preview}");
     }
@@ -635,7 +1077,7 @@ mod tests {
                 "outcome": "completed"
             }
         });
-        let (_, preview, _) = event_display(&payload, "note");
+        let (_, preview, _) = event_display(&payload, "note", &Theme::default());
         assert!(preview.starts_with("✓"), "got: {preview}");
         assert!(preview.contains("session"), "got: {preview}");
     }
@@ -652,7 +1094,7 @@ mod tests {
                 "outcome": "interrupted"
             }
         });
-        let (_, preview, style) = event_display(&payload, "note");
+        let (_, preview, style) = event_display(&payload, "note", &Theme::default());
         assert!(preview.starts_with("✗"), "got: {preview}");
         assert!(preview.contains("2h"), "got: {preview}");
         assert_eq!(style.fg, Some(Color::Red));
@@ -673,7 +1115,7 @@ mod tests {
             "tags": ["decision"],
             "decision": { "key": "db.engine", "value": "sqlite" }
         });
-        let (dtype, preview, style) = event_display(&payload, "note");
+        let (dtype, preview, style) = event_display(&payload, "note", &Theme::default());
         assert_eq!(dtype, "decide");
         assert_eq!(preview, "db.engine = sqlite");
         assert_eq!(style.fg, Some(Color::Yellow));
@@ -685,7 +1127,7 @@ mod tests {
             "text": "first line\nsecond line\nthird",
             "tags": []
         });
-        let (dtype, preview, _) = event_display(&payload, "note");
+        let (dtype, preview, _) = event_display(&payload, "note", &Theme::default());
         assert_eq!(dtype, "note");
         assert_eq!(preview, "first line");
     }
@@ -693,7 +1135,7 @@ mod tests {
     #[test]
     fn commit_shows_green_dot() {
         let payload = json!({ "title": "feat: add user auth" });
-        let (dtype, preview, style) = event_display(&payload, "commit");
+        let (dtype, preview, style) = event_display(&payload, "commit", &Theme::default());
         assert_eq!(dtype, "commit");
         assert!(preview.starts_with("●"));
         assert_eq!(style.fg, Some(Color::Green));
@@ -702,11 +1144,19 @@ mod tests {
     #[test]
     fn merge_shows_arrow() {
         let payload = json!({ "src": "feat/auth", "dst": "main" });
-        let (_, preview, style) = event_display(&payload, "merge");
+        let (_, preview, style) = event_display(&payload, "merge", &Theme::default());
         assert!(preview.contains("→"));
         assert_eq!(style.fg, Some(Color::Cyan));
     }
 
+    #[test]
+    fn ascii_theme_renders_merge_without_unicode() {
+        let payload = json!({ "src": "feat/auth", "dst": "main" });
+        let (_, preview, style) = event_display(&payload, "merge", &Theme::ascii());
+        assert_eq!(preview, "= feat/auth -> main");
+        assert_eq!(style.fg, None);
+    }
+
     #[test]
     fn shorten_cmd_extracts_after_cd() {
         assert_eq!(
@@ -735,7 +1185,7 @@ mod tests {
     #[test]
     fn unknown_type_tries_text_field() {
         let payload = json!({ "text": "some info\nmore" });
-        let (dtype, preview, _) = event_display(&payload, "custom_evt");
+        let (dtype, preview, _) = event_display(&payload, "custom_evt", &Theme::default());
         assert_eq!(dtype, "custom_evt");
         assert_eq!(preview, "some info");
     }
@@ -790,4 +1240,58 @@ mod tests {
         assert!(!is_internal_domain("coordination"));
         assert!(!is_internal_domain("runtime"));
     }
+
+    #[test]
+    fn elapsed_uses_now_for_running_phase() {
+        let now = chrono::DateTime::parse_from_rfc3339("2026-03-01T10:05:30Z")
+            .unwrap()
+            .to_utc();
+        assert_eq!(
+            elapsed_secs(Some("2026-03-01T10:00:00Z"), None, now),
+            Some(330)
+        );
+        assert_eq!(
+            elapsed_secs(
+                Some("2026-03-01T10:00:00Z"),
+                Some("2026-03-01T10:00:42Z"),
+                now
+            ),
+            Some(42)
+        );
+        assert_eq!(elapsed_secs(None, None, now), None);
+        assert_eq!(format_elapsed(330), "5m30s");
+        assert_eq!(format_elapsed(3900), "1h05m");
+    }
+
+    #[test]
+    fn phase_detail_prefers_last_check() {
+        use edda_conductor::state::machine::CheckResult;
+
+        let mut phase = PhaseState {
+            id: "build".into(),
+            status: PhaseStatus::Failed,
+            started_at: None,
+            completed_at: None,
+            attempts: 2,
+            checks: vec![CheckResult {
+                check_type: "cmd_succeeds".into(),
+                status: CheckStatus::Failed,
+                detail: Some("exit 101\nerror[E0308]".into()),
+                duration_ms: 10,
+                tests: None,
+            }],
+            error: None,
+            skip_reason: None,
+            retry_context: None,
+            rollback: Vec::new(),
+        };
+        assert_eq!(
+            phase_detail(&phase).as_deref(),
+            Some("cmd_succeeds failed: exit 101")
+        );
+
+        phase.status = PhaseStatus::Skipped;
+        phase.skip_reason = Some("not needed".into());
+        assert_eq!(phase_detail(&phase).as_deref(), Some("not needed"));
+    }
 }
@@ -0,0 +1,353 @@
+//! Non-Claude agents and per-phase dispatch.
+//!
+//! A plan picks the agent per phase with `agent: claude|shell|http`.
+//! [`AgentRouter`] holds one launcher per kind and hands each phase to the
+//! one it asks for, so a plan can mix a Claude codegen phase with a shell
+//! lint phase.
+
+use crate::agent::launcher::{AgentLauncher, PhaseResult};
+use crate::check::cmd_succeeds::shell_cmd;
+use crate::check::masked_tail;
+use crate::plan::schema::{AgentKind, Phase};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Most text kept from a shell phase's output.
+const MAX_OUTPUT_CHARS: usize = 2000;
+
+/// Routes each phase to the launcher for its `agent` kind.
+pub struct AgentRouter {
+    claude: Box<dyn AgentLauncher>,
+    shell: Box<dyn AgentLauncher>,
+    http: Box<dyn AgentLauncher>,
+}
+
+impl AgentRouter {
+    /// Claude phases go to `claude`; shell and http phases to the built-in
+    /// [`ShellLauncher`] and [`HttpLauncher`].
+    pub fn new(claude: impl AgentLauncher + 'static) -> Self {
+        Self {
+            claude: Box::new(claude),
+            shell: Box::new(ShellLauncher),
+            http: Box::new(HttpLauncher),
+        }
+    }
+
+    pub fn with_shell(mut self, shell: impl AgentLauncher + 'static) -> Self {
+        self.shell = Box::new(shell);
+        self
+    }
+
+    pub fn with_http(mut self, http: impl AgentLauncher + 'static) -> Self {
+        self.http = Box::new(http);
+        self
+    }
+
+    fn launcher_for(&self, kind: AgentKind) -> &dyn AgentLauncher {
+        match kind {
+            AgentKind::Claude => self.claude.as_ref(),
+            AgentKind::Shell => self.shell.as_ref(),
+            AgentKind::Http => self.http.as_ref(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AgentLauncher for AgentRouter {
+    async fn run_phase(
+        &self,
+        phase: &Phase,
+        prompt: &str,
+        plan_context: &str,
+        session_id: &str,
+        cwd: &Path,
+        cancel: CancellationToken,
+    ) -> Result<PhaseResult> {
+        self.launcher_for(phase.agent)
+            .run_phase(phase, prompt, plan_context, session_id, cwd, cancel)
+            .await
+    }
+}
+
+/// Runs the phase's `prompt` as a shell command (`sh -c`, or PowerShell on
+/// Windows). Exit 0 is done; anything else is a crash carrying stderr.
+///
+/// The full prompt the conductor built, retry feedback included, is passed
+/// in `EDDA_PHASE_PROMPT` for scripts that want it.
+pub struct ShellLauncher;
+
+#[async_trait::async_trait]
+impl AgentLauncher for ShellLauncher {
+    async fn run_phase(
+        &self,
+        phase: &Phase,
+        prompt: &str,
+        _plan_context: &str,
+        session_id: &str,
+        cwd: &Path,
+        cancel: CancellationToken,
+    ) -> Result<PhaseResult> {
+        let (shell, args) = shell_cmd(&phase.prompt);
+        let mut cmd = tokio::process::Command::new(shell);
+        cmd.args(&args)
+            .current_dir(cwd)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .env("EDDA_CONDUCTOR_MODE", "1")
+            .env("EDDA_SESSION_ID", session_id)
+            .env("EDDA_PHASE_ID", &phase.id)
+            .env("EDDA_PHASE_PROMPT", prompt);
+        for (k, v) in &phase.env {
+            cmd.env(k, v);
+        }
+
+        let child = cmd.spawn()?;
+        let timeout_sec = phase.timeout_sec.unwrap_or(1800);
+        tokio::select! {
+            output = child.wait_with_output() => {
+                let output = output?;
+                if output.status.success() {
+                    Ok(PhaseResult::AgentDone {
+                        cost_usd: None,
+                        result_text: Some(masked_tail(&String::from_utf8_lossy(&output.stdout), MAX_OUTPUT_CHARS))
+                            .filter(|s| !s.is_empty()),
+                    })
+                } else {
+                    Ok(PhaseResult::AgentCrash {
+                        error: format!(
+                            "exit {}: {}",
+                            output.status.code().unwrap_or(-1),
+                            masked_tail(&String::from_utf8_lossy(&output.stderr), MAX_OUTPUT_CHARS)
+                        ),
+                    })
+                }
+            }
+            // Dropping the child future kills the process (kill_on_drop).
+            _ = tokio::time::sleep(Duration::from_secs(timeout_sec)) => Ok(PhaseResult::Timeout),
+            _ = cancel.cancelled() => Ok(PhaseResult::AgentCrash { error: "conductor shutdown".into() }),
+        }
+    }
+}
+
+/// POSTs the phase to its `endpoint` as JSON and waits for the agent's
+/// answer, for agents that run as services.
+///
+/// Request: `{plan_context, prompt, phase_id, session_id, cwd, budget_usd}`.
+/// Response: `{status, cost_usd?, result?, error?}` where `status` is
+/// `done`, `failed`, `max_turns` or `budget_exceeded`. Transport errors and
+/// non-2xx responses count as a crash, so the phase's retry policy applies.
+pub struct HttpLauncher;
+
+#[derive(Serialize)]
+struct HttpAgentRequest<'a> {
+    phase_id: &'a str,
+    prompt: &'a str,
+    plan_context: &'a str,
+    session_id: &'a str,
+    cwd: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    budget_usd: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum HttpAgentStatus {
+    Done,
+    Failed,
+    MaxTurns,
+    BudgetExceeded,
+}
+
+#[derive(Debug, Deserialize)]
+struct HttpAgentResponse {
+    status: HttpAgentStatus,
+    #[serde(default)]
+    cost_usd: Option<f64>,
+    #[serde(default)]
+    result: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+impl HttpAgentResponse {
+    fn into_result(self) -> PhaseResult {
+        match self.status {
+            HttpAgentStatus::Done => PhaseResult::AgentDone {
+                cost_usd: self.cost_usd,
+                result_text: self.result,
+            },
+            HttpAgentStatus::Failed => PhaseResult::AgentCrash {
+                error: self
+                    .error
+                    .unwrap_or_else(|| "agent reported failure".into()),
+            },
+            HttpAgentStatus::MaxTurns => PhaseResult::MaxTurns {
+                cost_usd: self.cost_usd,
+            },
+            HttpAgentStatus::BudgetExceeded => PhaseResult::BudgetExceeded {
+                cost_usd: self.cost_usd,
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AgentLauncher for HttpLauncher {
+    async fn run_phase(
+        &self,
+        phase: &Phase,
+        prompt: &str,
+        plan_context: &str,
+        session_id: &str,
+        cwd: &Path,
+        cancel: CancellationToken,
+    ) -> Result<PhaseResult> {
+        let Some(endpoint) = phase.endpoint.clone() else {
+            anyhow::bail!(
+                "phase \"{}\" uses agent: http but has no endpoint",
+                phase.id
+            );
+        };
+        let body = serde_json::to_string(&HttpAgentRequest {
+            phase_id: &phase.id,
+            prompt,
+            plan_context,
+            session_id,
+            cwd: cwd.display().to_string(),
+            budget_usd: phase.budget_usd,
+        })?;
+        let timeout = Duration::from_secs(phase.timeout_sec.unwrap_or(1800));
+
+        let request = tokio::task::spawn_blocking(move || post_phase(&endpoint, body, timeout));
+        tokio::select! {
+            result = request => Ok(result?),
+            _ = cancel.cancelled() => Ok(PhaseResult::AgentCrash { error: "conductor shutdown".into() }),
+        }
+    }
+}
+
+fn post_phase(endpoint: &str, body: String, timeout: Duration) -> PhaseResult {
+    let agent = ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .build()
+        .new_agent();
+    let response = agent
+        .post(endpoint)
+        .header("Content-Type", "application/json")
+        .send(body)
+        .and_then(|mut r| r.body_mut().read_to_string());
+    let text = match response {
+        Ok(text) => text,
+        Err(ureq::Error::Timeout(_)) => return PhaseResult::Timeout,
+        Err(e) => {
+            return PhaseResult::AgentCrash {
+                error: format!("http agent {endpoint}: {e}"),
+            }
+        }
+    };
+    match serde_json::from_str::<HttpAgentResponse>(&text) {
+        Ok(r) => r.into_result(),
+        Err(e) => PhaseResult::AgentCrash {
+            error: format!("http agent {endpoint} returned an unreadable response: {e}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agent::launcher::MockLauncher;
+    use crate::plan::parser::parse_plan;
+
+    async fn run(launcher: &dyn AgentLauncher, phase: &Phase) -> Result<PhaseResult> {
+        launcher
+            .run_phase(
+                phase,
+                "built prompt",
+                "",
+                "sid",
+                Path::new("."),
+                CancellationToken::new(),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn router_dispatches_by_agent_kind() {
+        let plan = parse_plan(
+            "name: t\nphases:\n  - id: gen\n    prompt: x\n  - id: lint\n    agent: shell\n    prompt: x\n",
+        )
+        .unwrap();
+        let claude = MockLauncher::new();
+        claude.set_results("gen", vec![PhaseResult::Timeout]);
+        let shell = MockLauncher::new();
+        shell.set_results(
+            "lint",
+            vec![PhaseResult::AgentCrash {
+                error: "shell".into(),
+            }],
+        );
+        let router = AgentRouter::new(claude).with_shell(shell);
+
+        assert!(matches!(
+            run(&router, &plan.phases[0]).await.unwrap(),
+            PhaseResult::Timeout
+        ));
+        assert!(matches!(
+            run(&router, &plan.phases[1]).await.unwrap(),
+            PhaseResult::AgentCrash { error } if error == "shell"
+        ));
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn shell_phase_succeeds_on_exit_zero_and_crashes_otherwise() {
+        let plan = parse_plan(
+            "name: t\nphases:\n  - id: ok\n    agent: shell\n    prompt: 'echo \"$EDDA_PHASE_PROMPT\"'\n  - id: bad\n    agent: shell\n    prompt: 'echo nope >&2; exit 3'\n",
+        )
+        .unwrap();
+
+        match run(&ShellLauncher, &plan.phases[0]).await.unwrap() {
+            PhaseResult::AgentDone { result_text, .. } => {
+                assert_eq!(result_text.as_deref(), Some("built prompt"));
+            }
+            other => panic!("expected AgentDone, got {other:?}"),
+        }
+        match run(&ShellLauncher, &plan.phases[1]).await.unwrap() {
+            PhaseResult::AgentCrash { error } => assert_eq!(error, "exit 3: nope"),
+            other => panic!("expected AgentCrash, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn http_response_maps_to_phase_result() {
+        let r: HttpAgentResponse =
+            serde_json::from_str(r#"{"status":"done","cost_usd":0.5,"result":"ok"}"#).unwrap();
+        assert!(matches!(
+            r.into_result(),
+            PhaseResult::AgentDone { cost_usd: Some(c), result_text: Some(t) } if c == 0.5 && t == "ok"
+        ));
+        let r: HttpAgentResponse =
+            serde_json::from_str(r#"{"status":"failed","error":"tests red"}"#).unwrap();
+        assert!(matches!(
+            r.into_result(),
+            PhaseResult::AgentCrash { error } if error == "tests red"
+        ));
+    }
+
+    #[test]
+    fn unreachable_http_agent_is_a_crash() {
+        let result = post_phase(
+            "http://127.0.0.1:1/run",
+            "{}".into(),
+            Duration::from_secs(5),
+        );
+        assert!(matches!(result, PhaseResult::AgentCrash { .. }));
+    }
+}
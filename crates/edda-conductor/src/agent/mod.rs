@@ -1,3 +1,4 @@
+pub mod adapter;
 pub mod budget;
 pub mod launcher;
 pub mod stream;
@@ -0,0 +1,382 @@
+use crate::check::CheckOutput;
+use std::future::Future;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+use tokio::process::Command;
+
+/// Label on drafts opened by approval checks.
+pub const GATE_LABEL: &str = "conductor-gate";
+
+/// Human sign-off gate: open a draft with the required approval stages via
+/// `edda draft propose`, then poll `edda draft list --json` until it is
+/// approved, rejected, or `timeout_sec` runs out.
+///
+/// A still-open gate draft with the same title is reused instead of opening
+/// a second one, so a resumed run keeps waiting on the same sign-off.
+pub async fn check_approval(
+    title: &str,
+    stages: &[String],
+    interval_sec: u64,
+    timeout_sec: u64,
+    cwd: &Path,
+    phase_started_at: Option<&str>,
+) -> CheckOutput {
+    wait_for_gate(
+        title,
+        stages,
+        interval_sec,
+        timeout_sec,
+        cwd,
+        phase_started_at,
+        || list_drafts(cwd),
+    )
+    .await
+}
+
+/// Gate loop behind [`check_approval`], reading drafts through `list`.
+async fn wait_for_gate<F, Fut>(
+    title: &str,
+    stages: &[String],
+    interval_sec: u64,
+    timeout_sec: u64,
+    cwd: &Path,
+    phase_started_at: Option<&str>,
+    mut list: F,
+) -> CheckOutput
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Vec<serde_json::Value>, String>>,
+{
+    let start = Instant::now();
+    let drafts = match list().await {
+        Ok(d) => d,
+        Err(detail) => return CheckOutput::failed(detail, start.elapsed()),
+    };
+    let draft_id = match find_gate_draft(&drafts, title, phase_started_at) {
+        Some(id) => id,
+        None => match propose(cwd, title, stages).await {
+            Ok(id) => id,
+            Err(detail) => return CheckOutput::failed(detail, start.elapsed()),
+        },
+    };
+
+    let deadline = start + Duration::from_secs(timeout_sec);
+    loop {
+        let drafts = match list().await {
+            Ok(d) => d,
+            Err(detail) => return CheckOutput::failed(detail, start.elapsed()),
+        };
+        match drafts
+            .iter()
+            .find(|d| d["draft_id"] == draft_id.as_str())
+            .map(gate_status)
+        {
+            Some(GateStatus::Approved) => {
+                return CheckOutput::passed_with_detail(
+                    format!("draft {draft_id} approved"),
+                    start.elapsed(),
+                );
+            }
+            Some(GateStatus::Rejected(reason)) => {
+                return CheckOutput::failed(
+                    format!("draft {draft_id} rejected{reason}"),
+                    start.elapsed(),
+                );
+            }
+            Some(GateStatus::Pending) => {}
+            None => {
+                return CheckOutput::failed(
+                    format!("draft {draft_id} was deleted"),
+                    start.elapsed(),
+                );
+            }
+        }
+
+        if Instant::now() >= deadline {
+            return CheckOutput::failed(
+                format!("timed out after {timeout_sec}s waiting for approval of draft {draft_id}"),
+                start.elapsed(),
+            );
+        }
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        tokio::time::sleep(Duration::from_secs(interval_sec.max(1)).min(remaining)).await;
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum GateStatus {
+    Pending,
+    Approved,
+    /// Rejected, with a `" by actor: note"` suffix when known.
+    Rejected(String),
+}
+
+/// Run `edda draft list --json` and parse its one-draft-per-line output.
+async fn list_drafts(cwd: &Path) -> Result<Vec<serde_json::Value>, String> {
+    let output = Command::new("edda")
+        .args(["draft", "list", "--json"])
+        .current_dir(cwd)
+        .output()
+        .await
+        .map_err(|e| format!("edda not available: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("edda draft list failed: {}", stderr.trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(stdout
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect())
+}
+
+fn gate_status(draft: &serde_json::Value) -> GateStatus {
+    match draft.get("status").and_then(|v| v.as_str()) {
+        Some("approved") | Some("applied") => GateStatus::Approved,
+        Some("rejected") => {
+            let reject = draft
+                .get("approvals")
+                .and_then(|v| v.as_array())
+                .and_then(|a| a.iter().rev().find(|r| r["decision"] == "reject"));
+            let reason = match reject {
+                Some(r) => {
+                    let actor = r["actor"].as_str().unwrap_or("");
+                    let note = r["note"].as_str().unwrap_or("");
+                    if note.is_empty() {
+                        format!(" by {actor}")
+                    } else {
+                        format!(" by {actor}: {note}")
+                    }
+                }
+                None => String::new(),
+            };
+            GateStatus::Rejected(reason)
+        }
+        _ => GateStatus::Pending,
+    }
+}
+
+/// Newest gate draft titled `title` that is still open, or that was approved
+/// after the current phase attempt started.
+fn find_gate_draft(
+    drafts: &[serde_json::Value],
+    title: &str,
+    phase_started_at: Option<&str>,
+) -> Option<String> {
+    let started = phase_started_at.and_then(|s| OffsetDateTime::parse(s, &Rfc3339).ok());
+
+    let mut best: Option<(OffsetDateTime, String)> = None;
+    for draft in drafts {
+        let Some(id) = draft["draft_id"].as_str() else {
+            continue;
+        };
+        let is_gate = draft["title"] == title
+            && draft["labels"]
+                .as_array()
+                .is_some_and(|l| l.iter().any(|v| v == GATE_LABEL));
+        if !is_gate {
+            continue;
+        }
+        let Some(created) = draft["created_at"]
+            .as_str()
+            .and_then(|s| OffsetDateTime::parse(s, &Rfc3339).ok())
+        else {
+            continue;
+        };
+        let usable = match gate_status(draft) {
+            GateStatus::Pending => true,
+            GateStatus::Approved => started.is_some_and(|s| created >= s),
+            GateStatus::Rejected(_) => false,
+        };
+        if usable && best.as_ref().is_none_or(|(ts, _)| created > *ts) {
+            best = Some((created, id.to_string()));
+        }
+    }
+    best.map(|(_, id)| id)
+}
+
+/// Run `edda draft propose` and return the new draft ID.
+async fn propose(cwd: &Path, title: &str, stages: &[String]) -> Result<String, String> {
+    let mut args = vec![
+        "draft".to_string(),
+        "propose".to_string(),
+        "--title".to_string(),
+        title.to_string(),
+        "--purpose".to_string(),
+        "conductor approval gate".to_string(),
+        "--label".to_string(),
+        GATE_LABEL.to_string(),
+    ];
+    for stage in stages {
+        args.push("--stage".to_string());
+        args.push(stage.clone());
+    }
+
+    let output = Command::new("edda")
+        .args(&args)
+        .current_dir(cwd)
+        .output()
+        .await
+        .map_err(|e| format!("edda not available: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("edda draft propose failed: {}", stderr.trim()));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .find_map(|l| l.strip_prefix("Draft created: "))
+        .map(|id| id.trim().to_string())
+        .ok_or_else(|| "edda draft propose did not report a draft ID".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    fn draft(id: &str, status: &str, created_at: &str, approvals: &str) -> serde_json::Value {
+        serde_json::from_str(&format!(
+            r#"{{"draft_id":"{id}","title":"Deploy to prod","labels":["{GATE_LABEL}"],
+               "created_at":"{created_at}","status":"{status}","approvals":{approvals}}}"#
+        ))
+        .unwrap()
+    }
+
+    fn source(
+        drafts: &Arc<Mutex<Vec<serde_json::Value>>>,
+    ) -> impl FnMut() -> std::future::Ready<Result<Vec<serde_json::Value>, String>> {
+        let drafts = drafts.clone();
+        move || std::future::ready(Ok(drafts.lock().unwrap().clone()))
+    }
+
+    #[test]
+    fn gate_status_reads_draft_state() {
+        assert_eq!(
+            gate_status(&draft("drf_a", "proposed", "2026-01-01T00:00:00Z", "[]")),
+            GateStatus::Pending
+        );
+        assert_eq!(
+            gate_status(&draft("drf_b", "approved", "2026-01-01T00:00:00Z", "[]")),
+            GateStatus::Approved
+        );
+        assert_eq!(
+            gate_status(&draft(
+                "drf_c",
+                "rejected",
+                "2026-01-01T00:00:00Z",
+                r#"[{"decision":"reject","actor":"ops","note":"freeze week"}]"#,
+            )),
+            GateStatus::Rejected(" by ops: freeze week".into())
+        );
+    }
+
+    #[test]
+    fn find_gate_draft_reuses_open_or_fresh_approved_drafts() {
+        let mut drafts = vec![
+            draft("drf_old", "approved", "2026-01-01T00:00:00Z", "[]"),
+            draft("drf_no", "rejected", "2026-01-03T00:00:00Z", "[]"),
+        ];
+        let started = Some("2026-01-02T00:00:00Z");
+
+        // An approval from before this attempt does not count.
+        assert_eq!(find_gate_draft(&drafts, "Deploy to prod", started), None);
+
+        drafts.push(draft("drf_open", "proposed", "2025-12-31T00:00:00Z", "[]"));
+        assert_eq!(
+            find_gate_draft(&drafts, "Deploy to prod", started).as_deref(),
+            Some("drf_open")
+        );
+        assert_eq!(find_gate_draft(&drafts, "Other gate", started), None);
+    }
+
+    #[tokio::test]
+    async fn approval_passes_on_existing_approved_draft() {
+        let dir = tempfile::tempdir().unwrap();
+        let drafts = Arc::new(Mutex::new(vec![draft(
+            "drf_ok",
+            "approved",
+            "2026-01-02T00:00:01Z",
+            "[]",
+        )]));
+        let out = wait_for_gate(
+            "Deploy to prod",
+            &[],
+            1,
+            5,
+            dir.path(),
+            Some("2026-01-02T00:00:00Z"),
+            source(&drafts),
+        )
+        .await;
+        assert!(out.passed);
+        assert!(out.detail.unwrap().contains("drf_ok approved"));
+    }
+
+    #[tokio::test]
+    async fn approval_waits_until_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let drafts = Arc::new(Mutex::new(vec![draft(
+            "drf_wait",
+            "proposed",
+            "2026-01-02T00:00:01Z",
+            "[]",
+        )]));
+
+        let shared = drafts.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            *shared.lock().unwrap() = vec![draft(
+                "drf_wait",
+                "rejected",
+                "2026-01-02T00:00:01Z",
+                r#"[{"decision":"reject","actor":"lead","note":""}]"#,
+            )];
+        });
+
+        let out = wait_for_gate(
+            "Deploy to prod",
+            &[],
+            1,
+            5,
+            dir.path(),
+            None,
+            source(&drafts),
+        )
+        .await;
+        assert!(!out.passed);
+        assert!(out.detail.unwrap().contains("rejected by lead"));
+    }
+
+    #[tokio::test]
+    async fn approval_fails_when_draft_disappears() {
+        let dir = tempfile::tempdir().unwrap();
+        let drafts = Arc::new(Mutex::new(vec![draft(
+            "drf_gone",
+            "proposed",
+            "2026-01-02T00:00:01Z",
+            "[]",
+        )]));
+
+        let shared = drafts.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            shared.lock().unwrap().clear();
+        });
+
+        let out = wait_for_gate(
+            "Deploy to prod",
+            &[],
+            1,
+            5,
+            dir.path(),
+            None,
+            source(&drafts),
+        )
+        .await;
+        assert!(!out.passed);
+        assert!(out.detail.unwrap().contains("drf_gone was deleted"));
+    }
+}
@@ -0,0 +1,240 @@
+use crate::check::{masked_tail, CheckOutput};
+use crate::state::machine::{TestFailure, TestReport};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+
+/// Most failures kept per run; the rest are only counted.
+const MAX_FAILURES: usize = 20;
+
+/// Characters of captured output kept per failed test.
+const FAILURE_OUTPUT_CHARS: usize = 1500;
+
+/// Parameters of a `cargo_test` check.
+pub struct CargoTestParams<'a> {
+    pub package: Option<&'a str>,
+    pub filter: Option<&'a str>,
+    pub features: &'a [String],
+    pub timeout_sec: u64,
+}
+
+/// Run `cargo test` and pass when every selected test passes.
+///
+/// Test binaries are asked for libtest's JSON event stream, which is only
+/// available behind `-Z unstable-options`; `RUSTC_BOOTSTRAP=1` unlocks it on
+/// stable toolchains without affecting how the crates are compiled. Every
+/// test binary runs (`--no-fail-fast`) so one failing crate does not hide
+/// failures in the next. A filter that selects no tests fails the check.
+pub async fn check_cargo_test(params: &CargoTestParams<'_>, cwd: &Path) -> CheckOutput {
+    let start = Instant::now();
+    let result = Command::new("cargo")
+        .args(cargo_args(params))
+        .current_dir(cwd)
+        .env("RUSTC_BOOTSTRAP", "1")
+        .env("RUST_BACKTRACE", "0")
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true)
+        .output();
+
+    let output = match tokio::time::timeout(Duration::from_secs(params.timeout_sec), result).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => {
+            return CheckOutput::failed(format!("cannot run cargo: {e}"), start.elapsed());
+        }
+        Err(_) => {
+            return CheckOutput::failed(
+                format!("cargo test timed out after {}s", params.timeout_sec),
+                start.elapsed(),
+            );
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let Some(report) = parse_test_events(&stdout) else {
+        // No suite finished: the build failed or a test binary crashed.
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return CheckOutput::failed(
+            format!(
+                "cargo test exit {}: {}",
+                output.status.code().unwrap_or(-1),
+                masked_tail(&stderr, 2000)
+            ),
+            start.elapsed(),
+        );
+    };
+
+    let summary = summarize(&report);
+    if report.failed > 0 {
+        let names: Vec<&str> = report.failures.iter().map(|f| f.name.as_str()).collect();
+        let detail = format!("{summary}; failed: {}", names.join(", "));
+        CheckOutput::failed(detail, start.elapsed()).with_tests(report)
+    } else if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let detail = format!(
+            "{summary}, but cargo test exit {}: {}",
+            output.status.code().unwrap_or(-1),
+            masked_tail(&stderr, 2000)
+        );
+        CheckOutput::failed(detail, start.elapsed()).with_tests(report)
+    } else if report.passed == 0 && params.filter.is_some() {
+        let detail = format!("no tests matched filter {:?}", params.filter.unwrap_or(""));
+        CheckOutput::failed(detail, start.elapsed()).with_tests(report)
+    } else {
+        CheckOutput::passed_with_detail(summary, start.elapsed()).with_tests(report)
+    }
+}
+
+fn cargo_args(params: &CargoTestParams<'_>) -> Vec<String> {
+    let mut args = vec!["test".to_string(), "--no-fail-fast".to_string()];
+    if let Some(package) = params.package {
+        args.extend(["-p".to_string(), package.to_string()]);
+    }
+    if !params.features.is_empty() {
+        args.extend(["--features".to_string(), params.features.join(",")]);
+    }
+    args.push("--".to_string());
+    if let Some(filter) = params.filter {
+        args.push(filter.to_string());
+    }
+    args.extend(
+        ["-Z", "unstable-options", "--format", "json"]
+            .iter()
+            .map(|s| s.to_string()),
+    );
+    args
+}
+
+/// Fold libtest JSON events from every test binary into one report.
+/// Returns `None` when no suite reported a result.
+pub fn parse_test_events(stdout: &str) -> Option<TestReport> {
+    let mut report = TestReport::default();
+    let mut suites = 0;
+    for line in stdout.lines() {
+        let Ok(event) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+            continue;
+        };
+        let kind = event["event"].as_str().unwrap_or_default();
+        match event["type"].as_str() {
+            Some("suite") if matches!(kind, "ok" | "failed") => {
+                suites += 1;
+                let count = |key: &str| event[key].as_u64().unwrap_or(0);
+                report.passed += count("passed");
+                report.failed += count("failed");
+                report.ignored += count("ignored");
+                report.filtered_out += count("filtered_out");
+            }
+            Some("test") if kind == "failed" && report.failures.len() < MAX_FAILURES => {
+                let output = event["stdout"].as_str().unwrap_or_default();
+                report.failures.push(TestFailure {
+                    name: event["name"].as_str().unwrap_or("?").to_string(),
+                    output: masked_tail(output, FAILURE_OUTPUT_CHARS),
+                });
+            }
+            _ => {}
+        }
+    }
+    (suites > 0).then_some(report)
+}
+
+fn summarize(report: &TestReport) -> String {
+    let mut summary = format!("{} passed, {} failed", report.passed, report.failed);
+    if report.ignored > 0 {
+        summary.push_str(&format!(", {} ignored", report.ignored));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EVENTS: &str = r#"
+{ "type": "suite", "event": "started", "test_count": 3 }
+{ "type": "test", "event": "started", "name": "tests::a" }
+{ "type": "test", "name": "tests::a", "event": "ok" }
+{ "type": "test", "name": "tests::b", "event": "failed", "stdout": "thread 'tests::b' panicked at src/lib.rs:9:5:\nleft: 1\nright: 2\n" }
+{ "type": "test", "name": "tests::c", "event": "ignored" }
+{ "type": "suite", "event": "failed", "passed": 1, "failed": 1, "ignored": 1, "measured": 0, "filtered_out": 4, "exec_time": 0.01 }
+{ "type": "suite", "event": "started", "test_count": 2 }
+{ "type": "suite", "event": "ok", "passed": 2, "failed": 0, "ignored": 0, "measured": 0, "filtered_out": 0, "exec_time": 0.02 }
+"#;
+
+    #[test]
+    fn parses_counts_and_failures_across_binaries() {
+        let report = parse_test_events(EVENTS).unwrap();
+        assert_eq!(report.passed, 3);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.ignored, 1);
+        assert_eq!(report.filtered_out, 4);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].name, "tests::b");
+        assert!(report.failures[0].output.contains("right: 2"));
+        assert_eq!(summarize(&report), "3 passed, 1 failed, 1 ignored");
+    }
+
+    #[test]
+    fn no_finished_suite_is_not_a_report() {
+        assert!(parse_test_events("error[E0308]: mismatched types\n").is_none());
+        assert!(
+            parse_test_events(r#"{ "type": "suite", "event": "started", "test_count": 1 }"#)
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn runs_a_crate_and_reports_failing_tests() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Cargo.toml"),
+            "[package]\nname = \"probe\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[workspace]\n",
+        )
+        .unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "#[test] fn adds() { assert_eq!(1 + 1, 2); }\n\
+             #[test] fn breaks() { assert_eq!(1, 2, \"math broke\"); }\n",
+        )
+        .unwrap();
+
+        let mut params = CargoTestParams {
+            package: None,
+            filter: None,
+            features: &[],
+            timeout_sec: 300,
+        };
+        let out = check_cargo_test(&params, dir.path()).await;
+        assert!(!out.passed, "{:?}", out.detail);
+        let report = out.tests.unwrap();
+        assert_eq!((report.passed, report.failed), (1, 1));
+        assert_eq!(report.failures[0].name, "breaks");
+        assert!(report.failures[0].output.contains("math broke"));
+
+        params.filter = Some("adds");
+        let out = check_cargo_test(&params, dir.path()).await;
+        assert!(out.passed, "{:?}", out.detail);
+        assert_eq!(out.detail.as_deref(), Some("1 passed, 0 failed"));
+
+        params.filter = Some("nothing_matches");
+        let out = check_cargo_test(&params, dir.path()).await;
+        assert!(!out.passed);
+        assert!(out.detail.unwrap().contains("no tests matched"));
+    }
+
+    #[test]
+    fn args_select_package_features_and_filter() {
+        let features = vec!["sqlite".to_string(), "cli".to_string()];
+        let params = CargoTestParams {
+            package: Some("edda-ledger"),
+            filter: Some("append"),
+            features: &features,
+            timeout_sec: 60,
+        };
+        assert_eq!(
+            cargo_args(&params).join(" "),
+            "test --no-fail-fast -p edda-ledger --features sqlite,cli -- append \
+             -Z unstable-options --format json"
+        );
+    }
+}
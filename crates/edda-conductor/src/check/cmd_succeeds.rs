@@ -5,7 +5,7 @@ use tokio::process::Command;
 
 /// Shell program and args for the current platform.
 #[cfg(windows)]
-fn shell_cmd(cmd: &str) -> (String, Vec<String>) {
+pub(crate) fn shell_cmd(cmd: &str) -> (String, Vec<String>) {
     // Prefer PowerShell over cmd.exe for better Unix-ism support
     static SHELL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
     let shell = SHELL.get_or_init(|| {
@@ -29,7 +29,7 @@ fn shell_cmd(cmd: &str) -> (String, Vec<String>) {
 }
 
 #[cfg(not(windows))]
-fn shell_cmd(cmd: &str) -> (String, Vec<String>) {
+pub(crate) fn shell_cmd(cmd: &str) -> (String, Vec<String>) {
     ("sh".into(), vec!["-c".into(), cmd.into()])
 }
 
@@ -0,0 +1,421 @@
+use crate::check::CheckOutput;
+use crate::plan::schema::CoverageFormat;
+use std::path::Path;
+use std::time::Instant;
+use tokio::process::Command;
+
+/// Ledger tag on notes that record a passing coverage run.
+const COVERAGE_TAG: &str = "coverage";
+
+/// Slack for float noise when comparing percentages.
+const EPSILON: f64 = 1e-6;
+
+/// Line and branch coverage in percent; `None` when the report has no data.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Coverage {
+    pub line: Option<f64>,
+    pub branch: Option<f64>,
+}
+
+/// Parameters of a `coverage_threshold` check.
+pub struct CoverageParams<'a> {
+    pub path: &'a str,
+    pub format: Option<CoverageFormat>,
+    pub min_line: Option<f64>,
+    pub min_branch: Option<f64>,
+    pub max_regression: f64,
+    pub track: bool,
+}
+
+/// Parse a coverage report and compare it against fixed thresholds and,
+/// with `track`, against the last passing run recorded in the ledger.
+///
+/// A passing run is recorded as a `coverage`-tagged note through `edda note`,
+/// so the next run has a baseline. Without the `edda` binary the regression
+/// comparison is skipped and only the thresholds apply.
+pub async fn check_coverage_threshold(params: &CoverageParams<'_>, cwd: &Path) -> CheckOutput {
+    let start = Instant::now();
+    let full = cwd.join(params.path);
+    let content = match std::fs::read_to_string(&full) {
+        Ok(c) => c,
+        Err(e) => {
+            return CheckOutput::failed(
+                format!("cannot read coverage report {}: {e}", params.path),
+                start.elapsed(),
+            );
+        }
+    };
+    let format = params.format.unwrap_or_else(|| detect_format(&content));
+    let Some(current) = parse_report(format, &content) else {
+        return CheckOutput::failed(
+            format!(
+                "cannot parse {} coverage report {}",
+                format_name(format),
+                params.path
+            ),
+            start.elapsed(),
+        );
+    };
+
+    let baseline = if params.track {
+        last_recorded(params.path, cwd).await
+    } else {
+        None
+    };
+    if let Err(detail) = evaluate(params, current, baseline) {
+        return CheckOutput::failed(detail, start.elapsed());
+    }
+
+    if params.track {
+        record(params.path, current, cwd).await;
+    }
+    CheckOutput::passed_with_detail(summary(current), start.elapsed())
+}
+
+/// Thresholds first, then the regression check against `baseline`.
+fn evaluate(
+    params: &CoverageParams<'_>,
+    current: Coverage,
+    baseline: Option<Coverage>,
+) -> Result<(), String> {
+    for (kind, min, value) in [
+        ("line", params.min_line, current.line),
+        ("branch", params.min_branch, current.branch),
+    ] {
+        let Some(min) = min else { continue };
+        match value {
+            None => return Err(format!("coverage report has no {kind} data")),
+            Some(v) if v + EPSILON < min => {
+                return Err(format!("{kind} coverage {v:.2}% is below {min:.2}%"));
+            }
+            Some(_) => {}
+        }
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    for (kind, before, now) in [
+        ("line", baseline.line, current.line),
+        ("branch", baseline.branch, current.branch),
+    ] {
+        if let (Some(before), Some(now)) = (before, now) {
+            if now + params.max_regression + EPSILON < before {
+                return Err(format!(
+                    "{kind} coverage regressed from {before:.2}% to {now:.2}% \
+                     (last recorded run; max regression {:.2} points)",
+                    params.max_regression
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn summary(c: Coverage) -> String {
+    let pct = |v: Option<f64>| v.map_or("n/a".to_string(), |v| format!("{v:.2}%"));
+    format!("line {}, branch {}", pct(c.line), pct(c.branch))
+}
+
+fn format_name(format: CoverageFormat) -> &'static str {
+    match format {
+        CoverageFormat::Lcov => "lcov",
+        CoverageFormat::Cobertura => "cobertura",
+        CoverageFormat::Tarpaulin => "tarpaulin",
+    }
+}
+
+// ── Report parsing ──
+
+/// Guess the format from the content: XML is Cobertura, JSON is tarpaulin,
+/// anything else is read as lcov.
+pub fn detect_format(content: &str) -> CoverageFormat {
+    let head = content.trim_start();
+    if head.starts_with('<') {
+        CoverageFormat::Cobertura
+    } else if head.starts_with('{') {
+        CoverageFormat::Tarpaulin
+    } else {
+        CoverageFormat::Lcov
+    }
+}
+
+pub fn parse_report(format: CoverageFormat, content: &str) -> Option<Coverage> {
+    match format {
+        CoverageFormat::Lcov => parse_lcov(content),
+        CoverageFormat::Cobertura => parse_cobertura(content),
+        CoverageFormat::Tarpaulin => parse_tarpaulin(content),
+    }
+}
+
+fn percent(hit: u64, found: u64) -> Option<f64> {
+    (found > 0).then(|| hit as f64 * 100.0 / found as f64)
+}
+
+/// lcov tracefile: sums the `LF`/`LH` and `BRF`/`BRH` totals of every record.
+fn parse_lcov(content: &str) -> Option<Coverage> {
+    let (mut lf, mut lh, mut brf, mut brh) = (0u64, 0u64, 0u64, 0u64);
+    let mut records = 0;
+    for line in content.lines() {
+        let Some((tag, value)) = line.trim().split_once(':') else {
+            continue;
+        };
+        let n = value.trim().parse::<u64>().unwrap_or(0);
+        match tag {
+            "SF" => records += 1,
+            "LF" => lf += n,
+            "LH" => lh += n,
+            "BRF" => brf += n,
+            "BRH" => brh += n,
+            _ => {}
+        }
+    }
+    (records > 0).then(|| Coverage {
+        line: percent(lh, lf),
+        branch: percent(brh, brf),
+    })
+}
+
+/// Cobertura XML: the `line-rate`/`branch-rate` attributes of the root
+/// `<coverage>` element. A report with `branches-valid="0"` has no branch data.
+fn parse_cobertura(content: &str) -> Option<Coverage> {
+    let root = regex::Regex::new(r"<coverage\b[^>]*>").ok()?;
+    let tag = root.find(content)?.as_str();
+    let attr = |name: &str| -> Option<f64> {
+        let re = regex::Regex::new(&format!(r#"\s{name}="([0-9.eE+-]+)""#)).ok()?;
+        re.captures(tag)?.get(1)?.as_str().parse().ok()
+    };
+    let line = attr("line-rate").map(|r| r * 100.0);
+    let branch = if attr("branches-valid") == Some(0.0) {
+        None
+    } else {
+        attr("branch-rate").map(|r| r * 100.0)
+    };
+    (line.is_some() || branch.is_some()).then_some(Coverage { line, branch })
+}
+
+/// cargo-tarpaulin JSON (`--out Json`): top-level `covered`/`coverable`, or
+/// the per-file counts summed. Tarpaulin only measures lines.
+fn parse_tarpaulin(content: &str) -> Option<Coverage> {
+    let report: serde_json::Value = serde_json::from_str(content).ok()?;
+    let count = |v: &serde_json::Value, key: &str| v.get(key).and_then(|n| n.as_u64());
+    let (covered, coverable) = match (count(&report, "covered"), count(&report, "coverable")) {
+        (Some(c), Some(t)) => (c, t),
+        _ => report
+            .get("files")?
+            .as_array()?
+            .iter()
+            .fold((0, 0), |(c, t), f| {
+                (
+                    c + count(f, "covered").unwrap_or(0),
+                    t + count(f, "coverable").unwrap_or(0),
+                )
+            }),
+    };
+    Some(Coverage {
+        line: percent(covered, coverable),
+        branch: None,
+    })
+}
+
+// ── Ledger baseline ──
+
+fn note_prefix(path: &str) -> String {
+    format!("coverage[{path}]")
+}
+
+fn note_text(path: &str, c: Coverage) -> String {
+    let mut text = note_prefix(path);
+    if let Some(v) = c.line {
+        text.push_str(&format!(" line={v:.2}%"));
+    }
+    if let Some(v) = c.branch {
+        text.push_str(&format!(" branch={v:.2}%"));
+    }
+    text
+}
+
+fn parse_note(path: &str, text: &str) -> Option<Coverage> {
+    let rest = text.strip_prefix(&note_prefix(path))?;
+    let mut c = Coverage::default();
+    for token in rest.split_whitespace() {
+        let Some((kind, value)) = token.split_once('=') else {
+            continue;
+        };
+        let value = value.trim_end_matches('%').parse().ok();
+        match kind {
+            "line" => c.line = value,
+            "branch" => c.branch = value,
+            _ => {}
+        }
+    }
+    Some(c)
+}
+
+/// Coverage of the last passing run for this report, via `edda log`.
+async fn last_recorded(path: &str, cwd: &Path) -> Option<Coverage> {
+    let output = Command::new("edda")
+        .args([
+            "log",
+            "--json",
+            "--type",
+            "note",
+            "--tag",
+            COVERAGE_TAG,
+            "--keyword",
+            &note_prefix(path),
+            "--limit",
+            "1",
+        ])
+        .current_dir(cwd)
+        .output()
+        .await
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|l| serde_json::from_str::<serde_json::Value>(l).ok())
+        .find_map(|e| parse_note(path, e["payload"]["text"].as_str()?))
+}
+
+/// Record a passing run with `edda note`. Failures are ignored: the check
+/// result does not depend on the ledger being writable.
+async fn record(path: &str, c: Coverage, cwd: &Path) {
+    let _ = Command::new("edda")
+        .args([
+            "note",
+            &note_text(path, c),
+            "--role",
+            "system",
+            "--tag",
+            COVERAGE_TAG,
+        ])
+        .current_dir(cwd)
+        .output()
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(min_line: Option<f64>, min_branch: Option<f64>) -> CoverageParams<'static> {
+        CoverageParams {
+            path: "coverage/lcov.info",
+            format: None,
+            min_line,
+            min_branch,
+            max_regression: 0.5,
+            track: false,
+        }
+    }
+
+    #[test]
+    fn parses_all_three_formats() {
+        let lcov = "TN:\nSF:src/a.rs\nLF:10\nLH:8\nBRF:4\nBRH:2\nend_of_record\n\
+                    SF:src/b.rs\nLF:10\nLH:10\nend_of_record\n";
+        assert_eq!(detect_format(lcov), CoverageFormat::Lcov);
+        assert_eq!(
+            parse_report(CoverageFormat::Lcov, lcov),
+            Some(Coverage {
+                line: Some(90.0),
+                branch: Some(50.0)
+            })
+        );
+
+        let xml = r#"<?xml version="1.0" ?>
+<coverage line-rate="0.8125" branch-rate="0" branches-valid="0" version="1.9">
+  <packages/>
+</coverage>"#;
+        assert_eq!(detect_format(xml), CoverageFormat::Cobertura);
+        assert_eq!(
+            parse_report(CoverageFormat::Cobertura, xml),
+            Some(Coverage {
+                line: Some(81.25),
+                branch: None
+            })
+        );
+
+        let json = r#"{"files":[{"covered":3,"coverable":4},{"covered":1,"coverable":4}]}"#;
+        assert_eq!(detect_format(json), CoverageFormat::Tarpaulin);
+        assert_eq!(
+            parse_report(CoverageFormat::Tarpaulin, json),
+            Some(Coverage {
+                line: Some(50.0),
+                branch: None
+            })
+        );
+
+        assert_eq!(parse_report(CoverageFormat::Lcov, "not a report"), None);
+    }
+
+    #[test]
+    fn evaluate_thresholds_and_regression() {
+        let current = Coverage {
+            line: Some(80.0),
+            branch: None,
+        };
+        assert!(evaluate(&params(Some(80.0), None), current, None).is_ok());
+        let err = evaluate(&params(Some(85.0), None), current, None).unwrap_err();
+        assert!(err.contains("line coverage 80.00% is below 85.00%"));
+        let err = evaluate(&params(None, Some(50.0)), current, None).unwrap_err();
+        assert!(err.contains("no branch data"));
+
+        // Within max_regression of the baseline is fine; beyond it is not.
+        let baseline = |line| Coverage {
+            line: Some(line),
+            branch: None,
+        };
+        assert!(evaluate(&params(None, None), current, Some(baseline(80.4))).is_ok());
+        let err = evaluate(&params(None, None), current, Some(baseline(82.0))).unwrap_err();
+        assert!(err.contains("regressed from 82.00% to 80.00%"));
+    }
+
+    #[test]
+    fn note_round_trips_and_is_keyed_by_path() {
+        let c = Coverage {
+            line: Some(83.456),
+            branch: Some(70.0),
+        };
+        let text = note_text("coverage/lcov.info", c);
+        assert_eq!(
+            text,
+            "coverage[coverage/lcov.info] line=83.46% branch=70.00%"
+        );
+        assert_eq!(
+            parse_note("coverage/lcov.info", &text),
+            Some(Coverage {
+                line: Some(83.46),
+                branch: Some(70.0)
+            })
+        );
+        assert_eq!(parse_note("other.info", &text), None);
+    }
+
+    #[tokio::test]
+    async fn check_reads_report_from_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("coverage")).unwrap();
+        std::fs::write(
+            dir.path().join("coverage/lcov.info"),
+            "SF:src/a.rs\nLF:4\nLH:3\nend_of_record\n",
+        )
+        .unwrap();
+
+        let out = check_coverage_threshold(&params(Some(70.0), None), dir.path()).await;
+        assert!(out.passed, "{:?}", out.detail);
+        assert_eq!(out.detail.as_deref(), Some("line 75.00%, branch n/a"));
+
+        let out = check_coverage_threshold(&params(Some(80.0), None), dir.path()).await;
+        assert!(!out.passed);
+
+        let missing = CoverageParams {
+            path: "nope.info",
+            ..params(None, None)
+        };
+        let out = check_coverage_threshold(&missing, dir.path()).await;
+        assert!(out.detail.unwrap().contains("cannot read coverage report"));
+    }
+}
@@ -42,6 +42,7 @@ impl CheckEngine {
                 status,
                 detail: output.detail.clone(),
                 duration_ms: output.duration.as_millis() as u64,
+                tests: output.tests.clone(),
             });
 
             if !output.passed {
@@ -52,6 +53,7 @@ impl CheckEngine {
                         status: CheckStatus::Waiting,
                         detail: None,
                         duration_ms: 0,
+                        tests: None,
                     });
                 }
                 return CheckRunResult {
@@ -118,10 +120,72 @@ impl CheckEngine {
                 )
                 .await
             }
+            CheckSpec::Approval {
+                title,
+                stages,
+                interval_sec,
+                timeout_sec,
+            } => {
+                crate::check::approval::check_approval(
+                    title,
+                    stages,
+                    *interval_sec,
+                    *timeout_sec,
+                    &self.cwd,
+                    phase_started_at,
+                )
+                .await
+            }
+            CheckSpec::CoverageThreshold { .. } => coverage_check(spec, &self.cwd).await,
+            CheckSpec::CargoTest { .. } => cargo_test_check(spec, &self.cwd).await,
         }
     }
 }
 
+/// Run a `coverage_threshold` spec; shared with `wait_until`.
+pub(crate) async fn coverage_check(spec: &CheckSpec, cwd: &std::path::Path) -> CheckOutput {
+    let CheckSpec::CoverageThreshold {
+        path,
+        format,
+        min_line,
+        min_branch,
+        max_regression,
+        track,
+    } = spec
+    else {
+        unreachable!("coverage_check called with {}", spec.type_name());
+    };
+    let params = crate::check::coverage::CoverageParams {
+        path,
+        format: *format,
+        min_line: *min_line,
+        min_branch: *min_branch,
+        max_regression: *max_regression,
+        track: *track,
+    };
+    crate::check::coverage::check_coverage_threshold(&params, cwd).await
+}
+
+/// Run a `cargo_test` spec; shared with `wait_until`.
+pub(crate) async fn cargo_test_check(spec: &CheckSpec, cwd: &std::path::Path) -> CheckOutput {
+    let CheckSpec::CargoTest {
+        package,
+        filter,
+        features,
+        timeout_sec,
+    } = spec
+    else {
+        unreachable!("cargo_test_check called with {}", spec.type_name());
+    };
+    let params = crate::check::cargo_test::CargoTestParams {
+        package: package.as_deref(),
+        filter: filter.as_deref(),
+        features,
+        timeout_sec: *timeout_sec,
+    };
+    crate::check::cargo_test::check_cargo_test(&params, cwd).await
+}
+
 fn now_rfc3339() -> String {
     time::OffsetDateTime::now_utc()
         .format(&time::format_description::well_known::Rfc3339)
@@ -1,4 +1,7 @@
+pub mod approval;
+pub mod cargo_test;
 pub mod cmd_succeeds;
+pub mod coverage;
 pub mod edda_event;
 pub mod engine;
 pub mod file_contains;
@@ -6,6 +9,7 @@ pub mod file_exists;
 pub mod git_clean;
 pub mod wait_until;
 
+use crate::state::machine::TestReport;
 use std::time::Duration;
 
 /// Output from a single check execution.
@@ -14,6 +18,7 @@ pub struct CheckOutput {
     pub passed: bool,
     pub detail: Option<String>,
     pub duration: Duration,
+    pub tests: Option<TestReport>,
 }
 
 impl CheckOutput {
@@ -22,6 +27,7 @@ impl CheckOutput {
             passed: true,
             detail: None,
             duration,
+            tests: None,
         }
     }
 
@@ -30,6 +36,7 @@ impl CheckOutput {
             passed: true,
             detail: Some(detail),
             duration,
+            tests: None,
         }
     }
 
@@ -38,8 +45,26 @@ impl CheckOutput {
             passed: false,
             detail: Some(detail),
             duration,
+            tests: None,
         }
     }
+
+    /// Attach the per-test results of a test-suite check.
+    pub fn with_tests(mut self, tests: TestReport) -> Self {
+        self.tests = Some(tests);
+        self
+    }
+}
+
+/// The last `max_chars` characters of `text`, trimmed and secret-masked.
+pub(crate) fn masked_tail(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    let start = text
+        .char_indices()
+        .rev()
+        .nth(max_chars.saturating_sub(1))
+        .map_or(0, |(i, _)| i);
+    mask_secrets(&text[start..])
 }
 
 /// Mask secrets in output strings before storing.
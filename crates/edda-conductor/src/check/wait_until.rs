@@ -79,6 +79,17 @@ async fn run_inner(spec: &CheckSpec, cwd: &Path, phase_started_at: Option<&str>)
             // Nested wait_until is rejected at parse time, but handle gracefully
             CheckOutput::failed("nested wait_until is not supported".into(), Duration::ZERO)
         }
+        CheckSpec::Approval { .. } => {
+            // Rejected at parse time too: approval already polls on its own
+            CheckOutput::failed(
+                "approval cannot be nested in wait_until".into(),
+                Duration::ZERO,
+            )
+        }
+        CheckSpec::CoverageThreshold { .. } => {
+            crate::check::engine::coverage_check(spec, cwd).await
+        }
+        CheckSpec::CargoTest { .. } => crate::check::engine::cargo_test_check(spec, cwd).await,
     }
 }
 
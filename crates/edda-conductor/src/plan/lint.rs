@@ -0,0 +1,670 @@
+//! `edda conduct lint`: what `run` would reject in a plan, plus constructs
+//! it would accept but probably should not, each with the line it is on.
+//!
+//! Lint works on the raw YAML rather than the typed [`Plan`], so one bad
+//! check or dependency does not hide the rest. Once the structural rules
+//! pass, the plan goes through [`parse_plan`] and whatever that rejects is
+//! reported too.
+//!
+//! [`Plan`]: crate::plan::schema::Plan
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::ops::Range;
+
+use serde::Serialize;
+use serde_yml::Value;
+
+use crate::plan::parser::parse_plan;
+
+/// Check types `run` understands.
+const CHECK_TYPES: &[&str] = &[
+    "cmd_succeeds",
+    "file_exists",
+    "file_contains",
+    "git_clean",
+    "edda_event",
+    "wait_until",
+    "approval",
+    "coverage_threshold",
+    "cargo_test",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// `run` rejects the plan, or the plan cannot finish.
+    Error,
+    /// The plan runs, but likely not as intended.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        })
+    }
+}
+
+/// One lint finding.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable rule name: `yaml`, `schema`, `unknown-check`,
+    /// `duplicate-phase`, `missing-dependency`, `unreachable-phase`,
+    /// `suspicious-shell` or `budget`.
+    pub code: &'static str,
+    pub message: String,
+    /// 1-based line in the plan file, when it could be located.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+}
+
+impl fmt::Display for Diagnostic {
+    /// `error[unknown-check] phase "build": ...`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}] ", self.severity, self.code)?;
+        if let Some(phase) = &self.phase {
+            write!(f, "phase \"{phase}\": ")?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// Lint a plan's YAML. Diagnostics are ordered by line; an empty list means
+/// the plan is clean.
+pub fn lint_plan(yaml: &str) -> Vec<Diagnostic> {
+    let raw: Value = match serde_yml::from_str(yaml) {
+        Ok(v) => v,
+        Err(e) => {
+            return vec![Diagnostic {
+                severity: Severity::Error,
+                code: "yaml",
+                message: format!("invalid YAML syntax: {e}"),
+                line: e.location().map(|l| l.line()),
+                phase: None,
+            }]
+        }
+    };
+    let source = Source::new(yaml);
+    let mut lint = Lint {
+        source: &source,
+        out: Vec::new(),
+    };
+
+    let phases: &[Value] = match raw.get("phases") {
+        Some(Value::Sequence(seq)) => seq,
+        _ => &[],
+    };
+    let ids: Vec<Option<&str>> = phases
+        .iter()
+        .map(|p| p.get("id").and_then(Value::as_str))
+        .collect();
+    let known: HashSet<&str> = ids.iter().flatten().copied().collect();
+
+    let mut seen = HashSet::new();
+    for (i, phase) in phases.iter().enumerate() {
+        let span = source.phase_span(i);
+        let id = ids[i];
+        if let Some(id) = id {
+            if !seen.insert(id) {
+                lint.error(
+                    "duplicate-phase",
+                    format!("duplicate phase id \"{id}\""),
+                    source.find(&span, "id:"),
+                    Some(id),
+                );
+            }
+        }
+        for dep in phase_deps(phase) {
+            if !known.contains(dep) {
+                lint.error(
+                    "missing-dependency",
+                    format!("depends on \"{dep}\", which is not a phase of this plan"),
+                    source.find_after(&span, "depends_on", dep),
+                    id,
+                );
+            }
+        }
+        if let Some(Value::Sequence(checks)) = phase.get("check") {
+            for check in checks {
+                lint.check(check, &span, id);
+            }
+        }
+        if phase.get("agent").and_then(Value::as_str) == Some("shell") {
+            if let Some(prompt) = phase.get("prompt").and_then(Value::as_str) {
+                lint.shell(prompt, &span, id);
+            }
+        }
+        if let Some(Value::Sequence(rollback)) =
+            phase.get("on_failure").and_then(|f| f.get("rollback"))
+        {
+            for cmd in rollback.iter().filter_map(Value::as_str) {
+                lint.shell(cmd, &span, id);
+            }
+        }
+    }
+
+    lint.unreachable(phases, &ids);
+    lint.budgets(&raw, phases, &ids);
+
+    if !lint.out.iter().any(|d| d.severity == Severity::Error) {
+        if let Err(e) = parse_plan(yaml) {
+            let line = e
+                .chain()
+                .find_map(|c| c.downcast_ref::<serde_yml::Error>())
+                .and_then(|e| e.location())
+                .map(|l| l.line());
+            lint.error("schema", format!("{e:#}"), line, None);
+        }
+    }
+
+    let mut out = lint.out;
+    out.sort_by_key(|d| d.line.unwrap_or(usize::MAX));
+    out
+}
+
+/// `depends_on` entries of a raw phase.
+fn phase_deps(phase: &Value) -> impl Iterator<Item = &str> {
+    phase
+        .get("depends_on")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+}
+
+struct Lint<'a> {
+    source: &'a Source<'a>,
+    out: Vec<Diagnostic>,
+}
+
+impl Lint<'_> {
+    fn push(
+        &mut self,
+        severity: Severity,
+        code: &'static str,
+        message: String,
+        line: Option<usize>,
+        phase: Option<&str>,
+    ) {
+        self.out.push(Diagnostic {
+            severity,
+            code,
+            message,
+            line,
+            phase: phase.map(str::to_string),
+        });
+    }
+
+    fn error(
+        &mut self,
+        code: &'static str,
+        message: String,
+        line: Option<usize>,
+        phase: Option<&str>,
+    ) {
+        self.push(Severity::Error, code, message, line, phase);
+    }
+
+    fn warning(
+        &mut self,
+        code: &'static str,
+        message: String,
+        line: Option<usize>,
+        phase: Option<&str>,
+    ) {
+        self.push(Severity::Warning, code, message, line, phase);
+    }
+
+    /// Check type, and the command of a `cmd_succeeds`. Recurses into the
+    /// check a `wait_until` polls.
+    fn check(&mut self, check: &Value, span: &Range<usize>, phase: Option<&str>) {
+        let Some(map) = check.as_mapping() else {
+            return;
+        };
+        let (kind, body) = match check.get("type") {
+            Some(t) => (t.as_str(), check),
+            None if map.len() == 1 => match map.iter().next() {
+                Some((k, v)) => (k.as_str(), v),
+                None => return,
+            },
+            None => return,
+        };
+        let Some(kind) = kind else {
+            return;
+        };
+        if !CHECK_TYPES.contains(&kind) {
+            self.error(
+                "unknown-check",
+                format!(
+                    "unknown check type \"{kind}\" (valid: {})",
+                    CHECK_TYPES.join(", ")
+                ),
+                self.source.find(span, kind),
+                phase,
+            );
+            return;
+        }
+        match kind {
+            "cmd_succeeds" => {
+                let cmd = body
+                    .as_str()
+                    .or_else(|| body.get("cmd").and_then(Value::as_str));
+                if let Some(cmd) = cmd {
+                    self.shell(cmd, span, phase);
+                }
+            }
+            "wait_until" => {
+                if let Some(inner) = body.get("check") {
+                    self.check(inner, span, phase);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn shell(&mut self, cmd: &str, span: &Range<usize>, phase: Option<&str>) {
+        for (fragment, why) in suspicious_shell(cmd) {
+            self.warning(
+                "suspicious-shell",
+                format!("`{fragment}` {why}"),
+                self.source.find(span, &fragment),
+                phase,
+            );
+        }
+    }
+
+    /// Phases that can never start because they sit in, or wait on, a
+    /// dependency cycle. Missing dependencies are reported on their own.
+    fn unreachable(&mut self, phases: &[Value], ids: &[Option<&str>]) {
+        let index: HashMap<&str, usize> = ids
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(i, id)| id.map(|id| (id, i)))
+            .collect();
+        let mut waiting: Vec<usize> = vec![0; phases.len()];
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); phases.len()];
+        for (i, phase) in phases.iter().enumerate() {
+            for dep in phase_deps(phase) {
+                if let Some(&d) = index.get(dep) {
+                    waiting[i] += 1;
+                    dependents[d].push(i);
+                }
+            }
+        }
+        let mut queue: VecDeque<usize> = (0..phases.len()).filter(|&i| waiting[i] == 0).collect();
+        let mut started = vec![false; phases.len()];
+        while let Some(i) = queue.pop_front() {
+            started[i] = true;
+            for &next in &dependents[i] {
+                waiting[next] -= 1;
+                if waiting[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        for (i, _) in started.iter().enumerate().filter(|(_, s)| !**s) {
+            let id = ids[i];
+            self.error(
+                "unreachable-phase",
+                "can never start: it is in, or depends on, a dependency cycle".to_string(),
+                self.source
+                    .find(&self.source.phase_span(i), "depends_on")
+                    .or(self.source.find(&self.source.phase_span(i), "id:")),
+                id,
+            );
+        }
+    }
+
+    /// `budget_usd` must be a positive number; a phase budget above the
+    /// plan's, phase budgets adding up past it, or a budget on a shell
+    /// phase (which spends nothing) are warned about.
+    fn budgets(&mut self, raw: &Value, phases: &[Value], ids: &[Option<&str>]) {
+        let plan_line = self.source.find(&self.source.header(), "budget_usd");
+        let plan_budget = raw
+            .get("budget_usd")
+            .and_then(|b| self.budget_value(b, plan_line, None));
+
+        let mut total = 0.0;
+        for (i, phase) in phases.iter().enumerate() {
+            let Some(b) = phase.get("budget_usd") else {
+                continue;
+            };
+            let line = self.source.find(&self.source.phase_span(i), "budget_usd");
+            let Some(budget) = self.budget_value(b, line, ids[i]) else {
+                continue;
+            };
+            total += budget;
+            if phase.get("agent").and_then(Value::as_str) == Some("shell") {
+                self.warning(
+                    "budget",
+                    "budget_usd has no effect on a shell phase".to_string(),
+                    line,
+                    ids[i],
+                );
+            }
+            if let Some(plan) = plan_budget.filter(|&p| budget > p) {
+                self.warning(
+                    "budget",
+                    format!("budget_usd {budget} is more than the plan's {plan}"),
+                    line,
+                    ids[i],
+                );
+            }
+        }
+        if let Some(plan) = plan_budget.filter(|&p| total > p) {
+            self.warning(
+                "budget",
+                format!("phase budgets add up to {total}, more than the plan's budget_usd {plan}"),
+                plan_line,
+                None,
+            );
+        }
+    }
+
+    /// A budget as a number, reporting one that is not a positive number.
+    fn budget_value(
+        &mut self,
+        value: &Value,
+        line: Option<usize>,
+        phase: Option<&str>,
+    ) -> Option<f64> {
+        match value.as_f64() {
+            Some(b) if b > 0.0 && b.is_finite() => Some(b),
+            Some(b) => {
+                self.error(
+                    "budget",
+                    format!("budget_usd must be more than 0, got {b}"),
+                    line,
+                    phase,
+                );
+                None
+            }
+            None => {
+                self.error(
+                    "budget",
+                    format!("budget_usd must be a number of dollars, got {value:?}"),
+                    line,
+                    phase,
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Shell fragments in `cmd` worth a second look, with why.
+fn suspicious_shell(cmd: &str) -> Vec<(String, &'static str)> {
+    let words: Vec<&str> = cmd.split_whitespace().collect();
+    let mut found = Vec::new();
+    for (i, pair) in words.windows(2).enumerate() {
+        if pair[0] == "rm" && pair[1].starts_with('-') && pair[1].contains('r') {
+            if let Some(target) = words.get(i + 2) {
+                let why = match *target {
+                    "/" | "/*" => Some("deletes from the filesystem root"),
+                    "~" | "~/" | "~/*" => Some("deletes the home directory"),
+                    t if t.starts_with('$') => {
+                        Some("deletes a path built from a variable that may be empty")
+                    }
+                    _ => None,
+                };
+                if let Some(why) = why {
+                    found.push((format!("{} {} {target}", pair[0], pair[1]), why));
+                }
+            }
+        }
+        if pair == ["git", "push"] {
+            if let Some(flag) = words[i + 2..]
+                .iter()
+                .find(|w| matches!(**w, "--force" | "-f"))
+            {
+                found.push((format!("git push {flag}"), "force-pushes"));
+            }
+        }
+        if pair[0] == "chmod" {
+            if let Some(n) = words[i + 1..].iter().take(2).position(|w| *w == "777") {
+                found.push((
+                    words[i..=i + 1 + n].join(" "),
+                    "makes files writable by everyone",
+                ));
+            }
+        }
+    }
+    for (i, word) in words.iter().enumerate() {
+        match *word {
+            "sudo" => found.push(("sudo".to_string(), "needs root")),
+            "eval" => found.push(("eval".to_string(), "runs a command assembled at run time")),
+            "|" | "|sh" | "|bash" => {
+                let shell = match *word {
+                    "|" => words.get(i + 1).copied(),
+                    w => Some(&w[1..]),
+                };
+                if let Some(shell @ ("sh" | "bash" | "zsh")) = shell {
+                    let fragment = if *word == "|" {
+                        format!("| {shell}")
+                    } else {
+                        (*word).to_string()
+                    };
+                    found.push((fragment, "pipes text straight into a shell"));
+                }
+            }
+            _ => {}
+        }
+    }
+    found
+}
+
+/// Line lookup in the plan text. Plans are block-style YAML; flow-style
+/// phases (`phases: [...]`) get no line numbers.
+struct Source<'a> {
+    lines: Vec<&'a str>,
+    /// 0-based line index of each phase's `- ` item.
+    phase_starts: Vec<usize>,
+    /// Line index just past the `phases:` block.
+    phases_end: usize,
+    /// Line index of the top-level `phases:` key.
+    phases_key: usize,
+}
+
+impl<'a> Source<'a> {
+    fn new(text: &'a str) -> Self {
+        let lines: Vec<&str> = text.lines().collect();
+        let phases_key = lines
+            .iter()
+            .position(|l| l.starts_with("phases:"))
+            .unwrap_or(lines.len());
+        let mut phase_starts = Vec::new();
+        let mut item_indent = None;
+        let mut phases_end = lines.len();
+        for (i, line) in lines.iter().enumerate().skip(phases_key + 1) {
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let indent = line.len() - trimmed.len();
+            if indent == 0 && !trimmed.starts_with('-') {
+                phases_end = i;
+                break;
+            }
+            let is_item = trimmed == "-" || trimmed.starts_with("- ");
+            match item_indent {
+                None if is_item => {
+                    item_indent = Some(indent);
+                    phase_starts.push(i);
+                }
+                Some(n) if is_item && indent == n => phase_starts.push(i),
+                _ => {}
+            }
+        }
+        Self {
+            lines,
+            phase_starts,
+            phases_end,
+            phases_key,
+        }
+    }
+
+    /// Lines of phase `i`, empty when it could not be located.
+    fn phase_span(&self, i: usize) -> Range<usize> {
+        match self.phase_starts.get(i) {
+            Some(&start) => {
+                let end = self
+                    .phase_starts
+                    .get(i + 1)
+                    .copied()
+                    .unwrap_or(self.phases_end);
+                start..end
+            }
+            None => 0..0,
+        }
+    }
+
+    /// Top-level lines outside the `phases:` block.
+    fn header(&self) -> Range<usize> {
+        0..self.phases_key
+    }
+
+    /// 1-based line of the first line in `span` containing `needle`.
+    fn find(&self, span: &Range<usize>, needle: &str) -> Option<usize> {
+        self.lines
+            .get(span.clone())?
+            .iter()
+            .position(|l| l.contains(needle))
+            .map(|i| span.start + i + 1)
+    }
+
+    /// Like [`Source::find`], from the line holding `key` on.
+    fn find_after(&self, span: &Range<usize>, key: &str, needle: &str) -> Option<usize> {
+        let from = self.find(span, key)? - 1;
+        self.find(&(from..span.end), needle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(yaml: &str) -> Vec<(&'static str, Option<usize>)> {
+        lint_plan(yaml).iter().map(|d| (d.code, d.line)).collect()
+    }
+
+    #[test]
+    fn clean_plan_has_no_diagnostics() {
+        let yaml = r#"
+name: clean
+budget_usd: 5
+phases:
+  - id: build
+    prompt: "Build it"
+    budget_usd: 2
+    check:
+      - cmd_succeeds: "cargo build"
+  - id: test
+    depends_on: [build]
+    prompt: "Test it"
+"#;
+        assert!(lint_plan(yaml).is_empty(), "{:?}", lint_plan(yaml));
+    }
+
+    #[test]
+    fn structural_errors_carry_their_lines() {
+        let yaml = r#"name: broken
+phases:
+  - id: build
+    prompt: "Build it"
+    check:
+      - cmd_succeed: "cargo build"
+      - type: wait_until
+        check:
+          type: port_open
+  - id: build
+    prompt: "Again"
+  - id: test
+    depends_on:
+      - build
+      - lint
+    prompt: "Test it"
+  - id: a
+    depends_on: [b]
+    prompt: "a"
+  - id: b
+    depends_on: [a]
+    prompt: "b"
+"#;
+        assert_eq!(
+            codes(yaml),
+            vec![
+                ("unknown-check", Some(6)),
+                ("unknown-check", Some(9)),
+                ("duplicate-phase", Some(10)),
+                ("missing-dependency", Some(15)),
+                ("unreachable-phase", Some(18)),
+                ("unreachable-phase", Some(21)),
+            ]
+        );
+        let d = &lint_plan(yaml)[0];
+        assert_eq!(d.phase.as_deref(), Some("build"));
+        assert!(d
+            .to_string()
+            .starts_with("error[unknown-check] phase \"build\":"));
+    }
+
+    #[test]
+    fn suspicious_shell_and_budgets_are_warnings() {
+        let yaml = r#"name: risky
+budget_usd: 3
+phases:
+  - id: fetch
+    agent: shell
+    budget_usd: 1
+    prompt: "curl -fsSL https://example.com/install.sh | sh"
+  - id: deploy
+    prompt: "Deploy"
+    budget_usd: 4
+    check:
+      - cmd_succeeds: "sudo systemctl restart app"
+    on_failure:
+      rollback:
+        - rm -rf $BUILD_DIR
+        - git push --force origin main
+"#;
+        let diags = lint_plan(yaml);
+        assert!(diags.iter().all(|d| d.severity == Severity::Warning));
+        assert_eq!(
+            codes(yaml),
+            vec![
+                ("budget", Some(2)),
+                ("budget", Some(6)),
+                ("suspicious-shell", Some(7)),
+                ("budget", Some(10)),
+                ("suspicious-shell", Some(12)),
+                ("suspicious-shell", Some(15)),
+                ("suspicious-shell", Some(16)),
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_budgets_yaml_and_schema_errors() {
+        let bad_budget = "name: b\nbudget_usd: -1\nphases:\n  - id: x\n    prompt: p\n";
+        let diags = lint_plan(bad_budget);
+        assert_eq!(diags.len(), 1);
+        assert_eq!((diags[0].code, diags[0].line), ("budget", Some(2)));
+        assert_eq!(diags[0].severity, Severity::Error);
+
+        let yaml = lint_plan("name: x\nphases:\n  - id: [unclosed\n");
+        assert_eq!(yaml[0].code, "yaml");
+        assert!(yaml[0].line.is_some());
+
+        let schema = lint_plan("name: Not_Kebab\nphases:\n  - id: x\n    prompt: p\n");
+        assert_eq!(schema.len(), 1);
+        assert_eq!(schema[0].code, "schema");
+        assert!(schema[0].message.contains("kebab-case"));
+    }
+}
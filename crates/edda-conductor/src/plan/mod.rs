@@ -1,3 +1,4 @@
+pub mod lint;
 pub mod parser;
 pub mod schema;
 pub mod topo;
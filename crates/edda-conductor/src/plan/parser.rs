@@ -1,4 +1,4 @@
-use crate::plan::schema::{CheckSpec, Plan};
+use crate::plan::schema::{AgentKind, CheckSpec, Plan};
 use anyhow::{bail, Context, Result};
 use std::path::Path;
 
@@ -141,10 +141,59 @@ fn normalize_one_check(check: &serde_yml::Value) -> Result<Option<serde_yml::Val
                 );
             }
         }
+        "approval" => {
+            // approval: "Deploy to prod"  or  approval: { title: ..., stages: [...] }
+            if let Some(m) = value.as_mapping() {
+                for (k, v) in m {
+                    out.insert(k.clone(), v.clone());
+                }
+            } else {
+                let title = value
+                    .as_str()
+                    .ok_or_else(|| anyhow::anyhow!("approval value must be string or mapping"))?;
+                out.insert(
+                    serde_yml::Value::String("title".into()),
+                    serde_yml::Value::String(title.into()),
+                );
+            }
+        }
+        "coverage_threshold" => {
+            // coverage_threshold: "lcov.info"  or  coverage_threshold: { path: ..., min_line: 80 }
+            if let Some(m) = value.as_mapping() {
+                for (k, v) in m {
+                    out.insert(k.clone(), v.clone());
+                }
+            } else {
+                let path = value.as_str().ok_or_else(|| {
+                    anyhow::anyhow!("coverage_threshold value must be string or mapping")
+                })?;
+                out.insert(
+                    serde_yml::Value::String("path".into()),
+                    serde_yml::Value::String(path.into()),
+                );
+            }
+        }
+        "cargo_test" => {
+            // cargo_test: my-crate  or  cargo_test: { package: ..., filter: ..., features: [...] }
+            if let Some(m) = value.as_mapping() {
+                for (k, v) in m {
+                    out.insert(k.clone(), v.clone());
+                }
+            } else if let Some(package) = value.as_str() {
+                out.insert(
+                    serde_yml::Value::String("package".into()),
+                    serde_yml::Value::String(package.into()),
+                );
+            } else if value.as_bool() != Some(true) {
+                bail!("cargo_test value must be a package name, true or a mapping");
+            }
+            // cargo_test: true → whole workspace
+        }
         other => {
             bail!(
                 "unknown check type: \"{other}\". Valid types: cmd_succeeds, file_exists, \
-                 file_contains, git_clean, edda_event, wait_until"
+                 file_contains, git_clean, edda_event, wait_until, approval, coverage_threshold, \
+                 cargo_test"
             );
         }
     }
@@ -155,10 +204,8 @@ fn normalize_one_check(check: &serde_yml::Value) -> Result<Option<serde_yml::Val
 /// Expand `${{ env.VAR_NAME }}` patterns in string values.
 fn expand_variables(value: &mut serde_yml::Value) {
     match value {
-        serde_yml::Value::String(s) => {
-            if s.contains("${{") {
-                *s = expand_env_vars(s);
-            }
+        serde_yml::Value::String(s) if s.contains("${{") => {
+            *s = expand_env_vars(s);
         }
         serde_yml::Value::Mapping(m) => {
             for (_, v) in m.iter_mut() {
@@ -238,7 +285,23 @@ fn validate_plan(plan: &Plan) -> Result<()> {
     }
 
     // Rule 5: no cycles (delegated to topo module, checked separately)
-    // Rule 6: wait_until cannot nest another wait_until
+    // Rule 7: `endpoint` is required by, and only meaningful for, http agents
+    for phase in &plan.phases {
+        match (phase.agent, &phase.endpoint) {
+            (AgentKind::Http, None) => {
+                bail!(
+                    "phase \"{}\" uses agent: http but has no endpoint",
+                    phase.id
+                )
+            }
+            (AgentKind::Claude | AgentKind::Shell, Some(_)) => bail!(
+                "phase \"{}\" sets endpoint but its agent is not http",
+                phase.id
+            ),
+            _ => {}
+        }
+    }
+    // Rule 6: wait_until cannot nest another wait_until or an approval
     for phase in &plan.phases {
         for check in &phase.check {
             validate_check_nesting(check)?;
@@ -253,6 +316,9 @@ fn validate_check_nesting(check: &CheckSpec) -> Result<()> {
         if matches!(inner.as_ref(), CheckSpec::WaitUntil { .. }) {
             bail!("wait_until cannot nest another wait_until");
         }
+        if matches!(inner.as_ref(), CheckSpec::Approval { .. }) {
+            bail!("wait_until cannot nest an approval (approval already polls)");
+        }
     }
     Ok(())
 }
@@ -273,7 +339,7 @@ fn is_kebab_case(s: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::plan::schema::OnFail;
+    use crate::plan::schema::{CoverageFormat, OnFail};
 
     #[test]
     fn parse_minimal_plan() {
@@ -286,6 +352,13 @@ phases:
         let plan = parse_plan(yaml).unwrap();
         assert_eq!(plan.name, "test");
         assert_eq!(plan.phases.len(), 1);
+        assert!(
+            plan.ledger_branch,
+            "plans record on their own ledger branch by default"
+        );
+
+        let opted_out = parse_plan(&format!("ledger_branch: false\n{yaml}")).unwrap();
+        assert!(!opted_out.ledger_branch);
     }
 
     #[test]
@@ -482,6 +555,175 @@ phases:
         assert!(err.to_string().contains("wait_until cannot nest"));
     }
 
+    #[test]
+    fn parse_approval_check_short_and_tagged() {
+        let yaml = r#"
+name: test
+phases:
+  - id: deploy
+    prompt: "Deploy"
+    check:
+      - approval: "Deploy to prod"
+      - type: approval
+        title: "Prod smoke test signed off"
+        stages: [ops, "lead:2"]
+        timeout_sec: 3600
+"#;
+        let plan = parse_plan(yaml).unwrap();
+        let checks = &plan.phases[0].check;
+        assert_eq!(
+            checks[0],
+            CheckSpec::Approval {
+                title: "Deploy to prod".into(),
+                stages: vec![],
+                interval_sec: 30,
+                timeout_sec: 86400,
+            }
+        );
+        match &checks[1] {
+            CheckSpec::Approval {
+                stages,
+                timeout_sec,
+                ..
+            } => {
+                assert_eq!(stages, &["ops", "lead:2"]);
+                assert_eq!(*timeout_sec, 3600);
+            }
+            other => panic!("expected approval, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_coverage_threshold_short_and_tagged() {
+        let yaml = r#"
+name: test
+phases:
+  - id: test
+    prompt: "Write tests"
+    check:
+      - coverage_threshold: coverage/lcov.info
+      - type: coverage_threshold
+        path: cobertura.xml
+        format: cobertura
+        min_line: 80
+        max_regression: 0.5
+        track: false
+"#;
+        let plan = parse_plan(yaml).unwrap();
+        let checks = &plan.phases[0].check;
+        assert_eq!(
+            checks[0],
+            CheckSpec::CoverageThreshold {
+                path: "coverage/lcov.info".into(),
+                format: None,
+                min_line: None,
+                min_branch: None,
+                max_regression: 0.0,
+                track: true,
+            }
+        );
+        assert_eq!(
+            checks[1],
+            CheckSpec::CoverageThreshold {
+                path: "cobertura.xml".into(),
+                format: Some(CoverageFormat::Cobertura),
+                min_line: Some(80.0),
+                min_branch: None,
+                max_regression: 0.5,
+                track: false,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_cargo_test_short_and_tagged() {
+        let yaml = r#"
+name: test
+phases:
+  - id: test
+    prompt: "Fix the ledger"
+    check:
+      - cargo_test: edda-ledger
+      - cargo_test: true
+      - type: cargo_test
+        package: edda-ledger
+        filter: append
+        features: [sqlite]
+"#;
+        let plan = parse_plan(yaml).unwrap();
+        let checks = &plan.phases[0].check;
+        assert_eq!(
+            checks[0],
+            CheckSpec::CargoTest {
+                package: Some("edda-ledger".into()),
+                filter: None,
+                features: vec![],
+                timeout_sec: 900,
+            }
+        );
+        assert!(matches!(
+            &checks[1],
+            CheckSpec::CargoTest { package: None, .. }
+        ));
+        assert_eq!(
+            checks[2],
+            CheckSpec::CargoTest {
+                package: Some("edda-ledger".into()),
+                filter: Some("append".into()),
+                features: vec!["sqlite".into()],
+                timeout_sec: 900,
+            }
+        );
+    }
+
+    #[test]
+    fn reject_approval_inside_wait_until() {
+        let yaml = r#"
+name: test
+phases:
+  - id: one
+    prompt: "x"
+    check:
+      - type: wait_until
+        check:
+          type: approval
+          title: "Ship it"
+"#;
+        let err = parse_plan(yaml).unwrap_err();
+        assert!(err.to_string().contains("cannot nest an approval"));
+    }
+
+    #[test]
+    fn mixed_agents_and_endpoint_rule() {
+        let yaml = r#"
+name: mixed
+phases:
+  - id: codegen
+    prompt: "Implement the parser"
+  - id: lint
+    agent: shell
+    prompt: "cargo clippy -- -D warnings"
+  - id: review
+    agent: http
+    endpoint: "http://localhost:8088/run"
+    prompt: "Review the diff"
+"#;
+        let plan = parse_plan(yaml).unwrap();
+        let agents: Vec<_> = plan.phases.iter().map(|p| p.agent).collect();
+        assert_eq!(
+            agents,
+            [AgentKind::Claude, AgentKind::Shell, AgentKind::Http]
+        );
+
+        let err = parse_plan("name: t\nphases:\n  - id: a\n    agent: http\n    prompt: x\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("has no endpoint"));
+        let err =
+            parse_plan("name: t\nphases:\n  - id: a\n    endpoint: http://x\n    prompt: x\n")
+                .unwrap_err();
+        assert!(err.to_string().contains("agent is not http"));
+    }
+
     #[test]
     fn on_fail_variants_deserialize() {
         for (input, expected) in [
@@ -21,10 +21,20 @@ pub struct Plan {
     pub timeout_sec: u64,
     #[serde(default)]
     pub on_fail: OnFail,
+    /// What to do when a human gate is reached during notify quiet hours.
+    #[serde(default)]
+    pub quiet_hours: QuietHoursPolicy,
     #[serde(default)]
     pub env: HashMap<String, String>,
     #[serde(default)]
     pub tags: Vec<String>,
+    /// Record the run's ledger events on a `plan/<name>` branch, merged back
+    /// into the starting branch only when the plan completes. On by default;
+    /// `false` records on the current branch. The branch moves the
+    /// workspace-wide ledger HEAD for the whole run, so other sessions
+    /// working meanwhile record onto the plan branch too.
+    #[serde(default = "default_true")]
+    pub ledger_branch: bool,
     pub phases: Vec<Phase>,
 }
 
@@ -46,6 +56,9 @@ pub struct Phase {
     pub timeout_sec: Option<u64>,
     #[serde(default)]
     pub on_fail: Option<OnFail>,
+    /// Cleanup run when the phase fails, before it is marked failed.
+    #[serde(default)]
+    pub on_failure: Option<OnFailure>,
     #[serde(default)]
     pub context: Option<String>,
     #[serde(default)]
@@ -56,6 +69,25 @@ pub struct Phase {
     pub allowed_tools: Option<Vec<String>>,
     #[serde(default = "default_permission_mode")]
     pub permission_mode: String,
+    /// Which agent runs the phase.
+    #[serde(default)]
+    pub agent: AgentKind,
+    /// URL the phase is POSTed to when `agent: http`.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+}
+
+/// How a phase's work gets done.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentKind {
+    /// `claude -p` with the phase prompt.
+    #[default]
+    Claude,
+    /// The phase prompt is run as a shell command; exit 0 means done.
+    Shell,
+    /// The phase is POSTed as JSON to `endpoint`, which answers with the result.
+    Http,
 }
 
 /// Failure policy for a phase.
@@ -71,6 +103,32 @@ pub enum OnFail {
     Abort,
 }
 
+/// What to run when a phase fails.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct OnFailure {
+    /// Shell commands run in order in the phase's cwd (e.g.
+    /// `git checkout -- .`). Every command runs even if an earlier one fails.
+    #[serde(default)]
+    pub rollback: Vec<String>,
+    /// Per-command timeout.
+    #[serde(default = "default_cmd_timeout")]
+    pub timeout_sec: u64,
+}
+
+/// Plan behavior at a human gate during notify quiet hours
+/// (`notify_quiet_hours` in `.edda/config.json`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuietHoursPolicy {
+    /// Queue the gate notification until quiet hours end; the runner stops
+    /// as usual.
+    #[default]
+    Queue,
+    /// Queue the notification and hold the plan at the gate until quiet
+    /// hours end, then resume gate handling.
+    Pause,
+}
+
 /// Check specification — what to verify after a phase completes.
 ///
 /// In YAML, checks can be written in short format (`cmd_succeeds: "cargo test"`)
@@ -109,6 +167,59 @@ pub enum CheckSpec {
         #[serde(default)]
         backoff: BackoffStrategy,
     },
+    /// Human sign-off: opens an `edda draft` with the given stages
+    /// (`role` or `role:min_approvals`; empty means policy routing) and
+    /// blocks until it is approved or rejected.
+    Approval {
+        title: String,
+        #[serde(default)]
+        stages: Vec<String>,
+        #[serde(default = "default_wait_interval")]
+        interval_sec: u64,
+        #[serde(default = "default_approval_timeout")]
+        timeout_sec: u64,
+    },
+    /// Parse a coverage report and fail below `min_line`/`min_branch`
+    /// percent, or when coverage drops more than `max_regression` points
+    /// below the last passing run recorded in the ledger (`track`).
+    CoverageThreshold {
+        path: String,
+        /// Report format; detected from the content when omitted.
+        #[serde(default)]
+        format: Option<CoverageFormat>,
+        #[serde(default)]
+        min_line: Option<f64>,
+        #[serde(default)]
+        min_branch: Option<f64>,
+        #[serde(default)]
+        max_regression: f64,
+        #[serde(default = "default_true")]
+        track: bool,
+    },
+    /// Run `cargo test` (optionally one `package`, a test name `filter` and
+    /// `features`) and record per-test results from libtest's JSON output.
+    CargoTest {
+        #[serde(default)]
+        package: Option<String>,
+        #[serde(default)]
+        filter: Option<String>,
+        #[serde(default)]
+        features: Vec<String>,
+        #[serde(default = "default_cargo_test_timeout")]
+        timeout_sec: u64,
+    },
+}
+
+/// Coverage report formats understood by `coverage_threshold`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageFormat {
+    /// lcov tracefile (`lcov.info`, `cargo llvm-cov --lcov`).
+    Lcov,
+    /// Cobertura XML (`cobertura.xml`, `cargo tarpaulin --out Xml`).
+    Cobertura,
+    /// cargo-tarpaulin JSON (`tarpaulin-report.json`).
+    Tarpaulin,
 }
 
 impl CheckSpec {
@@ -121,6 +232,9 @@ impl CheckSpec {
             CheckSpec::GitClean { .. } => "git_clean",
             CheckSpec::EddaEvent { .. } => "edda_event",
             CheckSpec::WaitUntil { .. } => "wait_until",
+            CheckSpec::Approval { .. } => "approval",
+            CheckSpec::CoverageThreshold { .. } => "coverage_threshold",
+            CheckSpec::CargoTest { .. } => "cargo_test",
         }
     }
 
@@ -133,6 +247,9 @@ impl CheckSpec {
             CheckSpec::GitClean { .. } => true,
             CheckSpec::EddaEvent { .. } => true,
             CheckSpec::WaitUntil { .. } => false, // already has internal retry
+            CheckSpec::Approval { .. } => false,  // a rejection needs a human, not a rerun
+            CheckSpec::CoverageThreshold { .. } => true,
+            CheckSpec::CargoTest { .. } => true,
         }
     }
 }
@@ -158,12 +275,21 @@ fn default_permission_mode() -> String {
 fn default_cmd_timeout() -> u64 {
     120
 }
+fn default_cargo_test_timeout() -> u64 {
+    900
+}
 fn default_wait_interval() -> u64 {
     30
 }
 fn default_wait_timeout() -> u64 {
     600
 }
+fn default_approval_timeout() -> u64 {
+    86400
+}
+fn default_true() -> bool {
+    true
+}
 
 #[cfg(test)]
 mod tests {
@@ -207,6 +333,7 @@ phases:
         assert_eq!(plan.max_attempts, 3);
         assert_eq!(plan.on_fail, OnFail::AutoRetry);
         assert_eq!(plan.phases[0].permission_mode, "bypassPermissions");
+        assert_eq!(plan.phases[0].agent, AgentKind::Claude);
         assert!(plan.purpose.is_none());
     }
 
@@ -242,6 +369,8 @@ phases:
       FOO: bar
     allowed_tools: [Read, Write]
     permission_mode: default
+    on_failure:
+      rollback: ["git checkout -- ."]
     check:
       - type: cmd_succeeds
         cmd: "cargo build"
@@ -254,5 +383,8 @@ phases:
         assert_eq!(phase.on_fail, Some(OnFail::Abort));
         assert_eq!(phase.check.len(), 2);
         assert_eq!(phase.env.get("FOO").unwrap(), "bar");
+        let on_failure = phase.on_failure.as_ref().unwrap();
+        assert_eq!(on_failure.rollback, ["git checkout -- ."]);
+        assert_eq!(on_failure.timeout_sec, 120);
     }
 }
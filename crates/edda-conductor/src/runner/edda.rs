@@ -4,6 +4,7 @@
 //! fails, the Conductor continues without context injection. This keeps
 //! Edda optional — the Conductor works as a plain task runner without it.
 
+use crate::state::machine::RollbackResult;
 use std::path::Path;
 use std::process::Command;
 
@@ -64,6 +65,72 @@ pub fn record_note(cwd: &Path, text: &str, tags: &[&str]) {
     let _ = cmd.status();
 }
 
+/// Run `edda <args>` and return its stdout, or `None` if it failed.
+fn run_edda(cwd: &Path, args: &[&str]) -> Option<String> {
+    Command::new("edda")
+        .args(args)
+        .current_dir(cwd)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+}
+
+/// Ledger branch a plan's events are recorded on.
+pub fn plan_branch_name(plan_name: &str) -> String {
+    format!("plan/{plan_name}")
+}
+
+/// The ledger's HEAD branch, from `edda status`.
+pub fn head_branch(cwd: &Path) -> Option<String> {
+    parse_head_branch(&run_edda(cwd, &["status"])?)
+}
+
+fn parse_head_branch(status: &str) -> Option<String> {
+    status
+        .lines()
+        .find_map(|l| l.trim().strip_prefix("On branch "))
+        .map(|b| b.trim().to_string())
+        .filter(|b| !b.is_empty())
+}
+
+/// Create `branch` if needed and make it the ledger HEAD, so phase notes and
+/// whatever the phase agents record land there. Returns `false` if the
+/// switch failed (the run then records on the current branch).
+pub fn enter_plan_branch(cwd: &Path, branch: &str, purpose: &str) -> bool {
+    if head_branch(cwd).as_deref() == Some(branch) {
+        return true;
+    }
+    // Fails harmlessly when the branch survives from an earlier run.
+    let _ = run_edda(cwd, &["branch", "create", branch, "-m", purpose]);
+    run_edda(cwd, &["switch", branch]).is_some()
+}
+
+/// Switch the ledger back to `base`. With `merge`, first fold the plan
+/// branch into it, its decisions winning any conflicting keys; otherwise
+/// its events stay on the plan branch only. `edda merge` refuses to write
+/// those decisions to a protected `base`, so the merge then reports `false`.
+pub fn leave_plan_branch(cwd: &Path, branch: &str, base: &str, merge: Option<&str>) -> bool {
+    if run_edda(cwd, &["switch", base]).is_none() {
+        return false;
+    }
+    match merge {
+        Some(reason) => run_edda(
+            cwd,
+            &["merge", branch, base, "-m", reason, "--strategy", "theirs"],
+        )
+        .is_some(),
+        None => true,
+    }
+}
+
+/// Record a phase start event.
+pub fn record_phase_start(cwd: &Path, phase_id: &str, attempt: u32) {
+    let text = format!("Phase \"{phase_id}\" started (attempt {attempt})");
+    record_note(cwd, &text, &["conductor", &format!("phase:{phase_id}")]);
+}
+
 /// Truncate a string to at most `max` bytes on a valid UTF-8 char boundary.
 fn truncate_str(s: &str, max: usize) -> &str {
     if s.len() <= max {
@@ -111,6 +178,24 @@ pub fn record_phase_failed(cwd: &Path, phase_id: &str, error: &str) {
     );
 }
 
+/// Record the rollback commands run after a phase failure, one line each.
+pub fn record_phase_rollback(cwd: &Path, phase_id: &str, results: &[RollbackResult]) {
+    let mut text = format!("Phase \"{phase_id}\" rolled back:");
+    for r in results {
+        let status = match r.exit_code {
+            Some(0) => "ok".to_string(),
+            Some(code) => format!("exit {code}"),
+            None => truncate_str(&r.output, 80).to_string(),
+        };
+        text.push_str(&format!("\n- `{}` ({status})", r.cmd));
+    }
+    record_note(
+        cwd,
+        &text,
+        &["conductor", &format!("phase:{phase_id}"), "rollback"],
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,6 +278,16 @@ mod tests {
         assert!(result.is_empty() || result.contains("CONTEXT"));
     }
 
+    #[test]
+    fn head_branch_parsed_from_status() {
+        assert_eq!(
+            parse_head_branch("On branch plan/nightly\n  commits: 3\n").as_deref(),
+            Some("plan/nightly")
+        );
+        assert_eq!(parse_head_branch("No .edda/ workspace found."), None);
+        assert_eq!(plan_branch_name("nightly"), "plan/nightly");
+    }
+
     #[test]
     fn truncate_str_ascii() {
         assert_eq!(truncate_str("hello", 10), "hello");
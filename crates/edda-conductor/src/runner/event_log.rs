@@ -34,6 +34,13 @@ pub enum Event {
         duration_ms: u64,
         error: String,
     },
+    /// `on_failure.rollback` commands ran before the phase was marked failed.
+    PhaseRollback {
+        phase_id: String,
+        attempt: u32,
+        commands: usize,
+        failed: usize,
+    },
     PhaseSkipped {
         phase_id: String,
         reason: String,
@@ -46,6 +53,17 @@ pub enum Event {
         phases_passed: usize,
         phases_pending: usize,
     },
+    /// A phase that passed before the run was interrupted had its checks
+    /// re-run by `edda conduct resume`.
+    PhaseRevalidated {
+        phase_id: String,
+        passed: bool,
+    },
+    /// Held at a human gate until notification quiet hours end.
+    PlanPaused {
+        phase_id: String,
+        resume_in_secs: u64,
+    },
 }
 
 /// Wrapper that adds sequence number and timestamp to each event.
@@ -1,4 +1,6 @@
 pub mod edda;
 pub mod event_log;
 pub mod notify;
+pub mod resume;
+pub mod rollback;
 pub mod sequential;
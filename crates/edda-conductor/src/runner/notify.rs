@@ -2,6 +2,17 @@
 #[async_trait::async_trait]
 pub trait Notifier: Send + Sync {
     async fn notify(&self, message: &str);
+
+    /// The plan is blocked on a human (retry/skip/abort). Notifiers that page
+    /// people route this differently from progress messages.
+    async fn notify_gate(&self, message: &str) {
+        self.notify(message).await;
+    }
+
+    /// Time left in the do-not-disturb window, if one is active.
+    fn quiet_remaining(&self) -> Option<std::time::Duration> {
+        None
+    }
 }
 
 /// Prints to stdout.
@@ -0,0 +1,210 @@
+//! Resuming an interrupted run: settle phases the conductor was cut off in,
+//! then re-run the checks of every passed phase so a resumed plan only skips
+//! work that still holds.
+
+use crate::check::engine::CheckEngine;
+use crate::plan::schema::Plan;
+use crate::plan::topo::topo_sort;
+use crate::runner::event_log::{Event, EventLogger};
+use crate::state::checkpoint::{load_checkpoint, remove_checkpoint};
+use crate::state::derive::derive_plan_status;
+use crate::state::machine::{PhaseStatus, PlanState, PlanStatus};
+use crate::state::persist::save_state;
+use anyhow::{bail, Result};
+use std::path::Path;
+
+/// What [`prepare_resume`] did to each phase.
+#[derive(Debug, Default)]
+pub struct ResumeReport {
+    /// Passed phases whose checks still pass; the resumed run skips them.
+    pub kept: Vec<String>,
+    /// Passed phases whose checks no longer pass, reset to pending.
+    pub invalidated: Vec<String>,
+    /// Phases cut off before they passed, reset to pending.
+    pub interrupted: Vec<String>,
+    /// Phases cut off after their checkpoint was written, restored as passed
+    /// (and then revalidated like any other passed phase).
+    pub recovered: Vec<String>,
+}
+
+/// Prepare `state` for a resumed run and save it.
+///
+/// Phases left running or checking are restored from their checkpoint when
+/// the interrupted attempt had already passed, and reset to pending
+/// otherwise. Every passed phase then has its checks re-run; a phase whose
+/// checks fail is reset to pending and its checkpoint dropped.
+pub async fn prepare_resume(
+    plan: &Plan,
+    state: &mut PlanState,
+    engine: &CheckEngine,
+    cwd: &Path,
+) -> Result<ResumeReport> {
+    if state.plan_status == PlanStatus::Aborted {
+        bail!("plan \"{}\" was aborted and cannot be resumed", plan.name);
+    }
+
+    let order = topo_sort(plan)?;
+    let mut event_log = EventLogger::new(cwd, &plan.name);
+    let mut report = ResumeReport::default();
+
+    for phase in order
+        .iter()
+        .filter_map(|id| plan.phases.iter().find(|p| p.id == *id))
+    {
+        let Ok(ps) = state.get_phase_mut(&phase.id) else {
+            continue;
+        };
+
+        if matches!(ps.status, PhaseStatus::Running | PhaseStatus::Checking) {
+            match load_checkpoint(cwd, &plan.name, &phase.id)? {
+                Some(cp) if cp.attempt == ps.attempts => {
+                    ps.status = PhaseStatus::Passed;
+                    ps.completed_at = Some(cp.completed_at);
+                    ps.checks = cp.checks;
+                    ps.error = None;
+                    report.recovered.push(phase.id.clone());
+                }
+                _ => {
+                    ps.status = PhaseStatus::Pending;
+                    ps.error = None;
+                    report.interrupted.push(phase.id.clone());
+                }
+            }
+        }
+
+        if ps.status != PhaseStatus::Passed {
+            continue;
+        }
+
+        let started_at = ps.started_at.clone();
+        let result = engine.run_all(&phase.check, started_at.as_deref()).await;
+        let ps = state.get_phase_mut(&phase.id)?;
+        ps.checks = result.results;
+        event_log.record(Event::PhaseRevalidated {
+            phase_id: phase.id.clone(),
+            passed: result.all_passed,
+        });
+        if result.all_passed {
+            report.kept.push(phase.id.clone());
+        } else {
+            ps.status = PhaseStatus::Pending;
+            ps.completed_at = None;
+            remove_checkpoint(cwd, &plan.name, &phase.id)?;
+            report.invalidated.push(phase.id.clone());
+        }
+    }
+
+    state.plan_status = derive_plan_status(&state.phases);
+    if state.plan_status != PlanStatus::Completed {
+        state.completed_at = None;
+    }
+    state.version += 1;
+    save_state(cwd, state)?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::parser::parse_plan;
+    use crate::state::checkpoint::{save_checkpoint, PhaseCheckpoint};
+    use crate::state::persist::load_state;
+
+    const PLAN: &str = r#"
+name: resumable
+phases:
+  - id: a
+    prompt: "write a"
+    check:
+      - type: file_exists
+        path: a.txt
+  - id: b
+    prompt: "write b"
+    depends_on: [a]
+    check:
+      - type: file_exists
+        path: b.txt
+  - id: c
+    prompt: "write c"
+    depends_on: [b]
+"#;
+
+    fn checkpoint(phase_id: &str, attempt: u32) -> PhaseCheckpoint {
+        PhaseCheckpoint {
+            phase_id: phase_id.into(),
+            attempt,
+            completed_at: "2026-01-01T00:00:00Z".into(),
+            cost_usd: None,
+            output: None,
+            checks: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_valid_phases_and_resets_the_rest() {
+        let dir = tempfile::tempdir().unwrap();
+        let plan = parse_plan(PLAN).unwrap();
+        let mut state = PlanState::from_plan(&plan, "plan.yaml");
+        for (id, status) in [("a", PhaseStatus::Passed), ("b", PhaseStatus::Passed)] {
+            let ps = state.get_phase_mut(id).unwrap();
+            ps.status = status;
+            ps.attempts = 1;
+            save_checkpoint(dir.path(), "resumable", &checkpoint(id, 1)).unwrap();
+        }
+        state.get_phase_mut("c").unwrap().status = PhaseStatus::Running;
+        std::fs::write(dir.path().join("a.txt"), "a").unwrap();
+
+        let engine = CheckEngine::new(dir.path().to_path_buf());
+        let report = prepare_resume(&plan, &mut state, &engine, dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(report.kept, vec!["a"]);
+        assert_eq!(report.invalidated, vec!["b"]);
+        assert_eq!(report.interrupted, vec!["c"]);
+        assert!(load_checkpoint(dir.path(), "resumable", "b")
+            .unwrap()
+            .is_none());
+
+        let saved = load_state(dir.path(), "resumable").unwrap().unwrap();
+        assert_eq!(saved.get_phase("a").unwrap().status, PhaseStatus::Passed);
+        assert_eq!(saved.get_phase("b").unwrap().status, PhaseStatus::Pending);
+        assert_eq!(saved.get_phase("c").unwrap().status, PhaseStatus::Pending);
+        assert_eq!(saved.plan_status, PlanStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn restores_phase_interrupted_after_its_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let plan = parse_plan(PLAN).unwrap();
+        let mut state = PlanState::from_plan(&plan, "plan.yaml");
+        let ps = state.get_phase_mut("a").unwrap();
+        ps.status = PhaseStatus::Checking;
+        ps.attempts = 2;
+        save_checkpoint(dir.path(), "resumable", &checkpoint("a", 2)).unwrap();
+        std::fs::write(dir.path().join("a.txt"), "a").unwrap();
+
+        let engine = CheckEngine::new(dir.path().to_path_buf());
+        let report = prepare_resume(&plan, &mut state, &engine, dir.path())
+            .await
+            .unwrap();
+
+        assert_eq!(report.recovered, vec!["a"]);
+        assert_eq!(report.kept, vec!["a"]);
+        assert_eq!(state.get_phase("a").unwrap().status, PhaseStatus::Passed);
+    }
+
+    #[tokio::test]
+    async fn aborted_plan_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let plan = parse_plan(PLAN).unwrap();
+        let mut state = PlanState::from_plan(&plan, "plan.yaml");
+        state.plan_status = PlanStatus::Aborted;
+
+        let engine = CheckEngine::new(dir.path().to_path_buf());
+        let err = prepare_resume(&plan, &mut state, &engine, dir.path())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("aborted"));
+    }
+}
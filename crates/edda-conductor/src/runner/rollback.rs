@@ -0,0 +1,86 @@
+//! Phase rollback: the `on_failure.rollback` commands a phase declares to
+//! undo its partial work (`git checkout -- .`, dropping a test database)
+//! before it is marked failed.
+
+use crate::check::cmd_succeeds::shell_cmd;
+use crate::check::masked_tail;
+use crate::plan::schema::OnFailure;
+use crate::state::machine::RollbackResult;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+
+/// Most output kept per rollback command.
+const MAX_OUTPUT_CHARS: usize = 2000;
+
+/// Run every rollback command in order, continuing past failures so a
+/// failed `git checkout` does not leave the test database behind.
+pub async fn run_rollback(on_failure: &OnFailure, cwd: &Path) -> Vec<RollbackResult> {
+    let mut results = Vec::with_capacity(on_failure.rollback.len());
+    for cmd in &on_failure.rollback {
+        results.push(run_one(cmd, on_failure.timeout_sec, cwd).await);
+    }
+    results
+}
+
+async fn run_one(cmd: &str, timeout_sec: u64, cwd: &Path) -> RollbackResult {
+    let start = Instant::now();
+    let (shell, args) = shell_cmd(cmd);
+    let output = Command::new(&shell)
+        .args(&args)
+        .current_dir(cwd)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .kill_on_drop(true)
+        .output();
+
+    let (exit_code, output) =
+        match tokio::time::timeout(Duration::from_secs(timeout_sec), output).await {
+            Ok(Ok(out)) => {
+                let mut text = String::from_utf8_lossy(&out.stdout).into_owned();
+                text.push_str(&String::from_utf8_lossy(&out.stderr));
+                (
+                    Some(out.status.code().unwrap_or(-1)),
+                    masked_tail(&text, MAX_OUTPUT_CHARS),
+                )
+            }
+            Ok(Err(e)) => (None, format!("spawn error: {e}")),
+            Err(_) => (None, format!("timed out after {timeout_sec}s")),
+        };
+    RollbackResult {
+        cmd: cmd.to_string(),
+        exit_code,
+        output,
+        duration_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_every_command_and_captures_output() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("scratch.db"), "x").unwrap();
+        let on_failure = OnFailure {
+            rollback: vec![
+                "echo restoring; exit 1".into(),
+                "rm scratch.db && echo dropped".into(),
+                "sleep 5".into(),
+            ],
+            timeout_sec: 1,
+        };
+
+        let results = run_rollback(&on_failure, dir.path()).await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].exit_code, Some(1));
+        assert_eq!(results[0].output, "restoring");
+        assert!(results[1].succeeded());
+        assert_eq!(results[1].output, "dropped");
+        assert!(!dir.path().join("scratch.db").exists());
+        assert_eq!(results[2].exit_code, None);
+        assert!(results[2].output.contains("timed out"));
+    }
+}
@@ -1,18 +1,20 @@
 use crate::agent::budget::BudgetTracker;
 use crate::agent::launcher::{phase_session_id_attempt, AgentLauncher, PhaseResult};
 use crate::check::engine::{CheckEngine, CheckRunResult};
-use crate::plan::schema::{CheckSpec, OnFail, Plan};
+use crate::plan::schema::{CheckSpec, OnFail, Phase, Plan, QuietHoursPolicy};
 use crate::plan::topo::topo_sort;
 use crate::runner::edda;
 use crate::runner::event_log::{self, Event, EventLogger};
 use crate::runner::notify::Notifier;
+use crate::runner::rollback::run_rollback;
 use crate::state::brief::write_brief;
+use crate::state::checkpoint::{save_checkpoint, PhaseCheckpoint};
 use crate::state::derive::{
     detect_stale_phases, find_next_phase, is_plan_blocked, is_plan_complete, update_plan_status,
 };
 use crate::state::machine::{
     transition, CheckResult, CheckStatus, ErrorInfo, ErrorType, PhaseStatus, PhaseUpdate,
-    PlanState, PlanStatus,
+    PlanState, PlanStatus, RollbackResult,
 };
 use crate::state::persist::save_state;
 use crate::tmux::TmuxSession;
@@ -37,7 +39,79 @@ pub struct RunContext<'a> {
 }
 
 /// Run a plan sequentially. The main conductor loop.
+///
+/// The run records on its own ledger branch (see [`Plan::ledger_branch`]),
+/// so a failed run's notes and decisions stay out of the branch it started
+/// from. HEAD goes back to that branch whether the run completes or fails;
+/// a killed process cannot do it, and the next `edda conduct resume` or a
+/// manual `edda switch` restores HEAD then.
 pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -> Result<()> {
+    let cwd = ctx.cwd;
+
+    // Initialize edda ledger if available
+    let root = cwd.to_path_buf();
+    tokio::task::spawn_blocking(move || edda::ensure_init(&root)).await?;
+
+    if plan.ledger_branch {
+        enter_ledger_branch(plan, state, cwd).await?;
+    }
+
+    let result = drive_plan(plan, state, ctx).await;
+
+    // Leave the plan branch: merged on completion, parked otherwise (a
+    // resumed run switches back to it).
+    if let (Some(branch), Some(base)) = (state.ledger_branch.clone(), state.base_branch.clone()) {
+        let completed = result.is_ok() && state.plan_status == PlanStatus::Completed;
+        let reason = completed.then(|| format!("plan \"{}\" completed", plan.name));
+        let (root, b, base_) = (cwd.to_path_buf(), branch.clone(), base.clone());
+        let left = tokio::task::spawn_blocking(move || {
+            edda::leave_plan_branch(&root, &b, &base_, reason.as_deref())
+        })
+        .await?;
+        if left && completed {
+            println!("  ⎇ Merged ledger branch {branch} into {base}");
+        } else if completed {
+            // `edda merge` refuses protected destinations; those go
+            // through `edda draft propose` instead.
+            println!(
+                "  ⎇ Ledger branch {branch} was not merged into {base}; its events stay there"
+            );
+        }
+    }
+    result?;
+
+    event_log::write_runner_status(cwd, state, None);
+    write_brief(cwd, state, None);
+    Ok(())
+}
+
+/// Create the plan's ledger branch if needed and make it HEAD, remembering
+/// the branch the run started from. Skipped when HEAD cannot be read.
+async fn enter_ledger_branch(plan: &Plan, state: &mut PlanState, cwd: &Path) -> Result<()> {
+    let branch = edda::plan_branch_name(&plan.name);
+    if state.base_branch.is_none() {
+        let root = cwd.to_path_buf();
+        let head = tokio::task::spawn_blocking(move || edda::head_branch(&root)).await?;
+        state.base_branch = head.filter(|b| *b != branch);
+    }
+    if state.base_branch.is_none() {
+        return Ok(());
+    }
+    let purpose = plan
+        .purpose
+        .as_deref()
+        .or(plan.description.as_deref())
+        .unwrap_or("conductor plan run")
+        .to_string();
+    let (root, b) = (cwd.to_path_buf(), branch.clone());
+    let entered =
+        tokio::task::spawn_blocking(move || edda::enter_plan_branch(&root, &b, &purpose)).await?;
+    state.ledger_branch = entered.then_some(branch);
+    save_state(cwd, state)
+}
+
+/// Run phases until the plan completes, blocks, aborts or is cancelled.
+async fn drive_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -> Result<()> {
     let RunContext {
         launcher,
         check_engine,
@@ -53,9 +127,6 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
     let total_phases = order.len();
     let mut event_log = EventLogger::new(cwd, &plan.name).with_stdout_json(json_events);
 
-    // Initialize edda ledger if available
-    edda::ensure_init(cwd);
-
     // Detect stale phases from previous run
     detect_stale_phases(state, plan);
 
@@ -75,7 +146,10 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
     loop {
         // 1. Check termination
         if cancel.is_cancelled() {
-            println!("Shutdown. Run `edda conduct run` to resume.");
+            println!(
+                "Shutdown. Run `edda conduct resume {}` to resume.",
+                plan.name
+            );
             break;
         }
 
@@ -92,6 +166,28 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                 .find(|p| p.status == PhaseStatus::Failed || p.status == PhaseStatus::Stale);
             let failed_id = failed.map(|f| f.id.clone()).unwrap_or_default();
 
+            // Nobody should be paged at 3am: hold the plan at the gate until
+            // quiet hours end. Gate notifications raised meanwhile are queued
+            // by the notifier and flushed by the next one after the window.
+            if plan.quiet_hours == QuietHoursPolicy::Pause {
+                if let Some(left) = notifier.quiet_remaining() {
+                    println!(
+                        "  ☾ Quiet hours: holding at \"{failed_id}\" for {}",
+                        format_elapsed(left)
+                    );
+                    event_log.record(Event::PlanPaused {
+                        phase_id: failed_id.clone(),
+                        resume_in_secs: left.as_secs(),
+                    });
+                    event_log::write_runner_status(cwd, state, None);
+                    tokio::select! {
+                        _ = cancel.cancelled() => {}
+                        _ = tokio::time::sleep(left) => {}
+                    }
+                    continue;
+                }
+            }
+
             if interactive {
                 match prompt_blocked_action(&failed_id) {
                     BlockedAction::Retry => {
@@ -138,13 +234,13 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                         break;
                     }
                     BlockedAction::Quit => {
-                        println!("Paused. Run `edda conduct run` to resume.");
+                        println!("Paused. Run `edda conduct resume {}` to resume.", plan.name);
                         break;
                     }
                 }
             } else {
                 notifier
-                    .notify(&format!(
+                    .notify_gate(&format!(
                         "Plan blocked: phase \"{}\" is {:?}. Use retry/skip/abort.",
                         failed_id,
                         failed.map(|f| f.status),
@@ -207,6 +303,7 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
             phase_id: phase_id.clone(),
             attempt,
         });
+        edda::record_phase_start(cwd, &phase_id, attempt);
         event_log::write_runner_status(cwd, state, Some(&phase_id));
         write_brief(cwd, state, None);
 
@@ -270,6 +367,19 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                             ..Default::default()
                         }),
                     )?;
+                    let passed = state.get_phase(&phase_id)?;
+                    save_checkpoint(
+                        cwd,
+                        &plan.name,
+                        &PhaseCheckpoint {
+                            phase_id: phase_id.clone(),
+                            attempt,
+                            completed_at: passed.completed_at.clone().unwrap_or_default(),
+                            cost_usd,
+                            output: result_text.clone(),
+                            checks: passed.checks.clone(),
+                        },
+                    )?;
                     let elapsed_ms = phase_start.elapsed().as_millis() as u64;
                     println!(
                         "  ✓ Phase \"{phase_id}\" passed ({})",
@@ -288,6 +398,8 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                         cost_usd,
                     });
                 } else {
+                    let rollback =
+                        rollback_phase(phase, attempt, cwd, &phase_cwd, &mut event_log).await;
                     transition(
                         state,
                         &phase_id,
@@ -296,6 +408,7 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                         Some(PhaseUpdate {
                             checks: Some(check_result.results.clone()),
                             error: check_result.error.clone(),
+                            rollback,
                             ..Default::default()
                         }),
                     )?;
@@ -332,6 +445,8 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                 }
             }
             PhaseResult::Timeout => {
+                let rollback =
+                    rollback_phase(phase, attempt, cwd, &phase_cwd, &mut event_log).await;
                 transition(
                     state,
                     &phase_id,
@@ -345,6 +460,7 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                             check_index: None,
                             timestamp: now_rfc3339(),
                         }),
+                        rollback,
                         ..Default::default()
                     }),
                 )?;
@@ -365,6 +481,8 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                 });
             }
             PhaseResult::AgentCrash { error } => {
+                let rollback =
+                    rollback_phase(phase, attempt, cwd, &phase_cwd, &mut event_log).await;
                 transition(
                     state,
                     &phase_id,
@@ -378,6 +496,7 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                             check_index: None,
                             timestamp: now_rfc3339(),
                         }),
+                        rollback,
                         ..Default::default()
                     }),
                 )?;
@@ -420,6 +539,8 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                 }
                 let elapsed_ms = phase_start.elapsed().as_millis() as u64;
                 let msg = format!("{result:?}");
+                let rollback =
+                    rollback_phase(phase, attempt, cwd, &phase_cwd, &mut event_log).await;
                 transition(
                     state,
                     &phase_id,
@@ -433,6 +554,7 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
                             check_index: None,
                             timestamp: now_rfc3339(),
                         }),
+                        rollback,
                         ..Default::default()
                     }),
                 )?;
@@ -471,12 +593,42 @@ pub async fn run_plan(plan: &Plan, state: &mut PlanState, ctx: RunContext<'_>) -
             ))
             .await;
     }
-
-    event_log::write_runner_status(cwd, state, None);
-    write_brief(cwd, state, None);
     Ok(())
 }
 
+/// Run the phase's `on_failure.rollback` commands, if it declares any, and
+/// record their output before the caller marks the phase failed.
+async fn rollback_phase(
+    phase: &Phase,
+    attempt: u32,
+    cwd: &Path,
+    phase_cwd: &Path,
+    event_log: &mut EventLogger,
+) -> Option<Vec<RollbackResult>> {
+    let on_failure = phase
+        .on_failure
+        .as_ref()
+        .filter(|f| !f.rollback.is_empty())?;
+    println!(
+        "  ↺ Rolling back phase \"{}\" ({} command(s))",
+        phase.id,
+        on_failure.rollback.len()
+    );
+    let results = run_rollback(on_failure, phase_cwd).await;
+    let failed: Vec<&RollbackResult> = results.iter().filter(|r| !r.succeeded()).collect();
+    for r in &failed {
+        println!("    ✗ {}: {}", r.cmd, r.output.lines().last().unwrap_or(""));
+    }
+    edda::record_phase_rollback(cwd, &phase.id, &results);
+    event_log.record(Event::PhaseRollback {
+        phase_id: phase.id.clone(),
+        attempt,
+        commands: results.len(),
+        failed: failed.len(),
+    });
+    Some(results)
+}
+
 async fn handle_on_fail(
     plan: &Plan,
     phase: &crate::plan::schema::Phase,
@@ -514,7 +666,7 @@ async fn handle_on_fail(
                 println!("  ↻ Auto-retrying ({attempts}/{max})");
             } else {
                 notifier
-                    .notify(&format!(
+                    .notify_gate(&format!(
                         "Phase \"{phase_id}\" failed after {max} attempts. Retry, skip, or abort?"
                     ))
                     .await;
@@ -551,7 +703,7 @@ async fn handle_on_fail(
         }
         OnFail::Ask => {
             notifier
-                .notify(&format!(
+                .notify_gate(&format!(
                     "Phase \"{phase_id}\" failed. Retry, skip, or abort?"
                 ))
                 .await;
@@ -623,6 +775,12 @@ fn format_check_failures(results: &[CheckResult]) -> String {
             r.check_type,
             r.detail.as_deref().unwrap_or("(no detail)"),
         ));
+        for failure in r.tests.iter().flat_map(|t| &t.failures) {
+            out.push_str(&format!("  - {}\n", failure.name));
+            for line in failure.output.lines() {
+                out.push_str(&format!("    {line}\n"));
+            }
+        }
     }
     out
 }
@@ -870,6 +1028,79 @@ phases:
         assert!(msgs.iter().any(|m| m.contains("failed after 2 attempts")));
     }
 
+    /// Reports quiet hours for the first `quiet_checks` queries.
+    struct QuietNotifier {
+        inner: CollectNotifier,
+        quiet_checks: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl Notifier for QuietNotifier {
+        async fn notify(&self, message: &str) {
+            self.inner.notify(message).await;
+        }
+
+        fn quiet_remaining(&self) -> Option<std::time::Duration> {
+            use std::sync::atomic::Ordering;
+            let left = self.quiet_checks.load(Ordering::SeqCst);
+            if left == 0 {
+                return None;
+            }
+            self.quiet_checks.store(left - 1, Ordering::SeqCst);
+            Some(std::time::Duration::from_millis(10))
+        }
+    }
+
+    #[tokio::test]
+    async fn pause_policy_holds_gate_until_quiet_hours_end() {
+        let yaml = r#"
+name: test
+on_fail: ask
+quiet_hours: pause
+phases:
+  - id: a
+    prompt: "crash"
+"#;
+        let plan = parse_plan(yaml).unwrap();
+        assert_eq!(plan.quiet_hours, QuietHoursPolicy::Pause);
+        let launcher = MockLauncher::new();
+        launcher.set_results("a", vec![PhaseResult::AgentCrash { error: "x".into() }]);
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = PlanState::from_plan(&plan, "test.yaml");
+        let engine = CheckEngine::new(dir.path().to_path_buf());
+        let notifier = QuietNotifier {
+            inner: CollectNotifier::new(),
+            quiet_checks: std::sync::atomic::AtomicU32::new(2),
+        };
+        let mut budget = BudgetTracker::new(plan.budget_usd);
+
+        run_plan(
+            &plan,
+            &mut state,
+            RunContext {
+                launcher: &launcher,
+                check_engine: &engine,
+                notifier: &notifier,
+                budget: &mut budget,
+                cancel: CancellationToken::new(),
+                cwd: dir.path(),
+                interactive: false,
+                json_events: false,
+                tmux_session: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let log =
+            std::fs::read_to_string(dir.path().join(".edda/conductor/test/events.jsonl")).unwrap();
+        assert_eq!(log.matches("\"plan_paused\"").count(), 2);
+        // Once quiet hours end the gate is handled as usual.
+        assert_eq!(state.plan_status, PlanStatus::Blocked);
+        let msgs = notifier.inner.messages();
+        assert!(msgs.iter().any(|m| m.contains("Plan blocked")));
+    }
+
     #[tokio::test]
     async fn on_fail_skip() {
         let yaml = r#"
@@ -895,6 +1126,33 @@ phases:
         assert_eq!(state.plan_status, PlanStatus::Completed);
     }
 
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn failed_checks_run_rollback_before_marking_failed() {
+        let yaml = r#"
+name: test
+on_fail: abort
+phases:
+  - id: a
+    prompt: "write the report"
+    check:
+      - file_exists: report.txt
+    on_failure:
+      rollback:
+        - "echo undone"
+        - "exit 4"
+"#;
+        let launcher = MockLauncher::new();
+        let (state, _) = run_test_plan(yaml, &launcher).await;
+
+        let phase = &state.phases[0];
+        assert_eq!(phase.status, PhaseStatus::Failed);
+        assert_eq!(phase.rollback.len(), 2);
+        assert!(phase.rollback[0].succeeded());
+        assert_eq!(phase.rollback[0].output, "undone");
+        assert_eq!(phase.rollback[1].exit_code, Some(4));
+    }
+
     #[tokio::test]
     async fn on_fail_abort() {
         let yaml = r#"
@@ -1069,12 +1327,14 @@ phases:
                 status: CheckStatus::Passed,
                 detail: None,
                 duration_ms: 0,
+                tests: None,
             },
             CheckResult {
                 check_type: "cmd_succeeds".into(),
                 status: CheckStatus::Failed,
                 detail: Some("exit 1: test failed".into()),
                 duration_ms: 100,
+                tests: None,
             },
         ];
         let out = format_check_failures(&results);
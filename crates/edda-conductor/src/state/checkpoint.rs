@@ -0,0 +1,100 @@
+//! Per-phase checkpoints: what a passed phase produced, written when it
+//! passes so `edda conduct resume` can tell finished work from work that was
+//! cut off mid-phase.
+
+use crate::state::machine::CheckResult;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Record of one passed phase attempt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseCheckpoint {
+    pub phase_id: String,
+    pub attempt: u32,
+    pub completed_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+    /// Final result text the agent reported for the phase.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    #[serde(default)]
+    pub checks: Vec<CheckResult>,
+}
+
+/// Compute the checkpoint file path for a phase.
+/// Location: `{cwd}/.edda/conductor/{plan_name}/checkpoints/{phase_id}.json`
+pub fn checkpoint_path(cwd: &Path, plan_name: &str, phase_id: &str) -> PathBuf {
+    cwd.join(".edda")
+        .join("conductor")
+        .join(plan_name)
+        .join("checkpoints")
+        .join(format!("{phase_id}.json"))
+}
+
+/// Load a phase checkpoint. Returns None if the file doesn't exist.
+pub fn load_checkpoint(
+    cwd: &Path,
+    plan_name: &str,
+    phase_id: &str,
+) -> Result<Option<PhaseCheckpoint>> {
+    let path = checkpoint_path(cwd, plan_name, phase_id);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("reading checkpoint: {}", path.display()))?;
+    let checkpoint = serde_json::from_str(&content)
+        .with_context(|| format!("parsing checkpoint: {}", path.display()))?;
+    Ok(Some(checkpoint))
+}
+
+/// Save a phase checkpoint atomically.
+pub fn save_checkpoint(cwd: &Path, plan_name: &str, checkpoint: &PhaseCheckpoint) -> Result<()> {
+    let path = checkpoint_path(cwd, plan_name, &checkpoint.phase_id);
+    let data = serde_json::to_string_pretty(checkpoint)?;
+    edda_store::write_atomic(&path, data.as_bytes())
+        .with_context(|| format!("saving checkpoint: {}", path.display()))?;
+    Ok(())
+}
+
+/// Remove a phase checkpoint. Missing files are not an error.
+pub fn remove_checkpoint(cwd: &Path, plan_name: &str, phase_id: &str) -> Result<()> {
+    let path = checkpoint_path(cwd, plan_name, phase_id);
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e).with_context(|| format!("removing checkpoint: {}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(phase_id: &str) -> PhaseCheckpoint {
+        PhaseCheckpoint {
+            phase_id: phase_id.into(),
+            attempt: 2,
+            completed_at: "2026-01-01T00:00:00Z".into(),
+            cost_usd: Some(0.25),
+            output: Some("done".into()),
+            checks: vec![],
+        }
+    }
+
+    #[test]
+    fn save_load_remove_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_checkpoint(dir.path(), "p", "a").unwrap().is_none());
+
+        save_checkpoint(dir.path(), "p", &sample("a")).unwrap();
+        let loaded = load_checkpoint(dir.path(), "p", "a").unwrap().unwrap();
+        assert_eq!(loaded.attempt, 2);
+        assert_eq!(loaded.output.as_deref(), Some("done"));
+
+        remove_checkpoint(dir.path(), "p", "a").unwrap();
+        assert!(load_checkpoint(dir.path(), "p", "a").unwrap().is_none());
+        remove_checkpoint(dir.path(), "p", "a").unwrap();
+    }
+}
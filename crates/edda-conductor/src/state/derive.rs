@@ -132,6 +132,7 @@ mod tests {
                 error: None,
                 skip_reason: None,
                 retry_context: None,
+                rollback: Vec::new(),
             })
             .collect()
     }
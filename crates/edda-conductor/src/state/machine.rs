@@ -42,6 +42,13 @@ pub struct PlanState {
     pub aborted_at: Option<String>,
     #[serde(default)]
     pub total_cost_usd: f64,
+    /// Ledger branch the run records on (`plan/<name>`), once created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ledger_branch: Option<String>,
+    /// Branch the ledger was on when the run started; the plan branch merges
+    /// back into it on completion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_branch: Option<String>,
     pub phases: Vec<PhaseState>,
     #[serde(default)]
     pub version: u32,
@@ -66,6 +73,9 @@ pub struct PhaseState {
     /// Error context from previous attempt, injected into retry prompt.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub retry_context: Option<String>,
+    /// `on_failure.rollback` commands run after the latest failure.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rollback: Vec<RollbackResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,6 +105,47 @@ pub struct CheckResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub detail: Option<String>,
     pub duration_ms: u64,
+    /// Per-test outcome, for checks that run a test suite (`cargo_test`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tests: Option<TestReport>,
+}
+
+/// Test counts and failures parsed from a test run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TestReport {
+    pub passed: u64,
+    pub failed: u64,
+    pub ignored: u64,
+    pub filtered_out: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<TestFailure>,
+}
+
+/// One failed test and the tail of its captured output, secrets masked.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TestFailure {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub output: String,
+}
+
+/// Outcome of one rollback command.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RollbackResult {
+    pub cmd: String,
+    /// `None` when the command could not be spawned or timed out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Tail of combined stdout and stderr, secrets masked.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub output: String,
+    pub duration_ms: u64,
+}
+
+impl RollbackResult {
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -148,6 +199,7 @@ pub struct PhaseUpdate {
     pub error: Option<ErrorInfo>,
     pub skip_reason: Option<String>,
     pub retry_context: Option<Option<String>>,
+    pub rollback: Option<Vec<RollbackResult>>,
 }
 
 impl PhaseUpdate {
@@ -173,6 +225,9 @@ impl PhaseUpdate {
         if let Some(v) = self.retry_context {
             phase.retry_context = v;
         }
+        if let Some(v) = self.rollback {
+            phase.rollback = v;
+        }
     }
 }
 
@@ -220,6 +275,7 @@ impl PlanState {
                 error: None,
                 skip_reason: None,
                 retry_context: None,
+                rollback: Vec::new(),
             })
             .collect();
 
@@ -231,6 +287,8 @@ impl PlanState {
             completed_at: None,
             aborted_at: None,
             total_cost_usd: 0.0,
+            ledger_branch: None,
+            base_branch: None,
             phases,
             version: 0,
         }
@@ -249,6 +307,42 @@ impl PlanState {
             .find(|p| p.id == id)
             .ok_or_else(|| anyhow::anyhow!("phase not found: \"{id}\""))
     }
+
+    /// Reset a Failed or Stale phase to Pending so the runner picks it up
+    /// again. Unblocks the plan if it was Blocked.
+    pub fn retry_phase(&mut self, id: &str) -> Result<()> {
+        let current = self.get_phase(id)?.status;
+        if current != PhaseStatus::Failed && current != PhaseStatus::Stale {
+            bail!("Phase \"{id}\" is {current:?}, not Failed or Stale. Cannot retry.");
+        }
+        transition(self, id, current, PhaseStatus::Pending, None)?;
+        if self.plan_status == PlanStatus::Blocked {
+            self.plan_status = PlanStatus::Running;
+        }
+        Ok(())
+    }
+
+    /// Mark a Failed, Stale, or Pending phase as Skipped.
+    /// Unblocks the plan if it was Blocked.
+    pub fn skip_phase(&mut self, id: &str, reason: Option<&str>) -> Result<()> {
+        let ps = self.get_phase_mut(id)?;
+        if !matches!(
+            ps.status,
+            PhaseStatus::Failed | PhaseStatus::Stale | PhaseStatus::Pending
+        ) {
+            bail!(
+                "Phase \"{id}\" is {:?}. Can only skip Failed, Stale, or Pending phases.",
+                ps.status
+            );
+        }
+        ps.status = PhaseStatus::Skipped;
+        ps.skip_reason = Some(reason.unwrap_or("manually skipped").to_string());
+        self.version += 1;
+        if self.plan_status == PlanStatus::Blocked {
+            self.plan_status = PlanStatus::Running;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -454,4 +548,31 @@ phases:
         assert_eq!(restored.plan_name, "test");
         assert_eq!(restored.phases.len(), 2);
     }
+
+    #[test]
+    fn retry_phase_resets_failed_and_unblocks_plan() {
+        let plan = test_plan();
+        let mut state = PlanState::from_plan(&plan, "plan.yaml");
+        state.phases[0].status = PhaseStatus::Failed;
+        state.plan_status = PlanStatus::Blocked;
+
+        state.retry_phase("a").unwrap();
+        assert_eq!(state.phases[0].status, PhaseStatus::Pending);
+        assert_eq!(state.plan_status, PlanStatus::Running);
+
+        // Pending → retry is rejected
+        assert!(state.retry_phase("a").is_err());
+    }
+
+    #[test]
+    fn skip_phase_rejects_running() {
+        let plan = test_plan();
+        let mut state = PlanState::from_plan(&plan, "plan.yaml");
+        state.phases[0].status = PhaseStatus::Running;
+        assert!(state.skip_phase("a", None).is_err());
+
+        state.skip_phase("b", Some("not needed")).unwrap();
+        assert_eq!(state.phases[1].status, PhaseStatus::Skipped);
+        assert_eq!(state.phases[1].skip_reason.as_deref(), Some("not needed"));
+    }
 }
@@ -1,4 +1,5 @@
 pub mod brief;
+pub mod checkpoint;
 pub mod derive;
 pub mod machine;
 pub mod persist;
@@ -24,6 +24,25 @@ pub fn load_state(cwd: &Path, plan_name: &str) -> Result<Option<PlanState>> {
     Ok(Some(state))
 }
 
+/// List plan names that have a directory under `{cwd}/.edda/conductor/`, sorted.
+pub fn list_plans(cwd: &Path) -> Result<Vec<String>> {
+    let conductor_dir = cwd.join(".edda").join("conductor");
+    if !conductor_dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(&conductor_dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            if let Some(name) = entry.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
 /// Save state atomically (write to .tmp, then rename).
 pub fn save_state(cwd: &Path, state: &PlanState) -> Result<()> {
     let path = state_path(cwd, &state.plan_name);
@@ -82,6 +101,19 @@ mod tests {
         assert_eq!(loaded.version, 42);
     }
 
+    #[test]
+    fn list_plans_sorted_and_empty_without_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(list_plans(dir.path()).unwrap().is_empty());
+
+        for name in ["zeta", "alpha"] {
+            let yaml = format!("name: {name}\nphases:\n  - id: a\n    prompt: x\n");
+            let plan = parse_plan(&yaml).unwrap();
+            save_state(dir.path(), &PlanState::from_plan(&plan, "plan.yaml")).unwrap();
+        }
+        assert_eq!(list_plans(dir.path()).unwrap(), vec!["alpha", "zeta"]);
+    }
+
     // ── Corrupted state recovery tests ─────────────────────────────
 
     /// Helper: create the state.json directory structure and write content.
@@ -1,7 +1,8 @@
 use crate::canon::canonical_json_bytes;
 use crate::hash::sha256_hex;
 use crate::types::{
-    classify_event_type, DecisionPayload, Digest, Event, Refs, CANON_EDDA_V1, SCHEMA_VERSION,
+    classify_event_type, Attachment, DecisionPayload, Digest, Event, NoteBody, Refs, CANON_EDDA_V1,
+    SCHEMA_VERSION,
 };
 
 /// Compute the hash for an event: serialize without the `hash` field,
@@ -15,6 +16,11 @@ fn new_event_id() -> String {
     format!("evt_{}", ulid::Ulid::new().to_string().to_lowercase())
 }
 
+/// Generate a new draft ID (`drf_*`).
+pub fn new_draft_id() -> String {
+    format!("drf_{}", ulid::Ulid::new().to_string().to_lowercase())
+}
+
 fn now_rfc3339() -> String {
     let now = time::OffsetDateTime::now_utc();
     now.format(&time::format_description::well_known::Rfc3339)
@@ -64,11 +70,27 @@ pub fn new_note_event(
     text: &str,
     tags: &[String],
 ) -> anyhow::Result<Event> {
-    let payload = serde_json::json!({
+    new_note_event_with_body(branch, parent_hash, role, text, tags, None)
+}
+
+/// Create a new `note` event carrying an optional structured body
+/// (stored as `payload.body`, see [`NoteBody`]).
+pub fn new_note_event_with_body(
+    branch: &str,
+    parent_hash: Option<&str>,
+    role: &str,
+    text: &str,
+    tags: &[String],
+    body: Option<&NoteBody>,
+) -> anyhow::Result<Event> {
+    let mut payload = serde_json::json!({
         "role": role,
         "text": text,
         "tags": tags,
     });
+    if let Some(body) = body {
+        payload["body"] = serde_json::to_value(body)?;
+    }
 
     let mut event = Event {
         event_id: new_event_id(),
@@ -89,6 +111,21 @@ pub fn new_note_event(
     Ok(event)
 }
 
+/// Attach files to a note or decision event: lists them in
+/// `payload.attachments`, adds their blobs to `refs.blobs`, and re-hashes.
+pub fn attach_files(event: &mut Event, attachments: &[Attachment]) -> anyhow::Result<()> {
+    if attachments.is_empty() {
+        return Ok(());
+    }
+    event.payload["attachments"] = serde_json::to_value(attachments)?;
+    for a in attachments {
+        if !event.refs.blobs.contains(&a.blob) {
+            event.refs.blobs.push(a.blob.clone());
+        }
+    }
+    finalize(event)
+}
+
 /// Create a new `decision` event (a note event with structured decision payload).
 pub fn new_decision_event(
     branch: &str,
@@ -149,6 +186,204 @@ pub fn new_decision_ratify_event(
     Ok(event)
 }
 
+/// Create a `revoke` event that walks back an earlier decision or note.
+///
+/// Like ratification, revocation is an appended fact rather than an edit:
+/// the target stays in the ledger and the link is a `revokes` provenance
+/// entry. The decisions table deactivates a revoked decision's key, and
+/// `ask`/context mark the target as revoked.
+pub fn new_revoke_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    target: &Event,
+    reason: &str,
+) -> anyhow::Result<Event> {
+    use crate::types::{rel, Provenance};
+
+    let mut payload = serde_json::json!({
+        "target": target.event_id,
+        "target_type": target.event_type,
+        "reason": reason,
+    });
+    if let Some(dp) = crate::decision::extract_decision(&target.payload) {
+        payload["key"] = serde_json::json!(dp.key);
+    }
+
+    let mut event = Event {
+        event_id: new_event_id(),
+        ts: now_rfc3339(),
+        event_type: "revoke".to_string(),
+        branch: branch.to_string(),
+        parent_hash: parent_hash.map(|s| s.to_string()),
+        hash: String::new(),
+        payload,
+        refs: Refs {
+            provenance: vec![Provenance {
+                target: target.event_id.clone(),
+                rel: rel::REVOKES.to_string(),
+                note: Some(reason.to_string()),
+            }],
+            ..Default::default()
+        },
+        schema_version: SCHEMA_VERSION,
+        digests: Vec::new(),
+        event_family: None,
+        event_level: None,
+    };
+
+    finalize(&mut event)?;
+    Ok(event)
+}
+
+/// Create a note tagged `answer` that resolves an earlier question note.
+///
+/// The link is an `answers` provenance entry on the question; when the
+/// answer is an existing note or decision (`by`), it is linked `based_on`
+/// and its text is used unless `text` is given.
+pub fn new_answer_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    question: &Event,
+    text: Option<&str>,
+    by: Option<&Event>,
+) -> anyhow::Result<Event> {
+    use crate::types::{rel, Provenance};
+
+    let by_text = by
+        .and_then(|e| e.payload.get("text"))
+        .and_then(|t| t.as_str());
+    let text = match (text, by, by_text) {
+        (Some(t), _, _) => t.to_string(),
+        (None, Some(e), Some(t)) => format!("Answered by {}: {t}", e.event_id),
+        (None, Some(e), None) => format!("Answered by {}", e.event_id),
+        (None, None, _) => anyhow::bail!("an answer needs text or an answering event"),
+    };
+
+    let tags = vec!["answer".to_string()];
+    let mut event = new_note_event(branch, parent_hash, "user", &text, &tags)?;
+    event.payload["question"] = serde_json::json!(question.event_id);
+    event.refs.provenance.push(Provenance {
+        target: question.event_id.clone(),
+        rel: rel::ANSWERS.to_string(),
+        note: None,
+    });
+    if let Some(by) = by {
+        event.refs.provenance.push(Provenance {
+            target: by.event_id.clone(),
+            rel: rel::BASED_ON.to_string(),
+            note: None,
+        });
+    }
+
+    finalize(&mut event)?;
+    Ok(event)
+}
+
+/// Create a `tag_rename` event mapping tag `from` onto `to`.
+///
+/// History is not rewritten: readers resolve tags through the renames in
+/// ledger order, so both past and future uses of `from` count as `to`.
+pub fn new_tag_rename_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    from: &str,
+    to: &str,
+) -> anyhow::Result<Event> {
+    let mut event = Event {
+        event_id: new_event_id(),
+        ts: now_rfc3339(),
+        event_type: "tag_rename".to_string(),
+        branch: branch.to_string(),
+        parent_hash: parent_hash.map(|s| s.to_string()),
+        hash: String::new(),
+        payload: serde_json::json!({ "from": from, "to": to }),
+        refs: Refs::default(),
+        schema_version: SCHEMA_VERSION,
+        digests: Vec::new(),
+        event_family: None,
+        event_level: None,
+    };
+
+    finalize(&mut event)?;
+    Ok(event)
+}
+
+/// Create a `pin` event. `pinned = false` unpins the target again.
+///
+/// Pins are folded in ledger order by readers, so the latest `pin` event for
+/// a target decides whether it is pinned.
+pub fn new_pin_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    target: &str,
+    pinned: bool,
+    reason: Option<&str>,
+) -> anyhow::Result<Event> {
+    let mut payload = serde_json::json!({ "target": target, "pinned": pinned });
+    if let Some(r) = reason {
+        payload["reason"] = serde_json::json!(r);
+    }
+    let mut event = Event {
+        event_id: new_event_id(),
+        ts: now_rfc3339(),
+        event_type: "pin".to_string(),
+        branch: branch.to_string(),
+        parent_hash: parent_hash.map(|s| s.to_string()),
+        hash: String::new(),
+        payload,
+        refs: Refs {
+            events: vec![target.to_string()],
+            ..Default::default()
+        },
+        schema_version: SCHEMA_VERSION,
+        digests: Vec::new(),
+        event_family: None,
+        event_level: None,
+    };
+
+    finalize(&mut event)?;
+    Ok(event)
+}
+
+/// Config keys whose values never enter the ledger. `slack` holds the Slack
+/// bridge's signing secret.
+const SECRET_CONFIG_KEY_PARTS: &[&str] = &["token", "secret", "password", "webhook", "slack"];
+
+/// Create a `config_change` event for a `.edda/config.json` key. `None`
+/// means the key was unset before (`old`) or is removed (`new`).
+///
+/// Values of keys that look like credentials, or that contain a secret
+/// `secret_guard` recognizes, are recorded as `"[REDACTED]"`.
+pub fn new_config_change_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    key: &str,
+    old: Option<&serde_json::Value>,
+    new: Option<&serde_json::Value>,
+    actor: Option<&str>,
+) -> anyhow::Result<Event> {
+    let key_lower = key.to_lowercase();
+    let secret_key = SECRET_CONFIG_KEY_PARTS
+        .iter()
+        .any(|part| key_lower.contains(part));
+    let record = |value: Option<&serde_json::Value>| match value {
+        None => serde_json::Value::Null,
+        Some(v) if secret_key || !crate::secret_guard::redact(&v.to_string()).1.is_empty() => {
+            serde_json::json!("[REDACTED]")
+        }
+        Some(v) => v.clone(),
+    };
+    let mut payload = serde_json::json!({
+        "key": key,
+        "old": record(old),
+        "new": record(new),
+    });
+    if let Some(a) = actor {
+        payload["actor"] = serde_json::json!(a);
+    }
+    build_event(branch, parent_hash, "config_change", payload)
+}
+
 /// Parameters for creating a `cmd` event.
 pub struct CmdEventParams<'a> {
     pub branch: &'a str,
@@ -582,6 +817,43 @@ pub fn new_agent_phase_change_event(p: &AgentPhaseChangeParams<'_>) -> anyhow::R
     Ok(event)
 }
 
+/// Parameters for creating a `model_change` event.
+pub struct ModelChangeParams<'a> {
+    pub branch: &'a str,
+    pub parent_hash: Option<&'a str>,
+    pub session_id: &'a str,
+    pub from: &'a str,
+    pub to: &'a str,
+    /// Context profile the bridge switched to (`full` or `compact`).
+    pub profile: &'a str,
+}
+
+/// Create a new `model_change` event — a session switched models mid-run.
+pub fn new_model_change_event(p: &ModelChangeParams<'_>) -> anyhow::Result<Event> {
+    let mut event = Event {
+        event_id: new_event_id(),
+        ts: now_rfc3339(),
+        event_type: "model_change".to_string(),
+        branch: p.branch.to_string(),
+        parent_hash: p.parent_hash.map(|s| s.to_string()),
+        hash: String::new(),
+        payload: serde_json::json!({
+            "session_id": p.session_id,
+            "from": p.from,
+            "to": p.to,
+            "profile": p.profile,
+        }),
+        refs: Refs::default(),
+        schema_version: SCHEMA_VERSION,
+        digests: Vec::new(),
+        event_family: None,
+        event_level: None,
+    };
+
+    finalize(&mut event)?;
+    Ok(event)
+}
+
 /// Parameters for creating a `review_bundle` event.
 pub struct ReviewBundleParams {
     pub branch: String,
@@ -772,6 +1044,38 @@ pub fn new_telemetry_event(
     Ok(event)
 }
 
+/// Create a new `recap` event — the narrative summary of one agent session
+/// written at SessionEnd by the chronicle recap pipeline.
+///
+/// `payload` must carry `session_id`; the rest (summary text, decisions,
+/// open threads) is owned by `edda-chronicle`.
+pub fn new_recap_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    payload: serde_json::Value,
+) -> anyhow::Result<Event> {
+    if payload.get("session_id").and_then(|v| v.as_str()).is_none() {
+        anyhow::bail!("recap payload requires a session_id");
+    }
+    let mut event = Event {
+        event_id: new_event_id(),
+        ts: now_rfc3339(),
+        event_type: "recap".to_string(),
+        branch: branch.to_string(),
+        parent_hash: parent_hash.map(|s| s.to_string()),
+        hash: String::new(),
+        payload,
+        refs: Refs::default(),
+        schema_version: SCHEMA_VERSION,
+        digests: Vec::new(),
+        event_family: None,
+        event_level: None,
+    };
+
+    finalize(&mut event)?;
+    Ok(event)
+}
+
 /// Parameters for creating an `approval_policy_match` event.
 #[derive(Debug, Clone)]
 pub struct ApprovalPolicyMatchParams {
@@ -852,8 +1156,8 @@ pub struct TaskCreatedParams<'a> {
     pub idempotency_key: Option<&'a str>,
 }
 
-/// Build a finalized task.* event with the shared envelope fields.
-fn build_task_event(
+/// Build a finalized task.* or draft.* event with the shared envelope fields.
+fn build_event(
     branch: &str,
     parent_hash: Option<&str>,
     event_type: &str,
@@ -902,7 +1206,7 @@ pub fn new_task_created_event(p: &TaskCreatedParams<'_>) -> anyhow::Result<Event
     if let Some(v) = p.idempotency_key {
         payload["idempotency_key"] = serde_json::json!(v);
     }
-    build_task_event(p.branch, p.parent_hash, "task.created", payload)
+    build_event(p.branch, p.parent_hash, "task.created", payload)
 }
 
 /// Create a new `task.started` event (lease acquisition).
@@ -913,7 +1217,7 @@ pub fn new_task_started_event(
     lease_ttl_s: u64,
     attempt: u32,
 ) -> anyhow::Result<Event> {
-    build_task_event(
+    build_event(
         branch,
         parent_hash,
         "task.started",
@@ -932,7 +1236,7 @@ pub fn new_task_session_event(
     task_id: u64,
     acp_session_id: &str,
 ) -> anyhow::Result<Event> {
-    build_task_event(
+    build_event(
         branch,
         parent_hash,
         "task.session",
@@ -955,7 +1259,7 @@ pub fn new_task_done_event(
     if receipt.trim().is_empty() {
         anyhow::bail!("task.done requires a non-empty receipt (task {task_id})");
     }
-    build_task_event(
+    build_event(
         branch,
         parent_hash,
         "task.done",
@@ -974,7 +1278,7 @@ pub fn new_task_failed_event(
     task_id: u64,
     reason: &str,
 ) -> anyhow::Result<Event> {
-    build_task_event(
+    build_event(
         branch,
         parent_hash,
         "task.failed",
@@ -992,7 +1296,7 @@ pub fn new_task_requeued_event(
     task_id: u64,
     attempt: u32,
 ) -> anyhow::Result<Event> {
-    build_task_event(
+    build_event(
         branch,
         parent_hash,
         "task.requeued",
@@ -1003,6 +1307,105 @@ pub fn new_task_requeued_event(
     )
 }
 
+// ── Draft governance events (draft.*) ──
+//
+// A draft's lifecycle lives in the ledger: `draft.proposed` carries the whole
+// proposal, `approval` events record stage decisions, and `draft.applied` /
+// `draft.deleted` close it. Draft state is folded from these events
+// (`edda_ledger::drafts`); there is no draft file to keep in sync.
+
+/// One approval stage a proposed draft must clear.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DraftStageSpec {
+    pub stage_id: String,
+    pub role: String,
+    pub min_approvals: usize,
+    #[serde(default)]
+    pub assignees: Vec<String>,
+}
+
+/// Parameters for creating a `draft.proposed` event.
+pub struct DraftProposedParams<'a> {
+    pub branch: &'a str,
+    pub parent_hash: Option<&'a str>,
+    pub draft_id: &'a str,
+    /// Original creation time, when importing a draft written elsewhere.
+    /// Defaults to the event timestamp.
+    pub created_at: Option<&'a str>,
+    /// Ledger tip the draft was written against (empty for an empty ledger).
+    pub base_parent_hash: &'a str,
+    pub title: &'a str,
+    pub purpose: &'a str,
+    pub contribution: &'a str,
+    pub labels: &'a [String],
+    pub evidence: &'a [serde_json::Value],
+    pub auto_preview_lines: &'a [String],
+    pub route_rule_id: &'a str,
+    pub stages: &'a [DraftStageSpec],
+    pub policy_require_approval: bool,
+    pub policy_min_approvals: usize,
+    /// What opened the draft, when not a person (e.g. a high-risk action).
+    pub origin: Option<&'a serde_json::Value>,
+}
+
+/// Create a new `draft.proposed` event.
+pub fn new_draft_proposed_event(p: &DraftProposedParams<'_>) -> anyhow::Result<Event> {
+    let mut payload = serde_json::json!({
+        "draft_id": p.draft_id,
+        "base_parent_hash": p.base_parent_hash,
+        "title": p.title,
+        "purpose": p.purpose,
+        "contribution": p.contribution,
+        "labels": p.labels,
+        "evidence": p.evidence,
+        "auto_preview_lines": p.auto_preview_lines,
+        "route_rule_id": p.route_rule_id,
+        "stages": p.stages,
+        "policy_require_approval": p.policy_require_approval,
+        "policy_min_approvals": p.policy_min_approvals,
+    });
+    if let Some(v) = p.created_at {
+        payload["created_at"] = serde_json::json!(v);
+    }
+    if let Some(v) = p.origin {
+        payload["origin"] = v.clone();
+    }
+    build_event(p.branch, p.parent_hash, "draft.proposed", payload)
+}
+
+/// Create a new `draft.applied` event linking a draft to its commit.
+pub fn new_draft_applied_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    draft_id: &str,
+    commit_event_id: &str,
+) -> anyhow::Result<Event> {
+    build_event(
+        branch,
+        parent_hash,
+        "draft.applied",
+        serde_json::json!({
+            "draft_id": draft_id,
+            "commit_event_id": commit_event_id,
+        }),
+    )
+}
+
+/// Create a new `draft.deleted` event. The proposal stays in the chain;
+/// the draft just leaves every view.
+pub fn new_draft_deleted_event(
+    branch: &str,
+    parent_hash: Option<&str>,
+    draft_id: &str,
+) -> anyhow::Result<Event> {
+    build_event(
+        branch,
+        parent_hash,
+        "draft.deleted",
+        serde_json::json!({ "draft_id": draft_id }),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1022,6 +1425,45 @@ mod tests {
         assert_eq!(event.digests[0].value, event.hash);
     }
 
+    #[test]
+    fn note_event_with_body_stores_payload_body() {
+        let body = NoteBody::Code {
+            language: Some("rust".to_string()),
+            code: "let x = 1;".to_string(),
+        };
+        let event =
+            new_note_event_with_body("main", None, "user", "snippet", &[], Some(&body)).unwrap();
+        assert_eq!(event.payload["text"], "snippet");
+        assert_eq!(event.payload["body"]["kind"], "code");
+        assert_eq!(NoteBody::from_payload(&event.payload), Some(body));
+
+        let plain = new_note_event("main", None, "user", "snippet", &[]).unwrap();
+        assert!(plain.payload.get("body").is_none());
+    }
+
+    #[test]
+    fn attached_files_are_listed_and_referenced() {
+        let mut event = new_note_event("main", None, "user", "see diagram", &[]).unwrap();
+        let before = event.hash.clone();
+        let diagram = Attachment {
+            name: "diagram.png".into(),
+            blob: "blob:sha256:abc123".into(),
+            size: 42,
+        };
+        attach_files(&mut event, std::slice::from_ref(&diagram)).unwrap();
+
+        assert_ne!(event.hash, before);
+        assert_eq!(event.refs.blobs, vec!["blob:sha256:abc123".to_string()]);
+        assert_eq!(
+            Attachment::from_payload(&event.payload),
+            vec![diagram.clone()]
+        );
+        assert_eq!(
+            diagram.markdown_link(),
+            "[diagram.png](.edda/ledger/blobs/abc123)"
+        );
+    }
+
     #[test]
     fn decision_event_has_structured_payload() {
         let dp = DecisionPayload {
@@ -1485,6 +1927,31 @@ mod tests {
         assert_eq!(event.event_level.as_deref(), Some("trace"));
     }
 
+    #[test]
+    fn config_change_is_admin_and_redacts_credentials() {
+        let event = new_config_change_event(
+            "main",
+            None,
+            "gc.retention.signal",
+            None,
+            Some(&serde_json::json!(30)),
+            Some("alice"),
+        )
+        .unwrap();
+        assert_eq!(event.event_type, "config_change");
+        assert_eq!(event.event_family.as_deref(), Some("admin"));
+        assert_eq!(event.payload["old"], serde_json::Value::Null);
+        assert_eq!(event.payload["new"], 30);
+        assert_eq!(event.payload["actor"], "alice");
+
+        let tokens = serde_json::json!([{"name": "ci", "token": "abc"}]);
+        let event =
+            new_config_change_event("main", None, "serve.tokens", None, Some(&tokens), None)
+                .unwrap();
+        assert_eq!(event.payload["new"], "[REDACTED]");
+        assert!(event.payload.get("actor").is_none());
+    }
+
     #[test]
     fn taxonomy_approval_is_governance() {
         let event = new_approval_event(&ApprovalEventParams {
@@ -1684,6 +2151,27 @@ mod tests {
         assert_eq!(event.digests[0].value, event.hash);
     }
 
+    #[test]
+    fn model_change_event_fields() {
+        let event = new_model_change_event(&ModelChangeParams {
+            branch: "main",
+            parent_hash: None,
+            session_id: "sess-abc",
+            from: "claude-sonnet-4-20250514",
+            to: "claude-haiku-4-5",
+            profile: "compact",
+        })
+        .unwrap();
+        assert_eq!(event.event_type, "model_change");
+        assert_eq!(event.payload["from"], "claude-sonnet-4-20250514");
+        assert_eq!(event.payload["to"], "claude-haiku-4-5");
+        assert_eq!(event.payload["profile"], "compact");
+        assert_eq!(
+            event.event_family.as_deref(),
+            Some(crate::types::event_family::SIGNAL)
+        );
+    }
+
     #[test]
     fn agent_phase_change_event_minimal() {
         let event = new_agent_phase_change_event(&AgentPhaseChangeParams {
@@ -1775,6 +2263,73 @@ mod tests {
         assert!(event.payload.get("note").is_none());
     }
 
+    // ── revoke ──
+
+    #[test]
+    fn revoke_event_links_target_with_revokes_provenance() {
+        use crate::types::rel;
+
+        let dp = DecisionPayload {
+            key: "db.engine".to_string(),
+            value: "sqlite".to_string(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let target = new_decision_event("main", None, "system", &dp).unwrap();
+        let event = new_revoke_event("main", Some(&target.hash), &target, "wrong call").unwrap();
+        assert_eq!(event.event_type, "revoke");
+        assert_eq!(event.event_family.as_deref(), Some("governance"));
+        assert_eq!(event.payload["target"], target.event_id.as_str());
+        assert_eq!(event.payload["target_type"], "note");
+        assert_eq!(event.payload["key"], "db.engine");
+        assert_eq!(event.payload["reason"], "wrong call");
+        let prov = &event.refs.provenance[0];
+        assert_eq!(prov.target, target.event_id);
+        assert_eq!(prov.rel, rel::REVOKES);
+
+        let note = new_note_event("main", None, "user", "try redis", &[]).unwrap();
+        let event = new_revoke_event("main", None, &note, "never mind").unwrap();
+        assert!(event.payload.get("key").is_none());
+    }
+
+    #[test]
+    fn answer_event_links_question_and_answering_event() {
+        use crate::types::rel;
+
+        let question = new_note_event(
+            "main",
+            None,
+            "user",
+            "which queue?",
+            &["question".to_string()],
+        )
+        .unwrap();
+        let event = new_answer_event("main", None, &question, Some("use sqs"), None).unwrap();
+        assert_eq!(event.event_type, "note");
+        assert_eq!(event.payload["text"], "use sqs");
+        assert_eq!(event.payload["tags"][0], "answer");
+        assert_eq!(event.payload["question"], question.event_id.as_str());
+        assert_eq!(event.refs.provenance.len(), 1);
+        assert_eq!(event.refs.provenance[0].rel, rel::ANSWERS);
+
+        let decision = new_note_event("main", None, "user", "queue=sqs", &[]).unwrap();
+        let event = new_answer_event("main", None, &question, None, Some(&decision)).unwrap();
+        assert_eq!(
+            event.payload["text"],
+            format!("Answered by {}: queue=sqs", decision.event_id)
+        );
+        assert_eq!(event.refs.provenance[1].target, decision.event_id);
+        assert_eq!(event.refs.provenance[1].rel, rel::BASED_ON);
+
+        assert!(new_answer_event("main", None, &question, None, None).is_err());
+    }
+
     #[test]
     fn decision_ratify_is_governance() {
         let event = new_decision_ratify_event("main", None, "k", "operator", None).unwrap();
@@ -1920,4 +2475,45 @@ mod tests {
         assert_eq!(event.event_family.as_deref(), Some("admin"));
         assert_eq!(event.event_level.as_deref(), Some("info"));
     }
+
+    // ── draft.* governance events ──
+
+    #[test]
+    fn draft_proposed_event_carries_the_proposal() {
+        let stages = vec![DraftStageSpec {
+            stage_id: "lead".into(),
+            role: "lead".into(),
+            min_approvals: 1,
+            assignees: vec!["alice".into()],
+        }];
+        let event = new_draft_proposed_event(&DraftProposedParams {
+            branch: "main",
+            parent_hash: None,
+            draft_id: "drf_1",
+            created_at: None,
+            base_parent_hash: "",
+            title: "Add caching",
+            purpose: "",
+            contribution: "Add caching",
+            labels: &["risk".to_string()],
+            evidence: &[],
+            auto_preview_lines: &[],
+            route_rule_id: "require",
+            stages: &stages,
+            policy_require_approval: true,
+            policy_min_approvals: 1,
+            origin: None,
+        })
+        .unwrap();
+        assert_eq!(event.event_type, "draft.proposed");
+        assert_eq!(event.event_family.as_deref(), Some("governance"));
+        assert_eq!(event.payload["stages"][0]["assignees"][0], "alice");
+        assert!(event.payload.get("created_at").is_none());
+        assert!(event.payload.get("origin").is_none());
+
+        let applied = new_draft_applied_event("main", None, "drf_1", "evt_x").unwrap();
+        assert_eq!(applied.event_type, "draft.applied");
+        assert_eq!(applied.payload["commit_event_id"], "evt_x");
+        assert!(new_draft_id().starts_with("drf_"));
+    }
 }
@@ -8,6 +8,7 @@ pub mod git;
 pub mod hash;
 pub mod policy;
 pub mod secret_guard;
+pub mod text;
 pub mod tool_tier;
 pub mod types;
 
@@ -1,9 +1,12 @@
 //! Governance policy types and RBAC evaluation.
 //!
-//! Shared between `edda-cli` (draft approval workflow) and `edda-serve` (authz API).
+//! Shared between `edda-cli` (draft approval workflow), `edda-mcp` (draft
+//! governance tools) and `edda-serve` (authz API).
 
+use crate::event::DraftStageSpec;
+use crate::types::Event;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
 // ── Policy v2 data model ──
@@ -239,6 +242,461 @@ pub fn evaluate_authz(
     }
 }
 
+// ── Route selection ──
+
+/// Whether a rule's `when` clause matches the given draft attributes.
+pub fn when_matches(
+    when: &PolicyWhen,
+    labels: &HashSet<&str>,
+    has_failed_cmd: bool,
+    evidence_count: usize,
+) -> bool {
+    if when.default == Some(true) {
+        return true;
+    }
+    if let Some(ref la) = when.labels_any {
+        if la.iter().any(|l| labels.contains(l.as_str())) {
+            return true;
+        }
+    }
+    if when.failed_cmd == Some(true) && has_failed_cmd {
+        return true;
+    }
+    if let Some(n) = when.evidence_count_gte {
+        if n > 0 && evidence_count >= n {
+            return true;
+        }
+    }
+    false
+}
+
+/// First-match route selection. Returns (rule_id, stages).
+pub fn route_select(
+    policy: &PolicyV2Config,
+    labels: &[String],
+    has_failed_cmd: bool,
+    evidence_count: usize,
+) -> (String, Vec<PolicyStageDef>) {
+    let label_set: HashSet<&str> = labels.iter().map(|s| s.as_str()).collect();
+    for rule in &policy.rules {
+        if when_matches(&rule.when, &label_set, has_failed_cmd, evidence_count) {
+            return (rule.id.clone(), rule.stages.clone());
+        }
+    }
+    (String::new(), vec![])
+}
+
+/// Turn routed stages into draft stages, assigning every actor that holds
+/// the stage's role (sorted, capped at `max_assignees` when set).
+pub fn assign_stages(stages: &[PolicyStageDef], actors: &ActorsConfig) -> Vec<DraftStageSpec> {
+    stages
+        .iter()
+        .map(|ps| {
+            let mut assignees: Vec<String> = actors
+                .actors
+                .iter()
+                .filter(|(_, def)| def.roles.contains(&ps.role))
+                .map(|(name, _)| name.clone())
+                .collect();
+            assignees.sort();
+            if ps.max_assignees > 0 {
+                assignees.truncate(ps.max_assignees);
+            }
+            DraftStageSpec {
+                stage_id: ps.stage_id.clone(),
+                role: ps.role.clone(),
+                min_approvals: ps.min_approvals,
+                assignees,
+            }
+        })
+        .collect()
+}
+
+// ── Branch protection ──
+
+/// Ledger write that a protected branch can route through the draft flow.
+pub const ACTION_DECIDE: &str = "decide";
+pub const ACTION_COMMIT: &str = "commit";
+
+/// `protected_branches` entry in `policy.yaml`.
+///
+/// ```yaml
+/// protected_branches:
+///   - branch: main            # exact name, or a prefix ending in `*`
+///     require_draft: [decide, commit]
+///     override_roles: [lead]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BranchProtection {
+    pub branch: String,
+    /// Actions that must go through a draft; defaults to decide + commit.
+    #[serde(default = "default_protected_actions")]
+    pub require_draft: Vec<String>,
+    /// Actor roles (from `actors.yaml`) allowed to write directly.
+    #[serde(default)]
+    pub override_roles: Vec<String>,
+}
+
+fn default_protected_actions() -> Vec<String> {
+    vec![ACTION_DECIDE.to_string(), ACTION_COMMIT.to_string()]
+}
+
+impl BranchProtection {
+    fn matches(&self, branch: &str) -> bool {
+        match self.branch.strip_suffix('*') {
+            Some(prefix) => branch.starts_with(prefix),
+            None => self.branch == branch,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct BranchProtectionFile {
+    #[serde(default)]
+    protected_branches: Vec<BranchProtection>,
+}
+
+/// Why a direct write to a protected branch was rejected, with guidance.
+///
+/// Returns `None` when `branch` is unprotected for `action`, or when `actor`
+/// holds one of the rule's override roles. `actor` is self-asserted on the
+/// CLI and MCP paths; HTTP uses the API token name when one is presented.
+pub fn branch_protection_denial(
+    rules: &[BranchProtection],
+    actors: &ActorsConfig,
+    branch: &str,
+    action: &str,
+    actor: Option<&str>,
+) -> Option<String> {
+    let rule = rules
+        .iter()
+        .find(|r| r.matches(branch) && r.require_draft.iter().any(|a| a == action))?;
+
+    let actor_roles = actor
+        .and_then(|a| actors.actors.get(a))
+        .map(|a| a.roles.as_slice())
+        .unwrap_or_default();
+    if rule.override_roles.iter().any(|r| actor_roles.contains(r)) {
+        return None;
+    }
+
+    let route = if action == ACTION_DECIDE {
+        "Record the decision on a working branch, then bring it in with \
+         `edda draft propose --evidence <event_id>` and `edda draft apply` once approved."
+    } else {
+        "Propose it with `edda draft propose` and run `edda draft apply` once approved."
+    };
+    let mut msg =
+        format!("branch '{branch}' is protected: direct `edda {action}` is not allowed. {route}");
+    if !rule.override_roles.is_empty() {
+        msg.push_str(&format!(
+            " Actors with role {} may write directly.",
+            rule.override_roles.join(" or ")
+        ));
+    }
+    Some(msg)
+}
+
+/// Load `protected_branches` from `policy.yaml` (any policy version).
+pub fn load_branch_protection_from_dir(edda_dir: &Path) -> anyhow::Result<Vec<BranchProtection>> {
+    let path = edda_dir.join("policy.yaml");
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let content = std::fs::read(&path)?;
+    let file: BranchProtectionFile = serde_yaml::from_slice(&content)?;
+    Ok(file.protected_branches)
+}
+
+/// [`branch_protection_denial`] against the `.edda/` config on disk.
+pub fn check_branch_protection(
+    edda_dir: &Path,
+    branch: &str,
+    action: &str,
+    actor: Option<&str>,
+) -> anyhow::Result<Option<String>> {
+    let rules = load_branch_protection_from_dir(edda_dir)?;
+    if rules.is_empty() {
+        return Ok(None);
+    }
+    let actors = load_actors_from_dir(edda_dir)?;
+    Ok(branch_protection_denial(
+        &rules, &actors, branch, action, actor,
+    ))
+}
+
+// ── Append rules ──
+
+/// Event type an append rule can name for decisions, which are stored as
+/// `note` events carrying a `decision` payload.
+pub const APPEND_DECISION: &str = "decision";
+
+/// `append_rules` entry in `policy.yaml`: who may append matching events to
+/// the ledger at all, on any branch.
+///
+/// ```yaml
+/// append_rules:
+///   - event_type: decision    # `decision`, or an event type like `commit`
+///     keys: ["infra.*"]       # decision keys: exact, or a prefix ending in `*`
+///     roles: [lead]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppendRule {
+    /// Omitted matches every event.
+    #[serde(default)]
+    pub event_type: Option<String>,
+    /// Omitted matches any decision key; when set, only decisions match.
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Actor roles (from `actors.yaml`) allowed to append matching events.
+    pub roles: Vec<String>,
+}
+
+impl AppendRule {
+    fn matches(&self, event: &Event) -> bool {
+        let decision_key = (event.event_type == "note")
+            .then(|| crate::decision::extract_decision(&event.payload))
+            .flatten()
+            .map(|d| d.key);
+        let type_ok = match self.event_type.as_deref() {
+            None => true,
+            Some(APPEND_DECISION) => decision_key.is_some(),
+            Some(t) => event.event_type == t,
+        };
+        if !type_ok {
+            return false;
+        }
+        if self.keys.is_empty() {
+            return true;
+        }
+        decision_key.is_some_and(|key| {
+            self.keys.iter().any(|k| match k.strip_suffix('*') {
+                Some(prefix) => key.starts_with(prefix),
+                None => *k == key,
+            })
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct AppendRulesFile {
+    #[serde(default)]
+    append_rules: Vec<AppendRule>,
+}
+
+/// Why `actor` may not append `event`, or `None` when every matching rule
+/// grants one of its roles. An absent or unknown actor holds no roles.
+pub fn append_rule_denial(
+    rules: &[AppendRule],
+    actors: &ActorsConfig,
+    actor: Option<&str>,
+    event: &Event,
+) -> Option<String> {
+    let actor_roles = actor
+        .and_then(|a| actors.actors.get(a))
+        .map(|a| a.roles.as_slice())
+        .unwrap_or_default();
+    let rule = rules
+        .iter()
+        .filter(|r| r.matches(event))
+        .find(|r| !r.roles.iter().any(|role| actor_roles.contains(role)))?;
+
+    let what = match crate::decision::extract_decision(&event.payload) {
+        Some(d) if event.event_type == "note" => format!("decision `{}`", d.key),
+        _ => format!("`{}` event", event.event_type),
+    };
+    let who = match actor {
+        Some(a) => format!("actor '{a}' does not hold it"),
+        None => "no actor was given (pass --actor or set EDDA_ACTOR)".to_string(),
+    };
+    Some(format!(
+        "appending {what} requires role {}; {who}",
+        rule.roles.join(" or ")
+    ))
+}
+
+/// Load `append_rules` from `policy.yaml` (any policy version).
+pub fn load_append_rules_from_dir(edda_dir: &Path) -> anyhow::Result<Vec<AppendRule>> {
+    let path = edda_dir.join("policy.yaml");
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let content = std::fs::read(&path)?;
+    let file: AppendRulesFile = serde_yaml::from_slice(&content)?;
+    Ok(file.append_rules)
+}
+
+// ── High-risk agent actions ──
+
+/// Running or writing a database schema migration.
+pub const RISK_SCHEMA_MIGRATION: &str = "schema_migration";
+/// Raising a dependency's major version in a manifest.
+pub const RISK_MAJOR_BUMP: &str = "major_bump";
+/// Deleting files under `high_risk.protected_paths`.
+pub const RISK_PROTECTED_DELETE: &str = "protected_delete";
+
+/// `high_risk` section in `policy.yaml`: agent actions the bridge stops at,
+/// turning each into a draft that needs approval before the agent retries.
+///
+/// ```yaml
+/// high_risk:
+///   actions: [schema_migration, major_bump, protected_delete]
+///   protected_paths: ["migrations/**", ".github/**"]
+///   stages:                  # optional; defaults to one `lead` approval
+///     - stage_id: lead
+///       role: lead
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct HighRiskPolicy {
+    #[serde(default)]
+    pub actions: Vec<String>,
+    /// Globs (relative to the repo root) whose deletion is high-risk.
+    #[serde(default)]
+    pub protected_paths: Vec<String>,
+    #[serde(default = "default_high_risk_stages")]
+    pub stages: Vec<PolicyStageDef>,
+}
+
+fn default_high_risk_stages() -> Vec<PolicyStageDef> {
+    vec![PolicyStageDef {
+        stage_id: "lead".to_string(),
+        role: "lead".to_string(),
+        min_approvals: 1,
+        max_assignees: 2,
+    }]
+}
+
+impl HighRiskPolicy {
+    pub fn watches(&self, action: &str) -> bool {
+        self.actions.iter().any(|a| a == action)
+    }
+}
+
+#[derive(Deserialize)]
+struct HighRiskFile {
+    #[serde(default)]
+    high_risk: Option<HighRiskPolicy>,
+}
+
+/// Load the `high_risk` section from `policy.yaml`; `None` when absent or
+/// when it lists no actions.
+pub fn load_high_risk_from_dir(edda_dir: &Path) -> anyhow::Result<Option<HighRiskPolicy>> {
+    let path = edda_dir.join("policy.yaml");
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read(&path)?;
+    let file: HighRiskFile = serde_yaml::from_slice(&content)?;
+    Ok(file.high_risk.filter(|h| !h.actions.is_empty()))
+}
+
+// ── Domain ownership ──
+
+/// Prefix of the draft stages added for owned decision domains.
+pub const DOMAIN_STAGE_PREFIX: &str = "domain:";
+
+/// `domains` entry in `policy.yaml`: the role that owns a decision domain
+/// (the part of a decision key before the first `.`). Drafts carrying
+/// decisions in the domain get an approval stage for that role, and `ask`
+/// shows who owns each decision.
+///
+/// ```yaml
+/// domains:
+///   db:
+///     role: dba
+///     owners: [alice]     # optional; defaults to every actor with the role
+///     min_approvals: 1
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DomainOwner {
+    pub role: String,
+    /// Actors asked to approve; empty means everyone holding `role`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+    #[serde(default = "default_one")]
+    pub min_approvals: usize,
+}
+
+impl DomainOwner {
+    /// The named owners, or else every actor holding the role (sorted).
+    pub fn assignees(&self, actors: &ActorsConfig) -> Vec<String> {
+        if !self.owners.is_empty() {
+            return self.owners.clone();
+        }
+        actors
+            .actors
+            .iter()
+            .filter(|(_, def)| def.roles.contains(&self.role))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// This owner with `owners` filled in from `actors`, for display.
+    pub fn resolved(&self, actors: &ActorsConfig) -> Self {
+        Self {
+            owners: self.assignees(actors),
+            ..self.clone()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DomainsFile {
+    #[serde(default)]
+    domains: BTreeMap<String, DomainOwner>,
+}
+
+/// Load the `domains` section from `policy.yaml` (any policy version).
+pub fn load_domain_owners_from_dir(
+    edda_dir: &Path,
+) -> anyhow::Result<BTreeMap<String, DomainOwner>> {
+    let path = edda_dir.join("policy.yaml");
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let content = std::fs::read(&path)?;
+    let file: DomainsFile = serde_yaml::from_slice(&content)?;
+    Ok(file.domains)
+}
+
+/// Approval stages for the owned domains among `domains`, one per domain,
+/// skipping a domain whose role already has a stage in `existing`.
+pub fn domain_stages<'a>(
+    owners: &BTreeMap<String, DomainOwner>,
+    domains: impl IntoIterator<Item = &'a str>,
+    actors: &ActorsConfig,
+    existing: &[DraftStageSpec],
+) -> Vec<DraftStageSpec> {
+    let mut stages: Vec<DraftStageSpec> = Vec::new();
+    for domain in domains {
+        let Some(owner) = owners.get(domain) else {
+            continue;
+        };
+        if existing
+            .iter()
+            .chain(stages.iter())
+            .any(|s| s.role == owner.role)
+        {
+            continue;
+        }
+        stages.push(DraftStageSpec {
+            stage_id: format!("{DOMAIN_STAGE_PREFIX}{domain}"),
+            role: owner.role.clone(),
+            min_approvals: owner.min_approvals,
+            assignees: owner.assignees(actors),
+        });
+    }
+    stages
+}
+
+/// Why a draft stage was requested, for its `approval_request` event.
+pub fn stage_reason(stage: &DraftStageSpec, rule_id: &str) -> String {
+    match stage.stage_id.strip_prefix(DOMAIN_STAGE_PREFIX) {
+        Some(domain) => format!("domain {domain} is owned by role {}", stage.role),
+        None => format!("matched rule {rule_id}"),
+    }
+}
+
 // ── File loading helpers ──
 
 /// Load policy.yaml from a directory containing `.edda/`.
@@ -539,4 +997,226 @@ actors:
             .unwrap()
             .contains("no permissions section"));
     }
+
+    #[test]
+    fn route_select_first_match_wins() {
+        let policy = PolicyV2Config {
+            version: 2,
+            roles: vec!["lead".into()],
+            rules: vec![
+                PolicyRule {
+                    id: "risky".into(),
+                    when: PolicyWhen {
+                        labels_any: Some(vec!["risk".into()]),
+                        ..Default::default()
+                    },
+                    stages: vec![PolicyStageDef {
+                        stage_id: "lead".into(),
+                        role: "lead".into(),
+                        min_approvals: 1,
+                        max_assignees: 2,
+                    }],
+                },
+                PolicyRule {
+                    id: "default".into(),
+                    when: PolicyWhen {
+                        default: Some(true),
+                        ..Default::default()
+                    },
+                    stages: vec![],
+                },
+            ],
+            permissions: None,
+        };
+
+        let (id, stages) = route_select(&policy, &["risk".to_string()], false, 0);
+        assert_eq!(id, "risky");
+        assert_eq!(stages.len(), 1);
+
+        let (id, stages) = route_select(&policy, &[], false, 0);
+        assert_eq!(id, "default");
+        assert!(stages.is_empty());
+    }
+
+    #[test]
+    fn branch_protection_requires_draft_unless_override_role() {
+        let rules = vec![BranchProtection {
+            branch: "main".into(),
+            require_draft: default_protected_actions(),
+            override_roles: vec!["lead".into()],
+        }];
+        let actors = actors_with("alice", &["lead"]);
+
+        let denial = branch_protection_denial(&rules, &actors, "main", ACTION_DECIDE, None)
+            .expect("decide on main is protected");
+        assert!(denial.contains("edda draft propose"));
+        assert!(denial.contains("role lead"));
+        assert!(
+            branch_protection_denial(&rules, &actors, "main", ACTION_COMMIT, Some("bob")).is_some()
+        );
+
+        assert!(
+            branch_protection_denial(&rules, &actors, "main", ACTION_DECIDE, Some("alice"))
+                .is_none()
+        );
+        assert!(branch_protection_denial(&rules, &actors, "feat/x", ACTION_DECIDE, None).is_none());
+    }
+
+    #[test]
+    fn append_rules_gate_decisions_by_key_and_role() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("policy.yaml"),
+            "version: 2\nappend_rules:\n  - event_type: decision\n    keys: [\"infra.*\"]\n    roles: [lead]\n",
+        )
+        .unwrap();
+        let rules = load_append_rules_from_dir(tmp.path()).unwrap();
+        let actors = actors_with("alice", &["lead"]);
+        let decision = |key: &str| {
+            let dp = crate::types::DecisionPayload {
+                key: key.into(),
+                value: "x".into(),
+                reason: None,
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            crate::event::new_decision_event("main", None, "user", &dp).unwrap()
+        };
+
+        let denial = append_rule_denial(&rules, &actors, Some("bob"), &decision("infra.db"))
+            .expect("bob is not a lead");
+        assert!(denial.contains("decision `infra.db` requires role lead"));
+        assert!(
+            append_rule_denial(&rules, &actors, None, &decision("infra.db"))
+                .unwrap()
+                .contains("EDDA_ACTOR")
+        );
+        assert!(
+            append_rule_denial(&rules, &actors, Some("alice"), &decision("infra.db")).is_none()
+        );
+        assert!(
+            append_rule_denial(&rules, &actors, Some("bob"), &decision("auth.method")).is_none()
+        );
+
+        let note = crate::event::new_note_event("main", None, "user", "hi", &[]).unwrap();
+        assert!(append_rule_denial(&rules, &actors, Some("bob"), &note).is_none());
+        assert!(load_append_rules_from_dir(&tmp.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn branch_protection_loads_prefix_rules_from_yaml() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(
+            check_branch_protection(tmp.path(), "main", ACTION_COMMIT, None)
+                .unwrap()
+                .is_none()
+        );
+
+        std::fs::write(
+            tmp.path().join("policy.yaml"),
+            "version: 2\nprotected_branches:\n  - branch: release/*\n    require_draft: [commit]\n",
+        )
+        .unwrap();
+        assert!(
+            check_branch_protection(tmp.path(), "release/1.0", ACTION_COMMIT, None)
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            check_branch_protection(tmp.path(), "release/1.0", ACTION_DECIDE, None)
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            check_branch_protection(tmp.path(), "main", ACTION_COMMIT, None)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn high_risk_loads_actions_with_default_stage() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert!(load_high_risk_from_dir(tmp.path()).unwrap().is_none());
+
+        std::fs::write(
+            tmp.path().join("policy.yaml"),
+            "version: 2\nhigh_risk:\n  actions: [schema_migration, protected_delete]\n  protected_paths: [\"migrations/**\"]\n",
+        )
+        .unwrap();
+        let policy = load_high_risk_from_dir(tmp.path()).unwrap().unwrap();
+        assert!(policy.watches(RISK_SCHEMA_MIGRATION));
+        assert!(!policy.watches(RISK_MAJOR_BUMP));
+        assert_eq!(policy.protected_paths, ["migrations/**"]);
+        assert_eq!(policy.stages.len(), 1);
+        assert_eq!(policy.stages[0].role, "lead");
+
+        std::fs::write(
+            tmp.path().join("policy.yaml"),
+            "version: 2\nhigh_risk:\n  actions: []\n",
+        )
+        .unwrap();
+        assert!(load_high_risk_from_dir(tmp.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn owned_domains_add_one_stage_per_owning_role() {
+        let dir = std::env::temp_dir().join(format!("edda_policy_domains_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("policy.yaml"),
+            "version: 2\ndomains:\n  db:\n    role: dba\n  cache:\n    role: dba\n  auth:\n    role: security\n    owners: [carol]\n    min_approvals: 2\n",
+        )
+        .unwrap();
+        let owners = load_domain_owners_from_dir(&dir).unwrap();
+        let actors = actors_with("alice", &["dba"]);
+
+        let stages = domain_stages(&owners, ["db", "cache", "auth", "ui"], &actors, &[]);
+        let summary: Vec<_> = stages
+            .iter()
+            .map(|s| {
+                (
+                    s.stage_id.as_str(),
+                    s.role.as_str(),
+                    s.min_approvals,
+                    s.assignees.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("domain:db", "dba", 1, vec!["alice".to_string()]),
+                ("domain:auth", "security", 2, vec!["carol".to_string()]),
+            ]
+        );
+        assert_eq!(
+            stage_reason(&stages[1], "default"),
+            "domain auth is owned by role security"
+        );
+
+        let routed = assign_stages(
+            &[PolicyStageDef {
+                stage_id: "review".into(),
+                role: "dba".into(),
+                min_approvals: 1,
+                max_assignees: 2,
+            }],
+            &actors,
+        );
+        assert!(domain_stages(&owners, ["db"], &actors, &routed).is_empty());
+        assert!(load_domain_owners_from_dir(&dir.join("missing"))
+            .unwrap()
+            .is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,63 @@
+//! Display-width text helpers for budgeted rendering.
+//!
+//! Byte lengths over-count CJK text (three bytes, two columns) and char
+//! counts under-count it, so previews and budgets measure terminal columns
+//! instead, and cut only between grapheme clusters.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Terminal columns `s` takes: two per CJK or fullwidth character, zero for
+/// combining marks.
+pub fn display_width(s: &str) -> usize {
+    UnicodeWidthStr::width(s)
+}
+
+/// Byte index of the end of the longest prefix of `s` that fits in
+/// `max_width` columns without splitting a grapheme cluster.
+pub fn width_boundary(s: &str, max_width: usize) -> usize {
+    let mut width = 0;
+    for (i, g) in s.grapheme_indices(true) {
+        width += UnicodeWidthStr::width(g);
+        if width > max_width {
+            return i;
+        }
+    }
+    s.len()
+}
+
+/// `s` on one line (newlines become spaces), cut to `max_width` columns with
+/// `...` appended when anything was dropped.
+pub fn truncate_width(s: &str, max_width: usize) -> String {
+    let flat = s.replace('\n', " ");
+    if display_width(&flat) <= max_width {
+        return flat;
+    }
+    format!("{}...", &flat[..width_boundary(&flat, max_width)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cjk_counts_two_columns_per_character() {
+        assert_eq!(display_width("edda"), 4);
+        assert_eq!(display_width("決策紀錄"), 8);
+        assert_eq!(display_width("e\u{301}"), 1);
+    }
+
+    #[test]
+    fn truncation_is_proportional_and_keeps_graphemes_whole() {
+        // Ten columns hold ten ASCII letters or five CJK characters.
+        assert_eq!(truncate_width("abcdefghijkl", 10), "abcdefghij...");
+        assert_eq!(truncate_width("資料庫改用PostgreSQL", 10), "資料庫改用...");
+        assert_eq!(truncate_width("日本語のテキスト", 7), "日本語...");
+        assert_eq!(truncate_width("短い\n文", 20), "短い 文");
+
+        // A family emoji is one grapheme; it is kept or dropped whole.
+        let family = "👨\u{200d}👩\u{200d}👧";
+        assert_eq!(width_boundary(&format!("{family}x"), 1), 0);
+        assert_eq!(width_boundary(&format!("{family}x"), 2), family.len());
+    }
+}
@@ -101,12 +101,14 @@ pub fn classify_event_type(event_type: &str) -> (Option<&'static str>, Option<&'
         "rebuild" => (Some(event_family::ADMIN), Some(event_level::TRACE)),
         "branch_create" => (Some(event_family::ADMIN), Some(event_level::INFO)),
         "branch_switch" => (Some(event_family::ADMIN), Some(event_level::INFO)),
-        "approval" | "approval_request" => (
+        "approval" | "approval_request" | "draft.proposed" | "draft.applied" | "draft.deleted" => (
             Some(event_family::GOVERNANCE),
             Some(event_level::GOVERNANCE),
         ),
         "task_intake" => (Some(event_family::SIGNAL), Some(event_level::INFO)),
-        "agent_phase_change" => (Some(event_family::SIGNAL), Some(event_level::INFO)),
+        "agent_phase_change" | "model_change" => {
+            (Some(event_family::SIGNAL), Some(event_level::INFO))
+        }
         "review_bundle" => (Some(event_family::GOVERNANCE), Some(event_level::MILESTONE)),
         "approval_policy_match" => (
             Some(event_family::GOVERNANCE),
@@ -117,13 +119,17 @@ pub fn classify_event_type(event_type: &str) -> (Option<&'static str>, Option<&'
             Some(event_family::GOVERNANCE),
             Some(event_level::GOVERNANCE),
         ),
-        "decision_ratify" => (
+        "decision_ratify" | "revoke" => (
             Some(event_family::GOVERNANCE),
             Some(event_level::GOVERNANCE),
         ),
         "device_pair" | "device_revoke" => (Some(event_family::ADMIN), Some(event_level::INFO)),
+        "tag_rename" | "pin" | "config_change" => {
+            (Some(event_family::ADMIN), Some(event_level::INFO))
+        }
         "decide_snapshot" => (Some(event_family::GOVERNANCE), Some(event_level::MILESTONE)),
         "cycle_telemetry" => (Some(event_family::SIGNAL), Some(event_level::INFO)),
+        "recap" => (Some(event_family::SIGNAL), Some(event_level::INFO)),
         "task.created" | "task.started" | "task.failed" => {
             (Some(event_family::SIGNAL), Some(event_level::INFO))
         }
@@ -134,22 +140,37 @@ pub fn classify_event_type(event_type: &str) -> (Option<&'static str>, Option<&'
     }
 }
 
-/// Scope of a decision's propagation across projects.
+/// How far a decision reaches, from one session up to every registered
+/// project. Ordered narrowest first.
 #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "lowercase")]
 pub enum DecisionScope {
-    /// Default: stays in this project only.
+    /// An experiment inside one session.
+    Session,
+    /// Holds on the branch it was made on.
+    Branch,
+    /// Default: the whole project, not propagated (`project` on the CLI).
     #[default]
     Local,
     /// Propagates to projects in the same group.
     Shared,
-    /// Propagates to all registered projects.
+    /// Propagates to all registered projects (`org` on the CLI).
     Global,
 }
 
+impl DecisionScope {
+    /// Session and branch decisions sit on top of project-level ones instead
+    /// of superseding them.
+    pub fn is_narrow(self) -> bool {
+        self < Self::Local
+    }
+}
+
 impl std::fmt::Display for DecisionScope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Session => write!(f, "session"),
+            Self::Branch => write!(f, "branch"),
             Self::Local => write!(f, "local"),
             Self::Shared => write!(f, "shared"),
             Self::Global => write!(f, "global"),
@@ -161,11 +182,13 @@ impl std::str::FromStr for DecisionScope {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "local" => Ok(Self::Local),
+            "session" => Ok(Self::Session),
+            "branch" => Ok(Self::Branch),
+            "local" | "project" => Ok(Self::Local),
             "shared" => Ok(Self::Shared),
-            "global" => Ok(Self::Global),
+            "global" | "org" => Ok(Self::Global),
             other => Err(format!(
-                "unknown scope: {other} (expected local|shared|global)"
+                "unknown scope: {other} (expected session|branch|project|shared|org)"
             )),
         }
     }
@@ -203,6 +226,128 @@ pub struct DecisionPayload {
     pub village_id: Option<String>,
 }
 
+/// Optional structured body attached to a note event (`payload.body`).
+///
+/// The note's `text` stays the one-line summary; the body carries content
+/// that should keep its shape when rendered (code, link lists, tables).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum NoteBody {
+    /// A code snippet, optionally tagged with its language.
+    Code {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        language: Option<String>,
+        code: String,
+    },
+    /// A list of links.
+    Links { links: Vec<NoteLink> },
+    /// A key/value table.
+    Table { rows: Vec<NoteTableRow> },
+}
+
+/// A single entry of a [`NoteBody::Links`] body.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoteLink {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// A single row of a [`NoteBody::Table`] body.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NoteTableRow {
+    pub key: String,
+    pub value: String,
+}
+
+impl NoteBody {
+    /// Parse the structured body of a note payload, if present and well-formed.
+    pub fn from_payload(payload: &serde_json::Value) -> Option<Self> {
+        payload
+            .get("body")
+            .and_then(|b| serde_json::from_value(b.clone()).ok())
+    }
+
+    /// Render the body as a Markdown block (no trailing newline).
+    pub fn render_markdown(&self) -> String {
+        match self {
+            Self::Code { language, code } => format!(
+                "```{}\n{}\n```",
+                language.as_deref().unwrap_or(""),
+                code.trim_end_matches('\n')
+            ),
+            Self::Links { links } => links
+                .iter()
+                .map(|l| match &l.title {
+                    Some(title) => format!("- [{title}]({})", l.url),
+                    None => format!("- <{}>", l.url),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Table { rows } => {
+                let mut lines = vec!["| key | value |".to_string(), "|---|---|".to_string()];
+                for r in rows {
+                    lines.push(format!(
+                        "| {} | {} |",
+                        r.key.replace('|', "\\|"),
+                        r.value.replace('|', "\\|")
+                    ));
+                }
+                lines.join("\n")
+            }
+        }
+    }
+
+    /// One-line summary for budget-constrained surfaces (packs, log lines).
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Code { language, code } => {
+                let n = code.trim_end_matches('\n').lines().count();
+                match language {
+                    Some(lang) => format!("[code: {lang}, {n} lines]"),
+                    None => format!("[code: {n} lines]"),
+                }
+            }
+            Self::Links { links } => format!("[links: {}]", links.len()),
+            Self::Table { rows } => format!("[table: {} rows]", rows.len()),
+        }
+    }
+}
+
+/// A file attached to a note or decision. The content is in the blob store;
+/// the event lists it in `payload.attachments` and its blob in `refs.blobs`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Attachment {
+    /// File name, without directories.
+    pub name: String,
+    /// `blob:sha256:<hex>`.
+    pub blob: String,
+    pub size: u64,
+}
+
+impl Attachment {
+    /// The attachments of a note or decision payload; malformed entries are
+    /// skipped.
+    pub fn from_payload(payload: &serde_json::Value) -> Vec<Self> {
+        payload
+            .get("attachments")
+            .and_then(|a| a.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|a| serde_json::from_value(a.clone()).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Markdown link to the blob, relative to the repo root.
+    pub fn markdown_link(&self) -> String {
+        let hex = self.blob.strip_prefix("blob:sha256:").unwrap_or(&self.blob);
+        format!("[{}](.edda/ledger/blobs/{hex})", self.name)
+    }
+}
+
 /// Status of a task brief.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -300,6 +445,8 @@ pub mod rel {
     pub const REVIEWS: &str = "reviews";
     pub const DEPENDS_ON: &str = "depends_on";
     pub const IMPORTED_FROM: &str = "imported_from";
+    pub const REVOKES: &str = "revokes";
+    pub const ANSWERS: &str = "answers";
 }
 
 /// References to other events and blobs
@@ -386,6 +533,7 @@ mod tests {
                 event_family::SIGNAL,
                 event_level::INFO,
             ),
+            ("model_change", event_family::SIGNAL, event_level::INFO),
             (
                 "review_bundle",
                 event_family::GOVERNANCE,
@@ -404,23 +552,42 @@ mod tests {
             ),
             ("device_pair", event_family::ADMIN, event_level::INFO),
             ("device_revoke", event_family::ADMIN, event_level::INFO),
+            ("tag_rename", event_family::ADMIN, event_level::INFO),
+            ("pin", event_family::ADMIN, event_level::INFO),
             (
                 "decide_snapshot",
                 event_family::GOVERNANCE,
                 event_level::MILESTONE,
             ),
             ("cycle_telemetry", event_family::SIGNAL, event_level::INFO),
+            ("recap", event_family::SIGNAL, event_level::INFO),
             (
                 "decision_ratify",
                 event_family::GOVERNANCE,
                 event_level::GOVERNANCE,
             ),
+            ("revoke", event_family::GOVERNANCE, event_level::GOVERNANCE),
             ("task.created", event_family::SIGNAL, event_level::INFO),
             ("task.started", event_family::SIGNAL, event_level::INFO),
             ("task.session", event_family::SIGNAL, event_level::TRACE),
             ("task.done", event_family::MILESTONE, event_level::MILESTONE),
             ("task.failed", event_family::SIGNAL, event_level::INFO),
             ("task.requeued", event_family::ADMIN, event_level::INFO),
+            (
+                "draft.proposed",
+                event_family::GOVERNANCE,
+                event_level::GOVERNANCE,
+            ),
+            (
+                "draft.applied",
+                event_family::GOVERNANCE,
+                event_level::GOVERNANCE,
+            ),
+            (
+                "draft.deleted",
+                event_family::GOVERNANCE,
+                event_level::GOVERNANCE,
+            ),
         ];
 
         for (event_type, expected_family, expected_level) in &table {
@@ -620,8 +787,12 @@ mod tests {
 
     #[test]
     fn decision_scope_ordering() {
+        assert!(DecisionScope::Session < DecisionScope::Branch);
+        assert!(DecisionScope::Branch < DecisionScope::Local);
         assert!(DecisionScope::Local < DecisionScope::Shared);
         assert!(DecisionScope::Shared < DecisionScope::Global);
+        assert!(DecisionScope::Branch.is_narrow());
+        assert!(!DecisionScope::Local.is_narrow());
     }
 
     #[test]
@@ -631,6 +802,10 @@ mod tests {
             ("shared", DecisionScope::Shared),
             ("global", DecisionScope::Global),
             ("SHARED", DecisionScope::Shared),
+            ("session", DecisionScope::Session),
+            ("branch", DecisionScope::Branch),
+            ("project", DecisionScope::Local),
+            ("org", DecisionScope::Global),
         ] {
             let parsed: DecisionScope = s.parse().unwrap();
             assert_eq!(parsed, expected);
@@ -721,6 +896,7 @@ mod tests {
         assert_eq!(rel::REVIEWS, "reviews");
         assert_eq!(rel::DEPENDS_ON, "depends_on");
         assert_eq!(rel::IMPORTED_FROM, "imported_from");
+        assert_eq!(rel::ANSWERS, "answers");
     }
 
     // ── TaskBriefStatus tests ─────────────────────────────────────
@@ -852,4 +1028,70 @@ mod tests {
         let dp3: DecisionPayload = serde_json::from_str(json_no_village).unwrap();
         assert_eq!(dp3.village_id, None);
     }
+
+    #[test]
+    fn note_body_serde_roundtrip_tagged_by_kind() {
+        let json = r#"{"kind":"code","language":"rust","code":"fn main() {}\n"}"#;
+        let body: NoteBody = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            body,
+            NoteBody::Code {
+                language: Some("rust".into()),
+                code: "fn main() {}\n".into()
+            }
+        );
+        let back = serde_json::to_value(&body).unwrap();
+        assert_eq!(back["kind"], "code");
+
+        let payload = serde_json::json!({
+            "text": "refs",
+            "body": {"kind": "links", "links": [{"url": "https://a.dev"}]}
+        });
+        assert!(matches!(
+            NoteBody::from_payload(&payload),
+            Some(NoteBody::Links { .. })
+        ));
+        assert!(NoteBody::from_payload(&serde_json::json!({"text": "plain"})).is_none());
+        assert!(NoteBody::from_payload(&serde_json::json!({"body": {"kind": "bogus"}})).is_none());
+    }
+
+    #[test]
+    fn note_body_render_markdown_and_summary() {
+        let code = NoteBody::Code {
+            language: Some("sql".into()),
+            code: "SELECT 1;\nSELECT 2;\n".into(),
+        };
+        assert_eq!(code.render_markdown(), "```sql\nSELECT 1;\nSELECT 2;\n```");
+        assert_eq!(code.summary(), "[code: sql, 2 lines]");
+
+        let links = NoteBody::Links {
+            links: vec![
+                NoteLink {
+                    url: "https://a.dev".into(),
+                    title: Some("A".into()),
+                },
+                NoteLink {
+                    url: "https://b.dev".into(),
+                    title: None,
+                },
+            ],
+        };
+        assert_eq!(
+            links.render_markdown(),
+            "- [A](https://a.dev)\n- <https://b.dev>"
+        );
+        assert_eq!(links.summary(), "[links: 2]");
+
+        let table = NoteBody::Table {
+            rows: vec![NoteTableRow {
+                key: "p99".into(),
+                value: "a|b".into(),
+            }],
+        };
+        assert_eq!(
+            table.render_markdown(),
+            "| key | value |\n|---|---|\n| p99 | a\\|b |"
+        );
+        assert_eq!(table.summary(), "[table: 1 rows]");
+    }
 }
@@ -16,6 +16,18 @@ pub(super) fn cmd_base_key(signal_text: &str) -> String {
     }
 }
 
+/// Indent every line of a multi-line block, terminating each with `\n`.
+pub(super) fn indent_block(block: &str, indent: &str) -> String {
+    block.lines().map(|l| format!("{indent}{l}\n")).collect()
+}
+
+/// One indented `attached:` line per file, as a Markdown link to its blob.
+pub(super) fn push_attachments(out: &mut String, attachments: &[edda_core::Attachment]) {
+    for a in attachments {
+        out.push_str(&format!("  - attached: {}\n", a.markdown_link()));
+    }
+}
+
 /// Format a task list line with count and optional truncation.
 ///
 /// - Always shows count: `Done (5): ...`
@@ -1,4 +1,5 @@
 mod helpers;
+mod pinned;
 mod session;
 
 use anyhow::Result;
@@ -8,7 +9,8 @@ use std::collections::{BTreeMap, HashSet};
 use crate::snapshot::build_branch_snapshot;
 use crate::types::*;
 
-use helpers::cmd_base_key;
+use helpers::{cmd_base_key, indent_block, push_attachments};
+pub use pinned::{pinned_budget, render_pinned};
 use session::render_session_history;
 
 pub fn render_context(ledger: &Ledger, branch: &str, opt: DeriveOptions) -> Result<String> {
@@ -78,6 +80,8 @@ pub fn render_context(ledger: &Ledger, branch: &str, opt: DeriveOptions) -> Resu
     }
     out.push('\n');
 
+    out.push_str(&render_pinned(ledger, pinned_budget())?);
+
     out.push_str("## Branch\n");
     out.push_str(&format!("- name: {}\n\n", snap.branch));
 
@@ -167,7 +171,37 @@ pub fn render_context(ledger: &Ledger, branch: &str, opt: DeriveOptions) -> Resu
             active_decisions.len()
         ));
         for d in &active_decisions {
-            out.push_str(&format!("- {} ({})\n", d.text, d.event_id));
+            match &d.revoked {
+                Some(reason) => out.push_str(&format!(
+                    "- [revoked: {reason}] {} ({})\n",
+                    d.text, d.event_id
+                )),
+                None => out.push_str(&format!("- {} ({})\n", d.text, d.event_id)),
+            }
+            if let Some(body) = &d.body {
+                out.push_str(&indent_block(&body.render_markdown(), "  "));
+            }
+            push_attachments(&mut out, &d.attachments);
+        }
+        out.push('\n');
+    }
+
+    // Open questions — no time cutoff; they stay until answered
+    let open_questions: Vec<_> = snap.open_questions().collect();
+    if !open_questions.is_empty() {
+        let shown = open_questions.len().min(n.max(5));
+        out.push_str(&format!("## Open Questions ({})\n", open_questions.len()));
+        for q in &open_questions[open_questions.len() - shown..] {
+            out.push_str(&format!("- {} ({})\n", q.text, q.event_id));
+            if let Some(body) = &q.body {
+                out.push_str(&indent_block(&body.render_markdown(), "  "));
+            }
+        }
+        if open_questions.len() > shown {
+            out.push_str(&format!(
+                "- +{} older open questions\n",
+                open_questions.len() - shown
+            ));
         }
         out.push('\n');
     }
@@ -197,7 +231,19 @@ pub fn render_context(ledger: &Ledger, branch: &str, opt: DeriveOptions) -> Resu
         }
 
         for s in &todos {
-            out.push_str(&format!("- NOTE(todo): {} ({})\n", s.text, s.event_id));
+            let mark = if s.revoked.is_some() {
+                " [revoked]"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "- NOTE(todo){mark}: {} ({})\n",
+                s.text, s.event_id
+            ));
+            if let Some(body) = &s.body {
+                out.push_str(&indent_block(&body.render_markdown(), "  "));
+            }
+            push_attachments(&mut out, &s.attachments);
         }
         for (base, group) in &cmd_groups {
             if group.len() == 1 {
@@ -261,6 +307,135 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn pinned_notes_render_within_budget_until_unpinned() {
+        use edda_core::event::new_pin_event;
+        let (tmp, ledger) = setup_workspace();
+
+        let billing = new_note_event(
+            "main",
+            None,
+            "user",
+            "never touch the billing schema without a migration plan",
+            &[],
+        )
+        .unwrap();
+        let other = new_note_event("main", None, "user", "keep the API v1 stable", &[]).unwrap();
+        ledger.append_event(&billing).unwrap();
+        ledger.append_event(&other).unwrap();
+        for id in [&billing.event_id, &other.event_id] {
+            let pin = new_pin_event("main", None, id, true, None).unwrap();
+            ledger.append_event(&pin).unwrap();
+        }
+
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        let pinned_at = ctx.find("## Pinned").unwrap();
+        assert!(pinned_at < ctx.find("## Branch").unwrap());
+        assert!(ctx.contains(&format!(
+            "- never touch the billing schema without a migration plan ({})",
+            billing.event_id
+        )));
+
+        // A tight budget keeps the oldest pin and counts the rest.
+        let section = render_pinned(&ledger, 110).unwrap();
+        assert!(section.contains("billing schema"));
+        assert!(section.contains("+1 more pinned"));
+
+        let unpin = new_pin_event("main", None, &billing.event_id, false, None).unwrap();
+        ledger.append_event(&unpin).unwrap();
+        let section = render_pinned(&ledger, pinned_budget()).unwrap();
+        assert!(!section.contains("billing schema"));
+        assert!(section.contains("keep the API v1 stable"));
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn todo_note_structured_body_rendered_under_signal() {
+        let (tmp, ledger) = setup_workspace();
+
+        let body = edda_core::NoteBody::Code {
+            language: Some("rust".to_string()),
+            code: "assert!(ok);".to_string(),
+        };
+        let note = edda_core::event::new_note_event_with_body(
+            "main",
+            None,
+            "user",
+            "restore the check",
+            &["todo".to_string()],
+            Some(&body),
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        assert!(
+            ctx.contains("- NOTE(todo): restore the check"),
+            "missing todo in:\n{ctx}"
+        );
+        assert!(
+            ctx.contains("  ```rust\n  assert!(ok);\n  ```\n"),
+            "missing indented code block in:\n{ctx}"
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn decision_and_question_bodies_rendered_in_context() {
+        let (tmp, ledger) = setup_workspace();
+
+        let table = edda_core::NoteBody::Table {
+            rows: vec![edda_core::types::NoteTableRow {
+                key: "p99".to_string(),
+                value: "40ms".to_string(),
+            }],
+        };
+        let decision = edda_core::event::new_note_event_with_body(
+            "main",
+            None,
+            "user",
+            "keep the pool at 16",
+            &["decision".to_string()],
+            Some(&table),
+        )
+        .unwrap();
+        ledger.append_event(&decision).unwrap();
+        let links = edda_core::NoteBody::Links {
+            links: vec![edda_core::types::NoteLink {
+                url: "https://example.com/queues".to_string(),
+                title: Some("queue options".to_string()),
+            }],
+        };
+        let question = edda_core::event::new_note_event_with_body(
+            "main",
+            Some(&decision.hash),
+            "user",
+            "which queue?",
+            &["question".to_string()],
+            Some(&links),
+        )
+        .unwrap();
+        ledger.append_event(&question).unwrap();
+
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        let decision_line = format!("- keep the pool at 16 ({})\n", decision.event_id);
+        let decision_body = indent_block(&table.render_markdown(), "  ");
+        assert!(
+            ctx.contains(&format!("{decision_line}{decision_body}")),
+            "missing decision body in:\n{ctx}"
+        );
+        let question_line = format!("- which queue? ({})\n", question.event_id);
+        let question_body = indent_block(&links.render_markdown(), "  ");
+        assert!(
+            ctx.contains(&format!("{question_line}{question_body}")),
+            "missing question body in:\n{ctx}"
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn session_digest_surfaced_in_render_context() {
         let (tmp, ledger) = setup_workspace();
@@ -494,6 +669,33 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn decision_attachments_render_as_links() {
+        let (tmp, ledger) = setup_workspace();
+
+        let tags = vec!["decision".to_string()];
+        let mut d = new_note_event("main", None, "user", "Use SQLite", &tags).unwrap();
+        edda_core::event::attach_files(
+            &mut d,
+            &[edda_core::Attachment {
+                name: "arch.png".into(),
+                blob: "blob:sha256:f00d".into(),
+                size: 10,
+            }],
+        )
+        .unwrap();
+        ledger.append_event(&d).unwrap();
+
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        assert!(
+            ctx.contains("- Use SQLite (")
+                && ctx.contains("  - attached: [arch.png](.edda/ledger/blobs/f00d)\n"),
+            "missing attachment link in:\n{ctx}"
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn decision_notes_no_time_cutoff() {
         let (tmp, ledger) = setup_workspace();
@@ -669,6 +871,56 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn revoked_decision_is_marked_in_context() {
+        let (tmp, ledger) = setup_workspace();
+        let tags = vec!["decision".to_string()];
+        let d = new_note_event("main", None, "system", "db: mysql", &tags).unwrap();
+        ledger.append_event(&d).unwrap();
+        let rv = edda_core::event::new_revoke_event("main", None, &d, "wrong call").unwrap();
+        ledger.append_event(&rv).unwrap();
+
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        assert!(
+            ctx.contains(&format!(
+                "- [revoked: wrong call] db: mysql ({})",
+                d.event_id
+            )),
+            "revoked decision should be marked in:\n{ctx}"
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn open_questions_render_until_answered() {
+        let (tmp, ledger) = setup_workspace();
+        let tags = vec!["question".to_string()];
+        let queue = new_note_event("main", None, "user", "which queue?", &tags).unwrap();
+        let region = new_note_event("main", None, "user", "which region?", &tags).unwrap();
+        ledger.append_event(&queue).unwrap();
+        ledger.append_event(&region).unwrap();
+
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        assert!(ctx.contains("## Open Questions (2)"), "{ctx}");
+        assert!(ctx.contains(&format!("- which queue? ({})", queue.event_id)));
+
+        let answer =
+            edda_core::event::new_answer_event("main", None, &queue, Some("sqs"), None).unwrap();
+        ledger.append_event(&answer).unwrap();
+        let ctx = render_context(&ledger, "main", DeriveOptions::default()).unwrap();
+        assert!(ctx.contains("## Open Questions (1)"), "{ctx}");
+        assert!(!ctx.contains("which queue?"));
+
+        // Revoking the only answer reopens the question.
+        let rv = edda_core::event::new_revoke_event("main", None, &answer, "not decided").unwrap();
+        ledger.append_event(&rv).unwrap();
+        let snap = crate::snapshot::build_branch_snapshot(&ledger, "main").unwrap();
+        assert_eq!(snap.open_questions().count(), 2);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn old_decision_without_structured_fields_still_renders() {
         let (tmp, ledger) = setup_workspace();
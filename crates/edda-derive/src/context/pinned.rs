@@ -0,0 +1,62 @@
+use anyhow::Result;
+use edda_core::types::Event;
+use edda_ledger::Ledger;
+
+/// Default share of the context reserved for pinned items.
+const DEFAULT_PINNED_BUDGET: usize = 1200;
+
+/// Chars reserved for the `## Pinned` section (`EDDA_PINNED_BUDGET_CHARS`).
+pub fn pinned_budget() -> usize {
+    std::env::var("EDDA_PINNED_BUDGET_CHARS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_PINNED_BUDGET)
+}
+
+/// Render the `## Pinned` section, oldest pin first, within `budget` chars.
+///
+/// Items that do not fit are counted in a trailing line instead of being
+/// cut mid-sentence. Returns an empty string when nothing is pinned.
+pub fn render_pinned(ledger: &Ledger, budget: usize) -> Result<String> {
+    let pinned = ledger.pinned_events()?;
+    if pinned.is_empty() {
+        return Ok(String::new());
+    }
+
+    let header = "## Pinned\n";
+    let mut out = String::from(header);
+    let mut dropped = 0;
+    for event in &pinned {
+        let line = format!("- {} ({})\n", pinned_text(event), event.event_id);
+        if dropped == 0 && out.len() + line.len() < budget {
+            out.push_str(&line);
+        } else {
+            dropped += 1;
+        }
+    }
+    if out.len() == header.len() && dropped > 0 {
+        // Not even one item fits; still say that pins exist.
+        return Ok(format!("{header}- ({dropped} pinned, see `edda pin`)\n\n"));
+    }
+    if dropped > 0 {
+        out.push_str(&format!("- (+{dropped} more pinned, see `edda pin`)\n"));
+    }
+    out.push('\n');
+    Ok(out)
+}
+
+/// `key=value — reason` for decisions, the note text otherwise.
+fn pinned_text(event: &Event) -> String {
+    if let Some(dp) = edda_core::decision::extract_decision(&event.payload) {
+        return match dp.reason.as_deref().filter(|r| !r.is_empty()) {
+            Some(reason) => format!("{}={} — {reason}", dp.key, dp.value),
+            None => format!("{}={}", dp.key, dp.value),
+        };
+    }
+    event
+        .payload
+        .get("text")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
@@ -4,10 +4,10 @@ mod snapshot;
 mod types;
 mod writers;
 
-pub use context::render_context;
+pub use context::{pinned_budget, render_context, render_pinned};
 pub use evidence::{build_auto_evidence, last_commit_contribution, AutoEvidenceResult};
 pub use types::*;
-pub use writers::{rebuild_all, rebuild_branch};
+pub use writers::{rebuild_all, rebuild_all_full, rebuild_branch, rebuild_branch_full};
 
 #[cfg(test)]
 pub(crate) mod test_support {
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use edda_core::Event;
 use edda_ledger::Ledger;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::types::*;
@@ -86,31 +87,48 @@ pub(crate) fn resolve_branch_created_at_fallback(
     Ok(None)
 }
 
-pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<BranchSnapshot> {
-    let branch_events = collect_branch_events(ledger, branch)?;
-
-    let mut created_at = branch_events
-        .first()
-        .map(|e| e.ts.clone())
-        .unwrap_or_default();
-    // Fallback: if no events on this branch, check for a branch_create event
-    if created_at.is_empty() {
-        if let Some(ts) = resolve_branch_created_at_fallback(ledger, branch)? {
-            created_at = ts;
+/// Folds a branch's events, oldest first, into a [`BranchSnapshot`].
+///
+/// Persisted between `rebuild_branch` calls so only events appended since
+/// the last rebuild have to be applied.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct SnapshotBuilder {
+    branch: String,
+    created_at: String,
+    last_event_id: Option<String>,
+    commits: Vec<CommitEntry>,
+    signals: Vec<SignalEntry>,
+    merges: Vec<MergeEntry>,
+    session_digests: Vec<SessionDigestEntry>,
+    questions: Vec<QuestionEntry>,
+    uncommitted_events: usize,
+}
+
+impl SnapshotBuilder {
+    pub(crate) fn new(branch: &str) -> Self {
+        Self {
+            branch: branch.to_string(),
+            created_at: String::new(),
+            last_event_id: None,
+            commits: Vec::new(),
+            signals: Vec::new(),
+            merges: Vec::new(),
+            session_digests: Vec::new(),
+            questions: Vec::new(),
+            uncommitted_events: 0,
         }
     }
-    let last_event_id = branch_events.last().map(|e| e.event_id.clone());
 
-    let mut commits: Vec<CommitEntry> = Vec::new();
-    let mut signals: Vec<SignalEntry> = Vec::new();
-    let mut merges: Vec<MergeEntry> = Vec::new();
-    let mut session_digests: Vec<SessionDigestEntry> = Vec::new();
-    let mut last_commit_event_index: Option<usize> = None;
+    pub(crate) fn apply(&mut self, ev: &Event) {
+        if self.created_at.is_empty() {
+            self.created_at = ev.ts.clone();
+        }
+        self.last_event_id = Some(ev.event_id.clone());
+        self.uncommitted_events += 1;
 
-    for (idx, ev) in branch_events.iter().enumerate() {
         match ev.event_type.as_str() {
             "commit" => {
-                last_commit_event_index = Some(idx);
+                self.uncommitted_events = 0;
                 let p = &ev.payload;
                 let evidence_lines = p
                     .get("evidence")
@@ -118,7 +136,7 @@ pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<Bra
                     .map(|arr| arr.iter().filter_map(fmt_evidence_item).collect())
                     .unwrap_or_default();
 
-                commits.push(CommitEntry {
+                self.commits.push(CommitEntry {
                     ts: ev.ts.clone(),
                     event_id: ev.event_id.clone(),
                     title: as_str(p, "title"),
@@ -143,12 +161,15 @@ pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<Bra
                         .get("text")
                         .and_then(|x| x.as_str())
                         .unwrap_or("");
-                    signals.push(SignalEntry {
+                    self.signals.push(SignalEntry {
                         ts: ev.ts.clone(),
                         kind: SignalKind::NoteTodo,
                         text: text.to_string(),
                         event_id: ev.event_id.clone(),
                         supersedes: None,
+                        body: edda_core::NoteBody::from_payload(&ev.payload),
+                        revoked: None,
+                        attachments: edda_core::Attachment::from_payload(&ev.payload),
                     });
                 }
 
@@ -167,15 +188,38 @@ pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<Bra
                         .find(|p| p.rel == "supersedes")
                         .map(|p| p.target.clone());
 
-                    signals.push(SignalEntry {
+                    self.signals.push(SignalEntry {
                         ts: ev.ts.clone(),
                         kind: SignalKind::NoteDecision,
                         text: text.to_string(),
                         event_id: ev.event_id.clone(),
                         supersedes,
+                        body: edda_core::NoteBody::from_payload(&ev.payload),
+                        revoked: None,
+                        attachments: edda_core::Attachment::from_payload(&ev.payload),
+                    });
+                }
+
+                if tags.contains(&"question") {
+                    self.questions.push(QuestionEntry {
+                        ts: ev.ts.clone(),
+                        event_id: ev.event_id.clone(),
+                        text: as_str(&ev.payload, "text"),
+                        body: edda_core::NoteBody::from_payload(&ev.payload),
+                        answers: Vec::new(),
+                        revoked: None,
                     });
                 }
 
+                for p in &ev.refs.provenance {
+                    if p.rel != edda_core::types::rel::ANSWERS {
+                        continue;
+                    }
+                    if let Some(q) = self.questions.iter_mut().find(|q| q.event_id == p.target) {
+                        q.answers.push(ev.event_id.clone());
+                    }
+                }
+
                 if tags.contains(&"session_digest") {
                     let stats = ev.payload.get("session_stats");
                     let sid = ev
@@ -184,7 +228,7 @@ pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<Bra
                         .and_then(|x| x.as_str())
                         .unwrap_or("")
                         .to_string();
-                    session_digests.push(SessionDigestEntry {
+                    self.session_digests.push(SessionDigestEntry {
                         ts: ev.ts.clone(),
                         event_id: ev.event_id.clone(),
                         session_id: sid,
@@ -291,18 +335,21 @@ pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<Bra
                     .unwrap_or(0);
                 if exit_code != 0 {
                     let argv = fmt_cmd_argv(&ev.payload);
-                    signals.push(SignalEntry {
+                    self.signals.push(SignalEntry {
                         ts: ev.ts.clone(),
                         kind: SignalKind::CmdFail,
                         text: format!("{argv} (exit={exit_code})"),
                         event_id: ev.event_id.clone(),
                         supersedes: None,
+                        body: None,
+                        revoked: None,
+                        attachments: Vec::new(),
                     });
                 }
             }
             "merge" => {
                 let p = &ev.payload;
-                merges.push(MergeEntry {
+                self.merges.push(MergeEntry {
                     ts: ev.ts.clone(),
                     event_id: ev.event_id.clone(),
                     src: as_str(p, "src"),
@@ -315,25 +362,55 @@ pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<Bra
         }
     }
 
-    let last_commit = commits.last().cloned();
-    let last_commit_id = last_commit.as_ref().map(|c| c.event_id.clone());
-    let uncommitted_events = match last_commit_event_index {
-        Some(i) => branch_events.len().saturating_sub(i + 1),
-        None => branch_events.len(),
-    };
-
-    Ok(BranchSnapshot {
-        branch: branch.to_string(),
-        created_at,
-        last_event_id,
-        last_commit_id,
-        last_commit,
-        commits,
-        signals,
-        merges,
-        session_digests,
-        uncommitted_events,
-    })
+    /// Snapshot of everything applied so far. Revocations are looked up
+    /// fresh, since a revoke can land on any branch after the event.
+    pub(crate) fn finish(&self, ledger: &Ledger) -> Result<BranchSnapshot> {
+        let mut created_at = self.created_at.clone();
+        // Fallback: if no events on this branch, check for a branch_create event
+        if created_at.is_empty() {
+            if let Some(ts) = resolve_branch_created_at_fallback(ledger, &self.branch)? {
+                created_at = ts;
+            }
+        }
+
+        let revoked = ledger.revoked_events()?;
+        let mut signals = self.signals.clone();
+        for s in &mut signals {
+            s.revoked = revoked.get(&s.event_id).cloned();
+        }
+
+        // Revoking a question closes it; revoking its only answer reopens it.
+        let mut questions = self.questions.clone();
+        for q in &mut questions {
+            q.revoked = revoked.get(&q.event_id).cloned();
+            q.answers.retain(|a| !revoked.contains_key(a));
+        }
+
+        let last_commit = self.commits.last().cloned();
+        let last_commit_id = last_commit.as_ref().map(|c| c.event_id.clone());
+
+        Ok(BranchSnapshot {
+            branch: self.branch.clone(),
+            created_at,
+            last_event_id: self.last_event_id.clone(),
+            last_commit_id,
+            last_commit,
+            commits: self.commits.clone(),
+            signals,
+            merges: self.merges.clone(),
+            session_digests: self.session_digests.clone(),
+            questions,
+            uncommitted_events: self.uncommitted_events,
+        })
+    }
+}
+
+pub(crate) fn build_branch_snapshot(ledger: &Ledger, branch: &str) -> Result<BranchSnapshot> {
+    let mut builder = SnapshotBuilder::new(branch);
+    for ev in &collect_branch_events(ledger, branch)? {
+        builder.apply(ev);
+    }
+    builder.finish(ledger)
 }
 
 #[cfg(test)]
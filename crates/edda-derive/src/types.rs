@@ -1,8 +1,9 @@
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 // ── Data structures ──
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitEntry {
     pub ts: String,
     pub event_id: String,
@@ -14,14 +15,14 @@ pub struct CommitEntry {
     pub labels: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SignalKind {
     NoteTodo,
     NoteDecision,
     CmdFail,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignalEntry {
     pub ts: String,
     pub kind: SignalKind,
@@ -29,9 +30,39 @@ pub struct SignalEntry {
     pub event_id: String,
     /// Event ID this decision supersedes (from refs.provenance).
     pub supersedes: Option<String>,
+    /// Structured note body (`payload.body`), if the note carried one.
+    pub body: Option<edda_core::NoteBody>,
+    /// Revocation reason, if the event was walked back with `edda revoke`.
+    pub revoked: Option<String>,
+    /// Files attached to the note or decision.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<edda_core::Attachment>,
 }
 
-#[derive(Debug, Clone)]
+/// A note tagged `question`. It stays open until a later note or decision
+/// links to it with an `answers` provenance entry (see `edda answer`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionEntry {
+    pub ts: String,
+    pub event_id: String,
+    pub text: String,
+    /// Structured note body (`payload.body`), if the question carried one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<edda_core::NoteBody>,
+    /// Event IDs of the notes and decisions that answer it, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub answers: Vec<String>,
+    /// Revocation reason, if the question was walked back with `edda revoke`.
+    pub revoked: Option<String>,
+}
+
+impl QuestionEntry {
+    pub fn is_open(&self) -> bool {
+        self.answers.is_empty() && self.revoked.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MergeEntry {
     pub ts: String,
     pub event_id: String,
@@ -42,13 +73,14 @@ pub struct MergeEntry {
 }
 
 /// A task snapshot entry within a session digest.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskSnapshotEntry {
     pub subject: String,
     pub status: String,
 }
 
 /// A session digest note extracted from the workspace ledger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionDigestEntry {
     pub ts: String,
     pub event_id: String,
@@ -85,9 +117,17 @@ pub struct BranchSnapshot {
     pub signals: Vec<SignalEntry>,
     pub merges: Vec<MergeEntry>,
     pub session_digests: Vec<SessionDigestEntry>,
+    pub questions: Vec<QuestionEntry>,
     pub uncommitted_events: usize,
 }
 
+impl BranchSnapshot {
+    /// Questions with no answer that have not been revoked, oldest first.
+    pub fn open_questions(&self) -> impl Iterator<Item = &QuestionEntry> {
+        self.questions.iter().filter(|q| q.is_open())
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct DeriveOptions {
     pub depth: usize,
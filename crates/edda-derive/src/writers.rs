@@ -1,14 +1,14 @@
 use anyhow::Result;
+use edda_core::Event;
 use edda_ledger::Ledger;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::{BTreeMap, HashSet};
 use std::fs;
+use std::io::Write;
 use std::path::Path;
 
-use crate::snapshot::{
-    as_arr_str, as_str, build_branch_snapshot, collect_branch_events, fmt_cmd_argv,
-};
+use crate::snapshot::{as_arr_str, as_str, fmt_cmd_argv, SnapshotBuilder};
 use crate::types::*;
 
 // ── View writers ──
@@ -67,172 +67,220 @@ fn write_commit_md(dir: &Path, snap: &BranchSnapshot) -> Result<()> {
     Ok(())
 }
 
-fn write_log_md(dir: &Path, ledger: &Ledger, branch: &str) -> Result<()> {
-    let branch_events = collect_branch_events(ledger, branch)?;
+/// Write `log.md` for `events`, or append them to the existing file.
+/// Returns the file's new size.
+fn write_log_md(dir: &Path, events: &[&Event], append: bool) -> Result<u64> {
     let mut out = String::new();
+    for ev in events {
+        push_log_line(&mut out, ev);
+    }
+    let path = dir.join("log.md");
+    if append {
+        let mut file = fs::OpenOptions::new().append(true).open(&path)?;
+        file.write_all(out.as_bytes())?;
+    } else {
+        fs::write(&path, out.as_bytes())?;
+    }
+    Ok(fs::metadata(&path)?.len())
+}
 
-    for ev in &branch_events {
-        match ev.event_type.as_str() {
-            "note" => {
-                let role = ev
-                    .payload
-                    .get("role")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("user");
-                let text = ev
-                    .payload
-                    .get("text")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("");
-                let tags: String = ev
-                    .payload
-                    .get("tags")
-                    .and_then(|x| x.as_array())
-                    .map(|arr| {
-                        arr.iter()
-                            .filter_map(|i| i.as_str())
-                            .collect::<Vec<_>>()
-                            .join(",")
-                    })
-                    .unwrap_or_default();
-
-                if tags.is_empty() {
-                    out.push_str(&format!(
-                        "[{}] NOTE({}): {} ({})\n",
-                        ev.ts, role, text, ev.event_id
-                    ));
-                } else {
-                    out.push_str(&format!(
-                        "[{}] NOTE({}) tags={}: {} ({})\n",
-                        ev.ts, role, tags, text, ev.event_id
-                    ));
-                }
-            }
-            "cmd" => {
-                let exit_code = ev
-                    .payload
-                    .get("exit_code")
-                    .and_then(|x| x.as_i64())
-                    .unwrap_or(0);
-                let argv = fmt_cmd_argv(&ev.payload);
-                let stdout_blob = ev
-                    .payload
-                    .get("stdout_blob")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("");
-                let stderr_blob = ev
-                    .payload
-                    .get("stderr_blob")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("");
-                out.push_str(&format!(
-                    "[{}] CMD exit={}: {} (stdout={}, stderr={}) ({})\n",
-                    ev.ts, exit_code, argv, stdout_blob, stderr_blob, ev.event_id
-                ));
-            }
-            "commit" => {
-                let title = ev
-                    .payload
-                    .get("title")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("");
-                out.push_str(&format!(
-                    "[{}] COMMIT: {} ({})\n",
-                    ev.ts, title, ev.event_id
-                ));
-            }
-            "rebuild" => {
-                let scope = ev
-                    .payload
-                    .get("scope")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("");
-                let reason = ev
-                    .payload
-                    .get("reason")
-                    .and_then(|x| x.as_str())
-                    .unwrap_or("");
+fn push_log_line(out: &mut String, ev: &Event) {
+    match ev.event_type.as_str() {
+        "note" => {
+            let role = ev
+                .payload
+                .get("role")
+                .and_then(|x| x.as_str())
+                .unwrap_or("user");
+            let text = ev
+                .payload
+                .get("text")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            let tags: String = ev
+                .payload
+                .get("tags")
+                .and_then(|x| x.as_array())
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|i| i.as_str())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .unwrap_or_default();
+
+            if tags.is_empty() {
                 out.push_str(&format!(
-                    "[{}] REBUILD scope={} reason={} ({})\n",
-                    ev.ts, scope, reason, ev.event_id
+                    "[{}] NOTE({}): {} ({})\n",
+                    ev.ts, role, text, ev.event_id
                 ));
-            }
-            "branch_create" => {
-                let name = as_str(&ev.payload, "name");
-                let purpose = as_str(&ev.payload, "purpose");
-                out.push_str(&format!(
-                    "[{}] BRANCH_CREATE: {} purpose=\"{}\" ({})\n",
-                    ev.ts, name, purpose, ev.event_id
-                ));
-            }
-            "branch_switch" => {
-                let from = as_str(&ev.payload, "from");
-                let to = as_str(&ev.payload, "to");
-                out.push_str(&format!(
-                    "[{}] SWITCH: {} -> {} ({})\n",
-                    ev.ts, from, to, ev.event_id
-                ));
-            }
-            "merge" => {
-                let src = as_str(&ev.payload, "src");
-                let dst = as_str(&ev.payload, "dst");
-                let reason = as_str(&ev.payload, "reason");
-                let adopted = ev
-                    .payload
-                    .get("adopted_commits")
-                    .and_then(|x| x.as_array())
-                    .map(|a| a.len())
-                    .unwrap_or(0);
+            } else {
                 out.push_str(&format!(
-                    "[{}] MERGE: {} -> {} adopted={} reason=\"{}\" ({})\n",
-                    ev.ts, src, dst, adopted, reason, ev.event_id
+                    "[{}] NOTE({}) tags={}: {} ({})\n",
+                    ev.ts, role, tags, text, ev.event_id
                 ));
             }
-            "approval" => {
-                let draft_id = as_str(&ev.payload, "draft_id");
-                let decision = as_str(&ev.payload, "decision");
-                let actor = as_str(&ev.payload, "actor");
-                let stage_id = as_str(&ev.payload, "stage_id");
-                let role = as_str(&ev.payload, "role");
-                if stage_id.is_empty() {
-                    out.push_str(&format!(
-                        "[{}] APPROVAL {} by={} draft={} ({})\n",
-                        ev.ts, decision, actor, draft_id, ev.event_id
-                    ));
-                } else {
-                    out.push_str(&format!(
-                        "[{}] APPROVAL {} by={} draft={} stage={} role={} ({})\n",
-                        ev.ts, decision, actor, draft_id, stage_id, role, ev.event_id
-                    ));
+            if let Some(body) = edda_core::NoteBody::from_payload(&ev.payload) {
+                for line in body.render_markdown().lines() {
+                    out.push_str(&format!("    {line}\n"));
                 }
             }
-            "approval_request" => {
-                let draft_id = as_str(&ev.payload, "draft_id");
-                let stage_id = as_str(&ev.payload, "stage_id");
-                let role = as_str(&ev.payload, "role");
-                let assignees = as_arr_str(&ev.payload, "assignees");
+        }
+        "cmd" => {
+            let exit_code = ev
+                .payload
+                .get("exit_code")
+                .and_then(|x| x.as_i64())
+                .unwrap_or(0);
+            let argv = fmt_cmd_argv(&ev.payload);
+            let stdout_blob = ev
+                .payload
+                .get("stdout_blob")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            let stderr_blob = ev
+                .payload
+                .get("stderr_blob")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            out.push_str(&format!(
+                "[{}] CMD exit={}: {} (stdout={}, stderr={}) ({})\n",
+                ev.ts, exit_code, argv, stdout_blob, stderr_blob, ev.event_id
+            ));
+        }
+        "commit" => {
+            let title = ev
+                .payload
+                .get("title")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            out.push_str(&format!(
+                "[{}] COMMIT: {} ({})\n",
+                ev.ts, title, ev.event_id
+            ));
+        }
+        "rebuild" => {
+            let scope = ev
+                .payload
+                .get("scope")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            let reason = ev
+                .payload
+                .get("reason")
+                .and_then(|x| x.as_str())
+                .unwrap_or("");
+            out.push_str(&format!(
+                "[{}] REBUILD scope={} reason={} ({})\n",
+                ev.ts, scope, reason, ev.event_id
+            ));
+        }
+        "branch_create" => {
+            let name = as_str(&ev.payload, "name");
+            let purpose = as_str(&ev.payload, "purpose");
+            out.push_str(&format!(
+                "[{}] BRANCH_CREATE: {} purpose=\"{}\" ({})\n",
+                ev.ts, name, purpose, ev.event_id
+            ));
+        }
+        "branch_switch" => {
+            let from = as_str(&ev.payload, "from");
+            let to = as_str(&ev.payload, "to");
+            out.push_str(&format!(
+                "[{}] SWITCH: {} -> {} ({})\n",
+                ev.ts, from, to, ev.event_id
+            ));
+        }
+        "merge" => {
+            let src = as_str(&ev.payload, "src");
+            let dst = as_str(&ev.payload, "dst");
+            let reason = as_str(&ev.payload, "reason");
+            let adopted = ev
+                .payload
+                .get("adopted_commits")
+                .and_then(|x| x.as_array())
+                .map(|a| a.len())
+                .unwrap_or(0);
+            out.push_str(&format!(
+                "[{}] MERGE: {} -> {} adopted={} reason=\"{}\" ({})\n",
+                ev.ts, src, dst, adopted, reason, ev.event_id
+            ));
+        }
+        "approval" => {
+            let draft_id = as_str(&ev.payload, "draft_id");
+            let decision = as_str(&ev.payload, "decision");
+            let actor = as_str(&ev.payload, "actor");
+            let stage_id = as_str(&ev.payload, "stage_id");
+            let role = as_str(&ev.payload, "role");
+            if stage_id.is_empty() {
                 out.push_str(&format!(
-                    "[{}] APPROVAL_REQUEST draft={} stage={} role={} assignees={} ({})\n",
-                    ev.ts,
-                    draft_id,
-                    stage_id,
-                    role,
-                    assignees.join(","),
-                    ev.event_id
+                    "[{}] APPROVAL {} by={} draft={} ({})\n",
+                    ev.ts, decision, actor, draft_id, ev.event_id
                 ));
-            }
-            other => {
+            } else {
                 out.push_str(&format!(
-                    "[{}] {} ({})\n",
-                    ev.ts,
-                    other.to_uppercase(),
-                    ev.event_id
+                    "[{}] APPROVAL {} by={} draft={} stage={} role={} ({})\n",
+                    ev.ts, decision, actor, draft_id, stage_id, role, ev.event_id
                 ));
             }
         }
+        "approval_request" => {
+            let draft_id = as_str(&ev.payload, "draft_id");
+            let stage_id = as_str(&ev.payload, "stage_id");
+            let role = as_str(&ev.payload, "role");
+            let assignees = as_arr_str(&ev.payload, "assignees");
+            out.push_str(&format!(
+                "[{}] APPROVAL_REQUEST draft={} stage={} role={} assignees={} ({})\n",
+                ev.ts,
+                draft_id,
+                stage_id,
+                role,
+                assignees.join(","),
+                ev.event_id
+            ));
+        }
+        "draft.proposed" => {
+            let draft_id = as_str(&ev.payload, "draft_id");
+            let title = as_str(&ev.payload, "title");
+            let stages: Vec<&str> = ev
+                .payload
+                .get("stages")
+                .and_then(|x| x.as_array())
+                .map(|a| a.iter().filter_map(|s| s["stage_id"].as_str()).collect())
+                .unwrap_or_default();
+            out.push_str(&format!(
+                "[{}] DRAFT_PROPOSED draft={} title=\"{}\" stages={} ({})\n",
+                ev.ts,
+                draft_id,
+                title,
+                stages.join(","),
+                ev.event_id
+            ));
+        }
+        "draft.applied" => {
+            let draft_id = as_str(&ev.payload, "draft_id");
+            let commit = as_str(&ev.payload, "commit_event_id");
+            out.push_str(&format!(
+                "[{}] DRAFT_APPLIED draft={} commit={} ({})\n",
+                ev.ts, draft_id, commit, ev.event_id
+            ));
+        }
+        "draft.deleted" => {
+            let draft_id = as_str(&ev.payload, "draft_id");
+            out.push_str(&format!(
+                "[{}] DRAFT_DELETED draft={} ({})\n",
+                ev.ts, draft_id, ev.event_id
+            ));
+        }
+        other => {
+            out.push_str(&format!(
+                "[{}] {} ({})\n",
+                ev.ts,
+                other.to_uppercase(),
+                ev.event_id
+            ));
+        }
     }
-    fs::write(dir.join("log.md"), out.as_bytes())?;
-    Ok(())
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -338,23 +386,156 @@ fn list_branches_from_ledger(ledger: &Ledger) -> Result<Vec<String>> {
     Ok(v)
 }
 
+// ── Incremental state ──
+
+/// Bumped whenever [`SnapshotBuilder`] changes shape; older state files are
+/// ignored and the branch is replayed in full.
+const SNAPSHOT_STATE_VERSION: u32 = 2;
+const SNAPSHOT_STATE_FILE: &str = "snapshot.json";
+
+/// The newest ledger event (on any branch) a snapshot has seen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SnapshotCursor {
+    rowid: i64,
+    event_id: String,
+    hash: String,
+}
+
+/// `snapshot.json` in the branch view directory.
+#[derive(Serialize, Deserialize)]
+struct SnapshotState {
+    version: u32,
+    cursor: Option<SnapshotCursor>,
+    /// Size of `log.md` after the last write.
+    log_len: u64,
+    builder: SnapshotBuilder,
+}
+
+fn cursor_of(rowid: i64, ev: &Event) -> SnapshotCursor {
+    SnapshotCursor {
+        rowid,
+        event_id: ev.event_id.clone(),
+        hash: ev.hash.clone(),
+    }
+}
+
+fn read_state(dir: &Path) -> Option<SnapshotState> {
+    let content = fs::read_to_string(dir.join(SNAPSHOT_STATE_FILE)).ok()?;
+    let state: SnapshotState = serde_json::from_str(&content).ok()?;
+    (state.version == SNAPSHOT_STATE_VERSION).then_some(state)
+}
+
+fn write_state(dir: &Path, state: &SnapshotState) -> Result<()> {
+    fs::write(
+        dir.join(SNAPSHOT_STATE_FILE),
+        serde_json::to_vec(state)?.as_slice(),
+    )?;
+    Ok(())
+}
+
+/// Events appended since `cursor`, or `None` when the cursor no longer
+/// lines up with the ledger: the event is gone, moved, or re-hashed, or the
+/// next event does not chain onto it.
+fn events_since(ledger: &Ledger, cursor: &SnapshotCursor) -> Result<Option<Vec<(i64, Event)>>> {
+    if ledger.rowid_for_event_id(&cursor.event_id)? != Some(cursor.rowid) {
+        return Ok(None);
+    }
+    match ledger.get_event(&cursor.event_id)? {
+        Some(ev) if ev.hash == cursor.hash => {}
+        _ => return Ok(None),
+    }
+    let tail = ledger.events_after_rowid(cursor.rowid)?;
+    if let Some((_, first)) = tail.first() {
+        if first
+            .parent_hash
+            .as_deref()
+            .is_some_and(|h| h != cursor.hash)
+        {
+            return Ok(None);
+        }
+    }
+    Ok(Some(tail))
+}
+
 // ── Public API ──
 
+/// Rebuild a branch's views, applying only the events appended since the
+/// previous call. Falls back to a full replay when there is no saved state
+/// or the ledger no longer matches it.
 pub fn rebuild_branch(ledger: &Ledger, branch: &str) -> Result<BranchSnapshot> {
-    let snap = build_branch_snapshot(ledger, branch)?;
+    rebuild_branch_with(ledger, branch, false)
+}
+
+/// Rebuild a branch's views by replaying its whole event log.
+pub fn rebuild_branch_full(ledger: &Ledger, branch: &str) -> Result<BranchSnapshot> {
+    rebuild_branch_with(ledger, branch, true)
+}
+
+fn rebuild_branch_with(ledger: &Ledger, branch: &str, full: bool) -> Result<BranchSnapshot> {
     let dir = ensure_branch_dir(ledger, branch)?;
+
+    let saved = if full { None } else { read_state(&dir) };
+    let mut resumed = None;
+    if let Some(state) = saved {
+        // log.md is appended to, so it must still be exactly what we wrote.
+        let log_len = fs::metadata(dir.join("log.md")).map(|m| m.len()).ok();
+        if log_len == Some(state.log_len) {
+            let tail = match &state.cursor {
+                Some(cursor) => events_since(ledger, cursor)?,
+                None => Some(ledger.events_after_rowid(0)?),
+            };
+            resumed = tail.map(|tail| (state, tail));
+        }
+    }
+    let append = resumed.is_some();
+    let (mut state, events) = match resumed {
+        Some(r) => r,
+        None => (
+            SnapshotState {
+                version: SNAPSHOT_STATE_VERSION,
+                cursor: None,
+                log_len: 0,
+                builder: SnapshotBuilder::new(branch),
+            },
+            ledger.events_after_rowid(0)?,
+        ),
+    };
+
+    if let Some((rowid, ev)) = events.last() {
+        state.cursor = Some(cursor_of(*rowid, ev));
+    }
+    let branch_events: Vec<&Event> = events
+        .iter()
+        .map(|(_, ev)| ev)
+        .filter(|ev| ev.branch == branch)
+        .collect();
+    for ev in &branch_events {
+        state.builder.apply(ev);
+    }
+    let snap = state.builder.finish(ledger)?;
+
     write_commit_md(&dir, &snap)?;
-    write_log_md(&dir, ledger, branch)?;
+    state.log_len = write_log_md(&dir, &branch_events, append)?;
     write_metadata_yaml(&dir, ledger, &snap)?;
     write_main_md(&dir, ledger, &snap)?;
+    write_state(&dir, &state)?;
     Ok(snap)
 }
 
 pub fn rebuild_all(ledger: &Ledger) -> Result<Vec<BranchSnapshot>> {
+    rebuild_all_with(ledger, false)
+}
+
+/// [`rebuild_all`] with a full replay of every branch.
+pub fn rebuild_all_full(ledger: &Ledger) -> Result<Vec<BranchSnapshot>> {
+    rebuild_all_with(ledger, true)
+}
+
+fn rebuild_all_with(ledger: &Ledger, full: bool) -> Result<Vec<BranchSnapshot>> {
     let branches = list_branches_from_ledger(ledger)?;
     let mut snaps: Vec<BranchSnapshot> = Vec::new();
     for b in &branches {
-        snaps.push(rebuild_branch(ledger, b)?);
+        snaps.push(rebuild_branch_with(ledger, b, full)?);
     }
     write_branches_json(ledger, &snaps)?;
     Ok(snaps)
@@ -490,4 +671,73 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&tmp);
     }
+
+    #[test]
+    fn rebuild_branch_applies_only_new_events() {
+        let (tmp, ledger) = setup_workspace();
+        let dir = ledger.paths.branches_dir.join("main");
+
+        let n1 = new_note_event("main", None, "user", "first", &["todo".into()]).unwrap();
+        ledger.append_event(&n1).unwrap();
+        rebuild_branch(&ledger, "main").unwrap();
+        let state = read_state(&dir).expect("state saved");
+        assert!(state.cursor.is_some());
+
+        let other = new_note_event("feature", None, "user", "elsewhere", &[]).unwrap();
+        ledger.append_event(&other).unwrap();
+        let n2 = new_note_event("main", None, "user", "second", &["todo".into()]).unwrap();
+        ledger.append_event(&n2).unwrap();
+        let snap = rebuild_branch(&ledger, "main").unwrap();
+        let incremental_log = std::fs::read_to_string(dir.join("log.md")).unwrap();
+
+        let full = rebuild_branch_full(&ledger, "main").unwrap();
+        let full_log = std::fs::read_to_string(dir.join("log.md")).unwrap();
+        assert_eq!(incremental_log, full_log);
+        assert_eq!(snap.signals.len(), 2);
+        assert_eq!(snap.signals.len(), full.signals.len());
+        assert_eq!(snap.uncommitted_events, full.uncommitted_events);
+        assert_eq!(snap.last_event_id, full.last_event_id);
+        assert!(!full_log.contains("elsewhere"));
+
+        // Nothing new: the log is left alone.
+        rebuild_branch(&ledger, "main").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("log.md")).unwrap(),
+            full_log
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rebuild_branch_replays_in_full_when_state_is_stale() {
+        let (tmp, ledger) = setup_workspace();
+        let dir = ledger.paths.branches_dir.join("main");
+
+        let n1 = new_note_event("main", None, "user", "first", &[]).unwrap();
+        ledger.append_event(&n1).unwrap();
+        rebuild_branch(&ledger, "main").unwrap();
+
+        // A cursor whose event no longer hashes the same: the chain is broken.
+        let mut state = read_state(&dir).unwrap();
+        state.cursor.as_mut().unwrap().hash = "sha256:bogus".into();
+        state.builder = SnapshotBuilder::new("main");
+        write_state(&dir, &state).unwrap();
+
+        let n2 = new_note_event("main", None, "user", "second", &[]).unwrap();
+        ledger.append_event(&n2).unwrap();
+        let snap = rebuild_branch(&ledger, "main").unwrap();
+        assert_eq!(snap.uncommitted_events, 2);
+        let log = std::fs::read_to_string(dir.join("log.md")).unwrap();
+        assert!(log.contains("first") && log.contains("second"));
+
+        // A log.md edited behind our back is rewritten too.
+        std::fs::write(dir.join("log.md"), "garbage\n").unwrap();
+        rebuild_branch(&ledger, "main").unwrap();
+        let log = std::fs::read_to_string(dir.join("log.md")).unwrap();
+        assert!(!log.contains("garbage"));
+        assert_eq!(log.lines().count(), 2);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
-use std::io::{Read, Seek, SeekFrom, Write};
-use std::path::Path;
+use std::collections::BTreeSet;
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
 // ── IndexRecordV1 ──
 
@@ -43,11 +44,33 @@ pub struct UsageMeta {
 
 // ── Index operations ──
 
+/// Active index files are rotated into a segment once they reach this size.
+pub const ROTATE_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Subdirectory of the index directory holding rotated segments and their
+/// manifests.
+pub const SEGMENTS_DIR: &str = "segments";
+
 /// Append a single IndexRecordV1 line to the index JSONL file.
+///
+/// A full active file is first rotated into a segment (see
+/// [`rotate_index`]), so it never grows past roughly [`ROTATE_BYTES`].
 pub fn append_index(index_path: &Path, record: &IndexRecordV1) -> anyhow::Result<()> {
+    append_with_limit(index_path, record, ROTATE_BYTES)
+}
+
+fn append_with_limit(
+    index_path: &Path,
+    record: &IndexRecordV1,
+    rotate_bytes: u64,
+) -> anyhow::Result<()> {
     if let Some(parent) = index_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
+    // Rotate before appending so the active file is never left empty.
+    if std::fs::metadata(index_path).is_ok_and(|m| m.len() >= rotate_bytes) {
+        rotate_index(index_path)?;
+    }
     let line = serde_json::to_string(record)?;
     let mut file = std::fs::OpenOptions::new()
         .create(true)
@@ -57,21 +80,55 @@ pub fn append_index(index_path: &Path, record: &IndexRecordV1) -> anyhow::Result
     Ok(())
 }
 
-/// Read the tail of an index file (last N lines, up to max_bytes).
+/// Read the tail of an index (last N lines, up to max_bytes).
+///
+/// Starts with the active file and walks back through rotated segments,
+/// newest first, only while lines and bytes remain, so the cost follows
+/// what was asked for rather than the session's total history.
 pub fn read_index_tail(
     index_path: &Path,
     max_lines: usize,
     max_bytes: u64,
 ) -> anyhow::Result<Vec<IndexRecordV1>> {
-    if !index_path.exists() {
-        return Ok(vec![]);
+    let (mut records, mut lines, mut bytes) = read_file_tail(index_path, max_lines, max_bytes)?;
+    if lines >= max_lines || bytes >= max_bytes {
+        return Ok(records);
+    }
+
+    let manifest = read_manifest(index_path)?;
+    let dir = segments_dir(index_path);
+    for segment in manifest.segments.iter().rev() {
+        let (older, n, read) = read_file_tail(
+            &dir.join(&segment.file),
+            max_lines - lines,
+            max_bytes - bytes,
+        )?;
+        records.splice(0..0, older);
+        lines += n;
+        bytes += read;
+        if lines >= max_lines || bytes >= max_bytes {
+            break;
+        }
+    }
+    Ok(records)
+}
+
+/// Tail of one JSONL file: the parsed records, the number of lines taken and
+/// the bytes read. A missing file reads as empty.
+fn read_file_tail(
+    path: &Path,
+    max_lines: usize,
+    max_bytes: u64,
+) -> anyhow::Result<(Vec<IndexRecordV1>, usize, u64)> {
+    if !path.exists() {
+        return Ok((vec![], 0, 0));
     }
 
-    let meta = std::fs::metadata(index_path)?;
+    let meta = std::fs::metadata(path)?;
     let file_size = meta.len();
     let read_from = file_size.saturating_sub(max_bytes);
 
-    let mut file = std::fs::File::open(index_path)?;
+    let mut file = std::fs::File::open(path)?;
     file.seek(SeekFrom::Start(read_from))?;
 
     let mut buf = String::new();
@@ -88,23 +145,302 @@ pub fn read_index_tail(
         &buf
     };
 
-    let all_lines: Vec<&str> = lines_str.lines().collect();
-    let start = if all_lines.len() > max_lines {
-        all_lines.len() - max_lines
-    } else {
-        0
-    };
+    let all_lines: Vec<&str> = lines_str.lines().filter(|l| !l.is_empty()).collect();
+    let start = all_lines.len().saturating_sub(max_lines);
 
     let mut records = Vec::new();
     for line in &all_lines[start..] {
-        if line.is_empty() {
-            continue;
-        }
         if let Ok(rec) = serde_json::from_str::<IndexRecordV1>(line) {
             records.push(rec);
         }
     }
-    Ok(records)
+    Ok((records, all_lines.len() - start, file_size - read_from))
+}
+
+// ── Rotation ──
+
+/// One rotated segment of a session index.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SegmentInfo {
+    /// File name inside the segments directory, e.g. `<session>.0001.jsonl`.
+    pub file: String,
+    pub records: usize,
+    pub first_ts: String,
+    pub last_ts: String,
+    /// Lowest `store_offset` in the segment.
+    pub store_start: u64,
+    /// Highest `store_offset + store_len` in the segment.
+    pub store_end: u64,
+}
+
+/// `segments/<session>.manifest.json`: a session's rotated segments, oldest
+/// first.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IndexManifest {
+    pub segments: Vec<SegmentInfo>,
+    /// Store length at the last [`compact_index`]. While the store is at
+    /// least this long it has only been appended to, so segments ending
+    /// inside it are skipped unread; a shorter store means it was rewritten
+    /// and every record is re-checked.
+    #[serde(default)]
+    pub checkpoint_store_len: Option<u64>,
+}
+
+/// The segments directory next to an active index file.
+pub fn segments_dir(index_path: &Path) -> PathBuf {
+    index_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join(SEGMENTS_DIR)
+}
+
+fn session_stem(index_path: &Path) -> String {
+    index_path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+/// Manifest path for the session whose active index is `index_path`.
+pub fn manifest_path(index_path: &Path) -> PathBuf {
+    segments_dir(index_path).join(format!("{}.manifest.json", session_stem(index_path)))
+}
+
+/// Read a session's manifest; a session that never rotated has an empty one.
+pub fn read_manifest(index_path: &Path) -> anyhow::Result<IndexManifest> {
+    match std::fs::read_to_string(manifest_path(index_path)) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(IndexManifest::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn write_manifest(index_path: &Path, manifest: &IndexManifest) -> anyhow::Result<()> {
+    std::fs::create_dir_all(segments_dir(index_path))?;
+    edda_store::write_atomic(
+        &manifest_path(index_path),
+        serde_json::to_string_pretty(manifest)?.as_bytes(),
+    )
+}
+
+/// Segment stats for a set of records; `None` when there are none.
+fn segment_info(file: String, records: &[IndexRecordV1]) -> Option<SegmentInfo> {
+    let first = records.first()?;
+    let last = records.last()?;
+    Some(SegmentInfo {
+        file,
+        records: records.len(),
+        first_ts: first.ts.clone(),
+        last_ts: last.ts.clone(),
+        store_start: records.iter().map(|r| r.store_offset).min().unwrap_or(0),
+        store_end: records
+            .iter()
+            .map(|r| r.store_offset.saturating_add(r.store_len))
+            .max()
+            .unwrap_or(0),
+    })
+}
+
+fn read_records(path: &Path) -> anyhow::Result<Vec<IndexRecordV1>> {
+    Ok(read_file_tail(path, usize::MAX, u64::MAX)?.0)
+}
+
+/// Move the active index file into the next numbered segment and record it
+/// in the manifest. Returns the new segment, or `None` when there was
+/// nothing to rotate.
+///
+/// The manifest is written before the file moves: a crash in between leaves
+/// an entry for a segment that does not exist yet (skipped by readers and
+/// dropped by [`compact_index`]), never records that readers cannot find.
+pub fn rotate_index(index_path: &Path) -> anyhow::Result<Option<SegmentInfo>> {
+    let records = read_records(index_path)?;
+    let mut manifest = read_manifest(index_path)?;
+    let next = manifest
+        .segments
+        .iter()
+        .filter_map(|s| s.file.rsplit('.').nth(1)?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let file = format!("{}.{next:04}.jsonl", session_stem(index_path));
+    let Some(info) = segment_info(file, &records) else {
+        return Ok(None);
+    };
+
+    manifest.segments.push(info.clone());
+    write_manifest(index_path, &manifest)?;
+    std::fs::rename(index_path, segments_dir(index_path).join(&info.file))?;
+    Ok(Some(info))
+}
+
+/// Session ids with an index in `index_dir`: an active file, rotated
+/// segments, or both.
+pub fn indexed_sessions(index_dir: &Path) -> anyhow::Result<Vec<String>> {
+    let mut ids = BTreeSet::new();
+    for (dir, suffix) in [
+        (index_dir.to_path_buf(), ".jsonl"),
+        (index_dir.join(SEGMENTS_DIR), ".manifest.json"),
+    ] {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            if !entry.file_type().is_ok_and(|t| t.is_file()) {
+                continue;
+            }
+            let name = entry.file_name().to_string_lossy().to_string();
+            if let Some(id) = name.strip_suffix(suffix).filter(|id| !id.is_empty()) {
+                ids.insert(id.to_string());
+            }
+        }
+    }
+    Ok(ids.into_iter().collect())
+}
+
+// ── Compaction ──
+
+#[derive(Debug, Default, Serialize)]
+pub struct CompactReport {
+    /// Segments read and checked against the store.
+    pub segments_checked: usize,
+    /// Segments skipped because the manifest shows they end inside the store.
+    pub segments_skipped: usize,
+    pub segments_removed: usize,
+    pub records_dropped: usize,
+    /// The store is gone, so the whole index (active file, segments and
+    /// manifest) was removed.
+    pub removed_index: bool,
+}
+
+/// Drop index records whose store bytes no longer exist.
+///
+/// When the session's store file has been GC'd the whole index goes. When
+/// the store is shorter than a record's range (truncated or rewritten), that
+/// record is dropped, segments left empty are deleted, and partly affected
+/// files are rewritten atomically. The manifest's store ranges and
+/// checkpoint let unaffected segments be skipped without reading them, so a
+/// pass over an append-only store only reads the active file.
+pub fn compact_index(index_path: &Path, store_path: &Path) -> anyhow::Result<CompactReport> {
+    let mut report = CompactReport::default();
+    let mut manifest = read_manifest(index_path)?;
+    let dir = segments_dir(index_path);
+
+    let store_len = match std::fs::metadata(store_path) {
+        Ok(m) => m.len(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            for segment in &manifest.segments {
+                report.records_dropped += segment.records;
+                report.segments_removed += 1;
+                remove_if_exists(&dir.join(&segment.file))?;
+            }
+            report.records_dropped += read_records(index_path)?.len();
+            remove_if_exists(index_path)?;
+            remove_if_exists(&manifest_path(index_path))?;
+            report.removed_index = true;
+            return Ok(report);
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    // A store shorter than at the last compaction was rewritten, so records
+    // inside its bounds may now point at different lines: check their uuids.
+    let store_shrank = manifest
+        .checkpoint_store_len
+        .is_some_and(|checkpoint| store_len < checkpoint);
+    let keep = |records: &[IndexRecordV1]| -> Vec<IndexRecordV1> {
+        records
+            .iter()
+            .filter(|r| record_resolves(r, store_path, store_len, store_shrank))
+            .cloned()
+            .collect()
+    };
+
+    let mut segments = Vec::with_capacity(manifest.segments.len());
+    for segment in std::mem::take(&mut manifest.segments) {
+        let path = dir.join(&segment.file);
+        if !path.exists() {
+            // Left behind by an interrupted rotation.
+            continue;
+        }
+        if !store_shrank && segment.store_end <= store_len {
+            report.segments_skipped += 1;
+            segments.push(segment);
+            continue;
+        }
+        report.segments_checked += 1;
+        let records = read_records(&path)?;
+        let kept = keep(&records);
+        report.records_dropped += records.len() - kept.len();
+        match segment_info(segment.file.clone(), &kept) {
+            Some(info) => {
+                if kept.len() < records.len() {
+                    write_records(&path, &kept)?;
+                }
+                segments.push(info);
+            }
+            None => {
+                std::fs::remove_file(&path)?;
+                report.segments_removed += 1;
+            }
+        }
+    }
+
+    let active = read_records(index_path)?;
+    let kept = keep(&active);
+    if kept.len() < active.len() {
+        report.records_dropped += active.len() - kept.len();
+        write_records(index_path, &kept)?;
+    }
+
+    manifest.segments = segments;
+    if !manifest.segments.is_empty() {
+        manifest.checkpoint_store_len = Some(store_len);
+        write_manifest(index_path, &manifest)?;
+    } else {
+        remove_if_exists(&manifest_path(index_path))?;
+    }
+    Ok(report)
+}
+
+/// Whether a record's bytes are still in the store; with `check_uuid`, also
+/// that they are still the same record.
+fn record_resolves(
+    rec: &IndexRecordV1,
+    store_path: &Path,
+    store_len: u64,
+    check_uuid: bool,
+) -> bool {
+    if rec
+        .store_offset
+        .checked_add(rec.store_len)
+        .is_none_or(|end| end > store_len)
+    {
+        return false;
+    }
+    if !check_uuid {
+        return true;
+    }
+    fetch_store_line(store_path, rec.store_offset, rec.store_len)
+        .ok()
+        .and_then(|raw| serde_json::from_slice::<serde_json::Value>(&raw).ok())
+        .is_some_and(|parsed| parsed.get("uuid").and_then(|v| v.as_str()) == Some(&rec.uuid))
+}
+
+fn write_records(path: &Path, records: &[IndexRecordV1]) -> anyhow::Result<()> {
+    let mut out = String::new();
+    for record in records {
+        out.push_str(&serde_json::to_string(record)?);
+        out.push('\n');
+    }
+    edda_store::write_atomic(path, out.as_bytes())
+}
+
+fn remove_if_exists(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
 }
 
 // ── Deterministic fetch ──
@@ -123,6 +459,184 @@ pub fn fetch_store_line(store_path: &Path, offset: u64, len: u64) -> anyhow::Res
     Ok(buf)
 }
 
+// ── Integrity sweep ──
+
+/// Why an index line failed the sweep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SweepProblem {
+    /// The index line itself is not an `IndexRecordV1`.
+    UnparseableIndexLine,
+    /// `store_offset + store_len` runs past the end of the store file.
+    PastEndOfStore,
+    /// The bytes at the offset are not a JSON record.
+    UnparseableStoreLine,
+    /// The store record at the offset has a different uuid.
+    UuidMismatch,
+}
+
+impl std::fmt::Display for SweepProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::UnparseableIndexLine => "unparseable index line",
+            Self::PastEndOfStore => "points past the end of the store",
+            Self::UnparseableStoreLine => "store bytes are not a JSON record",
+            Self::UuidMismatch => "store record has a different uuid",
+        })
+    }
+}
+
+/// An index line moved out of the index by [`sweep_index`].
+#[derive(Debug, Clone, Serialize)]
+pub struct QuarantinedRecord {
+    /// 1-based line number in the index file before the sweep.
+    pub line: usize,
+    pub problem: SweepProblem,
+    pub raw: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct SweepReport {
+    pub checked: usize,
+    pub quarantined: Vec<QuarantinedRecord>,
+    /// Records re-derived from store lines no valid index record pointed at.
+    pub rebuilt: usize,
+}
+
+/// Check that every record in the active index file resolves to a parseable
+/// store record with the same uuid.
+///
+/// Failing lines are appended to `quarantine_path` and dropped from the
+/// index. With `rebuild`, every store line no surviving record points at is
+/// re-indexed from the store. The index is only rewritten when something
+/// changed, and then atomically, ordered by store offset.
+pub fn sweep_index(
+    index_path: &Path,
+    store_path: &Path,
+    quarantine_path: &Path,
+    session_id: &str,
+    rebuild: bool,
+) -> anyhow::Result<SweepReport> {
+    let index = std::fs::read_to_string(index_path)?;
+    let store_size = std::fs::metadata(store_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut report = SweepReport::default();
+    let mut kept: Vec<(u64, String)> = Vec::new();
+    for (i, line) in index.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.checked += 1;
+        match check_index_line(line, store_path, store_size) {
+            Ok(offset) => kept.push((offset, line.to_string())),
+            Err(problem) => report.quarantined.push(QuarantinedRecord {
+                line: i + 1,
+                problem,
+                raw: line.to_string(),
+            }),
+        }
+    }
+
+    let mut rebuilt: Vec<(u64, String)> = Vec::new();
+    if rebuild && store_size > 0 {
+        // Records rotated into segments are covered too.
+        let mut covered: BTreeSet<u64> = kept.iter().map(|(offset, _)| *offset).collect();
+        let dir = segments_dir(index_path);
+        for segment in read_manifest(index_path)?.segments {
+            covered.extend(
+                read_records(&dir.join(&segment.file))?
+                    .iter()
+                    .map(|r| r.store_offset),
+            );
+        }
+        let mut reader = std::io::BufReader::new(std::fs::File::open(store_path)?);
+        let mut offset = 0u64;
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            let n = reader.read_until(b'\n', &mut buf)? as u64;
+            if n == 0 {
+                break;
+            }
+            if !covered.contains(&offset) {
+                if let Ok(parsed) = serde_json::from_slice::<serde_json::Value>(&buf) {
+                    let record = build_index_record(session_id, offset, n, &parsed);
+                    rebuilt.push((offset, serde_json::to_string(&record)?));
+                }
+            }
+            offset += n;
+        }
+    }
+    report.rebuilt = rebuilt.len();
+
+    if !report.quarantined.is_empty() {
+        if let Some(parent) = quarantine_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(quarantine_path)?;
+        for q in &report.quarantined {
+            writeln!(file, "{}", serde_json::to_string(q)?)?;
+        }
+    }
+
+    if !report.quarantined.is_empty() || !rebuilt.is_empty() {
+        kept.extend(rebuilt);
+        kept.sort_by_key(|(offset, _)| *offset);
+        let mut out = String::new();
+        for (_, line) in &kept {
+            out.push_str(line);
+            out.push('\n');
+        }
+        edda_store::write_atomic(index_path, out.as_bytes())?;
+    }
+    Ok(report)
+}
+
+/// Check the active index file like [`sweep_index`], changing nothing:
+/// `quarantined` lists the records a sweep would move out.
+pub fn verify_index(index_path: &Path, store_path: &Path) -> anyhow::Result<SweepReport> {
+    let index = std::fs::read_to_string(index_path)?;
+    let store_size = std::fs::metadata(store_path).map(|m| m.len()).unwrap_or(0);
+    let mut report = SweepReport::default();
+    for (i, line) in index.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.checked += 1;
+        if let Err(problem) = check_index_line(line, store_path, store_size) {
+            report.quarantined.push(QuarantinedRecord {
+                line: i + 1,
+                problem,
+                raw: line.to_string(),
+            });
+        }
+    }
+    Ok(report)
+}
+
+/// Resolve one index line against the store; the record's offset when it
+/// checks out.
+fn check_index_line(line: &str, store_path: &Path, store_size: u64) -> Result<u64, SweepProblem> {
+    let rec: IndexRecordV1 =
+        serde_json::from_str(line).map_err(|_| SweepProblem::UnparseableIndexLine)?;
+    let end = rec.store_offset.checked_add(rec.store_len);
+    if end.is_none_or(|end| end > store_size) {
+        return Err(SweepProblem::PastEndOfStore);
+    }
+    let raw = fetch_store_line(store_path, rec.store_offset, rec.store_len)
+        .map_err(|_| SweepProblem::PastEndOfStore)?;
+    let parsed: serde_json::Value =
+        serde_json::from_slice(&raw).map_err(|_| SweepProblem::UnparseableStoreLine)?;
+    let uuid = parsed.get("uuid").and_then(|v| v.as_str()).unwrap_or("");
+    if uuid != rec.uuid {
+        return Err(SweepProblem::UuidMismatch);
+    }
+    Ok(rec.store_offset)
+}
+
 // ── Build IndexRecordV1 from raw JSON ──
 
 /// Build an IndexRecordV1 from a parsed transcript record JSON.
@@ -315,4 +829,193 @@ mod tests {
         assert_eq!(usage.input_tokens, 100);
         assert_eq!(usage.output_tokens, 50);
     }
+
+    #[test]
+    fn sweep_quarantines_bad_records_and_rebuilds_from_store() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = tmp.path().join("store.jsonl");
+        let index = tmp.path().join("index.jsonl");
+        let quarantine = tmp.path().join("quarantine").join("s1.jsonl");
+
+        let lines = [
+            r#"{"type":"user","uuid":"u1"}"#,
+            r#"{"type":"assistant","uuid":"a1"}"#,
+            r#"{"type":"user","uuid":"u2"}"#,
+        ];
+        let mut f = std::fs::File::create(&store).unwrap();
+        let mut offset = 0u64;
+        let mut records = Vec::new();
+        for line in lines {
+            writeln!(f, "{line}").unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(line).unwrap();
+            let len = line.len() as u64 + 1;
+            records.push(build_index_record("s1", offset, len, &parsed));
+            offset += len;
+        }
+
+        append_index(&index, &records[0]).unwrap();
+        let mut wrong_uuid = records[1].clone();
+        wrong_uuid.uuid = "someone-else".into();
+        append_index(&index, &wrong_uuid).unwrap();
+        let mut past_end = records[2].clone();
+        past_end.store_offset = 10_000;
+        append_index(&index, &past_end).unwrap();
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&index)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+
+        let dry = verify_index(&index, &store).unwrap();
+        assert_eq!((dry.checked, dry.quarantined.len()), (4, 3));
+        assert!(!quarantine.exists());
+
+        let report = sweep_index(&index, &store, &quarantine, "s1", false).unwrap();
+        assert_eq!(report.checked, 4);
+        let problems: Vec<SweepProblem> = report.quarantined.iter().map(|q| q.problem).collect();
+        assert_eq!(
+            problems,
+            [
+                SweepProblem::UuidMismatch,
+                SweepProblem::PastEndOfStore,
+                SweepProblem::UnparseableIndexLine,
+            ]
+        );
+        assert_eq!(report.rebuilt, 0);
+        let remaining = read_index_tail(&index, 100, 1 << 20).unwrap();
+        assert_eq!(remaining.len(), 1);
+        let quarantined = std::fs::read_to_string(&quarantine).unwrap();
+        assert_eq!(quarantined.lines().count(), 3);
+
+        let report = sweep_index(&index, &store, &quarantine, "s1", true).unwrap();
+        assert!(report.quarantined.is_empty());
+        assert_eq!(report.rebuilt, 2);
+        let uuids: Vec<String> = read_index_tail(&index, 100, 1 << 20)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.uuid)
+            .collect();
+        assert_eq!(uuids, ["u1", "a1", "u2"]);
+
+        // A clean index is left untouched.
+        let report = sweep_index(&index, &store, &quarantine, "s1", true).unwrap();
+        assert_eq!((report.checked, report.rebuilt), (3, 0));
+    }
+
+    fn write_store(store: &Path, uuids: &[&str]) -> Vec<IndexRecordV1> {
+        let mut f = std::fs::File::create(store).unwrap();
+        let mut offset = 0u64;
+        let mut records = Vec::new();
+        for (i, uuid) in uuids.iter().enumerate() {
+            let line = format!(r#"{{"type":"user","uuid":"{uuid}","timestamp":"t{i}"}}"#);
+            writeln!(f, "{line}").unwrap();
+            let parsed: serde_json::Value = serde_json::from_str(&line).unwrap();
+            let len = line.len() as u64 + 1;
+            records.push(build_index_record("s1", offset, len, &parsed));
+            offset += len;
+        }
+        records
+    }
+
+    #[test]
+    fn rotation_keeps_tail_reads_across_segments() {
+        let tmp = tempfile::tempdir().unwrap();
+        let index = tmp.path().join("index").join("s1.jsonl");
+        let store = tmp.path().join("s1.store.jsonl");
+        let uuids: Vec<String> = (0..10).map(|i| format!("u{i}")).collect();
+        let uuid_refs: Vec<&str> = uuids.iter().map(String::as_str).collect();
+        let records = write_store(&store, &uuid_refs);
+
+        // Roughly three records per file.
+        let limit = serde_json::to_string(&records[0]).unwrap().len() as u64 * 3;
+        for record in &records {
+            append_with_limit(&index, record, limit).unwrap();
+        }
+
+        let manifest = read_manifest(&index).unwrap();
+        let files: Vec<&str> = manifest.segments.iter().map(|s| s.file.as_str()).collect();
+        assert_eq!(files, ["s1.0001.jsonl", "s1.0002.jsonl", "s1.0003.jsonl"]);
+        assert_eq!(manifest.segments[0].first_ts, "t0");
+        assert_eq!(manifest.segments[0].store_start, 0);
+        assert_eq!(
+            manifest.segments[1].store_start,
+            manifest.segments[0].store_end
+        );
+        assert!(segments_dir(&index).join("s1.0003.jsonl").exists());
+
+        let all: Vec<String> = read_index_tail(&index, usize::MAX, u64::MAX)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.uuid)
+            .collect();
+        assert_eq!(all, uuids);
+        let tail: Vec<String> = read_index_tail(&index, 5, u64::MAX)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.uuid)
+            .collect();
+        assert_eq!(tail, ["u5", "u6", "u7", "u8", "u9"]);
+
+        // Nothing to rotate once the active file is gone.
+        rotate_index(&index).unwrap();
+        assert!(rotate_index(&index).unwrap().is_none());
+        assert_eq!(
+            read_index_tail(&index, usize::MAX, u64::MAX).unwrap().len(),
+            10
+        );
+    }
+
+    #[test]
+    fn compaction_drops_records_for_missing_store_bytes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let index = tmp.path().join("index").join("s1.jsonl");
+        let store = tmp.path().join("s1.store.jsonl");
+        let records = write_store(&store, &["u0", "u1", "u2", "u3", "u4", "u5"]);
+        for (i, record) in records.iter().enumerate() {
+            append_index(&index, record).unwrap();
+            if i % 2 == 1 {
+                rotate_index(&index).unwrap();
+            }
+        }
+        assert_eq!(read_manifest(&index).unwrap().segments.len(), 3);
+
+        // Append-only store: nothing is read, the checkpoint is recorded.
+        let report = compact_index(&index, &store).unwrap();
+        assert_eq!((report.segments_skipped, report.records_dropped), (3, 0));
+        let full_len = std::fs::metadata(&store).unwrap().len();
+        assert_eq!(
+            read_manifest(&index).unwrap().checkpoint_store_len,
+            Some(full_len)
+        );
+
+        // Truncate the store to its first three records.
+        let keep = records[2].store_offset + records[2].store_len;
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&store)
+            .unwrap()
+            .set_len(keep)
+            .unwrap();
+        let report = compact_index(&index, &store).unwrap();
+        assert_eq!(report.records_dropped, 3);
+        assert_eq!(report.segments_removed, 1);
+        let uuids: Vec<String> = read_index_tail(&index, usize::MAX, u64::MAX)
+            .unwrap()
+            .into_iter()
+            .map(|r| r.uuid)
+            .collect();
+        assert_eq!(uuids, ["u0", "u1", "u2"]);
+        assert_eq!(read_manifest(&index).unwrap().segments[1].records, 1);
+
+        // A GC'd store takes the whole index with it.
+        std::fs::remove_file(&store).unwrap();
+        let report = compact_index(&index, &store).unwrap();
+        assert!(report.removed_index);
+        assert_eq!(report.records_dropped, 3);
+        assert!(!manifest_path(&index).exists());
+        assert!(read_index_tail(&index, usize::MAX, u64::MAX)
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -0,0 +1,172 @@
+//! Append authorization for shared workspaces.
+//!
+//! Every [`crate::Ledger`] append is checked by an [`AppendAuthorizer`] with
+//! the ledger's actor (see [`crate::Ledger::set_actor`]) and the event. The
+//! default is [`PolicyAuthorizer`], which enforces `append_rules` from
+//! `.edda/policy.yaml` and allows everything when there are none. Embedders
+//! can plug in their own with [`crate::Ledger::set_authorizer`].
+//!
+//! The actor defaults to `EDDA_ACTOR`. Like `--actor`, it is self-asserted;
+//! only `edda serve` binds it to an API token.
+
+use std::path::Path;
+
+use edda_core::policy::{self, ActorsConfig, AppendRule};
+use edda_core::Event;
+
+/// An append refused by the authorizer.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("append denied: {reason}")]
+pub struct AppendDenied {
+    pub reason: String,
+}
+
+/// Decides whether `actor` may append `event`.
+pub trait AppendAuthorizer: Send + Sync {
+    fn authorize(&self, actor: Option<&str>, event: &Event) -> Result<(), AppendDenied>;
+}
+
+/// Allows every append.
+pub struct AllowAll;
+
+impl AppendAuthorizer for AllowAll {
+    fn authorize(&self, _actor: Option<&str>, _event: &Event) -> Result<(), AppendDenied> {
+        Ok(())
+    }
+}
+
+/// Enforces `append_rules` with the roles from `actors.yaml`.
+pub struct PolicyAuthorizer {
+    rules: Vec<AppendRule>,
+    actors: ActorsConfig,
+}
+
+impl PolicyAuthorizer {
+    pub fn new(rules: Vec<AppendRule>, actors: ActorsConfig) -> Self {
+        Self { rules, actors }
+    }
+
+    /// Load the rules and actors from an `.edda/` directory.
+    pub fn from_dir(edda_dir: &Path) -> anyhow::Result<Self> {
+        let rules = policy::load_append_rules_from_dir(edda_dir)?;
+        let actors = if rules.is_empty() {
+            ActorsConfig::default()
+        } else {
+            policy::load_actors_from_dir(edda_dir)?
+        };
+        Ok(Self::new(rules, actors))
+    }
+}
+
+impl AppendAuthorizer for PolicyAuthorizer {
+    fn authorize(&self, actor: Option<&str>, event: &Event) -> Result<(), AppendDenied> {
+        match policy::append_rule_denial(&self.rules, &self.actors, actor, event) {
+            Some(reason) => Err(AppendDenied { reason }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::{EddaPaths, Ledger};
+    use edda_core::event::{finalize_event, new_decision_event, new_note_event};
+    use edda_core::types::DecisionPayload;
+    use std::sync::Arc;
+
+    fn workspace(name: &str, policy_yaml: &str) -> (std::path::PathBuf, Ledger) {
+        let tmp =
+            std::env::temp_dir().join(format!("edda_authz_test_{}_{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        std::fs::write(paths.edda_dir.join("policy.yaml"), policy_yaml).unwrap();
+        std::fs::write(
+            paths.edda_dir.join("actors.yaml"),
+            "version: 1\nactors:\n  alice:\n    roles: [lead]\n  bob:\n    roles: [dev]\n",
+        )
+        .unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+        (tmp, ledger)
+    }
+
+    fn decision(ledger: &Ledger, key: &str) -> Event {
+        let dp = DecisionPayload {
+            key: key.into(),
+            value: "x".into(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let parent = ledger.last_event_hash().unwrap();
+        new_decision_event("main", parent.as_deref(), "user", &dp).unwrap()
+    }
+
+    #[test]
+    fn append_rules_are_enforced_for_the_ledger_actor() {
+        let (tmp, mut ledger) = workspace(
+            "rules",
+            "version: 2\nappend_rules:\n  - event_type: decision\n    keys: [\"infra.*\"]\n    roles: [lead]\n",
+        );
+
+        ledger.set_actor(Some("bob"));
+        let err = ledger
+            .append_event(&decision(&ledger, "infra.region"))
+            .unwrap_err();
+        let denied = err.downcast_ref::<AppendDenied>().expect("an AppendDenied");
+        assert!(denied.reason.contains("role lead"));
+        ledger
+            .append_event(&decision(&ledger, "auth.method"))
+            .unwrap();
+
+        ledger.set_actor(Some("alice"));
+        ledger
+            .append_event(&decision(&ledger, "infra.region"))
+            .unwrap();
+        assert_eq!(ledger.iter_events().unwrap().len(), 2);
+        let _ = std::fs::remove_dir_all(tmp);
+    }
+
+    #[test]
+    fn a_custom_authorizer_replaces_the_policy() {
+        struct NotesOnly;
+        impl AppendAuthorizer for NotesOnly {
+            fn authorize(&self, _actor: Option<&str>, event: &Event) -> Result<(), AppendDenied> {
+                if event.event_type == "note" {
+                    Ok(())
+                } else {
+                    Err(AppendDenied {
+                        reason: "notes only".into(),
+                    })
+                }
+            }
+        }
+
+        let (tmp, mut ledger) = workspace("custom", "version: 2\n");
+        ledger.set_authorizer(Arc::new(NotesOnly));
+        let mut note = new_note_event("main", None, "user", "hi", &[]).unwrap();
+        finalize_event(&mut note).unwrap();
+        ledger.append_event(&note).unwrap();
+
+        let mut other = note.clone();
+        other.event_id = "evt_other".into();
+        other.event_type = "commit".into();
+        other.parent_hash = ledger.last_event_hash().unwrap();
+        finalize_event(&mut other).unwrap();
+        let err = ledger.append_event_idempotent(&other).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<AppendDenied>().unwrap().reason,
+            "notes only"
+        );
+        let _ = std::fs::remove_dir_all(tmp);
+    }
+}
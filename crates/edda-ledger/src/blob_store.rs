@@ -1,8 +1,9 @@
 use crate::blob_meta::{self, BlobClass};
 use crate::paths::EddaPaths;
 use edda_core::hash::sha256_hex;
+use serde::{Deserialize, Serialize};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Metadata for a blob in the store.
 pub struct BlobInfo {
@@ -60,6 +61,11 @@ pub fn blob_size(paths: &EddaPaths, hash: &str) -> anyhow::Result<u64> {
 /// Write bytes to the blob store. Returns `blob:sha256:<hex>`.
 /// Atomic: writes to a temp file first, then renames.
 /// Idempotent: if the blob already exists, returns immediately.
+///
+/// Blobs larger than `blob.chunk_threshold_kb` in `config.json` (default
+/// [`DEFAULT_CHUNK_THRESHOLD_KB`], `0` disables) are split into
+/// content-defined chunks shared with every other chunked blob; the blob's
+/// file then holds a [`ChunkManifest`]. Read such blobs with [`blob_read`].
 pub fn blob_put(paths: &EddaPaths, bytes: &[u8]) -> anyhow::Result<String> {
     let hex = sha256_hex(bytes);
     let final_path = paths.blobs_dir.join(&hex);
@@ -70,19 +76,308 @@ pub fn blob_put(paths: &EddaPaths, bytes: &[u8]) -> anyhow::Result<String> {
         return Ok(blob_ref);
     }
 
-    // Atomic write: tmp file → rename
-    let tmp_path = paths.blobs_dir.join(format!(".tmp_{hex}"));
+    let threshold = chunk_threshold(&paths.config_json);
+    if threshold > 0 && bytes.len() > threshold {
+        let chunks_dir = paths.blobs_dir.join(CHUNKS_DIR);
+        std::fs::create_dir_all(&chunks_dir)?;
+        let mut chunks = Vec::new();
+        for range in chunk_ranges(bytes) {
+            let chunk = &bytes[range];
+            let chunk_hex = sha256_hex(chunk);
+            if !chunks_dir.join(&chunk_hex).exists() {
+                write_atomic(&chunks_dir, &chunk_hex, chunk)?;
+            }
+            chunks.push(chunk_hex);
+        }
+        let manifest = ChunkManifest {
+            size: bytes.len() as u64,
+            chunks,
+        };
+        let mut file = MANIFEST_MAGIC.to_vec();
+        file.extend(serde_json::to_vec(&manifest)?);
+        write_atomic(&paths.blobs_dir, &hex, &file)?;
+    } else {
+        write_atomic(&paths.blobs_dir, &hex, bytes)?;
+    }
+    Ok(blob_ref)
+}
+
+/// Atomic write: tmp file → rename, within `dir`.
+fn write_atomic(dir: &Path, name: &str, bytes: &[u8]) -> anyhow::Result<()> {
+    let tmp_path = dir.join(format!(".tmp_{name}"));
     let mut file = std::fs::File::create(&tmp_path)?;
     file.write_all(bytes)?;
     file.sync_all()?;
     drop(file);
+    std::fs::rename(&tmp_path, dir.join(name))?;
+    Ok(())
+}
 
-    std::fs::rename(&tmp_path, &final_path)?;
-    Ok(blob_ref)
+// ── Chunking ──
+
+/// Blobs above this many KiB are chunked unless `blob.chunk_threshold_kb`
+/// says otherwise.
+pub const DEFAULT_CHUNK_THRESHOLD_KB: u64 = 1024;
+
+/// Subdirectory of `blobs/` holding chunks, named by their SHA-256.
+const CHUNKS_DIR: &str = "chunks";
+
+/// First line of a chunked blob's file.
+const MANIFEST_MAGIC: &[u8] = b"edda-chunked-blob/1\n";
+
+const MIN_CHUNK: usize = 16 * 1024;
+const MAX_CHUNK: usize = 256 * 1024;
+
+/// Contents of a chunked blob's file (after [`MANIFEST_MAGIC`]): the
+/// blob's size and its chunks in order.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkManifest {
+    pub size: u64,
+    pub chunks: Vec<String>,
+}
+
+fn chunk_threshold(config_json: &Path) -> usize {
+    let kb = crate::settings::read_config(config_json)
+        .ok()
+        .and_then(|c| c.get("blob.chunk_threshold_kb").and_then(|v| v.as_u64()))
+        .unwrap_or(DEFAULT_CHUNK_THRESHOLD_KB);
+    usize::try_from(kb.saturating_mul(1024)).unwrap_or(usize::MAX)
+}
+
+/// Per-byte values for the gear rolling hash (splitmix64 of the index).
+const GEAR: [u64; 256] = {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut z = (i as u64 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        table[i] = z ^ (z >> 31);
+        i += 1;
+    }
+    table
+};
+
+/// Split `data` at content-defined boundaries (gear hash, FastCDC style):
+/// an insertion or deletion only moves the boundaries near it, so
+/// near-duplicate blobs share most of their chunks. Chunks are between
+/// [`MIN_CHUNK`] and [`MAX_CHUNK`] bytes, about 80 KiB on average.
+fn chunk_ranges(data: &[u8]) -> Vec<std::ops::Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < data.len() {
+        let rest = &data[start..];
+        let mut end = rest.len().min(MAX_CHUNK);
+        if rest.len() > MIN_CHUNK {
+            let mut hash: u64 = 0;
+            for (i, &b) in rest.iter().enumerate().take(end).skip(MIN_CHUNK) {
+                hash = (hash << 1).wrapping_add(GEAR[b as usize]);
+                // The top 16 bits depend on the last 64 bytes only.
+                if hash >> 48 == 0 {
+                    end = i + 1;
+                    break;
+                }
+            }
+        }
+        ranges.push(start..start + end);
+        start += end;
+    }
+    ranges
+}
+
+/// Parse a chunked blob's file, `None` for a plain blob.
+fn parse_manifest(bytes: &[u8]) -> Option<ChunkManifest> {
+    serde_json::from_slice(bytes.strip_prefix(MANIFEST_MAGIC)?).ok()
+}
+
+/// The manifest of a chunked blob (active or archived), `None` for a
+/// plain or missing blob. Reads only the head of plain blobs.
+pub fn blob_manifest(paths: &EddaPaths, hash: &str) -> Option<ChunkManifest> {
+    let path = blob_get_path(paths, &format!("blob:sha256:{hash}")).ok()?;
+    let mut head = vec![0u8; MANIFEST_MAGIC.len()];
+    std::fs::File::open(&path)
+        .and_then(|mut f| std::io::Read::read_exact(&mut f, &mut head))
+        .ok()?;
+    if head != MANIFEST_MAGIC {
+        return None;
+    }
+    parse_manifest(&std::fs::read(&path).ok()?)
+}
+
+/// Read a blob's bytes, reassembling chunked blobs. The result is checked
+/// against the blob's hash.
+pub fn blob_read(paths: &EddaPaths, blob_ref: &str) -> anyhow::Result<Vec<u8>> {
+    let path = blob_get_path(paths, blob_ref)?;
+    let hex = blob_ref.trim_start_matches("blob:sha256:");
+    let bytes = std::fs::read(&path)?;
+    // A plain blob that happens to start with the magic hashes to its name.
+    let Some(manifest) = parse_manifest(&bytes).filter(|_| sha256_hex(&bytes) != hex) else {
+        return Ok(bytes);
+    };
+    let chunks_dir = paths.blobs_dir.join(CHUNKS_DIR);
+    let mut out = Vec::with_capacity(usize::try_from(manifest.size).unwrap_or(0));
+    for chunk in &manifest.chunks {
+        let data = std::fs::read(chunks_dir.join(chunk))
+            .map_err(|e| anyhow::anyhow!("blob {hex}: chunk {chunk}: {e}"))?;
+        out.extend(data);
+    }
+    if sha256_hex(&out) != hex {
+        anyhow::bail!("blob {hex}: reassembled chunks do not match its hash");
+    }
+    Ok(out)
+}
+
+/// Remove chunks no manifest (active or archived) refers to any more, once
+/// older than `grace` so a concurrent [`blob_put`] keeps the chunks it has
+/// written before its manifest. Returns (chunks removed, bytes freed).
+pub fn blob_gc_chunks(
+    paths: &EddaPaths,
+    grace: std::time::Duration,
+) -> anyhow::Result<(usize, u64)> {
+    let chunks_dir = paths.blobs_dir.join(CHUNKS_DIR);
+    if !chunks_dir.exists() {
+        return Ok((0, 0));
+    }
+    let mut referenced = std::collections::HashSet::new();
+    for blob in blob_list(paths)?.iter().chain(&blob_list_archived(paths)?) {
+        if let Some(m) = blob_manifest(paths, &blob.hash) {
+            referenced.extend(m.chunks);
+        }
+    }
+    let now = std::time::SystemTime::now();
+    let (mut removed, mut freed) = (0, 0);
+    for entry in std::fs::read_dir(&chunks_dir)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if referenced.contains(&name) {
+            continue;
+        }
+        let meta = entry.metadata()?;
+        let old = meta
+            .modified()
+            .ok()
+            .and_then(|m| now.duration_since(m).ok())
+            .is_some_and(|age| age >= grace);
+        if meta.is_file() && old {
+            std::fs::remove_file(entry.path())?;
+            removed += 1;
+            freed += meta.len();
+        }
+    }
+    Ok((removed, freed))
+}
+
+/// Stored versus logical size of one blob class.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ClassDedup {
+    pub class: BlobClass,
+    pub blobs: usize,
+    /// Sum of the blobs' sizes as written.
+    pub logical_bytes: u64,
+    /// Bytes on disk: plain blobs, manifests, and each chunk the class
+    /// uses once.
+    pub stored_bytes: u64,
+}
+
+impl ClassDedup {
+    pub fn saved_bytes(&self) -> u64 {
+        self.logical_bytes.saturating_sub(self.stored_bytes)
+    }
+}
+
+/// Chunk-level deduplication across the active blob store.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DedupStats {
+    pub logical_bytes: u64,
+    /// Each chunk counted once, however many blobs share it.
+    pub stored_bytes: u64,
+    pub chunked_blobs: usize,
+    pub chunks: usize,
+    pub by_class: Vec<ClassDedup>,
+}
+
+impl DedupStats {
+    /// Logical over stored bytes; 1.0 for an empty store.
+    pub fn ratio(&self) -> f64 {
+        if self.stored_bytes == 0 {
+            1.0
+        } else {
+            self.logical_bytes as f64 / self.stored_bytes as f64
+        }
+    }
+}
+
+/// Deduplication statistics for the active blobs, by class.
+pub fn blob_dedup_stats(
+    paths: &EddaPaths,
+    meta: &blob_meta::BlobMetaMap,
+) -> anyhow::Result<DedupStats> {
+    let chunks_dir = paths.blobs_dir.join(CHUNKS_DIR);
+    let mut chunk_sizes: std::collections::HashMap<String, u64> = Default::default();
+    let mut chunk_size = |chunk: &str| -> u64 {
+        *chunk_sizes
+            .entry(chunk.to_string())
+            .or_insert_with(|| chunks_dir.join(chunk).metadata().map_or(0, |m| m.len()))
+    };
+
+    let classes = [
+        BlobClass::Artifact,
+        BlobClass::DecisionEvidence,
+        BlobClass::TraceNoise,
+    ];
+    let mut by_class: Vec<ClassDedup> = classes
+        .iter()
+        .map(|&class| ClassDedup {
+            class,
+            blobs: 0,
+            logical_bytes: 0,
+            stored_bytes: 0,
+        })
+        .collect();
+    let mut class_chunks: Vec<std::collections::HashSet<String>> =
+        vec![Default::default(); classes.len()];
+    let mut all_chunks = std::collections::HashSet::new();
+    let (mut logical, mut files, mut chunked_blobs) = (0, 0, 0);
+
+    for blob in blob_list(paths)? {
+        let class = blob_meta::get_meta(meta, &blob.hash).class;
+        let idx = classes.iter().position(|c| *c == class).unwrap_or(0);
+        let row = &mut by_class[idx];
+        row.blobs += 1;
+        row.stored_bytes += blob.size;
+        files += blob.size;
+        match blob_manifest(paths, &blob.hash) {
+            Some(m) => {
+                chunked_blobs += 1;
+                row.logical_bytes += m.size;
+                logical += m.size;
+                for chunk in m.chunks {
+                    if class_chunks[idx].insert(chunk.clone()) {
+                        row.stored_bytes += chunk_size(&chunk);
+                    }
+                    all_chunks.insert(chunk);
+                }
+            }
+            None => {
+                row.logical_bytes += blob.size;
+                logical += blob.size;
+            }
+        }
+    }
+    let chunk_bytes: u64 = all_chunks.iter().map(|c| chunk_size(c)).sum();
+    Ok(DedupStats {
+        logical_bytes: logical,
+        stored_bytes: files + chunk_bytes,
+        chunked_blobs,
+        chunks: all_chunks.len(),
+        by_class,
+    })
 }
 
 /// Resolve a blob ref to its filesystem path.
-/// Checks active blobs first, then falls back to archive.
+/// Checks active blobs first, then falls back to archive, fetching from
+/// cold storage when the blob was pushed there (see [`crate::cold`]). For a
+/// chunked blob this is its manifest; use [`blob_read`] for the content.
 /// Returns an error if the blob does not exist in either location.
 pub fn blob_get_path(paths: &EddaPaths, blob_ref: &str) -> anyhow::Result<PathBuf> {
     let hex = blob_ref
@@ -92,9 +387,8 @@ pub fn blob_get_path(paths: &EddaPaths, blob_ref: &str) -> anyhow::Result<PathBu
     if active_path.exists() {
         return Ok(active_path);
     }
-    // Fallback: check archive
-    let archive_path = paths.archive_blobs_dir.join(hex);
-    if archive_path.exists() {
+    // Fallback: check archive, fetching from cold storage if pushed there
+    if let Some(archive_path) = crate::cold::fetch(paths, &format!("blobs/{hex}"))? {
         return Ok(archive_path);
     }
     anyhow::bail!("blob not found: {blob_ref}");
@@ -139,6 +433,25 @@ pub fn blob_put_classified(
     Ok(blob_ref)
 }
 
+/// Store a file as decision evidence, for attaching to a note or decision.
+pub fn blob_put_attachment(
+    paths: &EddaPaths,
+    file: &Path,
+) -> anyhow::Result<edda_core::Attachment> {
+    let bytes = std::fs::read(file)
+        .map_err(|e| anyhow::anyhow!("reading attachment {}: {e}", file.display()))?;
+    let name = file
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .ok_or_else(|| anyhow::anyhow!("attachment has no file name: {}", file.display()))?;
+    let blob = blob_put_classified(paths, &bytes, BlobClass::DecisionEvidence)?;
+    Ok(edda_core::Attachment {
+        name,
+        blob,
+        size: bytes.len() as u64,
+    })
+}
+
 /// Threshold in bytes for offloading snapshot payloads to blob store.
 pub const SNAPSHOT_BLOB_THRESHOLD: usize = 8192;
 
@@ -358,4 +671,94 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&tmp);
     }
+
+    /// Deterministic incompressible bytes.
+    fn noise(seed: u64, len: usize) -> Vec<u8> {
+        let mut x = seed | 1;
+        (0..len)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x as u8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chunk_boundaries_resync_after_an_edit() {
+        let data = noise(7, 2 * 1024 * 1024);
+        let ranges = chunk_ranges(&data);
+        assert_eq!(ranges.first().unwrap().start, 0);
+        assert_eq!(ranges.last().unwrap().end, data.len());
+        assert!(ranges.windows(2).all(|w| w[0].end == w[1].start));
+        assert!(ranges[..ranges.len() - 1]
+            .iter()
+            .all(|r| r.len() >= MIN_CHUNK && r.len() <= MAX_CHUNK));
+
+        let mut edited = data.clone();
+        edited.splice(100_000..100_000, b"inserted line\n".iter().copied());
+        let hashes = |d: &[u8]| -> std::collections::HashSet<String> {
+            chunk_ranges(d)
+                .into_iter()
+                .map(|r| sha256_hex(&d[r]))
+                .collect()
+        };
+        let (a, b) = (hashes(&data), hashes(&edited));
+        assert!(
+            a.intersection(&b).count() + 3 >= a.len(),
+            "only chunks near the edit change"
+        );
+    }
+
+    #[test]
+    fn large_blobs_are_chunked_and_deduplicated() {
+        let tmp = std::env::temp_dir().join(format!("edda_blob_chunks_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let p = EddaPaths::discover(&tmp);
+        p.ensure_layout().unwrap();
+        std::fs::write(&p.config_json, r#"{"blob.chunk_threshold_kb": 64}"#).unwrap();
+
+        let log = noise(11, 1024 * 1024);
+        let mut rerun = log.clone();
+        rerun.extend_from_slice(b"one more line at the end\n");
+        let first = blob_put_classified(&p, &log, BlobClass::DecisionEvidence).unwrap();
+        let second = blob_put_classified(&p, &rerun, BlobClass::DecisionEvidence).unwrap();
+        let small = blob_put(&p, b"small").unwrap();
+
+        assert_eq!(blob_read(&p, &first).unwrap(), log);
+        assert_eq!(blob_read(&p, &second).unwrap(), rerun);
+        assert_eq!(blob_read(&p, &small).unwrap(), b"small");
+        let hex = first.strip_prefix("blob:sha256:").unwrap();
+        let manifest = blob_manifest(&p, hex).unwrap();
+        assert_eq!(manifest.size, log.len() as u64);
+        assert!(blob_manifest(&p, small.strip_prefix("blob:sha256:").unwrap()).is_none());
+        assert_eq!(blob_list(&p).unwrap().len(), 3, "chunks are not blobs");
+
+        let meta = crate::blob_meta::load_blob_meta(&p.blob_meta_json).unwrap();
+        let stats = blob_dedup_stats(&p, &meta).unwrap();
+        assert_eq!(stats.chunked_blobs, 2);
+        assert_eq!(stats.logical_bytes, (log.len() + rerun.len() + 5) as u64);
+        assert!(stats.ratio() > 1.5, "ratio {}", stats.ratio());
+        let evidence = &stats.by_class[1];
+        assert_eq!(evidence.class, BlobClass::DecisionEvidence);
+        assert!(evidence.saved_bytes() > 600 * 1024);
+
+        // Removing one blob keeps the chunks the other still uses.
+        blob_remove(&p, hex).unwrap();
+        let (removed, _) = blob_gc_chunks(&p, std::time::Duration::ZERO).unwrap();
+        assert!(removed <= 2, "removed {removed}");
+        assert_eq!(blob_read(&p, &second).unwrap(), rerun);
+        blob_remove(&p, second.strip_prefix("blob:sha256:").unwrap()).unwrap();
+        assert!(
+            blob_gc_chunks(&p, std::time::Duration::from_secs(3600))
+                .unwrap()
+                .0
+                == 0
+        );
+        let (removed, freed) = blob_gc_chunks(&p, std::time::Duration::ZERO).unwrap();
+        assert!(removed > 0 && freed >= log.len() as u64);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
 }
@@ -0,0 +1,506 @@
+//! Cold storage tier behind the archive.
+//!
+//! `edda gc` moves old events to `.edda/archive/events-YYYY-MM.jsonl.zst` and
+//! old blobs to `.edda/archive/blobs/` (see [`crate::retention`]). For
+//! long-lived projects even that archive grows, so `edda archive push` moves
+//! its files on to a cold location — a directory (external drive, network
+//! share) or an S3 prefix — named by `archive.cold` in `.edda/config.json`.
+//!
+//! Each pushed file is recorded in `.edda/archive/cold.json` with its size,
+//! hash and location. A read that needs a file no longer on disk fetches it
+//! back, checks the hash, and keeps it as a local cache until
+//! `edda archive evict` drops cached copies again. Appending to a pushed
+//! event segment fetches it first, so the next push uploads the whole file.
+//!
+//! Chunked blob manifests stay local: the chunks they point at are shared
+//! with active blobs, and chunk GC needs to see every manifest.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::Context;
+use edda_core::hash::sha256_hex;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::paths::EddaPaths;
+
+/// Config key naming the cold location.
+pub const COLD_LOCATION_KEY: &str = "archive.cold";
+
+/// Manifest of pushed files, under `.edda/archive/`.
+const MANIFEST_FILE: &str = "cold.json";
+
+/// Where cold files live.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColdLocation {
+    /// A directory, e.g. on an external drive.
+    Dir(PathBuf),
+    /// An `s3://bucket/prefix` URL, reached through the `aws` CLI.
+    S3(String),
+}
+
+impl ColdLocation {
+    /// Parse `s3://bucket/prefix`, `file:///path` or a plain path.
+    pub fn parse(spec: &str) -> anyhow::Result<Self> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            anyhow::bail!("empty cold location");
+        }
+        if spec.starts_with("s3://") {
+            return Ok(Self::S3(spec.trim_end_matches('/').to_string()));
+        }
+        let path = spec.strip_prefix("file://").unwrap_or(spec);
+        Ok(Self::Dir(PathBuf::from(path)))
+    }
+
+    /// The location configured under [`COLD_LOCATION_KEY`], if any.
+    pub fn from_config(paths: &EddaPaths) -> anyhow::Result<Option<Self>> {
+        let config = crate::settings::read_config(&paths.config_json)?;
+        match config.get(COLD_LOCATION_KEY) {
+            None | Some(serde_json::Value::Null) => Ok(None),
+            Some(serde_json::Value::String(s)) => Self::parse(s).map(Some),
+            Some(other) => anyhow::bail!("{COLD_LOCATION_KEY} must be a string, got {other}"),
+        }
+    }
+
+    /// Copy `local` to `name` under this location.
+    fn put(&self, local: &Path, name: &str) -> anyhow::Result<()> {
+        match self {
+            Self::Dir(dir) => {
+                let dest = dir.join(name);
+                if let Some(parent) = dest.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                let tmp = dest.with_extension("tmp");
+                std::fs::copy(local, &tmp)?;
+                std::fs::File::open(&tmp)?.sync_all()?;
+                std::fs::rename(&tmp, &dest)?;
+                Ok(())
+            }
+            Self::S3(url) => aws_cp(local.as_os_str(), format!("{url}/{name}").as_ref()),
+        }
+    }
+
+    /// Copy `name` under this location to `dest`.
+    fn get(&self, name: &str, dest: &Path) -> anyhow::Result<()> {
+        match self {
+            Self::Dir(dir) => {
+                std::fs::copy(dir.join(name), dest)?;
+                Ok(())
+            }
+            Self::S3(url) => aws_cp(format!("{url}/{name}").as_ref(), dest.as_os_str()),
+        }
+    }
+}
+
+impl std::fmt::Display for ColdLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dir(dir) => write!(f, "{}", dir.display()),
+            Self::S3(url) => f.write_str(url),
+        }
+    }
+}
+
+fn aws_cp(from: &std::ffi::OsStr, to: &std::ffi::OsStr) -> anyhow::Result<()> {
+    let out = Command::new("aws")
+        .args(["s3", "cp", "--only-show-errors"])
+        .arg(from)
+        .arg(to)
+        .output()
+        .context("running `aws s3 cp` (is the AWS CLI installed?)")?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "aws s3 cp {} {}: {}",
+            from.to_string_lossy(),
+            to.to_string_lossy(),
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// One pushed file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColdEntry {
+    /// The location it was pushed to, as configured at the time.
+    pub location: String,
+    pub size: u64,
+    pub sha256: String,
+    pub pushed_at: String,
+}
+
+/// `.edda/archive/cold.json`: pushed files by name relative to
+/// `.edda/archive/` (`events-2026-01.jsonl.zst`, `blobs/<hash>`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColdManifest {
+    pub entries: BTreeMap<String, ColdEntry>,
+}
+
+fn manifest_path(paths: &EddaPaths) -> PathBuf {
+    paths.archive_dir.join(MANIFEST_FILE)
+}
+
+/// Read the manifest. A missing file means nothing was pushed.
+pub fn load_manifest(paths: &EddaPaths) -> anyhow::Result<ColdManifest> {
+    match std::fs::read_to_string(manifest_path(paths)) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(ColdManifest::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn save_manifest(paths: &EddaPaths, manifest: &ColdManifest) -> anyhow::Result<()> {
+    let path = manifest_path(paths);
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(manifest)?)?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+/// Archive files that can go cold: event segments and archived plain
+/// blobs, by manifest name.
+fn local_files(paths: &EddaPaths) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    for path in crate::retention::archive_files(paths)? {
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            files.push((name.to_string(), path.clone()));
+        }
+    }
+    for blob in crate::blob_store::blob_list_archived(paths)? {
+        if crate::blob_store::blob_manifest(paths, &blob.hash).is_some() {
+            continue;
+        }
+        files.push((
+            format!("blobs/{}", blob.hash),
+            paths.archive_blobs_dir.join(&blob.hash),
+        ));
+    }
+    Ok(files)
+}
+
+/// Event segment names, local or cold, oldest month first.
+pub(crate) fn event_segments(paths: &EddaPaths) -> anyhow::Result<Vec<String>> {
+    let mut names: BTreeSet<String> = crate::retention::archive_files(paths)?
+        .iter()
+        .filter_map(|p| p.file_name()?.to_str().map(str::to_string))
+        .collect();
+    names.extend(
+        load_manifest(paths)?
+            .entries
+            .into_keys()
+            .filter(|n| n.starts_with("events-")),
+    );
+    Ok(names.into_iter().collect())
+}
+
+/// What [`push`] did (or would do, on a dry run).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PushReport {
+    /// Files uploaded, by manifest name.
+    pub pushed: Vec<String>,
+    pub pushed_bytes: u64,
+    /// Local copies removed, including already-cold cached copies.
+    pub removed_local: usize,
+    pub freed_bytes: u64,
+}
+
+/// Upload archive files that are new or changed since their last push, then
+/// remove the local copies unless `keep_local`. Callers hold the workspace
+/// lock, since `edda gc` writes the same files.
+///
+/// The manifest is saved after each upload, so an interrupted push never
+/// leaves a local file deleted without a manifest entry.
+pub fn push(
+    paths: &EddaPaths,
+    location: &ColdLocation,
+    keep_local: bool,
+    dry_run: bool,
+) -> anyhow::Result<PushReport> {
+    let mut manifest = load_manifest(paths)?;
+    let mut report = PushReport::default();
+    for (name, path) in local_files(paths)? {
+        let bytes = std::fs::read(&path)?;
+        let sha256 = sha256_hex(&bytes);
+        let size = bytes.len() as u64;
+        if manifest.entries.get(&name).map(|e| &e.sha256) != Some(&sha256) {
+            report.pushed.push(name.clone());
+            report.pushed_bytes += size;
+            if !dry_run {
+                location
+                    .put(&path, &name)
+                    .with_context(|| format!("pushing {name} to {location}"))?;
+                manifest.entries.insert(
+                    name.clone(),
+                    ColdEntry {
+                        location: location.to_string(),
+                        size,
+                        sha256,
+                        pushed_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
+                    },
+                );
+                save_manifest(paths, &manifest)?;
+            }
+        }
+        if !keep_local {
+            report.removed_local += 1;
+            report.freed_bytes += size;
+            if !dry_run {
+                std::fs::remove_file(&path)?;
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// The local path of archive file `name`, fetching it from cold storage
+/// when only the manifest has it. `None` when neither has it.
+pub fn fetch(paths: &EddaPaths, name: &str) -> anyhow::Result<Option<PathBuf>> {
+    let local = paths.archive_dir.join(name);
+    if local.exists() {
+        return Ok(Some(local));
+    }
+    let manifest = load_manifest(paths)?;
+    let Some(entry) = manifest.entries.get(name) else {
+        return Ok(None);
+    };
+    let location = ColdLocation::parse(&entry.location)?;
+    if let Some(parent) = local.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = local.with_extension("fetch");
+    if let Err(e) = location.get(name, &tmp) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e.context(format!("fetching {name} from {location}")));
+    }
+    let bytes = std::fs::read(&tmp)?;
+    if sha256_hex(&bytes) != entry.sha256 {
+        let _ = std::fs::remove_file(&tmp);
+        anyhow::bail!("{name} from {location} does not match its recorded hash");
+    }
+    std::fs::rename(&tmp, &local)?;
+    Ok(Some(local))
+}
+
+/// Fetch every cold file that is not cached locally. Returns the names
+/// fetched.
+pub fn fetch_all(paths: &EddaPaths) -> anyhow::Result<Vec<String>> {
+    let mut fetched = Vec::new();
+    for name in load_manifest(paths)?.entries.keys() {
+        if !paths.archive_dir.join(name).exists() {
+            fetch(paths, name)?;
+            fetched.push(name.clone());
+        }
+    }
+    Ok(fetched)
+}
+
+/// What [`evict`] removed (or would remove).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct EvictReport {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Remove local copies of cold files that match what was pushed. A copy
+/// changed since (an appended segment) is kept until the next push.
+pub fn evict(paths: &EddaPaths, dry_run: bool) -> anyhow::Result<EvictReport> {
+    let mut report = EvictReport::default();
+    for (name, entry) in load_manifest(paths)?.entries {
+        let local = paths.archive_dir.join(&name);
+        let Ok(bytes) = std::fs::read(&local) else {
+            continue;
+        };
+        if sha256_hex(&bytes) != entry.sha256 {
+            continue;
+        }
+        report.files += 1;
+        report.bytes += entry.size;
+        if !dry_run {
+            std::fs::remove_file(&local)?;
+        }
+    }
+    Ok(report)
+}
+
+/// State of one archive file across the tiers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ColdFileStatus {
+    pub name: String,
+    pub size: u64,
+    /// Recorded in the manifest.
+    pub cold: bool,
+    /// Present under `.edda/archive/`.
+    pub local: bool,
+    /// The local copy differs from the pushed one (or was never pushed).
+    pub changed: bool,
+}
+
+/// Every archive file, local or cold, by name.
+pub fn status(paths: &EddaPaths) -> anyhow::Result<Vec<ColdFileStatus>> {
+    let manifest = load_manifest(paths)?;
+    let mut files: BTreeMap<String, ColdFileStatus> = BTreeMap::new();
+    for (name, path) in local_files(paths)? {
+        let bytes = std::fs::read(&path)?;
+        let entry = manifest.entries.get(&name);
+        files.insert(
+            name.clone(),
+            ColdFileStatus {
+                name,
+                size: bytes.len() as u64,
+                cold: entry.is_some(),
+                local: true,
+                changed: entry.is_none_or(|e| e.sha256 != sha256_hex(&bytes)),
+            },
+        );
+    }
+    for (name, entry) in manifest.entries {
+        files.entry(name.clone()).or_insert(ColdFileStatus {
+            name,
+            size: entry.size,
+            cold: true,
+            local: false,
+            changed: false,
+        });
+    }
+    Ok(files.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blob_store::{blob_archive, blob_get_path, blob_put};
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::{Ledger, RetentionPolicy};
+    use edda_core::event::{finalize_event, new_note_event};
+
+    fn cmd_at(ledger: &Ledger, text: &str, ts: &str) -> String {
+        let mut e = new_note_event("main", None, "system", text, &[]).unwrap();
+        e.event_type = "cmd".into();
+        e.ts = ts.into();
+        e.parent_hash = ledger.last_event_hash().unwrap();
+        finalize_event(&mut e).unwrap();
+        ledger.append_event(&e).unwrap();
+        e.event_id
+    }
+
+    fn event_ids(ledger: &Ledger) -> Vec<String> {
+        ledger
+            .iter_events_with_archive()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_id)
+            .collect()
+    }
+
+    #[test]
+    fn pushed_archive_reads_back_transparently() {
+        let base = std::env::temp_dir().join(format!("edda_cold_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let (root, cold_dir) = (base.join("repo"), base.join("cold"));
+        let paths = EddaPaths::discover(&root);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(&root).unwrap();
+
+        cmd_at(&ledger, "early jan", "2026-01-02T10:00:00Z");
+        let late_jan = cmd_at(&ledger, "late jan", "2026-01-28T10:00:00Z");
+        cmd_at(&ledger, "feb", "2026-02-03T10:00:00Z");
+        cmd_at(&ledger, "tip", "2026-06-30T10:00:00Z");
+        let all = event_ids(&ledger);
+
+        let policy = RetentionPolicy::new([("cmd".to_string(), 30)].into());
+        let at = |ts: &str| OffsetDateTime::parse(ts, &Rfc3339).unwrap();
+        ledger
+            .archive_expired_events(&policy, at("2026-02-15T00:00:00Z"), false)
+            .unwrap();
+        let blob = blob_put(&paths, b"old trace").unwrap();
+        let hex = blob.trim_start_matches("blob:sha256:").to_string();
+        blob_archive(&paths, &hex).unwrap();
+
+        let location = ColdLocation::parse(&format!("file://{}", cold_dir.display())).unwrap();
+        let dry = push(&paths, &location, false, true).unwrap();
+        assert_eq!(dry.pushed.len(), 2);
+        assert!(load_manifest(&paths).unwrap().entries.is_empty());
+
+        let report = push(&paths, &location, false, false).unwrap();
+        assert_eq!(
+            report.pushed,
+            vec![
+                "events-2026-01.jsonl.zst".to_string(),
+                format!("blobs/{hex}")
+            ]
+        );
+        assert_eq!(report.removed_local, 2);
+        assert!(crate::retention::archive_files(&paths).unwrap().is_empty());
+        assert!(cold_dir.join("events-2026-01.jsonl.zst").exists());
+        assert!(status(&paths).unwrap().iter().all(|f| f.cold && !f.local));
+
+        // Reads fetch back and cache.
+        assert_eq!(event_ids(&ledger), all);
+        assert!(paths.archive_dir.join("events-2026-01.jsonl.zst").exists());
+        assert_eq!(
+            blob_get_path(&paths, &blob).unwrap(),
+            paths.archive_blobs_dir.join(&hex)
+        );
+        assert_eq!(evict(&paths, false).unwrap().files, 2);
+
+        // Archiving into a pushed month fetches it first, so nothing is lost
+        // and the segment counts as changed until pushed again.
+        ledger
+            .archive_expired_events(&policy, at("2026-07-01T00:00:00Z"), false)
+            .unwrap();
+        assert!(!ledger
+            .iter_events()
+            .unwrap()
+            .iter()
+            .any(|e| e.event_id == late_jan));
+        assert_eq!(event_ids(&ledger), all);
+        let changed: Vec<String> = status(&paths)
+            .unwrap()
+            .into_iter()
+            .filter(|f| f.changed)
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(
+            changed,
+            vec!["events-2026-01.jsonl.zst", "events-2026-02.jsonl.zst"]
+        );
+        assert_eq!(evict(&paths, false).unwrap().files, 0);
+        let again = push(&paths, &location, false, false).unwrap();
+        assert_eq!(
+            again.pushed,
+            vec!["events-2026-01.jsonl.zst", "events-2026-02.jsonl.zst"]
+        );
+        assert_eq!(event_ids(&ledger), all);
+
+        // A cold copy that no longer matches its hash is refused.
+        evict(&paths, false).unwrap();
+        std::fs::write(cold_dir.join("events-2026-02.jsonl.zst"), b"tampered").unwrap();
+        assert!(fetch(&paths, "events-2026-02.jsonl.zst").is_err());
+        assert!(!paths.archive_dir.join("events-2026-02.jsonl.zst").exists());
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn locations_parse_from_config_strings() {
+        assert_eq!(
+            ColdLocation::parse("s3://bucket/edda/").unwrap(),
+            ColdLocation::S3("s3://bucket/edda".into())
+        );
+        assert_eq!(
+            ColdLocation::parse("file:///mnt/cold").unwrap(),
+            ColdLocation::Dir(PathBuf::from("/mnt/cold"))
+        );
+        assert_eq!(
+            ColdLocation::parse("/mnt/cold").unwrap(),
+            ColdLocation::Dir(PathBuf::from("/mnt/cold"))
+        );
+        assert!(ColdLocation::parse("  ").is_err());
+    }
+}
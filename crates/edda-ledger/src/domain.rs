@@ -6,6 +6,30 @@
 //! Storage-internal types (e.g. `DecisionRow`) remain in `sqlite_store/types.rs`
 //! and are not exposed outside edda-ledger.
 
+/// An append refused because the event's `parent_hash` is not the ledger's
+/// current tip: another writer appended after the event was built.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("event {event_id} has stale parent_hash: expected {expected:?}, got {got:?}")]
+pub struct StaleParent {
+    pub event_id: String,
+    /// The current tip.
+    pub expected: Option<String>,
+    pub got: Option<String>,
+}
+
+/// Filters for a page of one branch's events (see `Ledger::page_events_filtered`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventFilter<'a> {
+    pub branch: &'a str,
+    pub event_type: Option<&'a str>,
+    /// Case-insensitive substring of the payload.
+    pub keyword: Option<&'a str>,
+    /// Inclusive lower bound on `ts`.
+    pub after: Option<&'a str>,
+    /// Inclusive upper bound on `ts`.
+    pub before: Option<&'a str>,
+}
+
 /// The type of recurring pattern detected.
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
@@ -232,3 +256,60 @@ pub struct SuggestionRow {
     pub created_at: String,
     pub reviewed_at: Option<String>,
 }
+
+/// One hot read path, as the planner sees it.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct QueryCheck {
+    pub name: String,
+    /// `EXPLAIN QUERY PLAN` detail lines, outermost first.
+    pub plan: Vec<String>,
+    /// Some step reads a whole table without an index.
+    pub full_scan: bool,
+    pub elapsed_ms: f64,
+    pub rows: usize,
+    pub slow: bool,
+}
+
+/// An applied schema step whose tables, columns or indexes are missing,
+/// from `Ledger::schema_drift`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SchemaDrift {
+    pub version: u32,
+    pub migration: String,
+    /// e.g. `column decisions.scope`, `index idx_events_ts`.
+    pub missing: Vec<String>,
+}
+
+/// Outcome of `Ledger::maintain` (`edda maintenance`).
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct LedgerMaintenance {
+    pub dry_run: bool,
+    pub db_bytes_before: u64,
+    pub db_bytes_after: u64,
+    pub wal_bytes_before: u64,
+    pub wal_bytes_after: u64,
+    /// Pages on the freelist before the pass; VACUUM returns them to the OS.
+    pub free_pages: u64,
+    pub page_size: u64,
+    /// WAL frames copied into the database by the checkpoint.
+    pub wal_frames_checkpointed: u64,
+    /// Another connection held the WAL, so the checkpoint could not finish.
+    pub checkpoint_busy: bool,
+}
+
+/// Query planner review of a ledger, from `Ledger::diagnose_queries`.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LedgerDiagnostics {
+    pub schema_version: u32,
+    pub decisions: usize,
+    pub missing_indexes: Vec<String>,
+    pub queries: Vec<QueryCheck>,
+}
+
+impl LedgerDiagnostics {
+    /// Nothing to act on: every index present and no probe scanned a table
+    /// or ran slow.
+    pub fn is_healthy(&self) -> bool {
+        self.missing_indexes.is_empty() && self.queries.iter().all(|q| !q.full_scan && !q.slow)
+    }
+}
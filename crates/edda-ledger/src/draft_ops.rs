@@ -0,0 +1,651 @@
+//! Draft write path — propose and approve/reject, shared by `edda draft`
+//! and the MCP draft tools.
+//!
+//! [`crate::drafts`] folds draft state out of the ledger; this module
+//! appends the events that change it. Both front ends call these functions
+//! under the workspace lock, so evidence parsing, policy routing (rules,
+//! failed-command detection, domain owners) and the stage admission check
+//! behave the same whichever side a draft is touched from.
+//!
+//! Deriving views is left to the caller (`edda_derive::rebuild_all`), as is
+//! auto-evidence collection: both live above the ledger.
+
+use std::collections::HashSet;
+
+use edda_core::event::{
+    new_approval_event, new_approval_request_event, new_draft_id, new_draft_proposed_event,
+    ApprovalEventParams, ApprovalRequestParams, DraftProposedParams, DraftStageSpec,
+};
+use edda_core::policy::{
+    assign_stages, domain_stages, load_actors_from_dir, load_domain_owners_from_dir, route_select,
+    stage_reason, PolicyRule, PolicyStageDef, PolicyV2Config, PolicyWhen,
+};
+use serde::Deserialize;
+
+use crate::drafts::{import_legacy_drafts, DraftView};
+use crate::Ledger;
+
+// ── Evidence ──
+
+/// Parse one evidence reference: `evt_*` or `blob:sha256:*`.
+pub fn parse_evidence_arg(s: &str) -> anyhow::Result<serde_json::Value> {
+    if s.starts_with("evt_") {
+        Ok(serde_json::json!({"event_id": s, "why": ""}))
+    } else if s.starts_with("blob:sha256:") {
+        Ok(serde_json::json!({"blob": s, "why": ""}))
+    } else {
+        anyhow::bail!("invalid evidence ref: {s} (must start with evt_ or blob:sha256:)")
+    }
+}
+
+fn evidence_key(item: &serde_json::Value) -> Option<&str> {
+    item.get("event_id")
+        .or_else(|| item.get("blob"))
+        .and_then(|x| x.as_str())
+}
+
+/// Manual evidence without repeats, followed by the `auto` items it does
+/// not already reference.
+pub fn merge_evidence(
+    manual: Vec<serde_json::Value>,
+    auto: Vec<serde_json::Value>,
+) -> Vec<serde_json::Value> {
+    let mut seen = HashSet::new();
+    manual
+        .into_iter()
+        .chain(auto)
+        .filter(|e| evidence_key(e).is_none_or(|k| seen.insert(k.to_string())))
+        .collect()
+}
+
+/// Whether any evidence item is a `cmd` event that exited non-zero.
+pub fn evidence_has_failed_cmd(
+    ledger: &Ledger,
+    evidence: &[serde_json::Value],
+) -> anyhow::Result<bool> {
+    let ids: HashSet<&str> = evidence
+        .iter()
+        .filter_map(|e| e.get("event_id").and_then(|x| x.as_str()))
+        .collect();
+    if ids.is_empty() {
+        return Ok(false);
+    }
+    for ev in ledger.iter_events()? {
+        if ev.event_type == "cmd" && ids.contains(ev.event_id.as_str()) {
+            let exit = ev
+                .payload
+                .get("exit_code")
+                .and_then(|x| x.as_i64())
+                .unwrap_or(0);
+            if exit != 0 {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
+/// Domains of the decisions among draft evidence items (`{"event_id": ..}`),
+/// in order and without repeats. Missing events and non-decisions are
+/// skipped.
+pub fn evidence_decision_domains(
+    ledger: &Ledger,
+    evidence: &[serde_json::Value],
+) -> anyhow::Result<Vec<String>> {
+    let mut domains: Vec<String> = Vec::new();
+    for id in evidence
+        .iter()
+        .filter_map(|item| item.get("event_id").and_then(|x| x.as_str()))
+    {
+        let Some(ev) = ledger.get_event(id)? else {
+            continue;
+        };
+        if ev.event_type != "note" || !edda_core::decision::is_decision(&ev.payload) {
+            continue;
+        }
+        if let Some(dp) = edda_core::decision::extract_decision(&ev.payload) {
+            let domain = edda_core::decision::extract_domain(&dp.key);
+            if !domains.contains(&domain) {
+                domains.push(domain);
+            }
+        }
+    }
+    Ok(domains)
+}
+
+// ── Policy ──
+
+#[derive(Debug, Deserialize, Clone)]
+struct RequireApprovalRuleV1 {
+    #[serde(default)]
+    default: bool,
+    #[serde(default)]
+    if_labels_any: Vec<String>,
+    #[serde(default)]
+    if_failed_cmd: bool,
+    #[serde(default)]
+    if_evidence_count_gte: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct PolicyV1 {
+    #[serde(default = "default_min_approvals")]
+    min_approvals: usize,
+    #[serde(default)]
+    require_approval: RequireApprovalRuleV1,
+}
+
+fn default_min_approvals() -> usize {
+    1
+}
+
+impl Default for RequireApprovalRuleV1 {
+    fn default() -> Self {
+        Self {
+            default: false,
+            if_labels_any: vec!["risk".into(), "security".into(), "prod".into()],
+            if_failed_cmd: true,
+            if_evidence_count_gte: 15,
+        }
+    }
+}
+
+fn convert_v1_to_v2(v1: PolicyV1) -> PolicyV2Config {
+    let r = &v1.require_approval;
+    let mut when = PolicyWhen::default();
+    if !r.if_labels_any.is_empty() {
+        when.labels_any = Some(r.if_labels_any.clone());
+    }
+    if r.if_failed_cmd {
+        when.failed_cmd = Some(true);
+    }
+    if r.if_evidence_count_gte > 0 {
+        when.evidence_count_gte = Some(r.if_evidence_count_gte);
+    }
+
+    let min_app = v1.min_approvals.max(1);
+    let require_rule = PolicyRule {
+        id: "require".to_string(),
+        when,
+        stages: vec![PolicyStageDef {
+            stage_id: "default".to_string(),
+            role: "approver".to_string(),
+            min_approvals: min_app,
+            max_assignees: 0,
+        }],
+    };
+
+    let default_rule = PolicyRule {
+        id: "default".to_string(),
+        when: PolicyWhen {
+            default: Some(true),
+            ..Default::default()
+        },
+        stages: if r.default {
+            vec![PolicyStageDef {
+                stage_id: "default".to_string(),
+                role: "approver".to_string(),
+                min_approvals: min_app,
+                max_assignees: 0,
+            }]
+        } else {
+            vec![]
+        },
+    };
+
+    PolicyV2Config {
+        version: 2,
+        roles: vec!["approver".to_string()],
+        rules: vec![require_rule, default_rule],
+        permissions: None,
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionCheck {
+    version: u32,
+}
+
+/// Load `policy.yaml` for draft routing. Version 1 policies are converted to
+/// the v2 rule form; a missing file routes every draft to no stages.
+pub fn load_draft_policy(ledger: &Ledger) -> anyhow::Result<PolicyV2Config> {
+    let path = ledger.paths.edda_dir.join("policy.yaml");
+    if !path.exists() {
+        return edda_core::policy::load_policy_from_dir(&ledger.paths.edda_dir);
+    }
+    let content = std::fs::read(&path)?;
+    let vc: VersionCheck = serde_yaml::from_slice(&content)?;
+    match vc.version {
+        1 => Ok(convert_v1_to_v2(serde_yaml::from_slice(&content)?)),
+        2 => Ok(serde_yaml::from_slice(&content)?),
+        other => anyhow::bail!("unsupported policy version: {other}"),
+    }
+}
+
+/// Route a draft: `explicit` stages when given, else the first matching
+/// policy rule. Either way, decisions in owned domains add their owners'
+/// stages. Returns the rule id and the assigned stages.
+pub fn route_draft(
+    ledger: &Ledger,
+    labels: &[String],
+    evidence: &[serde_json::Value],
+    explicit: Option<Vec<PolicyStageDef>>,
+) -> anyhow::Result<(String, Vec<DraftStageSpec>)> {
+    let actors = load_actors_from_dir(&ledger.paths.edda_dir)?;
+    let (rule_id, policy_stages) = match explicit {
+        Some(stages) => ("explicit".to_string(), stages),
+        None => {
+            let policy = load_draft_policy(ledger)?;
+            let failed = evidence_has_failed_cmd(ledger, evidence)?;
+            route_select(&policy, labels, failed, evidence.len())
+        }
+    };
+    let mut stages = assign_stages(&policy_stages, &actors);
+    let owners = load_domain_owners_from_dir(&ledger.paths.edda_dir)?;
+    if !owners.is_empty() {
+        let domains = evidence_decision_domains(ledger, evidence)?;
+        let extra = domain_stages(
+            &owners,
+            domains.iter().map(String::as_str),
+            &actors,
+            &stages,
+        );
+        stages.extend(extra);
+    }
+    Ok((rule_id, stages))
+}
+
+// ── Propose ──
+
+/// Input for [`propose_draft`].
+pub struct DraftProposal<'a> {
+    pub title: &'a str,
+    pub purpose: Option<&'a str>,
+    /// Defaults to the title.
+    pub contribution: Option<&'a str>,
+    pub labels: &'a [String],
+    /// Already parsed and merged (see [`merge_evidence`]).
+    pub evidence: Vec<serde_json::Value>,
+    /// Human-readable lines for the auto-collected evidence, if any.
+    pub auto_preview: Vec<String>,
+    /// Explicit stages; `None` routes through `policy.yaml`.
+    pub stages: Option<Vec<PolicyStageDef>>,
+}
+
+/// A freshly proposed draft.
+#[derive(Debug)]
+pub struct ProposedDraft {
+    pub draft_id: String,
+    pub event_id: String,
+    pub branch: String,
+    /// Ledger tip the draft was proposed against; empty on an empty ledger.
+    pub base_parent_hash: String,
+    pub rule_id: String,
+    pub stages: Vec<DraftStageSpec>,
+}
+
+/// Record a `draft.proposed` event on the HEAD branch and one
+/// `approval_request` per required stage. The commit itself is only
+/// written on apply (DRAFT-01).
+pub fn propose_draft(ledger: &Ledger, p: DraftProposal<'_>) -> anyhow::Result<ProposedDraft> {
+    import_legacy_drafts(ledger)?;
+    let branch = ledger.head_branch()?;
+    let base_parent_hash = ledger.last_event_hash()?.unwrap_or_default();
+
+    let (rule_id, stages) = route_draft(ledger, p.labels, &p.evidence, p.stages)?;
+    let need_approval = !stages.is_empty();
+
+    let draft_id = new_draft_id();
+    let parent_hash = ledger.last_event_hash()?;
+    let proposal = new_draft_proposed_event(&DraftProposedParams {
+        branch: &branch,
+        parent_hash: parent_hash.as_deref(),
+        draft_id: &draft_id,
+        created_at: None,
+        base_parent_hash: &base_parent_hash,
+        title: p.title,
+        purpose: p.purpose.unwrap_or(""),
+        contribution: p.contribution.unwrap_or(p.title),
+        labels: p.labels,
+        evidence: &p.evidence,
+        auto_preview_lines: &p.auto_preview,
+        route_rule_id: &rule_id,
+        stages: &stages,
+        policy_require_approval: need_approval,
+        policy_min_approvals: if need_approval { 1 } else { 0 },
+        origin: None,
+    })?;
+    ledger.append_event(&proposal)?;
+
+    for stage in &stages {
+        let parent_hash = ledger.last_event_hash()?;
+        let req = new_approval_request_event(&ApprovalRequestParams {
+            branch: &branch,
+            parent_hash: parent_hash.as_deref(),
+            draft_id: &draft_id,
+            draft_sha256: &proposal.hash,
+            route_rule_id: &rule_id,
+            stage_id: &stage.stage_id,
+            role: &stage.role,
+            assignees: &stage.assignees,
+            reason: &stage_reason(stage, &rule_id),
+        })?;
+        ledger.append_event(&req)?;
+    }
+
+    Ok(ProposedDraft {
+        draft_id,
+        event_id: proposal.event_id,
+        branch,
+        base_parent_hash,
+        rule_id,
+        stages,
+    })
+}
+
+// ── Approve / reject ──
+
+/// Approve or reject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DraftDecision {
+    Approve,
+    Reject,
+}
+
+impl DraftDecision {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+        }
+    }
+}
+
+/// Input for [`decide_draft`].
+pub struct DraftDecisionInput<'a> {
+    pub draft_id: &'a str,
+    pub decision: DraftDecision,
+    pub actor: &'a str,
+    pub note: &'a str,
+    /// Explicit stage to act on (takes precedence over `role`).
+    pub stage: Option<&'a str>,
+    /// Role the actor is acting as; picks the first pending stage with it.
+    pub role: Option<&'a str>,
+}
+
+/// A recorded decision and the draft as it stands after it.
+#[derive(Debug)]
+pub struct DecidedDraft {
+    pub event_id: String,
+    /// Empty for flat (stage-less) drafts.
+    pub stage_id: String,
+    pub draft: DraftView,
+}
+
+/// Record an `approval` event on a draft (and, for staged drafts, on the
+/// stage [`DraftView::target_stage`] picks) on behalf of `actor`, who must
+/// be admitted to that stage.
+pub fn decide_draft(
+    ledger: &Ledger,
+    input: DraftDecisionInput<'_>,
+) -> anyhow::Result<DecidedDraft> {
+    import_legacy_drafts(ledger)?;
+    let draft = ledger
+        .draft_view(input.draft_id)?
+        .ok_or_else(|| anyhow::anyhow!("draft not found: {}", input.draft_id))?;
+    let head = ledger.head_branch()?;
+    if draft.branch != head {
+        anyhow::bail!("draft branch mismatch: draft={}, head={head}", draft.branch);
+    }
+
+    let (stage_id, stage_role) = match draft.target_stage(input.stage, input.role)? {
+        Some(stage) => {
+            let actors = load_actors_from_dir(&ledger.paths.edda_dir)?;
+            if !stage.admits(&actors, input.actor) {
+                anyhow::bail!(
+                    "actor '{}' is not assigned to stage '{}' and does not have role '{}'",
+                    input.actor,
+                    stage.stage_id,
+                    stage.role
+                );
+            }
+            (stage.stage_id.clone(), stage.role.clone())
+        }
+        None => (String::new(), String::new()),
+    };
+
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_approval_event(&ApprovalEventParams {
+        branch: &head,
+        parent_hash: parent_hash.as_deref(),
+        draft_id: input.draft_id,
+        draft_sha256: &draft.proposal_hash,
+        decision: input.decision.as_str(),
+        actor: input.actor,
+        note: input.note,
+        stage_id: &stage_id,
+        role: &stage_role,
+        device_id: None,
+    })?;
+    ledger.append_event(&event)?;
+
+    let draft = ledger
+        .draft_view(input.draft_id)?
+        .ok_or_else(|| anyhow::anyhow!("draft not found: {}", input.draft_id))?;
+    Ok(DecidedDraft {
+        event_id: event.event_id,
+        stage_id,
+        draft,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::paths::EddaPaths;
+    use crate::StageStatus;
+    use edda_core::event::{new_cmd_event, new_note_event, CmdEventParams};
+
+    fn setup(name: &str) -> (std::path::PathBuf, Ledger) {
+        let tmp =
+            std::env::temp_dir().join(format!("edda_draft_ops_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+        (tmp, ledger)
+    }
+
+    /// Propose through policy routing with no labels.
+    fn propose_routed(
+        ledger: &Ledger,
+        title: &str,
+        evidence: Vec<serde_json::Value>,
+    ) -> ProposedDraft {
+        propose_draft(
+            ledger,
+            DraftProposal {
+                title,
+                purpose: None,
+                contribution: None,
+                labels: &[],
+                evidence,
+                auto_preview: vec![],
+                stages: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn append(ledger: &Ledger, mut event: edda_core::Event) -> edda_core::Event {
+        event.parent_hash = ledger.last_event_hash().unwrap();
+        edda_core::event::finalize_event(&mut event).unwrap();
+        ledger.append_event(&event).unwrap();
+        event
+    }
+
+    #[test]
+    fn evidence_refs_parse_and_merge_without_repeats() {
+        assert!(parse_evidence_arg("nope").is_err());
+        let manual = vec![
+            parse_evidence_arg("evt_a").unwrap(),
+            parse_evidence_arg("blob:sha256:b").unwrap(),
+            parse_evidence_arg("evt_a").unwrap(),
+        ];
+        let auto = vec![
+            serde_json::json!({"event_id": "evt_a", "why": "todo"}),
+            serde_json::json!({"event_id": "evt_c", "why": "todo"}),
+        ];
+        let keys: Vec<_> = merge_evidence(manual, auto)
+            .iter()
+            .filter_map(|e| evidence_key(e).map(String::from))
+            .collect();
+        assert_eq!(keys, ["evt_a", "blob:sha256:b", "evt_c"]);
+    }
+
+    #[test]
+    fn evidence_decision_domains_reads_decision_keys() {
+        let (tmp, ledger) = setup("domains");
+        let decision = append(
+            &ledger,
+            edda_core::event::new_decision_event(
+                "main",
+                None,
+                "system",
+                &edda_core::types::DecisionPayload {
+                    key: "db.engine".into(),
+                    value: "postgres".into(),
+                    reason: None,
+                    scope: None,
+                    authority: None,
+                    affected_paths: None,
+                    tags: None,
+                    review_after: None,
+                    reversibility: None,
+                    village_id: None,
+                },
+            )
+            .unwrap(),
+        );
+        let note = append(
+            &ledger,
+            new_note_event("main", None, "user", "cache: redis", &[]).unwrap(),
+        );
+
+        let evidence = vec![
+            serde_json::json!({"event_id": note.event_id}),
+            serde_json::json!({"event_id": decision.event_id}),
+            serde_json::json!({"event_id": decision.event_id}),
+            serde_json::json!({"event_id": "evt_missing"}),
+            serde_json::json!({"blob": "blob:sha256:abc"}),
+        ];
+        let domains = evidence_decision_domains(&ledger, &evidence).unwrap();
+        assert_eq!(domains, vec!["db".to_string()]);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn failed_cmd_evidence_routes_through_a_v1_policy() {
+        let (tmp, ledger) = setup("v1_policy");
+        std::fs::write(
+            ledger.paths.edda_dir.join("policy.yaml"),
+            "version: 1\nmin_approvals: 2\n",
+        )
+        .unwrap();
+        let argv = vec!["cargo".to_string(), "test".to_string()];
+        let cmd = append(
+            &ledger,
+            new_cmd_event(&CmdEventParams {
+                branch: "main",
+                parent_hash: None,
+                argv: &argv,
+                cwd: ".",
+                exit_code: 1,
+                duration_ms: 10,
+                stdout_blob: "",
+                stderr_blob: "",
+            })
+            .unwrap(),
+        );
+
+        let clean = propose_routed(&ledger, "Clean", vec![]);
+        assert_eq!(clean.rule_id, "default");
+        assert!(clean.stages.is_empty());
+
+        let evidence = vec![parse_evidence_arg(&cmd.event_id).unwrap()];
+        assert!(evidence_has_failed_cmd(&ledger, &evidence).unwrap());
+        let failed = propose_routed(&ledger, "Red build", evidence);
+        assert_eq!(failed.rule_id, "require");
+        assert_eq!(failed.stages.len(), 1);
+        assert_eq!(failed.stages[0].min_approvals, 2);
+        assert_eq!(
+            ledger
+                .iter_events_by_type("approval_request")
+                .unwrap()
+                .len(),
+            1
+        );
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn decisions_land_on_the_targeted_stage() {
+        let (tmp, ledger) = setup("decide");
+        let proposed = propose_draft(
+            &ledger,
+            DraftProposal {
+                title: "Deploy",
+                purpose: None,
+                contribution: None,
+                labels: &[],
+                evidence: vec![],
+                auto_preview: vec![],
+                stages: Some(vec![
+                    PolicyStageDef {
+                        stage_id: "ops".into(),
+                        role: "ops".into(),
+                        min_approvals: 1,
+                        max_assignees: 0,
+                    },
+                    PolicyStageDef {
+                        stage_id: "lead".into(),
+                        role: "lead".into(),
+                        min_approvals: 1,
+                        max_assignees: 0,
+                    },
+                ]),
+            },
+        )
+        .unwrap();
+        assert_eq!(proposed.rule_id, "explicit");
+
+        let decide = |decision, role: Option<&'static str>| {
+            decide_draft(
+                &ledger,
+                DraftDecisionInput {
+                    draft_id: &proposed.draft_id,
+                    decision,
+                    actor: "alice",
+                    note: "",
+                    stage: None,
+                    role,
+                },
+            )
+        };
+        let approved = decide(DraftDecision::Approve, Some("lead")).unwrap();
+        assert_eq!(approved.stage_id, "lead");
+        assert_eq!(approved.draft.stages[1].status, StageStatus::Approved);
+
+        let rejected = decide(DraftDecision::Reject, None).unwrap();
+        assert_eq!(rejected.stage_id, "ops");
+        assert_eq!(rejected.draft.status, crate::DraftStatus::Rejected);
+        assert!(decide(DraftDecision::Approve, None).is_err());
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
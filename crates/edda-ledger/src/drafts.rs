@@ -0,0 +1,705 @@
+//! Draft projection — derive draft state from `draft.*` and `approval`
+//! ledger events.
+//!
+//! A draft is proposed once (`draft.proposed`, carrying the whole proposal),
+//! collects stage decisions (`approval`), and is closed by `draft.applied`
+//! or dropped by `draft.deleted`. Status is never stored: the CLI, MCP
+//! server and HTTP API all read the same fold, so there is one draft model
+//! and no file that two writers can race on.
+//!
+//! As with the task rail, the fold applies events as facts; legality (no
+//! approving an applied draft, no acting on a closed stage) is checked on
+//! the write path under the workspace lock, via [`DraftView::target_stage`].
+
+use crate::paths::EddaPaths;
+use crate::Ledger;
+use edda_core::event::{
+    new_draft_applied_event, new_draft_proposed_event, DraftProposedParams, DraftStageSpec,
+};
+use edda_core::policy::ActorsConfig;
+use edda_core::types::Event;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Derived status of a draft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DraftStatus {
+    /// Waiting on approvals (or on nothing, when no stage is required).
+    Proposed,
+    /// Every stage approved (or enough flat approvals).
+    Approved,
+    /// A stage, or a flat decision, rejected it.
+    Rejected,
+    /// Written to the ledger as a commit.
+    Applied,
+}
+
+impl std::fmt::Display for DraftStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DraftStatus::Proposed => "proposed",
+            DraftStatus::Approved => "approved",
+            DraftStatus::Rejected => "rejected",
+            DraftStatus::Applied => "applied",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Derived status of one approval stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StageStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl std::fmt::Display for StageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            StageStatus::Pending => "pending",
+            StageStatus::Approved => "approved",
+            StageStatus::Rejected => "rejected",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// Projected view of one approval stage.
+#[derive(Debug, Clone, Serialize)]
+pub struct DraftStageView {
+    pub stage_id: String,
+    pub role: String,
+    pub min_approvals: usize,
+    pub assignees: Vec<String>,
+    pub status: StageStatus,
+    pub approved_by: Vec<String>,
+}
+
+impl DraftStageView {
+    /// Whether `actor` may decide this stage: assigned to it, holding its
+    /// role, or any actor when no actors are configured.
+    pub fn admits(&self, actors: &ActorsConfig, actor: &str) -> bool {
+        actors.actors.is_empty()
+            || self.assignees.iter().any(|a| a == actor)
+            || actors
+                .actors
+                .get(actor)
+                .is_some_and(|def| def.roles.contains(&self.role))
+    }
+}
+
+/// One `approval` event as seen from its draft.
+#[derive(Debug, Clone, Serialize)]
+pub struct ApprovalRecord {
+    pub ts: String,
+    pub actor: String,
+    /// `approve` or `reject`.
+    pub decision: String,
+    pub note: String,
+    pub approval_event_id: String,
+    /// Empty for flat (stage-less) drafts.
+    pub stage_id: String,
+    pub role: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_id: Option<String>,
+}
+
+/// Projected view of one draft, folded from its events.
+#[derive(Debug, Clone, Serialize)]
+pub struct DraftView {
+    pub draft_id: String,
+    pub created_at: String,
+    pub branch: String,
+    pub base_parent_hash: String,
+    pub title: String,
+    pub purpose: String,
+    pub contribution: String,
+    pub labels: Vec<String>,
+    pub evidence: Vec<serde_json::Value>,
+    pub auto_preview_lines: Vec<String>,
+    pub route_rule_id: String,
+    pub policy_require_approval: bool,
+    pub policy_min_approvals: usize,
+    pub stages: Vec<DraftStageView>,
+    pub approvals: Vec<ApprovalRecord>,
+    pub status: DraftStatus,
+    pub applied_commit_id: Option<String>,
+    /// What opened the draft, when not a person (e.g. a high-risk action).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin: Option<serde_json::Value>,
+    pub proposal_event_id: String,
+    /// Hash of the `draft.proposed` event; approvals cite it as
+    /// `draft_sha256` so a decision is bound to exactly this proposal.
+    pub proposal_hash: String,
+}
+
+/// Why an approve/reject cannot be recorded against a draft.
+#[derive(Debug, thiserror::Error)]
+pub enum DraftActionError {
+    #[error("draft {draft_id} is already {status}")]
+    Closed {
+        draft_id: String,
+        status: DraftStatus,
+    },
+    #[error("stage not found: {0}")]
+    StageNotFound(String),
+    #[error("no pending stages remain")]
+    NoPendingStage,
+    #[error("no pending stage for role '{0}'")]
+    NoPendingStageForRole(String),
+    #[error("stage '{stage_id}' requires role '{role}', not '{requested}'")]
+    RoleMismatch {
+        stage_id: String,
+        role: String,
+        requested: String,
+    },
+    #[error("stage '{stage_id}' is already {status}")]
+    StageClosed {
+        stage_id: String,
+        status: StageStatus,
+    },
+}
+
+impl DraftView {
+    /// Flat (stage-less) approvals recorded so far.
+    pub fn approve_count(&self) -> usize {
+        self.approvals
+            .iter()
+            .filter(|a| a.decision == "approve")
+            .count()
+    }
+
+    pub fn pending_stages(&self) -> impl Iterator<Item = &DraftStageView> {
+        self.stages
+            .iter()
+            .filter(|s| s.status == StageStatus::Pending)
+    }
+
+    /// The stage an approve/reject should land on: `stage` when given, else
+    /// the first pending stage (with `role`, when given). `None` for a flat
+    /// draft. Errors when the draft or the chosen stage is already closed.
+    pub fn target_stage(
+        &self,
+        stage: Option<&str>,
+        role: Option<&str>,
+    ) -> Result<Option<&DraftStageView>, DraftActionError> {
+        if matches!(self.status, DraftStatus::Applied | DraftStatus::Rejected) {
+            return Err(DraftActionError::Closed {
+                draft_id: self.draft_id.clone(),
+                status: self.status,
+            });
+        }
+        if self.stages.is_empty() {
+            return Ok(None);
+        }
+        let target = match (stage, role) {
+            (Some(sid), _) => self
+                .stages
+                .iter()
+                .find(|s| s.stage_id == sid)
+                .ok_or_else(|| DraftActionError::StageNotFound(sid.to_string()))?,
+            (None, Some(role)) => self
+                .pending_stages()
+                .find(|s| s.role == role)
+                .ok_or_else(|| DraftActionError::NoPendingStageForRole(role.to_string()))?,
+            (None, None) => self
+                .pending_stages()
+                .next()
+                .ok_or(DraftActionError::NoPendingStage)?,
+        };
+        if let Some(want) = role {
+            if want != target.role {
+                return Err(DraftActionError::RoleMismatch {
+                    stage_id: target.stage_id.clone(),
+                    role: target.role.clone(),
+                    requested: want.to_string(),
+                });
+            }
+        }
+        if target.status != StageStatus::Pending {
+            return Err(DraftActionError::StageClosed {
+                stage_id: target.stage_id.clone(),
+                status: target.status,
+            });
+        }
+        Ok(Some(target))
+    }
+
+    fn apply_approval(&mut self, event: &Event) {
+        let p = &event.payload;
+        let record = ApprovalRecord {
+            ts: event.ts.clone(),
+            actor: str_field(p, "actor"),
+            decision: str_field(p, "decision"),
+            note: str_field(p, "note"),
+            approval_event_id: event.event_id.clone(),
+            stage_id: str_field(p, "stage_id"),
+            role: str_field(p, "role"),
+            device_id: p
+                .get("device_id")
+                .and_then(|v| v.as_str())
+                .map(String::from),
+        };
+        if let Some(stage) = self
+            .stages
+            .iter_mut()
+            .find(|s| s.stage_id == record.stage_id)
+        {
+            if stage.status == StageStatus::Pending {
+                match record.decision.as_str() {
+                    "reject" => stage.status = StageStatus::Rejected,
+                    "approve" => {
+                        if !stage.approved_by.contains(&record.actor) {
+                            stage.approved_by.push(record.actor.clone());
+                        }
+                        if stage.approved_by.len() >= stage.min_approvals {
+                            stage.status = StageStatus::Approved;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.approvals.push(record);
+    }
+
+    fn resolve_status(&mut self) {
+        self.status = if self.applied_commit_id.is_some() {
+            DraftStatus::Applied
+        } else if self.stages.is_empty() {
+            if self.approvals.iter().any(|a| a.decision == "reject") {
+                DraftStatus::Rejected
+            } else if self.approve_count() >= self.policy_min_approvals.max(1) {
+                DraftStatus::Approved
+            } else {
+                DraftStatus::Proposed
+            }
+        } else if self
+            .stages
+            .iter()
+            .any(|s| s.status == StageStatus::Rejected)
+        {
+            DraftStatus::Rejected
+        } else if self
+            .stages
+            .iter()
+            .all(|s| s.status == StageStatus::Approved)
+        {
+            DraftStatus::Approved
+        } else {
+            DraftStatus::Proposed
+        };
+    }
+}
+
+fn str_field(payload: &serde_json::Value, key: &str) -> String {
+    payload
+        .get(key)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn vec_field<T: serde::de::DeserializeOwned>(payload: &serde_json::Value, key: &str) -> Vec<T> {
+    payload
+        .get(key)
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+fn proposal_view(event: &Event) -> DraftView {
+    let p = &event.payload;
+    let stages = vec_field::<DraftStageSpec>(p, "stages")
+        .into_iter()
+        .map(|s| DraftStageView {
+            stage_id: s.stage_id,
+            role: s.role,
+            min_approvals: s.min_approvals,
+            assignees: s.assignees,
+            status: StageStatus::Pending,
+            approved_by: Vec::new(),
+        })
+        .collect();
+    DraftView {
+        draft_id: str_field(p, "draft_id"),
+        created_at: p
+            .get("created_at")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&event.ts)
+            .to_string(),
+        branch: event.branch.clone(),
+        base_parent_hash: str_field(p, "base_parent_hash"),
+        title: str_field(p, "title"),
+        purpose: str_field(p, "purpose"),
+        contribution: str_field(p, "contribution"),
+        labels: vec_field(p, "labels"),
+        evidence: vec_field(p, "evidence"),
+        auto_preview_lines: vec_field(p, "auto_preview_lines"),
+        route_rule_id: str_field(p, "route_rule_id"),
+        policy_require_approval: p
+            .get("policy_require_approval")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false),
+        policy_min_approvals: p
+            .get("policy_min_approvals")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as usize,
+        stages,
+        approvals: Vec::new(),
+        status: DraftStatus::Proposed,
+        applied_commit_id: None,
+        origin: p.get("origin").cloned(),
+        proposal_event_id: event.event_id.clone(),
+        proposal_hash: event.hash.clone(),
+    }
+}
+
+/// Fold `draft.*` and `approval` events (in ledger insertion order) into
+/// draft views, oldest first. Deleted drafts are left out; approvals for
+/// drafts never proposed in the ledger are ignored.
+pub fn project_drafts(events: &[Event]) -> Vec<DraftView> {
+    let mut map: BTreeMap<String, DraftView> = BTreeMap::new();
+    let mut deleted = BTreeSet::new();
+
+    // Proposals first: an imported legacy draft is proposed after the
+    // approvals that were recorded against its file.
+    for event in events {
+        let Some(draft_id) = event.payload.get("draft_id").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        match event.event_type.as_str() {
+            "draft.proposed" => {
+                // First proposal wins; a reused id never rewrites history.
+                map.entry(draft_id.to_string())
+                    .or_insert_with(|| proposal_view(event));
+            }
+            "draft.applied" => {
+                if let Some(v) = map.get_mut(draft_id) {
+                    v.applied_commit_id = Some(str_field(&event.payload, "commit_event_id"));
+                }
+            }
+            "draft.deleted" => {
+                deleted.insert(draft_id.to_string());
+            }
+            _ => {}
+        }
+    }
+    for event in events.iter().filter(|e| e.event_type == "approval") {
+        let draft_id = event.payload.get("draft_id").and_then(|v| v.as_str());
+        if let Some(v) = draft_id.and_then(|id| map.get_mut(id)) {
+            v.apply_approval(event);
+        }
+    }
+
+    let mut views: Vec<DraftView> = map
+        .into_values()
+        .filter(|v| !deleted.contains(&v.draft_id))
+        .map(|mut v| {
+            v.resolve_status();
+            v
+        })
+        .collect();
+    views.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    views
+}
+
+// ── Legacy draft files ──
+
+/// The parts of a pre-ledger `.edda/drafts/<id>.json` file worth keeping.
+#[derive(Deserialize)]
+struct LegacyDraft {
+    draft_id: String,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    branch: String,
+    #[serde(default)]
+    base_parent_hash: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    purpose: String,
+    #[serde(default)]
+    contribution: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    #[serde(default)]
+    evidence: Vec<serde_json::Value>,
+    #[serde(default)]
+    auto_preview_lines: Vec<String>,
+    #[serde(default)]
+    route_rule_id: String,
+    #[serde(default)]
+    stages: Vec<DraftStageSpec>,
+    #[serde(default)]
+    policy_require_approval: bool,
+    #[serde(default)]
+    policy_min_approvals: usize,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    applied_commit_id: String,
+    #[serde(default)]
+    high_risk: Option<serde_json::Value>,
+}
+
+/// Draft files in `.edda/drafts/` still waiting for
+/// [`import_legacy_drafts`]. Reads only the directory, so read paths can
+/// point at the import without taking the workspace lock.
+pub fn legacy_draft_count(paths: &EddaPaths) -> usize {
+    std::fs::read_dir(&paths.drafts_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.path())
+                .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+                .filter(|p| p.file_stem().and_then(|s| s.to_str()) != Some("latest"))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+/// Move draft files written before drafts lived in the ledger into
+/// `draft.proposed` (and `draft.applied`) events. Their approvals are
+/// already `approval` events and fold in as-is. Imported files are moved to
+/// `.edda/drafts/imported/`. Returns how many drafts were imported.
+///
+/// The caller must hold the workspace lock. It appends, so only write paths
+/// (draft changes, `edda maintenance`) run it; reads project what is in the
+/// ledger.
+pub fn import_legacy_drafts(ledger: &Ledger) -> anyhow::Result<usize> {
+    let dir = &ledger.paths.drafts_dir;
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Ok(0);
+    };
+    let mut files: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("json"))
+        .collect();
+    if files.is_empty() {
+        return Ok(0);
+    }
+    files.sort();
+
+    let known: BTreeSet<String> = ledger
+        .draft_events()?
+        .iter()
+        .filter(|e| e.event_type == "draft.proposed")
+        .filter_map(|e| e.payload.get("draft_id")?.as_str().map(String::from))
+        .collect();
+    let imported_dir = dir.join("imported");
+    let mut count = 0;
+    for path in files {
+        let is_latest = path.file_stem().and_then(|s| s.to_str()) == Some("latest");
+        let legacy = std::fs::read(&path)
+            .ok()
+            .filter(|_| !is_latest)
+            .and_then(|b| serde_json::from_slice::<LegacyDraft>(&b).ok());
+        if let Some(d) = legacy.filter(|d| !known.contains(&d.draft_id)) {
+            let branch = if d.branch.is_empty() {
+                ledger.head_branch()?
+            } else {
+                d.branch.clone()
+            };
+            let parent_hash = ledger.last_event_hash()?;
+            let proposed = new_draft_proposed_event(&DraftProposedParams {
+                branch: &branch,
+                parent_hash: parent_hash.as_deref(),
+                draft_id: &d.draft_id,
+                created_at: (!d.created_at.is_empty()).then_some(d.created_at.as_str()),
+                base_parent_hash: &d.base_parent_hash,
+                title: &d.title,
+                purpose: &d.purpose,
+                contribution: &d.contribution,
+                labels: &d.labels,
+                evidence: &d.evidence,
+                auto_preview_lines: &d.auto_preview_lines,
+                route_rule_id: &d.route_rule_id,
+                stages: &d.stages,
+                policy_require_approval: d.policy_require_approval,
+                policy_min_approvals: d.policy_min_approvals,
+                origin: d.high_risk.as_ref(),
+            })?;
+            ledger.append_event(&proposed)?;
+            if d.status == "applied" && !d.applied_commit_id.is_empty() {
+                let parent_hash = ledger.last_event_hash()?;
+                let applied = new_draft_applied_event(
+                    &branch,
+                    parent_hash.as_deref(),
+                    &d.draft_id,
+                    &d.applied_commit_id,
+                )?;
+                ledger.append_event(&applied)?;
+            }
+            count += 1;
+        }
+        // `latest.json` and unreadable files are moved aside too, so the
+        // directory stops being read.
+        std::fs::create_dir_all(&imported_dir)?;
+        if let Some(name) = path.file_name() {
+            std::fs::rename(&path, imported_dir.join(name))?;
+        }
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_approval_event, new_draft_deleted_event, ApprovalEventParams};
+
+    fn proposed(draft_id: &str, stages: &[(&str, usize)]) -> Event {
+        let stages: Vec<DraftStageSpec> = stages
+            .iter()
+            .map(|(role, min)| DraftStageSpec {
+                stage_id: role.to_string(),
+                role: role.to_string(),
+                min_approvals: *min,
+                assignees: vec!["alice".into()],
+            })
+            .collect();
+        new_draft_proposed_event(&DraftProposedParams {
+            branch: "main",
+            parent_hash: None,
+            draft_id,
+            created_at: None,
+            base_parent_hash: "",
+            title: "Deploy",
+            purpose: "",
+            contribution: "Deploy",
+            labels: &[],
+            evidence: &[],
+            auto_preview_lines: &[],
+            route_rule_id: "require",
+            stages: &stages,
+            policy_require_approval: !stages.is_empty(),
+            policy_min_approvals: 1,
+            origin: None,
+        })
+        .unwrap()
+    }
+
+    fn decision(draft_id: &str, stage_id: &str, decision: &str, actor: &str) -> Event {
+        new_approval_event(&ApprovalEventParams {
+            branch: "main",
+            parent_hash: None,
+            draft_id,
+            draft_sha256: "",
+            decision,
+            actor,
+            note: "",
+            stage_id,
+            role: stage_id,
+            device_id: None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn stages_fold_to_draft_status() {
+        let events = vec![
+            proposed("drf_a", &[("lead", 1), ("ops", 2)]),
+            decision("drf_a", "lead", "approve", "alice"),
+            decision("drf_a", "ops", "approve", "bob"),
+            decision("drf_a", "ops", "approve", "bob"),
+        ];
+        let views = project_drafts(&events);
+        let d = &views[0];
+        assert_eq!(d.stages[0].status, StageStatus::Approved);
+        assert_eq!(
+            d.stages[1].approved_by,
+            ["bob"],
+            "repeat approvals count once"
+        );
+        assert_eq!(d.status, DraftStatus::Proposed);
+        assert_eq!(d.target_stage(None, None).unwrap().unwrap().stage_id, "ops");
+        assert!(matches!(
+            d.target_stage(Some("lead"), None),
+            Err(DraftActionError::StageClosed { .. })
+        ));
+
+        let mut events = events;
+        events.push(decision("drf_a", "ops", "approve", "carol"));
+        assert_eq!(project_drafts(&events)[0].status, DraftStatus::Approved);
+        events.push(new_draft_applied_event("main", None, "drf_a", "evt_c").unwrap());
+        let d = &project_drafts(&events)[0];
+        assert_eq!(d.status, DraftStatus::Applied);
+        assert!(matches!(
+            d.target_stage(None, None),
+            Err(DraftActionError::Closed { .. })
+        ));
+    }
+
+    #[test]
+    fn flat_drafts_rejects_and_deletes() {
+        let events = vec![
+            proposed("drf_flat", &[]),
+            decision("drf_flat", "", "approve", "alice"),
+            proposed("drf_no", &[("lead", 1)]),
+            decision("drf_no", "lead", "reject", "alice"),
+            proposed("drf_gone", &[]),
+            new_draft_deleted_event("main", None, "drf_gone").unwrap(),
+            decision("drf_unknown", "", "approve", "alice"),
+        ];
+        let views = project_drafts(&events);
+        let status: Vec<_> = views
+            .iter()
+            .map(|v| (v.draft_id.as_str(), v.status))
+            .collect();
+        assert_eq!(
+            status,
+            [
+                ("drf_flat", DraftStatus::Approved),
+                ("drf_no", DraftStatus::Rejected)
+            ]
+        );
+        assert!(views[0].target_stage(None, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn imports_legacy_draft_files_once() {
+        let tmp = std::env::temp_dir().join(format!("edda_drafts_import_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        std::fs::create_dir_all(&tmp).unwrap();
+        let ledger = Ledger::open_or_init(&tmp).unwrap();
+
+        // The approval was recorded before the import, as the old CLI did.
+        ledger
+            .append_event(&decision("drf_old", "lead", "approve", "alice"))
+            .unwrap();
+        std::fs::create_dir_all(&ledger.paths.drafts_dir).unwrap();
+        std::fs::write(
+            ledger.paths.drafts_dir.join("drf_old.json"),
+            r#"{"draft_id":"drf_old","created_at":"2026-01-01T00:00:00Z","branch":"main",
+                "title":"Old","status":"approved",
+                "stages":[{"stage_id":"lead","role":"lead","min_approvals":1,
+                           "assignees":["alice"],"status":"approved","approved_by":["alice"]}]}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            ledger.paths.drafts_dir.join("latest.json"),
+            r#"{"draft_id":"drf_old"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(legacy_draft_count(&ledger.paths), 1);
+        assert_eq!(import_legacy_drafts(&ledger).unwrap(), 1);
+        assert_eq!(legacy_draft_count(&ledger.paths), 0);
+        assert_eq!(import_legacy_drafts(&ledger).unwrap(), 0);
+        let d = ledger.draft_view("drf_old").unwrap().unwrap();
+        assert_eq!(d.created_at, "2026-01-01T00:00:00Z");
+        assert_eq!(d.status, DraftStatus::Approved);
+        assert!(ledger
+            .paths
+            .drafts_dir
+            .join("imported/drf_old.json")
+            .exists());
+        assert!(!ledger.paths.drafts_dir.join("latest.json").exists());
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
@@ -1,17 +1,56 @@
+use crate::authz::{AppendAuthorizer, PolicyAuthorizer};
+use crate::domain::EventFilter;
 use crate::paths::EddaPaths;
-use crate::sqlite_store::{BundleRow, SqliteStore};
+use crate::retention::{self, ArchiveReport, RetentionPolicy};
+use crate::segments;
+use crate::signing::{self, EventSigner, SignatureReport};
+use crate::sqlite_store::{validate_event_hash, ArchivedStub, BundleRow, SqliteStore};
 use crate::view::{self, DecisionView};
 use anyhow::Context;
 use edda_core::Event;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
+use std::sync::{Arc, OnceLock};
+
+/// Payload field naming the parent an event was built on before
+/// [`Ledger::append_event_rebasing`] moved it onto the tip.
+pub const REBASED_FROM: &str = "rebased_from";
+
+/// How often one append is rebased before giving up on a busy ledger.
+const REBASE_ATTEMPTS: usize = 5;
+
+/// Re-parent `event` onto `tip`, keeping the parent it was first built on.
+fn rebase_event(event: &mut Event, tip: Option<String>) -> anyhow::Result<()> {
+    let original = serde_json::json!(event.parent_hash);
+    let Some(payload) = event.payload.as_object_mut() else {
+        anyhow::bail!(
+            "cannot rebase event {}: payload is not an object",
+            event.event_id
+        );
+    };
+    payload.entry(REBASED_FROM).or_insert(original);
+    event.parent_hash = tip;
+    edda_core::event::finalize_event(event)
+}
 
 /// The append-only event ledger (SQLite backend).
 pub struct Ledger {
     pub paths: EddaPaths,
     pub(crate) sqlite: SqliteStore,
+    /// Loaded on first append when signing is enabled.
+    signer: OnceLock<Option<EventSigner>>,
+    /// Checks every append; loaded from `policy.yaml` on first use unless set.
+    authorizer: OnceLock<Arc<dyn AppendAuthorizer>>,
+    /// Who appends through this handle (see [`crate::authz`]).
+    actor: Option<String>,
+    /// Rotated segment manifest, reread when it changes.
+    manifest: segments::ManifestCache,
 }
 
 impl Ledger {
+    /// Schema version of a fully migrated `ledger.db` for this build.
+    pub const SCHEMA_VERSION: u32 = crate::sqlite_store::LATEST_SCHEMA_VERSION;
+
     /// Open an existing workspace. Fails if `.edda/` does not exist.
     pub fn open(repo_root: impl Into<std::path::PathBuf>) -> anyhow::Result<Self> {
         let paths = EddaPaths::discover(repo_root);
@@ -22,7 +61,14 @@ impl Ledger {
             );
         }
         let sqlite = SqliteStore::open_or_create(&paths.ledger_db)?;
-        Ok(Self { paths, sqlite })
+        Ok(Self {
+            paths,
+            sqlite,
+            signer: OnceLock::new(),
+            authorizer: OnceLock::new(),
+            actor: std::env::var("EDDA_ACTOR").ok().filter(|a| !a.is_empty()),
+            manifest: segments::ManifestCache::default(),
+        })
     }
 
     /// Open a workspace, auto-initializing `.edda/` if missing.
@@ -64,6 +110,40 @@ impl Ledger {
         Self::open(repo_root.to_path_buf())
     }
 
+    // ── Append authorization ────────────────────────────────────────
+
+    /// The actor appends are authorized as; defaults to `EDDA_ACTOR`.
+    pub fn actor(&self) -> Option<&str> {
+        self.actor.as_deref()
+    }
+
+    /// Authorize later appends as `actor` (`None`: anonymous).
+    pub fn set_actor(&mut self, actor: Option<&str>) {
+        self.actor = actor.map(str::to_string);
+    }
+
+    /// Replace the `policy.yaml` append rules with a custom authorizer.
+    pub fn set_authorizer(&mut self, authorizer: Arc<dyn AppendAuthorizer>) {
+        self.authorizer = OnceLock::from(authorizer);
+    }
+
+    /// Check that `event` may be appended, without writing it. For callers
+    /// with side effects that must not happen when the append is refused.
+    pub fn authorize_append(&self, event: &Event) -> anyhow::Result<()> {
+        let authorizer = match self.authorizer.get() {
+            Some(a) => a,
+            None => {
+                let loaded: Arc<dyn AppendAuthorizer> = Arc::new(
+                    PolicyAuthorizer::from_dir(&self.paths.edda_dir)
+                        .context("loading append_rules from policy.yaml")?,
+                );
+                self.authorizer.get_or_init(|| loaded)
+            }
+        };
+        authorizer.authorize(self.actor(), event)?;
+        Ok(())
+    }
+
     // ── HEAD branch ─────────────────────────────────────────────────
 
     /// Read the current HEAD branch name.
@@ -81,17 +161,142 @@ impl Ledger {
     // ── Events ──────────────────────────────────────────────────────
 
     /// Append an event to the ledger. Append-only (CONTRACT LEDGER-02).
+    ///
+    /// With signing enabled (see [`crate::signing`]) the event hash is signed
+    /// with the per-user key and the signature stored alongside.
+    ///
+    /// Successful appends are folded into `.edda/stats.json` (see
+    /// [`crate::stats`]) and handed to the registered append observers (see
+    /// [`crate::observe`]). Appends the authorizer refuses fail with
+    /// [`crate::authz::AppendDenied`].
+    ///
+    /// The parent hash is checked against the tip inside the append
+    /// transaction; an event built on a tip that another writer has since
+    /// moved past fails with [`crate::StaleParent`]. Callers that can take a
+    /// new parent opt in with [`Self::append_event_rebasing`].
     pub fn append_event(&self, event: &Event) -> anyhow::Result<()> {
+        self.authorize_append(event)?;
+        let start = std::time::Instant::now();
+        let signature = self.signer()?.map(|s| s.sign(event));
         self.sqlite
-            .append_event(event)
-            .with_context(|| format!("Ledger::append_event({})", event.event_id))
+            .append_event_signed(event, signature.as_ref())
+            .with_context(|| format!("Ledger::append_event({})", event.event_id))?;
+        crate::stats::record_append(&self.paths, event, elapsed_ms(start));
+        crate::observe::notify_append(&self.paths, event);
+        Ok(())
+    }
+
+    /// [`Self::append_event`], rebasing a stale parent instead of failing,
+    /// and returning the event as stored.
+    ///
+    /// When the parent is stale — two writers raced past the workspace lock —
+    /// but names an event in the chain, the event is re-parented onto the
+    /// tip, its original parent recorded as `payload.rebased_from`, and
+    /// re-finalized, so the chain stays linear instead of forking. The
+    /// returned event then has a new hash: anything chained after it must be
+    /// built on the returned hash, not the one passed in. An unknown parent,
+    /// or none on a non-empty ledger, still fails with [`crate::StaleParent`].
+    pub fn append_event_rebasing(&self, event: &Event) -> anyhow::Result<Event> {
+        self.authorize_append(event)?;
+        let mut event = event.clone();
+        for attempt in 0..=REBASE_ATTEMPTS {
+            let start = std::time::Instant::now();
+            let signature = self.signer()?.map(|s| s.sign(&event));
+            let err = match self.sqlite.append_event_signed(&event, signature.as_ref()) {
+                Ok(()) => {
+                    crate::stats::record_append(&self.paths, &event, elapsed_ms(start));
+                    crate::observe::notify_append(&self.paths, &event);
+                    return Ok(event);
+                }
+                Err(err) => err,
+            };
+            let tip = match err.downcast_ref::<crate::StaleParent>() {
+                Some(stale) if attempt < REBASE_ATTEMPTS && self.is_known_parent(&event)? => {
+                    stale.expected.clone()
+                }
+                _ => return Err(err.context(format!("Ledger::append_event({})", event.event_id))),
+            };
+            tracing::warn!(
+                event_id = %event.event_id,
+                "parent_hash went stale before append; rebasing onto the current tip"
+            );
+            rebase_event(&mut event, tip)?;
+        }
+        unreachable!("the last attempt returns")
+    }
+
+    /// Whether `event`'s parent is in the chain, so rebasing it only skips
+    /// events appended meanwhile rather than papering over a foreign parent.
+    /// A missing parent is only valid on an empty ledger, which is never
+    /// stale, so it is not known here.
+    fn is_known_parent(&self, event: &Event) -> anyhow::Result<bool> {
+        match event.parent_hash.as_deref() {
+            None => Ok(false),
+            Some(hash) => self.sqlite.has_event_hash(hash),
+        }
+    }
+
+    fn signer(&self) -> anyhow::Result<Option<&EventSigner>> {
+        if let Some(signer) = self.signer.get() {
+            return Ok(signer.as_ref());
+        }
+        let signer = if signing::signing_enabled(&self.paths.config_json) {
+            Some(signing::load_or_create_signer().context("loading event signing key")?)
+        } else {
+            None
+        };
+        Ok(self.signer.get_or_init(|| signer).as_ref())
+    }
+
+    /// Verify every stored signature against its event and the workspace's
+    /// trusted keys (see [`crate::signing`]). Signatures by any other key
+    /// are invalid, even if they match their stored public key.
+    ///
+    /// Does not check the hash chain; pair with [`Self::verify_chain`].
+    pub fn verify_signatures(&self) -> anyhow::Result<SignatureReport> {
+        let trusted = signing::trusted_keys(&self.paths.config_json)
+            .context("loading trusted signing keys")?;
+        let signatures = self.sqlite.event_signatures()?;
+        let mut report = SignatureReport::default();
+        for event in self.iter_events()? {
+            report.total_events += 1;
+            match signatures.get(&event.event_id) {
+                Some(sig) => {
+                    report.signed += 1;
+                    *report.keys.entry(sig.key_id.clone()).or_insert(0) += 1;
+                    if !trusted.contains(&sig.key_id)
+                        && !report.untrusted_keys.contains(&sig.key_id)
+                    {
+                        report.untrusted_keys.push(sig.key_id.clone());
+                    }
+                    if let Err(e) = signing::verify_event_signature(&event, sig, &trusted) {
+                        report.invalid.push((event.event_id.clone(), e.to_string()));
+                    }
+                }
+                None => {
+                    report.unsigned += 1;
+                    if report.signed > 0 {
+                        report.gaps.push(event.event_id.clone());
+                    }
+                }
+            }
+        }
+        Ok(report)
     }
 
     /// Append an event idempotently. Returns `true` if inserted, `false` if duplicate.
     pub fn append_event_idempotent(&self, event: &Event) -> anyhow::Result<bool> {
-        self.sqlite
+        self.authorize_append(event)?;
+        let start = std::time::Instant::now();
+        let inserted = self
+            .sqlite
             .append_event_idempotent(event)
-            .with_context(|| format!("Ledger::append_event_idempotent({})", event.event_id))
+            .with_context(|| format!("Ledger::append_event_idempotent({})", event.event_id))?;
+        if inserted {
+            crate::stats::record_append(&self.paths, event, elapsed_ms(start));
+            crate::observe::notify_append(&self.paths, event);
+        }
+        Ok(inserted)
     }
 
     /// Get the hash of the last event, or `None` if the ledger is empty.
@@ -101,14 +306,312 @@ impl Ledger {
             .context("Ledger::last_event_hash")
     }
 
-    /// Read all events in the ledger.
+    /// Get the timestamp of the last event, or `None` if the ledger is empty.
+    pub fn last_event_ts(&self) -> anyhow::Result<Option<String>> {
+        self.sqlite.last_event_ts().context("Ledger::last_event_ts")
+    }
+
+    /// Read all events in the ledger, including those rotated into
+    /// segments (see [`crate::segments`]).
     pub fn iter_events(&self) -> anyhow::Result<Vec<Event>> {
-        self.sqlite.iter_events().context("Ledger::iter_events")
+        let manifest = self
+            .manifest
+            .load(&self.paths)
+            .context("Ledger::iter_events")?;
+        if manifest.segments.is_empty() {
+            return self.sqlite.iter_events().context("Ledger::iter_events");
+        }
+        Ok(self
+            .events_after_rowid(0)?
+            .into_iter()
+            .map(|(_, e)| e)
+            .collect())
     }
 
-    /// Verify parent linkage and canonical hashes for the complete event log.
+    /// Verify parent linkage and canonical hashes for the complete event log,
+    /// including the events rotated into segments.
     pub fn verify_chain(&self) -> anyhow::Result<()> {
-        self.sqlite.verify_chain().context("Ledger::verify_chain")
+        self.sqlite.verify_chain().context("Ledger::verify_chain")?;
+        self.verify_segments().context("Ledger::verify_chain")
+    }
+
+    /// Rehash every rotated event and check it against the stub that holds
+    /// its place in the chain and against the manifest. Stubs pointing at a
+    /// segment must find their event there. Events of a rotation interrupted
+    /// before the database update have no stub yet and must still be live.
+    fn verify_segments(&self) -> anyhow::Result<()> {
+        let manifest = self.manifest.load(&self.paths)?;
+        let stubs = self.sqlite.archived_stubs()?;
+        let dir = segments::segments_dir(&self.paths);
+        for entry in &manifest.segments {
+            let events = retention::read_archive_file(&dir.join(&entry.name))
+                .with_context(|| format!("reading segment {}", entry.name))?;
+            if events.len() != entry.events {
+                anyhow::bail!(
+                    "segment {}: manifest lists {} events, file holds {}",
+                    entry.name,
+                    entry.events,
+                    events.len()
+                );
+            }
+            let mut expected: HashMap<&str, &ArchivedStub> = stubs
+                .iter()
+                .filter(|s| s.archive_file == entry.name)
+                .map(|s| (s.event_id.as_str(), s))
+                .collect();
+            let mut rowids = Vec::with_capacity(events.len());
+            for event in &events {
+                validate_event_hash(event).with_context(|| format!("segment {}", entry.name))?;
+                let rowid = match expected.remove(event.event_id.as_str()) {
+                    Some(stub) => {
+                        if stub.hash != event.hash || stub.parent_hash != event.parent_hash {
+                            anyhow::bail!(
+                                "segment {}: event {} does not match its stub",
+                                entry.name,
+                                event.event_id
+                            );
+                        }
+                        stub.rowid
+                    }
+                    None => match self.sqlite.get_event(&event.event_id)? {
+                        Some(live) if live.hash == event.hash => self
+                            .sqlite
+                            .rowid_for_event_id(&event.event_id)?
+                            .unwrap_or_default(),
+                        _ => anyhow::bail!(
+                            "segment {}: event {} has no stub and is not in ledger.db",
+                            entry.name,
+                            event.event_id
+                        ),
+                    },
+                };
+                rowids.push(rowid);
+            }
+            if let Some(missing) = expected.keys().next() {
+                anyhow::bail!("segment {}: event {missing} is missing", entry.name);
+            }
+            if rowids.windows(2).any(|w| w[0] >= w[1])
+                || rowids.first() != Some(&entry.first_rowid)
+                || rowids.last() != Some(&entry.last_rowid)
+            {
+                anyhow::bail!(
+                    "segment {}: rowids do not match the manifest range {}..={}",
+                    entry.name,
+                    entry.first_rowid,
+                    entry.last_rowid
+                );
+            }
+        }
+        if let Some(stub) = stubs.iter().find(|s| {
+            segments::is_segment_name(&s.archive_file)
+                && !manifest.segments.iter().any(|e| e.name == s.archive_file)
+        }) {
+            anyhow::bail!(
+                "event {} points at segment {}, which the manifest does not list",
+                stub.event_id,
+                stub.archive_file
+            );
+        }
+        Ok(())
+    }
+
+    /// Read all events, with the ones `edda gc` archived merged back in at
+    /// their original position.
+    pub fn iter_events_with_archive(&self) -> anyhow::Result<Vec<Event>> {
+        let rowids = self.sqlite.archived_rowids()?;
+        let mut events = self.events_after_rowid(0)?;
+        let live: HashSet<String> = events.iter().map(|(_, e)| e.event_id.clone()).collect();
+        for event in retention::read_archived_events(&self.paths)? {
+            if live.contains(&event.event_id) {
+                continue;
+            }
+            // No stub (a recreated ledger.db): keep archive order, up front.
+            let rowid = rowids.get(&event.event_id).copied().unwrap_or(0);
+            events.push((rowid, event));
+        }
+        events.sort_by_key(|(rowid, _)| *rowid);
+        Ok(events.into_iter().map(|(_, e)| e).collect())
+    }
+
+    /// Move events past their retention to the cold archive (see
+    /// [`crate::retention`]). With `dry_run`, only report what would move.
+    ///
+    /// Events are written to their archive file before they leave
+    /// `ledger.db`. Callers hold the workspace lock.
+    pub fn archive_expired_events(
+        &self,
+        policy: &RetentionPolicy,
+        now: time::OffsetDateTime,
+        dry_run: bool,
+    ) -> anyhow::Result<ArchiveReport> {
+        if policy.is_empty() {
+            return Ok(ArchiveReport::default());
+        }
+        let candidates = self.sqlite.archivable_events()?;
+        let expired = candidates
+            .iter()
+            .filter(|(_, event, _)| policy.is_expired(event, now))
+            .map(|(rowid, event, _)| (*rowid, event));
+        self.move_events_to_archive(expired, dry_run)
+            .context("Ledger::archive_expired_events")
+    }
+
+    /// Rotate the oldest events out of `ledger.db` once live payloads pass
+    /// `max_bytes` (`gc.ledger_max_mb`), into a new compressed segment (see
+    /// [`crate::segments`]). Reads still see them. Events retention keeps
+    /// forever stay in the database, so it can end up above `max_bytes`.
+    /// With `dry_run`, only report what would move. Callers hold the
+    /// workspace lock.
+    pub fn rotate_events(&self, max_bytes: u64, dry_run: bool) -> anyhow::Result<ArchiveReport> {
+        let mut excess = self
+            .sqlite
+            .live_payload_bytes()
+            .context("Ledger::rotate_events")?
+            .saturating_sub(max_bytes);
+        if excess == 0 {
+            return Ok(ArchiveReport::default());
+        }
+        let candidates = self.sqlite.archivable_events()?;
+        let oldest: Vec<(i64, &Event, u64)> = candidates
+            .iter()
+            .filter(|(_, event, _)| !retention::is_kept_forever(event))
+            .take_while(|(_, _, bytes)| {
+                if excess == 0 {
+                    return false;
+                }
+                excess = excess.saturating_sub(*bytes);
+                true
+            })
+            .map(|(rowid, event, bytes)| (*rowid, event, *bytes))
+            .collect();
+        if oldest.is_empty() {
+            return Ok(ArchiveReport::default());
+        }
+
+        let mut report = ArchiveReport {
+            archived: oldest.len(),
+            ..Default::default()
+        };
+        for (_, event, _) in &oldest {
+            *report.by_type.entry(event.event_type.clone()).or_insert(0) += 1;
+        }
+        if dry_run {
+            report
+                .files
+                .push(segments::next_segment_name(&segments::load_manifest(
+                    &self.paths,
+                )?));
+            return Ok(report);
+        }
+
+        let segment =
+            segments::write_segment(&self.paths, &oldest).context("Ledger::rotate_events")?;
+        let moves: Vec<(i64, &Event, String)> = oldest
+            .iter()
+            .map(|(rowid, event, _)| (*rowid, *event, segment.name.clone()))
+            .collect();
+        self.sqlite.move_to_archive(&moves)?;
+        report.files.push(segment.name);
+        Ok(report)
+    }
+
+    /// Write `events` to their monthly archive files, then replace them
+    /// with stubs in `ledger.db`.
+    fn move_events_to_archive<'a>(
+        &self,
+        events: impl Iterator<Item = (i64, &'a Event)>,
+        dry_run: bool,
+    ) -> anyhow::Result<ArchiveReport> {
+        let mut report = ArchiveReport::default();
+        let mut by_file: BTreeMap<String, Vec<(i64, &Event)>> = BTreeMap::new();
+        for (rowid, event) in events {
+            if let Some(file) = retention::archive_file_name(event) {
+                by_file.entry(file).or_default().push((rowid, event));
+            }
+        }
+        for (file, entries) in &by_file {
+            report.archived += entries.len();
+            for (_, event) in entries {
+                *report.by_type.entry(event.event_type.clone()).or_insert(0) += 1;
+            }
+            report.files.push(file.clone());
+        }
+        if dry_run || report.archived == 0 {
+            return Ok(report);
+        }
+
+        for (file, entries) in &by_file {
+            // A month already pushed to cold storage is appended to whole.
+            crate::cold::fetch(&self.paths, file)?;
+            let events: Vec<&Event> = entries.iter().map(|(_, e)| *e).collect();
+            retention::append_to_archive(&self.paths.archive_dir.join(file), &events)
+                .with_context(|| format!("writing {file}"))?;
+        }
+        let moves: Vec<(i64, &Event, String)> = by_file
+            .iter()
+            .flat_map(|(file, entries)| entries.iter().map(|(r, e)| (*r, *e, file.clone())))
+            .collect();
+        self.sqlite.move_to_archive(&moves)?;
+        Ok(report)
+    }
+
+    /// Schema version of `ledger.db`. Equal to [`Ledger::SCHEMA_VERSION`]
+    /// once migrations have run; higher means a newer edda wrote it.
+    pub fn schema_version(&self) -> anyhow::Result<u32> {
+        self.sqlite
+            .schema_version()
+            .context("Ledger::schema_version")
+    }
+
+    /// Plan and time the hot read paths and list missing schema indexes
+    /// (`edda doctor ledger`).
+    pub fn diagnose_queries(&self) -> anyhow::Result<crate::LedgerDiagnostics> {
+        self.sqlite
+            .diagnose_queries()
+            .context("Ledger::diagnose_queries")
+    }
+
+    /// Applied schema steps whose columns or indexes are missing.
+    pub fn schema_drift(&self) -> anyhow::Result<Vec<crate::SchemaDrift>> {
+        self.sqlite.schema_drift().context("Ledger::schema_drift")
+    }
+
+    /// Re-apply the DDL of drifted schema steps; returns what was repaired.
+    /// Callers hold the workspace lock.
+    pub fn repair_schema(&self) -> anyhow::Result<Vec<crate::SchemaDrift>> {
+        self.sqlite.repair_schema().context("Ledger::repair_schema")
+    }
+
+    /// Checkpoint the WAL, VACUUM and ANALYZE `ledger.db`
+    /// (`edda maintenance`). With `dry_run`, only measures. Callers hold the
+    /// workspace lock.
+    pub fn maintain(&self, dry_run: bool) -> anyhow::Result<crate::LedgerMaintenance> {
+        let db = &self.paths.ledger_db;
+        let wal = db.with_file_name("ledger.db-wal");
+        let file_len = |p: &Path| std::fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+
+        let (free_pages, page_size) = self.sqlite.page_stats().context("Ledger::maintain")?;
+        let mut report = crate::LedgerMaintenance {
+            dry_run,
+            db_bytes_before: file_len(db),
+            wal_bytes_before: file_len(&wal),
+            free_pages,
+            page_size,
+            ..Default::default()
+        };
+        if !dry_run {
+            let (busy, frames) = self.sqlite.checkpoint_wal().context("Ledger::maintain")?;
+            report.checkpoint_busy = busy;
+            report.wal_frames_checkpointed = frames;
+            self.sqlite.vacuum_analyze().context("Ledger::maintain")?;
+            // VACUUM goes through the WAL too; fold it back in.
+            let (busy, frames) = self.sqlite.checkpoint_wal().context("Ledger::maintain")?;
+            report.checkpoint_busy |= busy;
+            report.wal_frames_checkpointed += frames;
+        }
+        report.db_bytes_after = file_len(db);
+        report.wal_bytes_after = file_len(&wal);
+        Ok(report)
     }
 
     /// Get a single event by event_id.
@@ -190,6 +693,62 @@ impl Ledger {
         Ok(out)
     }
 
+    /// Revoked event ids mapped to the revocation reason.
+    pub fn revoked_events(&self) -> anyhow::Result<std::collections::BTreeMap<String, String>> {
+        let revokes = self
+            .iter_events_by_type("revoke")
+            .context("Ledger::revoked_events")?;
+        let mut out = std::collections::BTreeMap::new();
+        for e in &revokes {
+            let reason = e
+                .payload
+                .get("reason")
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            for p in &e.refs.provenance {
+                if p.rel == edda_core::types::rel::REVOKES {
+                    out.insert(p.target.clone(), reason.to_string());
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Events pinned with `edda pin`, oldest pin first. Unpinned, revoked and
+    /// missing targets are left out.
+    pub fn pinned_events(&self) -> anyhow::Result<Vec<Event>> {
+        let pins = self
+            .iter_events_by_type("pin")
+            .context("Ledger::pinned_events")?;
+        let mut order: Vec<String> = Vec::new();
+        for e in &pins {
+            let Some(target) = e.payload.get("target").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            order.retain(|t| t != target);
+            if e.payload.get("pinned").and_then(|v| v.as_bool()) != Some(false) {
+                order.push(target.to_string());
+            }
+        }
+
+        let revoked = self.revoked_events()?;
+        let mut out = Vec::new();
+        for id in order.iter().filter(|id| !revoked.contains_key(*id)) {
+            if let Some(event) = self.get_event(id)? {
+                out.push(event);
+            }
+        }
+        Ok(out)
+    }
+
+    /// Tag renames recorded with `edda tag rename`, in effect now.
+    pub fn tag_aliases(&self) -> anyhow::Result<crate::tags::TagAliases> {
+        let renames = self
+            .iter_events_by_type("tag_rename")
+            .context("Ledger::tag_aliases")?;
+        Ok(crate::tags::TagAliases::from_events(&renames))
+    }
+
     /// All `task.*` events in insertion order — the task rail's fold input.
     pub fn task_events(&self) -> anyhow::Result<Vec<Event>> {
         self.sqlite
@@ -203,6 +762,28 @@ impl Ledger {
         Ok(crate::tasks::project_tasks(&self.task_events()?))
     }
 
+    /// All `draft.*` and `approval` events in insertion order — the draft
+    /// fold input.
+    pub fn draft_events(&self) -> anyhow::Result<Vec<Event>> {
+        self.sqlite
+            .iter_draft_events()
+            .context("Ledger::draft_events")
+    }
+
+    /// Project draft views, oldest first. Status is derived from events,
+    /// never stored.
+    pub fn draft_views(&self) -> anyhow::Result<Vec<crate::drafts::DraftView>> {
+        Ok(crate::drafts::project_drafts(&self.draft_events()?))
+    }
+
+    /// One draft's view; `None` when it was never proposed or was deleted.
+    pub fn draft_view(&self, draft_id: &str) -> anyhow::Result<Option<crate::drafts::DraftView>> {
+        Ok(self
+            .draft_views()?
+            .into_iter()
+            .find(|d| d.draft_id == draft_id))
+    }
+
     /// Get all events for a specific branch, filtered at the SQL level.
     pub fn iter_branch_events(&self, branch: &str) -> anyhow::Result<Vec<Event>> {
         self.sqlite
@@ -226,6 +807,33 @@ impl Ledger {
             .with_context(|| format!("Ledger::iter_events_filtered(branch={branch})"))
     }
 
+    /// One page of events matching `filter`, newest first. `cursor` is the
+    /// event_id of the last event of the previous page; `None` starts at the
+    /// newest event. Errors when the cursor event is not in the ledger.
+    pub fn page_events_filtered(
+        &self,
+        filter: &EventFilter<'_>,
+        cursor: Option<&str>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<Event>> {
+        let before_rowid = match cursor {
+            Some(id) => Some(
+                self.sqlite
+                    .event_rowid(id)?
+                    .ok_or_else(|| anyhow::anyhow!("unknown cursor: {id}"))?,
+            ),
+            None => None,
+        };
+        self.sqlite
+            .page_events_filtered(filter, before_rowid, limit)
+            .with_context(|| format!("Ledger::page_events_filtered(branch={})", filter.branch))
+    }
+
+    /// Number of events matching `filter`.
+    pub fn count_events_filtered(&self, filter: &EventFilter<'_>) -> anyhow::Result<usize> {
+        self.sqlite.count_events_filtered(filter)
+    }
+
     /// Find commit events related to a query by evidence chain or keyword match.
     pub fn find_related_commits(
         &self,
@@ -256,9 +864,39 @@ impl Ledger {
     /// Returns `(rowid, Event)` pairs ordered by rowid, useful for cursor-based
     /// polling (e.g. SSE streaming).
     pub fn events_after_rowid(&self, after_rowid: i64) -> anyhow::Result<Vec<(i64, Event)>> {
-        self.sqlite
+        let mut events = self
+            .sqlite
             .events_after_rowid(after_rowid)
-            .context("Ledger::events_after_rowid")
+            .context("Ledger::events_after_rowid")?;
+        let manifest = self
+            .manifest
+            .load(&self.paths)
+            .context("Ledger::events_after_rowid")?;
+        if manifest
+            .segments
+            .iter()
+            .all(|s| s.last_rowid <= after_rowid)
+        {
+            return Ok(events);
+        }
+
+        // Rotated events go back in at the rowid their stub keeps. One still
+        // live (a rotation interrupted before the database update) is read
+        // from `ledger.db`.
+        let rowids = self.sqlite.archived_rowids()?;
+        let mut seen: HashSet<String> = events.iter().map(|(_, e)| e.event_id.clone()).collect();
+        for event in segments::read_segments_after(&self.paths, &manifest, after_rowid)
+            .context("Ledger::events_after_rowid")?
+        {
+            let Some(&rowid) = rowids.get(&event.event_id) else {
+                continue;
+            };
+            if rowid > after_rowid && seen.insert(event.event_id.clone()) {
+                events.push((rowid, event));
+            }
+        }
+        events.sort_by_key(|(rowid, _)| *rowid);
+        Ok(events)
     }
 
     /// Look up the rowid for a given `event_id`.
@@ -331,6 +969,59 @@ impl Ledger {
         Ok(rows.iter().map(view::to_view).collect())
     }
 
+    /// All decisions on keys whose key, value, or reason ever mentioned
+    /// `keyword` (active + superseded), ordered by key then time.
+    pub fn decision_history(
+        &self,
+        keyword: &str,
+        after: Option<&str>,
+        before: Option<&str>,
+    ) -> anyhow::Result<Vec<DecisionView>> {
+        let rows = self
+            .sqlite
+            .decision_history(keyword, after, before)
+            .with_context(|| format!("Ledger::decision_history(keyword={keyword})"))?;
+        Ok(rows.iter().map(view::to_view).collect())
+    }
+
+    /// Decisions that were active at `as_of` (ISO 8601), replayed from the
+    /// decision stream: the latest decision per key and branch recorded by
+    /// then, minus those revoked by then. Later supersessions and revocations
+    /// are ignored, so every returned view has status `active`. Ordered by
+    /// domain, then key.
+    pub fn decisions_as_of(&self, as_of: &str) -> anyhow::Result<Vec<DecisionView>> {
+        let rows = self
+            .sqlite
+            .decisions_until(as_of)
+            .with_context(|| format!("Ledger::decisions_as_of({as_of})"))?;
+
+        let mut revoked = std::collections::BTreeSet::new();
+        for e in self.iter_events_by_type("revoke")? {
+            if e.ts.as_str() > as_of {
+                continue;
+            }
+            for p in &e.refs.provenance {
+                if p.rel == edda_core::types::rel::REVOKES {
+                    revoked.insert(p.target.clone());
+                }
+            }
+        }
+
+        let mut latest: std::collections::BTreeMap<(String, String), DecisionView> =
+            std::collections::BTreeMap::new();
+        for row in &rows {
+            let mut view = view::to_view(row);
+            view.status = "active".to_string();
+            latest.insert((view.key.clone(), view.branch.clone()), view);
+        }
+        let mut out: Vec<DecisionView> = latest
+            .into_values()
+            .filter(|v| !revoked.contains(&v.event_id))
+            .collect();
+        out.sort_by(|a, b| (&a.domain, &a.key).cmp(&(&b.domain, &b.key)));
+        Ok(out)
+    }
+
     /// All decisions for a domain (active + superseded), ordered by time.
     /// `after`/`before` are optional ISO 8601 bounds for temporal filtering.
     pub fn domain_timeline(
@@ -739,6 +1430,10 @@ impl Ledger {
 
 // ── Init functions ──────────────────────────────────────────────────
 
+fn elapsed_ms(start: std::time::Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
 /// Initialize a new workspace from `EddaPaths`. Used by `cmd_init`.
 ///
 /// Creates the directory layout AND a fresh `ledger.db` with schema.
@@ -808,6 +1503,9 @@ mod tests {
         }
     }
 
+    /// Serializes tests that point `EDDA_STORE_ROOT` at a temp store.
+    static STORE_ROOT_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
     fn setup_workspace() -> (std::path::PathBuf, TestLedger) {
         let n = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
         let tmp = std::env::temp_dir().join(format!("edda_ledger_test_{}_{n}", std::process::id()));
@@ -820,6 +1518,168 @@ mod tests {
         (tmp, ledger)
     }
 
+    #[test]
+    fn revoke_deactivates_decision_and_is_listed() {
+        let (tmp, ledger) = setup_workspace();
+        append_decision(&ledger, "main", "db.engine", "pg");
+        let target = ledger.iter_events_by_type("note").unwrap().remove(0);
+        let parent = ledger.last_event_hash().unwrap();
+        let ev =
+            edda_core::event::new_revoke_event("main", parent.as_deref(), &target, "wrong call")
+                .unwrap();
+        ledger.append_event(&ev).unwrap();
+
+        let active = ledger.active_decisions(None, None, None, None).unwrap();
+        assert!(active.iter().all(|d| d.key != "db.engine"));
+        let timeline = ledger.decision_timeline("db.engine", None, None).unwrap();
+        assert_eq!(timeline[0].status, "revoked");
+        let revoked = ledger.revoked_events().unwrap();
+        assert_eq!(
+            revoked.get(&target.event_id).map(String::as_str),
+            Some("wrong call")
+        );
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn schema_drift_is_detected_and_repaired() {
+        let (tmp, ledger) = setup_workspace();
+        assert!(ledger.schema_drift().unwrap().is_empty());
+
+        let conn = rusqlite::Connection::open(&ledger.paths.ledger_db).unwrap();
+        conn.execute_batch("DROP INDEX idx_decisions_domain_active;")
+            .unwrap();
+        drop(conn);
+
+        let drift = ledger.schema_drift().unwrap();
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].version, 14);
+        assert_eq!(drift[0].missing, ["index idx_decisions_domain_active"]);
+
+        assert_eq!(ledger.repair_schema().unwrap().len(), 1);
+        assert!(ledger.schema_drift().unwrap().is_empty());
+        assert_eq!(ledger.schema_version().unwrap(), Ledger::SCHEMA_VERSION);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn maintain_checkpoints_the_wal_and_dry_run_only_measures() {
+        let (tmp, ledger) = setup_workspace();
+        for i in 0..20 {
+            append_decision(&ledger, "main", &format!("k.{i}"), "v");
+        }
+
+        let dry = ledger.maintain(true).unwrap();
+        assert!(dry.dry_run);
+        assert_eq!(dry.wal_frames_checkpointed, 0);
+        assert_eq!(dry.db_bytes_after, dry.db_bytes_before);
+
+        let report = ledger.maintain(false).unwrap();
+        assert!(!report.checkpoint_busy);
+        assert_eq!(report.wal_bytes_after, 0);
+        assert!(report.db_bytes_after > 0);
+        assert_eq!(
+            ledger
+                .active_decisions(None, None, None, None)
+                .unwrap()
+                .len(),
+            20
+        );
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn signed_appends_verify_and_tampering_is_reported() {
+        let _lock = STORE_ROOT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let (tmp, ledger) = setup_workspace();
+        let store = tmp.join("store");
+        std::env::set_var("EDDA_STORE_ROOT", &store);
+        std::fs::write(
+            &ledger.paths.config_json,
+            r#"{"ledger":{"sign_events":true}}"#,
+        )
+        .unwrap();
+        let ledger = TestLedger(Ledger::open(&tmp).unwrap());
+
+        ledger
+            .append_event(&new_note_event("main", None, "user", "one", &[]).unwrap())
+            .unwrap();
+        ledger
+            .append_event(&new_note_event("main", None, "user", "two", &[]).unwrap())
+            .unwrap();
+        assert!(store.join("keys").join("ed25519.pk8").exists());
+
+        let report = ledger.verify_signatures().unwrap();
+        assert_eq!((report.signed, report.unsigned), (2, 0));
+        assert!(report.is_ok());
+
+        let first = ledger.iter_events().unwrap()[0].event_id.clone();
+        let conn = rusqlite::Connection::open(&ledger.paths.ledger_db).unwrap();
+        conn.execute(
+            "UPDATE event_signatures SET signature = ?1 WHERE event_id = ?2",
+            rusqlite::params!["00".repeat(64), first],
+        )
+        .unwrap();
+        let report = ledger.verify_signatures().unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.invalid[0].0, first);
+
+        std::env::remove_var("EDDA_STORE_ROOT");
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn chain_re_signed_with_a_foreign_key_fails_verification() {
+        let _lock = STORE_ROOT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let (tmp, ledger) = setup_workspace();
+        let store = tmp.join("store");
+        std::env::set_var("EDDA_STORE_ROOT", &store);
+        std::fs::write(
+            &ledger.paths.config_json,
+            r#"{"ledger":{"sign_events":true}}"#,
+        )
+        .unwrap();
+        let ledger = TestLedger(Ledger::open(&tmp).unwrap());
+        for text in ["one", "two"] {
+            ledger
+                .append_event(&new_note_event("main", None, "user", text, &[]).unwrap())
+                .unwrap();
+        }
+        assert!(ledger.verify_signatures().unwrap().is_ok());
+
+        // Re-sign every event with a fresh key, as someone rewriting the
+        // ledger would. Each signature matches its stored public key.
+        let forger = EventSigner::from_pkcs8(&EventSigner::generate_pkcs8().unwrap()).unwrap();
+        let conn = rusqlite::Connection::open(&ledger.paths.ledger_db).unwrap();
+        for event in ledger.iter_events().unwrap() {
+            let sig = forger.sign(&event);
+            conn.execute(
+                "UPDATE event_signatures SET key_id = ?1, public_key = ?2, signature = ?3
+                 WHERE event_id = ?4",
+                rusqlite::params![sig.key_id, sig.public_key, sig.signature, event.event_id],
+            )
+            .unwrap();
+        }
+        let report = ledger.verify_signatures().unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.invalid.len(), 2);
+        assert_eq!(report.untrusted_keys, vec![forger.key_id().to_string()]);
+
+        // Trusting the key explicitly makes the same signatures valid.
+        std::fs::write(
+            &ledger.paths.config_json,
+            serde_json::json!({
+                "ledger": { "sign_events": true, "trusted_keys": [forger.public_key_hex()] }
+            })
+            .to_string(),
+        )
+        .unwrap();
+        assert!(ledger.verify_signatures().unwrap().is_ok());
+
+        std::env::remove_var("EDDA_STORE_ROOT");
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn empty_ledger_has_no_hash() {
         let (tmp, ledger) = setup_workspace();
@@ -830,9 +1690,11 @@ mod tests {
     #[test]
     fn append_and_read_back() {
         let (tmp, ledger) = setup_workspace();
+        assert_eq!(ledger.last_event_ts().unwrap(), None);
         let e1 = new_note_event("main", None, "system", "init", &[]).unwrap();
         ledger.append_event(&e1).unwrap();
         assert_eq!(ledger.last_event_hash().unwrap(), Some(e1.hash.clone()));
+        assert_eq!(ledger.last_event_ts().unwrap(), Some(e1.ts.clone()));
 
         let e2 = new_note_event("main", Some(&e1.hash), "user", "hello", &[]).unwrap();
         ledger.append_event(&e2).unwrap();
@@ -1072,6 +1934,90 @@ mod tests {
         let _ = std::fs::remove_dir_all(&tmp);
     }
 
+    #[test]
+    fn append_event_rebasing_moves_a_stale_parent_onto_the_tip() {
+        let (tmp, ledger) = setup_workspace();
+        let first = new_note_event("main", None, "system", "first", &[]).unwrap();
+        ledger.append_event(&first).unwrap();
+        // Two writers build on `first`; the second one's parent goes stale.
+        let a = new_note_event("main", Some(&first.hash), "system", "a", &[]).unwrap();
+        let b = new_note_event("main", Some(&first.hash), "system", "b", &[]).unwrap();
+        ledger.0.append_event(&a).unwrap();
+
+        // The default append stays strict.
+        let err = ledger.0.append_event(&b).unwrap_err();
+        assert!(
+            err.downcast_ref::<crate::StaleParent>().is_some(),
+            "{err:#}"
+        );
+
+        let stored = ledger.0.append_event_rebasing(&b).unwrap();
+        assert_eq!(stored.event_id, b.event_id);
+        assert_eq!(stored.parent_hash.as_deref(), Some(a.hash.as_str()));
+        assert_eq!(stored.payload[REBASED_FROM], first.hash.as_str());
+        assert_ne!(stored.hash, b.hash);
+        ledger.verify_chain().unwrap();
+        let events = ledger.iter_events().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[2].hash, stored.hash);
+
+        // A parent that was never in the chain is not papered over.
+        // Neither is a missing parent on a non-empty ledger.
+        let foreign = new_note_event("main", Some("deadbeef"), "system", "x", &[]).unwrap();
+        let orphan = new_note_event("main", None, "system", "y", &[]).unwrap();
+        for event in [&foreign, &orphan] {
+            let err = ledger.0.append_event_rebasing(event).unwrap_err();
+            assert!(
+                err.downcast_ref::<crate::StaleParent>().is_some(),
+                "{err:#}"
+            );
+        }
+        assert_eq!(ledger.iter_events().unwrap().len(), 3);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn concurrent_writers_past_the_lock_keep_one_linear_chain() {
+        use std::sync::Barrier;
+
+        const ROUNDS: usize = 10;
+        let (tmp, ledger) = setup_workspace();
+        let first = new_note_event("main", None, "system", "first", &[]).unwrap();
+        ledger.append_event(&first).unwrap();
+
+        // Two processes' worth of handles, each on its own connection. Both
+        // read the tip before either appends, so every round one of them
+        // builds on a parent the other has just moved past.
+        let barrier = Barrier::new(2);
+        std::thread::scope(|s| {
+            for writer in ["a", "b"] {
+                let (tmp, barrier) = (&tmp, &barrier);
+                s.spawn(move || {
+                    let ledger = Ledger::open(tmp).unwrap();
+                    for round in 0..ROUNDS {
+                        let tip = ledger.last_event_hash().unwrap();
+                        let text = format!("{writer}{round}");
+                        let event =
+                            new_note_event("main", tip.as_deref(), "system", &text, &[]).unwrap();
+                        barrier.wait();
+                        ledger.append_event_rebasing(&event).unwrap();
+                        barrier.wait();
+                    }
+                });
+            }
+        });
+
+        ledger.verify_chain().unwrap();
+        let events = ledger.iter_events().unwrap();
+        assert_eq!(events.len(), 1 + 2 * ROUNDS);
+        let rebased = events
+            .iter()
+            .filter(|e| e.payload.get(REBASED_FROM).is_some())
+            .count();
+        assert_eq!(rebased, ROUNDS, "the loser of every round is rebased");
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
     #[test]
     fn iter_events_by_type_filters_correctly() {
         use edda_core::event::new_execution_event;
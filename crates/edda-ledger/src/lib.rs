@@ -1,31 +1,60 @@
+pub mod authz;
 pub mod blob_meta;
 pub mod blob_store;
+pub mod cold;
 pub mod device_token;
 pub mod domain;
+pub mod draft_ops;
+pub mod drafts;
 pub mod ledger;
 pub mod lock;
+pub mod merge;
+pub mod observe;
 pub mod paths;
+pub mod retention;
+pub mod segments;
+pub mod settings;
+pub mod signing;
 pub(crate) mod sqlite_store;
+pub mod stats;
 pub mod sync;
+pub mod tags;
 pub mod tasks;
 pub mod tombstone;
 pub mod view;
 
+pub use authz::{AllowAll, AppendAuthorizer, AppendDenied, PolicyAuthorizer};
 pub use blob_meta::{BlobClass, BlobMetaEntry, BlobMetaMap, ClassChange};
 pub use blob_store::{
-    blob_archive, blob_get_path, blob_is_archived, blob_list, blob_list_archived,
-    blob_put_classified, blob_put_if_large, blob_remove, blob_size, BlobInfo,
+    blob_archive, blob_dedup_stats, blob_gc_chunks, blob_get_path, blob_is_archived, blob_list,
+    blob_list_archived, blob_manifest, blob_put_attachment, blob_put_classified, blob_put_if_large,
+    blob_read, blob_remove, blob_size, BlobInfo, ChunkManifest, ClassDedup, DedupStats,
     SNAPSHOT_BLOB_THRESHOLD,
 };
 pub use domain::{
     BundleRow, ChainEntryView, DayCount, DecideSnapshotRow, DependencyEdge, DetectedPattern,
-    DeviceTokenRow, DomainCount, ExecutionLinked, ImportParams, OutcomeMetrics,
-    PatternDetectionResult, PatternType, SuggestionRow, TaskBriefRow, VillageStats,
-    VillageStatsPeriod,
+    DeviceTokenRow, DomainCount, EventFilter, ExecutionLinked, ImportParams, LedgerDiagnostics,
+    LedgerMaintenance, OutcomeMetrics, PatternDetectionResult, PatternType, QueryCheck,
+    SchemaDrift, StaleParent, SuggestionRow, TaskBriefRow, VillageStats, VillageStatsPeriod,
+};
+pub use drafts::{
+    import_legacy_drafts, legacy_draft_count, ApprovalRecord, DraftActionError, DraftStageView,
+    DraftStatus, DraftView, StageStatus,
 };
 pub use ledger::Ledger;
 pub use lock::WorkspaceLock;
+pub use merge::{
+    decision_conflicts, decision_diff, BranchDecisionDiff, DecisionConflict, DecisionResolution,
+    MergeStrategy,
+};
+pub use observe::{add_append_observer, AppendObserver};
 pub use paths::{validate_branch_name, EddaPaths};
+pub use retention::{ArchiveReport, RetentionPolicy};
+pub use segments::{SegmentEntry, SegmentManifest};
+pub use settings::ConfigChange;
+pub use signing::{EventSignature, SignatureReport};
+pub use stats::{AppendStats, SizeStats, TypeStats};
+pub use tags::{TagAliases, TagQuery};
 pub use tasks::{TaskStatus, TaskView};
 pub use tombstone::{append_tombstone, list_tombstones, make_tombstone, DeleteReason, Tombstone};
 pub use view::DecisionView;
@@ -31,6 +31,27 @@ impl WorkspaceLock {
 
         Ok(Self { _file: file })
     }
+
+    /// Whether the lock could be taken right now, without keeping it.
+    ///
+    /// The lock is held only for the instant of the check. Errors mean the
+    /// lock file itself cannot be opened, which blocks every writer.
+    pub fn is_free(paths: &EddaPaths) -> anyhow::Result<bool> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&paths.lock_file)
+            .map_err(|e| {
+                anyhow::anyhow!("cannot open lock file {}: {}", paths.lock_file.display(), e)
+            })?;
+        let free = file.try_lock_exclusive().is_ok();
+        if free {
+            let _ = FileExt::unlock(&file);
+        }
+        Ok(free)
+    }
 }
 
 #[cfg(test)]
@@ -44,9 +65,11 @@ mod tests {
         let p = EddaPaths::discover(&tmp);
         p.ensure_layout().unwrap();
 
+        assert!(WorkspaceLock::is_free(&p).unwrap());
         let lock = WorkspaceLock::acquire(&p).unwrap();
         // Second acquire should fail while first is held
         assert!(WorkspaceLock::acquire(&p).is_err());
+        assert!(!WorkspaceLock::is_free(&p).unwrap());
         drop(lock);
         // After drop, should succeed again
         let _lock2 = WorkspaceLock::acquire(&p).unwrap();
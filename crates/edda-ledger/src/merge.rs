@@ -0,0 +1,231 @@
+//! Decision reconciliation for `edda merge`.
+//!
+//! Decisions are projected per branch, so merging `src` into `dst` can leave
+//! the same key active on both with different values. A [`MergeStrategy`]
+//! says which value `dst` should end up with; the resulting
+//! [`DecisionResolution`]s are recorded in the merge event payload so every
+//! automatic choice can be audited later.
+//!
+//! Only project-wide decisions take part. Session- and branch-scoped
+//! decisions deliberately do not travel with a merge.
+
+use crate::{DecisionView, Ledger};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How to reconcile a decision key that differs between `dst` and `src`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeStrategy {
+    /// Keep the destination branch's value.
+    Ours,
+    /// Take the source branch's value.
+    Theirs,
+    /// Combine both values as a comma-separated list, destination items
+    /// first, duplicates dropped.
+    Union,
+    /// Ask the operator to settle each key.
+    Interactive,
+}
+
+impl MergeStrategy {
+    /// The value `dst` should hold for a key decided `ours` there and
+    /// `theirs` on the source branch. `None` for [`Self::Interactive`],
+    /// where the caller has to ask.
+    pub fn resolve(self, ours: &str, theirs: &str) -> Option<String> {
+        match self {
+            Self::Ours => Some(ours.to_string()),
+            Self::Theirs => Some(theirs.to_string()),
+            Self::Interactive => None,
+            Self::Union => {
+                let mut items: Vec<&str> = Vec::new();
+                for item in ours.split(',').chain(theirs.split(',')) {
+                    let item = item.trim();
+                    if !item.is_empty() && !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                Some(items.join(","))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ours => "ours",
+            Self::Theirs => "theirs",
+            Self::Union => "union",
+            Self::Interactive => "interactive",
+        })
+    }
+}
+
+impl std::str::FromStr for MergeStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ours" => Ok(Self::Ours),
+            "theirs" => Ok(Self::Theirs),
+            "union" => Ok(Self::Union),
+            "interactive" => Ok(Self::Interactive),
+            other => Err(format!(
+                "unknown merge strategy {other:?}: expected ours|theirs|union|interactive"
+            )),
+        }
+    }
+}
+
+/// A key active on both branches with different values.
+#[derive(Debug, Clone)]
+pub struct DecisionConflict {
+    pub key: String,
+    pub ours: DecisionView,
+    pub theirs: DecisionView,
+}
+
+/// How one conflicting key was settled, as recorded in the merge payload.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecisionResolution {
+    pub key: String,
+    pub ours: String,
+    pub theirs: String,
+    pub resolved: String,
+    pub ours_event: String,
+    pub theirs_event: String,
+    /// The decision written on `dst` to apply the resolution; absent when
+    /// `dst` already held the resolved value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decision_event: Option<String>,
+}
+
+/// Active project-wide decisions of `src` compared with `dst`.
+#[derive(Debug, Clone, Default)]
+pub struct BranchDecisionDiff {
+    /// Keys decided only on `src`; they do not travel with the merge.
+    pub only_src: Vec<DecisionView>,
+    /// Keys decided only on `dst`.
+    pub only_dst: Vec<DecisionView>,
+    /// Keys active on both with different values.
+    pub conflicts: Vec<DecisionConflict>,
+    /// Keys active on both with the same value.
+    pub same: usize,
+}
+
+/// Diff the active project-wide decisions of `src` against `dst`; every
+/// list is ordered by key.
+pub fn decision_diff(ledger: &Ledger, src: &str, dst: &str) -> anyhow::Result<BranchDecisionDiff> {
+    let mut ours: BTreeMap<String, DecisionView> = BTreeMap::new();
+    let mut theirs: BTreeMap<String, DecisionView> = BTreeMap::new();
+    for d in ledger.active_decisions(None, None, None, None)? {
+        if matches!(d.propagation.as_str(), "session" | "branch") {
+            continue;
+        }
+        if d.branch == dst {
+            ours.insert(d.key.clone(), d);
+        } else if d.branch == src {
+            theirs.insert(d.key.clone(), d);
+        }
+    }
+
+    let mut diff = BranchDecisionDiff::default();
+    for (key, o) in ours {
+        match theirs.remove(&key) {
+            Some(t) if t.value == o.value => diff.same += 1,
+            Some(t) => diff.conflicts.push(DecisionConflict {
+                key,
+                ours: o,
+                theirs: t,
+            }),
+            None => diff.only_dst.push(o),
+        }
+    }
+    diff.only_src = theirs.into_values().collect();
+    Ok(diff)
+}
+
+/// Keys whose active project-wide decision differs between `dst` and `src`,
+/// ordered by key.
+pub fn decision_conflicts(
+    ledger: &Ledger,
+    src: &str,
+    dst: &str,
+) -> anyhow::Result<Vec<DecisionConflict>> {
+    Ok(decision_diff(ledger, src, dst)?.conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strategies_resolve_values() {
+        assert_eq!(
+            MergeStrategy::Ours.resolve("pg", "sqlite").as_deref(),
+            Some("pg")
+        );
+        assert_eq!(
+            MergeStrategy::Theirs.resolve("pg", "sqlite").as_deref(),
+            Some("sqlite")
+        );
+        assert_eq!(
+            MergeStrategy::Union
+                .resolve("clippy, rustfmt", "rustfmt,deny")
+                .as_deref(),
+            Some("clippy,rustfmt,deny")
+        );
+        assert_eq!(MergeStrategy::Interactive.resolve("pg", "sqlite"), None);
+        assert_eq!("theirs".parse(), Ok(MergeStrategy::Theirs));
+        assert_eq!("interactive".parse(), Ok(MergeStrategy::Interactive));
+        assert!("mine".parse::<MergeStrategy>().is_err());
+    }
+
+    #[test]
+    fn diff_splits_keys_by_branch_and_value() {
+        use edda_core::event::new_decision_event;
+        use edda_core::types::DecisionPayload;
+
+        let tmp = std::env::temp_dir().join(format!("edda_merge_diff_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        Ledger::ensure_initialized(&tmp).unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+        let decide = |branch: &str, key: &str, value: &str| {
+            let dp = DecisionPayload {
+                key: key.into(),
+                value: value.into(),
+                reason: None,
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            let parent = ledger.last_event_hash().unwrap();
+            let e = new_decision_event(branch, parent.as_deref(), "user", &dp).unwrap();
+            ledger.append_event(&e).unwrap();
+        };
+        decide("main", "db.engine", "postgres");
+        decide("main", "lint.tools", "clippy");
+        decide("main", "auth.method", "jwt");
+        decide("feat", "db.engine", "sqlite");
+        decide("feat", "lint.tools", "clippy");
+        decide("feat", "cache.ttl", "60");
+
+        let diff = decision_diff(&ledger, "feat", "main").unwrap();
+        assert_eq!(diff.same, 1);
+        assert_eq!(diff.conflicts.len(), 1);
+        assert_eq!(diff.conflicts[0].key, "db.engine");
+        assert_eq!(diff.conflicts[0].ours.value, "postgres");
+        assert_eq!(diff.conflicts[0].theirs.value, "sqlite");
+        let keys = |v: &[DecisionView]| v.iter().map(|d| d.key.clone()).collect::<Vec<_>>();
+        assert_eq!(keys(&diff.only_dst), ["auth.method"]);
+        assert_eq!(keys(&diff.only_src), ["cache.ttl"]);
+
+        drop(ledger);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
@@ -0,0 +1,91 @@
+//! Append observers: crates above the ledger reacting to what is written.
+//!
+//! The ledger sits below the crates that act on events (notifications), so
+//! it cannot call them directly. They register an [`AppendObserver`] once
+//! per process with [`add_append_observer`]; every successful append through
+//! any [`crate::Ledger`] handle then reaches it, after the event is stored.
+//! Observers run inline on the appending thread and cannot fail the append.
+
+use std::sync::{Arc, RwLock};
+
+use edda_core::Event;
+
+use crate::paths::EddaPaths;
+
+/// Called with every event stored in any workspace this process writes to.
+pub trait AppendObserver: Send + Sync {
+    fn on_append(&self, paths: &EddaPaths, event: &Event);
+}
+
+static OBSERVERS: RwLock<Vec<Arc<dyn AppendObserver>>> = RwLock::new(Vec::new());
+
+/// Register `observer` for the rest of the process.
+pub fn add_append_observer(observer: Arc<dyn AppendObserver>) {
+    OBSERVERS
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .push(observer);
+}
+
+/// Hand a stored event to every observer. The list is copied first, so an
+/// observer that appends in turn does not deadlock.
+pub(crate) fn notify_append(paths: &EddaPaths, event: &Event) {
+    let observers = OBSERVERS.read().unwrap_or_else(|e| e.into_inner()).clone();
+    for observer in observers {
+        observer.on_append(paths, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::Ledger;
+    use edda_core::event::new_note_event;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    /// Records appends to one workspace; the registry is process-wide, so
+    /// other tests' appends must be ignored.
+    struct Recorder {
+        root: PathBuf,
+        seen: Mutex<Vec<String>>,
+    }
+
+    impl AppendObserver for Recorder {
+        fn on_append(&self, paths: &EddaPaths, event: &Event) {
+            if paths.root == self.root {
+                self.seen.lock().unwrap().push(event.event_id.clone());
+            }
+        }
+    }
+
+    #[test]
+    fn observers_see_stored_appends_only() {
+        let tmp = std::env::temp_dir().join(format!("edda_observe_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let recorder = Arc::new(Recorder {
+            root: tmp.clone(),
+            seen: Mutex::new(Vec::new()),
+        });
+        add_append_observer(recorder.clone());
+
+        let ledger = Ledger::open(&tmp).unwrap();
+        let first = new_note_event("main", None, "user", "one", &[]).unwrap();
+        ledger.append_event(&first).unwrap();
+        let second = new_note_event("main", Some(&first.hash), "user", "two", &[]).unwrap();
+        assert!(ledger.append_event_idempotent(&second).unwrap());
+        // A duplicate is not stored, so it is not observed.
+        assert!(!ledger.append_event_idempotent(&second).unwrap());
+
+        assert_eq!(
+            *recorder.seen.lock().unwrap(),
+            vec![first.event_id.clone(), second.event_id.clone()]
+        );
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
@@ -12,6 +12,10 @@ pub struct EddaPaths {
     pub drafts_dir: PathBuf,
     pub lock_file: PathBuf,
     pub config_json: PathBuf,
+    /// Append-path metrics, see [`crate::stats`].
+    pub stats_json: PathBuf,
+    /// Serializes read-modify-write of `stats_json` across processes.
+    pub stats_lock: PathBuf,
     pub patterns_dir: PathBuf,
     pub blob_meta_json: PathBuf,
     pub tombstones_jsonl: PathBuf,
@@ -35,6 +39,8 @@ impl EddaPaths {
             drafts_dir: edda_dir.join("drafts"),
             lock_file: edda_dir.join("LOCK"),
             config_json: edda_dir.join("config.json"),
+            stats_json: edda_dir.join("stats.json"),
+            stats_lock: edda_dir.join("stats.lock"),
             patterns_dir: edda_dir.join("patterns"),
             archive_blobs_dir: archive_dir.join("blobs"),
             archive_dir,
@@ -0,0 +1,506 @@
+//! Per-family event retention and the cold archive tier.
+//!
+//! `gc.retention.<selector>` in `.edda/config.json` keeps events for that
+//! many days, where the selector is an event type (`note`, `cmd`,
+//! `task.session`) or a family (`signal`, `milestone`, `admin`,
+//! `governance`). A type rule wins over its family's, so `signal = 30` with
+//! `note = 180` keeps notes longer than the rest of the signal family.
+//! Events no rule covers are kept.
+//!
+//! `edda gc` moves older events out of `ledger.db` into zstd-compressed
+//! monthly files, `.edda/archive/events-YYYY-MM.jsonl.zst`, and leaves a stub
+//! with the event's hashes so `verify_chain` still walks the whole chain.
+//! Each run appends a new zstd frame, which the decoder reads as one stream.
+//! Months archived before the switch to zstd stay `.jsonl.gz` and are still
+//! read; new events for such a month go to its `.jsonl.zst` file.
+//! `gc.ledger_max_mb` rotation moves events into ledger segments instead
+//! ([`crate::segments`]), which reads still see.
+//! `edda archive push` can move these files on to cold storage
+//! ([`crate::cold`]); reads fetch them back.
+//!
+//! Decisions are kept forever, along with their ratifications and imports,
+//! every event another ledger table points at, and the chain tip. So are the
+//! events live projections fold over (tasks, drafts and approvals, pins and
+//! their targets, tag renames, config changes): those readers only scan
+//! `ledger.db`, so archiving them would silently drop open tasks or pending
+//! drafts.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use edda_core::Event;
+use flate2::read::MultiGzDecoder;
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::paths::EddaPaths;
+
+/// Config key prefix for retention rules.
+pub const RETENTION_KEY_PREFIX: &str = "gc.retention.";
+
+/// Event types that are never archived, whatever the rules say.
+const DECISION_RECORD_TYPES: &[&str] = &["decision_ratify", "decision_import", "revoke"];
+
+/// Event types folded by projections that read live rows only.
+const PROJECTION_INPUT_TYPES: &[&str] = &[
+    "approval",
+    "approval_request",
+    "pin",
+    "tag_rename",
+    "config_change",
+];
+
+/// Event type prefixes folded by projections that read live rows only.
+const PROJECTION_INPUT_PREFIXES: &[&str] = &["task.", "draft."];
+
+/// Retention in days per event type or family.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    rules: BTreeMap<String, u32>,
+}
+
+impl RetentionPolicy {
+    pub fn new(rules: BTreeMap<String, u32>) -> Self {
+        Self { rules }
+    }
+
+    /// Read the `gc.retention.*` keys from `config.json`. A missing file
+    /// means no rules.
+    pub fn from_config(config_json: &Path) -> anyhow::Result<Self> {
+        let content = match std::fs::read_to_string(config_json) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let config: serde_json::Value = serde_json::from_str(&content)?;
+        let mut rules = BTreeMap::new();
+        if let Some(map) = config.as_object() {
+            for (key, value) in map {
+                let Some(selector) = key.strip_prefix(RETENTION_KEY_PREFIX) else {
+                    continue;
+                };
+                let days = value.as_u64().ok_or_else(|| {
+                    anyhow::anyhow!("{key} must be a whole number of days, got {value}")
+                })?;
+                rules.insert(selector.to_string(), u32::try_from(days)?);
+            }
+        }
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn rules(&self) -> &BTreeMap<String, u32> {
+        &self.rules
+    }
+
+    /// Days to keep `event`, or `None` to keep it forever.
+    pub fn keep_days(&self, event: &Event) -> Option<u32> {
+        if is_kept_forever(event) {
+            return None;
+        }
+        self.rules.get(&event.event_type).copied().or_else(|| {
+            let family = event.event_family.as_deref()?;
+            self.rules.get(family).copied()
+        })
+    }
+
+    /// Whether `event` is past its retention at `now`. Events with an
+    /// unreadable timestamp never expire.
+    pub fn is_expired(&self, event: &Event, now: OffsetDateTime) -> bool {
+        let Some(days) = self.keep_days(event) else {
+            return false;
+        };
+        let Ok(ts) = OffsetDateTime::parse(&event.ts, &Rfc3339) else {
+            return false;
+        };
+        ts < now - time::Duration::days(i64::from(days))
+    }
+}
+
+/// Whether `event` stays in `ledger.db` under any retention or rotation.
+pub(crate) fn is_kept_forever(event: &Event) -> bool {
+    is_decision_record(event) || is_projection_input(event)
+}
+
+fn is_decision_record(event: &Event) -> bool {
+    DECISION_RECORD_TYPES.contains(&event.event_type.as_str())
+        || event.payload["tags"]
+            .as_array()
+            .is_some_and(|tags| tags.iter().any(|t| t.as_str() == Some("decision")))
+}
+
+fn is_projection_input(event: &Event) -> bool {
+    let t = event.event_type.as_str();
+    PROJECTION_INPUT_TYPES.contains(&t)
+        || PROJECTION_INPUT_PREFIXES.iter().any(|p| t.starts_with(p))
+}
+
+/// What an archive pass moved (or, in a dry run, would move).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchiveReport {
+    pub archived: usize,
+    /// Archived events per event type.
+    pub by_type: BTreeMap<String, usize>,
+    /// Archive files written to, by name.
+    pub files: Vec<String>,
+}
+
+/// Extension of the archive files written now.
+const ARCHIVE_EXT: &str = ".jsonl.zst";
+
+/// Extension of archive files written before the switch to zstd.
+const LEGACY_ARCHIVE_EXT: &str = ".jsonl.gz";
+
+/// zstd level for archive frames: the library default, a good ratio at
+/// gc-time speed.
+const ZSTD_LEVEL: i32 = 0;
+
+/// The monthly archive file an event belongs in, e.g. `events-2026-03.jsonl.zst`.
+pub fn archive_file_name(event: &Event) -> Option<String> {
+    let ts = OffsetDateTime::parse(&event.ts, &Rfc3339).ok()?;
+    Some(format!(
+        "events-{:04}-{:02}{ARCHIVE_EXT}",
+        ts.year(),
+        u8::from(ts.month())
+    ))
+}
+
+/// Append events to an archive file as one zstd frame, and sync it before
+/// returning so the events are durable before they leave `ledger.db`.
+pub(crate) fn append_to_archive(path: &Path, events: &[&Event]) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let mut zst = zstd::Encoder::new(file, ZSTD_LEVEL)?;
+    for event in events {
+        serde_json::to_writer(&mut zst, event)?;
+        zst.write_all(b"\n")?;
+    }
+    zst.finish()?.sync_all()?;
+    Ok(())
+}
+
+/// Read every event in an archive file, zstd or legacy gzip, in file order.
+pub(crate) fn read_archive_file(path: &Path) -> anyhow::Result<Vec<Event>> {
+    let file = std::fs::File::open(path)?;
+    let reader: Box<dyn BufRead> = if is_legacy_archive(path) {
+        Box::new(BufReader::new(MultiGzDecoder::new(file)))
+    } else {
+        Box::new(BufReader::new(zstd::Decoder::new(file)?))
+    };
+    let mut events = Vec::new();
+    for (n, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event: Event = serde_json::from_str(&line)
+            .map_err(|e| anyhow::anyhow!("{} line {}: {e}", path.display(), n + 1))?;
+        events.push(event);
+    }
+    Ok(events)
+}
+
+fn is_legacy_archive(path: &Path) -> bool {
+    path.to_str()
+        .is_some_and(|p| p.ends_with(LEGACY_ARCHIVE_EXT))
+}
+
+/// Whether `name` is a monthly event archive file, in either format.
+pub(crate) fn is_archive_file_name(name: &str) -> bool {
+    name.starts_with("events-")
+        && (name.ends_with(ARCHIVE_EXT) || name.ends_with(LEGACY_ARCHIVE_EXT))
+}
+
+/// Archive files under `.edda/archive/`, oldest month first.
+pub fn archive_files(paths: &EddaPaths) -> anyhow::Result<Vec<PathBuf>> {
+    let entries = match std::fs::read_dir(&paths.archive_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(is_archive_file_name)
+        })
+        .collect();
+    // A month's legacy `.gz` file sorts ahead of its `.zst` file, matching
+    // the order they were written in.
+    files.sort();
+    Ok(files)
+}
+
+/// Every archived event, read from the archive files. Files pushed to cold
+/// storage are fetched back (see [`crate::cold`]). An event written twice
+/// (a run interrupted between the file write and the ledger update) is
+/// returned once.
+pub fn read_archived_events(paths: &EddaPaths) -> anyhow::Result<Vec<Event>> {
+    let mut seen = HashSet::new();
+    let mut events = Vec::new();
+    for name in crate::cold::event_segments(paths)? {
+        let Some(path) = crate::cold::fetch(paths, &name)? else {
+            continue;
+        };
+        for event in read_archive_file(&path)? {
+            if seen.insert(event.event_id.clone()) {
+                events.push(event);
+            }
+        }
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::Ledger;
+    use edda_core::event::{finalize_event, new_decision_event, new_note_event};
+    use edda_core::types::DecisionPayload;
+
+    fn policy(rules: &[(&str, u32)]) -> RetentionPolicy {
+        RetentionPolicy::new(rules.iter().map(|(k, v)| (k.to_string(), *v)).collect())
+    }
+
+    fn append_at(ledger: &Ledger, mut event: Event, ts: &str) -> Event {
+        event.ts = ts.into();
+        event.parent_hash = ledger.last_event_hash().unwrap();
+        finalize_event(&mut event).unwrap();
+        ledger.append_event(&event).unwrap();
+        event
+    }
+
+    fn cmd(text: &str) -> Event {
+        let mut e = new_note_event("main", None, "system", text, &[]).unwrap();
+        e.event_type = "cmd".into();
+        e
+    }
+
+    #[test]
+    fn type_rules_win_over_family_rules() {
+        let p = policy(&[("signal", 30), ("note", 180)]);
+        let note = new_note_event("main", None, "user", "n", &[]).unwrap();
+        let mut c = cmd("ls");
+        finalize_event(&mut c).unwrap();
+        assert_eq!(p.keep_days(&note), Some(180));
+        assert_eq!(p.keep_days(&c), Some(30));
+
+        let dp = DecisionPayload {
+            key: "db.engine".into(),
+            value: "sqlite".into(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let decision = new_decision_event("main", None, "user", &dp).unwrap();
+        assert_eq!(p.keep_days(&decision), None);
+    }
+
+    #[test]
+    fn expired_events_move_to_the_archive_and_the_chain_still_verifies() {
+        let tmp = std::env::temp_dir().join(format!("edda_retention_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+
+        let old_cmd = append_at(&ledger, cmd("old"), "2026-01-05T10:00:00Z");
+        let old_note = append_at(
+            &ledger,
+            new_note_event("main", None, "user", "kept", &[]).unwrap(),
+            "2026-01-06T10:00:00Z",
+        );
+        let march_cmd = append_at(&ledger, cmd("march"), "2026-03-01T10:00:00Z");
+        let recent = append_at(&ledger, cmd("recent"), "2026-06-20T10:00:00Z");
+        let tip = append_at(&ledger, cmd("tip"), "2026-01-01T00:00:00Z");
+
+        let p = policy(&[("signal", 30), ("note", 365)]);
+        let now = OffsetDateTime::parse("2026-07-01T00:00:00Z", &Rfc3339).unwrap();
+
+        let dry = ledger.archive_expired_events(&p, now, true).unwrap();
+        assert_eq!(dry.archived, 2);
+        assert_eq!(ledger.iter_events().unwrap().len(), 5);
+
+        let report = ledger.archive_expired_events(&p, now, false).unwrap();
+        assert_eq!(report.archived, 2);
+        assert_eq!(report.by_type.get("cmd"), Some(&2));
+        assert_eq!(
+            report.files,
+            vec!["events-2026-01.jsonl.zst", "events-2026-03.jsonl.zst"]
+        );
+
+        let live: Vec<String> = ledger
+            .iter_events()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_id)
+            .collect();
+        assert_eq!(
+            live,
+            vec![
+                old_note.event_id.clone(),
+                recent.event_id.clone(),
+                tip.event_id.clone()
+            ]
+        );
+        ledger.verify_chain().unwrap();
+
+        let all: Vec<String> = ledger
+            .iter_events_with_archive()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_id)
+            .collect();
+        assert_eq!(
+            all,
+            vec![
+                old_cmd.event_id.clone(),
+                old_note.event_id.clone(),
+                march_cmd.event_id.clone(),
+                recent.event_id.clone(),
+                tip.event_id.clone(),
+            ]
+        );
+
+        // Nothing left to archive; the tip is never taken.
+        let again = ledger.archive_expired_events(&p, now, false).unwrap();
+        assert_eq!(again.archived, 0);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn legacy_gzip_months_are_read_alongside_zstd_ones() {
+        use flate2::write::GzEncoder;
+
+        let tmp =
+            std::env::temp_dir().join(format!("edda_retention_legacy_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+
+        let mut old = cmd("gzip era");
+        old.ts = "2026-01-05T10:00:00Z".into();
+        finalize_event(&mut old).unwrap();
+        let mut new = cmd("zstd era");
+        new.ts = "2026-01-20T10:00:00Z".into();
+        finalize_event(&mut new).unwrap();
+
+        std::fs::create_dir_all(&paths.archive_dir).unwrap();
+        let legacy =
+            std::fs::File::create(paths.archive_dir.join("events-2026-01.jsonl.gz")).unwrap();
+        let mut gz = GzEncoder::new(legacy, flate2::Compression::default());
+        serde_json::to_writer(&mut gz, &old).unwrap();
+        gz.write_all(b"\n").unwrap();
+        gz.finish().unwrap();
+        let name = archive_file_name(&new).unwrap();
+        assert_eq!(name, "events-2026-01.jsonl.zst");
+        append_to_archive(&paths.archive_dir.join(&name), &[&new]).unwrap();
+
+        let ids: Vec<String> = read_archived_events(&paths)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.event_id)
+            .collect();
+        assert_eq!(ids, vec![old.event_id, new.event_id]);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn family_rules_leave_projection_inputs_live() {
+        use edda_core::event::{
+            new_draft_proposed_event, new_pin_event, new_tag_rename_event, new_task_created_event,
+            DraftProposedParams, TaskCreatedParams,
+        };
+
+        let tmp = std::env::temp_dir().join(format!(
+            "edda_retention_projection_test_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+
+        let task = new_task_created_event(&TaskCreatedParams {
+            branch: "main",
+            parent_hash: None,
+            task_id: 1,
+            title: "open task",
+            assignee: None,
+            agent_kind: None,
+            after: &[],
+            plan_id: None,
+            work_unit_ref: None,
+            brief_ref: None,
+            idempotency_key: None,
+        })
+        .unwrap();
+        append_at(&ledger, task, "2026-01-02T10:00:00Z");
+        let target = append_at(&ledger, cmd("pinned"), "2026-01-03T10:00:00Z");
+        let pin = new_pin_event("main", None, &target.event_id, true, None).unwrap();
+        append_at(&ledger, pin, "2026-01-04T10:00:00Z");
+        let rename = new_tag_rename_event("main", None, "perf", "performance").unwrap();
+        append_at(&ledger, rename, "2026-01-05T10:00:00Z");
+        let draft = new_draft_proposed_event(&DraftProposedParams {
+            branch: "main",
+            parent_hash: None,
+            draft_id: "drf_old",
+            created_at: None,
+            base_parent_hash: "",
+            title: "pending draft",
+            purpose: "",
+            contribution: "",
+            labels: &[],
+            evidence: &[],
+            auto_preview_lines: &[],
+            route_rule_id: "",
+            stages: &[],
+            policy_require_approval: true,
+            policy_min_approvals: 1,
+            origin: None,
+        })
+        .unwrap();
+        append_at(&ledger, draft, "2026-01-06T10:00:00Z");
+        let old_cmd = append_at(&ledger, cmd("old"), "2026-01-07T10:00:00Z");
+        append_at(&ledger, cmd("tip"), "2026-06-30T10:00:00Z");
+
+        let p = policy(&[("signal", 30), ("admin", 30), ("governance", 30)]);
+        let now = OffsetDateTime::parse("2026-07-01T00:00:00Z", &Rfc3339).unwrap();
+        let report = ledger.archive_expired_events(&p, now, false).unwrap();
+        assert_eq!(report.archived, 1);
+        assert!(ledger.get_event(&old_cmd.event_id).unwrap().is_none());
+
+        let tasks = ledger.task_views().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].title, "open task");
+        let pinned = ledger.pinned_events().unwrap();
+        assert_eq!(pinned.len(), 1);
+        assert_eq!(pinned[0].event_id, target.event_id);
+        assert_eq!(ledger.tag_aliases().unwrap().resolve("perf"), "performance");
+        assert!(ledger.draft_view("drf_old").unwrap().is_some());
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
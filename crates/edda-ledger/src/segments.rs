@@ -0,0 +1,369 @@
+//! Rotated ledger segments.
+//!
+//! `gc.ledger_max_mb` caps the payload bytes kept in `ledger.db`. Past it,
+//! [`crate::Ledger::rotate_events`] moves the oldest events that may leave
+//! the database into a new zstd-compressed segment,
+//! `.edda/ledger/segments/segment-NNNNNN.jsonl.zst`, and leaves the same
+//! hash stub retention leaves, so `verify_chain` still walks the whole
+//! chain. `verify_chain` also rehashes every rotated event against its stub
+//! and the manifest, so a rewritten segment does not go unnoticed.
+//!
+//! Unlike the retention archive, rotated events are still part of the
+//! ledger: `iter_events`, `events_after_rowid` and the view replay read
+//! them back in their original position. `.edda/ledger/segments/manifest.json`
+//! lists every segment with the rowid range it covers, so readers only
+//! decompress the segments a query reaches.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use edda_core::Event;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::OffsetDateTime;
+
+use crate::paths::EddaPaths;
+
+/// Segment manifest, under the segments directory.
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// zstd level for segments: the library default.
+const ZSTD_LEVEL: i32 = 0;
+
+/// One rotated segment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentEntry {
+    /// File name under the segments directory.
+    pub name: String,
+    /// Lowest original rowid of the events it holds.
+    pub first_rowid: i64,
+    /// Highest original rowid of the events it holds.
+    pub last_rowid: i64,
+    pub events: usize,
+    /// Payload bytes the events took in `ledger.db`.
+    pub payload_bytes: u64,
+    /// Compressed file size.
+    pub size: u64,
+    pub first_ts: String,
+    pub last_ts: String,
+    pub rotated_at: String,
+}
+
+/// `.edda/ledger/segments/manifest.json`, oldest segment first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SegmentManifest {
+    pub segments: Vec<SegmentEntry>,
+}
+
+/// Directory holding rotated segments and their manifest.
+pub fn segments_dir(paths: &EddaPaths) -> PathBuf {
+    paths.ledger_dir.join("segments")
+}
+
+fn manifest_path(paths: &EddaPaths) -> PathBuf {
+    segments_dir(paths).join(MANIFEST_FILE)
+}
+
+/// Read the manifest. A missing file means nothing was rotated.
+pub fn load_manifest(paths: &EddaPaths) -> anyhow::Result<SegmentManifest> {
+    match std::fs::read_to_string(manifest_path(paths)) {
+        Ok(content) => Ok(serde_json::from_str(&content)?),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(SegmentManifest::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// The manifest as last read, reread only when the file changes. Polling
+/// readers (`events_after_rowid` behind SSE and `edda watch`) pay a `stat`
+/// per call instead of a parse.
+#[derive(Default)]
+pub(crate) struct ManifestCache {
+    cached: Mutex<Option<(SystemTime, u64, Arc<SegmentManifest>)>>,
+}
+
+impl ManifestCache {
+    pub(crate) fn load(&self, paths: &EddaPaths) -> anyhow::Result<Arc<SegmentManifest>> {
+        let meta = match std::fs::metadata(manifest_path(paths)) {
+            Ok(meta) => meta,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Arc::new(SegmentManifest::default()));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let stamp = (meta.modified()?, meta.len());
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((mtime, len, manifest)) = cached.as_ref() {
+            if (*mtime, *len) == stamp {
+                return Ok(manifest.clone());
+            }
+        }
+        let manifest = Arc::new(load_manifest(paths)?);
+        *cached = Some((stamp.0, stamp.1, manifest.clone()));
+        Ok(manifest)
+    }
+}
+
+/// Whether an archive file name is a rotated segment rather than a
+/// retention archive.
+pub(crate) fn is_segment_name(name: &str) -> bool {
+    name.starts_with("segment-")
+}
+
+fn save_manifest(paths: &EddaPaths, manifest: &SegmentManifest) -> anyhow::Result<()> {
+    let path = manifest_path(paths);
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(manifest)?)?;
+    std::fs::File::open(&tmp)?.sync_all()?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+/// Name of the segment after the ones in `manifest`.
+pub(crate) fn next_segment_name(manifest: &SegmentManifest) -> String {
+    format!("segment-{:06}.jsonl.zst", manifest.segments.len() + 1)
+}
+
+/// Write `events` (`(rowid, event, payload bytes)`, in rowid order) to a new
+/// segment and record it in the manifest. Both are synced before returning,
+/// so the events are durable before they leave `ledger.db`.
+pub(crate) fn write_segment(
+    paths: &EddaPaths,
+    events: &[(i64, &Event, u64)],
+) -> anyhow::Result<SegmentEntry> {
+    let (Some(first), Some(last)) = (events.first(), events.last()) else {
+        anyhow::bail!("no events to rotate");
+    };
+    let dir = segments_dir(paths);
+    std::fs::create_dir_all(&dir)?;
+    let mut manifest = load_manifest(paths)?;
+    let name = next_segment_name(&manifest);
+    let path = dir.join(&name);
+
+    // A file the manifest does not list is left from a run interrupted
+    // before its manifest write; its events never left `ledger.db`.
+    let file = std::fs::File::create(&path)?;
+    let mut zst = zstd::Encoder::new(file, ZSTD_LEVEL)?;
+    for (_, event, _) in events {
+        serde_json::to_writer(&mut zst, event)?;
+        zst.write_all(b"\n")?;
+    }
+    zst.finish()?.sync_all()?;
+
+    let entry = SegmentEntry {
+        name,
+        first_rowid: first.0,
+        last_rowid: last.0,
+        events: events.len(),
+        payload_bytes: events.iter().map(|(_, _, bytes)| bytes).sum(),
+        size: std::fs::metadata(&path)?.len(),
+        first_ts: first.1.ts.clone(),
+        last_ts: last.1.ts.clone(),
+        rotated_at: OffsetDateTime::now_utc().format(&Rfc3339)?,
+    };
+    manifest.segments.push(entry.clone());
+    save_manifest(paths, &manifest)?;
+    Ok(entry)
+}
+
+/// Events in the segments that hold rowids above `after_rowid`, in file
+/// order. Segments wholly at or below it are not opened.
+pub(crate) fn read_segments_after(
+    paths: &EddaPaths,
+    manifest: &SegmentManifest,
+    after_rowid: i64,
+) -> anyhow::Result<Vec<Event>> {
+    let mut events = Vec::new();
+    for entry in manifest
+        .segments
+        .iter()
+        .filter(|s| s.last_rowid > after_rowid)
+    {
+        let path = segments_dir(paths).join(&entry.name);
+        events.extend(crate::retention::read_archive_file(&path)?);
+    }
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::Ledger;
+    use edda_core::event::{finalize_event, new_decision_event, new_note_event};
+    use edda_core::types::DecisionPayload;
+
+    fn append_at(ledger: &Ledger, mut event: Event, ts: &str) -> Event {
+        event.ts = ts.into();
+        event.parent_hash = ledger.last_event_hash().unwrap();
+        finalize_event(&mut event).unwrap();
+        ledger.append_event(&event).unwrap();
+        event
+    }
+
+    fn cmd(text: &str) -> Event {
+        let mut e = new_note_event("main", None, "system", text, &[]).unwrap();
+        e.event_type = "cmd".into();
+        e
+    }
+
+    fn ids(events: Vec<Event>) -> Vec<String> {
+        events.into_iter().map(|e| e.event_id).collect()
+    }
+
+    fn write_segment_file(path: &std::path::Path, events: &[Event]) {
+        let mut zst = zstd::Encoder::new(std::fs::File::create(path).unwrap(), 0).unwrap();
+        for event in events {
+            serde_json::to_writer(&mut zst, event).unwrap();
+            zst.write_all(b"\n").unwrap();
+        }
+        zst.finish().unwrap();
+    }
+
+    #[test]
+    fn verify_chain_rehashes_rotated_events() {
+        let tmp = std::env::temp_dir().join(format!("edda_segments_verify_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+        for (text, ts) in [
+            ("cargo build", "2026-01-01T10:00:00Z"),
+            ("cargo test", "2026-01-02T10:00:00Z"),
+            ("cargo fmt", "2026-01-03T10:00:00Z"),
+            ("tip", "2026-01-04T10:00:00Z"),
+        ] {
+            append_at(&ledger, cmd(text), ts);
+        }
+        assert_eq!(ledger.rotate_events(0, false).unwrap().archived, 3);
+        ledger.verify_chain().unwrap();
+
+        let path = segments_dir(&paths).join("segment-000001.jsonl.zst");
+        let original = crate::retention::read_archive_file(&path).unwrap();
+        let err = |events: &[Event]| {
+            write_segment_file(&path, events);
+            format!("{:#}", ledger.verify_chain().unwrap_err())
+        };
+
+        let mut edited = original.clone();
+        edited[1].payload["text"] = serde_json::json!("rm -rf /");
+        assert!(err(&edited).contains("invalid hash"));
+
+        finalize_event(&mut edited[1]).unwrap();
+        assert!(err(&edited).contains("does not match its stub"));
+
+        let mut dropped = original.clone();
+        dropped.remove(1);
+        assert!(err(&dropped).contains("manifest lists 3 events"));
+
+        write_segment_file(&path, &original);
+        ledger.verify_chain().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(ledger.verify_chain().is_err(), "a missing segment fails");
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn rotated_events_stay_readable_in_order() {
+        let tmp = std::env::temp_dir().join(format!("edda_segments_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(&tmp).unwrap();
+
+        let dp = DecisionPayload {
+            key: "db.engine".into(),
+            value: "sqlite".into(),
+            reason: None,
+            scope: None,
+            authority: None,
+            affected_paths: None,
+            tags: None,
+            review_after: None,
+            reversibility: None,
+            village_id: None,
+        };
+        let decision = append_at(
+            &ledger,
+            new_decision_event("main", None, "user", &dp).unwrap(),
+            "2026-01-01T10:00:00Z",
+        );
+        let first = append_at(&ledger, cmd(&"a".repeat(500)), "2026-01-02T10:00:00Z");
+        let second = append_at(&ledger, cmd(&"b".repeat(500)), "2026-02-02T10:00:00Z");
+        let third = append_at(&ledger, cmd(&"c".repeat(500)), "2026-03-02T10:00:00Z");
+        let tip = append_at(&ledger, cmd("tip"), "2026-03-03T10:00:00Z");
+        let everything = vec![
+            decision.event_id.clone(),
+            first.event_id.clone(),
+            second.event_id.clone(),
+            third.event_id.clone(),
+            tip.event_id.clone(),
+        ];
+
+        // Under the limit: nothing moves.
+        let live = ledger.sqlite.live_payload_bytes().unwrap();
+        assert_eq!(ledger.rotate_events(live, false).unwrap().archived, 0);
+
+        // One byte over what the first cmd frees takes the two oldest cmds,
+        // since rotation counts bytes the way the limit does, and leaves the
+        // decision in the database.
+        let first_bytes = ledger.sqlite.archivable_events().unwrap()[1].2;
+        let target = live - first_bytes - 1;
+        let dry = ledger.rotate_events(target, true).unwrap();
+        assert_eq!(dry.archived, 2);
+        assert_eq!(dry.files, vec!["segment-000001.jsonl.zst"]);
+        assert!(load_manifest(&paths).unwrap().segments.is_empty());
+
+        let report = ledger.rotate_events(target, false).unwrap();
+        assert_eq!(report.archived, 2);
+        assert_eq!(report.files, vec!["segment-000001.jsonl.zst"]);
+        assert!(ledger.sqlite.live_payload_bytes().unwrap() <= target);
+        ledger.verify_chain().unwrap();
+
+        let segment = segments_dir(&paths).join("segment-000001.jsonl.zst");
+        let bytes = std::fs::read(&segment).unwrap();
+        assert_eq!(&bytes[..4], &[0x28, 0xb5, 0x2f, 0xfd], "zstd frame magic");
+        let manifest = load_manifest(&paths).unwrap();
+        assert_eq!(manifest.segments.len(), 1);
+        let entry = &manifest.segments[0];
+        assert_eq!(entry.events, 2);
+        assert_eq!(entry.first_ts, "2026-01-02T10:00:00Z");
+        assert_eq!(entry.last_ts, "2026-02-02T10:00:00Z");
+        assert_eq!(entry.size, bytes.len() as u64);
+
+        // The database holds three events; every reader still sees five.
+        assert_eq!(
+            ids(ledger.sqlite.iter_events().unwrap()),
+            vec![
+                decision.event_id.clone(),
+                third.event_id.clone(),
+                tip.event_id.clone()
+            ]
+        );
+        assert_eq!(ids(ledger.iter_events().unwrap()), everything);
+        assert_eq!(ids(ledger.iter_events_with_archive().unwrap()), everything);
+        let after_first: Vec<String> = ledger
+            .events_after_rowid(entry.first_rowid)
+            .unwrap()
+            .into_iter()
+            .map(|(_, e)| e.event_id)
+            .collect();
+        assert_eq!(after_first, everything[2..].to_vec());
+
+        // A later rotation opens the next segment.
+        let report = ledger.rotate_events(0, false).unwrap();
+        assert_eq!(report.archived, 1);
+        assert_eq!(report.files, vec!["segment-000002.jsonl.zst"]);
+        assert_eq!(load_manifest(&paths).unwrap().segments.len(), 2);
+        assert_eq!(ids(ledger.iter_events().unwrap()), everything);
+        ledger.verify_chain().unwrap();
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
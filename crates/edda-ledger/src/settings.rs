@@ -0,0 +1,170 @@
+//! Workspace settings in `.edda/config.json`, and their history.
+//!
+//! [`set_config`] writes a key and appends a `config_change` admin event
+//! with the old and new value and the ledger's actor, so a change in
+//! behavior can be traced back to the setting that caused it. Credential
+//! values are redacted before they reach the ledger (see
+//! `edda_core::event::new_config_change_event`).
+
+use std::path::Path;
+
+use edda_core::event::new_config_change_event;
+use edda_core::Event;
+use serde::Serialize;
+
+use crate::Ledger;
+
+/// Event type of a recorded config write.
+pub const CONFIG_CHANGE_EVENT: &str = "config_change";
+
+/// One recorded config write.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigChange {
+    pub event_id: String,
+    pub ts: String,
+    pub key: String,
+    /// `null` when the key was unset.
+    pub old: serde_json::Value,
+    /// `null` when the key was removed.
+    pub new: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+}
+
+impl ConfigChange {
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.event_type != CONFIG_CHANGE_EVENT {
+            return None;
+        }
+        Some(Self {
+            event_id: event.event_id.clone(),
+            ts: event.ts.clone(),
+            key: event.payload["key"].as_str()?.to_string(),
+            old: event.payload["old"].clone(),
+            new: event.payload["new"].clone(),
+            actor: event.payload["actor"].as_str().map(str::to_string),
+        })
+    }
+
+    /// One line: `ts actor: key old -> new`.
+    pub fn summary(&self) -> String {
+        let value = |v: &serde_json::Value| match v {
+            serde_json::Value::Null => "(unset)".to_string(),
+            v => v.to_string(),
+        };
+        format!(
+            "{} {}: {} {} -> {}",
+            self.ts,
+            self.actor.as_deref().unwrap_or("unknown"),
+            self.key,
+            value(&self.old),
+            value(&self.new)
+        )
+    }
+}
+
+/// Read `config.json`. A missing file, or one that is not a JSON object,
+/// reads as empty.
+pub fn read_config(path: &Path) -> anyhow::Result<serde_json::Map<String, serde_json::Value>> {
+    if !path.exists() {
+        return Ok(serde_json::Map::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    match serde_json::from_str(&content)? {
+        serde_json::Value::Object(map) => Ok(map),
+        _ => Ok(serde_json::Map::new()),
+    }
+}
+
+/// Set `key` to `value` in `config.json`, or remove it when `value` is
+/// `None`, and record the change. Returns `None` without writing anything
+/// when the value is unchanged. The caller holds the workspace lock.
+///
+/// The event is appended first, so an append the authorizer refuses leaves
+/// the config untouched.
+pub fn set_config(
+    ledger: &Ledger,
+    key: &str,
+    value: Option<serde_json::Value>,
+) -> anyhow::Result<Option<ConfigChange>> {
+    let path = &ledger.paths.config_json;
+    let mut config = read_config(path)?;
+    let old = config.get(key).cloned();
+    if old == value {
+        return Ok(None);
+    }
+
+    let branch = ledger.head_branch()?;
+    let parent_hash = ledger.last_event_hash()?;
+    let event = new_config_change_event(
+        &branch,
+        parent_hash.as_deref(),
+        key,
+        old.as_ref(),
+        value.as_ref(),
+        ledger.actor(),
+    )?;
+    ledger.append_event(&event)?;
+
+    match value {
+        Some(v) => config.insert(key.to_string(), v),
+        None => config.remove(key),
+    };
+    let json = serde_json::to_string_pretty(&config)?;
+    edda_store::write_atomic(path, json.as_bytes())?;
+    Ok(ConfigChange::from_event(&event))
+}
+
+/// The most recent config changes, newest first.
+pub fn recent_config_changes(ledger: &Ledger, limit: usize) -> anyhow::Result<Vec<ConfigChange>> {
+    Ok(ledger
+        .iter_events_by_type(CONFIG_CHANGE_EVENT)?
+        .iter()
+        .rev()
+        .filter_map(ConfigChange::from_event)
+        .take(limit)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ledger::{init_branches_json, init_head, init_workspace};
+    use crate::EddaPaths;
+
+    #[test]
+    fn set_config_records_old_and_new_values() {
+        let tmp = std::env::temp_dir().join(format!("edda_settings_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        init_workspace(&paths).unwrap();
+        init_head(&paths, "main").unwrap();
+        init_branches_json(&paths, "main").unwrap();
+        let mut ledger = Ledger::open(&tmp).unwrap();
+        ledger.set_actor(Some("alice"));
+
+        let key = "gc.retention.signal";
+        let first = set_config(&ledger, key, Some(serde_json::json!(30)))
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.old, serde_json::Value::Null);
+        assert!(set_config(&ledger, key, Some(serde_json::json!(30)))
+            .unwrap()
+            .is_none());
+        set_config(&ledger, key, Some(serde_json::json!(60))).unwrap();
+        set_config(&ledger, key, None).unwrap();
+        assert!(read_config(&paths.config_json).unwrap().is_empty());
+
+        let changes = recent_config_changes(&ledger, 2).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].old, 60);
+        assert_eq!(changes[0].new, serde_json::Value::Null);
+        assert_eq!(
+            changes[1].summary().split_once(' ').unwrap().1,
+            format!("alice: {key} 30 -> 60")
+        );
+        assert_eq!(ledger.iter_events().unwrap().len(), 3);
+
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
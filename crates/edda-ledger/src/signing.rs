@@ -0,0 +1,316 @@
+//! Optional ed25519 event signatures.
+//!
+//! The hash chain detects edits that break linkage, but anyone with write
+//! access can rewrite the chain end to end. Signing each event hash with a
+//! per-user key (`<store_root>/keys/ed25519.pk8`) makes such rewrites
+//! evident: a rewritten event no longer matches its signature, and forging a
+//! new one needs the private key.
+//!
+//! Signing is off by default. Enable it per workspace with
+//! `ledger.sign_events: true` in `.edda/config.json`, or per process with
+//! `EDDA_SIGN_EVENTS=1`. Signatures live in the `event_signatures` table,
+//! outside the hashed event body, so enabling signing never changes hashes.
+//!
+//! The public key stored next to a signature is not trusted on its own:
+//! whoever rewrites the ledger can re-sign it with a fresh key. Signatures
+//! are verified against [`TrustedKeys`] — this user's key plus the hex
+//! public keys listed under `ledger.trusted_keys` — and any other key is
+//! reported as invalid.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use edda_core::Event;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Signature algorithm recorded with each signature.
+pub const SIGNATURE_ALG: &str = "ed25519";
+
+const KEY_FILE: &str = "ed25519.pk8";
+
+/// A detached signature over an event's hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventSignature {
+    pub event_id: String,
+    pub alg: String,
+    /// Short fingerprint of `public_key` (first 16 hex chars of its SHA-256).
+    pub key_id: String,
+    /// Hex-encoded raw public key.
+    pub public_key: String,
+    /// Hex-encoded signature over the UTF-8 bytes of `Event::hash`.
+    pub signature: String,
+}
+
+/// An ed25519 key pair used to sign events at append time.
+pub struct EventSigner {
+    key_pair: Ed25519KeyPair,
+    key_id: String,
+}
+
+impl EventSigner {
+    /// Load a signer from a PKCS#8 document.
+    pub fn from_pkcs8(pkcs8: &[u8]) -> anyhow::Result<Self> {
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8)
+            .map_err(|e| anyhow::anyhow!("invalid ed25519 signing key: {e}"))?;
+        let key_id = key_id_for(key_pair.public_key().as_ref());
+        Ok(Self { key_pair, key_id })
+    }
+
+    /// Generate a fresh PKCS#8 key document.
+    pub fn generate_pkcs8() -> anyhow::Result<Vec<u8>> {
+        let doc = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+            .map_err(|_| anyhow::anyhow!("failed to generate ed25519 key"))?;
+        Ok(doc.as_ref().to_vec())
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.key_pair.public_key().as_ref())
+    }
+
+    /// Sign a finalized event.
+    pub fn sign(&self, event: &Event) -> EventSignature {
+        let sig = self.key_pair.sign(event.hash.as_bytes());
+        EventSignature {
+            event_id: event.event_id.clone(),
+            alg: SIGNATURE_ALG.to_string(),
+            key_id: self.key_id.clone(),
+            public_key: self.public_key_hex(),
+            signature: hex::encode(sig.as_ref()),
+        }
+    }
+}
+
+/// Public keys whose signatures verification accepts, by key id.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedKeys {
+    keys: BTreeMap<String, Vec<u8>>,
+}
+
+impl TrustedKeys {
+    /// Trust a raw ed25519 public key.
+    pub fn insert(&mut self, public_key: Vec<u8>) {
+        self.keys.insert(key_id_for(&public_key), public_key);
+    }
+
+    pub fn contains(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// Check a signature against the event it claims to cover, using the
+/// trusted key with the signature's key id.
+pub fn verify_event_signature(
+    event: &Event,
+    sig: &EventSignature,
+    trusted: &TrustedKeys,
+) -> anyhow::Result<()> {
+    if sig.alg != SIGNATURE_ALG {
+        anyhow::bail!("unsupported signature algorithm: {}", sig.alg);
+    }
+    let Some(public_key) = trusted.keys.get(&sig.key_id) else {
+        anyhow::bail!("signed by untrusted key {}", sig.key_id);
+    };
+    if hex::decode(&sig.public_key)? != *public_key {
+        anyhow::bail!("public key does not match trusted key {}", sig.key_id);
+    }
+    let signature = hex::decode(&sig.signature)?;
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(event.hash.as_bytes(), &signature)
+        .map_err(|_| anyhow::anyhow!("signature does not match event hash"))
+}
+
+fn key_id_for(public_key: &[u8]) -> String {
+    hex::encode(Sha256::digest(public_key))[..16].to_string()
+}
+
+// ── Key storage ──
+
+/// Directory holding the per-user signing key: `<store_root>/keys/`.
+pub fn keys_dir() -> PathBuf {
+    edda_store::store_root().join("keys")
+}
+
+/// Path of the per-user signing key (PKCS#8).
+pub fn key_path() -> PathBuf {
+    keys_dir().join(KEY_FILE)
+}
+
+/// Load the per-user signing key, creating it on first use.
+pub fn load_or_create_signer() -> anyhow::Result<EventSigner> {
+    load_or_create_signer_in(&keys_dir())
+}
+
+fn load_or_create_signer_in(dir: &Path) -> anyhow::Result<EventSigner> {
+    let path = dir.join(KEY_FILE);
+    if path.exists() {
+        return EventSigner::from_pkcs8(&std::fs::read(&path)?);
+    }
+    let pkcs8 = EventSigner::generate_pkcs8()?;
+    edda_store::write_private(&path, &pkcs8)?;
+    EventSigner::from_pkcs8(&pkcs8)
+}
+
+/// This user's signing key, without creating one.
+pub fn local_signer() -> Option<EventSigner> {
+    let pkcs8 = std::fs::read(key_path()).ok()?;
+    EventSigner::from_pkcs8(&pkcs8).ok()
+}
+
+/// The keys signatures in this workspace are verified against: this user's
+/// key (if one exists) and every hex public key in `ledger.trusted_keys`.
+pub(crate) fn trusted_keys(config_json: &Path) -> anyhow::Result<TrustedKeys> {
+    trusted_keys_in(&keys_dir(), config_json)
+}
+
+fn trusted_keys_in(dir: &Path, config_json: &Path) -> anyhow::Result<TrustedKeys> {
+    let mut trusted = TrustedKeys::default();
+    if let Ok(pkcs8) = std::fs::read(dir.join(KEY_FILE)) {
+        let signer = EventSigner::from_pkcs8(&pkcs8)?;
+        trusted.insert(signer.key_pair.public_key().as_ref().to_vec());
+    }
+    let config = match std::fs::read_to_string(config_json) {
+        Ok(s) => serde_json::from_str::<serde_json::Value>(&s)?,
+        Err(_) => return Ok(trusted),
+    };
+    let Some(listed) = config.pointer("/ledger/trusted_keys") else {
+        return Ok(trusted);
+    };
+    let listed = listed
+        .as_array()
+        .ok_or_else(|| anyhow::anyhow!("ledger.trusted_keys must be a list of public keys"))?;
+    for key in listed {
+        let public_key = key
+            .as_str()
+            .and_then(|k| hex::decode(k.trim()).ok())
+            .filter(|k| k.len() == 32)
+            .ok_or_else(|| {
+                anyhow::anyhow!("ledger.trusted_keys: {key} is not a hex ed25519 public key")
+            })?;
+        trusted.insert(public_key);
+    }
+    Ok(trusted)
+}
+
+/// Whether appends in this workspace should be signed.
+pub(crate) fn signing_enabled(config_json: &Path) -> bool {
+    if let Ok(v) = std::env::var("EDDA_SIGN_EVENTS") {
+        return matches!(v.as_str(), "1" | "true" | "on");
+    }
+    std::fs::read_to_string(config_json)
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .and_then(|v| v.pointer("/ledger/sign_events").and_then(|b| b.as_bool()))
+        .unwrap_or(false)
+}
+
+// ── Verification report ──
+
+/// Result of [`crate::Ledger::verify_signatures`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SignatureReport {
+    pub total_events: usize,
+    pub signed: usize,
+    pub unsigned: usize,
+    /// Signed event count per signing key id.
+    pub keys: BTreeMap<String, usize>,
+    /// Signing keys seen that are neither this user's nor in
+    /// `ledger.trusted_keys`. Their signatures are listed in `invalid`.
+    pub untrusted_keys: Vec<String>,
+    /// Events whose signature failed to verify, with the reason.
+    pub invalid: Vec<(String, String)>,
+    /// Unsigned events appended after the first signed one. Expected only
+    /// if signing was turned off again; otherwise a signature was removed.
+    pub gaps: Vec<String>,
+}
+
+impl SignatureReport {
+    pub fn is_ok(&self) -> bool {
+        self.invalid.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(text: &str) -> Event {
+        edda_core::event::new_note_event("main", None, "user", text, &[]).unwrap()
+    }
+
+    #[test]
+    fn sign_and_verify_roundtrip_detects_tampering() {
+        let tmp = std::env::temp_dir().join(format!("edda_signing_rt_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let signer = load_or_create_signer_in(&tmp).unwrap();
+        let again = load_or_create_signer_in(&tmp).unwrap();
+        assert_eq!(signer.key_id(), again.key_id(), "key is persisted");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(tmp.join(KEY_FILE))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600, "private key is owner-only");
+        }
+        let trusted = trusted_keys_in(&tmp, &tmp.join("no-config.json")).unwrap();
+        assert!(trusted.contains(signer.key_id()));
+
+        let ev = event("hello");
+        let sig = signer.sign(&ev);
+        verify_event_signature(&ev, &sig, &trusted).unwrap();
+
+        let mut tampered = ev.clone();
+        tampered.payload["text"] = serde_json::json!("goodbye");
+        edda_core::event::finalize_event(&mut tampered).unwrap();
+        assert!(verify_event_signature(&tampered, &sig, &trusted).is_err());
+
+        let other = EventSigner::from_pkcs8(&EventSigner::generate_pkcs8().unwrap()).unwrap();
+        let foreign = other.sign(&ev);
+        let err = verify_event_signature(&ev, &foreign, &trusted).unwrap_err();
+        assert!(err.to_string().contains("untrusted key"), "{err}");
+        let mut forged = foreign.clone();
+        forged.key_id = sig.key_id.clone();
+        assert!(verify_event_signature(&ev, &forged, &trusted).is_err());
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn trusted_keys_come_from_config() {
+        let tmp = std::env::temp_dir().join(format!("edda_signing_trust_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        std::fs::create_dir_all(&tmp).unwrap();
+        let config = tmp.join("config.json");
+        let teammate = EventSigner::from_pkcs8(&EventSigner::generate_pkcs8().unwrap()).unwrap();
+
+        let trusted = trusted_keys_in(&tmp, &config).unwrap();
+        assert!(trusted.is_empty(), "no local key, no config");
+
+        std::fs::write(
+            &config,
+            serde_json::json!({ "ledger": { "trusted_keys": [teammate.public_key_hex()] } })
+                .to_string(),
+        )
+        .unwrap();
+        let trusted = trusted_keys_in(&tmp, &config).unwrap();
+        assert!(trusted.contains(teammate.key_id()));
+        let ev = event("from a teammate");
+        verify_event_signature(&ev, &teammate.sign(&ev), &trusted).unwrap();
+
+        std::fs::write(&config, r#"{"ledger":{"trusted_keys":["not-hex"]}}"#).unwrap();
+        assert!(trusted_keys_in(&tmp, &config).is_err());
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
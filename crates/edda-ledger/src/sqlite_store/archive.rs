@@ -0,0 +1,163 @@
+//! Cold-archive bookkeeping: which events may leave `ledger.db`, and the
+//! stubs they leave behind.
+
+use edda_core::types::Event;
+use rusqlite::{params, OptionalExtension, Transaction, TransactionBehavior};
+
+use std::collections::HashMap;
+
+use super::mappers::*;
+use super::SqliteStore;
+
+/// One stub in `archived_events`.
+#[derive(Debug, Clone)]
+pub struct ArchivedStub {
+    pub rowid: i64,
+    pub event_id: String,
+    pub parent_hash: Option<String>,
+    pub hash: String,
+    /// Archive file or rotated segment holding the full event.
+    pub archive_file: String,
+}
+
+impl SqliteStore {
+    /// Events that may be moved to the archive, as `(rowid, Event, payload
+    /// bytes)` in insertion order. Payload bytes are measured the way
+    /// [`Self::live_payload_bytes`] sums them.
+    ///
+    /// Leaves out the chain tip, which the next append links to, every
+    /// event another table references (decisions, task intakes, device
+    /// pairings, decide snapshots and review bundles) and every pin target,
+    /// which `pinned_events` loads by id.
+    pub fn archivable_events(&self) -> anyhow::Result<Vec<(i64, Event, u64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT rowid, event_id, ts, event_type, branch, parent_hash, hash,
+                    payload, refs_blobs, refs_events, refs_provenance,
+                    schema_version, digests, event_family, event_level,
+                    LENGTH(CAST(payload AS BLOB))
+             FROM events
+             WHERE rowid < (SELECT MAX(rowid) FROM events)
+               AND event_id NOT IN (SELECT event_id FROM decisions)
+               AND event_id NOT IN (SELECT intake_event_id FROM task_briefs)
+               AND event_id NOT IN (SELECT pair_event_id FROM device_tokens)
+               AND event_id NOT IN (SELECT event_id FROM decide_snapshots)
+               AND event_id NOT IN (SELECT event_id FROM review_bundles)
+               AND event_id NOT IN (SELECT json_extract(payload, '$.target')
+                                    FROM events WHERE event_type = 'pin')
+             ORDER BY rowid",
+        )?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    EventRow {
+                        event_id: row.get(1)?,
+                        ts: row.get(2)?,
+                        event_type: row.get(3)?,
+                        branch: row.get(4)?,
+                        parent_hash: row.get(5)?,
+                        hash: row.get(6)?,
+                        payload_str: row.get(7)?,
+                        refs_blobs_str: row.get(8)?,
+                        refs_events_str: row.get(9)?,
+                        refs_prov_str: row.get(10)?,
+                        schema_version: row.get(11)?,
+                        digests_str: row.get(12)?,
+                        event_family: row.get(13)?,
+                        event_level: row.get(14)?,
+                    },
+                    row.get::<_, i64>(15)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(|(rowid, er, bytes)| {
+                Ok((rowid, row_to_event(er)?, u64::try_from(bytes).unwrap_or(0)))
+            })
+            .collect()
+    }
+
+    /// Replace events with archive stubs in one transaction. Each entry is
+    /// `(rowid, event, archive file name)`; the caller has already written
+    /// the events to their archive files. A signature moves to the stub.
+    pub fn move_to_archive(&self, entries: &[(i64, &Event, String)]) -> anyhow::Result<()> {
+        let tx = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?;
+        for (rowid, event, archive_file) in entries {
+            let signature: Option<String> = tx
+                .query_row(
+                    "SELECT json_object('alg', alg, 'key_id', key_id,
+                                        'public_key', public_key, 'signature', signature)
+                     FROM event_signatures WHERE event_id = ?1",
+                    params![event.event_id],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            tx.execute(
+                "INSERT OR REPLACE INTO archived_events
+                 (rowid, event_id, ts, event_type, parent_hash, hash, archive_file, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    rowid,
+                    event.event_id,
+                    event.ts,
+                    event.event_type,
+                    event.parent_hash,
+                    event.hash,
+                    archive_file,
+                    signature,
+                ],
+            )?;
+            tx.execute(
+                "DELETE FROM event_signatures WHERE event_id = ?1",
+                params![event.event_id],
+            )?;
+            tx.execute(
+                "DELETE FROM events WHERE rowid = ?1 AND event_id = ?2",
+                params![rowid, event.event_id],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Total payload bytes of the events still in `ledger.db`.
+    pub fn live_payload_bytes(&self) -> anyhow::Result<u64> {
+        let bytes: i64 = self.conn.query_row(
+            "SELECT COALESCE(SUM(LENGTH(CAST(payload AS BLOB))), 0) FROM events",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(u64::try_from(bytes).unwrap_or(0))
+    }
+
+    /// Every archive stub, in original insertion order.
+    pub fn archived_stubs(&self) -> anyhow::Result<Vec<ArchivedStub>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT rowid, event_id, parent_hash, hash, archive_file
+             FROM archived_events ORDER BY rowid",
+        )?;
+        let stubs = stmt
+            .query_map([], |row| {
+                Ok(ArchivedStub {
+                    rowid: row.get(0)?,
+                    event_id: row.get(1)?,
+                    parent_hash: row.get(2)?,
+                    hash: row.get(3)?,
+                    archive_file: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(stubs)
+    }
+
+    /// Original rowid of every archived event, keyed by event_id.
+    pub fn archived_rowids(&self) -> anyhow::Result<HashMap<String, i64>> {
+        Ok(self
+            .archived_stubs()?
+            .into_iter()
+            .map(|s| (s.event_id, s.rowid))
+            .collect())
+    }
+}
@@ -70,7 +70,7 @@ impl SqliteStore {
             sql.push_str(&format!(" LIMIT {lim}"));
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let mut stmt = self.conn.prepare_cached(&sql)?;
         let params_ref: Vec<&dyn rusqlite::types::ToSql> =
             param_values.iter().map(|p| p.as_ref()).collect();
         let rows = stmt.query_map(params_ref.as_slice(), map_decision_row)?;
@@ -127,7 +127,7 @@ impl SqliteStore {
             param_values.push(Box::new(lim as i64));
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let mut stmt = self.conn.prepare_cached(&sql)?;
         let refs: Vec<&dyn rusqlite::types::ToSql> =
             param_values.iter().map(|b| b.as_ref()).collect();
         let rows = stmt.query_map(refs.as_slice(), map_decision_row)?;
@@ -179,7 +179,7 @@ impl SqliteStore {
             sql.push_str(" ORDER BY e.ts");
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let mut stmt = self.conn.prepare_cached(&sql)?;
         let params_ref: Vec<&dyn rusqlite::types::ToSql> =
             param_values.iter().map(|p| p.as_ref()).collect();
         let rows = stmt.query_map(params_ref.as_slice(), map_decision_row)?;
@@ -187,6 +187,70 @@ impl SqliteStore {
             .map_err(|e| anyhow::anyhow!("decision timeline query failed: {e}"))
     }
 
+    /// Every decision (active, superseded, revoked) on each key whose key,
+    /// value, or reason ever mentioned `keyword`, ordered by key then time.
+    /// Whole keys are returned so the values that replaced a match are there
+    /// to date it.
+    pub fn decision_history(
+        &self,
+        keyword: &str,
+        after: Option<&str>,
+        before: Option<&str>,
+    ) -> anyhow::Result<Vec<DecisionRow>> {
+        let mut sql = String::from(
+            "SELECT d.event_id, d.key, d.value, d.reason, d.domain, d.branch,
+                    d.supersedes_id, d.is_active, e.ts,
+                    d.scope, d.source_project_id, d.source_event_id,
+                    d.status, d.authority, d.affected_paths, d.tags, d.review_after, d.reversibility, d.village_id
+             FROM decisions d JOIN events e ON d.event_id = e.event_id
+             WHERE d.key IN (
+                 SELECT key FROM decisions
+                 WHERE key LIKE ?1 OR value LIKE ?1 OR reason LIKE ?1
+             )",
+        );
+
+        let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+        param_values.push(Box::new(format!("%{keyword}%")));
+        let mut idx = 2;
+
+        if let Some(a) = after {
+            sql.push_str(&format!(" AND e.ts >= ?{idx}"));
+            param_values.push(Box::new(a.to_string()));
+            idx += 1;
+        }
+        if let Some(b) = before {
+            sql.push_str(&format!(" AND e.ts <= ?{idx}"));
+            param_values.push(Box::new(b.to_string()));
+            let _ = idx + 1;
+        }
+
+        sql.push_str(" ORDER BY d.key, e.ts, e.rowid");
+
+        let mut stmt = self.conn.prepare_cached(&sql)?;
+        let params_ref: Vec<&dyn rusqlite::types::ToSql> =
+            param_values.iter().map(|p| p.as_ref()).collect();
+        let rows = stmt.query_map(params_ref.as_slice(), map_decision_row)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("decision history query failed: {e}"))
+    }
+
+    /// Every decision recorded at or before `until` (ISO 8601), in append
+    /// order, whatever its state now.
+    pub fn decisions_until(&self, until: &str) -> anyhow::Result<Vec<DecisionRow>> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT d.event_id, d.key, d.value, d.reason, d.domain, d.branch,
+                    d.supersedes_id, d.is_active, e.ts,
+                    d.scope, d.source_project_id, d.source_event_id,
+                    d.status, d.authority, d.affected_paths, d.tags, d.review_after, d.reversibility, d.village_id
+             FROM decisions d JOIN events e ON d.event_id = e.event_id
+             WHERE e.ts <= ?1
+             ORDER BY e.ts, e.rowid",
+        )?;
+        let rows = stmt.query_map(params![until], map_decision_row)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow::anyhow!("decisions_until query failed: {e}"))
+    }
+
     /// All decisions for a domain (active + superseded), ordered by time.
     /// `after`/`before` are optional ISO 8601 bounds for temporal filtering.
     pub fn domain_timeline(
@@ -227,7 +291,7 @@ impl SqliteStore {
             sql.push_str(" ORDER BY e.ts");
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
+        let mut stmt = self.conn.prepare_cached(&sql)?;
         let params_ref: Vec<&dyn rusqlite::types::ToSql> =
             param_values.iter().map(|p| p.as_ref()).collect();
         let rows = stmt.query_map(params_ref.as_slice(), map_decision_row)?;
@@ -237,7 +301,7 @@ impl SqliteStore {
 
     /// Distinct domain values from active decisions.
     pub fn list_domains(&self) -> anyhow::Result<Vec<String>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "SELECT DISTINCT domain FROM decisions WHERE is_active = TRUE ORDER BY domain",
         )?;
         let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
@@ -251,13 +315,14 @@ impl SqliteStore {
         branch: &str,
         key: &str,
     ) -> anyhow::Result<Option<DecisionRow>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "SELECT d.event_id, d.key, d.value, d.reason, d.domain, d.branch,
                     d.supersedes_id, d.is_active, e.ts,
                     d.scope, d.source_project_id, d.source_event_id,
                     d.status, d.authority, d.affected_paths, d.tags, d.review_after, d.reversibility, d.village_id
              FROM decisions d JOIN events e ON d.event_id = e.event_id
              WHERE d.key = ?1 AND d.branch = ?2 AND d.is_active = TRUE
+             ORDER BY e.ts DESC
              LIMIT 1",
         )?;
         let result = stmt
@@ -272,7 +337,7 @@ impl SqliteStore {
 
     /// Look up a single decision by its event_id.
     pub fn get_decision_by_event_id(&self, event_id: &str) -> anyhow::Result<Option<DecisionRow>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "SELECT d.event_id, d.key, d.value, d.reason, d.domain, d.branch,
                     d.supersedes_id, d.is_active, e.ts,
                     d.scope, d.source_project_id, d.source_event_id,
@@ -295,7 +360,7 @@ impl SqliteStore {
     /// Query active decisions with shared or global scope.
     /// Used by the sync engine to find decisions that should be shared.
     pub fn shared_decisions(&self) -> anyhow::Result<Vec<DecisionRow>> {
-        let mut stmt = self.conn.prepare(
+        let mut stmt = self.conn.prepare_cached(
             "SELECT d.event_id, d.key, d.value, d.reason, d.domain, d.branch,
                     d.supersedes_id, d.is_active, e.ts,
                     d.scope, d.source_project_id, d.source_event_id,
@@ -0,0 +1,184 @@
+//! Query planner review for `edda doctor ledger`.
+//!
+//! Runs the ledger's hot read paths under `EXPLAIN QUERY PLAN`, times them
+//! against the real data, and checks that every index the schema registry
+//! promises is actually present. A ledger whose migrations half-applied, or
+//! whose indexes were dropped by hand, shows up here as full scans rather
+//! than as a vague "edda feels slow".
+
+use rusqlite::{params, OptionalExtension};
+use std::time::Instant;
+
+use super::schema::{index_exists, MIGRATIONS};
+use super::SqliteStore;
+use crate::domain::{LedgerDiagnostics, QueryCheck};
+
+/// A probe taking longer than this is reported as slow.
+pub const SLOW_QUERY_MS: f64 = 50.0;
+
+/// Indexes created by the v1 base schema, which has no registry entry.
+const BASE_INDEXES: &[&str] = &[
+    "idx_events_branch",
+    "idx_events_type",
+    "idx_events_branch_type",
+    "idx_events_ts",
+    "idx_events_branch_ts",
+];
+
+/// Hot read paths, in the shape the store issues them. Each takes the
+/// parameters `(domain, key, branch)`; unused ones are ignored by SQLite.
+const PROBES: &[(&str, &str)] = &[
+    (
+        "active decisions by domain",
+        "SELECT d.event_id FROM decisions d JOIN events e ON d.event_id = e.event_id
+         WHERE d.is_active = TRUE AND d.domain = ?1 AND ?2 IS NOT NULL AND ?3 IS NOT NULL
+         ORDER BY d.domain, d.key",
+    ),
+    (
+        "active decisions by keyword",
+        "SELECT d.event_id FROM decisions d JOIN events e ON d.event_id = e.event_id
+         WHERE d.is_active = TRUE AND (d.key LIKE '%' || ?2 || '%' OR d.value LIKE '%' || ?2 || '%')
+           AND ?1 IS NOT NULL AND ?3 IS NOT NULL
+         ORDER BY d.domain, d.key",
+    ),
+    (
+        "active decision for key on branch",
+        "SELECT d.event_id FROM decisions d JOIN events e ON d.event_id = e.event_id
+         WHERE d.key = ?2 AND d.branch = ?3 AND d.is_active = TRUE AND ?1 IS NOT NULL
+         ORDER BY e.ts DESC LIMIT 1",
+    ),
+    (
+        "decision timeline for key",
+        "SELECT d.event_id FROM decisions d JOIN events e ON d.event_id = e.event_id
+         WHERE d.key = ?2 AND ?1 IS NOT NULL AND ?3 IS NOT NULL
+         ORDER BY e.ts",
+    ),
+    (
+        "active decisions on branch",
+        "SELECT d.event_id FROM decisions d
+         WHERE d.branch = ?3 AND d.is_active = TRUE AND ?1 IS NOT NULL AND ?2 IS NOT NULL",
+    ),
+    (
+        "recent events on branch",
+        "SELECT event_id FROM events
+         WHERE branch = ?3 AND ?1 IS NOT NULL AND ?2 IS NOT NULL
+         ORDER BY ts DESC LIMIT 50",
+    ),
+];
+
+impl SqliteStore {
+    /// Plan and time the hot read paths, and list registry indexes that are
+    /// missing from the database.
+    pub fn diagnose_queries(&self) -> anyhow::Result<LedgerDiagnostics> {
+        let mut missing_indexes = Vec::new();
+        let expected = BASE_INDEXES
+            .iter()
+            .chain(MIGRATIONS.iter().flat_map(|m| m.expect_indexes));
+        for name in expected {
+            if !index_exists(&self.conn, name)? {
+                missing_indexes.push(name.to_string());
+            }
+        }
+
+        let decisions: usize =
+            self.conn
+                .query_row("SELECT COUNT(*) FROM decisions", [], |row| row.get(0))?;
+
+        // Probe with values that exist, so timings reflect real fan-out.
+        let (domain, key, branch): (String, String, String) = self
+            .conn
+            .query_row(
+                "SELECT domain, key, branch FROM decisions WHERE is_active = TRUE LIMIT 1",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()?
+            .unwrap_or_else(|| (String::new(), String::new(), "main".to_string()));
+
+        let mut queries = Vec::with_capacity(PROBES.len());
+        for (name, sql) in PROBES {
+            let mut explain = self.conn.prepare(&format!("EXPLAIN QUERY PLAN {sql}"))?;
+            let plan: Vec<String> = explain
+                .query_map(params![domain, key, branch], |row| row.get::<_, String>(3))?
+                .collect::<Result<_, _>>()?;
+            let full_scan = plan.iter().any(|step| is_full_scan(step));
+
+            let mut stmt = self.conn.prepare(sql)?;
+            let start = Instant::now();
+            let rows = stmt
+                .query_map(params![domain, key, branch], |_| Ok(()))?
+                .count();
+            let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            queries.push(QueryCheck {
+                name: name.to_string(),
+                plan,
+                full_scan,
+                elapsed_ms,
+                rows,
+                slow: elapsed_ms > SLOW_QUERY_MS,
+            });
+        }
+
+        Ok(LedgerDiagnostics {
+            schema_version: self.schema_version()?,
+            decisions,
+            missing_indexes,
+            queries,
+        })
+    }
+}
+
+/// `SCAN d` reads the whole table; `SCAN d USING INDEX ...` walks an index
+/// (for decisions, the partial active-only one) and is fine.
+fn is_full_scan(step: &str) -> bool {
+    step.starts_with("SCAN ") && !step.contains(" USING ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy_ledger_uses_indexes_and_reports_dropped_ones() {
+        let dir =
+            std::env::temp_dir().join(format!("edda_diagnostics_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let store = SqliteStore::open_or_create(&dir.join("ledger.db")).unwrap();
+
+        let report = store.diagnose_queries().unwrap();
+        assert!(report.missing_indexes.is_empty(), "{report:?}");
+        assert_eq!(report.queries.len(), PROBES.len());
+        let scans: Vec<&str> = report
+            .queries
+            .iter()
+            .filter(|q| q.full_scan)
+            .map(|q| q.name.as_str())
+            .collect();
+        assert!(scans.is_empty(), "unexpected full scans: {scans:?}");
+
+        store
+            .conn
+            .execute_batch(
+                "DROP INDEX idx_decisions_branch_active; DROP INDEX idx_decisions_branch_key;",
+            )
+            .unwrap();
+        let report = store.diagnose_queries().unwrap();
+        assert_eq!(
+            report.missing_indexes,
+            ["idx_decisions_branch_key", "idx_decisions_branch_active"]
+        );
+        assert!(!report.is_healthy());
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn scan_detection_ignores_index_walks() {
+        assert!(is_full_scan("SCAN d"));
+        assert!(!is_full_scan("SCAN d USING INDEX idx_decisions_active"));
+        assert!(!is_full_scan(
+            "SEARCH d USING INDEX idx_decisions_key (key=?)"
+        ));
+    }
+}
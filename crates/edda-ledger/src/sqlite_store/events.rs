@@ -4,11 +4,42 @@ use edda_core::event::finalize_event;
 use edda_core::types::Event;
 use rusqlite::{params, Connection, OptionalExtension, Transaction, TransactionBehavior};
 
+use std::collections::HashMap;
+
+use crate::domain::{EventFilter, StaleParent};
+use crate::signing::EventSignature;
+
 use super::mappers::*;
 use super::status_to_is_active;
 use super::SqliteStore;
 
-fn validate_event_hash(event: &Event) -> anyhow::Result<()> {
+/// `WHERE` clause and parameters for an [`EventFilter`].
+fn filter_clause(filter: &EventFilter<'_>) -> (String, Vec<Box<dyn rusqlite::types::ToSql>>) {
+    let mut sql = String::from("branch = ?");
+    let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+    param_values.push(Box::new(filter.branch.to_string()));
+
+    if let Some(et) = filter.event_type {
+        sql.push_str(" AND event_type = ?");
+        param_values.push(Box::new(et.to_string()));
+    }
+    if let Some(kw) = filter.keyword {
+        sql.push_str(" AND LOWER(payload) LIKE ?");
+        let pattern = format!("%{}%", kw.to_lowercase());
+        param_values.push(Box::new(pattern));
+    }
+    if let Some(a) = filter.after {
+        sql.push_str(" AND ts >= ?");
+        param_values.push(Box::new(a.to_string()));
+    }
+    if let Some(b) = filter.before {
+        sql.push_str(" AND ts <= ?");
+        param_values.push(Box::new(b.to_string()));
+    }
+    (sql, param_values)
+}
+
+pub(crate) fn validate_event_hash(event: &Event) -> anyhow::Result<()> {
     let mut canonical = event.clone();
     finalize_event(&mut canonical)?;
     if event.event_family != canonical.event_family || event.event_level != canonical.event_level {
@@ -29,12 +60,12 @@ pub(super) fn validate_event_for_append(conn: &Connection, event: &Event) -> any
         )
         .optional()?;
     if event.parent_hash != current_tail {
-        anyhow::bail!(
-            "event {} has stale parent_hash: expected {:?}, got {:?}",
-            event.event_id,
-            current_tail,
-            event.parent_hash,
-        );
+        return Err(StaleParent {
+            event_id: event.event_id.clone(),
+            expected: current_tail,
+            got: event.parent_hash.clone(),
+        }
+        .into());
     }
 
     validate_event_hash(event)
@@ -77,11 +108,22 @@ fn materialize_snapshot(conn: &Connection, event: &Event) -> anyhow::Result<()>
 }
 
 impl SqliteStore {
+    /// Unsigned [`Self::append_event_signed`].
+    #[cfg(test)]
+    pub fn append_event(&self, event: &Event) -> anyhow::Result<()> {
+        self.append_event_signed(event, None)
+    }
+
     /// Append an event. Append-only (CONTRACT LEDGER-02).
     ///
     /// If the event is a decision (note with `"decision"` tag), the `decisions`
-    /// table is also updated atomically within the same transaction.
-    pub fn append_event(&self, event: &Event) -> anyhow::Result<()> {
+    /// table is also updated atomically within the same transaction, as is
+    /// `signature` when given.
+    pub fn append_event_signed(
+        &self,
+        event: &Event,
+        signature: Option<&EventSignature>,
+    ) -> anyhow::Result<()> {
         let payload = serde_json::to_string(&event.payload)?;
         let refs_blobs = serde_json::to_string(&event.refs.blobs)?;
         let refs_events = serde_json::to_string(&event.refs.events)?;
@@ -129,17 +171,26 @@ impl SqliteStore {
                     .find(|p| p.rel == "supersedes")
                     .map(|p| p.target.as_str());
 
-                // Deactivate prior decision with same key on same branch
-                tx.execute(
-                    "UPDATE decisions SET is_active = FALSE, status = 'superseded'
-                     WHERE key = ?1 AND branch = ?2 AND is_active = TRUE",
-                    params![key, event.branch],
-                )?;
-
-                let scope_str = dp
-                    .scope
-                    .unwrap_or(edda_core::types::DecisionScope::Local)
-                    .to_string();
+                let scope = dp.scope.unwrap_or_default();
+                let scope_str = scope.to_string();
+
+                // Deactivate prior decision with same key on same branch. A
+                // session or branch decision only replaces decisions at its
+                // own scope or narrower; wider ones stay active underneath.
+                if scope.is_narrow() {
+                    tx.execute(
+                        "UPDATE decisions SET is_active = FALSE, status = 'superseded'
+                         WHERE key = ?1 AND branch = ?2 AND is_active = TRUE
+                           AND scope IN ('session', ?3)",
+                        params![key, event.branch, scope_str],
+                    )?;
+                } else {
+                    tx.execute(
+                        "UPDATE decisions SET is_active = FALSE, status = 'superseded'
+                         WHERE key = ?1 AND branch = ?2 AND is_active = TRUE",
+                        params![key, event.branch],
+                    )?;
+                }
 
                 // Read new V10 fields from payload, with safe defaults
                 let status = "active";
@@ -197,6 +248,20 @@ impl SqliteStore {
             }
         }
 
+        // A revocation deactivates the revoked decision; the key stays
+        // inactive until it is decided again.
+        if event.event_type == "revoke" {
+            for p in &event.refs.provenance {
+                if p.rel == edda_core::types::rel::REVOKES {
+                    tx.execute(
+                        "UPDATE decisions SET is_active = FALSE, status = 'revoked'
+                         WHERE event_id = ?1",
+                        params![p.target],
+                    )?;
+                }
+            }
+        }
+
         // Materialize review bundle if applicable
         if event.event_type == "review_bundle" {
             materialize_bundle_sql(
@@ -238,10 +303,46 @@ impl SqliteStore {
             materialize_snapshot(&tx, event)?;
         }
 
+        if let Some(sig) = signature {
+            tx.execute(
+                "INSERT INTO event_signatures (event_id, alg, key_id, public_key, signature)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    sig.event_id,
+                    sig.alg,
+                    sig.key_id,
+                    sig.public_key,
+                    sig.signature
+                ],
+            )?;
+        }
+
         tx.commit()?;
         Ok(())
     }
 
+    /// All stored event signatures, keyed by event_id.
+    pub fn event_signatures(&self) -> anyhow::Result<HashMap<String, EventSignature>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT event_id, alg, key_id, public_key, signature FROM event_signatures")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(EventSignature {
+                event_id: row.get(0)?,
+                alg: row.get(1)?,
+                key_id: row.get(2)?,
+                public_key: row.get(3)?,
+                signature: row.get(4)?,
+            })
+        })?;
+        let mut out = HashMap::new();
+        for row in rows {
+            let sig = row?;
+            out.insert(sig.event_id.clone(), sig);
+        }
+        Ok(out)
+    }
+
     /// Append an event idempotently. Returns `true` if inserted, `false` if duplicate.
     ///
     /// Duplicate `event_id` values are skipped without returning an error. New
@@ -350,6 +451,23 @@ impl SqliteStore {
         events.into_iter().map(row_to_event).collect()
     }
 
+    /// All `draft.*` and `approval` events in insertion order (draft fold input).
+    pub fn iter_draft_events(&self) -> anyhow::Result<Vec<Event>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT event_id, ts, event_type, branch, parent_hash, hash,
+                    payload, refs_blobs, refs_events, refs_provenance,
+                    schema_version, digests, event_family, event_level
+             FROM events WHERE event_type LIKE 'draft.%' OR event_type = 'approval'
+             ORDER BY rowid",
+        )?;
+
+        let events = stmt
+            .query_map([], map_event_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        events.into_iter().map(row_to_event).collect()
+    }
+
     /// Get all events for a specific branch, filtered at the SQL level using `idx_events_branch`.
     pub fn iter_branch_events(&self, branch: &str) -> anyhow::Result<Vec<Event>> {
         let mut stmt = self.conn.prepare(
@@ -379,31 +497,34 @@ impl SqliteStore {
         before: Option<&str>,
         limit: usize,
     ) -> anyhow::Result<Vec<Event>> {
-        let mut sql = String::from(
+        let filter = EventFilter {
+            branch,
+            event_type,
+            keyword,
+            after,
+            before,
+        };
+        self.page_events_filtered(&filter, None, limit)
+    }
+
+    /// Like [`Self::iter_events_filtered`], but only events inserted before
+    /// the event with rowid `before_rowid`, for paging.
+    pub fn page_events_filtered(
+        &self,
+        filter: &EventFilter<'_>,
+        before_rowid: Option<i64>,
+        limit: usize,
+    ) -> anyhow::Result<Vec<Event>> {
+        let (clause, mut param_values) = filter_clause(filter);
+        let mut sql = format!(
             "SELECT event_id, ts, event_type, branch, parent_hash, hash,
                     payload, refs_blobs, refs_events, refs_provenance,
                     schema_version, digests, event_family, event_level
-             FROM events WHERE branch = ?",
+             FROM events WHERE {clause}"
         );
-        let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
-        param_values.push(Box::new(branch.to_string()));
-
-        if let Some(et) = event_type {
-            sql.push_str(" AND event_type = ?");
-            param_values.push(Box::new(et.to_string()));
-        }
-        if let Some(kw) = keyword {
-            sql.push_str(" AND LOWER(payload) LIKE ?");
-            let pattern = format!("%{}%", kw.to_lowercase());
-            param_values.push(Box::new(pattern));
-        }
-        if let Some(a) = after {
-            sql.push_str(" AND ts >= ?");
-            param_values.push(Box::new(a.to_string()));
-        }
-        if let Some(b) = before {
-            sql.push_str(" AND ts <= ?");
-            param_values.push(Box::new(b.to_string()));
+        if let Some(rowid) = before_rowid {
+            sql.push_str(" AND rowid < ?");
+            param_values.push(Box::new(rowid));
         }
         sql.push_str(" ORDER BY rowid DESC LIMIT ?");
         param_values.push(Box::new(limit as i64));
@@ -419,6 +540,31 @@ impl SqliteStore {
         events.into_iter().map(row_to_event).collect()
     }
 
+    /// Number of events matching `filter`.
+    pub fn count_events_filtered(&self, filter: &EventFilter<'_>) -> anyhow::Result<usize> {
+        let (clause, param_values) = filter_clause(filter);
+        let param_refs: Vec<&dyn rusqlite::types::ToSql> =
+            param_values.iter().map(|p| p.as_ref()).collect();
+        let count: i64 = self.conn.query_row(
+            &format!("SELECT COUNT(*) FROM events WHERE {clause}"),
+            param_refs.as_slice(),
+            |row| row.get(0),
+        )?;
+        Ok(count as usize)
+    }
+
+    /// The rowid of an event, if it is in `events`.
+    pub fn event_rowid(&self, event_id: &str) -> anyhow::Result<Option<i64>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT rowid FROM events WHERE event_id = ?1",
+                params![event_id],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
     /// Find commit events related to a query by evidence chain or keyword match.
     ///
     /// Uses `idx_events_type` for `event_type = 'commit'` filtering.
@@ -620,6 +766,34 @@ impl SqliteStore {
         Ok(result)
     }
 
+    /// Whether an event with this hash is in the chain, live or archived.
+    pub fn has_event_hash(&self, hash: &str) -> anyhow::Result<bool> {
+        let found = self
+            .conn
+            .query_row(
+                "SELECT 1 FROM events WHERE hash = ?1
+                 UNION ALL SELECT 1 FROM archived_events WHERE hash = ?1
+                 LIMIT 1",
+                [hash],
+                |_| Ok(()),
+            )
+            .optional()?;
+        Ok(found.is_some())
+    }
+
+    /// Get the timestamp of the last event.
+    pub fn last_event_ts(&self) -> anyhow::Result<Option<String>> {
+        let result: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT ts FROM events ORDER BY rowid DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(result)
+    }
+
     // ── Refs ────────────────────────────────────────────────────────
 
     /// Read the current HEAD branch name.
@@ -668,35 +842,56 @@ impl SqliteStore {
     ///
     /// Returns `Ok(())` if the chain is valid: the first event has
     /// `parent_hash == None`, and each subsequent event's `parent_hash`
-    /// matches the previous event's `hash`.
+    /// matches the previous event's `hash`. Archived events take part through
+    /// their stubs, which carry both hashes but no payload to re-hash.
     ///
     /// Returns `Err` describing the first break found.
     pub fn verify_chain(&self) -> anyhow::Result<()> {
-        let events = self.iter_events()?;
-        if events.is_empty() {
-            return Ok(());
-        }
-
-        for event in &events {
+        let events = self.events_after_rowid(0)?;
+        for (_, event) in &events {
             validate_event_hash(event)?;
         }
 
+        let mut links: Vec<(i64, &str, Option<&str>, &str)> = events
+            .iter()
+            .map(|(rowid, e)| {
+                (
+                    *rowid,
+                    e.event_id.as_str(),
+                    e.parent_hash.as_deref(),
+                    e.hash.as_str(),
+                )
+            })
+            .collect();
+        let stubs = self.archived_stubs()?;
+        links.extend(stubs.iter().map(|s| {
+            (
+                s.rowid,
+                s.event_id.as_str(),
+                s.parent_hash.as_deref(),
+                s.hash.as_str(),
+            )
+        }));
+        links.sort_by_key(|(rowid, ..)| *rowid);
+
+        let Some(&(_, first_id, first_parent, _)) = links.first() else {
+            return Ok(());
+        };
+
         // First event must have no parent
-        if events[0].parent_hash.is_some() {
+        if first_parent.is_some() {
             anyhow::bail!(
-                "chain break at first event {}: expected parent_hash=None, got {:?}",
-                events[0].event_id,
-                events[0].parent_hash,
+                "chain break at first event {first_id}: expected parent_hash=None, got {first_parent:?}",
             );
         }
 
-        for i in 1..events.len() {
-            let expected = Some(events[i - 1].hash.as_str());
-            let actual = events[i].parent_hash.as_deref();
+        for i in 1..links.len() {
+            let expected = Some(links[i - 1].3);
+            let (_, event_id, actual, _) = links[i];
             if actual != expected {
                 anyhow::bail!(
                     "chain break at event {} (index {}): expected parent_hash={:?}, got {:?}",
-                    events[i].event_id,
+                    event_id,
                     i,
                     expected,
                     actual,
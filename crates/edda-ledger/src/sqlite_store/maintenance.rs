@@ -0,0 +1,33 @@
+//! Housekeeping for `edda maintenance`: fold the WAL back into the database,
+//! return free pages to the OS, and refresh planner statistics.
+
+use super::SqliteStore;
+
+impl SqliteStore {
+    /// `(freelist pages, page size)`.
+    pub(crate) fn page_stats(&self) -> anyhow::Result<(u64, u64)> {
+        let free: u64 = self
+            .conn
+            .query_row("PRAGMA freelist_count", [], |r| r.get(0))?;
+        let size: u64 = self.conn.query_row("PRAGMA page_size", [], |r| r.get(0))?;
+        Ok((free, size))
+    }
+
+    /// Checkpoint and truncate the WAL. Returns `(busy, frames checkpointed)`;
+    /// `busy` means another connection kept part of the log alive.
+    pub(crate) fn checkpoint_wal(&self) -> anyhow::Result<(bool, u64)> {
+        let (busy, checkpointed): (i64, i64) =
+            self.conn
+                .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |r| {
+                    Ok((r.get(0)?, r.get(2)?))
+                })?;
+        Ok((busy != 0, checkpointed.max(0) as u64))
+    }
+
+    /// Rebuild the database file without free pages, then re-gather the
+    /// statistics the query planner uses.
+    pub(crate) fn vacuum_analyze(&self) -> anyhow::Result<()> {
+        self.conn.execute_batch("VACUUM; ANALYZE;")?;
+        Ok(())
+    }
+}
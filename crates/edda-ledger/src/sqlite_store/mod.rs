@@ -3,15 +3,21 @@
 //! Replaces the file-based storage (events.jsonl, refs/HEAD, refs/branches.json)
 //! with a single `ledger.db` SQLite file using WAL mode.
 
+mod archive;
 mod decisions;
 mod dependencies;
+mod diagnostics;
 mod entities;
 mod events;
+mod maintenance;
 mod mappers;
 mod schema;
 pub mod types;
 mod village;
 
+pub(crate) use archive::ArchivedStub;
+pub(crate) use events::validate_event_hash;
+pub(crate) use schema::LATEST_SCHEMA_VERSION;
 pub use types::*;
 
 use rusqlite::Connection;
@@ -358,7 +364,7 @@ mod tests {
         drop(store);
 
         let reopened = SqliteStore::open_or_create(&db_path).unwrap();
-        assert_eq!(reopened.schema_version().unwrap(), 12);
+        assert_eq!(reopened.schema_version().unwrap(), 15);
         drop(reopened);
 
         let _ = std::fs::remove_dir_all(&dir);
@@ -389,7 +395,7 @@ mod tests {
         drop(store);
 
         let reopened = SqliteStore::open_or_create(&db_path).unwrap();
-        assert_eq!(reopened.schema_version().unwrap(), 12);
+        assert_eq!(reopened.schema_version().unwrap(), 15);
         let sentinel: String = reopened
             .conn
             .query_row(
@@ -431,7 +437,7 @@ mod tests {
         drop(store);
 
         let reopened = SqliteStore::open_or_create(&db_path).unwrap();
-        assert_eq!(reopened.schema_version().unwrap(), 12);
+        assert_eq!(reopened.schema_version().unwrap(), 15);
         assert!(table_columns(&reopened.conn, "decisions")
             .unwrap()
             .contains("village_id"));
@@ -440,6 +446,91 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn migration_registry_is_contiguous_and_checksummed() {
+        let versions: Vec<u32> = MIGRATIONS.iter().map(|m| m.version).collect();
+        let expected: Vec<u32> = (2..=15).collect();
+        assert_eq!(versions, expected);
+
+        let (dir, store) = tmp_db();
+        let recorded: Vec<(u32, String)> = store
+            .conn
+            .prepare("SELECT version, checksum FROM schema_migrations ORDER BY version")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(recorded.len(), MIGRATIONS.len());
+        for ((version, checksum), m) in recorded.iter().zip(MIGRATIONS) {
+            assert_eq!(*version, m.version);
+            assert_eq!(checksum, &m.checksum());
+        }
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn migration_failing_postcondition_rolls_back_and_keeps_version() {
+        let (dir, store) = tmp_db();
+        let bogus = Migration {
+            version: 16,
+            name: "bogus",
+            add_columns: &[],
+            sql: "CREATE TABLE IF NOT EXISTS bogus_table (id TEXT PRIMARY KEY);",
+            backfill: None,
+            expect_columns: &[("bogus_table", &["id", "missing_col"])],
+            expect_indexes: &["idx_bogus_missing"],
+        };
+
+        let err = run_migration(&store.conn, &bogus).unwrap_err().to_string();
+        assert!(err.contains("v16 (bogus)"), "{err}");
+        assert!(err.contains("column bogus_table.missing_col"), "{err}");
+        assert!(err.contains("index idx_bogus_missing"), "{err}");
+
+        assert_eq!(store.schema_version().unwrap(), 15);
+        assert!(table_columns(&store.conn, "bogus_table")
+            .unwrap()
+            .is_empty());
+        let recorded: i64 = store
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM schema_migrations WHERE version = 16",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(recorded, 0);
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn migration_rerun_records_checksum_for_repaired_step() {
+        let (dir, store) = tmp_db();
+        store
+            .conn
+            .execute("DELETE FROM schema_migrations WHERE version >= 11", [])
+            .unwrap();
+        store.set_schema_version(10).unwrap();
+        let db_path = dir.join("ledger.db");
+        drop(store);
+
+        let reopened = SqliteStore::open_or_create(&db_path).unwrap();
+        assert_eq!(reopened.schema_version().unwrap(), 15);
+        let versions: Vec<u32> = reopened
+            .conn
+            .prepare("SELECT version FROM schema_migrations WHERE version >= 11 ORDER BY version")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(versions, vec![11, 12, 13, 14, 15]);
+        drop(reopened);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     // ── Decision tests ──────────────────────────────────────────────
 
     fn make_decision_event(
@@ -724,6 +815,57 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn narrow_scope_does_not_supersede_project_decision() {
+        let (dir, store) = tmp_db();
+        let scoped = |value: &str, scope: Option<&str>, ts: &str| {
+            let mut e = make_decision_event_at("main", "db.engine", value, ts);
+            if let Some(s) = scope {
+                e.payload["decision"]["scope"] = serde_json::json!(s);
+                edda_core::event::finalize_event(&mut e).unwrap();
+            }
+            e
+        };
+        store
+            .append_event(&scoped("postgres", None, "2026-01-01T00:00:00Z"))
+            .unwrap();
+        store
+            .append_event(&scoped("sqlite", Some("session"), "2026-01-02T00:00:00Z"))
+            .unwrap();
+        store
+            .append_event(&scoped("duckdb", Some("session"), "2026-01-03T00:00:00Z"))
+            .unwrap();
+
+        // The project decision stays active under the latest session experiment.
+        let active = store
+            .active_decisions(None, None, None, None, None)
+            .unwrap();
+        let mut values: Vec<(&str, &str)> = active
+            .iter()
+            .map(|d| (d.value.as_str(), d.scope.as_str()))
+            .collect();
+        values.sort();
+        assert_eq!(values, [("duckdb", "session"), ("postgres", "local")]);
+        let current = store
+            .find_active_decision("main", "db.engine")
+            .unwrap()
+            .unwrap();
+        assert_eq!(current.value, "duckdb");
+
+        // A new project decision replaces every layer.
+        store
+            .append_event(&scoped("mysql", None, "2026-01-04T00:00:00Z"))
+            .unwrap();
+        let active = store
+            .active_decisions(None, None, None, None, None)
+            .unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].value, "mysql");
+
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn schema_migration_v1_to_v2() {
         let n = COUNTER.fetch_add(1, Ordering::SeqCst);
@@ -821,6 +963,52 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn decision_history_returns_whole_keys_that_ever_matched() {
+        let (dir, store) = tmp_db();
+        let d1 = make_decision_event("main", "cache.backend", "redis", Some("fast"), None);
+        let d1_id = d1.event_id.clone();
+        store.append_event(&d1).unwrap();
+        let d2 = make_decision_event(
+            "main",
+            "cache.backend",
+            "memcached",
+            Some("redis ops burden"),
+            Some(&d1_id),
+        );
+        let d2_id = d2.event_id.clone();
+        store.append_event(&d2).unwrap();
+        store
+            .append_event(&make_decision_event(
+                "main",
+                "cache.backend",
+                "in-process",
+                None,
+                Some(&d2_id),
+            ))
+            .unwrap();
+        store
+            .append_event(&make_decision_event(
+                "main",
+                "db.engine",
+                "postgres",
+                None,
+                None,
+            ))
+            .unwrap();
+
+        let history = store.decision_history("REDIS", None, None).unwrap();
+        let values: Vec<&str> = history.iter().map(|r| r.value.as_str()).collect();
+        assert_eq!(values, ["redis", "memcached", "in-process"]);
+        assert!(store
+            .decision_history("mongo", None, None)
+            .unwrap()
+            .is_empty());
+
+        drop(store);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn domain_timeline_empty_for_unknown_domain() {
         let (dir, store) = tmp_db();
@@ -1066,7 +1254,7 @@ mod tests {
         assert!(tables.contains(&"device_tokens".to_string()));
         assert!(tables.contains(&"decide_snapshots".to_string()));
         assert!(tables.contains(&"suggestions".to_string()));
-        assert_eq!(store.schema_version().unwrap(), 12);
+        assert_eq!(store.schema_version().unwrap(), 15);
         drop(store);
         let _ = std::fs::remove_dir_all(&dir);
     }
@@ -2876,8 +3064,8 @@ mod tests {
     fn test_schema_v10_fresh_db() {
         let (dir, store) = tmp_db();
 
-        // Version should be 12 (V11 village_id, V12 suggestions)
-        assert_eq!(store.schema_version().unwrap(), 12);
+        // Version should be 13 (V11 village_id, V12 suggestions, V13 event_signatures)
+        assert_eq!(store.schema_version().unwrap(), 15);
 
         // Verify new columns exist by inserting a test row
         store
@@ -3021,9 +3209,9 @@ mod tests {
             .unwrap();
         }
 
-        // Phase 2: Reopen — should auto-migrate to V12
+        // Phase 2: Reopen — should auto-migrate to V13
         let store = SqliteStore::open_or_create(&db_path).unwrap();
-        assert_eq!(store.schema_version().unwrap(), 12);
+        assert_eq!(store.schema_version().unwrap(), 15);
 
         // Active decision should have status='active'
         let status: String = store
@@ -1,6 +1,11 @@
 //! Schema constants, migrations, and version management.
+//!
+//! Migrations live in the [`MIGRATIONS`] registry. Each step runs in its own
+//! transaction, checks its post-conditions, and records a checksum row in
+//! `schema_migrations` before the version in `schema_meta` is bumped.
 
 use rusqlite::{params, Connection, OptionalExtension, Transaction, TransactionBehavior};
+use sha2::{Digest, Sha256};
 use tracing::warn;
 
 use super::mappers::*;
@@ -17,14 +22,9 @@ pub(super) fn table_columns(
     Ok(columns)
 }
 
-fn add_missing_columns(
-    conn: &Connection,
-    table: &str,
-    columns: &[(&str, &str)],
-) -> anyhow::Result<()> {
-    let existing = table_columns(conn, table)?;
-    for (name, sql) in columns {
-        if !existing.contains(*name) {
+fn add_missing_columns(conn: &Connection, columns: &[ColumnDef]) -> anyhow::Result<()> {
+    for (table, name, sql) in columns {
+        if !table_columns(conn, table)?.contains(*name) {
             conn.execute_batch(sql)?;
         }
     }
@@ -76,6 +76,13 @@ CREATE TABLE IF NOT EXISTS schema_meta (
     key TEXT PRIMARY KEY,
     value TEXT NOT NULL
 );
+
+CREATE TABLE IF NOT EXISTS schema_migrations (
+    version    INTEGER PRIMARY KEY,
+    name       TEXT NOT NULL,
+    checksum   TEXT NOT NULL,
+    applied_at TEXT NOT NULL
+);
 ";
 
 pub(super) const SCHEMA_V2_SQL: &str = "
@@ -213,82 +220,498 @@ CREATE TABLE IF NOT EXISTS suggestions (
 CREATE INDEX IF NOT EXISTS idx_suggestions_status ON suggestions(status);
 ";
 
-impl SqliteStore {
-    pub(super) fn apply_schema(&self) -> anyhow::Result<()> {
-        // Always apply v1 base schema (idempotent via IF NOT EXISTS)
-        self.conn.execute_batch(SCHEMA_SQL)?;
+pub(super) const SCHEMA_V13_SQL: &str = "
+CREATE TABLE IF NOT EXISTS event_signatures (
+    event_id   TEXT PRIMARY KEY REFERENCES events(event_id),
+    alg        TEXT NOT NULL,
+    key_id     TEXT NOT NULL,
+    public_key TEXT NOT NULL,
+    signature  TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_event_signatures_key ON event_signatures(key_id);
+";
 
-        // Bootstrap version if not set
-        self.conn.execute(
-            "INSERT OR IGNORE INTO schema_meta (key, value) VALUES ('version', '1')",
-            [],
-        )?;
+/// Secondary indexes for the decision read paths. `(key)` already exists as
+/// `idx_decisions_key`; per-branch history ordered by time is served by
+/// `idx_events_branch_ts`, since `ts` lives on `events`.
+pub(super) const SCHEMA_V14_SQL: &str = "
+CREATE INDEX IF NOT EXISTS idx_decisions_domain_active ON decisions(domain, is_active);
+CREATE INDEX IF NOT EXISTS idx_decisions_branch_active ON decisions(branch, is_active);
+";
 
-        // Migrate to v2 if needed
-        let current = self.schema_version()?;
-        if current < 2 {
-            self.migrate_v1_to_v2()?;
-        }
+/// Stubs for events `edda gc` moved to the cold archive. `rowid` is the
+/// event's original `events.rowid`, so the chain can still be walked in
+/// insertion order; the event itself lives in `archive_file` under
+/// `.edda/archive/`, and its signature, if any, moves here.
+pub(super) const SCHEMA_V15_SQL: &str = "
+CREATE TABLE IF NOT EXISTS archived_events (
+    rowid        INTEGER PRIMARY KEY,
+    event_id     TEXT UNIQUE NOT NULL,
+    ts           TEXT NOT NULL,
+    event_type   TEXT NOT NULL,
+    parent_hash  TEXT,
+    hash         TEXT NOT NULL,
+    archive_file TEXT NOT NULL,
+    signature    TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_archived_events_file ON archived_events(archive_file);
+";
 
-        // Migrate to v3 if needed
-        let current = self.schema_version()?;
-        if current < 3 {
-            self.migrate_v2_to_v3()?;
-        }
+/// An `ALTER TABLE ... ADD COLUMN` a migration performs, as
+/// `(table, column, ddl)`. SQLite has no `ADD COLUMN IF NOT EXISTS`, so the
+/// DDL is only executed when the column is missing, which keeps a
+/// half-applied step safe to re-run.
+pub(super) type ColumnDef = (&'static str, &'static str, &'static str);
+
+/// One step in the schema migration registry.
+///
+/// A step runs in a single immediate transaction: columns, then `sql`, then
+/// the optional backfill. Its post-conditions are checked before the
+/// `schema_migrations` row is written and the version is bumped; any missing
+/// table, column, or index rolls the whole step back.
+pub(super) struct Migration {
+    /// Schema version this step migrates *to*.
+    pub(super) version: u32,
+    pub(super) name: &'static str,
+    pub(super) add_columns: &'static [ColumnDef],
+    /// Idempotent DDL/DML applied after `add_columns`.
+    pub(super) sql: &'static str,
+    pub(super) backfill: Option<fn(&Connection) -> anyhow::Result<()>>,
+    /// Tables (and their columns) that must exist once the step has run.
+    pub(super) expect_columns: &'static [(&'static str, &'static [&'static str])],
+    /// Indexes that must exist once the step has run.
+    pub(super) expect_indexes: &'static [&'static str],
+}
 
-        // Migrate to v4 if needed
-        let current = self.schema_version()?;
-        if current < 4 {
-            self.migrate_v3_to_v4()?;
+impl Migration {
+    /// SHA-256 over everything that defines the step's schema effect.
+    /// Recorded in `schema_migrations` so later edits to an already-applied
+    /// step can be detected.
+    pub(super) fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.version.to_le_bytes());
+        hasher.update(self.name.as_bytes());
+        for (table, column, ddl) in self.add_columns {
+            hasher.update(table.as_bytes());
+            hasher.update(column.as_bytes());
+            hasher.update(ddl.as_bytes());
         }
+        hasher.update(self.sql.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
 
-        // Migrate to v5 if needed (cross-project sync fields)
-        let current = self.schema_version()?;
-        if current < 5 {
-            self.migrate_v4_to_v5()?;
-        }
+const V5_COLUMNS: &[ColumnDef] = &[
+    (
+        "decisions",
+        "scope",
+        "ALTER TABLE decisions ADD COLUMN scope TEXT NOT NULL DEFAULT 'local'",
+    ),
+    (
+        "decisions",
+        "source_project_id",
+        "ALTER TABLE decisions ADD COLUMN source_project_id TEXT",
+    ),
+    (
+        "decisions",
+        "source_event_id",
+        "ALTER TABLE decisions ADD COLUMN source_event_id TEXT",
+    ),
+];
+
+const V10_COLUMNS: &[ColumnDef] = &[
+    (
+        "decisions",
+        "status",
+        "ALTER TABLE decisions ADD COLUMN status TEXT NOT NULL DEFAULT 'active'",
+    ),
+    (
+        "decisions",
+        "authority",
+        "ALTER TABLE decisions ADD COLUMN authority TEXT NOT NULL DEFAULT 'human'",
+    ),
+    (
+        "decisions",
+        "affected_paths",
+        "ALTER TABLE decisions ADD COLUMN affected_paths TEXT NOT NULL DEFAULT '[]'",
+    ),
+    (
+        "decisions",
+        "tags",
+        "ALTER TABLE decisions ADD COLUMN tags TEXT NOT NULL DEFAULT '[]'",
+    ),
+    (
+        "decisions",
+        "review_after",
+        "ALTER TABLE decisions ADD COLUMN review_after TEXT",
+    ),
+    (
+        "decisions",
+        "reversibility",
+        "ALTER TABLE decisions ADD COLUMN reversibility TEXT NOT NULL DEFAULT 'medium'",
+    ),
+];
+
+const V11_COLUMNS: &[ColumnDef] = &[(
+    "decisions",
+    "village_id",
+    "ALTER TABLE decisions ADD COLUMN village_id TEXT",
+)];
+
+/// Ordered migration registry. Versions must be contiguous, starting at 2
+/// (v1 is the base `SCHEMA_SQL`).
+pub(super) const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 2,
+        name: "decisions",
+        add_columns: &[],
+        sql: SCHEMA_V2_SQL,
+        backfill: Some(backfill_decisions),
+        expect_columns: &[(
+            "decisions",
+            &[
+                "event_id",
+                "key",
+                "value",
+                "reason",
+                "domain",
+                "branch",
+                "supersedes_id",
+                "is_active",
+            ],
+        )],
+        expect_indexes: &[
+            "idx_decisions_key",
+            "idx_decisions_domain",
+            "idx_decisions_active",
+            "idx_decisions_branch_key",
+        ],
+    },
+    Migration {
+        version: 3,
+        name: "review_bundles",
+        add_columns: &[],
+        sql: SCHEMA_V3_SQL,
+        backfill: Some(backfill_review_bundles),
+        expect_columns: &[("review_bundles", &["event_id", "bundle_id", "status"])],
+        expect_indexes: &["idx_bundles_status", "idx_bundles_bundle_id"],
+    },
+    Migration {
+        version: 4,
+        name: "decision_deps",
+        add_columns: &[],
+        sql: SCHEMA_V4_SQL,
+        backfill: Some(backfill_domain_deps),
+        expect_columns: &[("decision_deps", &["source_key", "target_key", "dep_type"])],
+        expect_indexes: &["idx_deps_target", "idx_deps_source"],
+    },
+    Migration {
+        version: 5,
+        name: "decision_sync_fields",
+        add_columns: V5_COLUMNS,
+        sql: "CREATE INDEX IF NOT EXISTS idx_decisions_scope ON decisions(scope) WHERE scope != 'local';
+              CREATE INDEX IF NOT EXISTS idx_decisions_source ON decisions(source_project_id) WHERE source_project_id IS NOT NULL;",
+        backfill: None,
+        expect_columns: &[(
+            "decisions",
+            &["scope", "source_project_id", "source_event_id"],
+        )],
+        expect_indexes: &["idx_decisions_scope", "idx_decisions_source"],
+    },
+    Migration {
+        version: 6,
+        name: "task_briefs",
+        add_columns: &[],
+        sql: SCHEMA_V6_SQL,
+        backfill: Some(backfill_task_briefs),
+        expect_columns: &[(
+            "task_briefs",
+            &["task_id", "intake_event_id", "status", "branch", "updated_at"],
+        )],
+        expect_indexes: &[
+            "idx_task_briefs_status",
+            "idx_task_briefs_branch",
+            "idx_task_briefs_intent",
+        ],
+    },
+    Migration {
+        version: 7,
+        name: "device_tokens",
+        add_columns: &[],
+        sql: SCHEMA_V7_SQL,
+        // No backfill needed — device_tokens is a new feature with no existing data.
+        backfill: None,
+        expect_columns: &[("device_tokens", &["token_hash", "device_name", "revoked_at"])],
+        expect_indexes: &["idx_device_tokens_name", "idx_device_tokens_active"],
+    },
+    Migration {
+        version: 8,
+        name: "decide_snapshots",
+        add_columns: &[],
+        sql: SCHEMA_V8_SQL,
+        backfill: Some(backfill_decide_snapshots),
+        expect_columns: &[(
+            "decide_snapshots",
+            &["event_id", "context_hash", "engine_version", "village_id"],
+        )],
+        expect_indexes: &[
+            "idx_snapshots_context_hash",
+            "idx_snapshots_village",
+            "idx_snapshots_engine",
+            "idx_snapshots_village_engine",
+        ],
+    },
+    Migration {
+        version: 9,
+        name: "hot_path_indexes",
+        add_columns: &[],
+        sql: SCHEMA_V9_SQL,
+        backfill: None,
+        expect_columns: &[],
+        expect_indexes: &[
+            "idx_decisions_active_domain_branch",
+            "idx_decisions_active_domain",
+        ],
+    },
+    Migration {
+        version: 10,
+        name: "decision_deepening",
+        add_columns: V10_COLUMNS,
+        sql: "UPDATE decisions SET status = CASE WHEN is_active = 1 THEN 'active' ELSE 'superseded' END;
+              CREATE INDEX IF NOT EXISTS idx_decisions_status ON decisions(status);
+              CREATE INDEX IF NOT EXISTS idx_decisions_status_domain ON decisions(status, domain);
+              CREATE INDEX IF NOT EXISTS idx_decisions_affected_paths ON decisions(affected_paths) WHERE affected_paths != '[]';",
+        backfill: None,
+        expect_columns: &[(
+            "decisions",
+            &[
+                "status",
+                "authority",
+                "affected_paths",
+                "tags",
+                "review_after",
+                "reversibility",
+            ],
+        )],
+        expect_indexes: &[
+            "idx_decisions_status",
+            "idx_decisions_status_domain",
+            "idx_decisions_affected_paths",
+        ],
+    },
+    Migration {
+        version: 11,
+        name: "decision_village_id",
+        add_columns: V11_COLUMNS,
+        sql: "CREATE INDEX IF NOT EXISTS idx_decisions_village ON decisions(village_id) WHERE village_id IS NOT NULL;
+              CREATE INDEX IF NOT EXISTS idx_decisions_village_status ON decisions(village_id, status) WHERE village_id IS NOT NULL;",
+        backfill: None,
+        expect_columns: &[("decisions", &["village_id"])],
+        expect_indexes: &["idx_decisions_village", "idx_decisions_village_status"],
+    },
+    Migration {
+        version: 12,
+        name: "suggestions",
+        add_columns: &[],
+        sql: SCHEMA_V12_SQL,
+        // No backfill needed — suggestions is a new table with no existing data.
+        backfill: None,
+        expect_columns: &[(
+            "suggestions",
+            &["id", "event_type", "summary", "status", "created_at"],
+        )],
+        expect_indexes: &["idx_suggestions_status"],
+    },
+    Migration {
+        version: 13,
+        name: "event_signatures",
+        add_columns: &[],
+        sql: SCHEMA_V13_SQL,
+        // No backfill — only events appended with signing enabled are signed.
+        backfill: None,
+        expect_columns: &[(
+            "event_signatures",
+            &["event_id", "alg", "key_id", "public_key", "signature"],
+        )],
+        expect_indexes: &["idx_event_signatures_key"],
+    },
+    Migration {
+        version: 14,
+        name: "decision_query_indexes",
+        add_columns: &[],
+        sql: SCHEMA_V14_SQL,
+        backfill: None,
+        expect_columns: &[],
+        expect_indexes: &[
+            "idx_decisions_domain_active",
+            "idx_decisions_branch_active",
+        ],
+    },
+    Migration {
+        version: 15,
+        name: "archived_events",
+        add_columns: &[],
+        sql: SCHEMA_V15_SQL,
+        backfill: None,
+        expect_columns: &[(
+            "archived_events",
+            &["event_id", "parent_hash", "hash", "archive_file"],
+        )],
+        expect_indexes: &["idx_archived_events_file"],
+    },
+];
+
+/// Schema version a fully migrated ledger reports.
+pub(crate) const LATEST_SCHEMA_VERSION: u32 = MIGRATIONS[MIGRATIONS.len() - 1].version;
+
+pub(super) fn index_exists(conn: &Connection, name: &str) -> anyhow::Result<bool> {
+    let exists = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'index' AND name = ?1")?
+        .exists(params![name])?;
+    Ok(exists)
+}
 
-        // Migrate to v6 if needed (task_briefs materialized view)
-        let current = self.schema_version()?;
-        if current < 6 {
-            self.migrate_v5_to_v6()?;
+/// Every table, column and index a step promises that is not there.
+fn missing_postconditions(conn: &Connection, migration: &Migration) -> anyhow::Result<Vec<String>> {
+    let mut missing = Vec::new();
+    for (table, columns) in migration.expect_columns {
+        let actual = table_columns(conn, table)?;
+        if actual.is_empty() {
+            missing.push(format!("table {table}"));
+            continue;
         }
-
-        // Migrate to v7 if needed (device_tokens table)
-        let current = self.schema_version()?;
-        if current < 7 {
-            self.migrate_v6_to_v7()?;
+        for column in *columns {
+            if !actual.contains(*column) {
+                missing.push(format!("column {table}.{column}"));
+            }
         }
-
-        // Migrate to v8 if needed (decide_snapshots table)
-        let current = self.schema_version()?;
-        if current < 8 {
-            self.migrate_v7_to_v8()?;
+    }
+    for index in migration.expect_indexes {
+        if !index_exists(conn, index)? {
+            missing.push(format!("index {index}"));
         }
+    }
+    Ok(missing)
+}
+
+/// Check a step's post-conditions, listing every missing table, column,
+/// and index in the error.
+fn verify_postconditions(conn: &Connection, migration: &Migration) -> anyhow::Result<()> {
+    let missing = missing_postconditions(conn, migration)?;
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "schema migration v{} ({}) failed post-conditions; missing {}",
+            migration.version,
+            migration.name,
+            missing.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Run one migration step atomically. The version is only bumped (and the
+/// checksum row only written) if every post-condition holds; otherwise the
+/// transaction is dropped and rolled back.
+pub(super) fn run_migration(conn: &Connection, migration: &Migration) -> anyhow::Result<()> {
+    let tx = Transaction::new_unchecked(conn, TransactionBehavior::Immediate)?;
+    add_missing_columns(&tx, migration.add_columns)?;
+    tx.execute_batch(migration.sql)?;
+    if let Some(backfill) = migration.backfill {
+        backfill(&tx)?;
+    }
+    verify_postconditions(&tx, migration)?;
+    tx.execute(
+        "INSERT OR REPLACE INTO schema_migrations (version, name, checksum, applied_at)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![
+            migration.version,
+            migration.name,
+            migration.checksum(),
+            time_now_rfc3339()
+        ],
+    )?;
+    set_schema_version_on(&tx, migration.version)?;
+    tx.commit()?;
+    Ok(())
+}
 
-        // Migrate to v9 if needed (hot path query optimization indexes)
-        let current = self.schema_version()?;
-        if current < 9 {
-            self.migrate_v8_to_v9()?;
+/// Warn about recorded steps whose registry definition has changed since
+/// they were applied. Databases migrated before checksums were recorded
+/// simply have no rows for the older steps.
+fn check_recorded_checksums(conn: &Connection) -> anyhow::Result<()> {
+    let mut stmt = conn.prepare("SELECT version, checksum FROM schema_migrations")?;
+    let recorded: Vec<(u32, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+    for (version, checksum) in recorded {
+        match MIGRATIONS.iter().find(|m| m.version == version) {
+            Some(m) if m.checksum() != checksum => warn!(
+                version,
+                name = m.name,
+                "schema migration changed since it was applied (checksum mismatch)"
+            ),
+            Some(_) => {}
+            None => warn!(version, "schema_migrations records an unknown migration"),
         }
+    }
+    Ok(())
+}
 
-        // Migrate to v10 if needed (decision deepening columns)
-        let current = self.schema_version()?;
-        if current < 10 {
-            self.migrate_v9_to_v10()?;
+impl SqliteStore {
+    /// Applied steps whose post-conditions no longer hold: the version says
+    /// a column or index exists, the database disagrees.
+    pub(crate) fn schema_drift(&self) -> anyhow::Result<Vec<crate::SchemaDrift>> {
+        let version = self.schema_version()?;
+        let mut drift = Vec::new();
+        for migration in MIGRATIONS.iter().filter(|m| m.version <= version) {
+            let missing = missing_postconditions(&self.conn, migration)?;
+            if !missing.is_empty() {
+                drift.push(crate::SchemaDrift {
+                    version: migration.version,
+                    migration: migration.name.to_string(),
+                    missing,
+                });
+            }
         }
+        Ok(drift)
+    }
 
-        // Migrate to v11 if needed (village_id on decisions)
-        let current = self.schema_version()?;
-        if current < 11 {
-            self.migrate_v10_to_v11()?;
+    /// Re-apply the columns and idempotent DDL of every drifted step, each in
+    /// its own transaction, and re-check it. Backfills are not re-run and
+    /// the recorded version is left alone. Returns what was repaired.
+    pub(crate) fn repair_schema(&self) -> anyhow::Result<Vec<crate::SchemaDrift>> {
+        let drift = self.schema_drift()?;
+        for d in &drift {
+            let Some(migration) = MIGRATIONS.iter().find(|m| m.version == d.version) else {
+                continue;
+            };
+            let tx = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?;
+            add_missing_columns(&tx, migration.add_columns)?;
+            tx.execute_batch(migration.sql)?;
+            verify_postconditions(&tx, migration)?;
+            tx.commit()?;
         }
+        Ok(drift)
+    }
+}
 
-        // Migrate to v12 if needed (suggestions table for ingestion queue)
-        let current = self.schema_version()?;
-        if current < 12 {
-            self.migrate_v11_to_v12()?;
+impl SqliteStore {
+    pub(super) fn apply_schema(&self) -> anyhow::Result<()> {
+        // Always apply v1 base schema (idempotent via IF NOT EXISTS)
+        self.conn.execute_batch(SCHEMA_SQL)?;
+
+        // Bootstrap version if not set
+        self.conn.execute(
+            "INSERT OR IGNORE INTO schema_meta (key, value) VALUES ('version', '1')",
+            [],
+        )?;
+
+        for migration in MIGRATIONS {
+            if self.schema_version()? < migration.version {
+                run_migration(&self.conn, migration)?;
+            }
         }
+        check_recorded_checksums(&self.conn)?;
 
         // Post-migration verification: repair any columns that migrations
         // failed to add (e.g. version was bumped but ALTER TABLE didn't stick).
@@ -299,7 +722,7 @@ impl SqliteStore {
         Ok(())
     }
 
-    pub(super) fn schema_version(&self) -> anyhow::Result<u32> {
+    pub(crate) fn schema_version(&self) -> anyhow::Result<u32> {
         let version_str: String = self
             .conn
             .query_row(
@@ -311,6 +734,7 @@ impl SqliteStore {
         Ok(version_str.parse().unwrap_or(1))
     }
 
+    #[cfg(test)]
     pub(super) fn set_schema_version(&self, version: u32) -> anyhow::Result<()> {
         set_schema_version_on(&self.conn, version)
     }
@@ -319,86 +743,27 @@ impl SqliteStore {
     ///
     /// If a migration partially failed (version bumped but ALTER TABLE didn't
     /// stick), this repairs the schema by re-adding missing columns with their
-    /// correct defaults. Each ALTER TABLE ADD COLUMN is individually wrapped so
-    /// that already-existing columns are silently skipped.
+    /// correct defaults. The expected columns are the ones later migrations
+    /// add to `decisions`; base V2 columns come from CREATE TABLE and should
+    /// always be present. Each repair is attempted individually so one
+    /// failure doesn't abort the rest.
     pub(super) fn verify_decisions_schema(&self) -> anyhow::Result<()> {
+        let actual_columns = table_columns(&self.conn, "decisions")?;
         // Only run if the decisions table exists (schema >= v2).
-        let has_decisions: bool = self
-            .conn
-            .prepare("SELECT 1 FROM sqlite_master WHERE type='table' AND name='decisions'")
-            .and_then(|mut s| s.exists([]))
-            .unwrap_or(false);
-        if !has_decisions {
+        if actual_columns.is_empty() {
             return Ok(());
         }
 
-        // Collect actual column names from PRAGMA table_info.
-        let mut stmt = self.conn.prepare("PRAGMA table_info(decisions)")?;
-        let actual_columns: std::collections::HashSet<String> = stmt
-            .query_map([], |row| row.get::<_, String>(1))?
-            .filter_map(|r| r.ok())
-            .collect();
-
-        // Expected columns and their ALTER TABLE definitions.
-        // Base V2 columns (event_id, key, value, reason, domain, branch,
-        // supersedes_id, is_active) are created via CREATE TABLE so they
-        // should always be present. We verify the columns added by later
-        // migrations (V5, V10).
-        let expected_alters: &[(&str, &str)] = &[
-            // V5 columns
-            (
-                "scope",
-                "ALTER TABLE decisions ADD COLUMN scope TEXT NOT NULL DEFAULT 'local'",
-            ),
-            (
-                "source_project_id",
-                "ALTER TABLE decisions ADD COLUMN source_project_id TEXT",
-            ),
-            (
-                "source_event_id",
-                "ALTER TABLE decisions ADD COLUMN source_event_id TEXT",
-            ),
-            // V10 columns
-            (
-                "status",
-                "ALTER TABLE decisions ADD COLUMN status TEXT NOT NULL DEFAULT 'active'",
-            ),
-            (
-                "authority",
-                "ALTER TABLE decisions ADD COLUMN authority TEXT NOT NULL DEFAULT 'human'",
-            ),
-            (
-                "affected_paths",
-                "ALTER TABLE decisions ADD COLUMN affected_paths TEXT NOT NULL DEFAULT '[]'",
-            ),
-            (
-                "tags",
-                "ALTER TABLE decisions ADD COLUMN tags TEXT NOT NULL DEFAULT '[]'",
-            ),
-            (
-                "review_after",
-                "ALTER TABLE decisions ADD COLUMN review_after TEXT",
-            ),
-            (
-                "reversibility",
-                "ALTER TABLE decisions ADD COLUMN reversibility TEXT NOT NULL DEFAULT 'medium'",
-            ),
-            // V11 column
-            (
-                "village_id",
-                "ALTER TABLE decisions ADD COLUMN village_id TEXT",
-            ),
-        ];
-
-        for (col_name, alter_sql) in expected_alters {
+        let expected = MIGRATIONS
+            .iter()
+            .flat_map(|m| m.add_columns.iter())
+            .filter(|(table, _, _)| *table == "decisions");
+        for (_, col_name, alter_sql) in expected {
             if !actual_columns.contains(*col_name) {
                 warn!(
                     column = col_name,
                     "decisions table missing column — repairing"
                 );
-                // SQLite ALTER TABLE ADD COLUMN will error if the column
-                // already exists, but we checked above so this should succeed.
-                // Wrap in a match so one failure doesn't abort the rest.
                 if let Err(e) = self.conn.execute_batch(alter_sql) {
                     warn!(
                         column = col_name,
@@ -412,274 +777,6 @@ impl SqliteStore {
         Ok(())
     }
 
-    fn migrate_v1_to_v2(&self) -> anyhow::Result<()> {
-        // Create decisions table + indexes
-        self.conn.execute_batch(SCHEMA_V2_SQL)?;
-
-        // Backfill: scan existing events for decisions
-        let mut stmt = self.conn.prepare(
-            "SELECT event_id, ts, branch, payload, refs_provenance FROM events
-             WHERE event_type = 'note' ORDER BY rowid",
-        )?;
-        let rows: Vec<(String, String, String, String, String)> = stmt
-            .query_map([], |row| {
-                Ok((
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
-                ))
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        for (event_id, _ts, branch, payload_str, prov_str) in &rows {
-            let payload: serde_json::Value = match serde_json::from_str(payload_str) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-
-            if !edda_core::decision::is_decision(&payload) {
-                continue;
-            }
-
-            let dp = match edda_core::decision::extract_decision(&payload) {
-                Some(dp) => dp,
-                None => continue,
-            };
-            let key = &dp.key;
-            let value = &dp.value;
-            let reason = dp.reason.as_deref().unwrap_or("");
-            let domain = edda_core::decision::extract_domain(key);
-
-            let provenance: Vec<edda_core::types::Provenance> =
-                serde_json::from_str(prov_str).unwrap_or_default();
-            let supersedes_id = provenance
-                .iter()
-                .find(|p| p.rel == "supersedes")
-                .map(|p| p.target.as_str());
-
-            self.conn.execute(
-                "INSERT OR IGNORE INTO decisions
-                 (event_id, key, value, reason, domain, branch, supersedes_id, is_active)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, TRUE)",
-                params![event_id, key, value, reason, domain, branch, supersedes_id],
-            )?;
-        }
-
-        // Fix is_active: deactivate decisions that have been superseded
-        self.conn.execute(
-            "UPDATE decisions SET is_active = FALSE
-             WHERE event_id IN (
-                 SELECT d_old.event_id FROM decisions d_old
-                 JOIN decisions d_new ON d_new.supersedes_id = d_old.event_id
-             )",
-            [],
-        )?;
-
-        // Also deactivate by key+branch: for each (key, branch), only the latest is active
-        // This handles cases where supersedes_id wasn't set (legacy events)
-        self.conn.execute_batch(
-            "UPDATE decisions SET is_active = FALSE
-             WHERE rowid NOT IN (
-                 SELECT MAX(d.rowid) FROM decisions d
-                 GROUP BY d.key, d.branch
-             ) AND is_active = TRUE",
-        )?;
-
-        self.set_schema_version(2)?;
-        Ok(())
-    }
-
-    fn migrate_v2_to_v3(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V3_SQL)?;
-
-        // Backfill: scan existing review_bundle events
-        let mut stmt = self.conn.prepare(
-            "SELECT event_id, ts, branch, payload FROM events
-             WHERE event_type = 'review_bundle' ORDER BY rowid",
-        )?;
-        let rows: Vec<(String, String, String, String)> = stmt
-            .query_map([], |row| {
-                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        for (event_id, ts, branch, payload_str) in &rows {
-            let payload: serde_json::Value = match serde_json::from_str(payload_str) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            materialize_bundle_sql(&self.conn, event_id, ts, branch, &payload)?;
-        }
-
-        self.set_schema_version(3)?;
-        Ok(())
-    }
-
-    fn migrate_v3_to_v4(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V4_SQL)?;
-
-        // Backfill: create star-shaped auto_domain edges for existing active decisions
-        let mut stmt = self
-            .conn
-            .prepare("SELECT key, domain FROM decisions WHERE is_active = TRUE ORDER BY rowid")?;
-        let rows: Vec<(String, String)> = stmt
-            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        // Group by domain
-        let mut domain_keys: std::collections::HashMap<String, Vec<String>> =
-            std::collections::HashMap::new();
-        for (key, domain) in &rows {
-            domain_keys
-                .entry(domain.clone())
-                .or_default()
-                .push(key.clone());
-        }
-
-        let now = time_now_rfc3339();
-        for keys in domain_keys.values() {
-            if keys.len() < 2 {
-                continue;
-            }
-            // Star-shaped: each key after the first depends_on all prior keys
-            for i in 1..keys.len() {
-                for j in 0..i {
-                    self.conn.execute(
-                        "INSERT OR IGNORE INTO decision_deps
-                         (source_key, target_key, dep_type, created_event, created_at)
-                         VALUES (?1, ?2, 'auto_domain', NULL, ?3)",
-                        params![keys[i], keys[j], now],
-                    )?;
-                }
-            }
-        }
-
-        self.set_schema_version(4)?;
-        Ok(())
-    }
-
-    fn migrate_v4_to_v5(&self) -> anyhow::Result<()> {
-        let tx = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?;
-        add_missing_columns(
-            &tx,
-            "decisions",
-            &[
-                (
-                    "scope",
-                    "ALTER TABLE decisions ADD COLUMN scope TEXT NOT NULL DEFAULT 'local'",
-                ),
-                (
-                    "source_project_id",
-                    "ALTER TABLE decisions ADD COLUMN source_project_id TEXT",
-                ),
-                (
-                    "source_event_id",
-                    "ALTER TABLE decisions ADD COLUMN source_event_id TEXT",
-                ),
-            ],
-        )?;
-        tx.execute_batch(
-            "CREATE INDEX IF NOT EXISTS idx_decisions_scope ON decisions(scope) WHERE scope != 'local';
-             CREATE INDEX IF NOT EXISTS idx_decisions_source ON decisions(source_project_id) WHERE source_project_id IS NOT NULL;",
-        )?;
-        set_schema_version_on(&tx, 5)?;
-        tx.commit()?;
-        Ok(())
-    }
-
-    fn migrate_v5_to_v6(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V6_SQL)?;
-
-        // Backfill: scan existing task_intake events
-        let mut stmt = self.conn.prepare(
-            "SELECT event_id, ts, branch, payload FROM events
-             WHERE event_type = 'task_intake' ORDER BY rowid",
-        )?;
-        let rows: Vec<(String, String, String, String)> = stmt
-            .query_map([], |row| {
-                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        for (event_id, ts, branch, payload_str) in &rows {
-            let payload: serde_json::Value = match serde_json::from_str(payload_str) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            materialize_task_brief_sql(&self.conn, event_id, ts, branch, &payload)?;
-        }
-
-        // Backfill updates: scan commits, notes, and merges on brief branches
-        self.backfill_task_brief_updates()?;
-
-        self.set_schema_version(6)?;
-        Ok(())
-    }
-
-    fn migrate_v6_to_v7(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V7_SQL)?;
-        // No backfill needed — device_tokens is a new feature with no existing data.
-        self.set_schema_version(7)?;
-        Ok(())
-    }
-
-    fn migrate_v7_to_v8(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V8_SQL)?;
-
-        // Backfill: scan existing decide_snapshot events
-        let mut stmt = self.conn.prepare(
-            "SELECT event_id, ts, payload FROM events
-             WHERE event_type = 'decide_snapshot' ORDER BY rowid",
-        )?;
-        let rows: Vec<(String, String, String)> = stmt
-            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        for (event_id, ts, payload_str) in &rows {
-            let payload: serde_json::Value = match serde_json::from_str(payload_str) {
-                Ok(v) => v,
-                Err(_) => continue,
-            };
-            let context_hash = payload["context_hash"].as_str().unwrap_or("");
-            let engine_version = payload["engine_version"].as_str().unwrap_or("");
-            let schema_version = payload["schema_version"].as_str().unwrap_or("snapshot.v1");
-            let redaction_level = payload["redaction_level"].as_str().unwrap_or("full");
-            let village_id = payload["village_id"].as_str();
-            let cycle_id = payload["cycle_id"].as_str();
-            let has_blobs =
-                payload.get("context_blob").is_some() || payload.get("result_blob").is_some();
-
-            self.conn.execute(
-                "INSERT OR IGNORE INTO decide_snapshots
-                 (event_id, context_hash, engine_version, schema_version,
-                  redaction_level, village_id, cycle_id, has_blobs, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
-                params![
-                    event_id,
-                    context_hash,
-                    engine_version,
-                    schema_version,
-                    redaction_level,
-                    village_id,
-                    cycle_id,
-                    has_blobs,
-                    ts
-                ],
-            )?;
-        }
-
-        self.set_schema_version(8)?;
-        Ok(())
-    }
-
-    fn migrate_v8_to_v9(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V9_SQL)?;
-        self.set_schema_version(9)?;
-        Ok(())
-    }
-
     /// Rebuild missing snapshot rows from durable events. This is safe to run
     /// on every open and repairs event-only states created by older versions.
     fn repair_snapshot_materialization(&self) -> anyhow::Result<()> {
@@ -752,49 +849,8 @@ impl SqliteStore {
         Ok(())
     }
 
-    fn migrate_v9_to_v10(&self) -> anyhow::Result<()> {
-        let tx = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?;
-        add_missing_columns(
-            &tx,
-            "decisions",
-            &[
-                (
-                    "status",
-                    "ALTER TABLE decisions ADD COLUMN status TEXT NOT NULL DEFAULT 'active'",
-                ),
-                (
-                    "authority",
-                    "ALTER TABLE decisions ADD COLUMN authority TEXT NOT NULL DEFAULT 'human'",
-                ),
-                (
-                    "affected_paths",
-                    "ALTER TABLE decisions ADD COLUMN affected_paths TEXT NOT NULL DEFAULT '[]'",
-                ),
-                (
-                    "tags",
-                    "ALTER TABLE decisions ADD COLUMN tags TEXT NOT NULL DEFAULT '[]'",
-                ),
-                (
-                    "review_after",
-                    "ALTER TABLE decisions ADD COLUMN review_after TEXT",
-                ),
-                (
-                    "reversibility",
-                    "ALTER TABLE decisions ADD COLUMN reversibility TEXT NOT NULL DEFAULT 'medium'",
-                ),
-            ],
-        )?;
-        tx.execute_batch(
-            "UPDATE decisions SET status = CASE WHEN is_active = 1 THEN 'active' ELSE 'superseded' END;
-             CREATE INDEX IF NOT EXISTS idx_decisions_status ON decisions(status);
-             CREATE INDEX IF NOT EXISTS idx_decisions_status_domain ON decisions(status, domain);
-             CREATE INDEX IF NOT EXISTS idx_decisions_affected_paths ON decisions(affected_paths) WHERE affected_paths != '[]';",
-        )?;
-        set_schema_version_on(&tx, 10)?;
-        tx.commit()?;
-        Ok(())
-    }
-
+    /// One active decision per branch, key, and scope: a session or branch
+    /// decision can be active on top of the project-level one.
     fn enforce_active_decision_uniqueness(&self) -> anyhow::Result<()> {
         let tx = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?;
         tx.execute(
@@ -804,165 +860,339 @@ impl SqliteStore {
                AND rowid NOT IN (
                    SELECT MAX(rowid) FROM decisions
                    WHERE is_active = TRUE
-                   GROUP BY branch, key
+                   GROUP BY branch, key, scope
                )",
             [],
         )?;
+        tx.execute("DROP INDEX IF EXISTS idx_decisions_one_active_per_key", [])?;
         tx.execute(
-            "CREATE UNIQUE INDEX IF NOT EXISTS idx_decisions_one_active_per_key
-             ON decisions(branch, key) WHERE is_active = TRUE",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_decisions_one_active_per_scope
+             ON decisions(branch, key, scope) WHERE is_active = TRUE",
             [],
         )?;
         tx.commit()?;
         Ok(())
     }
+}
 
-    fn migrate_v10_to_v11(&self) -> anyhow::Result<()> {
-        let tx = Transaction::new_unchecked(&self.conn, TransactionBehavior::Immediate)?;
-        add_missing_columns(
-            &tx,
-            "decisions",
-            &[(
-                "village_id",
-                "ALTER TABLE decisions ADD COLUMN village_id TEXT",
-            )],
-        )?;
-        tx.execute_batch(
-            "CREATE INDEX IF NOT EXISTS idx_decisions_village ON decisions(village_id) WHERE village_id IS NOT NULL;
-             CREATE INDEX IF NOT EXISTS idx_decisions_village_status ON decisions(village_id, status) WHERE village_id IS NOT NULL;",
+/// V2 backfill: scan existing note events for decisions.
+fn backfill_decisions(conn: &Connection) -> anyhow::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT event_id, ts, branch, payload, refs_provenance FROM events
+         WHERE event_type = 'note' ORDER BY rowid",
+    )?;
+    let rows: Vec<(String, String, String, String, String)> = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get(2)?,
+                row.get(3)?,
+                row.get(4)?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (event_id, _ts, branch, payload_str, prov_str) in &rows {
+        let payload: serde_json::Value = match serde_json::from_str(payload_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        if !edda_core::decision::is_decision(&payload) {
+            continue;
+        }
+
+        let dp = match edda_core::decision::extract_decision(&payload) {
+            Some(dp) => dp,
+            None => continue,
+        };
+        let key = &dp.key;
+        let value = &dp.value;
+        let reason = dp.reason.as_deref().unwrap_or("");
+        let domain = edda_core::decision::extract_domain(key);
+
+        let provenance: Vec<edda_core::types::Provenance> =
+            serde_json::from_str(prov_str).unwrap_or_default();
+        let supersedes_id = provenance
+            .iter()
+            .find(|p| p.rel == "supersedes")
+            .map(|p| p.target.as_str());
+
+        conn.execute(
+            "INSERT OR IGNORE INTO decisions
+             (event_id, key, value, reason, domain, branch, supersedes_id, is_active)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, TRUE)",
+            params![event_id, key, value, reason, domain, branch, supersedes_id],
         )?;
-        set_schema_version_on(&tx, 11)?;
-        tx.commit()?;
-        Ok(())
     }
 
-    fn migrate_v11_to_v12(&self) -> anyhow::Result<()> {
-        self.conn.execute_batch(SCHEMA_V12_SQL)?;
-        // No backfill needed — suggestions is a new table with no existing data.
-        self.set_schema_version(12)?;
-        Ok(())
+    // Fix is_active: deactivate decisions that have been superseded
+    conn.execute(
+        "UPDATE decisions SET is_active = FALSE
+         WHERE event_id IN (
+             SELECT d_old.event_id FROM decisions d_old
+             JOIN decisions d_new ON d_new.supersedes_id = d_old.event_id
+         )",
+        [],
+    )?;
+
+    // Also deactivate by key+branch: for each (key, branch), only the latest is active
+    // This handles cases where supersedes_id wasn't set (legacy events)
+    conn.execute_batch(
+        "UPDATE decisions SET is_active = FALSE
+         WHERE rowid NOT IN (
+             SELECT MAX(d.rowid) FROM decisions d
+             GROUP BY d.key, d.branch
+         ) AND is_active = TRUE",
+    )?;
+    Ok(())
+}
+
+/// V3 backfill: scan existing review_bundle events.
+fn backfill_review_bundles(conn: &Connection) -> anyhow::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT event_id, ts, branch, payload FROM events
+         WHERE event_type = 'review_bundle' ORDER BY rowid",
+    )?;
+    let rows: Vec<(String, String, String, String)> = stmt
+        .query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (event_id, ts, branch, payload_str) in &rows {
+        let payload: serde_json::Value = match serde_json::from_str(payload_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        materialize_bundle_sql(conn, event_id, ts, branch, &payload)?;
     }
+    Ok(())
+}
 
-    /// Backfill task brief updates from existing commit/note/merge events.
-    fn backfill_task_brief_updates(&self) -> anyhow::Result<()> {
-        let mut brief_stmt = self
-            .conn
-            .prepare("SELECT task_id, branch, created_at FROM task_briefs")?;
-        let briefs: Vec<(String, String, String)> = brief_stmt
-            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
-            .collect::<Result<Vec<_>, _>>()?;
+/// V4 backfill: create star-shaped auto_domain edges for existing active decisions.
+fn backfill_domain_deps(conn: &Connection) -> anyhow::Result<()> {
+    let mut stmt =
+        conn.prepare("SELECT key, domain FROM decisions WHERE is_active = TRUE ORDER BY rowid")?;
+    let rows: Vec<(String, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Group by domain
+    let mut domain_keys: std::collections::HashMap<String, Vec<String>> =
+        std::collections::HashMap::new();
+    for (key, domain) in &rows {
+        domain_keys
+            .entry(domain.clone())
+            .or_default()
+            .push(key.clone());
+    }
 
-        for (_task_id, branch, created_at) in &briefs {
-            // Count commits on this branch after the intake
-            let commit_count: i64 = self.conn.query_row(
-                "SELECT COUNT(*) FROM events
-                 WHERE branch = ?1 AND event_type = 'commit' AND ts >= ?2",
-                params![branch, created_at],
-                |row| row.get(0),
-            )?;
+    let now = time_now_rfc3339();
+    for keys in domain_keys.values() {
+        if keys.len() < 2 {
+            continue;
+        }
+        // Star-shaped: each key after the first depends_on all prior keys
+        for i in 1..keys.len() {
+            for j in 0..i {
+                conn.execute(
+                    "INSERT OR IGNORE INTO decision_deps
+                     (source_key, target_key, dep_type, created_event, created_at)
+                     VALUES (?1, ?2, 'auto_domain', NULL, ?3)",
+                    params![keys[i], keys[j], now],
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
 
-            // Collect artifacts from commit payloads
-            let mut artifacts: Vec<String> = Vec::new();
-            let mut art_stmt = self.conn.prepare(
-                "SELECT payload FROM events
-                 WHERE branch = ?1 AND event_type = 'commit' AND ts >= ?2
-                 ORDER BY rowid",
-            )?;
-            let payloads: Vec<String> = art_stmt
-                .query_map(params![branch, created_at], |row| row.get(0))?
-                .collect::<Result<Vec<_>, _>>()?;
-            for p_str in &payloads {
-                if let Ok(p) = serde_json::from_str::<serde_json::Value>(p_str) {
-                    extract_artifacts_from_payload(&p, &mut artifacts);
-                }
+/// V6 backfill: scan existing task_intake events, then apply updates from
+/// commits, notes, and merges on the brief branches.
+fn backfill_task_briefs(conn: &Connection) -> anyhow::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT event_id, ts, branch, payload FROM events
+         WHERE event_type = 'task_intake' ORDER BY rowid",
+    )?;
+    let rows: Vec<(String, String, String, String)> = stmt
+        .query_map([], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (event_id, ts, branch, payload_str) in &rows {
+        let payload: serde_json::Value = match serde_json::from_str(payload_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        materialize_task_brief_sql(conn, event_id, ts, branch, &payload)?;
+    }
+
+    backfill_task_brief_updates(conn)
+}
+
+/// V8 backfill: scan existing decide_snapshot events.
+fn backfill_decide_snapshots(conn: &Connection) -> anyhow::Result<()> {
+    let mut stmt = conn.prepare(
+        "SELECT event_id, ts, payload FROM events
+         WHERE event_type = 'decide_snapshot' ORDER BY rowid",
+    )?;
+    let rows: Vec<(String, String, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (event_id, ts, payload_str) in &rows {
+        let payload: serde_json::Value = match serde_json::from_str(payload_str) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+        let context_hash = payload["context_hash"].as_str().unwrap_or("");
+        let engine_version = payload["engine_version"].as_str().unwrap_or("");
+        let schema_version = payload["schema_version"].as_str().unwrap_or("snapshot.v1");
+        let redaction_level = payload["redaction_level"].as_str().unwrap_or("full");
+        let village_id = payload["village_id"].as_str();
+        let cycle_id = payload["cycle_id"].as_str();
+        let has_blobs =
+            payload.get("context_blob").is_some() || payload.get("result_blob").is_some();
+
+        conn.execute(
+            "INSERT OR IGNORE INTO decide_snapshots
+             (event_id, context_hash, engine_version, schema_version,
+              redaction_level, village_id, cycle_id, has_blobs, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                event_id,
+                context_hash,
+                engine_version,
+                schema_version,
+                redaction_level,
+                village_id,
+                cycle_id,
+                has_blobs,
+                ts
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Backfill task brief updates from existing commit/note/merge events.
+fn backfill_task_brief_updates(conn: &Connection) -> anyhow::Result<()> {
+    let mut brief_stmt = conn.prepare("SELECT task_id, branch, created_at FROM task_briefs")?;
+    let briefs: Vec<(String, String, String)> = brief_stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (_task_id, branch, created_at) in &briefs {
+        // Count commits on this branch after the intake
+        let commit_count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM events
+             WHERE branch = ?1 AND event_type = 'commit' AND ts >= ?2",
+            params![branch, created_at],
+            |row| row.get(0),
+        )?;
+
+        // Collect artifacts from commit payloads
+        let mut artifacts: Vec<String> = Vec::new();
+        let mut art_stmt = conn.prepare(
+            "SELECT payload FROM events
+             WHERE branch = ?1 AND event_type = 'commit' AND ts >= ?2
+             ORDER BY rowid",
+        )?;
+        let payloads: Vec<String> = art_stmt
+            .query_map(params![branch, created_at], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        for p_str in &payloads {
+            if let Ok(p) = serde_json::from_str::<serde_json::Value>(p_str) {
+                extract_artifacts_from_payload(&p, &mut artifacts);
             }
+        }
 
-            // Scan all notes on this branch to find feedback and decision tags.
-            // We parse JSON in Rust instead of using SQL LIKE to avoid false matches.
-            let mut note_stmt = self.conn.prepare(
-                "SELECT payload FROM events
-                 WHERE branch = ?1 AND event_type = 'note' AND ts >= ?2
-                 ORDER BY rowid",
-            )?;
-            let note_payloads: Vec<String> = note_stmt
-                .query_map(params![branch, created_at], |row| row.get(0))?
-                .collect::<Result<Vec<_>, _>>()?;
-
-            let mut last_feedback: Option<String> = None;
-            let mut decision_keys: Vec<String> = Vec::new();
-
-            for p_str in &note_payloads {
-                let p: serde_json::Value = match serde_json::from_str(p_str) {
-                    Ok(v) => v,
-                    Err(_) => continue,
-                };
-                let tags = payload_tags(&p);
-                if tags.iter().any(|t| t == "review" || t == "feedback") {
-                    if let Some(fb) = extract_feedback_from_payload(&p) {
-                        last_feedback = Some(fb);
-                    }
+        // Scan all notes on this branch to find feedback and decision tags.
+        // We parse JSON in Rust instead of using SQL LIKE to avoid false matches.
+        let mut note_stmt = conn.prepare(
+            "SELECT payload FROM events
+             WHERE branch = ?1 AND event_type = 'note' AND ts >= ?2
+             ORDER BY rowid",
+        )?;
+        let note_payloads: Vec<String> = note_stmt
+            .query_map(params![branch, created_at], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut last_feedback: Option<String> = None;
+        let mut decision_keys: Vec<String> = Vec::new();
+
+        for p_str in &note_payloads {
+            let p: serde_json::Value = match serde_json::from_str(p_str) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            let tags = payload_tags(&p);
+            if tags.iter().any(|t| t == "review" || t == "feedback") {
+                if let Some(fb) = extract_feedback_from_payload(&p) {
+                    last_feedback = Some(fb);
                 }
-                if tags.iter().any(|t| t == "decision") {
-                    if let Some(key) = p["decision"]["key"].as_str() {
-                        if !decision_keys.contains(&key.to_string()) {
-                            decision_keys.push(key.to_string());
-                        }
+            }
+            if tags.iter().any(|t| t == "decision") {
+                if let Some(key) = p["decision"]["key"].as_str() {
+                    if !decision_keys.contains(&key.to_string()) {
+                        decision_keys.push(key.to_string());
                     }
                 }
             }
+        }
 
-            // Check for merge (completion)
-            let has_merge: bool = self.conn.query_row(
-                "SELECT COUNT(*) > 0 FROM events
-                 WHERE branch = ?1 AND event_type = 'merge' AND ts >= ?2",
+        // Check for merge (completion)
+        let has_merge: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM events
+             WHERE branch = ?1 AND event_type = 'merge' AND ts >= ?2",
+            params![branch, created_at],
+            |row| row.get(0),
+        )?;
+
+        // Get updated_at from the latest event on this branch
+        let latest_ts: Option<String> = conn
+            .query_row(
+                "SELECT ts FROM events
+                 WHERE branch = ?1 AND ts >= ?2
+                 ORDER BY rowid DESC LIMIT 1",
                 params![branch, created_at],
                 |row| row.get(0),
-            )?;
-
-            // Get updated_at from the latest event on this branch
-            let latest_ts: Option<String> = self
-                .conn
-                .query_row(
-                    "SELECT ts FROM events
-                     WHERE branch = ?1 AND ts >= ?2
-                     ORDER BY rowid DESC LIMIT 1",
-                    params![branch, created_at],
-                    |row| row.get(0),
-                )
-                .optional()?;
-
-            let artifacts_json =
-                serde_json::to_string(&artifacts).unwrap_or_else(|_| "[]".to_string());
-            let decisions_json =
-                serde_json::to_string(&decision_keys).unwrap_or_else(|_| "[]".to_string());
-            let status = if has_merge {
-                edda_core::types::TaskBriefStatus::Completed
-            } else {
-                edda_core::types::TaskBriefStatus::Active
-            };
-
-            self.conn.execute(
-                "UPDATE task_briefs SET
-                    iterations = ?1,
-                    artifacts = ?2,
-                    decisions = ?3,
-                    last_feedback = ?4,
-                    status = ?5,
-                    updated_at = COALESCE(?6, updated_at)
-                 WHERE branch = ?7 AND created_at = ?8",
-                params![
-                    commit_count,
-                    artifacts_json,
-                    decisions_json,
-                    last_feedback,
-                    status.as_str(),
-                    latest_ts,
-                    branch,
-                    created_at,
-                ],
-            )?;
-        }
+            )
+            .optional()?;
+
+        let artifacts_json = serde_json::to_string(&artifacts).unwrap_or_else(|_| "[]".to_string());
+        let decisions_json =
+            serde_json::to_string(&decision_keys).unwrap_or_else(|_| "[]".to_string());
+        let status = if has_merge {
+            edda_core::types::TaskBriefStatus::Completed
+        } else {
+            edda_core::types::TaskBriefStatus::Active
+        };
 
-        Ok(())
+        conn.execute(
+            "UPDATE task_briefs SET
+                iterations = ?1,
+                artifacts = ?2,
+                decisions = ?3,
+                last_feedback = ?4,
+                status = ?5,
+                updated_at = COALESCE(?6, updated_at)
+             WHERE branch = ?7 AND created_at = ?8",
+            params![
+                commit_count,
+                artifacts_json,
+                decisions_json,
+                last_feedback,
+                status.as_str(),
+                latest_ts,
+                branch,
+                created_at,
+            ],
+        )?;
     }
+
+    Ok(())
 }
@@ -31,7 +31,8 @@ pub struct DecisionRow {
     pub source_project_id: Option<String>,
     /// Source event ID if this decision was imported from another project.
     pub source_event_id: Option<String>,
-    /// Lifecycle status: "proposed", "active", "experimental", "deprecated", "superseded"
+    /// Lifecycle status: "proposed", "active", "experimental", "deprecated", "superseded",
+    /// "revoked"
     pub status: String,
     /// Decision authority: "human", "agent", "system"
     pub authority: String,
@@ -0,0 +1,245 @@
+//! Append-path metrics: payload sizes, event type mix, and append latency.
+//!
+//! Every [`crate::Ledger::append_event`] folds the event into
+//! `.edda/stats.json`, which `edda stats` and `GET /api/metrics/ledger` read
+//! to show what is bloating a ledger. Recording is best-effort: a failure is
+//! logged and never fails the append. Appenders in different processes (CLI,
+//! MCP, serve, hooks) do not all hold the workspace lock, so each update is
+//! a read-modify-write under `.edda/stats.lock`, written atomically.
+
+use std::collections::BTreeMap;
+
+use edda_core::Event;
+use serde::{Deserialize, Serialize};
+
+use crate::paths::EddaPaths;
+
+/// Count and byte totals for one event type or payload field.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SizeStats {
+    pub count: u64,
+    pub bytes: u64,
+    pub max_bytes: u64,
+}
+
+impl SizeStats {
+    fn add(&mut self, bytes: u64) {
+        self.count += 1;
+        self.bytes += bytes;
+        self.max_bytes = self.max_bytes.max(bytes);
+    }
+
+    pub fn avg_bytes(&self) -> u64 {
+        self.bytes.checked_div(self.count).unwrap_or(0)
+    }
+}
+
+/// Per event type: payload sizes plus append latency.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TypeStats {
+    #[serde(flatten)]
+    pub size: SizeStats,
+    pub latency_ms_total: f64,
+    pub latency_ms_max: f64,
+}
+
+impl TypeStats {
+    pub fn latency_ms_avg(&self) -> f64 {
+        if self.size.count == 0 {
+            0.0
+        } else {
+            self.latency_ms_total / self.size.count as f64
+        }
+    }
+}
+
+/// Contents of `.edda/stats.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AppendStats {
+    /// When recording started (first append, or last reset).
+    #[serde(default)]
+    pub since: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub by_type: BTreeMap<String, TypeStats>,
+    /// Serialized size of each top-level payload field, keyed
+    /// `"<event_type>.<field>"`.
+    #[serde(default)]
+    pub fields: BTreeMap<String, SizeStats>,
+}
+
+impl AppendStats {
+    /// Load the stats file; a missing file is empty stats. An unreadable
+    /// file (truncated, hand-edited) is logged and treated as a reset, so the
+    /// next append starts counting again.
+    pub fn load(paths: &EddaPaths) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(&paths.stats_json) {
+            Ok(s) => match serde_json::from_str(&s) {
+                Ok(stats) => Ok(stats),
+                Err(e) => {
+                    tracing::warn!(
+                        path = %paths.stats_json.display(),
+                        error = %e,
+                        "append stats unreadable, starting over"
+                    );
+                    Ok(Self::default())
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write atomically (unique temp file + rename). Callers updating the
+    /// file hold `.edda/stats.lock`; see [`Self::reset`].
+    pub fn save(&self, paths: &EddaPaths) -> anyhow::Result<()> {
+        edda_store::write_atomic(&paths.stats_json, &serde_json::to_vec_pretty(self)?)
+    }
+
+    /// Clear the stats, waiting for any in-flight update to finish.
+    pub fn reset(paths: &EddaPaths) -> anyhow::Result<()> {
+        let _lock = edda_store::lock_file(&paths.stats_lock)?;
+        Self::default().save(paths)
+    }
+
+    /// Fold one appended event into the totals.
+    pub fn record(&mut self, event: &Event, latency_ms: f64, now: &str) {
+        let payload_bytes = json_len(&event.payload);
+        let entry = self.by_type.entry(event.event_type.clone()).or_default();
+        entry.size.add(payload_bytes);
+        entry.latency_ms_total += latency_ms;
+        entry.latency_ms_max = entry.latency_ms_max.max(latency_ms);
+
+        if let Some(obj) = event.payload.as_object() {
+            for (field, value) in obj {
+                self.fields
+                    .entry(format!("{}.{field}", event.event_type))
+                    .or_default()
+                    .add(json_len(value));
+            }
+        }
+
+        if self.since.is_none() {
+            self.since = Some(now.to_string());
+        }
+        self.updated_at = Some(now.to_string());
+    }
+
+    /// Events and payload bytes across all types.
+    pub fn totals(&self) -> SizeStats {
+        let mut total = SizeStats::default();
+        for t in self.by_type.values() {
+            total.count += t.size.count;
+            total.bytes += t.size.bytes;
+            total.max_bytes = total.max_bytes.max(t.size.max_bytes);
+        }
+        total
+    }
+
+    /// The `n` payload fields with the most bytes, largest first.
+    pub fn top_fields(&self, n: usize) -> Vec<(&str, &SizeStats)> {
+        let mut fields: Vec<_> = self.fields.iter().map(|(k, v)| (k.as_str(), v)).collect();
+        fields.sort_by(|a, b| b.1.bytes.cmp(&a.1.bytes).then(a.0.cmp(b.0)));
+        fields.truncate(n);
+        fields
+    }
+}
+
+/// Record one append; called by the ledger after a successful insert.
+pub(crate) fn record_append(paths: &EddaPaths, event: &Event, latency_ms: f64) {
+    let result = edda_store::lock_file(&paths.stats_lock).and_then(|_lock| {
+        let mut stats = AppendStats::load(paths)?;
+        let now = time::OffsetDateTime::now_utc()
+            .format(&time::format_description::well_known::Rfc3339)?;
+        stats.record(event, latency_ms, &now);
+        stats.save(paths)
+    });
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "failed to record append stats");
+    }
+}
+
+fn json_len(value: &serde_json::Value) -> u64 {
+    serde_json::to_vec(value).map_or(0, |v| v.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Ledger;
+    use edda_core::event::new_note_event;
+
+    #[test]
+    fn appends_accumulate_sizes_fields_and_latency() {
+        let tmp = std::env::temp_dir().join(format!("edda_stats_test_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let ledger = Ledger::open_or_init(&tmp).unwrap();
+
+        let short = new_note_event("main", None, "user", "hi", &[]).unwrap();
+        ledger.append_event(&short).unwrap();
+        let long = new_note_event(
+            "main",
+            Some(&short.hash),
+            "user",
+            &"x".repeat(1000),
+            &["big".to_string()],
+        )
+        .unwrap();
+        ledger.append_event(&long).unwrap();
+
+        let stats = AppendStats::load(&ledger.paths).unwrap();
+        let notes = &stats.by_type["note"];
+        assert_eq!(notes.size.count, 2);
+        assert_eq!(notes.size.max_bytes, json_len(&long.payload));
+        assert!(notes.latency_ms_total > 0.0);
+        assert_eq!(stats.totals().count, 2);
+        assert!(stats.since.is_some());
+
+        let (field, text) = stats.top_fields(1)[0];
+        assert_eq!(field, "note.text");
+        assert_eq!(text.count, 2);
+        assert_eq!(text.max_bytes, 1002, "quoted 1000-char string");
+        drop(ledger);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn concurrent_recorders_keep_every_count() {
+        let tmp = std::env::temp_dir().join(format!("edda_stats_race_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        paths.ensure_layout().unwrap();
+        let event = new_note_event("main", None, "user", "hi", &[]).unwrap();
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..25 {
+                        record_append(&paths, &event, 1.0);
+                    }
+                });
+            }
+        });
+
+        let stats = AppendStats::load(&paths).unwrap();
+        assert_eq!(stats.by_type["note"].size.count, 100);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+
+    #[test]
+    fn corrupt_stats_file_resets_instead_of_failing() {
+        let tmp = std::env::temp_dir().join(format!("edda_stats_corrupt_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&tmp);
+        let paths = EddaPaths::discover(&tmp);
+        paths.ensure_layout().unwrap();
+        std::fs::write(&paths.stats_json, r#"{"by_type": {"note": {"cou"#).unwrap();
+
+        assert_eq!(AppendStats::load(&paths).unwrap(), AppendStats::default());
+        let event = new_note_event("main", None, "user", "hi", &[]).unwrap();
+        record_append(&paths, &event, 1.0);
+        let stats = AppendStats::load(&paths).unwrap();
+        assert_eq!(stats.by_type["note"].size.count, 1);
+        let _ = std::fs::remove_dir_all(&tmp);
+    }
+}
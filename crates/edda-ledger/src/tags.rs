@@ -0,0 +1,201 @@
+//! Tag bookkeeping for notes and decisions — counts, renames and queries.
+//!
+//! Tags live in note payloads (`payload.tags`) and, for decisions, also in
+//! the decision payload (`payload.decision.tags`). Renames are appended
+//! `tag_rename` events rather than edits, so every reader resolves tags
+//! through [`TagAliases`] built from those events in ledger order.
+
+use edda_core::types::Event;
+use std::collections::BTreeMap;
+
+/// Tag renames folded from `tag_rename` events: old name → current name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagAliases(BTreeMap<String, String>);
+
+impl TagAliases {
+    /// Fold `tag_rename` events (other event types are ignored) in order.
+    ///
+    /// Aliases are kept flat: renaming `b` → `c` after `a` → `b` makes `a`
+    /// resolve to `c` directly, and renaming a tag back to an old name makes
+    /// that old name canonical again.
+    pub fn from_events(events: &[Event]) -> Self {
+        let mut map: BTreeMap<String, String> = BTreeMap::new();
+        for e in events.iter().filter(|e| e.event_type == "tag_rename") {
+            let (Some(from), Some(to)) = (
+                e.payload.get("from").and_then(|v| v.as_str()),
+                e.payload.get("to").and_then(|v| v.as_str()),
+            ) else {
+                continue;
+            };
+            if from == to {
+                continue;
+            }
+            for target in map.values_mut() {
+                if target == from {
+                    *target = to.to_string();
+                }
+            }
+            map.remove(to);
+            map.retain(|k, v| k != v);
+            map.insert(from.to_string(), to.to_string());
+        }
+        Self(map)
+    }
+
+    /// Current name for `tag`.
+    pub fn resolve<'a>(&'a self, tag: &'a str) -> &'a str {
+        self.0.get(tag).map(String::as_str).unwrap_or(tag)
+    }
+
+    /// Whether `tag` has been renamed away.
+    pub fn is_renamed(&self, tag: &str) -> bool {
+        self.0.contains_key(tag)
+    }
+
+    /// Renames in effect, old name → current name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// Raw tags on a note or decision event, note tags first, without duplicates.
+/// Events of other types have no tags.
+pub fn event_tags(event: &Event) -> Vec<String> {
+    if event.event_type != "note" {
+        return Vec::new();
+    }
+    let note_tags = event.payload.get("tags").and_then(|v| v.as_array());
+    let decision_tags = event
+        .payload
+        .get("decision")
+        .and_then(|d| d.get("tags"))
+        .and_then(|v| v.as_array());
+
+    let mut out: Vec<String> = Vec::new();
+    for t in note_tags
+        .into_iter()
+        .chain(decision_tags)
+        .flatten()
+        .filter_map(|v| v.as_str())
+    {
+        if !out.iter().any(|o| o == t) {
+            out.push(t.to_string());
+        }
+    }
+    out
+}
+
+/// Tags on `event` after applying renames, without duplicates.
+pub fn resolved_tags(event: &Event, aliases: &TagAliases) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for t in event_tags(event) {
+        let t = aliases.resolve(&t).to_string();
+        if !out.contains(&t) {
+            out.push(t);
+        }
+    }
+    out
+}
+
+/// Number of events carrying each (resolved) tag.
+pub fn count_tags(events: &[Event], aliases: &TagAliases) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for e in events {
+        for t in resolved_tags(e, aliases) {
+            *counts.entry(t).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// A tag combination: every `all` tag, at least one `any` tag (when given),
+/// and no `none` tag. Query tags are resolved through renames too, so old
+/// names keep working.
+#[derive(Debug, Clone, Default)]
+pub struct TagQuery {
+    pub all: Vec<String>,
+    pub any: Vec<String>,
+    pub none: Vec<String>,
+}
+
+impl TagQuery {
+    pub fn is_empty(&self) -> bool {
+        self.all.is_empty() && self.any.is_empty() && self.none.is_empty()
+    }
+
+    /// Whether an event with the given resolved tags matches.
+    pub fn matches(&self, tags: &[String], aliases: &TagAliases) -> bool {
+        let has = |q: &String| tags.iter().any(|t| t == aliases.resolve(q));
+        self.all.iter().all(has)
+            && (self.any.is_empty() || self.any.iter().any(has))
+            && !self.none.iter().any(has)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_note_event, new_tag_rename_event};
+
+    fn rename(from: &str, to: &str) -> Event {
+        new_tag_rename_event("main", None, from, to).unwrap()
+    }
+
+    fn note(tags: &[&str]) -> Event {
+        let tags: Vec<String> = tags.iter().map(|t| t.to_string()).collect();
+        new_note_event("main", None, "user", "x", &tags).unwrap()
+    }
+
+    #[test]
+    fn renames_chain_and_can_be_reverted() {
+        let aliases = TagAliases::from_events(&[
+            rename("perf", "performance"),
+            rename("performance", "speed"),
+        ]);
+        assert_eq!(aliases.resolve("perf"), "speed");
+        assert_eq!(aliases.resolve("performance"), "speed");
+        assert_eq!(aliases.resolve("speed"), "speed");
+
+        let aliases = TagAliases::from_events(&[rename("a", "b"), rename("b", "a")]);
+        assert_eq!(aliases.resolve("a"), "a");
+        assert_eq!(aliases.resolve("b"), "a");
+        assert!(!aliases.is_renamed("a"));
+    }
+
+    #[test]
+    fn counts_and_queries_use_resolved_tags() {
+        let events = vec![
+            note(&["perf", "db"]),
+            note(&["performance"]),
+            note(&["db", "todo"]),
+            rename("perf", "performance"),
+        ];
+        let aliases = TagAliases::from_events(&events);
+        let counts = count_tags(&events, &aliases);
+        assert_eq!(counts.get("performance"), Some(&2));
+        assert_eq!(counts.get("db"), Some(&2));
+        assert!(!counts.contains_key("perf"));
+
+        let q = TagQuery {
+            all: vec!["db".into()],
+            any: vec![],
+            none: vec!["todo".into()],
+        };
+        let hits: Vec<_> = events
+            .iter()
+            .filter(|e| q.matches(&resolved_tags(e, &aliases), &aliases))
+            .collect();
+        assert_eq!(hits.len(), 1);
+
+        // The old name still works as a query term.
+        let q = TagQuery {
+            any: vec!["perf".into()],
+            ..Default::default()
+        };
+        let n = events
+            .iter()
+            .filter(|e| q.matches(&resolved_tags(e, &aliases), &aliases))
+            .count();
+        assert_eq!(n, 2);
+    }
+}
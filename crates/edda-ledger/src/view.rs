@@ -21,7 +21,7 @@ pub struct DecisionView {
     pub domain: String,
 
     // Governance state
-    /// "active" | "experimental" | "proposed" | "deprecated" | "superseded"
+    /// "active" | "experimental" | "proposed" | "deprecated" | "superseded" | "revoked"
     pub status: String,
     /// "human" | "agent" | "system"
     pub authority: String,
@@ -0,0 +1,127 @@
+//! Draft governance operations backing the `edda_draft_*` MCP tools.
+//!
+//! Drafts are ledger events (`draft.proposed`, `approval`, ...) folded into
+//! [`edda_ledger::DraftView`] — the same model `edda draft` and the HTTP API
+//! read — and both front ends write them through
+//! [`edda_ledger::draft_ops`], so drafts proposed over MCP can be inspected,
+//! approved and applied from either side. Callers hold the workspace lock.
+
+use edda_derive::{build_auto_evidence, rebuild_all};
+use edda_ledger::draft_ops::{
+    decide_draft, merge_evidence, parse_evidence_arg, propose_draft, DraftDecision,
+    DraftDecisionInput, DraftProposal,
+};
+use edda_ledger::{DraftStatus, Ledger};
+
+/// Input for [`propose`].
+pub(crate) struct ProposeInput<'a> {
+    pub title: &'a str,
+    pub purpose: Option<&'a str>,
+    pub contribution: Option<&'a str>,
+    pub labels: Vec<String>,
+    pub evidence: Vec<String>,
+    pub max_evidence: usize,
+}
+
+/// Summary of a freshly proposed draft.
+#[derive(Debug)]
+pub(crate) struct ProposeOutcome {
+    pub draft_id: String,
+    pub rule_id: String,
+    /// (stage_id, role, min_approvals) for every stage awaiting approval.
+    pub stages: Vec<(String, String, usize)>,
+}
+
+/// Input for [`act`].
+pub(crate) struct ActInput<'a> {
+    pub draft_id: &'a str,
+    pub action: DraftDecision,
+    pub actor: &'a str,
+    /// Role the actor is acting as; picks the first pending stage with this role.
+    pub role: Option<&'a str>,
+    /// Explicit stage to act on (takes precedence over `role`).
+    pub stage: Option<&'a str>,
+    pub note: &'a str,
+}
+
+/// Result of an approve/reject action.
+#[derive(Debug)]
+pub(crate) struct ActOutcome {
+    pub event_id: String,
+    pub stage_id: String,
+    pub stage_status: String,
+    pub draft_status: String,
+}
+
+/// Create a draft commit routed through `policy.yaml`. Evidence is
+/// auto-collected when none is given.
+pub(crate) fn propose(ledger: &Ledger, p: ProposeInput<'_>) -> anyhow::Result<ProposeOutcome> {
+    let manual: Vec<serde_json::Value> = p
+        .evidence
+        .iter()
+        .map(|s| parse_evidence_arg(s))
+        .collect::<anyhow::Result<_>>()?;
+    let (evidence, auto_preview) = if manual.is_empty() {
+        let auto = build_auto_evidence(ledger, &ledger.head_branch()?, p.max_evidence)?;
+        (auto.items, auto.preview_lines)
+    } else {
+        (merge_evidence(manual, Vec::new()), Vec::new())
+    };
+
+    let proposed = propose_draft(
+        ledger,
+        DraftProposal {
+            title: p.title,
+            purpose: p.purpose,
+            contribution: p.contribution,
+            labels: &p.labels,
+            evidence,
+            auto_preview,
+            stages: None,
+        },
+    )?;
+    rebuild_all(ledger)?;
+
+    Ok(ProposeOutcome {
+        draft_id: proposed.draft_id,
+        rule_id: proposed.rule_id,
+        stages: proposed
+            .stages
+            .into_iter()
+            .map(|s| (s.stage_id, s.role, s.min_approvals))
+            .collect(),
+    })
+}
+
+/// Approve or reject a draft (or one of its stages) on behalf of `actor`.
+pub(crate) fn act(ledger: &Ledger, input: ActInput<'_>) -> anyhow::Result<ActOutcome> {
+    let decided = decide_draft(
+        ledger,
+        DraftDecisionInput {
+            draft_id: input.draft_id,
+            decision: input.action,
+            actor: input.actor,
+            note: input.note,
+            stage: input.stage,
+            role: input.role,
+        },
+    )?;
+    rebuild_all(ledger)?;
+
+    let draft = &decided.draft;
+    let stage_status = match draft.stages.iter().find(|s| s.stage_id == decided.stage_id) {
+        Some(stage) => stage.status.to_string(),
+        // Flat drafts report the draft's own decision state.
+        None => match draft.status {
+            DraftStatus::Proposed => "pending".to_string(),
+            other => other.to_string(),
+        },
+    };
+
+    Ok(ActOutcome {
+        event_id: decided.event_id,
+        stage_status,
+        draft_status: draft.status.to_string(),
+        stage_id: decided.stage_id,
+    })
+}
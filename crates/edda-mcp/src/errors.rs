@@ -0,0 +1,136 @@
+//! Error taxonomy for tool and resource failures.
+//!
+//! Every error carries `data: {"code", "hint", "retryable"}` alongside the
+//! JSON-RPC error code, so agent clients can branch on a stable code and
+//! follow the hint instead of pattern-matching the message.
+
+use std::time::{Duration, Instant};
+
+use rmcp::ErrorData as McpError;
+
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::paths::EddaPaths;
+
+/// How long a write tool waits for another process to release the lock.
+const LOCK_WAIT: Duration = Duration::from_secs(2);
+const LOCK_POLL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ErrorCode {
+    /// No `.edda/` at or above the server's repo root.
+    WorkspaceNotInitialized,
+    /// Another process held the workspace lock for the whole wait.
+    LockTimeout,
+    /// A decision that is not `key=value`.
+    InvalidDecisionFormat,
+    /// A branch argument that names no branch in the workspace.
+    BranchNotFound,
+    /// Branch protection or approval policy refused the write.
+    PolicyDenied,
+    /// Any other malformed argument.
+    InvalidParams,
+    /// An event, decision or resource that does not exist.
+    NotFound,
+    /// Everything else: I/O, SQLite, serialization.
+    Internal,
+}
+
+impl ErrorCode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::WorkspaceNotInitialized => "workspace_not_initialized",
+            Self::LockTimeout => "lock_timeout",
+            Self::InvalidDecisionFormat => "invalid_decision_format",
+            Self::BranchNotFound => "branch_not_found",
+            Self::PolicyDenied => "policy_denied",
+            Self::InvalidParams => "invalid_params",
+            Self::NotFound => "not_found",
+            Self::Internal => "internal_error",
+        }
+    }
+
+    /// The next action that usually resolves this error.
+    fn hint(self) -> &'static str {
+        match self {
+            Self::WorkspaceNotInitialized => {
+                "Run `edda init` in the repository root, then retry."
+            }
+            Self::LockTimeout => {
+                "Another edda process is writing. Retry in a few seconds; if it persists, check for a stuck `edda` process holding .edda/LOCK."
+            }
+            Self::InvalidDecisionFormat => {
+                "Pass the decision as key=value with a dotted key, e.g. \"db.engine=postgres\"."
+            }
+            Self::BranchNotFound => {
+                "Omit the branch to use the current one, or call edda_status to see the current branch."
+            }
+            Self::PolicyDenied => {
+                "Retry with an actor whose role may override the policy, or propose the change with edda_draft_propose."
+            }
+            Self::InvalidParams => "Check the tool's input schema and fix the named argument.",
+            Self::NotFound => "Use edda_ask or edda_log to find a valid id or key.",
+            Self::Internal => {
+                "Retry once; if it fails again, run `edda doctor workspace` and report the message."
+            }
+        }
+    }
+
+    /// Whether the same call may succeed if simply retried.
+    fn retryable(self) -> bool {
+        matches!(self, Self::LockTimeout | Self::Internal)
+    }
+
+    /// Build the protocol error for this code.
+    pub(crate) fn err(self, message: impl Into<String>) -> McpError {
+        let data = Some(serde_json::json!({
+            "code": self.as_str(),
+            "hint": self.hint(),
+            "retryable": self.retryable(),
+        }));
+        let message = message.into();
+        match self {
+            Self::InvalidDecisionFormat | Self::BranchNotFound | Self::InvalidParams => {
+                McpError::invalid_params(message, data)
+            }
+            Self::WorkspaceNotInitialized | Self::PolicyDenied => {
+                McpError::invalid_request(message, data)
+            }
+            Self::NotFound => McpError::resource_not_found(message, data),
+            Self::LockTimeout | Self::Internal => McpError::internal_error(message, data),
+        }
+    }
+}
+
+/// Take the workspace lock, waiting briefly for a concurrent writer.
+pub(crate) fn acquire_lock(paths: &EddaPaths) -> Result<WorkspaceLock, McpError> {
+    let deadline = Instant::now() + LOCK_WAIT;
+    loop {
+        match WorkspaceLock::acquire(paths) {
+            Ok(lock) => return Ok(lock),
+            Err(e) if Instant::now() >= deadline => {
+                return Err(
+                    ErrorCode::LockTimeout.err(format!("{e} (waited {}s)", LOCK_WAIT.as_secs()))
+                )
+            }
+            Err(_) => std::thread::sleep(LOCK_POLL),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_data_carries_code_hint_and_retryable() {
+        let err = ErrorCode::LockTimeout.err("locked");
+        let data = err.data.unwrap();
+        assert_eq!(data["code"], "lock_timeout");
+        assert_eq!(data["retryable"], true);
+        assert!(data["hint"].as_str().unwrap().contains("Retry"));
+
+        let err = ErrorCode::InvalidDecisionFormat.err("bad");
+        assert_eq!(err.code, rmcp::model::ErrorCode::INVALID_PARAMS);
+        assert_eq!(err.data.unwrap()["retryable"], false);
+    }
+}
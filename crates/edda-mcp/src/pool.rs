@@ -0,0 +1,233 @@
+//! Shared ledger handles for tool calls.
+//!
+//! Opening a `Ledger` discovers the workspace, opens `ledger.db` and runs
+//! schema setup; doing that on every tool call dominates latency when an
+//! agent fires a burst of calls. The pool keeps a few open handles and lends
+//! them out, one call at a time, so concurrent calls never share a SQLite
+//! connection.
+//!
+//! A handle's SQLite reads always see other processes' appends, but the
+//! handle itself can go stale: `ledger.db` may be replaced (`edda rebuild`,
+//! a restore), and the append authorizer and signer are loaded once from
+//! `policy.yaml` and `config.json`. Each handle remembers those files as they
+//! were when it was opened and is reopened on checkout when they change, or
+//! when probing the branch tip through it fails.
+
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use edda_ledger::paths::EddaPaths;
+use edda_ledger::Ledger;
+use rmcp::ErrorData as McpError;
+
+use crate::errors::ErrorCode;
+
+/// Most idle handles kept open; extra handles from a burst are closed.
+const MAX_IDLE: usize = 4;
+
+pub(crate) struct LedgerPool {
+    repo_root: PathBuf,
+    idle: Mutex<Vec<Idle>>,
+}
+
+struct Idle {
+    ledger: Ledger,
+    stamp: Stamp,
+}
+
+/// The workspace files a handle depends on, as they were when it opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Stamp {
+    ledger_db: Option<u128>,
+    policy: Option<SystemTime>,
+    config: Option<SystemTime>,
+}
+
+impl Stamp {
+    fn read(paths: &EddaPaths) -> Self {
+        Self {
+            ledger_db: std::fs::metadata(&paths.ledger_db)
+                .ok()
+                .and_then(|m| file_identity(&m)),
+            policy: modified(&paths.edda_dir.join("policy.yaml")),
+            config: modified(&paths.config_json),
+        }
+    }
+}
+
+impl LedgerPool {
+    pub(crate) fn new(repo_root: PathBuf) -> Self {
+        Self {
+            repo_root,
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Lend out an open handle, reusing an idle one that is still current.
+    pub(crate) fn get(self: &Arc<Self>) -> Result<PooledLedger, McpError> {
+        loop {
+            let Some(idle) = self.lock_idle().pop() else {
+                break;
+            };
+            if idle.ledger.paths.is_initialized()
+                && Stamp::read(&idle.ledger.paths) == idle.stamp
+                && idle.ledger.head_branch().is_ok()
+            {
+                return Ok(self.lend(idle));
+            }
+        }
+
+        let paths = EddaPaths::discover(&self.repo_root);
+        if !paths.is_initialized() {
+            return Err(ErrorCode::WorkspaceNotInitialized.err(format!(
+                "not an edda workspace: {}",
+                self.repo_root.display()
+            )));
+        }
+        let stamp = Stamp::read(&paths);
+        let ledger = Ledger::open(&self.repo_root).map_err(crate::to_mcp_err)?;
+        Ok(self.lend(Idle { ledger, stamp }))
+    }
+
+    fn lend(self: &Arc<Self>, idle: Idle) -> PooledLedger {
+        let actor = idle.ledger.actor().map(str::to_string);
+        PooledLedger {
+            idle: Some(idle),
+            actor,
+            pool: Arc::clone(self),
+        }
+    }
+
+    fn put_back(&self, idle: Idle) {
+        let mut pool = self.lock_idle();
+        if pool.len() < MAX_IDLE {
+            pool.push(idle);
+        }
+    }
+
+    fn lock_idle(&self) -> std::sync::MutexGuard<'_, Vec<Idle>> {
+        self.idle.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn idle_count(&self) -> usize {
+        self.lock_idle().len()
+    }
+}
+
+/// A ledger handle borrowed from a [`LedgerPool`]; returned to it on drop.
+pub(crate) struct PooledLedger {
+    idle: Option<Idle>,
+    /// Actor the handle had when lent, restored before it goes back.
+    actor: Option<String>,
+    pool: Arc<LedgerPool>,
+}
+
+impl Deref for PooledLedger {
+    type Target = Ledger;
+
+    fn deref(&self) -> &Ledger {
+        &self.idle.as_ref().expect("pooled ledger present").ledger
+    }
+}
+
+impl DerefMut for PooledLedger {
+    fn deref_mut(&mut self) -> &mut Ledger {
+        &mut self.idle.as_mut().expect("pooled ledger present").ledger
+    }
+}
+
+impl Drop for PooledLedger {
+    fn drop(&mut self) {
+        if let Some(mut idle) = self.idle.take() {
+            idle.ledger.set_actor(self.actor.as_deref());
+            self.pool.put_back(idle);
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Identity of the file behind a path, so a replaced `ledger.db` is noticed
+/// even when it has the same size and mtime.
+#[cfg(unix)]
+fn file_identity(meta: &std::fs::Metadata) -> Option<u128> {
+    use std::os::unix::fs::MetadataExt;
+    Some((u128::from(meta.dev()) << 64) | u128::from(meta.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(meta: &std::fs::Metadata) -> Option<u128> {
+    meta.created()
+        .ok()
+        .and_then(|t| t.duration_since(SystemTime::UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_workspace() -> (tempfile::TempDir, PathBuf) {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let root = tmp.path().to_path_buf();
+        let paths = EddaPaths::discover(&root);
+        paths.ensure_layout().unwrap();
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        (tmp, root)
+    }
+
+    #[test]
+    fn reuses_returned_handles_up_to_the_idle_cap() {
+        let (_tmp, root) = setup_workspace();
+        let pool = Arc::new(LedgerPool::new(root));
+
+        drop(pool.get().unwrap());
+        assert_eq!(pool.idle_count(), 1);
+        let held: Vec<_> = (0..MAX_IDLE + 2).map(|_| pool.get().unwrap()).collect();
+        assert_eq!(pool.idle_count(), 0);
+        drop(held);
+        assert_eq!(pool.idle_count(), MAX_IDLE);
+    }
+
+    #[test]
+    fn restores_actor_before_returning_a_handle() {
+        let (_tmp, root) = setup_workspace();
+        let pool = Arc::new(LedgerPool::new(root));
+
+        let default_actor = pool.get().unwrap().actor().map(str::to_string);
+        let mut ledger = pool.get().unwrap();
+        ledger.set_actor(Some("lead"));
+        drop(ledger);
+        assert_eq!(pool.get().unwrap().actor(), default_actor.as_deref());
+    }
+
+    #[test]
+    fn reopens_when_policy_changes() {
+        let (_tmp, root) = setup_workspace();
+        let pool = Arc::new(LedgerPool::new(root.clone()));
+        drop(pool.get().unwrap());
+        let before = pool.lock_idle()[0].stamp.clone();
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        std::fs::write(root.join(".edda").join("policy.yaml"), "roles: []\n").unwrap();
+        drop(pool.get().unwrap());
+        assert_ne!(pool.lock_idle()[0].stamp, before);
+    }
+
+    #[test]
+    fn uninitialized_workspace_is_reported() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pool = Arc::new(LedgerPool::new(tmp.path().to_path_buf()));
+        let Err(err) = pool.get() else {
+            panic!("expected an error");
+        };
+        assert_eq!(err.data.unwrap()["code"], "workspace_not_initialized");
+    }
+}
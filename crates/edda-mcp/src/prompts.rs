@@ -0,0 +1,302 @@
+//! Prompt templates behind the MCP prompts capability.
+//!
+//! Each prompt expands into one user message pre-filled from the workspace
+//! (context snapshot, open peer requests, drafts awaiting approval), so an
+//! MCP client can start a recap, a decision or a handoff with one command
+//! instead of the user assembling tool calls by hand.
+
+use rmcp::model::{
+    GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+
+use edda_derive::{render_context, DeriveOptions};
+use edda_ledger::{DraftStatus, Ledger};
+
+pub(crate) const RECAP: &str = "recap";
+pub(crate) const DECIDE: &str = "decide";
+pub(crate) const HANDOFF: &str = "handoff";
+
+/// Why a prompt could not be expanded.
+#[derive(Debug)]
+pub(crate) enum PromptError {
+    Unknown(String),
+    MissingArgument(&'static str),
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for PromptError {
+    fn from(e: anyhow::Error) -> Self {
+        Self::Internal(e)
+    }
+}
+
+fn argument(name: &str, description: &str, required: bool) -> PromptArgument {
+    PromptArgument {
+        name: name.into(),
+        title: None,
+        description: Some(description.into()),
+        required: Some(required),
+    }
+}
+
+/// The prompts the server advertises.
+pub(crate) fn list() -> Vec<Prompt> {
+    vec![
+        Prompt::new(
+            RECAP,
+            Some("Recap where the project stands, from the ledger's current context"),
+            Some(vec![argument(
+                "focus",
+                "Area to concentrate on (e.g. auth)",
+                false,
+            )]),
+        ),
+        Prompt::new(
+            DECIDE,
+            Some("Work through a decision with the existing ones on the topic, then record it"),
+            Some(vec![argument(
+                "topic",
+                "What to decide (a domain like db, a key, or keywords)",
+                true,
+            )]),
+        ),
+        Prompt::new(
+            HANDOFF,
+            Some("Write a handoff with context, open requests and pending approvals"),
+            Some(vec![argument(
+                "to",
+                "Who picks up the work (a peer label or person)",
+                false,
+            )]),
+        ),
+    ]
+}
+
+/// Expand prompt `name` against the workspace.
+pub(crate) fn render(
+    ledger: &Ledger,
+    project_id: &str,
+    name: &str,
+    args: Option<&JsonObject>,
+) -> Result<GetPromptResult, PromptError> {
+    let arg = |key: &str| {
+        args.and_then(|a| a.get(key))
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let head = ledger.head_branch()?;
+    let context = render_context(ledger, &head, DeriveOptions { depth: 5 })?;
+
+    let (description, text) = match name {
+        RECAP => {
+            let focus = arg("focus")
+                .map(|f| format!(" Concentrate on {f}."))
+                .unwrap_or_default();
+            let text = format!(
+                "Write a short recap of where this project stands for someone picking it up cold: \
+                 what was decided and why, what changed recently, and what is still open.{focus} \
+                 Use only the workspace state below and cite event ids where you rely on them.\n\n\
+                 {}{}",
+                section("Workspace context", &context),
+                section("Pending approvals", &pending_approvals(ledger)?.join("\n")),
+            );
+            ("Project recap", text)
+        }
+        DECIDE => {
+            let topic = arg("topic").ok_or(PromptError::MissingArgument("topic"))?;
+            let opts = edda_ask::AskOptions {
+                limit: 10,
+                ..Default::default()
+            };
+            let result = edda_ask::ask(ledger, &topic, &opts, None)?;
+            let existing: Vec<String> = result
+                .decisions
+                .iter()
+                .map(|d| {
+                    let reason = if d.reason.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" — {}", d.reason)
+                    };
+                    format!("- {} = {}{reason} ({})", d.key, d.value, d.event_id)
+                })
+                .collect();
+            let text = format!(
+                "Help me decide about {topic}. Check the decisions already recorded below first: \
+                 say whether this confirms, changes or conflicts with any of them. Lay out the \
+                 options and recommend one. Once I agree, record it with the `edda_decide` tool \
+                 as `key=value` with a reason, reusing an existing key when the new decision \
+                 replaces it.\n\n{}{}",
+                section("Existing decisions", &existing.join("\n")),
+                section("Workspace context", &context),
+            );
+            ("Decision", text)
+        }
+        HANDOFF => {
+            let to = arg("to").unwrap_or_else(|| "the next session".to_string());
+            let text = format!(
+                "Write a handoff for {to}: what was being worked on, what is done, what is \
+                 half-done and the next concrete step, plus the open requests and approvals \
+                 below that they need to act on. Keep it short enough to read at a glance, then \
+                 save it with the `edda_note` tool tagged `handoff`.\n\n{}{}{}",
+                section("Workspace context", &context),
+                section("Open requests", &open_requests(project_id).join("\n")),
+                section("Pending approvals", &pending_approvals(ledger)?.join("\n")),
+            );
+            ("Handoff", text)
+        }
+        other => return Err(PromptError::Unknown(other.to_string())),
+    };
+    Ok(GetPromptResult {
+        description: Some(description.into()),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+fn section(title: &str, body: &str) -> String {
+    let body = body.trim();
+    let body = if body.is_empty() { "(none)" } else { body };
+    format!("## {title}\n\n{body}\n\n")
+}
+
+/// Drafts still waiting on approval, one line each.
+fn pending_approvals(ledger: &Ledger) -> anyhow::Result<Vec<String>> {
+    Ok(ledger
+        .draft_views()?
+        .iter()
+        .filter(|d| d.status == DraftStatus::Proposed)
+        .map(|d| {
+            let waiting: Vec<String> = d
+                .pending_stages()
+                .map(|s| format!("{} ({}/{})", s.role, s.approved_by.len(), s.min_approvals))
+                .collect();
+            if waiting.is_empty() {
+                format!("- {} \"{}\"", d.draft_id, d.title)
+            } else {
+                format!(
+                    "- {} \"{}\" waiting on {}",
+                    d.draft_id,
+                    d.title,
+                    waiting.join(", ")
+                )
+            }
+        })
+        .collect())
+}
+
+/// Peer requests on the coordination board nobody has acknowledged yet.
+fn open_requests(project_id: &str) -> Vec<String> {
+    let board = edda_bridge_claude::peers::compute_board_state(project_id);
+    edda_bridge_claude::handoff::open_requests(&board)
+        .iter()
+        .map(|r| {
+            format!(
+                "- [{}] → [{}]: {} ({})",
+                r.from_label, r.to_label, r.message, r.ts
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_decision_event, new_draft_proposed_event, DraftProposedParams};
+    use edda_core::types::DecisionPayload;
+
+    fn text(result: &GetPromptResult) -> &str {
+        match &result.messages[0].content {
+            rmcp::model::PromptMessageContent::Text { text } => text,
+            other => panic!("unexpected content {other:?}"),
+        }
+    }
+
+    #[test]
+    fn prompts_expand_from_the_ledger() {
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = edda_ledger::EddaPaths::discover(tmp.path());
+        paths.ensure_layout().unwrap();
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+
+        let decision = new_decision_event(
+            "main",
+            None,
+            "system",
+            &DecisionPayload {
+                key: "db.engine".into(),
+                value: "postgres".into(),
+                reason: Some("JSONB".into()),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            },
+        )
+        .unwrap();
+        ledger.append_event(&decision).unwrap();
+        let stages = vec![edda_core::event::DraftStageSpec {
+            stage_id: "lead".into(),
+            role: "lead".into(),
+            min_approvals: 1,
+            assignees: vec![],
+        }];
+        let draft = new_draft_proposed_event(&DraftProposedParams {
+            branch: "main",
+            parent_hash: ledger.last_event_hash().unwrap().as_deref(),
+            draft_id: "drf_pool",
+            created_at: None,
+            base_parent_hash: "",
+            title: "Raise pool size",
+            purpose: "",
+            contribution: "",
+            labels: &[],
+            evidence: &[],
+            auto_preview_lines: &[],
+            route_rule_id: "",
+            stages: &stages,
+            policy_require_approval: true,
+            policy_min_approvals: 1,
+            origin: None,
+        })
+        .unwrap();
+        ledger.append_event(&draft).unwrap();
+        let project_id = edda_store::project_id(tmp.path());
+
+        let names: Vec<_> = list().into_iter().map(|p| p.name).collect();
+        assert_eq!(names, [RECAP, DECIDE, HANDOFF]);
+
+        let args: JsonObject =
+            serde_json::from_value(serde_json::json!({ "topic": "db" })).unwrap();
+        let decide = render(&ledger, &project_id, DECIDE, Some(&args)).unwrap();
+        assert!(text(&decide).contains("Help me decide about db."));
+        assert!(text(&decide).contains(&format!(
+            "- db.engine = postgres — JSONB ({})",
+            decision.event_id
+        )));
+
+        let handoff = render(&ledger, &project_id, HANDOFF, None).unwrap();
+        assert!(text(&handoff).contains("Write a handoff for the next session"));
+        assert!(text(&handoff).contains("- drf_pool \"Raise pool size\" waiting on lead (0/1)"));
+        assert!(text(&handoff).contains("## Open requests\n\n(none)"));
+
+        let recap = render(&ledger, &project_id, RECAP, None).unwrap();
+        assert!(text(&recap).contains("## Workspace context"));
+
+        assert!(matches!(
+            render(&ledger, &project_id, DECIDE, None),
+            Err(PromptError::MissingArgument("topic"))
+        ));
+        assert!(matches!(
+            render(&ledger, &project_id, "nope", None),
+            Err(PromptError::Unknown(_))
+        ));
+    }
+}
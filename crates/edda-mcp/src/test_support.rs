@@ -0,0 +1,42 @@
+//! Test-only helpers shared by the test modules.
+
+use std::ffi::OsString;
+use std::sync::{Mutex, MutexGuard};
+
+/// Serialize tests that redirect `EDDA_STORE_ROOT`: the variable is
+/// process-wide and cargo runs a crate's tests on parallel threads.
+static ENV_STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Holds the redirect for the duration of a test and puts back whatever was
+/// there on drop, so a failing assert cannot leave `EDDA_STORE_ROOT`
+/// pointing at a deleted directory.
+pub(crate) struct IsolatedStore {
+    prev: Option<OsString>,
+    _dir: tempfile::TempDir,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl Drop for IsolatedStore {
+    fn drop(&mut self) {
+        match self.prev.take() {
+            Some(prev) => std::env::set_var("EDDA_STORE_ROOT", prev),
+            None => std::env::remove_var("EDDA_STORE_ROOT"),
+        }
+    }
+}
+
+/// Point the per-user store at a throwaway directory for this test, so
+/// tools that write coordination or scratch files stay off the developer's
+/// real store. Keep the returned value alive for the whole test.
+pub(crate) fn isolated_store() -> IsolatedStore {
+    // One panicking test poisons the lock; that must not fail the rest.
+    let guard = ENV_STORE_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let prev = std::env::var_os("EDDA_STORE_ROOT");
+    let dir = tempfile::tempdir().expect("tempdir for isolated store");
+    std::env::set_var("EDDA_STORE_ROOT", dir.path());
+    IsolatedStore {
+        prev,
+        _dir: dir,
+        _guard: guard,
+    }
+}
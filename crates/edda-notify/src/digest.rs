@@ -0,0 +1,478 @@
+//! Digest delivery — one scheduled summary instead of a message per event.
+//!
+//! A channel with `"mode": "digest"` gets nothing from [`crate::dispatch`]
+//! right away. When its `schedule` comes due, it gets one `digest` message
+//! covering everything since the last one: phase changes and decisions read
+//! back from the ledger, plus the anomalies, approvals, gates and session
+//! ends it subscribes to, which are held in `.edda/notify_digest.json` until
+//! then.
+//!
+//! ```json
+//! {"type": "telegram", "bot_token": "…", "chat_id": "…",
+//!  "events": ["phase_change", "decision", "anomaly"],
+//!  "mode": "digest", "schedule": "daily@18:00+02:00"}
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::{parse_hhmm, parse_utc_offset, rfc3339, Channel, NotifyConfig, NotifyEvent};
+
+/// Entries listed per digest section before the rest is counted.
+const MAX_SECTION_LINES: usize = 10;
+
+/// How a channel receives notifications.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChannelMode {
+    /// One message per event (the default).
+    #[default]
+    Immediate,
+    /// A scheduled summary; see [`DigestSchedule`].
+    Digest,
+}
+
+/// When a digest channel is sent its summary:
+///
+/// - `hourly` — at the top of every hour;
+/// - `daily@HH:MM` (optionally `daily@HH:MM+02:00`) — once a day at that
+///   local time; plain `daily` is midnight UTC;
+/// - `every 30m`, `every 4h` — at a fixed interval after the last digest.
+#[derive(Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(try_from = "String")]
+pub enum DigestSchedule {
+    #[default]
+    Hourly,
+    Daily {
+        at_secs: u32,
+        offset: UtcOffset,
+    },
+    Every(Duration),
+}
+
+impl TryFrom<String> for DigestSchedule {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::str::FromStr for DigestSchedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let bad = || {
+            format!("invalid digest schedule {s:?}: expected hourly, daily@HH:MM or every <N>m|h")
+        };
+        if s == "hourly" {
+            return Ok(Self::Hourly);
+        }
+        if s == "daily" {
+            return Ok(Self::Daily {
+                at_secs: 0,
+                offset: UtcOffset::UTC,
+            });
+        }
+        if let Some(rest) = s.strip_prefix("daily@") {
+            // `HH:MM` is five characters; anything after is the UTC offset.
+            let (at, offset) = rest.split_at(rest.len().min(5));
+            let at_secs = parse_hhmm(at).ok_or_else(bad)?;
+            let offset = if offset.is_empty() {
+                UtcOffset::UTC
+            } else {
+                parse_utc_offset(offset).ok_or_else(bad)?
+            };
+            return Ok(Self::Daily { at_secs, offset });
+        }
+        if let Some(rest) = s.strip_prefix("every ") {
+            let rest = rest.trim();
+            let (n, unit) = rest.split_at(rest.len().saturating_sub(1));
+            let n: u64 = n.trim().parse().map_err(|_| bad())?;
+            let secs = match unit {
+                "m" => n * 60,
+                "h" => n * 3600,
+                _ => return Err(bad()),
+            };
+            if secs == 0 {
+                return Err(bad());
+            }
+            return Ok(Self::Every(Duration::from_secs(secs)));
+        }
+        Err(bad())
+    }
+}
+
+impl fmt::Display for DigestSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hourly => write!(f, "hourly"),
+            Self::Daily { at_secs, offset } => {
+                write!(f, "daily@{:02}:{:02}", at_secs / 3600, at_secs % 3600 / 60)?;
+                if !offset.is_utc() {
+                    let (h, m, _) = offset.as_hms();
+                    let sign = if offset.is_negative() { '-' } else { '+' };
+                    write!(f, "{sign}{:02}:{:02}", h.abs(), m.abs())?;
+                }
+                Ok(())
+            }
+            Self::Every(d) if d.as_secs() % 3600 == 0 => write!(f, "every {}h", d.as_secs() / 3600),
+            Self::Every(d) => write!(f, "every {}m", d.as_secs() / 60),
+        }
+    }
+}
+
+impl DigestSchedule {
+    /// The first time a digest is due after one was sent at `last`.
+    pub fn next_after(&self, last: OffsetDateTime) -> OffsetDateTime {
+        match *self {
+            Self::Hourly => {
+                let hour = last
+                    .replace_minute(0)
+                    .and_then(|t| t.replace_second(0))
+                    .and_then(|t| t.replace_nanosecond(0))
+                    .unwrap_or(last);
+                hour + Duration::from_secs(3600)
+            }
+            Self::Daily { at_secs, offset } => {
+                let local = last.to_offset(offset);
+                let at =
+                    time::Time::from_hms((at_secs / 3600) as u8, (at_secs % 3600 / 60) as u8, 0)
+                        .unwrap_or(time::Time::MIDNIGHT);
+                let today = local.replace_time(at);
+                if today > local {
+                    today
+                } else {
+                    today + Duration::from_secs(24 * 3600)
+                }
+            }
+            Self::Every(d) => last + d,
+        }
+    }
+}
+
+/// Outcome of a digest pass.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DigestReport {
+    /// Digests delivered.
+    pub sent: usize,
+    /// Channels that were due but had nothing to report.
+    pub empty: usize,
+    /// Digests whose send failed; they are retried on the next pass.
+    pub failed: usize,
+}
+
+/// Per-channel digest state, keyed by [`Channel::display_name`].
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct DigestState {
+    /// End of the window the last digest covered; the next one starts here.
+    since: String,
+    /// Notifications held for the next digest.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pending: Vec<NotifyEvent>,
+}
+
+/// When each digest channel's next summary is due. A channel that has never
+/// been seen starts its first window now.
+pub fn next_digests(config: &NotifyConfig) -> Vec<(String, DigestSchedule, OffsetDateTime)> {
+    let now = OffsetDateTime::now_utc();
+    let state = load_state(config);
+    digest_channels(config)
+        .map(|(channel, schedule)| {
+            let name = channel.display_name();
+            let since = state
+                .get(&name)
+                .and_then(|s| parse_ts(&s.since))
+                .unwrap_or(now);
+            (name, schedule, schedule.next_after(since))
+        })
+        .collect()
+}
+
+/// Hold `event` for every digest channel subscribed to it. Phase changes are
+/// skipped: the digest reads them from the ledger.
+pub(crate) fn hold(config: &NotifyConfig, event: &NotifyEvent) {
+    if matches!(
+        event,
+        NotifyEvent::PhaseChange { .. } | NotifyEvent::Digest { .. }
+    ) {
+        return;
+    }
+    let subscribed: Vec<String> = digest_channels(config)
+        .filter(|(c, _)| c.accepts(event))
+        .map(|(c, _)| c.display_name())
+        .collect();
+    if subscribed.is_empty() {
+        return;
+    }
+    let now = rfc3339(OffsetDateTime::now_utc());
+    let mut state = load_state(config);
+    for name in subscribed {
+        let entry = state.entry(name).or_insert_with(|| DigestState {
+            since: now.clone(),
+            ..Default::default()
+        });
+        if !entry.pending.contains(event) {
+            entry.pending.push(event.clone());
+        }
+    }
+    save_state(config, &state);
+}
+
+/// Send every digest whose schedule has come due, or all of them with
+/// `force`. Quiet hours hold a due digest until they end, unless forced.
+pub fn send_due_digests(config: &NotifyConfig, force: bool) -> DigestReport {
+    let mut report = DigestReport::default();
+    let channels: Vec<_> = digest_channels(config).collect();
+    if channels.is_empty() {
+        return report;
+    }
+    let now = OffsetDateTime::now_utc();
+    let quiet = config.quiet_remaining().is_some();
+    let mut state = load_state(config);
+    let mut changed = false;
+    let agent = crate::make_agent();
+
+    for (channel, schedule) in channels {
+        let name = channel.display_name();
+        let entry = state.entry(name.clone()).or_insert_with(|| {
+            changed = true;
+            DigestState {
+                since: rfc3339(now),
+                ..Default::default()
+            }
+        });
+        let since = parse_ts(&entry.since).unwrap_or(now);
+        let due = schedule.next_after(since) <= now;
+        if !force && (!due || quiet) {
+            continue;
+        }
+
+        changed = true;
+        let Some(digest) = build_digest(config, channel, schedule, since, &entry.pending) else {
+            entry.since = rfc3339(now);
+            report.empty += 1;
+            continue;
+        };
+        match crate::send(&agent, &config.templates, channel, &digest, 0) {
+            Ok(()) => {
+                entry.since = rfc3339(now);
+                entry.pending.clear();
+                report.sent += 1;
+            }
+            Err(e) => {
+                tracing::warn!(channel = %name, error = %e, "digest send failed");
+                report.failed += 1;
+            }
+        }
+    }
+    if changed {
+        save_state(config, &state);
+    }
+    report
+}
+
+fn digest_channels(config: &NotifyConfig) -> impl Iterator<Item = (&Channel, DigestSchedule)> {
+    config
+        .channels
+        .iter()
+        .filter(|c| c.mode() == ChannelMode::Digest)
+        .map(|c| (c, c.schedule().unwrap_or_default()))
+}
+
+/// The digest for `channel` covering `since` until now, or `None` when there
+/// is nothing to report.
+fn build_digest(
+    config: &NotifyConfig,
+    channel: &Channel,
+    schedule: DigestSchedule,
+    since: OffsetDateTime,
+    pending: &[NotifyEvent],
+) -> Option<NotifyEvent> {
+    let (phases, decisions) = ledger_activity(config.repo_root.as_ref(), since);
+    let phases = if channel.subscribes("phase_change") {
+        phases
+    } else {
+        Vec::new()
+    };
+    let decisions = if channel.subscribes("decision") {
+        decisions
+    } else {
+        Vec::new()
+    };
+    let (anomalies, other): (Vec<&NotifyEvent>, Vec<&NotifyEvent>) = pending
+        .iter()
+        .partition(|e| matches!(e, NotifyEvent::Anomaly { .. }));
+    if phases.is_empty() && decisions.is_empty() && pending.is_empty() {
+        return None;
+    }
+
+    let anomaly_lines: Vec<String> = anomalies
+        .iter()
+        .map(|e| match e {
+            NotifyEvent::Anomaly {
+                signal_type,
+                count,
+                detail,
+            } if !detail.is_empty() => format!("{signal_type} x{count}: {detail}"),
+            _ => crate::format_ntfy(e).0,
+        })
+        .collect();
+    let other_lines: Vec<String> = other.iter().map(|e| crate::format_ntfy(e).0).collect();
+
+    let mut summary = String::new();
+    section(&mut summary, "Phase changes", &phases);
+    section(&mut summary, "Decisions", &decisions);
+    section(&mut summary, "Anomalies", &anomaly_lines);
+    section(&mut summary, "Other", &other_lines);
+
+    Some(NotifyEvent::Digest {
+        schedule: schedule.to_string(),
+        since: rfc3339(since),
+        phase_changes: phases.len(),
+        decisions: decisions.len(),
+        anomalies: anomaly_lines.len(),
+        summary: summary.trim_end().to_string(),
+    })
+}
+
+fn section(out: &mut String, title: &str, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+    out.push_str(&format!("{title} ({}):\n", lines.len()));
+    for line in lines.iter().take(MAX_SECTION_LINES) {
+        out.push_str(&format!("- {line}\n"));
+    }
+    if lines.len() > MAX_SECTION_LINES {
+        out.push_str(&format!(
+            "- … and {} more\n",
+            lines.len() - MAX_SECTION_LINES
+        ));
+    }
+}
+
+/// Phase changes and decisions recorded in the ledger after `since`.
+fn ledger_activity(
+    repo_root: Option<&PathBuf>,
+    since: OffsetDateTime,
+) -> (Vec<String>, Vec<String>) {
+    let events = match repo_root.map(edda_ledger::Ledger::open) {
+        Some(Ok(ledger)) => ledger.iter_events().unwrap_or_default(),
+        Some(Err(e)) => {
+            tracing::warn!(error = %e, "digest could not open the ledger");
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let mut phases = Vec::new();
+    let mut decisions = Vec::new();
+    for event in events
+        .iter()
+        .filter(|e| parse_ts(&e.ts).is_some_and(|t| t > since))
+    {
+        let p = &event.payload;
+        let field = |k: &str| p.get(k).and_then(|v| v.as_str()).unwrap_or("");
+        if event.event_type == "agent_phase_change" {
+            let who = match p.get("label").and_then(|v| v.as_str()) {
+                Some(label) => format!("{} ({label})", field("session_id")),
+                None => field("session_id").to_string(),
+            };
+            phases.push(format!("{who}: {} -> {}", field("from"), field("to")));
+        } else if edda_core::decision::is_decision(p) {
+            if let Some(d) = edda_core::decision::extract_decision(p) {
+                let mut line = format!("{}={}", d.key, d.value);
+                if let Some(reason) = d.reason {
+                    line.push_str(&format!(" — {reason}"));
+                }
+                decisions.push(line);
+            }
+        }
+    }
+    (phases, decisions)
+}
+
+fn parse_ts(ts: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(ts, &Rfc3339).ok()
+}
+
+/// `.edda/notify_digest.json`, next to the queue.
+fn state_path(config: &NotifyConfig) -> Option<PathBuf> {
+    config
+        .queue_path
+        .as_ref()
+        .map(|p| p.with_file_name("notify_digest.json"))
+}
+
+fn load_state(config: &NotifyConfig) -> BTreeMap<String, DigestState> {
+    state_path(config)
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|c| serde_json::from_str(&c).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(config: &NotifyConfig, state: &BTreeMap<String, DigestState>) {
+    let Some(path) = state_path(config) else {
+        return;
+    };
+    let result = serde_json::to_string_pretty(state)
+        .map_err(anyhow::Error::from)
+        .and_then(|s| std::fs::write(&path, s).map_err(Into::into));
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "failed to save digest state");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(s, &Rfc3339).unwrap()
+    }
+
+    #[test]
+    fn schedules_parse_and_round_trip() {
+        for s in [
+            "hourly",
+            "daily@18:00",
+            "daily@18:00+02:00",
+            "every 30m",
+            "every 4h",
+        ] {
+            let schedule: DigestSchedule = s.parse().unwrap();
+            assert_eq!(schedule.to_string(), s);
+        }
+        assert_eq!(
+            "daily".parse::<DigestSchedule>().unwrap().to_string(),
+            "daily@00:00"
+        );
+        for bad in ["weekly", "daily@25:00", "every 0m", "every 5d", "every m"] {
+            assert!(bad.parse::<DigestSchedule>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn next_digest_time_follows_the_schedule() {
+        let last = at("2026-07-01T10:20:00Z");
+        assert_eq!(
+            DigestSchedule::Hourly.next_after(last),
+            at("2026-07-01T11:00:00Z")
+        );
+        let daily: DigestSchedule = "daily@18:00+02:00".parse().unwrap();
+        assert_eq!(daily.next_after(last), at("2026-07-01T16:00:00Z"));
+        assert_eq!(
+            daily.next_after(at("2026-07-01T16:00:00Z")),
+            at("2026-07-02T16:00:00Z")
+        );
+        let every: DigestSchedule = "every 30m".parse().unwrap();
+        assert_eq!(every.next_after(last), at("2026-07-01T10:50:00Z"));
+    }
+}
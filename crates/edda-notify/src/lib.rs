@@ -1,22 +1,62 @@
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+mod digest;
+pub mod rules;
+mod template;
+
+pub use digest::{next_digests, send_due_digests, ChannelMode, DigestReport, DigestSchedule};
+pub use rules::NotifyRule;
+pub use template::{EventTemplate, MessageTemplate, NotifyTemplates, TemplateFormat};
 
 // ── Config ──
 
 /// Notification channel configuration — stored in `.edda/config.json` under key `notify_channels`.
+///
+/// Every channel takes an optional `mode` (`immediate` or `digest`) and, for
+/// digests, a `schedule`; see [`DigestSchedule`]. An optional `roles` list
+/// limits notifications addressed to a role (pending approvals) to those
+/// roles, so a domain owner's channel only hears about their own stages.
 #[derive(Deserialize, Clone, Debug)]
 #[serde(tag = "type")]
 pub enum Channel {
     #[serde(rename = "ntfy")]
-    Ntfy { url: String, events: Vec<String> },
+    Ntfy {
+        url: String,
+        events: Vec<String>,
+        #[serde(default)]
+        mode: ChannelMode,
+        #[serde(default)]
+        schedule: Option<DigestSchedule>,
+        #[serde(default)]
+        roles: Vec<String>,
+    },
     #[serde(rename = "webhook")]
-    Webhook { url: String, events: Vec<String> },
+    Webhook {
+        url: String,
+        events: Vec<String>,
+        #[serde(default)]
+        mode: ChannelMode,
+        #[serde(default)]
+        schedule: Option<DigestSchedule>,
+        #[serde(default)]
+        roles: Vec<String>,
+    },
     #[serde(rename = "telegram")]
     Telegram {
         bot_token: String,
         chat_id: String,
         events: Vec<String>,
+        #[serde(default)]
+        mode: ChannelMode,
+        #[serde(default)]
+        schedule: Option<DigestSchedule>,
+        #[serde(default)]
+        roles: Vec<String>,
     },
 }
 
@@ -29,6 +69,32 @@ impl Channel {
         }
     }
 
+    pub fn mode(&self) -> ChannelMode {
+        match self {
+            Channel::Ntfy { mode, .. }
+            | Channel::Webhook { mode, .. }
+            | Channel::Telegram { mode, .. } => *mode,
+        }
+    }
+
+    /// The digest schedule, if one is configured.
+    pub fn schedule(&self) -> Option<DigestSchedule> {
+        match self {
+            Channel::Ntfy { schedule, .. }
+            | Channel::Webhook { schedule, .. }
+            | Channel::Telegram { schedule, .. } => *schedule,
+        }
+    }
+
+    /// Channel type as written in config (`ntfy`, `webhook`, `telegram`).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Channel::Ntfy { .. } => "ntfy",
+            Channel::Webhook { .. } => "webhook",
+            Channel::Telegram { .. } => "telegram",
+        }
+    }
+
     pub fn display_name(&self) -> String {
         match self {
             Channel::Ntfy { url, .. } => format!("ntfy({})", url),
@@ -37,21 +103,177 @@ impl Channel {
         }
     }
 
-    fn matches(&self, event: &NotifyEvent) -> bool {
-        let name = event.event_name();
+    fn roles(&self) -> &[String] {
+        match self {
+            Channel::Ntfy { roles, .. }
+            | Channel::Webhook { roles, .. }
+            | Channel::Telegram { roles, .. } => roles,
+        }
+    }
+
+    /// Whether the channel's `events` list covers `name`.
+    fn subscribes(&self, name: &str) -> bool {
         self.events().iter().any(|e| e == name || e == "*")
     }
+
+    /// Whether the channel takes `event`: it subscribes to the event type
+    /// (or, for a rule match, to `rule:<name>`) and, when the event is
+    /// addressed to a role, serves that role.
+    fn accepts(&self, event: &NotifyEvent) -> bool {
+        let role_ok = match event.role() {
+            Some(role) if !self.roles().is_empty() => self.roles().iter().any(|r| r == role),
+            _ => true,
+        };
+        let subscribed = self.subscribes(event.event_name())
+            || matches!(event, NotifyEvent::RuleMatch { rule, .. }
+                if self.subscribes(&format!("rule:{rule}")));
+        role_ok && subscribed
+    }
+
+    /// Whether `event` is sent to this channel right away. Digest channels
+    /// get it in their next summary instead.
+    fn matches(&self, event: &NotifyEvent) -> bool {
+        self.mode() == ChannelMode::Immediate && self.accepts(event)
+    }
+}
+
+/// Do-not-disturb window — stored in `.edda/config.json` under key
+/// `notify_quiet_hours`, e.g. `{"start":"22:00","end":"07:00","utc_offset":"+02:00"}`.
+///
+/// Notifications raised inside the window are queued and sent once it ends.
+/// `start` after `end` wraps past midnight; `start == end` never matches.
+#[derive(Deserialize, Clone, Debug)]
+pub struct QuietHours {
+    /// Local start time, `HH:MM`.
+    pub start: String,
+    /// Local end time, `HH:MM`.
+    pub end: String,
+    /// Offset of the local clock from UTC, `+HH:MM` or `-HH:MM`. Defaults to UTC.
+    #[serde(default)]
+    pub utc_offset: Option<String>,
+}
+
+impl QuietHours {
+    /// Time left until the window ends, or `None` when `now` is outside it.
+    /// A malformed window is treated as never quiet.
+    pub fn remaining(&self, now: OffsetDateTime) -> Option<Duration> {
+        let start = parse_hhmm(&self.start)?;
+        let end = parse_hhmm(&self.end)?;
+        let offset = match self.utc_offset.as_deref() {
+            Some(o) => parse_utc_offset(o)?,
+            None => UtcOffset::UTC,
+        };
+        let local = now.to_offset(offset);
+        let (h, m, s) = local.to_hms();
+        let secs = h as u32 * 3600 + m as u32 * 60 + s as u32;
+
+        const DAY: u32 = 24 * 3600;
+        let left = if start < end {
+            (start..end).contains(&secs).then(|| end - secs)
+        } else if start > end {
+            if secs >= start {
+                Some(DAY - secs + end)
+            } else {
+                (secs < end).then(|| end - secs)
+            }
+        } else {
+            None
+        };
+        left.map(|s| Duration::from_secs(s as u64))
+    }
+}
+
+/// Parse `HH:MM` into seconds since midnight.
+fn parse_hhmm(s: &str) -> Option<u32> {
+    let (h, m) = s.trim().split_once(':')?;
+    let (h, m): (u32, u32) = (h.parse().ok()?, m.parse().ok()?);
+    (h < 24 && m < 60).then_some(h * 3600 + m * 60)
+}
+
+fn parse_utc_offset(s: &str) -> Option<UtcOffset> {
+    let s = s.trim();
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1i8, &s[1..]),
+        b'-' => (-1i8, &s[1..]),
+        _ => (1i8, s),
+    };
+    let (h, m) = rest.split_once(':').unwrap_or((rest, "0"));
+    let (h, m): (i8, i8) = (h.parse().ok()?, m.parse().ok()?);
+    UtcOffset::from_hms(sign * h, sign * m, 0).ok()
+}
+
+/// Longest wait between two retries of a notification.
+const MAX_BACKOFF: Duration = Duration::from_secs(3600);
+
+/// Retry, dedup and rate-limit settings — stored in `.edda/config.json` under
+/// key `notify_delivery`, e.g. `{"max_attempts":8,"rate_limit_per_minute":10}`.
+/// Missing fields keep their defaults.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct DeliveryPolicy {
+    /// Send attempts per channel before a notification is given up.
+    pub max_attempts: u32,
+    /// Wait before the first retry; doubles with every attempt, up to an hour.
+    pub backoff_secs: u64,
+    /// Repeats of a notification (same [`NotifyEvent::dedup_key`]) inside
+    /// this window are coalesced into one message with a counter.
+    pub dedup_window_secs: u64,
+    /// Sends per minute; the excess waits in the queue.
+    pub rate_limit_per_minute: usize,
+}
+
+impl Default for DeliveryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 6,
+            backoff_secs: 30,
+            dedup_window_secs: 300,
+            rate_limit_per_minute: 20,
+        }
+    }
+}
+
+impl DeliveryPolicy {
+    fn dedup_window(&self) -> Duration {
+        Duration::from_secs(self.dedup_window_secs)
+    }
+
+    /// Wait before the next try, after `attempts` failed ones.
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 1u64 << attempts.saturating_sub(1).min(16);
+        Duration::from_secs(self.backoff_secs.saturating_mul(factor)).min(MAX_BACKOFF)
+    }
 }
 
 /// Top-level notify configuration.
 #[derive(Deserialize, Clone, Debug, Default)]
 pub struct NotifyConfig {
     pub channels: Vec<Channel>,
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+    #[serde(default)]
+    pub delivery: DeliveryPolicy,
+    /// Message overrides; see [`NotifyTemplates`].
+    #[serde(default)]
+    pub templates: NotifyTemplates,
+    /// Conditions on ledger appends that raise a `rule_match`; see [`rules`].
+    #[serde(default)]
+    pub rules: Vec<NotifyRule>,
+    /// Outbox for notifications held by quiet hours, rate limiting, or a
+    /// failed send. Without it, failures are only logged.
+    #[serde(skip)]
+    pub queue_path: Option<PathBuf>,
+    /// Workspace whose ledger digests are rendered from.
+    #[serde(skip)]
+    pub repo_root: Option<PathBuf>,
 }
 
 impl NotifyConfig {
-    /// Load from `.edda/config.json` key `notify_channels`.
-    /// Returns empty config if key is missing or unparseable.
+    /// Load from `.edda/config.json` keys `notify_channels`,
+    /// `notify_quiet_hours`, `notify_delivery`, `notify_templates` and
+    /// `notify_rules`. Returns empty config if channels are missing or
+    /// unparseable; an unparseable quiet-hours window, delivery policy,
+    /// template set or rule list is ignored.
     pub fn load(paths: &edda_ledger::EddaPaths) -> Self {
         let path = &paths.config_json;
         let content = match std::fs::read_to_string(path) {
@@ -70,13 +292,51 @@ impl NotifyConfig {
             Ok(c) => c,
             Err(_) => return Self::default(),
         };
-        Self { channels }
+        let quiet_hours = val
+            .get("notify_quiet_hours")
+            .and_then(|v| serde_json::from_value(v.clone()).ok());
+        let delivery = val
+            .get("notify_delivery")
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+            .unwrap_or_default();
+        let templates = match val.get("notify_templates") {
+            Some(v) => serde_json::from_value(v.clone()).unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "ignoring unparseable notify_templates");
+                NotifyTemplates::default()
+            }),
+            None => NotifyTemplates::default(),
+        };
+        let rules = match val.get("notify_rules") {
+            Some(v) => serde_json::from_value(v.clone()).unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "ignoring unparseable notify_rules");
+                Vec::new()
+            }),
+            None => Vec::new(),
+        };
+        Self {
+            channels,
+            quiet_hours,
+            delivery,
+            templates,
+            rules,
+            queue_path: Some(paths.edda_dir.join("notify_queue.jsonl")),
+            repo_root: Some(paths.root.clone()),
+        }
+    }
+
+    /// Time left in the current quiet-hours window, if one is active.
+    pub fn quiet_remaining(&self) -> Option<Duration> {
+        self.quiet_hours
+            .as_ref()
+            .and_then(|q| q.remaining(OffsetDateTime::now_utc()))
     }
 }
 
 // ── Notification Events ──
 
 /// Notification event types mapped from edda domain events.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
 pub enum NotifyEvent {
     ApprovalPending {
         draft_id: String,
@@ -101,6 +361,25 @@ pub enum NotifyEvent {
         count: usize,
         detail: String,
     },
+    /// A conductor plan is blocked on a human decision (retry/skip/abort).
+    PlanGate { plan: String, message: String },
+    /// Scheduled summary for a `mode: digest` channel.
+    Digest {
+        schedule: String,
+        since: String,
+        phase_changes: usize,
+        decisions: usize,
+        anomalies: usize,
+        summary: String,
+    },
+    /// A ledger event matched a `notify_rules` entry.
+    RuleMatch {
+        rule: String,
+        event_id: String,
+        event_type: String,
+        branch: String,
+        summary: String,
+    },
 }
 
 impl NotifyEvent {
@@ -110,9 +389,38 @@ impl NotifyEvent {
             NotifyEvent::PhaseChange { .. } => "phase_change",
             NotifyEvent::SessionEnd { .. } => "session_end",
             NotifyEvent::Anomaly { .. } => "anomaly",
+            NotifyEvent::PlanGate { .. } => "plan_gate",
+            NotifyEvent::Digest { .. } => "digest",
+            NotifyEvent::RuleMatch { .. } => "rule_match",
         }
     }
 
+    /// The role a notification is addressed to, if any.
+    pub fn role(&self) -> Option<&str> {
+        match self {
+            NotifyEvent::ApprovalPending { role, .. } => Some(role),
+            _ => None,
+        }
+    }
+
+    /// Event type plus the fields that make two notifications "the same
+    /// thing happening again". Repeats of a key inside the dedup window are
+    /// coalesced into one message with a counter.
+    pub fn dedup_key(&self) -> String {
+        let salient = match self {
+            NotifyEvent::ApprovalPending {
+                draft_id, stage_id, ..
+            } => format!("{draft_id}|{stage_id}"),
+            NotifyEvent::PhaseChange { session_id, to, .. } => format!("{session_id}|{to}"),
+            NotifyEvent::SessionEnd { session_id, .. } => session_id.clone(),
+            NotifyEvent::Anomaly { signal_type, .. } => signal_type.clone(),
+            NotifyEvent::PlanGate { plan, message } => format!("{plan}|{message}"),
+            NotifyEvent::Digest { since, .. } => since.clone(),
+            NotifyEvent::RuleMatch { rule, event_id, .. } => format!("{rule}|{event_id}"),
+        };
+        format!("{}:{salient}", self.event_name())
+    }
+
     fn to_json(&self) -> serde_json::Value {
         match self {
             NotifyEvent::ApprovalPending {
@@ -157,6 +465,38 @@ impl NotifyEvent {
                 "count": count,
                 "detail": detail,
             }),
+            NotifyEvent::PlanGate { plan, message } => serde_json::json!({
+                "plan": plan,
+                "message": message,
+            }),
+            NotifyEvent::Digest {
+                schedule,
+                since,
+                phase_changes,
+                decisions,
+                anomalies,
+                summary,
+            } => serde_json::json!({
+                "schedule": schedule,
+                "since": since,
+                "phase_changes": phase_changes,
+                "decisions": decisions,
+                "anomalies": anomalies,
+                "summary": summary,
+            }),
+            NotifyEvent::RuleMatch {
+                rule,
+                event_id,
+                event_type,
+                branch,
+                summary,
+            } => serde_json::json!({
+                "rule": rule,
+                "event_id": event_id,
+                "event_type": event_type,
+                "branch": branch,
+                "summary": summary,
+            }),
         }
     }
 }
@@ -173,20 +513,476 @@ fn make_agent() -> ureq::Agent {
 }
 
 /// Send notifications to all channels matching this event.
-/// Errors are logged to stderr but never propagated.
+/// Errors are logged but never propagated.
+///
+/// Nothing is lost on a bad network: a failed send is queued for that channel
+/// and retried with exponential backoff by later dispatches (or
+/// `edda notify flush`). Notifications raised during quiet hours, or past the
+/// per-minute rate limit, wait in the same queue.
+///
+/// Repeats are coalesced per channel: once a channel got a notification, the
+/// same [`NotifyEvent::dedup_key`] inside the dedup window is only counted.
+/// When the window has passed, the latest repeat goes out with the count.
+///
+/// Channels in digest mode only hold the event; any digest that has come due
+/// is sent along the way.
 pub fn dispatch(config: &NotifyConfig, event: &NotifyEvent) {
+    digest::hold(config, event);
+    send_due_digests(config, false);
+    if !config.channels.iter().any(|c| c.matches(event)) {
+        return;
+    }
+    let quiet = config.quiet_remaining().is_some();
+    let key = event.dedup_key();
+    if !quiet {
+        // Repeats of this key are folded into this event below rather than
+        // summarized separately.
+        drain_queue(config, false, Some(&key));
+    }
+    if is_queued(config, event) {
+        tracing::debug!(event = event.event_name(), "notification already queued");
+        return;
+    }
+
+    let _lock = lock_sent(config);
+    let now = OffsetDateTime::now_utc();
+    let mut sent = load_sent(config, now);
+    if quiet || sent_last_minute(&sent, now) >= config.delivery.rate_limit_per_minute {
+        // Quiet entries are due right away; the next dispatch after the
+        // window sends them. Rate-limited ones wait out the minute.
+        let next_attempt_at = (!quiet).then(|| rfc3339(now + Duration::from_secs(60)));
+        if enqueue_entry(config, &QueueEntry::new(event.clone(), next_attempt_at)) {
+            return;
+        }
+    }
+
+    let window = config.delivery.dedup_window();
+    let agent = make_agent();
+    for channel in config.channels.iter().filter(|c| c.matches(event)) {
+        let name = channel.display_name();
+        let record = sent.iter_mut().find(|r| r.channel == name && r.key == key);
+        let repeats = match record {
+            Some(r) if age(&r.ts, now).is_some_and(|a| a < window) => {
+                r.repeats += 1;
+                r.event = event.clone();
+                continue;
+            }
+            // Window over with repeats not yet reported: fold them into this one.
+            Some(r) => {
+                let repeats = r.repeats + 1;
+                *r = SentRecord::new(now, &name, event.clone());
+                if repeats > 1 {
+                    repeats
+                } else {
+                    0
+                }
+            }
+            None => {
+                sent.push(SentRecord::new(now, &name, event.clone()));
+                0
+            }
+        };
+        if let Err(e) = send(&agent, &config.templates, channel, event, repeats) {
+            tracing::warn!(channel = %name, error = %e, "notification send failed");
+            let retry = QueueEntry {
+                channel: Some(name),
+                attempts: 1,
+                repeats,
+                next_attempt_at: Some(rfc3339(now + config.delivery.backoff(1))),
+                last_error: Some(e.to_string()),
+                ..QueueEntry::new(event.clone(), None)
+            };
+            enqueue_entry(config, &retry);
+        }
+    }
+    save_sent(config, &sent);
+}
+
+/// Queue `event` for every channel that takes it, without sending anything.
+/// Digest channels hold it as [`dispatch`] does; immediate channels get it
+/// from the next [`deliver`], dispatch or `edda notify flush`. For callers
+/// that must not wait on the network, such as ledger append observers.
+pub fn enqueue(config: &NotifyConfig, event: &NotifyEvent) {
+    digest::hold(config, event);
+    if !config.channels.iter().any(|c| c.matches(event)) || is_queued(config, event) {
+        return;
+    }
+    enqueue_entry(config, &QueueEntry::new(event.clone(), None));
+}
+
+/// Send due digests and the queued notifications that are due, honoring
+/// quiet hours, backoff and the rate limit.
+pub fn deliver(config: &NotifyConfig) -> FlushReport {
+    send_due_digests(config, false);
+    if config.quiet_remaining().is_some() {
+        return FlushReport {
+            pending: queued_count(config),
+            ..FlushReport::default()
+        };
+    }
+    drain_queue(config, false, None)
+}
+
+/// Outcome of a queue flush.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FlushReport {
+    /// Notifications delivered to every channel they were waiting on,
+    /// including coalesced repeat summaries.
+    pub sent: usize,
+    /// Notifications still queued, waiting for a later retry.
+    pub pending: usize,
+    /// Notifications given up after `max_attempts` failed sends.
+    pub dropped: usize,
+}
+
+/// Send every queued notification now, ignoring quiet hours, backoff and the
+/// rate limit. Failed sends stay queued for another retry.
+pub fn flush_queue(config: &NotifyConfig) -> FlushReport {
+    drain_queue(config, true, None)
+}
+
+/// Number of notifications waiting in the queue.
+pub fn queued_count(config: &NotifyConfig) -> usize {
+    config
+        .queue_path
+        .as_ref()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .map(|c| c.lines().filter(|l| !l.trim().is_empty()).count())
+        .unwrap_or(0)
+}
+
+/// One queued notification. Queue lines written before retries existed are
+/// a bare [`NotifyEvent`] and read back as due for every matching channel.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct QueueEntry {
+    #[serde(flatten)]
+    event: NotifyEvent,
+    /// Display name of the channel to retry; `None` means every matching one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    channel: Option<String>,
+    /// Failed sends so far.
+    #[serde(default)]
+    attempts: u32,
+    /// Coalesced repeats this notification stands for (0 = just itself).
+    #[serde(default, skip_serializing_if = "is_zero")]
+    repeats: u32,
+    /// RFC 3339 time of the next try; `None` means due now.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next_attempt_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_error: Option<String>,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+impl QueueEntry {
+    fn new(event: NotifyEvent, next_attempt_at: Option<String>) -> Self {
+        Self {
+            event,
+            channel: None,
+            attempts: 0,
+            repeats: 0,
+            next_attempt_at,
+            last_error: None,
+        }
+    }
+
+    fn is_due(&self, now: OffsetDateTime) -> bool {
+        self.next_attempt_at
+            .as_deref()
+            .and_then(|s| OffsetDateTime::parse(s, &Rfc3339).ok())
+            .is_none_or(|t| t <= now)
+    }
+}
+
+/// Last notification a channel got for one dedup key, plus the repeats
+/// swallowed since. Kept in `.edda/notify_sent.jsonl` for dedup and rate
+/// limiting.
+#[derive(Serialize, Deserialize)]
+struct SentRecord {
+    /// When the channel was last actually sent this key.
+    ts: String,
+    channel: String,
+    key: String,
+    /// The latest notification for this key — the one a repeat summary shows.
+    event: NotifyEvent,
+    /// Repeats coalesced since `ts` and not yet reported.
+    #[serde(default)]
+    repeats: u32,
+}
+
+impl SentRecord {
+    fn new(now: OffsetDateTime, channel: &str, event: NotifyEvent) -> Self {
+        Self {
+            ts: rfc3339(now),
+            channel: channel.to_string(),
+            key: event.dedup_key(),
+            event,
+            repeats: 0,
+        }
+    }
+}
+
+/// Retry queued notifications that are due, or all of them with `force`,
+/// then report repeats whose dedup window has closed (except for `skip_key`).
+fn drain_queue(config: &NotifyConfig, force: bool, skip_key: Option<&str>) -> FlushReport {
+    let mut report = FlushReport::default();
+    let _lock = lock_sent(config);
+    let now = OffsetDateTime::now_utc();
+    let mut sent = load_sent(config, now);
+    let mut allowance = config
+        .delivery
+        .rate_limit_per_minute
+        .saturating_sub(sent_last_minute(&sent, now));
     let agent = make_agent();
-    for channel in &config.channels {
-        if !channel.matches(event) {
+    let mut keep: Vec<QueueEntry> = Vec::new();
+
+    for entry in take_queue(config) {
+        if !force && (!entry.is_due(now) || allowance == 0) {
+            keep.push(entry);
             continue;
         }
-        let name = channel.display_name();
-        if let Err(e) = send(&agent, channel, event) {
+        allowance = allowance.saturating_sub(1);
+
+        let mut failed = false;
+        for channel in config.channels.iter().filter(|c| c.matches(&entry.event)) {
+            let name = channel.display_name();
+            if entry.channel.as_ref().is_some_and(|only| *only != name) {
+                continue;
+            }
+            let Err(e) = send(
+                &agent,
+                &config.templates,
+                channel,
+                &entry.event,
+                entry.repeats,
+            ) else {
+                record_sent(&mut sent, now, &name, &entry.event);
+                continue;
+            };
             tracing::warn!(channel = %name, error = %e, "notification send failed");
+            failed = true;
+            let attempts = entry.attempts + 1;
+            if attempts >= config.delivery.max_attempts {
+                tracing::warn!(
+                    channel = %name,
+                    event = entry.event.event_name(),
+                    attempts,
+                    "giving up on notification"
+                );
+                report.dropped += 1;
+                continue;
+            }
+            keep.push(QueueEntry {
+                channel: Some(name),
+                attempts,
+                next_attempt_at: Some(rfc3339(now + config.delivery.backoff(attempts))),
+                last_error: Some(e.to_string()),
+                ..entry.clone()
+            });
+        }
+        if !failed {
+            report.sent += 1;
+        }
+    }
+
+    // Repeat summaries: a burst that has gone quiet reports its count once.
+    let window = config.delivery.dedup_window();
+    for record in sent.iter_mut() {
+        let closed = age(&record.ts, now).is_none_or(|a| a >= window);
+        if record.repeats == 0
+            || !closed
+            || skip_key == Some(record.key.as_str())
+            || (!force && allowance == 0)
+        {
+            continue;
+        }
+        allowance = allowance.saturating_sub(1);
+        let summary = QueueEntry {
+            channel: Some(record.channel.clone()),
+            repeats: record.repeats,
+            ..QueueEntry::new(record.event.clone(), None)
+        };
+        record.ts = rfc3339(now);
+        record.repeats = 0;
+        let Some(channel) = config
+            .channels
+            .iter()
+            .find(|c| c.display_name() == record.channel)
+        else {
+            continue;
+        };
+        match send(
+            &agent,
+            &config.templates,
+            channel,
+            &summary.event,
+            summary.repeats,
+        ) {
+            Ok(()) => report.sent += 1,
+            Err(e) => keep.push(QueueEntry {
+                attempts: 1,
+                next_attempt_at: Some(rfc3339(now + config.delivery.backoff(1))),
+                last_error: Some(e.to_string()),
+                ..summary
+            }),
+        }
+    }
+
+    save_sent(config, &sent);
+    report.pending = keep.len();
+    for entry in &keep {
+        enqueue_entry(config, entry);
+    }
+    report
+}
+
+/// Take every entry out of the queue file. The queue is moved aside first so
+/// a concurrent dispatch cannot send an entry twice.
+fn take_queue(config: &NotifyConfig) -> Vec<QueueEntry> {
+    let Some(path) = &config.queue_path else {
+        return Vec::new();
+    };
+    let sending = path.with_extension("jsonl.sending");
+    if std::fs::rename(path, &sending).is_err() {
+        return Vec::new();
+    }
+    let content = std::fs::read_to_string(&sending).unwrap_or_default();
+    let _ = std::fs::remove_file(&sending);
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .filter_map(|l| match serde_json::from_str(l) {
+            Ok(e) => Some(e),
+            Err(e) => {
+                tracing::warn!(error = %e, "skipping unreadable queued notification");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Append `entry` to the queue. Returns `false` when there is no queue.
+fn enqueue_entry(config: &NotifyConfig, entry: &QueueEntry) -> bool {
+    let Some(path) = &config.queue_path else {
+        return false;
+    };
+    if let Err(e) = append_line(path, entry) {
+        tracing::warn!(error = %e, "failed to queue notification");
+    }
+    true
+}
+
+fn append_line<T: Serialize>(path: &Path, value: &T) -> anyhow::Result<()> {
+    use std::io::Write;
+    let mut f = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(f, "{}", serde_json::to_string(value)?)?;
+    Ok(())
+}
+
+/// Whether an identical notification is already waiting in the queue.
+fn is_queued(config: &NotifyConfig, event: &NotifyEvent) -> bool {
+    config
+        .queue_path
+        .as_ref()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .is_some_and(|c| {
+            c.lines()
+                .filter_map(|l| serde_json::from_str::<QueueEntry>(l).ok())
+                .any(|e| e.event == *event)
+        })
+}
+
+/// Note a successful send so it counts toward the rate limit and dedup
+/// window. Repeats still waiting to be reported are kept.
+fn record_sent(
+    sent: &mut Vec<SentRecord>,
+    now: OffsetDateTime,
+    channel: &str,
+    event: &NotifyEvent,
+) {
+    let key = event.dedup_key();
+    match sent
+        .iter_mut()
+        .find(|r| r.channel == channel && r.key == key)
+    {
+        Some(r) => {
+            r.ts = rfc3339(now);
+            r.event = event.clone();
         }
+        None => sent.push(SentRecord::new(now, channel, event.clone())),
     }
 }
 
+fn sent_last_minute(sent: &[SentRecord], now: OffsetDateTime) -> usize {
+    sent.iter()
+        .filter(|r| age(&r.ts, now).is_some_and(|a| a < Duration::from_secs(60)))
+        .count()
+}
+
+/// `.edda/notify_sent.jsonl`, next to the queue.
+fn sent_path(config: &NotifyConfig) -> Option<PathBuf> {
+    config
+        .queue_path
+        .as_ref()
+        .map(|p| p.with_file_name("notify_sent.jsonl"))
+}
+
+/// Hold `.edda/notify_sent.lock` from loading the sent log to saving it, so
+/// dispatchers in other processes see each other's sends. `None` (logged)
+/// when there is no queue or the lock cannot be taken; sending goes ahead.
+fn lock_sent(config: &NotifyConfig) -> Option<edda_store::LockGuard> {
+    let path = sent_path(config)?.with_extension("lock");
+    edda_store::lock_file(&path)
+        .inspect_err(|e| tracing::warn!(error = %e, "failed to lock sent notifications"))
+        .ok()
+}
+
+/// Sent records still inside the dedup or rate-limit window, or holding
+/// repeats that have not been reported yet.
+fn load_sent(config: &NotifyConfig, now: OffsetDateTime) -> Vec<SentRecord> {
+    let Some(content) = sent_path(config).and_then(|p| std::fs::read_to_string(p).ok()) else {
+        return Vec::new();
+    };
+    let keep_for = config.delivery.dedup_window().max(Duration::from_secs(60));
+    content
+        .lines()
+        .filter_map(|l| serde_json::from_str::<SentRecord>(l).ok())
+        .filter(|r| r.repeats > 0 || age(&r.ts, now).is_some_and(|a| a < keep_for))
+        .collect()
+}
+
+fn save_sent(config: &NotifyConfig, sent: &[SentRecord]) {
+    let Some(path) = sent_path(config) else {
+        return;
+    };
+    let mut out = String::new();
+    for r in sent {
+        if let Ok(line) = serde_json::to_string(r) {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    if let Err(e) = edda_store::write_atomic(&path, out.as_bytes()) {
+        tracing::warn!(error = %e, "failed to record sent notifications");
+    }
+}
+
+fn rfc3339(t: OffsetDateTime) -> String {
+    t.format(&Rfc3339).unwrap_or_default()
+}
+
+/// Time since `ts`; `None` when it does not parse.
+fn age(ts: &str, now: OffsetDateTime) -> Option<Duration> {
+    let t = OffsetDateTime::parse(ts, &Rfc3339).ok()?;
+    Some((now - t).try_into().unwrap_or(Duration::ZERO))
+}
+
 /// Send a test notification to all configured channels.
 /// Returns per-channel results for CLI display.
 pub fn test_channels(config: &NotifyConfig) -> Vec<(String, Result<(), String>)> {
@@ -202,34 +998,173 @@ pub fn test_channels(config: &NotifyConfig) -> Vec<(String, Result<(), String>)>
         .iter()
         .map(|ch| {
             let name = ch.display_name();
-            let result = send(&agent, ch, &test_event).map_err(|e| e.to_string());
+            let result =
+                send(&agent, &config.templates, ch, &test_event, 0).map_err(|e| e.to_string());
             (name, result)
         })
         .collect()
 }
 
-fn send(agent: &ureq::Agent, channel: &Channel, event: &NotifyEvent) -> anyhow::Result<()> {
+/// Send one notification. `repeats` > 0 marks it as standing for that many
+/// coalesced notifications with the same dedup key.
+fn send(
+    agent: &ureq::Agent,
+    templates: &NotifyTemplates,
+    channel: &Channel,
+    event: &NotifyEvent,
+    repeats: u32,
+) -> anyhow::Result<()> {
     match channel {
-        Channel::Ntfy { url, .. } => send_ntfy(agent, url, event),
-        Channel::Webhook { url, .. } => send_webhook(agent, url, event),
+        Channel::Ntfy { url, .. } => send_ntfy(agent, url, templates, event, repeats),
+        Channel::Webhook { url, .. } => send_webhook(agent, url, templates, event, repeats),
         Channel::Telegram {
             bot_token, chat_id, ..
-        } => send_telegram(agent, bot_token, chat_id, event),
+        } => send_telegram(agent, bot_token, chat_id, templates, event, repeats),
     }
 }
 
+/// Trailing line for a coalesced notification.
+fn repeats_note(repeats: u32) -> Option<String> {
+    (repeats > 0).then(|| format!("×{repeats} similar notifications, coalesced"))
+}
+
+/// Template variables for an event: its data fields plus `event`.
+fn template_vars(event: &NotifyEvent) -> serde_json::Value {
+    let mut vars = event.to_json();
+    vars["event"] = serde_json::json!(event.event_name());
+    vars
+}
+
+/// Render a preview of `event` as each channel type would send it, with the
+/// configured templates applied.
+pub fn preview(templates: &NotifyTemplates, event: &NotifyEvent) -> Vec<(&'static str, String)> {
+    let ntfy = render_ntfy(templates, event);
+    let mut ntfy_text = format!("Title: {}\nPriority: {}\n", ntfy.title, ntfy.priority);
+    if ntfy.markdown {
+        ntfy_text.push_str("Markdown: yes\n");
+    }
+    ntfy_text.push('\n');
+    ntfy_text.push_str(&ntfy.body);
+
+    let (text, parse_mode) = render_telegram(templates, event);
+    let telegram_text = match parse_mode {
+        Some(mode) => format!("parse_mode: {mode}\n\n{text}"),
+        None => text,
+    };
+
+    let webhook =
+        serde_json::to_string_pretty(&render_webhook(templates, event)).unwrap_or_default();
+    vec![
+        ("ntfy", ntfy_text),
+        ("telegram", telegram_text),
+        ("webhook", webhook),
+    ]
+}
+
+/// A sample of each event type, for `edda notify preview`.
+pub fn sample_event(name: &str) -> Option<NotifyEvent> {
+    Some(match name {
+        "approval_pending" => NotifyEvent::ApprovalPending {
+            draft_id: "drf_sample".to_string(),
+            title: "Add caching layer".to_string(),
+            stage_id: "lead".to_string(),
+            role: "lead".to_string(),
+        },
+        "phase_change" => NotifyEvent::PhaseChange {
+            session_id: "sess_sample".to_string(),
+            from: "implement".to_string(),
+            to: "review".to_string(),
+            issue: Some(42),
+        },
+        "session_end" => NotifyEvent::SessionEnd {
+            session_id: "sess_sample".to_string(),
+            outcome: "completed".to_string(),
+            duration_minutes: 37,
+            summary: "Added the cache and its tests".to_string(),
+        },
+        "anomaly" => NotifyEvent::Anomaly {
+            signal_type: "retry_loop".to_string(),
+            count: 5,
+            detail: "cargo test failed 5 times in a row".to_string(),
+        },
+        "plan_gate" => NotifyEvent::PlanGate {
+            plan: "release".to_string(),
+            message: "phase deploy failed: retry, skip or abort?".to_string(),
+        },
+        "digest" => NotifyEvent::Digest {
+            schedule: "daily@18:00".to_string(),
+            since: "2026-07-01T16:00:00Z".to_string(),
+            phase_changes: 2,
+            decisions: 1,
+            anomalies: 1,
+            summary: "Phase changes (2):\n- sess_a (builder): implement -> review\n- sess_b: review -> done\nDecisions (1):\n- cache.backend=redis — shared across workers\nAnomalies (1):\n- retry_loop x5: cargo test failed 5 times in a row".to_string(),
+        },
+        "rule_match" => NotifyEvent::RuleMatch {
+            rule: "security".to_string(),
+            event_id: "evt_sample".to_string(),
+            event_type: "note".to_string(),
+            branch: "main".to_string(),
+            summary: "auth.mfa: required \u{2014} admins first".to_string(),
+        },
+        _ => return None,
+    })
+}
+
 // ── ntfy ──
 
-fn send_ntfy(agent: &ureq::Agent, url: &str, event: &NotifyEvent) -> anyhow::Result<()> {
-    let (title, body, priority) = format_ntfy(event);
-    agent
+struct NtfyMessage {
+    title: String,
+    body: String,
+    priority: String,
+    markdown: bool,
+}
+
+fn send_ntfy(
+    agent: &ureq::Agent,
+    url: &str,
+    templates: &NotifyTemplates,
+    event: &NotifyEvent,
+    repeats: u32,
+) -> anyhow::Result<()> {
+    let NtfyMessage {
+        mut title,
+        mut body,
+        priority,
+        markdown,
+    } = render_ntfy(templates, event);
+    if let Some(note) = repeats_note(repeats) {
+        title.push_str(&format!(" (×{repeats})"));
+        body.push_str(&format!("\n{note}"));
+    }
+    let mut req = agent
         .post(url)
         .header("Title", &title)
-        .header("Priority", &priority)
-        .send(&body)?;
+        .header("Priority", &priority);
+    if markdown {
+        req = req.header("Markdown", "yes");
+    }
+    req.send(&body)?;
     Ok(())
 }
 
+/// The built-in ntfy message with any template overrides applied.
+fn render_ntfy(templates: &NotifyTemplates, event: &NotifyEvent) -> NtfyMessage {
+    let t = templates.resolve("ntfy", event.event_name());
+    let (title, body, priority) = format_ntfy(event);
+    let vars = template_vars(event);
+    let plain = |s: &str| s.to_string();
+    NtfyMessage {
+        title: t
+            .title
+            .map_or(title, |tpl| template::render(&tpl, &vars, plain)),
+        body: t
+            .body
+            .map_or(body, |tpl| template::render(&tpl, &vars, plain)),
+        priority: t.priority.unwrap_or(priority),
+        markdown: t.format == Some(TemplateFormat::Markdown),
+    }
+}
+
 fn format_ntfy(event: &NotifyEvent) -> (String, String, String) {
     match event {
         NotifyEvent::ApprovalPending {
@@ -272,13 +1207,45 @@ fn format_ntfy(event: &NotifyEvent) -> (String, String, String) {
             detail.clone(),
             "urgent".to_string(),
         ),
+        NotifyEvent::PlanGate { plan, message } => (
+            format!("Plan needs attention: {plan}"),
+            message.clone(),
+            "high".to_string(),
+        ),
+        NotifyEvent::Digest {
+            schedule, summary, ..
+        } => (
+            format!("Digest ({schedule}): {}", digest_counts(event)),
+            summary.clone(),
+            "low".to_string(),
+        ),
+        NotifyEvent::RuleMatch {
+            rule,
+            event_type,
+            branch,
+            summary,
+            ..
+        } => (
+            format!("Rule {rule}: {event_type} on {branch}"),
+            summary.clone(),
+            "default".to_string(),
+        ),
     }
 }
 
 // ── Webhook (generic JSON POST) ──
 
-fn send_webhook(agent: &ureq::Agent, url: &str, event: &NotifyEvent) -> anyhow::Result<()> {
-    let payload = format_webhook(event);
+fn send_webhook(
+    agent: &ureq::Agent,
+    url: &str,
+    templates: &NotifyTemplates,
+    event: &NotifyEvent,
+    repeats: u32,
+) -> anyhow::Result<()> {
+    let mut payload = render_webhook(templates, event);
+    if repeats > 0 {
+        payload["repeats"] = serde_json::json!(repeats);
+    }
     agent
         .post(url)
         .header("Content-Type", "application/json")
@@ -293,21 +1260,54 @@ fn format_webhook(event: &NotifyEvent) -> serde_json::Value {
     })
 }
 
+/// The webhook payload with template overrides: `fields` narrows `data`, and
+/// a `title`/`body` template adds a rendered `title`/`text`.
+fn render_webhook(templates: &NotifyTemplates, event: &NotifyEvent) -> serde_json::Value {
+    let t = templates.resolve("webhook", event.event_name());
+    let mut payload = format_webhook(event);
+    let vars = template_vars(event);
+    if let Some(fields) = &t.fields {
+        if let Some(data) = payload["data"].as_object_mut() {
+            data.retain(|k, _| fields.contains(k));
+        }
+    }
+    let plain = |s: &str| s.to_string();
+    if let Some(tpl) = &t.title {
+        payload["title"] = serde_json::json!(template::render(tpl, &vars, plain));
+    }
+    if let Some(tpl) = &t.body {
+        payload["text"] = serde_json::json!(template::render(tpl, &vars, plain));
+    }
+    payload
+}
+
 // ── Telegram ──
 
 fn send_telegram(
     agent: &ureq::Agent,
     bot_token: &str,
     chat_id: &str,
+    templates: &NotifyTemplates,
     event: &NotifyEvent,
+    repeats: u32,
 ) -> anyhow::Result<()> {
-    let text = format_telegram(event);
+    let (mut text, parse_mode) = render_telegram(templates, event);
+    if let Some(note) = repeats_note(repeats) {
+        let note = match parse_mode {
+            Some("HTML") => format!("<i>{note}</i>"),
+            Some(_) => format!("_{}_", escape_markdown(&note)),
+            None => note,
+        };
+        text.push_str(&format!("\n{note}"));
+    }
     let url = format!("https://api.telegram.org/bot{bot_token}/sendMessage");
-    let body = serde_json::json!({
+    let mut body = serde_json::json!({
         "chat_id": chat_id,
         "text": text,
-        "parse_mode": "HTML",
     });
+    if let Some(mode) = parse_mode {
+        body["parse_mode"] = serde_json::json!(mode);
+    }
     agent
         .post(&url)
         .header("Content-Type", "application/json")
@@ -315,6 +1315,37 @@ fn send_telegram(
     Ok(())
 }
 
+/// Telegram text and `parse_mode`. Without a title or body template the
+/// built-in HTML message is sent; otherwise the templates are rendered in
+/// the template's format (HTML by default), escaping field values.
+fn render_telegram(
+    templates: &NotifyTemplates,
+    event: &NotifyEvent,
+) -> (String, Option<&'static str>) {
+    let t = templates.resolve("telegram", event.event_name());
+    if t.title.is_none() && t.body.is_none() {
+        return (format_telegram(event), Some("HTML"));
+    }
+    let format = t.format.unwrap_or(TemplateFormat::Html);
+    let (escape, parse_mode): (fn(&str) -> String, _) = match format {
+        TemplateFormat::Html => (escape_html, Some("HTML")),
+        TemplateFormat::Markdown => (escape_markdown, Some("MarkdownV2")),
+        TemplateFormat::Plain => (|s: &str| s.to_string(), None),
+    };
+    let vars = template_vars(event);
+    let title = t.title.map(|tpl| {
+        let title = template::render(&tpl, &vars, escape);
+        match format {
+            TemplateFormat::Html => format!("<b>{title}</b>"),
+            TemplateFormat::Markdown => format!("*{title}*"),
+            TemplateFormat::Plain => title,
+        }
+    });
+    let body = t.body.map(|tpl| template::render(&tpl, &vars, escape));
+    let text = [title, body].into_iter().flatten().collect::<Vec<_>>();
+    (text.join("\n"), parse_mode)
+}
+
 fn format_telegram(event: &NotifyEvent) -> String {
     match event {
         NotifyEvent::ApprovalPending {
@@ -358,15 +1389,74 @@ fn format_telegram(event: &NotifyEvent) -> String {
             let d = escape_html(detail);
             format!("<b>Anomaly detected</b>\n{st} x{count}\n{d}")
         }
+        NotifyEvent::PlanGate { plan, message } => {
+            let p = escape_html(plan);
+            let m = escape_html(message);
+            format!("<b>Plan needs attention</b>: {p}\n{m}")
+        }
+        NotifyEvent::Digest {
+            schedule, summary, ..
+        } => {
+            let s = escape_html(schedule);
+            let counts = escape_html(&digest_counts(event));
+            let body = escape_html(summary);
+            format!("<b>Digest</b> ({s}): {counts}\n{body}")
+        }
+        NotifyEvent::RuleMatch {
+            rule,
+            event_type,
+            branch,
+            summary,
+            ..
+        } => {
+            let r = escape_html(rule);
+            let t = escape_html(event_type);
+            let b = escape_html(branch);
+            let s = escape_html(summary);
+            format!("<b>Rule {r}</b>: {t} on {b}\n{s}")
+        }
     }
 }
 
+/// "3 phase changes, 1 decision, 2 anomalies" for a digest title.
+fn digest_counts(event: &NotifyEvent) -> String {
+    let NotifyEvent::Digest {
+        phase_changes,
+        decisions,
+        anomalies,
+        ..
+    } = event
+    else {
+        return String::new();
+    };
+    let plural =
+        |n: usize, one: &str, many: &str| format!("{n} {}", if n == 1 { one } else { many });
+    [
+        plural(*phase_changes, "phase change", "phase changes"),
+        plural(*decisions, "decision", "decisions"),
+        plural(*anomalies, "anomaly", "anomalies"),
+    ]
+    .join(", ")
+}
+
 fn escape_html(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
         .replace('>', "&gt;")
 }
 
+/// Escape Telegram MarkdownV2 special characters.
+fn escape_markdown(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "_*[]()~`>#+-=|{}.!\\".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
 // ── Tests ──
 
 #[cfg(test)]
@@ -380,7 +1470,7 @@ mod tests {
         let channels: Vec<Channel> = serde_json::from_str(json).unwrap();
         assert_eq!(channels.len(), 1);
         assert!(
-            matches!(&channels[0], Channel::Ntfy { url, events } if url == "https://ntfy.sh/test" && events == &["approval_pending"])
+            matches!(&channels[0], Channel::Ntfy { url, events, .. } if url == "https://ntfy.sh/test" && events == &["approval_pending"])
         );
     }
 
@@ -431,6 +1521,59 @@ mod tests {
         assert!(!ch.matches(&phase));
     }
 
+    #[test]
+    fn channels_take_rule_matches_by_rule_name() {
+        let channel = |events: serde_json::Value| -> Channel {
+            serde_json::from_value(serde_json::json!({
+                "type": "ntfy",
+                "url": "https://ntfy.sh/test",
+                "events": events
+            }))
+            .unwrap()
+        };
+        let hit = |rule: &str| NotifyEvent::RuleMatch {
+            rule: rule.into(),
+            event_id: "evt_1".into(),
+            event_type: "note".into(),
+            branch: "main".into(),
+            summary: "s".into(),
+        };
+        let security = channel(serde_json::json!(["rule:security"]));
+        assert!(security.matches(&hit("security")));
+        assert!(!security.matches(&hit("outage")));
+        assert!(channel(serde_json::json!(["rule_match"])).matches(&hit("outage")));
+        assert_eq!(hit("security").dedup_key(), "rule_match:security|evt_1");
+    }
+
+    #[test]
+    fn role_channels_only_get_their_roles_approvals() {
+        let ch: Channel = serde_json::from_value(serde_json::json!({
+            "type": "ntfy",
+            "url": "https://ntfy.sh/dba",
+            "events": ["approval_pending", "anomaly"],
+            "roles": ["dba"]
+        }))
+        .unwrap();
+        let approval = |role: &str| NotifyEvent::ApprovalPending {
+            draft_id: "d1".into(),
+            title: "t".into(),
+            stage_id: format!("domain:{role}"),
+            role: role.into(),
+        };
+        assert!(ch.matches(&approval("dba")));
+        assert!(!ch.matches(&approval("security")));
+
+        let anomaly = NotifyEvent::Anomaly {
+            signal_type: "retry".into(),
+            count: 3,
+            detail: String::new(),
+        };
+        assert!(
+            ch.matches(&anomaly),
+            "events without a role are not filtered"
+        );
+    }
+
     #[test]
     fn wildcard_matches_all() {
         let ch: Channel = serde_json::from_value(serde_json::json!({
@@ -509,6 +1652,446 @@ mod tests {
         assert!(text.contains("<i>ops</i>"));
     }
 
+    fn quiet(start: &str, end: &str, offset: Option<&str>) -> QuietHours {
+        QuietHours {
+            start: start.into(),
+            end: end.into(),
+            utc_offset: offset.map(String::from),
+        }
+    }
+
+    fn at(rfc3339: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(rfc3339, &time::format_description::well_known::Rfc3339).unwrap()
+    }
+
+    #[test]
+    fn quiet_hours_window_wraps_midnight_and_honors_offset() {
+        let q = quiet("22:00", "07:00", None);
+        assert_eq!(
+            q.remaining(at("2026-07-01T03:00:00Z")),
+            Some(Duration::from_secs(4 * 3600))
+        );
+        assert_eq!(
+            q.remaining(at("2026-07-01T23:30:00Z")),
+            Some(Duration::from_secs(7 * 3600 + 1800))
+        );
+        assert_eq!(q.remaining(at("2026-07-01T12:00:00Z")), None);
+        assert_eq!(q.remaining(at("2026-07-01T07:00:00Z")), None);
+
+        // 03:00 UTC is 05:00 at +02:00 — still quiet, two hours left.
+        let q = quiet("22:00", "07:00", Some("+02:00"));
+        assert_eq!(
+            q.remaining(at("2026-07-01T03:00:00Z")),
+            Some(Duration::from_secs(2 * 3600))
+        );
+
+        let q = quiet("12:00", "13:00", None);
+        assert!(q.remaining(at("2026-07-01T12:30:00Z")).is_some());
+        assert!(quiet("09:00", "09:00", None)
+            .remaining(at("2026-07-01T09:00:00Z"))
+            .is_none());
+        assert!(quiet("25:00", "07:00", None)
+            .remaining(at("2026-07-01T03:00:00Z"))
+            .is_none());
+    }
+
+    #[test]
+    fn dispatch_queues_during_quiet_hours() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_q_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let config = NotifyConfig {
+            channels: vec![serde_json::from_value(serde_json::json!({
+                "type": "webhook",
+                "url": "http://127.0.0.1:9/never",
+                "events": ["plan_gate"]
+            }))
+            .unwrap()],
+            // A window covering the whole day except one minute at 00:00.
+            quiet_hours: Some(quiet("00:01", "00:00", None)),
+            queue_path: Some(dir.join("notify_queue.jsonl")),
+            ..Default::default()
+        };
+        if config.quiet_remaining().is_none() {
+            return; // ran in the one open minute
+        }
+        let event = NotifyEvent::PlanGate {
+            plan: "nightly".into(),
+            message: "Phase \"build\" failed. Retry, skip, or abort?".into(),
+        };
+        dispatch(&config, &event);
+        // The same gate raised again is already queued.
+        dispatch(&config, &event);
+        assert_eq!(queued_count(&config), 1);
+
+        let line = std::fs::read_to_string(config.queue_path.as_ref().unwrap()).unwrap();
+        let back: NotifyEvent = serde_json::from_str(line.lines().next().unwrap()).unwrap();
+        assert_eq!(back.event_name(), "plan_gate");
+
+        // Unmatched events are never queued.
+        dispatch(
+            &config,
+            &NotifyEvent::Anomaly {
+                signal_type: "x".into(),
+                count: 1,
+                detail: String::new(),
+            },
+        );
+        assert_eq!(queued_count(&config), 1);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn digest_channel_batches_ledger_activity_into_one_message() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_digest_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = edda_ledger::EddaPaths::discover(&dir);
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        let (url, bodies) = recording_server();
+        let config = NotifyConfig {
+            channels: vec![serde_json::from_value(serde_json::json!({
+                "type": "webhook", "url": url, "events": ["*"],
+                "mode": "digest", "schedule": "every 30m"
+            }))
+            .unwrap()],
+            queue_path: Some(paths.edda_dir.join("notify_queue.jsonl")),
+            repo_root: Some(dir.clone()),
+            ..Default::default()
+        };
+
+        // Held for the digest, not sent.
+        dispatch(&config, &anomaly(4));
+        assert!(bodies.lock().unwrap().is_empty());
+        assert_eq!(queued_count(&config), 0);
+
+        let ledger = edda_ledger::Ledger::open(&dir).unwrap();
+        let phase = edda_core::event::new_agent_phase_change_event(
+            &edda_core::event::AgentPhaseChangeParams {
+                branch: "main",
+                parent_hash: None,
+                session_id: "s1",
+                label: Some("builder"),
+                from: "implement",
+                to: "review",
+                issue: None,
+                confidence: 0.9,
+                signals: &[],
+            },
+        )
+        .unwrap();
+        ledger.append_event(&phase).unwrap();
+        let decision: edda_core::DecisionPayload =
+            serde_json::from_value(serde_json::json!({"key": "db.engine", "value": "postgres"}))
+                .unwrap();
+        let decision =
+            edda_core::event::new_decision_event("main", Some(&phase.hash), "system", &decision)
+                .unwrap();
+        ledger.append_event(&decision).unwrap();
+
+        // Not due until the window has passed.
+        assert_eq!(send_due_digests(&config, false).sent, 0);
+        let state_path = paths.edda_dir.join("notify_digest.json");
+        let state = std::fs::read_to_string(&state_path).unwrap();
+        let since = rfc3339(OffsetDateTime::now_utc() - Duration::from_secs(3600));
+        let mut state: serde_json::Value = serde_json::from_str(&state).unwrap();
+        for entry in state.as_object_mut().unwrap().values_mut() {
+            entry["since"] = serde_json::json!(since);
+        }
+        std::fs::write(&state_path, state.to_string()).unwrap();
+
+        assert_eq!(send_due_digests(&config, false).sent, 1);
+        {
+            let bodies = bodies.lock().unwrap();
+            assert_eq!(bodies.len(), 1);
+            assert_eq!(bodies[0]["event_type"], "digest");
+            let data = &bodies[0]["data"];
+            assert_eq!(data["phase_changes"], 1);
+            assert_eq!(data["decisions"], 1);
+            assert_eq!(data["anomalies"], 1);
+            let summary = data["summary"].as_str().unwrap();
+            assert!(summary.contains("s1 (builder): implement -> review"));
+            assert!(summary.contains("db.engine=postgres"));
+            assert!(summary.contains("cmd_failed x4: 4 failed commands"));
+        }
+
+        // The next window starts empty; forcing it sends nothing.
+        let report = send_due_digests(&config, true);
+        assert_eq!((report.sent, report.empty), (0, 1));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// A config whose only channel always fails (nothing listens on port 9).
+    fn failing_config(dir: &Path) -> NotifyConfig {
+        let _ = std::fs::remove_dir_all(dir);
+        std::fs::create_dir_all(dir).unwrap();
+        NotifyConfig {
+            channels: vec![serde_json::from_value(serde_json::json!({
+                "type": "webhook",
+                "url": "http://127.0.0.1:9/never",
+                "events": ["*"]
+            }))
+            .unwrap()],
+            queue_path: Some(dir.join("notify_queue.jsonl")),
+            ..Default::default()
+        }
+    }
+
+    fn read_queue(config: &NotifyConfig) -> Vec<QueueEntry> {
+        std::fs::read_to_string(config.queue_path.as_ref().unwrap())
+            .unwrap_or_default()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    fn approval(draft_id: &str) -> NotifyEvent {
+        NotifyEvent::ApprovalPending {
+            draft_id: draft_id.into(),
+            title: "Deploy v2".into(),
+            stage_id: "s1".into(),
+            role: "ops".into(),
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_an_hour() {
+        let policy = DeliveryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_secs(30));
+        assert_eq!(policy.backoff(2), Duration::from_secs(60));
+        assert_eq!(policy.backoff(4), Duration::from_secs(240));
+        assert_eq!(policy.backoff(30), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn failed_send_is_queued_retried_and_eventually_dropped() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_retry_{}", std::process::id()));
+        let mut config = failing_config(&dir);
+        config.delivery.max_attempts = 3;
+
+        dispatch(&config, &approval("drf_1"));
+        let queue = read_queue(&config);
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].attempts, 1);
+        assert_eq!(
+            queue[0].channel.as_deref(),
+            Some("webhook(http://127.0.0.1:9/never)")
+        );
+        assert!(queue[0].last_error.is_some());
+        assert!(
+            !queue[0].is_due(OffsetDateTime::now_utc()),
+            "waits out the backoff"
+        );
+
+        // Raised again while the first is pending: deduplicated.
+        dispatch(&config, &approval("drf_1"));
+        assert_eq!(queued_count(&config), 1);
+
+        let report = flush_queue(&config);
+        assert_eq!(
+            report,
+            FlushReport {
+                sent: 0,
+                pending: 1,
+                dropped: 0
+            }
+        );
+        assert_eq!(read_queue(&config)[0].attempts, 2);
+
+        let report = flush_queue(&config);
+        assert_eq!(
+            report,
+            FlushReport {
+                sent: 0,
+                pending: 0,
+                dropped: 1
+            }
+        );
+        assert_eq!(queued_count(&config), 0);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn rate_limit_holds_the_excess_in_the_queue() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_rate_{}", std::process::id()));
+        let mut config = failing_config(&dir);
+        config.delivery.rate_limit_per_minute = 1;
+
+        dispatch(&config, &approval("drf_1"));
+        dispatch(&config, &approval("drf_2"));
+        let queue = read_queue(&config);
+        assert_eq!(queue.len(), 2);
+        // The second was never attempted; it waits for the next minute.
+        assert_eq!(queue[1].attempts, 0);
+        assert!(queue[1].channel.is_none());
+        assert!(!queue[1].is_due(OffsetDateTime::now_utc()));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn queue_drains_count_toward_the_rate_limit() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_drain_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let (url, bodies) = recording_server();
+        let mut config = NotifyConfig {
+            channels: vec![serde_json::from_value(
+                serde_json::json!({ "type": "webhook", "url": url, "events": ["*"] }),
+            )
+            .unwrap()],
+            queue_path: Some(dir.join("notify_queue.jsonl")),
+            ..Default::default()
+        };
+        config.delivery.rate_limit_per_minute = 3;
+        for i in 0..5 {
+            enqueue(&config, &approval(&format!("drf_{i}")));
+        }
+
+        let report = deliver(&config);
+        assert_eq!((report.sent, report.pending), (3, 2));
+        // Same minute: the three queued sends used up the allowance.
+        let report = deliver(&config);
+        assert_eq!((report.sent, report.pending), (0, 2));
+        dispatch(&config, &approval("drf_new"));
+        assert_eq!(
+            queued_count(&config),
+            3,
+            "a fresh dispatch is rate limited too"
+        );
+        assert_eq!(bodies.lock().unwrap().len(), 3);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn concurrent_dispatchers_share_the_dedup_log() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_race_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let (url, bodies) = recording_server();
+        let config = NotifyConfig {
+            channels: vec![serde_json::from_value(
+                serde_json::json!({ "type": "webhook", "url": url, "events": ["*"] }),
+            )
+            .unwrap()],
+            queue_path: Some(dir.join("notify_queue.jsonl")),
+            ..Default::default()
+        };
+
+        std::thread::scope(|scope| {
+            for _ in 0..6 {
+                scope.spawn(|| dispatch(&config, &approval("drf_1")));
+            }
+        });
+        assert_eq!(bodies.lock().unwrap().len(), 1, "repeats inside the window");
+        let sent = load_sent(&config, OffsetDateTime::now_utc());
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].repeats, 5, "every repeat was recorded");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// A webhook endpoint on localhost that answers 200 and keeps each body.
+    fn recording_server() -> (
+        String,
+        std::sync::Arc<std::sync::Mutex<Vec<serde_json::Value>>>,
+    ) {
+        use std::io::{BufRead, BufReader, Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let bodies = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = bodies.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream);
+                let mut len = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(v) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        len = v.trim().parse().unwrap_or(0);
+                    }
+                }
+                let mut body = vec![0; len];
+                let _ = reader.read_exact(&mut body);
+                if let Ok(v) = serde_json::from_slice(&body) {
+                    seen.lock().unwrap().push(v);
+                }
+                let _ = reader.get_mut().write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                );
+            }
+        });
+        (url, bodies)
+    }
+
+    fn anomaly(count: usize) -> NotifyEvent {
+        NotifyEvent::Anomaly {
+            signal_type: "cmd_failed".into(),
+            count,
+            detail: format!("{count} failed commands"),
+        }
+    }
+
+    #[test]
+    fn dedup_key_uses_salient_fields() {
+        assert_eq!(anomaly(1).dedup_key(), anomaly(20).dedup_key());
+        assert_ne!(approval("drf_1").dedup_key(), approval("drf_2").dedup_key());
+        assert_eq!(approval("drf_1").dedup_key(), "approval_pending:drf_1|s1");
+    }
+
+    #[test]
+    fn repeats_are_coalesced_into_one_message_with_a_counter() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_dedup_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let (url, bodies) = recording_server();
+        let mut config = NotifyConfig {
+            channels: vec![serde_json::from_value(serde_json::json!({
+                "type": "webhook", "url": url, "events": ["anomaly"]
+            }))
+            .unwrap()],
+            queue_path: Some(dir.join("notify_queue.jsonl")),
+            ..Default::default()
+        };
+
+        for count in 1..=3 {
+            dispatch(&config, &anomaly(count));
+        }
+        assert_eq!(
+            bodies.lock().unwrap().len(),
+            1,
+            "repeats inside the window are held"
+        );
+        assert_eq!(queued_count(&config), 0);
+
+        // Once the window has closed, the burst is reported once, with its count.
+        config.delivery.dedup_window_secs = 0;
+        let report = flush_queue(&config);
+        assert_eq!(report.sent, 1);
+        {
+            let bodies = bodies.lock().unwrap();
+            assert_eq!(bodies.len(), 2);
+            assert_eq!(bodies[1]["repeats"], 2);
+            assert_eq!(bodies[1]["data"]["count"], 3);
+        }
+
+        // Nothing left to report.
+        assert_eq!(flush_queue(&config).sent, 0);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn legacy_queue_lines_read_as_due_entries() {
+        let line = serde_json::to_string(&approval("drf_old")).unwrap();
+        let entry: QueueEntry = serde_json::from_str(&line).unwrap();
+        assert_eq!(entry.event, approval("drf_old"));
+        assert_eq!(entry.attempts, 0);
+        assert!(entry.channel.is_none());
+        assert!(entry.is_due(OffsetDateTime::now_utc()));
+    }
+
     #[test]
     fn format_telegram_escapes_html() {
         let event = NotifyEvent::ApprovalPending {
@@ -520,4 +2103,48 @@ mod tests {
         let text = format_telegram(&event);
         assert!(text.contains("Fix &lt;script&gt; &amp; stuff"));
     }
+
+    #[test]
+    fn templates_override_each_channel_and_fall_back_to_builtins() {
+        let templates: NotifyTemplates = serde_json::from_value(serde_json::json!({
+            "approval_pending": {
+                "title": "🔔 {{ title }}",
+                "body": "{{ draft_id }} waits on {{ role }}",
+                "ntfy": { "format": "markdown", "priority": "urgent" },
+                "telegram": { "format": "markdown" },
+                "webhook": { "fields": ["draft_id"] }
+            }
+        }))
+        .unwrap();
+        let event = NotifyEvent::ApprovalPending {
+            draft_id: "d_1".into(),
+            title: "Cache (v2)".into(),
+            stage_id: "s1".into(),
+            role: "lead".into(),
+        };
+
+        let ntfy = render_ntfy(&templates, &event);
+        assert_eq!(ntfy.title, "🔔 Cache (v2)");
+        assert_eq!(ntfy.body, "d_1 waits on lead");
+        assert_eq!(ntfy.priority, "urgent");
+        assert!(ntfy.markdown);
+
+        let (text, mode) = render_telegram(&templates, &event);
+        assert_eq!(mode, Some("MarkdownV2"));
+        assert_eq!(text, "*🔔 Cache \\(v2\\)*\nd\\_1 waits on lead");
+
+        let payload = render_webhook(&templates, &event);
+        assert_eq!(payload["data"], serde_json::json!({ "draft_id": "d_1" }));
+        assert_eq!(payload["title"], "🔔 Cache (v2)");
+        assert_eq!(payload["text"], "d_1 waits on lead");
+
+        // Events without templates keep the built-in messages.
+        let gate = sample_event("plan_gate").unwrap();
+        assert_eq!(render_ntfy(&templates, &gate).title, format_ntfy(&gate).0);
+        assert_eq!(
+            render_telegram(&templates, &gate),
+            (format_telegram(&gate), Some("HTML"))
+        );
+        assert_eq!(render_webhook(&templates, &gate), format_webhook(&gate));
+    }
 }
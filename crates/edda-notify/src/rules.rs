@@ -0,0 +1,429 @@
+//! Notify rules — notifications raised by ledger appends that match
+//! conditions in config, instead of from fixed call sites.
+//!
+//! Rules live in `.edda/config.json` under `notify_rules`:
+//!
+//! ```json
+//! [{ "name": "security", "types": ["decision"], "domains": ["security"] }]
+//! ```
+//!
+//! Each condition is a list; an event matches a rule when every non-empty
+//! list has an entry that fits (`any of` within a list, `all of` across
+//! them). A rule without conditions matches nothing. A match is dispatched
+//! as a `rule_match` notification, which channels take by listing
+//! `rule_match` (every rule) or `rule:<name>` in their `events`.
+//!
+//! [`install`] registers the rules as a ledger append observer for the
+//! process, so they see every append whatever wrote it. The observer runs
+//! inside the append, often under the workspace lock in a hook, so it only
+//! queues matches. Short-lived commands, hooks included, send them with
+//! [`deliver_queued`] once done, along with anything an earlier process
+//! left queued; servers run [`spawn_delivery`] until they shut down.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, Once};
+use std::time::{Duration, SystemTime};
+
+use edda_core::Event;
+use edda_ledger::{AppendObserver, EddaPaths};
+use serde::Deserialize;
+
+use crate::{NotifyConfig, NotifyEvent};
+
+/// How often [`spawn_delivery`] sends what is queued.
+const DELIVERY_EVERY: Duration = Duration::from_secs(15);
+
+/// Longest summary carried by a `rule_match` notification.
+const SUMMARY_CHARS: usize = 200;
+
+/// One rule from `notify_rules`.
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct NotifyRule {
+    pub name: String,
+    /// Event types (`note`, `cmd`, `draft_proposed`, …); `decision` also
+    /// matches decision notes.
+    #[serde(default)]
+    pub types: Vec<String>,
+    /// Event families (`signal`, `milestone`, `admin`, `governance`).
+    #[serde(default)]
+    pub families: Vec<String>,
+    /// Note tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Decision domains: the part of the key before the first `.`.
+    #[serde(default)]
+    pub domains: Vec<String>,
+    #[serde(default)]
+    pub branches: Vec<String>,
+    /// Case-insensitive substrings of any text in the payload.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+impl NotifyRule {
+    fn is_empty(&self) -> bool {
+        self.types.is_empty()
+            && self.families.is_empty()
+            && self.tags.is_empty()
+            && self.domains.is_empty()
+            && self.branches.is_empty()
+            && self.keywords.is_empty()
+    }
+
+    /// Whether `event` meets every condition of the rule.
+    pub fn matches(&self, event: &Event) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+        let is_decision = edda_core::decision::is_decision(&event.payload);
+        let any = |list: &[String], test: &dyn Fn(&str) -> bool| {
+            list.is_empty() || list.iter().any(|v| test(v))
+        };
+        let tags: Vec<&str> = event.payload["tags"]
+            .as_array()
+            .map(|t| t.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        let domain = event.payload["decision"]["key"]
+            .as_str()
+            .filter(|_| is_decision)
+            .map(edda_core::decision::extract_domain);
+        let text = self
+            .keywords
+            .first()
+            .map(|_| payload_text(&event.payload).to_lowercase())
+            .unwrap_or_default();
+
+        any(&self.types, &|t| {
+            t == event.event_type || (t == "decision" && is_decision)
+        }) && any(&self.families, &|f| {
+            event.event_family.as_deref() == Some(f)
+        }) && any(&self.tags, &|t| tags.contains(&t))
+            && any(&self.domains, &|d| domain.as_deref() == Some(d))
+            && any(&self.branches, &|b| b == event.branch)
+            && any(&self.keywords, &|k| text.contains(&k.to_lowercase()))
+    }
+}
+
+/// Every string in a payload, space-separated.
+fn payload_text(value: &serde_json::Value) -> String {
+    fn collect(value: &serde_json::Value, out: &mut Vec<String>) {
+        match value {
+            serde_json::Value::String(s) => out.push(s.clone()),
+            serde_json::Value::Array(items) => items.iter().for_each(|v| collect(v, out)),
+            serde_json::Value::Object(map) => map.values().for_each(|v| collect(v, out)),
+            _ => {}
+        }
+    }
+    let mut out = Vec::new();
+    collect(value, &mut out);
+    out.join(" ")
+}
+
+/// One line describing `event` for a notification.
+fn summarize(event: &Event) -> String {
+    let p = &event.payload;
+    let text = ["text", "title", "message", "summary", "key"]
+        .iter()
+        .find_map(|k| p[*k].as_str().filter(|s| !s.trim().is_empty()))
+        .unwrap_or(&event.event_type);
+    let line = text.lines().next().unwrap_or("").trim();
+    match line.char_indices().nth(SUMMARY_CHARS) {
+        Some((cut, _)) => format!("{}…", &line[..cut]),
+        None => line.to_string(),
+    }
+}
+
+/// The notifications `event` raises under `rules`, one per matching rule.
+pub fn rule_matches(rules: &[NotifyRule], event: &Event) -> Vec<NotifyEvent> {
+    rules
+        .iter()
+        .filter(|r| r.matches(event))
+        .map(|r| NotifyEvent::RuleMatch {
+            rule: r.name.clone(),
+            event_id: event.event_id.clone(),
+            event_type: event.event_type.clone(),
+            branch: event.branch.clone(),
+            summary: summarize(event),
+        })
+        .collect()
+}
+
+/// Parsed notify config per `config.json`, with the mtime it was read at.
+type ConfigCache = HashMap<PathBuf, (Option<SystemTime>, Arc<NotifyConfig>)>;
+
+static CONFIGS: Mutex<Option<ConfigCache>> = Mutex::new(None);
+
+/// Workspaces this process queued rule matches for and has not delivered.
+static PENDING: Mutex<BTreeSet<PathBuf>> = Mutex::new(BTreeSet::new());
+
+/// The workspace's notify config, parsed again only when `config.json`
+/// changes, so an append costs a `stat` rather than a parse.
+fn cached_config(paths: &EddaPaths) -> Arc<NotifyConfig> {
+    let mtime = std::fs::metadata(&paths.config_json)
+        .and_then(|m| m.modified())
+        .ok();
+    let mut cache = CONFIGS.lock().unwrap_or_else(|e| e.into_inner());
+    let cache = cache.get_or_insert_with(HashMap::new);
+    if let Some((at, config)) = cache.get(&paths.config_json) {
+        if *at == mtime {
+            return config.clone();
+        }
+    }
+    let config = Arc::new(NotifyConfig::load(paths));
+    cache.insert(paths.config_json.clone(), (mtime, config.clone()));
+    config
+}
+
+/// Queues the workspace's `notify_rules` matches for each append.
+struct RuleObserver;
+
+impl AppendObserver for RuleObserver {
+    fn on_append(&self, paths: &EddaPaths, event: &Event) {
+        let config = cached_config(paths);
+        if config.rules.is_empty() || config.channels.is_empty() {
+            return;
+        }
+        let matches = rule_matches(&config.rules, event);
+        if matches.is_empty() {
+            return;
+        }
+        for notification in &matches {
+            crate::enqueue(&config, notification);
+        }
+        PENDING
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(paths.root.clone());
+    }
+}
+
+/// Evaluate notify rules on every ledger append in this process. Idempotent.
+pub fn install() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| edda_ledger::add_append_observer(Arc::new(RuleObserver)));
+}
+
+/// Deliver what this process's appends queued, plus whatever is waiting in
+/// `repo_root`'s queue (such as matches a hook queued). Meant for the end of
+/// a short-lived command, once its locks are released. Sends honor backoff,
+/// so a channel that is down costs no network time until its retry is due.
+pub fn deliver_queued(repo_root: &Path) {
+    let mut roots = std::mem::take(&mut *PENDING.lock().unwrap_or_else(|e| e.into_inner()));
+    roots.insert(repo_root.to_path_buf());
+    for root in roots {
+        let config = cached_config(&EddaPaths::discover(&root));
+        if !config.channels.is_empty() && crate::queued_count(&config) > 0 {
+            crate::deliver(&config);
+        }
+    }
+}
+
+/// Deliver the workspace's queued notifications every few seconds, whichever
+/// process queued them. For long-running servers: the task ends when `stop`
+/// fires or its sender is dropped, after one last delivery. A tick costs a
+/// `stat` of `config.json` unless the config changed.
+pub fn spawn_delivery(
+    repo_root: &Path,
+    mut stop: tokio::sync::oneshot::Receiver<()>,
+) -> tokio::task::JoinHandle<()> {
+    let root = repo_root.to_path_buf();
+    tokio::spawn(async move {
+        let start = tokio::time::Instant::now() + DELIVERY_EVERY;
+        let mut ticker = tokio::time::interval_at(start, DELIVERY_EVERY);
+        let mut stopping = false;
+        while !stopping {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = &mut stop => stopping = true,
+            }
+            let root = root.clone();
+            if let Err(e) = tokio::task::spawn_blocking(move || deliver_queued(&root)).await {
+                tracing::warn!(error = %e, "notify rule delivery failed");
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use edda_core::event::{new_decision_event, new_note_event};
+    use edda_core::types::DecisionPayload;
+
+    fn decision(key: &str, reason: &str) -> Event {
+        new_decision_event(
+            "main",
+            None,
+            "system",
+            &DecisionPayload {
+                key: key.into(),
+                value: "on".into(),
+                reason: Some(reason.into()),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn rules(json: serde_json::Value) -> Vec<NotifyRule> {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn conditions_combine_any_within_all_across() {
+        let rules = rules(serde_json::json!([
+            { "name": "security", "types": ["decision"], "domains": ["security", "auth"] },
+            { "name": "outage", "keywords": ["OUTAGE"], "branches": ["main"] },
+            { "name": "empty" },
+        ]));
+        let sec = decision("security.mfa", "required for admins");
+        let db = decision("db.engine", "outage on the old one");
+        let note = new_note_event("main", None, "user", "Auth outage at 9am", &[]).unwrap();
+        let mut on_branch = note.clone();
+        on_branch.branch = "feature".into();
+
+        let names = |e: &Event| -> Vec<String> {
+            rule_matches(&rules, e)
+                .into_iter()
+                .map(|n| match n {
+                    NotifyEvent::RuleMatch { rule, .. } => rule,
+                    other => panic!("unexpected {other:?}"),
+                })
+                .collect()
+        };
+        assert_eq!(names(&sec), ["security"]);
+        assert_eq!(names(&db), ["outage"]);
+        assert_eq!(names(&note), ["outage"]);
+        assert!(names(&on_branch).is_empty());
+
+        let NotifyEvent::RuleMatch {
+            summary, event_id, ..
+        } = &rule_matches(&rules, &sec)[0]
+        else {
+            unreachable!()
+        };
+        assert_eq!(event_id, &sec.event_id);
+        assert_eq!(summary, "security.mfa: on — required for admins");
+    }
+
+    #[test]
+    fn appends_only_queue_matches_until_delivered() {
+        let dir = std::env::temp_dir().join(format!("edda_notify_rules_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = EddaPaths::discover(&dir);
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+        std::fs::write(
+            &paths.config_json,
+            serde_json::json!({
+                // Nothing listens on port 9: a send attempt would fail.
+                "notify_channels": [{
+                    "type": "webhook", "url": "http://127.0.0.1:9/never",
+                    "events": ["rule:security"]
+                }],
+                "notify_rules": [{ "name": "security", "domains": ["security"] }],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        install();
+
+        let ledger = edda_ledger::Ledger::open(&dir).unwrap();
+        let sec = decision("security.mfa", "required");
+        ledger.append_event(&sec).unwrap();
+        let config = NotifyConfig::load(&paths);
+        let queue = config.queue_path.clone().unwrap();
+        assert_eq!(crate::queued_count(&config), 1);
+        // Queued as raised, with no send attempted during the append.
+        let line = std::fs::read_to_string(&queue).unwrap();
+        assert!(!line.contains("last_error"), "{line}");
+        assert!(line.contains(&sec.event_id));
+
+        deliver_queued(&dir);
+        let line = std::fs::read_to_string(&queue).unwrap();
+        assert!(line.contains("last_error"), "{line}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// A workspace whose only channel takes `rule:security` and always
+    /// fails, with one match queued by "another process" (not in PENDING).
+    fn workspace_with_queued_match(name: &str) -> (PathBuf, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("edda_notify_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let paths = EddaPaths::discover(&dir);
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        std::fs::write(
+            &paths.config_json,
+            serde_json::json!({
+                "notify_channels": [{
+                    "type": "webhook", "url": "http://127.0.0.1:9/never",
+                    "events": ["rule:security"]
+                }],
+                "notify_rules": [{ "name": "security", "domains": ["security"] }],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let config = NotifyConfig::load(&paths);
+        let rules = config.rules.clone();
+        for notification in rule_matches(&rules, &decision("security.mfa", "required")) {
+            crate::enqueue(&config, &notification);
+        }
+        let queue = config.queue_path.clone().unwrap();
+        assert!(!std::fs::read_to_string(&queue)
+            .unwrap()
+            .contains("last_error"));
+        (dir, queue)
+    }
+
+    #[test]
+    fn next_command_delivers_what_a_hook_left_queued() {
+        let (dir, queue) = workspace_with_queued_match("hook_backlog");
+        deliver_queued(&dir);
+        let line = std::fs::read_to_string(&queue).unwrap();
+        assert!(line.contains("last_error"), "send attempted: {line}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn delivery_task_stops_with_its_server_after_a_last_delivery() {
+        let (dir, queue) = workspace_with_queued_match("delivery_stop");
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let task = spawn_delivery(&dir, stopped);
+        drop(stop);
+        tokio::time::timeout(Duration::from_secs(30), task)
+            .await
+            .expect("delivery task ends once stopped")
+            .unwrap();
+        let line = std::fs::read_to_string(&queue).unwrap();
+        assert!(line.contains("last_error"), "send attempted: {line}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn tags_and_families_match_exactly() {
+        let rules = rules(serde_json::json!([
+            { "name": "handoffs", "tags": ["handoff"] },
+            { "name": "signals", "families": ["signal"], "types": ["cmd"] },
+        ]));
+        let handoff =
+            new_note_event("main", None, "system", "# Handoff", &["handoff".into()]).unwrap();
+        let mut cmd = new_note_event("main", None, "system", "cargo test", &[]).unwrap();
+        cmd.event_type = "cmd".into();
+        cmd.event_family = Some("signal".into());
+
+        assert_eq!(rule_matches(&rules, &handoff).len(), 1);
+        let NotifyEvent::RuleMatch { rule, .. } = &rule_matches(&rules, &cmd)[0] else {
+            unreachable!()
+        };
+        assert_eq!(rule, "signals");
+    }
+}
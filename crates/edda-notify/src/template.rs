@@ -0,0 +1,237 @@
+//! Notification templates — user overrides for titles, bodies and fields.
+//!
+//! Stored in `.edda/config.json` under key `notify_templates`, keyed by event
+//! name (or `*` for every event). Each entry sets message fields for all
+//! channels and may override them per channel type:
+//!
+//! ```json
+//! "notify_templates": {
+//!   "approval_pending": {
+//!     "title": "🔔 {{ title }}",
+//!     "body": "Draft {{ draft_id }} needs {{ role }}",
+//!     "telegram": { "body": "<b>{{ title }}</b>{% if role %} ({{ role }}){% endif %}" },
+//!     "webhook": { "fields": ["draft_id", "title"] }
+//!   }
+//! }
+//! ```
+//!
+//! Templates are [minijinja] (Jinja2) templates: `{{ field }}`, filters,
+//! `{% if %}` and `{% for %}` blocks. Fields are the event's data fields plus
+//! `event` (its name). Anything a template leaves unset falls back to the
+//! built-in formatting.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Markup a rendered message is sent as.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateFormat {
+    Plain,
+    Markdown,
+    Html,
+}
+
+/// Message fields a template can set; every one is optional.
+#[derive(Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct MessageTemplate {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub body: Option<String>,
+    /// ntfy: `plain` or `markdown`; Telegram: `html` (default), `markdown`
+    /// or `plain`. Ignored by webhooks.
+    #[serde(default)]
+    pub format: Option<TemplateFormat>,
+    /// ntfy priority (`min`, `low`, `default`, `high`, `urgent`).
+    #[serde(default)]
+    pub priority: Option<String>,
+    /// Webhook: the data fields to include (default: all).
+    #[serde(default)]
+    pub fields: Option<Vec<String>>,
+}
+
+impl MessageTemplate {
+    /// Fill the fields left unset here from `other`.
+    fn or(mut self, other: Option<&MessageTemplate>) -> Self {
+        let Some(other) = other else {
+            return self;
+        };
+        self.title = self.title.or_else(|| other.title.clone());
+        self.body = self.body.or_else(|| other.body.clone());
+        self.format = self.format.or(other.format);
+        self.priority = self.priority.or_else(|| other.priority.clone());
+        self.fields = self.fields.or_else(|| other.fields.clone());
+        self
+    }
+}
+
+/// Template for one event: shared fields plus per-channel-type overrides.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct EventTemplate {
+    #[serde(flatten)]
+    pub shared: MessageTemplate,
+    #[serde(default)]
+    pub ntfy: Option<MessageTemplate>,
+    #[serde(default)]
+    pub webhook: Option<MessageTemplate>,
+    #[serde(default)]
+    pub telegram: Option<MessageTemplate>,
+}
+
+impl EventTemplate {
+    fn for_channel(&self, kind: &str) -> Option<&MessageTemplate> {
+        match kind {
+            "ntfy" => self.ntfy.as_ref(),
+            "webhook" => self.webhook.as_ref(),
+            "telegram" => self.telegram.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// All configured templates, keyed by event name or `*`.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(transparent)]
+pub struct NotifyTemplates(pub HashMap<String, EventTemplate>);
+
+impl NotifyTemplates {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The effective template for `event` on a channel of type `kind`. The
+    /// most specific setting wins: event + channel, event, `*` + channel,
+    /// then `*`.
+    pub fn resolve(&self, kind: &str, event: &str) -> MessageTemplate {
+        let exact = self.0.get(event);
+        let any = self.0.get("*");
+        MessageTemplate::default()
+            .or(exact.and_then(|t| t.for_channel(kind)))
+            .or(exact.map(|t| &t.shared))
+            .or(any.and_then(|t| t.for_channel(kind)))
+            .or(any.map(|t| &t.shared))
+    }
+}
+
+// ── Rendering ──
+
+/// Render `template` with the fields of `vars` (a JSON object). Values pass
+/// through `escape`; literal template text is left as written. Unknown and
+/// null fields render empty. A template that fails to parse or render is
+/// sent as written, so a typo shows up in the message instead of dropping it.
+pub fn render(template: &str, vars: &serde_json::Value, escape: fn(&str) -> String) -> String {
+    let mut env = minijinja::Environment::new();
+    env.set_formatter(move |out, _state, value| {
+        if value.is_undefined() || value.is_none() {
+            return Ok(());
+        }
+        let text = match value.as_str() {
+            Some(s) => escape(s),
+            None => escape(&value.to_string()),
+        };
+        out.write_str(&text)
+            .map_err(|e| minijinja::Error::new(minijinja::ErrorKind::WriteFailure, e.to_string()))
+    });
+    let ctx = minijinja::Value::from_serialize(vars);
+    match env.render_str(template, ctx) {
+        Ok(rendered) => rendered,
+        Err(e) => {
+            tracing::warn!(error = %e, "notify template failed to render; sending it as written");
+            template.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(s: &str) -> String {
+        s.to_string()
+    }
+
+    #[test]
+    fn render_substitutes_fields_and_conditionals() {
+        let vars = serde_json::json!({
+            "title": "Add <cache>",
+            "issue": 42,
+            "summary": "",
+            "missing": null,
+        });
+        assert_eq!(
+            render("🔔 {{ title }} (#{{issue}})", &vars, plain),
+            "🔔 Add <cache> (#42)"
+        );
+        assert_eq!(
+            render(
+                "{% if summary %}{{ summary }}{% else %}no summary{% endif %}{{ missing }}",
+                &vars,
+                plain
+            ),
+            "no summary"
+        );
+        assert_eq!(
+            render(
+                "{% if issue %}a{% if summary %}b{% endif %}c{% endif %}",
+                &vars,
+                plain
+            ),
+            "ac"
+        );
+        assert_eq!(
+            render("<b>{{ title }}</b>", &vars, |s| s.replace('<', "&lt;")),
+            "<b>Add &lt;cache></b>"
+        );
+        assert_eq!(render("{{ unclosed", &vars, plain), "{{ unclosed");
+    }
+
+    #[test]
+    fn render_supports_filters_and_loops() {
+        let vars = serde_json::json!({
+            "title": "ship it",
+            "labels": ["perf", "<db>"],
+        });
+        assert_eq!(
+            render(
+                "{{ title | upper }}:{% for l in labels %} #{{ l }}{% endfor %}",
+                &vars,
+                |s| s.replace('<', "&lt;")
+            ),
+            "SHIP IT: #perf #&lt;db>"
+        );
+        assert_eq!(
+            render(
+                "{{ labels | length }} {{ nope | default('none') }}",
+                &vars,
+                plain
+            ),
+            "2 none"
+        );
+    }
+
+    #[test]
+    fn resolve_prefers_the_most_specific_setting() {
+        let templates: NotifyTemplates = serde_json::from_value(serde_json::json!({
+            "*": { "title": "any", "priority": "low", "telegram": { "format": "plain" } },
+            "anomaly": { "body": "shared", "ntfy": { "title": "ntfy only" } },
+        }))
+        .unwrap();
+
+        let ntfy = templates.resolve("ntfy", "anomaly");
+        assert_eq!(ntfy.title.as_deref(), Some("ntfy only"));
+        assert_eq!(ntfy.body.as_deref(), Some("shared"));
+        assert_eq!(ntfy.priority.as_deref(), Some("low"));
+
+        let telegram = templates.resolve("telegram", "anomaly");
+        assert_eq!(telegram.title.as_deref(), Some("any"));
+        assert_eq!(telegram.format, Some(TemplateFormat::Plain));
+
+        assert_eq!(
+            templates.resolve("webhook", "plan_gate").body,
+            None,
+            "event-specific fields do not leak to other events"
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use edda_core::text::{display_width, truncate_width, width_boundary};
 use edda_index::{fetch_store_line, read_index_tail, IndexRecordV1};
 use edda_ledger::view::DecisionView;
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::Path;
 
+mod tool_output;
+
 const DEFAULT_INDEX_TAIL_LINES: usize = 5000;
 const DEFAULT_INDEX_TAIL_MAX_BYTES: u64 = 8 * 1024 * 1024; // 8MB
 const DEFAULT_PACK_TURNS: usize = 12;
@@ -27,6 +30,10 @@ pub struct ToolUse {
     pub command: Option<String>,
     pub description: Option<String>,
     pub file_path: Option<String>,
+    /// Summary of the tool's result (failing tests, build errors, diff
+    /// shape); `None` when the result carried nothing worth packing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -59,6 +66,10 @@ pub fn build_turns(
         .and_then(|v| v.parse().ok())
         .unwrap_or(DEFAULT_PACK_TURNS);
     let max_turns = max_turns.min(pack_turns);
+    let output_chars: usize = std::env::var("EDDA_PACK_TOOL_OUTPUT_CHARS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(tool_output::DEFAULT_OUTPUT_CHARS);
 
     let index_path = project_dir
         .join("index")
@@ -79,9 +90,8 @@ pub fn build_turns(
         .filter(|r| r.record_type == "assistant")
         .collect();
 
-    let store_path = project_dir
-        .join("transcripts")
-        .join(format!("{session_id}.jsonl"));
+    let store_path =
+        edda_store::store_subdir(project_dir, "transcripts").join(format!("{session_id}.jsonl"));
 
     let mut turns = Vec::new();
     let mut seen_user_uuids = HashSet::new();
@@ -98,6 +108,8 @@ pub fn build_turns(
         // We start from the leaf assistant and walk up to find the root user with STRING content.
         let mut current_parent = asst_rec.parent_uuid.as_deref();
         let mut chain_tool_uses: Vec<ToolUse> = Vec::new();
+        // tool_use_id → (result text, is_error)
+        let mut tool_results: HashMap<String, (String, bool)> = HashMap::new();
         let mut real_user_uuid = String::new();
         let mut real_user_text = String::new();
 
@@ -127,6 +139,7 @@ pub fn build_turns(
                             real_user_text = text;
                             break; // Found the real user prompt
                         }
+                        collect_tool_results(&json, &mut tool_results);
                     }
                 }
                 // Content is array (tool_result) or empty → keep walking up
@@ -171,6 +184,12 @@ pub fn build_turns(
         // Merge tool_uses: chain (reversed to chronological) + final assistant's
         chain_tool_uses.reverse();
         chain_tool_uses.extend(final_tool_uses);
+        for tu in &mut chain_tool_uses {
+            let result = tu.id.as_deref().and_then(|id| tool_results.get(id));
+            if let Some((text, is_error)) = result {
+                tu.output = tool_output::summarize(tu, text, *is_error, output_chars);
+            }
+        }
 
         turns.push(Turn {
             user_uuid: real_user_uuid,
@@ -227,6 +246,30 @@ fn extract_user_text(user_json: &serde_json::Value) -> String {
     String::new()
 }
 
+/// Gather `tool_result` blocks from a user record, keyed by `tool_use_id`.
+fn collect_tool_results(user_json: &serde_json::Value, out: &mut HashMap<String, (String, bool)>) {
+    let blocks = user_json
+        .get("message")
+        .and_then(|m| m.get("content"))
+        .and_then(|c| c.as_array());
+    for block in blocks.into_iter().flatten() {
+        if block.get("type").and_then(|t| t.as_str()) != Some("tool_result") {
+            continue;
+        }
+        let Some(id) = block.get("tool_use_id").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let is_error = block
+            .get("is_error")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        out.insert(
+            id.to_string(),
+            (tool_output::tool_result_text(block), is_error),
+        );
+    }
+}
+
 fn parse_assistant_content(asst_json: &serde_json::Value) -> (Vec<String>, Vec<ToolUse>) {
     let mut texts = Vec::new();
     let mut tool_uses = Vec::new();
@@ -269,6 +312,7 @@ fn parse_assistant_content(asst_json: &serde_json::Value) -> (Vec<String>, Vec<T
                         command,
                         description,
                         file_path,
+                        output: None,
                     });
                 }
                 _ => {}
@@ -305,7 +349,7 @@ pub fn render_pack(turns: &[Turn], metadata: &PackMetadata, budget_chars: usize)
     // Render turns, newest first, truncate from oldest if over budget
     for (i, turn) in turns.iter().enumerate() {
         let mut section = String::new();
-        let user_preview = truncate_str(&turn.user_text, 200);
+        let user_preview = truncate_width(&turn.user_text, 200);
         section.push_str(&format!("### Turn {} (newest first)\n", i + 1));
         section.push_str(&format!("- User: {user_preview}\n"));
 
@@ -313,12 +357,12 @@ pub fn render_pack(turns: &[Turn], metadata: &PackMetadata, budget_chars: usize)
             let cmd_str = tu
                 .command
                 .as_deref()
-                .map(|c| format!(" `{}`", truncate_str(c, 80)))
+                .map(|c| format!(" `{}`", truncate_width(c, 80)))
                 .unwrap_or_default();
             let desc_str = tu
                 .description
                 .as_deref()
-                .map(|d| format!(" ({})", truncate_str(d, 60)))
+                .map(|d| format!(" ({})", truncate_width(d, 60)))
                 .unwrap_or_default();
             let file_str = tu
                 .file_path
@@ -329,15 +373,24 @@ pub fn render_pack(turns: &[Turn], metadata: &PackMetadata, budget_chars: usize)
                 "  - ToolUse: {}{}{}{}\n",
                 tu.name, cmd_str, desc_str, file_str
             ));
+            if let Some(output) = &tu.output {
+                let mut lines = output.lines();
+                if let Some(first) = lines.next() {
+                    section.push_str(&format!("    - Output: {first}\n"));
+                }
+                for line in lines {
+                    section.push_str(&format!("      {line}\n"));
+                }
+            }
         }
 
         for text in &turn.assistant_texts {
-            let preview = truncate_str(text, 300);
+            let preview = truncate_width(text, 300);
             section.push_str(&format!("  - Assistant: {preview}\n"));
         }
         section.push('\n');
 
-        if out.len() + section.len() > budget {
+        if display_width(&out) + display_width(&section) > budget {
             out.push_str(&format!(
                 "... ({} more turns truncated by budget)\n",
                 turns.len() - i
@@ -350,19 +403,6 @@ pub fn render_pack(turns: &[Turn], metadata: &PackMetadata, budget_chars: usize)
     out
 }
 
-fn truncate_str(s: &str, max: usize) -> String {
-    if s.len() <= max {
-        s.replace('\n', " ")
-    } else {
-        // Find the last char boundary at or before `max` bytes
-        let mut end = max;
-        while end > 0 && !s.is_char_boundary(end) {
-            end -= 1;
-        }
-        format!("{}...", s[..end].replace('\n', " "))
-    }
-}
-
 /// Write hot.md and hot.meta.json to the packs directory.
 pub fn write_pack(project_dir: &Path, pack_md: &str, meta: &PackMetadata) -> anyhow::Result<()> {
     let packs_dir = project_dir.join("packs");
@@ -376,6 +416,204 @@ pub fn write_pack(project_dir: &Path, pack_md: &str, meta: &PackMetadata) -> any
     Ok(())
 }
 
+// ── Warm Pack (cross-session summaries) ──
+
+const DEFAULT_WARM_SESSIONS: usize = 5;
+const DEFAULT_WARM_BUDGET_CHARS: usize = 3000;
+
+/// One finished session as recorded by its `session_digest` note.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WarmSession {
+    pub session_id: String,
+    /// Timestamp of the digest event (≈ when the session was digested).
+    pub ts: String,
+    pub outcome: String,
+    pub duration_minutes: u64,
+    /// Decisions recorded since the previous digest, as `key=value`.
+    pub decisions: Vec<String>,
+    /// Notes with a structured body recorded since the previous digest.
+    #[serde(default)]
+    pub notes: Vec<WarmNote>,
+    pub files_modified: Vec<String>,
+    pub commits: Vec<String>,
+}
+
+/// A note whose structured body (code, links, table) the warm pack keeps.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmNote {
+    pub text: String,
+    pub body: edda_core::NoteBody,
+}
+
+/// Collect the last `max_sessions` session digests from the workspace ledger,
+/// newest first.
+///
+/// Decisions and notes with a structured body are attributed to the first
+/// digest written after them, so each session carries what was decided and
+/// noted since the previous digest. Returns an
+/// empty list when the ledger cannot be opened.
+pub fn build_warm_sessions(repo_root: &Path, max_sessions: usize) -> Vec<WarmSession> {
+    let notes =
+        match edda_ledger::Ledger::open(repo_root).and_then(|l| l.iter_events_by_type("note")) {
+            Ok(events) => events,
+            Err(_) => return Vec::new(),
+        };
+
+    let mut sessions = Vec::new();
+    let mut pending_decisions: Vec<String> = Vec::new();
+    let mut pending_notes: Vec<WarmNote> = Vec::new();
+    for event in &notes {
+        if edda_core::decision::is_decision(&event.payload) {
+            if let Some(dp) = edda_core::decision::extract_decision(&event.payload) {
+                pending_decisions.push(format!("{}={}", dp.key, dp.value));
+            }
+            continue;
+        }
+        let is_digest = event.payload["tags"]
+            .as_array()
+            .is_some_and(|tags| tags.iter().any(|t| t == "session_digest"));
+        if !is_digest {
+            if let Some(body) = edda_core::NoteBody::from_payload(&event.payload) {
+                pending_notes.push(WarmNote {
+                    text: event.payload["text"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    body,
+                });
+            }
+            continue;
+        }
+
+        let stats = &event.payload["session_stats"];
+        let strings = |v: &serde_json::Value| -> Vec<String> {
+            v.as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|s| s.as_str().map(String::from))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        sessions.push(WarmSession {
+            session_id: event.payload["session_id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            ts: event.ts.clone(),
+            outcome: stats["outcome"].as_str().unwrap_or("completed").to_string(),
+            duration_minutes: stats["duration_minutes"].as_u64().unwrap_or(0),
+            decisions: std::mem::take(&mut pending_decisions),
+            notes: std::mem::take(&mut pending_notes),
+            files_modified: strings(&stats["files_modified"]),
+            commits: strings(&stats["commits_made"]),
+        });
+    }
+
+    sessions.reverse();
+    sessions.truncate(max_sessions);
+    sessions
+}
+
+/// Render warm sessions (newest first) as a markdown pack.
+///
+/// `budget_chars == 0` falls back to `EDDA_WARM_BUDGET_CHARS`. Older sessions
+/// are dropped first when over budget. Returns an empty string when there are
+/// no sessions to summarize.
+pub fn render_warm_pack(sessions: &[WarmSession], budget_chars: usize) -> String {
+    if sessions.is_empty() {
+        return String::new();
+    }
+    let budget = if budget_chars == 0 {
+        std::env::var("EDDA_WARM_BUDGET_CHARS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_WARM_BUDGET_CHARS)
+    } else {
+        budget_chars
+    };
+
+    let mut out = String::new();
+    out.push_str("# edda memory pack (warm)\n\n");
+    out.push_str(&format!("- sessions: {}\n\n", sessions.len()));
+    out.push_str("## Recent Sessions (newest first)\n\n");
+
+    for (i, s) in sessions.iter().enumerate() {
+        let sid_short = &s.session_id[..s.session_id.len().min(8)];
+        let date = s.ts.get(..10).unwrap_or(&s.ts);
+        let mut section = format!(
+            "### {sid_short} ({date}, {} min, {})\n",
+            s.duration_minutes, s.outcome
+        );
+        if !s.decisions.is_empty() {
+            let decisions: Vec<String> =
+                s.decisions.iter().map(|d| truncate_width(d, 80)).collect();
+            section.push_str(&format!("- Decisions: {}\n", decisions.join("; ")));
+        }
+        for note in &s.notes {
+            section.push_str(&format!("- Note: {}\n", truncate_width(&note.text, 80)));
+            for line in note.body.render_markdown().lines() {
+                section.push_str(&format!("  {line}\n"));
+            }
+        }
+        if !s.files_modified.is_empty() {
+            let shown: Vec<&str> = s
+                .files_modified
+                .iter()
+                .take(8)
+                .map(|f| f.rsplit(['/', '\\']).next().unwrap_or(f))
+                .collect();
+            let more = s.files_modified.len().saturating_sub(shown.len());
+            let suffix = if more > 0 {
+                format!(" (+{more} more)")
+            } else {
+                String::new()
+            };
+            section.push_str(&format!("- Files: {}{suffix}\n", shown.join(", ")));
+        }
+        if !s.commits.is_empty() {
+            let commits: Vec<String> = s.commits.iter().map(|c| truncate_width(c, 80)).collect();
+            section.push_str(&format!("- Commits: {}\n", commits.join("; ")));
+        }
+        section.push('\n');
+
+        if display_width(&out) + display_width(&section) > budget {
+            out.push_str(&format!(
+                "... ({} older sessions truncated by budget)\n",
+                sessions.len() - i
+            ));
+            break;
+        }
+        out.push_str(&section);
+    }
+
+    out
+}
+
+/// Build, render, and write `packs/warm.md` for a workspace. Returns the
+/// rendered pack, or `None` when there are no session digests yet.
+pub fn refresh_warm_pack(project_dir: &Path, repo_root: &Path) -> Option<String> {
+    let max_sessions: usize = std::env::var("EDDA_WARM_SESSIONS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_WARM_SESSIONS);
+    let sessions = build_warm_sessions(repo_root, max_sessions);
+    let md = render_warm_pack(&sessions, 0);
+    if md.is_empty() {
+        return None;
+    }
+    let _ = write_warm_pack(project_dir, &md);
+    Some(md)
+}
+
+/// Write warm.md to the packs directory.
+pub fn write_warm_pack(project_dir: &Path, pack_md: &str) -> anyhow::Result<()> {
+    let packs_dir = project_dir.join("packs");
+    std::fs::create_dir_all(&packs_dir)?;
+    edda_store::write_atomic(&packs_dir.join("warm.md"), pack_md.as_bytes())?;
+    Ok(())
+}
+
 // ── Doctrine Pack (judgment layer) ──
 
 const DEFAULT_DOCTRINE_FILE: &str = ".havamal-pack.md";
@@ -415,12 +653,8 @@ pub fn read_doctrine_pack(repo_root: &Path, budget: usize) -> Option<String> {
     }
 
     let mut body = trimmed.to_string();
-    if body.len() > budget {
-        let mut end = budget;
-        while end > 0 && !body.is_char_boundary(end) {
-            end -= 1;
-        }
-        body.truncate(end);
+    if display_width(&body) > budget {
+        body.truncate(width_boundary(&body, budget));
         body.push_str("\n<!-- doctrine truncated by EDDA_DOCTRINE_BUDGET_CHARS -->");
     }
 
@@ -645,6 +879,7 @@ mod tests {
                 command: Some("ls -la".into()),
                 description: Some("List files".into()),
                 file_path: None,
+                output: None,
             }],
         }];
 
@@ -663,6 +898,63 @@ mod tests {
         assert!(md.contains("Use the sort() method."));
     }
 
+    #[test]
+    fn build_turns_packs_tool_output_summaries() {
+        let project = tempfile::tempdir().unwrap();
+        let records = [
+            serde_json::json!({"type": "user", "uuid": "u1",
+                "message": {"content": "fix the failing test"}}),
+            serde_json::json!({"type": "assistant", "uuid": "a1", "parentUuid": "u1",
+                "message": {"content": [{"type": "tool_use", "id": "t1", "name": "Bash",
+                    "input": {"command": "cargo test"}}]}}),
+            serde_json::json!({"type": "user", "uuid": "u2", "parentUuid": "a1",
+                "message": {"content": [{"type": "tool_result", "tool_use_id": "t1",
+                    "is_error": true,
+                    "content": "test parse::empty ... FAILED\ntest result: FAILED. 9 passed; 1 failed"}]}}),
+            serde_json::json!({"type": "assistant", "uuid": "a2", "parentUuid": "u2",
+                "message": {"content": [{"type": "text", "text": "The empty case panics."}]}}),
+        ];
+        let (mut store, mut index) = (String::new(), String::new());
+        for record in &records {
+            let line = record.to_string();
+            let rec = edda_index::build_index_record(
+                "s1",
+                store.len() as u64,
+                line.len() as u64 + 1,
+                record,
+            );
+            store.push_str(&line);
+            store.push('\n');
+            index.push_str(&serde_json::to_string(&rec).unwrap());
+            index.push('\n');
+        }
+        for (dir, text) in [("transcripts", store), ("index", index)] {
+            std::fs::create_dir_all(project.path().join(dir)).unwrap();
+            std::fs::write(project.path().join(dir).join("s1.jsonl"), text).unwrap();
+        }
+
+        let turns = build_turns(project.path(), "s1", 5).unwrap();
+        assert_eq!(turns.len(), 1);
+        let output = turns[0].tool_uses[0].output.as_deref().unwrap();
+        assert!(
+            output.starts_with("test parse::empty ... FAILED"),
+            "{output}"
+        );
+
+        let meta = PackMetadata {
+            project_id: "p".into(),
+            session_id: "s1".into(),
+            git_branch: "main".into(),
+            turn_count: 1,
+            budget_chars: 12000,
+        };
+        let md = render_pack(&turns, &meta, 12000);
+        assert!(
+            md.contains("  - ToolUse: Bash `cargo test`\n    - Output: test parse::empty ... FAILED\n      test result: FAILED"),
+            "{md}"
+        );
+    }
+
     #[test]
     fn render_pack_budget_truncation() {
         let turns: Vec<Turn> = (0..20)
@@ -690,6 +982,28 @@ mod tests {
         assert!(md.len() <= 600); // some slack for the truncation message
     }
 
+    #[test]
+    fn render_pack_previews_cjk_by_width() {
+        let turns = vec![Turn {
+            user_uuid: "u0".into(),
+            assistant_uuid: "a0".into(),
+            user_text: "請".repeat(150),
+            assistant_texts: vec!["好的".into()],
+            tool_uses: vec![],
+        }];
+        let meta = PackMetadata {
+            project_id: "abc".into(),
+            session_id: "s1".into(),
+            git_branch: "main".into(),
+            turn_count: 1,
+            budget_chars: 12000,
+        };
+
+        // A 200-column preview holds 100 CJK characters, not 200 bytes' worth.
+        let md = render_pack(&turns, &meta, 12000);
+        assert!(md.contains(&format!("- User: {}...\n", "請".repeat(100))));
+    }
+
     #[test]
     fn write_pack_creates_files() {
         let tmp = tempfile::tempdir().unwrap();
@@ -991,4 +1305,151 @@ mod tests {
         assert!(md.contains("## Unratified Decisions (1 on `main`)"));
         assert!(md.contains("[agent] **`api.style=REST`**"));
     }
+
+    #[test]
+    fn warm_pack_summarizes_digests_with_decisions() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let ledger = edda_ledger::Ledger::open_or_init(root).unwrap();
+
+        let append_digest = |sid: &str, files: &[&str], commits: &[&str]| {
+            let parent = ledger.last_event_hash().unwrap();
+            let mut ev = edda_core::event::new_note_event(
+                "main",
+                parent.as_deref(),
+                "system",
+                "digest",
+                &["session_digest".to_string()],
+            )
+            .unwrap();
+            ev.payload["session_id"] = serde_json::json!(sid);
+            ev.payload["session_stats"] = serde_json::json!({
+                "outcome": "completed",
+                "duration_minutes": 42,
+                "files_modified": files,
+                "commits_made": commits,
+            });
+            edda_core::event::finalize_event(&mut ev).unwrap();
+            ledger.append_event(&ev).unwrap();
+        };
+        let decide = |key: &str, value: &str| {
+            let parent = ledger.last_event_hash().unwrap();
+            let dp = edda_core::types::DecisionPayload {
+                key: key.into(),
+                value: value.into(),
+                reason: None,
+                scope: None,
+                authority: Some("agent".into()),
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            let ev = edda_core::event::new_decision_event("main", parent.as_deref(), "worker", &dp)
+                .unwrap();
+            ledger.append_event(&ev).unwrap();
+        };
+
+        decide("db.engine", "sqlite");
+        append_digest("sess-aaaaaaaa", &["src/db.rs"], &["feat: add db"]);
+        append_digest("sess-bbbbbbbb", &["src/api/routes.rs", "README.md"], &[]);
+
+        let sessions = build_warm_sessions(root, 5);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].session_id, "sess-bbbbbbbb");
+        assert!(sessions[0].decisions.is_empty());
+        assert_eq!(sessions[1].decisions, vec!["db.engine=sqlite".to_string()]);
+
+        let md = render_warm_pack(&sessions, 3000);
+        assert!(md.starts_with("# edda memory pack (warm)"));
+        assert!(md.contains("- sessions: 2"));
+        assert!(md.contains("### sess-bbb"));
+        assert!(md.contains("- Files: routes.rs, README.md"));
+        assert!(md.contains("- Decisions: db.engine=sqlite"));
+        assert!(md.contains("- Commits: feat: add db"));
+        assert!(md.find("sess-bbb").unwrap() < md.find("sess-aaa").unwrap());
+
+        assert_eq!(build_warm_sessions(root, 1).len(), 1);
+    }
+
+    #[test]
+    fn warm_pack_renders_structured_note_bodies() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let ledger = edda_ledger::Ledger::open_or_init(root).unwrap();
+
+        let body = edda_core::NoteBody::Code {
+            language: Some("sql".to_string()),
+            code: "SELECT 1;".to_string(),
+        };
+        let note = edda_core::event::new_note_event_with_body(
+            "main",
+            None,
+            "assistant",
+            "migration query",
+            &[],
+            Some(&body),
+        )
+        .unwrap();
+        ledger.append_event(&note).unwrap();
+        let plain =
+            edda_core::event::new_note_event("main", Some(&note.hash), "user", "plain", &[])
+                .unwrap();
+        ledger.append_event(&plain).unwrap();
+        let mut digest = edda_core::event::new_note_event(
+            "main",
+            Some(&plain.hash),
+            "system",
+            "digest",
+            &["session_digest".to_string()],
+        )
+        .unwrap();
+        digest.payload["session_id"] = serde_json::json!("sess-cccccccc");
+        edda_core::event::finalize_event(&mut digest).unwrap();
+        ledger.append_event(&digest).unwrap();
+
+        let sessions = build_warm_sessions(root, 5);
+        assert_eq!(sessions[0].notes.len(), 1);
+        assert_eq!(sessions[0].notes[0].text, "migration query");
+
+        let md = render_warm_pack(&sessions, 3000);
+        assert!(
+            md.contains("- Note: migration query\n  ```sql\n  SELECT 1;\n  ```\n"),
+            "missing note body in:\n{md}"
+        );
+        assert!(!md.contains("plain"));
+    }
+
+    #[test]
+    fn warm_pack_empty_without_digests() {
+        assert!(build_warm_sessions(Path::new("/nonexistent/path"), 5).is_empty());
+        assert_eq!(render_warm_pack(&[], 3000), "");
+    }
+
+    #[test]
+    fn warm_pack_budget_drops_oldest_sessions() {
+        let sessions: Vec<WarmSession> = (0..10)
+            .map(|i| WarmSession {
+                session_id: format!("sid{i:02}"),
+                ts: "2026-10-01T00:00:00Z".into(),
+                outcome: "completed".into(),
+                duration_minutes: 10,
+                commits: vec![format!("commit number {i} with a long enough message")],
+                ..Default::default()
+            })
+            .collect();
+        let md = render_warm_pack(&sessions, 400);
+        assert!(md.contains("### sid00"));
+        assert!(!md.contains("### sid09"));
+        assert!(md.contains("older sessions truncated by budget"));
+    }
+
+    #[test]
+    fn write_warm_pack_creates_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_warm_pack(tmp.path(), "# edda memory pack (warm)\n").unwrap();
+        let content = std::fs::read_to_string(tmp.path().join("packs").join("warm.md")).unwrap();
+        assert!(content.contains("(warm)"));
+    }
 }
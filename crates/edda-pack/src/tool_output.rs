@@ -0,0 +1,249 @@
+//! Tool-output summarizers for the hot pack.
+//!
+//! A pack line like `ToolUse: Bash `cargo test`` says a command ran, not why
+//! it failed. These summarizers pull the few lines that matter out of a tool
+//! result — failing tests, compiler errors, a diff's shape — so the next
+//! prompt sees the cause. Output that carries no signal (a clean `ls`, a
+//! successful edit) summarizes to `None` and costs no budget.
+
+use crate::ToolUse;
+use edda_core::text::{display_width, width_boundary};
+
+/// Lines kept from one tool result.
+const MAX_LINES: usize = 6;
+
+/// Default character budget per summarized tool result.
+pub(crate) const DEFAULT_OUTPUT_CHARS: usize = 400;
+
+/// Summarize one tool result, or `None` when there is nothing worth keeping.
+pub(crate) fn summarize(
+    tool: &ToolUse,
+    output: &str,
+    is_error: bool,
+    max_chars: usize,
+) -> Option<String> {
+    if max_chars == 0 || output.trim().is_empty() {
+        return None;
+    }
+    let lines = match tool.name.as_str() {
+        "Bash" => summarize_bash(tool.command.as_deref().unwrap_or(""), output, is_error),
+        _ if is_error => tail(output, 2),
+        _ => Vec::new(),
+    };
+    if lines.is_empty() {
+        return None;
+    }
+    let mut summary = lines.join("\n");
+    if display_width(&summary) > max_chars {
+        summary.truncate(width_boundary(&summary, max_chars));
+        summary.push_str("...");
+    }
+    Some(summary)
+}
+
+fn summarize_bash(command: &str, output: &str, is_error: bool) -> Vec<String> {
+    if command.contains("git diff") || output.starts_with("diff --git") {
+        if let Some(line) = diff_summary(output) {
+            return vec![line];
+        }
+    }
+
+    let mut lines = test_failures(output);
+    lines.extend(build_errors(output));
+    lines.dedup();
+    if !lines.is_empty() {
+        lines.truncate(MAX_LINES);
+        return lines;
+    }
+
+    // A passing test run is still worth one line: the count.
+    if let Some(result) = output
+        .lines()
+        .map(str::trim)
+        .find(|l| l.starts_with("test result:") || is_pytest_summary(l))
+    {
+        return vec![result.to_string()];
+    }
+
+    if is_error {
+        tail(output, 3)
+    } else {
+        Vec::new()
+    }
+}
+
+/// Failing test names, panics and assertion messages (cargo, pytest, jest).
+fn test_failures(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|l| {
+            (l.starts_with("test ") && l.ends_with("FAILED"))
+                || l.contains("panicked at")
+                || l.starts_with("test result: FAILED")
+                || l.starts_with("FAILED ")
+                || l.starts_with("● ")
+                || l.starts_with("AssertionError")
+                || l.starts_with("assertion `left == right` failed")
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Compiler and type-checker errors with their location (rustc, tsc, gcc).
+fn build_errors(output: &str) -> Vec<String> {
+    let lines: Vec<&str> = output.lines().collect();
+    let mut errors = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let l = line.trim();
+        let is_error = (l.starts_with("error[") || l.starts_with("error:"))
+            && !l.starts_with("error: could not compile")
+            && !l.starts_with("error: aborting");
+        if is_error || l.contains(": error TS") || l.contains(": error:") {
+            let location = lines
+                .get(i + 1)
+                .map(|n| n.trim())
+                .filter(|n| n.starts_with("--> "));
+            errors.push(match location {
+                Some(loc) => format!("{l} ({})", loc.trim_start_matches("--> ")),
+                None => l.to_string(),
+            });
+        }
+    }
+    errors
+}
+
+fn is_pytest_summary(line: &str) -> bool {
+    line.starts_with('=') && (line.contains(" passed") || line.contains(" failed"))
+}
+
+/// `3 files changed (+40 -12): a.rs, b.rs, c.rs` from `--stat` or raw diff.
+fn diff_summary(output: &str) -> Option<String> {
+    if let Some(stat) = output
+        .lines()
+        .map(str::trim)
+        .find(|l| l.contains(" changed") && (l.contains("insertion") || l.contains("deletion")))
+    {
+        return Some(stat.to_string());
+    }
+
+    let mut files = Vec::new();
+    let (mut added, mut removed) = (0usize, 0usize);
+    for line in output.lines() {
+        if let Some(rest) = line.strip_prefix("diff --git a/") {
+            if let Some((path, _)) = rest.split_once(" b/") {
+                files.push(path.to_string());
+            }
+        } else if line.starts_with('+') && !line.starts_with("+++") {
+            added += 1;
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            removed += 1;
+        }
+    }
+    if files.is_empty() {
+        return None;
+    }
+    let shown = files.len().min(5);
+    let more = if files.len() > shown {
+        format!(", +{} more", files.len() - shown)
+    } else {
+        String::new()
+    };
+    Some(format!(
+        "{} file(s) changed (+{added} -{removed}): {}{more}",
+        files.len(),
+        files[..shown].join(", ")
+    ))
+}
+
+fn tail(output: &str, n: usize) -> Vec<String> {
+    let lines: Vec<String> = output
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(str::to_string)
+        .collect();
+    lines[lines.len().saturating_sub(n)..].to_vec()
+}
+
+/// Text of a `tool_result` block's `content` (a string or text blocks).
+pub(crate) fn tool_result_text(block: &serde_json::Value) -> String {
+    match block.get("content") {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|p| p.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bash(command: &str) -> ToolUse {
+        ToolUse {
+            id: None,
+            name: "Bash".into(),
+            command: Some(command.into()),
+            description: None,
+            file_path: None,
+            output: None,
+        }
+    }
+
+    #[test]
+    fn cargo_test_failure_keeps_failing_tests_and_panic() {
+        let output = "running 3 tests\n\
+            test a::ok ... ok\n\
+            test a::broken ... FAILED\n\
+            \n\
+            thread 'a::broken' panicked at src/a.rs:10:5:\n\
+            assertion `left == right` failed\n\
+            test result: FAILED. 2 passed; 1 failed";
+        let s = summarize(&bash("cargo test"), output, true, 400).unwrap();
+        assert!(s.contains("test a::broken ... FAILED"), "{s}");
+        assert!(s.contains("panicked at src/a.rs:10:5"), "{s}");
+        assert!(s.contains("test result: FAILED"), "{s}");
+        assert!(!s.contains("a::ok"), "{s}");
+    }
+
+    #[test]
+    fn rustc_error_includes_location() {
+        let output = "   Compiling x v0.1.0\n\
+            error[E0425]: cannot find value `y` in this scope\n  \
+            --> src/main.rs:3:13\n\
+            error: could not compile `x`";
+        let s = summarize(&bash("cargo build"), output, true, 400).unwrap();
+        assert_eq!(
+            s,
+            "error[E0425]: cannot find value `y` in this scope (src/main.rs:3:13)"
+        );
+    }
+
+    #[test]
+    fn diff_is_reduced_to_its_shape() {
+        let output =
+            "diff --git a/src/a.rs b/src/a.rs\n--- a/src/a.rs\n+++ b/src/a.rs\n+new\n-old\n+more\n";
+        let s = summarize(&bash("git diff"), output, false, 400).unwrap();
+        assert_eq!(s, "1 file(s) changed (+2 -1): src/a.rs");
+    }
+
+    #[test]
+    fn quiet_success_and_disabled_budget_cost_nothing() {
+        assert_eq!(summarize(&bash("ls"), "a\nb\n", false, 400), None);
+        assert_eq!(
+            summarize(
+                &bash("cargo test"),
+                "test result: FAILED. 0 passed",
+                true,
+                0
+            ),
+            None
+        );
+        let s = summarize(&bash("cargo test"), "test result: ok. 5 passed", false, 400);
+        assert_eq!(s.as_deref(), Some("test result: ok. 5 passed"));
+    }
+}
@@ -0,0 +1,370 @@
+//! Per-field analyzer configuration.
+//!
+//! Each full-text field (`title`, `body`, `tags`, `tokens`) can be analyzed
+//! differently, set in `.edda/config.json` under `search.analyzer.<field>`:
+//!
+//! ```json
+//! { "search.analyzer.body": { "code": true, "stem": "english" },
+//!   "search.analyzer.tokens": { "code": true, "cjk": false } }
+//! ```
+//!
+//! - `cjk` (default `true`): CJK runs as overlapping bigrams (GH-402); off
+//!   keeps each run as one token.
+//! - `code` (default `false`): index `snake_case` and `camelCase`
+//!   identifiers whole and split into their parts.
+//! - `stem` (default none): stem words with the named language's Snowball
+//!   stemmer.
+//!
+//! The analyzer is encoded in the tokenizer name stored in the index schema,
+//! so a reader registers the right tokenizers from the index alone (see
+//! `schema::register_tokenizers`) and needs no config. The default analyzer
+//! keeps the GH-402 name, [`CJK_TOKENIZER`], so existing indexes stay valid.
+//!
+//! `edda search index` copies the workspace's settings into the project's
+//! `search/analyzers.json`; `sync` rebuilds the index when they no longer
+//! match the index schema.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use tantivy::tokenizer::{Language, Stemmer, TextAnalyzer};
+use tantivy::Index;
+
+use crate::tokenizer::{CjkBigramTokenizer, EddaTokenizer, TokenizeOptions, CJK_TOKENIZER};
+
+/// Config key prefix for analyzer settings.
+pub const ANALYZER_KEY_PREFIX: &str = "search.analyzer.";
+
+/// Fields whose analyzer can be configured.
+pub const TEXT_FIELDS: &[&str] = &["title", "body", "tags", "tokens"];
+
+/// Prefix of configured tokenizer names.
+const NAME_PREFIX: &str = "edda:";
+
+const ANALYZERS_FILE: &str = "analyzers.json";
+
+const LANGUAGES: &[(&str, Language)] = &[
+    ("arabic", Language::Arabic),
+    ("danish", Language::Danish),
+    ("dutch", Language::Dutch),
+    ("english", Language::English),
+    ("finnish", Language::Finnish),
+    ("french", Language::French),
+    ("german", Language::German),
+    ("greek", Language::Greek),
+    ("hungarian", Language::Hungarian),
+    ("italian", Language::Italian),
+    ("norwegian", Language::Norwegian),
+    ("portuguese", Language::Portuguese),
+    ("romanian", Language::Romanian),
+    ("russian", Language::Russian),
+    ("spanish", Language::Spanish),
+    ("swedish", Language::Swedish),
+    ("tamil", Language::Tamil),
+    ("turkish", Language::Turkish),
+];
+
+fn language_name(language: Language) -> &'static str {
+    LANGUAGES
+        .iter()
+        .find(|(_, l)| *l == language)
+        .map_or("english", |(name, _)| name)
+}
+
+fn parse_language(name: &str) -> anyhow::Result<Language> {
+    let lower = name.to_lowercase();
+    LANGUAGES
+        .iter()
+        .find(|(n, _)| *n == lower)
+        .map(|(_, l)| *l)
+        .ok_or_else(|| {
+            let names: Vec<&str> = LANGUAGES.iter().map(|(n, _)| *n).collect();
+            anyhow::anyhow!(
+                "unknown stemming language \"{name}\" (one of: {})",
+                names.join(", ")
+            )
+        })
+}
+
+/// How one field's text is split into terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "AnalyzerSpec", into = "AnalyzerSpec")]
+pub struct Analyzer {
+    pub cjk_bigrams: bool,
+    pub code: bool,
+    pub stem: Option<Language>,
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Self {
+            cjk_bigrams: true,
+            code: false,
+            stem: None,
+        }
+    }
+}
+
+/// The JSON form of an [`Analyzer`], as written in `config.json`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AnalyzerSpec {
+    #[serde(default = "default_true")]
+    cjk: bool,
+    #[serde(default)]
+    code: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stem: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl TryFrom<AnalyzerSpec> for Analyzer {
+    type Error = anyhow::Error;
+
+    fn try_from(spec: AnalyzerSpec) -> anyhow::Result<Self> {
+        Ok(Self {
+            cjk_bigrams: spec.cjk,
+            code: spec.code,
+            stem: spec.stem.as_deref().map(parse_language).transpose()?,
+        })
+    }
+}
+
+impl From<Analyzer> for AnalyzerSpec {
+    fn from(a: Analyzer) -> Self {
+        Self {
+            cjk: a.cjk_bigrams,
+            code: a.code,
+            stem: a.stem.map(|l| language_name(l).to_string()),
+        }
+    }
+}
+
+impl Analyzer {
+    /// Name to register the analyzer under: [`CJK_TOKENIZER`] for the
+    /// default, otherwise `edda:` and its settings, e.g.
+    /// `edda:cjk,code,stem=english`.
+    pub fn tokenizer_name(&self) -> String {
+        if *self == Self::default() {
+            return CJK_TOKENIZER.to_string();
+        }
+        let mut parts = Vec::new();
+        if self.cjk_bigrams {
+            parts.push("cjk".to_string());
+        }
+        if self.code {
+            parts.push("code".to_string());
+        }
+        if let Some(l) = self.stem {
+            parts.push(format!("stem={}", language_name(l)));
+        }
+        format!("{NAME_PREFIX}{}", parts.join(","))
+    }
+
+    /// The analyzer a tokenizer name stands for, or `None` when the name is
+    /// not one of ours (`raw`, `default`).
+    pub fn from_tokenizer_name(name: &str) -> Option<Self> {
+        if name == CJK_TOKENIZER {
+            return Some(Self::default());
+        }
+        let settings = name.strip_prefix(NAME_PREFIX)?;
+        let mut analyzer = Self {
+            cjk_bigrams: false,
+            code: false,
+            stem: None,
+        };
+        for part in settings.split(',').filter(|p| !p.is_empty()) {
+            match part.split_once('=') {
+                None if part == "cjk" => analyzer.cjk_bigrams = true,
+                None if part == "code" => analyzer.code = true,
+                Some(("stem", lang)) => analyzer.stem = Some(parse_language(lang).ok()?),
+                _ => return None,
+            }
+        }
+        Some(analyzer)
+    }
+
+    /// The tantivy analyzer to register under [`Analyzer::tokenizer_name`].
+    pub fn text_analyzer(&self) -> TextAnalyzer {
+        if *self == Self::default() {
+            return TextAnalyzer::from(CjkBigramTokenizer);
+        }
+        let tokenizer = EddaTokenizer(TokenizeOptions {
+            cjk_bigrams: self.cjk_bigrams,
+            code: self.code,
+        });
+        match self.stem {
+            Some(language) => TextAnalyzer::builder(tokenizer)
+                .filter(Stemmer::new(language))
+                .build(),
+            None => TextAnalyzer::from(tokenizer),
+        }
+    }
+}
+
+/// Analyzer per full-text field. Fields left out use the default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AnalyzerConfig {
+    fields: BTreeMap<String, Analyzer>,
+}
+
+impl AnalyzerConfig {
+    /// The analyzer for `field`.
+    pub fn field(&self, field: &str) -> Analyzer {
+        self.fields.get(field).copied().unwrap_or_default()
+    }
+
+    /// Set `field`'s analyzer. Fails for a field that is not configurable.
+    pub fn set(&mut self, field: &str, analyzer: Analyzer) -> anyhow::Result<()> {
+        if !TEXT_FIELDS.contains(&field) {
+            anyhow::bail!(
+                "search field \"{field}\" has no analyzer (one of: {})",
+                TEXT_FIELDS.join(", ")
+            );
+        }
+        if analyzer == Analyzer::default() {
+            self.fields.remove(field);
+        } else {
+            self.fields.insert(field.to_string(), analyzer);
+        }
+        Ok(())
+    }
+
+    /// Fields with a non-default analyzer.
+    pub fn fields(&self) -> &BTreeMap<String, Analyzer> {
+        &self.fields
+    }
+
+    /// Read the `search.analyzer.*` keys from `config.json`. A missing file
+    /// means defaults.
+    pub fn from_config(config_json: &Path) -> anyhow::Result<Self> {
+        let content = match std::fs::read_to_string(config_json) {
+            Ok(c) => c,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let config: serde_json::Value = serde_json::from_str(&content)?;
+        let mut out = Self::default();
+        if let Some(map) = config.as_object() {
+            for (key, value) in map {
+                let Some(field) = key.strip_prefix(ANALYZER_KEY_PREFIX) else {
+                    continue;
+                };
+                let analyzer: Analyzer = serde_json::from_value(value.clone())
+                    .map_err(|e| anyhow::anyhow!("{key}: {e}"))?;
+                out.set(field, analyzer)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// The analyzers an index was built with, read from its schema.
+    pub fn of_index(index: &Index) -> Self {
+        let schema = index.schema();
+        let mut out = Self::default();
+        for field in TEXT_FIELDS {
+            let analyzer = schema
+                .get_field(field)
+                .ok()
+                .and_then(|f| match schema.get_field_entry(f).field_type() {
+                    tantivy::schema::FieldType::Str(opts) => opts
+                        .get_indexing_options()
+                        .and_then(|i| Analyzer::from_tokenizer_name(i.tokenizer())),
+                    _ => None,
+                })
+                .unwrap_or_default();
+            // Every name in TEXT_FIELDS is configurable.
+            let _ = out.set(field, analyzer);
+        }
+        out
+    }
+
+    /// The settings `sync` builds a project's index with, from
+    /// `<search_dir>/analyzers.json`. Missing means defaults.
+    pub fn load(search_dir: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(search_dir.join(ANALYZERS_FILE)) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Store these settings for `sync`. Returns whether they changed.
+    pub fn save(&self, search_dir: &Path) -> anyhow::Result<bool> {
+        if search_dir.join(ANALYZERS_FILE).exists() && Self::load(search_dir)? == *self {
+            return Ok(false);
+        }
+        std::fs::create_dir_all(search_dir)?;
+        let json = serde_json::to_string_pretty(self)?;
+        edda_store::write_atomic(&search_dir.join(ANALYZERS_FILE), json.as_bytes())?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizer_names_round_trip() {
+        let default = Analyzer::default();
+        assert_eq!(default.tokenizer_name(), CJK_TOKENIZER);
+        assert_eq!(Analyzer::from_tokenizer_name("cjk"), Some(default));
+
+        let code = Analyzer {
+            cjk_bigrams: false,
+            code: true,
+            stem: Some(Language::English),
+        };
+        assert_eq!(code.tokenizer_name(), "edda:code,stem=english");
+        assert_eq!(
+            Analyzer::from_tokenizer_name(&code.tokenizer_name()),
+            Some(code)
+        );
+        assert_eq!(Analyzer::from_tokenizer_name("raw"), None);
+        assert_eq!(Analyzer::from_tokenizer_name("edda:bogus"), None);
+    }
+
+    #[test]
+    fn config_keys_set_per_field_analyzers() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.json");
+        assert_eq!(
+            AnalyzerConfig::from_config(&path).unwrap(),
+            AnalyzerConfig::default()
+        );
+
+        std::fs::write(
+            &path,
+            r#"{ "search.analyzer.body": { "code": true, "stem": "English" },
+                 "search.analyzer.title": { "cjk": true },
+                 "gc.retention.note": 30 }"#,
+        )
+        .unwrap();
+        let config = AnalyzerConfig::from_config(&path).unwrap();
+        assert_eq!(config.fields().len(), 1, "a default entry is not stored");
+        assert_eq!(config.field("body").stem, Some(Language::English));
+        assert!(config.field("body").code);
+        assert_eq!(config.field("tags"), Analyzer::default());
+
+        std::fs::write(
+            &path,
+            r#"{ "search.analyzer.body": { "stem": "klingon" } }"#,
+        )
+        .unwrap();
+        let err = AnalyzerConfig::from_config(&path).unwrap_err().to_string();
+        assert!(err.contains("klingon"), "{err}");
+        std::fs::write(&path, r#"{ "search.analyzer.ts": { "code": true } }"#).unwrap();
+        assert!(AnalyzerConfig::from_config(&path).is_err());
+
+        let search_dir = tmp.path().join("search");
+        assert!(config.save(&search_dir).unwrap());
+        assert!(!config.save(&search_dir).unwrap(), "unchanged");
+        assert_eq!(AnalyzerConfig::load(&search_dir).unwrap(), config);
+    }
+}
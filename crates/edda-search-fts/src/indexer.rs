@@ -308,9 +308,8 @@ pub fn index_session(
         return Ok(PendingMeta::default());
     }
 
-    let store_path = project_dir
-        .join("transcripts")
-        .join(format!("{session_id}.jsonl"));
+    let store_path =
+        edda_store::store_subdir(project_dir, "transcripts").join(format!("{session_id}.jsonl"));
 
     // Read all index records
     let records = edda_index::read_index_tail(&index_path, 100_000, 256 * 1024 * 1024)?;
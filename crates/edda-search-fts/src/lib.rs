@@ -1,5 +1,7 @@
+pub mod analyzer;
 pub mod indexer;
 pub mod schema;
 pub mod search;
+pub mod stats;
 pub mod sync;
 pub mod tokenizer;
@@ -1,4 +1,4 @@
-use crate::tokenizer::{CjkBigramTokenizer, CJK_TOKENIZER};
+use crate::analyzer::{Analyzer, AnalyzerConfig};
 use fs2::FileExt;
 use rusqlite::{Connection, OptionalExtension};
 use std::path::Path;
@@ -68,11 +68,23 @@ pub fn index_is_outdated(index_dir: &Path) -> bool {
 
 /// Register edda's custom tokenizers on an index. Must be called on every
 /// opened or created index so both indexing and `QueryParser` tokenize
-/// symmetrically (GH-402).
+/// symmetrically (GH-402). The analyzers come from the index's own schema,
+/// so readers need no analyzer config.
 pub fn register_tokenizers(index: &Index) {
-    index
-        .tokenizers()
-        .register(CJK_TOKENIZER, CjkBigramTokenizer);
+    let schema = index.schema();
+    for (_, entry) in schema.fields() {
+        let FieldType::Str(opts) = entry.field_type() else {
+            continue;
+        };
+        let Some(indexing) = opts.get_indexing_options() else {
+            continue;
+        };
+        if let Some(analyzer) = Analyzer::from_tokenizer_name(indexing.tokenizer()) {
+            index
+                .tokenizers()
+                .register(indexing.tokenizer(), analyzer.text_analyzer());
+        }
+    }
 }
 
 /// Build the Tantivy schema used for all search documents.
@@ -90,6 +102,11 @@ pub fn register_tokenizers(index: &Index) {
 /// - `tags`: space-separated event tags (TEXT)
 /// - `tokens`: tool names, commands, file paths (TEXT)
 pub fn build_schema() -> Schema {
+    build_schema_with(&AnalyzerConfig::default())
+}
+
+/// [`build_schema`] with the full-text fields analyzed as `analyzers` says.
+pub fn build_schema_with(analyzers: &AnalyzerConfig) -> Schema {
     let mut builder = Schema::builder();
 
     // Filterable string fields (indexed as single token, stored for retrieval)
@@ -111,20 +128,20 @@ pub fn build_schema() -> Schema {
     // Stored-only field (not indexed)
     builder.add_text_field("ts", STORED);
 
-    // Full-text searchable fields — CJK bigram tokenizer (GH-402) with
-    // positions (needed for snippets/phrases).
-    let cjk_indexing = TextFieldIndexing::default()
-        .set_tokenizer(CJK_TOKENIZER)
-        .set_index_option(IndexRecordOption::WithFreqsAndPositions);
-    let cjk_stored = TextOptions::default()
-        .set_indexing_options(cjk_indexing.clone())
-        .set_stored();
-    let cjk_unstored = TextOptions::default().set_indexing_options(cjk_indexing);
+    // Full-text searchable fields — CJK bigram tokenizer (GH-402) unless
+    // configured otherwise, with positions (needed for snippets/phrases).
+    let text_opts = |field: &str| {
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(&analyzers.field(field).tokenizer_name())
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        )
+    };
 
-    builder.add_text_field("title", cjk_stored.clone());
-    builder.add_text_field("body", cjk_stored.clone());
-    builder.add_text_field("tags", cjk_stored);
-    builder.add_text_field("tokens", cjk_unstored);
+    builder.add_text_field("title", text_opts("title").set_stored());
+    builder.add_text_field("body", text_opts("body").set_stored());
+    builder.add_text_field("tags", text_opts("tags").set_stored());
+    builder.add_text_field("tokens", text_opts("tokens"));
 
     builder.build()
 }
@@ -140,7 +157,17 @@ pub fn build_schema() -> Schema {
 /// full commit succeeds (see `cmd_search::index`), so an interrupted rebuild
 /// leaves no marker and self-heals on the next run.
 pub fn open_or_create_index(index_dir: &Path) -> anyhow::Result<(Index, bool)> {
-    let schema = build_schema();
+    open_or_create_index_with(index_dir, &AnalyzerConfig::default())
+}
+
+/// [`open_or_create_index`], creating a new index with `analyzers`. An
+/// existing index keeps the analyzers it was built with; see
+/// [`analyzers_changed`].
+pub fn open_or_create_index_with(
+    index_dir: &Path,
+    analyzers: &AnalyzerConfig,
+) -> anyhow::Result<(Index, bool)> {
+    let schema = build_schema_with(analyzers);
     if index_dir.exists() {
         match Index::open_in_dir(index_dir) {
             Ok(index) => {
@@ -159,6 +186,13 @@ pub fn open_or_create_index(index_dir: &Path) -> anyhow::Result<(Index, bool)> {
     Ok((index, true))
 }
 
+/// Whether the index at `index_dir` was built with analyzers other than
+/// `wanted`, so it must be rebuilt to apply them. A missing or unreadable
+/// index has nothing to migrate.
+pub fn analyzers_changed(index_dir: &Path, wanted: &AnalyzerConfig) -> bool {
+    open_index(index_dir).is_some_and(|index| AnalyzerConfig::of_index(&index) != *wanted)
+}
+
 /// Open or create a Tantivy index at the given directory (ignoring whether it
 /// was freshly created). Use [`open_or_create_index`] on the write path.
 pub fn ensure_index(index_dir: &Path) -> anyhow::Result<Index> {
@@ -226,6 +260,16 @@ const META_DDL: &str = "
         last_rowid INTEGER NOT NULL DEFAULT 0,
         last_ts TEXT
     );
+
+    CREATE TABLE IF NOT EXISTS index_commits (
+        project_id TEXT PRIMARY KEY,
+        committed_at TEXT NOT NULL
+    );
+
+    CREATE TABLE IF NOT EXISTS query_latency (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        micros INTEGER NOT NULL
+    );
 ";
 
 /// Open (or create) the SQLite database for turns_meta (byte-offset pointers).
@@ -331,6 +375,52 @@ pub fn write_events_cursor(
     Ok(())
 }
 
+/// How many recent query latencies `query_latency` keeps. Old samples are
+/// dropped on insert, so the table is a ring buffer rather than a log.
+pub const QUERY_LATENCY_SAMPLES: i64 = 50;
+
+/// Record that a sync committed the project's index just now. The timestamp
+/// comes from SQLite so this crate needs no clock dependency.
+pub fn write_last_commit(conn: &Connection, project_id: &str) -> anyhow::Result<()> {
+    conn.execute(
+        "INSERT INTO index_commits (project_id, committed_at)
+         VALUES (?1, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+         ON CONFLICT(project_id) DO UPDATE SET committed_at = excluded.committed_at",
+        [project_id],
+    )?;
+    Ok(())
+}
+
+/// When a sync last committed the project's index, or `None` if it never has
+/// (or the index predates commit tracking).
+pub fn read_last_commit(conn: &Connection, project_id: &str) -> anyhow::Result<Option<String>> {
+    Ok(conn
+        .query_row(
+            "SELECT committed_at FROM index_commits WHERE project_id = ?1",
+            [project_id],
+            |r| r.get::<_, String>(0),
+        )
+        .optional()?)
+}
+
+/// Add one query latency sample, evicting all but the newest
+/// [`QUERY_LATENCY_SAMPLES`].
+pub fn record_query_latency(conn: &Connection, micros: i64) -> anyhow::Result<()> {
+    conn.execute("INSERT INTO query_latency (micros) VALUES (?1)", [micros])?;
+    conn.execute(
+        "DELETE FROM query_latency WHERE id <= (SELECT MAX(id) FROM query_latency) - ?1",
+        [QUERY_LATENCY_SAMPLES],
+    )?;
+    Ok(())
+}
+
+/// The retained query latency samples, oldest first.
+pub fn read_query_latencies(conn: &Connection) -> anyhow::Result<Vec<i64>> {
+    let mut stmt = conn.prepare("SELECT micros FROM query_latency ORDER BY id")?;
+    let rows = stmt.query_map([], |r| r.get::<_, i64>(0))?;
+    Ok(rows.collect::<Result<_, _>>()?)
+}
+
 /// Open an in-memory SQLite database with turns_meta schema (for testing).
 pub fn ensure_meta_db_memory() -> anyhow::Result<Connection> {
     let conn = Connection::open_in_memory()?;
@@ -441,6 +531,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn configured_analyzers_are_registered_from_the_schema() {
+        use crate::analyzer::Analyzer;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let index_dir = tmp.path().join("tantivy");
+        let mut analyzers = AnalyzerConfig::default();
+        let code = Analyzer {
+            code: true,
+            ..Analyzer::default()
+        };
+        analyzers.set("body", code).unwrap();
+
+        open_or_create_index_with(&index_dir, &analyzers).unwrap();
+        assert!(!analyzers_changed(&index_dir, &analyzers));
+        assert!(analyzers_changed(&index_dir, &AnalyzerConfig::default()));
+
+        // A reader opened without the config tokenizes like the writer did.
+        let index = open_index(&index_dir).unwrap();
+        let mut tokenizer = index.tokenizers().get(&code.tokenizer_name()).unwrap();
+        let mut stream = tokenizer.token_stream("task_nudge");
+        let mut texts = Vec::new();
+        while stream.advance() {
+            texts.push(stream.token().text.clone());
+        }
+        assert_eq!(texts, vec!["task_nudge", "task", "nudge"]);
+    }
+
     #[test]
     fn ensure_meta_db_memory_creates_tables() {
         let conn = ensure_meta_db_memory().unwrap();
@@ -490,6 +608,28 @@ mod tests {
         assert_eq!(read_events_cursor(&conn, "p1").unwrap().rowid, 0);
     }
 
+    #[test]
+    fn query_latency_keeps_only_the_newest_samples() {
+        let conn = ensure_meta_db_memory().unwrap();
+        for micros in 0..QUERY_LATENCY_SAMPLES + 10 {
+            record_query_latency(&conn, micros).unwrap();
+        }
+        let samples = read_query_latencies(&conn).unwrap();
+        assert_eq!(samples.len() as i64, QUERY_LATENCY_SAMPLES);
+        assert_eq!(samples.first(), Some(&10));
+        assert_eq!(samples.last(), Some(&(QUERY_LATENCY_SAMPLES + 9)));
+    }
+
+    #[test]
+    fn last_commit_is_per_project() {
+        let conn = ensure_meta_db_memory().unwrap();
+        assert_eq!(read_last_commit(&conn, "p1").unwrap(), None);
+        write_last_commit(&conn, "p1").unwrap();
+        let ts = read_last_commit(&conn, "p1").unwrap().unwrap();
+        assert!(ts.ends_with('Z') && ts.contains('T'), "{ts}");
+        assert_eq!(read_last_commit(&conn, "p2").unwrap(), None);
+    }
+
     #[test]
     fn memory_and_file_meta_dbs_have_the_same_tables() {
         // The two builders share one DDL const; this pins that they cannot drift.
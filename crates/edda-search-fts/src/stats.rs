@@ -0,0 +1,194 @@
+//! Index maintenance telemetry for `edda search stats`.
+//!
+//! Everything here is read-only: asking how healthy an index is must never
+//! build, wipe or lock it. Staleness against the ledger is left to the caller,
+//! for the same reason `sync` takes its events through a closure — this crate
+//! does not know about `edda-ledger`.
+
+use crate::schema;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tantivy::collector::Count;
+use tantivy::query::TermQuery;
+use tantivy::schema::IndexRecordOption;
+use tantivy::{Index, Term};
+
+/// A snapshot of one project's search index.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexStats {
+    /// Whether the tantivy directory exists and opens.
+    pub present: bool,
+    /// The index needs a schema rebuild (see [`schema::INDEX_VERSION`]).
+    pub outdated: bool,
+    /// Live documents by `doc_type` (`event`, `turn`).
+    pub docs_by_type: BTreeMap<String, u64>,
+    /// Live event documents by `event_type`.
+    pub events_by_type: BTreeMap<String, u64>,
+    /// Bytes on disk under `search/`, tantivy segments and meta.sqlite alike.
+    pub size_bytes: u64,
+    /// When a sync last committed, per `schema::write_last_commit`.
+    pub last_commit: Option<String>,
+    /// Ledger rowid and timestamp of the newest indexed event.
+    pub indexed_rowid: i64,
+    pub indexed_through: Option<String>,
+    /// Mean of the retained query latency samples, in milliseconds.
+    pub avg_query_ms: Option<f64>,
+    pub query_samples: usize,
+}
+
+/// Gather [`IndexStats`] for a project without modifying anything on disk.
+///
+/// A missing index is not an error: it reports `present: false` and zero
+/// counts, which is exactly what a user deciding whether to reindex needs.
+pub fn index_stats(proj_dir: &Path, project_id: &str) -> anyhow::Result<IndexStats> {
+    let search_dir = edda_store::store_subdir(proj_dir, "search");
+    let index_dir = search_dir.join("tantivy");
+    let meta_path = search_dir.join("meta.sqlite");
+
+    let mut stats = IndexStats {
+        outdated: schema::index_is_outdated(&index_dir),
+        size_bytes: dir_size(&search_dir),
+        ..IndexStats::default()
+    };
+
+    if let Some(index) = schema::open_index(&index_dir) {
+        stats.present = true;
+        stats.docs_by_type = count_terms(&index, "doc_type")?;
+        stats.events_by_type = count_terms(&index, "event_type")?;
+    }
+
+    // Opening meta.sqlite creates it; only read one that is already there.
+    if meta_path.exists() {
+        let conn = schema::ensure_meta_db(&meta_path)?;
+        let cursor = schema::read_events_cursor(&conn, project_id)?;
+        stats.indexed_rowid = cursor.rowid;
+        stats.indexed_through = cursor.ts;
+        stats.last_commit = schema::read_last_commit(&conn, project_id)?;
+        let samples = schema::read_query_latencies(&conn)?;
+        stats.query_samples = samples.len();
+        if !samples.is_empty() {
+            let total: i64 = samples.iter().sum();
+            stats.avg_query_ms = Some(total as f64 / samples.len() as f64 / 1000.0);
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Record how long one query took. Best-effort by design: callers ignore the
+/// error, since a query that already has its answer must not fail over
+/// bookkeeping.
+pub fn record_query(proj_dir: &Path, elapsed: std::time::Duration) -> anyhow::Result<()> {
+    let meta_path = edda_store::store_subdir(proj_dir, "search").join("meta.sqlite");
+    let conn = schema::ensure_meta_db(&meta_path)?;
+    let micros = i64::try_from(elapsed.as_micros()).unwrap_or(i64::MAX);
+    schema::record_query_latency(&conn, micros)
+}
+
+/// Live documents per distinct value of a raw-tokenized field.
+///
+/// Terms are enumerated from every segment's dictionary, then counted with a
+/// query so deleted documents (superseded events) are not included.
+fn count_terms(index: &Index, field_name: &str) -> anyhow::Result<BTreeMap<String, u64>> {
+    let field = index.schema().get_field(field_name)?;
+    let searcher = index.reader()?.searcher();
+
+    let mut values = std::collections::BTreeSet::new();
+    for segment in searcher.segment_readers() {
+        let inverted = segment.inverted_index(field)?;
+        let mut stream = inverted.terms().stream()?;
+        while stream.advance() {
+            if let Ok(value) = std::str::from_utf8(stream.key()) {
+                if !value.is_empty() {
+                    values.insert(value.to_string());
+                }
+            }
+        }
+    }
+
+    let mut counts = BTreeMap::new();
+    for value in values {
+        let query = TermQuery::new(
+            Term::from_field_text(field, &value),
+            IndexRecordOption::Basic,
+        );
+        let n = searcher.search(&query, &Count)? as u64;
+        if n > 0 {
+            counts.insert(value, n);
+        }
+    }
+    Ok(counts)
+}
+
+fn dir_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|e| match e.metadata() {
+            Ok(m) if m.is_dir() => dir_size(&e.path()),
+            Ok(m) => m.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tantivy::doc;
+
+    #[test]
+    fn missing_index_reports_absent_not_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let stats = index_stats(tmp.path(), "p1").unwrap();
+        assert!(!stats.present);
+        assert_eq!(stats.size_bytes, 0);
+        assert!(stats.docs_by_type.is_empty());
+        assert!(stats.avg_query_ms.is_none());
+        assert!(!tmp.path().join("search").exists(), "stats must not create");
+    }
+
+    #[test]
+    fn counts_live_documents_by_type_and_averages_latency() {
+        let tmp = tempfile::tempdir().unwrap();
+        let index_dir = tmp.path().join("search").join("tantivy");
+        let index = schema::ensure_index(&index_dir).unwrap();
+        schema::write_index_version(&index_dir).unwrap();
+        let s = index.schema();
+        let f_doc_type = s.get_field("doc_type").unwrap();
+        let f_event_type = s.get_field("event_type").unwrap();
+        let f_doc_id = s.get_field("doc_id").unwrap();
+
+        let mut writer = schema::index_writer(&index).unwrap();
+        for (dt, et, id) in [
+            ("event", "note", "evt_1"),
+            ("event", "note", "evt_2"),
+            ("event", "commit", "evt_3"),
+            ("turn", "", "u1:a1"),
+        ] {
+            writer
+                .add_document(doc!(f_doc_type => dt, f_event_type => et, f_doc_id => id))
+                .unwrap();
+        }
+        writer.commit().unwrap();
+        writer.delete_term(Term::from_field_text(f_doc_id, "evt_2"));
+        writer.commit().unwrap();
+
+        record_query(tmp.path(), std::time::Duration::from_millis(4)).unwrap();
+        record_query(tmp.path(), std::time::Duration::from_millis(8)).unwrap();
+
+        let stats = index_stats(tmp.path(), "p1").unwrap();
+        assert!(stats.present);
+        assert!(!stats.outdated);
+        assert_eq!(stats.docs_by_type.get("event"), Some(&2));
+        assert_eq!(stats.docs_by_type.get("turn"), Some(&1));
+        assert_eq!(stats.events_by_type.get("note"), Some(&1));
+        assert_eq!(stats.events_by_type.get("commit"), Some(&1));
+        assert_eq!(stats.query_samples, 2);
+        assert_eq!(stats.avg_query_ms, Some(6.0));
+        assert!(stats.size_bytes > 0);
+    }
+}
@@ -8,6 +8,7 @@
 //! Events arrive through an injected closure, keeping this crate unaware of
 //! `edda-ledger` (the same inversion `index_events` already used).
 
+use crate::analyzer::AnalyzerConfig;
 use crate::{indexer, schema};
 use anyhow::Context;
 use std::path::Path;
@@ -41,15 +42,17 @@ pub fn sync<F>(
 where
     F: Fn(i64) -> anyhow::Result<Vec<(i64, edda_core::Event)>>,
 {
-    let search_dir = proj_dir.join("search");
+    let search_dir = edda_store::store_subdir(proj_dir, "search");
     let index_dir = search_dir.join("tantivy");
     let meta_db_path = search_dir.join("meta.sqlite");
 
     // Serialize against any other indexer touching this project (GH-402).
     let _lock = schema::IndexLock::acquire(&search_dir)?;
 
-    // Schema upgrade: wipe so it is recreated fresh below.
-    if schema::index_is_outdated(&index_dir) {
+    // Schema upgrade, or analyzers changed in config: wipe so it is recreated
+    // fresh below.
+    let analyzers = AnalyzerConfig::load(&search_dir)?;
+    if schema::index_is_outdated(&index_dir) || schema::analyzers_changed(&index_dir, &analyzers) {
         // GH-418: this wipe is destruction too, and the guard further down cannot
         // see what it destroyed — afterwards the index is empty, so "would this
         // empty a populated index?" trivially passes. The upgrade path is exactly
@@ -86,7 +89,7 @@ where
         std::fs::remove_dir_all(&index_dir)?;
     }
 
-    let (index, created_fresh) = schema::open_or_create_index(&index_dir)?;
+    let (index, created_fresh) = schema::open_or_create_index_with(&index_dir, &analyzers)?;
     let tantivy_schema = index.schema();
     let mut writer = schema::index_writer(&index)?;
     let meta_conn = schema::ensure_meta_db(&meta_db_path)?;
@@ -160,6 +163,7 @@ where
 
     // Now that the documents are durable, it is safe to say so.
     let turns = pending.flush(&meta_conn)?;
+    schema::write_last_commit(&meta_conn, project_id)?;
 
     let indexed_through = if let Some((rowid, ev)) = batch.last() {
         schema::write_events_cursor(&meta_conn, project_id, *rowid, Some(ev.ts.as_str()))?;
@@ -396,6 +400,35 @@ mod tests {
         assert_eq!(reader.searcher().num_docs(), 2);
     }
 
+    #[test]
+    fn changed_analyzers_rebuild_the_index() {
+        use crate::analyzer::{Analyzer, AnalyzerConfig};
+
+        let tmp = tempfile::tempdir().unwrap();
+        let led = FakeLedger::new(vec![
+            (1, mk_event("evt_a", "2026-07-15T12:00:00Z")),
+            (2, mk_event("evt_b", "2026-07-15T12:01:00Z")),
+        ]);
+        sync(tmp.path(), "p1", None, led.source()).unwrap();
+
+        let mut analyzers = AnalyzerConfig::default();
+        let stemmed = Analyzer {
+            stem: Some(tantivy::tokenizer::Language::English),
+            ..Analyzer::default()
+        };
+        analyzers.set("body", stemmed).unwrap();
+        analyzers.save(&tmp.path().join("search")).unwrap();
+
+        let stats = sync(tmp.path(), "p1", None, led.source()).unwrap();
+        assert!(stats.rebuilt, "new analyzers need a rebuild");
+        assert_eq!(stats.events, 2);
+        let index = crate::schema::open_index(&tmp.path().join("search").join("tantivy")).unwrap();
+        assert_eq!(AnalyzerConfig::of_index(&index), analyzers);
+
+        let again = sync(tmp.path(), "p1", None, led.source()).unwrap();
+        assert!(!again.rebuilt, "applied analyzers are not rebuilt again");
+    }
+
     #[test]
     fn ledger_replaced_at_the_same_rowids_is_not_trusted() {
         let tmp = tempfile::tempdir().unwrap();
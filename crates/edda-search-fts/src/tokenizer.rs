@@ -12,6 +12,10 @@
 //! the field's tokenizer — at query time. So `權威事實` tokenizes to
 //! `[權威, 威事, 事實]`, every one of which is present in a document containing
 //! `…洗成權威事實`, making the phrase reachable.
+//!
+//! [`EddaTokenizer`] is the same tokenizer with the options a configured
+//! analyzer can change (see `analyzer`): CJK bigrams off, or code-aware
+//! identifier splitting on.
 
 use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
 
@@ -21,6 +25,30 @@ pub const CJK_TOKENIZER: &str = "cjk";
 #[derive(Clone, Default)]
 pub struct CjkBigramTokenizer;
 
+/// How text is split into tokens. The default is [`CjkBigramTokenizer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenizeOptions {
+    /// Emit overlapping bigrams for CJK runs; off emits each run whole.
+    pub cjk_bigrams: bool,
+    /// Read words as code identifiers: `task_nudge` and `parseJsonBody` are
+    /// emitted whole and as their parts (`task`, `nudge`; `parse`, `json`,
+    /// `body`), the whole at the position of the first part.
+    pub code: bool,
+}
+
+impl Default for TokenizeOptions {
+    fn default() -> Self {
+        Self {
+            cjk_bigrams: true,
+            code: false,
+        }
+    }
+}
+
+/// Tokenizer for a configured analyzer.
+#[derive(Clone, Default)]
+pub struct EddaTokenizer(pub TokenizeOptions);
+
 /// A token stream backed by a pre-computed token vector.
 pub struct PrecomputedTokenStream {
     tokens: Vec<Token>,
@@ -50,7 +78,18 @@ impl Tokenizer for CjkBigramTokenizer {
 
     fn token_stream<'a>(&'a mut self, text: &'a str) -> PrecomputedTokenStream {
         PrecomputedTokenStream {
-            tokens: tokenize(text),
+            tokens: tokenize(text, TokenizeOptions::default()),
+            cursor: 0,
+        }
+    }
+}
+
+impl Tokenizer for EddaTokenizer {
+    type TokenStream<'a> = PrecomputedTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> PrecomputedTokenStream {
+        PrecomputedTokenStream {
+            tokens: tokenize(text, self.0),
             cursor: 0,
         }
     }
@@ -90,7 +129,7 @@ fn mk(offset_from: usize, offset_to: usize, position: usize, text: String) -> To
     }
 }
 
-fn tokenize(text: &str) -> Vec<Token> {
+fn tokenize(text: &str, opts: TokenizeOptions) -> Vec<Token> {
     let chars: Vec<(usize, char)> = text.char_indices().collect();
     let mut tokens = Vec::new();
     let mut pos = 0usize;
@@ -104,7 +143,12 @@ fn tokenize(text: &str) -> Vec<Token> {
             while j < chars.len() && is_cjk(chars[j].1) {
                 j += 1;
             }
-            if j - i == 1 {
+            if !opts.cjk_bigrams {
+                // The whole run as one token.
+                let end = chars[j - 1].0 + chars[j - 1].1.len_utf8();
+                tokens.push(mk(byte_start, end, pos, text[byte_start..end].to_string()));
+                pos += 1;
+            } else if j - i == 1 {
                 // A lone CJK character is emitted by itself.
                 let end = byte_start + c.len_utf8();
                 tokens.push(mk(byte_start, end, pos, c.to_string()));
@@ -123,6 +167,33 @@ fn tokenize(text: &str) -> Vec<Token> {
                 }
             }
             i = j;
+        } else if opts.code && c.is_alphanumeric() {
+            // Identifier run: words joined by underscores.
+            let is_word = |ch: char| ch.is_alphanumeric() && !is_cjk(ch);
+            let mut j = i;
+            while j < chars.len() && (is_word(chars[j].1) || chars[j].1 == '_') {
+                j += 1;
+            }
+            while chars[j - 1].1 == '_' {
+                j -= 1;
+            }
+            let parts = identifier_parts(&chars[i..j]);
+            if parts.len() > 1 {
+                let end = chars[j - 1].0 + chars[j - 1].1.len_utf8();
+                tokens.push(mk(
+                    byte_start,
+                    end,
+                    pos,
+                    text[byte_start..end].to_lowercase(),
+                ));
+            }
+            for (from, to) in parts {
+                let start = chars[i + from].0;
+                let end = chars[i + to - 1].0 + chars[i + to - 1].1.len_utf8();
+                tokens.push(mk(start, end, pos, text[start..end].to_lowercase()));
+                pos += 1;
+            }
+            i = j;
         } else if c.is_alphanumeric() {
             // ASCII/Latin/digit run (non-CJK alphanumerics), lowercased.
             let mut j = i;
@@ -146,6 +217,41 @@ fn tokenize(text: &str) -> Vec<Token> {
     tokens
 }
 
+/// Char ranges of the words in an identifier: split at underscores, at a
+/// lower-to-upper case change (`parseJson`), and before the last capital of
+/// an acronym followed by lowercase (`HTTPServer` -> `HTTP`, `Server`).
+fn identifier_parts(chars: &[(usize, char)]) -> Vec<(usize, usize)> {
+    let mut parts = Vec::new();
+    let mut start = None;
+    for k in 0..chars.len() {
+        let c = chars[k].1;
+        if c == '_' {
+            if let Some(s) = start.take() {
+                parts.push((s, k));
+            }
+            continue;
+        }
+        if let Some(s) = start {
+            let prev = chars[k - 1].1;
+            let next = chars.get(k + 1).map(|(_, n)| *n);
+            let boundary = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next.is_some_and(char::is_lowercase)));
+            if boundary {
+                parts.push((s, k));
+                start = Some(k);
+            }
+        } else {
+            start = Some(k);
+        }
+    }
+    if let Some(s) = start {
+        parts.push((s, chars.len()));
+    }
+    parts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,6 +308,59 @@ mod tests {
         assert_eq!(toks("收據,驗收"), vec!["收據", "驗收"]);
     }
 
+    fn code_toks(s: &str, cjk_bigrams: bool) -> Vec<(String, usize)> {
+        let mut t = EddaTokenizer(TokenizeOptions {
+            cjk_bigrams,
+            code: true,
+        });
+        let mut stream = t.token_stream(s);
+        let mut out = Vec::new();
+        while stream.advance() {
+            let tok = stream.token();
+            assert_eq!(&s[tok.offset_from..tok.offset_to].to_lowercase(), &tok.text);
+            out.push((tok.text.clone(), tok.position));
+        }
+        out
+    }
+
+    #[test]
+    fn code_mode_keeps_identifiers_whole_and_split() {
+        let pairs = |v: &[(&str, usize)]| -> Vec<(String, usize)> {
+            v.iter().map(|(t, p)| (t.to_string(), *p)).collect()
+        };
+        assert_eq!(
+            code_toks("call parseJsonBody now", true),
+            pairs(&[
+                ("call", 0),
+                ("parsejsonbody", 1),
+                ("parse", 1),
+                ("json", 2),
+                ("body", 3),
+                ("now", 4),
+            ])
+        );
+        assert_eq!(
+            code_toks("ENV_LOCK_ HTTPServer", true),
+            pairs(&[
+                ("env_lock", 0),
+                ("env", 0),
+                ("lock", 1),
+                ("httpserver", 2),
+                ("http", 2),
+                ("server", 3),
+            ])
+        );
+        assert_eq!(code_toks("v2", true), pairs(&[("v2", 0)]));
+    }
+
+    #[test]
+    fn cjk_bigrams_can_be_turned_off() {
+        assert_eq!(
+            code_toks("權威事實 task", false),
+            vec![("權威事實".to_string(), 0), ("task".to_string(), 1)]
+        );
+    }
+
     #[test]
     fn byte_offsets_are_valid_utf8_boundaries() {
         let text = "洗成權威";
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use edda_bridge_claude::code_annotations::{self, CodeAnnotation, HoverEntry};
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+// ── GET /api/code-annotations ──
+
+#[derive(Deserialize)]
+struct AnnotationsQuery {
+    path: Option<String>,
+    line: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct AnnotationsResponse {
+    branch: String,
+    /// When the scanned locations were exported; `None` if scanned on demand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generated_at: Option<String>,
+    entries: Vec<HoverEntry>,
+}
+
+/// Hover data for editor extensions.
+///
+/// Locations come from the `edda annotate-code` export (or an on-demand scan
+/// when there is none); decision values are always resolved against the
+/// current ledger so a hover never shows a superseded decision.
+async fn get_code_annotations(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<AnnotationsQuery>,
+) -> Result<Json<AnnotationsResponse>, AppError> {
+    if params.line.is_some() && params.path.is_none() {
+        return Err(AppError::Validation("line requires path".to_string()));
+    }
+    if params.line == Some(0) {
+        return Err(AppError::Validation("line is 1-based".to_string()));
+    }
+
+    let ledger = state.open_ledger()?;
+    let branch = ledger.head_branch()?;
+    let (generated_at, annotations) = match code_annotations::read_export(&ledger.paths.edda_dir)? {
+        Some(export) => (
+            Some(export.generated_at),
+            export
+                .entries
+                .iter()
+                .map(HoverEntry::annotation)
+                .collect::<Vec<CodeAnnotation>>(),
+        ),
+        None => (None, code_annotations::scan_repo(&state.repo_root)?),
+    };
+
+    let mut entries = code_annotations::resolve(&ledger, &branch, &annotations)?;
+    if let Some(path) = params.path.as_deref() {
+        entries = match params.line {
+            Some(line) => code_annotations::lookup(&entries, path, line)
+                .into_iter()
+                .cloned()
+                .collect(),
+            None => {
+                let path = code_annotations::normalize_path(path);
+                entries.into_iter().filter(|e| e.path == path).collect()
+            }
+        };
+    }
+
+    Ok(Json(AnnotationsResponse {
+        branch,
+        generated_at,
+        entries,
+    }))
+}
+
+/// Code annotation routes.
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new().route("/api/code-annotations", get(get_code_annotations))
+}
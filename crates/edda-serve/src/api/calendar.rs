@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use time::format_description::well_known::Rfc3339;
+use time::{Date, Month, OffsetDateTime};
+
+use edda_conductor::check::approval::GATE_LABEL;
+use edda_conductor::plan::parser::load_plan;
+use edda_conductor::plan::schema::CheckSpec;
+use edda_conductor::state::machine::PhaseStatus;
+use edda_conductor::state::persist;
+use edda_ledger::{DraftStatus, Ledger};
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+// ── GET /api/calendar.ics ──
+
+/// When a calendar entry happens.
+enum When {
+    /// An all-day entry, for dates without a time (`review_after`).
+    Day(Date),
+    At(OffsetDateTime),
+}
+
+struct CalendarEntry {
+    uid: String,
+    when: When,
+    summary: String,
+    description: String,
+}
+
+/// iCalendar feed of review-after dates and plan gate deadlines, for
+/// subscribing from a calendar app.
+async fn get_calendar(State(state): State<Arc<AppState>>) -> Result<Response, AppError> {
+    let ledger = state.open_ledger()?;
+    let mut entries = review_entries(&ledger)?;
+    entries.extend(gate_entries(&ledger, &state.repo_root)?);
+    let name = state
+        .repo_root
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "workspace".to_string());
+    let body = render_calendar(&name, &entries, OffsetDateTime::now_utc());
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        body,
+    )
+        .into_response())
+}
+
+/// One all-day entry per active decision with a `review_after` date.
+fn review_entries(ledger: &Ledger) -> anyhow::Result<Vec<CalendarEntry>> {
+    let mut entries = Vec::new();
+    for d in ledger.active_decisions(None, None, None, None)? {
+        let Some(date) = d.review_after.as_deref().and_then(parse_date) else {
+            continue;
+        };
+        let mut description = format!("{} = {}", d.key, d.value);
+        if !d.reason.is_empty() {
+            description.push_str(&format!("\nReason: {}", d.reason));
+        }
+        description.push_str(&format!("\nDecided on {} ({})", d.branch, d.event_id));
+        entries.push(CalendarEntry {
+            uid: format!("review-{}@edda", d.event_id),
+            when: When::Day(date),
+            summary: format!("Review decision: {}", d.key),
+            description,
+        });
+    }
+    Ok(entries)
+}
+
+/// One entry per open conductor gate draft, at the time the waiting plan
+/// stops waiting for it: the draft's creation plus the approval check's
+/// `timeout_sec`. Gates of plans that are not waiting on them are left out.
+fn gate_entries(ledger: &Ledger, repo_root: &Path) -> anyhow::Result<Vec<CalendarEntry>> {
+    // Approval title -> (timeout, "plan/phase") for every phase now running.
+    let mut waiting: HashMap<String, (u64, String)> = HashMap::new();
+    for name in persist::list_plans(repo_root)? {
+        let Ok(Some(state)) = persist::load_state(repo_root, &name) else {
+            continue;
+        };
+        let plan_file = repo_root.join(&state.plan_file);
+        let Ok(plan) = load_plan(&plan_file) else {
+            continue;
+        };
+        for phase in &plan.phases {
+            let running = state.phases.iter().any(|p| {
+                p.id == phase.id && matches!(p.status, PhaseStatus::Running | PhaseStatus::Checking)
+            });
+            if !running {
+                continue;
+            }
+            for check in &phase.check {
+                if let CheckSpec::Approval {
+                    title, timeout_sec, ..
+                } = check
+                {
+                    waiting.insert(
+                        title.clone(),
+                        (*timeout_sec, format!("{}/{}", plan.name, phase.id)),
+                    );
+                }
+            }
+        }
+    }
+    if waiting.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for draft in ledger.draft_views()? {
+        if draft.status != DraftStatus::Proposed || !draft.labels.iter().any(|l| l == GATE_LABEL) {
+            continue;
+        }
+        let Some((timeout_sec, phase)) = waiting.get(&draft.title) else {
+            continue;
+        };
+        let Ok(created) = OffsetDateTime::parse(&draft.created_at, &Rfc3339) else {
+            continue;
+        };
+        let timeout = time::Duration::seconds(i64::try_from(*timeout_sec).unwrap_or(i64::MAX));
+        let Some(deadline) = created.checked_add(timeout) else {
+            continue;
+        };
+        entries.push(CalendarEntry {
+            uid: format!("gate-{}@edda", draft.draft_id),
+            when: When::At(deadline),
+            summary: format!("Plan gate expires: {}", draft.title),
+            description: format!(
+                "Approval gate of {phase}. The plan stops waiting at this time.\nedda draft approve {}",
+                draft.draft_id
+            ),
+        });
+    }
+    Ok(entries)
+}
+
+/// The date part of a `review_after` value (`2026-06-01` or a full timestamp).
+fn parse_date(s: &str) -> Option<Date> {
+    let mut parts = s.get(..10)?.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = Month::try_from(parts.next()?.parse::<u8>().ok()?).ok()?;
+    let day = parts.next()?.parse().ok()?;
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+fn render_calendar(name: &str, entries: &[CalendarEntry], now: OffsetDateTime) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//edda//calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape(&format!("edda: {name}"))),
+    ];
+    for entry in entries {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", entry.uid));
+        lines.push(format!("DTSTAMP:{}", utc_stamp(now)));
+        match entry.when {
+            When::Day(date) => lines.push(format!("DTSTART;VALUE=DATE:{}", day_stamp(date))),
+            When::At(at) => lines.push(format!("DTSTART:{}", utc_stamp(at))),
+        }
+        lines.push(format!("SUMMARY:{}", escape(&entry.summary)));
+        lines.push(format!("DESCRIPTION:{}", escape(&entry.description)));
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in &lines {
+        fold_into(&mut out, line);
+    }
+    out
+}
+
+fn day_stamp(date: Date) -> String {
+    format!(
+        "{:04}{:02}{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+fn utc_stamp(at: OffsetDateTime) -> String {
+    let at = at.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{}T{:02}{:02}{:02}Z",
+        day_stamp(at.date()),
+        at.hour(),
+        at.minute(),
+        at.second()
+    )
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11).
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Append a content line, folded at 75 octets without splitting a UTF-8
+/// character, with CRLF endings.
+fn fold_into(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Calendar feed route.
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new().route("/api/calendar.ics", get(get_calendar))
+}
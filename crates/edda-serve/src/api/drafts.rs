@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use edda_core::agent_phase::{mobile_context_summary, AgentPhaseState};
 use edda_core::event::{new_approval_event, ApprovalEventParams};
 use edda_ledger::lock::WorkspaceLock;
-use edda_ledger::Ledger;
+use edda_ledger::{import_legacy_drafts, DraftActionError, DraftStatus, Ledger};
 
 use crate::error::AppError;
 use crate::state::AppState;
@@ -48,45 +48,9 @@ struct DraftsResponse {
     drafts: Vec<DraftItem>,
 }
 
-#[derive(Deserialize)]
-struct MinimalDraft {
-    #[serde(default)]
-    draft_id: String,
-    #[serde(default)]
-    title: String,
-    #[serde(default)]
-    status: String,
-    #[serde(default)]
-    stages: Vec<MinimalStage>,
-    #[serde(default)]
-    labels: Vec<String>,
-    #[serde(default)]
-    created_at: Option<String>,
-    #[serde(default)]
-    branch: String,
-}
-
-#[derive(Deserialize)]
-struct MinimalStage {
-    #[serde(default)]
-    stage_id: String,
-    #[serde(default)]
-    role: String,
-    #[serde(default)]
-    min_approvals: usize,
-    #[serde(default)]
-    approved_by: Vec<String>,
-    #[serde(default)]
-    status: String,
-}
-
 async fn get_drafts(State(state): State<Arc<AppState>>) -> Result<Json<DraftsResponse>, AppError> {
     let ledger = state.open_ledger()?;
-    let drafts_dir = &ledger.paths.drafts_dir;
-
-    if !drafts_dir.exists() {
-        return Ok(Json(DraftsResponse { drafts: vec![] }));
-    }
+    let drafts = ledger.draft_views()?;
 
     // Load agent phase states for context enrichment
     let phase_states = load_agent_phase_states(&state.repo_root);
@@ -97,24 +61,8 @@ async fn get_drafts(State(state): State<Arc<AppState>>) -> Result<Json<DraftsRes
     let recent_commits = recent_commit_summaries(&ledger, &head, 3);
 
     let mut items = Vec::new();
-    for entry in std::fs::read_dir(drafts_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.extension().and_then(|e| e.to_str()) != Some("json") {
-            continue;
-        }
-        if path.file_stem().and_then(|s| s.to_str()) == Some("latest") {
-            continue;
-        }
-
-        let content = std::fs::read_to_string(&path)?;
-        let draft: MinimalDraft = match serde_json::from_str(&content) {
-            Ok(d) => d,
-            Err(_) => continue,
-        };
-
-        if draft.status == "applied" {
+    for draft in drafts {
+        if draft.status == DraftStatus::Applied {
             continue;
         }
 
@@ -149,10 +97,7 @@ async fn get_drafts(State(state): State<Arc<AppState>>) -> Result<Json<DraftsRes
                 }
             });
 
-        for stage in &draft.stages {
-            if stage.status != "pending" {
-                continue;
-            }
+        for stage in draft.pending_stages() {
             items.push(DraftItem {
                 draft_id: draft.draft_id.clone(),
                 title: draft.title.clone(),
@@ -165,7 +110,7 @@ async fn get_drafts(State(state): State<Arc<AppState>>) -> Result<Json<DraftsRes
                 agent: agent.clone(),
                 issue,
                 context_summary: context_summary.clone(),
-                requested_at: draft.created_at.clone(),
+                requested_at: Some(draft.created_at.clone()),
                 labels: draft.labels.clone(),
             });
         }
@@ -282,24 +227,11 @@ async fn handle_draft_action(
     let ledger = state.open_ledger()?;
     let _lock = WorkspaceLock::acquire(&ledger.paths)?;
 
-    // Read the draft
-    let draft_path = ledger.paths.drafts_dir.join(format!("{draft_id}.json"));
-    if !draft_path.exists() {
-        return Err(AppError::NotFound(format!("draft not found: {draft_id}")));
-    }
-    let content = std::fs::read_to_string(&draft_path)?;
-    let mut draft: serde_json::Value = serde_json::from_str(&content)?;
-
-    // Check draft status
-    let draft_status = draft
-        .get("status")
-        .and_then(|v| v.as_str())
-        .unwrap_or("proposed");
-    if draft_status == "applied" || draft_status == "rejected" {
-        return Err(AppError::Conflict(format!(
-            "draft {draft_id} is already {draft_status}"
-        )));
-    }
+    import_legacy_drafts(&ledger)?;
+
+    let draft = ledger
+        .draft_view(draft_id)?
+        .ok_or_else(|| AppError::NotFound(format!("draft not found: {draft_id}")))?;
 
     let actor = body.actor.as_deref().unwrap_or("human");
     let reason = body.reason.as_deref().unwrap_or("");
@@ -313,83 +245,24 @@ async fn handle_draft_action(
         "reject"
     };
 
-    let head = ledger.head_branch()?;
-
-    // Compute draft SHA256
-    let draft_sha256 = {
-        use sha2::Digest;
-        let bytes = std::fs::read(&draft_path)?;
-        let mut hasher = sha2::Sha256::new();
-        hasher.update(&bytes);
-        hex::encode(hasher.finalize())
-    };
-
-    let parent_hash = ledger.last_event_hash()?;
-
-    // Handle stage-aware drafts
-    let stages = draft
-        .get("stages")
-        .and_then(|v| v.as_array())
-        .cloned()
-        .unwrap_or_default();
-
-    let (stage_id, stage_role, stage_status) = if !stages.is_empty() {
-        // Determine which stage to act on
-        let requested_stage = body.stage.as_deref();
-        let target_stage = if let Some(sid) = requested_stage {
-            stages
-                .iter()
-                .find(|s| s.get("stage_id").and_then(|v| v.as_str()) == Some(sid))
-                .ok_or_else(|| AppError::NotFound(format!("stage not found: {sid}")))?
-        } else {
-            // Auto-select the first pending stage
-            stages
-                .iter()
-                .find(|s| s.get("status").and_then(|v| v.as_str()) == Some("pending"))
-                .ok_or_else(|| AppError::Conflict("no pending stages remain".to_string()))?
-        };
-
-        let sid = target_stage
-            .get("stage_id")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-        let role = target_stage
-            .get("role")
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-        let st_status = target_stage
-            .get("status")
-            .and_then(|v| v.as_str())
-            .unwrap_or("pending")
-            .to_string();
-
-        if st_status != "pending" {
-            return Err(AppError::Conflict(format!(
-                "stage '{sid}' is already {st_status}"
-            )));
+    // Replay protection: a closed draft or an already-decided stage is a
+    // conflict; a stage that does not exist is not found.
+    let (stage_id, stage_role) = match draft.target_stage(body.stage.as_deref(), None) {
+        Ok(Some(stage)) => (stage.stage_id.clone(), stage.role.clone()),
+        Ok(None) => (String::new(), String::new()),
+        Err(e @ DraftActionError::StageNotFound(_)) => {
+            return Err(AppError::NotFound(e.to_string()))
         }
-
-        (sid, role, st_status)
-    } else {
-        (String::new(), String::new(), "pending".to_string())
+        Err(e) => return Err(AppError::Conflict(e.to_string())),
     };
 
-    // Replay protection: stage already acted on
-    if stage_status != "pending" {
-        return Err(AppError::Conflict(format!(
-            "draft {draft_id} stage '{}' is already {stage_status}",
-            stage_id
-        )));
-    }
-
-    // Create approval event
+    let head = ledger.head_branch()?;
+    let parent_hash = ledger.last_event_hash()?;
     let event = new_approval_event(&ApprovalEventParams {
         branch: &head,
         parent_hash: parent_hash.as_deref(),
         draft_id,
-        draft_sha256: &draft_sha256,
+        draft_sha256: &draft.proposal_hash,
         decision,
         actor,
         note: reason,
@@ -399,113 +272,21 @@ async fn handle_draft_action(
     })?;
     ledger.append_event(&event)?;
 
-    // Update draft JSON
-    let ts = event.ts.clone();
-    let approval_record = serde_json::json!({
-        "ts": ts,
-        "actor": actor,
-        "decision": decision,
-        "note": reason,
-        "approval_event_id": event.event_id,
-        "stage_id": stage_id,
-        "role": stage_role,
-    });
-
-    // Append to approvals array
-    if let Some(approvals) = draft.get_mut("approvals") {
-        if let Some(arr) = approvals.as_array_mut() {
-            arr.push(approval_record);
-        }
-    } else {
-        draft["approvals"] = serde_json::json!([approval_record]);
-    }
-
-    // Update stage status
-    let mut new_stage_status = "pending".to_string();
-    if let Some(stages_arr) = draft.get_mut("stages").and_then(|v| v.as_array_mut()) {
-        for stage in stages_arr.iter_mut() {
-            let sid = stage.get("stage_id").and_then(|v| v.as_str()).unwrap_or("");
-            if sid == stage_id {
-                if decision == "reject" {
-                    stage["status"] = serde_json::Value::String("rejected".to_string());
-                    new_stage_status = "rejected".to_string();
-                } else {
-                    // Read min_approvals first to avoid borrow conflict
-                    let min = stage
-                        .get("min_approvals")
-                        .and_then(|v| v.as_u64())
-                        .unwrap_or(1) as usize;
-                    // Add actor to approved_by
-                    if let Some(ab) = stage.get_mut("approved_by") {
-                        if let Some(arr) = ab.as_array_mut() {
-                            let actor_val = serde_json::Value::String(actor.to_string());
-                            if !arr.contains(&actor_val) {
-                                arr.push(actor_val);
-                            }
-                            if arr.len() >= min {
-                                new_stage_status = "approved".to_string();
-                            }
-                        }
-                    }
-                    if new_stage_status == "approved" {
-                        stage["status"] = serde_json::Value::String("approved".to_string());
-                    }
-                }
-                break;
-            }
-        }
-
-        // Update draft-level status
-        let all_approved = stages_arr
-            .iter()
-            .all(|s| s.get("status").and_then(|v| v.as_str()) == Some("approved"));
-        let any_rejected = stages_arr
-            .iter()
-            .any(|s| s.get("status").and_then(|v| v.as_str()) == Some("rejected"));
-
-        if any_rejected {
-            draft["status"] = serde_json::Value::String("rejected".to_string());
-        } else if all_approved {
-            draft["status"] = serde_json::Value::String("approved".to_string());
-        }
-    } else {
-        // Flat (no stages) draft
-        if decision == "reject" {
-            draft["status"] = serde_json::Value::String("rejected".to_string());
-            new_stage_status = "rejected".to_string();
-        } else {
-            let min = draft
-                .get("policy_min_approvals")
-                .and_then(|v| v.as_u64())
-                .unwrap_or(1) as usize;
-            let count = draft
-                .get("approvals")
-                .and_then(|v| v.as_array())
-                .map(|a| {
-                    a.iter()
-                        .filter(|r| r.get("decision").and_then(|v| v.as_str()) == Some("approve"))
-                        .count()
-                })
-                .unwrap_or(0);
-            if count >= min.max(1) {
-                draft["status"] = serde_json::Value::String("approved".to_string());
-                new_stage_status = "approved".to_string();
-            }
-        }
-    }
-
-    let final_draft_status = draft
-        .get("status")
-        .and_then(|v| v.as_str())
-        .unwrap_or("proposed")
-        .to_string();
-
-    // Write updated draft
-    std::fs::write(&draft_path, serde_json::to_string_pretty(&draft)?)?;
+    let draft = ledger
+        .draft_view(draft_id)?
+        .ok_or_else(|| AppError::NotFound(format!("draft not found: {draft_id}")))?;
+    let new_stage_status = match draft.stages.iter().find(|s| s.stage_id == stage_id) {
+        Some(stage) => stage.status.to_string(),
+        // Flat drafts report the draft's own decision state.
+        None => match draft.status {
+            DraftStatus::Proposed => "pending".to_string(),
+            other => other.to_string(),
+        },
+    };
+    let final_draft_status = draft.status.to_string();
 
     // Rebuild derived state
-    let snap_branch = ledger.head_branch()?;
-    let _ = edda_derive::rebuild_branch(&ledger, &snap_branch);
+    let _ = edda_derive::rebuild_branch(&ledger, &head);
 
     let resp = ApprovalResponse {
         event_id: event.event_id,
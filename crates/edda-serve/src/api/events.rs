@@ -1,27 +1,26 @@
 use std::sync::Arc;
 
 use axum::extract::rejection::JsonRejection;
-use axum::extract::{Path as AxumPath, Query, State};
-use axum::http::StatusCode;
+use axum::extract::{Path as AxumPath, Query, RawQuery, State};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
-use axum::{Json, Router};
+use axum::{Extension, Json, Router};
 use serde::{Deserialize, Serialize};
 
-use edda_core::event::{finalize_event, new_decision_event, new_execution_event, new_note_event};
-use edda_core::types::{rel, DecisionPayload, Provenance};
+use edda_core::event::{
+    finalize_event, new_decision_event, new_execution_event, new_note_event_with_body,
+};
+use edda_core::policy;
+use edda_core::types::{rel, DecisionPayload, Event, Provenance};
 use edda_derive::{rebuild_branch, render_context, DeriveOptions};
 use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::{EventFilter, Ledger};
 
 use crate::error::AppError;
+use crate::middleware::ApiCaller;
 use crate::state::AppState;
 
-// ── Health ──
-
-async fn health() -> Json<serde_json::Value> {
-    Json(serde_json::json!({ "ok": true }))
-}
-
 // ── GET /api/status ──
 
 #[derive(Serialize)]
@@ -96,20 +95,51 @@ struct DecisionsQuery {
     tags: Option<String>,
     /// Filter decisions belonging to a specific village.
     village_id: Option<String>,
+    /// Filter decisions by scope (session, branch, project, shared, org).
+    scope: Option<String>,
+    /// `next_cursor` of the previous page.
+    cursor: Option<String>,
+}
+
+/// Decisions are paged; the other sections come with the first page only.
+#[derive(Serialize)]
+struct DecisionsResponse {
+    #[serde(flatten)]
+    result: edda_ask::AskResult,
+    /// Matching decisions across all pages.
+    total: usize,
+    /// Pass as `cursor` to fetch the next page; `null` on the last page.
+    next_cursor: Option<String>,
 }
 
+/// `limit` for the ask pass behind a paged response: every matching
+/// decision, so the page can be cut from the full list and counted.
+const ASK_UNBOUNDED: usize = i64::MAX as usize;
+
 async fn get_decisions(
     State(state): State<Arc<AppState>>,
     Query(params): Query<DecisionsQuery>,
-) -> Result<Json<edda_ask::AskResult>, AppError> {
+    RawQuery(raw_query): RawQuery,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     if let Some(ref after) = params.after {
         crate::helpers::validate_iso8601(after).map_err(AppError::Validation)?;
     }
     if let Some(ref before) = params.before {
         crate::helpers::validate_iso8601(before).map_err(AppError::Validation)?;
     }
+    let offset = match params.cursor.as_deref() {
+        Some(c) => c
+            .parse::<usize>()
+            .map_err(|_| AppError::Validation(format!("invalid cursor: {c}")))?,
+        None => 0,
+    };
 
     let ledger = state.open_ledger()?;
+    let etag = crate::helpers::ledger_etag(&ledger, raw_query.as_deref().unwrap_or(""))?;
+    if crate::helpers::etag_matches(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
     let q = params
         .q
         .as_deref()
@@ -121,18 +151,60 @@ async fn get_decisions(
         .filter(|s| !s.is_empty())
         .map(|s| s.split(',').map(|t| t.trim().to_string()).collect())
         .unwrap_or_default();
+    let scope = params
+        .scope
+        .as_deref()
+        .map(str::parse::<edda_core::types::DecisionScope>)
+        .transpose()
+        .map_err(AppError::Validation)?;
+    let limit = params.limit.unwrap_or(20);
     let opts = edda_ask::AskOptions {
-        limit: params.limit.unwrap_or(20),
+        limit: ASK_UNBOUNDED,
         include_superseded: params.all.unwrap_or(false),
         branch: params.branch,
         impact: false,
         after: params.after,
         before: params.before,
+        as_of: None,
         tags,
         village_id: params.village_id,
+        scope,
+        mode: edda_ask::AskMode::Standard,
+        snippet_chars: None,
+        sentence_boundary: false,
+        max_response_chars: None,
+        semantic: true,
+        top: 0,
     };
-    let result = edda_ask::ask(&ledger, q, &opts, None)?;
-    Ok(Json(result))
+    let mut result = edda_ask::ask(&ledger, q, &opts, None)?;
+
+    let total = result.decisions.len();
+    let end = offset.saturating_add(limit).min(total);
+    result.decisions = result.decisions.drain(offset.min(end)..end).collect();
+    let next_cursor = (end < total).then(|| end.to_string());
+    if offset == 0 {
+        result.timeline.truncate(limit);
+        result.related_commits.truncate(limit);
+        result.related_notes.truncate(limit);
+        result.conversations.truncate(limit);
+    } else {
+        result.timeline.clear();
+        result.related_commits.clear();
+        result.related_notes.clear();
+        result.conversations.clear();
+    }
+
+    let body = DecisionsResponse {
+        result,
+        total,
+        next_cursor,
+    };
+    Ok(([(header::ETAG, etag)], Json(body)).into_response())
+}
+
+/// `304 Not Modified` for a conditional GET whose tag still matches.
+fn not_modified(etag: &str) -> Response {
+    (StatusCode::NOT_MODIFIED, [(header::ETAG, etag.to_string())]).into_response()
 }
 
 // ── POST /api/decisions/batch ──
@@ -212,8 +284,16 @@ async fn post_decisions_batch(
             impact: false,
             after: None,
             before: None,
+            as_of: None,
             tags: vec![],
             village_id: None,
+            scope: None,
+            mode: edda_ask::AskMode::Standard,
+            snippet_chars: None,
+            sentence_boundary: false,
+            max_response_chars: None,
+            semantic: true,
+            top: edda_ask::DEFAULT_TOP,
         };
 
         match edda_ask::ask(&ledger, q, &opts, None) {
@@ -257,6 +337,165 @@ async fn post_decisions_batch(
     Ok(Json(BatchResponse { results }))
 }
 
+// ── POST /api/ask ──
+
+/// The full `edda ask` option set. Defaults match the CLI.
+#[derive(Deserialize)]
+struct AskBody {
+    #[serde(default)]
+    q: String,
+    limit: Option<usize>,
+    #[serde(default)]
+    include_superseded: bool,
+    branch: Option<String>,
+    #[serde(default)]
+    impact: bool,
+    after: Option<String>,
+    before: Option<String>,
+    /// Answer as of a date or timestamp: the decisions active then.
+    as_of: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    village_id: Option<String>,
+    scope: Option<String>,
+    /// `standard` (default), `why`, or `history`.
+    mode: Option<String>,
+    snippet_chars: Option<usize>,
+    #[serde(default)]
+    sentence_boundary: bool,
+    max_response_chars: Option<usize>,
+    #[serde(default = "default_true")]
+    semantic: bool,
+    /// Size of the cross-section `top` ranking (default 10; 0 leaves it out).
+    top: Option<usize>,
+    /// Search session transcripts through the project's Tantivy index.
+    #[serde(default = "default_true")]
+    transcripts: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+async fn post_ask(
+    State(state): State<Arc<AppState>>,
+    body: Result<Json<AskBody>, JsonRejection>,
+) -> Result<Json<edda_ask::AskResult>, AppError> {
+    let Json(body) = body.map_err(|e| AppError::Validation(e.body_text()))?;
+    if let Some(ref after) = body.after {
+        crate::helpers::validate_iso8601(after).map_err(AppError::Validation)?;
+    }
+    if let Some(ref before) = body.before {
+        crate::helpers::validate_iso8601(before).map_err(AppError::Validation)?;
+    }
+    if let Some(ref as_of) = body.as_of {
+        edda_ask::normalize_as_of(as_of).map_err(|e| AppError::Validation(e.to_string()))?;
+    }
+    let scope = body
+        .scope
+        .as_deref()
+        .map(str::parse::<edda_core::types::DecisionScope>)
+        .transpose()
+        .map_err(AppError::Validation)?;
+    let mode = match body.mode.as_deref() {
+        None | Some("standard") => edda_ask::AskMode::Standard,
+        Some("why") => edda_ask::AskMode::Why,
+        Some("history") => edda_ask::AskMode::History,
+        Some(other) => {
+            return Err(AppError::Validation(format!(
+                "mode must be 'standard', 'why' or 'history', got '{other}'"
+            )))
+        }
+    };
+
+    let opts = edda_ask::AskOptions {
+        limit: body.limit.unwrap_or(20).min(100),
+        include_superseded: body.include_superseded,
+        branch: body.branch,
+        impact: body.impact,
+        after: body.after,
+        before: body.before,
+        as_of: body.as_of,
+        tags: body.tags,
+        village_id: body.village_id,
+        scope,
+        mode,
+        snippet_chars: body.snippet_chars,
+        sentence_boundary: body.sentence_boundary,
+        max_response_chars: body.max_response_chars,
+        semantic: body.semantic,
+        top: body.top.unwrap_or(edda_ask::DEFAULT_TOP),
+    };
+
+    let ledger = state.open_ledger()?;
+    let transcript_cb = if body.transcripts {
+        transcript_search(&state.repo_root)
+    } else {
+        None
+    };
+    let mut result = match edda_ask::ask(&ledger, &body.q, &opts, transcript_cb.as_deref()) {
+        Ok(result) => result,
+        // Why mode rejects anything but an exact decision key; history mode
+        // rejects an empty query.
+        Err(e) if mode != edda_ask::AskMode::Standard => {
+            return Err(AppError::Validation(e.to_string()))
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let decisions_paths = edda_ask::affected_paths_for_hits(&ledger, &result.decisions);
+    edda_ask::staleness::annotate_hits(
+        &mut result.decisions,
+        &decisions_paths,
+        Some(&state.repo_root),
+    );
+    let timeline_paths = edda_ask::affected_paths_for_hits(&ledger, &result.timeline);
+    edda_ask::staleness::annotate_hits(
+        &mut result.timeline,
+        &timeline_paths,
+        Some(&state.repo_root),
+    );
+    Ok(Json(result))
+}
+
+/// Transcript search over the project's Tantivy index, as `edda ask` wires
+/// it. `None` when there is no index, or when it was built with an older
+/// schema and would return wrong results.
+fn transcript_search(repo_root: &std::path::Path) -> Option<Box<edda_ask::TranscriptSearchFn>> {
+    let project_id = edda_store::project_id(repo_root);
+    let index_dir = edda_store::project_subdir(&project_id, "search").join("tantivy");
+    if !index_dir.exists() {
+        return None;
+    }
+    if edda_search_fts::schema::index_is_outdated(&index_dir) {
+        tracing::warn!(
+            project_id,
+            "search index is out of date; skipping transcript search (run `edda search index`)"
+        );
+        return None;
+    }
+    let index = edda_search_fts::schema::open_index(&index_dir)?;
+
+    Some(Box::new(move |query: &str, limit: usize| {
+        let opts = edda_search_fts::search::SearchOptions {
+            project_id: Some(&project_id),
+            doc_type: Some("turn"),
+            ..Default::default()
+        };
+        edda_search_fts::search::search(&index, query, &opts, limit)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|r| edda_ask::ConversationHit {
+                doc_id: r.doc_id,
+                session_id: r.session_id,
+                ts: r.ts,
+                snippet: r.snippet,
+                rank: r.rank,
+            })
+            .collect()
+    }))
+}
+
 // ── GET /api/decisions/:event_id/outcomes ──
 
 async fn get_decision_outcomes(
@@ -369,6 +608,8 @@ struct LogQuery {
     after: Option<String>,
     before: Option<String>,
     limit: Option<usize>,
+    /// `next_cursor` of the previous page: the event_id of its last event.
+    cursor: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -381,29 +622,55 @@ struct LogEntry {
     #[serde(rename = "summary")]
     detail: String,
     tags: Vec<String>,
+    /// Structured note body rendered as Markdown, when present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body_markdown: Option<String>,
 }
 
 #[derive(Serialize)]
 struct LogResponse {
     events: Vec<LogEntry>,
+    /// Matching events across all pages.
+    total: usize,
+    /// Pass as `cursor` to fetch the next page; `null` on the last page.
+    next_cursor: Option<String>,
 }
 
 async fn get_log(
     State(state): State<Arc<AppState>>,
     Query(params): Query<LogQuery>,
-) -> Result<Json<LogResponse>, AppError> {
+    RawQuery(raw_query): RawQuery,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     let ledger = state.open_ledger()?;
+    let etag = crate::helpers::ledger_etag(&ledger, raw_query.as_deref().unwrap_or(""))?;
+    if crate::helpers::etag_matches(&headers, &etag) {
+        return Ok(not_modified(&etag));
+    }
     let head = ledger.head_branch()?;
     let limit = params.limit.unwrap_or(50);
+    let filter = EventFilter {
+        branch: &head,
+        event_type: params.r#type.as_deref(),
+        keyword: params.keyword.as_deref(),
+        after: params.after.as_deref(),
+        before: params.before.as_deref(),
+    };
+    if let Some(cursor) = params.cursor.as_deref() {
+        if ledger.get_event(cursor)?.is_none() {
+            return Err(AppError::Validation(format!("unknown cursor: {cursor}")));
+        }
+    }
 
-    let events = ledger.iter_events_filtered(
-        &head,
-        params.r#type.as_deref(),
-        params.keyword.as_deref(),
-        params.after.as_deref(),
-        params.before.as_deref(),
-        limit,
-    )?;
+    let total = ledger.count_events_filtered(&filter)?;
+    let mut events =
+        ledger.page_events_filtered(&filter, params.cursor.as_deref(), limit.saturating_add(1))?;
+    let next_cursor = if events.len() > limit {
+        events.truncate(limit);
+        events.last().map(|e| e.event_id.clone())
+    } else {
+        None
+    };
 
     let results: Vec<LogEntry> = events
         .iter()
@@ -432,11 +699,18 @@ async fn get_log(
                 branch: e.branch.clone(),
                 detail,
                 tags,
+                body_markdown: edda_core::NoteBody::from_payload(&e.payload)
+                    .map(|b| b.render_markdown()),
             }
         })
         .collect();
 
-    Ok(Json(LogResponse { events: results }))
+    let body = LogResponse {
+        events: results,
+        total,
+        next_cursor,
+    };
+    Ok(([(header::ETAG, etag)], Json(body)).into_response())
 }
 // ── POST /api/note ──
 
@@ -445,6 +719,8 @@ struct NoteBody {
     text: String,
     role: Option<String>,
     tags: Option<Vec<String>>,
+    /// Optional structured body (code snippet, links, or key/value table).
+    body: Option<edda_core::NoteBody>,
 }
 
 #[derive(Serialize)]
@@ -454,11 +730,13 @@ struct EventResponse {
 
 async fn post_note(
     State(state): State<Arc<AppState>>,
+    caller: Option<Extension<ApiCaller>>,
     body: Result<Json<NoteBody>, JsonRejection>,
 ) -> Result<impl IntoResponse, AppError> {
     let Json(body) = body.map_err(|e| AppError::Validation(e.body_text()))?;
 
-    let ledger = state.open_ledger()?;
+    let mut ledger = state.open_ledger()?;
+    ledger.set_actor(caller.map(|Extension(c)| c.0).as_deref());
     let _lock = WorkspaceLock::acquire(&ledger.paths)?;
 
     let branch = ledger.head_branch()?;
@@ -466,8 +744,15 @@ async fn post_note(
     let role = body.role.as_deref().unwrap_or("user");
     let tags = body.tags.unwrap_or_default();
 
-    let event = new_note_event(&branch, parent_hash.as_deref(), role, &body.text, &tags)?;
-    ledger.append_event(&event)?;
+    let event = new_note_event_with_body(
+        &branch,
+        parent_hash.as_deref(),
+        role,
+        &body.text,
+        &tags,
+        body.body.as_ref(),
+    )?;
+    ledger.append_event_rebasing(&event)?;
 
     Ok((
         StatusCode::CREATED,
@@ -483,6 +768,10 @@ async fn post_note(
 struct DecideBody {
     decision: String,
     reason: Option<String>,
+    /// Self-asserted actor for branch protection overrides; ignored when the
+    /// request authenticated with an API token (the token name is used).
+    #[serde(default)]
+    actor: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -494,28 +783,67 @@ struct DecideResponse {
 
 async fn post_decide(
     State(state): State<Arc<AppState>>,
+    caller: Option<Extension<ApiCaller>>,
     body: Result<Json<DecideBody>, JsonRejection>,
 ) -> Result<impl IntoResponse, AppError> {
     let Json(body) = body.map_err(|e| AppError::Validation(e.body_text()))?;
 
-    let (key, value) = body.decision.split_once('=').ok_or_else(|| {
+    let (key, value) = parse_decision(&body.decision)?;
+
+    let actor = caller.map(|Extension(c)| c.0).or(body.actor);
+    let mut ledger = state.open_ledger()?;
+    ledger.set_actor(actor.as_deref());
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let branch = ledger.head_branch()?;
+    if let Some(denial) = policy::check_branch_protection(
+        &ledger.paths.edda_dir,
+        &branch,
+        policy::ACTION_DECIDE,
+        actor.as_deref(),
+    )? {
+        return Err(AppError::Forbidden(denial));
+    }
+    let (event, superseded) = build_decision_event(&ledger, &branch, key, value, body.reason)?;
+    ledger.append_event_rebasing(&event)?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(DecideResponse {
+            event_id: event.event_id,
+            superseded,
+        }),
+    ))
+}
+
+/// Split a `key=value` decision string into trimmed parts.
+pub(crate) fn parse_decision(decision: &str) -> Result<(&str, &str), AppError> {
+    let (key, value) = decision.split_once('=').ok_or_else(|| {
         AppError::Validation(
             "decision must be in key=value format (e.g. \"db.engine=postgres\")".into(),
         )
     })?;
-    let key = key.trim();
-    let value = value.trim();
-
-    let ledger = state.open_ledger()?;
-    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+    Ok((key.trim(), value.trim()))
+}
 
-    let branch = ledger.head_branch()?;
+/// Build a decision event chained to the current ledger tip, superseding the
+/// active decision for `key` on `branch` when its value differs.
+///
+/// Returns the finalized event and the superseded event id, if any. The caller
+/// must hold the workspace lock and append the event.
+pub(crate) fn build_decision_event(
+    ledger: &Ledger,
+    branch: &str,
+    key: &str,
+    value: &str,
+    reason: Option<String>,
+) -> anyhow::Result<(Event, Option<String>)> {
     let parent_hash = ledger.last_event_hash()?;
 
     let dp = DecisionPayload {
         key: key.to_string(),
         value: value.to_string(),
-        reason: body.reason,
+        reason,
         scope: None,
         authority: None,
         affected_paths: None,
@@ -524,10 +852,10 @@ async fn post_decide(
         reversibility: None,
         village_id: None,
     };
-    let mut event = new_decision_event(&branch, parent_hash.as_deref(), "system", &dp)?;
+    let mut event = new_decision_event(branch, parent_hash.as_deref(), "system", &dp)?;
 
     // Auto-supersede: find prior decision with same key via SQL index
-    let prior = ledger.find_active_decision(&branch, key)?;
+    let prior = ledger.find_active_decision(branch, key)?;
     let mut superseded = None;
     if let Some(ref row) = prior {
         if row.value != value {
@@ -541,15 +869,176 @@ async fn post_decide(
     }
 
     finalize_event(&mut event)?;
-    ledger.append_event(&event)?;
+    Ok((event, superseded))
+}
 
-    Ok((
-        StatusCode::CREATED,
-        Json(DecideResponse {
-            event_id: event.event_id,
-            superseded,
-        }),
-    ))
+// ── POST /api/events/batch ──
+
+/// Upper bound on items accepted by one `POST /api/events/batch` request.
+const MAX_BATCH_EVENTS: usize = 100;
+
+#[derive(Deserialize)]
+struct EventsBatchBody {
+    items: Vec<serde_json::Value>,
+    /// Self-asserted actor for branch protection overrides on decision items;
+    /// ignored when the request authenticated with an API token.
+    #[serde(default)]
+    actor: Option<String>,
+}
+
+/// One batch item. Items are parsed individually so a malformed entry fails
+/// on its own instead of rejecting the whole batch.
+#[derive(Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum BatchItem {
+    Note {
+        text: String,
+        role: Option<String>,
+        tags: Option<Vec<String>>,
+        body: Option<edda_core::NoteBody>,
+    },
+    Decision {
+        decision: String,
+        reason: Option<String>,
+    },
+    /// A `todo`-tagged note; surfaces under "Recent Signals" in context.
+    Signal {
+        text: String,
+        role: Option<String>,
+        tags: Option<Vec<String>>,
+    },
+}
+
+#[derive(Serialize)]
+struct BatchItemResult {
+    index: usize,
+    ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    superseded: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EventsBatchResponse {
+    created: usize,
+    failed: usize,
+    results: Vec<BatchItemResult>,
+}
+
+async fn post_events_batch(
+    State(state): State<Arc<AppState>>,
+    caller: Option<Extension<ApiCaller>>,
+    body: Result<Json<EventsBatchBody>, JsonRejection>,
+) -> Result<Json<EventsBatchResponse>, AppError> {
+    let Json(body) = body.map_err(|e| AppError::Validation(e.body_text()))?;
+
+    if body.items.is_empty() || body.items.len() > MAX_BATCH_EVENTS {
+        return Err(AppError::Validation(format!(
+            "items must contain 1\u{2013}{MAX_BATCH_EVENTS} entries"
+        )));
+    }
+
+    let actor = caller.map(|Extension(c)| c.0).or(body.actor);
+    let mut ledger = state.open_ledger()?;
+    ledger.set_actor(actor.as_deref());
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+
+    let branch = ledger.head_branch()?;
+    let mut results = Vec::with_capacity(body.items.len());
+
+    for (index, raw) in body.items.into_iter().enumerate() {
+        let outcome = append_batch_item(&ledger, &branch, actor.as_deref(), raw);
+        results.push(match outcome {
+            Ok((event_id, superseded)) => BatchItemResult {
+                index,
+                ok: true,
+                event_id: Some(event_id),
+                superseded,
+                error: None,
+            },
+            Err(e) => BatchItemResult {
+                index,
+                ok: false,
+                event_id: None,
+                superseded: None,
+                error: Some(e.to_string()),
+            },
+        });
+    }
+
+    let created = results.iter().filter(|r| r.ok).count();
+    Ok(Json(EventsBatchResponse {
+        created,
+        failed: results.len() - created,
+        results,
+    }))
+}
+
+/// Validate and append a single batch item. Each append re-reads the ledger
+/// tip, so items chain onto each other in request order.
+fn append_batch_item(
+    ledger: &Ledger,
+    branch: &str,
+    actor: Option<&str>,
+    raw: serde_json::Value,
+) -> Result<(String, Option<String>), AppError> {
+    let item: BatchItem =
+        serde_json::from_value(raw).map_err(|e| AppError::Validation(e.to_string()))?;
+
+    match item {
+        BatchItem::Note {
+            text,
+            role,
+            tags,
+            body,
+        } => {
+            let parent_hash = ledger.last_event_hash()?;
+            let event = new_note_event_with_body(
+                branch,
+                parent_hash.as_deref(),
+                role.as_deref().unwrap_or("user"),
+                &text,
+                &tags.unwrap_or_default(),
+                body.as_ref(),
+            )?;
+            ledger.append_event_rebasing(&event)?;
+            Ok((event.event_id, None))
+        }
+        BatchItem::Signal { text, role, tags } => {
+            let mut tags = tags.unwrap_or_default();
+            if !tags.iter().any(|t| t == "todo") {
+                tags.push("todo".to_string());
+            }
+            let parent_hash = ledger.last_event_hash()?;
+            let event = new_note_event_with_body(
+                branch,
+                parent_hash.as_deref(),
+                role.as_deref().unwrap_or("user"),
+                &text,
+                &tags,
+                None,
+            )?;
+            ledger.append_event_rebasing(&event)?;
+            Ok((event.event_id, None))
+        }
+        BatchItem::Decision { decision, reason } => {
+            let (key, value) = parse_decision(&decision)?;
+            if let Some(denial) = policy::check_branch_protection(
+                &ledger.paths.edda_dir,
+                branch,
+                policy::ACTION_DECIDE,
+                actor,
+            )? {
+                return Err(AppError::Forbidden(denial));
+            }
+            let (event, superseded) = build_decision_event(ledger, branch, key, value, reason)?;
+            ledger.append_event_rebasing(&event)?;
+            Ok((event.event_id, superseded))
+        }
+    }
 }
 
 // ── POST /api/events/karvi ──
@@ -662,11 +1151,6 @@ async fn post_karvi_event(
     Ok((status, Json(response)).into_response())
 }
 
-/// Public event routes (no auth required).
-pub(crate) fn public_routes() -> Router<Arc<AppState>> {
-    Router::new().route("/api/health", get(health))
-}
-
 /// Protected event routes (auth middleware applied).
 pub(crate) fn protected_routes() -> Router<Arc<AppState>> {
     Router::new()
@@ -674,6 +1158,7 @@ pub(crate) fn protected_routes() -> Router<Arc<AppState>> {
         .route("/api/context", get(get_context))
         .route("/api/decisions", get(get_decisions))
         .route("/api/decisions/batch", post(post_decisions_batch))
+        .route("/api/ask", post(post_ask))
         .route(
             "/api/decisions/{event_id}/outcomes",
             get(get_decision_outcomes),
@@ -683,17 +1168,18 @@ pub(crate) fn protected_routes() -> Router<Arc<AppState>> {
         .route("/api/note", post(post_note))
         .route("/api/decide", post(post_decide))
         .route("/api/events/karvi", post(post_karvi_event))
+        .route("/api/events/batch", post(post_events_batch))
 }
 
 /// All event routes (for test router without auth middleware).
 #[cfg(test)]
 pub(crate) fn routes() -> Router<Arc<AppState>> {
     Router::new()
-        .route("/api/health", get(health))
         .route("/api/status", get(get_status))
         .route("/api/context", get(get_context))
         .route("/api/decisions", get(get_decisions))
         .route("/api/decisions/batch", post(post_decisions_batch))
+        .route("/api/ask", post(post_ask))
         .route(
             "/api/decisions/{event_id}/outcomes",
             get(get_decision_outcomes),
@@ -703,4 +1189,5 @@ pub(crate) fn routes() -> Router<Arc<AppState>> {
         .route("/api/note", post(post_note))
         .route("/api/decide", post(post_decide))
         .route("/api/events/karvi", post(post_karvi_event))
+        .route("/api/events/batch", post(post_events_batch))
 }
@@ -0,0 +1,385 @@
+//! Signed links to generated artifacts.
+//!
+//! `GET /files/{root}/{path}?expires=..&sig=..` serves one file from a
+//! named root: `packs` (the project's context packs in the store),
+//! `recaps` (`<session_id>.json`, a session's latest recap from the ledger)
+//! or a directory listed under `serve.files` in `.edda/config.json`, such
+//! as the target of `edda export md`. The route is public; the signature,
+//! minted by the protected `POST /api/files/sign`, is the credential, and
+//! it covers the project, the exact path and the expiry.
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::extract::{Path as UrlPath, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::helpers::{hmac_sign_hex, hmac_verify_hex};
+use crate::state::AppState;
+
+/// Root that always exists: the project's context packs.
+const PACKS_ROOT: &str = "packs";
+/// Root that always exists: session recaps, one `<session_id>.json` each.
+/// Recaps live in the ledger, not on disk, so this root has no directory.
+const RECAPS_ROOT: &str = "recaps";
+/// Link lifetime when the request names none.
+const DEFAULT_TTL_SECS: u64 = 15 * 60;
+/// Longest lifetime a link can be minted with.
+const MAX_TTL_SECS: u64 = 24 * 60 * 60;
+/// HMAC key file under the store's `keys/` directory.
+const KEY_FILE: &str = "file_urls.key";
+
+// ── Roots ──
+
+/// Named directories files may be served from.
+fn file_roots(repo_root: &Path) -> anyhow::Result<BTreeMap<String, PathBuf>> {
+    let mut roots = BTreeMap::new();
+    let project_id = edda_store::project_id(repo_root);
+    roots.insert(
+        PACKS_ROOT.to_string(),
+        edda_store::project_dir(&project_id).join("packs"),
+    );
+    roots.extend(configured_roots(
+        &edda_ledger::EddaPaths::discover(repo_root).config_json,
+        repo_root,
+    )?);
+    Ok(roots)
+}
+
+/// Roots from `serve.files` in `.edda/config.json`: a map of root name to
+/// directory, relative to the repo root unless absolute.
+fn configured_roots(
+    config_path: &Path,
+    repo_root: &Path,
+) -> anyhow::Result<BTreeMap<String, PathBuf>> {
+    let content = match std::fs::read_to_string(config_path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let config: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", config_path.display()))?;
+    let Some(section) = config.get("serve").and_then(|s| s.get("files")) else {
+        return Ok(BTreeMap::new());
+    };
+    let entries: BTreeMap<String, PathBuf> = serde_json::from_value(section.clone())
+        .map_err(|e| anyhow::anyhow!("invalid serve.files in config.json: {e}"))?;
+    entries
+        .into_iter()
+        .map(|(name, dir)| {
+            let valid = !name.is_empty()
+                && name != PACKS_ROOT
+                && name != RECAPS_ROOT
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+            if !valid {
+                anyhow::bail!(
+                    "serve.files root '{name}' must be [A-Za-z0-9_-] and not '{PACKS_ROOT}' or '{RECAPS_ROOT}'"
+                );
+            }
+            Ok((name, repo_root.join(dir)))
+        })
+        .collect()
+}
+
+/// Resolve `rel` inside `base`, refusing anything that leaves it: `..`,
+/// absolute paths, and symlinks pointing outside. `None` when the file does
+/// not exist or is not a regular file.
+fn resolve_within(base: &Path, rel: &str) -> Result<Option<PathBuf>, AppError> {
+    let rel = Path::new(rel);
+    if rel.as_os_str().is_empty() || !rel.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(AppError::Validation(format!(
+            "invalid file path: {}",
+            rel.display()
+        )));
+    }
+    let (Ok(base), Ok(path)) = (base.canonicalize(), base.join(rel).canonicalize()) else {
+        return Ok(None);
+    };
+    if !path.starts_with(&base) {
+        return Err(AppError::Forbidden("path escapes its root".into()));
+    }
+    Ok(path.is_file().then_some(path))
+}
+
+/// The latest recap payload for `<session_id>.json`, pretty-printed.
+fn recap_file(repo_root: &Path, rel: &str) -> Result<Option<Vec<u8>>, AppError> {
+    let session_id = rel
+        .strip_suffix(".json")
+        .filter(|id| {
+            !id.is_empty()
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        })
+        .ok_or_else(|| AppError::Validation(format!("invalid recap path: {rel}")))?;
+    let ledger = edda_ledger::Ledger::open(repo_root)?;
+    let recap = ledger
+        .iter_events_by_type("recap")?
+        .into_iter()
+        .rev()
+        .find(|e| e.payload.get("session_id").and_then(|v| v.as_str()) == Some(session_id));
+    match recap {
+        Some(event) => Ok(Some(serde_json::to_vec_pretty(&event.payload)?)),
+        None => Ok(None),
+    }
+}
+
+/// Read `rel` from the named root, with its content type. `None` when the
+/// root or the file does not exist.
+fn read_root_file(
+    repo_root: &Path,
+    root: &str,
+    rel: &str,
+) -> Result<Option<(Vec<u8>, &'static str)>, AppError> {
+    if root == RECAPS_ROOT {
+        return Ok(recap_file(repo_root, rel)?.map(|body| (body, "application/json")));
+    }
+    let roots = file_roots(repo_root)?;
+    let Some(base) = roots.get(root) else {
+        return Ok(None);
+    };
+    match resolve_within(base, rel)? {
+        Some(file) => Ok(Some((std::fs::read(&file)?, content_type(&file)))),
+        None => Ok(None),
+    }
+}
+
+// ── Signing ──
+
+/// Load the URL signing key, creating it on first use.
+fn load_or_create_key(dir: &Path) -> anyhow::Result<Vec<u8>> {
+    let path = dir.join(KEY_FILE);
+    if let Ok(hex_key) = std::fs::read_to_string(&path) {
+        return Ok(hex::decode(hex_key.trim())?);
+    }
+    let key: [u8; 32] = rand::random();
+    edda_store::write_atomic(&path, hex::encode(key).as_bytes())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(key.to_vec())
+}
+
+fn url_key() -> anyhow::Result<Vec<u8>> {
+    load_or_create_key(&edda_store::store_root().join("keys"))
+}
+
+/// What a link's signature covers.
+fn signed_message(project_id: &str, file: &str, expires: u64) -> Vec<u8> {
+    format!("{project_id}\n{file}\n{expires}").into_bytes()
+}
+
+fn signature(key: &[u8], project_id: &str, file: &str, expires: u64) -> String {
+    hmac_sign_hex(key, &signed_message(project_id, file, expires))
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Check a link's signature and expiry. Both failures look the same to the
+/// caller.
+fn verify(
+    key: &[u8],
+    project_id: &str,
+    file: &str,
+    query: &SignedQuery,
+    now: u64,
+) -> Result<(), AppError> {
+    let message = signed_message(project_id, file, query.expires);
+    if query.expires < now || !hmac_verify_hex(key, &message, &query.sig) {
+        return Err(AppError::Forbidden("invalid or expired file link".into()));
+    }
+    Ok(())
+}
+
+// ── GET /files/{root}/{*path} ──
+
+#[derive(Deserialize)]
+struct SignedQuery {
+    expires: u64,
+    sig: String,
+}
+
+async fn get_file(
+    State(state): State<Arc<AppState>>,
+    UrlPath((root, path)): UrlPath<(String, String)>,
+    Query(query): Query<SignedQuery>,
+) -> Result<Response, AppError> {
+    let project_id = edda_store::project_id(&state.repo_root);
+    verify(
+        &url_key()?,
+        &project_id,
+        &format!("{root}/{path}"),
+        &query,
+        unix_now(),
+    )?;
+    let (body, content_type) = read_root_file(&state.repo_root, &root, &path)?
+        .ok_or_else(|| AppError::NotFound(format!("file not found: {root}/{path}")))?;
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+            (header::CACHE_CONTROL, "private, no-store"),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("md") => "text/markdown; charset=utf-8",
+        Some("json") => "application/json",
+        Some("jsonl") => "application/x-ndjson",
+        Some("txt") | Some("log") => "text/plain; charset=utf-8",
+        Some("csv") => "text/csv; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+// ── POST /api/files/sign ──
+
+#[derive(Deserialize)]
+struct SignRequest {
+    /// `<root>/<path>`, e.g. `packs/hot.md`.
+    path: String,
+    #[serde(default)]
+    ttl_secs: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct SignResponse {
+    url: String,
+    expires_at: u64,
+}
+
+async fn post_sign(
+    State(state): State<Arc<AppState>>,
+    Json(body): Json<SignRequest>,
+) -> Result<Json<SignResponse>, AppError> {
+    let file = body.path.trim_start_matches('/');
+    let (root, rel) = file
+        .split_once('/')
+        .ok_or_else(|| AppError::Validation("path must be <root>/<file>".into()))?;
+    let ttl = body.ttl_secs.unwrap_or(DEFAULT_TTL_SECS);
+    if ttl == 0 || ttl > MAX_TTL_SECS {
+        return Err(AppError::Validation(format!(
+            "ttl_secs must be between 1 and {MAX_TTL_SECS}"
+        )));
+    }
+    if root != RECAPS_ROOT && !file_roots(&state.repo_root)?.contains_key(root) {
+        return Err(AppError::NotFound(format!("unknown file root: {root}")));
+    }
+    if read_root_file(&state.repo_root, root, rel)?.is_none() {
+        return Err(AppError::NotFound(format!("file not found: {file}")));
+    }
+
+    let project_id = edda_store::project_id(&state.repo_root);
+    let expires_at = unix_now() + ttl;
+    let sig = signature(&url_key()?, &project_id, file, expires_at);
+    Ok(Json(SignResponse {
+        url: format!("/files/{file}?expires={expires_at}&sig={sig}"),
+        expires_at,
+    }))
+}
+
+/// Public file route; the signed query string authenticates it.
+pub(crate) fn public_routes() -> Router<Arc<AppState>> {
+    Router::new().route("/files/{root}/{*path}", get(get_file))
+}
+
+/// Link minting, behind the API's auth.
+pub(crate) fn protected_routes() -> Router<Arc<AppState>> {
+    Router::new().route("/api/files/sign", post(post_sign))
+}
+
+#[cfg(test)]
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    public_routes().merge(protected_routes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signatures_bind_path_project_and_expiry() {
+        let key = b"k";
+        let query = |expires, sig: &str| SignedQuery {
+            expires,
+            sig: sig.to_string(),
+        };
+        let sig = signature(key, "p1", "packs/hot.md", 200);
+        assert!(verify(key, "p1", "packs/hot.md", &query(200, &sig), 100).is_ok());
+        assert!(verify(key, "p1", "packs/hot.md", &query(200, &sig), 201).is_err());
+        assert!(verify(key, "p1", "packs/warm.md", &query(200, &sig), 100).is_err());
+        assert!(verify(key, "p2", "packs/hot.md", &query(200, &sig), 100).is_err());
+        assert!(verify(key, "p1", "packs/hot.md", &query(300, &sig), 100).is_err());
+        assert!(verify(b"other", "p1", "packs/hot.md", &query(200, &sig), 100).is_err());
+    }
+
+    #[test]
+    fn key_is_created_once_and_reused() {
+        let tmp = tempfile::tempdir().unwrap();
+        let first = load_or_create_key(tmp.path()).unwrap();
+        assert_eq!(first.len(), 32);
+        assert_eq!(load_or_create_key(tmp.path()).unwrap(), first);
+    }
+
+    #[test]
+    fn paths_cannot_leave_their_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().join("packs");
+        std::fs::create_dir_all(base.join("sub")).unwrap();
+        std::fs::write(base.join("sub").join("a.md"), "a").unwrap();
+        std::fs::write(tmp.path().join("secret.txt"), "s").unwrap();
+
+        assert!(resolve_within(&base, "sub/a.md").unwrap().is_some());
+        assert!(resolve_within(&base, "missing.md").unwrap().is_none());
+        assert!(
+            resolve_within(&base, "sub").unwrap().is_none(),
+            "not a file"
+        );
+        assert!(resolve_within(&base, "../secret.txt").is_err());
+        assert!(resolve_within(&base, "sub/../../secret.txt").is_err());
+        assert!(resolve_within(&base, "/etc/passwd").is_err());
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(tmp.path().join("secret.txt"), base.join("link")).unwrap();
+            assert!(resolve_within(&base, "link").is_err());
+        }
+    }
+
+    #[test]
+    fn configured_roots_resolve_against_repo() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = tmp.path().join("config.json");
+        assert!(configured_roots(&config, tmp.path()).unwrap().is_empty());
+
+        std::fs::write(&config, r#"{"serve":{"files":{"exports":"docs/ledger"}}}"#).unwrap();
+        let roots = configured_roots(&config, tmp.path()).unwrap();
+        assert_eq!(roots["exports"], tmp.path().join("docs/ledger"));
+
+        std::fs::write(&config, r#"{"serve":{"files":{"packs":"x"}}}"#).unwrap();
+        assert!(configured_roots(&config, tmp.path()).is_err());
+        std::fs::write(&config, r#"{"serve":{"files":{"recaps":"x"}}}"#).unwrap();
+        assert!(configured_roots(&config, tmp.path()).is_err());
+        std::fs::write(&config, r#"{"serve":{"files":{"a/b":"x"}}}"#).unwrap();
+        assert!(configured_roots(&config, tmp.path()).is_err());
+    }
+}
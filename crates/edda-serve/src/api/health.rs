@@ -0,0 +1,163 @@
+//! Liveness and readiness probes for container orchestration.
+//!
+//! `/api/health` only says the process is serving requests. `/api/ready`
+//! checks what a request actually needs — an openable ledger at the schema
+//! this build expects, a workspace lock that writers can take, and a search
+//! index for transcript hits — and answers 503 when a required one fails.
+//! Both are public routes; component details never include raw error text,
+//! which can carry filesystem paths.
+
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Serialize;
+
+use edda_ledger::lock::WorkspaceLock;
+use edda_ledger::Ledger;
+
+use crate::state::AppState;
+
+// ── GET /api/health ──
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "ok": true,
+        "status": "alive",
+        "version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+// ── GET /api/ready ──
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ComponentStatus {
+    Ok,
+    /// Works, with reduced function; does not fail readiness.
+    Degraded,
+    Fail,
+}
+
+#[derive(Debug, Serialize)]
+struct Component {
+    name: &'static str,
+    status: ComponentStatus,
+    detail: String,
+}
+
+impl Component {
+    fn new(name: &'static str, status: ComponentStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ReadyResponse {
+    ready: bool,
+    components: Vec<Component>,
+}
+
+async fn ready(State(state): State<Arc<AppState>>) -> Response {
+    let components = probe(&state.repo_root);
+    let ready = components.iter().all(|c| c.status != ComponentStatus::Fail);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(ReadyResponse { ready, components })).into_response()
+}
+
+fn probe(repo_root: &std::path::Path) -> Vec<Component> {
+    use ComponentStatus::{Degraded, Fail, Ok};
+
+    let mut components = Vec::with_capacity(4);
+    let ledger = match Ledger::open(repo_root) {
+        Result::Ok(ledger) => {
+            components.push(Component::new("ledger", Ok, "open"));
+            ledger
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "readiness: ledger cannot be opened");
+            let detail = if edda_ledger::EddaPaths::discover(repo_root).is_initialized() {
+                "cannot open ledger"
+            } else {
+                "workspace not initialized"
+            };
+            components.push(Component::new("ledger", Fail, detail));
+            components.push(Component::new("schema", Fail, "ledger unavailable"));
+            components.push(Component::new("lock", Fail, "ledger unavailable"));
+            components.push(search_index(repo_root));
+            return components;
+        }
+    };
+
+    let expected = Ledger::SCHEMA_VERSION;
+    components.push(match ledger.schema_version() {
+        Result::Ok(v) if v == expected => Component::new("schema", Ok, format!("v{v}")),
+        Result::Ok(v) if v > expected => Component::new(
+            "schema",
+            Degraded,
+            format!("v{v} is newer than this build (v{expected})"),
+        ),
+        Result::Ok(v) => Component::new(
+            "schema",
+            Fail,
+            format!("v{v}, expected v{expected}; migrations did not complete"),
+        ),
+        Err(e) => {
+            tracing::warn!(error = %e, "readiness: schema version unreadable");
+            Component::new("schema", Fail, "schema version unreadable")
+        }
+    });
+
+    components.push(match WorkspaceLock::is_free(&ledger.paths) {
+        Result::Ok(true) => Component::new("lock", Ok, "free"),
+        // Writers hold the lock briefly; a busy lock alone is not a failure.
+        Result::Ok(false) => Component::new("lock", Degraded, "held by another process"),
+        Err(e) => {
+            tracing::warn!(error = %e, "readiness: lock file unusable");
+            Component::new("lock", Fail, "lock file cannot be opened")
+        }
+    });
+
+    components.push(search_index(repo_root));
+    components
+}
+
+/// Transcript search is optional: a missing or outdated index only drops
+/// conversation hits from `/api/ask`.
+fn search_index(repo_root: &std::path::Path) -> Component {
+    let project_id = edda_store::project_id(repo_root);
+    let index_dir = edda_store::project_subdir(&project_id, "search").join("tantivy");
+    if !index_dir.exists() {
+        Component::new(
+            "search_index",
+            ComponentStatus::Degraded,
+            "missing; run `edda search index`",
+        )
+    } else if edda_search_fts::schema::index_is_outdated(&index_dir) {
+        Component::new(
+            "search_index",
+            ComponentStatus::Degraded,
+            "outdated schema; run `edda search index`",
+        )
+    } else {
+        Component::new("search_index", ComponentStatus::Ok, "present")
+    }
+}
+
+/// Health routes (no auth required).
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/health", get(health))
+        .route("/api/ready", get(ready))
+}
@@ -10,6 +10,7 @@ use edda_aggregate::aggregate::{per_project_metrics, DateRange, ProjectMetrics};
 use edda_aggregate::controls::evaluate_controls_rules;
 use edda_aggregate::quality::{model_quality_from_events, QualityReport};
 use edda_aggregate::rollup;
+use edda_ledger::{AppendStats, SizeStats, TypeStats};
 use edda_store::registry::list_projects;
 
 use crate::error::AppError;
@@ -39,6 +40,58 @@ async fn get_quality_metrics(
     Ok(Json(report))
 }
 
+// ── GET /api/metrics/ledger ──
+
+fn default_ledger_top() -> usize {
+    10
+}
+
+#[derive(Deserialize)]
+struct LedgerMetricsQuery {
+    #[serde(default = "default_ledger_top")]
+    top: usize,
+}
+
+#[derive(Serialize)]
+struct FieldMetric {
+    field: String,
+    #[serde(flatten)]
+    size: SizeStats,
+}
+
+#[derive(Serialize)]
+struct LedgerMetricsResponse {
+    since: Option<String>,
+    updated_at: Option<String>,
+    totals: SizeStats,
+    by_type: std::collections::BTreeMap<String, TypeStats>,
+    top_fields: Vec<FieldMetric>,
+}
+
+/// Append-path metrics from `.edda/stats.json` (same data as `edda stats`).
+async fn get_ledger_metrics(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<LedgerMetricsQuery>,
+) -> Result<Json<LedgerMetricsResponse>, AppError> {
+    let paths = edda_ledger::EddaPaths::discover(&state.repo_root);
+    let stats = AppendStats::load(&paths)?;
+    let top_fields = stats
+        .top_fields(params.top)
+        .into_iter()
+        .map(|(field, size)| FieldMetric {
+            field: field.to_string(),
+            size: size.clone(),
+        })
+        .collect();
+    Ok(Json(LedgerMetricsResponse {
+        totals: stats.totals(),
+        since: stats.since,
+        updated_at: stats.updated_at,
+        by_type: stats.by_type,
+        top_fields,
+    }))
+}
+
 // ── GET /api/controls/suggestions ──
 
 #[derive(Deserialize)]
@@ -361,6 +414,7 @@ pub(crate) fn routes() -> Router<Arc<AppState>> {
         .route("/api/metrics/quality", get(get_quality_metrics))
         .route("/api/metrics/overview", get(get_metrics_overview))
         .route("/api/metrics/trends", get(get_metrics_trends))
+        .route("/api/metrics/ledger", get(get_ledger_metrics))
         .route("/api/controls/suggestions", get(get_controls_suggestions))
         .route("/api/controls/patches", get(get_controls_patches))
         .route(
@@ -1,12 +1,20 @@
 pub(crate) mod analytics;
+pub(crate) mod annotations;
 pub(crate) mod auth;
 pub(crate) mod briefs;
+pub(crate) mod calendar;
 pub(crate) mod dashboard;
 pub(crate) mod drafts;
 pub(crate) mod events;
+pub(crate) mod files;
+pub(crate) mod health;
 pub(crate) mod ingestion;
 pub(crate) mod metrics;
 pub(crate) mod policy;
+pub(crate) mod sessions;
+pub(crate) mod slack;
 pub(crate) mod snapshots;
 pub(crate) mod stream;
 pub(crate) mod telemetry;
+pub(crate) mod ui;
+pub(crate) mod ws;
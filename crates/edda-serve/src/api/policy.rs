@@ -155,6 +155,48 @@ async fn get_scope_whitelist(
     Ok(Json(WhitelistResponse { claims }))
 }
 
+// ── GET /api/peers ──
+
+#[derive(Serialize)]
+struct PeerItem {
+    session_id: String,
+    label: String,
+    age_secs: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    current_phase: Option<String>,
+    task_subjects: Vec<String>,
+    claimed_paths: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct PeersResponse {
+    peers: Vec<PeerItem>,
+}
+
+/// Non-stale sessions for this workspace's project, newest heartbeat first.
+async fn get_peers(State(state): State<Arc<AppState>>) -> Json<PeersResponse> {
+    let project_id = edda_store::project_id(&state.repo_root);
+    let stale = edda_bridge_claude::peers::stale_secs();
+
+    let peers = edda_bridge_claude::peers::discover_all_sessions(&project_id)
+        .into_iter()
+        .filter(|p| p.age_secs <= stale)
+        .map(|p| PeerItem {
+            session_id: p.session_id,
+            label: p.label,
+            age_secs: p.age_secs,
+            branch: p.branch,
+            current_phase: p.current_phase,
+            task_subjects: p.task_subjects,
+            claimed_paths: p.claimed_paths,
+        })
+        .collect();
+
+    Json(PeersResponse { peers })
+}
+
 // ── POST /api/authz/check ──
 
 async fn post_authz_check(
@@ -288,6 +330,7 @@ pub(crate) fn routes() -> Router<Arc<AppState>> {
     Router::new()
         .route("/api/scope/check", post(post_scope_check))
         .route("/api/scope/whitelist", get(get_scope_whitelist))
+        .route("/api/peers", get(get_peers))
         .route("/api/authz/check", post(post_authz_check))
         .route("/api/approval/check", post(post_approval_check))
         .route("/api/tool-tier/{tool_name}", get(get_tool_tier))
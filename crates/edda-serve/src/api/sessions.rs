@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use axum::extract::{Path as AxumPath, State};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::Serialize;
+
+use edda_bridge_claude::sessions::{self, SessionDetail, SessionListItem};
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+// ── GET /api/sessions ──
+
+#[derive(Serialize)]
+struct SessionsResponse {
+    sessions: Vec<SessionListItem>,
+}
+
+/// Every session with a heartbeat or a transcript index for this
+/// workspace's project, live sessions first.
+async fn get_sessions(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<SessionsResponse>, AppError> {
+    let ledger = state.open_ledger()?;
+    let project_id = edda_store::project_id(&state.repo_root);
+    let sessions = sessions::list_sessions(&project_id, &ledger)?;
+    Ok(Json(SessionsResponse { sessions }))
+}
+
+// ── GET /api/sessions/:id ──
+
+/// One session's heartbeat, latest pack, signals, usage and recap.
+async fn get_session(
+    State(state): State<Arc<AppState>>,
+    AxumPath(session_id): AxumPath<String>,
+) -> Result<Json<SessionDetail>, AppError> {
+    let ledger = state.open_ledger()?;
+    let project_id = edda_store::project_id(&state.repo_root);
+    match sessions::session_detail(&project_id, &ledger, &session_id)? {
+        Some(detail) => Ok(Json(detail)),
+        None => Err(AppError::NotFound(format!(
+            "session '{session_id}' not found"
+        ))),
+    }
+}
+
+/// Session drill-down routes.
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/sessions", get(get_sessions))
+        .route("/api/sessions/{id}", get(get_session))
+}
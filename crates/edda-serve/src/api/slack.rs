@@ -0,0 +1,514 @@
+//! Slack input bridge: capture team decisions from chat.
+//!
+//! A Slack app pointed at `POST /api/slack/events` (Events API, `message.*`
+//! subscriptions) turns chat commands in the configured channels into ledger
+//! events, with the Slack author as actor:
+//!
+//! ```text
+//! !decide db.engine=postgres because we need JSONB
+//! !note staging is down until the migration lands
+//! ```
+//!
+//! Configured in `.edda/config.json`; without a `slack` section the route
+//! answers 404:
+//!
+//! ```json
+//! { "slack": { "signing_secret": "…", "channels": ["C0123ABC"],
+//!              "users": { "U024BE7LH": "alice" } } }
+//! ```
+//!
+//! Requests are authenticated by Slack's request signature rather than the
+//! API's bearer tokens, so the route is public.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use serde::Deserialize;
+
+use edda_core::event::{finalize_event, new_note_event};
+use edda_core::policy;
+use edda_ledger::lock::WorkspaceLock;
+
+use crate::api::events::{build_decision_event, parse_decision};
+use crate::error::AppError;
+use crate::helpers::hmac_verify_hex;
+use crate::state::AppState;
+
+/// Environment variable read when the config has no `signing_secret`.
+const SIGNING_SECRET_ENV: &str = "EDDA_SLACK_SIGNING_SECRET";
+
+/// Oldest request timestamp accepted, against replays (Slack's own advice).
+const MAX_REQUEST_AGE_SECS: i64 = 5 * 60;
+
+/// How many Slack event ids are remembered to drop redelivered events.
+const SEEN_EVENTS: usize = 1000;
+
+/// The `slack` section of `.edda/config.json`.
+#[derive(Deserialize)]
+struct SlackConfigEntry {
+    #[serde(default)]
+    signing_secret: Option<String>,
+    #[serde(default)]
+    channels: Vec<String>,
+    #[serde(default)]
+    users: BTreeMap<String, String>,
+}
+
+/// A configured Slack bridge.
+pub(crate) struct SlackBridge {
+    signing_secret: String,
+    /// Channel ids whose messages are read. Others are ignored.
+    channels: Vec<String>,
+    /// Slack user id -> actor name. Unmapped users act as `slack:<id>`.
+    users: BTreeMap<String, String>,
+    /// Recently recorded event ids, oldest first.
+    seen: Mutex<VecDeque<String>>,
+}
+
+impl SlackBridge {
+    pub(crate) fn new(
+        signing_secret: &str,
+        channels: Vec<String>,
+        users: BTreeMap<String, String>,
+    ) -> Self {
+        Self {
+            signing_secret: signing_secret.to_string(),
+            channels,
+            users,
+            seen: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    fn actor(&self, user: &str) -> String {
+        self.users
+            .get(user)
+            .cloned()
+            .unwrap_or_else(|| format!("slack:{user}"))
+    }
+
+    /// Check Slack's `X-Slack-Signature` over the raw body.
+    fn verify(&self, headers: &HeaderMap, body: &[u8], now: i64) -> Result<(), AppError> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| AppError::Unauthorized(format!("missing {name} header")))
+        };
+        let ts = header("x-slack-request-timestamp")?;
+        let signature = header("x-slack-signature")?;
+        let ts_secs: i64 = ts
+            .parse()
+            .map_err(|_| AppError::Unauthorized("invalid Slack request timestamp".into()))?;
+        if (now - ts_secs).abs() > MAX_REQUEST_AGE_SECS {
+            return Err(AppError::Unauthorized("stale Slack request".into()));
+        }
+        let mut base = format!("v0:{ts}:").into_bytes();
+        base.extend_from_slice(body);
+        let valid = signature
+            .strip_prefix("v0=")
+            .is_some_and(|tag| hmac_verify_hex(self.signing_secret.as_bytes(), &base, tag));
+        if !valid {
+            return Err(AppError::Unauthorized("invalid Slack signature".into()));
+        }
+        Ok(())
+    }
+
+    fn is_seen(&self, event_id: &str) -> bool {
+        self.seen
+            .lock()
+            .map(|seen| seen.iter().any(|id| id == event_id))
+            .unwrap_or(false)
+    }
+
+    fn mark_seen(&self, event_id: &str) {
+        if let Ok(mut seen) = self.seen.lock() {
+            if seen.len() >= SEEN_EVENTS {
+                seen.pop_front();
+            }
+            seen.push_back(event_id.to_string());
+        }
+    }
+}
+
+/// Load the Slack bridge from `.edda/config.json` key `slack`.
+///
+/// A missing file or section leaves the bridge off. Like `serve.tokens`, a
+/// malformed section fails instead of being dropped, and so does one without
+/// a signing secret: the route would otherwise accept unsigned requests.
+pub(crate) fn load_slack_bridge(config_path: &Path) -> anyhow::Result<Option<SlackBridge>> {
+    let content = match std::fs::read_to_string(config_path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let config: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", config_path.display()))?;
+    let Some(section) = config.get("slack") else {
+        return Ok(None);
+    };
+    let entry: SlackConfigEntry = serde_json::from_value(section.clone())
+        .map_err(|e| anyhow::anyhow!("invalid slack section in config.json: {e}"))?;
+    let secret = entry
+        .signing_secret
+        .or_else(|| std::env::var(SIGNING_SECRET_ENV).ok())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "slack bridge needs `slack.signing_secret` in config.json or {SIGNING_SECRET_ENV}"
+            )
+        })?;
+    Ok(Some(SlackBridge::new(&secret, entry.channels, entry.users)))
+}
+
+/// A chat command the bridge records.
+#[derive(Debug, PartialEq)]
+enum Command {
+    Decide {
+        decision: String,
+        reason: Option<String>,
+    },
+    Note(String),
+}
+
+/// Parse `!decide key=value [because reason]` or `!note text`. Anything else,
+/// including a bare command, is not for us.
+fn parse_command(text: &str) -> Option<Command> {
+    let text = unescape(text.trim());
+    let (command, rest) = text.split_once(char::is_whitespace)?;
+    let rest = rest.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    match command {
+        "!decide" => {
+            let (decision, reason) = match rest.split_once(" because ") {
+                Some((d, r)) => (d.trim(), Some(r.trim().to_string())),
+                None => (rest, None),
+            };
+            Some(Command::Decide {
+                decision: decision.to_string(),
+                reason: reason.filter(|r| !r.is_empty()),
+            })
+        }
+        "!note" => Some(Command::Note(rest.to_string())),
+        _ => None,
+    }
+}
+
+/// Undo Slack's message formatting: `<url|label>` and `<url>` links become
+/// the url, and the `&amp;`, `&lt;`, `&gt;` escapes are decoded.
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = rest.find('<') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        match after.find('>') {
+            Some(close) => {
+                let inner = &after[..close];
+                out.push_str(inner.split_once('|').map_or(inner, |(url, _)| url));
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push_str(&rest[open..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+// ── POST /api/slack/events ──
+
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    challenge: Option<String>,
+    #[serde(default)]
+    event_id: Option<String>,
+    #[serde(default)]
+    event: Option<MessageEvent>,
+}
+
+#[derive(Deserialize)]
+struct MessageEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    subtype: Option<String>,
+    #[serde(default)]
+    bot_id: Option<String>,
+    #[serde(default)]
+    channel: Option<String>,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    ts: Option<String>,
+}
+
+/// Acknowledge without recording anything. Slack retries non-2xx answers,
+/// so messages that are not for us, or are refused, still get a 200.
+fn ignored(reason: &str) -> Response {
+    Json(serde_json::json!({ "ok": true, "ignored": reason })).into_response()
+}
+
+async fn post_slack_events(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, AppError> {
+    let Some(bridge) = state.slack.as_ref() else {
+        return Err(AppError::NotFound("slack bridge is not configured".into()));
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    bridge.verify(&headers, &body, now)?;
+
+    let envelope: Envelope = serde_json::from_slice(&body)
+        .map_err(|e| AppError::Validation(format!("invalid Slack payload: {e}")))?;
+    match envelope.kind.as_str() {
+        "url_verification" => {
+            return Ok(Json(serde_json::json!({ "challenge": envelope.challenge })).into_response())
+        }
+        "event_callback" => {}
+        _ => return Ok(ignored("unsupported payload type")),
+    }
+    let Some(event) = envelope.event else {
+        return Ok(ignored("no event"));
+    };
+    // Edits, joins and bot posts (our own echoes included) carry a subtype or
+    // bot id; only plain messages from people are commands.
+    if event.kind != "message" || event.subtype.is_some() || event.bot_id.is_some() {
+        return Ok(ignored("not a user message"));
+    }
+    let (Some(channel), Some(user), Some(text)) = (event.channel, event.user, event.text) else {
+        return Ok(ignored("incomplete message"));
+    };
+    if !bridge.channels.contains(&channel) {
+        return Ok(ignored("channel not bridged"));
+    }
+    let Some(command) = parse_command(&text) else {
+        return Ok(ignored("not a command"));
+    };
+    if let Some(id) = envelope.event_id.as_deref() {
+        if bridge.is_seen(id) {
+            return Ok(ignored("already recorded"));
+        }
+    }
+
+    let actor = bridge.actor(&user);
+    let source = serde_json::json!({
+        "kind": "slack",
+        "channel": channel,
+        "user": user,
+        "ts": event.ts,
+    });
+
+    let mut ledger = state.open_ledger()?;
+    ledger.set_actor(Some(&actor));
+    let _lock = WorkspaceLock::acquire(&ledger.paths)?;
+    let branch = ledger.head_branch()?;
+
+    let mut ledger_event = match command {
+        Command::Decide { decision, reason } => {
+            let Ok((key, value)) = parse_decision(&decision) else {
+                return Ok(ignored("decision must be key=value"));
+            };
+            if let Some(denial) = policy::check_branch_protection(
+                &ledger.paths.edda_dir,
+                &branch,
+                policy::ACTION_DECIDE,
+                Some(&actor),
+            )? {
+                tracing::warn!(%actor, %denial, "slack decision refused");
+                return Ok(ignored(&denial));
+            }
+            build_decision_event(&ledger, &branch, key, value, reason)?.0
+        }
+        Command::Note(text) => {
+            let parent_hash = ledger.last_event_hash()?;
+            new_note_event(&branch, parent_hash.as_deref(), "user", &text, &[])?
+        }
+    };
+    ledger_event.payload["actor"] = serde_json::json!(actor);
+    ledger_event.payload["source"] = source;
+    finalize_event(&mut ledger_event)?;
+    ledger.append_event(&ledger_event)?;
+
+    if let Some(id) = envelope.event_id.as_deref() {
+        bridge.mark_seen(id);
+    }
+    Ok((
+        StatusCode::CREATED,
+        Json(serde_json::json!({ "ok": true, "event_id": ledger_event.event_id })),
+    )
+        .into_response())
+}
+
+/// Slack bridge route. Public: Slack signs its requests instead.
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new().route("/api/slack/events", post(post_slack_events))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::helpers::hmac_sign_hex;
+
+    #[test]
+    fn hmac_matches_rfc_4231() {
+        let tag = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+        let message = b"what do ya want for nothing?";
+        assert_eq!(hmac_sign_hex(b"Jefe", message), tag);
+        assert!(hmac_verify_hex(b"Jefe", message, tag));
+        assert!(!hmac_verify_hex(b"Jefe", b"what do ya want?", tag));
+        assert!(!hmac_verify_hex(b"Jefe", message, "not hex"));
+    }
+
+    #[test]
+    fn signatures_are_checked_and_must_be_fresh() {
+        let bridge = SlackBridge::new("secret", Vec::new(), BTreeMap::new());
+        let body = br#"{"type":"url_verification"}"#;
+        let signed = |ts: i64, secret: &str| {
+            let mut base = format!("v0:{ts}:").into_bytes();
+            base.extend_from_slice(body);
+            let mut headers = HeaderMap::new();
+            headers.insert("x-slack-request-timestamp", ts.into());
+            headers.insert(
+                "x-slack-signature",
+                format!("v0={}", hmac_sign_hex(secret.as_bytes(), &base))
+                    .parse()
+                    .unwrap(),
+            );
+            headers
+        };
+        let now = 1_700_000_000;
+        assert!(bridge.verify(&signed(now, "secret"), body, now).is_ok());
+        assert!(bridge.verify(&signed(now, "other"), body, now).is_err());
+        assert!(bridge
+            .verify(&signed(now - 600, "secret"), body, now)
+            .is_err());
+        assert!(bridge.verify(&HeaderMap::new(), body, now).is_err());
+    }
+
+    #[tokio::test]
+    async fn bridged_messages_are_recorded_with_the_slack_author() {
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let paths = edda_ledger::EddaPaths::discover(tmp.path());
+        paths.ensure_layout().unwrap();
+        edda_ledger::ledger::init_workspace(&paths).unwrap();
+        edda_ledger::ledger::init_head(&paths, "main").unwrap();
+        edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
+
+        let users = BTreeMap::from([("U1".to_string(), "alice".to_string())]);
+        let state = Arc::new(AppState {
+            repo_root: tmp.path().to_path_buf(),
+            chronicle: None,
+            pending_pairings: Mutex::new(std::collections::HashMap::new()),
+            api_tokens: Vec::new(),
+            slack: Some(SlackBridge::new("secret", vec!["C1".into()], users)),
+        });
+        let app = routes().with_state(state);
+        let post = |event_id: &str, channel: &str, text: &str| {
+            let body = serde_json::json!({
+                "type": "event_callback",
+                "event_id": event_id,
+                "event": { "type": "message", "channel": channel, "user": "U1",
+                           "text": text, "ts": "1700000000.000100" },
+            })
+            .to_string();
+            let ts = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            let base = format!("v0:{ts}:{body}");
+            let signature = hmac_sign_hex(b"secret", base.as_bytes());
+            Request::builder()
+                .method("POST")
+                .uri("/api/slack/events")
+                .header("x-slack-request-timestamp", ts.to_string())
+                .header("x-slack-signature", format!("v0={signature}"))
+                .body(Body::from(body))
+                .unwrap()
+        };
+
+        let resp = app
+            .clone()
+            .oneshot(post(
+                "Ev1",
+                "C1",
+                "!decide db.engine=postgres because JSONB",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let resp = app
+            .clone()
+            .oneshot(post(
+                "Ev1",
+                "C1",
+                "!decide db.engine=postgres because JSONB",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.status(),
+            StatusCode::OK,
+            "redelivery is not recorded twice"
+        );
+        let resp = app
+            .clone()
+            .oneshot(post("Ev2", "C9", "!note elsewhere"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let ledger = edda_ledger::Ledger::open(tmp.path()).unwrap();
+        let events = ledger.iter_events().unwrap();
+        assert_eq!(events.len(), 1, "only the bridged decision");
+        let decision = &events[0];
+        assert_eq!(decision.payload["decision"]["key"], "db.engine");
+        assert_eq!(decision.payload["decision"]["reason"], "JSONB");
+        assert_eq!(decision.payload["source"]["kind"], "slack");
+        assert_eq!(decision.payload["actor"], "alice");
+    }
+
+    #[test]
+    fn commands_parse_from_slack_text() {
+        assert_eq!(
+            parse_command("!decide db.engine=postgres because we need JSONB &amp; speed"),
+            Some(Command::Decide {
+                decision: "db.engine=postgres".into(),
+                reason: Some("we need JSONB & speed".into()),
+            })
+        );
+        assert_eq!(
+            parse_command("!note see <https://example.com/pr/1|PR 1>"),
+            Some(Command::Note("see https://example.com/pr/1".into()))
+        );
+        assert_eq!(parse_command("!decide"), None);
+        assert_eq!(parse_command("we should !decide later"), None);
+        assert_eq!(parse_command("!deploy prod"), None);
+    }
+}
@@ -289,10 +289,7 @@ fn reconstruct_snapshot(
     let context = if let Some(inline) = payload.get("context_inline") {
         inline.clone()
     } else if let Some(blob_ref) = payload.get("context_blob").and_then(|v| v.as_str()) {
-        let path =
-            edda_ledger::blob_get_path(&ledger.paths, blob_ref).map_err(AppError::Internal)?;
-        let bytes = std::fs::read(&path)
-            .map_err(|e| AppError::Internal(anyhow::anyhow!("read context blob: {e}")))?;
+        let bytes = edda_ledger::blob_read(&ledger.paths, blob_ref).map_err(AppError::Internal)?;
         serde_json::from_slice(&bytes)?
     } else {
         serde_json::Value::Null
@@ -302,10 +299,7 @@ fn reconstruct_snapshot(
     let result = if let Some(inline) = payload.get("result_inline") {
         inline.clone()
     } else if let Some(blob_ref) = payload.get("result_blob").and_then(|v| v.as_str()) {
-        let path =
-            edda_ledger::blob_get_path(&ledger.paths, blob_ref).map_err(AppError::Internal)?;
-        let bytes = std::fs::read(&path)
-            .map_err(|e| AppError::Internal(anyhow::anyhow!("read result blob: {e}")))?;
+        let bytes = edda_ledger::blob_read(&ledger.paths, blob_ref).map_err(AppError::Internal)?;
         serde_json::from_slice(&bytes)?
     } else {
         serde_json::Value::Null
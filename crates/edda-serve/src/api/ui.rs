@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use axum::extract::{Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use serde::Deserialize;
+
+use crate::error::AppError;
+use crate::middleware::{identify, SESSION_COOKIE};
+use crate::state::AppState;
+
+// ── GET / (workspace dashboard HTML) ──
+
+async fn serve_index() -> impl IntoResponse {
+    Html(include_str!("../../static/index.html"))
+}
+
+// ── GET /login?token=... ──
+
+#[derive(Deserialize)]
+struct LoginParams {
+    token: String,
+}
+
+/// Exchange an API or device token for the session cookie the auth
+/// middleware accepts, then send the browser to the dashboard. The cookie is
+/// `HttpOnly` so page scripts never see the token, and `SameSite=Strict` so
+/// other sites cannot ride on it.
+async fn login(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<LoginParams>,
+) -> Result<Response, AppError> {
+    let cookie_safe = params
+        .token
+        .bytes()
+        .all(|b| b.is_ascii_graphic() && !matches!(b, b'"' | b',' | b';' | b'\\'));
+    if params.token.is_empty() || !cookie_safe {
+        return Err(AppError::Validation(
+            "token cannot be stored in a cookie".to_string(),
+        ));
+    }
+    identify(&state, &params.token)?;
+    let cookie = format!(
+        "{SESSION_COOKIE}={}; Path=/; HttpOnly; SameSite=Strict",
+        params.token
+    );
+    Ok((
+        StatusCode::SEE_OTHER,
+        [
+            (header::LOCATION, "/".to_string()),
+            (header::SET_COOKIE, cookie),
+        ],
+    )
+        .into_response())
+}
+
+/// `/login`, which has to work before the caller holds a session.
+pub(crate) fn public_routes() -> Router<Arc<AppState>> {
+    Router::new().route("/login", get(login))
+}
+
+/// Embedded workspace dashboard, behind the auth middleware like the API it
+/// calls. With `serve.tokens` set, open `/login?token=...` once to get the
+/// session cookie; without tokens, localhost needs nothing.
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new().route("/", get(serve_index))
+}
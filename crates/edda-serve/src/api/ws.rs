@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::extract::ws::rejection::WebSocketUpgradeRejection;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use serde::Deserialize;
+
+use crate::error::AppError;
+use crate::state::AppState;
+
+// ── GET /ws ──
+//
+// Pushes the `edda watch` snapshot (peers, coordination board, agent phases,
+// recent events) as JSON text frames. A frame goes out on connect and then
+// whenever the snapshot changes, so the TUI and web views share one push
+// channel instead of each re-reading heartbeat files.
+//
+// Client data frames are ignored; pings are answered by the protocol layer.
+//
+// CORS does not apply to WebSocket handshakes, so a browser `Origin` must be
+// one of the localhost origins the CORS layer allows for this port; any web
+// page could otherwise stream the workspace from a localhost browser.
+// Clients without an `Origin` (the TUI, curl) are not browsers and pass.
+
+/// Largest client message accepted; clients only send control frames.
+const MAX_CLIENT_MESSAGE: usize = 64 * 1024;
+
+const DEFAULT_EVENTS: usize = 50;
+const DEFAULT_INTERVAL_MS: u64 = 1000;
+const MIN_INTERVAL_MS: u64 = 250;
+
+#[derive(Deserialize)]
+struct WsParams {
+    /// Recent ledger events per snapshot (default 50).
+    events: Option<usize>,
+    /// How often to check for changes, in milliseconds (default 1000, min 250).
+    interval_ms: Option<u64>,
+}
+
+/// Whether the handshake's `Origin`, if any, is a localhost origin of the
+/// port the client connected to (taken from `Host`).
+fn origin_allowed(headers: &HeaderMap) -> bool {
+    let Some(origin) = headers.get(header::ORIGIN) else {
+        return true;
+    };
+    let port = headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|host| host.rsplit_once(':'))
+        .and_then(|(_, port)| port.parse::<u16>().ok());
+    port.is_some_and(|port| crate::localhost_origins(port).contains(origin))
+}
+
+async fn get_ws(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<WsParams>,
+    headers: HeaderMap,
+    ws: Result<WebSocketUpgrade, WebSocketUpgradeRejection>,
+) -> Response {
+    // The origin check comes first, so a foreign page learns nothing more
+    // from a malformed handshake.
+    if !origin_allowed(&headers) {
+        return AppError::Forbidden(
+            "WebSocket connections are only accepted from the local UI".to_string(),
+        )
+        .into_response();
+    }
+    let ws = match ws {
+        Ok(ws) => ws,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    let events = params.events.unwrap_or(DEFAULT_EVENTS);
+    let interval = Duration::from_millis(
+        params
+            .interval_ms
+            .unwrap_or(DEFAULT_INTERVAL_MS)
+            .max(MIN_INTERVAL_MS),
+    );
+    let repo_root = state.repo_root.clone();
+    ws.max_message_size(MAX_CLIENT_MESSAGE)
+        .on_upgrade(move |socket| async move {
+            if let Err(e) = push_snapshots(socket, repo_root, events, interval).await {
+                tracing::debug!(error = %e, "websocket closed");
+            }
+        })
+}
+
+/// One snapshot frame: `{"type":"snapshot","peers":…,"board":…,"events":…,"phases":…}`.
+fn snapshot_frame(repo_root: &std::path::Path, events: usize) -> anyhow::Result<String> {
+    let project_id = edda_store::project_id(repo_root);
+    let data = edda_bridge_claude::watch::snapshot(&project_id, repo_root, events)?;
+    let mut value = serde_json::to_value(&data)?;
+    value["type"] = serde_json::json!("snapshot");
+    Ok(value.to_string())
+}
+
+/// Send a snapshot on connect and on every change until the client leaves.
+async fn push_snapshots(
+    mut socket: WebSocket,
+    repo_root: std::path::PathBuf,
+    events: usize,
+    interval: Duration,
+) -> anyhow::Result<()> {
+    let mut ticker = tokio::time::interval(interval);
+    let mut last = String::new();
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                let root = repo_root.clone();
+                let frame = tokio::task::spawn_blocking(move || snapshot_frame(&root, events))
+                    .await??;
+                if frame != last {
+                    socket.send(Message::Text(frame.clone().into())).await?;
+                    last = frame;
+                }
+            }
+            // `recv` is cancel-safe: a read cut short by the ticker resumes
+            // where it left off.
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Close(_))) | None => return Ok(()),
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+            },
+        }
+    }
+}
+
+/// WebSocket push routes.
+pub(crate) fn routes() -> Router<Arc<AppState>> {
+    Router::new().route("/ws", get(get_ws))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::{HeaderValue, StatusCode};
+
+    #[test]
+    fn only_localhost_origins_of_the_served_port_may_connect() {
+        let headers = |origin: Option<&str>| {
+            let mut h = HeaderMap::new();
+            h.insert(header::HOST, HeaderValue::from_static("localhost:7433"));
+            if let Some(o) = origin {
+                h.insert(header::ORIGIN, HeaderValue::from_str(o).unwrap());
+            }
+            h
+        };
+        assert!(origin_allowed(&headers(None)));
+        assert!(origin_allowed(&headers(Some("http://localhost:7433"))));
+        assert!(origin_allowed(&headers(Some("http://127.0.0.1:7433"))));
+        assert!(!origin_allowed(&headers(Some("https://evil.example"))));
+        assert!(!origin_allowed(&headers(Some("http://localhost:3000"))));
+        assert!(!origin_allowed(&headers(Some("null"))));
+    }
+
+    #[tokio::test]
+    async fn foreign_origin_handshake_is_refused() {
+        use axum::body::Body;
+        use tower::ServiceExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let handshake = |origin: &'static str| {
+            axum::http::Request::builder()
+                .uri("/ws")
+                .header(header::HOST, "127.0.0.1:7433")
+                .header(header::ORIGIN, origin)
+                .header(header::UPGRADE, "websocket")
+                .header(header::CONNECTION, "Upgrade")
+                .header(header::SEC_WEBSOCKET_VERSION, "13")
+                .header(header::SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let resp = crate::router(tmp.path())
+            .oneshot(handshake("https://evil.example"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // The local UI gets past the origin check (and, without a real
+        // connection to upgrade, stops at the handshake).
+        let resp = crate::router(tmp.path())
+            .oneshot(handshake("http://127.0.0.1:7433"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UPGRADE_REQUIRED);
+    }
+}
@@ -18,6 +18,9 @@ pub(crate) enum AppError {
     #[error("{0}")]
     Unauthorized(String),
 
+    #[error("{0}")]
+    Forbidden(String),
+
     #[error("{0}")]
     ServiceUnavailable(String),
 
@@ -25,7 +28,17 @@ pub(crate) enum AppError {
     NotImplemented(String),
 
     #[error("{0}")]
-    Internal(#[from] anyhow::Error),
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for AppError {
+    fn from(err: anyhow::Error) -> Self {
+        // An append the ledger's authorizer refused is the caller's fault.
+        if let Some(denied) = err.downcast_ref::<edda_ledger::AppendDenied>() {
+            return Self::Forbidden(denied.to_string());
+        }
+        Self::Internal(err)
+    }
 }
 
 impl From<serde_json::Error> for AppError {
@@ -59,6 +72,7 @@ impl IntoResponse for AppError {
             AppError::NotFound(_) => (StatusCode::NOT_FOUND, "NOT_FOUND"),
             AppError::Conflict(_) => (StatusCode::CONFLICT, "CONFLICT"),
             AppError::Unauthorized(_) => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
+            AppError::Forbidden(_) => (StatusCode::FORBIDDEN, "FORBIDDEN"),
             AppError::ServiceUnavailable(_) => {
                 (StatusCode::SERVICE_UNAVAILABLE, "SERVICE_UNAVAILABLE")
             }
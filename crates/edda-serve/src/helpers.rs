@@ -1,3 +1,5 @@
+use ring::hmac;
+
 /// Validate that a string looks like a valid ISO 8601 / RFC 3339 timestamp.
 pub(crate) fn validate_iso8601(s: &str) -> Result<(), String> {
     time::OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339)
@@ -10,3 +12,38 @@ pub(crate) fn time_now_rfc3339() -> String {
         .format(&time::format_description::well_known::Rfc3339)
         .expect("RFC3339 formatting should not fail")
 }
+
+/// Entity tag for a read of the ledger: the chain tip, the head
+/// branch and the request's query string. Any append changes the tip, so
+/// the tag stays valid exactly as long as the response would.
+pub(crate) fn ledger_etag(ledger: &edda_ledger::Ledger, query: &str) -> anyhow::Result<String> {
+    let tip = ledger.last_event_hash()?.unwrap_or_else(|| "empty".into());
+    let head = ledger.head_branch()?;
+    let digest = edda_core::hash::sha256_hex(format!("{tip}\n{head}\n{query}").as_bytes());
+    Ok(format!("\"{}\"", &digest[..32]))
+}
+
+/// Whether an `If-None-Match` header matches `etag` (or is `*`).
+pub(crate) fn etag_matches(headers: &axum::http::HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(axum::http::header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|t| t.trim().trim_start_matches("W/"))
+        .any(|t| t == etag || t == "*")
+}
+
+/// HMAC-SHA256 tag of `message` under `key`, hex-encoded.
+pub(crate) fn hmac_sign_hex(key: &[u8], message: &[u8]) -> String {
+    hex::encode(hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, key), message))
+}
+
+/// Whether `tag_hex` is the HMAC-SHA256 tag of `message` under `key`.
+/// `ring` compares the tags in constant time.
+pub(crate) fn hmac_verify_hex(key: &[u8], message: &[u8], tag_hex: &str) -> bool {
+    let Ok(tag) = hex::decode(tag_hex) else {
+        return false;
+    };
+    hmac::verify(&hmac::Key::new(hmac::HMAC_SHA256, key), message, &tag).is_ok()
+}
@@ -47,14 +47,63 @@ pub async fn serve(repo_root: &Path, config: ServeConfig) -> anyhow::Result<()>
         None
     };
 
+    let api_tokens = state::load_api_tokens(&paths.config_json)?;
+    if !api_tokens.is_empty() {
+        eprintln!(
+            "edda HTTP API token auth enabled ({} token(s)); localhost requests need a token too \
+             (dashboard: open /login?token=<token> once)",
+            api_tokens.len()
+        );
+    }
+
+    let slack = api::slack::load_slack_bridge(&paths.config_json)?;
+    if slack.is_some() {
+        eprintln!("edda Slack bridge enabled on /api/slack/events");
+    }
+    let idle_digest = state::load_idle_digest(&paths.config_json)?;
+
     let state = Arc::new(AppState {
         repo_root: repo_root.to_path_buf(),
         chronicle,
         pending_pairings: Mutex::new(HashMap::new()),
+        api_tokens,
+        slack,
     });
+    let app = app(state, config.port);
+
+    let addr = format!("{}:{}", config.bind, config.port);
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+    eprintln!("edda HTTP server listening on http://{addr}");
+
+    // Dropping `stop` ends the sweep and rule delivery, so neither outlives
+    // the server.
+    let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+    let sweep = idle_digest.then(|| spawn_idle_digest(repo_root, stopped));
+    let (stop_delivery, delivery_stopped) = tokio::sync::oneshot::channel::<()>();
+    let delivery = edda_notify::rules::spawn_delivery(repo_root, delivery_stopped);
+    let served = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await;
+    drop(stop);
+    drop(stop_delivery);
+    if let Some(sweep) = sweep {
+        let _ = sweep.await;
+    }
+    let _ = delivery.await;
+    served?;
+    Ok(())
+}
 
+/// Every route `serve` answers, with auth and CORS for the UI on `port`.
+fn app(state: Arc<AppState>, port: u16) -> Router {
     // Public routes (no auth required)
-    let public_routes = api::auth::public_routes().merge(api::events::public_routes());
+    let public_routes = api::auth::public_routes()
+        .merge(api::health::routes())
+        .merge(api::files::public_routes())
+        .merge(api::slack::routes())
+        .merge(api::ui::public_routes());
 
     // Protected routes (auth middleware applied)
     let protected_routes = api::events::protected_routes()
@@ -66,9 +115,15 @@ pub async fn serve(repo_root: &Path, config: ServeConfig) -> anyhow::Result<()>
         .merge(api::dashboard::routes())
         .merge(api::policy::routes())
         .merge(api::briefs::routes())
+        .merge(api::calendar::routes())
+        .merge(api::annotations::routes())
+        .merge(api::sessions::routes())
         .merge(api::stream::routes())
+        .merge(api::ws::routes())
         .merge(api::ingestion::routes())
+        .merge(api::files::protected_routes())
         .merge(api::auth::protected_routes())
+        .merge(api::ui::routes())
         .layer(axum_mw::from_fn_with_state(
             state.clone(),
             middleware::auth_middleware,
@@ -78,35 +133,62 @@ pub async fn serve(repo_root: &Path, config: ServeConfig) -> anyhow::Result<()>
     // development tool; if remote access is needed, consider adding an
     // explicit --cors-origin CLI flag.
     let cors = CorsLayer::new()
-        .allow_origin(AllowOrigin::list([
-            format!("http://127.0.0.1:{}", config.port)
-                .parse()
-                .expect("valid localhost origin"),
-            format!("http://localhost:{}", config.port)
-                .parse()
-                .expect("valid localhost origin"),
-            format!("http://[::1]:{}", config.port)
-                .parse()
-                .expect("valid localhost origin"),
-        ]))
+        .allow_origin(AllowOrigin::list(localhost_origins(port)))
         .allow_methods(tower_http::cors::Any)
         .allow_headers(tower_http::cors::Any);
 
-    let app = Router::new()
+    Router::new()
         .merge(public_routes)
         .merge(protected_routes)
         .layer(cors)
-        .with_state(state);
+        .with_state(state)
+}
 
-    let addr = format!("{}:{}", config.bind, config.port);
-    let listener = tokio::net::TcpListener::bind(&addr).await?;
-    eprintln!("edda HTTP server listening on http://{addr}");
-    axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .await?;
-    Ok(())
+/// Browser origins allowed to call the API: the UI served from this port.
+/// Also checked by the `/ws` handshake, which CORS does not cover.
+pub(crate) fn localhost_origins(port: u16) -> [axum::http::HeaderValue; 3] {
+    ["127.0.0.1", "localhost", "[::1]"].map(|host| {
+        format!("http://{host}:{port}")
+            .parse()
+            .expect("valid localhost origin")
+    })
+}
+
+/// How often the server looks for Claude sessions that went idle.
+const IDLE_DIGEST_EVERY: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Digest sessions whose heartbeat went stale while the server runs, so
+/// their working memory reaches the ledger without a new session starting.
+/// Runs until `stop` resolves or its sender is dropped.
+fn spawn_idle_digest(
+    repo_root: &Path,
+    mut stop: tokio::sync::oneshot::Receiver<()>,
+) -> tokio::task::JoinHandle<()> {
+    let project_id = edda_store::project_id(repo_root);
+    let cwd = repo_root.to_string_lossy().to_string();
+    tokio::spawn(async move {
+        let start = tokio::time::Instant::now() + IDLE_DIGEST_EVERY;
+        let mut ticker = tokio::time::interval_at(start, IDLE_DIGEST_EVERY);
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                _ = &mut stop => return,
+            }
+            let (project_id, cwd) = (project_id.clone(), cwd.clone());
+            let swept = tokio::task::spawn_blocking(move || {
+                edda_bridge_claude::digest::sweep_idle_sessions(&project_id, &cwd)
+            })
+            .await;
+            match swept {
+                Ok(event_ids) => {
+                    for event_id in event_ids {
+                        tracing::info!(%event_id, "digested an idle session");
+                    }
+                }
+                Err(e) => tracing::warn!(error = %e, "idle session sweep failed"),
+            }
+        }
+    })
 }
 
 /// Build the router (for testing without binding to a port).
@@ -126,8 +208,11 @@ fn router(repo_root: &Path) -> Router {
         repo_root: repo_root.to_path_buf(),
         chronicle,
         pending_pairings: Mutex::new(HashMap::new()),
+        api_tokens: Vec::new(),
+        slack: None,
     });
     api::events::routes()
+        .merge(api::health::routes())
         .merge(api::drafts::routes())
         .merge(api::telemetry::routes())
         .merge(api::snapshots::routes())
@@ -136,9 +221,16 @@ fn router(repo_root: &Path) -> Router {
         .merge(api::dashboard::routes())
         .merge(api::policy::routes())
         .merge(api::briefs::routes())
+        .merge(api::calendar::routes())
+        .merge(api::annotations::routes())
+        .merge(api::sessions::routes())
         .merge(api::stream::routes())
+        .merge(api::ws::routes())
         .merge(api::ingestion::routes())
+        .merge(api::files::routes())
         .merge(api::auth::routes())
+        .merge(api::slack::routes())
+        .merge(api::ui::routes())
         .merge(sync_routes())
         .with_state(state)
 }
@@ -296,6 +388,79 @@ mod tests {
         edda_ledger::ledger::init_branches_json(&paths, "main").unwrap();
     }
 
+    #[tokio::test]
+    async fn ready_reports_component_statuses() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/ready")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["ready"], true);
+        let status_of = |name: &str| {
+            json["components"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|c| c["name"] == name)
+                .map(|c| c["status"].as_str().unwrap().to_string())
+                .unwrap()
+        };
+        assert_eq!(status_of("ledger"), "ok");
+        assert_eq!(status_of("schema"), "ok");
+        assert_eq!(status_of("lock"), "ok");
+        assert_ne!(status_of("search_index"), "fail");
+
+        let paths = edda_ledger::EddaPaths::discover(tmp.path());
+        let _held = edda_ledger::WorkspaceLock::acquire(&paths).unwrap();
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/ready")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.status(),
+            StatusCode::OK,
+            "a busy lock is degraded, not down"
+        );
+    }
+
+    #[tokio::test]
+    async fn ready_is_unavailable_without_a_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/ready")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["ready"], false);
+        assert_eq!(json["components"][0]["detail"], "workspace not initialized");
+    }
+
     #[tokio::test]
     async fn health_returns_ok() {
         let tmp = tempfile::tempdir().unwrap();
@@ -488,6 +653,177 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn code_annotations_resolve_against_the_live_ledger() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        std::fs::write(
+            tmp.path().join("db.rs"),
+            "// edda:decision db.engine\nfn open() {}\n",
+        )
+        .unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let decide = |value: &str| {
+            let dp = DecisionPayload {
+                key: "db.engine".into(),
+                value: value.into(),
+                reason: Some(format!("chose {value}")),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            let parent = ledger.last_event_hash().unwrap();
+            let event = new_decision_event("main", parent.as_deref(), "system", &dp).unwrap();
+            ledger.append_event(&event).unwrap();
+        };
+        decide("sqlite");
+        let export =
+            edda_bridge_claude::code_annotations::build_export(&ledger, tmp.path()).unwrap();
+        edda_bridge_claude::code_annotations::write_export(&ledger.paths.edda_dir, &export)
+            .unwrap();
+        decide("postgres");
+
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/code-annotations?path=db.rs&line=2")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let entries = json["entries"].as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["key"], "db.engine");
+        assert_eq!(entries[0]["value"], "postgres");
+        assert_eq!(entries[0]["reason"], "chose postgres");
+
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/code-annotations?line=2")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn calendar_feed_lists_review_dates_and_waiting_plan_gates() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        for (key, review_after) in [("db.engine", Some("2026-06-01")), ("auth.method", None)] {
+            let dp = DecisionPayload {
+                key: key.into(),
+                value: "x".into(),
+                reason: Some("cheap, for now".into()),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: review_after.map(String::from),
+                reversibility: None,
+                village_id: None,
+            };
+            let parent = ledger.last_event_hash().unwrap();
+            let event = new_decision_event("main", parent.as_deref(), "system", &dp).unwrap();
+            ledger.append_event(&event).unwrap();
+        }
+
+        std::fs::write(
+            tmp.path().join("plan.yaml"),
+            "name: ship\nphases:\n  - id: deploy\n    prompt: Deploy\n    check:\n      - type: approval\n        title: Deploy to prod\n        timeout_sec: 7200\n",
+        )
+        .unwrap();
+        let plan = edda_conductor::plan::parser::load_plan(&tmp.path().join("plan.yaml")).unwrap();
+        let mut state = edda_conductor::state::machine::PlanState::from_plan(&plan, "plan.yaml");
+        state.phases[0].status = edda_conductor::state::machine::PhaseStatus::Checking;
+        edda_conductor::state::persist::save_state(tmp.path(), &state).unwrap();
+
+        let parent = ledger.last_event_hash().unwrap();
+        let labels = vec![edda_conductor::check::approval::GATE_LABEL.to_string()];
+        let event =
+            edda_core::event::new_draft_proposed_event(&edda_core::event::DraftProposedParams {
+                branch: "main",
+                parent_hash: parent.as_deref(),
+                draft_id: "drf_gate",
+                created_at: Some("2026-03-01T10:00:00Z"),
+                base_parent_hash: "",
+                title: "Deploy to prod",
+                purpose: "conductor approval gate",
+                contribution: "Deploy to prod",
+                labels: &labels,
+                evidence: &[],
+                auto_preview_lines: &[],
+                route_rule_id: "",
+                stages: &[],
+                policy_require_approval: true,
+                policy_min_approvals: 1,
+                origin: None,
+            })
+            .unwrap();
+        ledger.append_event(&event).unwrap();
+
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/calendar.ics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()["content-type"],
+            "text/calendar; charset=utf-8"
+        );
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let ics = String::from_utf8(body.to_vec()).unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2, "{ics}");
+        assert!(ics.contains("DTSTART;VALUE=DATE:20260601\r\n"));
+        assert!(ics.contains("SUMMARY:Review decision: db.engine\r\n"));
+        assert!(ics.contains("Reason: cheap\\, for now"), "{ics}");
+        assert!(!ics.contains("auth.method"));
+        assert!(ics.contains("UID:gate-drf_gate@edda\r\n"));
+        assert!(ics.contains("DTSTART:20260301T120000Z\r\n"));
+        assert!(ics.lines().all(|l| l.len() <= 75), "{ics}");
+
+        // Once the phase passes, the gate is no longer scheduled.
+        state.phases[0].status = edda_conductor::state::machine::PhaseStatus::Passed;
+        edda_conductor::state::persist::save_state(tmp.path(), &state).unwrap();
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/calendar.ics")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let ics = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+    }
+
     #[tokio::test]
     async fn drafts_returns_empty() {
         let tmp = tempfile::tempdir().unwrap();
@@ -568,6 +904,87 @@ mod tests {
         assert!(json["updated_at"].is_string());
     }
 
+    #[tokio::test]
+    async fn ws_pushes_watch_snapshot_on_connect() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let _lock = STORE_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let store_dir = tmp.path().join("store");
+        std::fs::create_dir_all(&store_dir).unwrap();
+        std::env::set_var("EDDA_STORE_ROOT", &store_dir);
+        let _guard = StoreRootGuard;
+        setup_workspace(tmp.path());
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let note = new_note_event("main", None, "user", "hello ws", &[]).unwrap();
+        ledger.append_event(&note).unwrap();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = router(tmp.path());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let mut conn = tokio::net::TcpStream::connect(addr).await.unwrap();
+        conn.write_all(
+            format!(
+                "GET /ws HTTP/1.1\r\nHost: {addr}\r\nUpgrade: websocket\r\n\
+                 Connection: Upgrade\r\nSec-WebSocket-Version: 13\r\n\
+                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n"
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+
+        let mut head = Vec::new();
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(conn.read_u8().await.unwrap());
+        }
+        let head = String::from_utf8(head).unwrap();
+        assert!(head.starts_with("HTTP/1.1 101"), "{head}");
+        assert!(head.contains("s3pPLMBiTxaQ9kYGzzhZRbK+xOo="));
+
+        let first = conn.read_u8().await.unwrap();
+        assert_eq!(first, 0x81, "unfragmented text frame");
+        let len = match conn.read_u8().await.unwrap() {
+            126 => conn.read_u16().await.unwrap() as usize,
+            127 => conn.read_u64().await.unwrap() as usize,
+            n => n as usize,
+        };
+        let mut payload = vec![0u8; len];
+        conn.read_exact(&mut payload).await.unwrap();
+        let frame: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+        assert_eq!(frame["type"], "snapshot");
+        assert!(frame["peers"].is_array());
+        assert!(frame["phases"]["agents"].is_array());
+        assert_eq!(frame["events"][0]["payload"]["text"], "hello ws");
+
+        // Clients must mask their frames: an unmasked ping gets no pong, the
+        // server drops the connection instead.
+        conn.write_all(&[0x89, 0x02, b'h', b'i']).await.unwrap();
+        let closed = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let Ok(first) = conn.read_u8().await else {
+                    return true;
+                };
+                assert_ne!(first, 0x8A, "unmasked ping must not be answered");
+                if first == 0x88 {
+                    return true;
+                }
+                let len = match conn.read_u8().await.unwrap() {
+                    126 => conn.read_u16().await.unwrap() as usize,
+                    127 => conn.read_u64().await.unwrap() as usize,
+                    n => n as usize,
+                };
+                let mut skip = vec![0u8; len];
+                conn.read_exact(&mut skip).await.unwrap();
+            }
+        })
+        .await
+        .unwrap();
+        assert!(closed);
+    }
+
     #[tokio::test]
     async fn recap_returns_stub_response() {
         let tmp = tempfile::tempdir().unwrap();
@@ -867,6 +1284,51 @@ mod tests {
         assert!(tags.contains(&serde_json::json!("lesson")));
     }
 
+    #[tokio::test]
+    async fn post_note_with_structured_body_renders_in_log() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let app = router(tmp.path());
+
+        let resp = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/note")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({
+                            "text": "migration query",
+                            "body": {"kind": "code", "language": "sql", "code": "SELECT 1;"}
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/log?type=note")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let entry = &json["events"][0];
+        assert_eq!(entry["summary"], "migration query");
+        assert_eq!(entry["body_markdown"], "```sql\nSELECT 1;\n```");
+    }
+
     #[tokio::test]
     async fn post_decide_auto_supersedes_same_key() {
         let tmp = tempfile::tempdir().unwrap();
@@ -892,26 +1354,184 @@ mod tests {
             )
             .await
             .unwrap();
-        assert_eq!(resp1.status(), StatusCode::CREATED);
-        let body1 = axum::body::to_bytes(resp1.into_body(), usize::MAX)
+        assert_eq!(resp1.status(), StatusCode::CREATED);
+        let body1 = axum::body::to_bytes(resp1.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json1: serde_json::Value = serde_json::from_slice(&body1).unwrap();
+        let first_id = json1["event_id"].as_str().unwrap().to_string();
+        assert!(json1.get("superseded").is_none() || json1["superseded"].is_null());
+
+        // Second decide with same key, different value
+        let resp2 = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/decide")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({
+                            "decision": "runtime.spawn=cmd.exe",
+                            "reason": "verified in #598"
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp2.status(), StatusCode::CREATED);
+        let body2 = axum::body::to_bytes(resp2.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json2: serde_json::Value = serde_json::from_slice(&body2).unwrap();
+        assert_eq!(json2["superseded"].as_str().unwrap(), first_id);
+
+        // Verify only the second decision is active
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let active = ledger
+            .find_active_decision("main", "runtime.spawn")
+            .unwrap()
+            .unwrap();
+        assert_eq!(active.value, "cmd.exe");
+    }
+
+    #[tokio::test]
+    async fn post_decide_rejected_on_protected_branch_without_override() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let edda_dir = tmp.path().join(".edda");
+        std::fs::write(
+            edda_dir.join("policy.yaml"),
+            "version: 2\nprotected_branches:\n  - branch: main\n    override_roles: [lead]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            edda_dir.join("actors.yaml"),
+            "version: 1\nactors:\n  alice:\n    roles: [lead]\n",
+        )
+        .unwrap();
+        let app = Router::new().merge(router(tmp.path()));
+
+        let decide = |body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/decide")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        let resp = app
+            .clone()
+            .oneshot(decide(
+                serde_json::json!({ "decision": "db.engine=postgres" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .contains("edda draft propose"));
+
+        let resp = app
+            .oneshot(decide(serde_json::json!({
+                "decision": "db.engine=postgres",
+                "actor": "alice"
+            })))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn post_decide_checks_append_rules_for_the_actor() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let edda_dir = tmp.path().join(".edda");
+        std::fs::write(
+            edda_dir.join("policy.yaml"),
+            "version: 2\nappend_rules:\n  - event_type: decision\n    keys: [\"infra.*\"]\n    roles: [lead]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            edda_dir.join("actors.yaml"),
+            "version: 1\nactors:\n  alice:\n    roles: [lead]\n  bob:\n    roles: [dev]\n",
+        )
+        .unwrap();
+        let app = Router::new().merge(router(tmp.path()));
+
+        let decide = |body: serde_json::Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/decide")
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap()
+        };
+
+        let resp = app
+            .clone()
+            .oneshot(decide(serde_json::json!({
+                "decision": "infra.region=eu-west-1",
+                "actor": "bob"
+            })))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
             .await
             .unwrap();
-        let json1: serde_json::Value = serde_json::from_slice(&body1).unwrap();
-        let first_id = json1["event_id"].as_str().unwrap().to_string();
-        assert!(json1.get("superseded").is_none() || json1["superseded"].is_null());
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(json["error"]
+            .as_str()
+            .unwrap()
+            .contains("requires role lead"));
+
+        for (decision, actor) in [
+            ("db.engine=postgres", "bob"),
+            ("infra.region=eu-west-1", "alice"),
+        ] {
+            let resp = app
+                .clone()
+                .oneshot(decide(serde_json::json!({
+                    "decision": decision,
+                    "actor": actor
+                })))
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), StatusCode::CREATED);
+        }
+    }
 
-        // Second decide with same key, different value
-        let resp2 = app
-            .clone()
+    #[tokio::test]
+    async fn post_events_batch_appends_mixed_items_with_per_item_results() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let app = router(tmp.path());
+
+        let resp = app
             .oneshot(
                 Request::builder()
                     .method("POST")
-                    .uri("/api/decide")
+                    .uri("/api/events/batch")
                     .header("content-type", "application/json")
                     .body(Body::from(
                         serde_json::json!({
-                            "decision": "runtime.spawn=cmd.exe",
-                            "reason": "verified in #598"
+                            "items": [
+                                {"kind": "note", "text": "cache warmed", "tags": ["ops"]},
+                                {"kind": "decision", "decision": "db.engine=sqlite"},
+                                {"kind": "decision", "decision": "no equals sign"},
+                                {"kind": "signal", "text": "flaky test in ci"},
+                                {"kind": "bogus"},
+                                {"kind": "decision", "decision": "db.engine=postgres", "reason": "scale"}
+                            ]
                         })
                         .to_string(),
                     ))
@@ -919,20 +1539,60 @@ mod tests {
             )
             .await
             .unwrap();
-        assert_eq!(resp2.status(), StatusCode::CREATED);
-        let body2 = axum::body::to_bytes(resp2.into_body(), usize::MAX)
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
             .await
             .unwrap();
-        let json2: serde_json::Value = serde_json::from_slice(&body2).unwrap();
-        assert_eq!(json2["superseded"].as_str().unwrap(), first_id);
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["created"], 4);
+        assert_eq!(json["failed"], 2);
+        let results = json["results"].as_array().unwrap();
+        let ok: Vec<bool> = results.iter().map(|r| r["ok"] == true).collect();
+        assert_eq!(ok, [true, true, false, true, false, true]);
+        assert!(results[2]["error"].as_str().unwrap().contains("key=value"));
+        assert_eq!(results[5]["superseded"], results[1]["event_id"]);
 
-        // Verify only the second decision is active
         let ledger = Ledger::open(tmp.path()).unwrap();
+        ledger.verify_chain().unwrap();
         let active = ledger
-            .find_active_decision("main", "runtime.spawn")
+            .find_active_decision("main", "db.engine")
             .unwrap()
             .unwrap();
-        assert_eq!(active.value, "cmd.exe");
+        assert_eq!(active.value, "postgres");
+        let signal = ledger
+            .get_event(results[3]["event_id"].as_str().unwrap())
+            .unwrap()
+            .unwrap();
+        assert_eq!(signal.payload["tags"], serde_json::json!(["todo"]));
+    }
+
+    #[tokio::test]
+    async fn post_events_batch_rejects_empty_and_oversized_batches() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let app = Router::new().merge(router(tmp.path()));
+
+        let batch = |items: Vec<serde_json::Value>| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/events/batch")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "items": items }).to_string(),
+                ))
+                .unwrap()
+        };
+
+        let resp = app.clone().oneshot(batch(vec![])).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let many = vec![serde_json::json!({"kind": "note", "text": "x"}); 101];
+        let resp = app.oneshot(batch(many)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        assert!(ledger.iter_events_by_type("note").unwrap().is_empty());
     }
 
     #[tokio::test]
@@ -1934,6 +2594,35 @@ actors:
         assert!(json["totals"].is_object());
     }
 
+    #[tokio::test]
+    async fn ledger_metrics_report_append_sizes() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let event = new_note_event("main", None, "user", "metrics note", &[]).unwrap();
+        ledger.append_event(&event).unwrap();
+
+        let resp = router(tmp.path())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/metrics/ledger?top=1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["totals"]["count"], 1);
+        assert_eq!(json["by_type"]["note"]["count"], 1);
+        assert!(json["by_type"]["note"]["latency_ms_max"].as_f64().is_some());
+        assert_eq!(json["top_fields"].as_array().unwrap().len(), 1);
+        assert_eq!(json["top_fields"][0]["field"], "note.text");
+    }
+
     #[tokio::test]
     async fn metrics_trends_returns_200() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1999,37 +2688,322 @@ actors:
             .await
             .unwrap();
 
-        assert_eq!(resp.status(), StatusCode::OK);
-        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
-        assert_eq!(json["period"]["days"], 1);
-    }
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["period"]["days"], 1);
+    }
+
+    #[tokio::test]
+    async fn dashboard_html_returns_html() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let app = router(tmp.path());
+
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .uri("/dashboard")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("Edda Dashboard"));
+        assert!(html.contains("/api/dashboard"));
+    }
+
+    #[tokio::test]
+    async fn index_serves_workspace_dashboard() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let app = router(tmp.path());
+
+        let resp = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("Edda Workspace"));
+        for endpoint in [
+            "/api/status",
+            "/api/decisions",
+            "/api/log",
+            "/api/drafts",
+            "/api/peers",
+        ] {
+            assert!(html.contains(endpoint), "page does not call {endpoint}");
+        }
+    }
+
+    #[tokio::test]
+    async fn signed_file_links_serve_packs_until_tampered() {
+        let _lock = STORE_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        std::env::set_var("EDDA_STORE_ROOT", tmp.path().join("store"));
+        let _guard = StoreRootGuard;
+
+        let project_id = edda_store::project_id(tmp.path());
+        edda_store::ensure_dirs(&project_id).unwrap();
+        let packs = edda_store::project_dir(&project_id).join("packs");
+        std::fs::write(packs.join("hot.md"), "# hot\n").unwrap();
+        let app = router(tmp.path());
+
+        let sign = |path: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/files/sign")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "path": path, "ttl_secs": 60 }).to_string(),
+                ))
+                .unwrap()
+        };
+        let resp = app.clone().oneshot(sign("packs/hot.md")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let url = json["url"].as_str().unwrap().to_string();
+        assert!(url.starts_with("/files/packs/hot.md?expires="));
+
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let resp = app.clone().oneshot(get(&url)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers()["content-type"],
+            "text/markdown; charset=utf-8"
+        );
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"# hot\n");
+
+        let tampered = url.replace("hot.md", "warm.md");
+        let resp = app.clone().oneshot(get(&tampered)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let resp = app
+            .clone()
+            .oneshot(get("/files/packs/hot.md"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST, "no signature");
+
+        let resp = app.clone().oneshot(sign("packs/missing.md")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = app.clone().oneshot(sign("packs/../x.md")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn signed_file_links_serve_the_latest_session_recap() {
+        let _lock = STORE_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        std::env::set_var("EDDA_STORE_ROOT", tmp.path().join("store"));
+        let _guard = StoreRootGuard;
+
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        for summary in ["first pass", "second pass"] {
+            let parent = ledger.last_event_hash().unwrap();
+            let event = edda_core::event::new_recap_event(
+                "main",
+                parent.as_deref(),
+                serde_json::json!({ "session_id": "s1", "summary": summary }),
+            )
+            .unwrap();
+            ledger.append_event(&event).unwrap();
+        }
+        let app = router(tmp.path());
+
+        let sign = |path: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/api/files/sign")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::json!({ "path": path }).to_string()))
+                .unwrap()
+        };
+        let resp = app.clone().oneshot(sign("recaps/s1.json")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let url = json["url"].as_str().unwrap().to_string();
+
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let resp = app.clone().oneshot(get(&url)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers()["content-type"], "application/json");
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let recap: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(recap["summary"], "second pass");
+
+        let resp = app.clone().oneshot(sign("recaps/s2.json")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let resp = app.clone().oneshot(sign("recaps/s1.md")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn peers_lists_live_sessions_for_workspace() {
+        let _lock = STORE_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        std::env::set_var("EDDA_STORE_ROOT", tmp.path().join("store"));
+        let _guard = StoreRootGuard;
+
+        let project_id = edda_store::project_id(tmp.path());
+        edda_store::ensure_dirs(&project_id).unwrap();
+        edda_bridge_claude::peers::write_heartbeat_minimal(
+            &project_id,
+            "sess-1",
+            "auth",
+            &tmp.path().to_string_lossy(),
+        );
+        edda_bridge_claude::peers::write_claim(
+            &project_id,
+            "sess-1",
+            "auth",
+            &["src/auth/*".to_string()],
+        );
+
+        let app = router(tmp.path());
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/peers")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let peers = json["peers"].as_array().unwrap();
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0]["label"], "auth");
+        assert_eq!(peers[0]["claimed_paths"], serde_json::json!(["src/auth/*"]));
+    }
+
+    #[tokio::test]
+    async fn sessions_join_heartbeats_indexes_packs_and_recaps() {
+        let _lock = STORE_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        std::env::set_var("EDDA_STORE_ROOT", tmp.path().join("store"));
+        let _guard = StoreRootGuard;
+
+        let project_id = edda_store::project_id(tmp.path());
+        edda_store::ensure_dirs(&project_id).unwrap();
+        let proj_dir = edda_store::project_dir(&project_id);
+        edda_bridge_claude::peers::write_heartbeat_minimal(
+            &project_id,
+            "live-1",
+            "auth",
+            &tmp.path().to_string_lossy(),
+        );
+        std::fs::write(proj_dir.join("index").join("done-1.jsonl"), "").unwrap();
+        std::fs::write(
+            proj_dir.join("transcripts").join("done-1.jsonl"),
+            serde_json::json!({
+                "type": "assistant",
+                "message": { "usage": { "input_tokens": 10, "output_tokens": 5 }, "content": [] }
+            })
+            .to_string()
+                + "\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(proj_dir.join("packs")).unwrap();
+        std::fs::write(proj_dir.join("packs").join("hot.md"), "# hot\n").unwrap();
+        std::fs::write(
+            proj_dir.join("packs").join("hot.meta.json"),
+            serde_json::json!({
+                "project_id": project_id,
+                "session_id": "done-1",
+                "git_branch": "main",
+                "turn_count": 3,
+                "budget_chars": 1000,
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        let recap = edda_core::event::new_recap_event(
+            "main",
+            ledger.last_event_hash().unwrap().as_deref(),
+            serde_json::json!({ "session_id": "done-1", "what_happened": "shipped" }),
+        )
+        .unwrap();
+        ledger.append_event(&recap).unwrap();
+
+        let get = |uri: &str| {
+            let app = router(tmp.path());
+            let uri = uri.to_string();
+            async move {
+                let resp = app
+                    .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                let status = resp.status();
+                let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                (
+                    status,
+                    serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+                )
+            }
+        };
+
+        let (status, json) = get("/api/sessions").await;
+        assert_eq!(status, StatusCode::OK);
+        let ids: Vec<&str> = json["sessions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["session_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, ["live-1", "done-1"]);
+        assert_eq!(json["sessions"][0]["live"], true);
+        assert_eq!(json["sessions"][1]["has_recap"], true);
 
-    #[tokio::test]
-    async fn dashboard_html_returns_html() {
-        let tmp = tempfile::tempdir().unwrap();
-        setup_workspace(tmp.path());
-        let app = router(tmp.path());
+        let (status, json) = get("/api/sessions/done-1").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["pack"]["turn_count"], 3);
+        assert_eq!(json["usage"]["input_tokens"], 10);
+        assert_eq!(json["recap"]["what_happened"], "shipped");
+        assert!(json.get("heartbeat").is_none());
 
-        let resp = app
-            .oneshot(
-                Request::builder()
-                    .uri("/dashboard")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
+        let (status, json) = get("/api/sessions/live-1").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["heartbeat"]["label"], "auth");
+        assert!(json.get("pack").is_none());
 
-        assert_eq!(resp.status(), StatusCode::OK);
-        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let html = String::from_utf8(body.to_vec()).unwrap();
-        assert!(html.contains("Edda Dashboard"));
-        assert!(html.contains("/api/dashboard"));
+        let (status, _) = get("/api/sessions/nope").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
     }
 
     // ── Actor endpoint tests ──
@@ -2465,71 +3439,52 @@ actors:
         assert_eq!(json["approval"], "none");
     }
 
-    /// Helper: write a minimal draft JSON file into the workspace drafts dir.
-    fn write_test_draft(dir: &Path, draft_id: &str, status: &str, with_stages: bool) {
-        let paths = edda_ledger::EddaPaths::discover(dir);
-        std::fs::create_dir_all(&paths.drafts_dir).unwrap();
-        let draft = if with_stages {
-            serde_json::json!({
-                "version": 1,
-                "draft_id": draft_id,
-                "created_at": "2026-03-01T00:00:00Z",
-                "branch": "main",
-                "base_parent_hash": "",
-                "title": "test draft",
-                "purpose": "testing",
-                "contribution": "test",
-                "labels": ["auth", "risk:medium"],
-                "evidence": [],
-                "auto_preview_lines": [],
-                "event_preview": {},
-                "status": status,
-                "approvals": [],
-                "applied_commit_id": "",
-                "policy_require_approval": true,
-                "policy_min_approvals": 1,
-                "stages": [{
-                    "stage_id": "lead",
-                    "role": "lead",
-                    "min_approvals": 1,
-                    "approved_by": [],
-                    "status": "pending",
-                    "assignees": []
+    /// Helper: propose a minimal draft on the workspace ledger.
+    fn write_test_draft(dir: &Path, draft_id: &str, with_stages: bool) {
+        let ledger = edda_ledger::Ledger::open(dir).unwrap();
+        let (title, labels, stages) = if with_stages {
+            (
+                "test draft",
+                vec!["auth".to_string(), "risk:medium".to_string()],
+                vec![edda_core::event::DraftStageSpec {
+                    stage_id: "lead".into(),
+                    role: "lead".into(),
+                    min_approvals: 1,
+                    assignees: vec![],
                 }],
-                "route_rule_id": ""
-            })
+            )
         } else {
-            serde_json::json!({
-                "version": 1,
-                "draft_id": draft_id,
-                "created_at": "2026-03-01T00:00:00Z",
-                "branch": "main",
-                "base_parent_hash": "",
-                "title": "test draft flat",
-                "purpose": "testing",
-                "contribution": "test",
-                "labels": [],
-                "evidence": [],
-                "auto_preview_lines": [],
-                "event_preview": {},
-                "status": status,
-                "approvals": [],
-                "applied_commit_id": "",
-                "policy_require_approval": true,
-                "policy_min_approvals": 1,
-                "stages": [],
-                "route_rule_id": ""
-            })
+            ("test draft flat", vec![], vec![])
         };
-        let path = paths.drafts_dir.join(format!("{draft_id}.json"));
-        std::fs::write(&path, serde_json::to_string_pretty(&draft).unwrap()).unwrap();
+        let parent_hash = ledger.last_event_hash().unwrap();
+        let event =
+            edda_core::event::new_draft_proposed_event(&edda_core::event::DraftProposedParams {
+                branch: "main",
+                parent_hash: parent_hash.as_deref(),
+                draft_id,
+                created_at: Some("2026-03-01T00:00:00Z"),
+                base_parent_hash: "",
+                title,
+                purpose: "testing",
+                contribution: "test",
+                labels: &labels,
+                evidence: &[],
+                auto_preview_lines: &[],
+                route_rule_id: "",
+                stages: &stages,
+                policy_require_approval: true,
+                policy_min_approvals: 1,
+                origin: None,
+            })
+            .unwrap();
+        ledger.append_event(&event).unwrap();
     }
 
     #[tokio::test]
     async fn get_drafts_enriched_fields() {
         let tmp = tempfile::tempdir().unwrap();
         setup_workspace(tmp.path());
-        write_test_draft(tmp.path(), "drf_enrich", "proposed", true);
+        write_test_draft(tmp.path(), "drf_enrich", true);
 
         let app = router(tmp.path());
         let resp = app
@@ -2563,7 +3518,7 @@ actors:
     async fn post_draft_approve_creates_event() {
         let tmp = tempfile::tempdir().unwrap();
         setup_workspace(tmp.path());
-        write_test_draft(tmp.path(), "drf_app1", "proposed", true);
+        write_test_draft(tmp.path(), "drf_app1", true);
 
         let app = router(tmp.path());
         let resp = app
@@ -2599,7 +3554,7 @@ actors:
     async fn post_draft_approve_replay_protection() {
         let tmp = tempfile::tempdir().unwrap();
         setup_workspace(tmp.path());
-        write_test_draft(tmp.path(), "drf_replay", "proposed", true);
+        write_test_draft(tmp.path(), "drf_replay", true);
 
         // First approval
         let app1 = router(tmp.path());
@@ -2650,7 +3605,7 @@ actors:
     async fn post_draft_deny_creates_event() {
         let tmp = tempfile::tempdir().unwrap();
         setup_workspace(tmp.path());
-        write_test_draft(tmp.path(), "drf_deny1", "proposed", true);
+        write_test_draft(tmp.path(), "drf_deny1", true);
 
         let app = router(tmp.path());
         let resp = app
@@ -2709,7 +3664,7 @@ actors:
     async fn post_draft_approve_with_device_id() {
         let tmp = tempfile::tempdir().unwrap();
         setup_workspace(tmp.path());
-        write_test_draft(tmp.path(), "drf_devid", "proposed", true);
+        write_test_draft(tmp.path(), "drf_devid", true);
 
         let app = router(tmp.path());
         let resp = app
@@ -3135,6 +4090,103 @@ actors:
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
     }
 
+    // ── POST /api/ask tests ──
+
+    async fn post_json(
+        app: Router,
+        uri: &str,
+        body: serde_json::Value,
+    ) -> (StatusCode, serde_json::Value) {
+        let resp = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = resp.status();
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn ask_accepts_full_options_and_why_mode() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        for value in ["sqlite", "postgres"] {
+            let parent_hash = ledger.last_event_hash().unwrap();
+            let dp = DecisionPayload {
+                key: "db.engine".into(),
+                value: value.into(),
+                reason: Some("embedded".into()),
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            let event = new_decision_event("main", parent_hash.as_deref(), "user", &dp).unwrap();
+            ledger.append_event(&event).unwrap();
+        }
+        drop(ledger);
+
+        let (status, json) = post_json(
+            router(tmp.path()),
+            "/api/ask",
+            serde_json::json!({
+                "q": "db.engine",
+                "include_superseded": true,
+                "branch": "main",
+                "semantic": false,
+                "transcripts": false
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{json}");
+        assert_eq!(json["input_type"], "exact_key");
+        assert_eq!(json["timeline"].as_array().unwrap().len(), 2);
+
+        let (status, json) = post_json(
+            router(tmp.path()),
+            "/api/ask",
+            serde_json::json!({ "q": "db.engine", "mode": "why" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{json}");
+        assert!(!json["rationale"].as_array().unwrap().is_empty(), "{json}");
+    }
+
+    #[tokio::test]
+    async fn ask_rejects_bad_mode_and_why_without_key() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+
+        let (status, _) = post_json(
+            router(tmp.path()),
+            "/api/ask",
+            serde_json::json!({ "q": "db", "mode": "deep" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, json) = post_json(
+            router(tmp.path()),
+            "/api/ask",
+            serde_json::json!({ "q": "some free text", "mode": "why" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{json}");
+    }
+
     // ── POST /api/decisions/batch tests ──
 
     #[tokio::test]
@@ -3682,6 +4734,11 @@ actors:
 
     /// Build a production-like app with auth middleware for testing.
     fn app_with_auth(repo_root: &Path) -> Router {
+        app_with_api_tokens(repo_root, Vec::new())
+    }
+
+    /// Like [`app_with_auth`], with `serve.tokens` configured.
+    fn app_with_api_tokens(repo_root: &Path, api_tokens: Vec<state::ApiToken>) -> Router {
         let store_root = edda_store::store_root();
         let chronicle = if store_root.exists() {
             Some(ChronicleContext {
@@ -3695,9 +4752,11 @@ actors:
             repo_root: repo_root.to_path_buf(),
             chronicle,
             pending_pairings: Mutex::new(HashMap::new()),
+            api_tokens,
+            slack: None,
         });
 
-        let public_routes = api::events::public_routes();
+        let public_routes = api::health::routes();
 
         let protected_routes = api::events::protected_routes().layer(axum_mw::from_fn_with_state(
             state.clone(),
@@ -3924,6 +4983,247 @@ actors:
         assert_eq!(resp.status(), StatusCode::OK);
     }
 
+    fn api_token(name: &str, raw: &str, role: state::TokenRole) -> state::ApiToken {
+        state::ApiToken {
+            name: name.to_string(),
+            token_hash: hash_token(raw),
+            role,
+        }
+    }
+
+    /// Build a localhost POST /api/note request, optionally with a bearer token.
+    fn localhost_note_request(token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/api/note")
+            .header("content-type", "application/json");
+        if let Some(token) = token {
+            builder = builder.header("authorization", format!("Bearer {token}"));
+        }
+        let mut req = builder
+            .body(Body::from(r#"{"text":"from token"}"#))
+            .unwrap();
+        req.extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 12345))));
+        req
+    }
+
+    #[tokio::test]
+    async fn api_tokens_require_auth_from_localhost() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let tokens = vec![api_token("dash", "read-secret", state::TokenRole::ReadOnly)];
+
+        let app = app_with_api_tokens(tmp.path(), tokens.clone());
+        let resp = app.oneshot(localhost_request("/api/status")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let app = app_with_api_tokens(tmp.path(), tokens);
+        let mut req = localhost_request("/api/status");
+        req.headers_mut()
+            .insert("authorization", "Bearer read-secret".parse().unwrap());
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn api_read_only_token_cannot_write() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let tokens = vec![
+            api_token("dash", "read-secret", state::TokenRole::ReadOnly),
+            api_token("ci", "write-secret", state::TokenRole::ReadWrite),
+        ];
+
+        let app = app_with_api_tokens(tmp.path(), tokens.clone());
+        let resp = app
+            .oneshot(localhost_note_request(Some("read-secret")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["code"], "FORBIDDEN");
+        assert!(json["error"].as_str().unwrap().contains("'dash'"));
+
+        let app = app_with_api_tokens(tmp.path(), tokens);
+        let resp = app
+            .oneshot(localhost_note_request(Some("write-secret")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn api_read_only_token_allows_query_posts() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let app = app_with_api_tokens(
+            tmp.path(),
+            vec![api_token("dash", "read-secret", state::TokenRole::ReadOnly)],
+        );
+
+        let mut req = Request::builder()
+            .method("POST")
+            .uri("/api/decisions/batch")
+            .header("content-type", "application/json")
+            .header("authorization", "Bearer read-secret")
+            .body(Body::from(r#"{"queries":[{"q":"db"}]}"#))
+            .unwrap();
+        req.extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from(([203, 0, 113, 1], 12345))));
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn dashboard_and_ws_work_with_api_tokens_after_login() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(AppState {
+            repo_root: tmp.path().to_path_buf(),
+            chronicle: None,
+            pending_pairings: Mutex::new(HashMap::new()),
+            api_tokens: vec![api_token("dash", "read-secret", state::TokenRole::ReadOnly)],
+            slack: None,
+        });
+        let app = app(state, addr.port());
+
+        let get = |uri: &str, cookie: Option<&str>| {
+            let mut builder = Request::builder().uri(uri);
+            if let Some(cookie) = cookie {
+                builder = builder.header("cookie", cookie);
+            }
+            let mut req = builder.body(Body::empty()).unwrap();
+            req.extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 12345))));
+            req
+        };
+
+        let resp = app.clone().oneshot(get("/", None)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let resp = app
+            .clone()
+            .oneshot(get("/login?token=wrong", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = app
+            .clone()
+            .oneshot(get("/login?token=read-secret", None))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+        assert_eq!(resp.headers()["location"], "/");
+        let set_cookie = resp.headers()["set-cookie"].to_str().unwrap().to_string();
+        assert!(set_cookie.contains("HttpOnly"), "{set_cookie}");
+        let cookie = set_cookie.split(';').next().unwrap().to_string();
+        assert_eq!(cookie, "edda_token=read-secret");
+
+        for uri in ["/", "/api/status"] {
+            let resp = app.clone().oneshot(get(uri, Some(&cookie))).await.unwrap();
+            assert_eq!(resp.status(), StatusCode::OK, "{uri}");
+        }
+
+        tokio::spawn(async move {
+            axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap()
+        });
+        // The browser sends the session cookie with the handshake, from the
+        // dashboard's own origin.
+        let handshake = |cookie: Option<&str>| {
+            let cookie = cookie
+                .map(|c| format!("Cookie: {c}\r\n"))
+                .unwrap_or_default();
+            format!(
+                "GET /ws HTTP/1.1\r\nHost: {addr}\r\nOrigin: http://127.0.0.1:{}\r\n\
+                 Upgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Version: 13\r\n\
+                 Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\n{cookie}\r\n",
+                addr.port()
+            )
+        };
+        for (cookie, status) in [(None, "401"), (Some(cookie.as_str()), "101")] {
+            let mut conn = tokio::net::TcpStream::connect(addr).await.unwrap();
+            conn.write_all(handshake(cookie).as_bytes()).await.unwrap();
+            let mut head = Vec::new();
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(conn.read_u8().await.unwrap());
+            }
+            let head = String::from_utf8(head).unwrap();
+            assert!(head.starts_with(&format!("HTTP/1.1 {status}")), "{head}");
+        }
+    }
+
+    #[test]
+    fn load_api_tokens_from_config() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.json");
+        assert!(state::load_api_tokens(&path).unwrap().is_empty());
+
+        let hash = hash_token("ci-secret");
+        std::fs::write(
+            &path,
+            serde_json::json!({
+                "serve": { "tokens": [
+                    { "name": "dash", "token": "dash-secret" },
+                    { "name": "ci", "token_hash": hash.to_uppercase(), "role": "read_write" }
+                ]}
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let tokens = state::load_api_tokens(&path).unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].token_hash, hash_token("dash-secret"));
+        assert_eq!(tokens[0].role, state::TokenRole::ReadOnly);
+        assert_eq!(tokens[1].token_hash, hash);
+        assert_eq!(tokens[1].role, state::TokenRole::ReadWrite);
+
+        std::fs::write(
+            &path,
+            r#"{"serve":{"tokens":[{"name":"bad","token_hash":"abc"}]}}"#,
+        )
+        .unwrap();
+        let err = state::load_api_tokens(&path).unwrap_err().to_string();
+        assert!(err.contains("'bad'"), "{err}");
+    }
+
+    #[test]
+    fn idle_digest_is_off_unless_configured() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.json");
+        assert!(!state::load_idle_digest(&path).unwrap());
+        std::fs::write(&path, r#"{"serve":{"files":{}}}"#).unwrap();
+        assert!(!state::load_idle_digest(&path).unwrap());
+        std::fs::write(&path, r#"{"serve":{"idle_digest":true}}"#).unwrap();
+        assert!(state::load_idle_digest(&path).unwrap());
+        std::fs::write(&path, r#"{"serve":{"idle_digest":"yes"}}"#).unwrap();
+        assert!(state::load_idle_digest(&path).is_err());
+    }
+
+    #[tokio::test]
+    async fn idle_digest_sweep_ends_with_the_server() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let sweep = spawn_idle_digest(tmp.path(), stopped);
+        drop(stop);
+        tokio::time::timeout(Duration::from_secs(5), sweep)
+            .await
+            .expect("sweep stops once the server is gone")
+            .unwrap();
+    }
+
     // ── Telemetry endpoint tests ──
 
     fn sample_telemetry_body(cycle_id: &str) -> serde_json::Value {
@@ -4724,6 +6024,8 @@ actors:
             repo_root: repo_root.to_path_buf(),
             chronicle: None,
             pending_pairings: Mutex::new(HashMap::new()),
+            api_tokens: Vec::new(),
+            slack: None,
         });
         api::events::routes()
             .merge(api::drafts::routes())
@@ -4734,6 +6036,8 @@ actors:
             .merge(api::dashboard::routes())
             .merge(api::policy::routes())
             .merge(api::briefs::routes())
+            .merge(api::annotations::routes())
+            .merge(api::sessions::routes())
             .merge(api::stream::routes())
             .merge(api::ingestion::routes())
             .merge(api::auth::routes())
@@ -5069,6 +6373,8 @@ actors:
             repo_root: tmp.path().to_path_buf(),
             chronicle,
             pending_pairings: Mutex::new(HashMap::new()),
+            api_tokens: Vec::new(),
+            slack: None,
         });
 
         let make_app = || {
@@ -5542,6 +6848,144 @@ actors:
         assert!(events[0]["summary"].as_str().unwrap().contains("alpha"));
     }
 
+    #[tokio::test]
+    async fn log_pages_with_cursor_and_answers_conditional_gets() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        for i in 0..5 {
+            let parent_hash = ledger.last_event_hash().unwrap();
+            let note = new_note_event(
+                "main",
+                parent_hash.as_deref(),
+                "user",
+                &format!("n{i}"),
+                &[],
+            )
+            .unwrap();
+            ledger.append_event(&note).unwrap();
+        }
+
+        let get = |uri: String, etag: Option<String>| {
+            let mut req = Request::builder().uri(uri);
+            if let Some(tag) = etag {
+                req = req.header("if-none-match", tag);
+            }
+            router(tmp.path()).oneshot(req.body(Body::empty()).unwrap())
+        };
+        let json_of = |resp: axum::response::Response| async move {
+            let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+        };
+
+        let first = get("/api/log?limit=2".into(), None).await.unwrap();
+        assert_eq!(first.status(), StatusCode::OK);
+        let etag = first.headers()["etag"].to_str().unwrap().to_string();
+        let page = json_of(first).await;
+        assert_eq!(page["total"], 5);
+        let summaries = |page: &serde_json::Value| -> Vec<String> {
+            page["events"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|e| e["summary"].as_str().unwrap().to_string())
+                .collect()
+        };
+        assert_eq!(summaries(&page), vec!["n4", "n3"]);
+
+        let cursor = page["next_cursor"].as_str().unwrap().to_string();
+        let second = json_of(
+            get(format!("/api/log?limit=2&cursor={cursor}"), None)
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(summaries(&second), vec!["n2", "n1"]);
+        let cursor = second["next_cursor"].as_str().unwrap().to_string();
+        let last = json_of(
+            get(format!("/api/log?limit=2&cursor={cursor}"), None)
+                .await
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(summaries(&last), vec!["n0"]);
+        assert!(last["next_cursor"].is_null());
+
+        let unchanged = get("/api/log?limit=2".into(), Some(etag.clone()))
+            .await
+            .unwrap();
+        assert_eq!(unchanged.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(unchanged.headers()["etag"], etag.as_str());
+
+        let parent_hash = ledger.last_event_hash().unwrap();
+        let note = new_note_event("main", parent_hash.as_deref(), "user", "n5", &[]).unwrap();
+        ledger.append_event(&note).unwrap();
+        let changed = get("/api/log?limit=2".into(), Some(etag)).await.unwrap();
+        assert_eq!(changed.status(), StatusCode::OK);
+
+        let bad = get("/api/log?cursor=evt_missing".into(), None)
+            .await
+            .unwrap();
+        assert_eq!(bad.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn decisions_page_with_cursor_and_report_total() {
+        let tmp = tempfile::tempdir().unwrap();
+        setup_workspace(tmp.path());
+
+        let ledger = Ledger::open(tmp.path()).unwrap();
+        for i in 0..3 {
+            let dp = DecisionPayload {
+                key: format!("paging.k{i}"),
+                value: "v".to_string(),
+                reason: None,
+                scope: None,
+                authority: None,
+                affected_paths: None,
+                tags: None,
+                review_after: None,
+                reversibility: None,
+                village_id: None,
+            };
+            let parent_hash = ledger.last_event_hash().unwrap();
+            let event = new_decision_event("main", parent_hash.as_deref(), "system", &dp).unwrap();
+            ledger.append_event(&event).unwrap();
+        }
+        drop(ledger);
+
+        let mut keys = Vec::new();
+        let mut uri = "/api/decisions?limit=2".to_string();
+        let mut pages = 0;
+        loop {
+            let resp = router(tmp.path())
+                .oneshot(Request::builder().uri(&uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert!(resp.headers().contains_key("etag"));
+            let body = axum::body::to_bytes(resp.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            assert_eq!(json["total"], 3);
+            pages += 1;
+            for d in json["decisions"].as_array().unwrap() {
+                keys.push(d["key"].as_str().unwrap().to_string());
+            }
+            match json["next_cursor"].as_str() {
+                Some(cursor) => uri = format!("/api/decisions?limit=2&cursor={cursor}"),
+                None => break,
+            }
+        }
+        assert_eq!(pages, 2);
+        keys.sort();
+        assert_eq!(keys, vec!["paging.k0", "paging.k1", "paging.k2"]);
+    }
+
     // ── Telemetry Source Filter Test (GH-374, Step 8) ──
 
     #[tokio::test]
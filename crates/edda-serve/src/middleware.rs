@@ -2,13 +2,38 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 use axum::extract::{ConnectInfo, State};
-use axum::http::Request;
+use axum::http::{Method, Request};
 use axum::middleware::Next;
 use axum::response::Response;
 use edda_ledger::device_token::hash_token;
 
 use crate::error::AppError;
-use crate::state::AppState;
+use crate::state::{AppState, TokenRole};
+
+/// POST endpoints that only evaluate queries and never write, so read-only
+/// tokens may call them.
+const READ_ONLY_POST_PATHS: &[&str] = &[
+    "/api/decisions/batch",
+    "/api/ask",
+    "/api/scope/check",
+    "/api/authz/check",
+    "/api/approval/check",
+];
+
+/// Cookie the dashboard authenticates with, set by `GET /login?token=...`.
+/// Browsers send it with `/ws` upgrades, which cannot carry a bearer header.
+pub(crate) const SESSION_COOKIE: &str = "edda_token";
+
+/// Name of the API token that authenticated a request, stored as a request
+/// extension so write handlers can attribute and authorize by token.
+#[derive(Debug, Clone)]
+pub(crate) struct ApiCaller(pub String);
+
+/// Who a raw token belongs to.
+pub(crate) enum Credential<'a> {
+    Api(&'a crate::state::ApiToken),
+    Device,
+}
 
 /// Check if a socket address is localhost.
 pub(crate) fn is_localhost(addr: &SocketAddr) -> bool {
@@ -36,41 +61,82 @@ pub(crate) fn generate_pairing_token() -> String {
     hex::encode(bytes)
 }
 
-/// Auth middleware: localhost passes through, remote needs Bearer token.
+/// Whether a request can be served to a read-only token.
+pub(crate) fn is_read_request(method: &Method, path: &str) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+        || (*method == Method::POST && READ_ONLY_POST_PATHS.contains(&path))
+}
+
+/// Extract the raw token from an `Authorization: Bearer <token>` header,
+/// or else from the [`SESSION_COOKIE`].
+fn request_token(req: &Request<axum::body::Body>) -> Result<&str, AppError> {
+    let headers = req.headers();
+    let bearer = headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "));
+    let cookie = || {
+        headers
+            .get_all("cookie")
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(';'))
+            .find_map(|c| c.trim().strip_prefix(SESSION_COOKIE)?.strip_prefix('='))
+    };
+    bearer.or_else(cookie).ok_or_else(|| {
+        AppError::Unauthorized("missing or invalid Authorization header".to_string())
+    })
+}
+
+/// Look up a raw API or paired device token.
+pub(crate) fn identify<'a>(state: &'a AppState, raw: &str) -> Result<Credential<'a>, AppError> {
+    let token_hash = hash_token(raw);
+    if let Some(token) = state.api_tokens.iter().find(|t| t.token_hash == token_hash) {
+        return Ok(Credential::Api(token));
+    }
+    // Paired device tokens keep full access.
+    match state.open_ledger()?.validate_device_token(&token_hash)? {
+        Some(_) => Ok(Credential::Device),
+        None => Err(AppError::Unauthorized(
+            "invalid or revoked device token".to_string(),
+        )),
+    }
+}
+
+/// Auth middleware.
+///
+/// Without configured API tokens: localhost passes through, remote needs a
+/// paired device token. With `serve.tokens` configured, every caller
+/// (localhost included, since the box may be shared) must present either an
+/// API token or a device token; read-only API tokens are limited to
+/// [`is_read_request`]. The token comes from a bearer header or, for the
+/// dashboard and its `/ws` stream, the [`SESSION_COOKIE`].
 pub(crate) async fn auth_middleware(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<Arc<AppState>>,
-    req: Request<axum::body::Body>,
+    mut req: Request<axum::body::Body>,
     next: Next,
 ) -> Result<Response, AppError> {
-    // Localhost: always allowed (backward compat)
-    if is_localhost(&addr) {
+    // Localhost: allowed unless API tokens are configured (backward compat)
+    if state.api_tokens.is_empty() && is_localhost(&addr) {
         return Ok(next.run(req).await);
     }
 
-    // Remote: check Authorization header
-    let auth_header = req
-        .headers()
-        .get("authorization")
-        .and_then(|v| v.to_str().ok());
-
-    let raw_token = match auth_header {
-        Some(h) if h.starts_with("Bearer ") => &h[7..],
-        _ => {
-            return Err(AppError::Unauthorized(
-                "missing or invalid Authorization header".to_string(),
-            ));
+    let caller = match identify(&state, request_token(&req)?)? {
+        Credential::Api(token) => {
+            if token.role == TokenRole::ReadOnly && !is_read_request(req.method(), req.uri().path())
+            {
+                return Err(AppError::Forbidden(format!(
+                    "token '{}' is read-only",
+                    token.name
+                )));
+            }
+            Some(ApiCaller(token.name.clone()))
         }
+        Credential::Device => None,
     };
-
-    let token_hash = hash_token(raw_token);
-    let ledger = state.open_ledger()?;
-    let device = ledger.validate_device_token(&token_hash)?;
-
-    match device {
-        Some(_) => Ok(next.run(req).await),
-        None => Err(AppError::Unauthorized(
-            "invalid or revoked device token".to_string(),
-        )),
+    if let Some(caller) = caller {
+        req.extensions_mut().insert(caller);
     }
+    Ok(next.run(req).await)
 }
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use edda_ledger::device_token::hash_token;
 use edda_ledger::Ledger;
+use serde::Deserialize;
 
 // ── Config ──
 
@@ -11,12 +13,108 @@ pub struct ServeConfig {
     pub port: u16,
 }
 
+/// Access granted by an API token.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TokenRole {
+    /// Safe methods (GET/HEAD/OPTIONS) and read-only query endpoints.
+    #[default]
+    ReadOnly,
+    ReadWrite,
+}
+
+/// A bearer token accepted by the HTTP API, normalized to its hash.
+#[derive(Debug, Clone)]
+pub(crate) struct ApiToken {
+    pub(crate) name: String,
+    pub(crate) token_hash: String,
+    pub(crate) role: TokenRole,
+}
+
+/// One entry of `serve.tokens` in `.edda/config.json`. Either the raw
+/// `token` or its SHA-256 `token_hash` (hex) must be given.
+#[derive(Deserialize)]
+struct ApiTokenEntry {
+    name: String,
+    #[serde(default)]
+    token: Option<String>,
+    #[serde(default)]
+    token_hash: Option<String>,
+    #[serde(default)]
+    role: TokenRole,
+}
+
+/// Load API tokens from `.edda/config.json` key `serve.tokens`.
+///
+/// A missing file or key means token auth is disabled. Unlike most config
+/// readers this fails on malformed entries: silently dropping a token list
+/// would open the API instead of locking it down.
+pub(crate) fn load_api_tokens(config_path: &Path) -> anyhow::Result<Vec<ApiToken>> {
+    let content = match std::fs::read_to_string(config_path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let config: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", config_path.display()))?;
+    let Some(tokens_val) = config.get("serve").and_then(|s| s.get("tokens")) else {
+        return Ok(Vec::new());
+    };
+    let entries: Vec<ApiTokenEntry> = serde_json::from_value(tokens_val.clone())
+        .map_err(|e| anyhow::anyhow!("invalid serve.tokens in config.json: {e}"))?;
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let token_hash = match (entry.token, entry.token_hash) {
+                (Some(raw), None) if !raw.is_empty() => hash_token(&raw),
+                (None, Some(hash))
+                    if hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()) =>
+                { hash.to_ascii_lowercase()
+                }
+                _ => anyhow::bail!(
+                    "serve.tokens entry '{}' needs exactly one of `token` or a 64-char hex `token_hash`",
+                    entry.name
+                ),
+            };
+            Ok(ApiToken {
+                name: entry.name,
+                token_hash,
+                role: entry.role,
+            })
+        })
+        .collect()
+}
+
+/// Whether `serve.idle_digest` in `.edda/config.json` is `true`, turning on
+/// the idle-session sweep. Off when the file or key is missing.
+pub(crate) fn load_idle_digest(config_path: &Path) -> anyhow::Result<bool> {
+    let content = match std::fs::read_to_string(config_path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+    let config: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{}: {e}", config_path.display()))?;
+    match config.get("serve").and_then(|s| s.get("idle_digest")) {
+        None => Ok(false),
+        Some(v) => v.as_bool().ok_or_else(|| {
+            anyhow::anyhow!("serve.idle_digest in config.json must be true or false")
+        }),
+    }
+}
+
 // ── App State ──
 
 pub(crate) struct AppState {
     pub(crate) repo_root: PathBuf,
     pub(crate) chronicle: Option<ChronicleContext>,
     pub(crate) pending_pairings: Mutex<HashMap<String, PairingRequest>>,
+    /// Configured API tokens. When non-empty, every protected request
+    /// (localhost included) must present one of these or a paired device token.
+    pub(crate) api_tokens: Vec<ApiToken>,
+    /// The Slack input bridge, when `slack` is configured.
+    pub(crate) slack: Option<crate::api::slack::SlackBridge>,
 }
 
 pub(crate) struct PairingRequest {
@@ -29,7 +127,12 @@ pub(crate) struct ChronicleContext {
 }
 
 impl AppState {
+    /// Open the ledger with no actor: the server's own `EDDA_ACTOR` must not
+    /// speak for HTTP callers. Write handlers set the caller's identity.
     pub(crate) fn open_ledger(&self) -> Result<Ledger, crate::error::AppError> {
-        Ledger::open(&self.repo_root).map_err(crate::error::classify_open_error)
+        let mut ledger =
+            Ledger::open(&self.repo_root).map_err(crate::error::classify_open_error)?;
+        ledger.set_actor(None);
+        Ok(ledger)
     }
 }
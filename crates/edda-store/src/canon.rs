@@ -0,0 +1,313 @@
+//! Path canonicalization behind [`crate::project_id`].
+//!
+//! One checkout must hash to one project ID however it is reached, or its
+//! data splits across several stores. The rules:
+//!
+//! - **Symlinks are followed.** A repo reached through a symlinked directory
+//!   shares the ID of its target. When the path does not exist (yet), the
+//!   deepest existing ancestor is resolved and the rest appended as given.
+//! - **Windows paths take one form.** `\\?\C:\x`, `C:\x`, `c:/x` and the MSYS
+//!   form `/c/x` are the same path, as are `\\?\UNC\srv\share\x` and
+//!   `\\srv\share\x`. All of them are written in the verbatim form
+//!   `std::fs::canonicalize` returns, so IDs of existing directories are
+//!   unchanged from before these rules.
+//! - **Case folds where the filesystem does.** Windows paths are lowercased.
+//!   On macOS each component takes the case stored on disk. Elsewhere case is
+//!   significant and kept.
+//! - `.`, `..`, repeated and trailing separators are removed lexically.
+//!
+//! Stores split under older rules are joined with
+//! `edda store merge-duplicates` (see [`crate::duplicates`]).
+
+use std::path::{Component, Path, PathBuf};
+
+/// Which platform's path rules [`normalize_str`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathStyle {
+    Unix,
+    Windows,
+}
+
+impl PathStyle {
+    /// The rules of the platform edda runs on.
+    pub fn native() -> Self {
+        if cfg!(windows) {
+            Self::Windows
+        } else {
+            Self::Unix
+        }
+    }
+}
+
+/// The string a path hashes to for its project ID.
+pub fn canonical_path(p: &Path) -> String {
+    let resolved = resolve_symlinks(p);
+    #[cfg(target_os = "macos")]
+    let resolved = on_disk_case(&resolved);
+    normalize_str(&resolved.to_string_lossy(), PathStyle::native())
+}
+
+/// Make `p` absolute and resolve every symlink in its existing part.
+pub fn resolve_symlinks(p: &Path) -> PathBuf {
+    let abs = if p.is_absolute() {
+        p.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map(|cwd| cwd.join(p))
+            .unwrap_or_else(|_| p.to_path_buf())
+    };
+    let mut existing = abs.as_path();
+    let mut tail = Vec::new();
+    loop {
+        if let Ok(resolved) = existing.canonicalize() {
+            return tail.iter().rev().fold(resolved, |acc, name| acc.join(name));
+        }
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                tail.push(name.to_os_string());
+                existing = parent;
+            }
+            _ => return abs,
+        }
+    }
+}
+
+/// Rewrite each component of `p` to the case stored on disk, for
+/// case-insensitive filesystems where `/Users/Me/Repo` and `/users/me/repo`
+/// open the same directory. A component is left alone when it exists as
+/// written, does not exist, or matches more than one entry.
+pub fn on_disk_case(p: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in p.components() {
+        let Component::Normal(name) = component else {
+            out.push(component);
+            continue;
+        };
+        let on_disk = std::fs::read_dir(&out).ok().and_then(|entries| {
+            let names: Vec<_> = entries.flatten().map(|e| e.file_name()).collect();
+            if names.iter().any(|n| n == name) {
+                return None;
+            }
+            let wanted = name.to_string_lossy().to_lowercase();
+            let mut matches = names
+                .into_iter()
+                .filter(|n| n.to_string_lossy().to_lowercase() == wanted);
+            match (matches.next(), matches.next()) {
+                (Some(one), None) => Some(one),
+                _ => None,
+            }
+        });
+        out.push(on_disk.as_deref().unwrap_or(name));
+    }
+    out
+}
+
+/// Normalize a path string under `style`'s rules, without touching the
+/// filesystem.
+pub fn normalize_str(raw: &str, style: PathStyle) -> String {
+    match style {
+        PathStyle::Unix => {
+            let (root, rest) = match raw.strip_prefix('/') {
+                Some(rest) => ("/".to_string(), rest),
+                None => (String::new(), raw),
+            };
+            join_clean(root, rest)
+        }
+        PathStyle::Windows => {
+            let s = raw.replace('\\', "/").to_lowercase();
+            let verbatim = ["//?/", "//./"].iter().find_map(|p| s.strip_prefix(p));
+            let (root, rest) = match verbatim {
+                Some(v) => match v.strip_prefix("unc/") {
+                    Some(unc) => unc_root(unc),
+                    // `\\?\Volume{guid}\...` and other device paths.
+                    None => drive_root(v).unwrap_or_else(|| ("//?/".to_string(), v)),
+                },
+                None => match s.strip_prefix("//") {
+                    Some(unc) => unc_root(unc),
+                    None => drive_root(&s)
+                        .or_else(|| s.strip_prefix('/').and_then(drive_root_msys))
+                        .unwrap_or_else(|| match s.strip_prefix('/') {
+                            Some(rest) => ("/".to_string(), rest),
+                            None => (String::new(), s.as_str()),
+                        }),
+                },
+            };
+            join_clean(root, rest)
+        }
+    }
+}
+
+/// `server/share/rest` → (`//?/unc/server/share/`, `rest`).
+fn unc_root(unc: &str) -> (String, &str) {
+    let mut parts = unc.trim_start_matches('/').splitn(3, '/');
+    let server = parts.next().unwrap_or_default();
+    let share = parts.next().unwrap_or_default();
+    (
+        format!("//?/unc/{server}/{share}/"),
+        parts.next().unwrap_or_default(),
+    )
+}
+
+/// `c:/rest` → (`//?/c:/`, `rest`).
+fn drive_root(s: &str) -> Option<(String, &str)> {
+    match s.as_bytes() {
+        [d, b':'] | [d, b':', b'/', ..] if d.is_ascii_alphabetic() => Some((
+            format!("//?/{}:/", *d as char),
+            s.get(3..).unwrap_or_default(),
+        )),
+        _ => None,
+    }
+}
+
+/// MSYS/Git Bash `c/rest` (after the leading `/`) → (`//?/c:/`, `rest`).
+fn drive_root_msys(s: &str) -> Option<(String, &str)> {
+    match s.as_bytes() {
+        [d] | [d, b'/', ..] if d.is_ascii_alphabetic() => Some((
+            format!("//?/{}:/", *d as char),
+            s.get(2..).unwrap_or_default(),
+        )),
+        _ => None,
+    }
+}
+
+/// Append `rest` to `root`, dropping empty and `.` components and applying
+/// `..`. A `..` never climbs above the root.
+fn join_clean(root: String, rest: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for part in rest.split('/') {
+        match part {
+            "" | "." => {}
+            ".." if parts.last().is_some_and(|p| *p != "..") => {
+                parts.pop();
+            }
+            ".." if !root.is_empty() => {}
+            _ => parts.push(part),
+        }
+    }
+    root + &parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_spellings_of_one_path_agree() {
+        let same = [
+            r"\\?\C:\Users\Me\Repo",
+            r"C:\Users\Me\Repo",
+            r"c:/users/me/repo/",
+            r"C:\Users\Me\.\Work\..\Repo",
+            "/c/Users/Me/Repo",
+            r"\\.\C:\Users\Me\Repo",
+        ];
+        for raw in same {
+            assert_eq!(
+                normalize_str(raw, PathStyle::Windows),
+                "//?/c:/users/me/repo",
+                "{raw}"
+            );
+        }
+        assert_eq!(normalize_str(r"C:\", PathStyle::Windows), "//?/c:/");
+        assert_eq!(normalize_str("C:", PathStyle::Windows), "//?/c:/");
+        assert_eq!(normalize_str("/c", PathStyle::Windows), "//?/c:/");
+    }
+
+    #[test]
+    fn windows_unc_spellings_agree() {
+        for raw in [
+            r"\\?\UNC\Server\Share\Repo",
+            r"\\server\share\repo",
+            "//SERVER/Share/Repo/",
+        ] {
+            assert_eq!(
+                normalize_str(raw, PathStyle::Windows),
+                "//?/unc/server/share/repo",
+                "{raw}"
+            );
+        }
+        // `..` stops at the share.
+        assert_eq!(
+            normalize_str(r"\\srv\share\..\..\x", PathStyle::Windows),
+            "//?/unc/srv/share/x"
+        );
+    }
+
+    #[test]
+    fn windows_ids_match_the_old_canonicalize_form() {
+        // Before these rules: canonicalize, lowercase, `\` → `/`.
+        let old = |canonical: &str| canonical.to_lowercase().replace('\\', "/");
+        for canonical in [r"\\?\C:\Users\Me\Repo", r"\\?\UNC\srv\share\repo"] {
+            assert_eq!(normalize_str(canonical, PathStyle::Windows), old(canonical));
+        }
+    }
+
+    #[test]
+    fn windows_other_forms_are_kept() {
+        assert_eq!(
+            normalize_str(r"\\?\Volume{AB-12}\Repo", PathStyle::Windows),
+            "//?/volume{ab-12}/repo"
+        );
+        // Rooted on the current drive, and relative.
+        assert_eq!(
+            normalize_str(r"\work\repo", PathStyle::Windows),
+            "/work/repo"
+        );
+        assert_eq!(normalize_str(r"..\repo", PathStyle::Windows), "../repo");
+    }
+
+    #[test]
+    fn unix_paths_are_cleaned_but_keep_case() {
+        assert_eq!(
+            normalize_str("/home/Me//Repo/./src/../", PathStyle::Unix),
+            "/home/Me/Repo"
+        );
+        assert_eq!(normalize_str("/../x", PathStyle::Unix), "/x");
+        assert_eq!(normalize_str("/", PathStyle::Unix), "/");
+        assert_eq!(normalize_str("a/../../b", PathStyle::Unix), "../b");
+        // Backslashes are file name characters on Unix.
+        assert_eq!(normalize_str(r"/srv/a\b", PathStyle::Unix), r"/srv/a\b");
+    }
+
+    #[test]
+    fn missing_paths_resolve_through_their_existing_ancestor() {
+        let tmp = tempfile::tempdir().unwrap();
+        let missing = tmp.path().join("not").join("yet");
+        assert_eq!(
+            resolve_symlinks(&missing),
+            tmp.path().canonicalize().unwrap().join("not").join("yet")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_repo_roots_canonicalize_to_their_target() {
+        let tmp = tempfile::tempdir().unwrap();
+        let real = tmp.path().join("real");
+        std::fs::create_dir_all(&real).unwrap();
+        let link = tmp.path().join("link");
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        assert_eq!(canonical_path(&link), canonical_path(&real));
+        assert_eq!(
+            canonical_path(&link.join("new")),
+            canonical_path(&real.join("new"))
+        );
+    }
+
+    #[test]
+    fn on_disk_case_takes_the_stored_name() {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().canonicalize().unwrap();
+        std::fs::create_dir_all(base.join("Repo").join("Src")).unwrap();
+
+        // Exact names and missing components are left as written.
+        assert_eq!(on_disk_case(&base.join("Repo")), base.join("Repo"));
+        assert_eq!(on_disk_case(&base.join("gone")), base.join("gone"));
+        // A unique case-insensitive match takes the stored case.
+        assert_eq!(
+            on_disk_case(&base.join("repo").join("src")),
+            base.join("Repo").join("Src")
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Join project stores split by path spelling.
+//!
+//! Before [`crate::canon`], a repo reached through a symlink, another
+//! drive-letter case or a UNC spelling could hash to a second project ID and
+//! start a second store. The registry remembers the path behind each ID, so
+//! an entry whose path now hashes to a different ID names a store to fold
+//! into the canonical one.
+
+use crate::registry::{load_registry, registry_lock_path, save_registry};
+use crate::{lock_file, project_dir, project_id, project_subdir, RELOCATABLE_SUBDIRS};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Registered project IDs that all belong to one checkout.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DuplicateGroup {
+    /// The ID the path hashes to now.
+    pub project_id: String,
+    pub path: String,
+    /// Older IDs registered for the same path.
+    pub duplicates: Vec<String>,
+}
+
+/// What [`merge_duplicates`] did (or would do, on a dry run) for one group.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MergeReport {
+    pub project_id: String,
+    pub path: String,
+    /// Duplicate IDs fully folded in and dropped from the registry.
+    pub merged: Vec<String>,
+    pub moved_files: usize,
+    /// Files already present with the same content; the duplicate copy is
+    /// deleted.
+    pub identical_files: usize,
+    /// Files present in both stores with different content. They stay in the
+    /// duplicate's store, which is kept until they are resolved by hand.
+    pub conflicts: Vec<PathBuf>,
+}
+
+/// Registry entries whose path now hashes to another project ID, grouped by
+/// that ID. Entries whose path is gone are skipped: without the checkout
+/// there is nothing to hash.
+pub fn find_duplicates() -> Vec<DuplicateGroup> {
+    let reg = load_registry();
+    let mut groups: BTreeMap<String, DuplicateGroup> = BTreeMap::new();
+    for entry in reg.projects.values() {
+        let path = Path::new(&entry.path);
+        if !path.exists() {
+            continue;
+        }
+        let canonical = project_id(path);
+        if canonical == entry.project_id {
+            continue;
+        }
+        let group = groups
+            .entry(canonical.clone())
+            .or_insert_with(|| DuplicateGroup {
+                path: reg
+                    .projects
+                    .get(&canonical)
+                    .map_or_else(|| entry.path.clone(), |e| e.path.clone()),
+                project_id: canonical,
+                duplicates: Vec::new(),
+            });
+        group.duplicates.push(entry.project_id.clone());
+    }
+    groups.into_values().collect()
+}
+
+/// Move the duplicates' store data into the canonical project and fix up the
+/// registry. With `dry_run`, only count what would move.
+///
+/// Relocated subdirectories (`project_storage`) are honored on both sides.
+/// Run it with no agent sessions open: the bridges write to these stores.
+pub fn merge_duplicates(group: &DuplicateGroup, dry_run: bool) -> anyhow::Result<MergeReport> {
+    let mut report = MergeReport {
+        project_id: group.project_id.clone(),
+        path: group.path.clone(),
+        ..Default::default()
+    };
+    let target = project_dir(&group.project_id);
+
+    for dup in &group.duplicates {
+        let conflicts_before = report.conflicts.len();
+        let source = project_dir(dup);
+        for sub in RELOCATABLE_SUBDIRS {
+            let relocated = project_subdir(dup, sub);
+            if relocated != source.join(sub) {
+                merge_tree(
+                    &relocated,
+                    &project_subdir(&group.project_id, sub),
+                    dry_run,
+                    &mut report,
+                )?;
+            }
+        }
+        if let Ok(entries) = fs::read_dir(&source) {
+            for entry in entries.flatten() {
+                let name = entry.file_name();
+                let dest = match name.to_str() {
+                    Some(sub) if RELOCATABLE_SUBDIRS.contains(&sub) => {
+                        project_subdir(&group.project_id, sub)
+                    }
+                    _ => target.join(&name),
+                };
+                merge_tree(&entry.path(), &dest, dry_run, &mut report)?;
+            }
+        }
+        if !dry_run {
+            let _ = fs::remove_dir(&source);
+        }
+        if report.conflicts.len() == conflicts_before {
+            report.merged.push(dup.clone());
+        }
+    }
+
+    if !dry_run {
+        let _lock = lock_file(&registry_lock_path())?;
+        let mut reg = load_registry();
+        for dup in &report.merged {
+            let Some(old) = reg.projects.remove(dup) else {
+                continue;
+            };
+            let entry = reg
+                .projects
+                .entry(group.project_id.clone())
+                .or_insert_with(|| crate::registry::ProjectEntry {
+                    project_id: group.project_id.clone(),
+                    path: group.path.clone(),
+                    ..old.clone()
+                });
+            if entry.group.is_none() {
+                entry.group = old.group;
+            }
+            if old.registered_at < entry.registered_at {
+                entry.registered_at = old.registered_at;
+            }
+        }
+        save_registry(&reg)?;
+    }
+    Ok(report)
+}
+
+/// Fold `src` into `dst`: files missing from `dst` move, identical ones are
+/// dropped, differing ones are reported and left in place.
+fn merge_tree(
+    src: &Path,
+    dst: &Path,
+    dry_run: bool,
+    report: &mut MergeReport,
+) -> anyhow::Result<()> {
+    let Ok(meta) = fs::symlink_metadata(src) else {
+        return Ok(());
+    };
+    if meta.is_dir() {
+        if !dry_run {
+            fs::create_dir_all(dst)?;
+        }
+        for entry in fs::read_dir(src)?.flatten() {
+            merge_tree(&entry.path(), &dst.join(entry.file_name()), dry_run, report)?;
+        }
+        if !dry_run {
+            let _ = fs::remove_dir(src);
+        }
+    } else if !dst.exists() {
+        if !dry_run {
+            move_file(src, dst)?;
+        }
+        report.moved_files += 1;
+    } else if same_content(src, dst) {
+        if !dry_run {
+            fs::remove_file(src)?;
+        }
+        report.identical_files += 1;
+    } else {
+        report.conflicts.push(src.to_path_buf());
+    }
+    Ok(())
+}
+
+/// Rename, falling back to copy + delete across filesystems (relocated
+/// subdirectories may live on another disk).
+fn move_file(src: &Path, dst: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(src, dst).is_err() {
+        fs::copy(src, dst)?;
+        fs::remove_file(src)?;
+    }
+    Ok(())
+}
+
+fn same_content(a: &Path, b: &Path) -> bool {
+    let len = |p: &Path| fs::metadata(p).map(|m| m.len()).ok();
+    len(a) == len(b) && matches!((fs::read(a), fs::read(b)), (Ok(x), Ok(y)) if x == y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{get_project, ProjectEntry, Registry};
+
+    #[test]
+    fn split_stores_fold_into_the_canonical_project() {
+        let _guard = crate::ENV_STORE_LOCK.lock().unwrap();
+        let store = tempfile::tempdir().unwrap();
+        std::env::set_var("EDDA_STORE_ROOT", store.path());
+
+        let repo = tempfile::tempdir().unwrap();
+        let canonical = project_id(repo.path());
+        let dup = "0123456789abcdef0123456789abcdef".to_string();
+        let mut reg = Registry::default();
+        reg.projects.insert(
+            dup.clone(),
+            ProjectEntry {
+                project_id: dup.clone(),
+                path: repo.path().to_string_lossy().to_string(),
+                name: "repo".into(),
+                registered_at: "2025-01-01T00:00:00Z".into(),
+                last_seen: "2025-06-01T00:00:00Z".into(),
+                group: Some("team".into()),
+            },
+        );
+        save_registry(&reg).unwrap();
+
+        let write = |id: &str, rel: &str, body: &str| {
+            let path = project_dir(id).join(rel);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, body).unwrap();
+        };
+        write(&dup, "transcripts/s1.jsonl", "old session\n");
+        write(&dup, "state/same.json", "{}");
+        write(&dup, "state/clash.json", "1");
+        write(&canonical, "state/same.json", "{}");
+        write(&canonical, "state/clash.json", "2");
+
+        let groups = find_duplicates();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].project_id, canonical);
+        assert_eq!(groups[0].duplicates, vec![dup.clone()]);
+
+        let dry = merge_duplicates(&groups[0], true).unwrap();
+        assert_eq!((dry.moved_files, dry.identical_files), (1, 1));
+        assert_eq!(dry.conflicts.len(), 1);
+        assert!(project_dir(&dup).join("transcripts/s1.jsonl").exists());
+
+        // The clash keeps the duplicate store and its registry entry.
+        let report = merge_duplicates(&groups[0], false).unwrap();
+        assert!(report.merged.is_empty());
+        assert_eq!(
+            fs::read_to_string(project_dir(&canonical).join("transcripts/s1.jsonl")).unwrap(),
+            "old session\n"
+        );
+        assert!(get_project(&dup).is_some());
+
+        fs::remove_file(project_dir(&dup).join("state/clash.json")).unwrap();
+        let report = merge_duplicates(&find_duplicates()[0], false).unwrap();
+        assert_eq!(report.merged, vec![dup.clone()]);
+        assert!(!project_dir(&dup).exists());
+        assert!(get_project(&dup).is_none());
+        let entry = get_project(&canonical).unwrap();
+        assert_eq!(entry.group.as_deref(), Some("team"));
+        assert_eq!(entry.registered_at, "2025-01-01T00:00:00Z");
+        assert!(find_duplicates().is_empty());
+
+        std::env::remove_var("EDDA_STORE_ROOT");
+    }
+}
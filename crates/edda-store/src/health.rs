@@ -0,0 +1,485 @@
+//! Per-project store health.
+//!
+//! `state/health.json` is a snapshot of store sizes, ingest freshness, index
+//! lag and lock contention. The bridge refreshes it after transcript ingest
+//! (throttled), and `edda store doctor` recomputes it on demand to flag
+//! anomalies.
+//!
+//! This module only knows the on-disk naming conventions of the store
+//! (`transcripts/{sid}.jsonl`, `index/{sid}.jsonl`,
+//! `state/transcript_cursor.{sid}.json`, `state/session.{sid}.json`); it does
+//! not depend on the crates that write those files.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// File name of the health snapshot inside `state/`.
+pub const HEALTH_FILE: &str = "health.json";
+
+/// File name of the lock contention counters (next to the lock files).
+pub const LOCK_CONTENTION_FILE: &str = "lock_contention.json";
+
+/// A session with a heartbeat younger than this is considered running.
+const RUNNING_HEARTBEAT_SECS: u64 = 120;
+
+/// Ingest older than this while the session is running counts as stalled.
+const STALLED_INGEST_SECS: i64 = 600;
+
+/// Index lag is only reported once ingest has been quiet this long, so a
+/// snapshot taken mid-ingest does not raise a false alarm.
+const INDEX_LAG_GRACE_SECS: i64 = 60;
+
+/// Contended acquisitions of one lock kind before the doctor mentions it.
+const CONTENTION_WARN_COUNT: u64 = 100;
+
+/// `MAX_PATH` on Windows, in UTF-16 units. Rust's own file APIs get past it,
+/// but git, editors and backup tools without long-path support do not.
+const WINDOWS_MAX_PATH: usize = 260;
+
+const SUBDIRS: &[&str] = &["ledger", "transcripts", "index", "packs", "state", "search"];
+
+// ── Snapshot ──
+
+/// Health of a single session's transcript store and index.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionHealth {
+    pub session_id: String,
+    /// Size of `transcripts/{sid}.jsonl`.
+    pub store_bytes: u64,
+    /// Size of `index/{sid}.jsonl`.
+    pub index_bytes: u64,
+    /// End offset (`store_offset + store_len`) of the last index record.
+    pub indexed_through: u64,
+    /// Store bytes not yet covered by the index.
+    pub index_lag_bytes: u64,
+    /// `updated_at_unix` of the transcript cursor, if ingest ever ran.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_ingest_unix: Option<i64>,
+    /// Age of the session heartbeat file, if one exists.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heartbeat_age_secs: Option<u64>,
+}
+
+/// Snapshot written to `state/health.json`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoreHealth {
+    pub project_id: String,
+    pub generated_at: String,
+    pub generated_at_unix: i64,
+    /// Bytes per store subdirectory (`ledger`, `transcripts`, ...).
+    pub dir_bytes: BTreeMap<String, u64>,
+    pub total_bytes: u64,
+    /// Most recent ingest across all sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_ingest_unix: Option<i64>,
+    /// Sum of per-session index lag.
+    pub index_lag_bytes: u64,
+    /// Contended lock acquisitions, keyed by lock kind (e.g. `ingest`).
+    pub lock_contention: BTreeMap<String, u64>,
+    /// Longest file path under the store, in UTF-16 units as Windows counts.
+    #[serde(default)]
+    pub longest_path: usize,
+    pub sessions: Vec<SessionHealth>,
+}
+
+/// Collect a health snapshot for the project at `project_dir`.
+pub fn collect(project_dir: &Path, project_id: &str) -> anyhow::Result<StoreHealth> {
+    let now = time::OffsetDateTime::now_utc();
+    let now_unix = now.unix_timestamp();
+
+    let mut dir_bytes = BTreeMap::new();
+    let mut longest_path = 0;
+    for sub in SUBDIRS {
+        dir_bytes.insert(
+            sub.to_string(),
+            dir_size(&crate::store_subdir(project_dir, sub), &mut longest_path),
+        );
+    }
+    let total_bytes = dir_bytes.values().sum();
+
+    let transcripts = crate::store_subdir(project_dir, "transcripts");
+    let index = project_dir.join("index");
+    let state = project_dir.join("state");
+
+    let mut session_ids = BTreeSet::new();
+    session_ids.extend(ids_in(&transcripts, "", ".jsonl"));
+    session_ids.extend(ids_in(&index, "", ".jsonl"));
+    session_ids.extend(ids_in(&state, "transcript_cursor.", ".json"));
+
+    let sessions: Vec<SessionHealth> = session_ids
+        .into_iter()
+        .map(|sid| {
+            let store_bytes = file_len(&transcripts.join(format!("{sid}.jsonl")));
+            let index_path = index.join(format!("{sid}.jsonl"));
+            let indexed_through = last_index_end(&index_path).unwrap_or(0);
+            SessionHealth {
+                store_bytes,
+                index_bytes: file_len(&index_path),
+                indexed_through,
+                index_lag_bytes: store_bytes.saturating_sub(indexed_through),
+                last_ingest_unix: cursor_updated_at(&state, &sid),
+                heartbeat_age_secs: file_age_secs(&state.join(format!("session.{sid}.json"))),
+                session_id: sid,
+            }
+        })
+        .collect();
+
+    Ok(StoreHealth {
+        project_id: project_id.to_string(),
+        generated_at: now
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_default(),
+        generated_at_unix: now_unix,
+        dir_bytes,
+        total_bytes,
+        last_ingest_unix: sessions.iter().filter_map(|s| s.last_ingest_unix).max(),
+        index_lag_bytes: sessions.iter().map(|s| s.index_lag_bytes).sum(),
+        lock_contention: read_contention(&state),
+        longest_path,
+        sessions,
+    })
+}
+
+/// Collect a snapshot and write it to `state/health.json`.
+pub fn write_health(project_dir: &Path, project_id: &str) -> anyhow::Result<StoreHealth> {
+    let health = collect(project_dir, project_id)?;
+    let path = project_dir.join("state").join(HEALTH_FILE);
+    crate::write_atomic(&path, &serde_json::to_vec_pretty(&health)?)?;
+    Ok(health)
+}
+
+/// Rewrite `state/health.json` if it is missing or older than `max_age_secs`.
+///
+/// Returns `true` when the snapshot was rewritten. Meant for hot paths such
+/// as the post-ingest hook, where a fresh snapshot per call is wasted work.
+pub fn refresh_if_stale(
+    project_dir: &Path,
+    project_id: &str,
+    max_age_secs: u64,
+) -> anyhow::Result<bool> {
+    let path = project_dir.join("state").join(HEALTH_FILE);
+    if file_age_secs(&path).is_some_and(|age| age < max_age_secs) {
+        return Ok(false);
+    }
+    write_health(project_dir, project_id)?;
+    Ok(true)
+}
+
+/// Read the last written snapshot, if any.
+pub fn read_health(project_dir: &Path) -> Option<StoreHealth> {
+    let content = fs::read_to_string(project_dir.join("state").join(HEALTH_FILE)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// ── Diagnosis ──
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warn,
+    Error,
+}
+
+/// One anomaly found by [`diagnose`].
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    /// Stable machine-readable code, e.g. `index_ahead_of_store`.
+    pub code: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    pub message: String,
+}
+
+/// Flag anomalies in a snapshot, most severe first.
+pub fn diagnose(health: &StoreHealth) -> Vec<Finding> {
+    let now = health.generated_at_unix;
+    let mut findings = Vec::new();
+
+    for s in &health.sessions {
+        let sid = Some(s.session_id.clone());
+        let ingest_age = s.last_ingest_unix.map(|t| now - t);
+
+        if s.indexed_through > s.store_bytes {
+            findings.push(Finding {
+                severity: Severity::Error,
+                code: "index_ahead_of_store",
+                session_id: sid.clone(),
+                message: format!(
+                    "index references bytes up to {} but the transcript store is {} bytes; \
+                     the store was truncated or replaced after indexing \
+                     (repair with `edda index sweep --project <id> --rebuild`)",
+                    s.indexed_through, s.store_bytes
+                ),
+            });
+        } else if s.index_lag_bytes > 0 && ingest_age.is_none_or(|age| age >= INDEX_LAG_GRACE_SECS)
+        {
+            findings.push(Finding {
+                severity: Severity::Warn,
+                code: "index_lag",
+                session_id: sid.clone(),
+                message: format!(
+                    "{} store bytes are not covered by the index",
+                    s.index_lag_bytes
+                ),
+            });
+        }
+
+        let running = s
+            .heartbeat_age_secs
+            .is_some_and(|age| age < RUNNING_HEARTBEAT_SECS);
+        if let Some(age) = ingest_age.filter(|age| running && *age >= STALLED_INGEST_SECS) {
+            findings.push(Finding {
+                severity: Severity::Warn,
+                code: "ingest_stalled",
+                session_id: sid,
+                message: format!(
+                    "session is running but its transcript was last ingested {}s ago",
+                    age
+                ),
+            });
+        }
+    }
+
+    for (kind, count) in &health.lock_contention {
+        if *count >= CONTENTION_WARN_COUNT {
+            findings.push(Finding {
+                severity: Severity::Warn,
+                code: "lock_contention",
+                session_id: None,
+                message: format!("`{kind}` lock was contended {count} times"),
+            });
+        }
+    }
+
+    if health.longest_path > WINDOWS_MAX_PATH {
+        findings.push(Finding {
+            severity: Severity::Warn,
+            code: "long_path",
+            session_id: None,
+            message: format!(
+                "the longest store path is {} characters, over the Windows limit of {}; \
+                 tools without long-path support cannot open it (move the store with \
+                 `store_root`, or enable long paths in Windows)",
+                health.longest_path, WINDOWS_MAX_PATH
+            ),
+        });
+    }
+
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+    findings
+}
+
+// ── Lock contention ──
+
+/// Count one contended acquisition of the lock at `lock_path`.
+///
+/// Counters live in `lock_contention.json` next to the lock, keyed by the
+/// lock kind (`ingest.{sid}.lock` → `ingest`). Best-effort: concurrent
+/// updates may drop a count, and errors are ignored.
+pub(crate) fn record_contention(lock_path: &Path) {
+    let Some(dir) = lock_path.parent() else {
+        return;
+    };
+    let kind = lock_kind(lock_path);
+    let mut counts = read_contention(dir);
+    *counts.entry(kind).or_insert(0) += 1;
+    if let Ok(data) = serde_json::to_vec_pretty(&counts) {
+        let _ = crate::write_atomic(&dir.join(LOCK_CONTENTION_FILE), &data);
+    }
+}
+
+fn lock_kind(lock_path: &Path) -> String {
+    let name = lock_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    name.split('.').next().unwrap_or(&name).to_string()
+}
+
+fn read_contention(dir: &Path) -> BTreeMap<String, u64> {
+    fs::read_to_string(dir.join(LOCK_CONTENTION_FILE))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+// ── Helpers ──
+
+/// Bytes under `dir`, tracking the longest path seen in `longest`.
+fn dir_size(dir: &Path, longest: &mut usize) -> u64 {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|e| {
+            let path = e.path();
+            let len = path.as_os_str().to_string_lossy().encode_utf16().count();
+            *longest = (*longest).max(len);
+            match e.metadata() {
+                Ok(m) if m.is_dir() => dir_size(&path, longest),
+                Ok(m) => m.len(),
+                Err(_) => 0,
+            }
+        })
+        .sum()
+}
+
+fn file_len(path: &Path) -> u64 {
+    fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+fn file_age_secs(path: &Path) -> Option<u64> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    Some(
+        SystemTime::now()
+            .duration_since(modified)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    )
+}
+
+/// Session ids from file names shaped `{prefix}{sid}{suffix}`.
+fn ids_in(dir: &Path, prefix: &str, suffix: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            let sid = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            (!sid.is_empty()).then(|| sid.to_string())
+        })
+        .collect()
+}
+
+fn cursor_updated_at(state_dir: &Path, sid: &str) -> Option<i64> {
+    let content =
+        fs::read_to_string(state_dir.join(format!("transcript_cursor.{sid}.json"))).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+    value.get("updated_at_unix")?.as_i64()
+}
+
+/// End offset of the last record in an index file, reading only its tail.
+fn last_index_end(path: &Path) -> Option<u64> {
+    const TAIL: u64 = 64 * 1024;
+    let mut file = fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    file.seek(SeekFrom::Start(len.saturating_sub(TAIL))).ok()?;
+    let mut buf = String::new();
+    file.read_to_string(&mut buf).ok()?;
+
+    buf.lines().rev().find_map(|line| {
+        let value: serde_json::Value = serde_json::from_str(line.trim()).ok()?;
+        let offset = value.get("store_offset")?.as_u64()?;
+        let len = value.get("store_len")?.as_u64()?;
+        Some(offset + len)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(tmp: &Path) -> std::path::PathBuf {
+        let dir = tmp.join("proj");
+        for sub in SUBDIRS {
+            fs::create_dir_all(dir.join(sub)).unwrap();
+        }
+        dir
+    }
+
+    fn write_session(dir: &Path, sid: &str, store: &str, index_end: u64, ingest_unix: i64) {
+        fs::write(dir.join("transcripts").join(format!("{sid}.jsonl")), store).unwrap();
+        fs::write(
+            dir.join("index").join(format!("{sid}.jsonl")),
+            format!(
+                "{{\"store_offset\":0,\"store_len\":{index_end}}}\n",
+                index_end = index_end
+            ),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("state")
+                .join(format!("transcript_cursor.{sid}.json")),
+            format!("{{\"offset\":0,\"file_size\":0,\"mtime_unix\":0,\"updated_at_unix\":{ingest_unix}}}"),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn collect_reports_sizes_and_lag() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = project(tmp.path());
+        write_session(&dir, "s1", "0123456789\n", 6, 1_700_000_000);
+
+        let health = write_health(&dir, "p1").unwrap();
+        assert_eq!(health.sessions.len(), 1);
+        let s = &health.sessions[0];
+        assert_eq!(s.store_bytes, 11);
+        assert_eq!(s.indexed_through, 6);
+        assert_eq!(s.index_lag_bytes, 5);
+        assert_eq!(health.last_ingest_unix, Some(1_700_000_000));
+        assert_eq!(health.dir_bytes["transcripts"], 11);
+        assert_eq!(read_health(&dir), Some(health));
+
+        // Fresh snapshot is not rewritten.
+        assert!(!refresh_if_stale(&dir, "p1", 60).unwrap());
+    }
+
+    #[test]
+    fn diagnose_flags_index_ahead_and_stalled_ingest() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = project(tmp.path());
+        let now = time::OffsetDateTime::now_utc().unix_timestamp();
+        write_session(&dir, "ahead", "abc\n", 40, now);
+        write_session(&dir, "stalled", "abc\n", 4, now - 3600);
+        fs::write(dir.join("state").join("session.stalled.json"), "{}").unwrap();
+        write_session(&dir, "idle", "abc\n", 4, now - 3600);
+
+        let findings = diagnose(&collect(&dir, "p1").unwrap());
+        let codes: Vec<(&str, Option<&str>)> = findings
+            .iter()
+            .map(|f| (f.code, f.session_id.as_deref()))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                ("index_ahead_of_store", Some("ahead")),
+                ("ingest_stalled", Some("stalled")),
+            ]
+        );
+    }
+
+    #[test]
+    fn diagnose_flags_paths_over_windows_max_path() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = project(tmp.path());
+        assert!(diagnose(&collect(&dir, "p1").unwrap()).is_empty());
+
+        let deep = dir
+            .join("packs")
+            .join("p".repeat(150))
+            .join("q".repeat(150));
+        fs::create_dir_all(&deep).unwrap();
+        let health = collect(&dir, "p1").unwrap();
+        assert!(health.longest_path > WINDOWS_MAX_PATH);
+        let codes: Vec<&str> = diagnose(&health).iter().map(|f| f.code).collect();
+        assert_eq!(codes, ["long_path"]);
+    }
+
+    #[test]
+    fn contended_lock_is_counted_by_kind() {
+        let tmp = tempfile::tempdir().unwrap();
+        let lock = tmp.path().join("ingest.abc.lock");
+        record_contention(&lock);
+        record_contention(&tmp.path().join("ingest.def.lock"));
+        assert_eq!(read_contention(tmp.path())["ingest"], 2);
+    }
+}
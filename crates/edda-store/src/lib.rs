@@ -1,5 +1,9 @@
+pub mod canon;
+pub mod duplicates;
 pub mod fleet;
+pub mod health;
 pub mod registry;
+pub mod scratch;
 pub mod skill_registry;
 pub mod user_config;
 
@@ -9,40 +13,41 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 /// Compute a deterministic project ID from a repo root or cwd path.
-/// project_id = blake3(normalize_path(input)) → hex string (first 32 chars).
+/// project_id = blake3(canonical_path(input)) → hex string (first 32 chars).
 ///
 /// If `repo_root_or_cwd` is inside a git worktree, resolves to the main
-/// repository root so that all worktrees share the same project ID.
+/// repository root so that all worktrees share the same project ID. See
+/// [`canon`] for how symlinks, Windows prefixes and case are handled.
 pub fn project_id(repo_root_or_cwd: &Path) -> String {
     let resolved = edda_core::git::resolve_git_root(repo_root_or_cwd)
         .unwrap_or_else(|| repo_root_or_cwd.to_path_buf());
-    let normalized = normalize_path(&resolved);
+    let normalized = canon::canonical_path(&resolved);
     let hash = blake3::hash(normalized.as_bytes());
     hash.to_hex()[..32].to_string()
 }
 
-/// Normalize a path: canonicalize, lowercase on Windows, forward slashes.
-fn normalize_path(p: &Path) -> String {
-    let abs = p
-        .canonicalize()
-        .unwrap_or_else(|_| p.to_path_buf())
-        .to_string_lossy()
-        .to_string();
-    // Lowercase on Windows for consistency
-    #[cfg(windows)]
-    let abs = abs.to_lowercase();
-    // Normalize path separators to forward slashes
-    abs.replace('\\', "/")
-}
-
 /// Return the per-user store root: `~/.edda/`
 /// Windows: `%APPDATA%\edda\` (falls back to `%USERPROFILE%\.edda\`)
 ///
-/// Override with `EDDA_STORE_ROOT` env var (useful for testing).
+/// Override with `EDDA_STORE_ROOT` env var (useful for testing), or with a
+/// `store_root` key in the user config (see [`user_config`]).
 pub fn store_root() -> PathBuf {
     if let Ok(custom) = std::env::var("EDDA_STORE_ROOT") {
         return PathBuf::from(custom);
     }
+    user_config::configured_store_root().unwrap_or_else(default_store_root)
+}
+
+/// Where the user config lives: `EDDA_STORE_ROOT` or the default root, but
+/// never a `store_root` configured in that same file.
+pub(crate) fn config_root() -> PathBuf {
+    match std::env::var("EDDA_STORE_ROOT") {
+        Ok(custom) => PathBuf::from(custom),
+        Err(_) => default_store_root(),
+    }
+}
+
+fn default_store_root() -> PathBuf {
     if let Some(data_dir) = dirs::data_dir() {
         data_dir.join("edda")
     } else if let Some(home) = dirs::home_dir() {
@@ -57,12 +62,30 @@ pub fn project_dir(project_id: &str) -> PathBuf {
     store_root().join("projects").join(project_id)
 }
 
+/// Project subdirectories that can be moved elsewhere per project with
+/// `project_storage` in the user config, e.g. transcripts on a bigger disk.
+pub const RELOCATABLE_SUBDIRS: &[&str] = &["transcripts", "search"];
+
+/// Return a project subdirectory, honoring per-project storage overrides.
+pub fn project_subdir(project_id: &str, name: &str) -> PathBuf {
+    store_subdir(&project_dir(project_id), name)
+}
+
+/// Like [`project_subdir`], for callers that hold the project directory
+/// (`store_root/projects/<project_id>/`) rather than the ID.
+pub fn store_subdir(project_dir: &Path, name: &str) -> PathBuf {
+    project_dir
+        .file_name()
+        .and_then(|id| id.to_str())
+        .and_then(|id| user_config::project_storage_override(id, name))
+        .unwrap_or_else(|| project_dir.join(name))
+}
+
 /// Ensure all subdirectories exist for a project.
 pub fn ensure_dirs(project_id: &str) -> anyhow::Result<()> {
-    let base = project_dir(project_id);
     let subdirs = ["ledger", "transcripts", "index", "packs", "state", "search"];
     for sub in &subdirs {
-        fs::create_dir_all(base.join(sub))?;
+        fs::create_dir_all(project_subdir(project_id, sub))?;
     }
     Ok(())
 }
@@ -80,12 +103,89 @@ pub fn write_atomic(path: &Path, data: &[u8]) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Atomic write of a secret such as a private key. The temp file is
+/// restricted to the owner (0600 on Unix) before any data is written, so
+/// the secret is never readable at the default umask.
+pub fn write_private(path: &Path, data: &[u8]) -> anyhow::Result<()> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("no parent dir for {}", path.display()))?;
+    fs::create_dir_all(parent)?;
+    let mut builder = tempfile::Builder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(fs::Permissions::from_mode(0o600));
+    }
+    let mut tmp = builder.tempfile_in(parent)?;
+    tmp.write_all(data)?;
+    tmp.flush()?;
+    tmp.persist(path)?;
+    Ok(())
+}
+
+/// A temp file left behind by an interrupted atomic write.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct OrphanTempFile {
+    pub path: PathBuf,
+    pub bytes: u64,
+}
+
+/// Whether a file name is one of the temp names atomic writes use:
+/// `.tmpXXXXXX` ([`write_atomic`]), `.tmp_<hash>` (blob store) or
+/// `<name>.tmp`.
+pub fn is_temp_name(name: &str) -> bool {
+    name.starts_with(".tmp") || name.ends_with(".tmp")
+}
+
+/// Find temp files under `dir` (recursively) older than `min_age`, and
+/// delete them unless `dry_run`. The age guard keeps in-flight writes safe.
+pub fn sweep_temp_files(
+    dir: &Path,
+    min_age: std::time::Duration,
+    dry_run: bool,
+) -> anyhow::Result<Vec<OrphanTempFile>> {
+    let mut found = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(found);
+    };
+    for entry in entries.flatten() {
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        let path = entry.path();
+        if meta.is_dir() {
+            found.extend(sweep_temp_files(&path, min_age, dry_run)?);
+            continue;
+        }
+        let age = meta
+            .modified()
+            .ok()
+            .and_then(|m| m.elapsed().ok())
+            .unwrap_or_default();
+        if !is_temp_name(&entry.file_name().to_string_lossy()) || age < min_age {
+            continue;
+        }
+        if !dry_run {
+            fs::remove_file(&path)?;
+        }
+        found.push(OrphanTempFile {
+            path,
+            bytes: meta.len(),
+        });
+    }
+    Ok(found)
+}
+
 /// File-based exclusive lock guard.
 pub struct LockGuard {
     _file: fs::File,
 }
 
 /// Acquire an exclusive file lock. Creates the lock file if needed.
+///
+/// When the lock is already held, the wait is counted in
+/// `lock_contention.json` next to the lock (see [`health`]).
 pub fn lock_file(path: &Path) -> anyhow::Result<LockGuard> {
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
@@ -95,7 +195,10 @@ pub fn lock_file(path: &Path) -> anyhow::Result<LockGuard> {
         .truncate(false)
         .write(true)
         .open(path)?;
-    file.lock_exclusive()?;
+    if file.try_lock_exclusive().is_err() {
+        health::record_contention(path);
+        file.lock_exclusive()?;
+    }
     Ok(LockGuard { _file: file })
 }
 
@@ -122,6 +225,47 @@ mod tests {
         assert!(!root.as_os_str().is_empty());
     }
 
+    #[test]
+    fn store_root_and_project_storage_come_from_user_config() {
+        let _guard = ENV_STORE_LOCK.lock().unwrap();
+        let config = tempfile::tempdir().unwrap();
+        let data = tempfile::tempdir().unwrap();
+        let big_disk = tempfile::tempdir().unwrap();
+        std::env::set_var("EDDA_STORE_ROOT", config.path());
+
+        // The env var wins over the configured root.
+        user_config::set_user_config("store_root", data.path().to_str().unwrap().into()).unwrap();
+        assert_eq!(store_root(), config.path());
+        assert_eq!(
+            user_config::configured_store_root().as_deref(),
+            Some(data.path())
+        );
+
+        let transcripts = big_disk.path().join("transcripts");
+        user_config::set_user_config(
+            "project_storage",
+            serde_json::json!({
+                "p1": { "transcripts": transcripts, "state": "/elsewhere" }
+            }),
+        )
+        .unwrap();
+        assert_eq!(project_subdir("p1", "transcripts"), transcripts);
+        assert_eq!(store_subdir(&project_dir("p1"), "transcripts"), transcripts);
+        // Only relocatable subdirs move, and only for the named project.
+        assert_eq!(
+            project_subdir("p1", "state"),
+            project_dir("p1").join("state")
+        );
+        assert_eq!(
+            project_subdir("p2", "transcripts"),
+            project_dir("p2").join("transcripts")
+        );
+        ensure_dirs("p1").unwrap();
+        assert!(transcripts.is_dir());
+
+        std::env::remove_var("EDDA_STORE_ROOT");
+    }
+
     #[test]
     fn ensure_dirs_creates_subdirs() {
         let tmp = tempfile::tempdir().unwrap();
@@ -144,6 +288,46 @@ mod tests {
         assert_eq!(fs::read_to_string(&path).unwrap(), "hello world");
     }
 
+    #[test]
+    fn write_private_is_owner_only() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("keys").join("secret.key");
+        write_private(&path, b"secret").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"secret");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn sweep_temp_files_only_takes_old_temp_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let nested = tmp.path().join("ledger").join("blobs");
+        fs::create_dir_all(&nested).unwrap();
+        for name in [".tmpAb12Cd", "state.json.tmp"] {
+            fs::write(tmp.path().join(name), "x").unwrap();
+        }
+        fs::write(nested.join(".tmp_abc"), "xy").unwrap();
+        fs::write(tmp.path().join("config.json"), "{}").unwrap();
+
+        // Everything is brand new: nothing is old enough yet.
+        let young =
+            sweep_temp_files(tmp.path(), std::time::Duration::from_secs(3600), false).unwrap();
+        assert!(young.is_empty());
+
+        let dry = sweep_temp_files(tmp.path(), std::time::Duration::ZERO, true).unwrap();
+        assert_eq!(dry.len(), 3);
+        assert!(nested.join(".tmp_abc").exists());
+
+        let swept = sweep_temp_files(tmp.path(), std::time::Duration::ZERO, false).unwrap();
+        assert_eq!(swept.iter().map(|f| f.bytes).sum::<u64>(), 4);
+        assert!(!nested.join(".tmp_abc").exists());
+        assert!(tmp.path().join("config.json").exists());
+    }
+
     #[test]
     fn lock_file_acquires_and_drops() {
         let tmp = tempfile::tempdir().unwrap();
@@ -34,12 +34,12 @@ pub fn registry_path() -> PathBuf {
 }
 
 /// Path to the registry lock file.
-fn registry_lock_path() -> PathBuf {
+pub(crate) fn registry_lock_path() -> PathBuf {
     store_root().join("registry.lock")
 }
 
 /// Load the registry from disk. Returns empty registry if file doesn't exist.
-fn load_registry() -> Registry {
+pub(crate) fn load_registry() -> Registry {
     let path = registry_path();
     match std::fs::read_to_string(&path) {
         Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
@@ -48,7 +48,7 @@ fn load_registry() -> Registry {
 }
 
 /// Save the registry to disk atomically.
-fn save_registry(reg: &Registry) -> anyhow::Result<()> {
+pub(crate) fn save_registry(reg: &Registry) -> anyhow::Result<()> {
     let json = serde_json::to_string_pretty(reg)?;
     write_atomic(&registry_path(), json.as_bytes())
 }
@@ -116,6 +116,45 @@ pub fn get_project(pid: &str) -> Option<ProjectEntry> {
     reg.projects.get(pid).cloned()
 }
 
+/// Resolve a project by exact project_id, unique project_id prefix (4+
+/// characters), or unique name — in that order.
+pub fn find_project(query: &str) -> anyhow::Result<ProjectEntry> {
+    let reg = load_registry();
+    if let Some(entry) = reg.projects.get(query) {
+        return Ok(entry.clone());
+    }
+
+    let pick = |matches: Vec<&ProjectEntry>, what: &str| match matches.as_slice() {
+        [] => Ok(None),
+        [one] => Ok(Some((*one).clone())),
+        many => {
+            let names: Vec<String> = many
+                .iter()
+                .map(|e| format!("{} ({})", e.name, e.project_id))
+                .collect();
+            anyhow::bail!(
+                "{what} '{query}' matches {} projects: {}",
+                many.len(),
+                names.join(", ")
+            )
+        }
+    };
+
+    if query.len() >= 4 {
+        let by_prefix = reg
+            .projects
+            .values()
+            .filter(|e| e.project_id.starts_with(query))
+            .collect();
+        if let Some(entry) = pick(by_prefix, "project id prefix")? {
+            return Ok(entry);
+        }
+    }
+    let by_name = reg.projects.values().filter(|e| e.name == query).collect();
+    pick(by_name, "project name")?
+        .ok_or_else(|| anyhow::anyhow!("unknown project '{query}' (see `edda projects list`)"))
+}
+
 /// Update last_seen timestamp for a project.
 pub fn touch_project(repo_root: &Path) -> anyhow::Result<()> {
     let _lock = lock_file(&registry_lock_path())?;
@@ -270,6 +309,30 @@ mod tests {
         });
     }
 
+    #[test]
+    fn find_project_by_id_prefix_and_name() {
+        with_isolated_store(|| {
+            let a = tempfile::tempdir().unwrap();
+            let infra = a.path().join("infra");
+            std::fs::create_dir_all(&infra).unwrap();
+            register_project(&infra).unwrap();
+            let pid = project_id(&infra);
+
+            assert_eq!(find_project(&pid).unwrap().project_id, pid);
+            assert_eq!(find_project(&pid[..8]).unwrap().project_id, pid);
+            assert_eq!(find_project("infra").unwrap().project_id, pid);
+            assert!(find_project("nope").is_err());
+
+            // Two checkouts with the same directory name are ambiguous by name.
+            let b = tempfile::tempdir().unwrap();
+            let infra2 = b.path().join("infra");
+            std::fs::create_dir_all(&infra2).unwrap();
+            register_project(&infra2).unwrap();
+            let err = find_project("infra").unwrap_err().to_string();
+            assert!(err.contains("matches 2 projects"), "{err}");
+        });
+    }
+
     #[test]
     fn unregister_removes_entry() {
         with_isolated_store(|| {
@@ -0,0 +1,167 @@
+//! Per-session scratchpad.
+//!
+//! `state/scratch.{sid}.json` holds a small key/value map plus one freeform
+//! text block that an agent keeps for itself: working notes, the file it was
+//! halfway through, a hypothesis under test. None of it merits a ledger event,
+//! but all of it should survive a restart, so the bridge injects the pad into
+//! the next SessionStart of the same session (resume, compaction, `/clear`).
+//!
+//! The pad is deliberately small. It is injected verbatim into every
+//! SessionStart of its session, so a write that would push it past
+//! [`MAX_SCRATCH_BYTES`] is refused rather than silently truncated.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use time::format_description::well_known::Rfc3339;
+
+/// Upper bound on the serialized size of one session's pad.
+pub const MAX_SCRATCH_BYTES: usize = 4096;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Scratchpad {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub entries: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl Scratchpad {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.text.trim().is_empty()
+    }
+
+    /// Render as a Markdown section for context injection, or `None` when
+    /// there is nothing to show.
+    pub fn render(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut out = String::from("## Scratchpad (this session)\n");
+        for (k, v) in &self.entries {
+            out.push_str(&format!("- {k}: {v}\n"));
+        }
+        let text = self.text.trim();
+        if !text.is_empty() {
+            if !self.entries.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(text);
+            out.push('\n');
+        }
+        Some(out)
+    }
+}
+
+fn scratch_path(project_id: &str, session_id: &str) -> anyhow::Result<PathBuf> {
+    // The session id becomes part of a file name; never let it escape `state/`.
+    if session_id.is_empty() || session_id.contains(['/', '\\']) || session_id.starts_with('.') {
+        anyhow::bail!("invalid session id for scratchpad: {session_id:?}");
+    }
+    Ok(crate::project_dir(project_id)
+        .join("state")
+        .join(format!("scratch.{session_id}.json")))
+}
+
+/// Read a session's pad. A missing or unreadable file is an empty pad.
+pub fn read(project_id: &str, session_id: &str) -> Scratchpad {
+    scratch_path(project_id, session_id)
+        .ok()
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Apply `f` to a session's pad under a lock and persist the result.
+///
+/// An edit that leaves the pad empty removes the file. An edit that makes it
+/// larger than [`MAX_SCRATCH_BYTES`] is rejected and nothing is written.
+pub fn update(
+    project_id: &str,
+    session_id: &str,
+    f: impl FnOnce(&mut Scratchpad),
+) -> anyhow::Result<Scratchpad> {
+    let path = scratch_path(project_id, session_id)?;
+    let _guard = crate::lock_file(&path.with_extension("lock"))?;
+
+    let mut pad = read(project_id, session_id);
+    f(&mut pad);
+
+    if pad.is_empty() {
+        let _ = std::fs::remove_file(&path);
+        return Ok(Scratchpad::default());
+    }
+
+    pad.updated_at = time::OffsetDateTime::now_utc().format(&Rfc3339).ok();
+    let data = serde_json::to_vec_pretty(&pad)?;
+    if data.len() > MAX_SCRATCH_BYTES {
+        anyhow::bail!(
+            "scratchpad would be {} bytes, over the {MAX_SCRATCH_BYTES}-byte limit; \
+             unset some keys or shorten the text (use `edda note` for anything durable)",
+            data.len()
+        );
+    }
+    crate::write_atomic(&path, &data)?;
+    Ok(pad)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_store<T>(f: impl FnOnce() -> T) -> T {
+        let _guard = crate::ENV_STORE_LOCK.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        std::env::set_var("EDDA_STORE_ROOT", tmp.path());
+        let out = f();
+        std::env::remove_var("EDDA_STORE_ROOT");
+        out
+    }
+
+    #[test]
+    fn update_persists_and_empty_pad_removes_file() {
+        with_store(|| {
+            assert!(read("p1", "s1").is_empty());
+
+            update("p1", "s1", |p| {
+                p.entries.insert("focus".into(), "parser rewrite".into());
+                p.text = "check the CJK tokenizer next".into();
+            })
+            .unwrap();
+            let pad = read("p1", "s1");
+            assert_eq!(pad.entries["focus"], "parser rewrite");
+            assert!(pad.updated_at.is_some());
+            assert!(read("p1", "s2").is_empty(), "pads are per session");
+
+            let section = pad.render().unwrap();
+            assert!(section.contains("- focus: parser rewrite"));
+            assert!(section.contains("CJK tokenizer"));
+
+            update("p1", "s1", |p| *p = Scratchpad::default()).unwrap();
+            assert!(!scratch_path("p1", "s1").unwrap().exists());
+        });
+    }
+
+    #[test]
+    fn oversized_update_is_rejected_without_writing() {
+        with_store(|| {
+            update("p1", "s1", |p| {
+                p.entries.insert("k".into(), "v".into());
+            })
+            .unwrap();
+            let err = update("p1", "s1", |p| p.text = "x".repeat(MAX_SCRATCH_BYTES))
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("limit"), "{err}");
+            assert!(read("p1", "s1").text.is_empty());
+        });
+    }
+
+    #[test]
+    fn session_id_cannot_escape_state_dir() {
+        assert!(scratch_path("p1", "../escape").is_err());
+        assert!(scratch_path("p1", "").is_err());
+    }
+}
@@ -1,14 +1,42 @@
 //! User-level config at `~/.edda/config.json`.
 //!
-//! Provides get/set access to a simple JSON key-value store.
+//! Provides get/set access to a simple JSON key-value store. Two keys shape
+//! the store layout itself:
+//!
+//! - `store_root`: move the whole store (projects, registry, keys) elsewhere.
+//!   The config file stays at the default location so the key can be found.
+//! - `project_storage`: per-project overrides for the subdirectories in
+//!   [`crate::RELOCATABLE_SUBDIRS`], e.g.
+//!   `{"<project_id>": {"transcripts": "/mnt/big/edda/transcripts"}}`.
 
-use crate::{store_root, write_atomic};
+use crate::{config_root, write_atomic, RELOCATABLE_SUBDIRS};
 use serde_json::{Map, Value};
 use std::path::PathBuf;
 
 /// Path to the user-level config file.
 pub fn user_config_path() -> PathBuf {
-    store_root().join("config.json")
+    config_root().join("config.json")
+}
+
+/// The `store_root` key, if set to a non-empty path.
+pub fn configured_store_root() -> Option<PathBuf> {
+    get_user_config("store_root")?
+        .as_str()
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+}
+
+/// The `project_storage` override for one subdirectory of a project.
+pub fn project_storage_override(project_id: &str, subdir: &str) -> Option<PathBuf> {
+    if !RELOCATABLE_SUBDIRS.contains(&subdir) {
+        return None;
+    }
+    get_user_config("project_storage")?
+        .get(project_id)?
+        .get(subdir)?
+        .as_str()
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
 }
 
 /// Load user config from disk. Returns empty map if file doesn't exist.
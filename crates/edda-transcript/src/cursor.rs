@@ -7,6 +7,54 @@ pub struct TranscriptCursor {
     pub file_size: u64,
     pub mtime_unix: i64,
     pub updated_at_unix: i64,
+    /// uuid of the last record stored from a Codex log, so the next delta
+    /// chains onto it (Claude records carry their own `parentUuid`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_uuid: Option<String>,
+    /// Incomplete line found at `offset` on the last ingest. The next ingest
+    /// re-reads from `offset`, and uses this to spot a fragment the writer
+    /// abandoned (see [`PartialTail::abandoned_prefix`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial: Option<PartialTail>,
+}
+
+/// Length and hash of an incomplete trailing JSONL line.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PartialTail {
+    pub len: u64,
+    pub sha256: String,
+}
+
+impl PartialTail {
+    pub fn of(bytes: &[u8]) -> Self {
+        Self {
+            len: bytes.len() as u64,
+            sha256: sha256_hex(bytes),
+        }
+    }
+
+    /// How many leading bytes of `line` are this fragment, left unfinished.
+    ///
+    /// A writer that dies mid-record (or retries a large write) leaves the
+    /// fragment behind and appends the next record straight after it, so the
+    /// two share a line that parses as neither. When `line` starts with the
+    /// fragment and does not parse as a whole, the fragment's length is
+    /// returned so the caller can skip it; otherwise 0.
+    pub fn abandoned_prefix(&self, line: &[u8]) -> usize {
+        let len = self.len as usize;
+        if line.len() < len || sha256_hex(&line[..len]) != self.sha256 {
+            return 0;
+        }
+        match serde_json::from_slice::<serde_json::Value>(line) {
+            Ok(_) => 0,
+            Err(_) => len,
+        }
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(Sha256::digest(bytes))
 }
 
 impl TranscriptCursor {
@@ -30,6 +78,8 @@ impl TranscriptCursor {
     pub fn detect_truncation(&mut self, current_file_size: u64) {
         if current_file_size < self.offset {
             self.offset = 0;
+            self.last_uuid = None;
+            self.partial = None;
         }
     }
 }
@@ -46,6 +96,8 @@ mod tests {
             file_size: 5000,
             mtime_unix: 1700000000,
             updated_at_unix: 1700000001,
+            last_uuid: None,
+            partial: None,
         };
         cursor.save(tmp.path(), "sess1").unwrap();
         let loaded = TranscriptCursor::load(tmp.path(), "sess1")
@@ -69,8 +121,24 @@ mod tests {
             file_size: 5000,
             mtime_unix: 0,
             updated_at_unix: 0,
+            last_uuid: None,
+            partial: None,
         };
         cursor.detect_truncation(3000);
         assert_eq!(cursor.offset, 0);
     }
+
+    #[test]
+    fn abandoned_prefix_only_for_unparseable_lines() {
+        let tail = PartialTail::of(br#"{"type":"user","mess"#);
+        let joined = br#"{"type":"user","mess{"type":"user","uuid":"u2"}"#;
+        assert_eq!(tail.abandoned_prefix(joined), tail.len as usize);
+        // The writer finished the record: nothing to skip.
+        assert_eq!(tail.abandoned_prefix(br#"{"type":"user","message":{}}"#), 0);
+        // A different line that happens to be at the same offset.
+        assert_eq!(
+            tail.abandoned_prefix(br#"{"type":"assistant","mess{"uuid":"a1"}"#),
+            0
+        );
+    }
 }
@@ -0,0 +1,367 @@
+//! Transcript format detection and Codex CLI normalization.
+//!
+//! Downstream readers (index, pack, signal extraction, search) understand
+//! Claude Code's JSONL records. Codex CLI session logs
+//! (`~/.codex/sessions/**/rollout-*.jsonl`) carry the same turns as
+//! role/content items, either wrapped (`{"type":"response_item","payload":{..}}`)
+//! or bare (`{"type":"message","role":"user",..}`). Codex records are
+//! rewritten into the Claude shape at ingest so every reader works unchanged:
+//!
+//! | Codex item                                   | Stored as                               |
+//! |----------------------------------------------|-----------------------------------------|
+//! | `message` (role `user`)                      | `user` with string content              |
+//! | `message` (role `assistant`)                 | `assistant` with a `text` block         |
+//! | `function_call` / `custom_tool_call` / `local_shell_call` | `assistant` with `tool_use` blocks |
+//! | `function_call_output` / `custom_tool_call_output` | `user` with a `tool_result` block |
+//!
+//! Everything else (session meta, reasoning, token counts, injected
+//! environment context) is dropped. Claude records are stored verbatim.
+
+use serde_json::{json, Value};
+
+/// Source format of a transcript record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptFormat {
+    /// Claude Code session JSONL.
+    Claude,
+    /// OpenAI Codex CLI rollout JSONL.
+    Codex,
+}
+
+/// Codex top-level record types (wrapped rollout format).
+const CODEX_WRAPPER_TYPES: &[&str] = &[
+    "response_item",
+    "session_meta",
+    "event_msg",
+    "turn_context",
+    "compacted",
+];
+
+/// Codex item types, which appear bare in older rollout files.
+const CODEX_ITEM_TYPES: &[&str] = &[
+    "message",
+    "reasoning",
+    "function_call",
+    "function_call_output",
+    "custom_tool_call",
+    "custom_tool_call_output",
+    "local_shell_call",
+    "web_search_call",
+];
+
+/// User messages Codex injects on its own; not real prompts.
+const CODEX_INJECTED_PREFIXES: &[&str] = &["<environment_context>", "<user_instructions>"];
+
+/// Detect the format of one parsed transcript record.
+pub fn detect_format(record: &Value) -> TranscriptFormat {
+    if record.get("record_type").is_some() {
+        return TranscriptFormat::Codex;
+    }
+    match record.get("type").and_then(|v| v.as_str()) {
+        Some(t) if CODEX_WRAPPER_TYPES.contains(&t) || CODEX_ITEM_TYPES.contains(&t) => {
+            TranscriptFormat::Codex
+        }
+        // The old rollout header: `{"id":..,"timestamp":..,"instructions":..}`.
+        None if record.get("instructions").is_some() => TranscriptFormat::Codex,
+        _ => TranscriptFormat::Claude,
+    }
+}
+
+/// Label for ingest stats: `codex.<item type>` for Codex records.
+pub(crate) fn codex_record_type(record: &Value) -> String {
+    let item = codex_item(record).unwrap_or(record);
+    let t = item
+        .get("type")
+        .or_else(|| record.get("type"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown");
+    format!("codex.{t}")
+}
+
+fn codex_item(record: &Value) -> Option<&Value> {
+    match record.get("type").and_then(|v| v.as_str()) {
+        Some("response_item") => record.get("payload"),
+        Some(t) if CODEX_ITEM_TYPES.contains(&t) => Some(record),
+        _ => None,
+    }
+}
+
+/// Rewrite a Codex record into a Claude-shaped record, or `None` to drop it.
+///
+/// `uuid`/`parent_uuid` chain the records in file order so pack building can
+/// walk from an assistant reply back to the prompt that started the turn.
+pub fn normalize_codex_record(
+    record: &Value,
+    uuid: &str,
+    parent_uuid: Option<&str>,
+) -> Option<Value> {
+    let item = codex_item(record)?;
+    let ts = record
+        .get("timestamp")
+        .or_else(|| item.get("timestamp"))
+        .cloned()
+        .unwrap_or(Value::Null);
+
+    let (record_type, content) = match item.get("type").and_then(|v| v.as_str())? {
+        "message" => {
+            let text = message_text(item);
+            match item.get("role").and_then(|v| v.as_str())? {
+                "user" => {
+                    let trimmed = text.trim_start();
+                    if trimmed.is_empty()
+                        || CODEX_INJECTED_PREFIXES
+                            .iter()
+                            .any(|p| trimmed.starts_with(p))
+                    {
+                        return None;
+                    }
+                    ("user", Value::String(text))
+                }
+                "assistant" if !text.is_empty() => {
+                    ("assistant", json!([{ "type": "text", "text": text }]))
+                }
+                _ => return None,
+            }
+        }
+        "function_call" | "custom_tool_call" | "local_shell_call" => {
+            ("assistant", Value::Array(tool_use_blocks(item)))
+        }
+        "function_call_output" | "custom_tool_call_output" => {
+            let call_id = item.get("call_id").and_then(|v| v.as_str()).unwrap_or("");
+            (
+                "user",
+                json!([{
+                    "type": "tool_result",
+                    "tool_use_id": call_id,
+                    "content": output_text(item.get("output")),
+                }]),
+            )
+        }
+        _ => return None,
+    };
+
+    Some(json!({
+        "type": record_type,
+        "uuid": uuid,
+        "parentUuid": parent_uuid,
+        "timestamp": ts,
+        "source_format": "codex",
+        "message": { "role": record_type, "content": content },
+    }))
+}
+
+/// Concatenated text of a message's `input_text`/`output_text` blocks.
+fn message_text(item: &Value) -> String {
+    match item.get("content") {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(blocks)) => blocks
+            .iter()
+            .filter(|b| {
+                matches!(
+                    b.get("type").and_then(|t| t.as_str()),
+                    Some("input_text" | "output_text" | "text")
+                )
+            })
+            .filter_map(|b| b.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// Tool output as text; Codex stores it as a string or `{"output": ..}`.
+fn output_text(output: Option<&Value>) -> String {
+    match output {
+        Some(Value::String(s)) => {
+            // function_call_output often holds a JSON string `{"output":..,"metadata":..}`.
+            match serde_json::from_str::<Value>(s) {
+                Ok(Value::Object(o)) => o
+                    .get("output")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                    .unwrap_or_else(|| s.clone()),
+                _ => s.clone(),
+            }
+        }
+        Some(Value::Object(o)) => o
+            .get("output")
+            .or_else(|| o.get("content"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Map a Codex tool call onto Claude-style `tool_use` blocks.
+///
+/// Shell calls become `Bash` with a flat `command` string and `apply_patch`
+/// becomes one `Edit` per patched file, so signal extraction (commands run,
+/// files touched) sees the same names it does for Claude sessions.
+fn tool_use_blocks(item: &Value) -> Vec<Value> {
+    let call_id = item
+        .get("call_id")
+        .or_else(|| item.get("id"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    let name = match item.get("type").and_then(|v| v.as_str()) {
+        Some("local_shell_call") => "shell",
+        _ => item.get("name").and_then(|v| v.as_str()).unwrap_or("tool"),
+    };
+    let input: Value = match item.get("type").and_then(|v| v.as_str()) {
+        Some("local_shell_call") => item.get("action").cloned().unwrap_or_else(|| json!({})),
+        Some("custom_tool_call") => json!({
+            "input": item.get("input").and_then(|v| v.as_str()).unwrap_or("")
+        }),
+        _ => match item.get("arguments") {
+            Some(Value::String(s)) => {
+                serde_json::from_str(s).unwrap_or_else(|_| json!({ "arguments": s }))
+            }
+            Some(v) => v.clone(),
+            None => json!({}),
+        },
+    };
+
+    match name {
+        "shell" | "container.exec" | "exec_command" => {
+            vec![json!({
+                "type": "tool_use",
+                "id": call_id,
+                "name": "Bash",
+                "input": { "command": shell_command(&input) },
+            })]
+        }
+        "apply_patch" => {
+            let patch = input
+                .get("input")
+                .or_else(|| input.get("patch"))
+                .and_then(|v| v.as_str())
+                .unwrap_or("");
+            let blocks: Vec<Value> = patched_files(patch)
+                .into_iter()
+                .map(|path| {
+                    json!({
+                        "type": "tool_use",
+                        "id": call_id,
+                        "name": "Edit",
+                        "input": { "file_path": path },
+                    })
+                })
+                .collect();
+            if blocks.is_empty() {
+                vec![
+                    json!({ "type": "tool_use", "id": call_id, "name": "apply_patch", "input": input }),
+                ]
+            } else {
+                blocks
+            }
+        }
+        _ => vec![json!({ "type": "tool_use", "id": call_id, "name": name, "input": input })],
+    }
+}
+
+/// Shell command line from Codex's `command` argv (`["bash","-lc","…"]`
+/// collapses to the script itself).
+fn shell_command(input: &Value) -> String {
+    match input.get("command").or_else(|| input.get("cmd")) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(argv)) => {
+            let parts: Vec<&str> = argv.iter().filter_map(|v| v.as_str()).collect();
+            match parts.as_slice() {
+                [shell, flag, script] if shell.ends_with("sh") && flag.starts_with('-') => {
+                    script.to_string()
+                }
+                _ => parts.join(" "),
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+/// File paths named in an `apply_patch` body (`*** Add/Update/Delete File: path`).
+fn patched_files(patch: &str) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for line in patch.lines() {
+        let path = ["*** Add File: ", "*** Update File: ", "*** Delete File: "]
+            .iter()
+            .find_map(|p| line.strip_prefix(p));
+        if let Some(path) = path.map(str::trim).filter(|p| !p.is_empty()) {
+            if !out.iter().any(|o| o == path) {
+                out.push(path.to_string());
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_codex_wrapped_and_bare_records() {
+        let wrapped =
+            json!({"timestamp": "t", "type": "response_item", "payload": {"type": "message"}});
+        let bare = json!({"type": "function_call", "name": "shell"});
+        let header = json!({"id": "x", "timestamp": "t", "instructions": null});
+        let claude = json!({"type": "assistant", "uuid": "a1"});
+        assert_eq!(detect_format(&wrapped), TranscriptFormat::Codex);
+        assert_eq!(detect_format(&bare), TranscriptFormat::Codex);
+        assert_eq!(detect_format(&header), TranscriptFormat::Codex);
+        assert_eq!(detect_format(&claude), TranscriptFormat::Claude);
+    }
+
+    #[test]
+    fn normalizes_messages_and_tool_calls() {
+        let user = json!({"timestamp": "2025-09-01T10:00:00Z", "type": "response_item", "payload": {
+            "type": "message", "role": "user",
+            "content": [{"type": "input_text", "text": "fix the login bug"}]
+        }});
+        let out = normalize_codex_record(&user, "codex-0", None).unwrap();
+        assert_eq!(out["type"], "user");
+        assert_eq!(out["message"]["content"], "fix the login bug");
+        assert_eq!(out["timestamp"], "2025-09-01T10:00:00Z");
+        assert!(out["parentUuid"].is_null());
+
+        let call = json!({"type": "response_item", "payload": {
+            "type": "function_call", "name": "shell", "call_id": "call_1",
+            "arguments": "{\"command\":[\"bash\",\"-lc\",\"cargo test\"]}"
+        }});
+        let out = normalize_codex_record(&call, "codex-1", Some("codex-0")).unwrap();
+        assert_eq!(out["type"], "assistant");
+        assert_eq!(out["parentUuid"], "codex-0");
+        let block = &out["message"]["content"][0];
+        assert_eq!(block["name"], "Bash");
+        assert_eq!(block["input"]["command"], "cargo test");
+
+        let result = json!({"type": "function_call_output", "call_id": "call_1",
+            "output": "{\"output\":\"ok\",\"metadata\":{\"exit_code\":0}}"});
+        let out = normalize_codex_record(&result, "codex-2", Some("codex-1")).unwrap();
+        assert_eq!(out["type"], "user");
+        assert_eq!(out["message"]["content"][0]["type"], "tool_result");
+        assert_eq!(out["message"]["content"][0]["content"], "ok");
+
+        let patch = json!({"type": "custom_tool_call", "name": "apply_patch", "call_id": "c2",
+            "input": "*** Begin Patch\n*** Update File: src/a.rs\n@@\n*** Add File: src/b.rs\n*** End Patch"});
+        let out = normalize_codex_record(&patch, "codex-3", None).unwrap();
+        let files: Vec<&str> = out["message"]["content"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|b| b["input"]["file_path"].as_str().unwrap())
+            .collect();
+        assert_eq!(files, ["src/a.rs", "src/b.rs"]);
+    }
+
+    #[test]
+    fn drops_injected_context_and_non_turn_records() {
+        let env = json!({"type": "message", "role": "user",
+            "content": [{"type": "input_text", "text": "<environment_context>\n<cwd>/x</cwd>"}]});
+        assert!(normalize_codex_record(&env, "u", None).is_none());
+        let meta = json!({"type": "session_meta", "payload": {"id": "s"}});
+        assert!(normalize_codex_record(&meta, "u", None).is_none());
+        let reasoning = json!({"type": "response_item", "payload": {"type": "reasoning"}});
+        assert!(normalize_codex_record(&reasoning, "u", None).is_none());
+        assert_eq!(codex_record_type(&reasoning), "codex.reasoning");
+    }
+}
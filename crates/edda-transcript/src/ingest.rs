@@ -1,5 +1,6 @@
-use crate::cursor::TranscriptCursor;
+use crate::cursor::{PartialTail, TranscriptCursor};
 use crate::filter::{classify_record, update_progress_last, FilterAction};
+use crate::format::{codex_record_type, detect_format, normalize_codex_record, TranscriptFormat};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{Read, Seek, SeekFrom, Write};
@@ -22,14 +23,23 @@ pub struct IngestStats {
     pub to_offset: u64,
 }
 
-/// Perform cursor-based delta ingest from a Claude transcript JSONL file.
+/// Perform cursor-based delta ingest from a Claude Code or Codex CLI
+/// transcript JSONL file.
 ///
 /// Reads from `transcript_path` starting at the cursor offset (or 0 if new),
-/// classifies records, writes kept records verbatim to the store,
-/// and returns ingest statistics.
+/// classifies records, writes kept records to the store, and returns ingest
+/// statistics. Claude records are stored verbatim; Codex records are first
+/// rewritten into the Claude shape (see [`crate::format`]).
 ///
 /// If `index_writer` is Some, calls it for each kept record with
 /// (raw_line, store_offset, store_len, parsed_json) for index generation.
+///
+/// Only complete lines are consumed. An incomplete trailing line is hashed
+/// into the cursor, which stays before it, and is read again on the next
+/// ingest. If the writer abandoned it and appended the next record straight
+/// after, the fragment is skipped (counted as `partial_line`) instead of
+/// dropping both as one unparseable line. A single record larger than
+/// `EDDA_TRANSCRIPT_MAX_BYTES` is read whole rather than stalling the cursor.
 pub fn ingest_transcript_delta(
     project_dir: &Path,
     session_id: &str,
@@ -49,6 +59,8 @@ pub fn ingest_transcript_delta(
         file_size: 0,
         mtime_unix: 0,
         updated_at_unix: 0,
+        last_uuid: None,
+        partial: None,
     });
 
     // Check file metadata
@@ -82,9 +94,12 @@ pub fn ingest_transcript_delta(
     file.seek(SeekFrom::Start(cursor.offset))?;
 
     let bytes_to_read = (file_size - cursor.offset).min(max_bytes);
-    let mut buf = vec![0u8; bytes_to_read as usize];
-    let actually_read = file.read(&mut buf)?;
-    buf.truncate(actually_read);
+    let mut buf = Vec::with_capacity(bytes_to_read as usize);
+    (&mut file).take(bytes_to_read).read_to_end(&mut buf)?;
+    if !buf.contains(&b'\n') {
+        // One record larger than the budget (e.g. a big tool result)
+        read_to_line_end(&mut file, &mut buf, file_size - cursor.offset)?;
+    }
 
     // Partial line protection: only consume up to the last newline
     let consumable_len = match buf.iter().rposition(|&b| b == b'\n') {
@@ -92,7 +107,18 @@ pub fn ingest_transcript_delta(
         None => 0, // no complete line
     };
 
+    // Remember an incomplete tail at EOF so the next ingest can tell a line
+    // still being written from one the writer gave up on.
+    let abandoned = cursor.partial.take();
+    let tail = &buf[consumable_len..];
+    if !tail.is_empty() && cursor.offset + buf.len() as u64 >= file_size {
+        cursor.partial = Some(PartialTail::of(tail));
+    }
+
     if consumable_len == 0 {
+        if cursor.partial != abandoned {
+            cursor.save(&state_dir, session_id)?;
+        }
         return Ok(IngestStats {
             records_read: 0,
             records_kept: 0,
@@ -109,7 +135,7 @@ pub fn ingest_transcript_delta(
     let data = &buf[..consumable_len];
 
     // Prepare store path (verbatim append)
-    let transcripts_dir = project_dir.join("transcripts");
+    let transcripts_dir = edda_store::store_subdir(project_dir, "transcripts");
     std::fs::create_dir_all(&transcripts_dir)?;
     let store_path = transcripts_dir.join(format!("{session_id}.jsonl"));
     let mut store_file = std::fs::OpenOptions::new()
@@ -137,15 +163,30 @@ pub fn ingest_transcript_delta(
         to_offset: from_offset + consumable_len as u64,
     };
 
+    // Skip a fragment the writer abandoned before appending the next record
+    let first_line = data.split(|&b| b == b'\n').next().unwrap_or_default();
+    let skip = abandoned.map_or(0, |p| p.abandoned_prefix(first_line));
+    if skip > 0 {
+        stats.records_read += 1;
+        stats.records_dropped += 1;
+        *stats
+            .dropped_by_type
+            .entry("partial_line".into())
+            .or_insert(0) += 1;
+    }
+
     // Process line by line
-    for raw_line in data.split(|&b| b == b'\n') {
+    let mut line_offset = from_offset + skip as u64;
+    for raw_line in data[skip..].split(|&b| b == b'\n') {
+        let source_offset = line_offset;
+        line_offset += raw_line.len() as u64 + 1;
         if raw_line.is_empty() {
             continue;
         }
 
         stats.records_read += 1;
 
-        let parsed: serde_json::Value = match serde_json::from_slice(raw_line) {
+        let mut parsed: serde_json::Value = match serde_json::from_slice(raw_line) {
             Ok(v) => v,
             Err(_) => {
                 stats.records_dropped += 1;
@@ -157,6 +198,30 @@ pub fn ingest_transcript_delta(
             }
         };
 
+        // Codex records: rewrite into the Claude shape, chained by a uuid
+        // derived from the source offset so re-ingest is deterministic.
+        let normalized_line: Vec<u8>;
+        let mut raw_line = raw_line;
+        if detect_format(&parsed) == TranscriptFormat::Codex {
+            let uuid = format!("codex-{source_offset}");
+            match normalize_codex_record(&parsed, &uuid, cursor.last_uuid.as_deref()) {
+                Some(record) => {
+                    normalized_line = serde_json::to_vec(&record)?;
+                    raw_line = &normalized_line;
+                    parsed = record;
+                    cursor.last_uuid = Some(uuid);
+                }
+                None => {
+                    stats.records_dropped += 1;
+                    *stats
+                        .dropped_by_type
+                        .entry(codex_record_type(&parsed))
+                        .or_insert(0) += 1;
+                    continue;
+                }
+            }
+        }
+
         let record_type = parsed
             .get("type")
             .and_then(|v| v.as_str())
@@ -168,7 +233,8 @@ pub fn ingest_transcript_delta(
                 // Record store_offset before write
                 let store_offset = store_file.seek(SeekFrom::End(0)).unwrap_or(0);
 
-                // Write raw line verbatim (CONTRACT BRIDGE-03)
+                // Write raw line verbatim (CONTRACT BRIDGE-03); Codex lines
+                // are the normalized record.
                 store_file.write_all(raw_line)?;
                 store_file.write_all(b"\n")?;
 
@@ -211,6 +277,27 @@ pub fn ingest_transcript_delta(
     Ok(stats)
 }
 
+/// Keep reading until `buf` holds a newline, EOF, or `limit` bytes.
+fn read_to_line_end(
+    file: &mut std::fs::File,
+    buf: &mut Vec<u8>,
+    limit: u64,
+) -> std::io::Result<()> {
+    let mut chunk = vec![0u8; 1024 * 1024];
+    while (buf.len() as u64) < limit {
+        let want = chunk.len().min((limit - buf.len() as u64) as usize);
+        let n = file.read(&mut chunk[..want])?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if chunk[..n].contains(&b'\n') {
+            break;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +387,158 @@ mod tests {
         assert_eq!(content.lines().count(), 2);
     }
 
+    #[test]
+    fn ingest_codex_rollout_normalizes_and_chains_across_deltas() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project_dir = tmp.path().join("project");
+        std::fs::create_dir_all(&project_dir).unwrap();
+
+        let transcript = write_transcript(
+            tmp.path(),
+            &[
+                r#"{"timestamp":"2025-09-01T10:00:00Z","type":"session_meta","payload":{"id":"s1","cwd":"/repo"}}"#,
+                r#"{"timestamp":"2025-09-01T10:00:01Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"run the tests"}]}}"#,
+                r#"{"timestamp":"2025-09-01T10:00:02Z","type":"response_item","payload":{"type":"reasoning","summary":[]}}"#,
+                r#"{"timestamp":"2025-09-01T10:00:03Z","type":"response_item","payload":{"type":"function_call","name":"shell","call_id":"c1","arguments":"{\"command\":[\"bash\",\"-lc\",\"cargo test\"]}"}}"#,
+            ],
+        );
+        let stats = ingest_transcript_delta(&project_dir, "codex1", &transcript, None).unwrap();
+        assert_eq!(stats.records_read, 4);
+        assert_eq!(stats.records_kept, 2);
+        assert_eq!(stats.dropped_by_type.get("codex.session_meta"), Some(&1));
+        assert_eq!(stats.dropped_by_type.get("codex.reasoning"), Some(&1));
+
+        // Second delta chains onto the last stored record.
+        let mut f = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&transcript)
+            .unwrap();
+        writeln!(
+            f,
+            r#"{{"timestamp":"2025-09-01T10:00:09Z","type":"response_item","payload":{{"type":"message","role":"assistant","content":[{{"type":"output_text","text":"All tests pass."}}]}}}}"#
+        )
+        .unwrap();
+        let stats = ingest_transcript_delta(&project_dir, "codex1", &transcript, None).unwrap();
+        assert_eq!(stats.records_kept, 1);
+
+        let store = project_dir.join("transcripts").join("codex1.jsonl");
+        let records: Vec<serde_json::Value> = std::fs::read_to_string(&store)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        let types: Vec<&str> = records
+            .iter()
+            .map(|r| r["type"].as_str().unwrap())
+            .collect();
+        assert_eq!(types, ["user", "assistant", "assistant"]);
+        assert!(records[0]["parentUuid"].is_null());
+        assert_eq!(records[1]["parentUuid"], records[0]["uuid"]);
+        assert_eq!(records[2]["parentUuid"], records[1]["uuid"]);
+        assert_eq!(records[1]["message"]["content"][0]["name"], "Bash");
+
+        assert_eq!(
+            crate::extract_last_assistant_text(&store, 100).as_deref(),
+            Some("All tests pass.")
+        );
+    }
+
+    fn append(path: &Path, bytes: &str) {
+        let mut f = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        f.write_all(bytes.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn ingest_partial_tail_is_revisited_when_completed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project_dir = tmp.path().join("project");
+        let transcript = tmp.path().join("transcript.jsonl");
+        let first = r#"{"type":"user","uuid":"u1","message":{"content":"hi"}}"#;
+        append(&transcript, &format!("{first}\n{{\"type\":\"user\",\"uu"));
+
+        let stats = ingest_transcript_delta(&project_dir, "sess1", &transcript, None).unwrap();
+        assert_eq!(stats.records_kept, 1);
+        assert_eq!(stats.to_offset, first.len() as u64 + 1);
+        let cursor = TranscriptCursor::load(&project_dir.join("state"), "sess1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(cursor.partial.unwrap().len, 18);
+
+        // Still incomplete: nothing consumed, cursor stays before it.
+        let stats = ingest_transcript_delta(&project_dir, "sess1", &transcript, None).unwrap();
+        assert_eq!(
+            (stats.records_read, stats.from_offset),
+            (0, first.len() as u64 + 1)
+        );
+
+        append(
+            &transcript,
+            "id\":\"u2\",\"message\":{\"content\":\"more\"}}\n",
+        );
+        let stats = ingest_transcript_delta(&project_dir, "sess1", &transcript, None).unwrap();
+        assert_eq!(stats.records_kept, 1);
+        let store = project_dir.join("transcripts").join("sess1.jsonl");
+        let content = std::fs::read_to_string(&store).unwrap();
+        assert!(content.lines().nth(1).unwrap().contains("\"uuid\":\"u2\""));
+        let cursor = TranscriptCursor::load(&project_dir.join("state"), "sess1")
+            .unwrap()
+            .unwrap();
+        assert!(cursor.partial.is_none());
+    }
+
+    #[test]
+    fn ingest_skips_abandoned_fragment_and_keeps_index_offsets() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project_dir = tmp.path().join("project");
+        let transcript = tmp.path().join("transcript.jsonl");
+        append(&transcript, r#"{"type":"assistant","uuid":"a1","mess"#);
+        ingest_transcript_delta(&project_dir, "sess1", &transcript, None).unwrap();
+
+        // The writer gave up on the fragment and appended the next record.
+        let next = r#"{"type":"user","uuid":"u2","message":{"content":"retry"}}"#;
+        append(&transcript, &format!("{next}\n"));
+
+        let offsets = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = offsets.clone();
+        let writer = move |raw: &str, offset: u64, len: u64, _: &serde_json::Value| {
+            seen.lock().unwrap().push((raw.to_string(), offset, len));
+            Ok(())
+        };
+        let stats =
+            ingest_transcript_delta(&project_dir, "sess1", &transcript, Some(&writer)).unwrap();
+        assert_eq!(stats.records_kept, 1);
+        assert_eq!(stats.dropped_by_type.get("partial_line"), Some(&1));
+        assert!(!stats.dropped_by_type.contains_key("parse_error"));
+
+        let offsets = offsets.lock().unwrap();
+        assert_eq!(*offsets, [(next.to_string(), 0, next.len() as u64 + 1)]);
+        let store = project_dir.join("transcripts").join("sess1.jsonl");
+        assert_eq!(
+            std::fs::read_to_string(&store).unwrap(),
+            format!("{next}\n")
+        );
+    }
+
+    #[test]
+    fn oversized_record_is_read_to_its_line_end() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("big.jsonl");
+        let big = "x".repeat(3 * 1024 * 1024);
+        append(&path, &format!("{big}\nnext\n"));
+
+        let mut file = std::fs::File::open(&path).unwrap();
+        let mut buf = Vec::new();
+        (&mut file).take(1024).read_to_end(&mut buf).unwrap();
+        let size = std::fs::metadata(&path).unwrap().len();
+        read_to_line_end(&mut file, &mut buf, size).unwrap();
+        assert!(buf.len() > big.len());
+        assert_eq!(buf.iter().position(|&b| b == b'\n'), Some(big.len()));
+    }
+
     #[test]
     fn ingest_with_index_writer() {
         let tmp = tempfile::tempdir().unwrap();
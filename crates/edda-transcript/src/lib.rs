@@ -1,9 +1,11 @@
 mod cursor;
 mod extract;
 mod filter;
+mod format;
 mod ingest;
 
 pub use cursor::TranscriptCursor;
 pub use extract::extract_last_assistant_text;
 pub use filter::{classify_record, FilterAction};
+pub use format::{detect_format, normalize_codex_record, TranscriptFormat};
 pub use ingest::{ingest_transcript_delta, IngestStats};